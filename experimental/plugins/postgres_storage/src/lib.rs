@@ -50,6 +50,10 @@ use std::sync::Mutex;
 use std::str;
 
 pub static POSTGRES_STORAGE_NAME: &str = "postgres_storage";
+// Extended WQL operators executed by the storage, they are allowed for unencrypted tags only:
+// $contains - tag value is JSON array of strings that contains all the given strings
+// $match - full-text search of the given words in tag value
+pub static QUERY_EXTENSIONS: &str = r#"["$contains", "$match"]"#;
 
 
 #[no_mangle]
@@ -60,7 +64,7 @@ pub extern fn postgresstorage_init() -> libindy::ErrorCode {
         return err;
     }
 
-    libindy::wallet::register_wallet_storage(
+    let err = libindy::wallet::register_wallet_storage(
         postgres_storage_name.as_ptr(),
         PostgresWallet::create,
         PostgresWallet::open,
@@ -86,7 +90,14 @@ pub extern fn postgresstorage_init() -> libindy::ErrorCode {
         PostgresWallet::get_search_total_count,
         PostgresWallet::fetch_search_next_record,
         PostgresWallet::free_search,
-    )
+    );
+
+    if err != libindy::ErrorCode::Success {
+        return err;
+    }
+
+    let query_extensions = CString::new(QUERY_EXTENSIONS).unwrap();
+    libindy::wallet::set_wallet_storage_query_extensions(postgres_storage_name.as_ptr(), query_extensions.as_ptr())
 }
 
 #[no_mangle]
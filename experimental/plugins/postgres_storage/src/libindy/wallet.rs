@@ -394,7 +394,35 @@ pub fn register_wallet_storage(
     receiver.recv().unwrap()
 }
 
+pub fn set_wallet_storage_query_extensions(wallet_storage_name: *const c_char, extensions_json: *const c_char) -> ErrorCode {
+    let (sender, receiver) = channel();
+
+    let closure: Box<dyn FnMut(ErrorCode) + Send> = Box::new(move |err| {
+        sender.send(err).unwrap();
+    });
+
+    let (cmd_handle, cb) = callbacks::closure_to_cb_ec(closure);
+
+    unsafe {
+        indy_set_wallet_storage_query_extensions(
+            cmd_handle,
+            wallet_storage_name,
+            extensions_json,
+            cb,
+        );
+    }
+
+    receiver.recv().unwrap()
+}
+
 extern {
+    #[no_mangle]
+    pub fn indy_set_wallet_storage_query_extensions(command_handle: IndyHandle,
+                                                    type_: *const c_char,
+                                                    extensions_json: *const c_char,
+                                                    cb: Option<extern fn(command_handle_: IndyHandle,
+                                                                         err: ErrorCode)>) -> ErrorCode;
+
     #[no_mangle]
     pub fn indy_register_wallet_storage(command_handle: IndyHandle,
                                             type_: *const c_char,
@@ -75,6 +75,8 @@ pub enum Operator {
     Lte(TagName, TargetValue),
     Like(TagName, TargetValue),
     In(TagName, Vec<TargetValue>),
+    Contains(TagName, Vec<TargetValue>),
+    Match(TagName, TargetValue),
 }
 
 
@@ -159,6 +161,14 @@ impl string::ToString for Operator {
                 } else { "{}".to_string() }
             },
             Operator::Not(ref stmt) => format!(r#"{{"$not":{}}}"#, stmt.to_string()),
+            Operator::Contains(ref tag_name, ref tag_values) => {
+                format!(
+                    r#"{{{}:{{"$contains":[{}]}}}}"#,
+                    tag_name.to_string(),
+                    tag_values.iter().map(|v| v.to_string()).collect::<Vec<String>>().join(",")
+                )
+            }
+            Operator::Match(ref tag_name, ref tag_value) => format!(r#"{{{}:{{"$match":{}}}}}"#, tag_name.to_string(), tag_value.to_string()),
         }
     }
 }
@@ -328,6 +338,38 @@ fn parse_single_operator(operator_name: String, key: String, value: serde_json::
             Ok(Operator::In(target_name, target_values))
         }
         ("$in", _) => Err(WalletQueryError::ValueErr("$in must be used with array of strings".to_string())),
+        // Query extensions advertised to libindy, see QUERY_EXTENSIONS
+        ("$contains", serde_json::Value::Array(values)) => {
+            let target_name = _build_target_name(&key, encrypted);
+
+            if let TagName::EncryptedTagName(_) = target_name {
+                return Err(WalletQueryError::StructureErr("$contains must be used only for nonencrypted tag".to_string()));
+            }
+
+            let mut target_values: Vec<TargetValue> = Vec::new();
+
+            for v in values.into_iter() {
+                if let serde_json::Value::String(s) = v {
+                    target_values.push(_build_target_value(&target_name, &s, encrypted));
+                } else {
+                    return Err(WalletQueryError::ValueErr("$contains must be used with array of strings".to_string()));
+                }
+            }
+
+            Ok(Operator::Contains(target_name, target_values))
+        }
+        ("$contains", _) => Err(WalletQueryError::ValueErr("$contains must be used with array of strings".to_string())),
+        ("$match", serde_json::Value::String(s)) => {
+            let target_name = _build_target_name(&key, encrypted);
+            match target_name {
+                TagName::PlainTagName(_) => {
+                    let target_value = _build_target_value(&target_name, &s, encrypted);
+                    Ok(Operator::Match(target_name, target_value))
+                },
+                TagName::EncryptedTagName(_) => Err(WalletQueryError::StructureErr("$match must be used only for nonencrypted tag".to_string()))
+            }
+        }
+        ("$match", _) => Err(WalletQueryError::ValueErr("$match must be used with string".to_string())),
         (_, _) => Err(WalletQueryError::ValueErr(format!("Bad operator: {}", operator_name)))
     }
 }
@@ -401,7 +443,11 @@ mod tests {
                 | (Operator::Like(name, value), Operator::Like(other_name, other_value)) => {
                     name == other_name && value == other_value
                 },
-                (Operator::In(name, values), Operator::In(other_name, other_values)) => {
+                (Operator::Match(name, value), Operator::Match(other_name, other_value)) => {
+                    name == other_name && value == other_value
+                },
+                (Operator::In(name, values), Operator::In(other_name, other_values))
+                | (Operator::Contains(name, values), Operator::Contains(other_name, other_values)) => {
                     name == other_name && vec_to_set(values) == vec_to_set(other_values)
                 },
                 (Operator::Not(operator), Operator::Not(other_operator)) => operator == other_operator,
@@ -653,6 +699,31 @@ mod tests {
         assert_eq!(query, expected);
     }
 
+    #[test]
+    fn test_simple_operator_contains_plaintext_parse() {
+        let name1 = _random_string(10);
+        let value1 = _random_string(10);
+        let value2 = _random_string(10);
+
+        let json = format!(r#"{{"~{}":{{"$contains":["{}","{}"]}}}}"#, name1, value1, value2);
+
+        let query = parse_from_json(&json).unwrap();
+
+        let expected = Operator::Contains(
+            TagName::PlainTagName(name1.to_vec()),
+            vec![TargetValue::Unencrypted(value1.clone()), TargetValue::Unencrypted(value2.clone())]
+        );
+
+        assert_eq!(query, expected);
+    }
+
+    #[test]
+    fn test_simple_operator_match_encrypted_parse() {
+        let json = format!(r#"{{"{}":{{"$match":"{}"}}}}"#, _random_string(10), _random_string(10));
+
+        parse_from_json(&json).unwrap_err();
+    }
+
     #[test]
     fn test_simple_operator_in_plaintext_parse() {
         let name1 = _random_string(10);
@@ -56,6 +56,8 @@ fn operator_to_sql<'a>(op: &'a Operator, arguments: &mut Vec<&'a dyn ToSql>) ->
         Operator::And(ref suboperators) => and_to_sql(suboperators, arguments),
         Operator::Or(ref suboperators) => or_to_sql(suboperators, arguments),
         Operator::Not(ref suboperator) => not_to_sql(suboperator, arguments),
+        Operator::Contains(ref tag_name, ref target_values) => contains_to_sql(tag_name, target_values, arguments),
+        Operator::Match(ref tag_name, ref target_value) => match_to_sql(tag_name, target_value, arguments),
     }
 }
 
@@ -208,6 +210,41 @@ fn or_to_sql<'a>(suboperators: &'a [Operator], arguments: &mut Vec<&'a dyn ToSql
 }
 
 
+// Tag value must be JSON array of strings, matches if it contains all queried values
+fn contains_to_sql<'a>(name: &'a TagName, values: &'a Vec<TargetValue>, arguments: &mut Vec<&'a dyn ToSql>) -> Result<String, WalletQueryError> {
+    match name {
+        &TagName::PlainTagName(ref queried_name) => {
+            let mut contains_string = "(i.id in (SELECT item_id FROM tags_plaintext WHERE name = $$ AND value LIKE '[%'".to_string();
+            arguments.push(queried_name);
+
+            for value in values.iter() {
+                if let &TargetValue::Unencrypted(ref target) = value {
+                    contains_string.push_str(" AND value::jsonb ? $$");
+                    arguments.push(target);
+                } else {
+                    return Err(WalletQueryError::StructureErr("Encrypted tag value in $contains for nonencrypted tag name".to_string()))
+                }
+            }
+
+            Ok(contains_string + "))")
+        },
+        _ => Err(WalletQueryError::StructureErr("$contains must be used only for nonencrypted tag".to_string()))
+    }
+}
+
+
+fn match_to_sql<'a>(name: &'a TagName, value: &'a TargetValue, arguments: &mut Vec<&'a dyn ToSql>) -> Result<String, WalletQueryError> {
+    match (name, value) {
+        (&TagName::PlainTagName(ref queried_name), &TargetValue::Unencrypted(ref queried_value)) => {
+            arguments.push(queried_name);
+            arguments.push(queried_value);
+            Ok("(i.id in (SELECT item_id FROM tags_plaintext WHERE name = $$ AND to_tsvector('simple', value) @@ plainto_tsquery('simple', $$)))".to_string())
+        },
+        _ => Err(WalletQueryError::StructureErr("Invalid combination of tag name and value for $match operator".to_string()))
+    }
+}
+
+
 fn not_to_sql<'a>(suboperator: &'a Operator, arguments: &mut Vec<&'a dyn ToSql>) -> Result<String, WalletQueryError> {
     let suboperator_string = operator_to_sql(suboperator, arguments)?;
     Ok("NOT (".to_string() + &suboperator_string + ")")
@@ -17,6 +17,19 @@ extern "C" {
                                                                        const char*   schema_json)
                                                   );
 
+    /// Variant of `indy_issuer_create_schema` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_issuer_create_schema_ctx(const void*    context,
+                                                      const char *  issuer_did,
+                                                      const char *  name,
+                                                      const char *  version,
+                                                      const char *  attr_names,
+
+                                                      void           (*cb)(const void*    context,
+                                                                           indy_error_t  err,
+                                                                           const char*   id,
+                                                                           const char*   schema_json)
+                                                      );
+
     extern indy_error_t indy_issuer_create_and_store_credential_def(indy_handle_t command_handle,
                                                                     indy_handle_t wallet_handle,
                                                                     const char *  issuer_did,
@@ -31,6 +44,21 @@ extern "C" {
                                                                                          const char*   cred_def_json)
                                                                     );
 
+    /// Variant of `indy_issuer_create_and_store_credential_def` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_issuer_create_and_store_credential_def_ctx(const void*    context,
+                                                                        indy_handle_t wallet_handle,
+                                                                        const char *  issuer_did,
+                                                                        const char *  schema_json,
+                                                                        const char *  tag,
+                                                                        const char *  signature_type,
+                                                                        const char *  config_json,
+
+                                                                        void           (*cb)(const void*    context,
+                                                                                             indy_error_t  err,
+                                                                                             const char*   cred_def_id,
+                                                                                             const char*   cred_def_json)
+                                                                        );
+
     extern indy_error_t indy_issuer_rotate_credential_def_start(indy_handle_t command_handle,
                                                                 indy_handle_t wallet_handle,
                                                                 const char *  cred_def_id,
@@ -41,6 +69,17 @@ extern "C" {
                                                                                      const char*   cred_def_json)
                                                                 );
 
+    /// Variant of `indy_issuer_rotate_credential_def_start` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_issuer_rotate_credential_def_start_ctx(const void*    context,
+                                                                    indy_handle_t wallet_handle,
+                                                                    const char *  cred_def_id,
+                                                                    const char *  config_json,
+
+                                                                    void           (*cb)(const void*    context,
+                                                                                         indy_error_t  err,
+                                                                                         const char*   cred_def_json)
+                                                                    );
+
     extern indy_error_t indy_issuer_rotate_credential_def_apply(indy_handle_t command_handle,
                                                                 indy_handle_t wallet_handle,
                                                                 const char *  cred_def_id,
@@ -48,6 +87,15 @@ extern "C" {
                                                                 void           (*cb)(indy_handle_t command_handle_,
                                                                                      indy_error_t  err)
                                                                 );
+
+    /// Variant of `indy_issuer_rotate_credential_def_apply` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_issuer_rotate_credential_def_apply_ctx(const void*    context,
+                                                                    indy_handle_t wallet_handle,
+                                                                    const char *  cred_def_id,
+
+                                                                    void           (*cb)(const void*    context,
+                                                                                         indy_error_t  err)
+                                                                    );
     
     extern indy_error_t indy_issuer_create_and_store_revoc_reg(indy_handle_t command_handle,
                                                                indy_handle_t wallet_handle,
@@ -65,6 +113,23 @@ extern "C" {
                                                                                     const char*   revoc_reg_entry_json)
                                                                );
 
+    /// Variant of `indy_issuer_create_and_store_revoc_reg` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_issuer_create_and_store_revoc_reg_ctx(const void*    context,
+                                                                   indy_handle_t wallet_handle,
+                                                                   const char *  issuer_did,
+                                                                   const char *  revoc_def_type,
+                                                                   const char *  tag,
+                                                                   const char *  cred_def_id,
+                                                                   const char *  config_json,
+                                                                   indy_handle_t tails_writer_handle,
+
+                                                                   void           (*cb)(const void*    context,
+                                                                                        indy_error_t  err,
+                                                                                        const char*   revoc_reg_id,
+                                                                                        const char*   revoc_reg_def_json,
+                                                                                        const char*   revoc_reg_entry_json)
+                                                                   );
+
     extern indy_error_t indy_issuer_create_credential_offer(indy_handle_t command_handle,
                                                             indy_handle_t wallet_handle,
                                                             const char *  cred_def_id,
@@ -73,6 +138,16 @@ extern "C" {
                                                                                  indy_error_t  err,
                                                                                  const char*   cred_offer_json)
                                                             );
+
+    /// Variant of `indy_issuer_create_credential_offer` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_issuer_create_credential_offer_ctx(const void*    context,
+                                                                indy_handle_t wallet_handle,
+                                                                const char *  cred_def_id,
+
+                                                                void           (*cb)(const void*    context,
+                                                                                     indy_error_t  err,
+                                                                                     const char*   cred_offer_json)
+                                                                );
     
     extern indy_error_t indy_issuer_create_credential(indy_handle_t command_handle,
                                                       indy_handle_t wallet_handle,
@@ -88,6 +163,22 @@ extern "C" {
                                                                            const char*   cred_revoc_id,
                                                                            const char*   revoc_reg_delta_json)
                                                       );
+
+    /// Variant of `indy_issuer_create_credential` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_issuer_create_credential_ctx(const void*    context,
+                                                          indy_handle_t wallet_handle,
+                                                          const char *  cred_offer_json,
+                                                          const char *  cred_req_json,
+                                                          const char *  cred_values_json,
+                                                          const char *  rev_reg_id,
+                                                          indy_handle_t blob_storage_reader_handle,
+
+                                                          void           (*cb)(const void*    context,
+                                                                               indy_error_t  err,
+                                                                               const char*   cred_json,
+                                                                               const char*   cred_revoc_id,
+                                                                               const char*   revoc_reg_delta_json)
+                                                          );
     
     extern indy_error_t indy_issuer_revoke_credential(indy_handle_t command_handle,
                                                       indy_handle_t wallet_handle,
@@ -100,6 +191,18 @@ extern "C" {
                                                                            const char*   revoc_reg_delta_json)
                                                       );
 
+    /// Variant of `indy_issuer_revoke_credential` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_issuer_revoke_credential_ctx(const void*    context,
+                                                          indy_handle_t wallet_handle,
+                                                          indy_handle_t blob_storage_reader_handle,
+                                                          const char *  rev_reg_id,
+                                                          const char *  cred_revoc_id,
+
+                                                          void           (*cb)(const void*    context,
+                                                                               indy_error_t  err,
+                                                                               const char*   revoc_reg_delta_json)
+                                                          );
+
 /*    extern indy_error_t indy_issuer_recover_credential(indy_handle_t command_handle,
                                                        indy_handle_t wallet_handle,
                                                        indy_handle_t blob_storage_reader_handle,
@@ -121,6 +224,38 @@ extern "C" {
                                                                                           const char*   merged_rev_reg_delta)
                                                                      );
 
+    /// Variant of `indy_issuer_merge_revocation_registry_deltas` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_issuer_merge_revocation_registry_deltas_ctx(const void*    context,
+                                                                         const char *  rev_reg_delta_json,
+                                                                         const char *  other_rev_reg_delta_json,
+
+                                                                         void           (*cb)(const void*    context,
+                                                                                              indy_error_t  err,
+                                                                                              const char*   merged_rev_reg_delta)
+                                                                         );
+
+    /// Variant of `indy_issuer_recover_credential` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_issuer_recover_credential_ctx(const void*    context,
+                                                           indy_handle_t wallet_handle,
+                                                           indy_handle_t blob_storage_reader_handle,
+                                                           const char *  rev_reg_id,
+                                                           const char *  cred_revoc_id,
+
+                                                           void           (*cb)(indy_handle_t command_handle_,
+                                                                                indy_error_t  err,
+                                                                                const char*   revoc_reg_delta_json)
+                                                           );*/
+
+
+        extern indy_error_t indy_issuer_merge_revocation_registry_deltas(indy_handle_t command_handle,
+                                                                         const char *  rev_reg_delta_json,
+                                                                         const char *  other_rev_reg_delta_json,
+
+                                                                         void           (*cb)(const void*    context,
+                                                                                              indy_error_t  err,
+                                                                                              const char*   merged_rev_reg_delta)
+                                                                         );
+
     extern indy_error_t indy_prover_create_master_secret(indy_handle_t command_handle,
                                                          indy_handle_t wallet_handle,
                                                          const char *  master_secret_id,
@@ -129,6 +264,16 @@ extern "C" {
                                                                               indy_error_t  err,
                                                                               const char*   out_master_secret_id)
                                                          );
+
+    /// Variant of `indy_prover_create_master_secret` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_prover_create_master_secret_ctx(const void*    context,
+                                                             indy_handle_t wallet_handle,
+                                                             const char *  master_secret_id,
+
+                                                             void           (*cb)(const void*    context,
+                                                                                  indy_error_t  err,
+                                                                                  const char*   out_master_secret_id)
+                                                             );
     
     
     extern indy_error_t indy_prover_create_credential_req(indy_handle_t command_handle,
@@ -144,6 +289,39 @@ extern "C" {
                                                                                const char*   cred_req_metadata_json)
                                                           );
 
+    /// Variant of `indy_prover_create_credential_req` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_prover_create_credential_req_ctx(const void*    context,
+                                                              indy_handle_t wallet_handle,
+                                                              const char *  prover_did,
+                                                              const char *  cred_offer_json,
+                                                              const char *  cred_def_json,
+                                                              const char *  master_secret_id,
+
+                                                              void           (*cb)(const void*    context,
+                                                                                   indy_error_t  err,
+                                                                                   const char*   cred_req_json,
+                                                                                   const char*   cred_req_metadata_json)
+                                                              );
+
+    extern indy_error_t indy_prover_parse_credential_offer(indy_handle_t command_handle,
+                                                           const char *  cred_offer_json,
+                                                           const char *  cred_def_json,
+
+                                                           void           (*cb)(indy_handle_t command_handle_,
+                                                                                indy_error_t  err,
+                                                                                const char*   parsed_offer_json)
+                                                           );
+
+    /// Variant of `indy_prover_parse_credential_offer` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_prover_parse_credential_offer_ctx(const void*    context,
+                                                               const char *  cred_offer_json,
+                                                               const char *  cred_def_json,
+
+                                                               void           (*cb)(const void*    context,
+                                                                                    indy_error_t  err,
+                                                                                    const char*   parsed_offer_json)
+                                                               );
+
     extern indy_error_t indy_prover_store_credential(indy_handle_t command_handle,
                                                      indy_handle_t wallet_handle,
                                                      const char *  cred_id,
@@ -157,6 +335,76 @@ extern "C" {
                                                                           const char*   out_cred_id)
                                                      );
 
+    /// Variant of `indy_prover_store_credential` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_prover_store_credential_ctx(const void*    context,
+                                                         indy_handle_t wallet_handle,
+                                                         const char *  cred_id,
+                                                         const char *  cred_req_metadata_json,
+                                                         const char *  cred_json,
+                                                         const char *  cred_def_json,
+                                                         const char *  rev_reg_def_json,
+
+                                                         void           (*cb)(const void*    context,
+                                                                              indy_error_t  err,
+                                                                              const char*   out_cred_id)
+                                                         );
+
+    extern indy_error_t indy_prover_store_credential_with_tags(indy_handle_t command_handle,
+                                                               indy_handle_t wallet_handle,
+                                                               const char *  cred_id,
+                                                               const char *  cred_req_metadata_json,
+                                                               const char *  cred_json,
+                                                               const char *  cred_def_json,
+                                                               const char *  rev_reg_def_json,
+                                                               const char *  tags_json,
+
+                                                               void           (*cb)(indy_handle_t command_handle_,
+                                                                                    indy_error_t  err,
+                                                                                    const char*   out_cred_id)
+                                                               );
+
+    /// Variant of `indy_prover_store_credential_with_tags` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_prover_store_credential_with_tags_ctx(const void*    context,
+                                                                   indy_handle_t wallet_handle,
+                                                                   const char *  cred_id,
+                                                                   const char *  cred_req_metadata_json,
+                                                                   const char *  cred_json,
+                                                                   const char *  cred_def_json,
+                                                                   const char *  rev_reg_def_json,
+                                                                   const char *  tags_json,
+
+                                                                   void           (*cb)(const void*    context,
+                                                                                        indy_error_t  err,
+                                                                                        const char*   out_cred_id)
+                                                                   );
+
+    extern indy_error_t indy_prover_import_w3c_credential(indy_handle_t command_handle,
+                                                          indy_handle_t wallet_handle,
+                                                          const char *  cred_id,
+                                                          const char *  cred_req_metadata_json,
+                                                          const char *  w3c_cred_json,
+                                                          const char *  cred_def_json,
+                                                          const char *  rev_reg_def_json,
+
+                                                          void           (*cb)(indy_handle_t command_handle_,
+                                                                               indy_error_t  err,
+                                                                               const char*   out_cred_id)
+                                                          );
+
+    /// Variant of `indy_prover_import_w3c_credential` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_prover_import_w3c_credential_ctx(const void*    context,
+                                                              indy_handle_t wallet_handle,
+                                                              const char *  cred_id,
+                                                              const char *  cred_req_metadata_json,
+                                                              const char *  w3c_cred_json,
+                                                              const char *  cred_def_json,
+                                                              const char *  rev_reg_def_json,
+
+                                                              void           (*cb)(const void*    context,
+                                                                                   indy_error_t  err,
+                                                                                   const char*   out_cred_id)
+                                                              );
+
     extern indy_error_t indy_prover_delete_credential(indy_handle_t command_handle,
                                                       indy_handle_t wallet_handle,
                                                       const char *  cred_id,
@@ -165,6 +413,15 @@ extern "C" {
                                                                           indy_error_t        err)
                                                       );
 
+    /// Variant of `indy_prover_delete_credential` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_prover_delete_credential_ctx(const void*    context,
+                                                          indy_handle_t wallet_handle,
+                                                          const char *  cred_id,
+
+                                                          void          (*cb)(const void*    context,
+                                                                              indy_error_t        err)
+                                                          );
+
     extern indy_error_t indy_prover_get_credentials(indy_handle_t command_handle,
                                                     indy_handle_t wallet_handle,
                                                     const char *  filter_json,
@@ -183,6 +440,16 @@ extern "C" {
                                                                         const char*   credential_json)
                                                    );
 
+    /// Variant of `indy_prover_get_credential` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_prover_get_credential_ctx(const void*    context,
+                                                       indy_handle_t wallet_handle,
+                                                       const char *  cred_id,
+
+                                                       void           (*cb)(const void*    context,
+                                                                            indy_error_t  err,
+                                                                            const char*   credential_json)
+                                                       );
+
     extern indy_error_t indy_prover_search_credentials(indy_handle_t command_handle,
                                                        indy_handle_t wallet_handle,
                                                        const char *  query_json,
@@ -193,6 +460,17 @@ extern "C" {
                                                                             indy_u32_t    total_count)
                                                        );
 
+    /// Variant of `indy_prover_search_credentials` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_prover_search_credentials_ctx(const void*    context,
+                                                           indy_handle_t wallet_handle,
+                                                           const char *  query_json,
+
+                                                           void           (*cb)(const void*    context,
+                                                                                indy_error_t  err,
+                                                                                indy_handle_t search_handle,
+                                                                                indy_u32_t    total_count)
+                                                           );
+
     extern indy_error_t indy_prover_fetch_credentials(indy_handle_t command_handle,
                                                       indy_handle_t search_handle,
                                                       indy_u32_t    count,
@@ -202,6 +480,16 @@ extern "C" {
                                                                            const char*   credentials_json)
                                                       );
 
+    /// Variant of `indy_prover_fetch_credentials` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_prover_fetch_credentials_ctx(const void*    context,
+                                                          indy_handle_t search_handle,
+                                                          indy_u32_t    count,
+
+                                                          void           (*cb)(const void*    context,
+                                                                               indy_error_t  err,
+                                                                               const char*   credentials_json)
+                                                          );
+
     extern indy_error_t indy_prover_close_credentials_search(indy_handle_t command_handle,
                                                              indy_handle_t search_handle,
 
@@ -209,6 +497,14 @@ extern "C" {
                                                                                   indy_error_t  err)
                                                              );
 
+    /// Variant of `indy_prover_close_credentials_search` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_prover_close_credentials_search_ctx(const void*    context,
+                                                                 indy_handle_t search_handle,
+
+                                                                 void           (*cb)(const void*    context,
+                                                                                      indy_error_t  err)
+                                                                 );
+
     extern indy_error_t indy_prover_get_credentials_for_proof_req(indy_handle_t command_handle,
                                                                   indy_handle_t wallet_handle,
                                                                   const char *  proof_request_json,
@@ -229,6 +525,17 @@ extern "C" {
                                                                                           indy_handle_t search_handle)
                                                                      );
 
+    /// Variant of `indy_prover_search_credentials_for_proof_req` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_prover_search_credentials_for_proof_req_ctx(const void*    context,
+                                                                         indy_handle_t wallet_handle,
+                                                                         const char *  proof_request_json,
+                                                                         const char *  extra_query_json,
+
+                                                                         void           (*cb)(const void*    context,
+                                                                                              indy_error_t  err,
+                                                                                              indy_handle_t search_handle)
+                                                                         );
+
     extern indy_error_t indy_prover_fetch_credentials_for_proof_req(indy_handle_t command_handle,
                                                                     indy_handle_t search_handle,
                                                                     const char*   item_referent,
@@ -239,6 +546,17 @@ extern "C" {
                                                                                          const char*   credentials_json)
                                                                     );
 
+    /// Variant of `indy_prover_fetch_credentials_for_proof_req` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_prover_fetch_credentials_for_proof_req_ctx(const void*    context,
+                                                                        indy_handle_t search_handle,
+                                                                        const char*   item_referent,
+                                                                        indy_u32_t    count,
+
+                                                                        void           (*cb)(const void*    context,
+                                                                                             indy_error_t  err,
+                                                                                             const char*   credentials_json)
+                                                                        );
+
     extern indy_error_t indy_prover_close_credentials_search_for_proof_req(indy_handle_t command_handle,
                                                                            indy_handle_t search_handle,
 
@@ -246,6 +564,14 @@ extern "C" {
                                                                                                 indy_error_t  err)
                                                                            );
 
+    /// Variant of `indy_prover_close_credentials_search_for_proof_req` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_prover_close_credentials_search_for_proof_req_ctx(const void*    context,
+                                                                               indy_handle_t search_handle,
+
+                                                                               void           (*cb)(const void*    context,
+                                                                                                    indy_error_t  err)
+                                                                               );
+
     extern indy_error_t indy_prover_create_proof(indy_handle_t command_handle,
                                                  indy_handle_t wallet_handle,
                                                  const char *  proof_req_json,
@@ -260,6 +586,21 @@ extern "C" {
                                                                       const char*   proof_json)
                                                  );
 
+    /// Variant of `indy_prover_create_proof` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_prover_create_proof_ctx(const void*    context,
+                                                     indy_handle_t wallet_handle,
+                                                     const char *  proof_req_json,
+                                                     const char *  requested_credentials_json,
+                                                     const char *  master_secret_name,
+                                                     const char *  schemas_json,
+                                                     const char *  credential_defs_json,
+                                                     const char *  rev_states_json,
+
+                                                     void           (*cb)(const void*    context,
+                                                                          indy_error_t  err,
+                                                                          const char*   proof_json)
+                                                     );
+
 
     extern indy_error_t indy_verifier_verify_proof(indy_handle_t command_handle,
                                                    const char *  proof_request_json,
@@ -274,6 +615,58 @@ extern "C" {
                                                                         indy_bool_t   valid )
                                                    );
 
+    /// Variant of `indy_verifier_verify_proof` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_verifier_verify_proof_ctx(const void*    context,
+                                                       const char *  proof_request_json,
+                                                       const char *  proof_json,
+                                                       const char *  schemas_json,
+                                                       const char *  credential_defs_jsons,
+                                                       const char *  rev_reg_defs_json,
+                                                       const char *  rev_regs_json,
+
+                                                       void           (*cb)(const void*    context,
+                                                                            indy_error_t  err,
+                                                                            indy_bool_t   valid )
+                                                       );
+
+    extern indy_error_t indy_verifier_verify_proof_with_pools(indy_handle_t command_handle,
+                                                              const char *  pools_json,
+                                                              const char *  proof_request_json,
+                                                              const char *  proof_json,
+
+                                                              void           (*cb)(indy_handle_t command_handle_,
+                                                                                   indy_error_t  err,
+                                                                                   indy_bool_t   valid )
+                                                              );
+
+    /// Variant of `indy_verifier_verify_proof_with_pools` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_verifier_verify_proof_with_pools_ctx(const void*    context,
+                                                                  const char *  pools_json,
+                                                                  const char *  proof_request_json,
+                                                                  const char *  proof_json,
+
+                                                                  void           (*cb)(const void*    context,
+                                                                                       indy_error_t  err,
+                                                                                       indy_bool_t   valid )
+                                                                  );
+
+    extern indy_error_t indy_verifier_validate_proof_request(indy_handle_t command_handle,
+                                                             const char *  proof_request_json,
+
+                                                             void           (*cb)(indy_handle_t command_handle_,
+                                                                                  indy_error_t  err,
+                                                                                  const char*   validation_errors_json)
+                                                             );
+
+    /// Variant of `indy_verifier_validate_proof_request` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_verifier_validate_proof_request_ctx(const void*    context,
+                                                                 const char *  proof_request_json,
+
+                                                                 void           (*cb)(const void*    context,
+                                                                                      indy_error_t  err,
+                                                                                      const char*   validation_errors_json)
+                                                                 );
+
 
     extern indy_error_t indy_create_revocation_state(indy_handle_t command_handle,
                                                      indy_handle_t blob_storage_reader_handle,
@@ -287,6 +680,19 @@ extern "C" {
                                                                           const char*   rev_state_json)
                                                      );
 
+    /// Variant of `indy_create_revocation_state` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_create_revocation_state_ctx(const void*    context,
+                                                         indy_handle_t blob_storage_reader_handle,
+                                                         const char *  rev_reg_def_json,
+                                                         const char *  rev_reg_delta_json,
+                                                         indy_u64_t    timestamp,
+                                                         const char *  cred_rev_id,
+
+                                                         void           (*cb)(const void*    context,
+                                                                              indy_error_t  err,
+                                                                              const char*   rev_state_json)
+                                                         );
+
 
     extern indy_error_t indy_update_revocation_state(indy_handle_t command_handle,
                                                      indy_handle_t blob_storage_reader_handle,
@@ -301,6 +707,75 @@ extern "C" {
                                                                           const char*   updated_rev_state_json)
                                                      );
 
+    /// Variant of `indy_update_revocation_state` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_update_revocation_state_ctx(const void*    context,
+                                                         indy_handle_t blob_storage_reader_handle,
+                                                         const char *  rev_state_json,
+                                                         const char *  rev_reg_def_json,
+                                                         const char *  rev_reg_delta_json,
+                                                         indy_u64_t    timestamp,
+                                                         const char *  cred_rev_id,
+
+                                                         void           (*cb)(const void*    context,
+                                                                              indy_error_t  err,
+                                                                              const char*   updated_rev_state_json)
+                                                         );
+
+    extern indy_error_t indy_start_revocation_state_updater(indy_handle_t command_handle,
+                                                            indy_handle_t wallet_handle,
+                                                            indy_handle_t pool_handle,
+                                                            indy_handle_t blob_storage_reader_handle,
+                                                            const char *  config_json,
+
+                                                            void           (*cb)(indy_handle_t command_handle_,
+                                                                                 indy_error_t  err,
+                                                                                 indy_handle_t updater_handle)
+                                                            );
+
+    /// Variant of `indy_start_revocation_state_updater` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_start_revocation_state_updater_ctx(const void*    context,
+                                                                indy_handle_t wallet_handle,
+                                                                indy_handle_t pool_handle,
+                                                                indy_handle_t blob_storage_reader_handle,
+                                                                const char *  config_json,
+
+                                                                void           (*cb)(const void*    context,
+                                                                                     indy_error_t  err,
+                                                                                     indy_handle_t updater_handle)
+                                                                );
+
+    extern indy_error_t indy_stop_revocation_state_updater(indy_handle_t command_handle,
+                                                           indy_handle_t updater_handle,
+
+                                                           void           (*cb)(indy_handle_t command_handle_,
+                                                                                indy_error_t  err)
+                                                           );
+
+    /// Variant of `indy_stop_revocation_state_updater` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_stop_revocation_state_updater_ctx(const void*    context,
+                                                               indy_handle_t updater_handle,
+
+                                                               void           (*cb)(const void*    context,
+                                                                                    indy_error_t  err)
+                                                               );
+
+    extern indy_error_t indy_get_updated_revocation_states(indy_handle_t command_handle,
+                                                           indy_handle_t updater_handle,
+
+                                                           void           (*cb)(indy_handle_t command_handle_,
+                                                                                indy_error_t  err,
+                                                                                const char*   rev_states_json)
+                                                           );
+
+    /// Variant of `indy_get_updated_revocation_states` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_get_updated_revocation_states_ctx(const void*    context,
+                                                               indy_handle_t updater_handle,
+
+                                                               void           (*cb)(const void*    context,
+                                                                                    indy_error_t  err,
+                                                                                    const char*   rev_states_json)
+                                                               );
+
 
     extern indy_error_t indy_generate_nonce(indy_handle_t command_handle,
                                             void           (*cb)(indy_handle_t command_handle_,
@@ -308,6 +783,13 @@ extern "C" {
                                                                  const char*   nonce)
                                             );
 
+    /// Variant of `indy_generate_nonce` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_generate_nonce_ctx(const void*    context,
+                                                void           (*cb)(const void*    context,
+                                                                     indy_error_t  err,
+                                                                     const char*   nonce)
+                                                );
+
     extern indy_error_t indy_to_unqualified(indy_handle_t command_handle,
                                             const char *  entity,
                                             void           (*cb)(indy_handle_t command_handle_,
@@ -315,6 +797,14 @@ extern "C" {
                                                                  const char*   res)
                                             );
 
+    /// Variant of `indy_to_unqualified` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_to_unqualified_ctx(const void*    context,
+                                                const char *  entity,
+                                                void           (*cb)(const void*    context,
+                                                                     indy_error_t  err,
+                                                                     const char*   res)
+                                                );
+
 #ifdef __cplusplus
 }
 #endif
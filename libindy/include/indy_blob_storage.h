@@ -5,6 +5,36 @@
 extern "C" {
 #endif
 
+    /// Opens reader of tails files.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// type_: type of reader:
+    ///     "default" - reads tails files from local directory, config: {"base_dir": <str>}
+    ///     "memory" - reads tails written by "memory" writer of the same process, config: {}
+    ///     "http" - reads tails files from HTTP(S) server with range requests, available only if libindy is built
+    ///         with "http_blob_storage" feature, config: {
+    ///         "base_url": <str>, (optional) tails are requested from <base_url>/<base58 tails hash> if tails location isn't URL
+    ///         "timeout": <int>, (optional) timeout of connecting and socket reads and writes in seconds, 30 by default
+    ///         "block_size": <int>, (optional) size of blocks tails are requested and cached in memory by, 1 MiB by default
+    ///     }
+    ///     "s3" - reads tails from S3 compatible object storage with ranged requests, available only if libindy is built
+    ///         with "s3_blob_storage" feature, tails location is ignored, config: {
+    ///         "endpoint": <str>, URL of object storage, for example "https://s3.us-east-1.amazonaws.com"
+    ///         "region": <str>, region used for request signing
+    ///         "bucket": <str>, bucket name
+    ///         "prefix": <str>, (optional) prefix of object keys, tails are kept in objects named <prefix><base58 tails hash>
+    ///         "access_key": <str>, (optional) access key, anonymous requests are sent if credentials aren't set
+    ///         "secret_key": <str>, (optional) secret key
+    ///         "timeout": <int>, (optional) timeout of connecting and socket reads and writes in seconds, 30 by default
+    ///         "block_size": <int>, (optional) size of blocks tails are requested and cached in memory by, 1 MiB by default
+    ///     }
+    ///     Custom reader types can be registered with indy_register_blob_storage_reader call.
+    /// config_json: reader config.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error code
 
     extern indy_error_t indy_open_blob_storage_reader(indy_handle_t  command_handle,
                                                       const char*    type_,
@@ -12,12 +42,271 @@ extern "C" {
                                                       void           (*fn)(indy_handle_t command_handle_, indy_error_t err, indy_handle_t handle)
                                                      );
 
+    /// Variant of `indy_open_blob_storage_reader` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_open_blob_storage_reader_ctx(const void*    context,
+                                                          const char*    type_,
+                                                          const char*    config_json,
+                                                          void           (*fn)(const void*    context, indy_error_t err, indy_handle_t handle)
+                                                         );
+
+    /// Opens writer of tails files.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// type_: type of writer:
+    ///     "default" - writes tails files to local directory, config: {"base_dir": <str>, "uri_pattern": <str>}
+    ///     "memory" - keeps tails in process memory until the process is finished, intended for tests, config: {}
+    ///     "s3" - uploads tails to S3 compatible object storage with multipart upload, available only if libindy is built
+    ///         with "s3_blob_storage" feature, config is the same as for "s3" reader with addition of
+    ///         "part_size": <int>, (optional) size of uploaded parts in bytes, 5 MiB by default which is minimum allowed by S3
+    ///         Tails are uploaded to temporary object and copied to object named by tails hash when writing is completed.
+    ///         URL of the object is returned as tails location.
+    ///     Custom writer types can be registered with indy_register_blob_storage_writer call.
+    /// config_json: writer config.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error code
+
     extern indy_error_t indy_open_blob_storage_writer(indy_handle_t  command_handle,
                                                       const char*    type_,
                                                       const char*    config_json,
                                                       void           (*fn)(indy_handle_t command_handle_, indy_error_t err, indy_handle_t handle)
                                                      );
 
+    /// Variant of `indy_open_blob_storage_writer` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_open_blob_storage_writer_ctx(const void*    context,
+                                                          const char*    type_,
+                                                          const char*    config_json,
+                                                          void           (*fn)(const void*    context, indy_error_t err, indy_handle_t handle)
+                                                         );
+
+    /// Closes reader of tails files opened with indy_open_blob_storage_reader.
+    ///
+    /// close_reader handler of custom reader type is called if it is registered.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// handle: reader handle returned by indy_open_blob_storage_reader.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error code
+
+    extern indy_error_t indy_close_blob_storage_reader(indy_handle_t  command_handle,
+                                                       indy_handle_t  handle,
+                                                       void           (*fn)(indy_handle_t command_handle_, indy_error_t err)
+                                                      );
+
+    /// Variant of `indy_close_blob_storage_reader` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_close_blob_storage_reader_ctx(const void*    context,
+                                                           indy_handle_t  handle,
+                                                           void           (*fn)(const void*    context, indy_error_t err)
+                                                          );
+
+    /// Closes writer of tails files opened with indy_open_blob_storage_writer.
+    ///
+    /// close_writer handler of custom writer type is called if it is registered.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// handle: writer handle returned by indy_open_blob_storage_writer.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error code
+
+    extern indy_error_t indy_close_blob_storage_writer(indy_handle_t  command_handle,
+                                                       indy_handle_t  handle,
+                                                       void           (*fn)(indy_handle_t command_handle_, indy_error_t err)
+                                                      );
+
+    /// Variant of `indy_close_blob_storage_writer` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_close_blob_storage_writer_ctx(const void*    context,
+                                                           indy_handle_t  handle,
+                                                           void           (*fn)(const void*    context, indy_error_t err)
+                                                          );
+
+    /// Reads the whole tails file with reader opened by indy_open_blob_storage_reader
+    /// to a buffer owned by the application.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// handle: reader handle returned by indy_open_blob_storage_reader.
+    /// tails_location: tails location from revocation registry definition.
+    /// tails_hash: base58 encoded tails hash from revocation registry definition.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Tails file content. The content is checked against tails hash.
+    /// The buffer must be released with `indy_free_buffer`. On error NULL buffer is passed.
+    ///
+    /// #Errors
+    /// Common*
+
+    extern indy_error_t indy_read_tails_to_buffer(indy_handle_t  command_handle,
+                                                  indy_handle_t  handle,
+                                                  const char*    tails_location,
+                                                  const char*    tails_hash,
+                                                  void           (*fn)(indy_handle_t command_handle_,
+                                                                       indy_error_t  err,
+                                                                       indy_u8_t*    data,
+                                                                       indy_u32_t    data_len)
+                                                 );
+
+    /// Variant of `indy_read_tails_to_buffer` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_read_tails_to_buffer_ctx(const void*    context,
+                                                      indy_handle_t  handle,
+                                                      const char*    tails_location,
+                                                      const char*    tails_hash,
+                                                      void           (*fn)(const void*    context,
+                                                                           indy_error_t  err,
+                                                                           indy_u8_t*    data,
+                                                                           indy_u32_t    data_len)
+                                                     );
+
+    /// Register custom reader of tails files, so tails can be kept in the storage that isn't supported by libindy.
+    ///
+    /// Handlers are called from libindy thread and have to be thread safe.
+    /// Config of indy_open_blob_storage_reader is passed to open_reader handler as is.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// type_: Reader type name.
+    /// open_reader: Reader open operation handler
+    /// open_blob: Reader open blob operation handler
+    /// read: Blob read operation handler, it copies up to size bytes to the buffer and sets read_len to their number
+    /// close_blob: Blob close operation handler
+    /// close_reader: (optional) Reader close operation handler, it is called by indy_close_blob_storage_reader
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error code
+    ///
+    /// #Errors
+    /// Common*
+
+    extern indy_error_t indy_register_blob_storage_reader(indy_handle_t  command_handle,
+                                                          const char*    type_,
+                                                          indy_error_t (*openReaderFn)(const char* config,
+                                                                                       indy_handle_t* reader_handle),
+
+                                                          indy_error_t (*openBlobFn)(indy_handle_t reader_handle,
+                                                                                     const indy_u8_t* hash,
+                                                                                     indy_u32_t hash_len,
+                                                                                     const char* location,
+                                                                                     indy_handle_t* blob_handle),
+
+                                                          indy_error_t (*readFn)(indy_handle_t blob_handle,
+                                                                                 indy_u64_t offset,
+                                                                                 indy_u32_t size,
+                                                                                 indy_u8_t* buf,
+                                                                                 indy_u32_t* read_len),
+
+                                                          indy_error_t (*closeBlobFn)(indy_handle_t blob_handle),
+
+                                                          indy_error_t (*closeReaderFn)(indy_handle_t reader_handle),
+
+                                                          void           (*fn)(indy_handle_t command_handle_, indy_error_t err)
+                                                         );
+
+    /// Variant of `indy_register_blob_storage_reader` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_register_blob_storage_reader_ctx(const void*    context,
+                                                              const char*    type_,
+                                                              indy_error_t (*openReaderFn)(const char* config,
+                                                                                           indy_handle_t* reader_handle),
+
+                                                              indy_error_t (*openBlobFn)(indy_handle_t reader_handle,
+                                                                                         const indy_u8_t* hash,
+                                                                                         indy_u32_t hash_len,
+                                                                                         const char* location,
+                                                                                         indy_handle_t* blob_handle),
+
+                                                              indy_error_t (*readFn)(indy_handle_t blob_handle,
+                                                                                     indy_u64_t offset,
+                                                                                     indy_u32_t size,
+                                                                                     indy_u8_t* buf,
+                                                                                     indy_u32_t* read_len),
+
+                                                              indy_error_t (*closeBlobFn)(indy_handle_t blob_handle),
+
+                                                              indy_error_t (*closeReaderFn)(indy_handle_t reader_handle),
+
+                                                              void           (*fn)(const void*    context, indy_error_t err)
+                                                             );
+
+    /// Register custom writer of tails files, so tails can be kept in the storage that isn't supported by libindy.
+    ///
+    /// Handlers are called from libindy thread and have to be thread safe.
+    /// Config of indy_open_blob_storage_writer is passed to open_writer handler as is.
+    /// Location returned by finalize handler is used as tails location of revocation registry definition.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// type_: Writer type name.
+    /// open_writer: Writer open operation handler
+    /// create_blob: Writer create blob operation handler
+    /// append: Blob append operation handler
+    /// finalize: Blob finalize operation handler, location must stay valid until the blob is closed
+    /// close_blob: Blob close operation handler, it is called right after finalize
+    /// close_writer: (optional) Writer close operation handler, it is called by indy_close_blob_storage_writer
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error code
+    ///
+    /// #Errors
+    /// Common*
+
+    extern indy_error_t indy_register_blob_storage_writer(indy_handle_t  command_handle,
+                                                          const char*    type_,
+                                                          indy_error_t (*openWriterFn)(const char* config,
+                                                                                       indy_handle_t* writer_handle),
+
+                                                          indy_error_t (*createBlobFn)(indy_handle_t writer_handle,
+                                                                                       indy_handle_t* blob_handle),
+
+                                                          indy_error_t (*appendFn)(indy_handle_t blob_handle,
+                                                                                   const indy_u8_t* bytes,
+                                                                                   indy_u32_t bytes_len),
+
+                                                          indy_error_t (*finalizeFn)(indy_handle_t blob_handle,
+                                                                                     const indy_u8_t* hash,
+                                                                                     indy_u32_t hash_len,
+                                                                                     const char** location),
+
+                                                          indy_error_t (*closeBlobFn)(indy_handle_t blob_handle),
+
+                                                          indy_error_t (*closeWriterFn)(indy_handle_t writer_handle),
+
+                                                          void           (*fn)(indy_handle_t command_handle_, indy_error_t err)
+                                                         );
+
+    /// Variant of `indy_register_blob_storage_writer` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_register_blob_storage_writer_ctx(const void*    context,
+                                                              const char*    type_,
+                                                              indy_error_t (*openWriterFn)(const char* config,
+                                                                                           indy_handle_t* writer_handle),
+
+                                                              indy_error_t (*createBlobFn)(indy_handle_t writer_handle,
+                                                                                           indy_handle_t* blob_handle),
+
+                                                              indy_error_t (*appendFn)(indy_handle_t blob_handle,
+                                                                                       const indy_u8_t* bytes,
+                                                                                       indy_u32_t bytes_len),
+
+                                                              indy_error_t (*finalizeFn)(indy_handle_t blob_handle,
+                                                                                         const indy_u8_t* hash,
+                                                                                         indy_u32_t hash_len,
+                                                                                         const char** location),
+
+                                                              indy_error_t (*closeBlobFn)(indy_handle_t blob_handle),
+
+                                                              indy_error_t (*closeWriterFn)(indy_handle_t writer_handle),
+
+                                                              void           (*fn)(const void*    context, indy_error_t err)
+                                                             );
+
 
 #ifdef __cplusplus
 }
@@ -6,6 +6,8 @@ extern "C" {
 #endif
 
     /// Gets schema json data for specified schema id.
+    /// If schema is registered in the local registry of the wallet (see indy_register_local_schema), registered data is returned
+    /// without any cache or ledger lookup.
     /// If data is present inside of cache, cached data is returned.
     /// Otherwise data is fetched from the ledger and stored inside of cache for future use.
     ///
@@ -23,6 +25,12 @@ extern "C" {
     ///    noUpdate: (bool, optional, false by default) Use only cached data, do not try to update.
     ///    noStore: (bool, optional, false by default) Skip storing fresh data if updated,
     ///    minFresh: (int, optional, -1 by default) Return cached data if not older than this many seconds. -1 means do not check age.
+    ///    ttl: (int, optional, -1 by default) Time to live of stored data in seconds. Expired data is removed on read. -1 means never expire.
+    ///    maxEntries: (int, optional, unlimited by default) Max count of entries kept in cache. Entries are evicted according to evictionPolicy when fresh data is stored.
+    ///    evictionPolicy: (string, optional, "lru" by default) Which entries are evicted: "lru" - least recently used, "lfu" - least frequently used.
+    ///    maxEntrySize: (int, optional, unlimited by default) Max size of data in bytes to store in cache. Bigger data is returned but not stored.
+    ///    notFoundTtl: (int, optional, 60 by default) Time to live of cached "not found" ledger results in seconds. 0 means do not cache "not found" results.
+    ///    noNegativeCache: (bool, optional, false by default) Skip cached "not found" ledger results and query the ledger again.
     ///  }
     /// #Returns
     /// Schema json:
@@ -44,7 +52,21 @@ extern "C" {
                                                             const char*   schema_json)
                                        );
 
+    /// Variant of `indy_get_schema` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_get_schema_ctx(const void*    context,
+                                            indy_handle_t pool_handle,
+                                            indy_handle_t wallet_handle,
+                                            const char *  submitter_did,
+                                            const char *  id,
+                                            const char *  options_json,
+                                            void          (*cb)(const void*    context,
+                                                                indy_error_t  err,
+                                                                const char*   schema_json)
+                                           );
+
     /// Gets credential definition json data for specified credential definition id.
+    /// If credential definition is registered in the local registry of the wallet (see indy_register_local_cred_def), registered data is returned
+    /// without any cache or ledger lookup.
     /// If data is present inside of cache, cached data is returned.
     /// Otherwise data is fetched from the ledger and stored inside of cache for future use.
     ///
@@ -62,6 +84,12 @@ extern "C" {
     ///    noUpdate: (bool, optional, false by default) Use only cached data, do not try to update.
     ///    noStore: (bool, optional, false by default) Skip storing fresh data if updated,
     ///    minFresh: (int, optional, -1 by default) Return cached data if not older than this many seconds. -1 means do not check age.
+    ///    ttl: (int, optional, -1 by default) Time to live of stored data in seconds. Expired data is removed on read. -1 means never expire.
+    ///    maxEntries: (int, optional, unlimited by default) Max count of entries kept in cache. Entries are evicted according to evictionPolicy when fresh data is stored.
+    ///    evictionPolicy: (string, optional, "lru" by default) Which entries are evicted: "lru" - least recently used, "lfu" - least frequently used.
+    ///    maxEntrySize: (int, optional, unlimited by default) Max size of data in bytes to store in cache. Bigger data is returned but not stored.
+    ///    notFoundTtl: (int, optional, 60 by default) Time to live of cached "not found" ledger results in seconds. 0 means do not cache "not found" results.
+    ///    noNegativeCache: (bool, optional, false by default) Skip cached "not found" ledger results and query the ledger again.
     ///  }
     ///
     /// #Returns
@@ -88,6 +116,18 @@ extern "C" {
                                                               const char*   cred_def_json)
                                          );
 
+    /// Variant of `indy_get_cred_def` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_get_cred_def_ctx(const void*    context,
+                                              indy_handle_t pool_handle,
+                                              indy_handle_t wallet_handle,
+                                              const char *  submitter_did,
+                                              const char *  id,
+                                              const char *  options_json,
+                                              void          (*cb)(const void*    context,
+                                                                  indy_error_t  err,
+                                                                  const char*   cred_def_json)
+                                             );
+
     /// Purge schema cache.
     ///
     /// EXPERIMENTAL
@@ -98,6 +138,7 @@ extern "C" {
     /// options_json:
     ///  {
     ///    maxAge: (int, optional, -1 by default) Purge cached data if older than this many seconds. -1 means purge all.
+    ///    poolName: (string, optional) Purge only data fetched from the pool ledger with this name. All pools by default.
     ///  }
     extern indy_error_t indy_purge_schema_cache(indy_handle_t command_handle,
                                                 indy_handle_t wallet_handle,
@@ -106,6 +147,14 @@ extern "C" {
                                                                     indy_error_t  err)
                                                );
 
+    /// Variant of `indy_purge_schema_cache` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_purge_schema_cache_ctx(const void*    context,
+                                                    indy_handle_t wallet_handle,
+                                                    const char *  options_json,
+                                                    void          (*cb)(const void*    context,
+                                                                        indy_error_t  err)
+                                                   );
+
     /// Purge credential definition cache.
     ///
     /// EXPERIMENTAL
@@ -116,6 +165,7 @@ extern "C" {
     /// options_json:
     ///  {
     ///    maxAge: (int, optional, -1 by default) Purge cached data if older than this many seconds. -1 means purge all.
+    ///    poolName: (string, optional) Purge only data fetched from the pool ledger with this name. All pools by default.
     ///  }
     extern indy_error_t indy_purge_cred_def_cache(indy_handle_t command_handle,
                                                   indy_handle_t wallet_handle,
@@ -123,6 +173,388 @@ extern "C" {
                                                   void          (*cb)(indy_handle_t command_handle_,
                                                                       indy_error_t  err)
                                                  );
+
+    /// Variant of `indy_purge_cred_def_cache` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_purge_cred_def_cache_ctx(const void*    context,
+                                                      indy_handle_t wallet_handle,
+                                                      const char *  options_json,
+                                                      void          (*cb)(const void*    context,
+                                                                          indy_error_t  err)
+                                                     );
+
+    /// Gets revocation registry definition json data for specified revocation registry definition id.
+    /// If revocation registry definition is registered in the local registry of the wallet (see indy_register_local_rev_reg_def), registered data is returned
+    /// without any cache or ledger lookup.
+    /// If data is present inside of cache, cached data is returned.
+    /// Otherwise data is fetched from the ledger and stored inside of cache for future use.
+    ///
+    /// EXPERIMENTAL
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// submitter_did: DID of the submitter stored in secured Wallet.
+    /// id: identifier of revocation registry definition.
+    /// options_json:
+    ///  {
+    ///    noCache: (bool, optional, false by default) Skip usage of cache,
+    ///    noUpdate: (bool, optional, false by default) Use only cached data, do not try to update.
+    ///    noStore: (bool, optional, false by default) Skip storing fresh data if updated,
+    ///    minFresh: (int, optional, -1 by default) Return cached data if not older than this many seconds. -1 means do not check age.
+    ///    ttl: (int, optional, -1 by default) Time to live of stored data in seconds. Expired data is removed on read. -1 means never expire.
+    ///    maxEntries: (int, optional, unlimited by default) Max count of entries kept in cache. Entries are evicted according to evictionPolicy when fresh data is stored.
+    ///    evictionPolicy: (string, optional, "lru" by default) Which entries are evicted: "lru" - least recently used, "lfu" - least frequently used.
+    ///    maxEntrySize: (int, optional, unlimited by default) Max size of data in bytes to store in cache. Bigger data is returned but not stored.
+    ///    notFoundTtl: (int, optional, 60 by default) Time to live of cached "not found" ledger results in seconds. 0 means do not cache "not found" results.
+    ///    noNegativeCache: (bool, optional, false by default) Skip cached "not found" ledger results and query the ledger again.
+    ///  }
+    ///
+    /// #Returns
+    /// Revocation Registry Definition json (the same as indy_parse_get_revoc_reg_def_response).
+    extern indy_error_t indy_get_rev_reg_def(indy_handle_t command_handle,
+                                             indy_handle_t pool_handle,
+                                             indy_handle_t wallet_handle,
+                                             const char *  submitter_did,
+                                             const char *  id,
+                                             const char *  options_json,
+                                             void          (*cb)(indy_handle_t command_handle_,
+                                                                 indy_error_t  err,
+                                                                 const char*   rev_reg_def_json)
+                                            );
+
+    /// Variant of `indy_get_rev_reg_def` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_get_rev_reg_def_ctx(const void*    context,
+                                                 indy_handle_t pool_handle,
+                                                 indy_handle_t wallet_handle,
+                                                 const char *  submitter_did,
+                                                 const char *  id,
+                                                 const char *  options_json,
+                                                 void          (*cb)(const void*    context,
+                                                                     indy_error_t  err,
+                                                                     const char*   rev_reg_def_json)
+                                                );
+
+    /// Gets revocation registry json data for specified revocation registry definition id valid at specified time.
+    /// If cached revocation registry state is known to be actual at requested time, cached data is returned.
+    /// Otherwise data is fetched from the ledger and stored inside of cache for future use.
+    ///
+    /// EXPERIMENTAL
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// submitter_did: DID of the submitter stored in secured Wallet.
+    /// rev_reg_def_id: identifier of revocation registry definition.
+    /// timestamp: requested time represented as a total number of seconds from Unix Epoch.
+    /// options_json: the same as for indy_get_rev_reg_def.
+    ///
+    /// #Returns
+    /// Revocation Registry Definition Id, Revocation Registry json and Timestamp (the same as indy_parse_get_revoc_reg_response).
+    extern indy_error_t indy_get_rev_reg(indy_handle_t command_handle,
+                                         indy_handle_t pool_handle,
+                                         indy_handle_t wallet_handle,
+                                         const char *  submitter_did,
+                                         const char *  rev_reg_def_id,
+                                         long long     timestamp,
+                                         const char *  options_json,
+                                         void          (*cb)(indy_handle_t command_handle_,
+                                                             indy_error_t  err,
+                                                             const char*   rev_reg_def_id,
+                                                             const char*   rev_reg_json,
+                                                             unsigned long long timestamp)
+                                        );
+
+    /// Variant of `indy_get_rev_reg` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_get_rev_reg_ctx(const void*    context,
+                                             indy_handle_t pool_handle,
+                                             indy_handle_t wallet_handle,
+                                             const char *  submitter_did,
+                                             const char *  rev_reg_def_id,
+                                             long long     timestamp,
+                                             const char *  options_json,
+                                             void          (*cb)(const void*    context,
+                                                                 indy_error_t  err,
+                                                                 const char*   rev_reg_def_id,
+                                                                 const char*   rev_reg_json,
+                                                                 unsigned long long timestamp)
+                                            );
+
+    /// Gets revocation registry delta json data for specified revocation registry definition id and time interval.
+    /// If cached delta covers requested interval, cached data is returned.
+    /// If cached delta covers only the beginning of requested interval, only the missing part is fetched
+    /// from the ledger and merged with cached delta.
+    /// Otherwise data is fetched from the ledger and stored inside of cache for future use.
+    ///
+    /// EXPERIMENTAL
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// submitter_did: DID of the submitter stored in secured Wallet.
+    /// rev_reg_def_id: identifier of revocation registry definition.
+    /// from: requested time represented as a total number of seconds from Unix Epoch (-1 means the whole state till to).
+    /// to: requested time represented as a total number of seconds from Unix Epoch.
+    /// options_json: the same as for indy_get_rev_reg_def.
+    ///
+    /// #Returns
+    /// Revocation Registry Definition Id, Revocation Registry Delta json and Timestamp (the same as indy_parse_get_revoc_reg_delta_response).
+    extern indy_error_t indy_get_rev_reg_delta(indy_handle_t command_handle,
+                                               indy_handle_t pool_handle,
+                                               indy_handle_t wallet_handle,
+                                               const char *  submitter_did,
+                                               const char *  rev_reg_def_id,
+                                               long long     from,
+                                               long long     to,
+                                               const char *  options_json,
+                                               void          (*cb)(indy_handle_t command_handle_,
+                                                                   indy_error_t  err,
+                                                                   const char*   rev_reg_def_id,
+                                                                   const char*   rev_reg_delta_json,
+                                                                   unsigned long long timestamp)
+                                              );
+
+    /// Variant of `indy_get_rev_reg_delta` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_get_rev_reg_delta_ctx(const void*    context,
+                                                   indy_handle_t pool_handle,
+                                                   indy_handle_t wallet_handle,
+                                                   const char *  submitter_did,
+                                                   const char *  rev_reg_def_id,
+                                                   long long     from,
+                                                   long long     to,
+                                                   const char *  options_json,
+                                                   void          (*cb)(const void*    context,
+                                                                       indy_error_t  err,
+                                                                       const char*   rev_reg_def_id,
+                                                                       const char*   rev_reg_delta_json,
+                                                                       unsigned long long timestamp)
+                                                  );
+
+    /// Purge revocation registry definition, revocation registry and revocation registry delta caches.
+    ///
+    /// EXPERIMENTAL
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// options_json:
+    ///  {
+    ///    maxAge: (int, optional, -1 by default) Purge cached data if older than this many seconds. -1 means purge all.
+    ///    poolName: (string, optional) Purge only data fetched from the pool ledger with this name. All pools by default.
+    ///  }
+    extern indy_error_t indy_purge_rev_reg_cache(indy_handle_t command_handle,
+                                                 indy_handle_t wallet_handle,
+                                                 const char *  options_json,
+                                                 void          (*cb)(indy_handle_t command_handle_,
+                                                                     indy_error_t  err)
+                                                );
+
+    /// Variant of `indy_purge_rev_reg_cache` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_purge_rev_reg_cache_ctx(const void*    context,
+                                                     indy_handle_t wallet_handle,
+                                                     const char *  options_json,
+                                                     void          (*cb)(const void*    context,
+                                                                         indy_error_t  err)
+                                                    );
+
+    /// Fetches schemas, credential definitions and revocation registry definitions from the ledger
+    /// and stores them inside of cache for future use.
+    /// Ledger requests for all objects are sent concurrently; objects already present inside of cache are skipped.
+    ///
+    /// EXPERIMENTAL
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// ids_json:
+    ///  {
+    ///    schemas: (array of strings, optional) identifiers of schemas,
+    ///    credDefs: (array of strings, optional) identifiers of credential definitions,
+    ///    revRegDefs: (array of strings, optional) identifiers of revocation registry definitions,
+    ///  }
+    ///
+    /// #Returns
+    /// failed_json: map of identifiers which could not be fetched to error messages
+    ///  {
+    ///    "<id>": string - error message,
+    ///  }
+    extern indy_error_t indy_prefetch_ledger_cache(indy_handle_t command_handle,
+                                                   indy_handle_t pool_handle,
+                                                   indy_handle_t wallet_handle,
+                                                   const char *  ids_json,
+                                                   void          (*cb)(indy_handle_t command_handle_,
+                                                                       indy_error_t  err,
+                                                                       const char*   failed_json)
+                                                  );
+
+    /// Variant of `indy_prefetch_ledger_cache` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_prefetch_ledger_cache_ctx(const void*    context,
+                                                       indy_handle_t pool_handle,
+                                                       indy_handle_t wallet_handle,
+                                                       const char *  ids_json,
+                                                       void          (*cb)(const void*    context,
+                                                                           indy_error_t  err,
+                                                                           const char*   failed_json)
+                                                      );
+
+    /// Gets statistics of ledger cache stored inside of the wallet per object kind.
+    /// Counters are collected since library load and are shared among all wallets.
+    ///
+    /// EXPERIMENTAL
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// stats_json:
+    /// {
+    ///   "schemas"|"credDefs"|"revRegDefs"|"revRegs"|"revRegDeltas": {
+    ///       hits: int - count of lookups served from cache,
+    ///       misses: int - count of lookups which required the ledger (or failed with noUpdate),
+    ///       evictions: int - count of entries evicted because of maxEntries,
+    ///       oversized: int - count of ledger results not stored because of maxEntrySize,
+    ///       entries: int - count of cached entries,
+    ///       size: int - total size of cached data in bytes,
+    ///       oldest: (int, optional) time of storing of the oldest entry in seconds since Unix Epoch,
+    ///       newest: (int, optional) time of storing of the newest entry in seconds since Unix Epoch,
+    ///   },
+    /// }
+    extern indy_error_t indy_get_cache_stats(indy_handle_t command_handle,
+                                             indy_handle_t wallet_handle,
+                                             void          (*cb)(indy_handle_t command_handle_,
+                                                                 indy_error_t  err,
+                                                                 const char*   stats_json)
+                                            );
+
+    /// Variant of `indy_get_cache_stats` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_get_cache_stats_ctx(const void*    context,
+                                                 indy_handle_t wallet_handle,
+                                                 void          (*cb)(const void*    context,
+                                                                     indy_error_t  err,
+                                                                     const char*   stats_json)
+                                                );
+
+    /// Sets defaults of ledger cache options for all calls with the wallet.
+    /// Options passed to a call take precedence over the config.
+    /// Config is kept in memory until the wallet is reopened or config is set again.
+    ///
+    /// EXPERIMENTAL
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// config_json:
+    ///  {
+    ///    include: (array of strings, optional, all by default) Object kinds to cache: "schemas", "credDefs", "revRegDefs", "revRegs", "revRegDeltas".
+    ///             Other kinds are always fetched from the ledger and not stored.
+    ///    ttl: (int, optional) Default ttl option.
+    ///    maxEntries: (int, optional) Default maxEntries option.
+    ///    evictionPolicy: (string, optional) Default evictionPolicy option: "lru" or "lfu".
+    ///    maxEntrySize: (int, optional) Default maxEntrySize option.
+    ///    negativeCache: (bool, optional, true by default) false disables storing and usage of cached "not found" ledger results.
+    ///    notFoundTtl: (int, optional) Default notFoundTtl option.
+    ///  }
+    /// cb: Callback that takes command result as parameter.
+    extern indy_error_t indy_set_cache_config(indy_handle_t command_handle,
+                                              indy_handle_t wallet_handle,
+                                              const char *  config_json,
+                                              void          (*cb)(indy_handle_t command_handle_,
+                                                                  indy_error_t  err)
+                                             );
+
+    /// Variant of `indy_set_cache_config` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_set_cache_config_ctx(const void*    context,
+                                                  indy_handle_t wallet_handle,
+                                                  const char *  config_json,
+                                                  void          (*cb)(const void*    context,
+                                                                      indy_error_t  err)
+                                                 );
+
+    /// Registers schema in the local registry of the wallet.
+    /// Registered schema is trusted and returned by indy_get_schema instead of the ledger one,
+    /// so anoncreds can be used on private networks without any ledger writes.
+    /// Registering schema with the same id again replaces the registered one.
+    ///
+    /// EXPERIMENTAL
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// schema_json: schema json, as returned by indy_issuer_create_schema.
+    /// cb: Callback that takes command result as parameter.
+    extern indy_error_t indy_register_local_schema(indy_handle_t command_handle,
+                                                   indy_handle_t wallet_handle,
+                                                   const char *  schema_json,
+                                                   void          (*cb)(indy_handle_t command_handle_,
+                                                                       indy_error_t  err)
+                                                  );
+
+    /// Variant of `indy_register_local_schema` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_register_local_schema_ctx(const void*    context,
+                                                       indy_handle_t wallet_handle,
+                                                       const char *  schema_json,
+                                                       void          (*cb)(const void*    context,
+                                                                           indy_error_t  err)
+                                                      );
+
+    /// Registers credential definition in the local registry of the wallet.
+    /// Registered credential definition is trusted and returned by indy_get_cred_def instead of the ledger one,
+    /// so anoncreds can be used on private networks without any ledger writes.
+    /// Registering credential definition with the same id again replaces the registered one.
+    ///
+    /// EXPERIMENTAL
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// cred_def_json: credential definition json, as returned by indy_issuer_create_and_store_credential_def.
+    /// cb: Callback that takes command result as parameter.
+    extern indy_error_t indy_register_local_cred_def(indy_handle_t command_handle,
+                                                     indy_handle_t wallet_handle,
+                                                     const char *  cred_def_json,
+                                                     void          (*cb)(indy_handle_t command_handle_,
+                                                                         indy_error_t  err)
+                                                    );
+
+    /// Variant of `indy_register_local_cred_def` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_register_local_cred_def_ctx(const void*    context,
+                                                         indy_handle_t wallet_handle,
+                                                         const char *  cred_def_json,
+                                                         void          (*cb)(const void*    context,
+                                                                             indy_error_t  err)
+                                                        );
+
+    /// Registers revocation registry definition in the local registry of the wallet.
+    /// Registered revocation registry definition is trusted and returned by indy_get_revoc_reg_def instead of the ledger one,
+    /// so anoncreds can be used on private networks without any ledger writes.
+    /// Registering revocation registry definition with the same id again replaces the registered one.
+    ///
+    /// EXPERIMENTAL
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// revoc_reg_def_json: revocation registry definition json, as returned by indy_issuer_create_and_store_revoc_reg.
+    /// cb: Callback that takes command result as parameter.
+    extern indy_error_t indy_register_local_rev_reg_def(indy_handle_t command_handle,
+                                                        indy_handle_t wallet_handle,
+                                                        const char *  revoc_reg_def_json,
+                                                        void          (*cb)(indy_handle_t command_handle_,
+                                                                            indy_error_t  err)
+                                                       );
+
+    /// Variant of `indy_register_local_rev_reg_def` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_register_local_rev_reg_def_ctx(const void*    context,
+                                                            indy_handle_t wallet_handle,
+                                                            const char *  revoc_reg_def_json,
+                                                            void          (*cb)(const void*    context,
+                                                                                indy_error_t  err)
+                                                           );
 #ifdef __cplusplus
 }
 #endif
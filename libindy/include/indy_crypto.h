@@ -40,6 +40,16 @@ extern "C" {
                                                                 const char *const vk)
                                        );
 
+    /// Variant of `indy_create_key` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_create_key_ctx(const void*    context,
+                                            indy_handle_t     wallet_handle,
+                                            const char *const key_json,
+
+                                            void              (*cb)(const void*    context,
+                                                                    indy_error_t      err,
+                                                                    const char *const vk)
+                                           );
+
     /// Saves/replaces the meta information for the giving key in the wallet.
     ///
     /// #Params
@@ -68,6 +78,16 @@ extern "C" {
                                                                       indy_error_t      err)
                                               );
 
+    /// Variant of `indy_set_key_metadata` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_set_key_metadata_ctx(const void*    context,
+                                                  indy_handle_t     wallet_handle,
+                                                  const char *const verkey,
+                                                  const char *const metadata,
+
+                                                  void              (*cb)(const void*    context,
+                                                                          indy_error_t      err)
+                                                  );
+
     /// Retrieves the meta information for the giving key in the wallet.
     ///
     /// #Params
@@ -96,6 +116,16 @@ extern "C" {
                                                                       const char *const metadata)
                                              );
 
+    /// Variant of `indy_get_key_metadata` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_get_key_metadata_ctx(const void*    context,
+                                                  indy_handle_t     wallet_handle,
+                                                  const char *const verkey,
+
+                                                  void              (*cb)(const void*    context,
+                                                                          indy_error_t      err,
+                                                                          const char *const metadata)
+                                                 );
+
 
 
 
@@ -131,6 +161,18 @@ extern "C" {
                                                               indy_u32_t       signature_len)
                                         );
 
+    /// Variant of `indy_crypto_sign` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_crypto_sign_ctx(const void*    context,
+                                             indy_handle_t  wallet_handle,
+                                             const char*    signer_vk,
+                                             const indy_u8_t* message_raw,
+                                             indy_u32_t     message_len,
+                                             void           (*cb)(const void*    context,
+                                                                  indy_error_t   err,
+                                                                  const indy_u8_t* signature_raw,
+                                                                  indy_u32_t     signature_len)
+                                            );
+
     /// Verify a signature with a verkey.
     ///
     /// Note to use DID keys with this function you can call indy_key_for_did to get key id (verkey)
@@ -165,6 +207,18 @@ extern "C" {
                                                                 indy_bool_t   valid )
                                           );
 
+    /// Variant of `indy_crypto_verify` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_crypto_verify_ctx(const void*    context,
+                                               const char*    signer_vk,
+                                               const indy_u8_t* message_raw,
+                                               indy_u32_t     message_len,
+                                               const indy_u8_t* signature_raw,
+                                               indy_u32_t     signature_len,
+                                               void           (*cb)(const void*    context,
+                                                                    indy_error_t   err,
+                                                                    indy_bool_t    valid)
+                                              );
+
     /// **** THIS FUNCTION WILL BE DEPRECATED USE indy_pack_message() INSTEAD ****
     /// Encrypt a message by authenticated-encryption scheme.
     ///
@@ -207,6 +261,20 @@ extern "C" {
                                                                     indy_u32_t        encrypted_msg_len)
                                               );
 
+    /// Variant of `indy_crypto_auth_crypt` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_crypto_auth_crypt_ctx(const void*    context,
+                                                   indy_handle_t      wallet_handle,
+                                                   const char *       sender_vk,
+                                                   const char *       recipient_vk,
+                                                   const indy_u8_t *  message_raw,
+                                                   indy_u32_t         message_len,
+
+                                                   void           (*cb)(const void*    context,
+                                                                        indy_error_t      err,
+                                                                        const indy_u8_t*  encrypted_msg_raw,
+                                                                        indy_u32_t        encrypted_msg_len)
+                                                  );
+
     /// **** THIS FUNCTION WILL BE DEPRECATED USE indy_unpack_message() INSTEAD ****
     /// Decrypt a message by authenticated-encryption scheme.
     ///
@@ -247,6 +315,20 @@ extern "C" {
                                                                       indy_u32_t        decrypted_msg_len)
                                                 );
 
+    /// Variant of `indy_crypto_auth_decrypt` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_crypto_auth_decrypt_ctx(const void*    context,
+                                                     indy_handle_t      wallet_handle,
+                                                     const char *       recipient_vk,
+                                                     const indy_u8_t*   encrypted_msg_raw,
+                                                     indy_u32_t         encrypted_msg_len,
+
+                                                     void           (*cb)(const void*    context,
+                                                                          indy_error_t      err,
+                                                                          const char *      sender_vk,
+                                                                          const indy_u8_t*  decrypted_msg_raw,
+                                                                          indy_u32_t        decrypted_msg_len)
+                                                    );
+
 
     /// Encrypts a message by anonymous-encryption scheme.
     ///
@@ -285,6 +367,18 @@ extern "C" {
                                                                     indy_u32_t        encrypted_msg_len)
                                                );
 
+    /// Variant of `indy_crypto_anon_crypt` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_crypto_anon_crypt_ctx(const void*    context,
+                                                   const char *       recipient_vk,
+                                                   const indy_u8_t *  message_raw,
+                                                   indy_u32_t         message_len,
+
+                                                   void           (*cb)(const void*    context,
+                                                                        indy_error_t      err,
+                                                                        const indy_u8_t*  encrypted_msg_raw,
+                                                                        indy_u32_t        encrypted_msg_len)
+                                                   );
+
     /// Decrypts a message by anonymous-encryption scheme.
     ///
     /// Sealed boxes are designed to anonymously send messages to a Recipient given its public key.
@@ -323,6 +417,19 @@ extern "C" {
                                                                       indy_u32_t        decrypted_msg_len)
                                                  );
 
+    /// Variant of `indy_crypto_anon_decrypt` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_crypto_anon_decrypt_ctx(const void*    context,
+                                                     indy_handle_t      wallet_handle,
+                                                     const char *       recipient_vk,
+                                                     const indy_u8_t*   encrypted_msg,
+                                                     indy_u32_t         encrypted_len,
+
+                                                     void           (*cb)(const void*    context,
+                                                                          indy_error_t      err,
+                                                                          const indy_u8_t*  decrypted_msg_raw,
+                                                                          indy_u32_t        decrypted_msg_len)
+                                                     );
+
 
     /// Packs a message by encrypting the message and serializes it in a JWE-like format (Experimental)
     ///
@@ -403,6 +510,49 @@ extern "C" {
                                                                indy_u32_t        jwe_msg_len)
                                           );
 
+    /// Variant of `indy_pack_message` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_pack_message_ctx(const void*    context,
+                                              indy_handle_t  wallet_handle,
+                                              const indy_u8_t* message,
+                                              indy_u32_t     message_len,
+                                              const char*    receiver_keys,
+                                              const char*    sender,
+                                              void           (*cb)(const void*    context,
+                                                                   indy_error_t   err,
+                                                                   const indy_u8_t* jwe_data,
+                                                                   indy_u32_t     jwe_len)
+                                             );
+
+    /// Variant of `indy_pack_message` that passes ownership of the packed message to the application
+    /// instead of pointer valid only during the callback.
+    ///
+    /// The buffer passed to the callback must be released with `indy_free_buffer`.
+    /// On error NULL buffer is passed.
+    extern indy_error_t indy_pack_message_buffer(indy_handle_t  command_handle,
+                                                 indy_handle_t  wallet_handle,
+                                                 const indy_u8_t* message,
+                                                 indy_u32_t     message_len,
+                                                 const char*    receiver_keys,
+                                                 const char*    sender,
+                                                 void           (*cb)(indy_handle_t  command_handle_,
+                                                                      indy_error_t   err,
+                                                                      indy_u8_t*     jwe_data,
+                                                                      indy_u32_t     jwe_len)
+                                                );
+
+    /// Variant of `indy_pack_message_buffer` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_pack_message_buffer_ctx(const void*    context,
+                                                     indy_handle_t  wallet_handle,
+                                                     const indy_u8_t* message,
+                                                     indy_u32_t     message_len,
+                                                     const char*    receiver_keys,
+                                                     const char*    sender,
+                                                     void           (*cb)(const void*    context,
+                                                                          indy_error_t   err,
+                                                                          indy_u8_t*     jwe_data,
+                                                                          indy_u32_t     jwe_len)
+                                                    );
+
 
     /// Unpacks a JWE-like formatted message outputted by indy_pack_message (Experimental)
     ///
@@ -445,6 +595,43 @@ extern "C" {
                                                                  const indy_u8_t*  res_json_raw,
                                                                  indy_u32_t        res_json_len)
                                             );
+
+    /// Variant of `indy_unpack_message` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_unpack_message_ctx(const void*    context,
+                                                indy_handle_t  wallet_handle,
+                                                const indy_u8_t* jwe_data,
+                                                indy_u32_t     jwe_len,
+                                                void           (*cb)(const void*    context,
+                                                                     indy_error_t   err,
+                                                                     const indy_u8_t* res_json_data,
+                                                                     indy_u32_t     res_json_len)
+                                               );
+
+    /// Variant of `indy_unpack_message` that passes ownership of the unpacked result json to the application
+    /// instead of pointer valid only during the callback.
+    ///
+    /// The buffer passed to the callback must be released with `indy_free_buffer`.
+    /// On error NULL buffer is passed.
+    extern indy_error_t indy_unpack_message_buffer(indy_handle_t  command_handle,
+                                                   indy_handle_t  wallet_handle,
+                                                   const indy_u8_t* jwe_data,
+                                                   indy_u32_t     jwe_len,
+                                                   void           (*cb)(indy_handle_t  command_handle_,
+                                                                        indy_error_t   err,
+                                                                        indy_u8_t*     res_json_data,
+                                                                        indy_u32_t     res_json_len)
+                                                  );
+
+    /// Variant of `indy_unpack_message_buffer` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_unpack_message_buffer_ctx(const void*    context,
+                                                       indy_handle_t  wallet_handle,
+                                                       const indy_u8_t* jwe_data,
+                                                       indy_u32_t     jwe_len,
+                                                       void           (*cb)(const void*    context,
+                                                                            indy_error_t   err,
+                                                                            indy_u8_t*     res_json_data,
+                                                                            indy_u32_t     res_json_len)
+                                                      );
 #ifdef __cplusplus
 }
 #endif
@@ -26,6 +26,8 @@ extern "C" {
     ///               currently only 'ed25519' value is supported for this field)
     ///     "cid": bool, (optional; if not set then false is used;)
     ///     "method_name": string, method name to create fully qualified did (Example:  `did:method_name:NcYxiDXkpYi6ov5FcYDi1e`).
+    ///             Use `indy:<namespace>` (for example `indy:sovrin:staging`) to create did:indy DID:
+    ///             its identifier is the first 16 bytes of SHA256 hash of the verkey (`did:indy:sovrin:staging:<id>`).
     /// }
     /// cb: Callback that takes command result as parameter.
     ///
@@ -52,6 +54,16 @@ extern "C" {
                                                                           const char *const   verkey)
                                                     );
 
+    /// Variant of `indy_create_and_store_my_did` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_create_and_store_my_did_ctx(const void*    context,
+                                                         indy_handle_t  wallet_handle,
+                                                         const char*    did_info,
+                                                         void           (*cb)(const void*    context,
+                                                                              indy_error_t   err,
+                                                                              const char*    did,
+                                                                              const char*    verkey)
+                                                        );
+
     /// Generated temporary keys (signing and encryption keys) for an existing
     /// DID (owned by the caller of the library).
     ///
@@ -90,6 +102,17 @@ extern "C" {
                                                                      const char *const   verkey)
                                                );
 
+    /// Variant of `indy_replace_keys_start` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_replace_keys_start_ctx(const void*    context,
+                                                    indy_handle_t wallet_handle,
+                                                    const char *  did,
+                                                    const char *  identity_json,
+
+                                                    void           (*cb)(const void*    context,
+                                                                         indy_error_t  err,
+                                                                         const char *const   verkey)
+                                                   );
+
     /// Apply temporary keys as main for an existing DID (owned by the caller of the library).
     ///
     /// #Params
@@ -117,6 +140,76 @@ extern "C" {
                                                                      indy_error_t  err)
                                                );
 
+    /// Variant of `indy_replace_keys_apply` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_replace_keys_apply_ctx(const void*    context,
+                                                    indy_handle_t wallet_handle,
+                                                    const char *  did,
+
+                                                    void           (*cb)(const void*    context,
+                                                                         indy_error_t  err)
+                                                   );
+
+    /// Performs complete rotation of keys for an existing DID (owned by the caller of the library):
+    ///     - generates temporary keys (as "indy_replace_keys_start" does)
+    ///     - builds NYM request with the new verkey, signs it with the current key and sends it to the ledger.
+    ///       If endorser DID is passed the request is signed by both DID and endorser (endorser must be stored in the same wallet).
+    ///     - applies temporary keys as main on successful reply (as "indy_replace_keys_apply" does).
+    ///
+    /// If the ledger write fails temporary keys are removed and the DID keeps the current key.
+    /// The only exception is PoolLedgerTimeout error: the ledger state is unknown in this case so temporary keys are kept.
+    /// Check the ledger and finish rotation by "indy_replace_keys_apply" call.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// wallet_handle: wallet handler (created by open_wallet).
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// did: target did to rotate keys.
+    /// key_info: key information as json. Example:
+    /// {
+    ///     "seed": string, (optional) Seed that allows deterministic key creation (if not set random one will be created).
+    ///                                Can be UTF-8, base64 or hex string.
+    ///     "crypto_type": string, (optional; if not set then ed25519 curve is used;
+    ///               currently only 'ed25519' value is supported for this field)
+    /// }
+    /// endorser_did: (Optional) DID of the Endorser that will sign and pay for the transaction.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - verkey: The DIDs new verification key
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    /// Ledger*
+    /// Pool*
+    extern indy_error_t indy_rotate_key(indy_handle_t command_handle,
+                                        indy_handle_t wallet_handle,
+                                        indy_handle_t pool_handle,
+                                        const char *const did,
+                                        const char *const key_info,
+                                        const char *const endorser_did,
+
+                                        void (*cb)(indy_handle_t command_handle_,
+                                                   indy_error_t  err,
+                                                   const char *const verkey)
+                                        );
+
+    /// Variant of `indy_rotate_key` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_rotate_key_ctx(const void*    context,
+                                            indy_handle_t wallet_handle,
+                                            indy_handle_t pool_handle,
+                                            const char *const did,
+                                            const char *const key_info,
+                                            const char *const endorser_did,
+
+                                            void (*cb)(const void*    context,
+                                                       indy_error_t  err,
+                                                       const char *const verkey)
+                                            );
+
     /// Saves their DID for a pairwise connection in a secured Wallet,
     /// so that it can be used to verify transaction.
     ///
@@ -151,6 +244,15 @@ extern "C" {
                                                                  indy_error_t  err)
                                            );
 
+   /// Variant of `indy_store_their_did` that passes application `context` to the callback instead of command handle.
+   extern indy_error_t indy_store_their_did_ctx(const void*    context,
+                                                indy_handle_t wallet_handle,
+                                                const char *  identity_json,
+
+                                                void           (*cb)(const void*    context,
+                                                                     indy_error_t  err)
+                                               );
+
     /// Returns ver key (key id) for the given DID.
     ///
     /// "indy_key_for_did" call follow the idea that we resolve information about their DID from
@@ -191,6 +293,17 @@ extern "C" {
                                                                  const char *const key)
                                         );
 
+    /// Variant of `indy_key_for_did` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_key_for_did_ctx(const void*    context,
+                                             indy_handle_t     pool_handle,
+                                             indy_handle_t     wallet_handle,
+                                             const char *const did,
+
+                                             void              (*cb)(const void*    context,
+                                                                     indy_error_t      err,
+                                                                     const char *const key)
+                                            );
+
     /// Returns ver key (key id) for the given DID.
     ///
     /// "indy_key_for_local_did" call looks data stored in the local wallet only and skips freshness
@@ -228,6 +341,15 @@ extern "C" {
                                                                      const char *const key)
                                              );
 
+    /// Variant of `indy_key_for_local_did` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_key_for_local_did_ctx(const void*    context,
+                                                   indy_handle_t  wallet_handle,
+                                                   const char*    did,
+                                                   void           (*cb)(const void*    context,
+                                                                        indy_error_t   err,
+                                                                        const char*    key)
+                                                  );
+
     /// Set/replaces endpoint information for the given DID.
     ///
     /// #Params
@@ -258,6 +380,17 @@ extern "C" {
                                                                           indy_error_t      err)
                                                  );
 
+    /// Variant of `indy_set_endpoint_for_did` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_set_endpoint_for_did_ctx(const void*    context,
+                                                      indy_handle_t     wallet_handle,
+                                                      const char *const did,
+                                                      const char *const address,
+                                                      const char *const transport_key,
+
+                                                      void              (*cb)(const void*    context,
+                                                                              indy_error_t      err)
+                                                     );
+
     /// Returns endpoint information for the given DID.
     ///
     /// #Params
@@ -289,6 +422,18 @@ extern "C" {
                                                                           const char *const transport_vk)
                                                  );
 
+    /// Variant of `indy_get_endpoint_for_did` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_get_endpoint_for_did_ctx(const void*    context,
+                                                      indy_handle_t     wallet_handle,
+                                                      indy_handle_t     pool_handle,
+                                                      const char *const did,
+
+                                                      void              (*cb)(const void*    context,
+                                                                              indy_error_t      err,
+                                                                              const char *const address,
+                                                                              const char *const transport_vk)
+                                                     );
+
     /// Saves/replaces the meta information for the giving DID in the wallet.
     ///
     /// #Params
@@ -296,6 +441,9 @@ extern "C" {
     /// wallet_handle: Wallet handle (created by open_wallet).
     /// did - the DID to store metadata.
     /// metadata - the meta information that will be store with the DID.
+    ///     If it is a JSON object, its top level string fields are also set as tags of my DID,
+    ///     so my DIDs can be filtered by them with "indy_open_my_dids_search". Tags of the previous
+    ///     metadata fields are removed.
     /// cb: Callback that takes command result as parameter.
     ///
     /// #Returns
@@ -317,6 +465,16 @@ extern "C" {
                                                                       indy_error_t      err)
                                              );
 
+    /// Variant of `indy_set_did_metadata` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_set_did_metadata_ctx(const void*    context,
+                                                  indy_handle_t     wallet_handle,
+                                                  const char *const did,
+                                                  const char *const metadata,
+
+                                                  void              (*cb)(const void*    context,
+                                                                          indy_error_t      err)
+                                                 );
+
     /// Retrieves the meta information for the giving DID in the wallet.
     ///
     /// #Params
@@ -345,6 +503,16 @@ extern "C" {
                                                                       const char *const metadata)
                                              );
 
+    /// Variant of `indy_get_did_metadata` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_get_did_metadata_ctx(const void*    context,
+                                                  indy_handle_t     wallet_handle,
+                                                  const char *const did,
+
+                                                  void              (*cb)(const void*    context,
+                                                                          indy_error_t      err,
+                                                                          const char *const metadata)
+                                                 );
+
     /// Retrieves the information about the giving DID in the wallet.
     ///
     /// #Params
@@ -374,8 +542,18 @@ extern "C" {
                                                   void              (*fn)(indy_handle_t command_handle_, indy_error_t err, const char *const did_with_meta)
                                                  );
 
+    /// Variant of `indy_get_my_did_with_meta` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_get_my_did_with_meta_ctx(const void*    context,
+                                                      indy_handle_t     wallet_handle,
+                                                      const char *const my_did,
+                                                      void              (*fn)(const void*    context, indy_error_t err, const char *const did_with_meta)
+                                                     );
+
     /// Retrieves the information about all DIDs stored in the wallet.
     ///
+    /// NOTE: This method immediately returns all DIDs stored in the wallet.
+    /// Use <indy_open_my_dids_search> to filter DIDs and fetch them by small batches.
+    ///
     /// #Params
     /// command_handle: Command handle to map callback to caller context.
     /// wallet_handle: Wallet handle (created by open_wallet).
@@ -401,6 +579,184 @@ extern "C" {
                                                     void          (*fn)(indy_handle_t command_handle_, indy_error_t err, const char *const dids)
                                                    );
 
+    /// Variant of `indy_list_my_dids_with_meta` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_list_my_dids_with_meta_ctx(const void*    context,
+                                                        indy_handle_t wallet_handle,
+                                                        void          (*fn)(const void*    context, indy_error_t err, const char *const dids)
+                                                       );
+
+    /// Saves/replaces tags of my DID stored in the wallet.
+    /// Tags can be used later to filter DIDs with "indy_open_my_dids_search".
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// did - my DID to set tags for.
+    /// tags_json - Tags of the DID as json:
+    ///   {
+    ///     "tagName1": <str>, // string tag (will be stored encrypted)
+    ///     "~tagName2": <str>, // string tag (will be stored un-encrypted)
+    ///   }
+    ///   Note that all previously stored tags of the DID are replaced. Tags of metadata fields are kept
+    ///   unless the given tags have the same names.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: Command handle to map callback to caller context.
+    /// - err: Error code.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_set_my_did_tags(indy_handle_t     command_handle,
+                                             indy_handle_t     wallet_handle,
+                                             const char *const did,
+                                             const char *const tags_json,
+
+                                             void              (*cb)(indy_handle_t     command_handle,
+                                                                     indy_error_t      err)
+                                            );
+
+    /// Variant of `indy_set_my_did_tags` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_set_my_did_tags_ctx(const void*    context,
+                                                 indy_handle_t     wallet_handle,
+                                                 const char *const did,
+                                                 const char *const tags_json,
+
+                                                 void              (*cb)(const void*    context,
+                                                                         indy_error_t      err)
+                                                );
+
+    /// Search for my DIDs stored in the wallet.
+    /// DIDs can be filtered by tags set with "indy_set_my_did_tags".
+    ///
+    /// Instead of immediately returning of all DIDs (as "indy_list_my_dids_with_meta" does)
+    /// this call returns search_handle that can be used later
+    /// to fetch DIDs by small batches (with indy_fetch_my_dids).
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// query_json: (Optional) Wql query filter for DIDs searching based on tags.
+    ///     where query: indy-sdk/docs/design/011-wallet-query-language/README.md
+    /// options_json: (Optional) Search options:
+    ///   {
+    ///     "retrieveMetadata": (optional, true by default) Retrieve the meta information stored with the DID,
+    ///     "retrieveTempVerkey": (optional, true by default) Retrieve temporary verkey created by key rotation
+    ///   }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: Command handle to map callback to caller context.
+    /// - err: Error code.
+    /// - search_handle: Search handle that can be used later to fetch DIDs by small batches (with indy_fetch_my_dids)
+    /// - total_count: Total count of DIDs matching the query
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    extern indy_error_t indy_open_my_dids_search(indy_handle_t     command_handle,
+                                                 indy_handle_t     wallet_handle,
+                                                 const char *const query_json,
+                                                 const char *const options_json,
+
+                                                 void              (*cb)(indy_handle_t     command_handle,
+                                                                         indy_error_t      err,
+                                                                         indy_handle_t     search_handle,
+                                                                         indy_u32_t        total_count)
+                                                );
+
+    /// Variant of `indy_open_my_dids_search` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_open_my_dids_search_ctx(const void*    context,
+                                                     indy_handle_t     wallet_handle,
+                                                     const char *const query_json,
+                                                     const char *const options_json,
+
+                                                     void              (*cb)(const void*    context,
+                                                                             indy_error_t      err,
+                                                                             indy_handle_t     search_handle,
+                                                                             indy_u32_t        total_count)
+                                                    );
+
+    /// Fetch next DIDs for search.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// search_handle: Search handle (created by indy_open_my_dids_search)
+    /// count: Count of DIDs to fetch
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: Command handle to map callback to caller context.
+    /// - err: Error code.
+    ///   dids:  [{
+    ///     "did": string - DID stored in the wallet,
+    ///     "verkey": string - The DIDs transport key (ver key, key id).,
+    ///     "tempVerkey": string - Temporary DIDs verkey (null if retrieveTempVerkey option is false),
+    ///     "metadata": string - The meta information stored with the DID (null if retrieveMetadata option is false)
+    ///   }]
+    /// NOTE: The list of length less than the requested count means DIDs search iterator is completed.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    extern indy_error_t indy_fetch_my_dids(indy_handle_t     command_handle,
+                                           indy_handle_t     search_handle,
+                                           indy_u32_t        count,
+
+                                           void              (*cb)(indy_handle_t     command_handle,
+                                                                   indy_error_t      err,
+                                                                   const char *const dids)
+                                          );
+
+    /// Variant of `indy_fetch_my_dids` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_fetch_my_dids_ctx(const void*    context,
+                                               indy_handle_t     search_handle,
+                                               indy_u32_t        count,
+
+                                               void              (*cb)(const void*    context,
+                                                                       indy_error_t      err,
+                                                                       const char *const dids)
+                                              );
+
+    /// Close my DIDs search (make search handle invalid)
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// search_handle: Search handle (created by indy_open_my_dids_search)
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: Command handle to map callback to caller context.
+    /// - err: Error code.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    extern indy_error_t indy_close_my_dids_search(indy_handle_t     command_handle,
+                                                  indy_handle_t     search_handle,
+
+                                                  void              (*cb)(indy_handle_t     command_handle,
+                                                                          indy_error_t      err)
+                                                 );
+
+    /// Variant of `indy_close_my_dids_search` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_close_my_dids_search_ctx(const void*    context,
+                                                      indy_handle_t     search_handle,
+
+                                                      void              (*cb)(const void*    context,
+                                                                              indy_error_t      err)
+                                                     );
+
     /// Retrieves abbreviated verkey if it is possible otherwise return full verkey.
     ///
     /// #Params
@@ -427,6 +783,15 @@ extern "C" {
                                                                  const char *const verkey)
                                             );
 
+    /// Variant of `indy_abbreviate_verkey` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_abbreviate_verkey_ctx(const void*    context,
+                                                 const char *const did,
+                                                 const char *const full_verkey,
+                                                 void          (*fn)(const void*    context,
+                                                                     indy_error_t err,
+                                                                     const char *const verkey)
+                                                );
+
     /// Update DID stored in the wallet to make fully qualified, or to do other DID maintenance.
     ///     - If the DID has no prefix, a prefix will be appended (prepend did:peer to a legacy did)
     ///     - If the DID has a prefix, a prefix will be updated (migrate did:peer to did:peer-new)
@@ -435,7 +800,7 @@ extern "C" {
     /// command_handle: Command handle to map callback to caller context.
     /// wallet_handle: Wallet handle (created by open_wallet).
     /// did: target DID stored in the wallet.
-    /// prefix: prefix to apply to the DID.
+    /// prefix: prefix to apply to the DID (`indy:<namespace>` for did:indy DIDs).
     /// cb: Callback that takes command result as parameter.
     ///
     /// #Returns
@@ -457,6 +822,654 @@ extern "C" {
                                                                  const char *const full_qualified_did)
                                         );
 
+    /// Variant of `indy_qualify_did` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_qualify_did_ctx(const void*    context,
+                                             indy_handle_t     wallet_handle,
+                                             const char *const did,
+                                             const char *const method,
+
+                                             void              (*cb)(const void*    context,
+                                                                     indy_error_t      err,
+                                                                     const char *const full_qualified_did)
+                                            );
+
+    /// Updates all unqualified DIDs stored in the wallet to make them fully qualified (as "indy_qualify_did" does for a single DID).
+    ///     - my DIDs get the method and their related entities are moved.
+    ///     - their DIDs get the method, pairwise and other related entities are moved.
+    ///
+    /// All conflicts (fully qualified form of DID already stored in the wallet) are checked before the wallet is changed.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// method - method to apply to the DIDs (`indy:<namespace>` for did:indy DIDs).
+    /// options_json - (optional) options json:
+    /// {
+    ///     dryRun: (optional, false by default) Only build the report without changing of the wallet.
+    /// }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - report_json: qualification report json:
+    /// {
+    ///     dryRun: bool,
+    ///     myDids: [{did: string, qualifiedDid: string}],
+    ///     theirDids: [{did: string, qualifiedDid: string}],
+    ///     pairwiseCount: int, - number of updated pairwise records
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_qualify_all_dids(indy_handle_t     command_handle,
+                                              indy_handle_t     wallet_handle,
+                                              const char *const method,
+                                              const char *const options_json,
+
+                                              void              (*cb)(indy_handle_t     command_handle,
+                                                                    indy_error_t      err,
+                                                                    const char *const report_json)
+                                             );
+
+    /// Variant of `indy_qualify_all_dids` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_qualify_all_dids_ctx(const void*    context,
+                                                  indy_handle_t     wallet_handle,
+                                                  const char *const method,
+                                                  const char *const options_json,
+
+                                                  void              (*cb)(const void*    context,
+                                                                        indy_error_t      err,
+                                                                        const char *const report_json)
+                                                 );
+
+    /// Builds W3C DID Document for the given DID.
+    ///
+    /// The DID can be either my DID or their DID. Verkey and endpoint are resolved in the same way
+    /// as "indy_key_for_did" and "indy_get_endpoint_for_did" calls do: at first local wallet is checked and
+    /// information about their DID is fetched from the ledger (with cache in the local wallet) if it is missed.
+    /// Endpoint of my DID is looked only in the local wallet.
+    ///
+    /// Note if DID has no endpoint neither in the wallet nor on the ledger DID Document is built without services.
+    /// Additional keys and services stored for the DID (see "indy_add_did_key" and "indy_add_did_service")
+    /// are appended to the corresponding sections of DID Document.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// pool_handle: Pool handle (created by open_pool).
+    /// did - The DID to build DID Document for.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - did_doc: DID Document json
+    ///   {
+    ///     "@context": "https://www.w3.org/ns/did/v1",
+    ///     "id": string - fully qualified DID (unqualified DIDs are prefixed with "did:sov:"),
+    ///     "verificationMethod": [{"id": "<id>#key-1", "type": "Ed25519VerificationKey2018", "controller": "<id>", "publicKeyBase58": <verkey>}],
+    ///     "authentication": ["<id>#key-1"],
+    ///     "assertionMethod": ["<id>#key-1"],
+    ///     "keyAgreement": [{"id": "<id>#key-agreement-1", "type": "X25519KeyAgreementKey2019", "controller": "<id>", "publicKeyBase58": <verkey converted to X25519>}],
+    ///     "service": [{"id": "<id>#did-communication", "type": "did-communication", "serviceEndpoint": <endpoint address>,
+    ///                  "recipientKeys": [<endpoint transport key or verkey>], "routingKeys": [], "priority": 0}]
+    ///   }
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    /// Ledger*
+    extern indy_error_t indy_get_did_doc(indy_handle_t     command_handle,
+                                         indy_handle_t     wallet_handle,
+                                         indy_handle_t     pool_handle,
+                                         const char *const did,
+
+                                         void              (*cb)(indy_handle_t     command_handle,
+                                                                 indy_error_t      err,
+                                                                 const char *const did_doc)
+                                        );
+
+    /// Variant of `indy_get_did_doc` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_get_did_doc_ctx(const void*    context,
+                                             indy_handle_t     wallet_handle,
+                                             indy_handle_t     pool_handle,
+                                             const char *const did,
+
+                                             void              (*cb)(const void*    context,
+                                                                     indy_error_t      err,
+                                                                     const char *const did_doc)
+                                            );
+
+    /// Adds additional verification key to the DID stored in the wallet.
+    /// The key is published in DID Document built by "indy_get_did_doc" besides DID verkey.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// did - The DID (my or their) stored in the wallet.
+    /// key_json - Key information as json:
+    ///   {
+    ///     "id": string - key id unique among keys and services of the DID (DID URL fragment without leading `#`, for example "key-2"),
+    ///         ids "key-1", "key-agreement-1" and "did-communication" are reserved,
+    ///     "purpose": string - one of "authentication", "assertionMethod", "keyAgreement",
+    ///     "verkey": string - Ed25519 verkey (for "keyAgreement" purpose it is converted to X25519 key in DID Document)
+    ///   }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: Command handle to map callback to caller context.
+    /// - err: Error code.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_add_did_key(indy_handle_t     command_handle,
+                                         indy_handle_t     wallet_handle,
+                                         const char *const did,
+                                         const char *const key_json,
+
+                                         void              (*cb)(indy_handle_t     command_handle,
+                                                               indy_error_t      err)
+                                        );
+
+    /// Variant of `indy_add_did_key` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_add_did_key_ctx(const void*    context,
+                                             indy_handle_t     wallet_handle,
+                                             const char *const did,
+                                             const char *const key_json,
+
+                                             void              (*cb)(const void*    context,
+                                                                   indy_error_t      err)
+                                            );
+
+    /// Removes additional verification key added by "indy_add_did_key" from the DID stored in the wallet.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// did - The DID (my or their) stored in the wallet.
+    /// key_id - Id of the key to remove.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: Command handle to map callback to caller context.
+    /// - err: Error code.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_remove_did_key(indy_handle_t     command_handle,
+                                            indy_handle_t     wallet_handle,
+                                            const char *const did,
+                                            const char *const key_id,
+
+                                            void              (*cb)(indy_handle_t     command_handle,
+                                                                  indy_error_t      err)
+                                           );
+
+    /// Variant of `indy_remove_did_key` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_remove_did_key_ctx(const void*    context,
+                                                indy_handle_t     wallet_handle,
+                                                const char *const did,
+                                                const char *const key_id,
+
+                                                void              (*cb)(const void*    context,
+                                                                      indy_error_t      err)
+                                               );
+
+    /// Adds additional service endpoint to the DID stored in the wallet.
+    /// The service is published in DID Document built by "indy_get_did_doc" besides endpoint set by "indy_set_endpoint_for_did".
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// did - The DID (my or their) stored in the wallet.
+    /// service_json - Service information as json:
+    ///   {
+    ///     "id": string - service id unique among keys and services of the DID (DID URL fragment without leading `#`, for example "mediator"),
+    ///         ids "key-1", "key-agreement-1" and "did-communication" are reserved,
+    ///     "type": string - service type (for example "did-communication"),
+    ///     "serviceEndpoint": string - service endpoint address,
+    ///     "recipientKeys": [string], (optional) list of recipient verkeys,
+    ///     "routingKeys": [string], (optional) list of routing verkeys,
+    ///     "priority": int, (optional) service priority (0 by default)
+    ///   }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: Command handle to map callback to caller context.
+    /// - err: Error code.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_add_did_service(indy_handle_t     command_handle,
+                                             indy_handle_t     wallet_handle,
+                                             const char *const did,
+                                             const char *const service_json,
+
+                                             void              (*cb)(indy_handle_t     command_handle,
+                                                                   indy_error_t      err)
+                                            );
+
+    /// Variant of `indy_add_did_service` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_add_did_service_ctx(const void*    context,
+                                                 indy_handle_t     wallet_handle,
+                                                 const char *const did,
+                                                 const char *const service_json,
+
+                                                 void              (*cb)(const void*    context,
+                                                                       indy_error_t      err)
+                                                );
+
+    /// Removes additional service endpoint added by "indy_add_did_service" from the DID stored in the wallet.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// did - The DID (my or their) stored in the wallet.
+    /// service_id - Id of the service to remove.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: Command handle to map callback to caller context.
+    /// - err: Error code.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_remove_did_service(indy_handle_t     command_handle,
+                                                indy_handle_t     wallet_handle,
+                                                const char *const did,
+                                                const char *const service_id,
+
+                                                void              (*cb)(indy_handle_t     command_handle,
+                                                                      indy_error_t      err)
+                                               );
+
+    /// Variant of `indy_remove_did_service` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_remove_did_service_ctx(const void*    context,
+                                                    indy_handle_t     wallet_handle,
+                                                    const char *const did,
+                                                    const char *const service_id,
+
+                                                    void              (*cb)(const void*    context,
+                                                                          indy_error_t      err)
+                                                   );
+
+    /// Sets named endpoint with transport profile for the DID stored in the wallet.
+    /// DID can have several named endpoints besides endpoint set by "indy_set_endpoint_for_did".
+    /// Endpoint with the same name is replaced.
+    /// Named endpoints are published in DID Document built by "indy_get_did_doc"
+    /// and can be written to the ledger as "endpoints" attrib (see indy_build_named_endpoints_attrib_request).
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// did - The DID (my or their) stored in the wallet.
+    /// endpoint_json - Endpoint information as json:
+    ///   {
+    ///     "name": string - endpoint name unique for the DID (DID URL fragment without leading `#`, for example "agent"),
+    ///         names "key-1", "key-agreement-1" and "did-communication" are reserved,
+    ///     "endpoint": string - endpoint address with scheme matching transport (for example "wss://agent.example.com"),
+    ///     "transport": string - transport type, one of "http", "https", "ws", "wss",
+    ///     "verkey": string, (optional) recipient verkey of the endpoint (DID verkey is used by default),
+    ///     "routingKeys": [string], (optional) list of routing verkeys,
+    ///     "priority": int, (optional) endpoint priority (0 by default)
+    ///   }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: Command handle to map callback to caller context.
+    /// - err: Error code.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_set_named_endpoint_for_did(indy_handle_t     command_handle,
+                                                        indy_handle_t     wallet_handle,
+                                                        const char *const did,
+                                                        const char *const endpoint_json,
+
+                                                        void              (*cb)(indy_handle_t     command_handle,
+                                                                              indy_error_t      err)
+                                                       );
+
+    /// Variant of `indy_set_named_endpoint_for_did` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_set_named_endpoint_for_did_ctx(const void*    context,
+                                                            indy_handle_t     wallet_handle,
+                                                            const char *const did,
+                                                            const char *const endpoint_json,
+
+                                                            void              (*cb)(const void*    context,
+                                                                                  indy_error_t      err)
+                                                           );
+
+    /// Removes named endpoint set by "indy_set_named_endpoint_for_did" from the DID stored in the wallet.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// did - The DID (my or their) stored in the wallet.
+    /// name - Name of the endpoint to remove.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: Command handle to map callback to caller context.
+    /// - err: Error code.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_remove_named_endpoint_for_did(indy_handle_t     command_handle,
+                                                           indy_handle_t     wallet_handle,
+                                                           const char *const did,
+                                                           const char *const name,
+
+                                                           void              (*cb)(indy_handle_t     command_handle,
+                                                                                 indy_error_t      err)
+                                                          );
+
+    /// Variant of `indy_remove_named_endpoint_for_did` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_remove_named_endpoint_for_did_ctx(const void*    context,
+                                                               indy_handle_t     wallet_handle,
+                                                               const char *const did,
+                                                               const char *const name,
+
+                                                               void              (*cb)(const void*    context,
+                                                                                     indy_error_t      err)
+                                                              );
+
+    /// Returns named endpoints of the DID.
+    /// Endpoints are taken from the wallet if they are stored there,
+    /// otherwise "endpoints" attrib is fetched from the ledger and cached in the wallet.
+    /// The ledger is never queried for DIDs owned by the caller of the library.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// pool_handle: Pool handle (created by open_pool).
+    /// did - The DID to resolve endpoints.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: Command handle to map callback to caller context.
+    /// - err: Error code.
+    /// - endpoints_json - list of endpoints as json (see indy_set_named_endpoint_for_did for endpoint format).
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    /// Ledger*
+    extern indy_error_t indy_get_named_endpoints_for_did(indy_handle_t     command_handle,
+                                                         indy_handle_t     wallet_handle,
+                                                         indy_handle_t     pool_handle,
+                                                         const char *const did,
+
+                                                         void              (*cb)(indy_handle_t     command_handle,
+                                                                               indy_error_t      err,
+                                                                               const char *const endpoints_json)
+                                                        );
+
+    /// Variant of `indy_get_named_endpoints_for_did` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_get_named_endpoints_for_did_ctx(const void*    context,
+                                                             indy_handle_t     wallet_handle,
+                                                             indy_handle_t     pool_handle,
+                                                             const char *const did,
+
+                                                             void              (*cb)(const void*    context,
+                                                                                   indy_error_t      err,
+                                                                                   const char *const endpoints_json)
+                                                            );
+
+    /// Builds an ATTRIB request publishing named endpoints of the DID stored in the wallet
+    /// as "endpoints" raw attrib:
+    ///   {"endpoints": [<endpoint_json>, ...]}
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// submitter_did: Identifier (DID) of the transaction author as base58-encoded string.
+    /// target_did: Target DID whose named endpoints are published.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: Command handle to map callback to caller context.
+    /// - err: Error code.
+    /// - request_json: Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_build_named_endpoints_attrib_request(indy_handle_t     command_handle,
+                                                                  indy_handle_t     wallet_handle,
+                                                                  const char *const submitter_did,
+                                                                  const char *const target_did,
+
+                                                                  void              (*cb)(indy_handle_t     command_handle,
+                                                                                        indy_error_t      err,
+                                                                                        const char *const request_json)
+                                                                 );
+
+    /// Variant of `indy_build_named_endpoints_attrib_request` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_named_endpoints_attrib_request_ctx(const void*    context,
+                                                                      indy_handle_t     wallet_handle,
+                                                                      const char *const submitter_did,
+                                                                      const char *const target_did,
+
+                                                                      void              (*cb)(const void*    context,
+                                                                                            indy_error_t      err,
+                                                                                            const char *const request_json)
+                                                                     );
+
+    /// Exports DID owned by the caller of the library with all related wallet records
+    /// (keys, temporary keys, metadata, tags, endpoint, additional keys and services,
+    /// pairwise records with their DIDs) as an encrypted bundle.
+    /// The bundle is encrypted by anonymous-encryption scheme for the recipient key
+    /// that must be stored in the destination wallet (see indy_create_key).
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// did - DID to export.
+    /// recipient_vk - Verkey of the key stored in the destination wallet.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: Command handle to map callback to caller context.
+    /// - err: Error code.
+    /// - bundle_raw: a pointer to first byte of encrypted DID bundle
+    /// - bundle_len: an encrypted DID bundle length
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_export_did(indy_handle_t     command_handle,
+                                        indy_handle_t     wallet_handle,
+                                        const char *const did,
+                                        const char *const recipient_vk,
+
+                                        void              (*cb)(indy_handle_t     command_handle,
+                                                              indy_error_t      err,
+                                                              const indy_u8_t*  bundle_raw,
+                                                              indy_u32_t        bundle_len)
+                                       );
+
+    /// Variant of `indy_export_did` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_export_did_ctx(const void*    context,
+                                            indy_handle_t     wallet_handle,
+                                            const char *const did,
+                                            const char *const recipient_vk,
+
+                                            void              (*cb)(const void*    context,
+                                                                  indy_error_t      err,
+                                                                  const indy_u8_t*  bundle_raw,
+                                                                  indy_u32_t        bundle_len)
+                                           );
+
+    /// Imports DID bundle created by "indy_export_did" into the wallet.
+    /// Fails without changing the wallet if the DID, their DIDs or pairwise records from the bundle already exist.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// recipient_vk - Id (verkey) of my key the bundle is encrypted for.
+    /// bundle_raw: a pointer to first byte of encrypted DID bundle
+    /// bundle_len: an encrypted DID bundle length
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: Command handle to map callback to caller context.
+    /// - err: Error code.
+    /// - did: Imported DID.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_import_did(indy_handle_t     command_handle,
+                                        indy_handle_t     wallet_handle,
+                                        const char *const recipient_vk,
+                                        const indy_u8_t*  bundle_raw,
+                                        indy_u32_t        bundle_len,
+
+                                        void              (*cb)(indy_handle_t     command_handle,
+                                                              indy_error_t      err,
+                                                              const char *const did)
+                                       );
+
+    /// Variant of `indy_import_did` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_import_did_ctx(const void*    context,
+                                            indy_handle_t     wallet_handle,
+                                            const char *const recipient_vk,
+                                            const indy_u8_t*  bundle_raw,
+                                            indy_u32_t        bundle_len,
+
+                                            void              (*cb)(const void*    context,
+                                                                  indy_error_t      err,
+                                                                  const char *const did)
+                                           );
+
+    /// Marks DID (my or their) stored in the wallet as deactivated.
+    /// Ledger requests are not signed by deactivated DID anymore (see "indy_force_sign_request" to sign it anyway).
+    /// DID Document returned by "indy_get_did_doc" and DID info returned by "indy_get_my_did_with_meta"
+    /// contain "deactivated": true field for deactivated DID.
+    ///
+    /// Note that this call does not change the ledger state. Use "indy_publish_did_deactivation" for this.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// did - DID to deactivate.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: Command handle to map callback to caller context.
+    /// - err: Error code.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_deactivate_did(indy_handle_t     command_handle,
+                                            indy_handle_t     wallet_handle,
+                                            const char *const did,
+
+                                            void              (*cb)(indy_handle_t     command_handle,
+                                                                  indy_error_t      err)
+                                           );
+
+    /// Variant of `indy_deactivate_did` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_deactivate_did_ctx(const void*    context,
+                                                indy_handle_t     wallet_handle,
+                                                const char *const did,
+
+                                                void              (*cb)(const void*    context,
+                                                                      indy_error_t      err)
+                                               );
+
+    /// Publishes deactivation of DID (owned by the caller of the library) to the ledger:
+    ///     - builds NYM request with null verkey that removes DID verkey from the ledger
+    ///       so the DID can't sign transactions anymore,
+    ///     - signs it with the DID key (even if the DID is already deactivated in the wallet) and sends it to the ledger,
+    ///     - marks DID as deactivated in the wallet (as "indy_deactivate_did" does) on successful reply.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// pool_handle: Pool handle (created by open_pool_ledger).
+    /// did - DID to deactivate.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: Command handle to map callback to caller context.
+    /// - err: Error code.
+    /// - response: Ledger response json.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    /// Ledger*
+    /// Pool*
+    extern indy_error_t indy_publish_did_deactivation(indy_handle_t     command_handle,
+                                                      indy_handle_t     wallet_handle,
+                                                      indy_handle_t     pool_handle,
+                                                      const char *const did,
+
+                                                      void              (*cb)(indy_handle_t     command_handle,
+                                                                            indy_error_t      err,
+                                                                            const char *const response)
+                                                     );
+
+    /// Variant of `indy_publish_did_deactivation` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_publish_did_deactivation_ctx(const void*    context,
+                                                          indy_handle_t     wallet_handle,
+                                                          indy_handle_t     pool_handle,
+                                                          const char *const did,
+
+                                                          void              (*cb)(const void*    context,
+                                                                                indy_error_t      err,
+                                                                                const char *const response)
+                                                         );
+
 #ifdef __cplusplus
 }
 #endif
@@ -41,6 +41,17 @@ extern "C" {
                                                                           indy_error_t  err,
                                                                           const char*   request_result_json)
                                                      );
+
+    /// Variant of `indy_sign_and_submit_request` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_sign_and_submit_request_ctx(const void*    context,
+                                                         indy_handle_t  pool_handle,
+                                                         indy_handle_t  wallet_handle,
+                                                         const char*    submitter_did,
+                                                         const char*    request_json,
+                                                         void           (*cb)(const void*    context,
+                                                                              indy_error_t   err,
+                                                                              const char*    request_result_json)
+                                                        );
     
     /// Publishes request message to validator pool (no signing, unlike sign_and_submit_request).
     ///
@@ -68,6 +79,347 @@ extern "C" {
                                                                  const char*   request_result_json)
                                            );
 
+    /// Variant of `indy_submit_request` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_submit_request_ctx(const void*    context,
+                                                indy_handle_t  pool_handle,
+                                                const char*    request_json,
+                                                void           (*cb)(const void*    context,
+                                                                     indy_error_t   err,
+                                                                     const char*    request_result_json)
+                                               );
+
+    /// Publishes request message to validator pool (no signing) with per-request reply timeout and priority.
+    ///
+    /// High priority requests are sent before the normal priority requests still waiting in the queue
+    /// of the pool worker, so short reads aren't stuck behind the requests submitted earlier.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// request_json: Request data json.
+    /// options_json: submit options
+    ///     {
+    ///         "timeout": <int> (optional) - time in seconds to wait for the replies (ACK for write requests).
+    ///                                       Overrides pool `timeout` for this request.
+    ///         "priority": <string> (optional) - "normal" (default) or "high".
+    ///     }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+
+    extern indy_error_t indy_submit_request_with_options(indy_handle_t command_handle,
+                                                         indy_handle_t pool_handle,
+                                                         const char *  request_json,
+                                                         const char *  options_json,
+
+                                                         void           (*cb)(indy_handle_t command_handle_,
+                                                                              indy_error_t  err,
+                                                                              const char*   request_result_json)
+                                                        );
+
+    /// Variant of `indy_submit_request_with_options` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_submit_request_with_options_ctx(const void*    context,
+                                                             indy_handle_t pool_handle,
+                                                             const char *  request_json,
+                                                             const char *  options_json,
+
+                                                             void           (*cb)(const void*    context,
+                                                                                  indy_error_t  err,
+                                                                                  const char*   request_result_json)
+                                                            );
+
+    /// Publishes signed SCHEMA or CRED_DEF request message to validator pool treating rejection
+    /// of already existing ledger entity as success.
+    ///
+    /// Intended for retrying of the writes failed with timeout: if the pool rejects the request
+    /// because the entity already exists, the existing transaction is fetched from the ledger and returned instead.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// request_json: Signed SCHEMA or CRED_DEF request data json.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Submission result json:
+    ///     {
+    ///         "alreadyExists": <bool> - whether the entity was written before,
+    ///         "response": <object> - reply to the write request or reply to the read request fetching existing entity
+    ///     }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+
+    extern indy_error_t indy_submit_request_idempotent(indy_handle_t command_handle,
+                                                       indy_handle_t pool_handle,
+                                                       const char *  request_json,
+
+                                                       void           (*cb)(indy_handle_t command_handle_,
+                                                                            indy_error_t  err,
+                                                                            const char*   submit_result_json)
+                                                      );
+
+    /// Variant of `indy_submit_request_idempotent` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_submit_request_idempotent_ctx(const void*    context,
+                                                           indy_handle_t pool_handle,
+                                                           const char *  request_json,
+
+                                                           void           (*cb)(const void*    context,
+                                                                                indy_error_t  err,
+                                                                                const char*   submit_result_json)
+                                                          );
+
+    /// Publishes several request messages to validator pool at once (no signing).
+    ///
+    /// Requests are sent over the same pool connections concurrently and results are returned in the order of requests.
+    /// Failure of one request doesn't affect other requests of the batch.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// requests_json: json array of prepared requests.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Json array of results in the same order as requests:
+    /// [
+    ///     {
+    ///         "response": <string> - request result json (set if request succeed),
+    ///         "error": {            - (set if request failed)
+    ///             "code": <int> - error code,
+    ///             "message": <string> - error message
+    ///         }
+    ///     }
+    /// ]
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+
+    extern indy_error_t indy_submit_requests(indy_handle_t command_handle,
+                                             indy_handle_t pool_handle,
+                                             const char *  requests_json,
+
+                                             void           (*cb)(indy_handle_t command_handle_,
+                                                                  indy_error_t  err,
+                                                                  const char*   responses_json)
+                                            );
+
+    /// Variant of `indy_submit_requests` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_submit_requests_ctx(const void*    context,
+                                                 indy_handle_t pool_handle,
+                                                 const char *  requests_json,
+
+                                                 void           (*cb)(const void*    context,
+                                                                      indy_error_t  err,
+                                                                      const char*   responses_json)
+                                                );
+
+    /// Fetches a range of transactions from the ledger in one call.
+    ///
+    /// GET_TXN requests for the range are sent to the pool by batches of 100 concurrent requests.
+    /// Use `indy_stream_txns` to get transactions of large ranges without keeping them all in memory.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// submitter_did: (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+    /// ledger_type: (Optional) type of the ledger the requested transactions belong to:
+    ///     DOMAIN - used default,
+    ///     POOL,
+    ///     CONFIG
+    ///     any number
+    /// from: sequence number of the first requested transaction.
+    /// to: sequence number of the last requested transaction.
+    ///     Fetching stops at the end of the ledger, so INT32_MAX can be passed to get all the transactions since `from`.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Json array of transactions ordered by sequence number (transactions not found on the ledger are omitted):
+    /// [
+    ///     {
+    ///         "data": <object> - transaction data,
+    ///         "seqNo": <int> - transaction sequence number,
+    ///         "txnTime": <int> - transaction ordering time
+    ///     }
+    /// ]
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+    extern indy_error_t indy_get_txns(indy_handle_t command_handle,
+                                      indy_handle_t pool_handle,
+                                      const char *  submitter_did,
+                                      const char *  ledger_type,
+                                      indy_i32_t    from,
+                                      indy_i32_t    to,
+
+                                      void           (*cb)(indy_handle_t command_handle_,
+                                                           indy_error_t  err,
+                                                           const char*   txns_json)
+                                     );
+
+    /// Variant of `indy_get_txns` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_get_txns_ctx(const void*    context,
+                                          indy_handle_t pool_handle,
+                                          const char *  submitter_did,
+                                          const char *  ledger_type,
+                                          indy_i32_t    from,
+                                          indy_i32_t    to,
+
+                                          void           (*cb)(const void*    context,
+                                                               indy_error_t  err,
+                                                               const char*   txns_json)
+                                         );
+
+    /// Streams a range of transactions from the ledger.
+    ///
+    /// GET_TXN requests for the range are sent to the pool by batches of 100 concurrent requests
+    /// and transactions of every batch are passed to `txns_cb` as soon as the batch is completed.
+    /// `cb` is called once all the transactions are streamed or fetching is failed.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// submitter_did: (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+    /// ledger_type: (Optional) type of the ledger the requested transactions belong to:
+    ///     DOMAIN - used default,
+    ///     POOL,
+    ///     CONFIG
+    ///     any number
+    /// from: sequence number of the first requested transaction.
+    /// to: sequence number of the last requested transaction.
+    ///     Fetching stops at the end of the ledger, so INT32_MAX can be passed to get all the transactions since `from`.
+    /// txns_cb: Callback that takes json array of transactions of a batch ordered by sequence number:
+    ///     [
+    ///         {
+    ///             "data": <object> - transaction data,
+    ///             "seqNo": <int> - transaction sequence number,
+    ///             "txnTime": <int> - transaction ordering time
+    ///         }
+    ///     ]
+    ///     Transactions not found on the ledger are omitted.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error code
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+    extern indy_error_t indy_stream_txns(indy_handle_t command_handle,
+                                         indy_handle_t pool_handle,
+                                         const char *  submitter_did,
+                                         const char *  ledger_type,
+                                         indy_i32_t    from,
+                                         indy_i32_t    to,
+
+                                         void           (*txns_cb)(indy_handle_t command_handle_,
+                                                                   const char*   txns_json),
+
+                                         void           (*cb)(indy_handle_t command_handle_,
+                                                              indy_error_t  err)
+                                        );
+
+    /// Variant of `indy_stream_txns` that passes application `context` to the callbacks instead of command handle.
+    extern indy_error_t indy_stream_txns_ctx(const void*   context,
+                                             indy_handle_t pool_handle,
+                                             const char *  submitter_did,
+                                             const char *  ledger_type,
+                                             indy_i32_t    from,
+                                             indy_i32_t    to,
+
+                                             void           (*txns_cb)(const void*   context,
+                                                                       const char*   txns_json),
+
+                                             void           (*cb)(const void*   context,
+                                                                  indy_error_t  err)
+                                            );
+
+    /// Subscribes to notifications about new transactions written to the ledger.
+    ///
+    /// EXPERIMENTAL
+    ///
+    /// The ledger is polled by GET_TXN requests starting from `fromSeqNo` transaction
+    /// and `event_cb` is called for every transaction matching the filter in order of sequence numbers.
+    /// Polling is stopped once the subscription is cancelled by `indy_unsubscribe_ledger_events` or the pool is closed.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// filter_json: filter of transactions to notify about:
+    ///     {
+    ///         "dids": [<string>, ...], - (Optional) DIDs to notify about transactions authored by or targeting them
+    ///         "txnTypes": [<string>, ...], - (Optional) types of transactions to notify about (f.e. NYM or 1)
+    ///         "ledgerType": <string>, - (Optional) type of the ledger to watch (DOMAIN - used default, POOL, CONFIG or any number)
+    ///         "fromSeqNo": <int>, - (Optional) sequence number of the first transaction to check (1 - used default)
+    ///         "pollInterval": <int>, - (Optional) interval in seconds between polls of the ledger (10 - used default)
+    ///     }
+    ///     Empty list of DIDs or transaction types matches any transaction.
+    /// event_cb: Callback that takes subscription handle and matched transaction json:
+    ///     {
+    ///         "data": <object> - transaction data,
+    ///         "seqNo": <int> - transaction sequence number,
+    ///         "txnTime": <int> - transaction ordering time
+    ///     }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// There is no `_ctx` variant of this function: `event_cb` is called after the command completes
+    /// and receives subscription handle that the application maps to its context.
+    ///
+    /// #Returns
+    /// Handle of the subscription.
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+    extern indy_error_t indy_subscribe_ledger_events(indy_handle_t command_handle,
+                                                     indy_handle_t pool_handle,
+                                                     const char *  filter_json,
+
+                                                     void           (*event_cb)(indy_handle_t subscription_handle,
+                                                                                const char*   event_json),
+
+                                                     void           (*cb)(indy_handle_t command_handle_,
+                                                                          indy_error_t  err,
+                                                                          indy_handle_t subscription_handle)
+                                                    );
+
+    /// Cancels subscription to ledger events created by `indy_subscribe_ledger_events`.
+    ///
+    /// EXPERIMENTAL
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// subscription_handle: handle of the subscription.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error code
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_unsubscribe_ledger_events(indy_handle_t command_handle,
+                                                       indy_handle_t subscription_handle,
+
+                                                       void           (*cb)(indy_handle_t command_handle_,
+                                                                            indy_error_t  err)
+                                                      );
+
+    /// Variant of `indy_unsubscribe_ledger_events` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_unsubscribe_ledger_events_ctx(const void*    context,
+                                                           indy_handle_t subscription_handle,
+
+                                                           void           (*cb)(const void*    context,
+                                                                                indy_error_t  err)
+                                                          );
+
     /// Send action to particular nodes of validator pool.
     ///
     /// The list of requests can be send:
@@ -104,6 +456,18 @@ extern "C" {
                                                                 const char*   request_result_json)
                                            );
 
+    /// Variant of `indy_submit_action` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_submit_action_ctx(const void*    context,
+                                               indy_handle_t pool_handle,
+                                               const char *  request_json,
+                                               const char *  nodes,
+                                               indy_i32_t    timeout,
+
+                                               void           (*cb)(const void*    context,
+                                                                    indy_error_t  err,
+                                                                    const char*   request_result_json)
+                                               );
+
     /// Signs request message.
     ///
     /// Adds submitter information to passed request json, signs it with submitter
@@ -135,6 +499,57 @@ extern "C" {
                                                               const char*   signed_request_json)
                                          );
 
+    /// Variant of `indy_sign_request` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_sign_request_ctx(const void*    context,
+                                             indy_handle_t  wallet_handle,
+                                             const char *   submitter_did,
+                                             const char *   request_json,
+
+                                             void           (*cb)(const void*    context,
+                                                                  indy_error_t  err,
+                                                                  const char*   signed_request_json)
+                                             );
+
+    /// Signs request message as "indy_sign_request" does but even if submitter DID is deactivated
+    /// (see "indy_deactivate_did").
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// submitter_did: Id of Identity stored in secured Wallet.
+    /// request_json: Request data json.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Signed request json.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Ledger*
+    /// Crypto*
+
+    extern indy_error_t indy_force_sign_request(indy_handle_t command_handle,
+                                               indy_handle_t  wallet_handle,
+                                               const char *   submitter_did,
+                                               const char *   request_json,
+
+                                               void           (*cb)(indy_handle_t command_handle_,
+                                                                    indy_error_t  err,
+                                                                    const char*   signed_request_json)
+                                               );
+
+    /// Variant of `indy_force_sign_request` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_force_sign_request_ctx(const void*    context,
+                                                   indy_handle_t  wallet_handle,
+                                                   const char *   submitter_did,
+                                                   const char *   request_json,
+
+                                                   void           (*cb)(const void*    context,
+                                                                        indy_error_t  err,
+                                                                        const char*   signed_request_json)
+                                                   );
+
 
     /// Multi signs request message.
     ///
@@ -167,6 +582,106 @@ extern "C" {
                                                                      const char*   signed_request_json)
                                                 );
 
+    /// Variant of `indy_multi_sign_request` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_multi_sign_request_ctx(const void*    context,
+                                                    indy_handle_t  wallet_handle,
+                                                    const char *   submitter_did,
+                                                    const char *   request_json,
+
+                                                    void           (*cb)(const void*    context,
+                                                                         indy_error_t  err,
+                                                                         const char*   signed_request_json)
+                                                    );
+
+    /// Merges several multi signed copies of the same request into one request.
+    ///
+    /// Every copy can be signed by different signers (author, endorser, trustees) with multi_sign_request.
+    /// Single signature (see sign_request) is converted to multi signature of request author.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// requests_json: json array of signed copies of the request.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request json containing signatures of all signers.
+    ///
+    /// #Errors
+    /// Common*
+
+    extern indy_error_t indy_merge_multi_signed_requests(indy_handle_t command_handle,
+                                                         const char *  requests_json,
+
+                                                         void           (*cb)(indy_handle_t command_handle_,
+                                                                              indy_error_t  err,
+                                                                              const char*   request_json)
+                                                        );
+
+    /// Variant of `indy_merge_multi_signed_requests` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_merge_multi_signed_requests_ctx(const void*    context,
+                                                             const char *  requests_json,
+
+                                                             void           (*cb)(const void*    context,
+                                                                                  indy_error_t  err,
+                                                                                  const char*   request_json)
+                                                            );
+
+    /// Checks whether signatures of the request satisfy the ledger auth rules.
+    ///
+    /// Fetches auth rules and NYMs of all signers from the ledger, verifies signatures
+    /// and reports which signatures are still missing before the request can be submitted.
+    /// ADD action auth rule of the request transaction type is used and request author is considered as the owner.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// request_json: signed request json.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Report json:
+    /// {
+    ///     "txnType": <string> - transaction type of the request,
+    ///     "authRule": <object> - auth rule used for the check (see parse_get_auth_rule_response),
+    ///     "signers": [{
+    ///         "did": <string> - signer DID,
+    ///         "role": <string> - (Optional) signer role on the ledger,
+    ///         "onLedger": <bool> - whether signer DID is written on the ledger,
+    ///         "verified": <bool> - whether signature is verified against signer verkey from the ledger
+    ///     }],
+    ///     "missingSigners": [<string>] - DIDs of author and endorser which didn't sign the request,
+    ///     "missingSignatures": [{
+    ///         "role": <string> - (Optional) role of required signers ("*" means any role),
+    ///         "count": <int> - number of required signatures,
+    ///         "needToBeOwner": <bool> - (Optional) whether signer must be the owner of the transaction
+    ///     }],
+    ///     "forbidden": <bool> - whether action is forbidden by the auth rule,
+    ///     "complete": <bool> - whether request is ready to be submitted
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+
+    extern indy_error_t indy_check_request_signatures(indy_handle_t command_handle,
+                                                      indy_handle_t pool_handle,
+                                                      const char *  request_json,
+
+                                                      void           (*cb)(indy_handle_t command_handle_,
+                                                                           indy_error_t  err,
+                                                                           const char*   report_json)
+                                                     );
+
+    /// Variant of `indy_check_request_signatures` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_check_request_signatures_ctx(const void*    context,
+                                                          indy_handle_t pool_handle,
+                                                          const char *  request_json,
+
+                                                          void           (*cb)(const void*    context,
+                                                                               indy_error_t  err,
+                                                                               const char*   report_json)
+                                                         );
+
     /// Builds a request to get a DDO.
     ///
     /// #Params
@@ -189,6 +704,16 @@ extern "C" {
                                                                         indy_error_t  err,
                                                                         const char*   request_result_json)
                                                   );
+
+    /// Variant of `indy_build_get_ddo_request` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_get_ddo_request_ctx(const void*    context,
+                                                       const char *  submitter_did,
+                                                       const char *  target_did,
+
+                                                       void           (*cb)(const void*    context,
+                                                                            indy_error_t  err,
+                                                                            const char*   request_result_json)
+                                                      );
     
     /// Builds a NYM request. Request to create a new NYM record for a specific user.
     ///
@@ -226,13 +751,79 @@ extern "C" {
                                                                     const char*   request_json)
                                               );
 
-    /// Builds an ATTRIB request. Request to add attribute to a NYM record.
-    ///
-    /// #Params
-    /// command_handle: command handle to map callback to caller context.
-    /// submitter_did: Identifier (DID) of the transaction author as base58-encoded string.
-    ///                Actual request sender may differ if Endorser is used (look at `indy_append_request_endorser`)
-    /// target_did: Target DID as base58-encoded string for 16 or 32 bit DID value.
+    /// Variant of `indy_build_nym_request` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_nym_request_ctx(const void*    context,
+                                                   const char *  submitter_did,
+                                                   const char *  target_did,
+                                                   const char *  verkey,
+                                                   const char *  alias,
+                                                   const char *  role,
+
+                                                   void           (*cb)(const void*    context,
+                                                                        indy_error_t  err,
+                                                                        const char*   request_json)
+                                                  );
+
+    /// Builds a NYM request with the fields introduced by did:indy method.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// submitter_did: Identifier (DID) of the transaction author as base58-encoded string.
+    ///                Actual request sender may differ if Endorser is used (look at `indy_append_request_endorser`)
+    /// target_did: Target DID as base58-encoded string for 16 or 32 bit DID value.
+    /// nym_data_json: NYM data
+    ///     {
+    ///         "verkey": <string> (optional) - target identity verification key as base58-encoded string.
+    ///         "alias": <string> (optional) - NYM's alias.
+    ///         "role": <string> (optional) - role of a user NYM record:
+    ///                             TRUSTEE
+    ///                             STEWARD
+    ///                             ENDORSER (TRUST_ANCHOR is accepted as well)
+    ///                             NETWORK_MONITOR
+    ///                             RESET - to reset role to common USER
+    ///         "diddocContent": <object> (optional) - DID Document content to be merged into the DID Document
+    ///                           generated by the ledger. Must not contain `id` and must not exceed 10 KiB.
+    ///         "version": <int> (optional) - DID self-certification check done by the ledger:
+    ///                             0 - no check
+    ///                             1 - did:sov (DID is first 16 bytes of verkey)
+    ///                             2 - did:indy (DID is first 16 bytes of SHA256 of verkey)
+    ///     }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+
+    extern indy_error_t indy_build_nym_request_with_data(indy_handle_t command_handle,
+                                                         const char *  submitter_did,
+                                                         const char *  target_did,
+                                                         const char *  nym_data_json,
+
+                                                         void           (*cb)(indy_handle_t command_handle_,
+                                                                              indy_error_t  err,
+                                                                              const char*   request_json)
+                                                        );
+
+    /// Variant of `indy_build_nym_request_with_data` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_nym_request_with_data_ctx(const void*    context,
+                                                             const char *  submitter_did,
+                                                             const char *  target_did,
+                                                             const char *  nym_data_json,
+
+                                                             void           (*cb)(const void*    context,
+                                                                                  indy_error_t  err,
+                                                                                  const char*   request_json)
+                                                            );
+
+    /// Builds an ATTRIB request. Request to add attribute to a NYM record.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// submitter_did: Identifier (DID) of the transaction author as base58-encoded string.
+    ///                Actual request sender may differ if Endorser is used (look at `indy_append_request_endorser`)
+    /// target_did: Target DID as base58-encoded string for 16 or 32 bit DID value.
     /// hash: (Optional) Hash of attribute data.
     /// raw: (Optional) Json, where key is attribute name and value is attribute value.
     /// enc: (Optional) Encrypted value attribute data.
@@ -256,6 +847,19 @@ extern "C" {
                                                                        const char*   request_json)
                                                   );
 
+    /// Variant of `indy_build_attrib_request` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_attrib_request_ctx(const void*    context,
+                                                      const char *  submitter_did,
+                                                      const char *  target_did,
+                                                      const char *  hash,
+                                                      const char *  raw,
+                                                      const char *  enc,
+
+                                                      void           (*cb)(const void*    context,
+                                                                           indy_error_t  err,
+                                                                           const char*   request_json)
+                                                      );
+
     /// Builds a GET_ATTRIB request. Request to get information about an Attribute for the specified DID.
     ///
     /// #Params
@@ -285,57 +889,611 @@ extern "C" {
                                                                            const char*   request_json)
                                                      );
 
-    /// Builds a GET_NYM request. Request to get information about a DID (NYM).
+    /// Variant of `indy_build_get_attrib_request` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_get_attrib_request_ctx(const void*    context,
+                                                          const char *  submitter_did,
+                                                          const char *  target_did,
+                                                          const char *  hash,
+                                                          const char *  raw,
+                                                          const char *  enc,
+
+                                                          void           (*cb)(const void*    context,
+                                                                               indy_error_t  err,
+                                                                               const char*   request_json)
+                                                         );
+
+    /// Builds a GET_ATTRIB request to get information about an Attribute for the specified DID
+    /// as it was at some point in the past.
+    ///
+    /// Note: one of the fields `hash`, `raw`, `enc` must be specified.
+    /// Note: only one of `seq_no` and `timestamp` can be specified.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// submitter_did: (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+    /// target_did: Target DID as base58-encoded string for 16 or 32 bit DID value.
+    /// raw: (Optional) Requested attribute name.
+    /// hash: (Optional) Requested attribute hash.
+    /// enc: (Optional) Requested attribute encrypted value.
+    /// seq_no: (Optional) return attribute state as of the transaction with this sequence number (-1 to omit).
+    /// timestamp: (Optional) return attribute state as of this time (-1 to omit).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+
+    extern indy_error_t indy_build_get_historical_attrib_request(indy_handle_t command_handle,
+                                                                 const char *  submitter_did,
+                                                                 const char *  target_did,
+                                                                 const char *  raw,
+                                                                 const char *  hash,
+                                                                 const char *  enc,
+                                                                 indy_i64_t    seq_no,
+                                                                 indy_i64_t    timestamp,
+
+                                                                 void           (*cb)(indy_handle_t command_handle_,
+                                                                                      indy_error_t  err,
+                                                                                      const char*   request_json)
+                                                                );
+
+    /// Variant of `indy_build_get_historical_attrib_request` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_get_historical_attrib_request_ctx(const void*    context,
+                                                                     const char *  submitter_did,
+                                                                     const char *  target_did,
+                                                                     const char *  raw,
+                                                                     const char *  hash,
+                                                                     const char *  enc,
+                                                                     indy_i64_t    seq_no,
+                                                                     indy_i64_t    timestamp,
+
+                                                                     void           (*cb)(const void*    context,
+                                                                                          indy_error_t  err,
+                                                                                          const char*   request_json)
+                                                                    );
+
+    /// Builds an ATTRIB request publishing endpoint of the DID.
+    ///
+    /// Endpoint is always published as `raw` attrib `{"endpoint": {"ha": <address>, "verkey": <transport_key>}}`
+    /// as it has to be resolvable by other parties (look at `indy_get_endpoint_for_did`).
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// submitter_did: Identifier (DID) of the transaction author as base58-encoded string.
+    ///                Actual request sender may differ if Endorser is used (look at `indy_append_request_endorser`)
+    /// target_did: Target DID as base58-encoded string for 16 or 32 bit DID value.
+    /// address: Endpoint address in ip-address:port format (restricted by the ledger).
+    /// transport_key: (Optional) Endpoint transport verkey as base58-encoded string.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+
+    extern indy_error_t indy_build_set_endpoint_request(indy_handle_t command_handle,
+                                                        const char *  submitter_did,
+                                                        const char *  target_did,
+                                                        const char *  address,
+                                                        const char *  transport_key,
+
+                                                        void           (*cb)(indy_handle_t command_handle_,
+                                                                             indy_error_t  err,
+                                                                             const char*   request_json)
+                                                       );
+
+    /// Variant of `indy_build_set_endpoint_request` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_set_endpoint_request_ctx(const void*    context,
+                                                            const char *  submitter_did,
+                                                            const char *  target_did,
+                                                            const char *  address,
+                                                            const char *  transport_key,
+
+                                                            void           (*cb)(const void*    context,
+                                                                                 indy_error_t  err,
+                                                                                 const char*   request_json)
+                                                           );
+
+    /// Builds a GET_ATTRIB request to get endpoint of the DID published by `indy_build_set_endpoint_request`.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// submitter_did: (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+    /// target_did: Target DID as base58-encoded string for 16 or 32 bit DID value.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+
+    extern indy_error_t indy_build_get_endpoint_request(indy_handle_t command_handle,
+                                                        const char *  submitter_did,
+                                                        const char *  target_did,
+
+                                                        void           (*cb)(indy_handle_t command_handle_,
+                                                                             indy_error_t  err,
+                                                                             const char*   request_json)
+                                                       );
+
+    /// Variant of `indy_build_get_endpoint_request` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_get_endpoint_request_ctx(const void*    context,
+                                                            const char *  submitter_did,
+                                                            const char *  target_did,
+
+                                                            void           (*cb)(const void*    context,
+                                                                                 indy_error_t  err,
+                                                                                 const char*   request_json)
+                                                           );
+
+    /// Builds a GET_NYM request. Request to get information about a DID (NYM).
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// submitter_did:(Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+    /// target_did: Target DID as base58-encoded string for 16 or 32 bit DID value.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+
+    extern indy_error_t indy_build_get_nym_request(indy_handle_t command_handle,
+                                                   const char *  submitter_did,
+                                                   const char *  target_did,
+
+                                                   void           (*cb)(indy_handle_t command_handle_,
+                                                                        indy_error_t  err,
+                                                                        const char*   request_json)
+                                                  );
+
+    /// Variant of `indy_build_get_nym_request` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_get_nym_request_ctx(const void*    context,
+                                                       const char *  submitter_did,
+                                                       const char *  target_did,
+
+                                                       void           (*cb)(const void*    context,
+                                                                            indy_error_t  err,
+                                                                            const char*   request_json)
+                                                      );
+
+    /// Builds a GET_NYM request to get information about a DID (NYM) as it was at some point in the past.
+    ///
+    /// Note: only one of `seq_no` and `timestamp` can be specified.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// submitter_did: (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+    /// target_did: Target DID as base58-encoded string for 16 or 32 bit DID value.
+    /// seq_no: (Optional) return NYM state as of the transaction with this sequence number (-1 to omit).
+    /// timestamp: (Optional) return NYM state as of this time (-1 to omit).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+
+    extern indy_error_t indy_build_get_historical_nym_request(indy_handle_t command_handle,
+                                                              const char *  submitter_did,
+                                                              const char *  target_did,
+                                                              indy_i64_t    seq_no,
+                                                              indy_i64_t    timestamp,
+
+                                                              void           (*cb)(indy_handle_t command_handle_,
+                                                                                   indy_error_t  err,
+                                                                                   const char*   request_json)
+                                                             );
+
+    /// Variant of `indy_build_get_historical_nym_request` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_get_historical_nym_request_ctx(const void*    context,
+                                                                  const char *  submitter_did,
+                                                                  const char *  target_did,
+                                                                  indy_i64_t    seq_no,
+                                                                  indy_i64_t    timestamp,
+
+                                                                  void           (*cb)(const void*    context,
+                                                                                       indy_error_t  err,
+                                                                                       const char*   request_json)
+                                                                 );
+
+    /// Parse a GET_NYM response to get NYM data.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// get_nym_response: response on GET_NYM request.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// NYM data
+    /// {
+    ///     did: DID as base58-encoded string for 16 or 32 bit DID value.
+    ///     verkey: verification key as base58-encoded string.
+    ///     role: Role associated number
+    ///                             null (common USER)
+    ///                             0 - TRUSTEE
+    ///                             2 - STEWARD
+    ///                             101 - TRUST_ANCHOR
+    ///                             101 - ENDORSER - equal to TRUST_ANCHOR that will be removed soon
+    ///                             201 - NETWORK_MONITOR
+    ///     seqNo: (optional) sequence number of the NYM transaction,
+    ///     txnTime: (optional) time of the NYM transaction
+    /// }
+
+    extern indy_error_t indy_parse_get_nym_response(indy_handle_t command_handle,
+                                                    const char *  get_nym_response,
+
+                                                    void           (*cb)(indy_handle_t command_handle_,
+                                                                         indy_error_t  err,
+                                                                         const char*   nym_json)
+                                                   );
+
+    /// Variant of `indy_parse_get_nym_response` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_parse_get_nym_response_ctx(const void*    context,
+                                                        const char *  get_nym_response,
+
+                                                        void           (*cb)(const void*    context,
+                                                                             indy_error_t  err,
+                                                                             const char*   nym_json)
+                                                       );
+
+    /// Parse a GET_ATTRIB response to get attribute data.
+    /// Returns LedgerItemNotFound error if the requested data is absent on the ledger.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// get_attrib_response: response on GET_ATTRIB request.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Attribute data:
+    /// {
+    ///     data: {
+    ///         did: target DID as base58-encoded string for 16 or 32 bit DID value,
+    ///         raw: (optional) json, where key is attribute name and value is attribute value,
+    ///         hash: (optional) hash of attribute data,
+    ///         enc: (optional) encrypted attribute data
+    ///     },
+    ///     seqNo: (optional) sequence number of the attribute transaction,
+    ///     txnTime: (optional) time of the attribute transaction
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+
+    extern indy_error_t indy_parse_get_attrib_response(indy_handle_t command_handle,
+                                                       const char *  get_attrib_response,
+
+                                                       void           (*cb)(indy_handle_t command_handle_,
+                                                                            indy_error_t  err,
+                                                                            const char*   attrib_json)
+                                                      );
+
+    /// Variant of `indy_parse_get_attrib_response` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_parse_get_attrib_response_ctx(const void*    context,
+                                                           const char *  get_attrib_response,
+
+                                                           void           (*cb)(const void*    context,
+                                                                                indy_error_t  err,
+                                                                                const char*   attrib_json)
+                                                          );
+
+    /// Parse a GET_TXN response to get transaction data.
+    /// Returns LedgerItemNotFound error if the requested data is absent on the ledger.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// get_txn_response: response on GET_TXN request.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Transaction data:
+    /// {
+    ///     data: {
+    ///         type: transaction type,
+    ///         data: transaction specific data json,
+    ///         metadata: transaction metadata json (author, request id, etc.)
+    ///     },
+    ///     seqNo: sequence number of the transaction,
+    ///     txnTime: (optional) time of the transaction
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+
+    extern indy_error_t indy_parse_get_txn_response(indy_handle_t command_handle,
+                                                    const char *  get_txn_response,
+
+                                                    void           (*cb)(indy_handle_t command_handle_,
+                                                                         indy_error_t  err,
+                                                                         const char*   txn_json)
+                                                   );
+
+    /// Variant of `indy_parse_get_txn_response` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_parse_get_txn_response_ctx(const void*    context,
+                                                        const char *  get_txn_response,
+
+                                                        void           (*cb)(const void*    context,
+                                                                             indy_error_t  err,
+                                                                             const char*   txn_json)
+                                                       );
+
+    /// Parse a GET_AUTH_RULE response to get list of auth rules.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// get_auth_rule_response: response on GET_AUTH_RULE request.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Auth rules:
+    /// {
+    ///     data: [
+    ///         {
+    ///             auth_type: ledger transaction alias or associated value,
+    ///             auth_action: type of an action (ADD or EDIT),
+    ///             field: transaction field,
+    ///             old_value: (optional) old value of a field,
+    ///             new_value: (optional) new value of a field,
+    ///             constraint: set of constraints (look at indy_build_auth_rule_request)
+    ///         }
+    ///     ]
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+
+    extern indy_error_t indy_parse_get_auth_rule_response(indy_handle_t command_handle,
+                                                          const char *  get_auth_rule_response,
+
+                                                          void           (*cb)(indy_handle_t command_handle_,
+                                                                               indy_error_t  err,
+                                                                               const char*   auth_rules_json)
+                                                         );
+
+    /// Variant of `indy_parse_get_auth_rule_response` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_parse_get_auth_rule_response_ctx(const void*    context,
+                                                              const char *  get_auth_rule_response,
+
+                                                              void           (*cb)(const void*    context,
+                                                                                   indy_error_t  err,
+                                                                                   const char*   auth_rules_json)
+                                                             );
+
+    /// Parse a response on GET_VALIDATOR_INFO action (see indy_submit_action) to get validator info of each node.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// get_validator_info_response: response of indy_submit_action on GET_VALIDATOR_INFO request.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Validator info:
+    /// {
+    ///     data: {
+    ///         <node alias>: {
+    ///             data: (optional) validator info json of the node,
+    ///             error: (optional) error description if the node rejected the request or didn't reply
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+
+    extern indy_error_t indy_parse_get_validator_info_response(indy_handle_t command_handle,
+                                                               const char *  get_validator_info_response,
+
+                                                               void           (*cb)(indy_handle_t command_handle_,
+                                                                                    indy_error_t  err,
+                                                                                    const char*   validator_info_json)
+                                                              );
+
+    /// Variant of `indy_parse_get_validator_info_response` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_parse_get_validator_info_response_ctx(const void*    context,
+                                                                   const char *  get_validator_info_response,
+
+                                                                   void           (*cb)(const void*    context,
+                                                                                        indy_error_t  err,
+                                                                                        const char*   validator_info_json)
+                                                                  );
+
+    /// Parse a response on GET_VALIDATOR_INFO action (see indy_submit_action) to get validator info of each node
+    /// normalized into a common structure regardless of the node version.
+    /// Fields that aren't reported by the node are omitted.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// get_validator_info_response: response of indy_submit_action on GET_VALIDATOR_INFO request.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Validator info summary:
+    /// {
+    ///     data: {
+    ///         <node alias>: {
+    ///             alias: node alias,
+    ///             nodeVersion: (optional) version of indy-node package,
+    ///             mode: (optional) node mode (f.e. "participating"),
+    ///             uptime: (optional) node uptime in seconds,
+    ///             ledgerSizes: (optional) {<ledger name>: <transactions count>, ...},
+    ///             pool: (optional) {
+    ///                 totalNodes: (optional) count of nodes in the pool,
+    ///                 f: (optional) count of faulty nodes the pool tolerates,
+    ///                 readOnly: (optional) whether the pool is in read only mode,
+    ///                 reachableNodes: [<node alias>, ...],
+    ///                 unreachableNodes: [<node alias>, ...],
+    ///                 blacklistedNodes: [<node alias>, ...]
+    ///             },
+    ///             timestamp: (optional) time of the validator info collection,
+    ///             error: (optional) error description if the node rejected the request or didn't reply
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+
+    extern indy_error_t indy_parse_get_validator_info_summary_response(indy_handle_t command_handle,
+                                                                       const char *  get_validator_info_response,
+
+                                                                       void           (*cb)(indy_handle_t command_handle_,
+                                                                                            indy_error_t  err,
+                                                                                            const char*   validator_info_json)
+                                                                      );
+
+    /// Variant of `indy_parse_get_validator_info_summary_response` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_parse_get_validator_info_summary_response_ctx(const void*    context,
+                                                                           const char *  get_validator_info_response,
+
+                                                                           void           (*cb)(const void*    context,
+                                                                                                indy_error_t  err,
+                                                                                                const char*   validator_info_json)
+                                                                          );
+
+    /// Parse a response on GET_VALIDATOR_INFO action (see indy_submit_action) to get upgrade status of each node
+    /// taken from the last record of the node upgrade log.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// get_validator_info_response: response of indy_submit_action on GET_VALIDATOR_INFO request.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Upgrade status:
+    /// {
+    ///     data: {
+    ///         <node alias>: {
+    ///             alias: node alias,
+    ///             nodeVersion: (optional) current version of indy-node package,
+    ///             lastEvent: (optional) {
+    ///                 loggedAt: event logging time,
+    ///                 event: event name (f.e. "scheduled", "started", "succeeded", "failed", "cancelled"),
+    ///                 scheduledAt: scheduled upgrade time,
+    ///                 version: target version,
+    ///                 upgradeId: (optional) upgrade id,
+    ///                 package: (optional) upgraded package
+    ///             },
+    ///             error: (optional) error description if the node rejected the request or didn't reply
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+    extern indy_error_t indy_parse_pool_upgrade_status_response(indy_handle_t command_handle,
+                                                                const char *  get_validator_info_response,
+
+                                                                void           (*cb)(indy_handle_t command_handle_,
+                                                                                     indy_error_t  err,
+                                                                                     const char*   upgrade_status_json)
+                                                                );
+
+    /// Variant of `indy_parse_pool_upgrade_status_response` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_parse_pool_upgrade_status_response_ctx(const void*    context,
+                                                                    const char *  get_validator_info_response,
+
+                                                                    void           (*cb)(const void*    context,
+                                                                                         indy_error_t  err,
+                                                                                         const char*   upgrade_status_json)
+                                                                    );
+
+    /// Parse a GET_TXN_AUTHR_AGRMT response to get Transaction Author Agreement data.
+    /// Returns LedgerItemNotFound error if the requested data is absent on the ledger.
     ///
     /// #Params
     /// command_handle: command handle to map callback to caller context.
-    /// submitter_did:(Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
-    /// target_did: Target DID as base58-encoded string for 16 or 32 bit DID value.
+    /// get_txn_author_agreement_response: response on GET_TXN_AUTHR_AGRMT request.
     /// cb: Callback that takes command result as parameter.
     ///
     /// #Returns
-    /// Request result as json.
+    /// Transaction Author Agreement data:
+    /// {
+    ///     data: {
+    ///         text: (optional) text of TAA,
+    ///         version: version of TAA,
+    ///         digest: (optional) hash of TAA text and version,
+    ///         ratification_ts: (optional) TAA ratification timestamp,
+    ///         retirement_ts: (optional) TAA retirement timestamp
+    ///     },
+    ///     seqNo: (optional) sequence number of the TAA transaction,
+    ///     txnTime: (optional) time of the TAA transaction
+    /// }
     ///
     /// #Errors
     /// Common*
+    /// Ledger*
 
-    extern indy_error_t indy_build_get_nym_request(indy_handle_t command_handle,
-                                                   const char *  submitter_did,
-                                                   const char *  target_did,
+    extern indy_error_t indy_parse_get_txn_author_agreement_response(indy_handle_t command_handle,
+                                                                     const char *  get_txn_author_agreement_response,
 
-                                                   void           (*cb)(indy_handle_t command_handle_,
-                                                                        indy_error_t  err,
-                                                                        const char*   request_json)
-                                                  );
+                                                                     void           (*cb)(indy_handle_t command_handle_,
+                                                                                          indy_error_t  err,
+                                                                                          const char*   txn_author_agreement_json)
+                                                                    );
 
-    /// Parse a GET_NYM response to get NYM data.
+    /// Variant of `indy_parse_get_txn_author_agreement_response` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_parse_get_txn_author_agreement_response_ctx(const void*    context,
+                                                                         const char *  get_txn_author_agreement_response,
+
+                                                                         void           (*cb)(const void*    context,
+                                                                                              indy_error_t  err,
+                                                                                              const char*   txn_author_agreement_json)
+                                                                        );
+
+    /// Parse a GET_TXN_AUTHR_AGRMT_AML response to get acceptance mechanisms data.
+    /// Returns LedgerItemNotFound error if the requested data is absent on the ledger.
     ///
     /// #Params
     /// command_handle: command handle to map callback to caller context.
-    /// get_nym_response: response on GET_NYM request.
+    /// get_acceptance_mechanisms_response: response on GET_TXN_AUTHR_AGRMT_AML request.
     /// cb: Callback that takes command result as parameter.
     ///
     /// #Returns
-    /// NYM data
+    /// Acceptance mechanisms data:
     /// {
-    ///     did: DID as base58-encoded string for 16 or 32 bit DID value.
-    ///     verkey: verification key as base58-encoded string.
-    ///     role: Role associated number
-    ///                             null (common USER)
-    ///                             0 - TRUSTEE
-    ///                             2 - STEWARD
-    ///                             101 - TRUST_ANCHOR
-    ///                             101 - ENDORSER - equal to TRUST_ANCHOR that will be removed soon
-    ///                             201 - NETWORK_MONITOR
+    ///     data: {
+    ///         aml: a set of new acceptance mechanisms: {"<acceptance mechanism label>": <acceptance mechanism description>},
+    ///         version: version of acceptance mechanisms,
+    ///         amlContext: (optional) common context information about acceptance mechanisms
+    ///     },
+    ///     seqNo: (optional) sequence number of the AML transaction,
+    ///     txnTime: (optional) time of the AML transaction
     /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
 
-    extern indy_error_t indy_parse_get_nym_response(indy_handle_t command_handle,
-                                                    const char *  get_nym_response,
+    extern indy_error_t indy_parse_get_acceptance_mechanisms_response(indy_handle_t command_handle,
+                                                                      const char *  get_acceptance_mechanisms_response,
 
-                                                    void           (*cb)(indy_handle_t command_handle_,
-                                                                         indy_error_t  err,
-                                                                         const char*   nym_json)
-                                                   );
+                                                                      void           (*cb)(indy_handle_t command_handle_,
+                                                                                           indy_error_t  err,
+                                                                                           const char*   acceptance_mechanisms_json)
+                                                                     );
+
+    /// Variant of `indy_parse_get_acceptance_mechanisms_response` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_parse_get_acceptance_mechanisms_response_ctx(const void*    context,
+                                                                          const char *  get_acceptance_mechanisms_response,
+
+                                                                          void           (*cb)(const void*    context,
+                                                                                               indy_error_t  err,
+                                                                                               const char*   acceptance_mechanisms_json)
+                                                                         );
 
     /// Builds a SCHEMA request. Request to add Credential's schema.
     ///
@@ -368,6 +1526,16 @@ extern "C" {
                                                                        const char*   request_json)
                                                  );
 
+    /// Variant of `indy_build_schema_request` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_schema_request_ctx(const void*    context,
+                                                      const char *  submitter_did,
+                                                      const char *  data,
+
+                                                      void           (*cb)(const void*    context,
+                                                                           indy_error_t  err,
+                                                                           const char*   request_json)
+                                                     );
+
     /// Builds a GET_SCHEMA request. Request to get Credential's Schema.
     ///
     /// #Params
@@ -391,6 +1559,16 @@ extern "C" {
                                                                            const char*   request_json)
                                                      );
 
+    /// Variant of `indy_build_get_schema_request` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_get_schema_request_ctx(const void*    context,
+                                                          const char *  submitter_did,
+                                                          const char *  id,
+
+                                                          void           (*cb)(const void*    context,
+                                                                               indy_error_t  err,
+                                                                               const char*   request_json)
+                                                         );
+
     /// Parse a GET_SCHEMA response to get Schema in the format compatible with Anoncreds API.
     ///
     /// #Params
@@ -419,6 +1597,16 @@ extern "C" {
                                                                             const char*   schema_id,
                                                                             const char*   schema_json)
                                                        );
+
+    /// Variant of `indy_parse_get_schema_response` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_parse_get_schema_response_ctx(const void*    context,
+                                                           const char *  get_schema_response,
+
+                                                           void           (*cb)(const void*    context,
+                                                                                indy_error_t  err,
+                                                                                const char*   schema_id,
+                                                                                const char*   schema_json)
+                                                           );
     
     /// Builds an CRED_DEF request. Request to add a Credential Definition (in particular, public key),
     /// that Issuer creates for a particular Credential Schema.
@@ -455,6 +1643,16 @@ extern "C" {
                                                                          indy_error_t  err,
                                                                          const char*   request_json)
                                                     );
+
+    /// Variant of `indy_build_cred_def_request` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_cred_def_request_ctx(const void*    context,
+                                                        const char *  submitter_did,
+                                                        const char *  data,
+
+                                                        void           (*cb)(const void*    context,
+                                                                             indy_error_t  err,
+                                                                             const char*   request_json)
+                                                        );
     
     /// Builds a GET_CRED_DEF request. Request to get a Credential Definition (in particular, public key),
     /// that Issuer creates for a particular Credential Schema.
@@ -480,6 +1678,16 @@ extern "C" {
                                                                               const char*   request_json)
                                                          );
 
+     /// Variant of `indy_build_get_cred_def_request` that passes application `context` to the callback instead of command handle.
+     extern indy_error_t indy_build_get_cred_def_request_ctx(const void*    context,
+                                                             const char *  submitter_did,
+                                                             const char *  id,
+
+                                                             void           (*cb)(const void*    context,
+                                                                                  indy_error_t  err,
+                                                                                  const char*   request_json)
+                                                             );
+
     /// Parse a GET_CRED_DEF response to get Credential Definition in the format compatible with Anoncreds API.
     ///
     /// #Params
@@ -512,6 +1720,15 @@ extern "C" {
                                                                                const char*   cred_def_json)
                                                           );
 
+     /// Variant of `indy_parse_get_cred_def_response` that passes application `context` to the callback instead of command handle.
+     extern indy_error_t indy_parse_get_cred_def_response_ctx(const void*    context,
+                                                              const char *  get_cred_def_response,
+                                                              void           (*cb)(const void*    context,
+                                                                                   indy_error_t  err,
+                                                                                   const char*   cred_def_id,
+                                                                                   const char*   cred_def_json)
+                                                              );
+
     /// Builds a NODE request. Request to add a new node to the pool, or updates existing in the pool.
     ///
     /// #Params
@@ -547,6 +1764,17 @@ extern "C" {
                                                                      const char*   request_json)
                                                );
 
+    /// Variant of `indy_build_node_request` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_node_request_ctx(const void*    context,
+                                                    const char *  submitter_did,
+                                                    const char *  target_did,
+                                                    const char *  data,
+
+                                                    void           (*cb)(const void*    context,
+                                                                         indy_error_t  err,
+                                                                         const char*   request_json)
+                                                   );
+
         /// Builds a GET_VALIDATOR_INFO request.
         ///
         /// #Params
@@ -567,6 +1795,14 @@ extern "C" {
                                                                             const char*   request_json)
                                                        );
 
+        /// Variant of `indy_build_get_validator_info_request` that passes application `context` to the callback instead of command handle.
+        extern indy_error_t indy_build_get_validator_info_request_ctx(const void*    context,
+                                                           const char *  submitter_did,
+                                                           void           (*cb)(const void*    context,
+                                                                                indy_error_t  err,
+                                                                                const char*   request_json)
+                                                           );
+
 
     /// Builds a GET_TXN request. Request to get any transaction by its seq_no.
     ///
@@ -597,6 +1833,17 @@ extern "C" {
                                                                         const char*   request_json)
                                                    );
 
+    /// Variant of `indy_build_get_txn_request` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_get_txn_request_ctx(const void*    context,
+                                                       const char *  submitter_did,
+                                                       const char *  ledger_type,
+                                                       indy_i32_t    seq_no,
+
+                                                       void           (*cb)(const void*    context,
+                                                                            indy_error_t  err,
+                                                                            const char*   request_json)
+                                                       );
+
     /// Builds a POOL_CONFIG request. Request to change Pool's configuration.
     ///
     /// #Params
@@ -625,6 +1872,17 @@ extern "C" {
                                                                             const char*   request_json)
                                                        );
 
+    /// Variant of `indy_build_pool_config_request` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_pool_config_request_ctx(const void*    context,
+                                                           const char *  submitter_did,
+                                                           indy_bool_t    writes,
+                                                           indy_bool_t    force,
+
+                                                           void           (*cb)(const void*    context,
+                                                                                indy_error_t  err,
+                                                                                const char*   request_json)
+                                                           );
+
     /// Builds a POOL_RESTART request.
     ///
     /// #Params
@@ -650,6 +1908,16 @@ extern "C" {
                                                                              const char*   request_json)
                                                         );
 
+    /// Variant of `indy_build_pool_restart_request` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_pool_restart_request_ctx(const void*    context,
+                                                            const char *  submitter_did,
+                                                            const char *  action,
+                                                            const char *  datetime,
+                                                            void           (*cb)(const void*    context,
+                                                                                 indy_error_t  err,
+                                                                                 const char*   request_json)
+                                                            );
+
     /// Builds a POOL_UPGRADE request. Request to upgrade the Pool (sent by Trustee).
     /// It upgrades the specified Nodes (either all nodes in the Pool, or some specific ones).
     ///
@@ -695,6 +1963,76 @@ extern "C" {
                                                                              const char*   request_json)
                                                         );
 
+    /// Variant of `indy_build_pool_upgrade_request` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_pool_upgrade_request_ctx(const void*    context,
+                                                            const char *  submitter_did,
+                                                            const char *  name,
+                                                            const char *  version,
+                                                            const char *  action,
+                                                            const char *  sha256,
+                                                            indy_i32_t    timeout,
+                                                            const char *  schedule,
+                                                            const char *  justification,
+                                                            indy_bool_t   reinstall,
+                                                            indy_bool_t   force,
+                                                            const char *  package_,
+
+                                                            void           (*cb)(const void*    context,
+                                                                                 indy_error_t  err,
+                                                                                 const char*   request_json)
+                                                            );
+
+    /// Builds a POOL_UPGRADE request starting the upgrade by the schedule defined relative to a start time.
+    /// Nodes are referenced by aliases which are resolved to Node DIDs using the ledger of the opened pool.
+    /// Only validator nodes of the pool can be scheduled.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// submitter_did: Identifier (DID) of the transaction author as base58-encoded string.
+    ///                Actual request sender may differ if Endorser is used (look at `indy_append_request_endorser`)
+    /// upgrade_json: upgrade description:
+    ///     {
+    ///         "name": string - human-readable name for the upgrade,
+    ///         "version": string - the version of indy-node package we perform upgrade to,
+    ///         "sha256": string - sha256 hash of the package (64 hex characters),
+    ///         "package": (optional) string - package to be upgraded,
+    ///         "startTime": int - upgrade start time as UNIX timestamp in seconds,
+    ///         "nodeOffsets": {<node alias>: <offset in seconds from start time>, ...},
+    ///         "timeout": (optional) int - limits upgrade time on each Node,
+    ///         "justification": (optional) string - justification for this particular Upgrade,
+    ///         "reinstall": (optional) bool - whether it's allowed to re-install the same version. False by default,
+    ///         "force": (optional) bool - whether we should apply transaction without waiting for consensus. False by default
+    ///     }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+    extern indy_error_t indy_build_scheduled_pool_upgrade_request(indy_handle_t command_handle,
+                                                                  indy_handle_t pool_handle,
+                                                                  const char *  submitter_did,
+                                                                  const char *  upgrade_json,
+
+                                                                  void           (*cb)(indy_handle_t command_handle_,
+                                                                                       indy_error_t  err,
+                                                                                       const char*   request_json)
+                                                                  );
+
+    /// Variant of `indy_build_scheduled_pool_upgrade_request` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_scheduled_pool_upgrade_request_ctx(const void*    context,
+                                                                      indy_handle_t pool_handle,
+                                                                      const char *  submitter_did,
+                                                                      const char *  upgrade_json,
+
+                                                                      void           (*cb)(const void*    context,
+                                                                                           indy_error_t  err,
+                                                                                           const char*   request_json)
+                                                                      );
+
     /// Builds a REVOC_REG_DEF request. Request to add the definition of revocation registry
     /// to an exists credential definition.
     ///
@@ -734,6 +2072,16 @@ extern "C" {
                                                                               const char*   request_json)
                                                          );
 
+    /// Variant of `indy_build_revoc_reg_def_request` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_revoc_reg_def_request_ctx(const void*    context,
+                                                             const char *  submitter_did,
+                                                             const char *  data,
+
+                                                             void           (*cb)(const void*    context,
+                                                                                  indy_error_t  err,
+                                                                                  const char*   request_json)
+                                                             );
+
     /// Builds a GET_REVOC_REG_DEF request. Request to get a revocation registry definition,
     /// that Issuer creates for a particular Credential Definition.
     ///
@@ -758,6 +2106,16 @@ extern "C" {
                                                                                   const char*   request_json)
                                                             );
 
+    /// Variant of `indy_build_get_revoc_reg_def_request` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_get_revoc_reg_def_request_ctx(const void*    context,
+                                                                 const char *  submitter_did,
+                                                                 const char *  id,
+
+                                                                 void           (*cb)(const void*    context,
+                                                                                      indy_error_t  err,
+                                                                                      const char*   request_json)
+                                                                );
+
     /// Parse a GET_REVOC_REG_DEF response to get Revocation Registry Definition in the format
     /// compatible with Anoncreds API.
     ///
@@ -795,6 +2153,16 @@ extern "C" {
                                                                                    const char*   revoc_reg_def_json)
                                                              );
 
+    /// Variant of `indy_parse_get_revoc_reg_def_response` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_parse_get_revoc_reg_def_response_ctx(const void*    context,
+                                                                  const char *  get_revoc_ref_def_response,
+
+                                                                  void           (*cb)(const void*    context,
+                                                                                       indy_error_t  err,
+                                                                                       const char*   revoc_reg_def_id,
+                                                                                       const char*   revoc_reg_def_json)
+                                                                 );
+
     /// Builds a REVOC_REG_ENTRY request.  Request to add the RevocReg entry containing
     /// the new accumulator value and issued/revoked indices.
     /// This is just a delta of indices, not the whole list.
@@ -835,6 +2203,18 @@ extern "C" {
                                                                                 const char*   request_json)
                                                           );
 
+    /// Variant of `indy_build_revoc_reg_entry_request` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_revoc_reg_entry_request_ctx(const void*    context,
+                                                               const char *  submitter_did,
+                                                               const char *  revoc_reg_def_id,
+                                                               const char *  rev_def_type,
+                                                               const char *  value,
+
+                                                               void           (*cb)(const void*    context,
+                                                                                    indy_error_t  err,
+                                                                                    const char*   request_json)
+                                                              );
+
     /// Builds a GET_REVOC_REG request. Request to get the accumulated state of the Revocation Registry
     /// by ID. The state is defined by the given timestamp.
     ///
@@ -861,6 +2241,17 @@ extern "C" {
                                                                               const char*   request_json)
                                                         );
 
+    /// Variant of `indy_build_get_revoc_reg_request` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_get_revoc_reg_request_ctx(const void*    context,
+                                                             const char *  submitter_did,
+                                                             const char *  revoc_reg_def_id,
+                                                             long long    timestamp,
+
+                                                             void           (*cb)(const void*    context,
+                                                                                  indy_error_t  err,
+                                                                                  const char*   request_json)
+                                                            );
+
     /// Parse a GET_REVOC_REG response to get Revocation Registry in the format compatible with Anoncreds API.
     ///
     /// #Params
@@ -890,6 +2281,17 @@ extern "C" {
                                                                                unsigned long long      timestamp)
                                                          );
 
+    /// Variant of `indy_parse_get_revoc_reg_response` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_parse_get_revoc_reg_response_ctx(const void*    context,
+                                                              const char *  get_revoc_reg_response,
+
+                                                              void           (*cb)(const void*    context,
+                                                                                   indy_error_t  err,
+                                                                                   const char*   revoc_reg_def_id,
+                                                                                   const char*   revoc_reg_json,
+                                                                                   unsigned long long      timestamp)
+                                                             );
+
     /// Builds a GET_REVOC_REG_DELTA request. Request to get the delta of the accumulated state of the Revocation Registry.
     /// The Delta is defined by from and to timestamp fields.
     /// If from is not specified, then the whole state till to will be returned.
@@ -919,6 +2321,18 @@ extern "C" {
                                                                                     const char*   request_json)
                                                               );
 
+    /// Variant of `indy_build_get_revoc_reg_delta_request` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_get_revoc_reg_delta_request_ctx(const void*    context,
+                                                                   const char *  submitter_did,
+                                                                   const char *  revoc_reg_def_id,
+                                                                   long long    from,
+                                                                   long long    to,
+
+                                                                   void           (*cb)(const void*    context,
+                                                                                        indy_error_t  err,
+                                                                                        const char*   request_json)
+                                                                  );
+
     /// Parse a GET_REVOC_REG_DELTA response to get Revocation Registry Delta in the format compatible with Anoncreds API.
     ///
     /// #Params
@@ -944,12 +2358,219 @@ extern "C" {
     extern indy_error_t indy_parse_get_revoc_reg_delta_response(indy_handle_t command_handle,
                                                                 const char *  get_revoc_reg_delta_response,
 
-                                                                void           (*cb)(indy_handle_t command_handle_,
-                                                                                     indy_error_t  err,
-                                                                                     const char*   revoc_reg_def_id,
-                                                                                     const char*   revoc_reg_delta_json,
-                                                                                     unsigned long long      timestamp)
-                                                               );
+                                                                void           (*cb)(indy_handle_t command_handle_,
+                                                                                     indy_error_t  err,
+                                                                                     const char*   revoc_reg_def_id,
+                                                                                     const char*   revoc_reg_delta_json,
+                                                                                     unsigned long long      timestamp)
+                                                               );
+
+    /// Variant of `indy_parse_get_revoc_reg_delta_response` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_parse_get_revoc_reg_delta_response_ctx(const void*    context,
+                                                                    const char *  get_revoc_reg_delta_response,
+
+                                                                    void           (*cb)(const void*    context,
+                                                                                         indy_error_t  err,
+                                                                                         const char*   revoc_reg_def_id,
+                                                                                         const char*   revoc_reg_delta_json,
+                                                                                         unsigned long long      timestamp)
+                                                                   );
+
+
+    /// Register callbacks (see type description for `CustomTransactionBuilder`, `CustomResponseParser` and `CustomFree`)
+    /// to build requests and parse responses of custom transaction type (f.e. network specific config transaction).
+    ///
+    /// Requests built by `indy_build_custom_request` can be signed, endorsed, extended with TAA acceptance
+    /// and submitted the same way as requests of default transaction types.
+    ///
+    /// # params
+    /// command_handle: command handle to map callback to caller context.
+    /// txn_type: type of transaction to apply callbacks. Default transaction types can't be overridden.
+    /// builder: required callback to build request operation.
+    /// parser: (Optional) callback to parse reply.
+    /// free: required callback to deallocate memory.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// # returns
+    /// Status of callbacks registration.
+    ///
+    /// # errors
+    /// Common*
+    extern indy_error_t indy_register_ledger_transaction_builder(indy_handle_t command_handle,
+                                                                 const char *  txn_type,
+
+                                                                 indy_error_t   (*builder)(const char*   data_json,
+                                                                                           const char**  operation_json),
+
+                                                                 indy_error_t   (*parser)(const char*   response_json,
+                                                                                          const char**  parsed_json),
+
+                                                                 indy_error_t   (*free)(const char*   data),
+
+                                                                 void           (*cb)(indy_handle_t command_handle_,
+                                                                                      indy_error_t  err)
+                                                                );
+
+    /// Variant of `indy_register_ledger_transaction_builder` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_register_ledger_transaction_builder_ctx(const void*    context,
+                                                                     const char *  txn_type,
+
+                                                                     indy_error_t   (*builder)(const char*   data_json,
+                                                                                               const char**  operation_json),
+
+                                                                     indy_error_t   (*parser)(const char*   response_json,
+                                                                                              const char**  parsed_json),
+
+                                                                     indy_error_t   (*free)(const char*   data),
+
+                                                                     void           (*cb)(const void*    context,
+                                                                                          indy_error_t  err)
+                                                                    );
+
+    /// Register ledger middleware callbacks (see type description for `CustomRequestMiddleware`, `CustomReplyMiddleware` and `CustomFree`)
+    /// to observe and change requests submitted to the pool and replies received from it (f.e. for audit capture or signing proxies).
+    ///
+    /// Middlewares are applied to all requests submitted by Libindy to any pool.
+    /// Request middlewares are applied in order of registration and reply middlewares in reverse order.
+    ///
+    /// # params
+    /// command_handle: command handle to map callback to caller context.
+    /// request_middleware: (Optional) callback to apply to outgoing requests.
+    /// reply_middleware: (Optional) callback to apply to incoming replies.
+    ///                   At least one of the callbacks must be set.
+    /// free: required callback to deallocate memory.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// # returns
+    /// Handle of the middleware.
+    ///
+    /// # errors
+    /// Common*
+    extern indy_error_t indy_register_ledger_middleware(indy_handle_t command_handle,
+
+                                                        indy_error_t   (*request_middleware)(const char*   request_json,
+                                                                                             const char**  out_request_json),
+
+                                                        indy_error_t   (*reply_middleware)(const char*   reply_json,
+                                                                                           const char**  out_reply_json),
+
+                                                        indy_error_t   (*free)(const char*   data),
+
+                                                        void           (*cb)(indy_handle_t command_handle_,
+                                                                             indy_error_t  err,
+                                                                             indy_handle_t middleware_handle)
+                                                       );
+
+    /// Variant of `indy_register_ledger_middleware` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_register_ledger_middleware_ctx(const void*    context,
+
+                                                            indy_error_t   (*request_middleware)(const char*   request_json,
+                                                                                                 const char**  out_request_json),
+
+                                                            indy_error_t   (*reply_middleware)(const char*   reply_json,
+                                                                                               const char**  out_reply_json),
+
+                                                            indy_error_t   (*free)(const char*   data),
+
+                                                            void           (*cb)(const void*    context,
+                                                                                 indy_error_t  err,
+                                                                                 indy_handle_t middleware_handle)
+                                                           );
+
+    /// Unregister ledger middleware registered by `indy_register_ledger_middleware`.
+    ///
+    /// # params
+    /// command_handle: command handle to map callback to caller context.
+    /// middleware_handle: handle of the middleware.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// # returns
+    /// Error code
+    ///
+    /// # errors
+    /// Common*
+    extern indy_error_t indy_unregister_ledger_middleware(indy_handle_t command_handle,
+                                                          indy_handle_t middleware_handle,
+
+                                                          void           (*cb)(indy_handle_t command_handle_,
+                                                                               indy_error_t  err)
+                                                         );
+
+    /// Variant of `indy_unregister_ledger_middleware` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_unregister_ledger_middleware_ctx(const void*    context,
+                                                              indy_handle_t middleware_handle,
+
+                                                              void           (*cb)(const void*    context,
+                                                                                   indy_error_t  err)
+                                                             );
+
+    /// Builds a request of custom transaction type by the builder registered with `indy_register_ledger_transaction_builder`.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// submitter_did: (Optional) DID of the request sender (if not provided then default Libindy DID will be used).
+    /// txn_type: type of custom transaction.
+    /// data_json: transaction specific data passed to the registered builder.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_build_custom_request(indy_handle_t command_handle,
+                                                  const char *  submitter_did,
+                                                  const char *  txn_type,
+                                                  const char *  data_json,
+
+                                                  void           (*cb)(indy_handle_t command_handle_,
+                                                                       indy_error_t  err,
+                                                                       const char*   request_json)
+                                                 );
+
+    /// Variant of `indy_build_custom_request` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_custom_request_ctx(const void*    context,
+                                                      const char *  submitter_did,
+                                                      const char *  txn_type,
+                                                      const char *  data_json,
+
+                                                      void           (*cb)(const void*    context,
+                                                                           indy_error_t  err,
+                                                                           const char*   request_json)
+                                                     );
+
+    /// Parses a reply for a request of custom transaction type by the parser registered with `indy_register_ledger_transaction_builder`.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// txn_type: type of custom transaction.
+    /// response_json: response on a request of custom transaction type.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Json returned by the registered parser.
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+    extern indy_error_t indy_parse_custom_response(indy_handle_t command_handle,
+                                                   const char *  txn_type,
+                                                   const char *  response_json,
+
+                                                   void           (*cb)(indy_handle_t command_handle_,
+                                                                        indy_error_t  err,
+                                                                        const char*   parsed_json)
+                                                  );
+
+    /// Variant of `indy_parse_custom_response` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_parse_custom_response_ctx(const void*    context,
+                                                       const char *  txn_type,
+                                                       const char *  response_json,
+
+                                                       void           (*cb)(const void*    context,
+                                                                            indy_error_t  err,
+                                                                            const char*   parsed_json)
+                                                      );
 
 
     /// Parse transaction response to fetch metadata.
@@ -995,6 +2616,60 @@ extern "C" {
                                                                         const char*   response_metadata)
                                                   );
 
+    /// Variant of `indy_get_response_metadata` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_get_response_metadata_ctx(const void*    context,
+                                                       const char *  response,
+
+                                                       void           (*cb)(const void*    context,
+                                                                            indy_error_t  err,
+                                                                            const char*   response_metadata)
+                                                      );
+
+    /// Verifies state proof and multi signature of the pool nodes for a reply on read request
+    /// obtained out-of-band (f.e. through a proxy or from an audit log) without any opened pool.
+    ///
+    /// State proof is verified the same way as for replies received by `indy_submit_request`.
+    /// Note: freshness of the reply isn't checked (use `indy_get_response_metadata` for that).
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// request_json: read request the reply is received on (the state proof key is calculated from it).
+    /// response_json: node's reply on the read request ("as is").
+    /// bls_keys_json: BLS keys of the pool nodes (f.e. from `blskey` field of the genesis NODE transactions):
+    ///     {
+    ///         <node alias>: <base58 encoded BLS key>,
+    ///         ...
+    ///     }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// valid: true - if state proof and multi signature of at least n - f nodes are valid
+    ///        false - otherwise (f.e. reply doesn't contain state proof)
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+    extern indy_error_t indy_verify_state_proof(indy_handle_t command_handle,
+                                                const char *  request_json,
+                                                const char *  response_json,
+                                                const char *  bls_keys_json,
+
+                                                void           (*cb)(indy_handle_t command_handle_,
+                                                                     indy_error_t  err,
+                                                                     indy_bool_t   valid)
+                                               );
+
+    /// Variant of `indy_verify_state_proof` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_verify_state_proof_ctx(const void*    context,
+                                                    const char *  request_json,
+                                                    const char *  response_json,
+                                                    const char *  bls_keys_json,
+
+                                                    void           (*cb)(const void*    context,
+                                                                         indy_error_t  err,
+                                                                         indy_bool_t   valid)
+                                                   );
+
     /// Builds a AUTH_RULE request. Request to change authentication rules for a ledger transaction.
     ///
     /// #Params
@@ -1044,6 +2719,21 @@ extern "C" {
                                                                           const char*   request_json)
                                                     );
 
+    /// Variant of `indy_build_auth_rule_request` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_auth_rule_request_ctx(const void*    context,
+                                                         const char *  submitter_did,
+                                                         const char *  txn_type,
+                                                         const char *  action,
+                                                         const char *  field,
+                                                         const char *  old_value,
+                                                         const char *  new_value,
+                                                         const char *  constraint,
+
+                                                         void           (*cb)(const void*    context,
+                                                                              indy_error_t  err,
+                                                                              const char*   request_json)
+                                                        );
+
 
     /// Builds a AUTH_RULES request. Request to change multiple authentication rules for a ledger transaction.
     ///
@@ -1082,6 +2772,16 @@ extern "C" {
                                                                           const char*   request_json)
                                                     );
 
+    /// Variant of `indy_build_auth_rules_request` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_auth_rules_request_ctx(const void*    context,
+                                                         const char *  submitter_did,
+                                                         const char *  data,
+
+                                                         void           (*cb)(const void*    context,
+                                                                              indy_error_t  err,
+                                                                              const char*   request_json)
+                                                        );
+
     /// Builds a GET_AUTH_RULE request. Request to get authentication rules for a ledger transaction.
     ///
     /// NOTE: Either none or all transaction related parameters must be specified (`old_value` can be skipped for `ADD` action).
@@ -1117,6 +2817,248 @@ extern "C" {
                                                                               const char*   request_json)
                                                         );
 
+    /// Variant of `indy_build_get_auth_rule_request` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_get_auth_rule_request_ctx(const void*    context,
+                                                             const char *  submitter_did,
+                                                             const char *  txn_type,
+                                                             const char *  action,
+                                                             const char *  field,
+                                                             const char *  old_value,
+                                                             const char *  new_value,
+
+                                                             void           (*cb)(const void*    context,
+                                                                                  indy_error_t  err,
+                                                                                  const char*   request_json)
+                                                            );
+
+    /// Builds a ROLE constraint to be used in AUTH_RULE and AUTH_RULES requests.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// role: role of a user which satisfy to constrain.
+    ///     Can be either role alias ("TRUSTEE", "STEWARD", "ENDORSER", "NETWORK_MONITOR", "IDENTITY_OWNER"),
+    ///     associated value or "*" for any role.
+    /// sig_count: the number of signatures required to execution action.
+    /// need_to_be_owner: if user must be an owner of transaction.
+    /// off_ledger_signature: allow signature of DID unknown for the ledger (can be set only for any role).
+    /// metadata_json: (Optional) additional parameters of the constraint (e.g. fees).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Constraint json (see `indy_build_auth_rule_request`).
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_build_role_constraint(indy_handle_t command_handle,
+                                                   const char *  role,
+                                                   indy_u32_t    sig_count,
+                                                   indy_bool_t   need_to_be_owner,
+                                                   indy_bool_t   off_ledger_signature,
+                                                   const char *  metadata_json,
+
+                                                   void           (*cb)(indy_handle_t command_handle_,
+                                                                        indy_error_t  err,
+                                                                        const char*   constraint_json)
+                                                  );
+
+    /// Variant of `indy_build_role_constraint` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_role_constraint_ctx(const void*    context,
+                                                       const char *  role,
+                                                       indy_u32_t    sig_count,
+                                                       indy_bool_t   need_to_be_owner,
+                                                       indy_bool_t   off_ledger_signature,
+                                                       const char *  metadata_json,
+
+                                                       void           (*cb)(const void*    context,
+                                                                            indy_error_t  err,
+                                                                            const char*   constraint_json)
+                                                      );
+
+    /// Combines several constraints into AND or OR constraint to be used in AUTH_RULE and AUTH_RULES requests.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// combination: type of the combination. Can be either "AND" (all constraints must be met) or "OR" (any of them).
+    /// constraints_json: json array of constraints to combine (see `indy_build_role_constraint`).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Constraint json (see `indy_build_auth_rule_request`).
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_build_combined_constraint(indy_handle_t command_handle,
+                                                       const char *  combination,
+                                                       const char *  constraints_json,
+
+                                                       void           (*cb)(indy_handle_t command_handle_,
+                                                                            indy_error_t  err,
+                                                                            const char*   constraint_json)
+                                                      );
+
+    /// Variant of `indy_build_combined_constraint` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_combined_constraint_ctx(const void*    context,
+                                                           const char *  combination,
+                                                           const char *  constraints_json,
+
+                                                           void           (*cb)(const void*    context,
+                                                                                indy_error_t  err,
+                                                                                const char*   constraint_json)
+                                                          );
+
+    /// Evaluates whether the set of signers can perform the ledger write according to the auth rules.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// auth_rules_json: json array of auth rules (see `indy_parse_get_auth_rule_response`).
+    /// action_json: prospective ledger write:
+    ///     {
+    ///         "auth_type": <string> - ledger transaction alias or associated value,
+    ///         "auth_action": <string> - type of an action. Can be either "ADD" or "EDIT",
+    ///         "field": <string> - transaction field,
+    ///         "old_value": <string> - (Optional) old value of a field (for EDIT action),
+    ///         "new_value": <string> - (Optional) new value of a field,
+    ///         "owner": <string> - (Optional) DID of the transaction owner
+    ///     }
+    /// signers_json: json array of signers:
+    ///     [{
+    ///         "did": <string> - signer DID,
+    ///         "role": <string> - (Optional) signer role associated value (identity owner if not set),
+    ///         "onLedger": <bool> - (Optional) whether signer DID is written on the ledger (true by default),
+    ///         "verified": <bool> - (Optional) whether signer signature is valid (true by default)
+    ///     }]
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Evaluation json:
+    /// {
+    ///     "allowed": <bool> - whether signers can perform the action,
+    ///     "authRule": <object> - the most specific auth rule matching the action,
+    ///     "missingSignatures": [{
+    ///         "role": <string> - (Optional) role of required signers ("*" means any role),
+    ///         "count": <int> - number of required signatures,
+    ///         "needToBeOwner": <bool> - (Optional) whether signer must be the owner of the transaction
+    ///     }],
+    ///     "forbidden": <bool> - whether action is forbidden by the auth rule
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+    extern indy_error_t indy_evaluate_auth_rules(indy_handle_t command_handle,
+                                                 const char *  auth_rules_json,
+                                                 const char *  action_json,
+                                                 const char *  signers_json,
+
+                                                 void           (*cb)(indy_handle_t command_handle_,
+                                                                      indy_error_t  err,
+                                                                      const char*   evaluation_json)
+                                                );
+
+    /// Variant of `indy_evaluate_auth_rules` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_evaluate_auth_rules_ctx(const void*    context,
+                                                     const char *  auth_rules_json,
+                                                     const char *  action_json,
+                                                     const char *  signers_json,
+
+                                                     void           (*cb)(const void*    context,
+                                                                          indy_error_t  err,
+                                                                          const char*   evaluation_json)
+                                                    );
+
+    /// Fetches the complete set of auth rules of the ledger config and returns one page of them.
+    ///
+    /// All the rules are requested by a single GET_AUTH_RULE request and paginated on the client side
+    /// over the rules sorted by transaction type, action, field, old and new values,
+    /// so a page is stable while the ledger config isn't changed.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// submitter_did: (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+    /// page_options_json: (Optional) pagination options (all the rules are returned if not set):
+    ///     {
+    ///         "offset": <int> - (Optional) number of rules to skip (0 by default),
+    ///         "limit": <int> - (Optional) maximum number of rules in the page (all remaining rules by default)
+    ///     }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Auth rules page json:
+    /// {
+    ///     "rules": [<object>] - auth rules of the page (see `indy_parse_get_auth_rule_response`),
+    ///     "total": <int> - total number of auth rules of the ledger,
+    ///     "nextOffset": <int> - (Optional) offset of the next page, absent for the last page
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+    extern indy_error_t indy_get_auth_rules(indy_handle_t command_handle,
+                                            indy_handle_t pool_handle,
+                                            const char *  submitter_did,
+                                            const char *  page_options_json,
+
+                                            void           (*cb)(indy_handle_t command_handle_,
+                                                                 indy_error_t  err,
+                                                                 const char*   auth_rules_page_json)
+                                           );
+
+    /// Variant of `indy_get_auth_rules` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_get_auth_rules_ctx(const void*    context,
+                                                indy_handle_t pool_handle,
+                                                const char *  submitter_did,
+                                                const char *  page_options_json,
+
+                                                void           (*cb)(const void*    context,
+                                                                     indy_error_t  err,
+                                                                     const char*   auth_rules_page_json)
+                                               );
+
+    /// Compares the auth rules of the ledger with a proposed set of auth rules.
+    ///
+    /// Rules are matched by transaction type (alias or associated value), action, field, old (for EDIT action) and new values.
+    /// Constraints are compared structurally, absent and empty metadata are considered equal.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// current_auth_rules_json: json array of the current auth rules (see `indy_parse_get_auth_rule_response`).
+    /// proposed_auth_rules_json: json array of the proposed auth rules in the same format.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Auth rules diff json:
+    /// {
+    ///     "added": [<object>] - proposed rules missing in the current ones,
+    ///     "removed": [<object>] - current rules missing in the proposed ones,
+    ///     "changed": [{
+    ///         "current": <object> - current rule,
+    ///         "proposed": <object> - proposed rule with a different constraint
+    ///     }],
+    ///     "unchanged": <int> - number of rules with equal constraints
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_diff_auth_rules(indy_handle_t command_handle,
+                                             const char *  current_auth_rules_json,
+                                             const char *  proposed_auth_rules_json,
+
+                                             void           (*cb)(indy_handle_t command_handle_,
+                                                                  indy_error_t  err,
+                                                                  const char*   diff_json)
+                                            );
+
+    /// Variant of `indy_diff_auth_rules` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_diff_auth_rules_ctx(const void*    context,
+                                                 const char *  current_auth_rules_json,
+                                                 const char *  proposed_auth_rules_json,
+
+                                                 void           (*cb)(const void*    context,
+                                                                      indy_error_t  err,
+                                                                      const char*   diff_json)
+                                                );
+
     /// Builds a TXN_AUTHR_AGRMT request. Request to add a new version of Transaction Author Agreement to the ledger.
     ///
     /// EXPERIMENTAL
@@ -1166,6 +3108,19 @@ extern "C" {
                                                                                      const char*   request_json)
                                                                );
 
+    /// Variant of `indy_build_txn_author_agreement_request` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_txn_author_agreement_request_ctx(const void*    context,
+                                                                    const char *  submitter_did,
+                                                                    const char *  text,
+                                                                    const char *  version,
+                                                                    indy_i64_t  ratification_ts,
+                                                                    indy_i64_t  retirement_ts,
+
+                                                                    void           (*cb)(const void*    context,
+                                                                                         indy_error_t  err,
+                                                                                         const char*   request_json)
+                                                                   );
+
     /// Builds a DISABLE_ALL_TXN_AUTHR_AGRMTS request. Request to disable all Transaction Author Agreement on the ledger.
     ///
     /// EXPERIMENTAL
@@ -1189,6 +3144,15 @@ extern "C" {
                                                                                                   const char*   request_json)
                                                                             );
 
+    /// Variant of `indy_build_disable_all_txn_author_agreements_request` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_disable_all_txn_author_agreements_request_ctx(const void*    context,
+                                                                                 const char *  submitter_did,
+
+                                                                                 void           (*cb)(const void*    context,
+                                                                                                      indy_error_t  err,
+                                                                                                      const char*   request_json)
+                                                                                );
+
     /// Builds a GET_TXN_AUTHR_AGRMT request. Request to get a specific Transaction Author Agreement from the ledger.
     ///
     /// EXPERIMENTAL
@@ -1221,6 +3185,16 @@ extern "C" {
                                                                               const char*   request_json)
                                                         );
 
+    /// Variant of `indy_build_get_txn_author_agreement_request` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_get_txn_author_agreement_request_ctx(const void*    context,
+                                                             const char *  submitter_did,
+                                                             const char *  data,
+
+                                                             void           (*cb)(const void*    context,
+                                                                                  indy_error_t  err,
+                                                                                  const char*   request_json)
+                                                            );
+
     /// Builds a SET_TXN_AUTHR_AGRMT_AML request. Request to add a new list of acceptance mechanisms for transaction author agreement.
     /// Acceptance Mechanism is a description of the ways how the user may accept a transaction author agreement.
     ///
@@ -1256,6 +3230,18 @@ extern "C" {
                                                                                       const char*   request_json)
                                                                 );
 
+    /// Variant of `indy_build_acceptance_mechanisms_request` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_acceptance_mechanisms_request_ctx(const void*    context,
+                                                                     const char *  submitter_did,
+                                                                     const char *  aml,
+                                                                     const char *  version,
+                                                                     const char *  aml_context,
+
+                                                                     void           (*cb)(const void*    context,
+                                                                                          indy_error_t  err,
+                                                                                          const char*   request_json)
+                                                                    );
+
     /// Builds a LEDGERS_FREEZE request. Request to freeze list of ledgers.
     ///
     /// #Params
@@ -1278,6 +3264,16 @@ extern "C" {
                                                                                       const char*   request_json)
                                                                 );
 
+    /// Variant of `indy_build_ledgers_freeze_request` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_ledgers_freeze_request_ctx(const void*    context,
+                                                                     const char *  submitter_did,
+                                                                     const char *  ledgers_ids,
+
+                                                                     void           (*cb)(const void*    context,
+                                                                                          indy_error_t  err,
+                                                                                          const char*   request_json)
+                                                                    );
+
     /// Builds a GET_FROZEN_LEDGERS request. Request to get list of frozen ledgers.
     /// frozen ledgers are defined by LEDGERS_FREEZE request.
     ///
@@ -1307,6 +3303,59 @@ extern "C" {
                                                                                       const char*   request_json)
                                                                 );
 
+    /// Variant of `indy_build_get_frozen_ledgers_request` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_get_frozen_ledgers_request_ctx(const void*    context,
+                                                                     const char *  submitter_did,
+
+                                                                     void           (*cb)(const void*    context,
+                                                                                          indy_error_t  err,
+                                                                                          const char*   request_json)
+                                                                    );
+
+    /// Parse a GET_FROZEN_LEDGERS response to get the list of frozen ledgers.
+    /// Empty list is returned if there are no frozen ledgers.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// get_frozen_ledgers_response: response on GET_FROZEN_LEDGERS request.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Frozen ledgers sorted by ledger id:
+    /// {
+    ///     data: [
+    ///         {
+    ///             ledgerId: <int> - id of the frozen ledger,
+    ///             ledgerRoot: <string> - ledger root hash at the moment of freezing,
+    ///             stateRoot: <string> - state root hash at the moment of freezing,
+    ///             seqNo: <int> - the latest transaction seqNo of the frozen ledger
+    ///         },
+    ///         ...
+    ///     ],
+    ///     seqNo: (optional) sequence number of the LEDGERS_FREEZE transaction,
+    ///     txnTime: (optional) time of the LEDGERS_FREEZE transaction
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+    extern indy_error_t indy_parse_get_frozen_ledgers_response(indy_handle_t command_handle,
+                                                               const char *  get_frozen_ledgers_response,
+
+                                                               void           (*cb)(indy_handle_t command_handle_,
+                                                                                    indy_error_t  err,
+                                                                                    const char*   frozen_ledgers_json)
+                                                              );
+
+    /// Variant of `indy_parse_get_frozen_ledgers_response` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_parse_get_frozen_ledgers_response_ctx(const void*    context,
+                                                                   const char *  get_frozen_ledgers_response,
+
+                                                                   void           (*cb)(const void*    context,
+                                                                                        indy_error_t  err,
+                                                                                        const char*   frozen_ledgers_json)
+                                                                  );
+
     /// Builds a GET_TXN_AUTHR_AGRMT_AML request. Request to get a list of  acceptance mechanisms from the ledger
     /// valid for specified time or the latest one.
     ///
@@ -1336,6 +3385,17 @@ extern "C" {
                                                                                           const char*   request_json)
                                                                     );
 
+    /// Variant of `indy_build_get_acceptance_mechanisms_request` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_get_acceptance_mechanisms_request_ctx(const void*    context,
+                                                                         const char *  submitter_did,
+                                                                         indy_i64_t  timestamp,
+                                                                         const char *  version,
+
+                                                                         void           (*cb)(const void*    context,
+                                                                                              indy_error_t  err,
+                                                                                              const char*   request_json)
+                                                                        );
+
     /// Append transaction author agreement acceptance data to a request.
     /// This function should be called before signing and sending a request
     /// if there is any transaction author agreement set on the Ledger.
@@ -1377,6 +3437,75 @@ extern "C" {
                                                                                                     const char*   request_with_meta_json)
                                                                                );
 
+    /// Variant of `indy_append_txn_author_agreement_acceptance_to_request` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_append_txn_author_agreement_acceptance_to_request_ctx(const void*    context,
+                                                                                   const char *  request_json,
+                                                                                   const char *  text,
+                                                                                   const char *  version,
+                                                                                   const char *  taa_digest,
+                                                                                   const char *  mechanism,
+                                                                                   indy_u64_t    time,
+
+                                                                                   void           (*cb)(const void*    context,
+                                                                                                        indy_error_t  err,
+                                                                                                        const char*   request_with_meta_json)
+                                                                                   );
+
+    /// Prepares a request for signing with acceptance of the latest transaction author agreement set on the Ledger.
+    /// This function should be called instead of `indy_append_txn_author_agreement_acceptance_to_request`
+    /// if an application doesn't track the agreement by itself.
+    ///
+    /// EXPERIMENTAL
+    ///
+    /// Latest agreement and acceptance mechanisms are requested from the Ledger and cached for the pool for 10 minutes.
+    /// Acceptance is stored in the wallet for the submitter DID and reused for all the following requests,
+    /// so time of acceptance is kept unchanged until the Ledger sets a new agreement.
+    /// Time of acceptance is rounded to the date and never precedes the agreement transaction.
+    /// The request is returned unchanged if there is no agreement set on the Ledger.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// submitter_did: Id of Identity stored in secured Wallet that is going to sign the request.
+    /// request_json: original request data json.
+    /// mechanism: (Optional) mechanism how user has accepted the TAA.
+    ///     Can be omitted if the previous acceptance of the agreement was made by the submitter or
+    ///     if the Ledger allows the only acceptance mechanism.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Updated request result as json.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Ledger*
+    extern indy_error_t indy_prepare_request_with_taa(indy_handle_t command_handle,
+                                                      indy_handle_t pool_handle,
+                                                      indy_handle_t wallet_handle,
+                                                      const char *  submitter_did,
+                                                      const char *  request_json,
+                                                      const char *  mechanism,
+
+                                                      void           (*cb)(indy_handle_t command_handle_,
+                                                                           indy_error_t  err,
+                                                                           const char*   request_with_meta_json)
+                                                     );
+
+    /// Variant of `indy_prepare_request_with_taa` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_prepare_request_with_taa_ctx(const void*    context,
+                                                          indy_handle_t pool_handle,
+                                                          indy_handle_t wallet_handle,
+                                                          const char *  submitter_did,
+                                                          const char *  request_json,
+                                                          const char *  mechanism,
+
+                                                          void           (*cb)(const void*    context,
+                                                                               indy_error_t  err,
+                                                                               const char*   request_with_meta_json)
+                                                         );
+
     /// Append Endorser to an existing request.
     ///
     /// An author of request still is a `DID` used as a `submitter_did` parameter for the building of the request.
@@ -1405,6 +3534,55 @@ extern "C" {
                                                                           const char*   out_request_json)
                                                      );
 
+    /// Variant of `indy_append_request_endorser` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_append_request_endorser_ctx(const void*    context,
+                                                         const char *  request_json,
+                                                         const char *  endorser_did,
+
+                                                         void           (*cb)(const void*    context,
+                                                                              indy_error_t  err,
+                                                                              const char*   out_request_json)
+                                                         );
+
+    /// Sets protocol version of an existing request.
+    ///
+    /// Requests are built with the protocol version set by `indy_set_protocol_version` (2 - used default).
+    /// This call allows to prepare requests for a specific version of Indy Node without an opened pool
+    /// and without changing the global PROTOCOL_VERSION (f.e. for offline signing of transactions sent from another machine).
+    ///
+    /// Note: Request must be signed after that.
+    ///
+    /// #Params
+    /// request_json: original request
+    /// protocol_version: Protocol version will be used:
+    ///     1 - for Indy Node 1.3
+    ///     2 - for Indy Node 1.4 and greater
+    ///     Credential definition tag isn't supported by protocol version 1 and is removed from CRED_DEF request.
+    /// cb: Callback that takes command result as parameter.
+    ///     The command result is a request JSON with protocol version set.
+    ///
+    /// #Errors
+    /// Common*
+    /// PoolIncompatibleProtocolVersion
+    extern indy_error_t indy_set_request_protocol_version(indy_handle_t command_handle,
+                                                          const char *  request_json,
+                                                          indy_u64_t    protocol_version,
+
+                                                          void           (*cb)(indy_handle_t command_handle_,
+                                                                               indy_error_t  err,
+                                                                               const char*   out_request_json)
+                                                          );
+
+    /// Variant of `indy_set_request_protocol_version` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_set_request_protocol_version_ctx(const void*    context,
+                                                              const char *  request_json,
+                                                              indy_u64_t    protocol_version,
+
+                                                              void           (*cb)(const void*    context,
+                                                                                   indy_error_t  err,
+                                                                                   const char*   out_request_json)
+                                                              );
+
 #ifdef __cplusplus
 }
 #endif
@@ -35,6 +35,22 @@ extern "C" {
                                         void (*flushFn)(const void*  context)
                                                   );
 
+    /// Set log level and target filter of the installed logger.
+    ///
+    /// Can be called at any time after logger initialization, e.g. to enable pool tracing temporarily.
+    ///
+    /// #Params
+    /// filter: comma separated list of `target=level` or `level` directives in `env_logger` syntax,
+    ///     e.g. "indy::services::pool=trace,indy::services::wallet=warn".
+    ///     Targets that are not listed are not logged unless a global level is specified.
+    ///
+    /// NOTE: filter is not applied to the default logger on Android.
+    ///
+    /// #Returns
+    /// Error code
+
+    extern indy_error_t indy_set_log_filter(const char *  filter);
+
     /// Set default logger implementation.
     ///
     /// Allows library user use `env_logger` logger as default implementation.
@@ -19,6 +19,108 @@ extern "C" {
                                                                  const char*   metrics_json)
 		                            );
 
+    /// Variant of `indy_collect_metrics` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_collect_metrics_ctx(const void*    context,
+                                                 void          (*fn)(const void*    context,
+                                                                     indy_err_t    err,
+                                                                     const char*   metrics_json)
+    		                            );
+
+    /// Collect metrics in Prometheus text exposition format.
+    ///
+    /// Thread pool and wallet statistics are exposed as gauges labeled with `label`.
+    /// Command durations are exposed as `indy_commands_duration_ms` histogram labeled with `command` and `stage`.
+    /// Pool catch-up rounds, merkle consistency failures, reply mismatches and state proof rejections are exposed
+    /// as `indy_pool_events_total` counter labeled with `pool` and `event`.
+    ///
+    /// #Returns
+    /// Metrics in Prometheus text format ready to be served to a scraper.
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_collect_metrics_prometheus(indy_handle_t command_handle,
+                                                        void          (*fn)(indy_handle_t command_handle_,
+                                                                            indy_err_t    err,
+                                                                            const char*   metrics)
+                                                        );
+
+    /// Variant of `indy_collect_metrics_prometheus` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_collect_metrics_prometheus_ctx(const void*    context,
+                                                            void          (*fn)(const void*    context,
+                                                                                indy_err_t    err,
+                                                                                const char*   metrics)
+                                                            );
+
+    /// Collect metrics accumulated since the previous call of this function.
+    ///
+    /// Counters (commands count, durations and histogram buckets, wallet operations) contain only
+    /// values accumulated since the previous call or since `indy_reset_metrics`.
+    /// Gauges (thread pool and wallet counts) contain current values.
+    /// The first call returns values accumulated since libindy start.
+    ///
+    /// #Returns
+    /// Map in the JSON format of `indy_collect_metrics`.
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_collect_metrics_delta(indy_handle_t command_handle,
+                                                   void          (*fn)(indy_handle_t command_handle_,
+                                                                       indy_err_t    err,
+                                                                       const char*   metrics_json)
+                                                   );
+
+    /// Variant of `indy_collect_metrics_delta` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_collect_metrics_delta_ctx(const void*    context,
+                                                       void          (*fn)(const void*    context,
+                                                                           indy_err_t    err,
+                                                                           const char*   metrics_json)
+                                                       );
+
+    /// Reset metric counters.
+    ///
+    /// Sets commands count, durations, histogram buckets and wallet operation counters to zero.
+    /// Gauges (thread pool and wallet counts) are not affected.
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_reset_metrics(indy_handle_t command_handle,
+                                           void          (*fn)(indy_handle_t command_handle_,
+                                                               indy_err_t    err)
+                                           );
+
+    /// Variant of `indy_reset_metrics` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_reset_metrics_ctx(const void*    context,
+                                               void          (*fn)(const void*    context,
+                                                                   indy_err_t    err)
+                                               );
+
+    /// List handles that are currently open: wallets, pools, searches and blob storage readers and writers.
+    /// Handles that stay open for long may point to leaks of the application.
+    ///
+    /// #Returns
+    /// handles_json: [{
+    ///     "type": string - one of "wallet", "pool", "wallet_search", "did_search", "pairwise_search", "credentials_search",
+    ///         "credentials_for_proof_request_search", "blob_storage_reader", "blob_storage_writer",
+    ///     "handle": int - value of the handle,
+    ///     "name": Optional<string> - wallet id, pool name, type of searched records or blob storage type,
+    ///     "age_ms": int - time passed since the handle has been opened,
+    /// }] ordered from the oldest handle.
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_list_handles(indy_handle_t command_handle,
+                                          void          (*fn)(indy_handle_t command_handle_,
+                                                              indy_err_t    err,
+                                                              const char*   handles_json)
+                                          );
+
+    /// Variant of `indy_list_handles` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_list_handles_ctx(const void*    context,
+                                              void          (*fn)(const void*    context,
+                                                                  indy_err_t    err,
+                                                                  const char*   handles_json)
+                                              );
+
 #ifdef __cplusplus
 }
 #endif
@@ -54,6 +54,12 @@ typedef enum
     // IO Error
     CommonIOError = 114,
 
+    // Too many commands are waiting for execution
+    CommonQueueFull = 130,
+
+    // Command hasn't completed in the configured timeout
+    CommonTimeout = 131,
+
     // Wallet errors
     // Caller passed invalid wallet handle
     WalletInvalidHandle = 200,
@@ -100,6 +106,9 @@ typedef enum
     // Returned if provided wallet query is invalid
     WalletQueryError = 214,
 
+    // Returned if wallet item was modified since expected state was read
+    WalletItemConflict = 215,
+
     // Ledger errors
     // Trying to open pool ledger that wasn't created before
     PoolLedgerNotCreatedError = 300,
@@ -154,6 +163,9 @@ typedef enum
     // Attempt to create duplicate did
     DidAlreadyExistsError = 600,
 
+    // Attempt to sign with deactivated did
+    DidDeactivatedError = 601,
+
     // Unknown payment method was given
     PaymentUnknownMethodError = 700,
 
@@ -182,16 +194,167 @@ extern "C" {
     ///
     /// #Params
     /// config: {
-    ///     "crypto_thread_pool_size": Optional<int> - size of thread pool for the most expensive crypto operations. (4 by default)
-    ///     "collect_backtrace": Optional<bool> - whether errors backtrace should be collected.
+    ///     "crypto_thread_pool_size": Optional<int> - size of thread pool for the most expensive crypto operations
+    ///         like wallet key derivation and credential definition creation. (4 by default)
+    ///     "anoncreds_worker_threads": Optional<int> - number of worker threads creating and verifying proofs,
+    ///         so these operations don't block other commands. Proofs of the same wallet are created in
+    ///         submission order. Other commands still run on the single command thread. (4 by default)
+    ///     "collect_backtrace": Optional<bool> - whether errors backtrace should be collected and reported by `indy_get_current_error`.
     ///         Capturing of backtrace can affect library performance.
     ///         NOTE: must be set before invocation of any other API functions.
+    ///     "metrics_buckets_ms": Optional<array<float>> - increasing upper bounds of command duration histogram buckets in milliseconds.
+    ///         (0.5, 1, 2, 5, 10, 20, 50, 100, 200, 500, 1000, 2000, 5000, 10000, 20000 by default)
+    ///         NOTE: must be set before invocation of any other API functions.
+    ///     "log_format": Optional<string> - format of log records: "text" (default) or "json".
+    ///         JSON records contain fields: target, level, message, module_path, file, line,
+    ///         command_handle and wallet_handle_hash (if the message refers to them).
+    ///     "slow_operation_threshold_ms": Optional<int> - wallet storage calls and pool requests lasting longer
+    ///         are logged with warn level to `indy_slow_operations` target with their duration and parameters digest.
+    ///         (0 - disabled by default)
+    ///     "max_pending_commands": Optional<int> - maximum number of commands waiting for execution.
+    ///         When it is reached API calls fail with CommonQueueFull error. (0 - unbounded by default)
+    ///     "queue_full_timeout_ms": Optional<int> - how long API calls wait for the full queue to drain
+    ///         before failing with CommonQueueFull error. (0 - fail at once by default)
+    ///     "command_timeout_ms": Optional<int> - callbacks of API calls that haven't completed in this time
+    ///         are called with CommonTimeout error. The result that comes later is dropped. (0 - disabled by default)
+    ///         Streaming calls restart the timeout on every chunk. The work of the call is cancelled where possible:
+    ///         ledger requests are dropped, wallet searches are closed and streaming is stopped.
+    ///         Operation already running in a wallet storage plugin, key derivation or anoncreds can't be cancelled.
+    ///     "pool_socket_hwm": Optional<int> - high water mark of pool node sockets, maximum number of messages
+    ///         queued for the node in each direction. (0 - unlimited, 1000 by default)
+    ///         NOTE: applied to node sockets connected after the call.
+    ///     "pool_zmq_io_threads": Optional<int> - number of ZMQ IO threads serving node sockets of each pool.
+    ///         (1 by default)
+    ///         NOTE: applied to pools opened after the call.
+    ///     "blob_buffer_size": Optional<int> - size of buffer in bytes used to write and verify tails files
+    ///         of the default blob storage. (8192 by default)
+    ///         NOTE: applied to blob storage readers and writers opened after the call.
+    ///     "tails_cache_size": Optional<int> - total size in bytes of parsed tails shared by revocation operations,
+    ///         so tails are read and verified once while they remain in the cache. (0 - disabled, 33554432 by default)
+    ///     "anoncreds_test_seed": Optional<string> - FOR TESTS ONLY. Seed to derive nonces of credential offers,
+    ///         credential requests and proof requests and blinding factors of credential requests from,
+    ///         so the same sequence of calls produces byte-identical results.
+    ///         Setting the seed again restarts the sequence, empty string disables the mode. (disabled by default)
+    ///     "strict_attrib_validation": Optional<bool> - whether `raw` data of ATTRIB requests is validated against the known
+    ///         attrib structures by `indy_build_attrib_request`: it must contain a single attribute and `endpoint`, `endpoints`
+    ///         and `diddocContent` attributes must be well-formed. (false by default)
     /// }
     ///
     /// #Errors
     /// Common*
     extern indy_error_t indy_set_runtime_config(const char * config);
 
+    /// Register hooks invoked around execution of every libindy command.
+    /// Hooks are called from libindy threads, so they should return fast.
+    /// Registering new hooks replaces the previous ones. Pass NULL for both hooks to unregister them.
+    ///
+    /// #Params
+    /// pre_cb: Optional hook called before command execution with:
+    ///     command_name: snake case name of the command (the same as in metrics).
+    ///     params_digest: digest of parameters of the API call that sent the command, the same for calls with
+    ///         the same parameters. Parameters themselves aren't exposed. Empty for commands sent by libindy itself.
+    /// post_cb: Optional hook called once the callback of the API call is called with:
+    ///     command_name: snake case name of the command (the same as in metrics).
+    ///     params_digest: digest of parameters of the API call that sent the command.
+    ///     duration_ms: duration from the call to its callback in milliseconds, including the time the command
+    ///         waited for key derivation, pool replies and other continuations.
+    ///     err: code of error passed to the callback (CommonTimeout if the call timed out).
+    ///     Commands sent by libindy itself are reported right after their execution.
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_register_command_hook(void (*pre_cb)(const char* command_name,
+                                                                  const char* params_digest),
+                                                   void (*post_cb)(const char* command_name,
+                                                                   const char* params_digest,
+                                                                   indy_u64_t  duration_ms,
+                                                                   indy_error_t err));
+
+    /// Register callback receiving audit events of sensitive operations.
+    /// Audit is disabled until callback is registered. Pass NULL callback to disable it again.
+    /// Callback is called from libindy command thread after the operation succeeded, so it should return fast.
+    /// NOTE: events are only passed to the callback, libindy doesn't store them in the wallet or elsewhere.
+    ///     Application that needs durable audit trail should persist them itself, e.g. to append-only storage.
+    ///
+    /// #Params
+    /// context: pointer to application context that will be passed to the callback.
+    /// audit_cb: callback called with:
+    ///     context: application context passed on registration.
+    ///     event_json: audit event {
+    ///         "event": string - one of "key_created", "did_created", "message_signed", "request_signed",
+    ///             "did_keys_rotation_started", "did_keys_rotated", "wallet_exported", "credential_issued",
+    ///         "timestamp": int - unix time of the event in seconds,
+    ///         "wallet_handle": int - handle of the wallet the operation used,
+    ///         "context": Optional<object> - context set by `indy_set_audit_context` in the thread that called the operation,
+    ///         "details": object - public data of the operation (verkeys, dids, identifiers). Secrets are never included.
+    ///     }
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_register_audit_callback(const void* context,
+                                                     void (*audit_cb)(const void* context,
+                                                                      const char* event_json));
+
+    /// Set audit context of the calling thread.
+    /// Audit events of operations called from this thread afterwards contain this context.
+    ///
+    /// #Params
+    /// context_json: (optional) JSON object supplied by the application (e.g. user or request id).
+    ///     Pass NULL to clear the context.
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_set_audit_context(const char * context_json);
+
+    /// Set trace context of the calling thread. Available if libindy is built with `tracing_spans` feature.
+    ///
+    /// Spans of commands started from the calling thread (execution, pool round trips, wallet storage operations)
+    /// become children of this context. Spans are OpenTelemetry spans reported through `tracing` crate.
+    /// If the application hasn't set a global `tracing` subscriber, the first call installs the one
+    /// that emits finished spans as JSON records through `indy_tracing` log target.
+    ///
+    /// #Params
+    /// traceparent: W3C trace context in format `00-<trace id>-<parent span id>-<flags>`.
+    ///     Pass NULL to clear the context.
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_set_trace_context(const char * traceparent);
+
+    /// Gracefully shuts libindy down, e.g. on SIGTERM, so wallets storages are not left in inconsistent state.
+    ///
+    /// Stops accepting new API calls: they fail with CommonInvalidState afterwards. Calls queued before
+    /// are executed, then opened searches, wallets and pools are closed and the callback is called.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// force: don't wait for the queued calls. Their callbacks are called with CommonInvalidState error at once.
+    /// timeout_ms: how long to wait for the queued calls before cancelling them as with `force`. (0 - wait without limit)
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_shutdown(indy_handle_t command_handle,
+                                      indy_bool_t   force,
+                                      indy_u64_t    timeout_ms,
+                                      void (*cb)(indy_handle_t command_handle_,
+                                                 indy_error_t  err));
+
+    /// Variant of `indy_shutdown` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_shutdown_ctx(const void*    context,
+                                          indy_bool_t   force,
+                                          indy_u64_t    timeout_ms,
+                                          void (*cb)(const void*    context,
+                                                     indy_error_t  err));
+
+    /// Releases a buffer which ownership has been passed to the application by libindy
+    /// (e.g. by `indy_pack_message_buffer`, `indy_export_wallet_to_buffer` or `indy_read_tails_to_buffer`).
+    ///
+    /// #Params
+    /// data: pointer to the buffer. NULL is ignored.
+    /// data_len: length of the buffer passed together with the pointer.
+    extern void indy_free_buffer(indy_u8_t* data, indy_u32_t data_len);
+
     /// Get details for last occurred error.
     ///
     /// This function should be called in two places to handle both cases of error occurrence:
@@ -209,7 +372,13 @@ extern "C" {
     ///         Collecting of backtrace can be enabled by:
     ///             1) setting environment variable `RUST_BACKTRACE=1`
     ///             2) calling `indy_set_runtime_config` API function with `collect_backtrace: true`
+    ///         Calling `indy_set_runtime_config` with `collect_backtrace: false` stops reporting of backtrace.
     ///     "message": str - human-readable error description
+    ///     "error_code": int - code of the error
+    ///     "component": str - part of libindy the error comes from: "common", "anoncreds", "ledger", "pool",
+    ///         "crypto", "wallet", "did" or "payments"
+    ///     "retriable": bool - whether the same call may succeed if it's repeated later (e.g. on timeouts)
+    ///     "causes": array<str> - descriptions of the error causes from the closest one
     /// }
     ///
     extern void indy_get_current_error(const char ** error_json_p);
@@ -19,11 +19,15 @@ extern "C" {
     ///     "tagName2": <str>, // string tag (will be stored encrypted)
     ///     "~tagName3": <str>, // string tag (will be stored un-encrypted)
     ///     "~tagName4": <str>, // string tag (will be stored un-encrypted)
+    ///     "#~tagName5": <str>, // unsigned integer tag (will be stored encrypted with range index)
     ///   }
     ///   Note that null means no tags
     ///   If tag name starts with "~" the tag will be stored un-encrypted that will allow
     ///   usage of this tag in complex search queries (comparison, predicates)
     ///   Encrypted tags can be searched only for exact matching
+    ///   If tag name starts with "#~" the tag value must be unsigned integer. The tag will be stored
+    ///   encrypted together with range index that will allow usage of it in comparison queries.
+    ///   Other tags starting with "#" are stored as regular encrypted tags
 
     extern indy_error_t indy_add_wallet_record(indy_handle_t  command_handle,
                                                indy_handle_t  wallet_handle,
@@ -35,6 +39,17 @@ extern "C" {
                                                                     indy_error_t err)
                                               );
 
+    /// Variant of `indy_add_wallet_record` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_add_wallet_record_ctx(const void*    context,
+                                                   indy_handle_t  wallet_handle,
+                                                   const char*    type_,
+                                                   const char*    id,
+                                                   const char*    value,
+                                                   const char*    tags_json,
+                                                   void           (*fn)(const void*    context,
+                                                                        indy_error_t err)
+                                                  );
+
     /// Update a non-secret wallet record value
     ///
     /// #Params
@@ -53,6 +68,106 @@ extern "C" {
                                                                              indy_error_t err)
                                                        );
 
+    /// Variant of `indy_update_wallet_record_value` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_update_wallet_record_value_ctx(const void*    context,
+                                                            indy_handle_t  wallet_handle,
+                                                            const char*    type_,
+                                                            const char*    id,
+                                                            const char*    value,
+                                                            void           (*fn)(const void*    context,
+                                                                                 indy_error_t err)
+                                                           );
+
+    /// Update a non-secret wallet record value only if it wasn't changed since it was read (compare-and-swap).
+    ///
+    /// Current record value hash is compared with expected one and record is updated only if they match.
+    /// Check and update are performed as single operation with respect to other libindy wallet calls.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context
+    /// wallet_handle: wallet handle (created by open_wallet)
+    /// type_: allows to separate different record types collections
+    /// id: the id of record
+    /// expected_value_hash: hex encoded SHA-256 hash of the record value the update is based on
+    ///   (as returned by previous call of this function or calculated from value got with indy_get_wallet_record)
+    /// value: the new value of record
+    /// #Returns
+    /// value_hash: hex encoded SHA-256 hash of the new record value
+    ///
+    /// #Errors
+    /// WalletItemConflict - record value was changed since expected value was read
+
+    extern indy_error_t indy_compare_and_swap_wallet_record_value(indy_handle_t  command_handle,
+                                                                  indy_handle_t  wallet_handle,
+                                                                  const char*    type_,
+                                                                  const char*    id,
+                                                                  const char*    expected_value_hash,
+                                                                  const char*    value,
+                                                                  void           (*fn)(indy_handle_t command_handle_,
+                                                                                       indy_error_t err,
+                                                                                       const char*  value_hash)
+                                                                 );
+
+    /// Variant of `indy_compare_and_swap_wallet_record_value` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_compare_and_swap_wallet_record_value_ctx(const void*    context,
+                                                                      indy_handle_t  wallet_handle,
+                                                                      const char*    type_,
+                                                                      const char*    id,
+                                                                      const char*    expected_value_hash,
+                                                                      const char*    value,
+                                                                      void           (*fn)(const void*    context,
+                                                                                           indy_error_t err,
+                                                                                           const char*  value_hash)
+                                                                     );
+
+    /// Atomically update a non-secret wallet record value with the value calculated from the current one.
+    ///
+    /// The record is locked (on storage transaction level for default wallet storage),
+    /// its current value is passed to `modify_cb` and the returned value is written before lock is released.
+    /// Useful for counters and state-machine records shared across threads.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context
+    /// wallet_handle: wallet handle (created by open_wallet)
+    /// type_: allows to separate different record types collections
+    /// id: the id of record
+    /// modify_cb: callback calculating the new value of record from the current one.
+    ///   It must set `new_value_p` and return Success to write the new value
+    ///   or return any other error code to leave the record unchanged.
+    ///   The memory pointed by `new_value_p` must stay valid until `fn` callback is called.
+    ///   Libindy wallet functions must not be waited for from this callback.
+    /// #Returns
+    /// new_value: the value written to the record
+    ///
+    /// #Errors
+    /// WalletItemNotFound - record not found
+    /// Error code returned by `modify_cb` - record is left unchanged
+
+    extern indy_error_t indy_modify_wallet_record_value(indy_handle_t  command_handle,
+                                                        indy_handle_t  wallet_handle,
+                                                        const char*    type_,
+                                                        const char*    id,
+                                                        indy_error_t   (*modify_cb)(indy_handle_t command_handle_,
+                                                                                    const char*   current_value,
+                                                                                    const char**  new_value_p),
+                                                        void           (*fn)(indy_handle_t command_handle_,
+                                                                             indy_error_t err,
+                                                                             const char*  new_value)
+                                                       );
+
+    /// Variant of `indy_modify_wallet_record_value` that passes application `context` to the callbacks instead of command handle.
+    extern indy_error_t indy_modify_wallet_record_value_ctx(const void*    context,
+                                                            indy_handle_t  wallet_handle,
+                                                            const char*    type_,
+                                                            const char*    id,
+                                                            indy_error_t   (*modify_cb)(const void*   context,
+                                                                                        const char*   current_value,
+                                                                                        const char**  new_value_p),
+                                                            void           (*fn)(const void*    context,
+                                                                                 indy_error_t err,
+                                                                                 const char*  new_value)
+                                                           );
+
 /// Update a non-secret wallet record tags
 ///
 /// #Params
@@ -66,10 +181,14 @@ extern "C" {
 ///     "tagName2": <str>, // string tag (will be stored encrypted)
 ///     "~tagName3": <str>, // string tag (will be stored un-encrypted)
 ///     "~tagName4": <str>, // string tag (will be stored un-encrypted)
+///     "#~tagName5": <str>, // unsigned integer tag (will be stored encrypted with range index)
 ///   }
 ///   If tag name starts with "~" the tag will be stored un-encrypted that will allow
 ///   usage of this tag in complex search queries (comparison, predicates)
 ///   Encrypted tags can be searched only for exact matching
+///   If tag name starts with "#~" the tag value must be unsigned integer. The tag will be stored
+///   encrypted together with range index that will allow usage of it in comparison queries.
+///   Other tags starting with "#" are stored as regular encrypted tags
 
     extern indy_error_t indy_update_wallet_record_tags(indy_handle_t  command_handle,
                                                        indy_handle_t  wallet_handle,
@@ -80,6 +199,16 @@ extern "C" {
                                                                             indy_error_t err)
                                                       );
 
+    /// Variant of `indy_update_wallet_record_tags` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_update_wallet_record_tags_ctx(const void*    context,
+                                                           indy_handle_t  wallet_handle,
+                                                           const char*    type_,
+                                                           const char*    id,
+                                                           const char*    tags_json,
+                                                           void           (*fn)(const void*    context,
+                                                                                indy_error_t err)
+                                                          );
+
     /// Add new tags to the wallet record
     ///
     /// #Params
@@ -93,10 +222,14 @@ extern "C" {
     ///     "tagName2": <str>, // string tag (will be stored encrypted)
     ///     "~tagName3": <str>, // string tag (will be stored un-encrypted)
     ///     "~tagName4": <str>, // string tag (will be stored un-encrypted)
+    ///     "#~tagName5": <str>, // unsigned integer tag (will be stored encrypted with range index)
     ///   }
     ///   If tag name starts with "~" the tag will be stored un-encrypted that will allow
     ///   usage of this tag in complex search queries (comparison, predicates)
     ///   Encrypted tags can be searched only for exact matching
+    ///   If tag name starts with "#~" the tag value must be unsigned integer. The tag will be stored
+    ///   encrypted together with range index that will allow usage of it in comparison queries.
+    ///   Other tags starting with "#" are stored as regular encrypted tags
     ///   Note if some from provided tags already assigned to the record than
     ///     corresponding tags values will be replaced
 
@@ -109,6 +242,16 @@ extern "C" {
                                                                          indy_error_t err)
                                                    );
 
+    /// Variant of `indy_add_wallet_record_tags` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_add_wallet_record_tags_ctx(const void*    context,
+                                                        indy_handle_t  wallet_handle,
+                                                        const char*    type_,
+                                                        const char*    id,
+                                                        const char*    tags_json,
+                                                        void           (*fn)(const void*    context,
+                                                                             indy_error_t err)
+                                                       );
+
     /// Delete tags from the wallet record
     ///
     /// #Params
@@ -128,6 +271,16 @@ extern "C" {
                                                                             indy_error_t err)
                                                       );
 
+    /// Variant of `indy_delete_wallet_record_tags` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_delete_wallet_record_tags_ctx(const void*    context,
+                                                           indy_handle_t  wallet_handle,
+                                                           const char*    type_,
+                                                           const char*    id,
+                                                           const char*    tag_names_json,
+                                                           void           (*fn)(const void*    context,
+                                                                                indy_error_t err)
+                                                          );
+
     /// Delete an existing wallet record in the wallet
     ///
     /// #Params
@@ -144,6 +297,57 @@ extern "C" {
                                                                        indy_error_t err)
                                                  );
 
+    /// Variant of `indy_delete_wallet_record` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_delete_wallet_record_ctx(const void*    context,
+                                                      indy_handle_t  wallet_handle,
+                                                      const char*    type_,
+                                                      const char*    id,
+                                                      void           (*fn)(const void*    context,
+                                                                           indy_error_t err)
+                                                     );
+
+    /// Insert new or update existing non-secret records in the wallet.
+    ///
+    /// All records are applied in a single storage transaction: either all records are stored
+    /// or none of them in case of failure (if wallet storage supports transactions).
+    /// Value and tags of existing records are replaced.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context
+    /// wallet_handle: wallet handle (created by open_wallet)
+    /// type_: allows to separate different record types collections
+    /// records_json: list of records to store as json:
+    ///   [{
+    ///     "id": <str>, // the id of record
+    ///     "value": <str>, // the value of record
+    ///     "tags": <tags json>, // (optional) the record tags (see indy_add_wallet_record)
+    ///   }]
+    /// #Returns
+    /// statuses_json: status of each record in the same order as records passed:
+    ///   [{
+    ///     "id": <str>, // the id of record
+    ///     "status": <str>, // "inserted" for new record or "updated" for existing one
+    ///   }]
+
+    extern indy_error_t indy_upsert_wallet_records(indy_handle_t  command_handle,
+                                                   indy_handle_t  wallet_handle,
+                                                   const char*    type_,
+                                                   const char*    records_json,
+                                                   void           (*fn)(indy_handle_t command_handle_,
+                                                                        indy_error_t  err,
+                                                                        const char*   statuses_json)
+                                                  );
+
+    /// Variant of `indy_upsert_wallet_records` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_upsert_wallet_records_ctx(const void*    context,
+                                                       indy_handle_t  wallet_handle,
+                                                       const char*    type_,
+                                                       const char*    records_json,
+                                                       void           (*fn)(const void*    context,
+                                                                            indy_error_t  err,
+                                                                            const char*   statuses_json)
+                                                      );
+
     /// Get an wallet record by id
     ///
     /// #Params
@@ -176,6 +380,99 @@ extern "C" {
                                                                     const char*   record_json)
                                               );
 
+    /// Variant of `indy_get_wallet_record` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_get_wallet_record_ctx(const void*    context,
+                                                   indy_handle_t  wallet_handle,
+                                                   const char*    type_,
+                                                   const char*    id,
+                                                   const char*    options_json,
+                                                   void           (*fn)(const void*    context,
+                                                                        indy_error_t  err,
+                                                                        const char*   record_json)
+                                                  );
+
+    /// Get all record types present in the wallet with count of records of each type.
+    ///
+    /// Allows migration and cleanup tools to discover what the wallet actually contains.
+    /// Note that types of records created by libindy itself (prefixed with "Indy::") are listed too.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context
+    /// wallet_handle: wallet handle (created by open_wallet)
+    ///
+    /// #Returns
+    /// types_json: record types with count of records as json:
+    ///   {
+    ///     "type1": <int>, // count of records of type1
+    ///     ...
+    ///   }
+
+    extern indy_error_t indy_get_wallet_record_types(indy_handle_t  command_handle,
+                                                     indy_handle_t  wallet_handle,
+                                                     void           (*fn)(indy_handle_t command_handle_,
+                                                                          indy_error_t  err,
+                                                                          const char*   types_json)
+                                                    );
+
+    /// Variant of `indy_get_wallet_record_types` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_get_wallet_record_types_ctx(const void*    context,
+                                                         indy_handle_t  wallet_handle,
+                                                         void           (*fn)(const void*    context,
+                                                                              indy_error_t  err,
+                                                                              const char*   types_json)
+                                                        );
+
+    /// Explain how wallet search with the query is executed by the storage.
+    ///
+    /// Diagnostic call that helps to understand why some searches on large wallets are slow.
+    /// Records are only counted, so types of records created by libindy (prefixed with "Indy::") can be explained too.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context
+    /// wallet_handle: wallet handle (created by open_wallet)
+    /// type_: allows to separate different record types collections
+    /// query_json: MongoDB style query to wallet record tags (see indy_open_wallet_search)
+    ///
+    /// #Returns
+    /// plan_json: search plan as json:
+    ///   {
+    ///     "query": <wql>, // optimised query that is actually executed
+    ///     "tags": [{ // tags used by the query
+    ///         "name": string,
+    ///         "kind": "encrypted" | "plaintext" | "range",
+    ///         "operators": [string], // for example "$eq", "$like"
+    ///         "indexed": bool, // whether all conditions on the tag can use tag index
+    ///     }],
+    ///     "usesIndex": bool, // false if all records of the type are checked, for example for $neq, $not
+    ///                        // or $like patterns starting with a wildcard
+    ///     "recordsOfType": <int>, // count of records of the type
+    ///     "estimatedScan": <int>, // estimated count of records checked to execute the query
+    ///     "storagePlan": [string], // (optional) plan reported by storage, `EXPLAIN QUERY PLAN` details for default storage
+    ///   }
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+
+    extern indy_error_t indy_explain_wallet_search(indy_handle_t  command_handle,
+                                                   indy_handle_t  wallet_handle,
+                                                   const char*    type_,
+                                                   const char*    query_json,
+                                                   void           (*fn)(indy_handle_t command_handle_,
+                                                                        indy_error_t  err,
+                                                                        const char*   plan_json)
+                                                  );
+
+    /// Variant of `indy_explain_wallet_search` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_explain_wallet_search_ctx(const void*    context,
+                                                       indy_handle_t  wallet_handle,
+                                                       const char*    type_,
+                                                       const char*    query_json,
+                                                       void           (*fn)(const void*    context,
+                                                                            indy_error_t  err,
+                                                                            const char*   plan_json)
+                                                      );
+
     /// Search for wallet records.
     ///
     /// Note instead of immediately returning of fetched records
@@ -200,6 +497,7 @@ extern "C" {
     ///    retrieveType: (optional, false by default) Retrieve record type,
     ///    retrieveValue: (optional, true by default) Retrieve record value,
     ///    retrieveTags: (optional, false by default) Retrieve record tags,
+    ///      list of tag names can be passed instead of flag to retrieve only these tags: ["tagName1", "~tagName2"]
     ///  }
     /// #Returns
     /// search_handle: Wallet search handle that can be used later
@@ -215,6 +513,17 @@ extern "C" {
                                                                      indy_handle_t search_handle)
                                                );
 
+    /// Variant of `indy_open_wallet_search` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_open_wallet_search_ctx(const void*    context,
+                                                    indy_handle_t  wallet_handle,
+                                                    const char*    type_,
+                                                    const char*    query_json,
+                                                    const char*    options_json,
+                                                    void           (*fn)(const void*    context,
+                                                                         indy_error_t err,
+                                                                         indy_handle_t search_handle)
+                                                   );
+
     /// Get an wallet record by id
     ///
     /// #Params
@@ -246,6 +555,16 @@ extern "C" {
                                                                                    const char*   records_json)
                                                              );
 
+    /// Variant of `indy_fetch_wallet_search_next_records` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_fetch_wallet_search_next_records_ctx(const void*    context,
+                                                                  indy_handle_t  wallet_handle,
+                                                                  indy_handle_t  wallet_search_handle,
+                                                                  indy_u32_t   count,
+                                                                  void           (*fn)(const void*    context,
+                                                                                       indy_error_t  err,
+                                                                                       const char*   records_json)
+                                                                 );
+
     /// Close wallet search (make search handle invalid)
     ///
     /// #Params
@@ -257,6 +576,190 @@ extern "C" {
                                                                       indy_error_t  err)
                                                 );
 
+    /// Variant of `indy_close_wallet_search` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_close_wallet_search_ctx(const void*    context,
+                                                     indy_handle_t  wallet_search_handle,
+                                                     void           (*fn)(const void*    context,
+                                                                          indy_error_t  err)
+                                                    );
+
+    /// Streams wallet records matching the query.
+    ///
+    /// Records are fetched by chunks of `chunk_size` records and every chunk is passed to `records_cb`
+    /// as soon as it's fetched, so the result of the search doesn't have to fit into memory at once.
+    /// `cb` is called once all the records are streamed or fetching is failed.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// wallet_handle: wallet handle (created by open_wallet)
+    /// type_: allows to separate different record types collections
+    /// query_json: MongoDB style query to wallet record tags (see indy_open_wallet_search)
+    /// options_json: search options (see indy_open_wallet_search)
+    /// chunk_size: maximum count of records passed to `records_cb` at once
+    /// records_cb: Callback that takes a chunk of records in the format of indy_fetch_wallet_search_next_records result.
+    ///     Chunks without records are not passed.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error code
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    extern indy_error_t indy_stream_wallet_records(indy_handle_t  command_handle,
+                                                   indy_handle_t  wallet_handle,
+                                                   const char*    type_,
+                                                   const char*    query_json,
+                                                   const char*    options_json,
+                                                   indy_u32_t     chunk_size,
+
+                                                   void           (*records_cb)(indy_handle_t command_handle_,
+                                                                                const char*   records_json),
+
+                                                   void           (*cb)(indy_handle_t command_handle_,
+                                                                        indy_error_t  err)
+                                                  );
+
+    /// Variant of `indy_stream_wallet_records` that passes application `context` to the callbacks instead of command handle.
+    extern indy_error_t indy_stream_wallet_records_ctx(const void*    context,
+                                                       indy_handle_t  wallet_handle,
+                                                       const char*    type_,
+                                                       const char*    query_json,
+                                                       const char*    options_json,
+                                                       indy_u32_t     chunk_size,
+
+                                                       void           (*records_cb)(const void*   context,
+                                                                                    const char*   records_json),
+
+                                                       void           (*cb)(const void*    context,
+                                                                            indy_error_t  err)
+                                                      );
+
+
+    extern indy_error_t indy_enable_wallet_record_history(indy_handle_t  command_handle,
+                                                          indy_handle_t  wallet_handle,
+                                                          const char*    type_,
+                                                          const char*    config_json,
+                                                          void           (*cb)(indy_handle_t command_handle_,
+                                                                               indy_error_t  err)
+                                                         );
+
+    /// Variant of `indy_enable_wallet_record_history` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_enable_wallet_record_history_ctx(const void*    context,
+                                                              indy_handle_t  wallet_handle,
+                                                              const char*    type_,
+                                                              const char*    config_json,
+                                                              void           (*cb)(const void*    context,
+                                                                                   indy_error_t  err)
+                                                             );
+
+    /// Disable revision history of wallet records of the type.
+    /// Already saved revisions are kept until pruned with indy_prune_wallet_record_history.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context
+    /// wallet_handle: wallet handle (created by open_wallet)
+    /// type_: record type
+    extern indy_error_t indy_disable_wallet_record_history(indy_handle_t  command_handle,
+                                                           indy_handle_t  wallet_handle,
+                                                           const char*    type_,
+                                                           void           (*cb)(indy_handle_t command_handle_,
+                                                                                indy_error_t  err)
+                                                          );
+
+    /// Variant of `indy_disable_wallet_record_history` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_disable_wallet_record_history_ctx(const void*    context,
+                                                               indy_handle_t  wallet_handle,
+                                                               const char*    type_,
+                                                               void           (*cb)(const void*    context,
+                                                                                    indy_error_t  err)
+                                                              );
+
+    /// Get the list of saved revisions of wallet record (see indy_enable_wallet_record_history).
+    /// Revisions of deleted record are available too.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context
+    /// wallet_handle: wallet handle (created by open_wallet)
+    /// type_: record type
+    /// id: the id of record
+    ///
+    /// #Returns
+    /// revisions_json: revisions from the oldest to the latest one:
+    ///   [{
+    ///     "revision": <int>, // number of revision. Numbers start from 1 and are never reused
+    ///     "replacedAt": <int>, // time (in seconds since epoch) when the state was replaced by update or deletion
+    ///   }]
+    extern indy_error_t indy_get_wallet_record_revisions(indy_handle_t  command_handle,
+                                                         indy_handle_t  wallet_handle,
+                                                         const char*    type_,
+                                                         const char*    id,
+                                                         void           (*cb)(indy_handle_t command_handle_,
+                                                                              indy_error_t  err,
+                                                                              const char*   revisions_json)
+                                                        );
+
+    /// Variant of `indy_get_wallet_record_revisions` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_get_wallet_record_revisions_ctx(const void*    context,
+                                                             indy_handle_t  wallet_handle,
+                                                             const char*    type_,
+                                                             const char*    id,
+                                                             void           (*cb)(const void*    context,
+                                                                                  indy_error_t  err,
+                                                                                  const char*   revisions_json)
+                                                            );
+
+    ///
+    /// #Errors
+    /// WalletItemNotFound - revision doesn't exist or was pruned
+    extern indy_error_t indy_get_wallet_record_revision(indy_handle_t  command_handle,
+                                                        indy_handle_t  wallet_handle,
+                                                        const char*    type_,
+                                                        const char*    id,
+                                                        indy_u32_t     revision,
+                                                        void           (*cb)(indy_handle_t command_handle_,
+                                                                             indy_error_t  err,
+                                                                             const char*   revision_json)
+                                                       );
+
+    /// Variant of `indy_get_wallet_record_revision` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_get_wallet_record_revision_ctx(const void*    context,
+                                                            indy_handle_t  wallet_handle,
+                                                            const char*    type_,
+                                                            const char*    id,
+                                                            indy_u32_t     revision,
+                                                            void           (*cb)(const void*    context,
+                                                                                 indy_error_t  err,
+                                                                                 const char*   revision_json)
+                                                           );
+
+    /// Delete saved revisions of wallet record except the latest ones.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context
+    /// wallet_handle: wallet handle (created by open_wallet)
+    /// type_: record type
+    /// id: the id of record
+    /// keep_last: count of the latest revisions to keep. 0 means all revisions are deleted
+    extern indy_error_t indy_prune_wallet_record_history(indy_handle_t  command_handle,
+                                                         indy_handle_t  wallet_handle,
+                                                         const char*    type_,
+                                                         const char*    id,
+                                                         indy_u32_t     keep_last,
+                                                         void           (*cb)(indy_handle_t command_handle_,
+                                                                              indy_error_t  err)
+                                                        );
+
+    /// Variant of `indy_prune_wallet_record_history` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_prune_wallet_record_history_ctx(const void*    context,
+                                                             indy_handle_t  wallet_handle,
+                                                             const char*    type_,
+                                                             const char*    id,
+                                                             indy_u32_t     keep_last,
+                                                             void           (*cb)(const void*    context,
+                                                                                  indy_error_t  err)
+                                                            );
+
 
 #ifdef __cplusplus
 }
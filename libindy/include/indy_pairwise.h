@@ -32,6 +32,16 @@ extern "C" {
                                                                     indy_bool_t   exists)
                                                );
 
+    /// Variant of `indy_is_pairwise_exists` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_is_pairwise_exists_ctx(const void*    context,
+                                                    indy_handle_t wallet_handle,
+                                                    const char *  their_did,
+
+                                                    void          (*cb)(const void*    context,
+                                                                        indy_error_t  err,
+                                                                        indy_bool_t   exists)
+                                                   );
+
 
     /// Creates pairwise.
     ///
@@ -43,6 +53,8 @@ extern "C" {
     /// metadata Optional: extra information for pairwise
     /// cb: Callback that takes command result as parameter.
     ///
+    /// Creation time of the pairwise is stored in its structured meta (see "indy_set_pairwise_meta").
+    ///
     /// #Returns
     /// Error code
     ///
@@ -60,9 +72,23 @@ extern "C" {
                                                                  indy_error_t   err)
                                             );
 
+    /// Variant of `indy_create_pairwise` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_create_pairwise_ctx(const void*    context,
+                                                 indy_handle_t wallet_handle,
+                                                 const char *  their_did,
+                                                 const char *  my_did,
+                                                 const char *  metadata,
+
+                                                 void          (*cb)(const void*    context,
+                                                                     indy_error_t   err)
+                                                );
+
 
     /// Get list of saved pairwise.
     ///
+    /// NOTE: This method immediately returns all pairwise stored in the wallet.
+    /// Use <indy_open_pairwise_search> to filter pairwise and fetch them by small batches.
+    ///
     /// #Params
     /// wallet_handle: wallet handler (created by open_wallet).
     /// command_handle: command handle to map callback to user context.
@@ -83,6 +109,15 @@ extern "C" {
                                                                const char*    list_pairwise)
                                           );
 
+    /// Variant of `indy_list_pairwise` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_list_pairwise_ctx(const void*    context,
+                                               indy_handle_t wallet_handle,
+
+                                               void          (*cb)(const void*    context,
+                                                                   indy_error_t   err,
+                                                                   const char*    list_pairwise)
+                                              );
+
 
     /// Gets pairwise information for specific their_did.
     ///
@@ -108,6 +143,16 @@ extern "C" {
                                                               const char*    pairwise_info_json)
                                          );
 
+    /// Variant of `indy_get_pairwise` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_get_pairwise_ctx(const void*    context,
+                                              indy_handle_t wallet_handle,
+                                              const char *  their_did,
+
+                                              void          (*cb)(const void*    context,
+                                                                  indy_error_t   err,
+                                                                  const char*    pairwise_info_json)
+                                             );
+
 
     /// Save some data in the Wallet for pairwise associated with Did.
     ///
@@ -134,6 +179,430 @@ extern "C" {
                                                                        indy_error_t   err)
                                                   );
 
+    /// Variant of `indy_set_pairwise_metadata` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_set_pairwise_metadata_ctx(const void*    context,
+                                                       indy_handle_t wallet_handle,
+                                                       const char *  their_did,
+                                                       const char *  metadata,
+
+                                                       void          (*cb)(const void*    context,
+                                                                           indy_error_t   err)
+                                                      );
+
+
+    /// Updates their DID of pairwise, for example after rotation of DID by the other party.
+    /// Metadata and structured meta of the pairwise are kept.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// wallet_handle: wallet handler (created by open_wallet).
+    /// their_did: current their DID of the pairwise
+    /// new_their_did: new their DID of the pairwise (must be already stored with indy_store_their_did)
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error code
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    extern indy_error_t indy_update_pairwise(indy_handle_t command_handle,
+                                             indy_handle_t wallet_handle,
+                                             const char *  their_did,
+                                             const char *  new_their_did,
+
+                                             void          (*cb)(indy_handle_t  command_handle_,
+                                                                 indy_error_t   err)
+                                            );
+
+    /// Variant of `indy_update_pairwise` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_update_pairwise_ctx(const void*    context,
+                                                 indy_handle_t wallet_handle,
+                                                 const char *  their_did,
+                                                 const char *  new_their_did,
+
+                                                 void          (*cb)(const void*    context,
+                                                                     indy_error_t   err)
+                                                );
+
+
+    /// Deletes pairwise. Their DID and my DID of the pairwise are kept in the wallet.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// wallet_handle: wallet handler (created by open_wallet).
+    /// their_did: their DID of the pairwise
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error code
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    extern indy_error_t indy_delete_pairwise(indy_handle_t command_handle,
+                                             indy_handle_t wallet_handle,
+                                             const char *  their_did,
+
+                                             void          (*cb)(indy_handle_t  command_handle_,
+                                                                 indy_error_t   err)
+                                            );
+
+    /// Variant of `indy_delete_pairwise` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_delete_pairwise_ctx(const void*    context,
+                                                 indy_handle_t wallet_handle,
+                                                 const char *  their_did,
+
+                                                 void          (*cb)(const void*    context,
+                                                                     indy_error_t   err)
+                                                );
+
+    /// Links wallet record (credential, non-secret item, etc.) to pairwise.
+    /// Linked record gets managed tag "pairwise:<their_did>" with value "1",
+    /// so records linked to pairwise can be also found with the search of records of given type.
+    /// Links are moved by indy_update_pairwise and removed by indy_delete_pairwise.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// wallet_handle: wallet handler (created by open_wallet).
+    /// their_did: their DID of the pairwise
+    /// record_type: type of wallet record to link, for example "Indy::Credential" or type of non-secret record
+    /// record_id: id of wallet record to link
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error code
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    extern indy_error_t indy_link_pairwise_record(indy_handle_t command_handle,
+                                                  indy_handle_t wallet_handle,
+                                                  const char *  their_did,
+                                                  const char *  record_type,
+                                                  const char *  record_id,
+
+                                                  void          (*cb)(indy_handle_t  command_handle_,
+                                                                      indy_error_t   err)
+                                                 );
+
+    /// Variant of `indy_link_pairwise_record` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_link_pairwise_record_ctx(const void*    context,
+                                                      indy_handle_t wallet_handle,
+                                                      const char *  their_did,
+                                                      const char *  record_type,
+                                                      const char *  record_id,
+
+                                                      void          (*cb)(const void*    context,
+                                                                          indy_error_t   err)
+                                                     );
+
+
+    /// Unlinks wallet record from pairwise. Managed tag is removed from the record if it still exists.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// wallet_handle: wallet handler (created by open_wallet).
+    /// their_did: their DID of the pairwise
+    /// record_type: type of linked wallet record
+    /// record_id: id of linked wallet record
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error code
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    extern indy_error_t indy_unlink_pairwise_record(indy_handle_t command_handle,
+                                                    indy_handle_t wallet_handle,
+                                                    const char *  their_did,
+                                                    const char *  record_type,
+                                                    const char *  record_id,
+
+                                                    void          (*cb)(indy_handle_t  command_handle_,
+                                                                        indy_error_t   err)
+                                                   );
+
+    /// Variant of `indy_unlink_pairwise_record` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_unlink_pairwise_record_ctx(const void*    context,
+                                                        indy_handle_t wallet_handle,
+                                                        const char *  their_did,
+                                                        const char *  record_type,
+                                                        const char *  record_id,
+
+                                                        void          (*cb)(const void*    context,
+                                                                            indy_error_t   err)
+                                                       );
+
+
+    /// Get wallet records linked to pairwise with indy_link_pairwise_record.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// wallet_handle: wallet handler (created by open_wallet).
+    /// their_did: their DID of the pairwise
+    /// record_type: (optional) return only linked records of this type
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// links_json: list of linked records
+    ///   [{
+    ///     "type": string - type of linked wallet record,
+    ///     "id": string - id of linked wallet record
+    ///   }]
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    extern indy_error_t indy_get_pairwise_links(indy_handle_t command_handle,
+                                                indy_handle_t wallet_handle,
+                                                const char *  their_did,
+                                                const char *  record_type,
+
+                                                void          (*cb)(indy_handle_t  command_handle_,
+                                                                    indy_error_t   err,
+                                                                    const char*    links_json)
+                                               );
+
+    /// Variant of `indy_get_pairwise_links` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_get_pairwise_links_ctx(const void*    context,
+                                                    indy_handle_t wallet_handle,
+                                                    const char *  their_did,
+                                                    const char *  record_type,
+
+                                                    void          (*cb)(const void*    context,
+                                                                        indy_error_t   err,
+                                                                        const char*    links_json)
+                                                   );
+
+
+    /// Sets structured meta of pairwise: label and state.
+    /// Unlike the metadata, structured meta is stored as tags of pairwise record,
+    /// so pairwise can be filtered by it with "indy_open_pairwise_search".
+    /// Once structured meta is set, "label" and "state" tags no longer come from the metadata fields with these names.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// wallet_handle: wallet handler (created by open_wallet).
+    /// their_did: encoded Did
+    /// meta_json: structured meta to set:
+    ///   {
+    ///     "label": (optional) string - human readable label of the pairwise,
+    ///     "state": (optional) string - state of the connection, for example "invited" or "active"
+    ///   }
+    ///   Omitted fields are left unchanged.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error code
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    extern indy_error_t indy_set_pairwise_meta(indy_handle_t command_handle,
+                                               indy_handle_t wallet_handle,
+                                               const char *  their_did,
+                                               const char *  meta_json,
+
+                                               void          (*cb)(indy_handle_t  command_handle_,
+                                                                   indy_error_t   err)
+                                              );
+
+    /// Variant of `indy_set_pairwise_meta` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_set_pairwise_meta_ctx(const void*    context,
+                                                   indy_handle_t wallet_handle,
+                                                   const char *  their_did,
+                                                   const char *  meta_json,
+
+                                                   void          (*cb)(const void*    context,
+                                                                       indy_error_t   err)
+                                                  );
+
+
+    /// Migrates pairwise stored in the wallet by older versions of libindy to structured meta.
+    ///     - missed tags are added to pairwise records.
+    ///     - "label" and "state" fields of JSON metadata are copied to structured meta if it isn't set yet.
+    /// Metadata itself is left unchanged. Creation time of migrated pairwise is unknown.
+    ///
+    /// The call is idempotent and should be done once after the upgrade, before pairwise are searched.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// wallet_handle: wallet handler (created by open_wallet).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error code
+    /// cb:
+    /// - report_json: migration report json:
+    /// {
+    ///     total: int, - number of pairwise in the wallet
+    ///     migrated: int, - number of updated pairwise
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    extern indy_error_t indy_migrate_pairwise_meta(indy_handle_t command_handle,
+                                                   indy_handle_t wallet_handle,
+
+                                                   void          (*cb)(indy_handle_t  command_handle_,
+                                                                       indy_error_t   err,
+                                                                       const char*    report_json)
+                                                  );
+
+    /// Variant of `indy_migrate_pairwise_meta` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_migrate_pairwise_meta_ctx(const void*    context,
+                                                       indy_handle_t wallet_handle,
+
+                                                       void          (*cb)(const void*    context,
+                                                                           indy_error_t   err,
+                                                                           const char*    report_json)
+                                                      );
+
+
+    /// Search for pairwise stored in the wallet.
+    ///
+    /// Pairwise are tagged with:
+    ///     - "my_did"
+    ///     - "label", "state" and "~created_at" of structured meta (see "indy_set_pairwise_meta")
+    ///     - all other top level string fields of the metadata if it is a JSON object
+    ///       (all of them, including "label" and "state", until structured meta is set)
+    ///       (fields with "~" prefix are stored un-encrypted).
+    /// Tags are updated on each "indy_set_pairwise_metadata" and "indy_set_pairwise_meta" call.
+    /// Note that "~created_at" is un-encrypted tag, so pairwise can be filtered by creation time
+    /// with "$gt", "$gte", "$lt" and "$lte" operators.
+    ///
+    /// Instead of immediately returning of all pairwise (as "indy_list_pairwise" does)
+    /// this call returns search_handle that can be used later
+    /// to fetch pairwise by small batches (with indy_fetch_pairwise).
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// query_json: (Optional) Wql query filter for pairwise searching based on tags.
+    ///     where query: indy-sdk/docs/design/011-wallet-query-language/README.md
+    /// options_json: (Optional) Search options:
+    ///   {
+    ///     "retrieveVerkeys": (optional, false by default) Retrieve verkeys of my and their DIDs
+    ///   }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: Command handle to map callback to caller context.
+    /// - err: Error code.
+    /// - search_handle: Search handle that can be used later to fetch pairwise by small batches (with indy_fetch_pairwise)
+    /// - total_count: Total count of pairwise matching the query
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    extern indy_error_t indy_open_pairwise_search(indy_handle_t command_handle,
+                                                  indy_handle_t wallet_handle,
+                                                  const char *  query_json,
+                                                  const char *  options_json,
+
+                                                  void          (*cb)(indy_handle_t  command_handle_,
+                                                                      indy_error_t   err,
+                                                                      indy_handle_t  search_handle,
+                                                                      indy_u32_t     total_count)
+                                                 );
+
+    /// Variant of `indy_open_pairwise_search` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_open_pairwise_search_ctx(const void*    context,
+                                                      indy_handle_t wallet_handle,
+                                                      const char *  query_json,
+                                                      const char *  options_json,
+
+                                                      void          (*cb)(const void*    context,
+                                                                          indy_error_t   err,
+                                                                          indy_handle_t  search_handle,
+                                                                          indy_u32_t     total_count)
+                                                     );
+
+
+    /// Fetch next pairwise for search.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// search_handle: Search handle (created by indy_open_pairwise_search)
+    /// count: Count of pairwise to fetch
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: Command handle to map callback to caller context.
+    /// - err: Error code.
+    ///   list_pairwise:  [{
+    ///     "my_did": string - my DID of the pairwise,
+    ///     "their_did": string - their DID of the pairwise,
+    ///     "metadata": (optional) string - the meta information stored with the pairwise,
+    ///     "my_verkey": (optional) string - verkey of my DID (only if retrieveVerkeys option is true),
+    ///     "their_verkey": (optional) string - verkey of their DID (only if retrieveVerkeys option is true),
+    ///     "label": (optional) string - label of the pairwise (only if structured meta is set),
+    ///     "state": (optional) string - state of the pairwise (only if structured meta is set),
+    ///     "created_at": (optional) int - creation time of the pairwise (only if structured meta is set)
+    ///   }]
+    /// NOTE: The list of length less than the requested count means pairwise search iterator is completed.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    extern indy_error_t indy_fetch_pairwise(indy_handle_t command_handle,
+                                            indy_handle_t search_handle,
+                                            indy_u32_t    count,
+
+                                            void          (*cb)(indy_handle_t  command_handle_,
+                                                                indy_error_t   err,
+                                                                const char*    list_pairwise)
+                                           );
+
+    /// Variant of `indy_fetch_pairwise` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_fetch_pairwise_ctx(const void*    context,
+                                                indy_handle_t search_handle,
+                                                indy_u32_t    count,
+
+                                                void          (*cb)(const void*    context,
+                                                                    indy_error_t   err,
+                                                                    const char*    list_pairwise)
+                                               );
+
+
+    /// Close pairwise search (make search handle invalid)
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// search_handle: Search handle (created by indy_open_pairwise_search)
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - command_handle_: Command handle to map callback to caller context.
+    /// - err: Error code.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    extern indy_error_t indy_close_pairwise_search(indy_handle_t command_handle,
+                                                   indy_handle_t search_handle,
+
+                                                   void          (*cb)(indy_handle_t  command_handle_,
+                                                                       indy_error_t   err)
+                                                  );
+
+    /// Variant of `indy_close_pairwise_search` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_close_pairwise_search_ctx(const void*    context,
+                                                       indy_handle_t search_handle,
+
+                                                       void          (*cb)(const void*    context,
+                                                                           indy_error_t   err)
+                                                      );
+
 
 #ifdef __cplusplus
 }
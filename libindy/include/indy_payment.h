@@ -8,6 +8,62 @@
 extern "C" {
 #endif
 
+    /// Register built-in "null" payment method.
+    ///
+    /// The method works against in-memory ledger and is intended for testing only.
+    /// Requests built by the method are applied to the in-memory ledger immediately and
+    /// can be passed to the corresponding parse functions instead of ledger replies.
+    /// Available only if libindy is built with "null_payment_method" feature.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    ///
+    /// #Returns
+    /// Error code
+
+    extern indy_error_t indy_register_null_payment_method(indy_handle_t command_handle,
+
+                                                          void           (*cb)(indy_handle_t command_handle_,
+                                                                               indy_error_t  err)
+                                                         );
+
+    /// Variant of `indy_register_null_payment_method` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_register_null_payment_method_ctx(const void*    context,
+
+                                                              void           (*cb)(const void*    context,
+                                                                                   indy_error_t  err)
+                                                             );
+
+    /// Unregister payment method, so its library can be unloaded or registered again.
+    /// Payment method can't be unregistered while it has calls in progress.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// payment_method: The type of registered payment method
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error code
+    ///
+    /// #Errors
+    /// Common*
+    /// Payment*
+
+    extern indy_error_t indy_unregister_payment_method(indy_handle_t command_handle,
+                                                       const char*    payment_method,
+
+                                                       void           (*cb)(indy_handle_t command_handle_,
+                                                                            indy_error_t  err)
+                                                      );
+
+    /// Variant of `indy_unregister_payment_method` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_unregister_payment_method_ctx(const void*    context,
+                                                           const char*    payment_method,
+
+                                                           void           (*cb)(const void*    context,
+                                                                                indy_error_t  err)
+                                                          );
+
     /// Create the payment address for specified payment method
     ///
     ///
@@ -40,6 +96,17 @@ extern "C" {
                                                                          const char*   payment_address)
                                                     );
 
+    /// Variant of `indy_create_payment_address` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_create_payment_address_ctx(const void*    context,
+                                                        indy_handle_t wallet_handle,
+                                                        const char *  payment_method,
+                                                        const char *  config,
+
+                                                        void           (*cb)(const void*    context,
+                                                                             indy_error_t  err,
+                                                                             const char*   payment_address)
+                                                        );
+
     /// Lists all payment addresses that are stored in the wallet
     ///
     /// #Params
@@ -57,6 +124,60 @@ extern "C" {
                                                                          const char*   payment_addresses_json)
                                                     );
 
+    /// Variant of `indy_list_payment_addresses` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_list_payment_addresses_ctx(const void*    context,
+                                                        indy_handle_t wallet_handle,
+
+                                                        void           (*cb)(const void*    context,
+                                                                             indy_error_t  err,
+                                                                             const char*   payment_addresses_json)
+                                                        );
+
+    /// Fetches sources of all payment addresses that are stored in the wallet and
+    /// returns consolidated balances summary.
+    ///
+    /// Sources of the addresses are requested from the ledger concurrently (all pages of sources are fetched).
+    /// A failure for some address doesn't fail whole operation but is reported in the address entry.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to context
+    /// wallet_handle: wallet to search for payment_addresses in
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    ///
+    /// #Returns
+    /// balances_json - consolidated balances as json:
+    ///   {
+    ///     balances: { // total balance of successfully fetched addresses per payment method
+    ///         <str>: <int>,
+    ///     },
+    ///     addresses: [{
+    ///         address: <str>, // payment address
+    ///         paymentMethod: <str>, // (Optional) payment method of the address
+    ///         balance: <int>, // sum of the address sources amounts
+    ///         sources: [{source: <str>, paymentAddress: <str>, amount: <int>, extra: <str>}],
+    ///         error: <str>, // (Optional) reason why sources of the address can't be fetched
+    ///     }]
+    ///   }
+
+    extern indy_error_t indy_get_payment_balances(indy_handle_t command_handle,
+                                                  indy_handle_t wallet_handle,
+                                                  indy_handle_t pool_handle,
+
+                                                  void           (*cb)(indy_handle_t command_handle_,
+                                                                       indy_error_t  err,
+                                                                       const char*   balances_json)
+                                                  );
+
+    /// Variant of `indy_get_payment_balances` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_get_payment_balances_ctx(const void*    context,
+                                                      indy_handle_t wallet_handle,
+                                                      indy_handle_t pool_handle,
+
+                                                      void           (*cb)(const void*    context,
+                                                                           indy_error_t  err,
+                                                                           const char*   balances_json)
+                                                      );
+
     /// Modifies Indy request by adding information how to pay fees for this transaction
     /// according to this payment method.
     ///
@@ -102,6 +223,111 @@ extern "C" {
                                                                    const char*   payment_method)
                                               );
 
+    /// Variant of `indy_add_request_fees` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_add_request_fees_ctx(const void*    context,
+                                                  indy_handle_t wallet_handle,
+                                                  const char *  submitter_did,
+                                                  const char *  req_json,
+                                                  const char *  inputs_json,
+                                                  const char *  outputs_json,
+                                                  const char *  extra,
+
+                                                  void           (*cb)(const void*    context,
+                                                                       indy_error_t  err,
+                                                                       const char*   req_with_fees_json,
+                                                                       const char*   payment_method)
+                                                  );
+
+    /// Prepares Indy write request for sending with fees in one call.
+    ///
+    /// This method performs the following steps in the proper order:
+    ///  - appends TAA acceptance data to the request (if acceptance mechanism is passed)
+    ///  - fetches fees set on the ledger using the payment method determined from inputs
+    ///  - adds fees information to the request (see `indy_add_request_fees`)
+    ///  - multi signs the request by submitter DID
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: wallet handle
+    /// pool_handle: pool handle (created by open_pool_ledger) used to fetch fees.
+    /// submitter_did: DID of request sender
+    /// req_json: initial transaction request as json
+    /// inputs_json: The list of payment sources as json array:
+    ///   ["source1", ...]
+    ///     - each input should reference paymentAddress
+    ///     - this param will be used to determine payment_method
+    /// outputs_json: The list of outputs as json array:
+    ///   [{
+    ///     recipient: <str>, // payment address of recipient
+    ///     amount: <int>, // amount
+    ///   }]
+    /// extra: // optional information for payment operation
+    /// text and version - (optional) raw data about TAA from ledger.
+    ///     These parameters should be passed together.
+    ///     These parameters are required if taa_digest parameter is omitted.
+    /// taa_digest - (optional) digest on text and version.
+    ///     This parameter is required if text and version parameters are omitted.
+    /// mechanism - (optional) mechanism how user has accepted the TAA. TAA acceptance isn't appended if omitted.
+    /// time - UTC timestamp when user has accepted the TAA
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// req_with_fees_json - Indy request with TAA acceptance and fees signed by submitter DID
+    /// receipt_json - expected payment of the request:
+    ///   {
+    ///     paymentMethod: <str>, // used payment method
+    ///     txnType: <str>, // type of the request transaction
+    ///     fee: <int>, // fee set on the ledger for the transaction type (0 if not set)
+    ///     inputs: [<str>, ...], // consumed payment sources
+    ///     outputs: [{recipient: <str>, amount: <int>}, ...] // outputs of the payment
+    ///   }
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Ledger*
+    /// Payment*
+    extern indy_error_t indy_prepare_request_with_fees(indy_handle_t command_handle,
+                                                       indy_handle_t wallet_handle,
+                                                       indy_handle_t pool_handle,
+                                                       const char *  submitter_did,
+                                                       const char *  req_json,
+                                                       const char *  inputs_json,
+                                                       const char *  outputs_json,
+                                                       const char *  extra,
+                                                       const char *  text,
+                                                       const char *  version,
+                                                       const char *  taa_digest,
+                                                       const char *  mechanism,
+                                                       indy_u64_t    time,
+
+                                                       void           (*cb)(indy_handle_t command_handle_,
+                                                                            indy_error_t  err,
+                                                                            const char*   req_with_fees_json,
+                                                                            const char*   receipt_json)
+                                                       );
+
+    /// Variant of `indy_prepare_request_with_fees` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_prepare_request_with_fees_ctx(const void*    context,
+                                                           indy_handle_t wallet_handle,
+                                                           indy_handle_t pool_handle,
+                                                           const char *  submitter_did,
+                                                           const char *  req_json,
+                                                           const char *  inputs_json,
+                                                           const char *  outputs_json,
+                                                           const char *  extra,
+                                                           const char *  text,
+                                                           const char *  version,
+                                                           const char *  taa_digest,
+                                                           const char *  mechanism,
+                                                           indy_u64_t    time,
+
+                                                           void           (*cb)(const void*    context,
+                                                                                indy_error_t  err,
+                                                                                const char*   req_with_fees_json,
+                                                                                const char*   receipt_json)
+                                                           );
+
     /// Parses response for Indy request with fees.
     ///
     /// #Params
@@ -128,6 +354,16 @@ extern "C" {
                                                                 const char*   receipts_json)
                                                      );
 
+    /// Variant of `indy_parse_response_with_fees` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_parse_response_with_fees_ctx(const void*    context,
+                                                            const char *  payment_method,
+                                                            const char *  resp_json,
+
+                                                            void           (*cb)(const void*    context,
+                                                                    indy_error_t  err,
+                                                                    const char*   receipts_json)
+                                                         );
+
     /// Builds Indy request for getting sources list for payment address
     /// according to this payment method.
     /// This method is be deprecated and will be removed in Indy SDK 2.0. Use indy_build_get_payment_sources_with_from_request
@@ -153,6 +389,18 @@ extern "C" {
                                                                                     const char*   payment_method)
                                                                );
 
+    /// Variant of `indy_build_get_payment_sources_request` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_get_payment_sources_request_ctx(const void*    context,
+                                                                   indy_handle_t wallet_handle,
+                                                                   const char *  submitter_did,
+                                                                   const char *  payment_address,
+
+                                                                   void           (*cb)(const void*    context,
+                                                                                        indy_error_t  err,
+                                                                                        const char*   get_sources_txn_json,
+                                                                                        const char*   payment_method)
+                                                                   );
+
     /// Builds Indy request for getting sources list for payment address
     /// according to this payment method.
     ///
@@ -179,6 +427,19 @@ extern "C" {
                                                                                               const char*   payment_method)
                                                                          );
 
+    /// Variant of `indy_build_get_payment_sources_with_from_request` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_get_payment_sources_with_from_request_ctx(const void*    context,
+                                                                             indy_handle_t wallet_handle,
+                                                                             const char *  submitter_did,
+                                                                             const char *  payment_address,
+                                                                             indy_u64_t    from,
+
+                                                                             void           (*cb)(const void*    context,
+                                                                                                  indy_error_t  err,
+                                                                                                  const char*   get_sources_txn_json,
+                                                                                                  const char*   payment_method)
+                                                                             );
+
     /// Parses response for Indy request for getting sources list.
     /// This method is be deprecated and will be removed in Indy SDK 2.0. Use indy_parse_get_payment_sources_with_from_response
     ///
@@ -206,6 +467,16 @@ extern "C" {
                                                                                      const char*   sources_json)
                                                                 );
 
+    /// Variant of `indy_parse_get_payment_sources_response` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_parse_get_payment_sources_response_ctx(const void*    context,
+                                                                    const char *  payment_method,
+                                                                    const char *  resp_json,
+
+                                                                    void           (*cb)(const void*    context,
+                                                                                         indy_error_t  err,
+                                                                                         const char*   sources_json)
+                                                                    );
+
     /// Parses response for Indy request for getting sources list.
     ///
     /// #Params
@@ -234,6 +505,98 @@ extern "C" {
                                                                                      indy_i64_t    next)
                                                                 );
 
+    /// Variant of `indy_parse_get_payment_sources_with_from_response` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_parse_get_payment_sources_with_from_response_ctx(const void*    context,
+                                                                    const char *  payment_method,
+                                                                    const char *  resp_json,
+
+                                                                    void           (*cb)(const void*    context,
+                                                                                         indy_error_t  err,
+                                                                                         const char*   sources_json,
+                                                                                         indy_i64_t    next)
+                                                                    );
+
+    /// Builds Indy request for getting transaction history of payment address
+    /// according to specified payment method.
+    ///
+    /// Payment method must have history handlers registered with `indy_register_payment_history_handlers`.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: wallet handle
+    /// submitter_did: (Optional) DID of request sender
+    /// payment_address: target payment address
+    /// from: shift to the next slice of payment history (as returned by `indy_parse_get_payment_history_response`)
+    ///       or -1 to start from the beginning
+    ///
+    /// #Returns
+    /// get_history_txn_json - Indy request for getting transaction history of payment address
+    /// payment_method - used payment method
+
+    extern indy_error_t indy_build_get_payment_history_request(indy_handle_t command_handle,
+                                                               indy_handle_t wallet_handle,
+                                                               const char *  submitter_did,
+                                                               const char *  payment_address,
+                                                               indy_i64_t    from,
+
+                                                               void           (*cb)(indy_handle_t command_handle_,
+                                                                                    indy_error_t  err,
+                                                                                    const char*   get_history_txn_json,
+                                                                                    const char*   payment_method)
+                                                               );
+
+    /// Variant of `indy_build_get_payment_history_request` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_get_payment_history_request_ctx(const void*    context,
+                                                                   indy_handle_t wallet_handle,
+                                                                   const char *  submitter_did,
+                                                                   const char *  payment_address,
+                                                                   indy_i64_t    from,
+
+                                                                   void           (*cb)(const void*    context,
+                                                                                        indy_error_t  err,
+                                                                                        const char*   get_history_txn_json,
+                                                                                        const char*   payment_method)
+                                                                   );
+
+    /// Parses response for Indy request for getting transaction history of payment address.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// payment_method: payment method to use.
+    /// resp_json: response for Indy request for getting transaction history
+    ///
+    /// #Returns
+    /// history_json - parsed (payment method and node version agnostic) transactions as json:
+    ///   [{
+    ///      receipt: <str>, // receipt of the transaction output related to the address
+    ///      txnTime: <int>, // (Optional) time when transaction was written to the ledger
+    ///      sources: [<str>, ] // transaction inputs
+    ///      receipts: [ {recipient: <str>, receipt: <str>, amount: <int>}, ] // transaction outputs
+    ///      extra: <str>, // (Optional) extra data of the transaction
+    ///   }]
+    /// next - shift to the next slice of payment history or -1 if there are no more transactions
+
+    extern indy_error_t indy_parse_get_payment_history_response(indy_handle_t command_handle,
+                                                                const char *  payment_method,
+                                                                const char *  resp_json,
+
+                                                                void           (*cb)(indy_handle_t command_handle_,
+                                                                                     indy_error_t  err,
+                                                                                     const char*   history_json,
+                                                                                     indy_i64_t    next)
+                                                                );
+
+    /// Variant of `indy_parse_get_payment_history_response` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_parse_get_payment_history_response_ctx(const void*    context,
+                                                                    const char *  payment_method,
+                                                                    const char *  resp_json,
+
+                                                                    void           (*cb)(const void*    context,
+                                                                                         indy_error_t  err,
+                                                                                         const char*   history_json,
+                                                                                         indy_i64_t    next)
+                                                                    );
+
     /// Builds Indy request for doing payment
     /// according to this payment method.
     ///
@@ -273,6 +636,20 @@ extern "C" {
                                                                     const char*   payment_method)
                                                );
 
+    /// Variant of `indy_build_payment_req` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_payment_req_ctx(const void*    context,
+                                                   indy_handle_t wallet_handle,
+                                                   const char *  submitter_did,
+                                                   const char *  inputs_json,
+                                                   const char *  outputs_json,
+                                                   const char *  extra,
+
+                                                   void           (*cb)(const void*    context,
+                                                                        indy_error_t  err,
+                                                                        const char*   payment_req_json,
+                                                                        const char*   payment_method)
+                                                   );
+
     /// Parses response for Indy request for payment txn.
     ///
     /// #Params
@@ -299,6 +676,16 @@ extern "C" {
                                                                          const char*   receipts_json)
                                                     );
 
+    /// Variant of `indy_parse_payment_response` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_parse_payment_response_ctx(const void*    context,
+                                                        const char *  payment_method,
+                                                        const char *  resp_json,
+
+                                                        void           (*cb)(const void*    context,
+                                                                             indy_error_t  err,
+                                                                             const char*   receipts_json)
+                                                        );
+
     /// Append payment extra JSON with TAA acceptance data
     ///
     /// EXPERIMENTAL
@@ -336,6 +723,20 @@ extern "C" {
                                                                                              const char*   extra_with_acceptance)
                                                                         );
 
+    /// Variant of `indy_prepare_payment_extra_with_acceptance_data` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_prepare_payment_extra_with_acceptance_data_ctx(const void*    context,
+                                                                            const char *  extra_json,
+                                                                            const char *  text,
+                                                                            const char *  version,
+                                                                            const char *  taa_digest,
+                                                                            const char *  mechanism,
+                                                                            indy_u64_t  time,
+
+                                                                            void           (*cb)(const void*    context,
+                                                                                                 indy_error_t  err,
+                                                                                                 const char*   extra_with_acceptance)
+                                                                            );
+
     /// Builds Indy request for doing minting
     /// according to this payment method.
     ///
@@ -366,6 +767,19 @@ extern "C" {
                                                                  const char*   payment_method)
                                             );
 
+    /// Variant of `indy_build_mint_req` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_mint_req_ctx(const void*    context,
+                                                indy_handle_t wallet_handle,
+                                                const char *  submitter_did,
+                                                const char *  outputs_json,
+                                                const char *  extra,
+
+                                                void           (*cb)(const void*    context,
+                                                                     indy_error_t  err,
+                                                                     const char*   mint_req_json,
+                                                                     const char*   payment_method)
+                                                );
+
     /// Builds Indy request for setting fees for transactions in the ledger
     ///
     /// # Params
@@ -393,6 +807,18 @@ extern "C" {
                                                                          const char*   set_txn_fees_json)
                                                     );
 
+    /// Variant of `indy_build_set_txn_fees_req` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_set_txn_fees_req_ctx(const void*    context,
+                                                        indy_handle_t wallet_handle,
+                                                        const char *  submitter_did,
+                                                        const char *  payment_method,
+                                                        const char *  fees_json,
+
+                                                        void           (*cb)(const void*    context,
+                                                                             indy_error_t  err,
+                                                                             const char*   set_txn_fees_json)
+                                                        );
+
     /// Builds Indy get request for getting fees for transactions in the ledger
     ///
     /// # Params
@@ -414,6 +840,17 @@ extern "C" {
                                                                          const char*   get_txn_fees_json)
                                                     );
 
+    /// Variant of `indy_build_get_txn_fees_req` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_get_txn_fees_req_ctx(const void*    context,
+                                                        indy_handle_t wallet_handle,
+                                                        const char *  submitter_did,
+                                                        const char *  payment_method,
+
+                                                        void           (*cb)(const void*    context,
+                                                                             indy_error_t  err,
+                                                                             const char*   get_txn_fees_json)
+                                                        );
+
     /// Parses response for Indy request for getting fees
     ///
     /// # Params
@@ -438,6 +875,16 @@ extern "C" {
                                                                               const char*   fees_json)
                                                          );
 
+    /// Variant of `indy_parse_get_txn_fees_response` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_parse_get_txn_fees_response_ctx(const void*    context,
+                                                             const char *  payment_method,
+                                                             const char *  resp_json,
+
+                                                             void           (*cb)(const void*    context,
+                                                                                  indy_error_t  err,
+                                                                                  const char*   fees_json)
+                                                             );
+
     /// Builds Indy request for information to verify the payment receipt
     ///
     /// # Params
@@ -461,6 +908,18 @@ extern "C" {
                                                                            const char*   payment_method)
                                                       );
 
+    /// Variant of `indy_build_verify_payment_req` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_build_verify_payment_req_ctx(const void*    context,
+                                                          indy_handle_t wallet_handle,
+                                                          const char *  submitter_did,
+                                                          const char *  receipt,
+
+                                                          void           (*cb)(const void*    context,
+                                                                               indy_error_t  err,
+                                                                               const char*   verify_txn_json,
+                                                                               const char*   payment_method)
+                                                          );
+
     /// Parses Indy response with information to verify receipt
     ///
     /// # Params
@@ -488,6 +947,16 @@ extern "C" {
                                                                                 const char*   txn_json)
                                                            );
 
+    /// Variant of `indy_parse_verify_payment_response` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_parse_verify_payment_response_ctx(const void*    context,
+                                                               const char *  payment_method,
+                                                               const char *  resp_json,
+
+                                                               void           (*cb)(const void*    context,
+                                                                                    indy_error_t  err,
+                                                                                    const char*   txn_json)
+                                                               );
+
     /// Gets request requirements (with minimal price) correspondent to specific auth rule
     /// in case the requester can perform this action.
     ///
@@ -529,6 +998,17 @@ extern "C" {
                                                                    const char*   request_info_json)
                                               );
 
+    /// Variant of `indy_get_request_info` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_get_request_info_ctx(const void*    context,
+                                                  const char *  get_auth_rule_response_json,
+                                                  const char *  requester_info_json,
+                                                  const char *  fees_json,
+
+                                                  void           (*cb)(const void*    context,
+                                                                       indy_error_t  err,
+                                                                       const char*   request_info_json)
+                                                  );
+
 
     /// Signs a message with a payment address.
     ///
@@ -559,6 +1039,19 @@ extern "C" {
                                                                     indy_u32_t       signature_len)
                                               );
 
+    /// Variant of `indy_sign_with_address` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_sign_with_address_ctx(const void*    context,
+                                                   indy_handle_t      wallet_handle,
+                                                   const char *       address,
+                                                   const indy_u8_t *  message_raw,
+                                                   indy_u32_t         message_len,
+
+                                                   void           (*cb)(const void*    context,
+                                                                        indy_error_t     err,
+                                                                        const indy_u8_t* signature_raw,
+                                                                        indy_u32_t       signature_len)
+                                                  );
+
     /// Verify a signature with a payment address.
     ///
     /// #Params
@@ -590,6 +1083,153 @@ extern "C" {
                                                                       indy_bool_t   valid )
                                                 );
 
+    /// Variant of `indy_verify_with_address` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_verify_with_address_ctx(const void*    context,
+                                                     const char *       address,
+                                                     const indy_u8_t *  message_raw,
+                                                     indy_u32_t         message_len,
+                                                     const indy_u8_t *  signature_raw,
+                                                     indy_u32_t         signature_len,
+
+                                                     void           (*cb)(const void*    context,
+                                                                          indy_error_t  err,
+                                                                          indy_bool_t   valid )
+                                                    );
+
+    /// Estimates fees that will be charged for the request.
+    ///
+    /// Payment method is resolved from inputs and must be registered with `indy_register_payment_method_v2`.
+    /// Request is not modified, use `indy_add_request_fees` to add fees to the request.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: wallet handle
+    /// submitter_did: (Optional) DID of request sender
+    /// req_json: initial transaction request as json
+    /// inputs_json: The list of payment sources as json array:
+    ///   ["source1", ...]
+    ///   Note that each source should reference payment address
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// fees_estimate_json: {
+    ///     fee: <int>, // amount that will be charged for the request
+    ///     <str>: <any>, // any additional payment method specific details
+    /// }
+    extern indy_error_t indy_estimate_request_fees(indy_handle_t command_handle,
+                                                   indy_handle_t wallet_handle,
+                                                   const char *  submitter_did,
+                                                   const char *  req_json,
+                                                   const char *  inputs_json,
+
+                                                   void           (*cb)(indy_handle_t command_handle_,
+                                                                        indy_error_t  err,
+                                                                        const char*   fees_estimate_json)
+                                                  );
+
+    /// Variant of `indy_estimate_request_fees` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_estimate_request_fees_ctx(const void*    context,
+                                                       indy_handle_t wallet_handle,
+                                                       const char *  submitter_did,
+                                                       const char *  req_json,
+                                                       const char *  inputs_json,
+
+                                                       void           (*cb)(const void*    context,
+                                                                            indy_error_t  err,
+                                                                            const char*   fees_estimate_json)
+                                                      );
+
+    /// Verifies a batch of payment receipts.
+    ///
+    /// Payment method is resolved from receipts and must be registered with `indy_register_payment_method_v2`.
+    /// All receipts must belong to the same payment method.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: wallet handle
+    /// submitter_did: (Optional) DID of request sender
+    /// receipts_json: The list of payment receipts as json array:
+    ///   ["receipt1", ...]
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// receipts_info_json: [{
+    ///     receipt: <str>, // verified receipt
+    ///     verified: <bool>, // whether receipt is confirmed by the ledger
+    ///     txn: { // (Optional) receipt transaction info
+    ///         sources: [<str>, ]
+    ///         receipts: [ {recipient: <str>, receipt: <str>, amount: <int>}, ]
+    ///         extra: <str>,
+    ///     },
+    ///     error: <str>, // (Optional) reason why receipt isn't verified
+    /// }]
+    extern indy_error_t indy_verify_payment_receipts(indy_handle_t command_handle,
+                                                     indy_handle_t wallet_handle,
+                                                     const char *  submitter_did,
+                                                     const char *  receipts_json,
+
+                                                     void           (*cb)(indy_handle_t command_handle_,
+                                                                          indy_error_t  err,
+                                                                          const char*   receipts_info_json)
+                                                    );
+
+    /// Variant of `indy_verify_payment_receipts` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_verify_payment_receipts_ctx(const void*    context,
+                                                         indy_handle_t wallet_handle,
+                                                         const char *  submitter_did,
+                                                         const char *  receipts_json,
+
+                                                         void           (*cb)(const void*    context,
+                                                                              indy_error_t  err,
+                                                                              const char*   receipts_info_json)
+                                                        );
+
+    /// Verifies that payment receipt is included into the ledger using state proof of the reply.
+    ///
+    /// Payment method is resolved from the receipt and must have a parser registered with
+    /// `indy_register_payment_receipt_proof_parser`. The parser extracts receipt info and state proofs
+    /// from the reply, but state proofs and their multi signatures are checked by libindy against
+    /// BLS keys of the pool nodes, so the reply can be received from a single node.
+    /// Freshness of the reply isn't checked.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// receipt: payment receipt to verify
+    /// resp_json: response for the request built by `indy_build_verify_payment_req`
+    /// bls_keys_json: BLS keys of the pool nodes:
+    ///   {
+    ///     <str>: <str>, // node alias -> base58 encoded BLS key
+    ///   }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// receipt_info_json: receipt transaction info confirmed by state proof
+    ///   {
+    ///     sources: [<str>, ]
+    ///     receipts: [ {recipient: <str>, receipt: <str>, amount: <int>}, ]
+    ///     extra: <str>,
+    ///   }
+    extern indy_error_t indy_verify_payment_receipt_with_proof(indy_handle_t command_handle,
+                                                               const char *  receipt,
+                                                               const char *  resp_json,
+                                                               const char *  bls_keys_json,
+
+                                                               void           (*cb)(indy_handle_t command_handle_,
+                                                                                    indy_error_t  err,
+                                                                                    const char*   receipt_info_json)
+                                                              );
+
+    /// Variant of `indy_verify_payment_receipt_with_proof` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_verify_payment_receipt_with_proof_ctx(const void*    context,
+                                                                   const char *  receipt,
+                                                                   const char *  resp_json,
+                                                                   const char *  bls_keys_json,
+
+                                                                   void           (*cb)(const void*    context,
+                                                                                        indy_error_t  err,
+                                                                                        const char*   receipt_info_json)
+                                                                  );
+
 #ifdef __cplusplus
 }
 #endif
@@ -13,36 +13,99 @@ extern "C" {
                                                        const char *  config,
                                                        void          (*cb)(indy_handle_t command_handle_, indy_error_t err)
                                                        );
+
+    /// Variant of `indy_create_pool_ledger_config` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_create_pool_ledger_config_ctx(const void*    context,
+                                                           const char *  config_name,
+                                                           const char *  config,
+                                                           void          (*cb)(const void*    context, indy_error_t err)
+                                                           );
     
     extern indy_error_t indy_open_pool_ledger(indy_handle_t command_handle,
                                               const char *  config_name,
                                               const char *  config,
                                               void          (*cb)(indy_handle_t command_handle_, indy_error_t err, indy_handle_t pool_handle)
                                               );
+
+    /// Variant of `indy_open_pool_ledger` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_open_pool_ledger_ctx(const void*    context,
+                                                  const char*    config_name,
+                                                  const char*    config,
+                                                  void           (*cb)(const void*    context,
+                                                                       indy_error_t   err,
+                                                                       indy_handle_t  pool_handle)
+                                                 );
     
     extern indy_error_t indy_refresh_pool_ledger(indy_handle_t command_handle,
                                                  indy_handle_t handle,
                                                  void          (*cb)(indy_handle_t command_handle_, indy_error_t err)
                                                  );
 
+    /// Variant of `indy_refresh_pool_ledger` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_refresh_pool_ledger_ctx(const void*    context,
+                                                     indy_handle_t handle,
+                                                     void          (*cb)(const void*    context, indy_error_t err)
+                                                     );
+
     extern indy_error_t indy_list_pools(indy_handle_t command_handle,
                                         void          (*fn)(indy_handle_t command_handle_, indy_error_t err, const char *const pools)
                                         );
+
+    /// Variant of `indy_list_pools` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_list_pools_ctx(const void*    context,
+                                            void          (*fn)(const void*    context, indy_error_t err, const char *const pools)
+                                            );
     
     extern indy_error_t indy_close_pool_ledger(indy_handle_t command_handle,
                                                indy_handle_t handle,
                                                void          (*cb)(indy_handle_t command_handle_, indy_error_t err)
                                                );
+
+    /// Variant of `indy_close_pool_ledger` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_close_pool_ledger_ctx(const void*    context,
+                                                   indy_handle_t  handle,
+                                                   void           (*cb)(const void*    context,
+                                                                        indy_error_t   err)
+                                                  );
     
     extern indy_error_t indy_delete_pool_ledger_config(indy_handle_t command_handle,
                                                        const char *  config_name,
                                                        void          (*cb)(indy_handle_t command_handle_, indy_error_t err)
                                                        );
 
+    /// Variant of `indy_delete_pool_ledger_config` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_delete_pool_ledger_config_ctx(const void*    context,
+                                                           const char *  config_name,
+                                                           void          (*cb)(const void*    context, indy_error_t err)
+                                                           );
+
     extern indy_error_t indy_set_protocol_version(indy_handle_t command_handle,
                                                   indy_u64_t    protocol_version,
                                                   void          (*cb)(indy_handle_t command_handle_, indy_error_t err)
                                                   );
+
+    /// Variant of `indy_set_protocol_version` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_set_protocol_version_ctx(const void*    context,
+                                                      indy_u64_t    protocol_version,
+                                                      void          (*cb)(const void*    context, indy_error_t err)
+                                                      );
+
+    /// Returns the last requests sent by the pool with raw node replies to them.
+    /// Requests are recorded only if the pool is opened with `reply_capture_size` option.
+    extern indy_error_t indy_get_pool_reply_capture(indy_handle_t command_handle,
+                                                    indy_handle_t handle,
+                                                    void          (*cb)(indy_handle_t command_handle_,
+                                                                        indy_error_t  err,
+                                                                        const char*   captured_requests_json)
+                                                    );
+
+    /// Variant of `indy_get_pool_reply_capture` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_get_pool_reply_capture_ctx(const void*    context,
+                                                        indy_handle_t handle,
+                                                        void          (*cb)(const void*    context,
+                                                                            indy_error_t  err,
+                                                                            const char*   captured_requests_json)
+                                                        );
 #ifdef __cplusplus
 }
 #endif
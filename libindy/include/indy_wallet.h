@@ -144,6 +144,68 @@ extern "C" {
                                                   void         (*fn)(indy_handle_t command_handle_, indy_error_t err)
                                                   );
 
+    /// Unregister custom wallet storage implementation, so its library can be unloaded or registered again.
+    /// Storage type can't be unregistered while wallets that use it are open.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// type_: Storage type name.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error code
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+
+    extern indy_error_t indy_unregister_wallet_storage(indy_handle_t  command_handle,
+                                                       const char*    type_,
+
+                                                       void           (*fn)(indy_handle_t command_handle_, indy_error_t err)
+                                                       );
+
+    /// Variant of `indy_unregister_wallet_storage` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_unregister_wallet_storage_ctx(const void*    context,
+                                                           const char*    type_,
+
+                                                           void           (*fn)(const void*    context, indy_error_t err)
+                                                           );
+
+    /// Sets extended WQL operators supported by custom wallet storage implementation.
+    /// Queries using extended operators (any operator starting with `$` that isn't a part of WQL,
+    /// for example `$contains` or `$match`) are rejected unless wallet storage supports them.
+    /// Supported operators are passed to storage search as is and can be used with unencrypted tags only.
+    /// Applies to wallets opened after the call.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// type_: Storage type name.
+    /// extensions_json: JSON array of operator names, for example ["$contains", "$match"].
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error code
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+
+    extern indy_error_t indy_set_wallet_storage_query_extensions(indy_handle_t  command_handle,
+                                                                 const char*    type_,
+                                                                 const char*    extensions_json,
+
+                                                                 void           (*fn)(indy_handle_t command_handle_, indy_error_t err)
+                                                                 );
+
+    /// Variant of `indy_set_wallet_storage_query_extensions` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_set_wallet_storage_query_extensions_ctx(const void*    context,
+                                                                     const char*    type_,
+                                                                     const char*    extensions_json,
+
+                                                                     void           (*fn)(const void*    context, indy_error_t err)
+                                                                     );
+
     /// Create a new secure wallet.
     ///
     /// #Params
@@ -190,6 +252,14 @@ extern "C" {
                                            void           (*fn)(indy_handle_t command_handle_, indy_error_t err)
                                           );
 
+    /// Variant of `indy_create_wallet` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_create_wallet_ctx(const void*    context,
+                                               const char*    config,
+                                               const char*    credentials,
+                                               void           (*cb)(const void*    context,
+                                                                    indy_error_t   err)
+                                              );
+
     /// Open the wallet.
     ///
     /// Wallet must be previously created with indy_create_wallet method.
@@ -209,6 +279,12 @@ extern "C" {
     ///              "path": optional<string>, Path to the directory with wallet files.
     ///                      Defaults to $HOME/.indy_client/wallet.
     ///                      Wallet will be stored in the file {path}/{id}/sqlite.db
+    ///              "journal_mode": optional<string>, SQLite journal mode: "delete", "truncate", "persist", "memory", "wal" or "off".
+    ///                              Defaults to "wal".
+    ///              "synchronous": optional<string>, SQLite synchronous level: "off", "normal", "full" or "extra".
+    ///                             Defaults to "full" for "wal" journal mode and to SQLite default otherwise.
+    ///              "cache_size": optional<int>, SQLite page cache size: number of pages if positive, size in KiB if negative.
+    ///              "busy_timeout": optional<int>, Time in milliseconds to wait for locked database.
     ///           }
     ///
     ///   }
@@ -246,6 +322,15 @@ extern "C" {
                                          void           (*fn)(indy_handle_t command_handle_, indy_error_t err, indy_handle_t handle)
                                         );
 
+    /// Variant of `indy_open_wallet` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_open_wallet_ctx(const void*    context,
+                                             const char*    config,
+                                             const char*    credentials,
+                                             void           (*cb)(const void*    context,
+                                                                  indy_error_t   err,
+                                                                  indy_handle_t  wallet_handle)
+                                            );
+
     /// Exports opened wallet
     ///
     /// #Params:
@@ -275,6 +360,97 @@ extern "C" {
                                            void           (*fn)(indy_handle_t command_handle_, indy_error_t err)
                                            );
 
+    /// Variant of `indy_export_wallet` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_export_wallet_ctx(const void*    context,
+                                               indy_handle_t  wallet_handle,
+                                               const char*    export_config_json,
+                                               void           (*fn)(const void*    context, indy_error_t err)
+                                               );
+
+    /// Exports opened wallet to a buffer owned by the application instead of a file.
+    ///
+    /// #Params:
+    /// wallet_handle: wallet handle returned by indy_open_wallet
+    /// export_config: JSON containing settings for input operation.
+    ///   {
+    ///     "key": <string>, Key or passphrase used for wallet export key derivation.
+    ///     "key_derivation_method": optional<string> Algorithm to use for wallet export key derivation
+    ///                              (see indy_export_wallet).
+    ///   }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Exported wallet content in the same format as the file written by indy_export_wallet.
+    /// The buffer must be released with `indy_free_buffer`. On error NULL buffer is passed.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    extern indy_error_t indy_export_wallet_to_buffer(indy_handle_t  command_handle,
+                                                     indy_handle_t  wallet_handle,
+                                                     const char*    export_config_json,
+                                                     void           (*fn)(indy_handle_t command_handle_,
+                                                                          indy_error_t  err,
+                                                                          indy_u8_t*    data,
+                                                                          indy_u32_t    data_len)
+                                                     );
+
+    /// Variant of `indy_export_wallet_to_buffer` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_export_wallet_to_buffer_ctx(const void*    context,
+                                                         indy_handle_t  wallet_handle,
+                                                         const char*    export_config_json,
+                                                         void           (*fn)(const void*    context,
+                                                                              indy_error_t  err,
+                                                                              indy_u8_t*    data,
+                                                                              indy_u32_t    data_len)
+                                                         );
+
+    /// Streams export of opened wallet to the application instead of a file.
+    ///
+    /// Exported content is passed to `data_cb` by chunks as soon as they are produced,
+    /// so the whole export doesn't have to fit into memory at once.
+    /// `cb` is called once the export is completed or failed.
+    ///
+    /// #Params:
+    /// command_handle: command handle to map callback to caller context.
+    /// wallet_handle: wallet handle returned by indy_open_wallet
+    /// export_config: JSON containing settings for input operation (see indy_export_wallet_to_buffer).
+    /// data_cb: Callback that takes next chunk of exported wallet content.
+    ///     Concatenated chunks have the same format as the file written by indy_export_wallet.
+    ///     Pointer is valid only during the callback.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error code
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    extern indy_error_t indy_stream_export_wallet(indy_handle_t  command_handle,
+                                                  indy_handle_t  wallet_handle,
+                                                  const char*    export_config_json,
+
+                                                  void           (*data_cb)(indy_handle_t    command_handle_,
+                                                                            const indy_u8_t* data,
+                                                                            indy_u32_t       data_len),
+
+                                                  void           (*cb)(indy_handle_t command_handle_,
+                                                                       indy_error_t  err)
+                                                 );
+
+    /// Variant of `indy_stream_export_wallet` that passes application `context` to the callbacks instead of command handle.
+    extern indy_error_t indy_stream_export_wallet_ctx(const void*    context,
+                                                      indy_handle_t  wallet_handle,
+                                                      const char*    export_config_json,
+
+                                                      void           (*data_cb)(const void*      context,
+                                                                                const indy_u8_t* data,
+                                                                                indy_u32_t       data_len),
+
+                                                      void           (*cb)(const void*    context,
+                                                                           indy_error_t  err)
+                                                     );
+
 
     /// Creates a new secure wallet and then imports its content
     /// according to fields provided in import_config
@@ -330,6 +506,14 @@ extern "C" {
                                            void           (*fn)(indy_handle_t command_handle_, indy_error_t err)
                                            );
 
+    /// Variant of `indy_import_wallet` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_import_wallet_ctx(const void*    context,
+                                               const char*    config,
+                                               const char*    credentials,
+                                               const char*    import_config_json,
+                                               void           (*fn)(const void*    context, indy_error_t err)
+                                               );
+
     /// Closes opened wallet and frees allocated resources.
     ///
     /// #Params
@@ -347,6 +531,13 @@ extern "C" {
                                           void           (*fn)(indy_handle_t command_handle_, indy_error_t err)
                                          );
 
+    /// Variant of `indy_close_wallet` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_close_wallet_ctx(const void*    context,
+                                              indy_handle_t  wallet_handle,
+                                              void           (*cb)(const void*    context,
+                                                                   indy_error_t   err)
+                                             );
+
     /// Deletes created wallet.
     ///
     /// #Params
@@ -393,6 +584,14 @@ extern "C" {
                                            void           (*fn)(indy_handle_t command_handle_, indy_error_t err)
                                           );
 
+    /// Variant of `indy_delete_wallet` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_delete_wallet_ctx(const void*    context,
+                                               const char*    config,
+                                               const char*    credentials,
+                                               void           (*cb)(const void*    context,
+                                                                    indy_error_t   err)
+                                              );
+
     /// Generate wallet master key.
     /// Returned key is compatible with "RAW" key derivation method.
     /// It allows to avoid expensive key derivation for use cases when wallet keys can be stored in a secure enclave.
@@ -418,6 +617,15 @@ extern "C" {
                                                                          const char *const key)
                                                 );
 
+    /// Variant of `indy_generate_wallet_key` that passes application `context` to the callback instead of command handle.
+    extern indy_error_t indy_generate_wallet_key_ctx(const void*    context,
+                                                     const char *const config,
+
+                                                     void              (*cb)(const void*    context,
+                                                                             indy_error_t      err,
+                                                                             const char *const key)
+                                                    );
+
 #ifdef __cplusplus
 }
 #endif
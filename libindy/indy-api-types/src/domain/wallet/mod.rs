@@ -41,6 +41,13 @@ pub struct ExportConfig {
     pub key_derivation_method: KeyDerivationMethod
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ExportBufferConfig {
+    pub key: String,
+    #[serde(default = "default_key_derivation_method")]
+    pub key_derivation_method: KeyDerivationMethod
+}
+
 #[derive(Debug, Deserialize)]
 pub struct KeyConfig {
     pub seed: Option<String>
@@ -5,6 +5,7 @@ use std::sync::Arc;
 use std::ffi::{CString, NulError};
 use std::cell::RefCell;
 use std::ptr;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 
 use failure::{Backtrace, Context, Fail};
@@ -33,6 +34,10 @@ pub enum IndyErrorKind {
     InvalidParam(u32),
     #[fail(display = "IO error")]
     IOError,
+    #[fail(display = "Command queue is full")]
+    QueueFull,
+    #[fail(display = "Command timeout")]
+    Timeout,
     // Anoncreds errors
     #[fail(display = "Duplicated master secret")]
     MasterSecretDuplicateName,
@@ -96,9 +101,13 @@ pub enum IndyErrorKind {
     WalletItemAlreadyExists,
     #[fail(display = "Wallet query error")]
     WalletQueryError,
+    #[fail(display = "Wallet item conflict")]
+    WalletItemConflict,
     // DID errors
     #[fail(display = "DID already exists")]
     DIDAlreadyExists,
+    #[fail(display = "DID is deactivated")]
+    DIDDeactivated,
     // Payments errors
     #[fail(display = "Unknown payment method type")]
     UnknownPaymentMethodType,
@@ -116,6 +125,72 @@ pub enum IndyErrorKind {
     TransactionNotAllowed,
 }
 
+impl IndyErrorKind {
+    /// Part of libindy the error comes from.
+    pub fn component(&self) -> &'static str {
+        match self {
+            IndyErrorKind::InvalidState
+            | IndyErrorKind::InvalidStructure
+            | IndyErrorKind::InvalidParam(_)
+            | IndyErrorKind::IOError
+            | IndyErrorKind::QueueFull
+            | IndyErrorKind::Timeout => "common",
+            IndyErrorKind::MasterSecretDuplicateName
+            | IndyErrorKind::ProofRejected
+            | IndyErrorKind::RevocationRegistryFull
+            | IndyErrorKind::InvalidUserRevocId
+            | IndyErrorKind::CredentialRevoked
+            | IndyErrorKind::CredDefAlreadyExists => "anoncreds",
+            IndyErrorKind::NoConsensus
+            | IndyErrorKind::InvalidTransaction
+            | IndyErrorKind::LedgerItemNotFound => "ledger",
+            IndyErrorKind::PoolNotCreated
+            | IndyErrorKind::InvalidPoolHandle
+            | IndyErrorKind::PoolTerminated
+            | IndyErrorKind::PoolTimeout
+            | IndyErrorKind::PoolConfigAlreadyExists
+            | IndyErrorKind::PoolIncompatibleProtocolVersion => "pool",
+            IndyErrorKind::UnknownCrypto => "crypto",
+            IndyErrorKind::InvalidWalletHandle
+            | IndyErrorKind::UnknownWalletStorageType
+            | IndyErrorKind::WalletStorageTypeAlreadyRegistered
+            | IndyErrorKind::WalletAlreadyExists
+            | IndyErrorKind::WalletNotFound
+            | IndyErrorKind::WalletAlreadyOpened
+            | IndyErrorKind::WalletAccessFailed
+            | IndyErrorKind::WalletEncodingError
+            | IndyErrorKind::WalletStorageError
+            | IndyErrorKind::WalletEncryptionError
+            | IndyErrorKind::WalletItemNotFound
+            | IndyErrorKind::WalletItemAlreadyExists
+            | IndyErrorKind::WalletQueryError
+            | IndyErrorKind::WalletItemConflict => "wallet",
+            IndyErrorKind::DIDAlreadyExists
+            | IndyErrorKind::DIDDeactivated => "did",
+            IndyErrorKind::UnknownPaymentMethodType
+            | IndyErrorKind::IncompatiblePaymentMethods
+            | IndyErrorKind::PaymentInsufficientFunds
+            | IndyErrorKind::PaymentSourceDoesNotExist
+            | IndyErrorKind::PaymentOperationNotSupported
+            | IndyErrorKind::PaymentExtraFunds
+            | IndyErrorKind::TransactionNotAllowed => "payments",
+        }
+    }
+
+    /// Whether the same call may succeed if it's repeated later without changes.
+    pub fn is_retriable(&self) -> bool {
+        match self {
+            IndyErrorKind::IOError
+            | IndyErrorKind::QueueFull
+            | IndyErrorKind::Timeout
+            | IndyErrorKind::NoConsensus
+            | IndyErrorKind::PoolTimeout
+            | IndyErrorKind::WalletItemConflict => true,
+            _ => false
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct IndyError {
     // FIXME: We have to use Arc as for now we clone messages in pool service
@@ -288,7 +363,9 @@ impl<T> From<IndyResult<T>> for ErrorCode {
 impl From<IndyError> for ErrorCode {
     fn from(err: IndyError) -> ErrorCode {
         set_current_error(&err);
-        err.kind().into()
+        let code: ErrorCode = err.kind().into();
+        LAST_ERROR_CODE.with(|last| last.set(Some(code)));
+        code
     }
 }
 
@@ -329,6 +406,8 @@ impl From<IndyErrorKind> for ErrorCode {
                     _ => ErrorCode::CommonInvalidState
                 },
             IndyErrorKind::IOError => ErrorCode::CommonIOError,
+            IndyErrorKind::QueueFull => ErrorCode::CommonQueueFull,
+            IndyErrorKind::Timeout => ErrorCode::CommonTimeout,
             IndyErrorKind::MasterSecretDuplicateName => ErrorCode::AnoncredsMasterSecretDuplicateNameError,
             IndyErrorKind::ProofRejected => ErrorCode::AnoncredsProofRejected,
             IndyErrorKind::RevocationRegistryFull => ErrorCode::AnoncredsRevocationRegistryFullError,
@@ -358,7 +437,9 @@ impl From<IndyErrorKind> for ErrorCode {
             IndyErrorKind::WalletItemNotFound => ErrorCode::WalletItemNotFound,
             IndyErrorKind::WalletItemAlreadyExists => ErrorCode::WalletItemAlreadyExists,
             IndyErrorKind::WalletQueryError => ErrorCode::WalletQueryError,
+            IndyErrorKind::WalletItemConflict => ErrorCode::WalletItemConflict,
             IndyErrorKind::DIDAlreadyExists => ErrorCode::DidAlreadyExistsError,
+            IndyErrorKind::DIDDeactivated => ErrorCode::DidDeactivatedError,
             IndyErrorKind::UnknownPaymentMethodType => ErrorCode::PaymentUnknownMethodError,
             IndyErrorKind::IncompatiblePaymentMethods => ErrorCode::PaymentIncompatibleMethodsError,
             IndyErrorKind::PaymentInsufficientFunds => ErrorCode::PaymentInsufficientFundsError,
@@ -419,6 +500,8 @@ impl From<ErrorCode> for IndyErrorKind {
             ErrorCode::CommonInvalidParam26 => IndyErrorKind::InvalidParam(26),
             ErrorCode::CommonInvalidParam27 => IndyErrorKind::InvalidParam(27),
             ErrorCode::CommonIOError => IndyErrorKind::IOError,
+            ErrorCode::CommonQueueFull => IndyErrorKind::QueueFull,
+            ErrorCode::CommonTimeout => IndyErrorKind::Timeout,
             ErrorCode::AnoncredsMasterSecretDuplicateNameError => IndyErrorKind::MasterSecretDuplicateName,
             ErrorCode::AnoncredsProofRejected => IndyErrorKind::ProofRejected,
             ErrorCode::AnoncredsRevocationRegistryFullError => IndyErrorKind::RevocationRegistryFull,
@@ -448,7 +531,9 @@ impl From<ErrorCode> for IndyErrorKind {
             ErrorCode::WalletItemNotFound => IndyErrorKind::WalletItemNotFound,
             ErrorCode::WalletItemAlreadyExists => IndyErrorKind::WalletItemAlreadyExists,
             ErrorCode::WalletQueryError => IndyErrorKind::WalletQueryError,
+            ErrorCode::WalletItemConflict => IndyErrorKind::WalletItemConflict,
             ErrorCode::DidAlreadyExistsError => IndyErrorKind::DIDAlreadyExists,
+            ErrorCode::DidDeactivatedError => IndyErrorKind::DIDDeactivated,
             ErrorCode::PaymentUnknownMethodError => IndyErrorKind::UnknownPaymentMethodType,
             ErrorCode::PaymentIncompatibleMethodsError => IndyErrorKind::IncompatiblePaymentMethods,
             ErrorCode::PaymentInsufficientFundsError => IndyErrorKind::PaymentInsufficientFunds,
@@ -489,19 +574,44 @@ impl<E> IndyErrorExt for E where E: Fail
 
 thread_local! {
     pub static CURRENT_ERROR_C_JSON: RefCell<Option<CString>> = RefCell::new(None);
+    static LAST_ERROR_CODE: cell::Cell<Option<ErrorCode>> = cell::Cell::new(None);
+}
+
+static BACKTRACE_REPORTED: AtomicBool = AtomicBool::new(true);
+
+/// Sets whether backtrace of the error is reported in the current error details.
+pub fn set_backtrace_reported(reported: bool) {
+    BACKTRACE_REPORTED.store(reported, Ordering::Relaxed);
+}
+
+/// Returns code of the last error converted into `ErrorCode` on the current thread and resets it.
+pub fn take_last_error_code() -> Option<ErrorCode> {
+    LAST_ERROR_CODE.with(|last| last.take())
 }
 
 pub fn set_current_error(err: &IndyError) {
     CURRENT_ERROR_C_JSON.try_with(|error| {
-        let error_json = json!({
-            "message": err.to_string(),
-            "backtrace": err.backtrace().map(|bt| bt.to_string())
-        }).to_string();
+        let error_json = _error_details(err).to_string();
         error.replace(Some(string_to_cstring(error_json)));
     })
         .map_err(|err| error!("Thread local variable access failed with: {:?}", err)).ok();
 }
 
+fn _error_details(err: &IndyError) -> serde_json::Value {
+    let kind = err.kind();
+    let backtrace = if BACKTRACE_REPORTED.load(Ordering::Relaxed) { err.backtrace().map(|bt| bt.to_string()) } else { None };
+
+    json!({
+        "message": err.to_string(),
+        "error_code": ErrorCode::from(kind) as i32,
+        "component": kind.component(),
+        "retriable": kind.is_retriable(),
+        // the first item of the chain is the kind itself
+        "causes": <dyn Fail>::iter_chain(err.inner.as_ref()).skip(1).map(|cause| cause.to_string()).collect::<Vec<String>>(),
+        "backtrace": backtrace
+    })
+}
+
 pub fn get_current_error_c_json() -> *const c_char {
     let mut value = ptr::null();
 
@@ -144,6 +144,12 @@ pub enum ErrorCode
     // Caller passed invalid value as param 27 (null, invalid json and etc..)
     CommonInvalidParam27 = 129,
 
+    // Too many commands are waiting for execution
+    CommonQueueFull = 130,
+
+    // Command hasn't completed in the configured timeout
+    CommonTimeout = 131,
+
     // Wallet errors
     // Caller passed invalid wallet handle
     WalletInvalidHandle = 200,
@@ -190,6 +196,9 @@ pub enum ErrorCode
     // Returned if provided wallet query is invalid
     WalletQueryError = 214,
 
+    // Returned if wallet item was modified since expected state was read
+    WalletItemConflict = 215,
+
     // Ledger errors
     // Trying to open pool ledger that wasn't created before
     PoolLedgerNotCreatedError = 300,
@@ -244,6 +253,9 @@ pub enum ErrorCode
     // Attempt to create duplicate did
     DidAlreadyExistsError = 600,
 
+    // Attempt to sign with deactivated did
+    DidDeactivatedError = 601,
+
     // Unknown payment method was given
     PaymentUnknownMethodError = 700,
 
@@ -586,4 +598,101 @@ pub mod wallet {
     pub type WalletFreeSearch = extern fn(storage_handle: StorageHandle,
                                           search_handle: i32) -> ErrorCode;
 
+}
+
+pub mod blob_storage {
+    use super::*;
+    use libc::c_char;
+
+    /// Open the reader of blobs (For example, establishing connection to the storage)
+    ///
+    /// #Params
+    /// config: reader config passed to indy_open_blob_storage_reader
+    /// reader_handle_p: pointer to store opened reader handle
+    pub type BlobStorageOpenReader = extern fn(config: *const c_char,
+                                               reader_handle_p: *mut IndyHandle) -> ErrorCode;
+
+    /// Open the blob for reading
+    ///
+    /// #Params
+    /// reader_handle: opened reader handle (See open_reader handler)
+    /// hash: hash of blob content (pointer to buffer)
+    /// hash_len: hash of blob content (buffer size)
+    /// location: blob location (For example, tails location of revocation registry definition)
+    /// blob_handle_p: pointer to store opened blob handle
+    pub type BlobStorageOpenBlob = extern fn(reader_handle: IndyHandle,
+                                             hash: *const u8,
+                                             hash_len: u32,
+                                             location: *const c_char,
+                                             blob_handle_p: *mut IndyHandle) -> ErrorCode;
+
+    /// Read the part of blob into the buffer provided by libindy
+    ///
+    /// #Params
+    /// blob_handle: opened blob handle (See open_blob handler)
+    /// offset: offset of the part in the blob
+    /// size: size of the part and of the buffer
+    /// buf: buffer to copy the part to
+    /// read_len_p: pointer to store the number of copied bytes, less than size only at the end of blob
+    pub type BlobStorageRead = extern fn(blob_handle: IndyHandle,
+                                         offset: u64,
+                                         size: u32,
+                                         buf: *mut u8,
+                                         read_len_p: *mut u32) -> ErrorCode;
+
+    /// Close the blob and release its resources
+    ///
+    /// #Params
+    /// blob_handle: opened blob handle (See open_blob and create_blob handlers)
+    pub type BlobStorageCloseBlob = extern fn(blob_handle: IndyHandle) -> ErrorCode;
+
+    /// Close the reader and release its resources (For example, closing connection to the storage)
+    ///
+    /// #Params
+    /// reader_handle: opened reader handle (See open_reader handler)
+    pub type BlobStorageCloseReader = extern fn(reader_handle: IndyHandle) -> ErrorCode;
+
+    /// Open the writer of blobs (For example, establishing connection to the storage)
+    ///
+    /// #Params
+    /// config: writer config passed to indy_open_blob_storage_writer
+    /// writer_handle_p: pointer to store opened writer handle
+    pub type BlobStorageOpenWriter = extern fn(config: *const c_char,
+                                               writer_handle_p: *mut IndyHandle) -> ErrorCode;
+
+    /// Create the new blob
+    ///
+    /// #Params
+    /// writer_handle: opened writer handle (See open_writer handler)
+    /// blob_handle_p: pointer to store created blob handle
+    pub type BlobStorageCreateBlob = extern fn(writer_handle: IndyHandle,
+                                               blob_handle_p: *mut IndyHandle) -> ErrorCode;
+
+    /// Append bytes to the end of blob
+    ///
+    /// #Params
+    /// blob_handle: created blob handle (See create_blob handler)
+    /// bytes: appended bytes (pointer to buffer)
+    /// bytes_len: appended bytes (buffer size)
+    pub type BlobStorageAppend = extern fn(blob_handle: IndyHandle,
+                                           bytes: *const u8,
+                                           bytes_len: u32) -> ErrorCode;
+
+    /// Complete the blob after all bytes are appended
+    ///
+    /// #Params
+    /// blob_handle: created blob handle (See create_blob handler)
+    /// hash: hash of blob content (pointer to buffer)
+    /// hash_len: hash of blob content (buffer size)
+    /// location_p: pointer to store blob location, it must stay valid until blob is closed
+    pub type BlobStorageFinalize = extern fn(blob_handle: IndyHandle,
+                                             hash: *const u8,
+                                             hash_len: u32,
+                                             location_p: *mut *const c_char) -> ErrorCode;
+
+    /// Close the writer and release its resources
+    ///
+    /// #Params
+    /// writer_handle: opened writer handle (See open_writer handler)
+    pub type BlobStorageCloseWriter = extern fn(writer_handle: IndyHandle) -> ErrorCode;
 }
\ No newline at end of file
@@ -224,10 +224,24 @@ pub fn vec_to_pointer(v: &Vec<u8>) -> (*const u8, u32) {
     (v.as_ptr() as *const u8, len)
 }
 
+//Passes ownership of the vector content to the caller. Pointer must be released by free_owned_pointer
+pub fn vec_into_owned_pointer(v: Vec<u8>) -> (*mut u8, u32) {
+    let len = v.len() as u32;
+    (Box::into_raw(v.into_boxed_slice()) as *mut u8, len)
+}
+
+/// # Safety
+/// `data` and `len` must be returned by `vec_into_owned_pointer` and not released yet
+pub unsafe fn free_owned_pointer(data: *mut u8, len: u32) {
+    if !data.is_null() {
+        drop(Box::from_raw(::std::slice::from_raw_parts_mut(data, len as usize) as *mut [u8]));
+    }
+}
+
 #[macro_export]
 macro_rules! boxed_callback_string {
     ($method_name: expr, $cb: ident, $command_handle: ident) => {
-        Box::new(move |result| {
+        Box::new(move |result: indy_api_types::errors::IndyResult<String>| {
             let (err, result_string) = prepare_result_1!(result, String::new());
             trace!("{}: result: {:?}", $method_name, result_string);
             let result_string = ctypes::string_to_cstring(result_string);
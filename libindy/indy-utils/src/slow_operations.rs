@@ -0,0 +1,63 @@
+//! Log of storage calls and pool requests slower than the configured threshold.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use crate::crypto::base64;
+use crate::crypto::hash::hash;
+
+pub const SLOW_OPERATIONS_TARGET: &str = "indy_slow_operations";
+
+static THRESHOLD_MS: AtomicU64 = AtomicU64::new(0);
+
+/// Sets threshold in milliseconds above which operations are logged. `0` disables the log.
+pub fn set_threshold_ms(threshold_ms: u64) {
+    THRESHOLD_MS.store(threshold_ms, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    THRESHOLD_MS.load(Ordering::Relaxed) > 0
+}
+
+pub fn is_slow(duration: Duration) -> bool {
+    let threshold_ms = THRESHOLD_MS.load(Ordering::Relaxed);
+    threshold_ms > 0 && duration.as_millis() > u128::from(threshold_ms)
+}
+
+pub fn log(kind: &str, operation: &str, params_digest: &str, duration: Duration) {
+    log::warn!(target: SLOW_OPERATIONS_TARGET, "Slow {} operation: {}, duration_ms: {}, params_digest: {}",
+               kind, operation, duration.as_millis(), params_digest);
+}
+
+/// Parameters are logged as digest only, so records of the same call can be
+/// correlated without exposing record names or requests.
+pub fn params_digest(params: &[&str]) -> String {
+    hash(params.join("\u{0}").as_bytes())
+        .map(|digest| base64::encode_urlsafe(&digest[..12]))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn params_digest_works() {
+        let digest = params_digest(&["Indy::Did", "did"]);
+
+        assert_eq!(digest.len(), 16);
+        assert_eq!(digest, params_digest(&["Indy::Did", "did"]));
+        assert_ne!(digest, params_digest(&["Indy::Did", "did2"]));
+        assert_ne!(digest, params_digest(&["Indy::Di", "ddid"]));
+    }
+
+    #[test]
+    fn is_slow_works() {
+        set_threshold_ms(250);
+        assert!(is_slow(Duration::from_millis(251)));
+        assert!(!is_slow(Duration::from_millis(250)));
+
+        set_threshold_ms(0);
+        assert!(!is_slow(Duration::from_secs(10)));
+    }
+}
@@ -18,6 +18,14 @@ pub enum Query {
     Lte(String, String),
     Like(String, String),
     In(String, Vec<String>),
+    // Extended operator that isn't a part of WQL, storage must advertise it in its query extensions
+    Ext(String, String, ExtValue),
+}
+
+#[derive(Debug, Hash, Clone, PartialEq, Eq)]
+pub enum ExtValue {
+    Single(String),
+    List(Vec<String>),
 }
 
 impl Serialize for Query {
@@ -66,6 +74,16 @@ impl<'de> Deserialize<'de> for Query
 }
 
 impl Query {
+    /// Returns extended operators used by the query.
+    pub fn extensions(&self) -> Vec<&str> {
+        match *self {
+            Query::Ext(_, ref operator, _) => vec![operator.as_str()],
+            Query::And(ref queries) | Query::Or(ref queries) => queries.iter().flat_map(|query| query.extensions()).collect(),
+            Query::Not(ref query) => query.extensions(),
+            _ => Vec::new()
+        }
+    }
+
     pub fn optimise(self) -> Option<Query> {
         match self {
             Query::Not(boxed_operator) => if let Query::Not(nested_operator) = *boxed_operator {
@@ -131,6 +149,8 @@ impl Query {
             Query::Lte(ref tag_name, ref tag_value) => json!({tag_name: {"$lte": tag_value}}),
             Query::Like(ref tag_name, ref tag_value) => json!({tag_name: {"$like": tag_value}}),
             Query::In(ref tag_name, ref tag_values) => json!({tag_name: {"$in": tag_values}}),
+            Query::Ext(ref tag_name, ref operator, ExtValue::Single(ref tag_value)) => json!({tag_name: {operator: tag_value}}),
+            Query::Ext(ref tag_name, ref operator, ExtValue::List(ref tag_values)) => json!({tag_name: {operator: tag_values}}),
             Query::And(ref operators) => {
                 if !operators.is_empty() {
                     json!({
@@ -262,10 +282,39 @@ fn parse_single_operator(operator_name: String, key: String, value: serde_json::
             Ok(Query::In(key, target_values))
         }
         ("$in", _) => Err("$in must be used with array of strings"),
+        (operator, value) if is_ext_operator(operator) => {
+            let value = match value {
+                serde_json::Value::String(value_) => ExtValue::Single(value_),
+                serde_json::Value::Array(values) => {
+                    let mut target_values: Vec<String> = Vec::with_capacity(values.len());
+
+                    for v in values.into_iter() {
+                        if let serde_json::Value::String(s) = v {
+                            target_values.push(s);
+                        } else {
+                            return Err("extended operator must be used with string or array of strings");
+                        }
+                    }
+
+                    ExtValue::List(target_values)
+                }
+                _ => return Err("extended operator must be used with string or array of strings")
+            };
+
+            Ok(Query::Ext(key, operator.to_string(), value))
+        }
         (_, _) => Err("Unknown operator")
     }
 }
 
+// Operators unknown to WQL are passed to storage as is, storage decides whether it supports them
+fn is_ext_operator(operator: &str) -> bool {
+    operator.len() > 1 &&
+        operator.starts_with('$') &&
+        operator[1..].chars().all(|c| c.is_ascii_alphanumeric() || c == '_') &&
+        !["$and", "$or", "$not"].contains(&operator)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -449,6 +498,48 @@ mod tests {
         assert_eq!(query, expected);
     }
 
+    #[test]
+    fn test_simple_operator_ext_parse() {
+        let name1 = _random_string(10);
+        let value1 = _random_string(10);
+
+        let json = format!(r#"{{"{}":{{"$match":"{}"}}}}"#, name1, value1);
+
+        let query: Query = ::serde_json::from_str(&json).unwrap();
+
+        let expected = Query::Ext(name1, "$match".to_string(), ExtValue::Single(value1));
+
+        assert_eq!(query, expected);
+        assert_eq!(vec!["$match"], query.extensions());
+    }
+
+    #[test]
+    fn test_simple_operator_ext_with_array_parse() {
+        let name1 = _random_string(10);
+        let value1 = _random_string(10);
+        let value2 = _random_string(10);
+
+        let json = format!(r#"{{"{}":{{"$contains":["{}","{}"]}}}}"#, name1, value1, value2);
+
+        let query: Query = ::serde_json::from_str(&json).unwrap();
+
+        let expected = Query::Ext(name1, "$contains".to_string(), ExtValue::List(vec![value1, value2]));
+
+        assert_eq!(query, expected);
+        assert_eq!(json, query.to_string());
+    }
+
+    #[test]
+    fn test_simple_operator_ext_with_invalid_value_parse() {
+        let json = format!(r#"{{"{}":{{"$contains":1}}}}"#, _random_string(10));
+
+        ::serde_json::from_str::<Query>(&json).unwrap_err();
+
+        let json = format!(r#"{{"{}":{{"contains":"{}"}}}}"#, _random_string(10), _random_string(10));
+
+        ::serde_json::from_str::<Query>(&json).unwrap_err();
+    }
+
 
     #[test]
     fn test_and_with_one_eq_parse() {
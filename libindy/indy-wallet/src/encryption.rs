@@ -7,6 +7,7 @@ use indy_utils::crypto::{chacha20poly1305_ietf, hmacsha256, pwhash_argon2i13};
 
 use super::{Keys, WalletRecord, Metadata};
 use super::storage::{StorageRecord, Tag, TagName};
+use super::range_index;
 use rust_base58::FromBase58;
 
 #[cfg(test)]
@@ -86,38 +87,61 @@ fn _raw_master_key(passphrase: &str) -> IndyResult<chacha20poly1305_ietf::Key> {
 }
 
 pub(super) fn encrypt_tag_names(tag_names: &[&str], tag_name_key: &chacha20poly1305_ietf::Key, tags_hmac_key: &hmacsha256::Key) -> Vec<TagName> {
-    tag_names
-        .iter()
-        .map(|tag_name|
-            if tag_name.starts_with('~') {
-                TagName::OfPlain(encrypt_as_searchable(
-                    &tag_name.as_bytes()[1..], tag_name_key, tags_hmac_key))
-            } else {
-                TagName::OfEncrypted(encrypt_as_searchable(tag_name.as_bytes(), tag_name_key, tags_hmac_key))
-            })
-        .collect::<Vec<TagName>>()
+    let mut encrypted_tag_names = Vec::with_capacity(tag_names.len());
+
+    for tag_name in tag_names {
+        if tag_name.starts_with('~') {
+            encrypted_tag_names.push(TagName::OfPlain(encrypt_as_searchable(
+                &tag_name.as_bytes()[1..], tag_name_key, tags_hmac_key)));
+        } else {
+            encrypted_tag_names.push(TagName::OfEncrypted(encrypt_as_searchable(tag_name.as_bytes(), tag_name_key, tags_hmac_key)));
+
+            // Index of range tag is deleted together with the tag
+            if range_index::is_range_tag(tag_name) {
+                for index_tag_name in range_index::index_tag_names(tag_name) {
+                    encrypted_tag_names.push(TagName::OfEncrypted(encrypt_as_searchable(index_tag_name.as_bytes(), tag_name_key, tags_hmac_key)));
+                }
+            }
+        }
+    }
+
+    encrypted_tag_names
 }
 
 pub(super) fn encrypt_tags(tags: &HashMap<String, String>,
                            tag_name_key: &chacha20poly1305_ietf::Key,
                            tag_value_key: &chacha20poly1305_ietf::Key,
-                           tags_hmac_key: &hmacsha256::Key) -> Vec<Tag> {
-    tags
-        .iter()
-        .map(|(tag_name, tag_value)|
-            if tag_name.starts_with('~') {
-                // '~' character on start is skipped.
-                Tag::PlainText(
-                    encrypt_as_searchable(&tag_name.as_bytes()[1..], tag_name_key, tags_hmac_key),
-                    tag_value.to_string(),
-                )
-            } else {
-                Tag::Encrypted(
-                    encrypt_as_searchable(tag_name.as_bytes(), tag_name_key, tags_hmac_key),
-                    encrypt_as_searchable(tag_value.as_bytes(), tag_value_key, tags_hmac_key),
-                )
-            })
-        .collect::<Vec<Tag>>()
+                           tags_hmac_key: &hmacsha256::Key) -> IndyResult<Vec<Tag>> {
+    let mut encrypted_tags = Vec::with_capacity(tags.len());
+
+    for (tag_name, tag_value) in tags {
+        if tag_name.starts_with('~') {
+            // '~' character on start is skipped.
+            encrypted_tags.push(Tag::PlainText(
+                encrypt_as_searchable(&tag_name.as_bytes()[1..], tag_name_key, tags_hmac_key),
+                tag_value.to_string(),
+            ));
+        } else {
+            encrypted_tags.push(Tag::Encrypted(
+                encrypt_as_searchable(tag_name.as_bytes(), tag_name_key, tags_hmac_key),
+                encrypt_as_searchable(tag_value.as_bytes(), tag_value_key, tags_hmac_key),
+            ));
+
+            if range_index::is_range_tag(tag_name) {
+                let value = range_index::parse_value(tag_value)
+                    .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, format!("Value of range tag {} must be unsigned integer", tag_name)))?;
+
+                for (index_tag_name, index_tag_value) in range_index::index_tags(tag_name, value) {
+                    encrypted_tags.push(Tag::Encrypted(
+                        encrypt_as_searchable(index_tag_name.as_bytes(), tag_name_key, tags_hmac_key),
+                        encrypt_as_searchable(index_tag_value.as_bytes(), tag_value_key, tags_hmac_key),
+                    ));
+                }
+            }
+        }
+    }
+
+    Ok(encrypted_tags)
 }
 
 
@@ -170,6 +194,11 @@ pub(super) fn decrypt_tags(etags: &Option<Vec<Tag>>, tag_name_key: &chacha20poly
                     }
                     Tag::Encrypted(ref ename, ref evalue) => {
                         let name = String::from_utf8(decrypt_merged(&ename, tag_name_key)?).to_indy(IndyErrorKind::WalletEncryptionError, "Tag name is invalid utf8")?;
+
+                        if range_index::is_index_tag(&name) {
+                            continue;
+                        }
+
                         let value = String::from_utf8(decrypt_merged(&evalue, tag_value_key)?).to_indy(IndyErrorKind::WalletEncryptionError, "Tag value is invalid utf8")?;
                         (name, value)
                     }
@@ -360,7 +389,7 @@ mod tests {
         let tag_value_key = chacha20poly1305_ietf::gen_key();
         let hmac_key = hmacsha256::gen_key();
 
-        let c = encrypt_tags(&tags, &tag_name_key, &tag_value_key, &hmac_key);
+        let c = encrypt_tags(&tags, &tag_name_key, &tag_value_key, &hmac_key).unwrap();
         let u = decrypt_tags(&Some(c), &tag_name_key, &tag_value_key).unwrap().unwrap();
         assert_eq!(tags, u);
     }
@@ -386,7 +415,7 @@ mod tests {
         let mut tags = HashMap::new();
         tags.insert("tag_name_1".to_string(), "tag_value_1".to_string());
         tags.insert("~tag_name_2".to_string(), "tag_value_2".to_string());
-        let encrypted_tags = encrypt_tags(&tags, &keys.tag_name_key, &keys.tag_value_key, &keys.tags_hmac_key);
+        let encrypted_tags = encrypt_tags(&tags, &keys.tag_name_key, &keys.tag_value_key, &keys.tags_hmac_key).unwrap();
 
         let storage_record = StorageRecord {
             id: encrypted_name,
@@ -415,7 +444,7 @@ mod tests {
         let mut tags = HashMap::new();
         tags.insert("tag_name_1".to_string(), "tag_value_1".to_string());
         tags.insert("~tag_name_2".to_string(), "tag_value_2".to_string());
-        let encrypted_tags = encrypt_tags(&tags, &keys.tag_name_key, &keys.tag_value_key, &keys.tags_hmac_key);
+        let encrypted_tags = encrypt_tags(&tags, &keys.tag_name_key, &keys.tag_value_key, &keys.tags_hmac_key).unwrap();
 
         let storage_record = StorageRecord {
             id: encrypted_name,
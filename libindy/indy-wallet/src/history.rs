@@ -0,0 +1,74 @@
+// Revision history of wallet records.
+//
+// History is enabled per record type and stored in the same wallet as records of libindy
+// own types, so it is encrypted, exported and imported together with the wallet. Before
+// record of such type is changed or deleted its current value and tags are saved as the
+// next revision. Revisions of a record are numbered from 1 and numbers are never reused
+// even if history is pruned or the record is deleted and created again.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use indy_api_types::domain::wallet::Tags;
+
+/// Record types used to keep history itself. History can't be enabled for them.
+pub(super) const HISTORY_TYPES: [&str; 3] = ["RecordHistoryConfig", "RecordRevision", "RecordRevisionCounter"];
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct RecordHistoryConfig {
+    /// Count of the latest revisions to keep for each record. Older ones are pruned on update.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_revisions: Option<usize>,
+}
+
+/// Prior state of a record.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordRevision {
+    pub revision: u32,
+    pub value: String,
+    pub tags: Tags,
+    /// Time (in seconds since epoch) when this state was replaced by update or deletion.
+    pub replaced_at: u64,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordRevisionInfo {
+    pub revision: u32,
+    pub replaced_at: u64,
+}
+
+impl<'a> From<&'a RecordRevision> for RecordRevisionInfo {
+    fn from(revision: &'a RecordRevision) -> Self {
+        RecordRevisionInfo {
+            revision: revision.revision,
+            replaced_at: revision.replaced_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct RecordRevisionCounter {
+    pub last: u32,
+}
+
+// Type and id are separated unambiguously, as both can contain any characters
+pub(super) fn record_key(type_: &str, id: &str) -> String {
+    serde_json::to_string(&(type_, id)).unwrap()
+}
+
+pub(super) fn revision_name(key: &str, revision: u32) -> String {
+    format!("{}:{}", key, revision)
+}
+
+pub(super) fn revision_tags(type_: &str, id: &str) -> Tags {
+    let mut tags = Tags::new();
+    tags.insert("type".to_string(), type_.to_string());
+    tags.insert("id".to_string(), id.to_string());
+    tags
+}
+
+pub(super) fn now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs()
+}
@@ -4,12 +4,13 @@ use indy_api_types::errors::IndyError;
 
 use super::WalletRecord;
 use super::wallet::Keys;
-use super::storage::StorageIterator;
+use super::storage::{StorageIterator, Tag, TagName};
 use super::encryption::{decrypt_storage_record};
 
 pub(super) struct WalletIterator {
     storage_iterator: Box<dyn StorageIterator>,
     keys: Rc<Keys>,
+    tag_names: Option<Vec<TagName>>,
 }
 
 
@@ -18,17 +19,37 @@ impl WalletIterator {
         WalletIterator {
             storage_iterator: storage_iter,
             keys,
+            tag_names: None,
         }
     }
 
+    /// Restricts tags of returned records to the given (encrypted) tag names.
+    /// Filtering is done before decryption, so skipped tags are never decrypted.
+    pub fn with_tag_names(mut self, tag_names: Vec<TagName>) -> Self {
+        self.tag_names = Some(tag_names);
+        self
+    }
+
     pub fn next(&mut self) -> Result<Option<WalletRecord>, IndyError> {
         let next_storage_entity = self.storage_iterator.next()?;
-        if let Some(next_storage_entity) = next_storage_entity {
+        if let Some(mut next_storage_entity) = next_storage_entity {
+            if let (Some(tags), Some(tag_names)) = (next_storage_entity.tags.as_mut(), self.tag_names.as_ref()) {
+                tags.retain(|tag| WalletIterator::_is_requested_tag(tag, tag_names));
+            }
+
             let record = decrypt_storage_record(&next_storage_entity, &self.keys)?;
             Ok(Some(record))
         } else { Ok(None) }
     }
 
+    fn _is_requested_tag(tag: &Tag, tag_names: &[TagName]) -> bool {
+        tag_names.iter().any(|tag_name| match (tag, tag_name) {
+            (Tag::Encrypted(name, _), TagName::OfEncrypted(requested_name)) => name == requested_name,
+            (Tag::PlainText(name, _), TagName::OfPlain(requested_name)) => name == requested_name,
+            _ => false
+        })
+    }
+
     pub fn get_total_count(&self) -> Result<Option<usize>, IndyError> {
         let total_count = self.storage_iterator.get_total_count()?;
         Ok(total_count)
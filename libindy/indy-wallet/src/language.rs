@@ -2,6 +2,7 @@ use std::string;
 
 use indy_api_types::errors::prelude::*;
 use indy_utils::crypto::base64;
+use indy_utils::wql::ExtValue;
 
 #[derive(Debug, PartialEq, Hash, Clone)]
 pub enum TagName {
@@ -67,6 +68,7 @@ pub enum Operator {
     Lte(TagName, TargetValue),
     Like(TagName, TargetValue),
     In(TagName, Vec<TargetValue>),
+    Ext(TagName, String, ExtValue),
 }
 
 impl string::ToString for Operator {
@@ -101,6 +103,17 @@ impl string::ToString for Operator {
                 } else { "{}".to_string() }
             },
             Operator::Not(ref stmt) => format!(r#"{{"$not":{}}}"#, stmt.to_string()),
+            Operator::Ext(ref tag_name, ref operator, ExtValue::Single(ref tag_value)) => {
+                format!(r#"{{{}:{{"{}":{}}}}}"#, tag_name.to_string(), operator, TargetValue::from(tag_value.clone()).to_string())
+            }
+            Operator::Ext(ref tag_name, ref operator, ExtValue::List(ref tag_values)) => {
+                format!(
+                    r#"{{{}:{{"{}":[{}]}}}}"#,
+                    tag_name.to_string(),
+                    operator,
+                    tag_values.iter().map(|v| TargetValue::from(v.clone()).to_string()).collect::<Vec<String>>().join(",")
+                )
+            }
         }
     }
 }
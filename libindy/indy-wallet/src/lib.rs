@@ -12,11 +12,12 @@ extern crate serde;
 extern crate serde_derive;
 
 use std::cell::RefCell;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs;
-use std::io::BufReader;
+use std::io::{BufReader, Write};
 use std::path::PathBuf;
 use std::rc::Rc;
+use std::time::Instant;
 
 use serde_json::Value as SValue;
 
@@ -26,6 +27,7 @@ use indy_api_types::domain::wallet::{Config, Credentials, ExportConfig, Tags};
 use indy_api_types::errors::prelude::*;
 pub use crate::encryption::KeyDerivationData;
 use indy_utils::crypto::chacha20poly1305_ietf;
+use indy_utils::slow_operations;
 use indy_utils::crypto::chacha20poly1305_ietf::Key as MasterKey;
 
 use self::export_import::{export_continue, finish_import, preparse_file_to_import};
@@ -33,23 +35,35 @@ use self::storage::{WalletStorage, WalletStorageType};
 use self::storage::default::SQLiteStorageType;
 use self::storage::plugged::PluggedStorageType;
 use self::wallet::{Keys, Wallet};
+use self::metrics::*;
+use self::history::{HISTORY_TYPES, RecordRevisionCounter};
+use self::search_plan::SearchPlan;
 use indy_api_types::{WalletHandle};
 
+pub use self::history::{RecordHistoryConfig, RecordRevision, RecordRevisionInfo};
+
 mod storage;
 mod encryption;
 mod query_encryption;
+mod range_index;
+pub mod search_plan;
 mod iterator;
 // TODO: Remove query language out of wallet module
 pub mod language;
 mod export_import;
+mod history;
 mod wallet;
+pub mod metrics;
 
 pub struct WalletService {
     storage_types: RefCell<HashMap<String, Box<dyn WalletStorageType>>>,
     wallets: RefCell<HashMap<WalletHandle, Box<Wallet>>>,
     wallet_ids: RefCell<HashSet<String>>,
-    pending_for_open: RefCell<HashMap<WalletHandle, (String /* id */, Box<dyn WalletStorage>, Metadata, Option<KeyDerivationData>)>>,
+    pending_for_open: RefCell<HashMap<WalletHandle, (String /* id */, String /* storage type */, Box<dyn WalletStorage>, Metadata, Option<KeyDerivationData>)>>,
     pending_for_import: RefCell<HashMap<WalletHandle, (BufReader<::std::fs::File>, chacha20poly1305_ietf::Nonce, usize, Vec<u8>, KeyDerivationData)>>,
+    wallet_storage_types: RefCell<HashMap<WalletHandle, String>>,
+    operation_counters: RefCell<BTreeMap<(String /* storage type */, &'static str /* operation */), OperationCounters>>,
+    history_configs: RefCell<HashMap<WalletHandle, HashMap<String /* record type */, RecordHistoryConfig>>>,
 }
 
 impl WalletService {
@@ -66,6 +80,9 @@ impl WalletService {
             wallet_ids: RefCell::new(HashSet::new()),
             pending_for_open: RefCell::new(HashMap::new()),
             pending_for_import: RefCell::new(HashMap::new()),
+            wallet_storage_types: RefCell::new(HashMap::new()),
+            operation_counters: RefCell::new(BTreeMap::new()),
+            history_configs: RefCell::new(HashMap::new()),
         }
     }
 
@@ -119,6 +136,49 @@ impl WalletService {
         Ok(())
     }
 
+    /// Removes plugged storage type, so its library can be unloaded or registered again.
+    /// Storage type can't be removed while wallets that use it are open.
+    pub fn unregister_wallet_storage(&self, type_: &str) -> IndyResult<()> {
+        trace!("unregister_wallet_storage >>> type_: {:?}", type_);
+
+        if type_ == "default" {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "Default wallet storage can't be unregistered"));
+        }
+
+        if !self.storage_types.borrow().contains_key(type_) {
+            return Err(err_msg(IndyErrorKind::UnknownWalletStorageType, format!("Wallet storage isn't registered for type: {}", type_)));
+        }
+
+        let open_count = self.wallet_storage_types.borrow().values().filter(|storage_type| *storage_type == type_).count()
+            + self.pending_for_open.borrow().values().filter(|(_, storage_type, _, _, _)| storage_type == type_).count();
+
+        if open_count > 0 {
+            return Err(err_msg(IndyErrorKind::InvalidState, format!("Wallet storage type {} is used by {} open wallets", type_, open_count)));
+        }
+
+        self.storage_types.borrow_mut().remove(type_);
+
+        trace!("unregister_wallet_storage <<<");
+        Ok(())
+    }
+
+    /// Sets extended WQL operators supported by plugged storage type, so queries using them are passed to the storage.
+    /// Applies to wallets opened after the call.
+    pub fn set_wallet_storage_query_extensions(&self, type_: &str, extensions: Vec<String>) -> IndyResult<()> {
+        trace!("set_wallet_storage_query_extensions >>> type_: {:?}, extensions: {:?}", type_, extensions);
+
+        if let Some(extension) = extensions.iter().find(|extension| !extension.starts_with('$') || extension.len() < 2) {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Invalid query extension operator: {}", extension)));
+        }
+
+        self.storage_types.borrow().get(type_)
+            .ok_or_else(|| err_msg(IndyErrorKind::UnknownWalletStorageType, format!("Wallet storage isn't registered for type: {}", type_)))?
+            .set_query_extensions(extensions)?;
+
+        trace!("set_wallet_storage_query_extensions <<<");
+        Ok(())
+    }
+
     pub fn create_wallet(&self,
                          config: &Config,
                          credentials: &Credentials,
@@ -201,13 +261,13 @@ impl WalletService {
         let rekey_data: Option<KeyDerivationData> = credentials.rekey.as_ref().map(|ref rekey|
             KeyDerivationData::from_passphrase_with_new_salt(rekey, &credentials.rekey_derivation_method));
 
-        self.pending_for_open.borrow_mut().insert(wallet_handle, (WalletService::_get_wallet_id(config), storage, metadata, rekey_data.clone()));
+        self.pending_for_open.borrow_mut().insert(wallet_handle, (WalletService::_get_wallet_id(config), WalletService::_get_storage_type(config), storage, metadata, rekey_data.clone()));
 
         Ok((wallet_handle, key_derivation_data, rekey_data))
     }
 
     pub fn open_wallet_continue(&self, wallet_handle: WalletHandle, master_key: (&MasterKey, Option<&MasterKey>)) -> IndyResult<WalletHandle> {
-        let (id, storage, metadata, rekey_data) = self.pending_for_open.borrow_mut().remove(&wallet_handle)
+        let (id, storage_type, storage, metadata, rekey_data) = self.pending_for_open.borrow_mut().remove(&wallet_handle)
             .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "Open data not found"))?;
 
         let (master_key, rekey) = master_key;
@@ -225,6 +285,7 @@ impl WalletService {
         wallets.insert(wallet_handle, Box::new(wallet));
         let mut wallet_ids = self.wallet_ids.borrow_mut();
         wallet_ids.insert(id.to_string());
+        self.wallet_storage_types.borrow_mut().insert(wallet_handle, storage_type);
 
         trace!("open_wallet <<< res: {:?}", wallet_handle);
         Ok(wallet_handle)
@@ -247,6 +308,8 @@ impl WalletService {
         match self.wallets.borrow_mut().remove(&handle) {
             Some(mut wallet) => {
                 self.wallet_ids.borrow_mut().remove(wallet.get_id());
+                self.wallet_storage_types.borrow_mut().remove(&handle);
+                self.history_configs.borrow_mut().remove(&handle);
                 wallet.close()
             },
             None => Err(err_msg(IndyErrorKind::InvalidWalletHandle, "Unknown wallet handle"))
@@ -256,6 +319,25 @@ impl WalletService {
         Ok(())
     }
 
+    /// Returns id of the opened wallet.
+    pub fn get_wallet_id(&self, wallet_handle: WalletHandle) -> IndyResult<String> {
+        match self.wallets.borrow().get(&wallet_handle) {
+            Some(wallet) => Ok(wallet.get_id().to_string()),
+            None => Err(err_msg(IndyErrorKind::InvalidWalletHandle, "Unknown wallet handle"))
+        }
+    }
+
+    /// Closes all opened wallets. Failure to close one wallet doesn't prevent closing of the others.
+    pub fn close_all_wallets(&self) {
+        let handles: Vec<WalletHandle> = self.wallets.borrow().keys().cloned().collect();
+
+        for handle in handles {
+            if let Err(err) = self.close_wallet(handle) {
+                warn!("Can't close wallet {:?}: {:?}", handle, err);
+            }
+        }
+    }
+
     fn _map_wallet_storage_error(err: IndyError, type_: &str, name: &str) -> IndyError {
         match err.kind() {
             IndyErrorKind::WalletItemAlreadyExists => err_msg(IndyErrorKind::WalletItemAlreadyExists, format!("Wallet item already exists with type: {}, id: {}", type_, name)),
@@ -265,11 +347,11 @@ impl WalletService {
     }
 
     pub fn add_record(&self, wallet_handle: WalletHandle, type_: &str, name: &str, value: &str, tags: &Tags) -> IndyResult<()> {
-        match self.wallets.borrow_mut().get_mut(&wallet_handle) {
+        self._measure(wallet_handle, ADD_OPERATION, &[type_, name], || match self.wallets.borrow_mut().get_mut(&wallet_handle) {
             Some(wallet) => wallet.add(type_, name, value, tags)
                 .map_err(|err| WalletService::_map_wallet_storage_error(err, type_, name)),
             None => Err(err_msg(IndyErrorKind::InvalidWalletHandle, "Unknown wallet handle"))
-        }
+        })
     }
 
     pub fn add_indy_record<T>(&self, wallet_handle: WalletHandle, name: &str, value: &str, tags: &Tags)
@@ -286,18 +368,39 @@ impl WalletService {
         Ok(object_json)
     }
 
+    /// Adds new records of any types. Fails if any of the records already exists.
+    /// Records are added atomically if wallet storage supports transactions.
+    pub fn add_records(&self, wallet_handle: WalletHandle, records: &[AddRecord]) -> IndyResult<()> {
+        self._measure(wallet_handle, ADD_OPERATION, &[], || match self.wallets.borrow().get(&wallet_handle) {
+            Some(wallet) => wallet.add_all(records),
+            None => Err(err_msg(IndyErrorKind::InvalidWalletHandle, "Unknown wallet handle"))
+        })
+    }
+
+    pub fn indy_object_record<T>(&self, name: &str, object: &T, tags: &Tags) -> IndyResult<AddRecord> where T: ::serde::Serialize + Sized {
+        let value = serde_json::to_string(object)
+            .to_indy(IndyErrorKind::InvalidState, format!("Cannot serialize {:?}", short_type_name::<T>()))?;
+
+        Ok(AddRecord {
+            type_: self.add_prefix(short_type_name::<T>()),
+            id: name.to_string(),
+            value,
+            tags: tags.clone(),
+        })
+    }
+
     pub fn update_record_value(&self, wallet_handle: WalletHandle, type_: &str, name: &str, value: &str) -> IndyResult<()> {
-        match self.wallets.borrow().get(&wallet_handle) {
+        self._with_history(wallet_handle, type_, name, || self._measure(wallet_handle, UPDATE_OPERATION, &[type_, name], || match self.wallets.borrow().get(&wallet_handle) {
             Some(wallet) =>
                 wallet.update(type_, name, value)
                     .map_err(|err| WalletService::_map_wallet_storage_error(err, type_, name)),
             None => Err(err_msg(IndyErrorKind::InvalidWalletHandle, "Unknown wallet handle"))
-        }
+        }))
     }
 
     pub fn update_indy_object<T>(&self, wallet_handle: WalletHandle, name: &str, object: &T) -> IndyResult<String> where T: ::serde::Serialize + Sized {
         let type_ = short_type_name::<T>();
-        match self.wallets.borrow().get(&wallet_handle) {
+        self._with_history(wallet_handle, &self.add_prefix(type_), name, || self._measure(wallet_handle, UPDATE_OPERATION, &[type_, name], || match self.wallets.borrow().get(&wallet_handle) {
             Some(wallet) => {
                 let object_json = serde_json::to_string(object)
                     .to_indy(IndyErrorKind::InvalidState, format!("Cannot serialize {:?}", type_))?;
@@ -305,39 +408,76 @@ impl WalletService {
                 Ok(object_json)
             }
             None => Err(err_msg(IndyErrorKind::InvalidWalletHandle, "Unknown wallet handle"))
+        }))
+    }
+
+    /// Inserts new or replaces value and tags of existing records of the type.
+    /// Returns `true` for every inserted record and `false` for every updated one.
+    pub fn upsert_records(&self, wallet_handle: WalletHandle, type_: &str, records: &[UpsertRecord]) -> IndyResult<Vec<bool>> {
+        let records_before_change = records.iter()
+            .map(|record| self._record_before_change(wallet_handle, type_, &record.id))
+            .collect::<IndyResult<Vec<_>>>()?;
+
+        let inserted = self._measure(wallet_handle, UPDATE_OPERATION, &[type_], || match self.wallets.borrow().get(&wallet_handle) {
+            Some(wallet) => wallet.upsert(type_, records),
+            None => Err(err_msg(IndyErrorKind::InvalidWalletHandle, "Unknown wallet handle"))
+        })?;
+
+        for (record, record_before_change) in records.iter().zip(records_before_change) {
+            if let Some((config, record_before_change)) = record_before_change {
+                self._add_revision(wallet_handle, type_, &record.id, &config, record_before_change)?;
+            }
+        }
+
+        Ok(inserted)
+    }
+
+    pub fn modify_record_value(&self, wallet_handle: WalletHandle, type_: &str, name: &str, modify: &mut dyn FnMut(&str) -> IndyResult<String>) -> IndyResult<String> {
+        self._with_history(wallet_handle, type_, name, || self._measure(wallet_handle, UPDATE_OPERATION, &[type_, name], || match self.wallets.borrow().get(&wallet_handle) {
+            Some(wallet) =>
+                wallet.modify(type_, name, modify)
+                    .map_err(|err| WalletService::_map_wallet_storage_error(err, type_, name)),
+            None => Err(err_msg(IndyErrorKind::InvalidWalletHandle, "Unknown wallet handle"))
+        }))
+    }
+
+    pub fn get_record_types(&self, wallet_handle: WalletHandle) -> IndyResult<BTreeMap<String, usize>> {
+        match self.wallets.borrow().get(&wallet_handle) {
+            Some(wallet) => wallet.count_types(),
+            None => Err(err_msg(IndyErrorKind::InvalidWalletHandle, "Unknown wallet handle"))
         }
     }
 
     pub fn add_record_tags(&self, wallet_handle: WalletHandle, type_: &str, name: &str, tags: &Tags) -> IndyResult<()> {
-        match self.wallets.borrow_mut().get_mut(&wallet_handle) {
+        self._with_history(wallet_handle, type_, name, || self._measure(wallet_handle, UPDATE_TAGS_OPERATION, &[type_, name], || match self.wallets.borrow_mut().get_mut(&wallet_handle) {
             Some(wallet) => wallet.add_tags(type_, name, tags)
                 .map_err(|err| WalletService::_map_wallet_storage_error(err, type_, name)),
             None => Err(err_msg(IndyErrorKind::InvalidWalletHandle, "Unknown wallet handle"))
-        }
+        }))
     }
 
     pub fn update_record_tags(&self, wallet_handle: WalletHandle, type_: &str, name: &str, tags: &Tags) -> IndyResult<()> {
-        match self.wallets.borrow_mut().get_mut(&wallet_handle) {
+        self._with_history(wallet_handle, type_, name, || self._measure(wallet_handle, UPDATE_TAGS_OPERATION, &[type_, name], || match self.wallets.borrow_mut().get_mut(&wallet_handle) {
             Some(wallet) => wallet.update_tags(type_, name, tags)
                 .map_err(|err| WalletService::_map_wallet_storage_error(err, type_, name)),
             None => Err(err_msg(IndyErrorKind::InvalidWalletHandle, "Unknown wallet handle"))
-        }
+        }))
     }
 
     pub fn delete_record_tags(&self, wallet_handle: WalletHandle, type_: &str, name: &str, tag_names: &[&str]) -> IndyResult<()> {
-        match self.wallets.borrow().get(&wallet_handle) {
+        self._with_history(wallet_handle, type_, name, || self._measure(wallet_handle, UPDATE_TAGS_OPERATION, &[type_, name], || match self.wallets.borrow().get(&wallet_handle) {
             Some(wallet) => wallet.delete_tags(type_, name, tag_names)
                 .map_err(|err| WalletService::_map_wallet_storage_error(err, type_, name)),
             None => Err(err_msg(IndyErrorKind::InvalidWalletHandle, "Unknown wallet handle"))
-        }
+        }))
     }
 
     pub fn delete_record(&self, wallet_handle: WalletHandle, type_: &str, name: &str) -> IndyResult<()> {
-        match self.wallets.borrow().get(&wallet_handle) {
+        self._with_history(wallet_handle, type_, name, || self._measure(wallet_handle, DELETE_OPERATION, &[type_, name], || match self.wallets.borrow().get(&wallet_handle) {
             Some(wallet) => wallet.delete(type_, name)
                 .map_err(|err| WalletService::_map_wallet_storage_error(err, type_, name)),
             None => Err(err_msg(IndyErrorKind::InvalidWalletHandle, "Unknown wallet handle"))
-        }
+        }))
     }
 
     pub fn delete_indy_record<T>(&self, wallet_handle: WalletHandle, name: &str) -> IndyResult<()> where T: Sized {
@@ -345,12 +485,12 @@ impl WalletService {
     }
 
     pub fn get_record(&self, wallet_handle: WalletHandle, type_: &str, name: &str, options_json: &str) -> IndyResult<WalletRecord> {
-        match self.wallets.borrow().get(&wallet_handle) {
+        self._measure(wallet_handle, GET_OPERATION, &[type_, name], || match self.wallets.borrow().get(&wallet_handle) {
             Some(wallet) =>
                 wallet.get(type_, name, options_json)
                     .map_err(|err| WalletService::_map_wallet_storage_error(err, type_, name)),
             None => Err(err_msg(IndyErrorKind::InvalidWalletHandle, "Unknown wallet handle"))
-        }
+        })
     }
 
     pub fn get_indy_record<T>(&self, wallet_handle: WalletHandle, name: &str, options_json: &str) -> IndyResult<WalletRecord> where T: Sized {
@@ -360,10 +500,10 @@ impl WalletService {
     pub fn get_indy_record_value<T>(&self, wallet_handle: WalletHandle, name: &str, options_json: &str) -> IndyResult<String> where T: Sized {
         let type_ = short_type_name::<T>();
 
-        let record: WalletRecord = match self.wallets.borrow().get(&wallet_handle) {
+        let record: WalletRecord = self._measure(wallet_handle, GET_OPERATION, &[type_, name], || match self.wallets.borrow().get(&wallet_handle) {
             Some(wallet) => wallet.get(&self.add_prefix(type_), name, options_json),
             None => Err(err_msg(IndyErrorKind::InvalidWalletHandle, "Unknown wallet handle"))
-        }?;
+        })?;
 
         let record_value = record.get_value()
             .ok_or_else(||err_msg(IndyErrorKind::InvalidState, format!("{} not found for id: {:?}", type_, name)))?.to_string();
@@ -389,9 +529,16 @@ impl WalletService {
     }
 
     pub fn search_records(&self, wallet_handle: WalletHandle, type_: &str, query_json: &str, options_json: &str) -> IndyResult<WalletSearch> {
-        match self.wallets.borrow().get(&wallet_handle) {
+        self._measure(wallet_handle, SEARCH_OPERATION, &[type_, query_json], || match self.wallets.borrow().get(&wallet_handle) {
             Some(wallet) => Ok(WalletSearch { iter: wallet.search(type_, query_json, Some(options_json))? }),
             None => Err(err_msg(IndyErrorKind::InvalidWalletHandle, "Unknown wallet handle"))
+        })
+    }
+
+    pub fn explain_search(&self, wallet_handle: WalletHandle, type_: &str, query_json: &str) -> IndyResult<SearchPlan> {
+        match self.wallets.borrow().get(&wallet_handle) {
+            Some(wallet) => wallet.explain_search(type_, query_json),
+            None => Err(err_msg(IndyErrorKind::InvalidWalletHandle, "Unknown wallet handle"))
         }
     }
 
@@ -429,6 +576,151 @@ impl WalletService {
         }
     }
 
+    /// Enables revision history of records of the type or replaces its config if history is already enabled.
+    pub fn enable_record_history(&self, wallet_handle: WalletHandle, type_: &str, config: &RecordHistoryConfig) -> IndyResult<()> {
+        if HISTORY_TYPES.iter().any(|history_type| self.add_prefix(history_type) == type_) {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, format!("History can't be enabled for records of type: {}", type_)));
+        }
+
+        if config.max_revisions == Some(0) {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "Max revisions must be positive"));
+        }
+
+        self.upsert_indy_object(wallet_handle, type_, config)?;
+        self.history_configs.borrow_mut().remove(&wallet_handle);
+        Ok(())
+    }
+
+    /// Disables revision history of records of the type. Already saved revisions are kept.
+    pub fn disable_record_history(&self, wallet_handle: WalletHandle, type_: &str) -> IndyResult<()> {
+        match self.delete_indy_record::<RecordHistoryConfig>(wallet_handle, type_) {
+            Ok(()) => {}
+            Err(ref err) if err.kind() == IndyErrorKind::WalletItemNotFound => {}
+            Err(err) => return Err(err)
+        }
+
+        self.history_configs.borrow_mut().remove(&wallet_handle);
+        Ok(())
+    }
+
+    /// Returns saved revisions of the record from the oldest to the latest one.
+    pub fn get_record_revisions(&self, wallet_handle: WalletHandle, type_: &str, name: &str) -> IndyResult<Vec<RecordRevision>> {
+        let query_json = serde_json::to_string(&history::revision_tags(type_, name))
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize revisions query")?;
+
+        let mut search = self.search_indy_records::<RecordRevision>(wallet_handle, &query_json, "{}")?;
+        let mut revisions = Vec::new();
+
+        while let Some(record) = search.fetch_next_record()? {
+            let revision = record.get_value()
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "RecordRevision value not found"))?;
+
+            let revision: RecordRevision = serde_json::from_str(revision)
+                .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize RecordRevision")?;
+
+            revisions.push(revision);
+        }
+
+        revisions.sort_by_key(|revision| revision.revision);
+        Ok(revisions)
+    }
+
+    pub fn get_record_revision(&self, wallet_handle: WalletHandle, type_: &str, name: &str, revision: u32) -> IndyResult<RecordRevision> {
+        let revision_name = history::revision_name(&history::record_key(type_, name), revision);
+
+        self.get_indy_object::<RecordRevision>(wallet_handle, &revision_name, &RecordOptions::id_value())
+            .map_err(|err| match err.kind() {
+                IndyErrorKind::WalletItemNotFound => err_msg(IndyErrorKind::WalletItemNotFound, format!("Revision {} not found for type: {}, id: {}", revision, type_, name)),
+                _ => err
+            })
+    }
+
+    /// Deletes all saved revisions of the record except `keep_last` latest ones. Returns count of deleted revisions.
+    pub fn prune_record_history(&self, wallet_handle: WalletHandle, type_: &str, name: &str, keep_last: usize) -> IndyResult<usize> {
+        let revisions = self.get_record_revisions(wallet_handle, type_, name)?;
+        let key = history::record_key(type_, name);
+        let pruned = revisions.len().saturating_sub(keep_last);
+
+        for revision in &revisions[..pruned] {
+            self.delete_indy_record::<RecordRevision>(wallet_handle, &history::revision_name(&key, revision.revision))?;
+        }
+
+        Ok(pruned)
+    }
+
+    // Saves current state of the record as revision if the change made by `action` succeeds
+    fn _with_history<T>(&self, wallet_handle: WalletHandle, type_: &str, name: &str, action: impl FnOnce() -> IndyResult<T>) -> IndyResult<T> {
+        let record_before_change = self._record_before_change(wallet_handle, type_, name)?;
+
+        let res = action()?;
+
+        if let Some((config, record_before_change)) = record_before_change {
+            self._add_revision(wallet_handle, type_, name, &config, record_before_change)?;
+        }
+
+        Ok(res)
+    }
+
+    fn _record_before_change(&self, wallet_handle: WalletHandle, type_: &str, name: &str) -> IndyResult<Option<(RecordHistoryConfig, WalletRecord)>> {
+        let config = match self._history_config(wallet_handle, type_)? {
+            Some(config) => config,
+            None => return Ok(None)
+        };
+
+        match self.get_record(wallet_handle, type_, name, r#"{"retrieveValue":true,"retrieveTags":true}"#) {
+            Ok(record) => Ok(Some((config, record))),
+            Err(ref err) if err.kind() == IndyErrorKind::WalletItemNotFound => Ok(None),
+            Err(err) => Err(err)
+        }
+    }
+
+    fn _add_revision(&self, wallet_handle: WalletHandle, type_: &str, name: &str, config: &RecordHistoryConfig, record: WalletRecord) -> IndyResult<()> {
+        let key = history::record_key(type_, name);
+
+        let revision = self.get_indy_opt_object::<RecordRevisionCounter>(wallet_handle, &key, &RecordOptions::id_value())?
+            .map(|counter| counter.last + 1)
+            .unwrap_or(1);
+
+        self.upsert_indy_object(wallet_handle, &key, &RecordRevisionCounter { last: revision })?;
+
+        let revision = RecordRevision {
+            revision,
+            value: record.value.unwrap_or_default(),
+            tags: record.tags.unwrap_or_default(),
+            replaced_at: history::now(),
+        };
+
+        self.add_indy_object(wallet_handle, &history::revision_name(&key, revision.revision), &revision, &history::revision_tags(type_, name))?;
+
+        if let Some(max_revisions) = config.max_revisions {
+            self.prune_record_history(wallet_handle, type_, name, max_revisions)?;
+        }
+
+        Ok(())
+    }
+
+    // Configs are loaded once per opened wallet
+    fn _history_config(&self, wallet_handle: WalletHandle, type_: &str) -> IndyResult<Option<RecordHistoryConfig>> {
+        if !self.history_configs.borrow().contains_key(&wallet_handle) {
+            let mut configs = HashMap::new();
+            let mut search = self.search_indy_records::<RecordHistoryConfig>(wallet_handle, "{}", "{}")?;
+
+            while let Some(record) = search.fetch_next_record()? {
+                let config = record.get_value()
+                    .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "RecordHistoryConfig value not found"))?;
+
+                let config: RecordHistoryConfig = serde_json::from_str(config)
+                    .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize RecordHistoryConfig")?;
+
+                configs.insert(record.get_id().to_string(), config);
+            }
+
+            self.history_configs.borrow_mut().insert(wallet_handle, configs);
+        }
+
+        Ok(self.history_configs.borrow().get(&wallet_handle).and_then(|configs| configs.get(type_).cloned()))
+    }
+
     pub fn check(&self, handle: WalletHandle) -> IndyResult<()> {
         match self.wallets.borrow().get(&handle) {
             Some(_) => Ok(()),
@@ -471,6 +763,38 @@ impl WalletService {
         res
     }
 
+    pub fn export_wallet_to_buffer(&self, wallet_handle: WalletHandle, version: u32, key: (&KeyDerivationData, &MasterKey)) -> IndyResult<Vec<u8>> {
+        trace!("export_wallet_to_buffer >>> wallet_handle: {:?}, version: {:?}", wallet_handle, version);
+
+        let mut buffer = Vec::new();
+        self.export_wallet_to_writer(wallet_handle, &mut buffer, version, key)?;
+
+        trace!("export_wallet_to_buffer <<< len: {:?}", buffer.len());
+
+        Ok(buffer)
+    }
+
+    pub fn export_wallet_to_writer(&self, wallet_handle: WalletHandle, writer: &mut dyn Write, version: u32, key: (&KeyDerivationData, &MasterKey)) -> IndyResult<()> {
+        trace!("export_wallet_to_writer >>> wallet_handle: {:?}, version: {:?}", wallet_handle, version);
+
+        if version != 0 {
+            return Err(err_msg(IndyErrorKind::InvalidState, "Unsupported version"));
+        }
+
+        let (key_data, key) = key;
+
+        let wallets = self.wallets.borrow();
+        let wallet = wallets
+            .get(&wallet_handle)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidWalletHandle, "Unknown wallet handle"))?;
+
+        let res = export_continue(wallet, writer, version, key.clone(), key_data);
+
+        trace!("export_wallet_to_writer <<<");
+
+        res
+    }
+
     pub fn import_wallet_prepare(&self,
                                  config: &Config,
                                  credentials: &Credentials,
@@ -540,6 +864,44 @@ impl WalletService {
         self.pending_for_open.borrow().len()
     }
 
+    /// Returns counters of record operations grouped by storage type and operation.
+    pub fn get_operation_counters(&self) -> BTreeMap<(String, &'static str), OperationCounters> {
+        self.operation_counters.borrow().clone()
+    }
+
+    pub fn reset_operation_counters(&self) {
+        self.operation_counters.borrow_mut().clear();
+    }
+
+    fn _measure<T>(&self, wallet_handle: WalletHandle, operation: &'static str, params: &[&str], action: impl FnOnce() -> IndyResult<T>) -> IndyResult<T> {
+        // child of the span of the command that is executed, so storage operations are traced as its part
+        #[cfg(feature = "tracing_spans")]
+        let _span = tracing::info_span!(target: "indy_tracing", "indy.wallet.storage",
+                                        otel.name = %format!("indy.wallet.storage.{}", operation),
+                                        indy.wallet_handle = wallet_handle.0,
+                                        indy.storage_type = ?self.wallet_storage_types.borrow().get(&wallet_handle))
+            .entered();
+
+        let start = Instant::now();
+        let res = action();
+
+        // Operations on unknown handles don't reach any storage
+        if let Some(storage_type) = self.wallet_storage_types.borrow().get(&wallet_handle) {
+            let elapsed = start.elapsed();
+
+            self.operation_counters.borrow_mut()
+                .entry((storage_type.clone(), operation))
+                .or_default()
+                .add(elapsed.as_millis(), res.is_ok());
+
+            if slow_operations::is_slow(elapsed) {
+                slow_operations::log(&format!("wallet storage ({})", storage_type), operation, &slow_operations::params_digest(params), elapsed);
+            }
+        }
+
+        res
+    }
+
     fn _get_config_and_cred_for_storage<'a>(config: &Config, credentials: &Credentials, storage_types: &'a HashMap<String, Box<dyn WalletStorageType>>) -> IndyResult<(&'a Box<dyn WalletStorageType>, Option<String>, Option<String>)> {
         let storage_type = {
             let storage_type = config.storage_type
@@ -567,6 +929,10 @@ impl WalletService {
         Ok(())
     }
 
+    fn _get_storage_type(config: &Config) -> String {
+        config.storage_type.clone().unwrap_or_else(|| "default".to_string())
+    }
+
     fn _get_wallet_id(config: &Config) -> String {
         let wallet_path = config.storage_config.as_ref().and_then(|storage_config| storage_config["path"].as_str()).unwrap_or("");
         let wallet_id = format!("{}{}", config.id, wallet_path);
@@ -700,6 +1066,22 @@ impl WalletRecord {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AddRecord {
+    pub type_: String,
+    pub id: String,
+    pub value: String,
+    pub tags: Tags,
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+pub struct UpsertRecord {
+    pub id: String,
+    pub value: String,
+    #[serde(default)]
+    pub tags: Tags,
+}
+
 fn default_true() -> bool { true }
 
 fn default_false() -> bool { false }
@@ -772,8 +1154,31 @@ pub struct SearchOptions {
     retrieve_type: bool,
     #[serde(default = "default_true")]
     retrieve_value: bool,
-    #[serde(default = "default_false")]
-    retrieve_tags: bool,
+    #[serde(default)]
+    retrieve_tags: RetrieveTags,
+}
+
+/// Tags to retrieve with searched records: either flag for all tags or list of tag names.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum RetrieveTags {
+    All(bool),
+    Names(Vec<String>),
+}
+
+impl RetrieveTags {
+    pub fn is_requested(&self) -> bool {
+        match self {
+            RetrieveTags::All(retrieve) => *retrieve,
+            RetrieveTags::Names(_) => true,
+        }
+    }
+}
+
+impl Default for RetrieveTags {
+    fn default() -> RetrieveTags {
+        RetrieveTags::All(false)
+    }
 }
 
 impl SearchOptions {
@@ -783,7 +1188,19 @@ impl SearchOptions {
             retrieve_total_count: true,
             retrieve_type: true,
             retrieve_value: true,
-            retrieve_tags: false,
+            retrieve_tags: RetrieveTags::All(false),
+        };
+
+        serde_json::to_string(&options).unwrap()
+    }
+
+    pub fn id_value_tags() -> String {
+        let options = SearchOptions {
+            retrieve_records: true,
+            retrieve_total_count: true,
+            retrieve_type: true,
+            retrieve_value: true,
+            retrieve_tags: RetrieveTags::All(true),
         };
 
         serde_json::to_string(&options).unwrap()
@@ -797,7 +1214,7 @@ impl Default for SearchOptions {
             retrieve_total_count: false,
             retrieve_type: false,
             retrieve_value: true,
-            retrieve_tags: false,
+            retrieve_tags: RetrieveTags::All(false),
         }
     }
 }
@@ -842,7 +1259,7 @@ mod tests {
             let rekey_data: Option<KeyDerivationData> = credentials.rekey.as_ref().map(|ref rekey|
                 KeyDerivationData::from_passphrase_with_new_salt(rekey, &credentials.rekey_derivation_method));
 
-            self.pending_for_open.borrow_mut().insert(wallet_handle, (WalletService::_get_wallet_id(config), storage, metadata, rekey_data.clone()));
+            self.pending_for_open.borrow_mut().insert(wallet_handle, (WalletService::_get_wallet_id(config), WalletService::_get_storage_type(config), storage, metadata, rekey_data.clone()));
 
             let key = key_derivation_data.calc_master_key()?;
 
@@ -909,6 +1326,78 @@ mod tests {
         _cleanup("wallet_service_register_type_works");
     }
 
+    #[test]
+    fn wallet_service_unregister_type_works() {
+        _cleanup("wallet_service_unregister_type_works");
+
+        let wallet_service = WalletService::new();
+        _register_inmem_wallet(&wallet_service);
+
+        wallet_service.create_wallet(&_config_inmem(), &RAW_CREDENTIAL, (&RAW_KDD, &RAW_MASTER_KEY)).unwrap();
+        let wallet_handle = wallet_service.open_wallet(&_config_inmem(), &RAW_CREDENTIAL).unwrap();
+
+        let res = wallet_service.unregister_wallet_storage("inmem");
+        assert_kind!(IndyErrorKind::InvalidState, res);
+
+        wallet_service.close_wallet(wallet_handle).unwrap();
+        wallet_service.unregister_wallet_storage("inmem").unwrap();
+
+        let res = wallet_service.open_wallet(&_config_inmem(), &RAW_CREDENTIAL);
+        assert_kind!(IndyErrorKind::UnknownWalletStorageType, res);
+
+        let res = wallet_service.unregister_wallet_storage("inmem");
+        assert_kind!(IndyErrorKind::UnknownWalletStorageType, res);
+
+        let res = wallet_service.unregister_wallet_storage("default");
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+
+        // registered again
+        _register_inmem_wallet(&wallet_service);
+        let wallet_handle = wallet_service.open_wallet(&_config_inmem(), &RAW_CREDENTIAL).unwrap();
+        wallet_service.close_wallet(wallet_handle).unwrap();
+
+        _cleanup("wallet_service_unregister_type_works");
+    }
+
+    #[test]
+    fn wallet_service_set_query_extensions_works() {
+        _cleanup("wallet_service_set_query_extensions_works");
+
+        let wallet_service = WalletService::new();
+        _register_inmem_wallet(&wallet_service);
+
+        let query = r#"{"~tags": {"$contains": ["tag"]}}"#;
+
+        wallet_service.create_wallet(&_config_inmem(), &RAW_CREDENTIAL, (&RAW_KDD, &RAW_MASTER_KEY)).unwrap();
+        let wallet_handle = wallet_service.open_wallet(&_config_inmem(), &RAW_CREDENTIAL).unwrap();
+
+        let res = wallet_service.search_records(wallet_handle, "type", query, &_fetch_options(true, true, true));
+        assert_kind!(IndyErrorKind::WalletQueryError, res);
+
+        wallet_service.close_wallet(wallet_handle).unwrap();
+        wallet_service.set_wallet_storage_query_extensions("inmem", vec!["$contains".to_string()]).unwrap();
+
+        let wallet_handle = wallet_service.open_wallet(&_config_inmem(), &RAW_CREDENTIAL).unwrap();
+        wallet_service.search_records(wallet_handle, "type", query, &_fetch_options(true, true, true)).unwrap();
+
+        // extensions work with unencrypted tags only
+        let res = wallet_service.search_records(wallet_handle, "type", r#"{"tags": {"$contains": ["tag"]}}"#, &_fetch_options(true, true, true));
+        assert_kind!(IndyErrorKind::WalletQueryError, res);
+
+        wallet_service.close_wallet(wallet_handle).unwrap();
+
+        let res = wallet_service.set_wallet_storage_query_extensions("default", vec!["$contains".to_string()]);
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+
+        let res = wallet_service.set_wallet_storage_query_extensions("unknown", vec!["$contains".to_string()]);
+        assert_kind!(IndyErrorKind::UnknownWalletStorageType, res);
+
+        let res = wallet_service.set_wallet_storage_query_extensions("inmem", vec!["contains".to_string()]);
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+
+        _cleanup("wallet_service_set_query_extensions_works");
+    }
+
     #[test]
     fn wallet_service_create_wallet_works() {
         test::cleanup_wallet("wallet_service_create_wallet_works");
@@ -1297,6 +1786,81 @@ mod tests {
         test::cleanup_wallet("wallet_service_add_record_works");
     }
 
+    #[test]
+    fn wallet_service_add_records_works() {
+        test::cleanup_wallet("wallet_service_add_records_works");
+        {
+            let wallet_service = WalletService::new();
+            wallet_service.create_wallet(&_config("wallet_service_add_records_works"), &RAW_CREDENTIAL, (&RAW_KDD, &RAW_MASTER_KEY)).unwrap();
+            let wallet_handle = wallet_service.open_wallet(&_config("wallet_service_add_records_works"), &RAW_CREDENTIAL).unwrap();
+
+            wallet_service.add_record(wallet_handle, "type2", "key1", "value1", &HashMap::new()).unwrap();
+
+            let records = vec![
+                AddRecord { type_: "type1".to_string(), id: "key1".to_string(), value: "value1".to_string(), tags: HashMap::new() },
+                AddRecord { type_: "type2".to_string(), id: "key1".to_string(), value: "value2".to_string(), tags: HashMap::new() },
+            ];
+
+            let res = wallet_service.add_records(wallet_handle, &records);
+            assert_kind!(IndyErrorKind::WalletItemAlreadyExists, res);
+            let res = wallet_service.get_record(wallet_handle, "type1", "key1", "{}");
+            assert_kind!(IndyErrorKind::WalletItemNotFound, res);
+
+            wallet_service.delete_record(wallet_handle, "type2", "key1").unwrap();
+            wallet_service.add_records(wallet_handle, &records).unwrap();
+
+            wallet_service.get_record(wallet_handle, "type1", "key1", "{}").unwrap();
+            let record = wallet_service.get_record(wallet_handle, "type2", "key1", "{}").unwrap();
+            assert_eq!(record.get_value().unwrap(), "value2");
+        }
+        test::cleanup_wallet("wallet_service_add_records_works");
+    }
+
+    #[test]
+    fn wallet_service_get_operation_counters_works() {
+        test::cleanup_wallet("wallet_service_get_operation_counters_works");
+        {
+            let wallet_service = WalletService::new();
+            wallet_service.create_wallet(&_config("wallet_service_get_operation_counters_works"), &RAW_CREDENTIAL, (&RAW_KDD, &RAW_MASTER_KEY)).unwrap();
+            let wallet_handle = wallet_service.open_wallet(&_config("wallet_service_get_operation_counters_works"), &RAW_CREDENTIAL).unwrap();
+
+            wallet_service.add_record(wallet_handle, "type", "key1", "value1", &HashMap::new()).unwrap();
+            wallet_service.get_record(wallet_handle, "type", "key1", "{}").unwrap();
+            wallet_service.get_record(wallet_handle, "type", "key2", "{}").unwrap_err();
+            wallet_service.get_record(INVALID_WALLET_HANDLE, "type", "key1", "{}").unwrap_err();
+
+            let counters = wallet_service.get_operation_counters();
+            assert_eq!(counters.len(), 2);
+
+            let add_counters = counters[&("default".to_string(), ADD_OPERATION)];
+            assert_eq!(add_counters.count, 1);
+            assert_eq!(add_counters.failed_count, 0);
+
+            let get_counters = counters[&("default".to_string(), GET_OPERATION)];
+            assert_eq!(get_counters.count, 2);
+            assert_eq!(get_counters.failed_count, 1);
+        }
+        test::cleanup_wallet("wallet_service_get_operation_counters_works");
+    }
+
+    #[test]
+    fn wallet_service_get_operation_counters_works_for_plugged() {
+        _cleanup("wallet_service_get_operation_counters_works_for_plugged");
+
+        let wallet_service = WalletService::new();
+        _register_inmem_wallet(&wallet_service);
+
+        wallet_service.create_wallet(&_config_inmem(), &RAW_CREDENTIAL, (&RAW_KDD, &RAW_MASTER_KEY)).unwrap();
+        let wallet_handle = wallet_service.open_wallet(&_config_inmem(), &RAW_CREDENTIAL).unwrap();
+
+        wallet_service.add_record(wallet_handle, "type", "key1", "value1", &HashMap::new()).unwrap();
+        wallet_service.search_records(wallet_handle, "type", "{}", "{}").unwrap();
+
+        let counters = wallet_service.get_operation_counters();
+        assert_eq!(counters[&("inmem".to_string(), ADD_OPERATION)].count, 1);
+        assert_eq!(counters[&("inmem".to_string(), SEARCH_OPERATION)].count, 1);
+    }
+
     #[test]
     fn wallet_service_add_record_works_for_plugged() {
         _cleanup("wallet_service_add_record_works_for_plugged");
@@ -1311,6 +1875,68 @@ mod tests {
         wallet_service.get_record(wallet_handle, "type", "key1", "{}").unwrap();
     }
 
+    #[test]
+    fn wallet_service_upsert_records_works_for_plugged() {
+        _cleanup("wallet_service_upsert_records_works_for_plugged");
+
+        let wallet_service = WalletService::new();
+        _register_inmem_wallet(&wallet_service);
+
+        wallet_service.create_wallet(&_config_inmem(), &RAW_CREDENTIAL, (&RAW_KDD, &RAW_MASTER_KEY)).unwrap();
+        let wallet_handle = wallet_service.open_wallet(&_config_inmem(), &RAW_CREDENTIAL).unwrap();
+
+        wallet_service.add_record(wallet_handle, "type", "key1", "value1", &HashMap::new()).unwrap();
+
+        let records = vec![
+            UpsertRecord { id: "key1".to_string(), value: "value2".to_string(), tags: HashMap::new() },
+            UpsertRecord { id: "key2".to_string(), value: "value1".to_string(), tags: HashMap::new() },
+        ];
+
+        let inserted = wallet_service.upsert_records(wallet_handle, "type", &records).unwrap();
+        assert_eq!(inserted, vec![false, true]);
+
+        let record = wallet_service.get_record(wallet_handle, "type", "key1", "{}").unwrap();
+        assert_eq!(record.get_value().unwrap(), "value2");
+        wallet_service.get_record(wallet_handle, "type", "key2", "{}").unwrap();
+    }
+
+    #[test]
+    fn wallet_service_modify_record_value_works_for_plugged() {
+        _cleanup("wallet_service_modify_record_value_works_for_plugged");
+
+        let wallet_service = WalletService::new();
+        _register_inmem_wallet(&wallet_service);
+
+        wallet_service.create_wallet(&_config_inmem(), &RAW_CREDENTIAL, (&RAW_KDD, &RAW_MASTER_KEY)).unwrap();
+        let wallet_handle = wallet_service.open_wallet(&_config_inmem(), &RAW_CREDENTIAL).unwrap();
+
+        wallet_service.add_record(wallet_handle, "type", "key1", "value1", &HashMap::new()).unwrap();
+
+        let new_value = wallet_service.modify_record_value(wallet_handle, "type", "key1", &mut |value| Ok(format!("{}-modified", value))).unwrap();
+        assert_eq!(new_value, "value1-modified");
+
+        let record = wallet_service.get_record(wallet_handle, "type", "key1", "{}").unwrap();
+        assert_eq!(record.get_value().unwrap(), "value1-modified");
+    }
+
+    #[test]
+    fn wallet_service_get_record_types_works_for_plugged() {
+        _cleanup("wallet_service_get_record_types_works_for_plugged");
+
+        let wallet_service = WalletService::new();
+        _register_inmem_wallet(&wallet_service);
+
+        wallet_service.create_wallet(&_config_inmem(), &RAW_CREDENTIAL, (&RAW_KDD, &RAW_MASTER_KEY)).unwrap();
+        let wallet_handle = wallet_service.open_wallet(&_config_inmem(), &RAW_CREDENTIAL).unwrap();
+
+        wallet_service.add_record(wallet_handle, "type1", "key1", "value1", &HashMap::new()).unwrap();
+        wallet_service.add_record(wallet_handle, "type1", "key2", "value2", &HashMap::new()).unwrap();
+        wallet_service.add_record(wallet_handle, "type2", "key1", "value1", &HashMap::new()).unwrap();
+
+        let types = wallet_service.get_record_types(wallet_handle).unwrap();
+        assert_eq!(types.into_iter().collect::<Vec<_>>(), vec![("type1".to_string(), 2), ("type2".to_string(), 1)]);
+    }
+
     #[test]
     fn wallet_service_get_record_works_for_id_only() {
         test::cleanup_wallet("wallet_service_get_record_works_for_id_only");
@@ -1625,6 +2251,109 @@ mod tests {
         assert_eq!(expected_tags, retrieved_tags);
     }
 
+    /**
+     * Record history tests
+     */
+    #[test]
+    fn wallet_service_record_history_works() {
+        test::cleanup_wallet("wallet_service_record_history_works");
+        {
+            let wallet_service = WalletService::new();
+            wallet_service.create_wallet(&_config("wallet_service_record_history_works"), &RAW_CREDENTIAL, (&RAW_KDD, &RAW_MASTER_KEY)).unwrap();
+            let wallet_handle = wallet_service.open_wallet(&_config("wallet_service_record_history_works"), &RAW_CREDENTIAL).unwrap();
+
+            let tags: Tags = serde_json::from_str(r#"{"tag_name_1":"tag_value_1"}"#).unwrap();
+            wallet_service.add_record(wallet_handle, "type", "name", "value1", &tags).unwrap();
+            wallet_service.add_record(wallet_handle, "other_type", "name", "value1", &HashMap::new()).unwrap();
+
+            wallet_service.enable_record_history(wallet_handle, "type", &RecordHistoryConfig::default()).unwrap();
+
+            wallet_service.update_record_value(wallet_handle, "type", "name", "value2").unwrap();
+            wallet_service.update_record_tags(wallet_handle, "type", "name", &HashMap::new()).unwrap();
+            wallet_service.update_record_value(wallet_handle, "other_type", "name", "value2").unwrap();
+
+            let revisions = wallet_service.get_record_revisions(wallet_handle, "type", "name").unwrap();
+            assert_eq!(vec![1, 2], revisions.iter().map(|revision| revision.revision).collect::<Vec<_>>());
+            assert_eq!(("value1", &tags), (revisions[0].value.as_str(), &revisions[0].tags));
+            assert_eq!(("value2", &tags), (revisions[1].value.as_str(), &revisions[1].tags));
+
+            assert_eq!(revisions[1], wallet_service.get_record_revision(wallet_handle, "type", "name", 2).unwrap());
+            assert_kind!(IndyErrorKind::WalletItemNotFound, wallet_service.get_record_revision(wallet_handle, "type", "name", 3));
+
+            assert!(wallet_service.get_record_revisions(wallet_handle, "other_type", "name").unwrap().is_empty());
+        }
+        test::cleanup_wallet("wallet_service_record_history_works");
+    }
+
+    #[test]
+    fn wallet_service_record_history_works_for_delete_and_prune() {
+        test::cleanup_wallet("wallet_service_record_history_works_for_delete_and_prune");
+        {
+            let wallet_service = WalletService::new();
+            wallet_service.create_wallet(&_config("wallet_service_record_history_works_for_delete_and_prune"), &RAW_CREDENTIAL, (&RAW_KDD, &RAW_MASTER_KEY)).unwrap();
+            let wallet_handle = wallet_service.open_wallet(&_config("wallet_service_record_history_works_for_delete_and_prune"), &RAW_CREDENTIAL).unwrap();
+
+            wallet_service.enable_record_history(wallet_handle, "type", &RecordHistoryConfig::default()).unwrap();
+
+            wallet_service.add_record(wallet_handle, "type", "name", "value1", &HashMap::new()).unwrap();
+            wallet_service.update_record_value(wallet_handle, "type", "name", "value2").unwrap();
+            wallet_service.delete_record(wallet_handle, "type", "name").unwrap();
+
+            assert_eq!(1, wallet_service.prune_record_history(wallet_handle, "type", "name", 1).unwrap());
+
+            // Numbers of pruned revisions are not reused
+            wallet_service.add_record(wallet_handle, "type", "name", "value3", &HashMap::new()).unwrap();
+            wallet_service.update_record_value(wallet_handle, "type", "name", "value4").unwrap();
+
+            let revisions = wallet_service.get_record_revisions(wallet_handle, "type", "name").unwrap();
+            assert_eq!(vec![(2, "value2"), (3, "value3")], revisions.iter().map(|revision| (revision.revision, revision.value.as_str())).collect::<Vec<_>>());
+
+            assert_eq!(2, wallet_service.prune_record_history(wallet_handle, "type", "name", 0).unwrap());
+            assert!(wallet_service.get_record_revisions(wallet_handle, "type", "name").unwrap().is_empty());
+        }
+        test::cleanup_wallet("wallet_service_record_history_works_for_delete_and_prune");
+    }
+
+    #[test]
+    fn wallet_service_record_history_works_for_max_revisions_and_disable() {
+        test::cleanup_wallet("wallet_service_record_history_works_for_max_revisions_and_disable");
+        {
+            let wallet_service = WalletService::new();
+            wallet_service.create_wallet(&_config("wallet_service_record_history_works_for_max_revisions_and_disable"), &RAW_CREDENTIAL, (&RAW_KDD, &RAW_MASTER_KEY)).unwrap();
+            let wallet_handle = wallet_service.open_wallet(&_config("wallet_service_record_history_works_for_max_revisions_and_disable"), &RAW_CREDENTIAL).unwrap();
+
+            wallet_service.enable_record_history(wallet_handle, "type", &RecordHistoryConfig { max_revisions: Some(2) }).unwrap();
+
+            wallet_service.add_record(wallet_handle, "type", "name", "value0", &HashMap::new()).unwrap();
+            for value in &["value1", "value2", "value3"] {
+                wallet_service.modify_record_value(wallet_handle, "type", "name", &mut |_| Ok(value.to_string())).unwrap();
+            }
+
+            let revisions = wallet_service.get_record_revisions(wallet_handle, "type", "name").unwrap();
+            assert_eq!(vec![(2, "value1"), (3, "value2")], revisions.iter().map(|revision| (revision.revision, revision.value.as_str())).collect::<Vec<_>>());
+
+            wallet_service.disable_record_history(wallet_handle, "type").unwrap();
+            wallet_service.update_record_value(wallet_handle, "type", "name", "value4").unwrap();
+
+            assert_eq!(2, wallet_service.get_record_revisions(wallet_handle, "type", "name").unwrap().len());
+        }
+        test::cleanup_wallet("wallet_service_record_history_works_for_max_revisions_and_disable");
+    }
+
+    #[test]
+    fn wallet_service_enable_record_history_works_for_invalid_config() {
+        test::cleanup_wallet("wallet_service_enable_record_history_works_for_invalid_config");
+        {
+            let wallet_service = WalletService::new();
+            wallet_service.create_wallet(&_config("wallet_service_enable_record_history_works_for_invalid_config"), &RAW_CREDENTIAL, (&RAW_KDD, &RAW_MASTER_KEY)).unwrap();
+            let wallet_handle = wallet_service.open_wallet(&_config("wallet_service_enable_record_history_works_for_invalid_config"), &RAW_CREDENTIAL).unwrap();
+
+            assert_kind!(IndyErrorKind::InvalidStructure, wallet_service.enable_record_history(wallet_handle, "type", &RecordHistoryConfig { max_revisions: Some(0) }));
+            assert_kind!(IndyErrorKind::InvalidStructure, wallet_service.enable_record_history(wallet_handle, "Indy::RecordRevision", &RecordHistoryConfig::default()));
+        }
+        test::cleanup_wallet("wallet_service_enable_record_history_works_for_invalid_config");
+    }
+
     /**
      * Update tags tests
      */
@@ -1987,6 +2716,28 @@ mod tests {
         test::cleanup_wallet("wallet_service_export_wallet_1_item_raw_method");
     }
 
+    #[test]
+    fn wallet_service_export_wallet_to_buffer_works() {
+        test::cleanup_wallet("wallet_service_export_wallet_to_buffer_works");
+        let export_config = _export_config_raw("wallet_service_export_wallet_to_buffer_works");
+        {
+            let wallet_service = WalletService::new();
+            wallet_service.create_wallet(&_config("wallet_service_export_wallet_to_buffer_works"), &RAW_CREDENTIAL, (&RAW_KDD, &RAW_MASTER_KEY)).unwrap();
+            let wallet_handle = wallet_service.open_wallet(&_config("wallet_service_export_wallet_to_buffer_works"), &RAW_CREDENTIAL).unwrap();
+
+            wallet_service.add_record(wallet_handle, "type", "key1", "value1", &HashMap::new()).unwrap();
+
+            let export_path = remove_exported_wallet(&export_config);
+            let (kdd, master_key) = _export_key_raw("key_wallet_service_export_wallet_to_buffer_works");
+            wallet_service.export_wallet(wallet_handle, &export_config, 0, (&kdd, &master_key)).unwrap();
+            let buffer = wallet_service.export_wallet_to_buffer(wallet_handle, 0, (&kdd, &master_key)).unwrap();
+
+            assert_eq!(fs::metadata(&export_path).unwrap().len(), buffer.len() as u64);
+        }
+        remove_exported_wallet(&export_config);
+        test::cleanup_wallet("wallet_service_export_wallet_to_buffer_works");
+    }
+
     #[test]
     fn wallet_service_export_wallet_returns_error_if_file_exists() {
         test::cleanup_wallet("wallet_service_export_wallet_returns_error_if_file_exists");
@@ -0,0 +1,62 @@
+pub const ADD_OPERATION: &str = "add";
+pub const GET_OPERATION: &str = "get";
+pub const UPDATE_OPERATION: &str = "update";
+pub const UPDATE_TAGS_OPERATION: &str = "update_tags";
+pub const DELETE_OPERATION: &str = "delete";
+pub const SEARCH_OPERATION: &str = "search";
+
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Default, PartialEq)]
+pub struct OperationCounters {
+    pub count: u128,
+    pub failed_count: u128,
+    pub duration_ms_sum: u128,
+}
+
+impl OperationCounters {
+    pub fn add(&mut self, duration: u128, succeeded: bool) {
+        self.count += 1;
+        self.duration_ms_sum += duration;
+        if !succeeded {
+            self.failed_count += 1;
+        }
+    }
+
+    /// Returns counters accumulated after `previous` snapshot or all counters if they were reset in between.
+    pub fn since(&self, previous: &OperationCounters) -> OperationCounters {
+        if self.count < previous.count {
+            return *self;
+        }
+
+        OperationCounters {
+            count: self.count - previous.count,
+            failed_count: self.failed_count.saturating_sub(previous.failed_count),
+            duration_ms_sum: self.duration_ms_sum.saturating_sub(previous.duration_ms_sum),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn operation_counters_add_works() {
+        let mut counters = OperationCounters::default();
+
+        counters.add(5, true);
+        counters.add(2, false);
+
+        assert_eq!(counters, OperationCounters { count: 2, failed_count: 1, duration_ms_sum: 7 });
+    }
+
+    #[test]
+    fn operation_counters_since_works() {
+        let mut counters = OperationCounters::default();
+        counters.add(5, true);
+        let snapshot = counters;
+        counters.add(2, false);
+
+        assert_eq!(counters.since(&snapshot), OperationCounters { count: 1, failed_count: 1, duration_ms_sum: 2 });
+        assert_eq!(snapshot.since(&counters), snapshot);
+    }
+}
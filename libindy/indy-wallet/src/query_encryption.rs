@@ -1,8 +1,11 @@
+use std::collections::BTreeMap;
+
 use indy_api_types::errors::prelude::*;
 
 use super::wallet::Keys;
 use super::language::{Operator, TargetValue, TagName};
 use super::encryption::encrypt_as_searchable;
+use super::range_index;
 use indy_utils::wql::Query;
 
 // Performs encryption of WQL query
@@ -21,18 +24,30 @@ fn transform(query: Query, keys: &Keys) -> IndyResult<Operator> {
             let (encrypted_name, encrypted_value) = encrypt_name_value(name, value, keys)?;
             Ok(Operator::Neq(encrypted_name, encrypted_value))
         }
+        Query::Gt(name, value) if range_index::is_range_tag(&name) => {
+            encrypt_range(&name, &value, |value| value.checked_add(1).map(|from| (from, u64::max_value())), keys)
+        }
         Query::Gt(name, value) => {
             let (encrypted_name, encrypted_value) = encrypt_name_value(name, value, keys)?;
             Ok(Operator::Gt(encrypted_name, encrypted_value))
         }
+        Query::Gte(name, value) if range_index::is_range_tag(&name) => {
+            encrypt_range(&name, &value, |value| Some((value, u64::max_value())), keys)
+        }
         Query::Gte(name, value) => {
             let (encrypted_name, encrypted_value) = encrypt_name_value(name, value, keys)?;
             Ok(Operator::Gte(encrypted_name, encrypted_value))
         }
+        Query::Lt(name, value) if range_index::is_range_tag(&name) => {
+            encrypt_range(&name, &value, |value| value.checked_sub(1).map(|to| (0, to)), keys)
+        }
         Query::Lt(name, value) => {
             let (encrypted_name, encrypted_value) = encrypt_name_value(name, value, keys)?;
             Ok(Operator::Lt(encrypted_name, encrypted_value))
         }
+        Query::Lte(name, value) if range_index::is_range_tag(&name) => {
+            encrypt_range(&name, &value, |value| Some((0, value)), keys)
+        }
         Query::Lte(name, value) => {
             let (encrypted_name, encrypted_value) = encrypt_name_value(name, value, keys)?;
             Ok(Operator::Lte(encrypted_name, encrypted_value))
@@ -62,7 +77,19 @@ fn transform(query: Query, keys: &Keys) -> IndyResult<Operator> {
         }
         Query::And(operators) => Ok(Operator::And(transform_list_operators(operators, keys)?)),
         Query::Or(operators) => Ok(Operator::Or(transform_list_operators(operators, keys)?)),
-        Query::Not(boxed_operator) => Ok(Operator::Not(Box::new(transform(*boxed_operator, keys)?)))
+        Query::Not(boxed_operator) => Ok(Operator::Not(Box::new(transform(*boxed_operator, keys)?))),
+        // Values of encrypted tags are only comparable for equality, so extensions work with plain tags
+        Query::Ext(name, operator, value) => {
+            match TagName::from(name.clone())? {
+                TagName::PlainTagName(ref plain_name) => {
+                    let encrypted_name = encrypt_as_searchable(&plain_name[..], &keys.tag_name_key, &keys.tags_hmac_key);
+                    Ok(Operator::Ext(TagName::PlainTagName(encrypted_name), operator, value))
+                }
+                TagName::EncryptedTagName(_) => {
+                    Err(err_msg(IndyErrorKind::WalletQueryError, format!("Operator {} can be used only with unencrypted tags, but used with {}", operator, name)))
+                }
+            }
+        }
     }
 }
 
@@ -77,6 +104,45 @@ fn transform_list_operators(operators: Vec<Query>, keys: &Keys) -> IndyResult<Ve
     Ok(transformed)
 }
 
+// Replaces range query on range tag with equality queries on its index tags.
+// Index tags of the blocks on the same level are queried with single $in.
+fn encrypt_range(name: &str, value: &str, bounds: fn(u64) -> Option<(u64, u64)>, keys: &Keys) -> IndyResult<Operator> {
+    let value = range_index::parse_value(value)
+        .ok_or_else(|| err_msg(IndyErrorKind::WalletQueryError, format!("Value of range tag {} must be unsigned integer", name)))?;
+
+    let encrypt_name = |level: u32| TagName::EncryptedTagName(
+        encrypt_as_searchable(range_index::index_tag_name(name, level).as_bytes(), &keys.tag_name_key, &keys.tags_hmac_key));
+    let encrypt_value = |value: &str| TargetValue::Encrypted(
+        encrypt_as_searchable(value.as_bytes(), &keys.tag_value_key, &keys.tags_hmac_key));
+
+    let blocks = match bounds(value) {
+        Some((from, to)) => range_index::range_blocks(from, to),
+        // Empty range. Empty value is never stored in index tags, so nothing is matched
+        None => return Ok(Operator::Eq(encrypt_name(0), encrypt_value("")))
+    };
+
+    let mut prefixes: BTreeMap<u32, Vec<TargetValue>> = BTreeMap::new();
+
+    for (level, prefix) in blocks {
+        prefixes.entry(level).or_insert_with(Vec::new).push(encrypt_value(&prefix.to_string()));
+    }
+
+    let mut operators: Vec<Operator> = prefixes.into_iter()
+        .map(|(level, mut values)|
+            if values.len() == 1 {
+                Operator::Eq(encrypt_name(level), values.remove(0))
+            } else {
+                Operator::In(encrypt_name(level), values)
+            })
+        .collect();
+
+    if operators.len() == 1 {
+        Ok(operators.remove(0))
+    } else {
+        Ok(Operator::Or(operators))
+    }
+}
+
 // Encrypts a single tag name, tag value pair.
 // If the tag name is EncryptedTagName enum variant, encrypts both the tag name and the tag value
 // If the tag name is PlainTagName enum variant, encrypts only the tag name
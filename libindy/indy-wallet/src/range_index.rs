@@ -0,0 +1,132 @@
+// Bucketized index of range tags.
+//
+// Tags with names starting with "#~" are range tags. They are encrypted as other tags,
+// but their values must be unsigned integers. Other tags starting with '#' are regular
+// encrypted tags, so such tags created before range index was introduced keep working.
+// For each range tag wallet stores hidden encrypted index tags with prefixes of the value
+// in base 16, one per level. So range queries can be replaced by equality queries on
+// prefixes of blocks that cover the range.
+// Storage learns only which records share value prefixes, but not the values.
+
+pub(super) const RANGE_TAG_PREFIX: &str = "#~";
+
+// Can't be passed in tag names through C API, so index tags never clash with user tags
+const INDEX_TAG_SEPARATOR: char = '\u{0}';
+const LEVEL_BITS: u32 = 4;
+const LEVELS: u32 = 64 / LEVEL_BITS;
+
+pub(super) fn is_range_tag(name: &str) -> bool {
+    name.starts_with(RANGE_TAG_PREFIX)
+}
+
+pub(super) fn is_index_tag(name: &str) -> bool {
+    is_range_tag(name) && name.contains(INDEX_TAG_SEPARATOR)
+}
+
+pub(super) fn parse_value(value: &str) -> Option<u64> {
+    value.parse().ok()
+}
+
+pub(super) fn index_tag_name(name: &str, level: u32) -> String {
+    format!("{}{}{}", name, INDEX_TAG_SEPARATOR, level)
+}
+
+pub(super) fn index_tag_names(name: &str) -> Vec<String> {
+    (0..LEVELS)
+        .map(|level| index_tag_name(name, level))
+        .collect()
+}
+
+pub(super) fn index_tags(name: &str, value: u64) -> Vec<(String, String)> {
+    (0..LEVELS)
+        .map(|level| (index_tag_name(name, level), (value >> (level * LEVEL_BITS)).to_string()))
+        .collect()
+}
+
+// Splits inclusive range to the minimal list of aligned blocks.
+// Block (level, prefix) covers all values with the prefix on the level.
+pub(super) fn range_blocks(from: u64, to: u64) -> Vec<(u32, u64)> {
+    let mut blocks = Vec::new();
+    let mut from = u128::from(from);
+    let to = u128::from(to);
+
+    while from <= to {
+        let mut level = 0;
+
+        while level + 1 < LEVELS {
+            let size = 1u128 << ((level + 1) * LEVEL_BITS);
+
+            if from % size != 0 || from + size - 1 > to {
+                break;
+            }
+
+            level += 1;
+        }
+
+        blocks.push((level, (from >> (level * LEVEL_BITS)) as u64));
+        from += 1u128 << (level * LEVEL_BITS);
+    }
+
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn _covered(blocks: &[(u32, u64)]) -> Vec<(u128, u128)> {
+        blocks.iter()
+            .map(|&(level, prefix)| {
+                let from = u128::from(prefix) << (level * LEVEL_BITS);
+                (from, from + (1u128 << (level * LEVEL_BITS)) - 1)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn range_blocks_works() {
+        assert_eq!(vec![(0, 5)], range_blocks(5, 5));
+        assert_eq!(vec![(1, 1)], range_blocks(16, 31));
+        assert_eq!(vec![(0, 15), (1, 1), (0, 32)], range_blocks(15, 32));
+        assert_eq!(vec![(2, 0)], range_blocks(0, 255));
+    }
+
+    #[test]
+    fn range_blocks_works_for_whole_range() {
+        let blocks = range_blocks(0, u64::max_value());
+
+        assert_eq!(16, blocks.len());
+        assert!(blocks.iter().all(|&(level, _)| level == LEVELS - 1));
+    }
+
+    #[test]
+    fn range_blocks_works_for_exact_cover() {
+        for &(from, to) in &[(0, 1000), (17, 4097), (123456789, 987654321), (u64::max_value() - 300, u64::max_value())] {
+            let covered = _covered(&range_blocks(from, to));
+
+            assert_eq!(u128::from(from), covered[0].0);
+            assert_eq!(u128::from(to), covered[covered.len() - 1].1);
+            assert!(covered.windows(2).all(|pair| pair[0].1 + 1 == pair[1].0));
+        }
+    }
+
+    #[test]
+    fn is_range_tag_works() {
+        assert!(is_range_tag("#~amount"));
+        assert!(!is_range_tag("#amount"));
+        assert!(!is_range_tag("~amount"));
+        assert!(!is_range_tag("amount"));
+    }
+
+    #[test]
+    fn index_tags_works() {
+        let tags = index_tags("#~amount", 0x1234);
+
+        assert_eq!(LEVELS as usize, tags.len());
+        assert_eq!((index_tag_name("#~amount", 0), "4660".to_string()), tags[0]);
+        assert_eq!((index_tag_name("#~amount", 2), "18".to_string()), tags[2]);
+        assert_eq!((index_tag_name("#~amount", 15), "0".to_string()), tags[15]);
+        assert!(tags.iter().all(|(name, _)| is_index_tag(name)));
+        assert!(!is_index_tag("#~amount"));
+    }
+}
@@ -0,0 +1,178 @@
+// Explanation of how storage executes wallet search.
+//
+// Tag indexes of storage can be used only by conditions that select records by tag value.
+// Conditions negating tags ($neq, $not) and $like patterns starting with a wildcard
+// make storage to check every record of the type.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use indy_utils::wql::Query;
+
+use super::range_index;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchPlan {
+    /// Optimised query that is actually executed
+    pub query: Query,
+    /// Tags used by the query
+    pub tags: Vec<SearchPlanTag>,
+    /// Whether candidate records are selected by tag indexes instead of scanning all records of the type
+    pub uses_index: bool,
+    /// Count of records of the type
+    pub records_of_type: usize,
+    /// Estimated count of records storage checks to execute the query
+    pub estimated_scan: usize,
+    /// Plan reported by storage itself if it's able to, for example SQLite `EXPLAIN QUERY PLAN` details
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage_plan: Option<Vec<String>>,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchPlanTag {
+    pub name: String,
+    pub kind: SearchPlanTagKind,
+    pub operators: BTreeSet<String>,
+    /// Whether all conditions on the tag can use tag index
+    pub indexed: bool,
+}
+
+#[derive(Debug, Serialize, PartialEq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchPlanTagKind {
+    Encrypted,
+    Plaintext,
+    Range,
+}
+
+impl SearchPlan {
+    pub fn new(query: Query, records_of_type: usize, matched_records: usize, storage_plan: Option<Vec<String>>) -> SearchPlan {
+        let mut tags = BTreeMap::new();
+        let uses_index = _collect_tags(&query, false, &mut tags);
+
+        SearchPlan {
+            query,
+            tags: tags.into_iter().map(|(_, tag)| tag).collect(),
+            uses_index,
+            records_of_type,
+            estimated_scan: if uses_index { matched_records } else { records_of_type },
+            storage_plan,
+        }
+    }
+}
+
+// Collects tags used by the query and returns whether the query can be executed by tag indexes
+fn _collect_tags(query: &Query, negated: bool, tags: &mut BTreeMap<String, SearchPlanTag>) -> bool {
+    match *query {
+        Query::Eq(ref name, _) => _add_tag(tags, name, "$eq", !negated),
+        Query::Neq(ref name, _) => _add_tag(tags, name, "$neq", false),
+        Query::Gt(ref name, _) => _add_tag(tags, name, "$gt", !negated),
+        Query::Gte(ref name, _) => _add_tag(tags, name, "$gte", !negated),
+        Query::Lt(ref name, _) => _add_tag(tags, name, "$lt", !negated),
+        Query::Lte(ref name, _) => _add_tag(tags, name, "$lte", !negated),
+        Query::Like(ref name, ref value) => {
+            let is_prefix_pattern = !value.starts_with('%') && !value.starts_with('_');
+            _add_tag(tags, name, "$like", !negated && is_prefix_pattern)
+        }
+        Query::In(ref name, _) => _add_tag(tags, name, "$in", !negated),
+        // storage specific, assume the worst
+        Query::Ext(ref name, ref operator, _) => _add_tag(tags, name, operator, false),
+        Query::And(ref queries) => {
+            // collect all the tags, so no short-circuit
+            queries.iter()
+                .map(|query| _collect_tags(query, negated, tags))
+                .fold(false, |uses_index, query_uses_index| uses_index || query_uses_index)
+        }
+        Query::Or(ref queries) => {
+            !queries.is_empty() && queries.iter()
+                .map(|query| _collect_tags(query, negated, tags))
+                .fold(true, |uses_index, query_uses_index| uses_index && query_uses_index)
+        }
+        Query::Not(ref query) => {
+            _collect_tags(query, true, tags);
+            false
+        }
+    }
+}
+
+fn _add_tag(tags: &mut BTreeMap<String, SearchPlanTag>, name: &str, operator: &str, indexed: bool) -> bool {
+    let tag = tags.entry(name.to_string())
+        .or_insert_with(|| SearchPlanTag {
+            name: name.to_string(),
+            kind: _tag_kind(name),
+            operators: BTreeSet::new(),
+            indexed: true,
+        });
+
+    tag.operators.insert(operator.to_string());
+    tag.indexed &= indexed;
+
+    indexed
+}
+
+fn _tag_kind(name: &str) -> SearchPlanTagKind {
+    if name.starts_with('~') {
+        SearchPlanTagKind::Plaintext
+    } else if range_index::is_range_tag(name) {
+        SearchPlanTagKind::Range
+    } else {
+        SearchPlanTagKind::Encrypted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn _plan(query: &str) -> SearchPlan {
+        let query = serde_json::from_str::<Query>(query).unwrap().optimise().unwrap_or_default();
+        SearchPlan::new(query, 100, 10, None)
+    }
+
+    #[test]
+    fn search_plan_works_for_empty_query() {
+        let plan = _plan("{}");
+
+        assert!(!plan.uses_index);
+        assert!(plan.tags.is_empty());
+        assert_eq!(100, plan.estimated_scan);
+    }
+
+    #[test]
+    fn search_plan_works_for_indexed_query() {
+        let plan = _plan(r##"{"tag": "value", "~plain": {"$gt": "1"}, "#~range": {"$lte": "5"}}"##);
+
+        assert!(plan.uses_index);
+        assert_eq!(10, plan.estimated_scan);
+        assert_eq!(vec![
+            SearchPlanTag { name: "#~range".to_string(), kind: SearchPlanTagKind::Range, operators: vec!["$lte".to_string()].into_iter().collect(), indexed: true },
+            SearchPlanTag { name: "tag".to_string(), kind: SearchPlanTagKind::Encrypted, operators: vec!["$eq".to_string()].into_iter().collect(), indexed: true },
+            SearchPlanTag { name: "~plain".to_string(), kind: SearchPlanTagKind::Plaintext, operators: vec!["$gt".to_string()].into_iter().collect(), indexed: true },
+        ], plan.tags);
+    }
+
+    #[test]
+    fn search_plan_works_for_and_with_not_indexed_condition() {
+        let plan = _plan(r#"{"tag": "value", "$not": {"other": "value"}}"#);
+
+        assert!(plan.uses_index);
+        assert!(!plan.tags.iter().find(|tag| tag.name == "other").unwrap().indexed);
+    }
+
+    #[test]
+    fn search_plan_works_for_or_with_not_indexed_condition() {
+        let plan = _plan(r#"{"$or": [{"tag": "value"}, {"~plain": {"$like": "%suffix"}}]}"#);
+
+        assert!(!plan.uses_index);
+        assert_eq!(100, plan.estimated_scan);
+    }
+
+    #[test]
+    fn search_plan_works_for_neq() {
+        let plan = _plan(r#"{"tag": {"$neq": "value"}}"#);
+
+        assert!(!plan.uses_index);
+        assert!(!plan.tags[0].indexed);
+    }
+}
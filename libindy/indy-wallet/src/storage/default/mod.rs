@@ -11,7 +11,7 @@ use indy_api_types::errors::prelude::*;
 use crate::language;
 use indy_utils::environment;
 
-use super::{EncryptedValue, StorageIterator, StorageRecord, Tag, TagName, WalletStorage, WalletStorageType};
+use super::{EncryptedValue, StorageAddRecord, StorageIterator, StorageRecord, StorageUpsertRecord, Tag, TagName, WalletStorage, WalletStorageType};
 use super::super::{RecordOptions, SearchOptions};
 
 use self::owning_ref::OwningHandle;
@@ -215,9 +215,57 @@ impl StorageIterator for SQLiteStorageIterator {
     }
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Default)]
 struct Config {
     path: Option<String>,
+    journal_mode: Option<JournalMode>,
+    synchronous: Option<Synchronous>,
+    cache_size: Option<i64>,
+    busy_timeout: Option<u64>,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+enum JournalMode {
+    Delete,
+    Truncate,
+    Persist,
+    Memory,
+    Wal,
+    Off,
+}
+
+impl JournalMode {
+    fn as_str(&self) -> &'static str {
+        match self {
+            JournalMode::Delete => "DELETE",
+            JournalMode::Truncate => "TRUNCATE",
+            JournalMode::Persist => "PERSIST",
+            JournalMode::Memory => "MEMORY",
+            JournalMode::Wal => "WAL",
+            JournalMode::Off => "OFF",
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+enum Synchronous {
+    Off,
+    Normal,
+    Full,
+    Extra,
+}
+
+impl Synchronous {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Synchronous::Off => "OFF",
+            Synchronous::Normal => "NORMAL",
+            Synchronous::Full => "FULL",
+            Synchronous::Extra => "EXTRA",
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -235,7 +283,7 @@ impl SQLiteStorageType {
 
     fn _db_path(id: &str, config: Option<&Config>) -> std::path::PathBuf {
         let mut path = match config {
-            Some(Config { path: Some(ref path) }) => std::path::PathBuf::from(path),
+            Some(Config { path: Some(ref path), .. }) => std::path::PathBuf::from(path),
             _ => environment::wallet_home_path()
         };
 
@@ -243,6 +291,34 @@ impl SQLiteStorageType {
         path.push(_SQLITE_DB);
         path
     }
+
+    /// Applies SQLite pragmas of the config to the opened connection.
+    /// Journal mode defaults to WAL, because it provides better performance.
+    fn _apply_pragmas(conn: &rusqlite::Connection, config: &Config) -> IndyResult<()> {
+        let journal_mode: String = conn.query_row(
+            &format!("PRAGMA journal_mode = {}", config.journal_mode.unwrap_or(JournalMode::Wal).as_str()),
+            [],
+            |row| { row.get(0) },
+        )?;
+
+        // if journal mode is set to WAL, set synchronous to FULL by default for safety reasons.
+        // (synchronous = NORMAL with journal_mode = WAL does not guaranties durability).
+        match config.synchronous {
+            Some(synchronous) => conn.execute_batch(&format!("PRAGMA synchronous = {}", synchronous.as_str()))?,
+            None if journal_mode.to_lowercase() == "wal" => conn.execute_batch("PRAGMA synchronous = FULL")?,
+            None => {}
+        }
+
+        if let Some(cache_size) = config.cache_size {
+            conn.execute_batch(&format!("PRAGMA cache_size = {}", cache_size))?;
+        }
+
+        if let Some(busy_timeout) = config.busy_timeout {
+            conn.busy_timeout(std::time::Duration::from_millis(busy_timeout))?;
+        }
+
+        Ok(())
+    }
 }
 
 impl WalletStorage for SQLiteStorage {
@@ -492,6 +568,136 @@ impl WalletStorage for SQLiteStorage {
         }
     }
 
+    ///
+    /// inserts new or replaces value and tags of existing items in single transaction.
+    /// Returns Result with flags (`true` for inserted item) on success or
+    /// Result with IndyError in case of failure. No records are stored in case of failure.
+    ///
+    fn upsert(&self, type_: &[u8], records: &[StorageUpsertRecord]) -> IndyResult<Vec<bool>> {
+        let tx: transaction::Transaction = transaction::Transaction::new(&self.conn, rusqlite::TransactionBehavior::Deferred)?;
+        let mut inserted = Vec::with_capacity(records.len());
+        {
+            let mut item_select_stmt = tx.prepare_cached("SELECT id FROM items WHERE type = ?1 AND name = ?2")?;
+            let mut item_insert_stmt = tx.prepare_cached("INSERT INTO items (type, name, value, key) VALUES (?1, ?2, ?3, ?4)")?;
+            let mut item_update_stmt = tx.prepare_cached("UPDATE items SET value = ?1, key = ?2 WHERE id = ?3")?;
+            let mut enc_tags_delete_stmt = tx.prepare_cached("DELETE FROM tags_encrypted WHERE item_id = ?1")?;
+            let mut plain_tags_delete_stmt = tx.prepare_cached("DELETE FROM tags_plaintext WHERE item_id = ?1")?;
+            let mut enc_tag_insert_stmt = tx.prepare_cached("INSERT INTO tags_encrypted (item_id, name, value) VALUES (?1, ?2, ?3)")?;
+            let mut plain_tag_insert_stmt = tx.prepare_cached("INSERT INTO tags_plaintext (item_id, name, value) VALUES (?1, ?2, ?3)")?;
+
+            for record in records {
+                let res = item_select_stmt.query_row(&[&type_.to_vec(), &record.id], |row| row.get(0));
+
+                let item_id: i64 = match res {
+                    Ok(item_id) => {
+                        item_update_stmt.execute(rusqlite::params![&record.value.data, &record.value.key, &item_id])?;
+                        enc_tags_delete_stmt.execute(&[&item_id])?;
+                        plain_tags_delete_stmt.execute(&[&item_id])?;
+                        inserted.push(false);
+                        item_id
+                    }
+                    Err(rusqlite::Error::QueryReturnedNoRows) => {
+                        let item_id = item_insert_stmt.insert(&[&type_.to_vec(), &record.id, &record.value.data, &record.value.key])?;
+                        inserted.push(true);
+                        item_id
+                    }
+                    Err(err) => return Err(IndyError::from(err))
+                };
+
+                for tag in &record.tags {
+                    match *tag {
+                        Tag::Encrypted(ref tag_name, ref tag_data) => enc_tag_insert_stmt.execute(rusqlite::params![&item_id, tag_name, tag_data])?,
+                        Tag::PlainText(ref tag_name, ref tag_data) => plain_tag_insert_stmt.execute(rusqlite::params![&item_id, tag_name, tag_data])?
+                    };
+                }
+            }
+        }
+
+        tx.commit()?;
+        Ok(inserted)
+    }
+
+    ///
+    /// adds new items of any types in single transaction.
+    /// Result with IndyError is returned if any of items already exists. No items are stored in case of failure.
+    ///
+    fn add_all(&self, records: &[StorageAddRecord]) -> IndyResult<()> {
+        let tx: transaction::Transaction = transaction::Transaction::new(&self.conn, rusqlite::TransactionBehavior::Deferred)?;
+        {
+            let mut item_insert_stmt = tx.prepare_cached("INSERT INTO items (type, name, value, key) VALUES (?1, ?2, ?3, ?4)")?;
+            let mut enc_tag_insert_stmt = tx.prepare_cached("INSERT INTO tags_encrypted (item_id, name, value) VALUES (?1, ?2, ?3)")?;
+            let mut plain_tag_insert_stmt = tx.prepare_cached("INSERT INTO tags_plaintext (item_id, name, value) VALUES (?1, ?2, ?3)")?;
+
+            for record in records {
+                let item_id = item_insert_stmt.insert(&[&record.type_, &record.id, &record.value.data, &record.value.key])?;
+
+                for tag in &record.tags {
+                    match *tag {
+                        Tag::Encrypted(ref tag_name, ref tag_data) => enc_tag_insert_stmt.execute(rusqlite::params![&item_id, tag_name, tag_data])?,
+                        Tag::PlainText(ref tag_name, ref tag_data) => plain_tag_insert_stmt.execute(rusqlite::params![&item_id, tag_name, tag_data])?
+                    };
+                }
+            }
+        }
+
+        tx.commit()?;
+        Ok(())
+    }
+
+    ///
+    /// Replaces value of item with result of `modify` applied to its current value.
+    /// Write lock on database is acquired before item is read and released after it is written,
+    /// so concurrent writers can't change item in between.
+    ///
+    /// # Arguments
+    ///
+    ///  * `type_` - type of the item in storage
+    ///  * `id` - id of the item in storage
+    ///  * `modify` - function calculating new item value from current one
+    ///
+    /// # Returns
+    ///
+    /// Result that can be either:
+    ///
+    ///  * `()`
+    ///  * `IndyError`
+    ///
+    /// # Errors
+    ///
+    /// Any of the following `IndyError` class of errors can be throw by this method:
+    ///
+    ///  * `IndyError::Closed` - Storage is closed
+    ///  * `IndyError::ItemNotFound` - Item with the given type and id doesn't exist
+    ///  * Error returned by `modify` - Item is left unchanged
+    ///
+    fn modify(&self, type_: &[u8], id: &[u8], modify: &mut dyn FnMut(&EncryptedValue) -> IndyResult<EncryptedValue>) -> IndyResult<()> {
+        let tx: transaction::Transaction = transaction::Transaction::new(&self.conn, rusqlite::TransactionBehavior::Immediate)?;
+        {
+            let (item_id, value): (i64, EncryptedValue) = tx.prepare_cached("SELECT id, value, key FROM items WHERE type = ?1 AND name = ?2")?
+                .query_row(&[&type_.to_vec(), &id.to_vec()], |row| Ok((row.get(0)?, EncryptedValue::new(row.get(1)?, row.get(2)?))))?;
+
+            let new_value = modify(&value)?;
+
+            tx.prepare_cached("UPDATE items SET value = ?1, key = ?2 WHERE id = ?3")?
+                .execute(rusqlite::params![&new_value.data, &new_value.key, &item_id])?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    fn count_types(&self) -> IndyResult<Vec<(Vec<u8>, usize)>> {
+        let mut stmt = self.conn.prepare_cached("SELECT type, COUNT(*) FROM items GROUP BY type")?;
+        let mut rows = stmt.query([])?;
+
+        let mut counts = Vec::new();
+        while let Some(row) = rows.next()? {
+            let count: i64 = row.get(1)?;
+            counts.push((row.get(0)?, count as usize));
+        }
+
+        Ok(counts)
+    }
+
     fn get_storage_metadata(&self) -> IndyResult<Vec<u8>> {
         self.conn.query_row(
             "SELECT value FROM metadata",
@@ -547,7 +753,7 @@ impl WalletStorage for SQLiteStorage {
         if search_options.retrieve_records {
             let fetch_options = RecordOptions {
                 retrieve_value: search_options.retrieve_value,
-                retrieve_tags: search_options.retrieve_tags,
+                retrieve_tags: search_options.retrieve_tags.is_requested(),
                 retrieve_type: search_options.retrieve_type,
             };
 
@@ -567,6 +773,21 @@ impl WalletStorage for SQLiteStorage {
         }
     }
 
+    fn explain_search(&self, type_: &[u8], query: &language::Operator) -> IndyResult<Option<Vec<String>>> {
+        let type_ = type_.to_vec();
+        let (query_string, query_arguments) = query::wql_to_sql(&type_, query, None)?;
+
+        let mut stmt = self.conn.prepare(&format!("EXPLAIN QUERY PLAN {}", query_string))?;
+        let mut rows = stmt.query(&*query_arguments)?;
+
+        let mut details = Vec::new();
+        while let Some(row) = rows.next()? {
+            details.push(row.get(3)?);
+        }
+
+        Ok(Some(details))
+    }
+
     fn close(&mut self) -> IndyResult<()> {
         Ok(())
     }
@@ -693,7 +914,8 @@ impl WalletStorageType for SQLiteStorageType {
     /// # Arguments
     ///
     ///  * `id` - id of the SQLite DB file
-    ///  * `config` - config containing the location of SQLite DB files
+    ///  * `config` - config containing the location of SQLite DB files and SQLite pragmas
+    ///  (journal_mode, synchronous, cache_size, busy_timeout) that are applied to the connection
     ///  * `credentials` - DB credentials
     ///
     /// # Returns
@@ -724,18 +946,7 @@ impl WalletStorageType for SQLiteStorageType {
 
         let conn = rusqlite::Connection::open(db_file_path.as_path())?;
 
-        // set journal mode to WAL, because it provides better performance.
-        let journal_mode: String = conn.query_row(
-            "PRAGMA journal_mode = WAL",
-            [],
-            |row| { row.get(0) },
-        )?;
-
-        // if journal mode is set to WAL, set synchronous to FULL for safety reasons.
-        // (synchronous = NORMAL with journal_mode = WAL does not guaranties durability).
-        if journal_mode.to_lowercase() == "wal" {
-            conn.execute("PRAGMA synchronous = FULL", [])?;
-        }
+        SQLiteStorageType::_apply_pragmas(&conn, &config.unwrap_or_default())?;
 
         Ok(Box::new(SQLiteStorage { conn: Rc::new(conn) }))
     }
@@ -856,6 +1067,66 @@ mod tests {
         fs::remove_dir_all(path).unwrap();
     }
 
+    #[test]
+    fn sqlite_storage_type_open_works_for_pragmas() {
+        _cleanup("sqlite_storage_type_open_works_for_pragmas");
+
+        let storage_type = SQLiteStorageType::new();
+        storage_type.create_storage("sqlite_storage_type_open_works_for_pragmas", None, None, &_metadata()).unwrap();
+
+        let config = json!({
+            "journal_mode": "truncate",
+            "synchronous": "normal",
+            "cache_size": -4000,
+            "busy_timeout": 1000
+        }).to_string();
+
+        let storage = storage_type.open_storage("sqlite_storage_type_open_works_for_pragmas", Some(&config), None).unwrap();
+        assert_eq!(_metadata(), storage.get_storage_metadata().unwrap());
+        drop(storage);
+
+        let conn = rusqlite::Connection::open(SQLiteStorageType::_db_path("sqlite_storage_type_open_works_for_pragmas", None)).unwrap();
+        SQLiteStorageType::_apply_pragmas(&conn, &serde_json::from_str(&config).unwrap()).unwrap();
+
+        assert_eq!("truncate", conn.query_row("PRAGMA journal_mode", [], |row| row.get::<_, String>(0)).unwrap());
+        assert_eq!(1, conn.query_row("PRAGMA synchronous", [], |row| row.get::<_, i64>(0)).unwrap());
+        assert_eq!(-4000, conn.query_row("PRAGMA cache_size", [], |row| row.get::<_, i64>(0)).unwrap());
+        assert_eq!(1000, conn.query_row("PRAGMA busy_timeout", [], |row| row.get::<_, i64>(0)).unwrap());
+        drop(conn);
+
+        _cleanup("sqlite_storage_type_open_works_for_pragmas");
+    }
+
+    #[test]
+    fn sqlite_storage_type_open_works_for_default_pragmas() {
+        _cleanup("sqlite_storage_type_open_works_for_default_pragmas");
+
+        let storage = _storage("sqlite_storage_type_open_works_for_default_pragmas");
+        drop(storage);
+
+        let conn = rusqlite::Connection::open(SQLiteStorageType::_db_path("sqlite_storage_type_open_works_for_default_pragmas", None)).unwrap();
+        SQLiteStorageType::_apply_pragmas(&conn, &Config::default()).unwrap();
+
+        assert_eq!("wal", conn.query_row("PRAGMA journal_mode", [], |row| row.get::<_, String>(0)).unwrap());
+        assert_eq!(2, conn.query_row("PRAGMA synchronous", [], |row| row.get::<_, i64>(0)).unwrap());
+        drop(conn);
+
+        _cleanup("sqlite_storage_type_open_works_for_default_pragmas");
+    }
+
+    #[test]
+    fn sqlite_storage_type_open_works_for_invalid_pragma() {
+        _cleanup("sqlite_storage_type_open_works_for_invalid_pragma");
+
+        let storage_type = SQLiteStorageType::new();
+        storage_type.create_storage("sqlite_storage_type_open_works_for_invalid_pragma", None, None, &_metadata()).unwrap();
+
+        let res = storage_type.open_storage("sqlite_storage_type_open_works_for_invalid_pragma", Some(r#"{"journal_mode":"fast"}"#), None);
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+
+        _cleanup("sqlite_storage_type_open_works_for_invalid_pragma");
+    }
+
     #[test]
     fn sqlite_storage_type_open_works_for_not_created() {
         _cleanup("sqlite_storage_type_open_works_for_not_created");
@@ -1242,6 +1513,162 @@ mod tests {
         _cleanup("sqlite_storage_update_tags_works_for_already_existing");
     }
 
+    #[test]
+    fn sqlite_storage_upsert_works() {
+        _cleanup("sqlite_storage_upsert_works");
+        {
+            let storage = _storage("sqlite_storage_upsert_works");
+            storage.add(&_type1(), &_id1(), &_value1(), &_tags()).unwrap();
+
+            let records = vec![
+                StorageUpsertRecord { id: _id1(), value: _value2(), tags: _new_tags() },
+                StorageUpsertRecord { id: _id2(), value: _value1(), tags: _tags() },
+            ];
+
+            let inserted = storage.upsert(&_type1(), &records).unwrap();
+            assert_eq!(inserted, vec![false, true]);
+
+            let record = storage.get(&_type1(), &_id1(), r##"{"retrieveType": false, "retrieveValue": true, "retrieveTags": true}"##).unwrap();
+            assert_eq!(record.value.unwrap(), _value2());
+            assert_eq!(_sort(record.tags.unwrap()), _sort(_new_tags()));
+
+            let record = storage.get(&_type1(), &_id2(), r##"{"retrieveType": false, "retrieveValue": true, "retrieveTags": true}"##).unwrap();
+            assert_eq!(record.value.unwrap(), _value1());
+            assert_eq!(_sort(record.tags.unwrap()), _sort(_tags()));
+        }
+        _cleanup("sqlite_storage_upsert_works");
+    }
+
+    #[test]
+    fn sqlite_storage_upsert_works_for_rollback() {
+        _cleanup("sqlite_storage_upsert_works_for_rollback");
+        {
+            let storage = _storage("sqlite_storage_upsert_works_for_rollback");
+
+            let duplicated_tags = {
+                let mut tags = _tags();
+                tags.extend(_tags());
+                tags
+            };
+
+            let records = vec![
+                StorageUpsertRecord { id: _id1(), value: _value1(), tags: _tags() },
+                StorageUpsertRecord { id: _id2(), value: _value2(), tags: duplicated_tags },
+            ];
+
+            let res = storage.upsert(&_type1(), &records);
+            assert!(res.is_err());
+
+            let res = storage.get(&_type1(), &_id1(), "{}");
+            assert_kind!(IndyErrorKind::WalletItemNotFound, res);
+        }
+        _cleanup("sqlite_storage_upsert_works_for_rollback");
+    }
+
+    #[test]
+    fn sqlite_storage_add_all_works() {
+        _cleanup("sqlite_storage_add_all_works");
+        {
+            let storage = _storage("sqlite_storage_add_all_works");
+
+            let records = vec![
+                StorageAddRecord { type_: _type1(), id: _id1(), value: _value1(), tags: _tags() },
+                StorageAddRecord { type_: _type2(), id: _id1(), value: _value2(), tags: _new_tags() },
+            ];
+
+            storage.add_all(&records).unwrap();
+
+            let record = storage.get(&_type1(), &_id1(), r##"{"retrieveType": false, "retrieveValue": true, "retrieveTags": true}"##).unwrap();
+            assert_eq!(record.value.unwrap(), _value1());
+            assert_eq!(_sort(record.tags.unwrap()), _sort(_tags()));
+
+            let record = storage.get(&_type2(), &_id1(), r##"{"retrieveType": false, "retrieveValue": true, "retrieveTags": true}"##).unwrap();
+            assert_eq!(record.value.unwrap(), _value2());
+            assert_eq!(_sort(record.tags.unwrap()), _sort(_new_tags()));
+        }
+        _cleanup("sqlite_storage_add_all_works");
+    }
+
+    #[test]
+    fn sqlite_storage_add_all_works_for_rollback() {
+        _cleanup("sqlite_storage_add_all_works_for_rollback");
+        {
+            let storage = _storage("sqlite_storage_add_all_works_for_rollback");
+            storage.add(&_type2(), &_id1(), &_value1(), &_tags()).unwrap();
+
+            let records = vec![
+                StorageAddRecord { type_: _type1(), id: _id1(), value: _value1(), tags: _tags() },
+                StorageAddRecord { type_: _type2(), id: _id1(), value: _value2(), tags: _new_tags() },
+            ];
+
+            let res = storage.add_all(&records);
+            assert_kind!(IndyErrorKind::WalletItemAlreadyExists, res);
+
+            let res = storage.get(&_type1(), &_id1(), "{}");
+            assert_kind!(IndyErrorKind::WalletItemNotFound, res);
+
+            let record = storage.get(&_type2(), &_id1(), r##"{"retrieveType": false, "retrieveValue": true, "retrieveTags": false}"##).unwrap();
+            assert_eq!(record.value.unwrap(), _value1());
+        }
+        _cleanup("sqlite_storage_add_all_works_for_rollback");
+    }
+
+    #[test]
+    fn sqlite_storage_modify_works() {
+        _cleanup("sqlite_storage_modify_works");
+        {
+            let storage = _storage("sqlite_storage_modify_works");
+            storage.add(&_type1(), &_id1(), &_value1(), &_tags()).unwrap();
+
+            storage.modify(&_type1(), &_id1(), &mut |value| {
+                assert_eq!(value, &_value1());
+                Ok(_value2())
+            }).unwrap();
+
+            let record = storage.get(&_type1(), &_id1(), r##"{"retrieveType": false, "retrieveValue": true, "retrieveTags": true}"##).unwrap();
+            assert_eq!(record.value.unwrap(), _value2());
+            assert_eq!(_sort(record.tags.unwrap()), _sort(_tags()));
+        }
+        _cleanup("sqlite_storage_modify_works");
+    }
+
+    #[test]
+    fn sqlite_storage_modify_works_for_rollback() {
+        _cleanup("sqlite_storage_modify_works_for_rollback");
+        {
+            let storage = _storage("sqlite_storage_modify_works_for_rollback");
+            storage.add(&_type1(), &_id1(), &_value1(), &_tags()).unwrap();
+
+            let res = storage.modify(&_type1(), &_id1(), &mut |_| Err(err_msg(IndyErrorKind::InvalidState, "modify failed")));
+            assert_kind!(IndyErrorKind::InvalidState, res);
+
+            let record = storage.get(&_type1(), &_id1(), "{}").unwrap();
+            assert_eq!(record.value.unwrap(), _value1());
+
+            let res = storage.modify(&_type1(), &_id2(), &mut |_| Ok(_value2()));
+            assert_kind!(IndyErrorKind::WalletItemNotFound, res);
+        }
+        _cleanup("sqlite_storage_modify_works_for_rollback");
+    }
+
+    #[test]
+    fn sqlite_storage_count_types_works() {
+        _cleanup("sqlite_storage_count_types_works");
+        {
+            let storage = _storage("sqlite_storage_count_types_works");
+            assert!(storage.count_types().unwrap().is_empty());
+
+            storage.add(&_type1(), &_id1(), &_value1(), &_tags()).unwrap();
+            storage.add(&_type1(), &_id2(), &_value2(), &_tags()).unwrap();
+            storage.add(&_type2(), &_id1(), &_value1(), &_tags()).unwrap();
+
+            let mut counts = storage.count_types().unwrap();
+            counts.sort();
+            assert_eq!(counts, vec![(_type1(), 2), (_type2(), 1)]);
+        }
+        _cleanup("sqlite_storage_count_types_works");
+    }
+
     #[test]
     fn sqlite_storage_delete_tags_works() {
         _cleanup("sqlite_storage_delete_tags_works");
@@ -50,6 +50,7 @@ fn operator_to_sql<'a>(op: &'a Operator, arguments: &mut Vec<&'a dyn ToSql>) ->
         Operator::And(ref suboperators) => and_to_sql(suboperators, arguments),
         Operator::Or(ref suboperators) => or_to_sql(suboperators, arguments),
         Operator::Not(ref suboperator) => not_to_sql(suboperator, arguments),
+        Operator::Ext(_, ref operator, _) => Err(err_msg(IndyErrorKind::WalletQueryError, format!("Operator {} is not supported by storage", operator))),
     }
 }
 
@@ -1,6 +1,8 @@
 pub mod default;
 pub mod plugged;
 
+use std::collections::HashMap;
+
 use indy_api_types::errors::prelude::*;
 use crate::language;
 use crate::wallet::EncryptedValue;
@@ -36,6 +38,21 @@ impl StorageRecord {
     }
 }
 
+#[derive(Clone, Debug)]
+pub struct StorageUpsertRecord {
+    pub id: Vec<u8>,
+    pub value: EncryptedValue,
+    pub tags: Vec<Tag>,
+}
+
+#[derive(Clone, Debug)]
+pub struct StorageAddRecord {
+    pub type_: Vec<u8>,
+    pub id: Vec<u8>,
+    pub value: EncryptedValue,
+    pub tags: Vec<Tag>,
+}
+
 pub trait StorageIterator {
     fn next(&mut self) -> Result<Option<StorageRecord>, IndyError>;
     fn get_total_count(&self) -> Result<Option<usize>, IndyError>;
@@ -49,10 +66,66 @@ pub trait WalletStorage {
     fn update_tags(&self, type_: &[u8], id: &[u8], tags: &[Tag]) -> Result<(), IndyError>;
     fn delete_tags(&self, type_: &[u8], id: &[u8], tag_names: &[TagName]) -> Result<(), IndyError>;
     fn delete(&self, type_: &[u8], id: &[u8]) -> Result<(), IndyError>;
+    /// Inserts new items or replaces value and tags of existing ones.
+    /// Returns `true` for every inserted item and `false` for every updated one.
+    /// Default implementation applies records one by one,
+    /// storages supporting transactions should override it to apply all records atomically.
+    fn upsert(&self, type_: &[u8], records: &[StorageUpsertRecord]) -> Result<Vec<bool>, IndyError> {
+        records.iter()
+            .map(|record| match self.update(type_, &record.id, &record.value) {
+                Ok(()) => self.update_tags(type_, &record.id, &record.tags).map(|_| false),
+                Err(ref err) if err.kind() == IndyErrorKind::WalletItemNotFound =>
+                    self.add(type_, &record.id, &record.value, &record.tags).map(|_| true),
+                Err(err) => Err(err)
+            })
+            .collect()
+    }
+    /// Adds new items of any types. Fails if any of the items already exists.
+    /// Default implementation adds items one by one,
+    /// storages supporting transactions should override it to add all items atomically.
+    fn add_all(&self, records: &[StorageAddRecord]) -> Result<(), IndyError> {
+        records.iter()
+            .map(|record| self.add(&record.type_, &record.id, &record.value, &record.tags))
+            .collect()
+    }
+    /// Replaces value of item with result of `modify` applied to its current value.
+    /// Default implementation reads and updates item as separate operations,
+    /// storages supporting transactions should override it to lock item until it is written.
+    fn modify(&self, type_: &[u8], id: &[u8], modify: &mut dyn FnMut(&EncryptedValue) -> Result<EncryptedValue, IndyError>) -> Result<(), IndyError> {
+        let record = self.get(type_, id, &crate::RecordOptions::id_value())?;
+        let value = record.value
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "Item value not retrieved"))?;
+        self.update(type_, id, &modify(&value)?)
+    }
+    /// Returns count of items for every item type present in storage.
+    /// Default implementation iterates over all storage items,
+    /// storages able to aggregate items should override it.
+    fn count_types(&self) -> Result<Vec<(Vec<u8>, usize)>, IndyError> {
+        let mut counts: HashMap<Vec<u8>, usize> = HashMap::new();
+        let mut items = self.get_all()?;
+
+        while let Some(item) = items.next()? {
+            let type_ = item.type_
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "Item type not retrieved"))?;
+            *counts.entry(type_).or_insert(0) += 1;
+        }
+
+        Ok(counts.into_iter().collect())
+    }
     fn get_storage_metadata(&self) -> Result<Vec<u8>, IndyError>;
     fn set_storage_metadata(&self, metadata: &[u8]) -> Result<(), IndyError>;
     fn get_all(&self) -> Result<Box<dyn StorageIterator>, IndyError>;
     fn search(&self, type_: &[u8], query: &language::Operator, options: Option<&str>) -> Result<Box<dyn StorageIterator>, IndyError>;
+    /// Returns storage specific description of how the search query is executed.
+    /// Storages unable to explain queries return None.
+    fn explain_search(&self, _type_: &[u8], _query: &language::Operator) -> Result<Option<Vec<String>>, IndyError> {
+        Ok(None)
+    }
+    /// Returns extended WQL operators (for example `$contains`) search is able to execute.
+    /// Queries with other extended operators are rejected before reaching the storage.
+    fn query_extensions(&self) -> Vec<String> {
+        Vec::new()
+    }
     fn close(&mut self) -> Result<(), IndyError>;
 }
 
@@ -60,4 +133,9 @@ pub trait WalletStorageType {
     fn create_storage(&self, id: &str, config: Option<&str>, credentials: Option<&str>, metadata: &[u8]) -> Result<(), IndyError>;
     fn open_storage(&self, id: &str, config: Option<&str>, credentials: Option<&str>) -> Result<Box<dyn WalletStorage>, IndyError>;
     fn delete_storage(&self, id: &str, config: Option<&str>, credentials: Option<&str>) -> Result<(), IndyError>;
+    /// Sets extended WQL operators supported by storages of this type opened after the call.
+    /// Built-in storage types know what they support, so only plugged ones accept it.
+    fn set_query_extensions(&self, _extensions: Vec<String>) -> Result<(), IndyError> {
+        Err(err_msg(IndyErrorKind::InvalidStructure, "Query extensions can't be set for built-in storage type"))
+    }
 }
\ No newline at end of file
@@ -1,4 +1,5 @@
 use std::{slice, str};
+use std::cell::RefCell;
 use std::collections::HashMap;
 use std::ffi::{CStr, CString};
 use std::ptr;
@@ -13,7 +14,7 @@ use crate::language;
 use indy_utils::crypto::base64;
 
 use super::{EncryptedValue, StorageIterator, StorageRecord, Tag, TagName, WalletStorage, WalletStorageType};
-use super::super::{RecordOptions, SearchOptions};
+use super::super::{RecordOptions, RetrieveTags, SearchOptions};
 
 #[derive(Debug, Deserialize)]
 pub struct PluggedWalletJSONValue {
@@ -159,7 +160,7 @@ impl StorageIterator for PluggedStorageIterator {
             Some(EncryptedValue::from_bytes(value)?)
         } else { None };
 
-        let tags = if self.options.retrieve_tags {
+        let tags = if self.options.retrieve_tags.is_requested() {
             let mut tags_ptr: *const c_char = ptr::null_mut();
             let err = (self.get_record_tags_handler)(self.storage_handle,
                                                      record_handle,
@@ -235,6 +236,7 @@ struct PluggedStorage {
     fetch_search_next_record_handler: WalletFetchSearchNextRecord,
     free_search_handler: WalletFreeSearch,
     close_handler: WalletClose,
+    query_extensions: Vec<String>,
 }
 
 impl PluggedStorage {
@@ -259,7 +261,8 @@ impl PluggedStorage {
            get_search_total_count_handler: WalletGetSearchTotalCount,
            fetch_search_next_record_handler: WalletFetchSearchNextRecord,
            free_search_handler: WalletFreeSearch,
-           close_handler: WalletClose) -> PluggedStorage {
+           close_handler: WalletClose,
+           query_extensions: Vec<String>) -> PluggedStorage {
         PluggedStorage {
             handle,
             add_record_handler,
@@ -283,6 +286,7 @@ impl PluggedStorage {
             fetch_search_next_record_handler,
             free_search_handler,
             close_handler,
+            query_extensions,
         }
     }
 }
@@ -571,7 +575,7 @@ impl WalletStorage for PluggedStorage {
                     retrieve_total_count: false,
                     retrieve_type: true,
                     retrieve_value: true,
-                    retrieve_tags: true,
+                    retrieve_tags: RetrieveTags::All(true),
                 },
             )
         ))
@@ -606,6 +610,10 @@ impl WalletStorage for PluggedStorage {
         ))
     }
 
+    fn query_extensions(&self) -> Vec<String> {
+        self.query_extensions.clone()
+    }
+
     fn close(&mut self) -> IndyResult<()> {
         let err = (self.close_handler)(self.handle);
 
@@ -655,6 +663,7 @@ pub struct PluggedStorageType {
     get_search_total_count_handler: WalletGetSearchTotalCount,
     fetch_search_next_record_handler: WalletFetchSearchNextRecord,
     free_search_handler: WalletFreeSearch,
+    query_extensions: RefCell<Vec<String>>,
 }
 
 
@@ -708,6 +717,7 @@ impl PluggedStorageType {
             get_search_total_count_handler,
             fetch_search_next_record_handler,
             free_search_handler,
+            query_extensions: RefCell::new(Vec::new()),
         }
     }
 }
@@ -781,7 +791,8 @@ impl WalletStorageType for PluggedStorageType {
                 self.get_search_total_count_handler,
                 self.fetch_search_next_record_handler,
                 self.free_search_handler,
-                self.close_handler)))
+                self.close_handler,
+                self.query_extensions.borrow().clone())))
     }
 
     fn delete_storage(&self, id: &str, config: Option<&str>, credentials: Option<&str>) -> IndyResult<()> {
@@ -805,6 +816,11 @@ impl WalletStorageType for PluggedStorageType {
 
         Ok(())
     }
+
+    fn set_query_extensions(&self, extensions: Vec<String>) -> IndyResult<()> {
+        *self.query_extensions.borrow_mut() = extensions;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
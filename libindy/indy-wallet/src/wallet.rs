@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::rc::Rc;
 
 use indy_utils::crypto::{hmacsha256, chacha20poly1305_ietf};
@@ -12,7 +12,8 @@ use super::storage;
 use super::iterator::WalletIterator;
 use super::encryption::*;
 use super::query_encryption::encrypt_query;
-use super::WalletRecord;
+use super::search_plan::SearchPlan;
+use super::{AddRecord, RetrieveTags, SearchOptions, UpsertRecord, WalletRecord};
 
 #[derive(Serialize, Deserialize)]
 pub(super) struct Keys {
@@ -127,7 +128,7 @@ impl Wallet {
         let etype = encrypt_as_searchable(type_.as_bytes(), &self.keys.type_key, &self.keys.item_hmac_key);
         let ename = encrypt_as_searchable(name.as_bytes(), &self.keys.name_key, &self.keys.item_hmac_key);
         let evalue = EncryptedValue::encrypt(value, &self.keys.value_key);
-        let etags = encrypt_tags(tags, &self.keys.tag_name_key, &self.keys.tag_value_key, &self.keys.tags_hmac_key);
+        let etags = encrypt_tags(tags, &self.keys.tag_name_key, &self.keys.tag_value_key, &self.keys.tags_hmac_key)?;
         self.storage.add(&etype, &ename, &evalue, &etags)?;
         Ok(())
     }
@@ -135,7 +136,7 @@ impl Wallet {
     pub fn add_tags(&self, type_: &str, name: &str, tags: &HashMap<String, String>) -> IndyResult<()> {
         let encrypted_type = encrypt_as_searchable(type_.as_bytes(), &self.keys.type_key, &self.keys.item_hmac_key);
         let encrypted_name = encrypt_as_searchable(name.as_bytes(), &self.keys.name_key, &self.keys.item_hmac_key);
-        let encrypted_tags = encrypt_tags(tags, &self.keys.tag_name_key, &self.keys.tag_value_key, &self.keys.tags_hmac_key);
+        let encrypted_tags = encrypt_tags(tags, &self.keys.tag_name_key, &self.keys.tag_value_key, &self.keys.tags_hmac_key)?;
         self.storage.add_tags(&encrypted_type, &encrypted_name, &encrypted_tags)?;
         Ok(())
     }
@@ -143,7 +144,7 @@ impl Wallet {
     pub fn update_tags(&self, type_: &str, name: &str, tags: &HashMap<String, String>) -> IndyResult<()> {
         let encrypted_type = encrypt_as_searchable(type_.as_bytes(), &self.keys.type_key, &self.keys.item_hmac_key);
         let encrypted_name = encrypt_as_searchable(name.as_bytes(), &self.keys.name_key, &self.keys.item_hmac_key);
-        let encrypted_tags = encrypt_tags(tags, &self.keys.tag_name_key, &self.keys.tag_value_key, &self.keys.tags_hmac_key);
+        let encrypted_tags = encrypt_tags(tags, &self.keys.tag_name_key, &self.keys.tag_value_key, &self.keys.tags_hmac_key)?;
         self.storage.update_tags(&encrypted_type, &encrypted_name, &encrypted_tags)?;
         Ok(())
     }
@@ -164,6 +165,47 @@ impl Wallet {
         Ok(())
     }
 
+    pub fn upsert(&self, type_: &str, records: &[UpsertRecord]) -> IndyResult<Vec<bool>> {
+        let encrypted_type = encrypt_as_searchable(type_.as_bytes(), &self.keys.type_key, &self.keys.item_hmac_key);
+        let encrypted_records: Vec<storage::StorageUpsertRecord> = records.iter()
+            .map(|record| Ok(storage::StorageUpsertRecord {
+                id: encrypt_as_searchable(record.id.as_bytes(), &self.keys.name_key, &self.keys.item_hmac_key),
+                value: EncryptedValue::encrypt(&record.value, &self.keys.value_key),
+                tags: encrypt_tags(&record.tags, &self.keys.tag_name_key, &self.keys.tag_value_key, &self.keys.tags_hmac_key)?,
+            }))
+            .collect::<IndyResult<_>>()?;
+        self.storage.upsert(&encrypted_type, &encrypted_records)
+    }
+
+    pub fn add_all(&self, records: &[AddRecord]) -> IndyResult<()> {
+        let encrypted_records: Vec<storage::StorageAddRecord> = records.iter()
+            .map(|record| Ok(storage::StorageAddRecord {
+                type_: encrypt_as_searchable(record.type_.as_bytes(), &self.keys.type_key, &self.keys.item_hmac_key),
+                id: encrypt_as_searchable(record.id.as_bytes(), &self.keys.name_key, &self.keys.item_hmac_key),
+                value: EncryptedValue::encrypt(&record.value, &self.keys.value_key),
+                tags: encrypt_tags(&record.tags, &self.keys.tag_name_key, &self.keys.tag_value_key, &self.keys.tags_hmac_key)?,
+            }))
+            .collect::<IndyResult<_>>()?;
+        self.storage.add_all(&encrypted_records)
+    }
+
+    /// Replaces record value with result of `modify` applied to its current value and returns the new value.
+    pub fn modify(&self, type_: &str, name: &str, modify: &mut dyn FnMut(&str) -> IndyResult<String>) -> IndyResult<String> {
+        let encrypted_type = encrypt_as_searchable(type_.as_bytes(), &self.keys.type_key, &self.keys.item_hmac_key);
+        let encrypted_name = encrypt_as_searchable(name.as_bytes(), &self.keys.name_key, &self.keys.item_hmac_key);
+
+        let mut new_value = None;
+
+        self.storage.modify(&encrypted_type, &encrypted_name, &mut |encrypted_value| {
+            let value = modify(&encrypted_value.decrypt(&self.keys.value_key)?)?;
+            let encrypted_value = EncryptedValue::encrypt(&value, &self.keys.value_key);
+            new_value = Some(value);
+            Ok(encrypted_value)
+        })?;
+
+        new_value.ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "Record value wasn't modified"))
+    }
+
     pub fn get(&self, type_: &str, name: &str, options: &str) -> IndyResult<WalletRecord> {
         let etype = encrypt_as_searchable(type_.as_bytes(), &self.keys.type_key, &self.keys.item_hmac_key);
         let ename = encrypt_as_searchable(name.as_bytes(), &self.keys.name_key, &self.keys.item_hmac_key);
@@ -189,18 +231,72 @@ impl Wallet {
     }
 
     pub fn search<'a>(&'a self, type_: &str, query: &str, options: Option<&str>) -> IndyResult<WalletIterator> {
-        let parsed_query: Query = ::serde_json::from_str::<Query>(query)
-            .map_err(|err| IndyError::from_msg(IndyErrorKind::WalletQueryError, err))?
-            .optimise()
-            .unwrap_or_default();
+        let parsed_query = self._parse_query(query)?;
 
         let encrypted_query = encrypt_query(parsed_query, &self.keys)?;
         let encrypted_type_ = encrypt_as_searchable(type_.as_bytes(), &self.keys.type_key, &self.keys.item_hmac_key);
+
+        let search_options: Option<SearchOptions> = options
+            .map(|options| ::serde_json::from_str(options)
+                .to_indy(IndyErrorKind::InvalidStructure, "Search options is malformed json"))
+            .transpose()?;
+
+        if let Some(mut search_options) = search_options {
+            // Storages know only about retrieving of all tags, so projection is applied by iterator
+            if let RetrieveTags::Names(tag_names) = ::std::mem::replace(&mut search_options.retrieve_tags, RetrieveTags::All(true)) {
+                let storage_options = ::serde_json::to_string(&search_options)
+                    .to_indy(IndyErrorKind::InvalidState, "Cannot serialize search options")?;
+
+                let tag_names: Vec<&str> = tag_names.iter().map(String::as_str).collect();
+                let encrypted_tag_names = encrypt_tag_names(&tag_names, &self.keys.tag_name_key, &self.keys.tags_hmac_key);
+
+                let storage_iterator = self.storage.search(&encrypted_type_, &encrypted_query, Some(&storage_options))?;
+                return Ok(WalletIterator::new(storage_iterator, Rc::clone(&self.keys)).with_tag_names(encrypted_tag_names));
+            }
+        }
+
         let storage_iterator = self.storage.search(&encrypted_type_, &encrypted_query, options)?;
         let wallet_iterator = WalletIterator::new(storage_iterator, Rc::clone(&self.keys));
         Ok(wallet_iterator)
     }
 
+    fn _parse_query(&self, query: &str) -> IndyResult<Query> {
+        let parsed_query: Query = ::serde_json::from_str::<Query>(query)
+            .map_err(|err| IndyError::from_msg(IndyErrorKind::WalletQueryError, err))?
+            .optimise()
+            .unwrap_or_default();
+
+        let supported_extensions = self.storage.query_extensions();
+
+        if let Some(operator) = parsed_query.extensions().into_iter().find(|operator| !supported_extensions.iter().any(|supported| supported == operator)) {
+            return Err(err_msg(IndyErrorKind::WalletQueryError, format!("Unknown operator {}, it isn't supported by wallet storage", operator)));
+        }
+
+        Ok(parsed_query)
+    }
+
+    pub fn explain_search(&self, type_: &str, query: &str) -> IndyResult<SearchPlan> {
+        let parsed_query = self._parse_query(query)?;
+
+        let encrypted_query = encrypt_query(parsed_query.clone(), &self.keys)?;
+        let encrypted_all_query = encrypt_query(Query::default(), &self.keys)?;
+        let encrypted_type_ = encrypt_as_searchable(type_.as_bytes(), &self.keys.type_key, &self.keys.item_hmac_key);
+
+        let count_options = r#"{"retrieveRecords": false, "retrieveTotalCount": true}"#;
+
+        let records_of_type = self.storage.search(&encrypted_type_, &encrypted_all_query, Some(count_options))?
+            .get_total_count()?
+            .unwrap_or(0);
+
+        let matched_records = self.storage.search(&encrypted_type_, &encrypted_query, Some(count_options))?
+            .get_total_count()?
+            .unwrap_or(0);
+
+        let storage_plan = self.storage.explain_search(&encrypted_type_, &encrypted_query)?;
+
+        Ok(SearchPlan::new(parsed_query, records_of_type, matched_records, storage_plan))
+    }
+
     pub fn close(&mut self) -> IndyResult<()> {
         self.storage.close()
             .map_err(IndyError::from)
@@ -211,6 +307,17 @@ impl Wallet {
         Ok(WalletIterator::new(all_items, Rc::clone(&self.keys)))
     }
 
+    pub fn count_types(&self) -> IndyResult<BTreeMap<String, usize>> {
+        self.storage.count_types()?
+            .into_iter()
+            .map(|(encrypted_type, count)| {
+                let type_ = String::from_utf8(decrypt_merged(&encrypted_type, &self.keys.type_key)?)
+                    .to_indy(IndyErrorKind::WalletEncryptionError, "Record type is invalid utf8")?;
+                Ok((type_, count))
+            })
+            .collect()
+    }
+
     pub fn get_id<'a>(&'a self) -> &'a str {
         &self.id
     }
@@ -444,6 +551,85 @@ mod tests {
         test::cleanup_wallet("wallet_update_tags_works");
     }
 
+    #[test]
+    fn wallet_upsert_works() {
+        test::cleanup_wallet("wallet_upsert_works");
+        {
+            let tags = jsonmap!({
+                "tag_name_1": "tag_value_1",
+            });
+
+            let mut wallet = _wallet("wallet_upsert_works");
+            wallet.add(_type1(), _id1(), _value1(), &tags).unwrap();
+
+            let new_tags = jsonmap!({
+                "~tag_name_2": "~tag_value_2",
+            });
+
+            let records = vec![
+                UpsertRecord { id: _id1().to_string(), value: _value2().to_string(), tags: new_tags.clone() },
+                UpsertRecord { id: _id2().to_string(), value: _value1().to_string(), tags: tags.clone() },
+            ];
+
+            let inserted = wallet.upsert(_type1(), &records).unwrap();
+            assert_eq!(inserted, vec![false, true]);
+
+            let record = wallet.get(_type1(), _id1(), &_fetch_options(false, true, true)).unwrap();
+            assert_eq!(record.value.unwrap(), _value2());
+            assert_eq!(record.tags.unwrap(), new_tags);
+
+            let record = wallet.get(_type1(), _id2(), &_fetch_options(false, true, true)).unwrap();
+            assert_eq!(record.value.unwrap(), _value1());
+            assert_eq!(record.tags.unwrap(), tags);
+
+            wallet.close().unwrap();
+        }
+        test::cleanup_wallet("wallet_upsert_works");
+    }
+
+    #[test]
+    fn wallet_count_types_works() {
+        test::cleanup_wallet("wallet_count_types_works");
+        {
+            let mut wallet = _wallet("wallet_count_types_works");
+            wallet.add(_type1(), _id1(), _value1(), &HashMap::new()).unwrap();
+            wallet.add(_type1(), _id2(), _value2(), &HashMap::new()).unwrap();
+            wallet.add(_type2(), _id1(), _value1(), &HashMap::new()).unwrap();
+
+            let counts = wallet.count_types().unwrap();
+            assert_eq!(counts.len(), 2);
+            assert_eq!(counts[_type1()], 2);
+            assert_eq!(counts[_type2()], 1);
+
+            wallet.close().unwrap();
+        }
+        test::cleanup_wallet("wallet_count_types_works");
+    }
+
+    #[test]
+    fn wallet_modify_works() {
+        test::cleanup_wallet("wallet_modify_works");
+        {
+            let mut wallet = _wallet("wallet_modify_works");
+            wallet.add(_type1(), _id1(), "1", &HashMap::new()).unwrap();
+
+            let new_value = wallet.modify(_type1(), _id1(), &mut |value| {
+                let counter: u64 = value.parse().unwrap();
+                Ok((counter + 1).to_string())
+            }).unwrap();
+            assert_eq!(new_value, "2");
+
+            let record = wallet.get(_type1(), _id1(), &_fetch_options(false, true, false)).unwrap();
+            assert_eq!(record.value.unwrap(), "2");
+
+            let res = wallet.modify(_type1(), _id2(), &mut |value| Ok(value.to_string()));
+            assert_kind!(IndyErrorKind::WalletItemNotFound, res);
+
+            wallet.close().unwrap();
+        }
+        test::cleanup_wallet("wallet_modify_works");
+    }
+
     #[test]
     fn wallet_delete_tags_works() {
         test::cleanup_wallet("wallet_delete_tags_works");
@@ -640,6 +826,46 @@ mod tests {
         test::cleanup_wallet("wallet_search_works_for_empty_query_with_count");
     }
 
+    #[test]
+    fn wallet_search_works_for_tag_names_projection() {
+        test::cleanup_wallet("wallet_search_works_for_tag_names_projection");
+        {
+            let tags = jsonmap!({
+                "tag_name_1": "tag_value_1",
+                "tag_name_2": "tag_value_2",
+                "~tag_name_3": "tag_value_3",
+                "~tag_name_4": "tag_value_4",
+            });
+
+            let mut wallet = _wallet("wallet_search_works_for_tag_names_projection");
+            wallet.add(_type1(), _id1(), _value1(), &tags).unwrap();
+
+            let options = json!({
+                "retrieveType": true,
+                "retrieveTags": ["tag_name_1", "~tag_name_3", "~tag_name_2", "unknown"],
+            }).to_string();
+
+            let mut iterator = wallet.search(_type1(), "{}", Some(&options)).unwrap();
+
+            let expected_records = vec![
+                WalletRecord {
+                    id: _id1().to_string(),
+                    value: Some(_value1().to_string()),
+                    tags: Some(jsonmap!({
+                        "tag_name_1": "tag_value_1",
+                        "~tag_name_3": "tag_value_3",
+                    })),
+                    type_: Some(_type1().to_string()),
+                },
+            ];
+
+            assert_eq!(_fetch_all(&mut iterator), expected_records);
+
+            wallet.close().unwrap();
+        }
+        test::cleanup_wallet("wallet_search_works_for_tag_names_projection");
+    }
+
     #[test]
     fn wallet_search_works_for_empty_query_with_only_count() {
         test::cleanup_wallet("wallet_search_works_for_empty_query_with_only_count");
@@ -661,6 +887,30 @@ mod tests {
         test::cleanup_wallet("wallet_search_works_for_empty_query_with_only_count");
     }
 
+    #[test]
+    fn wallet_explain_search_works() {
+        test::cleanup_wallet("wallet_explain_search_works");
+        {
+            let mut wallet = _wallet("wallet_explain_search_works");
+
+            wallet.add(_type1(), _id1(), _value1(), &jsonmap!({"tag_name_1": "tag_value_1"})).unwrap();
+            wallet.add(_type1(), _id2(), _value2(), &jsonmap!({"tag_name_1": "tag_value_2"})).unwrap();
+
+            let plan = wallet.explain_search(_type1(), &jsonstr!({"tag_name_1": "tag_value_1"})).unwrap();
+
+            assert!(plan.uses_index);
+            assert_eq!(2, plan.records_of_type);
+            assert_eq!(1, plan.estimated_scan);
+            assert!(!plan.storage_plan.unwrap().is_empty());
+
+            let plan = wallet.explain_search(_type1(), &jsonstr!({"tag_name_1": {"$neq": "tag_value_1"}})).unwrap();
+
+            assert!(!plan.uses_index);
+            assert_eq!(2, plan.estimated_scan);
+        }
+        test::cleanup_wallet("wallet_explain_search_works");
+    }
+
     #[test]
     fn wallet_search_works_for_eq_encrypted() {
         test::cleanup_wallet("wallet_search_works_for_eq_encrypted");
@@ -1269,6 +1519,95 @@ mod tests {
         test::cleanup_wallet("wallet_search_works_for_lte_encrypted");
     }
 
+    #[test]
+    fn wallet_search_works_for_range_tag() {
+        test::cleanup_wallet("wallet_search_works_for_range_tag");
+        {
+            let mut wallet = _wallet("wallet_search_works_for_range_tag");
+            wallet.add(_type1(), _id1(), _value1(), &jsonmap!({"#~amount":"5"})).unwrap();
+            wallet.add(_type1(), _id2(), _value2(), &jsonmap!({"#~amount":"300"})).unwrap();
+            wallet.add(_type1(), _id3(), _value3(), &jsonmap!({"#~amount":"70000"})).unwrap();
+
+            assert_eq!(vec![_id2(), _id3()], _search_ids(&wallet, &jsonstr!({"#~amount": {"$gt": "5"}})));
+            assert_eq!(vec![_id1(), _id2()], _search_ids(&wallet, &jsonstr!({"#~amount": {"$lt": "301"}})));
+            assert_eq!(vec![_id2()], _search_ids(&wallet, &jsonstr!({"$and": [{"#~amount": {"$gte": "300"}}, {"#~amount": {"$lte": "69999"}}]})));
+            assert_eq!(vec![_id1()], _search_ids(&wallet, &jsonstr!({"$not": {"#~amount": {"$gt": "5"}}})));
+            assert_eq!(vec![_id3()], _search_ids(&wallet, &jsonstr!({"#~amount": "70000"})));
+            assert!(_search_ids(&wallet, &jsonstr!({"#~amount": {"$lt": "5"}})).is_empty());
+            assert!(_search_ids(&wallet, &jsonstr!({"#~amount": {"$lt": "0"}})).is_empty());
+            assert!(_search_ids(&wallet, &jsonstr!({"#~amount": {"$gt": "18446744073709551615"}})).is_empty());
+            assert!(_search_ids(&wallet, &jsonstr!({"#~other": {"$gt": "1"}})).is_empty());
+
+            // index tags are not returned
+            let record = wallet.get(_type1(), _id2(), &_fetch_options(false, true, true)).unwrap();
+            assert_eq!(jsonmap!({"#~amount":"300"}), record.tags.unwrap());
+
+            wallet.close().unwrap();
+        }
+        test::cleanup_wallet("wallet_search_works_for_range_tag");
+    }
+
+    #[test]
+    fn wallet_search_works_for_range_tag_after_tags_update() {
+        test::cleanup_wallet("wallet_search_works_for_range_tag_after_tags_update");
+        {
+            let mut wallet = _wallet("wallet_search_works_for_range_tag_after_tags_update");
+            wallet.add(_type1(), _id1(), _value1(), &jsonmap!({"#~amount":"5"})).unwrap();
+            wallet.add(_type1(), _id2(), _value2(), &jsonmap!({"#~amount":"300", "tag_name":"tag_value"})).unwrap();
+
+            wallet.add_tags(_type1(), _id1(), &jsonmap!({"#~amount":"500"})).unwrap();
+            assert_eq!(vec![_id1(), _id2()], _search_ids(&wallet, &jsonstr!({"#~amount": {"$gt": "100"}})));
+
+            wallet.update_tags(_type1(), _id1(), &jsonmap!({"#~amount":"50"})).unwrap();
+            assert_eq!(vec![_id2()], _search_ids(&wallet, &jsonstr!({"#~amount": {"$gt": "100"}})));
+
+            wallet.delete_tags(_type1(), _id2(), &["#~amount"]).unwrap();
+            assert!(_search_ids(&wallet, &jsonstr!({"#~amount": {"$gt": "100"}})).is_empty());
+
+            let record = wallet.get(_type1(), _id2(), &_fetch_options(false, true, true)).unwrap();
+            assert_eq!(jsonmap!({"tag_name":"tag_value"}), record.tags.unwrap());
+
+            wallet.close().unwrap();
+        }
+        test::cleanup_wallet("wallet_search_works_for_range_tag_after_tags_update");
+    }
+
+    #[test]
+    fn wallet_search_works_for_invalid_range_tag_value() {
+        test::cleanup_wallet("wallet_search_works_for_invalid_range_tag_value");
+        {
+            let mut wallet = _wallet("wallet_search_works_for_invalid_range_tag_value");
+
+            let res = wallet.add(_type1(), _id1(), _value1(), &jsonmap!({"#~amount":"five"}));
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+
+            let res = wallet.search(_type1(), &jsonstr!({"#~amount": {"$gt": "-1"}}), None);
+            assert_kind!(IndyErrorKind::WalletQueryError, res);
+
+            wallet.close().unwrap();
+        }
+        test::cleanup_wallet("wallet_search_works_for_invalid_range_tag_value");
+    }
+
+    #[test]
+    fn wallet_search_works_for_hash_prefixed_tag() {
+        test::cleanup_wallet("wallet_search_works_for_hash_prefixed_tag");
+        {
+            let mut wallet = _wallet("wallet_search_works_for_hash_prefixed_tag");
+            wallet.add(_type1(), _id1(), _value1(), &jsonmap!({"#amount":"five"})).unwrap();
+            wallet.add(_type1(), _id2(), _value2(), &jsonmap!({"#amount":"300"})).unwrap();
+
+            assert_eq!(vec![_id1()], _search_ids(&wallet, &jsonstr!({"#amount": "five"})));
+            assert_eq!(vec![_id2()], _search_ids(&wallet, &jsonstr!({"#amount": "300"})));
+
+            let record = wallet.get(_type1(), _id2(), &_fetch_options(false, true, true)).unwrap();
+            assert_eq!(jsonmap!({"#amount":"300"}), record.tags.unwrap());
+
+            wallet.close().unwrap();
+        }
+        test::cleanup_wallet("wallet_search_works_for_hash_prefixed_tag");
+    }
+
     #[test]
     fn wallet_search_works_for_like_plain() {
         test::cleanup_wallet("wallet_search_works_for_like_plain");
@@ -2066,6 +2405,14 @@ mod tests {
         }).to_string()
     }
 
+    fn _search_ids(wallet: &Wallet, query: &str) -> Vec<&'static str> {
+        let mut iterator = wallet.search(_type1(), query, Some(&_search_options(true, false, false, false, false))).unwrap();
+
+        _fetch_all(&mut iterator).into_iter()
+            .map(|record| [_id1(), _id2(), _id3()].iter().find(|id| **id == record.id).cloned().unwrap())
+            .collect()
+    }
+
     fn _fetch_all<'a>(iterator: &mut WalletIterator) -> Vec<WalletRecord> {
         let mut v = Vec::new();
 
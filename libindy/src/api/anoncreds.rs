@@ -1,6 +1,10 @@
 use indy_api_types::{ErrorCode, IndyHandle, CommandHandle, WalletHandle, SearchHandle, INVALID_SEARCH_HANDLE};
+#[cfg(feature = "ledger")]
+use indy_api_types::PoolHandle;
 use indy_api_types::errors::prelude::*;
+use indy_api_types::domain::wallet::Tags;
 use crate::commands::{Command, CommandExecutor};
+use crate::commands::timeout::with_timeout;
 use crate::commands::anoncreds::AnoncredsCommand;
 use crate::commands::anoncreds::issuer::IssuerCommand;
 use crate::commands::anoncreds::prover::ProverCommand;
@@ -19,6 +23,11 @@ use crate::domain::anoncreds::proof_request::{ProofRequest, ProofRequestExtraQue
 use crate::domain::anoncreds::requested_credential::RequestedCredentials;
 use crate::domain::anoncreds::revocation_registry::RevocationRegistries;
 use crate::domain::anoncreds::revocation_state::{RevocationState, RevocationStates};
+#[cfg(feature = "ledger")]
+use crate::domain::anoncreds::revocation_state::RevocationStateUpdaterConfig;
+#[cfg(feature = "ledger")]
+use crate::domain::anoncreds::verifier_pools::VerifierPools;
+use crate::domain::anoncreds::w3c_credential::W3CCredential;
 use indy_utils::ctypes;
 
 use libc::c_char;
@@ -78,6 +87,8 @@ pub extern fn indy_issuer_create_schema(command_handle: CommandHandle,
                                                              schema_id: *const c_char, schema_json: *const c_char)>) -> ErrorCode {
     trace!("indy_issuer_create_schema: >>> issuer_did: {:?}, name: {:?}, version: {:?}, attrs: {:?}", issuer_did, name, version, attrs);
 
+    command_params!(issuer_did, name, version, attrs);
+
     check_useful_validatable_string!(issuer_did, ErrorCode::CommonInvalidParam2, DidValue);
     check_useful_c_str!(name, ErrorCode::CommonInvalidParam3);
     check_useful_c_str!(version, ErrorCode::CommonInvalidParam4);
@@ -86,15 +97,14 @@ pub extern fn indy_issuer_create_schema(command_handle: CommandHandle,
 
     trace!("indy_issuer_create_schema: entity >>> issuer_did: {:?}, name: {:?}, version: {:?}, attrs: {:?}", issuer_did, name, version, attrs);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+    let result = CommandExecutor::submit(Command::Anoncreds(
             AnoncredsCommand::Issuer(
                 IssuerCommand::CreateSchema(
                     issuer_did,
                     name,
                     version,
                     attrs,
-                    Box::new(move |result| {
+                    with_timeout(move |result| {
                         let (err, id, schema_json) = prepare_result_2!(result, String::new(), String::new());
                         trace!("ursa_cl_credential_public_key_to_json: id: {:?}, schema_json: {:?}", id, schema_json);
                         let id = ctypes::string_to_cstring(id);
@@ -184,6 +194,8 @@ pub extern fn indy_issuer_create_and_store_credential_def(command_handle: Comman
     trace!("indy_issuer_create_and_store_credential_def: >>> wallet_handle: {:?}, issuer_did: {:?}, schema_json: {:?}, tag: {:?}, \
     signature_type: {:?}, config_json: {:?}", wallet_handle, issuer_did, schema_json, tag, signature_type, config_json);
 
+    command_params!(wallet_handle, issuer_did, schema_json, tag, signature_type, config_json);
+
     check_useful_validatable_string!(issuer_did, ErrorCode::CommonInvalidParam3, DidValue);
     check_useful_validatable_json!(schema_json, ErrorCode::CommonInvalidParam4, Schema);
     check_useful_c_str!(tag, ErrorCode::CommonInvalidParam5);
@@ -194,8 +206,7 @@ pub extern fn indy_issuer_create_and_store_credential_def(command_handle: Comman
     trace!("indy_issuer_create_and_store_credential_def: entities >>> wallet_handle: {:?}, issuer_did: {:?}, schema_json: {:?}, tag: {:?}, \
     signature_type: {:?}, config_json: {:?}", wallet_handle, issuer_did, schema_json, tag, signature_type, config_json);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+    let result = CommandExecutor::submit(Command::Anoncreds(
             AnoncredsCommand::Issuer(
                 IssuerCommand::CreateAndStoreCredentialDefinition(
                     wallet_handle,
@@ -204,7 +215,7 @@ pub extern fn indy_issuer_create_and_store_credential_def(command_handle: Comman
                     tag,
                     signature_type,
                     config_json,
-                    Box::new(move |result| {
+                    with_timeout(move |result| {
                         let (err, cred_def_id, cred_def_json) = prepare_result_2!(result, String::new(), String::new());
                         trace!("indy_issuer_create_and_store_credential_def: cred_def_id: {:?}, cred_def_json: {:?}", cred_def_id, cred_def_json);
                         let cred_def_id = ctypes::string_to_cstring(cred_def_id);
@@ -268,6 +279,8 @@ pub extern fn indy_issuer_rotate_credential_def_start(command_handle: CommandHan
     trace!("indy_issuer_rotate_credential_def_start: >>> wallet_handle: {:?}, cred_def_id: {:?}, config_json: {:?}",
            wallet_handle, cred_def_id, config_json);
 
+    command_params!(wallet_handle, cred_def_id, config_json);
+
     check_useful_validatable_string!(cred_def_id, ErrorCode::CommonInvalidParam3, CredentialDefinitionId);
     check_useful_opt_validatable_json!(config_json, ErrorCode::CommonInvalidParam4, CredentialDefinitionConfig);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
@@ -275,14 +288,13 @@ pub extern fn indy_issuer_rotate_credential_def_start(command_handle: CommandHan
     trace!("indy_issuer_rotate_credential_def_start: entities >>> wallet_handle: {:?}, cred_def_id: {:?}, config_json: {:?}",
            wallet_handle, cred_def_id, config_json);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+    let result = CommandExecutor::submit(Command::Anoncreds(
             AnoncredsCommand::Issuer(
                 IssuerCommand::RotateCredentialDefinitionStart(
                     wallet_handle,
                     cred_def_id,
                     config_json,
-                    Box::new(move |result| {
+                    with_timeout(move |result| {
                         let (err, cred_def_json) = prepare_result_1!(result, String::new());
                         trace!("indy_issuer_rotate_credential_def_start:cred_def_json: {:?}", cred_def_json);
                         let cred_def_json = ctypes::string_to_cstring(cred_def_json);
@@ -321,19 +333,20 @@ pub extern fn indy_issuer_rotate_credential_def_apply(command_handle: CommandHan
     trace!("indy_issuer_rotate_credential_def_apply: >>> wallet_handle: {:?}, cred_def_id: {:?}",
            wallet_handle, cred_def_id);
 
+    command_params!(wallet_handle, cred_def_id);
+
     check_useful_validatable_string!(cred_def_id, ErrorCode::CommonInvalidParam3, CredentialDefinitionId);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     trace!("indy_issuer_rotate_credential_def_apply: entities >>> wallet_handle: {:?}, cred_def_id: {:?}",
            wallet_handle, cred_def_id);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+    let result = CommandExecutor::submit(Command::Anoncreds(
             AnoncredsCommand::Issuer(
                 IssuerCommand::RotateCredentialDefinitionApply(
                     wallet_handle,
                     cred_def_id,
-                    Box::new(move |result| {
+                    with_timeout(move |result| {
                         let err = prepare_result!(result);
                         trace!("indy_issuer_rotate_credential_def_apply:");
                         cb(command_handle, err)
@@ -439,6 +452,8 @@ pub extern fn indy_issuer_create_and_store_revoc_reg(command_handle: CommandHand
     trace!("indy_issuer_create_and_store_credential_def: >>> wallet_handle: {:?}, issuer_did: {:?}, revoc_def_type: {:?}, tag: {:?}, \
     cred_def_id: {:?}, config_json: {:?}, tails_writer_handle: {:?}", wallet_handle, issuer_did, revoc_def_type, tag, cred_def_id, config_json, tails_writer_handle);
 
+    command_params!(wallet_handle, issuer_did, revoc_def_type, tag, cred_def_id, config_json, tails_writer_handle);
+
     check_useful_validatable_string!(issuer_did, ErrorCode::CommonInvalidParam3, DidValue);
     check_useful_opt_c_str!(revoc_def_type, ErrorCode::CommonInvalidParam4);
     check_useful_c_str!(tag, ErrorCode::CommonInvalidParam5);
@@ -449,8 +464,7 @@ pub extern fn indy_issuer_create_and_store_revoc_reg(command_handle: CommandHand
     trace!("indy_issuer_create_and_store_credential_def: entities >>> wallet_handle: {:?}, issuer_did: {:?}, revoc_def_type: {:?}, tag: {:?}, \
     cred_def_id: {:?}, config_json: {:?}, tails_writer_handle: {:?}", wallet_handle, issuer_did, revoc_def_type, tag, cred_def_id, config_json, tails_writer_handle);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+    let result = CommandExecutor::submit(Command::Anoncreds(
             AnoncredsCommand::Issuer(
                 IssuerCommand::CreateAndStoreRevocationRegistry(
                     wallet_handle,
@@ -460,7 +474,7 @@ pub extern fn indy_issuer_create_and_store_revoc_reg(command_handle: CommandHand
                     cred_def_id,
                     config_json,
                     tails_writer_handle,
-                    Box::new(move |result| {
+                    with_timeout(move |result| {
                         let (err, revoc_reg_id, revoc_reg_def_json, revoc_reg_json) = prepare_result_3!(result, String::new(), String::new(), String::new());
                         trace!("indy_issuer_create_and_store_credential_def: revoc_reg_id: {:?}, revoc_reg_def_json: {:?}, revoc_reg_json: {:?}",
                                revoc_reg_id, revoc_reg_def_json, revoc_reg_json);
@@ -512,18 +526,19 @@ pub extern fn indy_issuer_create_credential_offer(command_handle: CommandHandle,
                                                                        cred_offer_json: *const c_char)>) -> ErrorCode {
     trace!("indy_issuer_create_credential_offer: >>> wallet_handle: {:?}, cred_def_id: {:?}", wallet_handle, cred_def_id);
 
+    command_params!(wallet_handle, cred_def_id);
+
     check_useful_validatable_string!(cred_def_id, ErrorCode::CommonInvalidParam3, CredentialDefinitionId);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     trace!("indy_issuer_create_credential_offer: entities >>> wallet_handle: {:?}, cred_def_id: {:?}", wallet_handle, cred_def_id);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+    let result = CommandExecutor::submit(Command::Anoncreds(
             AnoncredsCommand::Issuer(
                 IssuerCommand::CreateCredentialOffer(
                     wallet_handle,
                     cred_def_id,
-                    boxed_callback_string!("indy_issuer_create_credential_offer", cb, command_handle)
+                    with_timeout(boxed_callback_string!("indy_issuer_create_credential_offer", cb, command_handle))
                 ))));
 
     let res = prepare_result!(result);
@@ -603,6 +618,8 @@ pub extern fn indy_issuer_create_credential(command_handle: CommandHandle,
     trace!("indy_issuer_create_credential: >>> wallet_handle: {:?}, cred_offer_json: {:?}, cred_req_json: {:?}, cred_values_json: {:?}, rev_reg_id: {:?}, \
     blob_storage_reader_handle: {:?}", wallet_handle, cred_offer_json, cred_req_json, cred_values_json, rev_reg_id, blob_storage_reader_handle);
 
+    command_params!(wallet_handle, cred_offer_json, cred_req_json, cred_values_json, rev_reg_id, blob_storage_reader_handle);
+
     check_useful_validatable_json!(cred_offer_json, ErrorCode::CommonInvalidParam3, CredentialOffer);
     check_useful_validatable_json!(cred_req_json, ErrorCode::CommonInvalidParam4, CredentialRequest);
     check_useful_validatable_json!(cred_values_json, ErrorCode::CommonInvalidParam5, CredentialValues);
@@ -614,8 +631,7 @@ pub extern fn indy_issuer_create_credential(command_handle: CommandHandle,
     trace!("indy_issuer_create_credential: entities >>> wallet_handle: {:?}, cred_offer_json: {:?}, cred_req_json: {:?}, cred_values_json: {:?}, rev_reg_id: {:?}, \
     blob_storage_reader_handle: {:?}", wallet_handle, cred_offer_json, secret!(&cred_req_json), secret!(&cred_values_json), secret!(&rev_reg_id), blob_storage_reader_handle);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+    let result = CommandExecutor::submit(Command::Anoncreds(
             AnoncredsCommand::Issuer(
                 IssuerCommand::CreateCredential(
                     wallet_handle,
@@ -624,7 +640,7 @@ pub extern fn indy_issuer_create_credential(command_handle: CommandHandle,
                     cred_values_json,
                     rev_reg_id,
                     blob_storage_reader_handle,
-                    Box::new(move |result| {
+                    with_timeout(move |result| {
                         let (err, cred_json, revoc_id, revoc_reg_delta_json) = prepare_result_3!(result, String::new(), None, None);
                         trace!("indy_issuer_create_credential: cred_json: {:?}, revoc_id: {:?}, revoc_reg_delta_json: {:?}",
                                secret!(cred_json.as_str()), secret!(&revoc_id), revoc_reg_delta_json);
@@ -686,6 +702,8 @@ pub extern fn indy_issuer_revoke_credential(command_handle: CommandHandle,
     trace!("indy_issuer_revoke_credential: >>> wallet_handle: {:?}, blob_storage_reader_cfg_handle: {:?}, rev_reg_id: {:?}, cred_revoc_id: {:?}",
            wallet_handle, blob_storage_reader_cfg_handle, rev_reg_id, cred_revoc_id);
 
+    command_params!(wallet_handle, blob_storage_reader_cfg_handle, rev_reg_id, cred_revoc_id);
+
     check_useful_validatable_string!(rev_reg_id, ErrorCode::CommonInvalidParam4, RevocationRegistryId);
     check_useful_c_str!(cred_revoc_id, ErrorCode::CommonInvalidParam5);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
@@ -693,15 +711,14 @@ pub extern fn indy_issuer_revoke_credential(command_handle: CommandHandle,
     trace!("indy_issuer_revoke_credential: entities >>> wallet_handle: {:?}, blob_storage_reader_cfg_handle: {:?}, rev_reg_id: {:?}, cred_revoc_id: {:?}",
            wallet_handle, blob_storage_reader_cfg_handle, rev_reg_id, secret!(cred_revoc_id.as_str()));
 
-    let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+    let result = CommandExecutor::submit(Command::Anoncreds(
             AnoncredsCommand::Issuer(
                 IssuerCommand::RevokeCredential(
                     wallet_handle,
                     blob_storage_reader_cfg_handle,
                     rev_reg_id,
                     cred_revoc_id,
-                    boxed_callback_string!("indy_issuer_revoke_credential", cb, command_handle)
+                    with_timeout(boxed_callback_string!("indy_issuer_revoke_credential", cb, command_handle))
                 ))));
 
     let res = prepare_result!(result);
@@ -751,19 +768,20 @@ pub extern fn indy_issuer_recover_credential(command_handle: CommandHandle,
                                              cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
                                                                   revoc_reg_delta_json: *const c_char,
                                              )>) -> ErrorCode {
+    command_params!(wallet_handle, blob_storage_reader_cfg_handle, rev_reg_id, cred_revoc_id);
+
     check_useful_c_str!(rev_reg_id, ErrorCode::CommonInvalidParam4);
     check_useful_c_str!(cred_revoc_id, ErrorCode::CommonInvalidParam5);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+    let result = CommandExecutor::submit(Command::Anoncreds(
             AnoncredsCommand::Issuer(
                 IssuerCommand::RecoverCredential(
                     wallet_handle,
                     blob_storage_reader_cfg_handle,
                     rev_reg_id,
                     cred_revoc_id,
-                    Box::new(move |result| {
+                    with_timeout(move |result| {
                         let (err, revoc_reg_update_json) = prepare_result_1!(result, String::new());
                         let revoc_reg_update_json = ctypes::string_to_cstring(revoc_reg_update_json);
                         cb(command_handle, err, revoc_reg_update_json.as_ptr())
@@ -817,6 +835,8 @@ pub extern fn indy_issuer_merge_revocation_registry_deltas(command_handle: Comma
     trace!("indy_issuer_merge_revocation_registry_deltas: >>> rev_reg_delta_json: {:?}, other_rev_reg_delta_json: {:?}",
            rev_reg_delta_json, other_rev_reg_delta_json);
 
+    command_params!(rev_reg_delta_json, other_rev_reg_delta_json);
+
     check_useful_validatable_json!(rev_reg_delta_json, ErrorCode::CommonInvalidParam2, RevocationRegistryDelta);
     check_useful_validatable_json!(other_rev_reg_delta_json, ErrorCode::CommonInvalidParam3, RevocationRegistryDelta);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
@@ -824,13 +844,12 @@ pub extern fn indy_issuer_merge_revocation_registry_deltas(command_handle: Comma
     trace!("indy_issuer_merge_revocation_registry_deltas: entities >>> rev_reg_delta_json: {:?}, other_rev_reg_delta_json: {:?}",
            rev_reg_delta_json, other_rev_reg_delta_json);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+    let result = CommandExecutor::submit(Command::Anoncreds(
             AnoncredsCommand::Issuer(
                 IssuerCommand::MergeRevocationRegistryDeltas(
                     rev_reg_delta_json,
                     other_rev_reg_delta_json,
-                    boxed_callback_string!("indy_issuer_merge_revocation_registry_deltas", cb, command_handle)
+                    with_timeout(boxed_callback_string!("indy_issuer_merge_revocation_registry_deltas", cb, command_handle))
                 ))));
 
     let res = prepare_result!(result);
@@ -863,18 +882,19 @@ pub extern fn indy_prover_create_master_secret(command_handle: CommandHandle,
                                                                     out_master_secret_id: *const c_char)>) -> ErrorCode {
     trace!("indy_prover_create_master_secret: >>> wallet_handle: {:?}, master_secret_id: {:?}", wallet_handle, master_secret_id);
 
+    command_params!(wallet_handle, master_secret_id);
+
     check_useful_opt_c_str!(master_secret_id, ErrorCode::CommonInvalidParam3);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     trace!("indy_prover_create_master_secret: entities >>> wallet_handle: {:?}, master_secret_id: {:?}", wallet_handle, master_secret_id);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+    let result = CommandExecutor::submit(Command::Anoncreds(
             AnoncredsCommand::Prover(
                 ProverCommand::CreateMasterSecret(
                     wallet_handle,
                     master_secret_id,
-                    boxed_callback_string!("indy_prover_create_master_secret", cb, command_handle)
+                    with_timeout(boxed_callback_string!("indy_prover_create_master_secret", cb, command_handle))
                 ))));
 
     let res = prepare_result!(result);
@@ -941,6 +961,8 @@ pub extern fn indy_prover_create_credential_req(command_handle: CommandHandle,
     trace!("indy_prover_create_credential_req: >>> wallet_handle: {:?}, prover_did: {:?}, cred_offer_json: {:?}, cred_def_json: {:?}, master_secret_id: {:?}",
            wallet_handle, prover_did, cred_offer_json, cred_def_json, master_secret_id);
 
+    command_params!(wallet_handle, prover_did, cred_offer_json, cred_def_json, master_secret_id);
+
     check_useful_validatable_string!(prover_did, ErrorCode::CommonInvalidParam3, DidValue);
     check_useful_validatable_json!(cred_offer_json, ErrorCode::CommonInvalidParam4, CredentialOffer);
     check_useful_validatable_json!(cred_def_json, ErrorCode::CommonInvalidParam5, CredentialDefinition);
@@ -950,8 +972,7 @@ pub extern fn indy_prover_create_credential_req(command_handle: CommandHandle,
     trace!("indy_prover_create_credential_req: entities >>> wallet_handle: {:?}, prover_did: {:?}, cred_offer_json: {:?}, cred_def_json: {:?}, master_secret_id: {:?}",
            wallet_handle, prover_did, cred_offer_json, cred_def_json, master_secret_id);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+    let result = CommandExecutor::submit(Command::Anoncreds(
             AnoncredsCommand::Prover(
                 ProverCommand::CreateCredentialRequest(
                     wallet_handle,
@@ -959,7 +980,7 @@ pub extern fn indy_prover_create_credential_req(command_handle: CommandHandle,
                     cred_offer_json,
                     cred_def_json,
                     master_secret_id,
-                    Box::new(move |result| {
+                    with_timeout(move |result| {
                         let (err, cred_req_json, cred_req_metadata_json) = prepare_result_2!(result, String::new(), String::new());
                         trace!("indy_prover_create_credential_req: cred_req_json: {:?}, cred_req_metadata_json: {:?}", cred_req_json, cred_req_metadata_json);
                         let cred_req_json = ctypes::string_to_cstring(cred_req_json);
@@ -975,6 +996,69 @@ pub extern fn indy_prover_create_credential_req(command_handle: CommandHandle,
     res
 }
 
+/// Validates a credential offer received from an issuer and parses it into a normalized structure.
+///
+/// Checks format of schema and credential definition ids, nonce of the offer and that the offer
+/// references the provided credential definition, then verifies key correctness proof of the offer
+/// against the credential definition the same way as `indy_prover_create_credential_req` does.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// cred_offer_json: credential offer as a json containing information about the issuer and a credential
+/// cred_def_json: credential definition json related to <cred_def_id> in <cred_offer_json>
+///     (usually received from ledger by `indy_build_get_cred_def_request` / `indy_parse_get_cred_def_response`)
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// parsed_offer_json: credential offer data in the form
+///     {
+///         "schema_id": string, - identifier of schema
+///         "cred_def_id": string, - identifier of credential definition
+///         "issuer_did": string, - DID of credential issuer
+///         "schema_issuer_did": Optional<string>, - DID of schema issuer
+///         "schema_name": Optional<string>, - name of schema
+///         "schema_version": Optional<string>, - version of schema
+///         "cred_def_tag": string, - tag of credential definition (empty if not set)
+///         "method_name": Optional<string>, - DID method of fully qualified identifiers
+///         "nonce": string, - nonce of the offer
+///         "attr_names": [string], - names of attributes of the credential
+///         "revocable": bool - whether credential definition supports revocation
+///     }
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+#[no_mangle]
+pub extern fn indy_prover_parse_credential_offer(command_handle: CommandHandle,
+                                                 cred_offer_json: *const c_char,
+                                                 cred_def_json: *const c_char,
+                                                 cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                      parsed_offer_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_prover_parse_credential_offer: >>> cred_offer_json: {:?}, cred_def_json: {:?}", cred_offer_json, cred_def_json);
+
+    command_params!(cred_offer_json, cred_def_json);
+
+    check_useful_validatable_json!(cred_offer_json, ErrorCode::CommonInvalidParam2, CredentialOffer);
+    check_useful_validatable_json!(cred_def_json, ErrorCode::CommonInvalidParam3, CredentialDefinition);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_prover_parse_credential_offer: entities >>> cred_offer_json: {:?}, cred_def_json: {:?}", cred_offer_json, cred_def_json);
+
+    let result = CommandExecutor::submit(Command::Anoncreds(
+            AnoncredsCommand::Prover(
+                ProverCommand::ParseCredentialOffer(
+                    cred_offer_json,
+                    cred_def_json,
+                    with_timeout(boxed_callback_string!("indy_prover_parse_credential_offer", cb, command_handle))
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_prover_parse_credential_offer: <<< res: {:?}", res);
+
+    res
+}
+
 /// Set credential attribute tagging policy.
 /// Writes a non-secret record marking attributes to tag, and optionally
 /// updates tags on existing credentials on the credential definition to match.
@@ -996,14 +1080,19 @@ pub extern fn indy_prover_create_credential_req(command_handle: CommandHandle,
 /// credentials and (optionally) all existing credentials on the credential definition:
 ///     {
 ///         "attr::<attribute name>::marker": "1",
-///         "attr::<attribute name>::value": <attribute raw value>,
+///         "attr::<attribute name>::value": <attribute raw value>, // omitted for attributes the policy marks marker-only
 ///     }
 ///
 /// #Params
 /// command_handle: command handle to map callback to user context.
 /// wallet_handle: wallet handle (created by open_wallet).
 /// cred_def_id: credential definition id
-/// tag_attrs_json: JSON array with names of attributes to tag by policy, or null for all
+/// tag_attrs_json: JSON array with names of attributes to tag by policy, or null for all.
+///     To keep raw values of some attributes out of tags, the policy can be set as object:
+///     {
+///         "taggable": Optional<[string]>, - names of attributes to tag (all attributes if not set)
+///         "marker_only": Optional<[string]>, - names of attributes tagged by marker only without value tag
+///     }
 /// retroactive: boolean, whether to apply policy to existing credentials on credential definition identifier
 /// cb: Callback that takes command result as parameter.
 ///
@@ -1020,6 +1109,8 @@ pub extern fn indy_prover_set_credential_attr_tag_policy(command_handle: Command
                                                          cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode)>) -> ErrorCode {
     trace!("indy_prover_set_credential_attr_tag_policy: >>> wallet_handle: {:?}, cred_def_id: {:?}, tag_attrs_json: {:?}, retroactive: {:?}", wallet_handle, cred_def_id, tag_attrs_json, retroactive);
 
+    command_params!(wallet_handle, cred_def_id, tag_attrs_json, retroactive);
+
     check_useful_validatable_string!(cred_def_id, ErrorCode::CommonInvalidParam3, CredentialDefinitionId);
     check_useful_opt_json!(tag_attrs_json, ErrorCode::CommonInvalidParam4, CredentialAttrTagPolicy);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
@@ -1027,15 +1118,14 @@ pub extern fn indy_prover_set_credential_attr_tag_policy(command_handle: Command
     trace!("indy_prover_set_credential_attr_tag_policy: entities >>> wallet_handle: {:?}, cred_def_id: {:?}, tag_attrs_json: {:?}, retroactive: {:?}",
            wallet_handle, cred_def_id, tag_attrs_json, retroactive);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+    let result = CommandExecutor::submit(Command::Anoncreds(
             AnoncredsCommand::Prover(
                 ProverCommand::SetCredentialAttrTagPolicy(
                     wallet_handle,
                     cred_def_id,
                     tag_attrs_json,
                     retroactive,
-                    Box::new(move |result| {
+                    with_timeout(move |result| {
                         let err = prepare_result!(result);
                         trace!("indy_prover_set_credential_attr_tag_policy: ");
                         cb(command_handle, err)
@@ -1076,18 +1166,19 @@ pub extern fn indy_prover_get_credential_attr_tag_policy(command_handle: Command
                                                                               catpol_json: *const c_char)>) -> ErrorCode {
     trace!("indy_prover_get_credential_attr_tag_policy: >>> wallet_handle: {:?}, cred_def_id: {:?}", wallet_handle, cred_def_id);
 
+    command_params!(wallet_handle, cred_def_id);
+
     check_useful_validatable_string!(cred_def_id, ErrorCode::CommonInvalidParam3, CredentialDefinitionId);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     trace!("indy_prover_get_credential_attr_tag_policy: entities >>> wallet_handle: {:?}, cred_def_id: {:?}", wallet_handle, cred_def_id);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+    let result = CommandExecutor::submit(Command::Anoncreds(
             AnoncredsCommand::Prover(
                 ProverCommand::GetCredentialAttrTagPolicy(
                     wallet_handle,
                     cred_def_id,
-                    boxed_callback_string!("indy_prover_get_credential_attr_tag_policy", cb, command_handle)
+                    with_timeout(boxed_callback_string!("indy_prover_get_credential_attr_tag_policy", cb, command_handle))
                 ))));
 
     let res = prepare_result!(result);
@@ -1157,6 +1248,8 @@ pub extern fn indy_prover_store_credential(command_handle: CommandHandle,
     trace!("indy_prover_store_credential: >>> wallet_handle: {:?}, cred_id: {:?}, cred_req_metadata_json: {:?}, cred_json: {:?}, cred_def_json: {:?}, \
     cred_def_json: {:?}", wallet_handle, cred_id, cred_req_metadata_json, cred_json, cred_def_json, rev_reg_def_json);
 
+    command_params!(wallet_handle, cred_id, cred_req_metadata_json, cred_json, cred_def_json, rev_reg_def_json);
+
     check_useful_opt_c_str!(cred_id, ErrorCode::CommonInvalidParam3);
     check_useful_validatable_json!(cred_req_metadata_json, ErrorCode::CommonInvalidParam4, CredentialRequestMetadata);
     check_useful_validatable_json!(cred_json, ErrorCode::CommonInvalidParam5, Credential);
@@ -1167,8 +1260,7 @@ pub extern fn indy_prover_store_credential(command_handle: CommandHandle,
     trace!("indy_prover_store_credential: entities >>> wallet_handle: {:?}, cred_id: {:?}, cred_req_metadata_json: {:?}, cred_json: {:?}, cred_def_json: {:?}, \
     rev_reg_def_json: {:?}", wallet_handle, cred_id, cred_req_metadata_json, cred_json, cred_def_json, rev_reg_def_json);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+    let result = CommandExecutor::submit(Command::Anoncreds(
             AnoncredsCommand::Prover(
                 ProverCommand::StoreCredential(
                     wallet_handle,
@@ -1177,7 +1269,8 @@ pub extern fn indy_prover_store_credential(command_handle: CommandHandle,
                     cred_json,
                     cred_def_json,
                     rev_reg_def_json,
-                    boxed_callback_string!("indy_prover_store_credential", cb, command_handle)
+                    None,
+                    with_timeout(boxed_callback_string!("indy_prover_store_credential", cb, command_handle))
                 ))));
 
     let res = prepare_result!(result);
@@ -1187,6 +1280,179 @@ pub extern fn indy_prover_store_credential(command_handle: CommandHandle,
     res
 }
 
+/// Check credential provided by Issuer for the given credential request,
+/// updates the credential by a master secret and stores in a secure wallet with custom tags
+/// in addition to the tags described in `indy_prover_store_credential`.
+///
+/// EXPERIMENTAL
+///
+/// Custom tags are kept when the tags of the credential are rebuilt by `indy_prover_set_credential_attr_tag_policy`.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// cred_id: (optional, default is a random one) identifier by which credential will be stored in the wallet
+/// cred_req_metadata_json: a credential request metadata created by indy_prover_create_credential_req
+/// cred_json: credential json received from issuer (see `indy_prover_store_credential`)
+/// cred_def_json: credential definition json related to <cred_def_id> in <cred_json>
+/// rev_reg_def_json: revocation registry definition json related to <rev_reg_def_id> in <cred_json>
+/// tags_json: custom tags of the credential:
+///     {
+///         "tagName1": <str>, // string tag (will be stored encrypted)
+///         "~tagName2": <str>, // string tag (will be stored un-encrypted)
+///     }
+///     Tag names can't clash with the tags created by libindy (`schema_id`, `attr::<attribute name>::value` and so on).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// out_cred_id: identifier by which credential is stored in the wallet
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_prover_store_credential_with_tags(command_handle: CommandHandle,
+                                                     wallet_handle: WalletHandle,
+                                                     cred_id: *const c_char,
+                                                     cred_req_metadata_json: *const c_char,
+                                                     cred_json: *const c_char,
+                                                     cred_def_json: *const c_char,
+                                                     rev_reg_def_json: *const c_char,
+                                                     tags_json: *const c_char,
+                                                     cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                          out_cred_id: *const c_char)>) -> ErrorCode {
+    trace!("indy_prover_store_credential_with_tags: >>> wallet_handle: {:?}, cred_id: {:?}, cred_req_metadata_json: {:?}, cred_json: {:?}, cred_def_json: {:?}, \
+    rev_reg_def_json: {:?}, tags_json: {:?}", wallet_handle, cred_id, cred_req_metadata_json, cred_json, cred_def_json, rev_reg_def_json, tags_json);
+
+    command_params!(wallet_handle, cred_id, cred_req_metadata_json, cred_json, cred_def_json, rev_reg_def_json, tags_json);
+
+    check_useful_opt_c_str!(cred_id, ErrorCode::CommonInvalidParam3);
+    check_useful_validatable_json!(cred_req_metadata_json, ErrorCode::CommonInvalidParam4, CredentialRequestMetadata);
+    check_useful_validatable_json!(cred_json, ErrorCode::CommonInvalidParam5, Credential);
+    check_useful_validatable_json!(cred_def_json, ErrorCode::CommonInvalidParam6, CredentialDefinition);
+    check_useful_opt_validatable_json!(rev_reg_def_json, ErrorCode::CommonInvalidParam7, RevocationRegistryDefinition);
+    check_useful_json!(tags_json, ErrorCode::CommonInvalidParam8, Tags);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam9);
+
+    trace!("indy_prover_store_credential_with_tags: entities >>> wallet_handle: {:?}, cred_id: {:?}, cred_req_metadata_json: {:?}, cred_json: {:?}, cred_def_json: {:?}, \
+    rev_reg_def_json: {:?}, tags_json: {:?}", wallet_handle, cred_id, cred_req_metadata_json, cred_json, cred_def_json, rev_reg_def_json, tags_json);
+
+    let result = CommandExecutor::submit(Command::Anoncreds(
+            AnoncredsCommand::Prover(
+                ProverCommand::StoreCredential(
+                    wallet_handle,
+                    cred_id,
+                    cred_req_metadata_json,
+                    cred_json,
+                    cred_def_json,
+                    rev_reg_def_json,
+                    Some(tags_json),
+                    with_timeout(boxed_callback_string!("indy_prover_store_credential_with_tags", cb, command_handle))
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_prover_store_credential_with_tags: <<< res: {:?}", res);
+
+    res
+}
+
+/// Imports AnonCreds credential issued in W3C Verifiable Credential format (data integrity proof
+/// with "anoncreds-2023" cryptosuite) into the wallet.
+///
+/// The credential is converted to the legacy format, checked, updated by a master secret
+/// and stored the same way as indy_prover_store_credential does. So it can be found by
+/// the same tags and used in Indy proof requests.
+///
+/// Attributes are encoded as AnonCreds issuers do: 32-bit integers are encoded as themselves,
+/// other values as decimal representation of SHA-256 hash of raw value. Only "auto" encoding is supported.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// cred_id: (optional, default is a random one) identifier by which credential will be stored in the wallet
+/// cred_req_metadata_json: a credential request metadata created by indy_prover_create_credential_req
+/// w3c_cred_json: W3C credential json received from issuer
+///     {
+///         "@context": [...],
+///         "type": ["VerifiableCredential", "AnonCredsCredential"],
+///         "issuer": string, - did of issuer
+///         "issuanceDate": string,
+///         "credentialSchema": {
+///             "type": "AnonCredsDefinition",
+///             "definition": string, - identifier of credential definition
+///             "schema": string, - identifier of schema
+///             "revocationRegistry": Optional<string>, - identifier of revocation registry
+///             "encoding": Optional<string> - "auto" (default)
+///         },
+///         "credentialSubject": {
+///             "id": Optional<string>, - ignored
+///             "attr1": "raw value1", - string or number
+///             "attr2": "raw value2"
+///         },
+///         "proof": [{ - can be single object
+///             "type": "DataIntegrityProof",
+///             "cryptosuite": "anoncreds-2023",
+///             "proofPurpose": "assertionMethod",
+///             "proofValue": string - multibase (base64url) encoded credential signature
+///         }]
+///     }
+/// cred_def_json: credential definition json related to "definition" in <w3c_cred_json>
+/// rev_reg_def_json: revocation registry definition json related to "revocationRegistry" in <w3c_cred_json>
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// out_cred_id: identifier by which credential is stored in the wallet
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_prover_import_w3c_credential(command_handle: CommandHandle,
+                                                wallet_handle: WalletHandle,
+                                                cred_id: *const c_char,
+                                                cred_req_metadata_json: *const c_char,
+                                                w3c_cred_json: *const c_char,
+                                                cred_def_json: *const c_char,
+                                                rev_reg_def_json: *const c_char,
+                                                cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                     out_cred_id: *const c_char)>) -> ErrorCode {
+    trace!("indy_prover_import_w3c_credential: >>> wallet_handle: {:?}, cred_id: {:?}, cred_req_metadata_json: {:?}, w3c_cred_json: {:?}, cred_def_json: {:?}, \
+    rev_reg_def_json: {:?}", wallet_handle, cred_id, cred_req_metadata_json, w3c_cred_json, cred_def_json, rev_reg_def_json);
+
+    command_params!(wallet_handle, cred_id, cred_req_metadata_json, w3c_cred_json, cred_def_json, rev_reg_def_json);
+
+    check_useful_opt_c_str!(cred_id, ErrorCode::CommonInvalidParam3);
+    check_useful_validatable_json!(cred_req_metadata_json, ErrorCode::CommonInvalidParam4, CredentialRequestMetadata);
+    check_useful_validatable_json!(w3c_cred_json, ErrorCode::CommonInvalidParam5, W3CCredential);
+    check_useful_validatable_json!(cred_def_json, ErrorCode::CommonInvalidParam6, CredentialDefinition);
+    check_useful_opt_validatable_json!(rev_reg_def_json, ErrorCode::CommonInvalidParam7, RevocationRegistryDefinition);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam8);
+
+    trace!("indy_prover_import_w3c_credential: entities >>> wallet_handle: {:?}, cred_id: {:?}, cred_req_metadata_json: {:?}, w3c_cred_json: {:?}, cred_def_json: {:?}, \
+    rev_reg_def_json: {:?}", wallet_handle, cred_id, cred_req_metadata_json, w3c_cred_json, cred_def_json, rev_reg_def_json);
+
+    let result = CommandExecutor::submit(Command::Anoncreds(
+            AnoncredsCommand::Prover(
+                ProverCommand::ImportW3CCredential(
+                    wallet_handle,
+                    cred_id,
+                    cred_req_metadata_json,
+                    w3c_cred_json,
+                    cred_def_json,
+                    rev_reg_def_json,
+                    with_timeout(boxed_callback_string!("indy_prover_import_w3c_credential", cb, command_handle))
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_prover_import_w3c_credential: <<< res: {:?}", res);
+
+    res
+}
+
 /// Gets human readable credential by the given id.
 ///
 /// #Params
@@ -1218,18 +1484,19 @@ pub extern fn indy_prover_get_credential(command_handle: CommandHandle,
                                              credential_json: *const c_char)>) -> ErrorCode {
     trace!("indy_prover_get_credential: >>> wallet_handle: {:?}, cred_id: {:?}", wallet_handle, cred_id);
 
+    command_params!(wallet_handle, cred_id);
+
     check_useful_c_str!(cred_id, ErrorCode::CommonInvalidParam3);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     trace!("indy_prover_get_credential: entities >>> wallet_handle: {:?}, cred_id: {:?}", wallet_handle, cred_id);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+    let result = CommandExecutor::submit(Command::Anoncreds(
             AnoncredsCommand::Prover(
                 ProverCommand::GetCredential(
                     wallet_handle,
                     cred_id,
-                    boxed_callback_string!("indy_prover_get_credential", cb, command_handle)
+                    with_timeout(boxed_callback_string!("indy_prover_get_credential", cb, command_handle))
                 ))));
 
     let res = prepare_result!(result);
@@ -1259,16 +1526,17 @@ pub extern fn indy_prover_delete_credential(command_handle: CommandHandle,
                                                 err: ErrorCode)>) -> ErrorCode {
     trace!("indy_prover_delete_credential: >>> wallet_handle: {:?}, cred_id: {:?}", wallet_handle, cred_id);
 
+    command_params!(wallet_handle, cred_id);
+
     check_useful_c_str!(cred_id, ErrorCode::CommonInvalidParam3);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+    let result = CommandExecutor::submit(Command::Anoncreds(
             AnoncredsCommand::Prover(
                 ProverCommand::DeleteCredential(
                     wallet_handle,
                     cred_id,
-                    Box::new(move |result| {
+                    with_timeout(move |result| {
                         let err = prepare_result!(result);
                         trace!("indy_prover_delete_credential: ");
                         cb(command_handle, err)
@@ -1327,18 +1595,19 @@ pub extern fn indy_prover_get_credentials(command_handle: CommandHandle,
                                               matched_credentials_json: *const c_char)>) -> ErrorCode {
     trace!("indy_prover_get_credentials: >>> wallet_handle: {:?}, filter_json: {:?}", wallet_handle, filter_json);
 
+    command_params!(wallet_handle, filter_json);
+
     check_useful_opt_c_str!(filter_json, ErrorCode::CommonInvalidParam3);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     trace!("indy_prover_get_credentials: entities >>> wallet_handle: {:?}, filter_json: {:?}", wallet_handle, filter_json);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+    let result = CommandExecutor::submit(Command::Anoncreds(
             AnoncredsCommand::Prover(
                 ProverCommand::GetCredentials(
                     wallet_handle,
                     filter_json,
-                    boxed_callback_string!("indy_prover_get_credentials", cb, command_handle)
+                    with_timeout(boxed_callback_string!("indy_prover_get_credentials", cb, command_handle))
                 ))));
 
     let res = prepare_result!(result);
@@ -1379,18 +1648,19 @@ pub extern fn indy_prover_search_credentials(command_handle: CommandHandle,
                                                  total_count: usize)>) -> ErrorCode {
     trace!("indy_prover_search_credentials: >>> wallet_handle: {:?}, query_json: {:?}", wallet_handle, query_json);
 
+    command_params!(wallet_handle, query_json);
+
     check_useful_opt_c_str!(query_json, ErrorCode::CommonInvalidParam3);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     trace!("indy_prover_search_credentials: entities >>> wallet_handle: {:?}, query_json: {:?}", wallet_handle, query_json);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+    let result = CommandExecutor::submit(Command::Anoncreds(
             AnoncredsCommand::Prover(
                 ProverCommand::SearchCredentials(
                     wallet_handle,
                     query_json,
-                    Box::new(move |result| {
+                    with_timeout(move |result| {
                         let (err, handle, total_count) = prepare_result_2!(result, INVALID_SEARCH_HANDLE, 0);
                         cb(command_handle, err, handle, total_count)
                     })
@@ -1434,17 +1704,18 @@ pub  extern fn indy_prover_fetch_credentials(command_handle: CommandHandle,
                                                                   credentials_json: *const c_char)>) -> ErrorCode {
     trace!("indy_prover_fetch_credentials: >>> search_handle: {:?}, count: {:?}", search_handle, count);
 
+    command_params!(search_handle, count);
+
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     trace!("indy_prover_fetch_credentials: entities >>> search_handle: {:?}, count: {:?}", search_handle, count);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+    let result = CommandExecutor::submit(Command::Anoncreds(
             AnoncredsCommand::Prover(
                 ProverCommand::FetchCredentials(
                     search_handle,
                     count,
-                    boxed_callback_string!("indy_prover_fetch_credentials", cb, command_handle)
+                    with_timeout(boxed_callback_string!("indy_prover_fetch_credentials", cb, command_handle))
                 ))));
 
     let res = prepare_result!(result);
@@ -1469,16 +1740,17 @@ pub  extern fn indy_prover_close_credentials_search(command_handle: CommandHandl
                                                     cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode)>) -> ErrorCode {
     trace!("indy_prover_close_credentials_search: >>> search_handle: {:?}", search_handle);
 
+    command_params!(search_handle);
+
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
 
     trace!("indy_prover_close_credentials_search: entities >>> search_handle: {:?}", search_handle);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+    let result = CommandExecutor::submit(Command::Anoncreds(
             AnoncredsCommand::Prover(
                 ProverCommand::CloseCredentialsSearch(
                     search_handle,
-                    Box::new(move |result| {
+                    with_timeout(move |result| {
                         let err = prepare_result!(result);
                         trace!("indy_prover_close_credentials_search:");
                         cb(command_handle, err)
@@ -1598,19 +1870,20 @@ pub extern fn indy_prover_get_credentials_for_proof_req(command_handle: CommandH
                                                             credentials_json: *const c_char)>) -> ErrorCode {
     trace!("indy_prover_get_credentials_for_proof_req: >>> wallet_handle: {:?}, proof_request_json: {:?}", wallet_handle, proof_request_json);
 
+    command_params!(wallet_handle, proof_request_json);
+
     check_useful_validatable_json!(proof_request_json, ErrorCode::CommonInvalidParam3, ProofRequest);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
 
     trace!("indy_prover_get_credentials_for_proof_req: entities >>> wallet_handle: {:?}, proof_request_json: {:?}",
            wallet_handle, proof_request_json);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+    let result = CommandExecutor::submit(Command::Anoncreds(
             AnoncredsCommand::Prover(
                 ProverCommand::GetCredentialsForProofReq(
                     wallet_handle,
                     proof_request_json,
-                    boxed_callback_string!("indy_prover_get_credentials_for_proof_req", cb, command_handle)
+                    with_timeout(boxed_callback_string!("indy_prover_get_credentials_for_proof_req", cb, command_handle))
                 ))));
 
     let res = prepare_result!(result);
@@ -1718,6 +1991,8 @@ pub extern fn indy_prover_search_credentials_for_proof_req(command_handle: Comma
                                                                search_handle: SearchHandle)>) -> ErrorCode {
     trace!("indy_prover_search_credentials_for_proof_req: >>> wallet_handle: {:?}, proof_request_json: {:?}, extra_query_json: {:?}", wallet_handle, proof_request_json, extra_query_json);
 
+    command_params!(wallet_handle, proof_request_json, extra_query_json);
+
     check_useful_validatable_json!(proof_request_json, ErrorCode::CommonInvalidParam3, ProofRequest);
     check_useful_opt_json!(extra_query_json, ErrorCode::CommonInvalidParam4, ProofRequestExtraQuery);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
@@ -1725,14 +2000,13 @@ pub extern fn indy_prover_search_credentials_for_proof_req(command_handle: Comma
     trace!("indy_prover_search_credentials_for_proof_req: entities >>> wallet_handle: {:?}, proof_request_json: {:?}, extra_query_json: {:?}",
            wallet_handle, proof_request_json, extra_query_json);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+    let result = CommandExecutor::submit(Command::Anoncreds(
             AnoncredsCommand::Prover(
                 ProverCommand::SearchCredentialsForProofReq(
                     wallet_handle,
                     proof_request_json,
                     extra_query_json,
-                    Box::new(move |result| {
+                    with_timeout(move |result| {
                         let (err, search_handle) = prepare_result_1!(result, INVALID_SEARCH_HANDLE);
                         trace!("indy_prover_search_credentials_for_proof_req: search_handle: {:?}", search_handle);
                         cb(command_handle, err, search_handle)
@@ -1792,19 +2066,20 @@ pub  extern fn indy_prover_fetch_credentials_for_proof_req(command_handle: Comma
                                                                                 credentials_json: *const c_char)>) -> ErrorCode {
     trace!("indy_prover_fetch_credentials_for_proof_req: >>> search_handle: {:?}, count: {:?}", search_handle, count);
 
+    command_params!(search_handle, item_referent, count);
+
     check_useful_c_str!(item_referent, ErrorCode::CommonInvalidParam4);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
 
     trace!("indy_prover_fetch_credentials_for_proof_req: entities >>> search_handle: {:?}, count: {:?}", search_handle, count);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+    let result = CommandExecutor::submit(Command::Anoncreds(
             AnoncredsCommand::Prover(
                 ProverCommand::FetchCredentialForProofReq(
                     search_handle,
                     item_referent,
                     count,
-                    boxed_callback_string!("indy_prover_fetch_credentials_for_proof_request", cb, command_handle)
+                    with_timeout(boxed_callback_string!("indy_prover_fetch_credentials_for_proof_request", cb, command_handle))
                 ))));
 
     let res = prepare_result!(result);
@@ -1829,16 +2104,17 @@ pub  extern fn indy_prover_close_credentials_search_for_proof_req(command_handle
                                                                   cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode)>) -> ErrorCode {
     trace!("indy_prover_close_credentials_search_for_proof_req: >>> search_handle: {:?}", search_handle);
 
+    command_params!(search_handle);
+
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
 
     trace!("indy_prover_close_credentials_search_for_proof_req: entities >>> search_handle: {:?}", search_handle);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(
+    let result = CommandExecutor::submit(Command::Anoncreds(
             AnoncredsCommand::Prover(
                 ProverCommand::CloseCredentialsSearchForProofReq(
                     search_handle,
-                    Box::new(move |result| {
+                    with_timeout(move |result| {
                         let err = prepare_result!(result);
                         trace!("indy_prover_close_credentials_search:");
                         cb(command_handle, err)
@@ -2032,6 +2308,8 @@ pub extern fn indy_prover_create_proof(command_handle: CommandHandle,
     schemas_json: {:?}, credential_defs_json: {:?}, rev_states_json: {:?}",
            wallet_handle, proof_req_json, requested_credentials_json, master_secret_id, schemas_json, credential_defs_json, rev_states_json);
 
+    command_params!(wallet_handle, proof_req_json, requested_credentials_json, master_secret_id, schemas_json, credential_defs_json, rev_states_json);
+
     check_useful_validatable_json!(proof_req_json, ErrorCode::CommonInvalidParam3, ProofRequest);
     check_useful_validatable_json!(requested_credentials_json, ErrorCode::CommonInvalidParam4, RequestedCredentials);
     check_useful_c_str!(master_secret_id, ErrorCode::CommonInvalidParam5);
@@ -2044,8 +2322,7 @@ pub extern fn indy_prover_create_proof(command_handle: CommandHandle,
     schemas_json: {:?}, credential_defs_json: {:?}, rev_states_json: {:?}",
            wallet_handle, proof_req_json, requested_credentials_json, master_secret_id, schemas_json, credential_defs_json, rev_states_json);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::CreateProof(
+    let result = CommandExecutor::submit(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::CreateProof(
             wallet_handle,
             proof_req_json,
             requested_credentials_json,
@@ -2053,7 +2330,7 @@ pub extern fn indy_prover_create_proof(command_handle: CommandHandle,
             schemas_json,
             credential_defs_json,
             rev_states_json,
-            boxed_callback_string!("indy_prover_create_proof", cb, command_handle)
+            with_timeout(boxed_callback_string!("indy_prover_create_proof", cb, command_handle))
         ))));
 
     let res = prepare_result!(result);
@@ -2225,6 +2502,8 @@ pub extern fn indy_verifier_verify_proof(command_handle: CommandHandle,
     trace!("indy_verifier_verify_proof: >>> proof_request_json: {:?}, proof_json: {:?}, schemas_json: {:?}, credential_defs_json: {:?}, \
     rev_reg_defs_json: {:?}, rev_regs_json: {:?}", proof_request_json, proof_json, schemas_json, credential_defs_json, rev_reg_defs_json, rev_regs_json);
 
+    command_params!(proof_request_json, proof_json, schemas_json, credential_defs_json, rev_reg_defs_json, rev_regs_json);
+
     check_useful_validatable_json!(proof_request_json, ErrorCode::CommonInvalidParam2, ProofRequest);
     check_useful_validatable_json!(proof_json, ErrorCode::CommonInvalidParam3, Proof);
     check_useful_json!(schemas_json, ErrorCode::CommonInvalidParam4, Schemas);
@@ -2236,15 +2515,14 @@ pub extern fn indy_verifier_verify_proof(command_handle: CommandHandle,
     trace!("indy_verifier_verify_proof: entities >>> proof_request_json: {:?}, proof_json: {:?}, schemas_json: {:?}, credential_defs_json: {:?}, \
     rev_reg_defs_json: {:?}, rev_regs_json: {:?}", proof_request_json, proof_json, schemas_json, credential_defs_json, rev_reg_defs_json, rev_regs_json);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(AnoncredsCommand::Verifier(VerifierCommand::VerifyProof(
+    let result = CommandExecutor::submit(Command::Anoncreds(AnoncredsCommand::Verifier(VerifierCommand::VerifyProof(
             proof_request_json,
             proof_json,
             schemas_json,
             credential_defs_json,
             rev_reg_defs_json,
             rev_regs_json,
-            Box::new(move |result| {
+            with_timeout(move |result| {
                 let (err, valid) = prepare_result_1!(result, false);
                 trace!("indy_verifier_verify_proof: valid: {:?}", valid);
 
@@ -2259,6 +2537,135 @@ pub extern fn indy_verifier_verify_proof(command_handle: CommandHandle,
     res
 }
 
+/// Verifies a proof referencing schemas, credential definitions and revocation registries from multiple ledgers.
+///
+/// EXPERIMENTAL
+///
+/// Ledger objects the proof is built on (listed in `identifiers` of the proof) are fetched from the pools
+/// resolved by DIDs of their issuers and the proof is verified once all of them are received,
+/// the same way as `indy_verifier_verify_proof` does.
+/// Revocation registries are fetched for timestamps of the proof identifiers.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// pools_json: pools to fetch the ledger objects from:
+///     {
+///         "pools": { - (Optional) pools mapped by issuer DID or by did:indy namespace (for example `sovrin:staging`)
+///             <issuer did or did:indy namespace>: <pool handle>,
+///             ...
+///         },
+///         "default": <pool handle>, - (Optional) pool for the objects of issuers not matched by `pools`
+///     }
+///     Object is fetched from the pool mapped to its issuer DID, then to the did:indy namespace of the DID,
+///     then from the default pool.
+/// proof_request_json: proof request json (see `indy_verifier_verify_proof` for the format)
+/// proof_json: created for request proof json (see `indy_verifier_verify_proof` for the format)
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// valid: true - if signature is valid, false - otherwise
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Ledger*
+#[cfg(feature = "ledger")]
+#[no_mangle]
+pub extern fn indy_verifier_verify_proof_with_pools(command_handle: CommandHandle,
+                                                    pools_json: *const c_char,
+                                                    proof_request_json: *const c_char,
+                                                    proof_json: *const c_char,
+                                                    cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                         valid: bool)>) -> ErrorCode {
+    trace!("indy_verifier_verify_proof_with_pools: >>> pools_json: {:?}, proof_request_json: {:?}, proof_json: {:?}",
+           pools_json, proof_request_json, proof_json);
+
+    command_params!(pools_json, proof_request_json, proof_json);
+
+    check_useful_validatable_json!(pools_json, ErrorCode::CommonInvalidParam2, VerifierPools);
+    check_useful_validatable_json!(proof_request_json, ErrorCode::CommonInvalidParam3, ProofRequest);
+    check_useful_validatable_json!(proof_json, ErrorCode::CommonInvalidParam4, Proof);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_verifier_verify_proof_with_pools: entities >>> pools_json: {:?}, proof_request_json: {:?}, proof_json: {:?}",
+           pools_json, proof_request_json, proof_json);
+
+    let result = CommandExecutor::submit(Command::Anoncreds(AnoncredsCommand::Verifier(VerifierCommand::VerifyProofWithPools(
+            proof_request_json,
+            proof_json,
+            pools_json,
+            with_timeout(move |result| {
+                let (err, valid) = prepare_result_1!(result, false);
+                trace!("indy_verifier_verify_proof_with_pools: valid: {:?}", valid);
+
+                cb(command_handle, err, valid)
+            })
+        ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_verifier_verify_proof_with_pools: <<< res: {:?}", res);
+
+    res
+}
+
+/// Statically validates a proof request before it is sent to a prover.
+///
+/// Unlike other functions accepting a proof request, this one doesn't stop on the first problem
+/// and reports all of them, so the request can be fixed at once.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// proof_request_json: proof request json (see `indy_verifier_verify_proof` for the format).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// validation_errors_json: list of found problems (empty if the proof request is valid):
+///     [{
+///         "referent": Optional<string>, - referent of requested attribute or predicate the problem relates to,
+///                                         absent for the problems of the whole proof request
+///         "code": string, - one of:
+///             "empty_request" - both `requested_attributes` and `requested_predicates` are empty
+///             "duplicate_referent" - referent is used for both requested attribute and requested predicate
+///             "empty_attribute_name" - requested attribute or predicate has no attribute name
+///             "attribute_name_conflict" - requested attribute has both `name` and `names`
+///             "invalid_identifier" - restriction value doesn't match format of schema id, credential definition id,
+///                                    revocation registry id or DID
+///             "qualified_identifier_in_v1" - fully qualified identifier is used in proof request of the first version
+///             "unsupported_operator" - restriction uses an operator that can't be checked by verifier ($gt, $gte, $lt, $lte, $like)
+///             "unsupported_restriction" - restriction uses an unknown key
+///             "invalid_interval" - `from` of non-revocation interval is greater than `to`
+///         "message": string - human readable description of the problem
+///     }]
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_verifier_validate_proof_request(command_handle: CommandHandle,
+                                                   proof_request_json: *const c_char,
+                                                   cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                        validation_errors_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_verifier_validate_proof_request: >>> proof_request_json: {:?}", proof_request_json);
+
+    command_params!(proof_request_json);
+
+    check_useful_json!(proof_request_json, ErrorCode::CommonInvalidParam2, ProofRequest);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_verifier_validate_proof_request: entities >>> proof_request_json: {:?}", proof_request_json);
+
+    let result = CommandExecutor::submit(Command::Anoncreds(AnoncredsCommand::Verifier(VerifierCommand::ValidateProofRequest(
+            proof_request_json,
+            with_timeout(boxed_callback_string!("indy_verifier_validate_proof_request", cb, command_handle))
+        ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_verifier_validate_proof_request: <<< res: {:?}", res);
+
+    res
+}
+
 /// Create revocation state for a credential that corresponds to a particular time.
 ///
 /// Note that revocation delta must cover the whole registry existence time.
@@ -2303,6 +2710,8 @@ pub extern fn indy_create_revocation_state(command_handle: CommandHandle,
     trace!("indy_create_revocation_state: >>> blob_storage_reader_handle: {:?}, rev_reg_def_json: {:?}, rev_reg_delta_json: {:?}, timestamp: {:?}, \
     cred_rev_id: {:?}", blob_storage_reader_handle, rev_reg_def_json, rev_reg_delta_json, timestamp, cred_rev_id);
 
+    command_params!(blob_storage_reader_handle, rev_reg_def_json, rev_reg_delta_json, timestamp, cred_rev_id);
+
     check_useful_validatable_json!(rev_reg_def_json, ErrorCode::CommonInvalidParam3, RevocationRegistryDefinition);
     check_useful_validatable_json!(rev_reg_delta_json, ErrorCode::CommonInvalidParam4, RevocationRegistryDelta);
     check_useful_c_str!(cred_rev_id, ErrorCode::CommonInvalidParam6);
@@ -2311,14 +2720,13 @@ pub extern fn indy_create_revocation_state(command_handle: CommandHandle,
     trace!("indy_create_revocation_state: entities >>> blob_storage_reader_handle: {:?}, rev_reg_def_json: {:?}, rev_reg_delta_json: {:?}, timestamp: {:?}, \
     cred_rev_id: {:?}", blob_storage_reader_handle, rev_reg_def_json, rev_reg_delta_json, timestamp, cred_rev_id);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::CreateRevocationState(
+    let result = CommandExecutor::submit(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::CreateRevocationState(
             blob_storage_reader_handle,
             rev_reg_def_json,
             rev_reg_delta_json,
             timestamp,
             cred_rev_id,
-            boxed_callback_string!("indy_create_revocation_state", cb, command_handle)
+            with_timeout(boxed_callback_string!("indy_create_revocation_state", cb, command_handle))
         ))));
 
     let res = prepare_result!(result);
@@ -2373,6 +2781,8 @@ pub extern fn indy_update_revocation_state(command_handle: CommandHandle,
     trace!("indy_update_revocation_state: >>> blob_storage_reader_handle: {:?}, rev_state_json: {:?}, rev_reg_def_json: {:?}, rev_reg_delta_json: {:?}, \
     timestamp: {:?}, cred_rev_id: {:?}", blob_storage_reader_handle, rev_state_json, rev_reg_def_json, rev_reg_delta_json, timestamp, cred_rev_id);
 
+    command_params!(blob_storage_reader_handle, rev_state_json, rev_reg_def_json, rev_reg_delta_json, timestamp, cred_rev_id);
+
     check_useful_validatable_json!(rev_state_json, ErrorCode::CommonInvalidParam3, RevocationState);
     check_useful_validatable_json!(rev_reg_def_json, ErrorCode::CommonInvalidParam4, RevocationRegistryDefinition);
     check_useful_validatable_json!(rev_reg_delta_json, ErrorCode::CommonInvalidParam5, RevocationRegistryDelta);
@@ -2382,15 +2792,14 @@ pub extern fn indy_update_revocation_state(command_handle: CommandHandle,
     trace!("indy_update_revocation_state: entities >>> blob_storage_reader_handle: {:?}, rev_state_json: {:?}, rev_reg_def_json: {:?}, rev_reg_delta_json: {:?}, \
     timestamp: {:?}, cred_rev_id: {:?}", blob_storage_reader_handle, rev_state_json, rev_reg_def_json, rev_reg_delta_json, timestamp, cred_rev_id);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::UpdateRevocationState(
+    let result = CommandExecutor::submit(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::UpdateRevocationState(
             blob_storage_reader_handle,
             rev_state_json,
             rev_reg_def_json,
             rev_reg_delta_json,
             timestamp,
             cred_rev_id,
-            boxed_callback_string!("indy_update_revocation_state", cb, command_handle)
+            with_timeout(boxed_callback_string!("indy_update_revocation_state", cb, command_handle))
         ))));
 
     let res = prepare_result!(result);
@@ -2400,6 +2809,166 @@ pub extern fn indy_update_revocation_state(command_handle: CommandHandle,
     res
 }
 
+/// Starts background updates of revocation states for the credentials stored in the wallet.
+///
+/// EXPERIMENTAL
+///
+/// Revocation registry definitions and deltas are periodically fetched from the ledger and revocation states
+/// of the credentials are updated, so a proof can be created without a ledger request followed by witness calculation.
+/// The latest states can be got by `indy_get_updated_revocation_states`.
+/// Updates are stopped once the updater is stopped by `indy_stop_revocation_state_updater` or the pool is closed.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context
+/// wallet_handle: wallet handle (created by open_wallet).
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// blob_storage_reader_handle: configuration of blob storage reader handle that will allow to read revocation tails (returned by `indy_open_blob_storage_reader`)
+/// config_json: updater config:
+///     {
+///         "credIds": [<string>, ...], - identifiers of revocable credentials in the wallet
+///         "updateInterval": <int>, - (Optional) interval in seconds between updates (60 - used default)
+///     }
+/// cb: Callback that takes command result as parameter
+///
+/// #Returns
+/// Handle of the updater.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Ledger*
+#[cfg(feature = "ledger")]
+#[no_mangle]
+pub extern fn indy_start_revocation_state_updater(command_handle: CommandHandle,
+                                                  wallet_handle: WalletHandle,
+                                                  pool_handle: PoolHandle,
+                                                  blob_storage_reader_handle: IndyHandle,
+                                                  config_json: *const c_char,
+                                                  cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                       err: ErrorCode,
+                                                                       updater_handle: IndyHandle)>) -> ErrorCode {
+    trace!("indy_start_revocation_state_updater: >>> wallet_handle: {:?}, pool_handle: {:?}, blob_storage_reader_handle: {:?}, config_json: {:?}",
+           wallet_handle, pool_handle, blob_storage_reader_handle, config_json);
+
+    command_params!(wallet_handle, pool_handle, blob_storage_reader_handle, config_json);
+
+    check_useful_validatable_json!(config_json, ErrorCode::CommonInvalidParam5, RevocationStateUpdaterConfig);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_start_revocation_state_updater: entities >>> wallet_handle: {:?}, pool_handle: {:?}, blob_storage_reader_handle: {:?}, config_json: {:?}",
+           wallet_handle, pool_handle, blob_storage_reader_handle, config_json);
+
+    let result = CommandExecutor::submit(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::StartRevocationStateUpdater(
+            wallet_handle,
+            pool_handle,
+            blob_storage_reader_handle,
+            config_json,
+            with_timeout(move |result| {
+                let (err, updater_handle) = prepare_result_1!(result, 0);
+                trace!("indy_start_revocation_state_updater: updater_handle: {:?}", updater_handle);
+                cb(command_handle, err, updater_handle)
+            })
+        ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_start_revocation_state_updater: <<< res: {:?}", res);
+
+    res
+}
+
+/// Stops revocation state updater started by `indy_start_revocation_state_updater`.
+///
+/// EXPERIMENTAL
+///
+/// #Params
+/// command_handle: command handle to map callback to user context
+/// updater_handle: handle of the updater.
+/// cb: Callback that takes command result as parameter
+///
+/// #Returns
+/// Error code
+///
+/// #Errors
+/// Common*
+#[cfg(feature = "ledger")]
+#[no_mangle]
+pub extern fn indy_stop_revocation_state_updater(command_handle: CommandHandle,
+                                                 updater_handle: IndyHandle,
+                                                 cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                      err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_stop_revocation_state_updater: >>> updater_handle: {:?}", updater_handle);
+
+    command_params!(updater_handle);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    let result = CommandExecutor::submit(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::StopRevocationStateUpdater(
+            updater_handle,
+            with_timeout(move |res| {
+                let res = prepare_result!(res);
+                trace!("indy_stop_revocation_state_updater: res: {:?}", res);
+                cb(command_handle, res)
+            })
+        ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_stop_revocation_state_updater: <<< res: {:?}", res);
+
+    res
+}
+
+/// Gets the latest revocation states computed by revocation state updater.
+///
+/// EXPERIMENTAL
+///
+/// Credentials which states aren't computed yet are omitted.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context
+/// updater_handle: handle of the updater (returned by `indy_start_revocation_state_updater`).
+/// cb: Callback that takes command result as parameter
+///
+/// #Returns
+/// rev_states_json: revocation states of credentials:
+///     {
+///         <credential id>: {
+///             "rev_reg_id": string, - revocation registry the credential belongs to
+///             "cred_rev_id": string, - credential revocation id in the registry
+///             "timestamp": integer, - time of the registry state the revocation state corresponds to
+///             "rev_state": <revocation state json> - as returned by `indy_create_revocation_state`
+///         },
+///         ...
+///     }
+///
+/// #Errors
+/// Common*
+#[cfg(feature = "ledger")]
+#[no_mangle]
+pub extern fn indy_get_updated_revocation_states(command_handle: CommandHandle,
+                                                 updater_handle: IndyHandle,
+                                                 cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                      err: ErrorCode,
+                                                                      rev_states_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_get_updated_revocation_states: >>> updater_handle: {:?}", updater_handle);
+
+    command_params!(updater_handle);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    let result = CommandExecutor::submit(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::GetUpdatedRevocationStates(
+            updater_handle,
+            with_timeout(boxed_callback_string!("indy_get_updated_revocation_states", cb, command_handle))
+        ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_get_updated_revocation_states: <<< res: {:?}", res);
+
+    res
+}
+
 
 ///  Generates 80-bit numbers that can be used as a nonce for proof request.
 ///
@@ -2417,12 +2986,13 @@ pub extern fn indy_generate_nonce(command_handle: CommandHandle,
                                       nonce: *const c_char)>) -> ErrorCode {
     trace!("indy_generate_nonce: >>> ");
 
+    command_params!();
+
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam2);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(AnoncredsCommand::Verifier(
+    let result = CommandExecutor::submit(Command::Anoncreds(AnoncredsCommand::Verifier(
             VerifierCommand::GenerateNonce(
-                boxed_callback_string!("indy_generate_nonce", cb, command_handle)
+                with_timeout(boxed_callback_string!("indy_generate_nonce", cb, command_handle))
             ))));
 
     let res = prepare_result!(result);
@@ -2463,15 +3033,16 @@ pub  extern fn indy_to_unqualified(command_handle: CommandHandle,
                                                         res: *const c_char)>) -> ErrorCode {
     trace!("indy_to_unqualified: >>> entity: {:?}", entity);
 
+    command_params!(entity);
+
     check_useful_c_str!(entity, ErrorCode::CommonInvalidParam2);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     trace!("indy_to_unqualified: entities >>> entity: {:?}", entity);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(AnoncredsCommand::ToUnqualified(
+    let result = CommandExecutor::submit(Command::Anoncreds(AnoncredsCommand::ToUnqualified(
             entity,
-            Box::new(move |result| {
+            with_timeout(move |result| {
                 let (err, res) = prepare_result_1!(result, String::new());
                 trace!("indy_to_unqualified: did: {:?}", res);
                 let res = ctypes::string_to_cstring(res);
@@ -2486,3 +3057,83 @@ pub  extern fn indy_to_unqualified(command_handle: CommandHandle,
     res
 }
 
+context_api! {
+    indy_issuer_create_schema_ctx => indy_issuer_create_schema(issuer_did: *const c_char, name: *const c_char, version: *const c_char, attrs: *const c_char)
+        -> cb(schema_id: *const c_char, schema_json: *const c_char), CommonInvalidParam6;
+    indy_issuer_create_and_store_credential_def_ctx => indy_issuer_create_and_store_credential_def(wallet_handle: WalletHandle, issuer_did: *const c_char, schema_json: *const c_char, tag: *const c_char, signature_type: *const c_char, config_json: *const c_char)
+        -> cb(cred_def_id: *const c_char, cred_def_json: *const c_char), CommonInvalidParam8;
+    indy_issuer_rotate_credential_def_start_ctx => indy_issuer_rotate_credential_def_start(wallet_handle: WalletHandle, cred_def_id: *const c_char, config_json: *const c_char)
+        -> cb(cred_def_json: *const c_char), CommonInvalidParam5;
+    indy_issuer_rotate_credential_def_apply_ctx => indy_issuer_rotate_credential_def_apply(wallet_handle: WalletHandle, cred_def_id: *const c_char)
+        -> cb(), CommonInvalidParam4;
+    indy_issuer_create_and_store_revoc_reg_ctx => indy_issuer_create_and_store_revoc_reg(wallet_handle: WalletHandle, issuer_did: *const c_char, revoc_def_type: *const c_char, tag: *const c_char, cred_def_id: *const c_char, config_json: *const c_char, tails_writer_handle: IndyHandle)
+        -> cb(revoc_reg_id: *const c_char, revoc_reg_def_json: *const c_char, revoc_reg_entry_json: *const c_char), CommonInvalidParam9;
+    indy_issuer_create_credential_offer_ctx => indy_issuer_create_credential_offer(wallet_handle: WalletHandle, cred_def_id: *const c_char)
+        -> cb(cred_offer_json: *const c_char), CommonInvalidParam4;
+    indy_issuer_create_credential_ctx => indy_issuer_create_credential(wallet_handle: WalletHandle, cred_offer_json: *const c_char, cred_req_json: *const c_char, cred_values_json: *const c_char, rev_reg_id: *const c_char, blob_storage_reader_handle: IndyHandle)
+        -> cb(cred_json: *const c_char, cred_revoc_id: *const c_char, revoc_reg_delta_json: *const c_char), CommonInvalidParam8;
+    indy_issuer_revoke_credential_ctx => indy_issuer_revoke_credential(wallet_handle: WalletHandle, blob_storage_reader_cfg_handle: IndyHandle, rev_reg_id: *const c_char, cred_revoc_id: *const c_char)
+        -> cb(revoc_reg_delta_json: *const c_char), CommonInvalidParam6;
+    indy_issuer_recover_credential_ctx => indy_issuer_recover_credential(wallet_handle: WalletHandle, blob_storage_reader_cfg_handle: IndyHandle, rev_reg_id: *const c_char, cred_revoc_id: *const c_char)
+        -> cb(revoc_reg_delta_json: *const c_char), CommonInvalidParam6;
+    indy_issuer_merge_revocation_registry_deltas_ctx => indy_issuer_merge_revocation_registry_deltas(rev_reg_delta_json: *const c_char, other_rev_reg_delta_json: *const c_char)
+        -> cb(merged_rev_reg_delta: *const c_char), CommonInvalidParam4;
+    indy_prover_create_master_secret_ctx => indy_prover_create_master_secret(wallet_handle: WalletHandle, master_secret_id: *const c_char)
+        -> cb(out_master_secret_id: *const c_char), CommonInvalidParam4;
+    indy_prover_create_credential_req_ctx => indy_prover_create_credential_req(wallet_handle: WalletHandle, prover_did: *const c_char, cred_offer_json: *const c_char, cred_def_json: *const c_char, master_secret_id: *const c_char)
+        -> cb(cred_req_json: *const c_char, cred_req_metadata_json: *const c_char), CommonInvalidParam7;
+    indy_prover_parse_credential_offer_ctx => indy_prover_parse_credential_offer(cred_offer_json: *const c_char, cred_def_json: *const c_char)
+        -> cb(parsed_offer_json: *const c_char), CommonInvalidParam4;
+    indy_prover_set_credential_attr_tag_policy_ctx => indy_prover_set_credential_attr_tag_policy(wallet_handle: WalletHandle, cred_def_id: *const c_char, tag_attrs_json: *const c_char, retroactive: bool)
+        -> cb(), CommonInvalidParam6;
+    indy_prover_get_credential_attr_tag_policy_ctx => indy_prover_get_credential_attr_tag_policy(wallet_handle: WalletHandle, cred_def_id: *const c_char)
+        -> cb(catpol_json: *const c_char), CommonInvalidParam4;
+    indy_prover_store_credential_ctx => indy_prover_store_credential(wallet_handle: WalletHandle, cred_id: *const c_char, cred_req_metadata_json: *const c_char, cred_json: *const c_char, cred_def_json: *const c_char, rev_reg_def_json: *const c_char)
+        -> cb(out_cred_id: *const c_char), CommonInvalidParam8;
+    indy_prover_store_credential_with_tags_ctx => indy_prover_store_credential_with_tags(wallet_handle: WalletHandle, cred_id: *const c_char, cred_req_metadata_json: *const c_char, cred_json: *const c_char, cred_def_json: *const c_char, rev_reg_def_json: *const c_char, tags_json: *const c_char)
+        -> cb(out_cred_id: *const c_char), CommonInvalidParam9;
+    indy_prover_import_w3c_credential_ctx => indy_prover_import_w3c_credential(wallet_handle: WalletHandle, cred_id: *const c_char, cred_req_metadata_json: *const c_char, w3c_cred_json: *const c_char, cred_def_json: *const c_char, rev_reg_def_json: *const c_char)
+        -> cb(out_cred_id: *const c_char), CommonInvalidParam8;
+    indy_prover_get_credential_ctx => indy_prover_get_credential(wallet_handle: WalletHandle, cred_id: *const c_char)
+        -> cb(credential_json: *const c_char), CommonInvalidParam4;
+    indy_prover_delete_credential_ctx => indy_prover_delete_credential(wallet_handle: WalletHandle, cred_id: *const c_char)
+        -> cb(), CommonInvalidParam4;
+    indy_prover_search_credentials_ctx => indy_prover_search_credentials(wallet_handle: WalletHandle, query_json: *const c_char)
+        -> cb(search_handle: SearchHandle, total_count: usize), CommonInvalidParam4;
+    indy_prover_fetch_credentials_ctx => indy_prover_fetch_credentials(search_handle: SearchHandle, count: usize)
+        -> cb(credentials_json: *const c_char), CommonInvalidParam4;
+    indy_prover_close_credentials_search_ctx => indy_prover_close_credentials_search(search_handle: SearchHandle)
+        -> cb(), CommonInvalidParam3;
+    indy_prover_search_credentials_for_proof_req_ctx => indy_prover_search_credentials_for_proof_req(wallet_handle: WalletHandle, proof_request_json: *const c_char, extra_query_json: *const c_char)
+        -> cb(search_handle: SearchHandle), CommonInvalidParam5;
+    indy_prover_fetch_credentials_for_proof_req_ctx => indy_prover_fetch_credentials_for_proof_req(search_handle: SearchHandle, item_referent: *const c_char, count: usize)
+        -> cb(credentials_json: *const c_char), CommonInvalidParam5;
+    indy_prover_close_credentials_search_for_proof_req_ctx => indy_prover_close_credentials_search_for_proof_req(search_handle: SearchHandle)
+        -> cb(), CommonInvalidParam3;
+    indy_prover_create_proof_ctx => indy_prover_create_proof(wallet_handle: WalletHandle, proof_req_json: *const c_char, requested_credentials_json: *const c_char, master_secret_id: *const c_char, schemas_json: *const c_char, credential_defs_json: *const c_char, rev_states_json: *const c_char)
+        -> cb(proof_json: *const c_char), CommonInvalidParam9;
+    indy_verifier_verify_proof_ctx => indy_verifier_verify_proof(proof_request_json: *const c_char, proof_json: *const c_char, schemas_json: *const c_char, credential_defs_json: *const c_char, rev_reg_defs_json: *const c_char, rev_regs_json: *const c_char)
+        -> cb(valid: bool), CommonInvalidParam8;
+    #[cfg(feature = "ledger")]
+    indy_verifier_verify_proof_with_pools_ctx => indy_verifier_verify_proof_with_pools(pools_json: *const c_char, proof_request_json: *const c_char, proof_json: *const c_char)
+        -> cb(valid: bool), CommonInvalidParam5;
+    indy_verifier_validate_proof_request_ctx => indy_verifier_validate_proof_request(proof_request_json: *const c_char)
+        -> cb(validation_errors_json: *const c_char), CommonInvalidParam3;
+    indy_create_revocation_state_ctx => indy_create_revocation_state(blob_storage_reader_handle: IndyHandle, rev_reg_def_json: *const c_char, rev_reg_delta_json: *const c_char, timestamp: u64, cred_rev_id: *const c_char)
+        -> cb(rev_state_json: *const c_char), CommonInvalidParam7;
+    indy_update_revocation_state_ctx => indy_update_revocation_state(blob_storage_reader_handle: IndyHandle, rev_state_json: *const c_char, rev_reg_def_json: *const c_char, rev_reg_delta_json: *const c_char, timestamp: u64, cred_rev_id: *const c_char)
+        -> cb(updated_rev_state_json: *const c_char), CommonInvalidParam8;
+    #[cfg(feature = "ledger")]
+    indy_start_revocation_state_updater_ctx => indy_start_revocation_state_updater(wallet_handle: WalletHandle, pool_handle: PoolHandle, blob_storage_reader_handle: IndyHandle, config_json: *const c_char)
+        -> cb(updater_handle: IndyHandle), CommonInvalidParam6;
+    #[cfg(feature = "ledger")]
+    indy_stop_revocation_state_updater_ctx => indy_stop_revocation_state_updater(updater_handle: IndyHandle)
+        -> cb(), CommonInvalidParam3;
+    #[cfg(feature = "ledger")]
+    indy_get_updated_revocation_states_ctx => indy_get_updated_revocation_states(updater_handle: IndyHandle)
+        -> cb(rev_states_json: *const c_char), CommonInvalidParam3;
+    indy_generate_nonce_ctx => indy_generate_nonce()
+        -> cb(nonce: *const c_char), CommonInvalidParam2;
+    indy_to_unqualified_ctx => indy_to_unqualified(entity: *const c_char)
+        -> cb(res: *const c_char), CommonInvalidParam3;
+}
@@ -1,12 +1,46 @@
 
 use indy_api_types::{ErrorCode, IndyHandle, CommandHandle};
 use crate::commands::{Command, CommandExecutor};
+use crate::commands::timeout::with_timeout;
 use crate::commands::blob_storage::BlobStorageCommand;
+use crate::services::blob_storage::{PluggedReaderType, PluggedWriterType};
+use indy_api_types::blob_storage::*;
 use indy_api_types::errors::prelude::*;
 use indy_utils::ctypes;
 
 use libc::c_char;
+use std::ptr;
 
+/// Opens reader of tails files.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// type_: type of reader:
+///     "default" - reads tails files from local directory, config: {"base_dir": <str>}
+///     "memory" - reads tails written by "memory" writer of the same process, config: {}
+///     "http" - reads tails files from HTTP(S) server with range requests, available only if libindy is built
+///         with "http_blob_storage" feature, config: {
+///         "base_url": <str>, (optional) tails are requested from <base_url>/<base58 tails hash> if tails location isn't URL
+///         "timeout": <int>, (optional) timeout of connecting and socket reads and writes in seconds, 30 by default
+///         "block_size": <int>, (optional) size of blocks tails are requested and cached in memory by, 1 MiB by default
+///     }
+///     "s3" - reads tails from S3 compatible object storage with ranged requests, available only if libindy is built
+///         with "s3_blob_storage" feature, tails location is ignored, config: {
+///         "endpoint": <str>, URL of object storage, for example "https://s3.us-east-1.amazonaws.com"
+///         "region": <str>, region used for request signing
+///         "bucket": <str>, bucket name
+///         "prefix": <str>, (optional) prefix of object keys, tails are kept in objects named <prefix><base58 tails hash>
+///         "access_key": <str>, (optional) access key, anonymous requests are sent if credentials aren't set
+///         "secret_key": <str>, (optional) secret key
+///         "timeout": <int>, (optional) timeout of connecting and socket reads and writes in seconds, 30 by default
+///         "block_size": <int>, (optional) size of blocks tails are requested and cached in memory by, 1 MiB by default
+///     }
+///     Custom reader types can be registered with indy_register_blob_storage_reader call.
+/// config_json: reader config.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error code
 #[no_mangle]
 pub extern fn indy_open_blob_storage_reader(command_handle: CommandHandle,
                                             type_: *const c_char,
@@ -16,17 +50,18 @@ pub extern fn indy_open_blob_storage_reader(command_handle: CommandHandle,
                                                                  handle: IndyHandle)>) -> ErrorCode {
     trace!("indy_open_blob_storage_reader: >>> type_: {:?}, config_json: {:?}", type_, config_json);
 
+    command_params!(type_, config_json);
+
     check_useful_c_str!(type_, ErrorCode::CommonInvalidParam2);
     check_useful_c_str!(config_json, ErrorCode::CommonInvalidParam3);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     trace!("indy_open_blob_storage_reader: entities >>> type_: {:?}, config_json: {:?}", type_, config_json);
 
-    let result = CommandExecutor::instance()
-        .send(Command::BlobStorage(BlobStorageCommand::OpenReader(
+    let result = CommandExecutor::submit(Command::BlobStorage(BlobStorageCommand::OpenReader(
             type_,
             config_json,
-            Box::new(move |result| {
+            with_timeout(move |result| {
                 let (err, handle) = prepare_result_1!(result, 0);
                 trace!("indy_open_blob_storage_reader: handle: {:?}", handle);
                 cb(command_handle, err, handle)
@@ -40,6 +75,24 @@ pub extern fn indy_open_blob_storage_reader(command_handle: CommandHandle,
     res
 }
 
+/// Opens writer of tails files.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// type_: type of writer:
+///     "default" - writes tails files to local directory, config: {"base_dir": <str>, "uri_pattern": <str>}
+///     "memory" - keeps tails in process memory until the process is finished, intended for tests, config: {}
+///     "s3" - uploads tails to S3 compatible object storage with multipart upload, available only if libindy is built
+///         with "s3_blob_storage" feature, config is the same as for "s3" reader with addition of
+///         "part_size": <int>, (optional) size of uploaded parts in bytes, 5 MiB by default which is minimum allowed by S3
+///         Tails are uploaded to temporary object and copied to object named by tails hash when writing is completed.
+///         URL of the object is returned as tails location.
+///     Custom writer types can be registered with indy_register_blob_storage_writer call.
+/// config_json: writer config.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error code
 #[no_mangle]
 pub extern fn indy_open_blob_storage_writer(command_handle: CommandHandle,
                                             type_: *const c_char,
@@ -49,17 +102,18 @@ pub extern fn indy_open_blob_storage_writer(command_handle: CommandHandle,
                                                                  handle: IndyHandle)>) -> ErrorCode {
     trace!("indy_open_blob_storage_writer: >>> type_: {:?}, config_json: {:?}", type_, config_json);
 
+    command_params!(type_, config_json);
+
     check_useful_c_str!(type_, ErrorCode::CommonInvalidParam2);
     check_useful_c_str!(config_json, ErrorCode::CommonInvalidParam3);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     trace!("indy_open_blob_storage_writer: entities >>> type_: {:?}, config_json: {:?}", type_, config_json);
 
-    let result = CommandExecutor::instance()
-        .send(Command::BlobStorage(BlobStorageCommand::OpenWriter(
+    let result = CommandExecutor::submit(Command::BlobStorage(BlobStorageCommand::OpenWriter(
             type_,
             config_json,
-            Box::new(move |result| {
+            with_timeout(move |result| {
                 let (err, handle) = prepare_result_1!(result, 0);
                 trace!("indy_open_blob_storage_writer: handle: {:?}", handle);
                 cb(command_handle, err, handle)
@@ -72,3 +126,275 @@ pub extern fn indy_open_blob_storage_writer(command_handle: CommandHandle,
 
     res
 }
+
+/// Closes reader of tails files opened with indy_open_blob_storage_reader.
+///
+/// close_reader handler of custom reader type is called if it is registered.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// handle: reader handle returned by indy_open_blob_storage_reader.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error code
+#[no_mangle]
+pub extern fn indy_close_blob_storage_reader(command_handle: CommandHandle,
+                                             handle: IndyHandle,
+                                             cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                  err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_close_blob_storage_reader: >>> handle: {:?}", handle);
+
+    command_params!(handle);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    let result = CommandExecutor::submit(Command::BlobStorage(BlobStorageCommand::CloseReader(
+            handle,
+            with_timeout(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_close_blob_storage_reader: cb command_handle: {:?}, err: {:?}", command_handle, err);
+                cb(command_handle, err)
+            }),
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_close_blob_storage_reader: <<< res: {:?}", res);
+
+    res
+}
+
+/// Closes writer of tails files opened with indy_open_blob_storage_writer.
+///
+/// close_writer handler of custom writer type is called if it is registered.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// handle: writer handle returned by indy_open_blob_storage_writer.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error code
+#[no_mangle]
+pub extern fn indy_close_blob_storage_writer(command_handle: CommandHandle,
+                                             handle: IndyHandle,
+                                             cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                  err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_close_blob_storage_writer: >>> handle: {:?}", handle);
+
+    command_params!(handle);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    let result = CommandExecutor::submit(Command::BlobStorage(BlobStorageCommand::CloseWriter(
+            handle,
+            with_timeout(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_close_blob_storage_writer: cb command_handle: {:?}, err: {:?}", command_handle, err);
+                cb(command_handle, err)
+            }),
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_close_blob_storage_writer: <<< res: {:?}", res);
+
+    res
+}
+
+/// Reads the whole tails file with reader opened by indy_open_blob_storage_reader
+/// to a buffer owned by the application.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// handle: reader handle returned by indy_open_blob_storage_reader.
+/// tails_location: tails location from revocation registry definition.
+/// tails_hash: base58 encoded tails hash from revocation registry definition.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Tails file content. The content is checked against tails hash.
+/// The buffer must be released with `indy_free_buffer`. On error NULL buffer is passed.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_read_tails_to_buffer(command_handle: CommandHandle,
+                                        handle: IndyHandle,
+                                        tails_location: *const c_char,
+                                        tails_hash: *const c_char,
+                                        cb: Option<extern fn(command_handle_: CommandHandle,
+                                                             err: ErrorCode,
+                                                             data: *mut u8,
+                                                             data_len: u32)>) -> ErrorCode {
+    trace!("indy_read_tails_to_buffer: >>> handle: {:?}, tails_location: {:?}, tails_hash: {:?}", handle, tails_location, tails_hash);
+
+    command_params!(handle, tails_location, tails_hash);
+
+    check_useful_c_str_empty_accepted!(tails_location, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(tails_hash, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_read_tails_to_buffer: entities >>> handle: {:?}, tails_location: {:?}, tails_hash: {:?}", handle, tails_location, tails_hash);
+
+    let result = CommandExecutor::submit(Command::BlobStorage(BlobStorageCommand::ReadTails(
+            handle,
+            tails_location,
+            tails_hash,
+            with_timeout(move |result| {
+                let result = result.map(Some);
+                let (err, data) = prepare_result_1!(result, None);
+                trace!("indy_read_tails_to_buffer: cb command_handle: {:?}, err: {:?}", command_handle, err);
+                let (data, data_len) = data.map(ctypes::vec_into_owned_pointer).unwrap_or((ptr::null_mut(), 0));
+                cb(command_handle, err, data, data_len)
+            }),
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_read_tails_to_buffer: <<< res: {:?}", res);
+
+    res
+}
+
+/// Register custom reader of tails files, so tails can be kept in the storage that isn't supported by libindy.
+///
+/// Handlers are called from libindy thread and have to be thread safe.
+/// Config of indy_open_blob_storage_reader is passed to open_reader handler as is.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// type_: Reader type name.
+/// open_reader: Reader open operation handler
+/// open_blob: Reader open blob operation handler
+/// read: Blob read operation handler
+/// close_blob: Blob close operation handler
+/// close_reader: (optional) Reader close operation handler, it is called by indy_close_blob_storage_reader
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error code
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_register_blob_storage_reader(command_handle: CommandHandle,
+                                                type_: *const c_char,
+                                                open_reader: Option<BlobStorageOpenReader>,
+                                                open_blob: Option<BlobStorageOpenBlob>,
+                                                read: Option<BlobStorageRead>,
+                                                close_blob: Option<BlobStorageCloseBlob>,
+                                                close_reader: Option<BlobStorageCloseReader>,
+                                                cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                     err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_register_blob_storage_reader: >>> command_handle: {:?}, type_: {:?}", command_handle, type_);
+
+    command_params!(type_);
+
+    check_useful_c_str!(type_, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(open_reader, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(open_blob, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(read, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(close_blob, ErrorCode::CommonInvalidParam6);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam8);
+
+    trace!("indy_register_blob_storage_reader: entities >>> type_: {:?}", type_);
+
+    let result = CommandExecutor::submit(Command::BlobStorage(BlobStorageCommand::RegisterReader(
+            type_,
+            PluggedReaderType::new(open_reader, open_blob, read, close_blob, close_reader),
+            with_timeout(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_register_blob_storage_reader: cb command_handle: {:?}, err: {:?}", command_handle, err);
+                cb(command_handle, err)
+            }),
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_register_blob_storage_reader: <<< res: {:?}", res);
+
+    res
+}
+
+/// Register custom writer of tails files, so tails can be kept in the storage that isn't supported by libindy.
+///
+/// Handlers are called from libindy thread and have to be thread safe.
+/// Config of indy_open_blob_storage_writer is passed to open_writer handler as is.
+/// Location returned by finalize handler is used as tails location of revocation registry definition.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// type_: Writer type name.
+/// open_writer: Writer open operation handler
+/// create_blob: Writer create blob operation handler
+/// append: Blob append operation handler
+/// finalize: Blob finalize operation handler
+/// close_blob: Blob close operation handler, it is called right after finalize
+/// close_writer: (optional) Writer close operation handler, it is called by indy_close_blob_storage_writer
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error code
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_register_blob_storage_writer(command_handle: CommandHandle,
+                                                type_: *const c_char,
+                                                open_writer: Option<BlobStorageOpenWriter>,
+                                                create_blob: Option<BlobStorageCreateBlob>,
+                                                append: Option<BlobStorageAppend>,
+                                                finalize: Option<BlobStorageFinalize>,
+                                                close_blob: Option<BlobStorageCloseBlob>,
+                                                close_writer: Option<BlobStorageCloseWriter>,
+                                                cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                     err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_register_blob_storage_writer: >>> command_handle: {:?}, type_: {:?}", command_handle, type_);
+
+    command_params!(type_);
+
+    check_useful_c_str!(type_, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(open_writer, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(create_blob, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(append, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(finalize, ErrorCode::CommonInvalidParam6);
+    check_useful_c_callback!(close_blob, ErrorCode::CommonInvalidParam7);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam9);
+
+    trace!("indy_register_blob_storage_writer: entities >>> type_: {:?}", type_);
+
+    let result = CommandExecutor::submit(Command::BlobStorage(BlobStorageCommand::RegisterWriter(
+            type_,
+            PluggedWriterType::new(open_writer, create_blob, append, finalize, close_blob, close_writer),
+            with_timeout(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_register_blob_storage_writer: cb command_handle: {:?}, err: {:?}", command_handle, err);
+                cb(command_handle, err)
+            }),
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_register_blob_storage_writer: <<< res: {:?}", res);
+
+    res
+}
+
+context_api! {
+    indy_open_blob_storage_reader_ctx => indy_open_blob_storage_reader(type_: *const c_char, config_json: *const c_char)
+        -> cb(handle: IndyHandle), CommonInvalidParam4;
+    indy_open_blob_storage_writer_ctx => indy_open_blob_storage_writer(type_: *const c_char, config_json: *const c_char)
+        -> cb(handle: IndyHandle), CommonInvalidParam4;
+    indy_close_blob_storage_reader_ctx => indy_close_blob_storage_reader(handle: IndyHandle)
+        -> cb(), CommonInvalidParam3;
+    indy_close_blob_storage_writer_ctx => indy_close_blob_storage_writer(handle: IndyHandle)
+        -> cb(), CommonInvalidParam3;
+    indy_read_tails_to_buffer_ctx => indy_read_tails_to_buffer(handle: IndyHandle, tails_location: *const c_char, tails_hash: *const c_char)
+        -> cb(data: *mut u8, data_len: u32), CommonInvalidParam5;
+    indy_register_blob_storage_reader_ctx => indy_register_blob_storage_reader(type_: *const c_char, open_reader: Option<BlobStorageOpenReader>, open_blob: Option<BlobStorageOpenBlob>, read: Option<BlobStorageRead>, close_blob: Option<BlobStorageCloseBlob>, close_reader: Option<BlobStorageCloseReader>)
+        -> cb(), CommonInvalidParam8;
+    indy_register_blob_storage_writer_ctx => indy_register_blob_storage_writer(type_: *const c_char, open_writer: Option<BlobStorageOpenWriter>, create_blob: Option<BlobStorageCreateBlob>, append: Option<BlobStorageAppend>, finalize: Option<BlobStorageFinalize>, close_blob: Option<BlobStorageCloseBlob>, close_writer: Option<BlobStorageCloseWriter>)
+        -> cb(), CommonInvalidParam9;
+}
@@ -1,17 +1,21 @@
 use indy_api_types::{ErrorCode, CommandHandle, WalletHandle, PoolHandle};
 use crate::commands::{Command, CommandExecutor};
+use crate::commands::timeout::with_timeout;
 use crate::commands::cache::CacheCommand;
 use indy_api_types::errors::prelude::*;
 use indy_utils::ctypes;
-use crate::domain::cache::{GetCacheOptions, PurgeOptions};
-use crate::domain::anoncreds::schema::SchemaId;
-use crate::domain::anoncreds::credential_definition::CredentialDefinitionId;
+use crate::domain::cache::{GetCacheOptions, PurgeOptions, PrefetchIds, CacheConfig};
+use crate::domain::anoncreds::schema::{Schema, SchemaId};
+use crate::domain::anoncreds::credential_definition::{CredentialDefinition, CredentialDefinitionId};
+use crate::domain::anoncreds::revocation_registry_definition::{RevocationRegistryDefinition, RevocationRegistryId};
 use crate::domain::crypto::did::DidValue;
 use indy_api_types::validation::Validatable;
 use libc::c_char;
 
 
 /// Gets credential definition json data for specified credential definition id.
+/// If credential definition is registered in the local registry of the wallet (see indy_register_local_cred_def), registered data is returned
+/// without any cache or ledger lookup.
 /// If data is present inside of cache, cached data is returned.
 /// Otherwise data is fetched from the ledger and stored inside of cache for future use.
 ///
@@ -41,6 +45,8 @@ pub extern fn indy_get_cred_def(command_handle: CommandHandle,
     trace!("indy_get_cred_def: >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, id: {:?}, options_json: {:?}",
            pool_handle, wallet_handle, submitter_did, id, options_json);
 
+    command_params!(pool_handle, wallet_handle, submitter_did, id, options_json);
+
     check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam4, DidValue);
     check_useful_validatable_string!(id, ErrorCode::CommonInvalidParam5, CredentialDefinitionId);
     check_useful_json!(options_json, ErrorCode::CommonInvalidParam6, GetCacheOptions);
@@ -49,14 +55,13 @@ pub extern fn indy_get_cred_def(command_handle: CommandHandle,
     trace!("indy_get_cred_def: entities >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, id: {:?}, options_json: {:?}",
            pool_handle, wallet_handle, submitter_did, id, options_json);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Cache(CacheCommand::GetCredDef(
+    let result = CommandExecutor::submit(Command::Cache(CacheCommand::GetCredDef(
             pool_handle,
             wallet_handle,
             submitter_did,
             id,
             options_json,
-            boxed_callback_string!("indy_get_cred_def", cb, command_handle)
+            with_timeout(boxed_callback_string!("indy_get_cred_def", cb, command_handle))
         )));
 
     let res = prepare_result!(result);
@@ -67,6 +72,8 @@ pub extern fn indy_get_cred_def(command_handle: CommandHandle,
 }
 
 /// Gets schema json data for specified schema id.
+/// If schema is registered in the local registry of the wallet (see indy_register_local_schema), registered data is returned
+/// without any cache or ledger lookup.
 /// If data is present inside of cache, cached data is returned.
 /// Otherwise data is fetched from the ledger and stored inside of cache for future use.
 ///
@@ -84,6 +91,12 @@ pub extern fn indy_get_cred_def(command_handle: CommandHandle,
 ///    noUpdate: (bool, optional, false by default) Use only cached data, do not try to update.
 ///    noStore: (bool, optional, false by default) Skip storing fresh data if updated,
 ///    minFresh: (int, optional, -1 by default) Return cached data if not older than this many seconds. -1 means do not check age.
+///    ttl: (int, optional, -1 by default) Time to live of stored data in seconds. Expired data is removed on read. -1 means never expire.
+///    maxEntries: (int, optional, unlimited by default) Max count of entries kept in cache. Entries are evicted according to evictionPolicy when fresh data is stored.
+///    evictionPolicy: (string, optional, "lru" by default) Which entries are evicted: "lru" - least recently used, "lfu" - least frequently used.
+///    maxEntrySize: (int, optional, unlimited by default) Max size of data in bytes to store in cache. Bigger data is returned but not stored.
+///    notFoundTtl: (int, optional, 60 by default) Time to live of cached "not found" ledger results in seconds. 0 means do not cache "not found" results.
+///    noNegativeCache: (bool, optional, false by default) Skip cached "not found" ledger results and query the ledger again.
 ///  }
 /// cb: Callback that takes command result as parameter.
 #[no_mangle]
@@ -99,6 +112,8 @@ pub extern fn indy_get_schema(command_handle: CommandHandle,
     trace!("indy_get_schema: >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, id: {:?}, options_json: {:?}",
            pool_handle, wallet_handle, submitter_did, id, options_json);
 
+    command_params!(pool_handle, wallet_handle, submitter_did, id, options_json);
+
     check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam4, DidValue);
     check_useful_validatable_string!(id, ErrorCode::CommonInvalidParam5, SchemaId);
     check_useful_json!(options_json, ErrorCode::CommonInvalidParam6, GetCacheOptions);
@@ -107,14 +122,13 @@ pub extern fn indy_get_schema(command_handle: CommandHandle,
     trace!("indy_get_schema: entities >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, id: {:?}, options_json: {:?}",
            pool_handle, wallet_handle, submitter_did, id, options_json);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Cache(CacheCommand::GetSchema(
+    let result = CommandExecutor::submit(Command::Cache(CacheCommand::GetSchema(
             pool_handle,
             wallet_handle,
             submitter_did,
             id,
             options_json,
-            boxed_callback_string!("indy_get_schema", cb, command_handle)
+            with_timeout(boxed_callback_string!("indy_get_schema", cb, command_handle))
         )));
 
     let res = prepare_result!(result);
@@ -134,6 +148,7 @@ pub extern fn indy_get_schema(command_handle: CommandHandle,
 /// options_json:
 ///  {
 ///    minFresh: (int, optional, -1 by default) Purge cached data if older than this many seconds. -1 means purge all.
+///    poolName: (string, optional) Purge only data fetched from the pool ledger with this name. All pools by default.
 ///  }
 /// cb: Callback that takes command result as parameter.
 #[no_mangle]
@@ -145,17 +160,18 @@ pub extern fn indy_purge_cred_def_cache(command_handle: CommandHandle,
     trace!("indy_purge_cred_def_cache: >>> wallet_handle: {:?}, options_json: {:?}",
            wallet_handle, options_json);
 
+    command_params!(wallet_handle, options_json);
+
     check_useful_json!(options_json, ErrorCode::CommonInvalidParam3, PurgeOptions);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     trace!("indy_purge_cred_def_cache: entities >>> wallet_handle: {:?}, options_json: {:?}",
            wallet_handle, options_json);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Cache(CacheCommand::PurgeCredDefCache(
+    let result = CommandExecutor::submit(Command::Cache(CacheCommand::PurgeCredDefCache(
             wallet_handle,
             options_json,
-            Box::new(move |result| {
+            with_timeout(move |result| {
                 let err = prepare_result!(result);
                 trace!("indy_purge_cred_def_cache:");
                 cb(command_handle, err)
@@ -179,6 +195,7 @@ pub extern fn indy_purge_cred_def_cache(command_handle: CommandHandle,
 /// options_json:
 ///  {
 ///    minFresh: (int, optional, -1 by default) Purge cached data if older than this many seconds. -1 means purge all.
+///    poolName: (string, optional) Purge only data fetched from the pool ledger with this name. All pools by default.
 ///  }
 /// cb: Callback that takes command result as parameter.
 #[no_mangle]
@@ -190,17 +207,18 @@ pub extern fn indy_purge_schema_cache(command_handle: CommandHandle,
     trace!("indy_purge_schema_cache: >>> wallet_handle: {:?}, options_json: {:?}",
            wallet_handle, options_json);
 
+    command_params!(wallet_handle, options_json);
+
     check_useful_json!(options_json, ErrorCode::CommonInvalidParam3, PurgeOptions);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     trace!("indy_purge_schema_cache: entities >>> wallet_handle: {:?}, options_json: {:?}",
            wallet_handle, options_json);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Cache(CacheCommand::PurgeSchemaCache(
+    let result = CommandExecutor::submit(Command::Cache(CacheCommand::PurgeSchemaCache(
             wallet_handle,
             options_json,
-            Box::new(move |result| {
+            with_timeout(move |result| {
                 let err = prepare_result!(result);
                 trace!("indy_purge_schema_cache:");
                 cb(command_handle, err)
@@ -213,3 +231,612 @@ pub extern fn indy_purge_schema_cache(command_handle: CommandHandle,
 
     res
 }
+
+/// Gets revocation registry definition json data for specified revocation registry definition id.
+/// If revocation registry definition is registered in the local registry of the wallet (see indy_register_local_rev_reg_def), registered data is returned
+/// without any cache or ledger lookup.
+/// If data is present inside of cache, cached data is returned.
+/// Otherwise data is fetched from the ledger and stored inside of cache for future use.
+///
+/// EXPERIMENTAL
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// wallet_handle: wallet handle (created by open_wallet).
+/// submitter_did: DID of the submitter stored in secured Wallet.
+/// id: identifier of revocation registry definition.
+/// options_json:
+///  {
+///    noCache: (bool, optional, false by default) Skip usage of cache,
+///    noUpdate: (bool, optional, false by default) Use only cached data, do not try to update.
+///    noStore: (bool, optional, false by default) Skip storing fresh data if updated,
+///    minFresh: (int, optional, -1 by default) Return cached data if not older than this many seconds. -1 means do not check age.
+///    ttl: (int, optional, -1 by default) Time to live of stored data in seconds. Expired data is removed on read. -1 means never expire.
+///    maxEntries: (int, optional, unlimited by default) Max count of entries kept in cache. Entries are evicted according to evictionPolicy when fresh data is stored.
+///    evictionPolicy: (string, optional, "lru" by default) Which entries are evicted: "lru" - least recently used, "lfu" - least frequently used.
+///    maxEntrySize: (int, optional, unlimited by default) Max size of data in bytes to store in cache. Bigger data is returned but not stored.
+///    notFoundTtl: (int, optional, 60 by default) Time to live of cached "not found" ledger results in seconds. 0 means do not cache "not found" results.
+///    noNegativeCache: (bool, optional, false by default) Skip cached "not found" ledger results and query the ledger again.
+///  }
+/// cb: Callback that takes command result as parameter.
+#[no_mangle]
+pub extern fn indy_get_rev_reg_def(command_handle: CommandHandle,
+                                   pool_handle: PoolHandle,
+                                   wallet_handle: WalletHandle,
+                                   submitter_did: *const c_char,
+                                   id: *const c_char,
+                                   options_json: *const c_char,
+                                   cb: Option<extern fn(command_handle_: CommandHandle,
+                                                        err: ErrorCode,
+                                                        rev_reg_def_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_get_rev_reg_def: >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, id: {:?}, options_json: {:?}",
+           pool_handle, wallet_handle, submitter_did, id, options_json);
+
+    command_params!(pool_handle, wallet_handle, submitter_did, id, options_json);
+
+    check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam4, DidValue);
+    check_useful_validatable_string!(id, ErrorCode::CommonInvalidParam5, RevocationRegistryId);
+    check_useful_json!(options_json, ErrorCode::CommonInvalidParam6, GetCacheOptions);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    trace!("indy_get_rev_reg_def: entities >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, id: {:?}, options_json: {:?}",
+           pool_handle, wallet_handle, submitter_did, id, options_json);
+
+    let result = CommandExecutor::submit(Command::Cache(CacheCommand::GetRevocRegDef(
+            pool_handle,
+            wallet_handle,
+            submitter_did,
+            id,
+            options_json,
+            with_timeout(boxed_callback_string!("indy_get_rev_reg_def", cb, command_handle))
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_get_rev_reg_def: <<< res: {:?}", res);
+
+    res
+}
+
+/// Gets revocation registry json data for specified revocation registry definition id valid at specified time.
+/// If cached revocation registry state is known to be actual at requested time, cached data is returned.
+/// Otherwise data is fetched from the ledger and stored inside of cache for future use.
+///
+/// EXPERIMENTAL
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// wallet_handle: wallet handle (created by open_wallet).
+/// submitter_did: DID of the submitter stored in secured Wallet.
+/// rev_reg_def_id: identifier of revocation registry definition.
+/// timestamp: requested time represented as a total number of seconds from Unix Epoch.
+/// options_json:
+///  {
+///    noCache: (bool, optional, false by default) Skip usage of cache,
+///    noUpdate: (bool, optional, false by default) Use only cached data, do not try to update.
+///    noStore: (bool, optional, false by default) Skip storing fresh data if updated,
+///    minFresh: (int, optional, -1 by default) Return cached data if not older than this many seconds. -1 means do not check age.
+///    ttl: (int, optional, -1 by default) Time to live of stored data in seconds. Expired data is removed on read. -1 means never expire.
+///    maxEntries: (int, optional, unlimited by default) Max count of entries kept in cache. Entries are evicted according to evictionPolicy when fresh data is stored.
+///    evictionPolicy: (string, optional, "lru" by default) Which entries are evicted: "lru" - least recently used, "lfu" - least frequently used.
+///    maxEntrySize: (int, optional, unlimited by default) Max size of data in bytes to store in cache. Bigger data is returned but not stored.
+///  }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Revocation Registry Definition Id, Revocation Registry json and Timestamp (the same as indy_parse_get_revoc_reg_response).
+#[no_mangle]
+pub extern fn indy_get_rev_reg(command_handle: CommandHandle,
+                               pool_handle: PoolHandle,
+                               wallet_handle: WalletHandle,
+                               submitter_did: *const c_char,
+                               rev_reg_def_id: *const c_char,
+                               timestamp: i64,
+                               options_json: *const c_char,
+                               cb: Option<extern fn(command_handle_: CommandHandle,
+                                                    err: ErrorCode,
+                                                    rev_reg_def_id: *const c_char,
+                                                    rev_reg_json: *const c_char,
+                                                    timestamp: u64)>) -> ErrorCode {
+    trace!("indy_get_rev_reg: >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, rev_reg_def_id: {:?}, timestamp: {:?}, options_json: {:?}",
+           pool_handle, wallet_handle, submitter_did, rev_reg_def_id, timestamp, options_json);
+
+    command_params!(pool_handle, wallet_handle, submitter_did, rev_reg_def_id, timestamp, options_json);
+
+    check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam4, DidValue);
+    check_useful_validatable_string!(rev_reg_def_id, ErrorCode::CommonInvalidParam5, RevocationRegistryId);
+    check_useful_json!(options_json, ErrorCode::CommonInvalidParam7, GetCacheOptions);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam8);
+
+    trace!("indy_get_rev_reg: entities >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, rev_reg_def_id: {:?}, timestamp: {:?}, options_json: {:?}",
+           pool_handle, wallet_handle, submitter_did, rev_reg_def_id, timestamp, options_json);
+
+    let result = CommandExecutor::submit(Command::Cache(CacheCommand::GetRevocReg(
+            pool_handle,
+            wallet_handle,
+            submitter_did,
+            rev_reg_def_id,
+            timestamp,
+            options_json,
+            with_timeout(move |result| {
+                let (err, rev_reg_def_id, rev_reg_json, timestamp) = prepare_result_3!(result, String::new(), String::new(), 0);
+                trace!("indy_get_rev_reg: rev_reg_def_id: {:?}, rev_reg_json: {:?}, timestamp: {:?}",
+                       rev_reg_def_id, rev_reg_json, timestamp);
+
+                let rev_reg_def_id = ctypes::string_to_cstring(rev_reg_def_id);
+                let rev_reg_json = ctypes::string_to_cstring(rev_reg_json);
+                cb(command_handle, err, rev_reg_def_id.as_ptr(), rev_reg_json.as_ptr(), timestamp)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_get_rev_reg: <<< res: {:?}", res);
+
+    res
+}
+
+/// Gets revocation registry delta json data for specified revocation registry definition id and time interval.
+/// If cached delta covers requested interval, cached data is returned.
+/// If cached delta covers only the beginning of requested interval, only the missing part is fetched
+/// from the ledger and merged with cached delta.
+/// Otherwise data is fetched from the ledger and stored inside of cache for future use.
+///
+/// EXPERIMENTAL
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// wallet_handle: wallet handle (created by open_wallet).
+/// submitter_did: DID of the submitter stored in secured Wallet.
+/// rev_reg_def_id: identifier of revocation registry definition.
+/// from: requested time represented as a total number of seconds from Unix Epoch (-1 means the whole state till to).
+/// to: requested time represented as a total number of seconds from Unix Epoch.
+/// options_json:
+///  {
+///    noCache: (bool, optional, false by default) Skip usage of cache,
+///    noUpdate: (bool, optional, false by default) Use only cached data, do not try to update.
+///    noStore: (bool, optional, false by default) Skip storing fresh data if updated,
+///    minFresh: (int, optional, -1 by default) Return cached data if not older than this many seconds. -1 means do not check age.
+///    ttl: (int, optional, -1 by default) Time to live of stored data in seconds. Expired data is removed on read. -1 means never expire.
+///    maxEntries: (int, optional, unlimited by default) Max count of entries kept in cache. Entries are evicted according to evictionPolicy when fresh data is stored.
+///    evictionPolicy: (string, optional, "lru" by default) Which entries are evicted: "lru" - least recently used, "lfu" - least frequently used.
+///    maxEntrySize: (int, optional, unlimited by default) Max size of data in bytes to store in cache. Bigger data is returned but not stored.
+///  }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Revocation Registry Definition Id, Revocation Registry Delta json and Timestamp (the same as indy_parse_get_revoc_reg_delta_response).
+#[no_mangle]
+pub extern fn indy_get_rev_reg_delta(command_handle: CommandHandle,
+                                     pool_handle: PoolHandle,
+                                     wallet_handle: WalletHandle,
+                                     submitter_did: *const c_char,
+                                     rev_reg_def_id: *const c_char,
+                                     from: i64,
+                                     to: i64,
+                                     options_json: *const c_char,
+                                     cb: Option<extern fn(command_handle_: CommandHandle,
+                                                          err: ErrorCode,
+                                                          rev_reg_def_id: *const c_char,
+                                                          rev_reg_delta_json: *const c_char,
+                                                          timestamp: u64)>) -> ErrorCode {
+    trace!("indy_get_rev_reg_delta: >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, rev_reg_def_id: {:?}, from: {:?}, to: {:?}, options_json: {:?}",
+           pool_handle, wallet_handle, submitter_did, rev_reg_def_id, from, to, options_json);
+
+    command_params!(pool_handle, wallet_handle, submitter_did, rev_reg_def_id, from, to, options_json);
+
+    check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam4, DidValue);
+    check_useful_validatable_string!(rev_reg_def_id, ErrorCode::CommonInvalidParam5, RevocationRegistryId);
+    check_useful_json!(options_json, ErrorCode::CommonInvalidParam8, GetCacheOptions);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam9);
+
+    let from = if from != -1 { Some(from) } else { None };
+
+    trace!("indy_get_rev_reg_delta: entities >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, rev_reg_def_id: {:?}, from: {:?}, to: {:?}, options_json: {:?}",
+           pool_handle, wallet_handle, submitter_did, rev_reg_def_id, from, to, options_json);
+
+    let result = CommandExecutor::submit(Command::Cache(CacheCommand::GetRevocRegDelta(
+            pool_handle,
+            wallet_handle,
+            submitter_did,
+            rev_reg_def_id,
+            from,
+            to,
+            options_json,
+            with_timeout(move |result| {
+                let (err, rev_reg_def_id, rev_reg_delta_json, timestamp) = prepare_result_3!(result, String::new(), String::new(), 0);
+                trace!("indy_get_rev_reg_delta: rev_reg_def_id: {:?}, rev_reg_delta_json: {:?}, timestamp: {:?}",
+                       rev_reg_def_id, rev_reg_delta_json, timestamp);
+
+                let rev_reg_def_id = ctypes::string_to_cstring(rev_reg_def_id);
+                let rev_reg_delta_json = ctypes::string_to_cstring(rev_reg_delta_json);
+                cb(command_handle, err, rev_reg_def_id.as_ptr(), rev_reg_delta_json.as_ptr(), timestamp)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_get_rev_reg_delta: <<< res: {:?}", res);
+
+    res
+}
+
+/// Purge revocation registry definition, revocation registry and revocation registry delta caches.
+///
+/// EXPERIMENTAL
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// options_json:
+///  {
+///    maxAge: (int, optional, -1 by default) Purge cached data if older than this many seconds. -1 means purge all.
+///    poolName: (string, optional) Purge only data fetched from the pool ledger with this name. All pools by default.
+///  }
+/// cb: Callback that takes command result as parameter.
+#[no_mangle]
+pub extern fn indy_purge_rev_reg_cache(command_handle: CommandHandle,
+                                       wallet_handle: WalletHandle,
+                                       options_json: *const c_char,
+                                       cb: Option<extern fn(command_handle_: CommandHandle,
+                                                            err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_purge_rev_reg_cache: >>> wallet_handle: {:?}, options_json: {:?}",
+           wallet_handle, options_json);
+
+    command_params!(wallet_handle, options_json);
+
+    check_useful_json!(options_json, ErrorCode::CommonInvalidParam3, PurgeOptions);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_purge_rev_reg_cache: entities >>> wallet_handle: {:?}, options_json: {:?}",
+           wallet_handle, options_json);
+
+    let result = CommandExecutor::submit(Command::Cache(CacheCommand::PurgeRevocRegCache(
+            wallet_handle,
+            options_json,
+            with_timeout(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_purge_rev_reg_cache:");
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_purge_rev_reg_cache: <<< res: {:?}", res);
+
+    res
+}
+
+/// Fetches schemas, credential definitions and revocation registry definitions from the ledger
+/// and stores them inside of cache for future use.
+/// Ledger requests for all objects are sent concurrently; objects already present inside of cache are skipped.
+///
+/// EXPERIMENTAL
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// wallet_handle: wallet handle (created by open_wallet).
+/// ids_json:
+///  {
+///    schemas: (array of strings, optional) identifiers of schemas,
+///    credDefs: (array of strings, optional) identifiers of credential definitions,
+///    revRegDefs: (array of strings, optional) identifiers of revocation registry definitions,
+///  }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// failed_json: map of identifiers which could not be fetched to error messages
+///  {
+///    "<id>": string - error message,
+///  }
+#[no_mangle]
+pub extern fn indy_prefetch_ledger_cache(command_handle: CommandHandle,
+                                         pool_handle: PoolHandle,
+                                         wallet_handle: WalletHandle,
+                                         ids_json: *const c_char,
+                                         cb: Option<extern fn(command_handle_: CommandHandle,
+                                                              err: ErrorCode,
+                                                              failed_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_prefetch_ledger_cache: >>> pool_handle: {:?}, wallet_handle: {:?}, ids_json: {:?}",
+           pool_handle, wallet_handle, ids_json);
+
+    command_params!(pool_handle, wallet_handle, ids_json);
+
+    check_useful_validatable_json!(ids_json, ErrorCode::CommonInvalidParam4, PrefetchIds);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_prefetch_ledger_cache: entities >>> pool_handle: {:?}, wallet_handle: {:?}, ids_json: {:?}",
+           pool_handle, wallet_handle, ids_json);
+
+    let result = CommandExecutor::submit(Command::Cache(CacheCommand::PrefetchLedgerCache(
+            pool_handle,
+            wallet_handle,
+            ids_json,
+            with_timeout(boxed_callback_string!("indy_prefetch_ledger_cache", cb, command_handle))
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_prefetch_ledger_cache: <<< res: {:?}", res);
+
+    res
+}
+
+/// Gets statistics of ledger cache stored inside of the wallet per object kind.
+/// Counters are collected since library load and are shared among all wallets.
+///
+/// EXPERIMENTAL
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// stats_json:
+/// {
+///   "schemas"|"credDefs"|"revRegDefs"|"revRegs"|"revRegDeltas": {
+///       hits: int - count of lookups served from cache,
+///       misses: int - count of lookups which required the ledger (or failed with noUpdate),
+///       evictions: int - count of entries evicted because of maxEntries,
+///       oversized: int - count of ledger results not stored because of maxEntrySize,
+///       entries: int - count of cached entries,
+///       size: int - total size of cached data in bytes,
+///       oldest: (int, optional) time of storing of the oldest entry in seconds since Unix Epoch,
+///       newest: (int, optional) time of storing of the newest entry in seconds since Unix Epoch,
+///   },
+/// }
+#[no_mangle]
+pub extern fn indy_get_cache_stats(command_handle: CommandHandle,
+                                   wallet_handle: WalletHandle,
+                                   cb: Option<extern fn(command_handle_: CommandHandle,
+                                                        err: ErrorCode,
+                                                        stats_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_get_cache_stats: >>> wallet_handle: {:?}", wallet_handle);
+
+    command_params!(wallet_handle);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_get_cache_stats: entities >>> wallet_handle: {:?}", wallet_handle);
+
+    let result = CommandExecutor::submit(Command::Cache(CacheCommand::GetCacheStats(
+            wallet_handle,
+            with_timeout(boxed_callback_string!("indy_get_cache_stats", cb, command_handle))
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_get_cache_stats: <<< res: {:?}", res);
+
+    res
+}
+
+/// Sets defaults of ledger cache options for all calls with the wallet.
+/// Options passed to a call take precedence over the config.
+/// Config is kept in memory until the wallet is reopened or config is set again.
+///
+/// EXPERIMENTAL
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// config_json:
+///  {
+///    include: (array of strings, optional, all by default) Object kinds to cache: "schemas", "credDefs", "revRegDefs", "revRegs", "revRegDeltas".
+///             Other kinds are always fetched from the ledger and not stored.
+///    ttl: (int, optional) Default ttl option.
+///    maxEntries: (int, optional) Default maxEntries option.
+///    evictionPolicy: (string, optional) Default evictionPolicy option: "lru" or "lfu".
+///    maxEntrySize: (int, optional) Default maxEntrySize option.
+///    negativeCache: (bool, optional, true by default) false disables storing and usage of cached "not found" ledger results.
+///    notFoundTtl: (int, optional) Default notFoundTtl option.
+///  }
+/// cb: Callback that takes command result as parameter.
+#[no_mangle]
+pub extern fn indy_set_cache_config(command_handle: CommandHandle,
+                                    wallet_handle: WalletHandle,
+                                    config_json: *const c_char,
+                                    cb: Option<extern fn(command_handle_: CommandHandle,
+                                                         err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_set_cache_config: >>> wallet_handle: {:?}, config_json: {:?}",
+           wallet_handle, config_json);
+
+    command_params!(wallet_handle, config_json);
+
+    check_useful_json!(config_json, ErrorCode::CommonInvalidParam3, CacheConfig);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_set_cache_config: entities >>> wallet_handle: {:?}, config_json: {:?}",
+           wallet_handle, config_json);
+
+    let result = CommandExecutor::submit(Command::Cache(CacheCommand::SetCacheConfig(
+            wallet_handle,
+            config_json,
+            with_timeout(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_set_cache_config:");
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_set_cache_config: <<< res: {:?}", res);
+
+    res
+}
+
+/// Registers schema in the local registry of the wallet.
+/// Registered schema is trusted and returned by indy_get_schema instead of the ledger one,
+/// so anoncreds can be used on private networks without any ledger writes.
+/// Registering schema with the same id again replaces the registered one.
+///
+/// EXPERIMENTAL
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// schema_json: schema json, as returned by indy_issuer_create_schema.
+/// cb: Callback that takes command result as parameter.
+#[no_mangle]
+pub extern fn indy_register_local_schema(command_handle: CommandHandle,
+                                         wallet_handle: WalletHandle,
+                                         schema_json: *const c_char,
+                                         cb: Option<extern fn(command_handle_: CommandHandle,
+                                                              err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_register_local_schema: >>> wallet_handle: {:?}, schema_json: {:?}",
+           wallet_handle, schema_json);
+
+    command_params!(wallet_handle, schema_json);
+
+    check_useful_validatable_json!(schema_json, ErrorCode::CommonInvalidParam3, Schema);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_register_local_schema: entities >>> wallet_handle: {:?}, schema_json: {:?}",
+           wallet_handle, schema_json);
+
+    let result = CommandExecutor::submit(Command::Cache(CacheCommand::RegisterLocalSchema(
+            wallet_handle,
+            schema_json,
+            with_timeout(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_register_local_schema:");
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_register_local_schema: <<< res: {:?}", res);
+
+    res
+}
+
+/// Registers credential definition in the local registry of the wallet.
+/// Registered credential definition is trusted and returned by indy_get_cred_def instead of the ledger one,
+/// so anoncreds can be used on private networks without any ledger writes.
+/// Registering credential definition with the same id again replaces the registered one.
+///
+/// EXPERIMENTAL
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// cred_def_json: credential definition json, as returned by indy_issuer_create_and_store_credential_def.
+/// cb: Callback that takes command result as parameter.
+#[no_mangle]
+pub extern fn indy_register_local_cred_def(command_handle: CommandHandle,
+                                           wallet_handle: WalletHandle,
+                                           cred_def_json: *const c_char,
+                                           cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_register_local_cred_def: >>> wallet_handle: {:?}, cred_def_json: {:?}",
+           wallet_handle, cred_def_json);
+
+    command_params!(wallet_handle, cred_def_json);
+
+    check_useful_validatable_json!(cred_def_json, ErrorCode::CommonInvalidParam3, CredentialDefinition);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_register_local_cred_def: entities >>> wallet_handle: {:?}, cred_def_json: {:?}",
+           wallet_handle, cred_def_json);
+
+    let result = CommandExecutor::submit(Command::Cache(CacheCommand::RegisterLocalCredDef(
+            wallet_handle,
+            cred_def_json,
+            with_timeout(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_register_local_cred_def:");
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_register_local_cred_def: <<< res: {:?}", res);
+
+    res
+}
+
+/// Registers revocation registry definition in the local registry of the wallet.
+/// Registered revocation registry definition is trusted and returned by indy_get_revoc_reg_def instead of the ledger one,
+/// so anoncreds can be used on private networks without any ledger writes.
+/// Registering revocation registry definition with the same id again replaces the registered one.
+///
+/// EXPERIMENTAL
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// revoc_reg_def_json: revocation registry definition json, as returned by indy_issuer_create_and_store_revoc_reg.
+/// cb: Callback that takes command result as parameter.
+#[no_mangle]
+pub extern fn indy_register_local_rev_reg_def(command_handle: CommandHandle,
+                                              wallet_handle: WalletHandle,
+                                              revoc_reg_def_json: *const c_char,
+                                              cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                   err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_register_local_rev_reg_def: >>> wallet_handle: {:?}, revoc_reg_def_json: {:?}",
+           wallet_handle, revoc_reg_def_json);
+
+    command_params!(wallet_handle, revoc_reg_def_json);
+
+    check_useful_validatable_json!(revoc_reg_def_json, ErrorCode::CommonInvalidParam3, RevocationRegistryDefinition);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_register_local_rev_reg_def: entities >>> wallet_handle: {:?}, revoc_reg_def_json: {:?}",
+           wallet_handle, revoc_reg_def_json);
+
+    let result = CommandExecutor::submit(Command::Cache(CacheCommand::RegisterLocalRevocRegDef(
+            wallet_handle,
+            revoc_reg_def_json,
+            with_timeout(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_register_local_rev_reg_def:");
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_register_local_rev_reg_def: <<< res: {:?}", res);
+
+    res
+}
+
+context_api! {
+    indy_get_cred_def_ctx => indy_get_cred_def(pool_handle: PoolHandle, wallet_handle: WalletHandle, submitter_did: *const c_char, id: *const c_char, options_json: *const c_char)
+        -> cb(cred_def_json: *const c_char), CommonInvalidParam7;
+    indy_get_schema_ctx => indy_get_schema(pool_handle: PoolHandle, wallet_handle: WalletHandle, submitter_did: *const c_char, id: *const c_char, options_json: *const c_char)
+        -> cb(schema_json: *const c_char), CommonInvalidParam7;
+    indy_purge_cred_def_cache_ctx => indy_purge_cred_def_cache(wallet_handle: WalletHandle, options_json: *const c_char)
+        -> cb(), CommonInvalidParam4;
+    indy_purge_schema_cache_ctx => indy_purge_schema_cache(wallet_handle: WalletHandle, options_json: *const c_char)
+        -> cb(), CommonInvalidParam4;
+    indy_get_rev_reg_def_ctx => indy_get_rev_reg_def(pool_handle: PoolHandle, wallet_handle: WalletHandle, submitter_did: *const c_char, id: *const c_char, options_json: *const c_char)
+        -> cb(rev_reg_def_json: *const c_char), CommonInvalidParam7;
+    indy_get_rev_reg_ctx => indy_get_rev_reg(pool_handle: PoolHandle, wallet_handle: WalletHandle, submitter_did: *const c_char, rev_reg_def_id: *const c_char, timestamp: i64, options_json: *const c_char)
+        -> cb(rev_reg_def_id: *const c_char, rev_reg_json: *const c_char, timestamp: u64), CommonInvalidParam8;
+    indy_get_rev_reg_delta_ctx => indy_get_rev_reg_delta(pool_handle: PoolHandle, wallet_handle: WalletHandle, submitter_did: *const c_char, rev_reg_def_id: *const c_char, from: i64, to: i64, options_json: *const c_char)
+        -> cb(rev_reg_def_id: *const c_char, rev_reg_delta_json: *const c_char, timestamp: u64), CommonInvalidParam9;
+    indy_purge_rev_reg_cache_ctx => indy_purge_rev_reg_cache(wallet_handle: WalletHandle, options_json: *const c_char)
+        -> cb(), CommonInvalidParam4;
+    indy_prefetch_ledger_cache_ctx => indy_prefetch_ledger_cache(pool_handle: PoolHandle, wallet_handle: WalletHandle, ids_json: *const c_char)
+        -> cb(failed_json: *const c_char), CommonInvalidParam5;
+    indy_get_cache_stats_ctx => indy_get_cache_stats(wallet_handle: WalletHandle)
+        -> cb(stats_json: *const c_char), CommonInvalidParam3;
+    indy_set_cache_config_ctx => indy_set_cache_config(wallet_handle: WalletHandle, config_json: *const c_char)
+        -> cb(), CommonInvalidParam4;
+    indy_register_local_schema_ctx => indy_register_local_schema(wallet_handle: WalletHandle, schema_json: *const c_char)
+        -> cb(), CommonInvalidParam4;
+    indy_register_local_cred_def_ctx => indy_register_local_cred_def(wallet_handle: WalletHandle, cred_def_json: *const c_char)
+        -> cb(), CommonInvalidParam4;
+    indy_register_local_rev_reg_def_ctx => indy_register_local_rev_reg_def(wallet_handle: WalletHandle, revoc_reg_def_json: *const c_char)
+        -> cb(), CommonInvalidParam4;
+}
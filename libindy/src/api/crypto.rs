@@ -1,6 +1,7 @@
 
 use indy_api_types::{ErrorCode, CommandHandle, WalletHandle};
 use crate::commands::{Command, CommandExecutor};
+use crate::commands::timeout::with_timeout;
 use crate::commands::crypto::CryptoCommand;
 use crate::domain::crypto::pack::JWE;
 use crate::domain::crypto::key::KeyInfo;
@@ -9,6 +10,7 @@ use indy_utils::ctypes;
 
 use serde_json;
 use libc::c_char;
+use std::ptr;
 
 
 /// Creates keys pair and stores in the wallet.
@@ -44,16 +46,17 @@ pub extern fn indy_create_key(command_handle: CommandHandle,
                                                    verkey: *const c_char)>) -> ErrorCode {
     trace!("indy_create_key: >>> wallet_handle: {:?}, key_json: {:?}", wallet_handle, key_json);
 
+    command_params!(wallet_handle, key_json);
+
     check_useful_json!(key_json, ErrorCode::CommonInvalidParam3, KeyInfo);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     trace!("indy_create_key: entities >>> wallet_handle: {:?}, key_json: {:?}", wallet_handle, secret!(&key_json));
 
-    let result = CommandExecutor::instance()
-        .send(Command::Crypto(CryptoCommand::CreateKey(
+    let result = CommandExecutor::submit(Command::Crypto(CryptoCommand::CreateKey(
             wallet_handle,
             key_json,
-            boxed_callback_string!("indy_create_key", cb, command_handle)
+            with_timeout(boxed_callback_string!("indy_create_key", cb, command_handle))
         )));
 
     let res = prepare_result!(result);
@@ -91,18 +94,19 @@ pub  extern fn indy_set_key_metadata(command_handle: CommandHandle,
                                                           err: ErrorCode)>) -> ErrorCode {
     trace!("indy_set_key_metadata: >>> wallet_handle: {:?}, verkey: {:?}, metadata: {:?}", wallet_handle, verkey, metadata);
 
+    command_params!(wallet_handle, verkey, metadata);
+
     check_useful_c_str!(verkey, ErrorCode::CommonInvalidParam3);
     check_useful_c_str_empty_accepted!(metadata, ErrorCode::CommonInvalidParam4);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
 
     trace!("indy_set_key_metadata: entities >>> wallet_handle: {:?}, verkey: {:?}, metadata: {:?}", wallet_handle, verkey, metadata);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Crypto(CryptoCommand::SetKeyMetadata(
+    let result = CommandExecutor::submit(Command::Crypto(CryptoCommand::SetKeyMetadata(
             wallet_handle,
             verkey,
             metadata,
-            Box::new(move |result| {
+            with_timeout(move |result| {
                 let err = prepare_result!(result);
                 trace!("indy_set_key_metadata: ");
                 cb(command_handle, err)
@@ -144,16 +148,17 @@ pub  extern fn indy_get_key_metadata(command_handle: CommandHandle,
                                                           metadata: *const c_char)>) -> ErrorCode {
     trace!("indy_get_key_metadata: >>> wallet_handle: {:?}, verkey: {:?}", wallet_handle, verkey);
 
+    command_params!(wallet_handle, verkey);
+
     check_useful_c_str!(verkey, ErrorCode::CommonInvalidParam3);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     trace!("indy_get_key_metadata: entities >>> wallet_handle: {:?}, verkey: {:?}", wallet_handle, verkey);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Crypto(CryptoCommand::GetKeyMetadata(
+    let result = CommandExecutor::submit(Command::Crypto(CryptoCommand::GetKeyMetadata(
             wallet_handle,
             verkey,
-            boxed_callback_string!("indy_get_key_metadata", cb, command_handle)
+            with_timeout(boxed_callback_string!("indy_get_key_metadata", cb, command_handle))
         )));
 
     let res = prepare_result!(result);
@@ -196,6 +201,8 @@ pub  extern fn indy_crypto_sign(command_handle: CommandHandle,
     trace!("indy_crypto_sign: >>> wallet_handle: {:?}, signer_vk: {:?}, message_raw: {:?}, message_len: {:?}",
            wallet_handle, signer_vk, message_raw, message_len);
 
+    command_params!(wallet_handle, signer_vk, (message_raw, message_len));
+
     check_useful_c_str!(signer_vk, ErrorCode::CommonInvalidParam3);
     check_useful_c_byte_array!(message_raw, message_len, ErrorCode::CommonInvalidParam4, ErrorCode::CommonInvalidParam5);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
@@ -203,12 +210,11 @@ pub  extern fn indy_crypto_sign(command_handle: CommandHandle,
     trace!("indy_crypto_sign: entities >>> wallet_handle: {:?}, signer_vk: {:?}, message_raw: {:?}, message_len: {:?}",
            wallet_handle, signer_vk, message_raw, message_len);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Crypto(CryptoCommand::CryptoSign(
+    let result = CommandExecutor::submit(Command::Crypto(CryptoCommand::CryptoSign(
             wallet_handle,
             signer_vk,
             message_raw,
-            Box::new(move |result| {
+            with_timeout(move |result| {
                 let (err, signature) = prepare_result_1!(result, Vec::new());
                 trace!("indy_crypto_sign: signature: {:?}", signature);
                 let (signature_raw, signature_len) = ctypes::vec_to_pointer(&signature);
@@ -258,6 +264,8 @@ pub  extern fn indy_crypto_verify(command_handle: CommandHandle,
     trace!("indy_crypto_verify: >>> signer_vk: {:?}, message_raw: {:?}, message_len: {:?}, signature_raw: {:?}, signature_len: {:?}",
            signer_vk, message_raw, message_len, signature_raw, signature_len);
 
+    command_params!(signer_vk, (message_raw, message_len), (signature_raw, signature_len));
+
     check_useful_c_str!(signer_vk, ErrorCode::CommonInvalidParam2);
     check_useful_c_byte_array!(message_raw, message_len, ErrorCode::CommonInvalidParam3, ErrorCode::CommonInvalidParam4);
     check_useful_c_byte_array!(signature_raw, signature_len, ErrorCode::CommonInvalidParam5, ErrorCode::CommonInvalidParam6);
@@ -266,12 +274,11 @@ pub  extern fn indy_crypto_verify(command_handle: CommandHandle,
     trace!("indy_crypto_verify: entities >>> signer_vk: {:?}, message_raw: {:?}, message_len: {:?}, signature_raw: {:?}, signature_len: {:?}",
            signer_vk, message_raw, message_len, signature_raw, signature_len);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Crypto(CryptoCommand::CryptoVerify(
+    let result = CommandExecutor::submit(Command::Crypto(CryptoCommand::CryptoVerify(
             signer_vk,
             message_raw,
             signature_raw,
-            Box::new(move |result| {
+            with_timeout(move |result| {
                 let (err, valid) = prepare_result_1!(result, false);
                 trace!("indy_crypto_verify: valid: {:?}", valid);
                 cb(command_handle, err, valid)
@@ -328,6 +335,8 @@ pub  extern fn indy_crypto_auth_crypt(command_handle: CommandHandle,
     trace!("indy_crypto_auth_crypt: >>> wallet_handle: {:?}, sender_vk: {:?}, recipient_vk: {:?}, msg_data: {:?}, msg_len: {:?}",
            wallet_handle, sender_vk, recipient_vk, msg_data, msg_len);
 
+    command_params!(wallet_handle, sender_vk, recipient_vk, (msg_data, msg_len));
+
     check_useful_c_str!(sender_vk, ErrorCode::CommonInvalidParam3);
     check_useful_c_str!(recipient_vk, ErrorCode::CommonInvalidParam4);
     check_useful_c_byte_array!(msg_data, msg_len, ErrorCode::CommonInvalidParam5, ErrorCode::CommonInvalidParam6);
@@ -336,13 +345,12 @@ pub  extern fn indy_crypto_auth_crypt(command_handle: CommandHandle,
     trace!("indy_crypto_auth_crypt: entities >>> wallet_handle: {:?}, sender_vk: {:?}, recipient_vk: {:?}, msg_data: {:?}, msg_len: {:?}",
            wallet_handle, sender_vk, recipient_vk, msg_data, msg_len);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Crypto(CryptoCommand::AuthenticatedEncrypt(
+    let result = CommandExecutor::submit(Command::Crypto(CryptoCommand::AuthenticatedEncrypt(
             wallet_handle,
             sender_vk,
             recipient_vk,
             msg_data,
-            Box::new(move |result| {
+            with_timeout(move |result| {
                 let (err, encrypted_msg) = prepare_result_1!(result, Vec::new());
                 trace!("indy_crypto_auth_crypt: encrypted_msg: {:?}", encrypted_msg);
                 let (encrypted_msg_raw, encrypted_msg_len) = ctypes::vec_to_pointer(&encrypted_msg);
@@ -398,6 +406,8 @@ pub  extern fn indy_crypto_auth_decrypt(command_handle: CommandHandle,
     trace!("indy_crypto_auth_decrypt: >>> wallet_handle: {:?}, recipient_vk: {:?}, encrypted_msg: {:?}, encrypted_len: {:?}",
            wallet_handle, recipient_vk, encrypted_msg, encrypted_len);
 
+    command_params!(wallet_handle, recipient_vk, (encrypted_msg, encrypted_len));
+
     check_useful_c_str!(recipient_vk, ErrorCode::CommonInvalidParam3);
     check_useful_c_byte_array!(encrypted_msg, encrypted_len, ErrorCode::CommonInvalidParam4, ErrorCode::CommonInvalidParam5);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
@@ -405,12 +415,11 @@ pub  extern fn indy_crypto_auth_decrypt(command_handle: CommandHandle,
     trace!("indy_crypto_auth_decrypt: entities >>> wallet_handle: {:?}, recipient_vk: {:?}, encrypted_msg: {:?}, encrypted_len: {:?}",
            wallet_handle, recipient_vk, encrypted_msg, encrypted_len);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Crypto(CryptoCommand::AuthenticatedDecrypt(
+    let result = CommandExecutor::submit(Command::Crypto(CryptoCommand::AuthenticatedDecrypt(
             wallet_handle,
             recipient_vk,
             encrypted_msg,
-            Box::new(move |result| {
+            with_timeout(move |result| {
                 let (err, sender_vk, msg) = prepare_result_2!(result, String::new(), Vec::new());
                 trace!("indy_crypto_auth_decrypt: sender_vk: {:?}, msg: {:?}", sender_vk, msg);
                 let (msg_data, msg_len) = ctypes::vec_to_pointer(&msg);
@@ -463,17 +472,18 @@ pub  extern fn indy_crypto_anon_crypt(command_handle: CommandHandle,
                                                            encrypted_len: u32)>) -> ErrorCode {
     trace!("indy_crypto_anon_crypt: >>> recipient_vk: {:?}, msg_data: {:?}, msg_len: {:?}", recipient_vk, msg_data, msg_len);
 
+    command_params!(recipient_vk, (msg_data, msg_len));
+
     check_useful_c_str!(recipient_vk, ErrorCode::CommonInvalidParam2);
     check_useful_c_byte_array!(msg_data, msg_len, ErrorCode::CommonInvalidParam3, ErrorCode::CommonInvalidParam4);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
 
     trace!("indy_crypto_anon_crypt: entities >>> recipient_vk: {:?}, msg_data: {:?}, msg_len: {:?}", recipient_vk, msg_data, msg_len);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Crypto(CryptoCommand::AnonymousEncrypt(
+    let result = CommandExecutor::submit(Command::Crypto(CryptoCommand::AnonymousEncrypt(
             recipient_vk,
             msg_data,
-            Box::new(move |result| {
+            with_timeout(move |result| {
                 let (err, encrypted_msg) = prepare_result_1!(result, Vec::new());
                 trace!("indy_crypto_anon_crypt: encrypted_msg: {:?}", encrypted_msg);
                 let (encrypted_msg_raw, encrypted_msg_len) = ctypes::vec_to_pointer(&encrypted_msg);
@@ -527,6 +537,8 @@ pub  extern fn indy_crypto_anon_decrypt(command_handle: CommandHandle,
     trace!("indy_crypto_anon_decrypt: >>> wallet_handle: {:?}, recipient_vk: {:?}, encrypted_msg: {:?}, encrypted_len: {:?}",
            wallet_handle, recipient_vk, encrypted_msg, encrypted_len);
 
+    command_params!(wallet_handle, recipient_vk, (encrypted_msg, encrypted_len));
+
     check_useful_c_str!(recipient_vk, ErrorCode::CommonInvalidParam3);
     check_useful_c_byte_array!(encrypted_msg, encrypted_len, ErrorCode::CommonInvalidParam4, ErrorCode::CommonInvalidParam5);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
@@ -534,12 +546,11 @@ pub  extern fn indy_crypto_anon_decrypt(command_handle: CommandHandle,
     trace!("indy_crypto_anon_decrypt: entities >>> wallet_handle: {:?}, recipient_vk: {:?}, encrypted_msg: {:?}, encrypted_len: {:?}",
            wallet_handle, recipient_vk, encrypted_msg, encrypted_len);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Crypto(CryptoCommand::AnonymousDecrypt(
+    let result = CommandExecutor::submit(Command::Crypto(CryptoCommand::AnonymousDecrypt(
             wallet_handle,
             recipient_vk,
             encrypted_msg,
-            Box::new(move |result| {
+            with_timeout(move |result| {
                 let (err, msg) = prepare_result_1!(result, Vec::new());
                 trace!("indy_crypto_anon_decrypt: msg: {:?}", msg);
                 let (msg_data, msg_len) = ctypes::vec_to_pointer(&msg);
@@ -633,6 +644,8 @@ pub extern fn indy_pack_message(
     trace!("indy_pack_message: >>> wallet_handle: {:?}, message: {:?}, message_len {:?},\
             receiver_keys: {:?}, sender: {:?}", wallet_handle, message, message_len, receiver_keys, sender);
 
+    command_params!(wallet_handle, (message, message_len), receiver_keys, sender);
+
     check_useful_c_byte_array!(message, message_len, ErrorCode::CommonInvalidParam2, ErrorCode::CommonInvalidParam3);
     check_useful_c_str!(receiver_keys, ErrorCode::CommonInvalidParam4);
     check_useful_opt_c_str!(sender, ErrorCode::CommonInvalidParam5);
@@ -654,12 +667,12 @@ pub extern fn indy_pack_message(
         return IndyError::from_msg(IndyErrorKind::InvalidParam(4), "Empty RecipientKeys has been passed").into();
     }
 
-    let result = CommandExecutor::instance().send(Command::Crypto(CryptoCommand::PackMessage(
+    let result = CommandExecutor::submit(Command::Crypto(CryptoCommand::PackMessage(
         message,
         receiver_list,
         sender,
         wallet_handle,
-        Box::new(move |result| {
+        with_timeout(move |result| {
             let (err, jwe) = prepare_result_1!(result, Vec::new());
             trace!("indy_auth_pack_message: jwe: {:?}", jwe);
             let (jwe_data, jwe_len) = ctypes::vec_to_pointer(&jwe);
@@ -674,6 +687,67 @@ pub extern fn indy_pack_message(
     res
 }
 
+/// Variant of `indy_pack_message` that passes ownership of the packed message to the application
+/// instead of pointer valid only during the callback.
+///
+/// The buffer passed to the callback must be released with `indy_free_buffer`.
+/// On error NULL buffer is passed.
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern fn indy_pack_message_buffer(
+    command_handle: CommandHandle,
+    wallet_handle: WalletHandle,
+    message: *const u8,
+    message_len: u32,
+    receiver_keys: *const c_char,
+    sender: *const c_char,
+    cb: Option<extern fn(xcommand_handle: CommandHandle, err: ErrorCode, jwe_data: *mut u8, jwe_len: u32)>,
+) -> ErrorCode {
+    trace!("indy_pack_message_buffer: >>> wallet_handle: {:?}, message: {:?}, message_len {:?},\
+            receiver_keys: {:?}, sender: {:?}", wallet_handle, message, message_len, receiver_keys, sender);
+
+    command_params!(wallet_handle, (message, message_len), receiver_keys, sender);
+
+    check_useful_c_byte_array!(message, message_len, ErrorCode::CommonInvalidParam2, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(receiver_keys, ErrorCode::CommonInvalidParam4);
+    check_useful_opt_c_str!(sender, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_pack_message_buffer: entities >>> wallet_handle: {:?}, message: {:?}, message_len {:?},\
+            receiver_keys: {:?}, sender: {:?}", wallet_handle, message, message_len, receiver_keys, sender);
+
+    let receiver_list = match serde_json::from_str::<Vec<String>>(&receiver_keys) {
+        Ok(x) => x,
+        Err(_) => {
+            return IndyError::from_msg(IndyErrorKind::InvalidParam(4), "Invalid RecipientKeys has been passed").into();
+        },
+    };
+
+    if receiver_list.is_empty() {
+        return IndyError::from_msg(IndyErrorKind::InvalidParam(4), "Empty RecipientKeys has been passed").into();
+    }
+
+    let result = CommandExecutor::submit(Command::Crypto(CryptoCommand::PackMessage(
+        message,
+        receiver_list,
+        sender,
+        wallet_handle,
+        with_timeout(move |result| {
+            let result = result.map(Some);
+            let (err, jwe) = prepare_result_1!(result, None);
+            trace!("indy_pack_message_buffer: cb command_handle: {:?}, err: {:?}", command_handle, err);
+            let (jwe_data, jwe_len) = jwe.map(ctypes::vec_into_owned_pointer).unwrap_or((ptr::null_mut(), 0));
+            cb(command_handle, err, jwe_data, jwe_len)
+        }),
+    )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_pack_message_buffer: <<< res: {:?}", res);
+
+    res
+}
+
 
 /// Unpacks a JWE-like formatted message outputted by indy_pack_message (Experimental)
 ///
@@ -728,6 +802,8 @@ pub extern fn indy_unpack_message(
         jwe_len
     );
 
+    command_params!(wallet_handle, (jwe_data, jwe_len));
+
     check_useful_c_byte_array!(jwe_data, jwe_len, ErrorCode::CommonInvalidParam2, ErrorCode::CommonInvalidParam3);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
@@ -744,10 +820,10 @@ pub extern fn indy_unpack_message(
         Err(_) => return ErrorCode::CommonInvalidParam3
     };
 
-    let result = CommandExecutor::instance().send(Command::Crypto(CryptoCommand::UnpackMessage(
+    let result = CommandExecutor::submit(Command::Crypto(CryptoCommand::UnpackMessage(
         jwe_struct,
         wallet_handle,
-        Box::new(move |result| {
+        with_timeout(move |result| {
             let (err, res_json) = prepare_result_1!(result, Vec::new());
             trace!("indy_unpack_message: cb command_handle: {:?}, err: {:?}, res_json: {:?}",
                 command_handle, err, res_json
@@ -763,3 +839,89 @@ pub extern fn indy_unpack_message(
 
     res
 }
+
+/// Variant of `indy_unpack_message` that passes ownership of the unpacked result json to the application
+/// instead of pointer valid only during the callback.
+///
+/// The buffer passed to the callback must be released with `indy_free_buffer`.
+/// On error NULL buffer is passed.
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern fn indy_unpack_message_buffer(
+    command_handle: CommandHandle,
+    wallet_handle: WalletHandle,
+    jwe_data: *const u8,
+    jwe_len: u32,
+    cb: Option<
+        extern fn(
+            xcommand_handle: CommandHandle,
+            err: ErrorCode,
+            res_json_data : *mut u8,
+            res_json_len : u32
+        ),
+    >,
+) -> ErrorCode {
+    trace!(
+        "indy_unpack_message_buffer: >>> wallet_handle: {:?}, jwe_data: {:?}, jwe_len {:?}",
+        wallet_handle,
+        jwe_data,
+        jwe_len
+    );
+
+    command_params!(wallet_handle, (jwe_data, jwe_len));
+
+    check_useful_c_byte_array!(jwe_data, jwe_len, ErrorCode::CommonInvalidParam2, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    let jwe_struct: JWE = match serde_json::from_slice(jwe_data.as_slice()) {
+        Ok(x) => x,
+        Err(_) => return ErrorCode::CommonInvalidParam3
+    };
+
+    let result = CommandExecutor::submit(Command::Crypto(CryptoCommand::UnpackMessage(
+        jwe_struct,
+        wallet_handle,
+        with_timeout(move |result| {
+            let result = result.map(Some);
+            let (err, res_json) = prepare_result_1!(result, None);
+            trace!("indy_unpack_message_buffer: cb command_handle: {:?}, err: {:?}", command_handle, err);
+            let (res_json_data, res_json_len) = res_json.map(ctypes::vec_into_owned_pointer).unwrap_or((ptr::null_mut(), 0));
+            cb(command_handle, err, res_json_data, res_json_len)
+        }),
+    )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_unpack_message_buffer: <<< res: {:?}", res);
+
+    res
+}
+
+context_api! {
+    indy_create_key_ctx => indy_create_key(wallet_handle: WalletHandle, key_json: *const c_char)
+        -> cb(verkey: *const c_char), CommonInvalidParam4;
+    indy_set_key_metadata_ctx => indy_set_key_metadata(wallet_handle: WalletHandle, verkey: *const c_char, metadata: *const c_char)
+        -> cb(), CommonInvalidParam5;
+    indy_get_key_metadata_ctx => indy_get_key_metadata(wallet_handle: WalletHandle, verkey: *const c_char)
+        -> cb(metadata: *const c_char), CommonInvalidParam4;
+    indy_crypto_sign_ctx => indy_crypto_sign(wallet_handle: WalletHandle, signer_vk: *const c_char, message_raw: *const u8, message_len: u32)
+        -> cb(signature_raw: *const u8, signature_len: u32), CommonInvalidParam6;
+    indy_crypto_verify_ctx => indy_crypto_verify(signer_vk: *const c_char, message_raw: *const u8, message_len: u32, signature_raw: *const u8, signature_len: u32)
+        -> cb(valid: bool), CommonInvalidParam7;
+    indy_crypto_auth_crypt_ctx => indy_crypto_auth_crypt(wallet_handle: WalletHandle, sender_vk: *const c_char, recipient_vk: *const c_char, msg_data: *const u8, msg_len: u32)
+        -> cb(encrypted_msg: *const u8, encrypted_len: u32), CommonInvalidParam7;
+    indy_crypto_auth_decrypt_ctx => indy_crypto_auth_decrypt(wallet_handle: WalletHandle, recipient_vk: *const c_char, encrypted_msg: *const u8, encrypted_len: u32)
+        -> cb(sender_vk: *const c_char, msg_data: *const u8, msg_len: u32), CommonInvalidParam6;
+    indy_crypto_anon_crypt_ctx => indy_crypto_anon_crypt(recipient_vk: *const c_char, msg_data: *const u8, msg_len: u32)
+        -> cb(encrypted_msg: *const u8, encrypted_len: u32), CommonInvalidParam5;
+    indy_crypto_anon_decrypt_ctx => indy_crypto_anon_decrypt(wallet_handle: WalletHandle, recipient_vk: *const c_char, encrypted_msg: *const u8, encrypted_len: u32)
+        -> cb(msg_data: *const u8, msg_len: u32), CommonInvalidParam6;
+    indy_pack_message_ctx => indy_pack_message(wallet_handle: WalletHandle, message: *const u8, message_len: u32, receiver_keys: *const c_char, sender: *const c_char)
+        -> cb(jwe_data: *const u8, jwe_len: u32), CommonInvalidParam7;
+    indy_pack_message_buffer_ctx => indy_pack_message_buffer(wallet_handle: WalletHandle, message: *const u8, message_len: u32, receiver_keys: *const c_char, sender: *const c_char)
+        -> cb(jwe_data: *mut u8, jwe_len: u32), CommonInvalidParam7;
+    indy_unpack_message_ctx => indy_unpack_message(wallet_handle: WalletHandle, jwe_data: *const u8, jwe_len: u32)
+        -> cb(res_json_data: *const u8, res_json_len: u32), CommonInvalidParam5;
+    indy_unpack_message_buffer_ctx => indy_unpack_message_buffer(wallet_handle: WalletHandle, jwe_data: *const u8, jwe_len: u32)
+        -> cb(res_json_data: *mut u8, res_json_len: u32), CommonInvalidParam5;
+}
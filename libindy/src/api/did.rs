@@ -1,7 +1,11 @@
-use indy_api_types::{ErrorCode, CommandHandle, WalletHandle, PoolHandle};
+use indy_api_types::{ErrorCode, CommandHandle, WalletHandle, SearchHandle, INVALID_SEARCH_HANDLE};
+#[cfg(feature = "ledger")]
+use indy_api_types::PoolHandle;
+use indy_api_types::domain::wallet::Tags;
 use crate::commands::{Command, CommandExecutor};
+use crate::commands::timeout::with_timeout;
 use crate::commands::did::DidCommand;
-use crate::domain::crypto::did::{TheirDidInfo, DidValue, MyDidInfo, DidMethod};
+use crate::domain::crypto::did::{TheirDidInfo, DidValue, MyDidInfo, DidMethod, DidKey, DidService, DidSearchOptions, QualifyDidsOptions};
 use crate::domain::crypto::key::KeyInfo;
 use indy_api_types::errors::prelude::*;
 use indy_utils::ctypes;
@@ -10,8 +14,9 @@ use indy_api_types::validation::Validatable;
 use serde_json;
 use libc::c_char;
 
+#[cfg(feature = "ledger")]
 use std::ptr;
-use crate::domain::ledger::attrib::Endpoint;
+use crate::domain::ledger::attrib::{Endpoint, NamedEndpoint};
 
 
 /// Creates keys (signing and encryption keys) for a new
@@ -36,6 +41,8 @@ use crate::domain::ledger::attrib::Endpoint;
 ///               currently only 'ed25519' value is supported for this field)
 ///     "cid": bool, (optional; if not set then false is used;)
 ///     "method_name": string, (optional) method name to create fully qualified did.
+///             Use `indy:<namespace>` (for example `indy:sovrin:staging`) to create did:indy DID:
+///             its identifier is the first 16 bytes of SHA256 hash of the verkey (`did:indy:sovrin:staging:<id>`).
 /// }
 /// cb: Callback that takes command result as parameter.
 ///
@@ -61,16 +68,17 @@ pub  extern fn indy_create_and_store_my_did(command_handle: CommandHandle,
                                                                  verkey: *const c_char)>) -> ErrorCode {
     trace!("indy_create_and_store_my_did: >>> wallet_handle: {:?}, did_json: {:?}", wallet_handle, did_info);
 
+    command_params!(wallet_handle, did_info);
+
     check_useful_validatable_json!(did_info, ErrorCode::CommonInvalidParam3, MyDidInfo); // redefine to MyDidInfo if valid
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     trace!("indy_create_and_store_my_did: entities >>> wallet_handle: {:?}, did_json: {:?}", wallet_handle, secret!(&did_info));
 
-    let result = CommandExecutor::instance()
-        .send(Command::Did(DidCommand::CreateAndStoreMyDid(
+    let result = CommandExecutor::submit(Command::Did(DidCommand::CreateAndStoreMyDid(
             wallet_handle,
             did_info,
-            Box::new(move |result| {
+            with_timeout(move |result| {
                 let (err, did, verkey) = prepare_result_2!(result, String::new(), String::new());
                 trace!("indy_create_and_store_my_did: did: {:?}, verkey: {:?}", did, verkey);
                 let did = ctypes::string_to_cstring(did);
@@ -124,18 +132,19 @@ pub  extern fn indy_replace_keys_start(command_handle: CommandHandle,
                                                             verkey: *const c_char)>) -> ErrorCode {
     trace!("indy_replace_keys_start: >>> wallet_handle: {:?}, did: {:?}, identity_json: {:?}", wallet_handle, did, key_info);
 
+    command_params!(wallet_handle, did, key_info);
+
     check_useful_validatable_string!(did, ErrorCode::CommonInvalidParam3, DidValue);
     check_useful_json!(key_info, ErrorCode::CommonInvalidParam4, KeyInfo);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
 
     trace!("indy_replace_keys_start: entities>>> wallet_handle: {:?}, did: {:?}, key_info: {:?}", wallet_handle, did, secret!(&key_info));
 
-    let result = CommandExecutor::instance()
-        .send(Command::Did(DidCommand::ReplaceKeysStart(
+    let result = CommandExecutor::submit(Command::Did(DidCommand::ReplaceKeysStart(
             wallet_handle,
             key_info,
             did,
-            boxed_callback_string!("indy_replace_keys_start", cb, command_handle)
+            with_timeout(boxed_callback_string!("indy_replace_keys_start", cb, command_handle))
         )));
 
     let res = prepare_result!(result);
@@ -171,16 +180,17 @@ pub  extern fn indy_replace_keys_apply(command_handle: CommandHandle,
                                                             err: ErrorCode)>) -> ErrorCode {
     trace!("indy_replace_keys_apply: >>> wallet_handle: {:?}, did: {:?}", wallet_handle, did);
 
+    command_params!(wallet_handle, did);
+
     check_useful_validatable_string!(did, ErrorCode::CommonInvalidParam3, DidValue);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     trace!("indy_replace_keys_apply: entities >>> wallet_handle: {:?}, did: {:?}", wallet_handle, did);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Did(DidCommand::ReplaceKeysApply(
+    let result = CommandExecutor::submit(Command::Did(DidCommand::ReplaceKeysApply(
             wallet_handle,
             did,
-            Box::new(move |result| {
+            with_timeout(move |result| {
                 let err = prepare_result!(result);
                 trace!("indy_replace_keys_apply:");
                 cb(command_handle, err)
@@ -194,6 +204,84 @@ pub  extern fn indy_replace_keys_apply(command_handle: CommandHandle,
     res
 }
 
+/// Performs complete rotation of keys for an existing DID (owned by the caller of the library):
+///     - generates temporary keys (as "indy_replace_keys_start" does)
+///     - builds NYM request with the new verkey, signs it with the current key and sends it to the ledger.
+///       If endorser DID is passed the request is signed by both DID and endorser (endorser must be stored in the same wallet).
+///     - applies temporary keys as main on successful reply (as "indy_replace_keys_apply" does).
+///
+/// If the ledger write fails temporary keys are removed and the DID keeps the current key.
+/// The only exception is PoolLedgerTimeout error: the ledger state is unknown in this case so temporary keys are kept.
+/// Check the ledger and finish rotation by "indy_replace_keys_apply" call.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// did: target did to rotate keys.
+/// key_info: key information as json. Example:
+/// {
+///     "seed": string, (optional) Seed that allows deterministic key creation (if not set random one will be created).
+///                                Can be UTF-8, base64 or hex string.
+///     "crypto_type": string, (optional; if not set then ed25519 curve is used;
+///               currently only 'ed25519' value is supported for this field)
+/// }
+/// endorser_did: (Optional) DID of the Endorser that will sign and pay for the transaction.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+///   verkey: The DIDs new verification key
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+/// Ledger*
+/// Pool*
+#[cfg(feature = "ledger")]
+#[no_mangle]
+pub  extern fn indy_rotate_key(command_handle: CommandHandle,
+                               wallet_handle: WalletHandle,
+                               pool_handle: PoolHandle,
+                               did: *const c_char,
+                               key_info: *const c_char,
+                               endorser_did: *const c_char,
+                               cb: Option<extern fn(command_handle_: CommandHandle,
+                                                    err: ErrorCode,
+                                                    verkey: *const c_char)>) -> ErrorCode {
+    trace!("indy_rotate_key: >>> wallet_handle: {:?}, pool_handle: {:?}, did: {:?}, key_info: {:?}, endorser_did: {:?}",
+           wallet_handle, pool_handle, did, key_info, endorser_did);
+
+    command_params!(wallet_handle, pool_handle, did, key_info, endorser_did);
+
+    check_useful_validatable_string!(did, ErrorCode::CommonInvalidParam4, DidValue);
+    check_useful_json!(key_info, ErrorCode::CommonInvalidParam5, KeyInfo);
+    check_useful_validatable_opt_string!(endorser_did, ErrorCode::CommonInvalidParam6, DidValue);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    trace!("indy_rotate_key: entities >>> wallet_handle: {:?}, pool_handle: {:?}, did: {:?}, key_info: {:?}, endorser_did: {:?}",
+           wallet_handle, pool_handle, did, secret!(&key_info), endorser_did);
+
+    let result = CommandExecutor::submit(Command::Did(DidCommand::RotateKey(
+            wallet_handle,
+            pool_handle,
+            did,
+            key_info,
+            endorser_did,
+            with_timeout(boxed_callback_string!("indy_rotate_key", cb, command_handle))
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_rotate_key: <<< res: {:?}", res);
+
+    res
+}
+
 /// Saves their DID for a pairwise connection in a secured Wallet,
 /// so that it can be used to verify transaction.
 /// Updates DID associated verkey in case DID already exists in the Wallet.
@@ -228,16 +316,17 @@ pub  extern fn indy_store_their_did(command_handle: CommandHandle,
                                                          err: ErrorCode)>) -> ErrorCode {
     trace!("indy_store_their_did: >>> wallet_handle: {:?}, identity_json: {:?}", wallet_handle, identity_json);
 
+    command_params!(wallet_handle, identity_json);
+
     check_useful_validatable_json!(identity_json, ErrorCode::CommonInvalidParam3, TheirDidInfo);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     trace!("indy_store_their_did: entities >>> wallet_handle: {:?}, identity_json: {:?}", wallet_handle, identity_json);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Did(DidCommand::StoreTheirDid(
+    let result = CommandExecutor::submit(Command::Did(DidCommand::StoreTheirDid(
             wallet_handle,
             identity_json,
-            Box::new(move |result| {
+            with_timeout(move |result| {
                 let err = prepare_result!(result);
                 trace!("indy_store_their_did:");
                 cb(command_handle, err)
@@ -282,6 +371,7 @@ pub  extern fn indy_store_their_did(command_handle: CommandHandle,
 /// Common*
 /// Wallet*
 /// Crypto*
+#[cfg(feature = "ledger")]
 #[no_mangle]
 pub extern fn indy_key_for_did(command_handle: CommandHandle,
                                pool_handle: PoolHandle,
@@ -292,17 +382,18 @@ pub extern fn indy_key_for_did(command_handle: CommandHandle,
                                                     key: *const c_char)>) -> ErrorCode {
     trace!("indy_key_for_did: >>> pool_handle: {:?}, wallet_handle: {:?}, did: {:?}", pool_handle, wallet_handle, did);
 
+    command_params!(pool_handle, wallet_handle, did);
+
     check_useful_validatable_string!(did, ErrorCode::CommonInvalidParam4, DidValue);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
 
     trace!("indy_key_for_did: entities >>> pool_handle: {:?}, wallet_handle: {:?}, did: {:?}", pool_handle, wallet_handle, did);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Did(DidCommand::KeyForDid(
+    let result = CommandExecutor::submit(Command::Did(DidCommand::KeyForDid(
             pool_handle,
             wallet_handle,
             did,
-            boxed_callback_string!("indy_key_for_did", cb, command_handle)
+            with_timeout(boxed_callback_string!("indy_key_for_did", cb, command_handle))
         )));
 
     let res = prepare_result!(result);
@@ -349,16 +440,17 @@ pub extern fn indy_key_for_local_did(command_handle: CommandHandle,
                                                           key: *const c_char)>) -> ErrorCode {
     trace!("indy_key_for_local_did: >>> wallet_handle: {:?}, did: {:?}", wallet_handle, did);
 
+    command_params!(wallet_handle, did);
+
     check_useful_validatable_string!(did, ErrorCode::CommonInvalidParam3, DidValue);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     trace!("indy_key_for_local_did: entities >>> wallet_handle: {:?}, did: {:?}", wallet_handle, did);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Did(DidCommand::KeyForLocalDid(
+    let result = CommandExecutor::submit(Command::Did(DidCommand::KeyForLocalDid(
             wallet_handle,
             did,
-            boxed_callback_string!("indy_key_for_local_did", cb, command_handle)
+            with_timeout(boxed_callback_string!("indy_key_for_local_did", cb, command_handle))
         )));
 
     let res = prepare_result!(result);
@@ -398,6 +490,8 @@ pub extern fn indy_set_endpoint_for_did(command_handle: CommandHandle,
                                                              err: ErrorCode)>) -> ErrorCode {
     trace!("indy_set_endpoint_for_did: >>> wallet_handle: {:?}, did: {:?}, address: {:?}, transport_key: {:?}", wallet_handle, did, address, transport_key);
 
+    command_params!(wallet_handle, did, address, transport_key);
+
     check_useful_validatable_string!(did, ErrorCode::CommonInvalidParam3, DidValue);
     check_useful_c_str!(address, ErrorCode::CommonInvalidParam4);
     check_useful_c_str!(transport_key, ErrorCode::CommonInvalidParam5);
@@ -408,12 +502,11 @@ pub extern fn indy_set_endpoint_for_did(command_handle: CommandHandle,
 
     let endpoint = Endpoint::new(address, Some(transport_key));
 
-    let result = CommandExecutor::instance()
-        .send(Command::Did(DidCommand::SetEndpointForDid(
+    let result = CommandExecutor::submit(Command::Did(DidCommand::SetEndpointForDid(
             wallet_handle,
             did,
             endpoint,
-            Box::new(move |result| {
+            with_timeout(move |result| {
                 let err = prepare_result!(result);
                 trace!("indy_set_endpoint_for_did:");
                 cb(command_handle, err)
@@ -447,6 +540,7 @@ pub extern fn indy_set_endpoint_for_did(command_handle: CommandHandle,
 /// Common*
 /// Wallet*
 /// Crypto*
+#[cfg(feature = "ledger")]
 #[no_mangle]
 pub extern fn indy_get_endpoint_for_did(command_handle: CommandHandle,
                                         wallet_handle: WalletHandle,
@@ -458,17 +552,18 @@ pub extern fn indy_get_endpoint_for_did(command_handle: CommandHandle,
                                                              transport_vk: *const c_char)>) -> ErrorCode {
     trace!("indy_get_endpoint_for_did: >>> wallet_handle: {:?}, pool_handle: {:?}, did: {:?}", wallet_handle, pool_handle, did);
 
+    command_params!(wallet_handle, pool_handle, did);
+
     check_useful_validatable_string!(did, ErrorCode::CommonInvalidParam3, DidValue);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     trace!("indy_get_endpoint_for_did: entities >>> wallet_handle: {:?}, pool_handle: {:?}, did: {:?}", wallet_handle, pool_handle, did);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Did(DidCommand::GetEndpointForDid(
+    let result = CommandExecutor::submit(Command::Did(DidCommand::GetEndpointForDid(
             wallet_handle,
             pool_handle,
             did,
-            Box::new(move |result| {
+            with_timeout(move |result| {
                 let (err, address, transport_vk) = prepare_result_2!(result, String::new(), None);
                 trace!("indy_get_endpoint_for_did: address: {:?}, transport_vk: {:?}", address, transport_vk);
                 let address = ctypes::string_to_cstring(address);
@@ -492,6 +587,9 @@ pub extern fn indy_get_endpoint_for_did(command_handle: CommandHandle,
 /// wallet_handle: Wallet handle (created by open_wallet).
 /// did - the DID to store metadata.
 /// metadata - the meta information that will be store with the DID.
+///     If it is a JSON object, its top level string fields are also set as tags of my DID,
+///     so my DIDs can be filtered by them with "indy_open_my_dids_search". Tags of the previous
+///     metadata fields are removed.
 /// cb: Callback that takes command result as parameter.
 ///
 /// #Returns
@@ -513,18 +611,19 @@ pub extern fn indy_set_did_metadata(command_handle: CommandHandle,
                                                          err: ErrorCode)>) -> ErrorCode {
     trace!("indy_set_did_metadata: >>> wallet_handle: {:?}, did: {:?}, metadata: {:?}", wallet_handle, did, metadata);
 
+    command_params!(wallet_handle, did, metadata);
+
     check_useful_validatable_string!(did, ErrorCode::CommonInvalidParam3, DidValue);
     check_useful_c_str_empty_accepted!(metadata, ErrorCode::CommonInvalidParam4);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
 
     trace!("indy_set_did_metadata: entities >>> wallet_handle: {:?}, did: {:?}, metadata: {:?}", wallet_handle, did, metadata);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Did(DidCommand::SetDidMetadata(
+    let result = CommandExecutor::submit(Command::Did(DidCommand::SetDidMetadata(
             wallet_handle,
             did,
             metadata,
-            Box::new(move |result| {
+            with_timeout(move |result| {
                 let err = prepare_result!(result);
                 trace!("indy_set_did_metadata:");
                 cb(command_handle, err)
@@ -566,16 +665,17 @@ pub extern fn indy_get_did_metadata(command_handle: CommandHandle,
                                                          metadata: *const c_char)>) -> ErrorCode {
     trace!("indy_get_did_metadata: >>> wallet_handle: {:?}, did: {:?}", wallet_handle, did);
 
+    command_params!(wallet_handle, did);
+
     check_useful_validatable_string!(did, ErrorCode::CommonInvalidParam3, DidValue);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     trace!("indy_get_did_metadata: entities >>> wallet_handle: {:?}, did: {:?}", wallet_handle, did);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Did(DidCommand::GetDidMetadata(
+    let result = CommandExecutor::submit(Command::Did(DidCommand::GetDidMetadata(
             wallet_handle,
             did,
-            boxed_callback_string!("indy_get_did_metadata", cb, command_handle))));
+            with_timeout(boxed_callback_string!("indy_get_did_metadata", cb, command_handle)))));
 
     let res = prepare_result!(result);
 
@@ -618,16 +718,17 @@ pub extern fn indy_get_my_did_with_meta(command_handle: CommandHandle,
                                                              did_with_meta: *const c_char)>) -> ErrorCode {
     trace!("indy_get_my_did_with_meta: >>> wallet_handle: {:?}, my_did: {:?}", wallet_handle, my_did);
 
+    command_params!(wallet_handle, my_did);
+
     check_useful_validatable_string!(my_did, ErrorCode::CommonInvalidParam3, DidValue);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     trace!("indy_get_my_did_with_meta: entities >>> wallet_handle: {:?}, my_did: {:?}", wallet_handle, my_did);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Did(DidCommand::GetMyDidWithMeta(
+    let result = CommandExecutor::submit(Command::Did(DidCommand::GetMyDidWithMeta(
             wallet_handle,
             my_did,
-            boxed_callback_string!("indy_get_my_did_with_meta", cb, command_handle)
+            with_timeout(boxed_callback_string!("indy_get_my_did_with_meta", cb, command_handle))
         )));
 
     let res = prepare_result!(result);
@@ -639,6 +740,9 @@ pub extern fn indy_get_my_did_with_meta(command_handle: CommandHandle,
 
 /// Retrieves the information about all DIDs stored in the wallet.
 ///
+/// NOTE: This method immediately returns all DIDs stored in the wallet.
+/// Use <indy_open_my_dids_search> to filter DIDs and fetch them by small batches.
+///
 /// #Params
 /// command_handle: Command handle to map callback to caller context.
 /// wallet_handle: Wallet handle (created by open_wallet).
@@ -667,14 +771,15 @@ pub extern fn indy_list_my_dids_with_meta(command_handle: CommandHandle,
                                                                dids: *const c_char)>) -> ErrorCode {
     trace!("indy_list_my_dids_with_meta: >>> wallet_handle: {:?}", wallet_handle);
 
+    command_params!(wallet_handle);
+
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
 
     trace!("indy_list_my_dids_with_meta: entities >>> wallet_handle: {:?}", wallet_handle);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Did(DidCommand::ListMyDidsWithMeta(
+    let result = CommandExecutor::submit(Command::Did(DidCommand::ListMyDidsWithMeta(
             wallet_handle,
-            boxed_callback_string!("indy_list_my_dids_with_meta", cb, command_handle)
+            with_timeout(boxed_callback_string!("indy_list_my_dids_with_meta", cb, command_handle))
         )));
 
     let res = prepare_result!(result);
@@ -684,6 +789,230 @@ pub extern fn indy_list_my_dids_with_meta(command_handle: CommandHandle,
     res
 }
 
+/// Saves/replaces tags of my DID stored in the wallet.
+/// Tags can be used later to filter DIDs with "indy_open_my_dids_search".
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: Wallet handle (created by open_wallet).
+/// did - my DID to set tags for.
+/// tags_json - Tags of the DID as json:
+///   {
+///     "tagName1": <str>, // string tag (will be stored encrypted)
+///     "~tagName2": <str>, // string tag (will be stored un-encrypted)
+///   }
+///   Note that all previously stored tags of the DID are replaced. Tags of metadata fields are kept
+///   unless the given tags have the same names.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_set_my_did_tags(command_handle: CommandHandle,
+                                   wallet_handle: WalletHandle,
+                                   did: *const c_char,
+                                   tags_json: *const c_char,
+                                   cb: Option<extern fn(command_handle_: CommandHandle,
+                                                        err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_set_my_did_tags: >>> wallet_handle: {:?}, did: {:?}, tags_json: {:?}", wallet_handle, did, tags_json);
+
+    command_params!(wallet_handle, did, tags_json);
+
+    check_useful_validatable_string!(did, ErrorCode::CommonInvalidParam3, DidValue);
+    check_useful_json!(tags_json, ErrorCode::CommonInvalidParam4, Tags);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_set_my_did_tags: entities >>> wallet_handle: {:?}, did: {:?}, tags_json: {:?}", wallet_handle, did, tags_json);
+
+    let result = CommandExecutor::submit(Command::Did(DidCommand::SetMyDidTags(
+            wallet_handle,
+            did,
+            tags_json,
+            with_timeout(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_set_my_did_tags:");
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_set_my_did_tags: <<< res: {:?}", res);
+
+    res
+}
+
+/// Search for my DIDs stored in the wallet.
+/// DIDs can be filtered by tags set with "indy_set_my_did_tags".
+///
+/// Instead of immediately returning of all DIDs (as "indy_list_my_dids_with_meta" does)
+/// this call returns search_handle that can be used later
+/// to fetch DIDs by small batches (with indy_fetch_my_dids).
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: Wallet handle (created by open_wallet).
+/// query_json: (Optional) Wql query filter for DIDs searching based on tags.
+///     where query: indy-sdk/docs/design/011-wallet-query-language/README.md
+/// options_json: (Optional) Search options:
+///   {
+///     "retrieveMetadata": (optional, true by default) Retrieve the meta information stored with the DID,
+///     "retrieveTempVerkey": (optional, true by default) Retrieve temporary verkey created by key rotation
+///   }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+/// - search_handle: Search handle that can be used later to fetch DIDs by small batches (with indy_fetch_my_dids)
+/// - total_count: Total count of DIDs matching the query
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_open_my_dids_search(command_handle: CommandHandle,
+                                       wallet_handle: WalletHandle,
+                                       query_json: *const c_char,
+                                       options_json: *const c_char,
+                                       cb: Option<extern fn(command_handle_: CommandHandle,
+                                                            err: ErrorCode,
+                                                            search_handle: SearchHandle,
+                                                            total_count: usize)>) -> ErrorCode {
+    trace!("indy_open_my_dids_search: >>> wallet_handle: {:?}, query_json: {:?}, options_json: {:?}", wallet_handle, query_json, options_json);
+
+    command_params!(wallet_handle, query_json, options_json);
+
+    check_useful_opt_c_str!(query_json, ErrorCode::CommonInvalidParam3);
+    check_useful_opt_json!(options_json, ErrorCode::CommonInvalidParam4, DidSearchOptions);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_open_my_dids_search: entities >>> wallet_handle: {:?}, query_json: {:?}, options_json: {:?}", wallet_handle, query_json, options_json);
+
+    let result = CommandExecutor::submit(Command::Did(DidCommand::OpenMyDidsSearch(
+            wallet_handle,
+            query_json,
+            options_json,
+            with_timeout(move |result| {
+                let (err, handle, total_count) = prepare_result_2!(result, INVALID_SEARCH_HANDLE, 0);
+                cb(command_handle, err, handle, total_count)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_open_my_dids_search: <<< res: {:?}", res);
+
+    res
+}
+
+/// Fetch next DIDs for search.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// search_handle: Search handle (created by indy_open_my_dids_search)
+/// count: Count of DIDs to fetch
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+///   dids:  [{
+///     "did": string - DID stored in the wallet,
+///     "verkey": string - The DIDs transport key (ver key, key id).,
+///     "tempVerkey": string - Temporary DIDs verkey (null if retrieveTempVerkey option is false),
+///     "metadata": string - The meta information stored with the DID (null if retrieveMetadata option is false)
+///   }]
+/// NOTE: The list of length less than the requested count means DIDs search iterator is completed.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_fetch_my_dids(command_handle: CommandHandle,
+                                 search_handle: SearchHandle,
+                                 count: usize,
+                                 cb: Option<extern fn(command_handle_: CommandHandle,
+                                                      err: ErrorCode,
+                                                      dids: *const c_char)>) -> ErrorCode {
+    trace!("indy_fetch_my_dids: >>> search_handle: {:?}, count: {:?}", search_handle, count);
+
+    command_params!(search_handle, count);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_fetch_my_dids: entities >>> search_handle: {:?}, count: {:?}", search_handle, count);
+
+    let result = CommandExecutor::submit(Command::Did(DidCommand::FetchMyDids(
+            search_handle,
+            count,
+            with_timeout(boxed_callback_string!("indy_fetch_my_dids", cb, command_handle))
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_fetch_my_dids: <<< res: {:?}", res);
+
+    res
+}
+
+/// Close my DIDs search (make search handle invalid)
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// search_handle: Search handle (created by indy_open_my_dids_search)
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_close_my_dids_search(command_handle: CommandHandle,
+                                        search_handle: SearchHandle,
+                                        cb: Option<extern fn(command_handle_: CommandHandle,
+                                                             err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_close_my_dids_search: >>> search_handle: {:?}", search_handle);
+
+    command_params!(search_handle);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_close_my_dids_search: entities >>> search_handle: {:?}", search_handle);
+
+    let result = CommandExecutor::submit(Command::Did(DidCommand::CloseMyDidsSearch(
+            search_handle,
+            with_timeout(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_close_my_dids_search:");
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_close_my_dids_search: <<< res: {:?}", res);
+
+    res
+}
+
 /// Retrieves abbreviated verkey if it is possible otherwise return full verkey.
 ///
 /// #Params
@@ -711,17 +1040,18 @@ pub  extern fn indy_abbreviate_verkey(command_handle: CommandHandle,
                                                            verkey: *const c_char)>) -> ErrorCode {
     trace!("indy_abbreviate_verkey: >>> did: {:?}, full_verkey: {:?}", did, full_verkey);
 
+    command_params!(did, full_verkey);
+
     check_useful_validatable_string!(did, ErrorCode::CommonInvalidParam3, DidValue);
     check_useful_c_str!(full_verkey, ErrorCode::CommonInvalidParam4);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
 
     trace!("indy_abbreviate_verkey: entities >>> did: {:?}, full_verkey: {:?}", did, full_verkey);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Did(DidCommand::AbbreviateVerkey(
+    let result = CommandExecutor::submit(Command::Did(DidCommand::AbbreviateVerkey(
             did,
             full_verkey,
-            boxed_callback_string!("indy_abbreviate_verkey", cb, command_handle)
+            with_timeout(boxed_callback_string!("indy_abbreviate_verkey", cb, command_handle))
         )));
 
     let res = prepare_result!(result);
@@ -741,7 +1071,7 @@ pub  extern fn indy_abbreviate_verkey(command_handle: CommandHandle,
 /// command_handle: Command handle to map callback to caller context.
 /// wallet_handle: Wallet handle (created by open_wallet).
 /// did: target DID stored in the wallet.
-/// method: method to apply to the DID.
+/// method: method to apply to the DID (`indy:<namespace>` for did:indy DIDs).
 /// cb: Callback that takes command result as parameter.
 ///
 /// #Returns
@@ -763,18 +1093,19 @@ pub extern fn indy_qualify_did(command_handle: CommandHandle,
                                                                  full_qualified_did: *const c_char)>) -> ErrorCode {
     trace!("indy_qualify_did: >>> wallet_handle: {:?}, did: {:?}, method: {:?}", wallet_handle, did, method);
 
+    command_params!(wallet_handle, did, method);
+
     check_useful_validatable_string!(did, ErrorCode::CommonInvalidParam3, DidValue);
     check_useful_validatable_string!(method, ErrorCode::CommonInvalidParam4, DidMethod);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
 
     trace!("indy_qualify_did: entities >>> wallet_handle: {:?}, did: {:?}, method: {:?}", wallet_handle, did, method);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Did(DidCommand::QualifyDid(
+    let result = CommandExecutor::submit(Command::Did(DidCommand::QualifyDid(
             wallet_handle,
             did,
             method,
-            boxed_callback_string!("indy_qualify_did", cb, command_handle)
+            with_timeout(boxed_callback_string!("indy_qualify_did", cb, command_handle))
         )));
 
     let res = prepare_result!(result);
@@ -782,4 +1113,910 @@ pub extern fn indy_qualify_did(command_handle: CommandHandle,
     trace!("indy_qualify_did: <<< res: {:?}", res);
 
     res
-}
\ No newline at end of file
+}
+
+/// Updates all unqualified DIDs stored in the wallet to make them fully qualified (as "indy_qualify_did" does for a single DID).
+///     - my DIDs get the method and their related entities are moved.
+///     - their DIDs get the method, pairwise and other related entities are moved.
+///
+/// All conflicts (fully qualified form of DID already stored in the wallet) are checked before the wallet is changed.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: Wallet handle (created by open_wallet).
+/// method: method to apply to the DIDs (`indy:<namespace>` for did:indy DIDs).
+/// options_json: (optional) options json:
+/// {
+///     dryRun: (optional, false by default) Only build the report without changing of the wallet.
+/// }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - report_json: qualification report json:
+/// {
+///     dryRun: bool,
+///     myDids: [{did: string, qualifiedDid: string}],
+///     theirDids: [{did: string, qualifiedDid: string}],
+///     pairwiseCount: int, - number of updated pairwise records
+/// }
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_qualify_all_dids(command_handle: CommandHandle,
+                                    wallet_handle: WalletHandle,
+                                    method: *const c_char,
+                                    options_json: *const c_char,
+                                    cb: Option<extern fn(command_handle_: CommandHandle,
+                                                         err: ErrorCode,
+                                                         report_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_qualify_all_dids: >>> wallet_handle: {:?}, method: {:?}, options_json: {:?}", wallet_handle, method, options_json);
+
+    command_params!(wallet_handle, method, options_json);
+
+    check_useful_validatable_string!(method, ErrorCode::CommonInvalidParam3, DidMethod);
+    check_useful_opt_json!(options_json, ErrorCode::CommonInvalidParam4, QualifyDidsOptions);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_qualify_all_dids: entities >>> wallet_handle: {:?}, method: {:?}, options_json: {:?}", wallet_handle, method, options_json);
+
+    let result = CommandExecutor::submit(Command::Did(DidCommand::QualifyAllDids(
+            wallet_handle,
+            method,
+            options_json,
+            with_timeout(boxed_callback_string!("indy_qualify_all_dids", cb, command_handle))
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_qualify_all_dids: <<< res: {:?}", res);
+
+    res
+}
+/// Builds W3C DID Document for the given DID.
+///
+/// The DID can be either my DID or their DID. Verkey and endpoint are resolved in the same way
+/// as "indy_key_for_did" and "indy_get_endpoint_for_did" calls do: at first local wallet is checked and
+/// information about their DID is fetched from the ledger (with cache in the local wallet) if it is missed.
+/// Endpoint of my DID is looked only in the local wallet.
+///
+/// Note if DID has no endpoint neither in the wallet nor on the ledger DID Document is built without services.
+/// Additional keys and services stored for the DID (see "indy_add_did_key" and "indy_add_did_service")
+/// are appended to the corresponding sections of DID Document.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: Wallet handle (created by open_wallet).
+/// pool_handle: Pool handle (created by open_pool).
+/// did - The DID to build DID Document for.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+/// - did_doc: DID Document json
+///   {
+///     "@context": "https://www.w3.org/ns/did/v1",
+///     "id": string - fully qualified DID (unqualified DIDs are prefixed with "did:sov:"),
+///     "verificationMethod": [{"id": "<id>#key-1", "type": "Ed25519VerificationKey2018", "controller": "<id>", "publicKeyBase58": <verkey>}],
+///     "authentication": ["<id>#key-1"],
+///     "assertionMethod": ["<id>#key-1"],
+///     "keyAgreement": [{"id": "<id>#key-agreement-1", "type": "X25519KeyAgreementKey2019", "controller": "<id>", "publicKeyBase58": <verkey converted to X25519>}],
+///     "service": [{"id": "<id>#did-communication", "type": "did-communication", "serviceEndpoint": <endpoint address>,
+///                  "recipientKeys": [<endpoint transport key or verkey>], "routingKeys": [], "priority": 0}]
+///   }
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+/// Ledger*
+#[cfg(feature = "ledger")]
+#[no_mangle]
+pub extern fn indy_get_did_doc(command_handle: CommandHandle,
+                               wallet_handle: WalletHandle,
+                               pool_handle: PoolHandle,
+                               did: *const c_char,
+                               cb: Option<extern fn(command_handle_: CommandHandle,
+                                                    err: ErrorCode,
+                                                    did_doc: *const c_char)>) -> ErrorCode {
+    trace!("indy_get_did_doc: >>> wallet_handle: {:?}, pool_handle: {:?}, did: {:?}", wallet_handle, pool_handle, did);
+
+    command_params!(wallet_handle, pool_handle, did);
+
+    check_useful_validatable_string!(did, ErrorCode::CommonInvalidParam4, DidValue);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_get_did_doc: entities >>> wallet_handle: {:?}, pool_handle: {:?}, did: {:?}", wallet_handle, pool_handle, did);
+
+    let result = CommandExecutor::submit(Command::Did(DidCommand::GetDidDoc(
+            wallet_handle,
+            pool_handle,
+            did,
+            with_timeout(boxed_callback_string!("indy_get_did_doc", cb, command_handle))
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_get_did_doc: <<< res: {:?}", res);
+
+    res
+}
+
+/// Adds additional verification key to the DID stored in the wallet.
+/// The key is published in DID Document built by "indy_get_did_doc" besides DID verkey.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: Wallet handle (created by open_wallet).
+/// did - The DID (my or their) stored in the wallet.
+/// key_json - Key information as json:
+///   {
+///     "id": string - key id unique among keys and services of the DID (DID URL fragment without leading `#`, for example "key-2"),
+///         ids "key-1", "key-agreement-1" and "did-communication" are reserved,
+///     "purpose": string - one of "authentication", "assertionMethod", "keyAgreement",
+///     "verkey": string - Ed25519 verkey (for "keyAgreement" purpose it is converted to X25519 key in DID Document)
+///   }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_add_did_key(command_handle: CommandHandle,
+                               wallet_handle: WalletHandle,
+                               did: *const c_char,
+                               key_json: *const c_char,
+                               cb: Option<extern fn(command_handle_: CommandHandle,
+                                                    err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_add_did_key: >>> wallet_handle: {:?}, did: {:?}, key_json: {:?}", wallet_handle, did, key_json);
+
+    command_params!(wallet_handle, did, key_json);
+
+    check_useful_validatable_string!(did, ErrorCode::CommonInvalidParam3, DidValue);
+    check_useful_validatable_json!(key_json, ErrorCode::CommonInvalidParam4, DidKey);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_add_did_key: entities >>> wallet_handle: {:?}, did: {:?}, key_json: {:?}", wallet_handle, did, key_json);
+
+    let result = CommandExecutor::submit(Command::Did(DidCommand::AddDidKey(
+            wallet_handle,
+            did,
+            key_json,
+            with_timeout(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_add_did_key:");
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_add_did_key: <<< res: {:?}", res);
+
+    res
+}
+
+/// Removes additional verification key added by "indy_add_did_key" from the DID stored in the wallet.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: Wallet handle (created by open_wallet).
+/// did - The DID (my or their) stored in the wallet.
+/// key_id - Id of the key to remove.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_remove_did_key(command_handle: CommandHandle,
+                                  wallet_handle: WalletHandle,
+                                  did: *const c_char,
+                                  key_id: *const c_char,
+                                  cb: Option<extern fn(command_handle_: CommandHandle,
+                                                       err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_remove_did_key: >>> wallet_handle: {:?}, did: {:?}, key_id: {:?}", wallet_handle, did, key_id);
+
+    command_params!(wallet_handle, did, key_id);
+
+    check_useful_validatable_string!(did, ErrorCode::CommonInvalidParam3, DidValue);
+    check_useful_c_str!(key_id, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_remove_did_key: entities >>> wallet_handle: {:?}, did: {:?}, key_id: {:?}", wallet_handle, did, key_id);
+
+    let result = CommandExecutor::submit(Command::Did(DidCommand::RemoveDidKey(
+            wallet_handle,
+            did,
+            key_id,
+            with_timeout(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_remove_did_key:");
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_remove_did_key: <<< res: {:?}", res);
+
+    res
+}
+
+/// Adds additional service endpoint to the DID stored in the wallet.
+/// The service is published in DID Document built by "indy_get_did_doc" besides endpoint set by "indy_set_endpoint_for_did".
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: Wallet handle (created by open_wallet).
+/// did - The DID (my or their) stored in the wallet.
+/// service_json - Service information as json:
+///   {
+///     "id": string - service id unique among keys and services of the DID (DID URL fragment without leading `#`, for example "mediator"),
+///         ids "key-1", "key-agreement-1" and "did-communication" are reserved,
+///     "type": string - service type (for example "did-communication"),
+///     "serviceEndpoint": string - service endpoint address,
+///     "recipientKeys": [string], (optional) list of recipient verkeys,
+///     "routingKeys": [string], (optional) list of routing verkeys,
+///     "priority": int, (optional) service priority (0 by default)
+///   }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_add_did_service(command_handle: CommandHandle,
+                                   wallet_handle: WalletHandle,
+                                   did: *const c_char,
+                                   service_json: *const c_char,
+                                   cb: Option<extern fn(command_handle_: CommandHandle,
+                                                        err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_add_did_service: >>> wallet_handle: {:?}, did: {:?}, service_json: {:?}", wallet_handle, did, service_json);
+
+    command_params!(wallet_handle, did, service_json);
+
+    check_useful_validatable_string!(did, ErrorCode::CommonInvalidParam3, DidValue);
+    check_useful_validatable_json!(service_json, ErrorCode::CommonInvalidParam4, DidService);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_add_did_service: entities >>> wallet_handle: {:?}, did: {:?}, service_json: {:?}", wallet_handle, did, service_json);
+
+    let result = CommandExecutor::submit(Command::Did(DidCommand::AddDidService(
+            wallet_handle,
+            did,
+            service_json,
+            with_timeout(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_add_did_service:");
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_add_did_service: <<< res: {:?}", res);
+
+    res
+}
+
+/// Removes additional service endpoint added by "indy_add_did_service" from the DID stored in the wallet.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: Wallet handle (created by open_wallet).
+/// did - The DID (my or their) stored in the wallet.
+/// service_id - Id of the service to remove.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_remove_did_service(command_handle: CommandHandle,
+                                      wallet_handle: WalletHandle,
+                                      did: *const c_char,
+                                      service_id: *const c_char,
+                                      cb: Option<extern fn(command_handle_: CommandHandle,
+                                                           err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_remove_did_service: >>> wallet_handle: {:?}, did: {:?}, service_id: {:?}", wallet_handle, did, service_id);
+
+    command_params!(wallet_handle, did, service_id);
+
+    check_useful_validatable_string!(did, ErrorCode::CommonInvalidParam3, DidValue);
+    check_useful_c_str!(service_id, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_remove_did_service: entities >>> wallet_handle: {:?}, did: {:?}, service_id: {:?}", wallet_handle, did, service_id);
+
+    let result = CommandExecutor::submit(Command::Did(DidCommand::RemoveDidService(
+            wallet_handle,
+            did,
+            service_id,
+            with_timeout(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_remove_did_service:");
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_remove_did_service: <<< res: {:?}", res);
+
+    res
+}
+
+/// Sets named endpoint with transport profile for the DID stored in the wallet.
+/// DID can have several named endpoints besides endpoint set by "indy_set_endpoint_for_did".
+/// Endpoint with the same name is replaced.
+/// Named endpoints are published in DID Document built by "indy_get_did_doc"
+/// and can be written to the ledger as "endpoints" attrib (see indy_build_named_endpoints_attrib_request).
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: Wallet handle (created by open_wallet).
+/// did - The DID (my or their) stored in the wallet.
+/// endpoint_json - Endpoint information as json:
+///   {
+///     "name": string - endpoint name unique for the DID (DID URL fragment without leading `#`, for example "agent"),
+///         names "key-1", "key-agreement-1" and "did-communication" are reserved,
+///     "endpoint": string - endpoint address with scheme matching transport (for example "wss://agent.example.com"),
+///     "transport": string - transport type, one of "http", "https", "ws", "wss",
+///     "verkey": string, (optional) recipient verkey of the endpoint (DID verkey is used by default),
+///     "routingKeys": [string], (optional) list of routing verkeys,
+///     "priority": int, (optional) endpoint priority (0 by default)
+///   }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_set_named_endpoint_for_did(command_handle: CommandHandle,
+                                              wallet_handle: WalletHandle,
+                                              did: *const c_char,
+                                              endpoint_json: *const c_char,
+                                              cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                   err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_set_named_endpoint_for_did: >>> wallet_handle: {:?}, did: {:?}, endpoint_json: {:?}", wallet_handle, did, endpoint_json);
+
+    command_params!(wallet_handle, did, endpoint_json);
+
+    check_useful_validatable_string!(did, ErrorCode::CommonInvalidParam3, DidValue);
+    check_useful_validatable_json!(endpoint_json, ErrorCode::CommonInvalidParam4, NamedEndpoint);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_set_named_endpoint_for_did: entities >>> wallet_handle: {:?}, did: {:?}, endpoint_json: {:?}", wallet_handle, did, endpoint_json);
+
+    let result = CommandExecutor::submit(Command::Did(DidCommand::SetNamedEndpointForDid(
+            wallet_handle,
+            did,
+            endpoint_json,
+            with_timeout(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_set_named_endpoint_for_did:");
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_set_named_endpoint_for_did: <<< res: {:?}", res);
+
+    res
+}
+
+/// Removes named endpoint set by "indy_set_named_endpoint_for_did" from the DID stored in the wallet.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: Wallet handle (created by open_wallet).
+/// did - The DID (my or their) stored in the wallet.
+/// name - Name of the endpoint to remove.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_remove_named_endpoint_for_did(command_handle: CommandHandle,
+                                                 wallet_handle: WalletHandle,
+                                                 did: *const c_char,
+                                                 name: *const c_char,
+                                                 cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                      err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_remove_named_endpoint_for_did: >>> wallet_handle: {:?}, did: {:?}, name: {:?}", wallet_handle, did, name);
+
+    command_params!(wallet_handle, did, name);
+
+    check_useful_validatable_string!(did, ErrorCode::CommonInvalidParam3, DidValue);
+    check_useful_c_str!(name, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_remove_named_endpoint_for_did: entities >>> wallet_handle: {:?}, did: {:?}, name: {:?}", wallet_handle, did, name);
+
+    let result = CommandExecutor::submit(Command::Did(DidCommand::RemoveNamedEndpointForDid(
+            wallet_handle,
+            did,
+            name,
+            with_timeout(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_remove_named_endpoint_for_did:");
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_remove_named_endpoint_for_did: <<< res: {:?}", res);
+
+    res
+}
+
+/// Returns named endpoints of the DID.
+/// Endpoints are taken from the wallet if they are stored there,
+/// otherwise "endpoints" attrib is fetched from the ledger and cached in the wallet.
+/// The ledger is never queried for DIDs owned by the caller of the library.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: Wallet handle (created by open_wallet).
+/// pool_handle: Pool handle (created by open_pool).
+/// did - The DID to resolve endpoints.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+/// - endpoints_json - list of endpoints as json (see indy_set_named_endpoint_for_did for endpoint format).
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+/// Ledger*
+#[cfg(feature = "ledger")]
+#[no_mangle]
+pub extern fn indy_get_named_endpoints_for_did(command_handle: CommandHandle,
+                                               wallet_handle: WalletHandle,
+                                               pool_handle: PoolHandle,
+                                               did: *const c_char,
+                                               cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                    err: ErrorCode,
+                                                                    endpoints_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_get_named_endpoints_for_did: >>> wallet_handle: {:?}, pool_handle: {:?}, did: {:?}", wallet_handle, pool_handle, did);
+
+    command_params!(wallet_handle, pool_handle, did);
+
+    check_useful_validatable_string!(did, ErrorCode::CommonInvalidParam4, DidValue);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_get_named_endpoints_for_did: entities >>> wallet_handle: {:?}, pool_handle: {:?}, did: {:?}", wallet_handle, pool_handle, did);
+
+    let result = CommandExecutor::submit(Command::Did(DidCommand::GetNamedEndpointsForDid(
+            wallet_handle,
+            pool_handle,
+            did,
+            with_timeout(boxed_callback_string!("indy_get_named_endpoints_for_did", cb, command_handle))
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_get_named_endpoints_for_did: <<< res: {:?}", res);
+
+    res
+}
+
+/// Builds an ATTRIB request publishing named endpoints of the DID stored in the wallet
+/// as "endpoints" raw attrib:
+///   {"endpoints": [<endpoint_json>, ...]}
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: Wallet handle (created by open_wallet).
+/// submitter_did: Identifier (DID) of the transaction author as base58-encoded string.
+/// target_did: Target DID whose named endpoints are published.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+/// - request_json: Request result as json.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[cfg(feature = "ledger")]
+#[no_mangle]
+pub extern fn indy_build_named_endpoints_attrib_request(command_handle: CommandHandle,
+                                                        wallet_handle: WalletHandle,
+                                                        submitter_did: *const c_char,
+                                                        target_did: *const c_char,
+                                                        cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                             err: ErrorCode,
+                                                                             request_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_build_named_endpoints_attrib_request: >>> wallet_handle: {:?}, submitter_did: {:?}, target_did: {:?}", wallet_handle, submitter_did, target_did);
+
+    command_params!(wallet_handle, submitter_did, target_did);
+
+    check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam3, DidValue);
+    check_useful_validatable_string!(target_did, ErrorCode::CommonInvalidParam4, DidValue);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_build_named_endpoints_attrib_request: entities >>> wallet_handle: {:?}, submitter_did: {:?}, target_did: {:?}", wallet_handle, submitter_did, target_did);
+
+    let result = CommandExecutor::submit(Command::Did(DidCommand::BuildNamedEndpointsAttribRequest(
+            wallet_handle,
+            submitter_did,
+            target_did,
+            with_timeout(boxed_callback_string!("indy_build_named_endpoints_attrib_request", cb, command_handle))
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_build_named_endpoints_attrib_request: <<< res: {:?}", res);
+
+    res
+}
+
+/// Exports DID owned by the caller of the library with all related wallet records
+/// (keys, temporary keys, metadata, tags, endpoint, additional keys and services,
+/// pairwise records with their DIDs) as an encrypted bundle.
+/// The bundle is encrypted by anonymous-encryption scheme for the recipient key
+/// that must be stored in the destination wallet (see indy_create_key).
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// did: DID to export.
+/// recipient_vk: verkey of the key stored in the destination wallet.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+/// - bundle_raw: a pointer to first byte of encrypted DID bundle
+/// - bundle_len: an encrypted DID bundle length
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_export_did(command_handle: CommandHandle,
+                              wallet_handle: WalletHandle,
+                              did: *const c_char,
+                              recipient_vk: *const c_char,
+                              cb: Option<extern fn(command_handle_: CommandHandle,
+                                                   err: ErrorCode,
+                                                   bundle_raw: *const u8,
+                                                   bundle_len: u32)>) -> ErrorCode {
+    trace!("indy_export_did: >>> wallet_handle: {:?}, did: {:?}, recipient_vk: {:?}", wallet_handle, did, recipient_vk);
+
+    command_params!(wallet_handle, did, recipient_vk);
+
+    check_useful_validatable_string!(did, ErrorCode::CommonInvalidParam3, DidValue);
+    check_useful_c_str!(recipient_vk, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_export_did: entities >>> wallet_handle: {:?}, did: {:?}, recipient_vk: {:?}", wallet_handle, did, recipient_vk);
+
+    let result = CommandExecutor::submit(Command::Did(DidCommand::ExportDid(
+            wallet_handle,
+            did,
+            recipient_vk,
+            with_timeout(move |result| {
+                let (err, bundle) = prepare_result_1!(result, Vec::new());
+                trace!("indy_export_did: bundle: {:?}", bundle);
+                let (bundle_raw, bundle_len) = ctypes::vec_to_pointer(&bundle);
+                cb(command_handle, err, bundle_raw, bundle_len)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_export_did: <<< res: {:?}", res);
+
+    res
+}
+
+/// Imports DID bundle created by indy_export_did into the wallet.
+/// Fails without changing the wallet if the DID, their DIDs or pairwise records from the bundle already exist.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// recipient_vk: id (verkey) of my key the bundle is encrypted for.
+/// bundle_raw: a pointer to first byte of encrypted DID bundle
+/// bundle_len: an encrypted DID bundle length
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+/// - did: imported DID
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_import_did(command_handle: CommandHandle,
+                              wallet_handle: WalletHandle,
+                              recipient_vk: *const c_char,
+                              bundle_raw: *const u8,
+                              bundle_len: u32,
+                              cb: Option<extern fn(command_handle_: CommandHandle,
+                                                   err: ErrorCode,
+                                                   did: *const c_char)>) -> ErrorCode {
+    trace!("indy_import_did: >>> wallet_handle: {:?}, recipient_vk: {:?}, bundle_raw: {:?}, bundle_len: {:?}", wallet_handle, recipient_vk, bundle_raw, bundle_len);
+
+    command_params!(wallet_handle, recipient_vk, (bundle_raw, bundle_len));
+
+    check_useful_c_str!(recipient_vk, ErrorCode::CommonInvalidParam3);
+    check_useful_c_byte_array!(bundle_raw, bundle_len, ErrorCode::CommonInvalidParam4, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_import_did: entities >>> wallet_handle: {:?}, recipient_vk: {:?}, bundle_raw: {:?}", wallet_handle, recipient_vk, bundle_raw);
+
+    let result = CommandExecutor::submit(Command::Did(DidCommand::ImportDid(
+            wallet_handle,
+            recipient_vk,
+            bundle_raw,
+            with_timeout(boxed_callback_string!("indy_import_did", cb, command_handle))
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_import_did: <<< res: {:?}", res);
+
+    res
+}
+
+/// Marks DID (my or their) stored in the wallet as deactivated.
+/// Ledger requests are not signed by deactivated DID anymore (see "indy_force_sign_request" to sign it anyway).
+/// DID Document returned by "indy_get_did_doc" and DID info returned by "indy_get_my_did_with_meta"
+/// contain "deactivated": true field for deactivated DID.
+///
+/// Note that this call does not change the ledger state. Use "indy_publish_did_deactivation" for this.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// did: DID to deactivate.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_deactivate_did(command_handle: CommandHandle,
+                                  wallet_handle: WalletHandle,
+                                  did: *const c_char,
+                                  cb: Option<extern fn(command_handle_: CommandHandle,
+                                                       err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_deactivate_did: >>> wallet_handle: {:?}, did: {:?}", wallet_handle, did);
+
+    command_params!(wallet_handle, did);
+
+    check_useful_validatable_string!(did, ErrorCode::CommonInvalidParam3, DidValue);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_deactivate_did: entities >>> wallet_handle: {:?}, did: {:?}", wallet_handle, did);
+
+    let result = CommandExecutor::submit(Command::Did(DidCommand::DeactivateDid(
+            wallet_handle,
+            did,
+            with_timeout(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_deactivate_did:");
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_deactivate_did: <<< res: {:?}", res);
+
+    res
+}
+
+/// Publishes deactivation of DID (owned by the caller of the library) to the ledger:
+///     - builds NYM request with null verkey that removes DID verkey from the ledger
+///       so the DID can't sign transactions anymore,
+///     - signs it with the DID key (even if the DID is already deactivated in the wallet) and sends it to the ledger,
+///     - marks DID as deactivated in the wallet (as "indy_deactivate_did" does) on successful reply.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// did: DID to deactivate.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+/// - response: Ledger response json
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+/// Ledger*
+/// Pool*
+#[cfg(feature = "ledger")]
+#[no_mangle]
+pub extern fn indy_publish_did_deactivation(command_handle: CommandHandle,
+                                            wallet_handle: WalletHandle,
+                                            pool_handle: PoolHandle,
+                                            did: *const c_char,
+                                            cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                 err: ErrorCode,
+                                                                 response: *const c_char)>) -> ErrorCode {
+    trace!("indy_publish_did_deactivation: >>> wallet_handle: {:?}, pool_handle: {:?}, did: {:?}", wallet_handle, pool_handle, did);
+
+    command_params!(wallet_handle, pool_handle, did);
+
+    check_useful_validatable_string!(did, ErrorCode::CommonInvalidParam4, DidValue);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_publish_did_deactivation: entities >>> wallet_handle: {:?}, pool_handle: {:?}, did: {:?}", wallet_handle, pool_handle, did);
+
+    let result = CommandExecutor::submit(Command::Did(DidCommand::PublishDidDeactivation(
+            wallet_handle,
+            pool_handle,
+            did,
+            with_timeout(boxed_callback_string!("indy_publish_did_deactivation", cb, command_handle))
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_publish_did_deactivation: <<< res: {:?}", res);
+
+    res
+}
+
+context_api! {
+    indy_create_and_store_my_did_ctx => indy_create_and_store_my_did(wallet_handle: WalletHandle, did_info: *const c_char)
+        -> cb(did: *const c_char, verkey: *const c_char), CommonInvalidParam4;
+    indy_replace_keys_start_ctx => indy_replace_keys_start(wallet_handle: WalletHandle, did: *const c_char, key_info: *const c_char)
+        -> cb(verkey: *const c_char), CommonInvalidParam5;
+    indy_replace_keys_apply_ctx => indy_replace_keys_apply(wallet_handle: WalletHandle, did: *const c_char)
+        -> cb(), CommonInvalidParam4;
+    #[cfg(feature = "ledger")]
+    indy_rotate_key_ctx => indy_rotate_key(wallet_handle: WalletHandle, pool_handle: PoolHandle, did: *const c_char, key_info: *const c_char, endorser_did: *const c_char)
+        -> cb(verkey: *const c_char), CommonInvalidParam7;
+    indy_store_their_did_ctx => indy_store_their_did(wallet_handle: WalletHandle, identity_json: *const c_char)
+        -> cb(), CommonInvalidParam4;
+    #[cfg(feature = "ledger")]
+    indy_key_for_did_ctx => indy_key_for_did(pool_handle: PoolHandle, wallet_handle: WalletHandle, did: *const c_char)
+        -> cb(key: *const c_char), CommonInvalidParam5;
+    indy_key_for_local_did_ctx => indy_key_for_local_did(wallet_handle: WalletHandle, did: *const c_char)
+        -> cb(key: *const c_char), CommonInvalidParam4;
+    indy_set_endpoint_for_did_ctx => indy_set_endpoint_for_did(wallet_handle: WalletHandle, did: *const c_char, address: *const c_char, transport_key: *const c_char)
+        -> cb(), CommonInvalidParam6;
+    #[cfg(feature = "ledger")]
+    indy_get_endpoint_for_did_ctx => indy_get_endpoint_for_did(wallet_handle: WalletHandle, pool_handle: PoolHandle, did: *const c_char)
+        -> cb(address: *const c_char, transport_vk: *const c_char), CommonInvalidParam5;
+    indy_set_did_metadata_ctx => indy_set_did_metadata(wallet_handle: WalletHandle, did: *const c_char, metadata: *const c_char)
+        -> cb(), CommonInvalidParam5;
+    indy_get_did_metadata_ctx => indy_get_did_metadata(wallet_handle: WalletHandle, did: *const c_char)
+        -> cb(metadata: *const c_char), CommonInvalidParam4;
+    indy_get_my_did_with_meta_ctx => indy_get_my_did_with_meta(wallet_handle: WalletHandle, my_did: *const c_char)
+        -> cb(did_with_meta: *const c_char), CommonInvalidParam4;
+    indy_list_my_dids_with_meta_ctx => indy_list_my_dids_with_meta(wallet_handle: WalletHandle)
+        -> cb(dids: *const c_char), CommonInvalidParam3;
+    indy_set_my_did_tags_ctx => indy_set_my_did_tags(wallet_handle: WalletHandle, did: *const c_char, tags_json: *const c_char)
+        -> cb(), CommonInvalidParam5;
+    indy_open_my_dids_search_ctx => indy_open_my_dids_search(wallet_handle: WalletHandle, query_json: *const c_char, options_json: *const c_char)
+        -> cb(search_handle: SearchHandle, total_count: usize), CommonInvalidParam5;
+    indy_fetch_my_dids_ctx => indy_fetch_my_dids(search_handle: SearchHandle, count: usize)
+        -> cb(dids: *const c_char), CommonInvalidParam4;
+    indy_close_my_dids_search_ctx => indy_close_my_dids_search(search_handle: SearchHandle)
+        -> cb(), CommonInvalidParam3;
+    indy_abbreviate_verkey_ctx => indy_abbreviate_verkey(did: *const c_char, full_verkey: *const c_char)
+        -> cb(verkey: *const c_char), CommonInvalidParam4;
+    indy_qualify_did_ctx => indy_qualify_did(wallet_handle: WalletHandle, did: *const c_char, method: *const c_char)
+        -> cb(full_qualified_did: *const c_char), CommonInvalidParam5;
+    indy_qualify_all_dids_ctx => indy_qualify_all_dids(wallet_handle: WalletHandle, method: *const c_char, options_json: *const c_char)
+        -> cb(report_json: *const c_char), CommonInvalidParam5;
+    #[cfg(feature = "ledger")]
+    indy_get_did_doc_ctx => indy_get_did_doc(wallet_handle: WalletHandle, pool_handle: PoolHandle, did: *const c_char)
+        -> cb(did_doc: *const c_char), CommonInvalidParam5;
+    indy_add_did_key_ctx => indy_add_did_key(wallet_handle: WalletHandle, did: *const c_char, key_json: *const c_char)
+        -> cb(), CommonInvalidParam5;
+    indy_remove_did_key_ctx => indy_remove_did_key(wallet_handle: WalletHandle, did: *const c_char, key_id: *const c_char)
+        -> cb(), CommonInvalidParam5;
+    indy_add_did_service_ctx => indy_add_did_service(wallet_handle: WalletHandle, did: *const c_char, service_json: *const c_char)
+        -> cb(), CommonInvalidParam5;
+    indy_remove_did_service_ctx => indy_remove_did_service(wallet_handle: WalletHandle, did: *const c_char, service_id: *const c_char)
+        -> cb(), CommonInvalidParam5;
+    indy_set_named_endpoint_for_did_ctx => indy_set_named_endpoint_for_did(wallet_handle: WalletHandle, did: *const c_char, endpoint_json: *const c_char)
+        -> cb(), CommonInvalidParam5;
+    indy_remove_named_endpoint_for_did_ctx => indy_remove_named_endpoint_for_did(wallet_handle: WalletHandle, did: *const c_char, name: *const c_char)
+        -> cb(), CommonInvalidParam5;
+    #[cfg(feature = "ledger")]
+    indy_get_named_endpoints_for_did_ctx => indy_get_named_endpoints_for_did(wallet_handle: WalletHandle, pool_handle: PoolHandle, did: *const c_char)
+        -> cb(endpoints_json: *const c_char), CommonInvalidParam5;
+    #[cfg(feature = "ledger")]
+    indy_build_named_endpoints_attrib_request_ctx => indy_build_named_endpoints_attrib_request(wallet_handle: WalletHandle, submitter_did: *const c_char, target_did: *const c_char)
+        -> cb(request_json: *const c_char), CommonInvalidParam5;
+    indy_export_did_ctx => indy_export_did(wallet_handle: WalletHandle, did: *const c_char, recipient_vk: *const c_char)
+        -> cb(bundle_raw: *const u8, bundle_len: u32), CommonInvalidParam5;
+    indy_import_did_ctx => indy_import_did(wallet_handle: WalletHandle, recipient_vk: *const c_char, bundle_raw: *const u8, bundle_len: u32)
+        -> cb(did: *const c_char), CommonInvalidParam6;
+    indy_deactivate_did_ctx => indy_deactivate_did(wallet_handle: WalletHandle, did: *const c_char)
+        -> cb(), CommonInvalidParam4;
+    #[cfg(feature = "ledger")]
+    indy_publish_did_deactivation_ctx => indy_publish_did_deactivation(wallet_handle: WalletHandle, pool_handle: PoolHandle, did: *const c_char)
+        -> cb(response: *const c_char), CommonInvalidParam5;
+}
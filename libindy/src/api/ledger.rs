@@ -1,21 +1,27 @@
-use indy_api_types::{CommandHandle, ErrorCode, PoolHandle, WalletHandle};
+use indy_api_types::{CommandHandle, ErrorCode, IndyHandle, PoolHandle, WalletHandle};
 use indy_api_types::errors::prelude::*;
 use indy_api_types::validation::Validatable;
 use indy_utils::ctypes;
 use libc::c_char;
+use std::collections::HashMap;
 use serde_json;
 
 use crate::commands::{Command, CommandExecutor};
+use crate::commands::timeout::with_timeout;
 use crate::commands::ledger::LedgerCommand;
 use crate::domain::anoncreds::credential_definition::{CredentialDefinition, CredentialDefinitionId};
 use crate::domain::anoncreds::revocation_registry_definition::{RevocationRegistryDefinition, RevocationRegistryId};
 use crate::domain::anoncreds::revocation_registry_delta::RevocationRegistryDelta;
 use crate::domain::anoncreds::schema::{Schema, SchemaId};
 use crate::domain::crypto::did::DidValue;
-use crate::domain::ledger::auth_rule::{AuthRules, Constraint};
+use crate::domain::ledger::attrib::Endpoint;
+use crate::domain::ledger::auth_rule::{AuthRule, AuthRuleAction, AuthRules, AuthRulesPageOptions, Constraint, RequestSigner};
 use crate::domain::ledger::author_agreement::{AcceptanceMechanisms, GetTxnAuthorAgreementData};
 use crate::domain::ledger::node::NodeOperationData;
-use crate::domain::ledger::pool::Schedule;
+use crate::domain::ledger::nym::NymRequestData;
+use crate::domain::ledger::pool::{PoolUpgradeScheduleData, Schedule};
+use crate::domain::ledger::txn::LedgerEventsFilter;
+use crate::domain::pool::SubmitRequestOptions;
 
 /// Signs and submits request message to validator pool.
 ///
@@ -51,6 +57,8 @@ pub extern fn indy_sign_and_submit_request(command_handle: CommandHandle,
     trace!("indy_sign_and_submit_request: >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, request_json: {:?}",
            pool_handle, wallet_handle, submitter_did, request_json);
 
+    command_params!(pool_handle, wallet_handle, submitter_did, request_json);
+
     check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam3, DidValue);
     check_useful_c_str!(request_json, ErrorCode::CommonInvalidParam4);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
@@ -58,13 +66,12 @@ pub extern fn indy_sign_and_submit_request(command_handle: CommandHandle,
     trace!("indy_sign_and_submit_request: entities >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, request_json: {:?}",
            pool_handle, wallet_handle, submitter_did, request_json);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::SignAndSubmitRequest(
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::SignAndSubmitRequest(
             pool_handle,
             wallet_handle,
             submitter_did,
             request_json,
-            boxed_callback_string!("indy_sign_and_submit_request", cb, command_handle)
+            with_timeout(boxed_callback_string!("indy_sign_and_submit_request", cb, command_handle))
         )));
 
     let res = prepare_result!(result);
@@ -99,16 +106,17 @@ pub extern fn indy_submit_request(command_handle: CommandHandle,
                                                        request_result_json: *const c_char)>) -> ErrorCode {
     trace!("indy_submit_request: >>> pool_handle: {:?}, request_json: {:?}", pool_handle, request_json);
 
+    command_params!(pool_handle, request_json);
+
     check_useful_c_str!(request_json, ErrorCode::CommonInvalidParam3);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     trace!("indy_submit_request: entities >>> pool_handle: {:?}, request_json: {:?}", pool_handle, request_json);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::SubmitRequest(
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::SubmitRequest(
             pool_handle,
             request_json,
-            boxed_callback_string!("indy_submit_request", cb, command_handle)
+            with_timeout(boxed_callback_string!("indy_submit_request", cb, command_handle))
         )));
 
     let res = prepare_result!(result);
@@ -118,6 +126,437 @@ pub extern fn indy_submit_request(command_handle: CommandHandle,
     res
 }
 
+/// Publishes request message to validator pool (no signing) with per-request reply timeout and priority.
+///
+/// High priority requests are sent before the normal priority requests still waiting in the queue
+/// of the pool worker, so short reads aren't stuck behind the requests submitted earlier.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// request_json: Request data json.
+/// options_json: submit options
+///     {
+///         "timeout": <int> (optional) - time in seconds to wait for the replies (ACK for write requests).
+///                                       Overrides pool `timeout` for this request.
+///         "priority": <string> (optional) - "normal" (default) or "high".
+///     }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Request result as json.
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_submit_request_with_options(command_handle: CommandHandle,
+                                               pool_handle: PoolHandle,
+                                               request_json: *const c_char,
+                                               options_json: *const c_char,
+                                               cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                    err: ErrorCode,
+                                                                    request_result_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_submit_request_with_options: >>> pool_handle: {:?}, request_json: {:?}, options_json: {:?}", pool_handle, request_json, options_json);
+
+    command_params!(pool_handle, request_json, options_json);
+
+    check_useful_c_str!(request_json, ErrorCode::CommonInvalidParam3);
+    check_useful_validatable_json!(options_json, ErrorCode::CommonInvalidParam4, SubmitRequestOptions);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_submit_request_with_options: entities >>> pool_handle: {:?}, request_json: {:?}, options_json: {:?}", pool_handle, request_json, options_json);
+
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::SubmitRequestWithOptions(
+            pool_handle,
+            request_json,
+            options_json,
+            with_timeout(boxed_callback_string!("indy_submit_request_with_options", cb, command_handle))
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_submit_request_with_options: <<< res: {:?}", res);
+
+    res
+}
+
+/// Publishes signed SCHEMA or CRED_DEF request message to validator pool treating rejection
+/// of already existing ledger entity as success.
+///
+/// Intended for retrying of the writes failed with timeout: if the pool rejects the request
+/// because the entity already exists, the existing transaction is fetched from the ledger and returned instead.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// request_json: Signed SCHEMA or CRED_DEF request data json.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Submission result json:
+///     {
+///         "alreadyExists": <bool> - whether the entity was written before,
+///         "response": <object> - reply to the write request or reply to the read request fetching existing entity
+///     }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_submit_request_idempotent(command_handle: CommandHandle,
+                                             pool_handle: PoolHandle,
+                                             request_json: *const c_char,
+                                             cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                  err: ErrorCode,
+                                                                  submit_result_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_submit_request_idempotent: >>> pool_handle: {:?}, request_json: {:?}", pool_handle, request_json);
+
+    command_params!(pool_handle, request_json);
+
+    check_useful_c_str!(request_json, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_submit_request_idempotent: entities >>> pool_handle: {:?}, request_json: {:?}", pool_handle, request_json);
+
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::SubmitRequestIdempotent(
+            pool_handle,
+            request_json,
+            with_timeout(boxed_callback_string!("indy_submit_request_idempotent", cb, command_handle))
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_submit_request_idempotent: <<< res: {:?}", res);
+
+    res
+}
+
+/// Publishes several request messages to validator pool at once (no signing).
+///
+/// Requests are sent over the same pool connections concurrently and results are returned in the order of requests.
+/// Failure of one request doesn't affect other requests of the batch.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// requests_json: json array of prepared requests.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Json array of results in the same order as requests:
+/// [
+///     {
+///         "response": <string> - request result json (set if request succeed),
+///         "error": {            - (set if request failed)
+///             "code": <int> - error code,
+///             "message": <string> - error message
+///         }
+///     }
+/// ]
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_submit_requests(command_handle: CommandHandle,
+                                   pool_handle: PoolHandle,
+                                   requests_json: *const c_char,
+                                   cb: Option<extern fn(command_handle_: CommandHandle,
+                                                        err: ErrorCode,
+                                                        responses_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_submit_requests: >>> pool_handle: {:?}, requests_json: {:?}", pool_handle, requests_json);
+
+    command_params!(pool_handle, requests_json);
+
+    check_useful_json!(requests_json, ErrorCode::CommonInvalidParam3, Vec<serde_json::Value>);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_submit_requests: entities >>> pool_handle: {:?}, requests_json: {:?}", pool_handle, requests_json);
+
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::SubmitRequests(
+            pool_handle,
+            requests_json,
+            with_timeout(boxed_callback_string!("indy_submit_requests", cb, command_handle))
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_submit_requests: <<< res: {:?}", res);
+
+    res
+}
+
+/// Fetches a range of transactions from the ledger in one call.
+///
+/// GET_TXN requests for the range are sent to the pool by batches of 100 concurrent requests.
+/// Use `indy_stream_txns` to get transactions of large ranges without keeping them all in memory.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// submitter_did: (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+/// ledger_type: (Optional) type of the ledger the requested transactions belong to:
+///     DOMAIN - used default,
+///     POOL,
+///     CONFIG
+///     any number
+/// from: sequence number of the first requested transaction.
+/// to: sequence number of the last requested transaction.
+///     Fetching stops at the end of the ledger, so i32::MAX can be passed to get all the transactions since `from`.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Json array of transactions ordered by sequence number (transactions not found on the ledger are omitted):
+/// [
+///     {
+///         "data": <object> - transaction data,
+///         "seqNo": <int> - transaction sequence number,
+///         "txnTime": <int> - transaction ordering time
+///     }
+/// ]
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_get_txns(command_handle: CommandHandle,
+                            pool_handle: PoolHandle,
+                            submitter_did: *const c_char,
+                            ledger_type: *const c_char,
+                            from: i32,
+                            to: i32,
+                            cb: Option<extern fn(command_handle_: CommandHandle,
+                                                 err: ErrorCode,
+                                                 txns_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_get_txns: >>> pool_handle: {:?}, submitter_did: {:?}, ledger_type: {:?}, from: {:?}, to: {:?}",
+           pool_handle, submitter_did, ledger_type, from, to);
+
+    command_params!(pool_handle, submitter_did, ledger_type, from, to);
+
+    check_useful_validatable_opt_string!(submitter_did, ErrorCode::CommonInvalidParam3, DidValue);
+    check_useful_opt_c_str!(ledger_type, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    trace!("indy_get_txns: entities >>> pool_handle: {:?}, submitter_did: {:?}, ledger_type: {:?}, from: {:?}, to: {:?}",
+           pool_handle, submitter_did, ledger_type, from, to);
+
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::GetTxns(
+            pool_handle,
+            submitter_did,
+            ledger_type,
+            from,
+            to,
+            with_timeout(boxed_callback_string!("indy_get_txns", cb, command_handle))
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_get_txns: <<< res: {:?}", res);
+
+    res
+}
+
+/// Streams a range of transactions from the ledger.
+///
+/// GET_TXN requests for the range are sent to the pool by batches of 100 concurrent requests
+/// and transactions of every batch are passed to `txns_cb` as soon as the batch is completed.
+/// `cb` is called once all the transactions are streamed or fetching is failed.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// submitter_did: (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+/// ledger_type: (Optional) type of the ledger the requested transactions belong to:
+///     DOMAIN - used default,
+///     POOL,
+///     CONFIG
+///     any number
+/// from: sequence number of the first requested transaction.
+/// to: sequence number of the last requested transaction.
+///     Fetching stops at the end of the ledger, so i32::MAX can be passed to get all the transactions since `from`.
+/// txns_cb: Callback that takes json array of transactions of a batch ordered by sequence number:
+///     [
+///         {
+///             "data": <object> - transaction data,
+///             "seqNo": <int> - transaction sequence number,
+///             "txnTime": <int> - transaction ordering time
+///         }
+///     ]
+///     Transactions not found on the ledger are omitted.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error code
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_stream_txns(command_handle: CommandHandle,
+                               pool_handle: PoolHandle,
+                               submitter_did: *const c_char,
+                               ledger_type: *const c_char,
+                               from: i32,
+                               to: i32,
+                               txns_cb: Option<extern fn(command_handle_: CommandHandle,
+                                                         txns_json: *const c_char)>,
+                               cb: Option<extern fn(command_handle_: CommandHandle,
+                                                    err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_stream_txns: >>> pool_handle: {:?}, submitter_did: {:?}, ledger_type: {:?}, from: {:?}, to: {:?}",
+           pool_handle, submitter_did, ledger_type, from, to);
+
+    command_params!(pool_handle, submitter_did, ledger_type, from, to);
+
+    check_useful_validatable_opt_string!(submitter_did, ErrorCode::CommonInvalidParam3, DidValue);
+    check_useful_opt_c_str!(ledger_type, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(txns_cb, ErrorCode::CommonInvalidParam7);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam8);
+
+    trace!("indy_stream_txns: entities >>> pool_handle: {:?}, submitter_did: {:?}, ledger_type: {:?}, from: {:?}, to: {:?}",
+           pool_handle, submitter_did, ledger_type, from, to);
+
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::StreamTxns(
+            pool_handle,
+            submitter_did,
+            ledger_type,
+            from,
+            to,
+            Box::new(move |txns_json| {
+                trace!("indy_stream_txns: txns: {:?}", txns_json);
+                let txns_json = ctypes::string_to_cstring(txns_json);
+                txns_cb(command_handle, txns_json.as_ptr())
+            }),
+            with_timeout(move |res| {
+                let res = prepare_result!(res);
+                trace!("indy_stream_txns: res: {:?}", res);
+                cb(command_handle, res)
+            }),
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_stream_txns: <<< res: {:?}", res);
+
+    res
+}
+
+/// Subscribes to notifications about new transactions written to the ledger.
+///
+/// EXPERIMENTAL
+///
+/// The ledger is polled by GET_TXN requests starting from `fromSeqNo` transaction
+/// and `event_cb` is called for every transaction matching the filter in order of sequence numbers.
+/// Polling is stopped once the subscription is cancelled by `indy_unsubscribe_ledger_events` or the pool is closed.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// filter_json: filter of transactions to notify about:
+///     {
+///         "dids": [<string>, ...], - (Optional) DIDs to notify about transactions authored by or targeting them
+///         "txnTypes": [<string>, ...], - (Optional) types of transactions to notify about (f.e. NYM or 1)
+///         "ledgerType": <string>, - (Optional) type of the ledger to watch (DOMAIN - used default, POOL, CONFIG or any number)
+///         "fromSeqNo": <int>, - (Optional) sequence number of the first transaction to check (1 - used default)
+///         "pollInterval": <int>, - (Optional) interval in seconds between polls of the ledger (10 - used default)
+///     }
+///     Empty list of DIDs or transaction types matches any transaction.
+/// event_cb: Callback that takes subscription handle and matched transaction json:
+///     {
+///         "data": <object> - transaction data,
+///         "seqNo": <int> - transaction sequence number,
+///         "txnTime": <int> - transaction ordering time
+///     }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Handle of the subscription.
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_subscribe_ledger_events(command_handle: CommandHandle,
+                                           pool_handle: PoolHandle,
+                                           filter_json: *const c_char,
+                                           event_cb: Option<extern fn(subscription_handle: IndyHandle,
+                                                                      event_json: *const c_char)>,
+                                           cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                err: ErrorCode,
+                                                                subscription_handle: IndyHandle)>) -> ErrorCode {
+    trace!("indy_subscribe_ledger_events: >>> pool_handle: {:?}, filter_json: {:?}", pool_handle, filter_json);
+
+    command_params!(pool_handle, filter_json);
+
+    check_useful_validatable_json!(filter_json, ErrorCode::CommonInvalidParam3, LedgerEventsFilter);
+    check_useful_c_callback!(event_cb, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_subscribe_ledger_events: entities >>> pool_handle: {:?}, filter_json: {:?}", pool_handle, filter_json);
+
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::SubscribeLedgerEvents(
+            pool_handle,
+            filter_json,
+            Box::new(move |subscription_handle, event_json| {
+                trace!("indy_subscribe_ledger_events: event: {:?}", event_json);
+                let event_json = ctypes::string_to_cstring(event_json);
+                event_cb(subscription_handle, event_json.as_ptr())
+            }),
+            with_timeout(move |result| {
+                let (err, subscription_handle) = prepare_result_1!(result, 0);
+                trace!("indy_subscribe_ledger_events: subscription_handle: {:?}", subscription_handle);
+                cb(command_handle, err, subscription_handle)
+            }),
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_subscribe_ledger_events: <<< res: {:?}", res);
+
+    res
+}
+
+/// Cancels subscription to ledger events created by `indy_subscribe_ledger_events`.
+///
+/// EXPERIMENTAL
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// subscription_handle: handle of the subscription.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error code
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_unsubscribe_ledger_events(command_handle: CommandHandle,
+                                             subscription_handle: IndyHandle,
+                                             cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                  err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_unsubscribe_ledger_events: >>> subscription_handle: {:?}", subscription_handle);
+
+    command_params!(subscription_handle);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::UnsubscribeLedgerEvents(
+            subscription_handle,
+            with_timeout(move |res| {
+                let res = prepare_result!(res);
+                trace!("indy_unsubscribe_ledger_events: res: {:?}", res);
+                cb(command_handle, res)
+            }),
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_unsubscribe_ledger_events: <<< res: {:?}", res);
+
+    res
+}
+
 /// Send action to particular nodes of validator pool.
 ///
 /// The list of requests can be send:
@@ -153,6 +592,8 @@ pub extern fn indy_submit_action(command_handle: CommandHandle,
                                                       request_result_json: *const c_char)>) -> ErrorCode {
     trace!("indy_submit_action: >>> pool_handle: {:?}, request_json: {:?}, nodes: {:?}, timeout: {:?}", pool_handle, request_json, nodes, timeout);
 
+    command_params!(pool_handle, request_json, nodes, timeout);
+
     check_useful_c_str!(request_json, ErrorCode::CommonInvalidParam3);
     check_useful_opt_c_str!(nodes, ErrorCode::CommonInvalidParam4);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
@@ -161,14 +602,13 @@ pub extern fn indy_submit_action(command_handle: CommandHandle,
 
     trace!("indy_submit_action: entities >>> pool_handle: {:?}, request_json: {:?}, nodes: {:?}, timeout: {:?}", pool_handle, request_json, nodes, timeout);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Ledger(
+    let result = CommandExecutor::submit(Command::Ledger(
             LedgerCommand::SubmitAction(
                 pool_handle,
                 request_json,
                 nodes,
                 timeout,
-                boxed_callback_string!("indy_submit_action", cb, command_handle)
+                with_timeout(boxed_callback_string!("indy_submit_action", cb, command_handle))
             )));
 
     let res = prepare_result!(result);
@@ -208,18 +648,19 @@ pub extern fn indy_sign_request(command_handle: CommandHandle,
                                                      signed_request_json: *const c_char)>) -> ErrorCode {
     trace!("indy_sign_request: >>> wallet_handle: {:?}, submitter_did: {:?}, request_json: {:?}", wallet_handle, submitter_did, request_json);
 
+    command_params!(wallet_handle, submitter_did, request_json);
+
     check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
     check_useful_c_str!(request_json, ErrorCode::CommonInvalidParam3);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     trace!("indy_sign_request: entities >>> wallet_handle: {:?}, submitter_did: {:?}, request_json: {:?}", wallet_handle, submitter_did, request_json);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::SignRequest(
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::SignRequest(
             wallet_handle,
             submitter_did,
             request_json,
-            boxed_callback_string!("indy_sign_request", cb, command_handle)
+            with_timeout(boxed_callback_string!("indy_sign_request", cb, command_handle))
         )));
 
     let res = prepare_result!(result);
@@ -229,10 +670,8 @@ pub extern fn indy_sign_request(command_handle: CommandHandle,
     res
 }
 
-/// Multi signs request message.
-///
-/// Adds submitter information to passed request json, signs it with submitter
-/// sign key (see wallet_sign).
+/// Signs request message as "indy_sign_request" does but even if submitter DID is deactivated
+/// (see "indy_deactivate_did").
 ///
 /// #Params
 /// command_handle: command handle to map callback to caller context.
@@ -250,26 +689,79 @@ pub extern fn indy_sign_request(command_handle: CommandHandle,
 /// Ledger*
 /// Crypto*
 #[no_mangle]
-pub extern fn indy_multi_sign_request(command_handle: CommandHandle,
+pub extern fn indy_force_sign_request(command_handle: CommandHandle,
                                       wallet_handle: WalletHandle,
                                       submitter_did: *const c_char,
                                       request_json: *const c_char,
-                                      cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                      cb: Option<extern fn(command_handle_: CommandHandle,
+                                                           err: ErrorCode,
                                                            signed_request_json: *const c_char)>) -> ErrorCode {
-    trace!("indy_multi_sign_request: >>> wallet_handle: {:?}, submitter_did: {:?}, request_json: {:?}", wallet_handle, submitter_did, request_json);
+    trace!("indy_force_sign_request: >>> wallet_handle: {:?}, submitter_did: {:?}, request_json: {:?}", wallet_handle, submitter_did, request_json);
 
-    check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
-    check_useful_c_str!(request_json, ErrorCode::CommonInvalidParam3);
+    command_params!(wallet_handle, submitter_did, request_json);
+
+    check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam3, DidValue);
+    check_useful_c_str!(request_json, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_force_sign_request: entities >>> wallet_handle: {:?}, submitter_did: {:?}, request_json: {:?}", wallet_handle, submitter_did, request_json);
+
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::ForceSignRequest(
+            wallet_handle,
+            submitter_did,
+            request_json,
+            with_timeout(boxed_callback_string!("indy_force_sign_request", cb, command_handle))
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_force_sign_request: <<< res: {:?}", res);
+
+    res
+}
+
+/// Multi signs request message.
+///
+/// Adds submitter information to passed request json, signs it with submitter
+/// sign key (see wallet_sign).
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// submitter_did: Id of Identity stored in secured Wallet.
+/// request_json: Request data json.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Signed request json.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Ledger*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_multi_sign_request(command_handle: CommandHandle,
+                                      wallet_handle: WalletHandle,
+                                      submitter_did: *const c_char,
+                                      request_json: *const c_char,
+                                      cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                           signed_request_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_multi_sign_request: >>> wallet_handle: {:?}, submitter_did: {:?}, request_json: {:?}", wallet_handle, submitter_did, request_json);
+
+    command_params!(wallet_handle, submitter_did, request_json);
+
+    check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
+    check_useful_c_str!(request_json, ErrorCode::CommonInvalidParam3);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     trace!("indy_multi_sign_request: entities >>> wallet_handle: {:?}, submitter_did: {:?}, request_json: {:?}", wallet_handle, submitter_did, request_json);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::MultiSignRequest(
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::MultiSignRequest(
             wallet_handle,
             submitter_did,
             request_json,
-            boxed_callback_string!("indy_multi_sign_request", cb, command_handle)
+            with_timeout(boxed_callback_string!("indy_multi_sign_request", cb, command_handle))
         )));
 
     let res = prepare_result!(result);
@@ -279,202 +771,862 @@ pub extern fn indy_multi_sign_request(command_handle: CommandHandle,
     res
 }
 
+/// Merges several multi signed copies of the same request into one request.
+///
+/// Every copy can be signed by different signers (author, endorser, trustees) with multi_sign_request.
+/// Single signature (see sign_request) is converted to multi signature of request author.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// requests_json: json array of signed copies of the request.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Request json containing signatures of all signers.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_merge_multi_signed_requests(command_handle: CommandHandle,
+                                               requests_json: *const c_char,
+                                               cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                    err: ErrorCode,
+                                                                    request_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_merge_multi_signed_requests: >>> requests_json: {:?}", requests_json);
+
+    command_params!(requests_json);
+
+    check_useful_json!(requests_json, ErrorCode::CommonInvalidParam2, Vec<serde_json::Value>);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_merge_multi_signed_requests: entities >>> requests_json: {:?}", requests_json);
+
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::MergeMultiSignedRequests(
+            requests_json,
+            with_timeout(boxed_callback_string!("indy_merge_multi_signed_requests", cb, command_handle))
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_merge_multi_signed_requests: <<< res: {:?}", res);
+
+    res
+}
+
+/// Checks whether signatures of the request satisfy the ledger auth rules.
+///
+/// Fetches auth rules and NYMs of all signers from the ledger, verifies signatures
+/// and reports which signatures are still missing before the request can be submitted.
+/// ADD action auth rule of the request transaction type is used and request author is considered as the owner.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// request_json: signed request json.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Report json:
+/// {
+///     "txnType": <string> - transaction type of the request,
+///     "authRule": <object> - auth rule used for the check (see parse_get_auth_rule_response),
+///     "signers": [{
+///         "did": <string> - signer DID,
+///         "role": <string> - (Optional) signer role on the ledger,
+///         "onLedger": <bool> - whether signer DID is written on the ledger,
+///         "verified": <bool> - whether signature is verified against signer verkey from the ledger
+///     }],
+///     "missingSigners": [<string>] - DIDs of author and endorser which didn't sign the request,
+///     "missingSignatures": [{
+///         "role": <string> - (Optional) role of required signers ("*" means any role),
+///         "count": <int> - number of required signatures,
+///         "needToBeOwner": <bool> - (Optional) whether signer must be the owner of the transaction
+///     }],
+///     "forbidden": <bool> - whether action is forbidden by the auth rule,
+///     "complete": <bool> - whether request is ready to be submitted
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_check_request_signatures(command_handle: CommandHandle,
+                                            pool_handle: PoolHandle,
+                                            request_json: *const c_char,
+                                            cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                 err: ErrorCode,
+                                                                 report_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_check_request_signatures: >>> pool_handle: {:?}, request_json: {:?}", pool_handle, request_json);
+
+    command_params!(pool_handle, request_json);
+
+    check_useful_c_str!(request_json, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_check_request_signatures: entities >>> pool_handle: {:?}, request_json: {:?}", pool_handle, request_json);
+
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::CheckRequestSignatures(
+            pool_handle,
+            request_json,
+            with_timeout(boxed_callback_string!("indy_check_request_signatures", cb, command_handle))
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_check_request_signatures: <<< res: {:?}", res);
+
+    res
+}
+
 
 /// Builds a request to get a DDO.
 ///
 /// #Params
 /// command_handle: command handle to map callback to caller context.
-/// submitter_did: (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
-/// target_did: Target DID as base58-encoded string for 16 or 32 bit DID value.
+/// submitter_did: (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+/// target_did: Target DID as base58-encoded string for 16 or 32 bit DID value.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Request result as json.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_build_get_ddo_request(command_handle: CommandHandle,
+                                         submitter_did: *const c_char,
+                                         target_did: *const c_char,
+                                         cb: Option<extern fn(command_handle_: CommandHandle,
+                                                              err: ErrorCode,
+                                                              request_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_build_get_ddo_request: >>> submitter_did: {:?}, target_did: {:?}", submitter_did, target_did);
+
+    command_params!(submitter_did, target_did);
+
+    check_useful_validatable_opt_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
+    check_useful_validatable_string!(target_did, ErrorCode::CommonInvalidParam3, DidValue);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_build_get_ddo_request: entities >>> submitter_did: {:?}, target_did: {:?}", submitter_did, target_did);
+
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::BuildGetDdoRequest(
+            submitter_did,
+            target_did,
+            with_timeout(boxed_callback_string!("indy_build_get_ddo_request", cb, command_handle))
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_build_get_ddo_request: <<< res: {:?}", res);
+
+    res
+}
+
+
+/// Builds a NYM request. Request to create a new NYM record for a specific user.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// submitter_did: Identifier (DID) of the transaction author as base58-encoded string.
+///                Actual request sender may differ if Endorser is used (look at `indy_append_request_endorser`)
+/// target_did: Target DID as base58-encoded string for 16 or 32 bit DID value.
+/// verkey: Target identity verification key as base58-encoded string.
+/// alias: NYM's alias.
+/// role: Role of a user NYM record:
+///                             null (common USER)
+///                             TRUSTEE
+///                             STEWARD
+///                             TRUST_ANCHOR
+///                             ENDORSER - equal to TRUST_ANCHOR that will be removed soon
+///                             NETWORK_MONITOR
+///                             empty string to reset role
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Request result as json.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_build_nym_request(command_handle: CommandHandle,
+                                     submitter_did: *const c_char,
+                                     target_did: *const c_char,
+                                     verkey: *const c_char,
+                                     alias: *const c_char,
+                                     role: *const c_char,
+                                     cb: Option<extern fn(command_handle_: CommandHandle,
+                                                          err: ErrorCode,
+                                                          request_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_build_nym_request: >>> submitter_did: {:?}, target_did: {:?}, verkey: {:?}, alias: {:?}, role: {:?}",
+           submitter_did, target_did, verkey, alias, role);
+
+    command_params!(submitter_did, target_did, verkey, alias, role);
+
+    check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
+    check_useful_validatable_string!(target_did, ErrorCode::CommonInvalidParam3, DidValue);
+    check_useful_opt_c_str!(verkey, ErrorCode::CommonInvalidParam4);
+    check_useful_opt_c_str!(alias, ErrorCode::CommonInvalidParam5);
+    check_useful_opt_c_str!(role, ErrorCode::CommonInvalidParam6);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    trace!("indy_build_nym_request: entities >>> submitter_did: {:?}, target_did: {:?}, verkey: {:?}, alias: {:?}, role: {:?}",
+           submitter_did, target_did, verkey, alias, role);
+
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::BuildNymRequest(
+            submitter_did,
+            target_did,
+            verkey,
+            alias,
+            role,
+            with_timeout(boxed_callback_string!("indy_build_nym_request", cb, command_handle))
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_build_nym_request: <<< res: {:?}", res);
+
+    res
+}
+
+/// Builds a NYM request with the fields introduced by did:indy method.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// submitter_did: Identifier (DID) of the transaction author as base58-encoded string.
+///                Actual request sender may differ if Endorser is used (look at `indy_append_request_endorser`)
+/// target_did: Target DID as base58-encoded string for 16 or 32 bit DID value.
+/// nym_data_json: NYM data
+///     {
+///         "verkey": <string> (optional) - target identity verification key as base58-encoded string.
+///         "alias": <string> (optional) - NYM's alias.
+///         "role": <string> (optional) - role of a user NYM record:
+///                             TRUSTEE
+///                             STEWARD
+///                             ENDORSER (TRUST_ANCHOR is accepted as well)
+///                             NETWORK_MONITOR
+///                             RESET - to reset role to common USER
+///         "diddocContent": <object> (optional) - DID Document content to be merged into the DID Document
+///                           generated by the ledger. Must not contain `id` and must not exceed 10 KiB.
+///         "version": <int> (optional) - DID self-certification check done by the ledger:
+///                             0 - no check
+///                             1 - did:sov (DID is first 16 bytes of verkey)
+///                             2 - did:indy (DID is first 16 bytes of SHA256 of verkey)
+///     }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Request result as json.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_build_nym_request_with_data(command_handle: CommandHandle,
+                                               submitter_did: *const c_char,
+                                               target_did: *const c_char,
+                                               nym_data_json: *const c_char,
+                                               cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                    err: ErrorCode,
+                                                                    request_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_build_nym_request_with_data: >>> submitter_did: {:?}, target_did: {:?}, nym_data_json: {:?}",
+           submitter_did, target_did, nym_data_json);
+
+    command_params!(submitter_did, target_did, nym_data_json);
+
+    check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
+    check_useful_validatable_string!(target_did, ErrorCode::CommonInvalidParam3, DidValue);
+    check_useful_validatable_json!(nym_data_json, ErrorCode::CommonInvalidParam4, NymRequestData);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_build_nym_request_with_data: entities >>> submitter_did: {:?}, target_did: {:?}, nym_data_json: {:?}",
+           submitter_did, target_did, nym_data_json);
+
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::BuildNymRequestWithData(
+            submitter_did,
+            target_did,
+            nym_data_json,
+            with_timeout(boxed_callback_string!("indy_build_nym_request_with_data", cb, command_handle))
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_build_nym_request_with_data: <<< res: {:?}", res);
+
+    res
+}
+
+/// Builds a GET_NYM request. Request to get information about a DID (NYM).
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// submitter_did: (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+/// target_did: Target DID as base58-encoded string for 16 or 32 bit DID value.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Request result as json.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_build_get_nym_request(command_handle: CommandHandle,
+                                         submitter_did: *const c_char,
+                                         target_did: *const c_char,
+                                         cb: Option<extern fn(command_handle_: CommandHandle,
+                                                              err: ErrorCode,
+                                                              request_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_build_get_nym_request: >>> submitter_did: {:?}, target_did: {:?}", submitter_did, target_did);
+
+    command_params!(submitter_did, target_did);
+
+    check_useful_validatable_opt_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
+    check_useful_validatable_string!(target_did, ErrorCode::CommonInvalidParam3, DidValue);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_build_get_nym_request: entities >>> submitter_did: {:?}, target_did: {:?}", submitter_did, target_did);
+
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::BuildGetNymRequest(
+            submitter_did,
+            target_did,
+            None,
+            None,
+            with_timeout(boxed_callback_string!("indy_build_get_nym_request", cb, command_handle))
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_build_get_nym_request: <<< res: {:?}", res);
+
+    res
+}
+
+/// Builds a GET_NYM request to get information about a DID (NYM) as it was at some point in the past.
+///
+/// Note: only one of `seq_no` and `timestamp` can be specified.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// submitter_did: (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+/// target_did: Target DID as base58-encoded string for 16 or 32 bit DID value.
+/// seq_no: (Optional) return NYM state as of the transaction with this sequence number (-1 to omit).
+/// timestamp: (Optional) return NYM state as of this time (-1 to omit).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Request result as json.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_build_get_historical_nym_request(command_handle: CommandHandle,
+                                                    submitter_did: *const c_char,
+                                                    target_did: *const c_char,
+                                                    seq_no: i64,
+                                                    timestamp: i64,
+                                                    cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                         err: ErrorCode,
+                                                                         request_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_build_get_historical_nym_request: >>> submitter_did: {:?}, target_did: {:?}, seq_no: {:?}, timestamp: {:?}",
+           submitter_did, target_did, seq_no, timestamp);
+
+    command_params!(submitter_did, target_did, seq_no, timestamp);
+
+    check_useful_validatable_opt_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
+    check_useful_validatable_string!(target_did, ErrorCode::CommonInvalidParam3, DidValue);
+    check_useful_opt_u64!(seq_no, ErrorCode::CommonInvalidParam4);
+    check_useful_opt_u64!(timestamp, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_build_get_historical_nym_request: entities >>> submitter_did: {:?}, target_did: {:?}, seq_no: {:?}, timestamp: {:?}",
+           submitter_did, target_did, seq_no, timestamp);
+
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::BuildGetNymRequest(
+            submitter_did,
+            target_did,
+            seq_no,
+            timestamp,
+            with_timeout(boxed_callback_string!("indy_build_get_historical_nym_request", cb, command_handle))
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_build_get_historical_nym_request: <<< res: {:?}", res);
+
+    res
+}
+
+/// Parse a GET_NYM response to get NYM data.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// get_nym_response: response on GET_NYM request.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// NYM data
+/// {
+///     did: DID as base58-encoded string for 16 or 32 bit DID value.
+///     verkey: verification key as base58-encoded string.
+///     role: Role associated number
+///                             null (common USER)
+///                             0 - TRUSTEE
+///                             2 - STEWARD
+///                             101 - TRUST_ANCHOR
+///                             101 - ENDORSER - equal to TRUST_ANCHOR that will be removed soon
+///                             201 - NETWORK_MONITOR
+///     seqNo: (optional) sequence number of the NYM transaction,
+///     txnTime: (optional) time of the NYM transaction
+/// }
+///
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_parse_get_nym_response(command_handle: CommandHandle,
+                                          get_nym_response: *const c_char,
+                                          cb: Option<extern fn(command_handle_: CommandHandle,
+                                                               err: ErrorCode,
+                                                               nym_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_parse_get_nym_response: >>> get_nym_response: {:?}", get_nym_response);
+
+    command_params!(get_nym_response);
+
+    check_useful_c_str!(get_nym_response, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_parse_get_nym_response: entities >>> get_nym_response: {:?}", get_nym_response);
+
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::ParseGetNymResponse(
+            get_nym_response,
+            with_timeout(boxed_callback_string!("indy_parse_get_nym_response", cb, command_handle))
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_parse_get_nym_response: <<< res: {:?}", res);
+
+    res
+}
+
+/// Parse a GET_ATTRIB response to get attribute data.
+/// Returns LedgerItemNotFound error if the requested data is absent on the ledger.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// get_attrib_response: response on GET_ATTRIB request.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Attribute data:
+/// {
+///     data: {
+///         did: target DID as base58-encoded string for 16 or 32 bit DID value,
+///         raw: (optional) json, where key is attribute name and value is attribute value,
+///         hash: (optional) hash of attribute data,
+///         enc: (optional) encrypted attribute data
+///     },
+///     seqNo: (optional) sequence number of the attribute transaction,
+///     txnTime: (optional) time of the attribute transaction
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_parse_get_attrib_response(command_handle: CommandHandle,
+                                             get_attrib_response: *const c_char,
+                                             cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                  err: ErrorCode,
+                                                                  attrib_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_parse_get_attrib_response: >>> get_attrib_response: {:?}", get_attrib_response);
+
+    command_params!(get_attrib_response);
+
+    check_useful_c_str!(get_attrib_response, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_parse_get_attrib_response: entities >>> get_attrib_response: {:?}", get_attrib_response);
+
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::ParseGetAttribResponse(
+            get_attrib_response,
+            with_timeout(boxed_callback_string!("indy_parse_get_attrib_response", cb, command_handle))
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_parse_get_attrib_response: <<< res: {:?}", res);
+
+    res
+}
+
+/// Parse a GET_TXN response to get transaction data.
+/// Returns LedgerItemNotFound error if the requested data is absent on the ledger.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// get_txn_response: response on GET_TXN request.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Transaction data:
+/// {
+///     data: {
+///         type: transaction type,
+///         data: transaction specific data json,
+///         metadata: transaction metadata json (author, request id, etc.)
+///     },
+///     seqNo: sequence number of the transaction,
+///     txnTime: (optional) time of the transaction
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_parse_get_txn_response(command_handle: CommandHandle,
+                                          get_txn_response: *const c_char,
+                                          cb: Option<extern fn(command_handle_: CommandHandle,
+                                                               err: ErrorCode,
+                                                               txn_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_parse_get_txn_response: >>> get_txn_response: {:?}", get_txn_response);
+
+    command_params!(get_txn_response);
+
+    check_useful_c_str!(get_txn_response, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_parse_get_txn_response: entities >>> get_txn_response: {:?}", get_txn_response);
+
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::ParseGetTxnResponse(
+            get_txn_response,
+            with_timeout(boxed_callback_string!("indy_parse_get_txn_response", cb, command_handle))
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_parse_get_txn_response: <<< res: {:?}", res);
+
+    res
+}
+
+/// Parse a GET_AUTH_RULE response to get list of auth rules.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// get_auth_rule_response: response on GET_AUTH_RULE request.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Auth rules:
+/// {
+///     data: [
+///         {
+///             auth_type: ledger transaction alias or associated value,
+///             auth_action: type of an action (ADD or EDIT),
+///             field: transaction field,
+///             old_value: (optional) old value of a field,
+///             new_value: (optional) new value of a field,
+///             constraint: set of constraints (look at indy_build_auth_rule_request)
+///         }
+///     ]
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_parse_get_auth_rule_response(command_handle: CommandHandle,
+                                                get_auth_rule_response: *const c_char,
+                                                cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                     err: ErrorCode,
+                                                                     auth_rules_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_parse_get_auth_rule_response: >>> get_auth_rule_response: {:?}", get_auth_rule_response);
+
+    command_params!(get_auth_rule_response);
+
+    check_useful_c_str!(get_auth_rule_response, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_parse_get_auth_rule_response: entities >>> get_auth_rule_response: {:?}", get_auth_rule_response);
+
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::ParseGetAuthRuleResponse(
+            get_auth_rule_response,
+            with_timeout(boxed_callback_string!("indy_parse_get_auth_rule_response", cb, command_handle))
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_parse_get_auth_rule_response: <<< res: {:?}", res);
+
+    res
+}
+
+/// Parse a response on GET_VALIDATOR_INFO action (see indy_submit_action) to get validator info of each node.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// get_validator_info_response: response of indy_submit_action on GET_VALIDATOR_INFO request.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Validator info:
+/// {
+///     data: {
+///         <node alias>: {
+///             data: (optional) validator info json of the node,
+///             error: (optional) error description if the node rejected the request or didn't reply
+///         }
+///     }
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_parse_get_validator_info_response(command_handle: CommandHandle,
+                                                     get_validator_info_response: *const c_char,
+                                                     cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                          err: ErrorCode,
+                                                                          validator_info_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_parse_get_validator_info_response: >>> get_validator_info_response: {:?}", get_validator_info_response);
+
+    command_params!(get_validator_info_response);
+
+    check_useful_c_str!(get_validator_info_response, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_parse_get_validator_info_response: entities >>> get_validator_info_response: {:?}", get_validator_info_response);
+
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::ParseGetValidatorInfoResponse(
+            get_validator_info_response,
+            with_timeout(boxed_callback_string!("indy_parse_get_validator_info_response", cb, command_handle))
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_parse_get_validator_info_response: <<< res: {:?}", res);
+
+    res
+}
+
+/// Parse a response on GET_VALIDATOR_INFO action (see indy_submit_action) to get validator info of each node
+/// normalized into a common structure regardless of the node version.
+/// Fields that aren't reported by the node are omitted.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// get_validator_info_response: response of indy_submit_action on GET_VALIDATOR_INFO request.
 /// cb: Callback that takes command result as parameter.
 ///
 /// #Returns
-/// Request result as json.
+/// Validator info summary:
+/// {
+///     data: {
+///         <node alias>: {
+///             alias: node alias,
+///             nodeVersion: (optional) version of indy-node package,
+///             mode: (optional) node mode (f.e. "participating"),
+///             uptime: (optional) node uptime in seconds,
+///             ledgerSizes: (optional) {<ledger name>: <transactions count>, ...},
+///             pool: (optional) {
+///                 totalNodes: (optional) count of nodes in the pool,
+///                 f: (optional) count of faulty nodes the pool tolerates,
+///                 readOnly: (optional) whether the pool is in read only mode,
+///                 reachableNodes: [<node alias>, ...],
+///                 unreachableNodes: [<node alias>, ...],
+///                 blacklistedNodes: [<node alias>, ...]
+///             },
+///             timestamp: (optional) time of the validator info collection,
+///             error: (optional) error description if the node rejected the request or didn't reply
+///         }
+///     }
+/// }
 ///
 /// #Errors
 /// Common*
+/// Ledger*
 #[no_mangle]
-pub extern fn indy_build_get_ddo_request(command_handle: CommandHandle,
-                                         submitter_did: *const c_char,
-                                         target_did: *const c_char,
-                                         cb: Option<extern fn(command_handle_: CommandHandle,
-                                                              err: ErrorCode,
-                                                              request_json: *const c_char)>) -> ErrorCode {
-    trace!("indy_build_get_ddo_request: >>> submitter_did: {:?}, target_did: {:?}", submitter_did, target_did);
+pub extern fn indy_parse_get_validator_info_summary_response(command_handle: CommandHandle,
+                                                             get_validator_info_response: *const c_char,
+                                                             cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                                  err: ErrorCode,
+                                                                                  validator_info_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_parse_get_validator_info_summary_response: >>> get_validator_info_response: {:?}", get_validator_info_response);
 
-    check_useful_validatable_opt_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
-    check_useful_validatable_string!(target_did, ErrorCode::CommonInvalidParam3, DidValue);
-    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+    command_params!(get_validator_info_response);
 
-    trace!("indy_build_get_ddo_request: entities >>> submitter_did: {:?}, target_did: {:?}", submitter_did, target_did);
+    check_useful_c_str!(get_validator_info_response, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildGetDdoRequest(
-            submitter_did,
-            target_did,
-            boxed_callback_string!("indy_build_get_ddo_request", cb, command_handle)
+    trace!("indy_parse_get_validator_info_summary_response: entities >>> get_validator_info_response: {:?}", get_validator_info_response);
+
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::ParseGetValidatorInfoSummaryResponse(
+            get_validator_info_response,
+            with_timeout(boxed_callback_string!("indy_parse_get_validator_info_summary_response", cb, command_handle))
         )));
 
     let res = prepare_result!(result);
 
-    trace!("indy_build_get_ddo_request: <<< res: {:?}", res);
+    trace!("indy_parse_get_validator_info_summary_response: <<< res: {:?}", res);
 
     res
 }
 
-
-/// Builds a NYM request. Request to create a new NYM record for a specific user.
+/// Parse a response on GET_VALIDATOR_INFO action (see indy_submit_action) to get upgrade status of each node
+/// taken from the last record of the node upgrade log.
 ///
 /// #Params
 /// command_handle: command handle to map callback to caller context.
-/// submitter_did: Identifier (DID) of the transaction author as base58-encoded string.
-///                Actual request sender may differ if Endorser is used (look at `indy_append_request_endorser`)
-/// target_did: Target DID as base58-encoded string for 16 or 32 bit DID value.
-/// verkey: Target identity verification key as base58-encoded string.
-/// alias: NYM's alias.
-/// role: Role of a user NYM record:
-///                             null (common USER)
-///                             TRUSTEE
-///                             STEWARD
-///                             TRUST_ANCHOR
-///                             ENDORSER - equal to TRUST_ANCHOR that will be removed soon
-///                             NETWORK_MONITOR
-///                             empty string to reset role
+/// get_validator_info_response: response of indy_submit_action on GET_VALIDATOR_INFO request.
 /// cb: Callback that takes command result as parameter.
 ///
 /// #Returns
-/// Request result as json.
+/// Upgrade status:
+/// {
+///     data: {
+///         <node alias>: {
+///             alias: node alias,
+///             nodeVersion: (optional) current version of indy-node package,
+///             lastEvent: (optional) {
+///                 loggedAt: event logging time,
+///                 event: event name (f.e. "scheduled", "started", "succeeded", "failed", "cancelled"),
+///                 scheduledAt: scheduled upgrade time,
+///                 version: target version,
+///                 upgradeId: (optional) upgrade id,
+///                 package: (optional) upgraded package
+///             },
+///             error: (optional) error description if the node rejected the request or didn't reply
+///         }
+///     }
+/// }
 ///
 /// #Errors
 /// Common*
+/// Ledger*
 #[no_mangle]
-pub extern fn indy_build_nym_request(command_handle: CommandHandle,
-                                     submitter_did: *const c_char,
-                                     target_did: *const c_char,
-                                     verkey: *const c_char,
-                                     alias: *const c_char,
-                                     role: *const c_char,
-                                     cb: Option<extern fn(command_handle_: CommandHandle,
-                                                          err: ErrorCode,
-                                                          request_json: *const c_char)>) -> ErrorCode {
-    trace!("indy_build_nym_request: >>> submitter_did: {:?}, target_did: {:?}, verkey: {:?}, alias: {:?}, role: {:?}",
-           submitter_did, target_did, verkey, alias, role);
+pub extern fn indy_parse_pool_upgrade_status_response(command_handle: CommandHandle,
+                                                      get_validator_info_response: *const c_char,
+                                                      cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                           err: ErrorCode,
+                                                                           upgrade_status_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_parse_pool_upgrade_status_response: >>> get_validator_info_response: {:?}", get_validator_info_response);
 
-    check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
-    check_useful_validatable_string!(target_did, ErrorCode::CommonInvalidParam3, DidValue);
-    check_useful_opt_c_str!(verkey, ErrorCode::CommonInvalidParam4);
-    check_useful_opt_c_str!(alias, ErrorCode::CommonInvalidParam5);
-    check_useful_opt_c_str!(role, ErrorCode::CommonInvalidParam6);
-    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+    command_params!(get_validator_info_response);
 
-    trace!("indy_build_nym_request: entities >>> submitter_did: {:?}, target_did: {:?}, verkey: {:?}, alias: {:?}, role: {:?}",
-           submitter_did, target_did, verkey, alias, role);
+    check_useful_c_str!(get_validator_info_response, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildNymRequest(
-            submitter_did,
-            target_did,
-            verkey,
-            alias,
-            role,
-            boxed_callback_string!("indy_build_nym_request", cb, command_handle)
+    trace!("indy_parse_pool_upgrade_status_response: entities >>> get_validator_info_response: {:?}", get_validator_info_response);
+
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::ParsePoolUpgradeStatusResponse(
+            get_validator_info_response,
+            with_timeout(boxed_callback_string!("indy_parse_pool_upgrade_status_response", cb, command_handle))
         )));
 
     let res = prepare_result!(result);
 
-    trace!("indy_build_nym_request: <<< res: {:?}", res);
+    trace!("indy_parse_pool_upgrade_status_response: <<< res: {:?}", res);
 
     res
 }
 
-/// Builds a GET_NYM request. Request to get information about a DID (NYM).
+/// Parse a GET_TXN_AUTHR_AGRMT response to get Transaction Author Agreement data.
+/// Returns LedgerItemNotFound error if the requested data is absent on the ledger.
 ///
 /// #Params
 /// command_handle: command handle to map callback to caller context.
-/// submitter_did: (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
-/// target_did: Target DID as base58-encoded string for 16 or 32 bit DID value.
+/// get_txn_author_agreement_response: response on GET_TXN_AUTHR_AGRMT request.
 /// cb: Callback that takes command result as parameter.
 ///
 /// #Returns
-/// Request result as json.
+/// Transaction Author Agreement data:
+/// {
+///     data: {
+///         text: (optional) text of TAA,
+///         version: version of TAA,
+///         digest: (optional) hash of TAA text and version,
+///         ratification_ts: (optional) TAA ratification timestamp,
+///         retirement_ts: (optional) TAA retirement timestamp
+///     },
+///     seqNo: (optional) sequence number of the TAA transaction,
+///     txnTime: (optional) time of the TAA transaction
+/// }
 ///
 /// #Errors
 /// Common*
+/// Ledger*
 #[no_mangle]
-pub extern fn indy_build_get_nym_request(command_handle: CommandHandle,
-                                         submitter_did: *const c_char,
-                                         target_did: *const c_char,
-                                         cb: Option<extern fn(command_handle_: CommandHandle,
-                                                              err: ErrorCode,
-                                                              request_json: *const c_char)>) -> ErrorCode {
-    trace!("indy_build_get_nym_request: >>> submitter_did: {:?}, target_did: {:?}", submitter_did, target_did);
+pub extern fn indy_parse_get_txn_author_agreement_response(command_handle: CommandHandle,
+                                                           get_txn_author_agreement_response: *const c_char,
+                                                           cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                                err: ErrorCode,
+                                                                                txn_author_agreement_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_parse_get_txn_author_agreement_response: >>> get_txn_author_agreement_response: {:?}", get_txn_author_agreement_response);
 
-    check_useful_validatable_opt_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
-    check_useful_validatable_string!(target_did, ErrorCode::CommonInvalidParam3, DidValue);
-    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+    command_params!(get_txn_author_agreement_response);
 
-    trace!("indy_build_get_nym_request: entities >>> submitter_did: {:?}, target_did: {:?}", submitter_did, target_did);
+    check_useful_c_str!(get_txn_author_agreement_response, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildGetNymRequest(
-            submitter_did,
-            target_did,
-            boxed_callback_string!("indy_build_get_nym_request", cb, command_handle)
+    trace!("indy_parse_get_txn_author_agreement_response: entities >>> get_txn_author_agreement_response: {:?}", get_txn_author_agreement_response);
+
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::ParseGetTxnAuthorAgreementResponse(
+            get_txn_author_agreement_response,
+            with_timeout(boxed_callback_string!("indy_parse_get_txn_author_agreement_response", cb, command_handle))
         )));
 
     let res = prepare_result!(result);
 
-    trace!("indy_build_get_nym_request: <<< res: {:?}", res);
+    trace!("indy_parse_get_txn_author_agreement_response: <<< res: {:?}", res);
 
     res
 }
 
-/// Parse a GET_NYM response to get NYM data.
+/// Parse a GET_TXN_AUTHR_AGRMT_AML response to get acceptance mechanisms data.
+/// Returns LedgerItemNotFound error if the requested data is absent on the ledger.
 ///
 /// #Params
 /// command_handle: command handle to map callback to caller context.
-/// get_nym_response: response on GET_NYM request.
+/// get_acceptance_mechanisms_response: response on GET_TXN_AUTHR_AGRMT_AML request.
 /// cb: Callback that takes command result as parameter.
 ///
 /// #Returns
-/// NYM data
+/// Acceptance mechanisms data:
 /// {
-///     did: DID as base58-encoded string for 16 or 32 bit DID value.
-///     verkey: verification key as base58-encoded string.
-///     role: Role associated number
-///                             null (common USER)
-///                             0 - TRUSTEE
-///                             2 - STEWARD
-///                             101 - TRUST_ANCHOR
-///                             101 - ENDORSER - equal to TRUST_ANCHOR that will be removed soon
-///                             201 - NETWORK_MONITOR
+///     data: {
+///         aml: a set of new acceptance mechanisms: {"<acceptance mechanism label>": <acceptance mechanism description>},
+///         version: version of acceptance mechanisms,
+///         amlContext: (optional) common context information about acceptance mechanisms
+///     },
+///     seqNo: (optional) sequence number of the AML transaction,
+///     txnTime: (optional) time of the AML transaction
 /// }
 ///
-///
 /// #Errors
 /// Common*
+/// Ledger*
 #[no_mangle]
-pub extern fn indy_parse_get_nym_response(command_handle: CommandHandle,
-                                          get_nym_response: *const c_char,
-                                          cb: Option<extern fn(command_handle_: CommandHandle,
-                                                               err: ErrorCode,
-                                                               nym_json: *const c_char)>) -> ErrorCode {
-    trace!("indy_parse_get_nym_response: >>> get_nym_response: {:?}", get_nym_response);
+pub extern fn indy_parse_get_acceptance_mechanisms_response(command_handle: CommandHandle,
+                                                            get_acceptance_mechanisms_response: *const c_char,
+                                                            cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                                 err: ErrorCode,
+                                                                                 acceptance_mechanisms_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_parse_get_acceptance_mechanisms_response: >>> get_acceptance_mechanisms_response: {:?}", get_acceptance_mechanisms_response);
 
-    check_useful_c_str!(get_nym_response, ErrorCode::CommonInvalidParam2);
+    command_params!(get_acceptance_mechanisms_response);
+
+    check_useful_c_str!(get_acceptance_mechanisms_response, ErrorCode::CommonInvalidParam2);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
 
-    trace!("indy_parse_get_nym_response: entities >>> get_nym_response: {:?}", get_nym_response);
+    trace!("indy_parse_get_acceptance_mechanisms_response: entities >>> get_acceptance_mechanisms_response: {:?}", get_acceptance_mechanisms_response);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::ParseGetNymResponse(
-            get_nym_response,
-            boxed_callback_string!("indy_parse_get_nym_response", cb, command_handle)
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::ParseGetAcceptanceMechanismsResponse(
+            get_acceptance_mechanisms_response,
+            with_timeout(boxed_callback_string!("indy_parse_get_acceptance_mechanisms_response", cb, command_handle))
         )));
 
     let res = prepare_result!(result);
 
-    trace!("indy_parse_get_nym_response: <<< res: {:?}", res);
+    trace!("indy_parse_get_acceptance_mechanisms_response: <<< res: {:?}", res);
 
     res
 }
@@ -511,6 +1663,8 @@ pub extern fn indy_build_attrib_request(command_handle: CommandHandle,
     trace!("indy_build_attrib_request: >>> submitter_did: {:?}, target_did: {:?}, hash: {:?}, raw: {:?}, enc: {:?}",
            submitter_did, target_did, hash, raw, enc);
 
+    command_params!(submitter_did, target_did, hash, raw, enc);
+
     check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
     check_useful_validatable_string!(target_did, ErrorCode::CommonInvalidParam3, DidValue);
     check_useful_opt_c_str!(hash, ErrorCode::CommonInvalidParam4);
@@ -525,14 +1679,13 @@ pub extern fn indy_build_attrib_request(command_handle: CommandHandle,
         return IndyError::from_msg(IndyErrorKind::InvalidStructure, "Either raw or hash or enc must be specified").into();
     }
 
-    let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildAttribRequest(
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::BuildAttribRequest(
             submitter_did,
             target_did,
             hash,
             raw,
             enc,
-            boxed_callback_string!("indy_build_attrib_request", cb, command_handle)
+            with_timeout(boxed_callback_string!("indy_build_attrib_request", cb, command_handle))
         )));
 
     let res = prepare_result!(result);
@@ -573,6 +1726,8 @@ pub extern fn indy_build_get_attrib_request(command_handle: CommandHandle,
     trace!("indy_build_get_attrib_request: >>> submitter_did: {:?}, target_did: {:?}, hash: {:?}, raw: {:?}, enc: {:?}",
            submitter_did, target_did, hash, raw, enc);
 
+    command_params!(submitter_did, target_did, raw, hash, enc);
+
     check_useful_validatable_opt_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
     check_useful_validatable_string!(target_did, ErrorCode::CommonInvalidParam3, DidValue);
     check_useful_opt_c_str!(raw, ErrorCode::CommonInvalidParam4);
@@ -587,14 +1742,15 @@ pub extern fn indy_build_get_attrib_request(command_handle: CommandHandle,
         return IndyError::from_msg(IndyErrorKind::InvalidStructure, "Either raw or hash or enc must be specified").into();
     }
 
-    let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildGetAttribRequest(
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::BuildGetAttribRequest(
             submitter_did,
             target_did,
             raw,
             hash,
             enc,
-            boxed_callback_string!("indy_build_get_attrib_request", cb, command_handle)
+            None,
+            None,
+            with_timeout(boxed_callback_string!("indy_build_get_attrib_request", cb, command_handle))
         )));
 
     let res = prepare_result!(result);
@@ -604,6 +1760,178 @@ pub extern fn indy_build_get_attrib_request(command_handle: CommandHandle,
     res
 }
 
+/// Builds a GET_ATTRIB request to get information about an Attribute for the specified DID
+/// as it was at some point in the past.
+///
+/// Note: one of the fields `hash`, `raw`, `enc` must be specified.
+/// Note: only one of `seq_no` and `timestamp` can be specified.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// submitter_did: (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+/// target_did: Target DID as base58-encoded string for 16 or 32 bit DID value.
+/// raw: (Optional) Requested attribute name.
+/// hash: (Optional) Requested attribute hash.
+/// enc: (Optional) Requested attribute encrypted value.
+/// seq_no: (Optional) return attribute state as of the transaction with this sequence number (-1 to omit).
+/// timestamp: (Optional) return attribute state as of this time (-1 to omit).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Request result as json.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_build_get_historical_attrib_request(command_handle: CommandHandle,
+                                                       submitter_did: *const c_char,
+                                                       target_did: *const c_char,
+                                                       raw: *const c_char,
+                                                       hash: *const c_char,
+                                                       enc: *const c_char,
+                                                       seq_no: i64,
+                                                       timestamp: i64,
+                                                       cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                            err: ErrorCode,
+                                                                            request_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_build_get_historical_attrib_request: >>> submitter_did: {:?}, target_did: {:?}, hash: {:?}, raw: {:?}, enc: {:?}, seq_no: {:?}, timestamp: {:?}",
+           submitter_did, target_did, hash, raw, enc, seq_no, timestamp);
+
+    command_params!(submitter_did, target_did, raw, hash, enc, seq_no, timestamp);
+
+    check_useful_validatable_opt_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
+    check_useful_validatable_string!(target_did, ErrorCode::CommonInvalidParam3, DidValue);
+    check_useful_opt_c_str!(raw, ErrorCode::CommonInvalidParam4);
+    check_useful_opt_c_str!(hash, ErrorCode::CommonInvalidParam5);
+    check_useful_opt_c_str!(enc, ErrorCode::CommonInvalidParam6);
+    check_useful_opt_u64!(seq_no, ErrorCode::CommonInvalidParam7);
+    check_useful_opt_u64!(timestamp, ErrorCode::CommonInvalidParam8);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam9);
+
+    trace!("indy_build_get_historical_attrib_request: entities >>> submitter_did: {:?}, target_did: {:?}, hash: {:?}, raw: {:?}, enc: {:?}, seq_no: {:?}, timestamp: {:?}",
+           submitter_did, target_did, hash, raw, enc, seq_no, timestamp);
+
+    if raw.is_none() && hash.is_none() && enc.is_none() {
+        return IndyError::from_msg(IndyErrorKind::InvalidStructure, "Either raw or hash or enc must be specified").into();
+    }
+
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::BuildGetAttribRequest(
+            submitter_did,
+            target_did,
+            raw,
+            hash,
+            enc,
+            seq_no,
+            timestamp,
+            with_timeout(boxed_callback_string!("indy_build_get_historical_attrib_request", cb, command_handle))
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_build_get_historical_attrib_request: <<< res: {:?}", res);
+
+    res
+}
+
+/// Builds an ATTRIB request publishing endpoint of the DID.
+///
+/// Endpoint is always published as `raw` attrib `{"endpoint": {"ha": <address>, "verkey": <transport_key>}}`
+/// as it has to be resolvable by other parties (look at `indy_get_endpoint_for_did`).
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// submitter_did: Identifier (DID) of the transaction author as base58-encoded string.
+///                Actual request sender may differ if Endorser is used (look at `indy_append_request_endorser`)
+/// target_did: Target DID as base58-encoded string for 16 or 32 bit DID value.
+/// address: Endpoint address in ip-address:port format (restricted by the ledger).
+/// transport_key: (Optional) Endpoint transport verkey as base58-encoded string.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Request result as json.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_build_set_endpoint_request(command_handle: CommandHandle,
+                                              submitter_did: *const c_char,
+                                              target_did: *const c_char,
+                                              address: *const c_char,
+                                              transport_key: *const c_char,
+                                              cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                   err: ErrorCode,
+                                                                   request_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_build_set_endpoint_request: >>> submitter_did: {:?}, target_did: {:?}, address: {:?}, transport_key: {:?}",
+           submitter_did, target_did, address, transport_key);
+
+    command_params!(submitter_did, target_did, address, transport_key);
+
+    check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
+    check_useful_validatable_string!(target_did, ErrorCode::CommonInvalidParam3, DidValue);
+    check_useful_c_str!(address, ErrorCode::CommonInvalidParam4);
+    check_useful_opt_c_str!(transport_key, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_build_set_endpoint_request: entities >>> submitter_did: {:?}, target_did: {:?}, address: {:?}, transport_key: {:?}",
+           submitter_did, target_did, address, transport_key);
+
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::BuildSetEndpointRequest(
+            submitter_did,
+            target_did,
+            Endpoint::new(address, transport_key),
+            with_timeout(boxed_callback_string!("indy_build_set_endpoint_request", cb, command_handle))
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_build_set_endpoint_request: <<< res: {:?}", res);
+
+    res
+}
+
+/// Builds a GET_ATTRIB request to get endpoint of the DID published by `indy_build_set_endpoint_request`.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// submitter_did: (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+/// target_did: Target DID as base58-encoded string for 16 or 32 bit DID value.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Request result as json.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_build_get_endpoint_request(command_handle: CommandHandle,
+                                              submitter_did: *const c_char,
+                                              target_did: *const c_char,
+                                              cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                   err: ErrorCode,
+                                                                   request_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_build_get_endpoint_request: >>> submitter_did: {:?}, target_did: {:?}", submitter_did, target_did);
+
+    command_params!(submitter_did, target_did);
+
+    check_useful_validatable_opt_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
+    check_useful_validatable_string!(target_did, ErrorCode::CommonInvalidParam3, DidValue);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_build_get_endpoint_request: entities >>> submitter_did: {:?}, target_did: {:?}", submitter_did, target_did);
+
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::BuildGetEndpointRequest(
+            submitter_did,
+            target_did,
+            with_timeout(boxed_callback_string!("indy_build_get_endpoint_request", cb, command_handle))
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_build_get_endpoint_request: <<< res: {:?}", res);
+
+    res
+}
+
 /// Builds a SCHEMA request. Request to add Credential's schema.
 ///
 /// #Params
@@ -634,17 +1962,18 @@ pub extern fn indy_build_schema_request(command_handle: CommandHandle,
                                                              request_json: *const c_char)>) -> ErrorCode {
     trace!("indy_build_schema_request: >>> submitter_did: {:?}, data: {:?}", submitter_did, data);
 
+    command_params!(submitter_did, data);
+
     check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
     check_useful_validatable_json!(data, ErrorCode::CommonInvalidParam3, Schema);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     trace!("indy_build_schema_request: entities >>> submitter_did: {:?}, data: {:?}", submitter_did, data);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildSchemaRequest(
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::BuildSchemaRequest(
             submitter_did,
             data,
-            boxed_callback_string!("indy_build_schema_request", cb, command_handle)
+            with_timeout(boxed_callback_string!("indy_build_schema_request", cb, command_handle))
         )));
 
     let res = prepare_result!(result);
@@ -676,17 +2005,18 @@ pub extern fn indy_build_get_schema_request(command_handle: CommandHandle,
                                                                  request_json: *const c_char)>) -> ErrorCode {
     trace!("indy_build_get_schema_request: >>> submitter_did: {:?}, id: {:?}", submitter_did, id);
 
+    command_params!(submitter_did, id);
+
     check_useful_validatable_opt_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
     check_useful_validatable_string!(id, ErrorCode::CommonInvalidParam3, SchemaId);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     trace!("indy_build_get_schema_request: entities >>> submitter_did: {:?}, id: {:?}", submitter_did, id);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildGetSchemaRequest(
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::BuildGetSchemaRequest(
             submitter_did,
             id,
-            boxed_callback_string!("indy_build_get_schema_request", cb, command_handle)
+            with_timeout(boxed_callback_string!("indy_build_get_schema_request", cb, command_handle))
         )));
 
     let res = prepare_result!(result);
@@ -724,15 +2054,16 @@ pub extern fn indy_parse_get_schema_response(command_handle: CommandHandle,
                                                                   schema_json: *const c_char)>) -> ErrorCode {
     trace!("indy_parse_get_schema_response: >>> get_schema_response: {:?}", get_schema_response);
 
+    command_params!(get_schema_response);
+
     check_useful_c_str!(get_schema_response, ErrorCode::CommonInvalidParam2);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
 
     trace!("indy_parse_get_schema_response: entities >>> get_schema_response: {:?}", get_schema_response);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::ParseGetSchemaResponse(
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::ParseGetSchemaResponse(
             get_schema_response,
-            Box::new(move |result| {
+            with_timeout(move |result| {
                 let (err, schema_id, schema_json) = prepare_result_2!(result, String::new(), String::new());
                 trace!("indy_parse_get_schema_response: schema_id: {:?}, schema_json: {:?}", schema_id, schema_json);
                 let schema_id = ctypes::string_to_cstring(schema_id);
@@ -783,17 +2114,18 @@ pub extern fn indy_build_cred_def_request(command_handle: CommandHandle,
                                                                request_result_json: *const c_char)>) -> ErrorCode {
     trace!("indy_build_cred_def_request: >>> submitter_did: {:?}, data: {:?}", submitter_did, data);
 
+    command_params!(submitter_did, data);
+
     check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
     check_useful_validatable_json!(data, ErrorCode::CommonInvalidParam3, CredentialDefinition);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     trace!("indy_build_cred_def_request: entities >>> submitter_did: {:?}, data: {:?}", submitter_did, data);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildCredDefRequest(
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::BuildCredDefRequest(
             submitter_did,
             data,
-            boxed_callback_string!("indy_build_cred_def_request", cb, command_handle)
+            with_timeout(boxed_callback_string!("indy_build_cred_def_request", cb, command_handle))
         )));
 
     let res = prepare_result!(result);
@@ -826,17 +2158,18 @@ pub extern fn indy_build_get_cred_def_request(command_handle: CommandHandle,
                                                                    request_json: *const c_char)>) -> ErrorCode {
     trace!("indy_build_get_cred_def_request: >>> submitter_did: {:?}, id: {:?}", submitter_did, id);
 
+    command_params!(submitter_did, id);
+
     check_useful_validatable_opt_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
     check_useful_validatable_string!(id, ErrorCode::CommonInvalidParam3, CredentialDefinitionId);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     trace!("indy_build_get_cred_def_request: entities >>> submitter_did: {:?}, id: {:?}", submitter_did, id);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildGetCredDefRequest(
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::BuildGetCredDefRequest(
             submitter_did,
             id,
-            boxed_callback_string!("indy_build_get_cred_def_request", cb, command_handle)
+            with_timeout(boxed_callback_string!("indy_build_get_cred_def_request", cb, command_handle))
         )));
 
     let res = prepare_result!(result);
@@ -878,15 +2211,16 @@ pub extern fn indy_parse_get_cred_def_response(command_handle: CommandHandle,
                                                                     cred_def_json: *const c_char)>) -> ErrorCode {
     trace!("indy_parse_get_cred_def_response: >>> get_cred_def_response: {:?}", get_cred_def_response);
 
+    command_params!(get_cred_def_response);
+
     check_useful_c_str!(get_cred_def_response, ErrorCode::CommonInvalidParam2);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
 
     trace!("indy_parse_get_cred_def_response: entities >>> get_cred_def_response: {:?}", get_cred_def_response);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::ParseGetCredDefResponse(
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::ParseGetCredDefResponse(
             get_cred_def_response,
-            Box::new(move |result| {
+            with_timeout(move |result| {
                 let (err, cred_def_id, cred_def_json) = prepare_result_2!(result, String::new(), String::new());
                 trace!("indy_parse_get_cred_def_response: cred_def_id: {:?}, cred_def_json: {:?}", cred_def_id, cred_def_json);
                 let cred_def_id = ctypes::string_to_cstring(cred_def_id);
@@ -936,6 +2270,8 @@ pub extern fn indy_build_node_request(command_handle: CommandHandle,
                                                            request_json: *const c_char)>) -> ErrorCode {
     trace!("indy_build_node_request: >>> submitter_did: {:?}, target_did: {:?}, data: {:?}", submitter_did, target_did, data);
 
+    command_params!(submitter_did, target_did, data);
+
     check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
     check_useful_validatable_string!(target_did, ErrorCode::CommonInvalidParam3, DidValue);
     check_useful_json!(data, ErrorCode::CommonInvalidParam4, NodeOperationData);
@@ -943,12 +2279,11 @@ pub extern fn indy_build_node_request(command_handle: CommandHandle,
 
     trace!("indy_build_node_request: entities >>> submitter_did: {:?}, target_did: {:?}, data: {:?}", submitter_did, target_did, data);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildNodeRequest(
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::BuildNodeRequest(
             submitter_did,
             target_did,
             data,
-            boxed_callback_string!("indy_build_node_request", cb, command_handle)
+            with_timeout(boxed_callback_string!("indy_build_node_request", cb, command_handle))
         )));
 
     let res = prepare_result!(result);
@@ -975,13 +2310,14 @@ pub extern fn indy_build_get_validator_info_request(command_handle: CommandHandl
                                                     submitter_did: *const c_char,
                                                     cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
                                                                          request_json: *const c_char)>) -> ErrorCode {
+    command_params!(submitter_did);
+
     check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildGetValidatorInfoRequest(
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::BuildGetValidatorInfoRequest(
             submitter_did,
-            boxed_callback_string!("indy_build_get_validator_info_request", cb, command_handle)
+            with_timeout(boxed_callback_string!("indy_build_get_validator_info_request", cb, command_handle))
         )));
 
     prepare_result!(result)
@@ -1015,18 +2351,19 @@ pub extern fn indy_build_get_txn_request(command_handle: CommandHandle,
                                                               request_json: *const c_char)>) -> ErrorCode {
     trace!("indy_build_get_txn_request: >>> submitter_did: {:?}, ledger_type: {:?}, seq_no: {:?}", submitter_did, ledger_type, seq_no);
 
+    command_params!(submitter_did, ledger_type, seq_no);
+
     check_useful_validatable_opt_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
     check_useful_opt_c_str!(ledger_type, ErrorCode::CommonInvalidParam4);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
 
     trace!("indy_build_get_txn_request: entities >>> submitter_did: {:?}, ledger_type: {:?}, seq_no: {:?}", submitter_did, ledger_type, seq_no);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildGetTxnRequest(
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::BuildGetTxnRequest(
             submitter_did,
             ledger_type,
             seq_no,
-            boxed_callback_string!("indy_build_get_txn_request", cb, command_handle)
+            with_timeout(boxed_callback_string!("indy_build_get_txn_request", cb, command_handle))
         )));
 
     let res = prepare_result!(result);
@@ -1063,17 +2400,18 @@ pub extern fn indy_build_pool_config_request(command_handle: CommandHandle,
                                                                   request_json: *const c_char)>) -> ErrorCode {
     trace!("indy_build_pool_config_request: >>> submitter_did: {:?}, writes: {:?}, force: {:?}", submitter_did, writes, force);
 
+    command_params!(submitter_did, writes, force);
+
     check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
 
     trace!("indy_build_pool_config_request: entities >>> submitter_did: {:?}, writes: {:?}, force: {:?}", submitter_did, writes, force);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildPoolConfigRequest(
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::BuildPoolConfigRequest(
             submitter_did,
             writes,
             force,
-            boxed_callback_string!("indy_build_pool_config_request", cb, command_handle)
+            with_timeout(boxed_callback_string!("indy_build_pool_config_request", cb, command_handle))
         )));
 
     let res = prepare_result!(result);
@@ -1107,6 +2445,8 @@ pub extern fn indy_build_pool_restart_request(command_handle: CommandHandle,
                                                                    request_json: *const c_char)>) -> ErrorCode {
     trace!("indy_build_pool_restart_request: >>> submitter_did: {:?}, action: {:?}, datetime: {:?}", submitter_did, action, datetime);
 
+    command_params!(submitter_did, action, datetime);
+
     check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
     check_useful_c_str!(action, ErrorCode::CommonInvalidParam3);
     check_useful_opt_c_str!(datetime, ErrorCode::CommonInvalidParam4);
@@ -1118,13 +2458,12 @@ pub extern fn indy_build_pool_restart_request(command_handle: CommandHandle,
         return IndyError::from_msg(IndyErrorKind::InvalidStructure, format!("Unsupported action: {}. Must be either `start` or `cancel`", action)).into();
     }
 
-    let result = CommandExecutor::instance()
-        .send(Command::Ledger(
+    let result = CommandExecutor::submit(Command::Ledger(
             LedgerCommand::BuildPoolRestartRequest(
                 submitter_did,
                 action,
                 datetime,
-                boxed_callback_string!("indy_build_pool_restart_request", cb, command_handle)
+                with_timeout(boxed_callback_string!("indy_build_pool_restart_request", cb, command_handle))
             )));
 
     let res = prepare_result!(result);
@@ -1181,6 +2520,8 @@ pub extern fn indy_build_pool_upgrade_request(command_handle: CommandHandle,
     schedule: {:?}, justification: {:?}, reinstall: {:?}, force: {:?}, package: {:?}",
            submitter_did, name, version, action, sha256, timeout, schedule, justification, reinstall, force, package);
 
+    command_params!(submitter_did, name, version, action, sha256, timeout, schedule, justification, reinstall, force, package);
+
     check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
     check_useful_c_str!(name, ErrorCode::CommonInvalidParam3);
     check_useful_c_str!(version, ErrorCode::CommonInvalidParam4);
@@ -1205,8 +2546,7 @@ pub extern fn indy_build_pool_upgrade_request(command_handle: CommandHandle,
         return IndyError::from_msg(IndyErrorKind::InvalidStructure, format!("Schedule is required for `{}` action", action)).into();
     }
 
-    let result = CommandExecutor::instance()
-        .send(Command::Ledger(
+    let result = CommandExecutor::submit(Command::Ledger(
             LedgerCommand::BuildPoolUpgradeRequest(
                 submitter_did,
                 name,
@@ -1219,7 +2559,7 @@ pub extern fn indy_build_pool_upgrade_request(command_handle: CommandHandle,
                 reinstall,
                 force,
                 package,
-                boxed_callback_string!("indy_build_pool_upgrade_request", cb, command_handle)
+                with_timeout(boxed_callback_string!("indy_build_pool_upgrade_request", cb, command_handle))
             )));
 
     let res = prepare_result!(result);
@@ -1229,6 +2569,71 @@ pub extern fn indy_build_pool_upgrade_request(command_handle: CommandHandle,
     res
 }
 
+/// Builds a POOL_UPGRADE request starting the upgrade by the schedule defined relative to a start time.
+/// Nodes are referenced by aliases which are resolved to Node DIDs using the ledger of the opened pool.
+/// Only validator nodes of the pool can be scheduled.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// submitter_did: Identifier (DID) of the transaction author as base58-encoded string.
+///                Actual request sender may differ if Endorser is used (look at `indy_append_request_endorser`)
+/// upgrade_json: upgrade description:
+///     {
+///         "name": string - human-readable name for the upgrade,
+///         "version": string - the version of indy-node package we perform upgrade to,
+///         "sha256": string - sha256 hash of the package (64 hex characters),
+///         "package": (optional) string - package to be upgraded,
+///         "startTime": int - upgrade start time as UNIX timestamp in seconds,
+///         "nodeOffsets": {<node alias>: <offset in seconds from start time>, ...},
+///         "timeout": (optional) int - limits upgrade time on each Node,
+///         "justification": (optional) string - justification for this particular Upgrade,
+///         "reinstall": (optional) bool - whether it's allowed to re-install the same version. False by default,
+///         "force": (optional) bool - whether we should apply transaction without waiting for consensus. False by default
+///     }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Request result as json.
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_build_scheduled_pool_upgrade_request(command_handle: CommandHandle,
+                                                        pool_handle: PoolHandle,
+                                                        submitter_did: *const c_char,
+                                                        upgrade_json: *const c_char,
+                                                        cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                             err: ErrorCode,
+                                                                             request_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_build_scheduled_pool_upgrade_request: >>> pool_handle: {:?}, submitter_did: {:?}, upgrade_json: {:?}",
+           pool_handle, submitter_did, upgrade_json);
+
+    command_params!(pool_handle, submitter_did, upgrade_json);
+
+    check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam3, DidValue);
+    check_useful_validatable_json!(upgrade_json, ErrorCode::CommonInvalidParam4, PoolUpgradeScheduleData);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_build_scheduled_pool_upgrade_request: entities >>> pool_handle: {:?}, submitter_did: {:?}, upgrade_json: {:?}",
+           pool_handle, submitter_did, upgrade_json);
+
+    let result = CommandExecutor::submit(Command::Ledger(
+            LedgerCommand::BuildScheduledPoolUpgradeRequest(
+                pool_handle,
+                submitter_did,
+                upgrade_json,
+                with_timeout(boxed_callback_string!("indy_build_scheduled_pool_upgrade_request", cb, command_handle))
+            )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_build_scheduled_pool_upgrade_request: <<< res: {:?}", res);
+
+    res
+}
+
 /// Builds a REVOC_REG_DEF request. Request to add the definition of revocation registry
 /// to an exists credential definition.
 ///
@@ -1267,17 +2672,18 @@ pub extern fn indy_build_revoc_reg_def_request(command_handle: CommandHandle,
                                                                     rev_reg_def_req: *const c_char)>) -> ErrorCode {
     trace!("indy_build_revoc_reg_def_request: >>> submitter_did: {:?}, data: {:?}", submitter_did, data);
 
+    command_params!(submitter_did, data);
+
     check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
     check_useful_validatable_json!(data, ErrorCode::CommonInvalidParam3, RevocationRegistryDefinition);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     trace!("indy_build_revoc_reg_def_request: entities >>> submitter_did: {:?}, data: {:?}", submitter_did, data);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildRevocRegDefRequest(
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::BuildRevocRegDefRequest(
             submitter_did,
             data,
-            boxed_callback_string!("indy_build_revoc_reg_def_request", cb, command_handle)
+            with_timeout(boxed_callback_string!("indy_build_revoc_reg_def_request", cb, command_handle))
         )));
 
     let res = prepare_result!(result);
@@ -1310,17 +2716,18 @@ pub extern fn indy_build_get_revoc_reg_def_request(command_handle: CommandHandle
                                                                         request_json: *const c_char)>) -> ErrorCode {
     trace!("indy_build_get_revoc_reg_def_request: >>> submitter_did: {:?}, id: {:?}", submitter_did, id);
 
+    command_params!(submitter_did, id);
+
     check_useful_validatable_opt_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
     check_useful_validatable_string!(id, ErrorCode::CommonInvalidParam3, RevocationRegistryId);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     trace!("indy_build_get_revoc_reg_def_request: entities>>> submitter_did: {:?}, id: {:?}", submitter_did, id);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildGetRevocRegDefRequest(
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::BuildGetRevocRegDefRequest(
             submitter_did,
             id,
-            boxed_callback_string!("indy_build_get_revoc_reg_def_request", cb, command_handle)
+            with_timeout(boxed_callback_string!("indy_build_get_revoc_reg_def_request", cb, command_handle))
         )));
 
     let res = prepare_result!(result);
@@ -1366,15 +2773,16 @@ pub extern fn indy_parse_get_revoc_reg_def_response(command_handle: CommandHandl
                                                                          revoc_reg_def_json: *const c_char)>) -> ErrorCode {
     trace!("indy_parse_get_revoc_reg_def_response: >>> get_revoc_reg_def_response: {:?}", get_revoc_reg_def_response);
 
+    command_params!(get_revoc_reg_def_response);
+
     check_useful_c_str!(get_revoc_reg_def_response, ErrorCode::CommonInvalidParam2);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
 
     trace!("indy_parse_get_revoc_reg_def_response: entities >>> get_revoc_reg_def_response: {:?}", get_revoc_reg_def_response);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::ParseGetRevocRegDefResponse(
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::ParseGetRevocRegDefResponse(
             get_revoc_reg_def_response,
-            Box::new(move |result| {
+            with_timeout(move |result| {
                 let (err, revoc_reg_def_id, revoc_reg_def_json) = prepare_result_2!(result, String::new(), String::new());
                 trace!("indy_parse_get_revoc_reg_def_response: revoc_reg_def_id: {:?}, revoc_reg_def_json: {:?}", revoc_reg_def_id, revoc_reg_def_json);
 
@@ -1430,6 +2838,8 @@ pub extern fn indy_build_revoc_reg_entry_request(command_handle: CommandHandle,
     trace!("indy_build_revoc_reg_entry_request: >>> submitter_did: {:?}, revoc_reg_def_id: {:?}, rev_def_type: {:?}, value: {:?}",
            submitter_did, revoc_reg_def_id, rev_def_type, value);
 
+    command_params!(submitter_did, revoc_reg_def_id, rev_def_type, value);
+
     check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
     check_useful_validatable_string!(revoc_reg_def_id, ErrorCode::CommonInvalidParam3, RevocationRegistryId);
     check_useful_c_str!(rev_def_type, ErrorCode::CommonInvalidParam4);
@@ -1439,13 +2849,12 @@ pub extern fn indy_build_revoc_reg_entry_request(command_handle: CommandHandle,
     trace!("indy_build_revoc_reg_entry_request: entities >>> submitter_did: {:?}, revoc_reg_def_id: {:?}, rev_def_type: {:?}, value: {:?}",
            submitter_did, revoc_reg_def_id, rev_def_type, value);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildRevocRegEntryRequest(
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::BuildRevocRegEntryRequest(
             submitter_did,
             revoc_reg_def_id,
             rev_def_type,
             value,
-            boxed_callback_string!("indy_build_revoc_reg_entry_request", cb, command_handle)
+            with_timeout(boxed_callback_string!("indy_build_revoc_reg_entry_request", cb, command_handle))
         )));
 
     let res = prepare_result!(result);
@@ -1480,18 +2889,19 @@ pub extern fn indy_build_get_revoc_reg_request(command_handle: CommandHandle,
                                                                     request_json: *const c_char)>) -> ErrorCode {
     trace!("indy_build_get_revoc_reg_request: >>> submitter_did: {:?}, revoc_reg_def_id: {:?}, timestamp: {:?}", submitter_did, revoc_reg_def_id, timestamp);
 
+    command_params!(submitter_did, revoc_reg_def_id, timestamp);
+
     check_useful_validatable_opt_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
     check_useful_validatable_string!(revoc_reg_def_id, ErrorCode::CommonInvalidParam3, RevocationRegistryId);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
 
     trace!("indy_build_get_revoc_reg_request: entities >>> submitter_did: {:?}, revoc_reg_def_id: {:?}, timestamp: {:?}", submitter_did, revoc_reg_def_id, timestamp);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildGetRevocRegRequest(
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::BuildGetRevocRegRequest(
             submitter_did,
             revoc_reg_def_id,
             timestamp,
-            boxed_callback_string!("indy_build_get_revoc_reg_request", cb, command_handle)
+            with_timeout(boxed_callback_string!("indy_build_get_revoc_reg_request", cb, command_handle))
         )));
 
     let res = prepare_result!(result);
@@ -1529,15 +2939,16 @@ pub extern fn indy_parse_get_revoc_reg_response(command_handle: CommandHandle,
                                                                      timestamp: u64)>) -> ErrorCode {
     trace!("indy_parse_get_revoc_reg_response: >>> get_revoc_reg_response: {:?}", get_revoc_reg_response);
 
+    command_params!(get_revoc_reg_response);
+
     check_useful_c_str!(get_revoc_reg_response, ErrorCode::CommonInvalidParam2);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
 
     trace!("indy_parse_get_revoc_reg_response: entities >>> get_revoc_reg_response: {:?}", get_revoc_reg_response);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::ParseGetRevocRegResponse(
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::ParseGetRevocRegResponse(
             get_revoc_reg_response,
-            Box::new(move |result| {
+            with_timeout(move |result| {
                 let (err, revoc_reg_def_id, revoc_reg_json, timestamp) = prepare_result_3!(result, String::new(), String::new(), 0);
                 trace!("indy_parse_get_revoc_reg_response: revoc_reg_def_id: {:?}, revoc_reg_json: {:?}, timestamp: {:?}",
                        revoc_reg_def_id, revoc_reg_json, timestamp);
@@ -1584,6 +2995,8 @@ pub extern fn indy_build_get_revoc_reg_delta_request(command_handle: CommandHand
     trace!("indy_build_get_revoc_reg_request: >>> submitter_did: {:?}, revoc_reg_def_id: {:?}, from: {:?}, to: {:?}",
            submitter_did, revoc_reg_def_id, from, to);
 
+    command_params!(submitter_did, revoc_reg_def_id, from, to);
+
     check_useful_validatable_opt_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
     check_useful_validatable_string!(revoc_reg_def_id, ErrorCode::CommonInvalidParam3, RevocationRegistryId);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
@@ -1593,13 +3006,12 @@ pub extern fn indy_build_get_revoc_reg_delta_request(command_handle: CommandHand
     trace!("indy_build_get_revoc_reg_request: entities >>> submitter_did: {:?}, revoc_reg_def_id: {:?}, from: {:?}, to: {:?}",
            submitter_did, revoc_reg_def_id, from, to);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildGetRevocRegDeltaRequest(
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::BuildGetRevocRegDeltaRequest(
             submitter_did,
             revoc_reg_def_id,
             from,
             to,
-            boxed_callback_string!("indy_build_get_revoc_reg_request", cb, command_handle)
+            with_timeout(boxed_callback_string!("indy_build_get_revoc_reg_request", cb, command_handle))
         )));
 
     let res = prepare_result!(result);
@@ -1640,15 +3052,16 @@ pub extern fn indy_parse_get_revoc_reg_delta_response(command_handle: CommandHan
                                                                            timestamp: u64)>) -> ErrorCode {
     trace!("indy_parse_get_revoc_reg_delta_response: >>> get_revoc_reg_delta_response: {:?}", get_revoc_reg_delta_response);
 
+    command_params!(get_revoc_reg_delta_response);
+
     check_useful_c_str!(get_revoc_reg_delta_response, ErrorCode::CommonInvalidParam2);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
 
     trace!("indy_parse_get_revoc_reg_delta_response: entities >>> get_revoc_reg_delta_response: {:?}", get_revoc_reg_delta_response);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::ParseGetRevocRegDeltaResponse(
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::ParseGetRevocRegDeltaResponse(
             get_revoc_reg_delta_response,
-            Box::new(move |result| {
+            with_timeout(move |result| {
                 let (err, revoc_reg_def_id, revoc_reg_delta_json, timestamp) = prepare_result_3!(result, String::new(), String::new(), 0);
                 trace!("indy_parse_get_revoc_reg_delta_response: revoc_reg_def_id: {:?}, revoc_reg_delta_json: {:?}, timestamp: {:?}",
                        revoc_reg_def_id, revoc_reg_delta_json, timestamp);
@@ -1681,6 +3094,55 @@ pub type CustomTransactionParser = extern fn(reply_from_node: *const c_char, par
 /// Callback type to deallocate result buffer `parsed_sp` from `CustomTransactionParser`
 pub type CustomFree = extern fn(data: *const c_char) -> ErrorCode;
 
+/// Callback type for building operation of custom transaction from transaction specific data
+///
+/// # params
+/// data_json: transaction specific data passed to `indy_build_custom_request` ("as is")
+/// operation_json: out param to return serialized as string JSON object with request operation.
+///                 `type` field of the operation is set by Libindy.
+///
+/// # return
+/// result ErrorCode
+///
+/// Note: this method allocate memory for result string `CustomFree` should be called to deallocate it
+pub type CustomTransactionBuilder = extern fn(data_json: *const c_char, operation_json: *mut *const c_char) -> ErrorCode;
+
+/// Callback type for parsing Reply from Node for custom transaction
+///
+/// # params
+/// response_json: string representation of node's reply ("as is")
+/// parsed_json: out param to return serialized as string JSON with parsed data
+///
+/// # return
+/// result ErrorCode
+///
+/// Note: this method allocate memory for result string `CustomFree` should be called to deallocate it
+pub type CustomResponseParser = extern fn(response_json: *const c_char, parsed_json: *mut *const c_char) -> ErrorCode;
+
+/// Callback type for observing and changing of a request before its submission to the pool
+///
+/// # params
+/// request_json: request to submit (as built or returned by the previously applied middleware)
+/// out_request_json: out param to return the request to submit
+///
+/// # return
+/// result ErrorCode. Submission is failed with the returned error if it isn't Success.
+///
+/// Note: this method allocate memory for result string `CustomFree` should be called to deallocate it
+pub type CustomRequestMiddleware = extern fn(request_json: *const c_char, out_request_json: *mut *const c_char) -> ErrorCode;
+
+/// Callback type for observing and changing of a reply from the pool before it's returned to the caller
+///
+/// # params
+/// reply_json: reply from the pool (as received or returned by the previously applied middleware)
+/// out_reply_json: out param to return the reply to pass to the caller
+///
+/// # return
+/// result ErrorCode. Submission is failed with the returned error if it isn't Success.
+///
+/// Note: this method allocate memory for result string `CustomFree` should be called to deallocate it
+pub type CustomReplyMiddleware = extern fn(reply_json: *const c_char, out_reply_json: *mut *const c_char) -> ErrorCode;
+
 
 /// Register callbacks (see type description for `CustomTransactionParser` and `CustomFree`
 ///
@@ -1705,6 +3167,8 @@ pub extern fn indy_register_transaction_parser_for_sp(command_handle: CommandHan
     trace!("indy_register_transaction_parser_for_sp: >>> txn_type {:?}, parser {:?}, free {:?}",
            txn_type, parser, free);
 
+    command_params!(txn_type);
+
     check_useful_c_str!(txn_type, ErrorCode::CommonInvalidParam2);
     check_useful_c_callback!(parser, ErrorCode::CommonInvalidParam3);
     check_useful_c_callback!(free, ErrorCode::CommonInvalidParam4);
@@ -1713,12 +3177,11 @@ pub extern fn indy_register_transaction_parser_for_sp(command_handle: CommandHan
     trace!("indy_register_transaction_parser_for_sp: entities: txn_type {}, parser {:?}, free {:?}",
            txn_type, parser, free);
 
-    let res = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::RegisterSPParser(
+    let res = CommandExecutor::submit(Command::Ledger(LedgerCommand::RegisterSPParser(
             txn_type,
             parser,
             free,
-            Box::new(move |res| {
+            with_timeout(move |res| {
                 let res = prepare_result!(res);
                 trace!("indy_register_transaction_parser_for_sp: res: {:?}", res);
                 cb(command_handle, res)
@@ -1732,6 +3195,250 @@ pub extern fn indy_register_transaction_parser_for_sp(command_handle: CommandHan
     res
 }
 
+/// Register callbacks (see type description for `CustomTransactionBuilder`, `CustomResponseParser` and `CustomFree`)
+/// to build requests and parse responses of custom transaction type (f.e. network specific config transaction).
+///
+/// Requests built by `indy_build_custom_request` can be signed, endorsed, extended with TAA acceptance
+/// and submitted the same way as requests of default transaction types.
+///
+/// # params
+/// command_handle: command handle to map callback to caller context.
+/// txn_type: type of transaction to apply callbacks. Default transaction types can't be overridden.
+/// builder: required callback to build request operation.
+/// parser: (Optional) callback to parse reply.
+/// free: required callback to deallocate memory.
+/// cb: Callback that takes command result as parameter.
+///
+/// # returns
+/// Status of callbacks registration.
+///
+/// # errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_register_ledger_transaction_builder(command_handle: CommandHandle,
+                                                       txn_type: *const c_char,
+                                                       builder: Option<CustomTransactionBuilder>,
+                                                       parser: Option<CustomResponseParser>,
+                                                       free: Option<CustomFree>,
+                                                       cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_register_ledger_transaction_builder: >>> txn_type {:?}, builder {:?}, parser {:?}, free {:?}",
+           txn_type, builder, parser, free);
+
+    command_params!(txn_type);
+
+    check_useful_c_str!(txn_type, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(builder, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(free, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_register_ledger_transaction_builder: entities: txn_type {}, builder {:?}, parser {:?}, free {:?}",
+           txn_type, builder, parser, free);
+
+    let res = CommandExecutor::submit(Command::Ledger(LedgerCommand::RegisterTxnBuilder(
+            txn_type,
+            builder,
+            parser,
+            free,
+            with_timeout(move |res| {
+                let res = prepare_result!(res);
+                trace!("indy_register_ledger_transaction_builder: res: {:?}", res);
+                cb(command_handle, res)
+            }),
+        )));
+
+    let res = prepare_result!(res);
+
+    trace!("indy_register_ledger_transaction_builder: <<< res: {:?}", res);
+
+    res
+}
+
+/// Register ledger middleware callbacks (see type description for `CustomRequestMiddleware`, `CustomReplyMiddleware` and `CustomFree`)
+/// to observe and change requests submitted to the pool and replies received from it (f.e. for audit capture or signing proxies).
+///
+/// Middlewares are applied to all requests submitted by Libindy to any pool.
+/// Request middlewares are applied in order of registration and reply middlewares in reverse order.
+///
+/// # params
+/// command_handle: command handle to map callback to caller context.
+/// request_middleware: (Optional) callback to apply to outgoing requests.
+/// reply_middleware: (Optional) callback to apply to incoming replies.
+///                   At least one of the callbacks must be set.
+/// free: required callback to deallocate memory.
+/// cb: Callback that takes command result as parameter.
+///
+/// # returns
+/// Handle of the middleware.
+///
+/// # errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_register_ledger_middleware(command_handle: CommandHandle,
+                                              request_middleware: Option<CustomRequestMiddleware>,
+                                              reply_middleware: Option<CustomReplyMiddleware>,
+                                              free: Option<CustomFree>,
+                                              cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                   err: ErrorCode,
+                                                                   middleware_handle: IndyHandle)>) -> ErrorCode {
+    trace!("indy_register_ledger_middleware: >>> request_middleware {:?}, reply_middleware {:?}, free {:?}",
+           request_middleware, reply_middleware, free);
+
+    command_params!();
+
+    check_useful_c_callback!(free, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_register_ledger_middleware: entities: request_middleware {:?}, reply_middleware {:?}, free {:?}",
+           request_middleware, reply_middleware, free);
+
+    let res = CommandExecutor::submit(Command::Ledger(LedgerCommand::RegisterLedgerMiddleware(
+            request_middleware,
+            reply_middleware,
+            free,
+            with_timeout(move |result| {
+                let (err, middleware_handle) = prepare_result_1!(result, 0);
+                trace!("indy_register_ledger_middleware: middleware_handle: {:?}", middleware_handle);
+                cb(command_handle, err, middleware_handle)
+            }),
+        )));
+
+    let res = prepare_result!(res);
+
+    trace!("indy_register_ledger_middleware: <<< res: {:?}", res);
+
+    res
+}
+
+/// Unregister ledger middleware registered by `indy_register_ledger_middleware`.
+///
+/// # params
+/// command_handle: command handle to map callback to caller context.
+/// middleware_handle: handle of the middleware.
+/// cb: Callback that takes command result as parameter.
+///
+/// # returns
+/// Error code
+///
+/// # errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_unregister_ledger_middleware(command_handle: CommandHandle,
+                                                middleware_handle: IndyHandle,
+                                                cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                     err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_unregister_ledger_middleware: >>> middleware_handle: {:?}", middleware_handle);
+
+    command_params!(middleware_handle);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    let res = CommandExecutor::submit(Command::Ledger(LedgerCommand::UnregisterLedgerMiddleware(
+            middleware_handle,
+            with_timeout(move |res| {
+                let res = prepare_result!(res);
+                trace!("indy_unregister_ledger_middleware: res: {:?}", res);
+                cb(command_handle, res)
+            }),
+        )));
+
+    let res = prepare_result!(res);
+
+    trace!("indy_unregister_ledger_middleware: <<< res: {:?}", res);
+
+    res
+}
+
+/// Builds a request of custom transaction type by the builder registered with `indy_register_ledger_transaction_builder`.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// submitter_did: (Optional) DID of the request sender (if not provided then default Libindy DID will be used).
+/// txn_type: type of custom transaction.
+/// data_json: transaction specific data passed to the registered builder.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Request result as json.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_build_custom_request(command_handle: CommandHandle,
+                                        submitter_did: *const c_char,
+                                        txn_type: *const c_char,
+                                        data_json: *const c_char,
+                                        cb: Option<extern fn(command_handle_: CommandHandle,
+                                                             err: ErrorCode,
+                                                             request_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_build_custom_request: >>> submitter_did: {:?}, txn_type: {:?}, data_json: {:?}", submitter_did, txn_type, data_json);
+
+    command_params!(submitter_did, txn_type, data_json);
+
+    check_useful_validatable_opt_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
+    check_useful_c_str!(txn_type, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(data_json, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_build_custom_request: entities >>> submitter_did: {:?}, txn_type: {:?}, data_json: {:?}", submitter_did, txn_type, data_json);
+
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::BuildCustomRequest(
+            submitter_did,
+            txn_type,
+            data_json,
+            with_timeout(boxed_callback_string!("indy_build_custom_request", cb, command_handle))
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_build_custom_request: <<< res: {:?}", res);
+
+    res
+}
+
+/// Parses a reply for a request of custom transaction type by the parser registered with `indy_register_ledger_transaction_builder`.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// txn_type: type of custom transaction.
+/// response_json: response on a request of custom transaction type.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Json returned by the registered parser.
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_parse_custom_response(command_handle: CommandHandle,
+                                         txn_type: *const c_char,
+                                         response_json: *const c_char,
+                                         cb: Option<extern fn(command_handle_: CommandHandle,
+                                                              err: ErrorCode,
+                                                              parsed_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_parse_custom_response: >>> txn_type: {:?}, response_json: {:?}", txn_type, response_json);
+
+    command_params!(txn_type, response_json);
+
+    check_useful_c_str!(txn_type, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(response_json, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_parse_custom_response: entities >>> txn_type: {:?}, response_json: {:?}", txn_type, response_json);
+
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::ParseCustomResponse(
+            txn_type,
+            response_json,
+            with_timeout(boxed_callback_string!("indy_parse_custom_response", cb, command_handle))
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_parse_custom_response: <<< res: {:?}", res);
+
+    res
+}
+
 /// Parse transaction response to fetch metadata.
 /// The important use case for this method is validation of Node's response freshens.
 ///
@@ -1774,20 +3481,84 @@ pub extern fn indy_get_response_metadata(command_handle: CommandHandle,
                                                               response_metadata: *const c_char)>) -> ErrorCode {
     trace!("indy_get_response_metadata: >>> response: {:?}", response);
 
+    command_params!(response);
+
     check_useful_c_str!(response, ErrorCode::CommonInvalidParam2);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
 
-    trace!("indy_get_response_metadata: entities >>> response: {:?}", response);
+    trace!("indy_get_response_metadata: entities >>> response: {:?}", response);
+
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::GetResponseMetadata(
+            response,
+            with_timeout(boxed_callback_string!("indy_get_response_metadata", cb, command_handle))
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_get_response_metadata: <<< res: {:?}", res);
+
+    res
+}
+
+/// Verifies state proof and multi signature of the pool nodes for a reply on read request
+/// obtained out-of-band (f.e. through a proxy or from an audit log) without any opened pool.
+///
+/// State proof is verified the same way as for replies received by `indy_submit_request`.
+/// Note: freshness of the reply isn't checked (use `indy_get_response_metadata` for that).
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// request_json: read request the reply is received on (the state proof key is calculated from it).
+/// response_json: node's reply on the read request ("as is").
+/// bls_keys_json: BLS keys of the pool nodes (f.e. from `blskey` field of the genesis NODE transactions):
+///     {
+///         <node alias>: <base58 encoded BLS key>,
+///         ...
+///     }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// valid: true - if state proof and multi signature of at least n - f nodes are valid
+///        false - otherwise (f.e. reply doesn't contain state proof)
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_verify_state_proof(command_handle: CommandHandle,
+                                      request_json: *const c_char,
+                                      response_json: *const c_char,
+                                      bls_keys_json: *const c_char,
+                                      cb: Option<extern fn(command_handle_: CommandHandle,
+                                                           err: ErrorCode,
+                                                           valid: bool)>) -> ErrorCode {
+    trace!("indy_verify_state_proof: >>> request_json: {:?}, response_json: {:?}, bls_keys_json: {:?}",
+           request_json, response_json, bls_keys_json);
+
+    command_params!(request_json, response_json, bls_keys_json);
+
+    check_useful_c_str!(request_json, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(response_json, ErrorCode::CommonInvalidParam3);
+    check_useful_json!(bls_keys_json, ErrorCode::CommonInvalidParam4, HashMap<String, String>);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_verify_state_proof: entities >>> request_json: {:?}, response_json: {:?}, bls_keys_json: {:?}",
+           request_json, response_json, bls_keys_json);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::GetResponseMetadata(
-            response,
-            boxed_callback_string!("indy_get_response_metadata", cb, command_handle)
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::VerifyStateProof(
+            request_json,
+            response_json,
+            bls_keys_json,
+            with_timeout(move |result| {
+                let (err, valid) = prepare_result_1!(result, false);
+                trace!("indy_verify_state_proof: valid: {:?}", valid);
+                cb(command_handle, err, valid)
+            })
         )));
 
     let res = prepare_result!(result);
 
-    trace!("indy_get_response_metadata: <<< res: {:?}", res);
+    trace!("indy_verify_state_proof: <<< res: {:?}", res);
 
     res
 }
@@ -1814,15 +3585,16 @@ pub extern fn indy_build_ledgers_freeze_request(command_handle: CommandHandle,
                                                                       request_json: *const c_char)>) -> ErrorCode {
     trace!("indy_build_ledgers_freeze_request: entities >>> submitter_did: {:?}, ledgers_ids: {:?}", submitter_did, ledgers_ids);
 
+    command_params!(submitter_did, ledgers_ids);
+
     check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
     check_useful_json!(ledgers_ids, ErrorCode::CommonInvalidParam3, Vec<u64>);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildLedgersFreezeRequest(
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::BuildLedgersFreezeRequest(
             submitter_did,
             ledgers_ids,
-            boxed_callback_string!("indy_build_ledgers_freeze_request", cb, command_handle)
+            with_timeout(boxed_callback_string!("indy_build_ledgers_freeze_request", cb, command_handle))
         )));
 
     let res = prepare_result!(result);
@@ -1861,13 +3633,14 @@ pub extern fn indy_build_get_frozen_ledgers_request(command_handle: CommandHandl
                                                                 request_json: *const c_char)>) -> ErrorCode {
     trace!("indy_build_get_frozen_ledgers_request: entities >>> submitter_did: {:?}", submitter_did);
 
+    command_params!(submitter_did);
+
     check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildGetFrozenLedgersRequest(
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::BuildGetFrozenLedgersRequest(
             submitter_did,
-            boxed_callback_string!("indy_build_get_frozen_ledgers_request", cb, command_handle)
+            with_timeout(boxed_callback_string!("indy_build_get_frozen_ledgers_request", cb, command_handle))
         )));
 
     let res = prepare_result!(result);
@@ -1877,6 +3650,60 @@ pub extern fn indy_build_get_frozen_ledgers_request(command_handle: CommandHandl
     res
 }
 
+/// Parse a GET_FROZEN_LEDGERS response to get the list of frozen ledgers.
+/// Empty list is returned if there are no frozen ledgers.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// get_frozen_ledgers_response: response on GET_FROZEN_LEDGERS request.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Frozen ledgers sorted by ledger id:
+/// {
+///     data: [
+///         {
+///             ledgerId: <int> - id of the frozen ledger,
+///             ledgerRoot: <string> - ledger root hash at the moment of freezing,
+///             stateRoot: <string> - state root hash at the moment of freezing,
+///             seqNo: <int> - the latest transaction seqNo of the frozen ledger
+///         },
+///         ...
+///     ],
+///     seqNo: (optional) sequence number of the LEDGERS_FREEZE transaction,
+///     txnTime: (optional) time of the LEDGERS_FREEZE transaction
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_parse_get_frozen_ledgers_response(command_handle: CommandHandle,
+                                                     get_frozen_ledgers_response: *const c_char,
+                                                     cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                          err: ErrorCode,
+                                                                          frozen_ledgers_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_parse_get_frozen_ledgers_response: >>> get_frozen_ledgers_response: {:?}", get_frozen_ledgers_response);
+
+    command_params!(get_frozen_ledgers_response);
+
+    check_useful_c_str!(get_frozen_ledgers_response, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_parse_get_frozen_ledgers_response: entities >>> get_frozen_ledgers_response: {:?}", get_frozen_ledgers_response);
+
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::ParseGetFrozenLedgersResponse(
+            get_frozen_ledgers_response,
+            with_timeout(boxed_callback_string!("indy_parse_get_frozen_ledgers_response", cb, command_handle))
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_parse_get_frozen_ledgers_response: <<< res: {:?}", res);
+
+    res
+}
+
 /// Builds a AUTH_RULE request. Request to change authentication rules for a ledger transaction.
 ///
 /// #Params
@@ -1932,6 +3759,8 @@ pub extern fn indy_build_auth_rule_request(command_handle: CommandHandle,
     old_value: {:?}, new_value: {:?}, constraint: {:?}",
            submitter_did, txn_type, action, field, old_value, new_value, constraint);
 
+    command_params!(submitter_did, txn_type, action, field, old_value, new_value, constraint);
+
     check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
     check_useful_c_str!(txn_type, ErrorCode::CommonInvalidParam3);
     check_useful_c_str!(action, ErrorCode::CommonInvalidParam4);
@@ -1945,8 +3774,7 @@ pub extern fn indy_build_auth_rule_request(command_handle: CommandHandle,
     old_value: {:?}, new_value: {:?}, constraint: {:?}",
            submitter_did, txn_type, action, field, old_value, new_value, constraint);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildAuthRuleRequest(
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::BuildAuthRuleRequest(
             submitter_did,
             txn_type,
             action,
@@ -1954,7 +3782,7 @@ pub extern fn indy_build_auth_rule_request(command_handle: CommandHandle,
             old_value,
             new_value,
             constraint,
-            boxed_callback_string!("indy_build_auth_rule_request", cb, command_handle)
+            with_timeout(boxed_callback_string!("indy_build_auth_rule_request", cb, command_handle))
         )));
 
     let res = prepare_result!(result);
@@ -2002,6 +3830,8 @@ pub extern fn indy_build_auth_rules_request(command_handle: CommandHandle,
                                                                  request_json: *const c_char)>) -> ErrorCode {
     trace!("indy_build_auth_rules_request: >>> submitter_did: {:?}, rules: {:?}", submitter_did, rules);
 
+    command_params!(submitter_did, rules);
+
     check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
     check_useful_json!(rules, ErrorCode::CommonInvalidParam3, AuthRules);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
@@ -2012,11 +3842,10 @@ pub extern fn indy_build_auth_rules_request(command_handle: CommandHandle,
 
     trace!("indy_build_auth_rules_request: entities >>> submitter_did: {:?}, rules: {:?}", submitter_did, rules);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildAuthRulesRequest(
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::BuildAuthRulesRequest(
             submitter_did,
             rules,
-            boxed_callback_string!("indy_build_auth_rules_request", cb, command_handle)
+            with_timeout(boxed_callback_string!("indy_build_auth_rules_request", cb, command_handle))
         )));
 
     let res = prepare_result!(result);
@@ -2063,6 +3892,8 @@ pub extern fn indy_build_get_auth_rule_request(command_handle: CommandHandle,
     old_value: {:?}, new_value: {:?}",
            submitter_did, txn_type, action, field, old_value, new_value);
 
+    command_params!(submitter_did, txn_type, action, field, old_value, new_value);
+
     check_useful_validatable_opt_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
     check_useful_opt_c_str!(txn_type, ErrorCode::CommonInvalidParam3);
     check_useful_opt_c_str!(action, ErrorCode::CommonInvalidParam4);
@@ -2075,15 +3906,14 @@ pub extern fn indy_build_get_auth_rule_request(command_handle: CommandHandle,
     old_value: {:?}, new_value: {:?}",
            submitter_did, txn_type, action, field, old_value, new_value);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildGetAuthRuleRequest(
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::BuildGetAuthRuleRequest(
             submitter_did,
             txn_type,
             action,
             field,
             old_value,
             new_value,
-            boxed_callback_string!("indy_build_get_auth_rule_request", cb, command_handle)
+            with_timeout(boxed_callback_string!("indy_build_get_auth_rule_request", cb, command_handle))
         )));
 
     let res = prepare_result!(result);
@@ -2093,6 +3923,292 @@ pub extern fn indy_build_get_auth_rule_request(command_handle: CommandHandle,
     res
 }
 
+/// Builds a ROLE constraint to be used in AUTH_RULE and AUTH_RULES requests.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// role: role of a user which satisfy to constrain.
+///     Can be either role alias ("TRUSTEE", "STEWARD", "ENDORSER", "NETWORK_MONITOR", "IDENTITY_OWNER"),
+///     associated value or "*" for any role.
+/// sig_count: the number of signatures required to execution action.
+/// need_to_be_owner: if user must be an owner of transaction.
+/// off_ledger_signature: allow signature of DID unknown for the ledger (can be set only for any role).
+/// metadata_json: (Optional) additional parameters of the constraint (e.g. fees).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Constraint json (see `indy_build_auth_rule_request`).
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_build_role_constraint(command_handle: CommandHandle,
+                                         role: *const c_char,
+                                         sig_count: u32,
+                                         need_to_be_owner: bool,
+                                         off_ledger_signature: bool,
+                                         metadata_json: *const c_char,
+                                         cb: Option<extern fn(command_handle_: CommandHandle,
+                                                              err: ErrorCode,
+                                                              constraint_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_build_role_constraint: >>> role: {:?}, sig_count: {:?}, need_to_be_owner: {:?}, off_ledger_signature: {:?}, metadata_json: {:?}",
+           role, sig_count, need_to_be_owner, off_ledger_signature, metadata_json);
+
+    command_params!(role, sig_count, need_to_be_owner, off_ledger_signature, metadata_json);
+
+    check_useful_c_str!(role, ErrorCode::CommonInvalidParam2);
+    check_useful_opt_json!(metadata_json, ErrorCode::CommonInvalidParam6, serde_json::Value);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    trace!("indy_build_role_constraint: entities >>> role: {:?}, sig_count: {:?}, need_to_be_owner: {:?}, off_ledger_signature: {:?}, metadata_json: {:?}",
+           role, sig_count, need_to_be_owner, off_ledger_signature, metadata_json);
+
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::BuildRoleConstraint(
+            role,
+            sig_count,
+            need_to_be_owner,
+            off_ledger_signature,
+            metadata_json,
+            with_timeout(boxed_callback_string!("indy_build_role_constraint", cb, command_handle))
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_build_role_constraint: <<< res: {:?}", res);
+
+    res
+}
+
+/// Combines several constraints into AND or OR constraint to be used in AUTH_RULE and AUTH_RULES requests.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// combination: type of the combination. Can be either "AND" (all constraints must be met) or "OR" (any of them).
+/// constraints_json: json array of constraints to combine (see `indy_build_role_constraint`).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Constraint json (see `indy_build_auth_rule_request`).
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_build_combined_constraint(command_handle: CommandHandle,
+                                             combination: *const c_char,
+                                             constraints_json: *const c_char,
+                                             cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                  err: ErrorCode,
+                                                                  constraint_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_build_combined_constraint: >>> combination: {:?}, constraints_json: {:?}", combination, constraints_json);
+
+    command_params!(combination, constraints_json);
+
+    check_useful_c_str!(combination, ErrorCode::CommonInvalidParam2);
+    check_useful_json!(constraints_json, ErrorCode::CommonInvalidParam3, Vec<Constraint>);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_build_combined_constraint: entities >>> combination: {:?}, constraints_json: {:?}", combination, constraints_json);
+
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::BuildCombinedConstraint(
+            combination,
+            constraints_json,
+            with_timeout(boxed_callback_string!("indy_build_combined_constraint", cb, command_handle))
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_build_combined_constraint: <<< res: {:?}", res);
+
+    res
+}
+
+/// Evaluates whether the set of signers can perform the ledger write according to the auth rules.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// auth_rules_json: json array of auth rules (see `indy_parse_get_auth_rule_response`).
+/// action_json: prospective ledger write:
+///     {
+///         "auth_type": <string> - ledger transaction alias or associated value,
+///         "auth_action": <string> - type of an action. Can be either "ADD" or "EDIT",
+///         "field": <string> - transaction field,
+///         "old_value": <string> - (Optional) old value of a field (for EDIT action),
+///         "new_value": <string> - (Optional) new value of a field,
+///         "owner": <string> - (Optional) DID of the transaction owner
+///     }
+/// signers_json: json array of signers:
+///     [{
+///         "did": <string> - signer DID,
+///         "role": <string> - (Optional) signer role associated value (identity owner if not set),
+///         "onLedger": <bool> - (Optional) whether signer DID is written on the ledger (true by default),
+///         "verified": <bool> - (Optional) whether signer signature is valid (true by default)
+///     }]
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Evaluation json:
+/// {
+///     "allowed": <bool> - whether signers can perform the action,
+///     "authRule": <object> - the most specific auth rule matching the action,
+///     "missingSignatures": [{
+///         "role": <string> - (Optional) role of required signers ("*" means any role),
+///         "count": <int> - number of required signatures,
+///         "needToBeOwner": <bool> - (Optional) whether signer must be the owner of the transaction
+///     }],
+///     "forbidden": <bool> - whether action is forbidden by the auth rule
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_evaluate_auth_rules(command_handle: CommandHandle,
+                                       auth_rules_json: *const c_char,
+                                       action_json: *const c_char,
+                                       signers_json: *const c_char,
+                                       cb: Option<extern fn(command_handle_: CommandHandle,
+                                                            err: ErrorCode,
+                                                            evaluation_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_evaluate_auth_rules: >>> auth_rules_json: {:?}, action_json: {:?}, signers_json: {:?}", auth_rules_json, action_json, signers_json);
+
+    command_params!(auth_rules_json, action_json, signers_json);
+
+    check_useful_json!(auth_rules_json, ErrorCode::CommonInvalidParam2, Vec<AuthRule>);
+    check_useful_json!(action_json, ErrorCode::CommonInvalidParam3, AuthRuleAction);
+    check_useful_json!(signers_json, ErrorCode::CommonInvalidParam4, Vec<RequestSigner>);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_evaluate_auth_rules: entities >>> auth_rules_json: {:?}, action_json: {:?}, signers_json: {:?}", auth_rules_json, action_json, signers_json);
+
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::EvaluateAuthRules(
+            auth_rules_json,
+            action_json,
+            signers_json,
+            with_timeout(boxed_callback_string!("indy_evaluate_auth_rules", cb, command_handle))
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_evaluate_auth_rules: <<< res: {:?}", res);
+
+    res
+}
+
+/// Fetches the complete set of auth rules of the ledger config and returns one page of them.
+///
+/// All the rules are requested by a single GET_AUTH_RULE request and paginated on the client side
+/// over the rules sorted by transaction type, action, field, old and new values,
+/// so a page is stable while the ledger config isn't changed.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// submitter_did: (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+/// page_options_json: (Optional) pagination options (all the rules are returned if not set):
+///     {
+///         "offset": <int> - (Optional) number of rules to skip (0 by default),
+///         "limit": <int> - (Optional) maximum number of rules in the page (all remaining rules by default)
+///     }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Auth rules page json:
+/// {
+///     "rules": [<object>] - auth rules of the page (see `indy_parse_get_auth_rule_response`),
+///     "total": <int> - total number of auth rules of the ledger,
+///     "nextOffset": <int> - (Optional) offset of the next page, absent for the last page
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_get_auth_rules(command_handle: CommandHandle,
+                                  pool_handle: PoolHandle,
+                                  submitter_did: *const c_char,
+                                  page_options_json: *const c_char,
+                                  cb: Option<extern fn(command_handle_: CommandHandle,
+                                                       err: ErrorCode,
+                                                       auth_rules_page_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_get_auth_rules: >>> pool_handle: {:?}, submitter_did: {:?}, page_options_json: {:?}", pool_handle, submitter_did, page_options_json);
+
+    command_params!(pool_handle, submitter_did, page_options_json);
+
+    check_useful_validatable_opt_string!(submitter_did, ErrorCode::CommonInvalidParam3, DidValue);
+    check_useful_opt_json!(page_options_json, ErrorCode::CommonInvalidParam4, AuthRulesPageOptions);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_get_auth_rules: entities >>> pool_handle: {:?}, submitter_did: {:?}, page_options_json: {:?}", pool_handle, submitter_did, page_options_json);
+
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::GetAuthRules(
+            pool_handle,
+            submitter_did,
+            page_options_json.unwrap_or_default(),
+            with_timeout(boxed_callback_string!("indy_get_auth_rules", cb, command_handle))
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_get_auth_rules: <<< res: {:?}", res);
+
+    res
+}
+
+/// Compares the auth rules of the ledger with a proposed set of auth rules.
+///
+/// Rules are matched by transaction type (alias or associated value), action, field, old (for EDIT action) and new values.
+/// Constraints are compared structurally, absent and empty metadata are considered equal.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// current_auth_rules_json: json array of the current auth rules (see `indy_parse_get_auth_rule_response`).
+/// proposed_auth_rules_json: json array of the proposed auth rules in the same format.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Auth rules diff json:
+/// {
+///     "added": [<object>] - proposed rules missing in the current ones,
+///     "removed": [<object>] - current rules missing in the proposed ones,
+///     "changed": [{
+///         "current": <object> - current rule,
+///         "proposed": <object> - proposed rule with a different constraint
+///     }],
+///     "unchanged": <int> - number of rules with equal constraints
+/// }
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_diff_auth_rules(command_handle: CommandHandle,
+                                   current_auth_rules_json: *const c_char,
+                                   proposed_auth_rules_json: *const c_char,
+                                   cb: Option<extern fn(command_handle_: CommandHandle,
+                                                        err: ErrorCode,
+                                                        diff_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_diff_auth_rules: >>> current_auth_rules_json: {:?}, proposed_auth_rules_json: {:?}", current_auth_rules_json, proposed_auth_rules_json);
+
+    command_params!(current_auth_rules_json, proposed_auth_rules_json);
+
+    check_useful_json!(current_auth_rules_json, ErrorCode::CommonInvalidParam2, Vec<AuthRule>);
+    check_useful_json!(proposed_auth_rules_json, ErrorCode::CommonInvalidParam3, Vec<AuthRule>);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_diff_auth_rules: entities >>> current_auth_rules_json: {:?}, proposed_auth_rules_json: {:?}", current_auth_rules_json, proposed_auth_rules_json);
+
+    let result = CommandExecutor::submit(Command::Ledger(LedgerCommand::DiffAuthRules(
+            current_auth_rules_json,
+            proposed_auth_rules_json,
+            with_timeout(boxed_callback_string!("indy_diff_auth_rules", cb, command_handle))
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_diff_auth_rules: <<< res: {:?}", res);
+
+    res
+}
+
 /// Builds a TXN_AUTHR_AGRMT request. Request to add a new version of Transaction Author Agreement to the ledger.
 ///
 /// EXPERIMENTAL
@@ -2143,6 +4259,8 @@ pub extern fn indy_build_txn_author_agreement_request(command_handle: CommandHan
     trace!("indy_build_txn_author_agreement_request: >>> submitter_did: {:?}, text: {:?}, version: {:?}, ratification_ts {:?}, retirement_ts {:?}",
            submitter_did, text, version, ratification_ts, retirement_ts);
 
+    command_params!(submitter_did, text, version, ratification_ts, retirement_ts);
+
     check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
     check_useful_opt_c_str!(text, ErrorCode::CommonInvalidParam3);
     check_useful_c_str!(version, ErrorCode::CommonInvalidParam4);
@@ -2153,15 +4271,14 @@ pub extern fn indy_build_txn_author_agreement_request(command_handle: CommandHan
     trace!("indy_build_txn_author_agreement_request: entities >>> submitter_did: {:?}, text: {:?}, version: {:?}, ratification_ts {:?}, retirement_ts {:?}",
            submitter_did, text, version, ratification_ts, retirement_ts);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Ledger(
+    let result = CommandExecutor::submit(Command::Ledger(
             LedgerCommand::BuildTxnAuthorAgreementRequest(
                 submitter_did,
                 text,
                 version,
                 ratification_ts,
                 retirement_ts,
-                boxed_callback_string!("indy_build_txn_author_agreement_request", cb, command_handle)
+                with_timeout(boxed_callback_string!("indy_build_txn_author_agreement_request", cb, command_handle))
             )));
 
     let res = prepare_result!(result);
@@ -2195,16 +4312,17 @@ pub extern fn indy_build_disable_all_txn_author_agreements_request(command_handl
                                                                                         request_json: *const c_char)>) -> ErrorCode {
     trace!("indy_build_disable_all_txn_author_agreements_request: >>> submitter_did: {:?}", submitter_did);
 
+    command_params!(submitter_did);
+
     check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
 
     trace!("indy_build_disable_all_txn_author_agreements_request: entities >>> submitter_did: {:?}", submitter_did);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Ledger(
+    let result = CommandExecutor::submit(Command::Ledger(
             LedgerCommand::BuildDisableAllTxnAuthorAgreementsRequest(
                 submitter_did,
-                boxed_callback_string!("indy_build_disable_all_txn_author_agreements_request", cb, command_handle)
+                with_timeout(boxed_callback_string!("indy_build_disable_all_txn_author_agreements_request", cb, command_handle))
             )));
 
     let res = prepare_result!(result);
@@ -2246,18 +4364,19 @@ pub extern fn indy_build_get_txn_author_agreement_request(command_handle: Comman
                                                                                request_json: *const c_char)>) -> ErrorCode {
     trace!("indy_build_get_txn_author_agreement_request: >>> submitter_did: {:?}, data: {:?}?", submitter_did, data);
 
+    command_params!(submitter_did, data);
+
     check_useful_validatable_opt_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
     check_useful_opt_validatable_json!(data, ErrorCode::CommonInvalidParam3, GetTxnAuthorAgreementData);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     trace!("indy_build_get_txn_author_agreement_request: entities >>> submitter_did: {:?}, data: {:?}", submitter_did, data);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Ledger(
+    let result = CommandExecutor::submit(Command::Ledger(
             LedgerCommand::BuildGetTxnAuthorAgreementRequest(
                 submitter_did,
                 data,
-                boxed_callback_string!("indy_build_get_txn_author_agreement_request", cb, command_handle)
+                with_timeout(boxed_callback_string!("indy_build_get_txn_author_agreement_request", cb, command_handle))
             )));
 
     let res = prepare_result!(result);
@@ -2303,6 +4422,8 @@ pub extern fn indy_build_acceptance_mechanisms_request(command_handle: CommandHa
     trace!("indy_build_acceptance_mechanisms_request: >>> submitter_did: {:?}, aml: {:?}, version: {:?}, aml_context: {:?}",
            submitter_did, aml, version, aml_context);
 
+    command_params!(submitter_did, aml, version, aml_context);
+
     check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
     check_useful_validatable_json!(aml, ErrorCode::CommonInvalidParam3, AcceptanceMechanisms);
     check_useful_c_str!(version, ErrorCode::CommonInvalidParam4);
@@ -2312,14 +4433,13 @@ pub extern fn indy_build_acceptance_mechanisms_request(command_handle: CommandHa
     trace!("indy_build_acceptance_mechanisms_request: entities >>> submitter_did: {:?}, aml: {:?}, version: {:?}, aml_context: {:?}",
            submitter_did, aml, version, aml_context);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Ledger(
+    let result = CommandExecutor::submit(Command::Ledger(
             LedgerCommand::BuildAcceptanceMechanismRequests(
                 submitter_did,
                 aml,
                 version,
                 aml_context,
-                boxed_callback_string!("indy_build_acceptance_mechanisms_request", cb, command_handle)
+                with_timeout(boxed_callback_string!("indy_build_acceptance_mechanisms_request", cb, command_handle))
             )));
 
     let res = prepare_result!(result);
@@ -2358,6 +4478,8 @@ pub extern fn indy_build_get_acceptance_mechanisms_request(command_handle: Comma
                                                                                 request_json: *const c_char)>) -> ErrorCode {
     trace!("indy_build_get_acceptance_mechanisms_request: >>> submitter_did: {:?}, timestamp: {:?}, version: {:?}", submitter_did, timestamp, version);
 
+    command_params!(submitter_did, timestamp, version);
+
     check_useful_validatable_opt_string!(submitter_did, ErrorCode::CommonInvalidParam2, DidValue);
     check_useful_opt_c_str!(version, ErrorCode::CommonInvalidParam4);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
@@ -2366,13 +4488,12 @@ pub extern fn indy_build_get_acceptance_mechanisms_request(command_handle: Comma
 
     trace!("indy_build_get_acceptance_mechanisms_request: entities >>> submitter_did: {:?}, timestamp: {:?}, version: {:?}", submitter_did, timestamp, version);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Ledger(
+    let result = CommandExecutor::submit(Command::Ledger(
             LedgerCommand::BuildGetAcceptanceMechanismsRequest(
                 submitter_did,
                 timestamp,
                 version,
-                boxed_callback_string!("indy_build_get_acceptance_mechanisms_request", cb, command_handle)
+                with_timeout(boxed_callback_string!("indy_build_get_acceptance_mechanisms_request", cb, command_handle))
             )));
 
     let res = prepare_result!(result);
@@ -2424,6 +4545,8 @@ pub extern fn indy_append_txn_author_agreement_acceptance_to_request(command_han
         mechanism: {:?}, time: {:?}",
            request_json, text, version, taa_digest, mechanism, time);
 
+    command_params!(request_json, text, version, taa_digest, mechanism, time);
+
     check_useful_c_str!(request_json, ErrorCode::CommonInvalidParam2);
     check_useful_opt_c_str!(text, ErrorCode::CommonInvalidParam3);
     check_useful_opt_c_str!(version, ErrorCode::CommonInvalidParam4);
@@ -2435,8 +4558,7 @@ pub extern fn indy_append_txn_author_agreement_acceptance_to_request(command_han
         mechanism: {:?}, time: {:?}",
            request_json, text, version, taa_digest, mechanism, time);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Ledger(
+    let result = CommandExecutor::submit(Command::Ledger(
             LedgerCommand::AppendTxnAuthorAgreementAcceptanceToRequest(
                 request_json,
                 text,
@@ -2444,7 +4566,7 @@ pub extern fn indy_append_txn_author_agreement_acceptance_to_request(command_han
                 taa_digest,
                 mechanism,
                 time,
-                boxed_callback_string!("indy_append_txn_author_agreement_acceptance_to_request", cb, command_handle)
+                with_timeout(boxed_callback_string!("indy_append_txn_author_agreement_acceptance_to_request", cb, command_handle))
             )));
 
     let res = prepare_result!(result);
@@ -2454,6 +4576,76 @@ pub extern fn indy_append_txn_author_agreement_acceptance_to_request(command_han
     res
 }
 
+/// Prepares a request for signing with acceptance of the latest transaction author agreement set on the Ledger.
+/// This function should be called instead of `indy_append_txn_author_agreement_acceptance_to_request`
+/// if an application doesn't track the agreement by itself.
+///
+/// EXPERIMENTAL
+///
+/// Latest agreement and acceptance mechanisms are requested from the Ledger and cached for the pool for 10 minutes.
+/// Acceptance is stored in the wallet for the submitter DID and reused for all the following requests,
+/// so time of acceptance is kept unchanged until the Ledger sets a new agreement.
+/// Time of acceptance is rounded to the date and never precedes the agreement transaction.
+/// The request is returned unchanged if there is no agreement set on the Ledger.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// wallet_handle: wallet handle (created by open_wallet).
+/// submitter_did: Id of Identity stored in secured Wallet that is going to sign the request.
+/// request_json: original request data json.
+/// mechanism: (Optional) mechanism how user has accepted the TAA.
+///     Can be omitted if the previous acceptance of the agreement was made by the submitter or
+///     if the Ledger allows the only acceptance mechanism.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Updated request result as json.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_prepare_request_with_taa(command_handle: CommandHandle,
+                                            pool_handle: PoolHandle,
+                                            wallet_handle: WalletHandle,
+                                            submitter_did: *const c_char,
+                                            request_json: *const c_char,
+                                            mechanism: *const c_char,
+                                            cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                 err: ErrorCode,
+                                                                 request_with_meta_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_prepare_request_with_taa: >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, request_json: {:?}, mechanism: {:?}",
+           pool_handle, wallet_handle, submitter_did, request_json, mechanism);
+
+    command_params!(pool_handle, wallet_handle, submitter_did, request_json, mechanism);
+
+    check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam4, DidValue);
+    check_useful_c_str!(request_json, ErrorCode::CommonInvalidParam5);
+    check_useful_opt_c_str!(mechanism, ErrorCode::CommonInvalidParam6);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    trace!("indy_prepare_request_with_taa: entities >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, request_json: {:?}, mechanism: {:?}",
+           pool_handle, wallet_handle, submitter_did, request_json, mechanism);
+
+    let result = CommandExecutor::submit(Command::Ledger(
+            LedgerCommand::PrepareRequestWithTaa(
+                pool_handle,
+                wallet_handle,
+                submitter_did,
+                request_json,
+                mechanism,
+                with_timeout(boxed_callback_string!("indy_prepare_request_with_taa", cb, command_handle))
+            )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_prepare_request_with_taa: <<< res: {:?}", res);
+
+    res
+}
+
 /// Append Endorser to an existing request.
 ///
 /// An author of request still is a `DID` used as a `submitter_did` parameter for the building of the request.
@@ -2483,18 +4675,19 @@ pub extern fn indy_append_request_endorser(command_handle: CommandHandle,
     trace!("indy_append_request_endorser: >>> request_json: {:?}, endorser_did: {:?}",
            request_json, endorser_did);
 
+    command_params!(request_json, endorser_did);
+
     check_useful_c_str!(request_json, ErrorCode::CommonInvalidParam2);
     check_useful_validatable_string!(endorser_did, ErrorCode::CommonInvalidParam3, DidValue);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     trace!("indy_append_request_endorser: entities >>> request_json: {:?},endorser_did: {:?}", request_json, endorser_did);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Ledger(
+    let result = CommandExecutor::submit(Command::Ledger(
             LedgerCommand::AppendRequestEndorser(
                 request_json,
                 endorser_did,
-                boxed_callback_string!("indy_append_request_endorser", cb, command_handle)
+                with_timeout(boxed_callback_string!("indy_append_request_endorser", cb, command_handle))
             )));
 
     let res = prepare_result!(result);
@@ -2502,4 +4695,228 @@ pub extern fn indy_append_request_endorser(command_handle: CommandHandle,
     trace!("indy_append_request_endorser: <<< res: {:?}", res);
 
     res
-}
\ No newline at end of file
+}
+
+/// Sets protocol version of an existing request.
+///
+/// Requests are built with the protocol version set by `indy_set_protocol_version` (2 - used default).
+/// This call allows to prepare requests for a specific version of Indy Node without an opened pool
+/// and without changing the global PROTOCOL_VERSION (f.e. for offline signing of transactions sent from another machine).
+///
+/// Note: Request must be signed after that.
+///
+/// #Params
+/// request_json: original request
+/// protocol_version: Protocol version will be used:
+///     1 - for Indy Node 1.3
+///     2 - for Indy Node 1.4 and greater
+///     Credential definition tag isn't supported by protocol version 1 and is removed from CRED_DEF request.
+/// cb: Callback that takes command result as parameter.
+///     The command result is a request JSON with protocol version set.
+///
+/// #Errors
+/// Common*
+/// PoolIncompatibleProtocolVersion
+#[no_mangle]
+pub extern fn indy_set_request_protocol_version(command_handle: CommandHandle,
+                                                request_json: *const c_char,
+                                                protocol_version: usize,
+                                                cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                     err: ErrorCode,
+                                                                     out_request_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_set_request_protocol_version: >>> request_json: {:?}, protocol_version: {:?}",
+           request_json, protocol_version);
+
+    command_params!(request_json, protocol_version);
+
+    check_useful_c_str!(request_json, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_set_request_protocol_version: entities >>> request_json: {:?}, protocol_version: {:?}", request_json, protocol_version);
+
+    let result = CommandExecutor::submit(Command::Ledger(
+            LedgerCommand::SetRequestProtocolVersion(
+                request_json,
+                protocol_version,
+                with_timeout(boxed_callback_string!("indy_set_request_protocol_version", cb, command_handle))
+            )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_set_request_protocol_version: <<< res: {:?}", res);
+
+    res
+}
+
+context_api! {
+    indy_sign_and_submit_request_ctx => indy_sign_and_submit_request(pool_handle: PoolHandle, wallet_handle: WalletHandle, submitter_did: *const c_char, request_json: *const c_char)
+        -> cb(request_result_json: *const c_char), CommonInvalidParam6;
+    indy_submit_request_ctx => indy_submit_request(pool_handle: PoolHandle, request_json: *const c_char)
+        -> cb(request_result_json: *const c_char), CommonInvalidParam4;
+    indy_submit_request_with_options_ctx => indy_submit_request_with_options(pool_handle: PoolHandle, request_json: *const c_char, options_json: *const c_char)
+        -> cb(request_result_json: *const c_char), CommonInvalidParam5;
+    indy_submit_request_idempotent_ctx => indy_submit_request_idempotent(pool_handle: PoolHandle, request_json: *const c_char)
+        -> cb(submit_result_json: *const c_char), CommonInvalidParam4;
+    indy_submit_requests_ctx => indy_submit_requests(pool_handle: PoolHandle, requests_json: *const c_char)
+        -> cb(responses_json: *const c_char), CommonInvalidParam4;
+    indy_get_txns_ctx => indy_get_txns(pool_handle: PoolHandle, submitter_did: *const c_char, ledger_type: *const c_char, from: i32, to: i32)
+        -> cb(txns_json: *const c_char), CommonInvalidParam7;
+    indy_unsubscribe_ledger_events_ctx => indy_unsubscribe_ledger_events(subscription_handle: IndyHandle)
+        -> cb(), CommonInvalidParam3;
+    indy_submit_action_ctx => indy_submit_action(pool_handle: PoolHandle, request_json: *const c_char, nodes: *const c_char, timeout: i32)
+        -> cb(request_result_json: *const c_char), CommonInvalidParam6;
+    indy_sign_request_ctx => indy_sign_request(wallet_handle: WalletHandle, submitter_did: *const c_char, request_json: *const c_char)
+        -> cb(signed_request_json: *const c_char), CommonInvalidParam5;
+    indy_force_sign_request_ctx => indy_force_sign_request(wallet_handle: WalletHandle, submitter_did: *const c_char, request_json: *const c_char)
+        -> cb(signed_request_json: *const c_char), CommonInvalidParam5;
+    indy_multi_sign_request_ctx => indy_multi_sign_request(wallet_handle: WalletHandle, submitter_did: *const c_char, request_json: *const c_char)
+        -> cb(signed_request_json: *const c_char), CommonInvalidParam5;
+    indy_merge_multi_signed_requests_ctx => indy_merge_multi_signed_requests(requests_json: *const c_char)
+        -> cb(request_json: *const c_char), CommonInvalidParam3;
+    indy_check_request_signatures_ctx => indy_check_request_signatures(pool_handle: PoolHandle, request_json: *const c_char)
+        -> cb(report_json: *const c_char), CommonInvalidParam4;
+    indy_build_get_ddo_request_ctx => indy_build_get_ddo_request(submitter_did: *const c_char, target_did: *const c_char)
+        -> cb(request_json: *const c_char), CommonInvalidParam4;
+    indy_build_nym_request_ctx => indy_build_nym_request(submitter_did: *const c_char, target_did: *const c_char, verkey: *const c_char, alias: *const c_char, role: *const c_char)
+        -> cb(request_json: *const c_char), CommonInvalidParam7;
+    indy_build_nym_request_with_data_ctx => indy_build_nym_request_with_data(submitter_did: *const c_char, target_did: *const c_char, nym_data_json: *const c_char)
+        -> cb(request_json: *const c_char), CommonInvalidParam5;
+    indy_build_get_nym_request_ctx => indy_build_get_nym_request(submitter_did: *const c_char, target_did: *const c_char)
+        -> cb(request_json: *const c_char), CommonInvalidParam4;
+    indy_build_get_historical_nym_request_ctx => indy_build_get_historical_nym_request(submitter_did: *const c_char, target_did: *const c_char, seq_no: i64, timestamp: i64)
+        -> cb(request_json: *const c_char), CommonInvalidParam6;
+    indy_parse_get_nym_response_ctx => indy_parse_get_nym_response(get_nym_response: *const c_char)
+        -> cb(nym_json: *const c_char), CommonInvalidParam3;
+    indy_parse_get_attrib_response_ctx => indy_parse_get_attrib_response(get_attrib_response: *const c_char)
+        -> cb(attrib_json: *const c_char), CommonInvalidParam3;
+    indy_parse_get_txn_response_ctx => indy_parse_get_txn_response(get_txn_response: *const c_char)
+        -> cb(txn_json: *const c_char), CommonInvalidParam3;
+    indy_parse_get_auth_rule_response_ctx => indy_parse_get_auth_rule_response(get_auth_rule_response: *const c_char)
+        -> cb(auth_rules_json: *const c_char), CommonInvalidParam3;
+    indy_parse_get_validator_info_response_ctx => indy_parse_get_validator_info_response(get_validator_info_response: *const c_char)
+        -> cb(validator_info_json: *const c_char), CommonInvalidParam3;
+    indy_parse_get_validator_info_summary_response_ctx => indy_parse_get_validator_info_summary_response(get_validator_info_response: *const c_char)
+        -> cb(validator_info_json: *const c_char), CommonInvalidParam3;
+    indy_parse_pool_upgrade_status_response_ctx => indy_parse_pool_upgrade_status_response(get_validator_info_response: *const c_char)
+        -> cb(upgrade_status_json: *const c_char), CommonInvalidParam3;
+    indy_parse_get_txn_author_agreement_response_ctx => indy_parse_get_txn_author_agreement_response(get_txn_author_agreement_response: *const c_char)
+        -> cb(txn_author_agreement_json: *const c_char), CommonInvalidParam3;
+    indy_parse_get_acceptance_mechanisms_response_ctx => indy_parse_get_acceptance_mechanisms_response(get_acceptance_mechanisms_response: *const c_char)
+        -> cb(acceptance_mechanisms_json: *const c_char), CommonInvalidParam3;
+    indy_build_attrib_request_ctx => indy_build_attrib_request(submitter_did: *const c_char, target_did: *const c_char, hash: *const c_char, raw: *const c_char, enc: *const c_char)
+        -> cb(request_json: *const c_char), CommonInvalidParam7;
+    indy_build_get_attrib_request_ctx => indy_build_get_attrib_request(submitter_did: *const c_char, target_did: *const c_char, raw: *const c_char, hash: *const c_char, enc: *const c_char)
+        -> cb(request_json: *const c_char), CommonInvalidParam7;
+    indy_build_get_historical_attrib_request_ctx => indy_build_get_historical_attrib_request(submitter_did: *const c_char, target_did: *const c_char, raw: *const c_char, hash: *const c_char, enc: *const c_char, seq_no: i64, timestamp: i64)
+        -> cb(request_json: *const c_char), CommonInvalidParam9;
+    indy_build_set_endpoint_request_ctx => indy_build_set_endpoint_request(submitter_did: *const c_char, target_did: *const c_char, address: *const c_char, transport_key: *const c_char)
+        -> cb(request_json: *const c_char), CommonInvalidParam6;
+    indy_build_get_endpoint_request_ctx => indy_build_get_endpoint_request(submitter_did: *const c_char, target_did: *const c_char)
+        -> cb(request_json: *const c_char), CommonInvalidParam4;
+    indy_build_schema_request_ctx => indy_build_schema_request(submitter_did: *const c_char, data: *const c_char)
+        -> cb(request_json: *const c_char), CommonInvalidParam4;
+    indy_build_get_schema_request_ctx => indy_build_get_schema_request(submitter_did: *const c_char, id: *const c_char)
+        -> cb(request_json: *const c_char), CommonInvalidParam4;
+    indy_parse_get_schema_response_ctx => indy_parse_get_schema_response(get_schema_response: *const c_char)
+        -> cb(schema_id: *const c_char, schema_json: *const c_char), CommonInvalidParam3;
+    indy_build_cred_def_request_ctx => indy_build_cred_def_request(submitter_did: *const c_char, data: *const c_char)
+        -> cb(request_result_json: *const c_char), CommonInvalidParam4;
+    indy_build_get_cred_def_request_ctx => indy_build_get_cred_def_request(submitter_did: *const c_char, id: *const c_char)
+        -> cb(request_json: *const c_char), CommonInvalidParam4;
+    indy_parse_get_cred_def_response_ctx => indy_parse_get_cred_def_response(get_cred_def_response: *const c_char)
+        -> cb(cred_def_id: *const c_char, cred_def_json: *const c_char), CommonInvalidParam3;
+    indy_build_node_request_ctx => indy_build_node_request(submitter_did: *const c_char, target_did: *const c_char, data: *const c_char)
+        -> cb(request_json: *const c_char), CommonInvalidParam5;
+    indy_build_get_validator_info_request_ctx => indy_build_get_validator_info_request(submitter_did: *const c_char)
+        -> cb(request_json: *const c_char), CommonInvalidParam3;
+    indy_build_get_txn_request_ctx => indy_build_get_txn_request(submitter_did: *const c_char, ledger_type: *const c_char, seq_no: i32)
+        -> cb(request_json: *const c_char), CommonInvalidParam5;
+    indy_build_pool_config_request_ctx => indy_build_pool_config_request(submitter_did: *const c_char, writes: bool, force: bool)
+        -> cb(request_json: *const c_char), CommonInvalidParam5;
+    indy_build_pool_restart_request_ctx => indy_build_pool_restart_request(submitter_did: *const c_char, action: *const c_char, datetime: *const c_char)
+        -> cb(request_json: *const c_char), CommonInvalidParam5;
+    indy_build_pool_upgrade_request_ctx => indy_build_pool_upgrade_request(submitter_did: *const c_char, name: *const c_char, version: *const c_char, action: *const c_char, sha256: *const c_char, timeout: i32, schedule: *const c_char, justification: *const c_char, reinstall: bool, force: bool, package: *const c_char)
+        -> cb(request_json: *const c_char), CommonInvalidParam13;
+    indy_build_scheduled_pool_upgrade_request_ctx => indy_build_scheduled_pool_upgrade_request(pool_handle: PoolHandle, submitter_did: *const c_char, upgrade_json: *const c_char)
+        -> cb(request_json: *const c_char), CommonInvalidParam5;
+    indy_build_revoc_reg_def_request_ctx => indy_build_revoc_reg_def_request(submitter_did: *const c_char, data: *const c_char)
+        -> cb(rev_reg_def_req: *const c_char), CommonInvalidParam4;
+    indy_build_get_revoc_reg_def_request_ctx => indy_build_get_revoc_reg_def_request(submitter_did: *const c_char, id: *const c_char)
+        -> cb(request_json: *const c_char), CommonInvalidParam4;
+    indy_parse_get_revoc_reg_def_response_ctx => indy_parse_get_revoc_reg_def_response(get_revoc_reg_def_response: *const c_char)
+        -> cb(revoc_reg_def_id: *const c_char, revoc_reg_def_json: *const c_char), CommonInvalidParam3;
+    indy_build_revoc_reg_entry_request_ctx => indy_build_revoc_reg_entry_request(submitter_did: *const c_char, revoc_reg_def_id: *const c_char, rev_def_type: *const c_char, value: *const c_char)
+        -> cb(request_json: *const c_char), CommonInvalidParam6;
+    indy_build_get_revoc_reg_request_ctx => indy_build_get_revoc_reg_request(submitter_did: *const c_char, revoc_reg_def_id: *const c_char, timestamp: i64)
+        -> cb(request_json: *const c_char), CommonInvalidParam5;
+    indy_parse_get_revoc_reg_response_ctx => indy_parse_get_revoc_reg_response(get_revoc_reg_response: *const c_char)
+        -> cb(revoc_reg_def_id: *const c_char, revoc_reg_json: *const c_char, timestamp: u64), CommonInvalidParam3;
+    indy_build_get_revoc_reg_delta_request_ctx => indy_build_get_revoc_reg_delta_request(submitter_did: *const c_char, revoc_reg_def_id: *const c_char, from: i64, to: i64)
+        -> cb(request_json: *const c_char), CommonInvalidParam6;
+    indy_parse_get_revoc_reg_delta_response_ctx => indy_parse_get_revoc_reg_delta_response(get_revoc_reg_delta_response: *const c_char)
+        -> cb(revoc_reg_def_id: *const c_char, revoc_reg_delta_json: *const c_char, timestamp: u64), CommonInvalidParam3;
+    indy_register_transaction_parser_for_sp_ctx => indy_register_transaction_parser_for_sp(txn_type: *const c_char, parser: Option<CustomTransactionParser>, free: Option<CustomFree>)
+        -> cb(), CommonInvalidParam5;
+    indy_register_ledger_transaction_builder_ctx => indy_register_ledger_transaction_builder(txn_type: *const c_char, builder: Option<CustomTransactionBuilder>, parser: Option<CustomResponseParser>, free: Option<CustomFree>)
+        -> cb(), CommonInvalidParam6;
+    indy_register_ledger_middleware_ctx => indy_register_ledger_middleware(request_middleware: Option<CustomRequestMiddleware>, reply_middleware: Option<CustomReplyMiddleware>, free: Option<CustomFree>)
+        -> cb(middleware_handle: IndyHandle), CommonInvalidParam5;
+    indy_unregister_ledger_middleware_ctx => indy_unregister_ledger_middleware(middleware_handle: IndyHandle)
+        -> cb(), CommonInvalidParam3;
+    indy_build_custom_request_ctx => indy_build_custom_request(submitter_did: *const c_char, txn_type: *const c_char, data_json: *const c_char)
+        -> cb(request_json: *const c_char), CommonInvalidParam5;
+    indy_parse_custom_response_ctx => indy_parse_custom_response(txn_type: *const c_char, response_json: *const c_char)
+        -> cb(parsed_json: *const c_char), CommonInvalidParam4;
+    indy_get_response_metadata_ctx => indy_get_response_metadata(response: *const c_char)
+        -> cb(response_metadata: *const c_char), CommonInvalidParam3;
+    indy_verify_state_proof_ctx => indy_verify_state_proof(request_json: *const c_char, response_json: *const c_char, bls_keys_json: *const c_char)
+        -> cb(valid: bool), CommonInvalidParam5;
+    indy_build_ledgers_freeze_request_ctx => indy_build_ledgers_freeze_request(submitter_did: *const c_char, ledgers_ids: *const c_char)
+        -> cb(request_json: *const c_char), CommonInvalidParam4;
+    indy_build_get_frozen_ledgers_request_ctx => indy_build_get_frozen_ledgers_request(submitter_did: *const c_char)
+        -> cb(request_json: *const c_char), CommonInvalidParam3;
+    indy_parse_get_frozen_ledgers_response_ctx => indy_parse_get_frozen_ledgers_response(get_frozen_ledgers_response: *const c_char)
+        -> cb(frozen_ledgers_json: *const c_char), CommonInvalidParam3;
+    indy_build_auth_rule_request_ctx => indy_build_auth_rule_request(submitter_did: *const c_char, txn_type: *const c_char, action: *const c_char, field: *const c_char, old_value: *const c_char, new_value: *const c_char, constraint: *const c_char)
+        -> cb(request_json: *const c_char), CommonInvalidParam9;
+    indy_build_auth_rules_request_ctx => indy_build_auth_rules_request(submitter_did: *const c_char, rules: *const c_char)
+        -> cb(request_json: *const c_char), CommonInvalidParam4;
+    indy_build_get_auth_rule_request_ctx => indy_build_get_auth_rule_request(submitter_did: *const c_char, txn_type: *const c_char, action: *const c_char, field: *const c_char, old_value: *const c_char, new_value: *const c_char)
+        -> cb(request_json: *const c_char), CommonInvalidParam8;
+    indy_build_role_constraint_ctx => indy_build_role_constraint(role: *const c_char, sig_count: u32, need_to_be_owner: bool, off_ledger_signature: bool, metadata_json: *const c_char)
+        -> cb(constraint_json: *const c_char), CommonInvalidParam7;
+    indy_build_combined_constraint_ctx => indy_build_combined_constraint(combination: *const c_char, constraints_json: *const c_char)
+        -> cb(constraint_json: *const c_char), CommonInvalidParam4;
+    indy_evaluate_auth_rules_ctx => indy_evaluate_auth_rules(auth_rules_json: *const c_char, action_json: *const c_char, signers_json: *const c_char)
+        -> cb(evaluation_json: *const c_char), CommonInvalidParam5;
+    indy_get_auth_rules_ctx => indy_get_auth_rules(pool_handle: PoolHandle, submitter_did: *const c_char, page_options_json: *const c_char)
+        -> cb(auth_rules_page_json: *const c_char), CommonInvalidParam5;
+    indy_diff_auth_rules_ctx => indy_diff_auth_rules(current_auth_rules_json: *const c_char, proposed_auth_rules_json: *const c_char)
+        -> cb(diff_json: *const c_char), CommonInvalidParam4;
+    indy_build_txn_author_agreement_request_ctx => indy_build_txn_author_agreement_request(submitter_did: *const c_char, text: *const c_char, version: *const c_char, ratification_ts: i64, retirement_ts: i64)
+        -> cb(request_json: *const c_char), CommonInvalidParam7;
+    indy_build_disable_all_txn_author_agreements_request_ctx => indy_build_disable_all_txn_author_agreements_request(submitter_did: *const c_char)
+        -> cb(request_json: *const c_char), CommonInvalidParam3;
+    indy_build_get_txn_author_agreement_request_ctx => indy_build_get_txn_author_agreement_request(submitter_did: *const c_char, data: *const c_char)
+        -> cb(request_json: *const c_char), CommonInvalidParam4;
+    indy_build_acceptance_mechanisms_request_ctx => indy_build_acceptance_mechanisms_request(submitter_did: *const c_char, aml: *const c_char, version: *const c_char, aml_context: *const c_char)
+        -> cb(request_json: *const c_char), CommonInvalidParam6;
+    indy_build_get_acceptance_mechanisms_request_ctx => indy_build_get_acceptance_mechanisms_request(submitter_did: *const c_char, timestamp: i64, version: *const c_char)
+        -> cb(request_json: *const c_char), CommonInvalidParam5;
+    indy_append_txn_author_agreement_acceptance_to_request_ctx => indy_append_txn_author_agreement_acceptance_to_request(request_json: *const c_char, text: *const c_char, version: *const c_char, taa_digest: *const c_char, mechanism: *const c_char, time: u64)
+        -> cb(request_with_meta_json: *const c_char), CommonInvalidParam8;
+    indy_prepare_request_with_taa_ctx => indy_prepare_request_with_taa(pool_handle: PoolHandle, wallet_handle: WalletHandle, submitter_did: *const c_char, request_json: *const c_char, mechanism: *const c_char)
+        -> cb(request_with_meta_json: *const c_char), CommonInvalidParam7;
+    indy_append_request_endorser_ctx => indy_append_request_endorser(request_json: *const c_char, endorser_did: *const c_char)
+        -> cb(out_request_json: *const c_char), CommonInvalidParam4;
+    indy_set_request_protocol_version_ctx => indy_set_request_protocol_version(request_json: *const c_char, protocol_version: usize)
+        -> cb(out_request_json: *const c_char), CommonInvalidParam4;
+}
+
+// `indy_subscribe_ledger_events` has no `_ctx` variant: its event callback outlives the command and receives
+// subscription handle that application maps to its context itself.
+context_data_api! {
+    indy_stream_txns_ctx => indy_stream_txns(pool_handle: PoolHandle, submitter_did: *const c_char, ledger_type: *const c_char, from: i32, to: i32)
+        -> txns_cb(txns_json: *const c_char), CommonInvalidParam7,
+           cb(), CommonInvalidParam8;
+}
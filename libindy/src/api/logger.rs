@@ -3,7 +3,7 @@ use libc::{c_void, c_char};
 use indy_api_types::ErrorCode;
 use indy_api_types::errors::prelude::*;
 
-use crate::utils::logger::{EnabledCB, LogCB, FlushCB, LibindyLogger, LibindyDefaultLogger, LOGGER_STATE};
+use crate::utils::logger::{EnabledCB, LogCB, FlushCB, LibindyLogger, LibindyDefaultLogger, LOGGER_STATE, set_log_filter};
 use indy_utils::ctypes;
 use log::LevelFilter;
 
@@ -100,6 +100,36 @@ pub extern fn indy_set_log_max_lvl(max_lvl: u32) -> ErrorCode {
 }
 
 
+/// Set log level and target filter of the installed logger.
+///
+/// Can be called at any time after logger initialization, e.g. to enable pool tracing temporarily.
+///
+/// #Params
+/// filter: comma separated list of `target=level` or `level` directives in `env_logger` syntax,
+///     e.g. "indy::services::pool=trace,indy::services::wallet=warn".
+///     Targets that are not listed are not logged unless a global level is specified.
+///
+/// NOTE: filter is not applied to the default logger on Android.
+///
+/// #Returns
+/// Error code
+#[no_mangle]
+pub extern fn indy_set_log_filter(filter: *const c_char) -> ErrorCode {
+    trace!("indy_set_log_filter >>> filter: {:?}", filter);
+
+    check_useful_c_str!(filter, ErrorCode::CommonInvalidParam1);
+
+    trace!("indy_set_log_filter: entities >>> filter: {:?}", filter);
+
+    let result = set_log_filter(&filter);
+
+    let res = prepare_result!(result);
+
+    trace!("indy_set_log_filter: <<< res: {:?}", res);
+
+    res
+}
+
 /// Set default logger implementation.
 ///
 /// Allows library user use `env_logger` logger as default implementation.
@@ -1,5 +1,6 @@
 use indy_api_types::{ErrorCode, CommandHandle};
 use crate::commands::{Command, CommandExecutor};
+use crate::commands::timeout::with_timeout;
 use crate::commands::metrics::MetricsCommand;
 use indy_utils::ctypes;
 use libc::c_char;
@@ -19,13 +20,155 @@ pub extern fn indy_collect_metrics(command_handle: CommandHandle,
     trace!("indy_collect_metrics: >>> command_handle: {:?}, cb: {:?}",
            command_handle, cb);
 
+    command_params!();
+
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Metrics(MetricsCommand::CollectMetrics(
-            boxed_callback_string!("indy_collect_metrics", cb, command_handle)
+    let result = CommandExecutor::submit(Command::Metrics(MetricsCommand::CollectMetrics(
+            with_timeout(boxed_callback_string!("indy_collect_metrics", cb, command_handle))
         )));
     let res = prepare_result!(result);
     trace!("indy_collect_metrics: <<< res: {:?}", res);
     res
 }
+
+/// Collect metrics in Prometheus text exposition format.
+///
+/// Thread pool and wallet statistics are exposed as gauges labeled with `label`.
+/// Command durations are exposed as `indy_commands_duration_ms` histogram labeled with `command` and `stage`.
+/// Pool catch-up rounds, merkle consistency failures, reply mismatches and state proof rejections are exposed
+/// as `indy_pool_events_total` counter labeled with `pool` and `event`.
+///
+/// #Returns
+/// Metrics in Prometheus text format ready to be served to a scraper.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_collect_metrics_prometheus(command_handle: CommandHandle,
+                                              cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                   err: ErrorCode,
+                                                                   metrics: *const c_char)>) -> ErrorCode {
+    trace!("indy_collect_metrics_prometheus: >>> command_handle: {:?}, cb: {:?}",
+           command_handle, cb);
+
+    command_params!();
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam2);
+
+    let result = CommandExecutor::submit(Command::Metrics(MetricsCommand::CollectMetricsPrometheus(
+            with_timeout(boxed_callback_string!("indy_collect_metrics_prometheus", cb, command_handle))
+        )));
+    let res = prepare_result!(result);
+    trace!("indy_collect_metrics_prometheus: <<< res: {:?}", res);
+    res
+}
+
+/// Collect metrics accumulated since the previous call of this function.
+///
+/// Counters (commands count, durations and histogram buckets, wallet operations) contain only
+/// values accumulated since the previous call or since `indy_reset_metrics`.
+/// Gauges (thread pool and wallet counts) contain current values.
+/// The first call returns values accumulated since libindy start.
+///
+/// #Returns
+/// Map in the JSON format of `indy_collect_metrics`.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_collect_metrics_delta(command_handle: CommandHandle,
+                                         cb: Option<extern fn(command_handle_: CommandHandle,
+                                                              err: ErrorCode,
+                                                              metrics_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_collect_metrics_delta: >>> command_handle: {:?}, cb: {:?}",
+           command_handle, cb);
+
+    command_params!();
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam2);
+
+    let result = CommandExecutor::submit(Command::Metrics(MetricsCommand::CollectMetricsDelta(
+            with_timeout(boxed_callback_string!("indy_collect_metrics_delta", cb, command_handle))
+        )));
+    let res = prepare_result!(result);
+    trace!("indy_collect_metrics_delta: <<< res: {:?}", res);
+    res
+}
+
+/// Reset metric counters.
+///
+/// Sets commands count, durations, histogram buckets and wallet operation counters to zero.
+/// Gauges (thread pool and wallet counts) are not affected.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_reset_metrics(command_handle: CommandHandle,
+                                 cb: Option<extern fn(command_handle_: CommandHandle,
+                                                      err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_reset_metrics: >>> command_handle: {:?}, cb: {:?}",
+           command_handle, cb);
+
+    command_params!();
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam2);
+
+    let result = CommandExecutor::submit(Command::Metrics(MetricsCommand::ResetMetrics(
+            with_timeout(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_reset_metrics:");
+                cb(command_handle, err)
+            })
+        )));
+    let res = prepare_result!(result);
+    trace!("indy_reset_metrics: <<< res: {:?}", res);
+    res
+}
+
+/// List handles that are currently open: wallets, pools, searches and blob storage readers and writers.
+/// Handles that stay open for long may point to leaks of the application.
+///
+/// #Returns
+/// handles_json: [{
+///     "type": string - one of "wallet", "pool", "wallet_search", "did_search", "pairwise_search", "credentials_search",
+///         "credentials_for_proof_request_search", "blob_storage_reader", "blob_storage_writer",
+///     "handle": int - value of the handle,
+///     "name": Optional<string> - wallet id, pool name, type of searched records or blob storage type,
+///     "age_ms": int - time passed since the handle has been opened,
+/// }] ordered from the oldest handle.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_list_handles(command_handle: CommandHandle,
+                                cb: Option<extern fn(command_handle_: CommandHandle,
+                                                     err: ErrorCode,
+                                                     handles_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_list_handles: >>> command_handle: {:?}, cb: {:?}",
+           command_handle, cb);
+
+    command_params!();
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam2);
+
+    let result = CommandExecutor::submit(Command::Metrics(MetricsCommand::ListHandles(
+            with_timeout(boxed_callback_string!("indy_list_handles", cb, command_handle))
+        )));
+    let res = prepare_result!(result);
+    trace!("indy_list_handles: <<< res: {:?}", res);
+    res
+}
+
+context_api! {
+    indy_collect_metrics_ctx => indy_collect_metrics()
+        -> cb(metrics_json: *const c_char), CommonInvalidParam2;
+    indy_collect_metrics_prometheus_ctx => indy_collect_metrics_prometheus()
+        -> cb(metrics: *const c_char), CommonInvalidParam2;
+    indy_collect_metrics_delta_ctx => indy_collect_metrics_delta()
+        -> cb(metrics_json: *const c_char), CommonInvalidParam2;
+    indy_reset_metrics_ctx => indy_reset_metrics()
+        -> cb(), CommonInvalidParam2;
+    indy_list_handles_ctx => indy_list_handles()
+        -> cb(handles_json: *const c_char), CommonInvalidParam2;
+}
@@ -1,19 +1,26 @@
+#[cfg(feature = "anoncreds")]
 pub mod anoncreds;
 pub mod crypto;
+#[cfg(feature = "ledger")]
 pub mod ledger;
 pub mod pairwise;
+#[cfg(feature = "ledger")]
 pub mod pool;
 pub mod did;
 pub mod wallet;
+#[cfg(feature = "anoncreds")]
 pub mod blob_storage;
 pub mod non_secrets;
+#[cfg(feature = "payments")]
 pub mod payments;
+#[cfg(feature = "payments")]
 pub mod payments_v2;
 pub mod logger;
+#[cfg(feature = "cache")]
 pub mod cache;
 pub mod metrics;
 
-use libc::c_char;
+use libc::{c_char, c_void};
 
 use crate::domain::IndyConfig;
 use indy_api_types::validation::Validatable;
@@ -26,10 +33,50 @@ use indy_utils::ctypes;
 ///
 /// #Params
 /// config: {
-///     "crypto_thread_pool_size": Optional<int> - size of thread pool for the most expensive crypto operations. (4 by default)
-///     "collect_backtrace": Optional<bool> - whether errors backtrace should be collected.
+///     "crypto_thread_pool_size": Optional<int> - size of thread pool for the most expensive crypto operations
+///         like wallet key derivation and credential definition creation. (4 by default)
+///     "anoncreds_worker_threads": Optional<int> - number of worker threads creating and verifying proofs,
+///         so these operations don't block other commands. Proofs of the same wallet are created in
+///         submission order. Other commands still run on the single command thread. (4 by default)
+///     "collect_backtrace": Optional<bool> - whether errors backtrace should be collected and reported by `indy_get_current_error`.
 ///         Capturing of backtrace can affect library performance.
 ///         NOTE: must be set before invocation of any other API functions.
+///     "metrics_buckets_ms": Optional<array<float>> - increasing upper bounds of command duration histogram buckets in milliseconds.
+///         (0.5, 1, 2, 5, 10, 20, 50, 100, 200, 500, 1000, 2000, 5000, 10000, 20000 by default)
+///         NOTE: must be set before invocation of any other API functions.
+///     "log_format": Optional<string> - format of log records: "text" (default) or "json".
+///         JSON records contain fields: target, level, message, module_path, file, line,
+///         command_handle and wallet_handle_hash (if the message refers to them).
+///     "slow_operation_threshold_ms": Optional<int> - wallet storage calls and pool requests lasting longer
+///         are logged with warn level to `indy_slow_operations` target with their duration and parameters digest.
+///         (0 - disabled by default)
+///     "max_pending_commands": Optional<int> - maximum number of commands waiting for execution.
+///         When it is reached API calls fail with CommonQueueFull error. (0 - unbounded by default)
+///     "queue_full_timeout_ms": Optional<int> - how long API calls wait for the full queue to drain
+///         before failing with CommonQueueFull error. (0 - fail at once by default)
+///     "command_timeout_ms": Optional<int> - callbacks of API calls that haven't completed in this time
+///         are called with CommonTimeout error. The result that comes later is dropped. (0 - disabled by default)
+///         Streaming calls restart the timeout on every chunk. The work of the call is cancelled where possible:
+///         ledger requests are dropped, wallet searches are closed and streaming is stopped.
+///         Operation already running in a wallet storage plugin, key derivation or anoncreds can't be cancelled.
+///     "pool_socket_hwm": Optional<int> - high water mark of pool node sockets, maximum number of messages
+///         queued for the node in each direction. (0 - unlimited, 1000 by default)
+///         NOTE: applied to node sockets connected after the call.
+///     "pool_zmq_io_threads": Optional<int> - number of ZMQ IO threads serving node sockets of each pool.
+///         (1 by default)
+///         NOTE: applied to pools opened after the call.
+///     "blob_buffer_size": Optional<int> - size of buffer in bytes used to write and verify tails files
+///         of the default blob storage. (8192 by default)
+///         NOTE: applied to blob storage readers and writers opened after the call.
+///     "tails_cache_size": Optional<int> - total size in bytes of parsed tails shared by revocation operations,
+///         so tails are read and verified once while they remain in the cache. (0 - disabled, 33554432 by default)
+///     "anoncreds_test_seed": Optional<string> - FOR TESTS ONLY. Seed to derive nonces of credential offers,
+///         credential requests and proof requests and blinding factors of credential requests from,
+///         so the same sequence of calls produces byte-identical results.
+///         Setting the seed again restarts the sequence, empty string disables the mode. (disabled by default)
+///     "strict_attrib_validation": Optional<bool> - whether `raw` data of ATTRIB requests is validated against the known
+///         attrib structures by `indy_build_attrib_request`: it must contain a single attribute and `endpoint`, `endpoints`
+///         and `diddocContent` attributes must be well-formed. (false by default)
 /// }
 ///
 /// #Errors
@@ -49,6 +96,158 @@ pub extern fn indy_set_runtime_config(config: *const c_char) -> ErrorCode {
     res
 }
 
+/// Register hooks invoked around execution of every libindy command.
+/// Hooks are called from libindy threads, so they should return fast.
+/// Registering new hooks replaces the previous ones. Pass NULL for both hooks to unregister them.
+///
+/// #Params
+/// pre_cb: Optional hook called before command execution with:
+///     command_name: snake case name of the command (the same as in metrics).
+///     params_digest: digest of parameters of the API call that sent the command, the same for calls with
+///         the same parameters. Parameters themselves aren't exposed. Empty for commands sent by libindy itself.
+/// post_cb: Optional hook called once the callback of the API call is called with:
+///     command_name: snake case name of the command (the same as in metrics).
+///     params_digest: digest of parameters of the API call that sent the command.
+///     duration_ms: duration from the call to its callback in milliseconds, including the time the command
+///         waited for key derivation, pool replies and other continuations.
+///     err: code of error passed to the callback (CommonTimeout if the call timed out).
+///     Commands sent by libindy itself are reported right after their execution.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_register_command_hook(pre_cb: Option<extern fn(command_name: *const c_char,
+                                                                  params_digest: *const c_char)>,
+                                         post_cb: Option<extern fn(command_name: *const c_char,
+                                                                   params_digest: *const c_char,
+                                                                   duration_ms: u64,
+                                                                   err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_register_command_hook >>> pre_cb: {:?}, post_cb: {:?}", pre_cb, post_cb);
+
+    crate::commands::indy_register_command_hook(crate::commands::CommandHooks { pre: pre_cb, post: post_cb });
+
+    let res = ErrorCode::Success;
+
+    trace!("indy_register_command_hook: <<< res: {:?}", res);
+
+    res
+}
+
+/// Register callback receiving audit events of sensitive operations.
+/// Audit is disabled until callback is registered. Pass NULL callback to disable it again.
+/// Callback is called from libindy command thread after the operation succeeded, so it should return fast.
+/// NOTE: events are only passed to the callback, libindy doesn't store them in the wallet or elsewhere.
+///     Application that needs durable audit trail should persist them itself, e.g. to append-only storage.
+///
+/// #Params
+/// context: pointer to application context that will be passed to the callback.
+/// audit_cb: callback called with:
+///     context: application context passed on registration.
+///     event_json: audit event {
+///         "event": string - one of "key_created", "did_created", "message_signed", "request_signed",
+///             "did_keys_rotation_started", "did_keys_rotated", "wallet_exported", "credential_issued",
+///         "timestamp": int - unix time of the event in seconds,
+///         "wallet_handle": int - handle of the wallet the operation used,
+///         "context": Optional<object> - context set by `indy_set_audit_context` in the thread that called the operation,
+///         "details": object - public data of the operation (verkeys, dids, identifiers). Secrets are never included.
+///     }
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_register_audit_callback(context: *const c_void,
+                                           audit_cb: Option<extern fn(context: *const c_void,
+                                                                      event_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_register_audit_callback >>> context: {:?}, audit_cb: {:?}", context, audit_cb);
+
+    crate::utils::audit::register_audit_callback(context, audit_cb);
+
+    let res = ErrorCode::Success;
+
+    trace!("indy_register_audit_callback: <<< res: {:?}", res);
+
+    res
+}
+
+/// Set audit context of the calling thread.
+/// Audit events of operations called from this thread afterwards contain this context.
+///
+/// #Params
+/// context_json: (optional) JSON object supplied by the application (e.g. user or request id).
+///     Pass NULL to clear the context.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_set_audit_context(context_json: *const c_char) -> ErrorCode {
+    trace!("indy_set_audit_context >>> context_json: {:?}", context_json);
+
+    check_useful_opt_json!(context_json, ErrorCode::CommonInvalidParam1, serde_json::Value);
+
+    crate::utils::audit::set_current_context(context_json);
+
+    let res = ErrorCode::Success;
+
+    trace!("indy_set_audit_context: <<< res: {:?}", res);
+
+    res
+}
+
+/// Gracefully shuts libindy down, e.g. on SIGTERM, so wallets storages are not left in inconsistent state.
+///
+/// Stops accepting new API calls: they fail with CommonInvalidState afterwards. Calls queued before
+/// are executed, then opened searches, wallets and pools are closed and the callback is called.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// force: don't wait for the queued calls. Their callbacks are called with CommonInvalidState error at once.
+/// timeout_ms: how long to wait for the queued calls before cancelling them as with `force`. (0 - wait without limit)
+/// cb: Callback that takes command result as parameter.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_shutdown(command_handle: CommandHandle,
+                            force: bool,
+                            timeout_ms: u64,
+                            cb: Option<extern fn(command_handle_: CommandHandle,
+                                                 err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_shutdown: >>> command_handle: {:?}, force: {:?}, timeout_ms: {:?}, cb: {:?}",
+           command_handle, force, timeout_ms, cb);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    // Not limited by command timeout: cancelling the queued calls must not cancel the shutdown itself
+    let result = crate::commands::indy_shutdown(
+        force,
+        timeout_ms,
+        Box::new(move |result| {
+            let err = prepare_result!(result);
+            trace!("indy_shutdown: cb command_handle: {:?}, err: {:?}", command_handle, err);
+            cb(command_handle, err)
+        }));
+
+    let res = prepare_result!(result);
+    trace!("indy_shutdown: <<< res: {:?}", res);
+    res
+}
+
+/// Releases a buffer which ownership has been passed to the application by libindy
+/// (e.g. by `indy_pack_message_buffer`, `indy_export_wallet_to_buffer` or `indy_read_tails_to_buffer`).
+///
+/// #Params
+/// data: pointer to the buffer. NULL is ignored.
+/// data_len: length of the buffer passed together with the pointer.
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern fn indy_free_buffer(data: *mut u8, data_len: u32) {
+    trace!("indy_free_buffer >>> data: {:?}, data_len: {:?}", data, data_len);
+
+    unsafe { ctypes::free_owned_pointer(data, data_len) };
+
+    trace!("indy_free_buffer: <<<");
+}
+
 /// Get details for last occurred error.
 ///
 /// This function should be called in two places to handle both cases of error occurrence:
@@ -66,7 +265,13 @@ pub extern fn indy_set_runtime_config(config: *const c_char) -> ErrorCode {
 ///         Collecting of backtrace can be enabled by:
 ///             1) setting environment variable `RUST_BACKTRACE=1`
 ///             2) calling `indy_set_runtime_config` API function with `collect_backtrace: true`
+///         Calling `indy_set_runtime_config` with `collect_backtrace: false` stops reporting of backtrace.
 ///     "message": str - human-readable error description
+///     "error_code": int - code of the error
+///     "component": str - part of libindy the error comes from: "common", "anoncreds", "ledger", "pool",
+///         "crypto", "wallet", "did" or "payments"
+///     "retriable": bool - whether the same call may succeed if it's repeated later (e.g. on timeouts)
+///     "causes": array<str> - descriptions of the error causes from the closest one
 /// }
 ///
 #[no_mangle]
@@ -78,3 +283,44 @@ pub extern fn indy_get_current_error(error_json_p: *mut *const c_char) {
 
     trace!("indy_get_current_error: <<<");
 }
+
+/// Set trace context of the calling thread. Available if libindy is built with `tracing_spans` feature.
+///
+/// Spans of commands started from the calling thread (execution, pool round trips, wallet storage operations)
+/// become children of this context. Spans are OpenTelemetry spans reported through `tracing` crate.
+/// If the application hasn't set a global `tracing` subscriber, the first call installs the one
+/// that emits finished spans as JSON records through `indy_tracing` log target.
+///
+/// #Params
+/// traceparent: W3C trace context in format `00-<trace id>-<parent span id>-<flags>`.
+///     Pass NULL to clear the context.
+///
+/// #Errors
+/// Common*
+#[cfg(feature = "tracing_spans")]
+#[no_mangle]
+pub extern fn indy_set_trace_context(traceparent: *const c_char) -> ErrorCode {
+    trace!("indy_set_trace_context >>> traceparent: {:?}", traceparent);
+
+    check_useful_opt_c_str!(traceparent, ErrorCode::CommonInvalidParam1);
+
+    let context = match traceparent.map(|traceparent| crate::utils::tracing::TraceContext::from_traceparent(&traceparent)) {
+        Some(Ok(context)) => Some(context),
+        Some(Err(err)) => return err.into(),
+        None => None
+    };
+
+    crate::utils::tracing::init_log_export();
+    crate::utils::tracing::set_current_context(context);
+
+    let res = ErrorCode::Success;
+
+    trace!("indy_set_trace_context: <<< res: {:?}", res);
+
+    res
+}
+
+context_api! {
+    indy_shutdown_ctx => indy_shutdown(force: bool, timeout_ms: u64)
+        -> cb(), CommonInvalidParam4;
+}
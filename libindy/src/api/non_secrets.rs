@@ -1,8 +1,10 @@
 
 use indy_api_types::{ErrorCode, CommandHandle, WalletHandle, SearchHandle, INVALID_SEARCH_HANDLE};
 use crate::commands::{Command, CommandExecutor};
+use crate::commands::timeout::with_timeout;
 use crate::commands::non_secrets::NonSecretsCommand;
 use indy_api_types::domain::wallet::Tags;
+use indy_wallet::{RecordHistoryConfig, UpsertRecord};
 use indy_api_types::errors::prelude::*;
 use indy_utils::ctypes;
 
@@ -23,11 +25,15 @@ use libc::c_char;
 ///     "tagName2": <str>, // string tag (will be stored encrypted)
 ///     "~tagName3": <str>, // string tag (will be stored un-encrypted)
 ///     "~tagName4": <str>, // string tag (will be stored un-encrypted)
+///     "#~tagName5": <str>, // unsigned integer tag (will be stored encrypted with range index)
 ///   }
 ///   Note that null means no tags
 ///   If tag name starts with "~" the tag will be stored un-encrypted that will allow
 ///   usage of this tag in complex search queries (comparison, predicates)
 ///   Encrypted tags can be searched only for exact matching
+///   If tag name starts with "#~" the tag value must be unsigned integer. The tag will be stored
+///   encrypted together with range index that will allow usage of it in comparison queries.
+///   Other tags starting with "#" are stored as regular encrypted tags
 #[no_mangle]
 pub extern fn indy_add_wallet_record(command_handle: CommandHandle,
                                      wallet_handle: WalletHandle,
@@ -38,6 +44,8 @@ pub extern fn indy_add_wallet_record(command_handle: CommandHandle,
                                      cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode)>) -> ErrorCode {
     trace!("indy_add_wallet_record: >>> wallet_handle: {:?}, type_: {:?}, id: {:?}, value: {:?}, tags_json: {:?}", wallet_handle, type_, id, value, tags_json);
 
+    command_params!(wallet_handle, type_, id, value, tags_json);
+
     check_useful_c_str!(type_, ErrorCode::CommonInvalidParam3);
     check_useful_c_str!(id, ErrorCode::CommonInvalidParam4);
     check_useful_c_str!(value, ErrorCode::CommonInvalidParam5);
@@ -46,15 +54,14 @@ pub extern fn indy_add_wallet_record(command_handle: CommandHandle,
 
     trace!("indy_add_wallet_record: entities >>> wallet_handle: {:?}, type_: {:?}, id: {:?}, value: {:?}, tags_json: {:?}", wallet_handle, type_, id, value, tags_json);
 
-    let result = CommandExecutor::instance()
-        .send(Command::NonSecrets(
+    let result = CommandExecutor::submit(Command::NonSecrets(
             NonSecretsCommand::AddRecord(
                 wallet_handle,
                 type_,
                 id,
                 value,
                 tags_json,
-                Box::new(move |result| {
+                with_timeout(move |result| {
                     let err = prepare_result!(result);
                     trace!("indy_add_wallet_record:");
                     cb(command_handle, err)
@@ -85,6 +92,8 @@ pub extern fn indy_update_wallet_record_value(command_handle: CommandHandle,
                                               cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode)>) -> ErrorCode {
     trace!("indy_update_wallet_record_value: >>> wallet_handle: {:?}, type_: {:?}, id: {:?}, value: {:?}", wallet_handle, type_, id, value);
 
+    command_params!(wallet_handle, type_, id, value);
+
     check_useful_c_str!(type_, ErrorCode::CommonInvalidParam3);
     check_useful_c_str!(id, ErrorCode::CommonInvalidParam4);
     check_useful_c_str!(value, ErrorCode::CommonInvalidParam5);
@@ -92,14 +101,13 @@ pub extern fn indy_update_wallet_record_value(command_handle: CommandHandle,
 
     trace!("indy_update_wallet_record_value: entities >>> wallet_handle: {:?}, type_: {:?}, id: {:?}, value: {:?}", wallet_handle, type_, id, value);
 
-    let result = CommandExecutor::instance()
-        .send(Command::NonSecrets(
+    let result = CommandExecutor::submit(Command::NonSecrets(
             NonSecretsCommand::UpdateRecordValue(
                 wallet_handle,
                 type_,
                 id,
                 value,
-                Box::new(move |result| {
+                with_timeout(move |result| {
                     let err = prepare_result!(result);
                     trace!("indy_update_wallet_record_value:");
                     cb(command_handle, err)
@@ -113,6 +121,149 @@ pub extern fn indy_update_wallet_record_value(command_handle: CommandHandle,
     res
 }
 
+/// Update a non-secret wallet record value only if it wasn't changed since it was read (compare-and-swap).
+///
+/// Current record value hash is compared with expected one and record is updated only if they match.
+/// Check and update are performed as single operation with respect to other libindy wallet calls.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context
+/// wallet_handle: wallet handle (created by open_wallet)
+/// type_: allows to separate different record types collections
+/// id: the id of record
+/// expected_value_hash: hex encoded SHA-256 hash of the record value the update is based on
+///   (as returned by previous call of this function or calculated from value got with indy_get_wallet_record)
+/// value: the new value of record
+/// #Returns
+/// value_hash: hex encoded SHA-256 hash of the new record value
+///
+/// #Errors
+/// WalletItemConflict - record value was changed since expected value was read
+#[no_mangle]
+pub extern fn indy_compare_and_swap_wallet_record_value(command_handle: CommandHandle,
+                                                        wallet_handle: WalletHandle,
+                                                        type_: *const c_char,
+                                                        id: *const c_char,
+                                                        expected_value_hash: *const c_char,
+                                                        value: *const c_char,
+                                                        cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                             value_hash: *const c_char)>) -> ErrorCode {
+    trace!("indy_compare_and_swap_wallet_record_value: >>> wallet_handle: {:?}, type_: {:?}, id: {:?}, expected_value_hash: {:?}, value: {:?}",
+           wallet_handle, type_, id, expected_value_hash, value);
+
+    command_params!(wallet_handle, type_, id, expected_value_hash, value);
+
+    check_useful_c_str!(type_, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(id, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(expected_value_hash, ErrorCode::CommonInvalidParam5);
+    check_useful_c_str!(value, ErrorCode::CommonInvalidParam6);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    trace!("indy_compare_and_swap_wallet_record_value: entities >>> wallet_handle: {:?}, type_: {:?}, id: {:?}, expected_value_hash: {:?}, value: {:?}",
+           wallet_handle, type_, id, expected_value_hash, value);
+
+    let result = CommandExecutor::submit(Command::NonSecrets(
+            NonSecretsCommand::CompareAndSwapRecordValue(
+                wallet_handle,
+                type_,
+                id,
+                expected_value_hash,
+                value,
+                with_timeout(boxed_callback_string!("indy_compare_and_swap_wallet_record_value", cb, command_handle))
+            )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_compare_and_swap_wallet_record_value: <<< res: {:?}", res);
+
+    res
+}
+
+/// Calculates new value of a non-secret wallet record from the current one.
+/// Called by indy_modify_wallet_record_value while the record is locked.
+///
+/// Libindy wallet functions must not be waited for from this callback.
+///
+/// #Params
+/// command_handle: command handle passed to indy_modify_wallet_record_value
+/// current_value: the current value of record
+/// new_value_p: pointer to be set to the new value of record.
+///   The memory must stay valid until indy_modify_wallet_record_value callback is called.
+///
+/// #Returns
+/// Success to write the new value, any other error code to leave the record unchanged
+pub type ModifyWalletRecordValueCB = extern fn(command_handle_: CommandHandle,
+                                               current_value: *const c_char,
+                                               new_value_p: *mut *const c_char) -> ErrorCode;
+
+/// Atomically update a non-secret wallet record value with the value calculated from the current one.
+///
+/// The record is locked (on storage transaction level for default wallet storage),
+/// its current value is passed to `modify_cb` and the returned value is written before lock is released.
+/// Useful for counters and state-machine records shared across threads.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context
+/// wallet_handle: wallet handle (created by open_wallet)
+/// type_: allows to separate different record types collections
+/// id: the id of record
+/// modify_cb: callback calculating the new value of record from the current one
+///
+/// #Returns
+/// new_value: the value written to the record
+///
+/// #Errors
+/// WalletItemNotFound - record not found
+/// Error code returned by `modify_cb` - record is left unchanged
+#[no_mangle]
+pub extern fn indy_modify_wallet_record_value(command_handle: CommandHandle,
+                                              wallet_handle: WalletHandle,
+                                              type_: *const c_char,
+                                              id: *const c_char,
+                                              modify_cb: Option<ModifyWalletRecordValueCB>,
+                                              cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                   new_value: *const c_char)>) -> ErrorCode {
+    trace!("indy_modify_wallet_record_value: >>> wallet_handle: {:?}, type_: {:?}, id: {:?}", wallet_handle, type_, id);
+
+    command_params!(wallet_handle, type_, id);
+
+    check_useful_c_str!(type_, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(id, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(modify_cb, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_modify_wallet_record_value: entities >>> wallet_handle: {:?}, type_: {:?}, id: {:?}", wallet_handle, type_, id);
+
+    let modify = Box::new(move |current_value: &str| -> IndyResult<String> {
+        let current_value = ctypes::str_to_cstring(current_value);
+        let mut new_value: *const c_char = ::std::ptr::null();
+
+        let res: IndyResult<()> = modify_cb(command_handle, current_value.as_ptr(), &mut new_value).into();
+        res?;
+
+        ctypes::c_str_to_string(new_value)
+            .to_indy(IndyErrorKind::InvalidStructure, "Modified record value is invalid UTF-8 string")?
+            .filter(|new_value| !new_value.is_empty())
+            .map(String::from)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "Modified record value is empty"))
+    });
+
+    let result = CommandExecutor::submit(Command::NonSecrets(
+            NonSecretsCommand::ModifyRecordValue(
+                wallet_handle,
+                type_,
+                id,
+                modify,
+                with_timeout(boxed_callback_string!("indy_modify_wallet_record_value", cb, command_handle))
+            )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_modify_wallet_record_value: <<< res: {:?}", res);
+
+    res
+}
+
 /// Update a non-secret wallet record tags
 ///
 /// #Params
@@ -126,10 +277,14 @@ pub extern fn indy_update_wallet_record_value(command_handle: CommandHandle,
 ///     "tagName2": <str>, // string tag (will be stored encrypted)
 ///     "~tagName3": <str>, // string tag (will be stored un-encrypted)
 ///     "~tagName4": <str>, // string tag (will be stored un-encrypted)
+///     "#~tagName5": <str>, // unsigned integer tag (will be stored encrypted with range index)
 ///   }
 ///   If tag name starts with "~" the tag will be stored un-encrypted that will allow
 ///   usage of this tag in complex search queries (comparison, predicates)
 ///   Encrypted tags can be searched only for exact matching
+///   If tag name starts with "#~" the tag value must be unsigned integer. The tag will be stored
+///   encrypted together with range index that will allow usage of it in comparison queries.
+///   Other tags starting with "#" are stored as regular encrypted tags
 #[no_mangle]
 pub extern fn indy_update_wallet_record_tags(command_handle: CommandHandle,
                                              wallet_handle: WalletHandle,
@@ -139,6 +294,8 @@ pub extern fn indy_update_wallet_record_tags(command_handle: CommandHandle,
                                              cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode)>) -> ErrorCode {
     trace!("indy_update_wallet_record_tags: >>> wallet_handle: {:?}, type_: {:?}, id: {:?}, tags_json: {:?}", wallet_handle, type_, id, tags_json);
 
+    command_params!(wallet_handle, type_, id, tags_json);
+
     check_useful_c_str!(type_, ErrorCode::CommonInvalidParam3);
     check_useful_c_str!(id, ErrorCode::CommonInvalidParam4);
     check_useful_json!(tags_json, ErrorCode::CommonInvalidParam5, Tags);
@@ -146,14 +303,13 @@ pub extern fn indy_update_wallet_record_tags(command_handle: CommandHandle,
 
     trace!("indy_update_wallet_record_tags: entities >>> wallet_handle: {:?}, type_: {:?}, id: {:?}, tags_json: {:?}", wallet_handle, type_, id, tags_json);
 
-    let result = CommandExecutor::instance()
-        .send(Command::NonSecrets(
+    let result = CommandExecutor::submit(Command::NonSecrets(
             NonSecretsCommand::UpdateRecordTags(
                 wallet_handle,
                 type_,
                 id,
                 tags_json,
-                Box::new(move |result| {
+                with_timeout(move |result| {
                     let err = prepare_result!(result);
                     trace!("indy_update_wallet_record_tags:");
                     cb(command_handle, err)
@@ -180,10 +336,14 @@ pub extern fn indy_update_wallet_record_tags(command_handle: CommandHandle,
 ///     "tagName2": <str>, // string tag (will be stored encrypted)
 ///     "~tagName3": <str>, // string tag (will be stored un-encrypted)
 ///     "~tagName4": <str>, // string tag (will be stored un-encrypted)
+///     "#~tagName5": <str>, // unsigned integer tag (will be stored encrypted with range index)
 ///   }
 ///   If tag name starts with "~" the tag will be stored un-encrypted that will allow
 ///   usage of this tag in complex search queries (comparison, predicates)
 ///   Encrypted tags can be searched only for exact matching
+///   If tag name starts with "#~" the tag value must be unsigned integer. The tag will be stored
+///   encrypted together with range index that will allow usage of it in comparison queries.
+///   Other tags starting with "#" are stored as regular encrypted tags
 ///   Note if some from provided tags already assigned to the record than
 ///     corresponding tags values will be replaced
 #[no_mangle]
@@ -195,6 +355,8 @@ pub extern fn indy_add_wallet_record_tags(command_handle: CommandHandle,
                                           cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode)>) -> ErrorCode {
     trace!("indy_add_wallet_record_tags: >>> wallet_handle: {:?}, type_: {:?}, id: {:?}, tags_json: {:?}", wallet_handle, type_, id, tags_json);
 
+    command_params!(wallet_handle, type_, id, tags_json);
+
     check_useful_c_str!(type_, ErrorCode::CommonInvalidParam3);
     check_useful_c_str!(id, ErrorCode::CommonInvalidParam4);
     check_useful_json!(tags_json, ErrorCode::CommonInvalidParam5, Tags);
@@ -202,14 +364,13 @@ pub extern fn indy_add_wallet_record_tags(command_handle: CommandHandle,
 
     trace!("indy_add_wallet_record_tags: entities >>> wallet_handle: {:?}, type_: {:?}, id: {:?}, tags_json: {:?}", wallet_handle, type_, id, tags_json);
 
-    let result = CommandExecutor::instance()
-        .send(Command::NonSecrets(
+    let result = CommandExecutor::submit(Command::NonSecrets(
             NonSecretsCommand::AddRecordTags(
                 wallet_handle,
                 type_,
                 id,
                 tags_json,
-                Box::new(move |result| {
+                with_timeout(move |result| {
                     let err = prepare_result!(result);
                     trace!("indy_add_wallet_record_tags:");
                     cb(command_handle, err)
@@ -241,6 +402,8 @@ pub extern fn indy_delete_wallet_record_tags(command_handle: CommandHandle,
                                              cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode)>) -> ErrorCode {
     trace!("indy_delete_wallet_record_tags: >>> wallet_handle: {:?}, type_: {:?}, id: {:?}, tag_names_json: {:?}", wallet_handle, type_, id, tag_names_json);
 
+    command_params!(wallet_handle, type_, id, tag_names_json);
+
     check_useful_c_str!(type_, ErrorCode::CommonInvalidParam3);
     check_useful_c_str!(id, ErrorCode::CommonInvalidParam4);
     check_useful_c_str!(tag_names_json, ErrorCode::CommonInvalidParam5);
@@ -248,14 +411,13 @@ pub extern fn indy_delete_wallet_record_tags(command_handle: CommandHandle,
 
     trace!("indy_delete_wallet_record_tags: entities >>> wallet_handle: {:?}, type_: {:?}, id: {:?}, tag_names_json: {:?}", wallet_handle, type_, id, tag_names_json);
 
-    let result = CommandExecutor::instance()
-        .send(Command::NonSecrets(
+    let result = CommandExecutor::submit(Command::NonSecrets(
             NonSecretsCommand::DeleteRecordTags(
                 wallet_handle,
                 type_,
                 id,
                 tag_names_json,
-                Box::new(move |result| {
+                with_timeout(move |result| {
                     let err = prepare_result!(result);
                     trace!("indy_delete_wallet_record_tags:");
                     cb(command_handle, err)
@@ -284,19 +446,20 @@ pub extern fn indy_delete_wallet_record(command_handle: CommandHandle,
                                         cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode)>) -> ErrorCode {
     trace!("indy_delete_wallet_record: >>> wallet_handle: {:?}, type_: {:?}, id: {:?}", wallet_handle, type_, id);
 
+    command_params!(wallet_handle, type_, id);
+
     check_useful_c_str!(type_, ErrorCode::CommonInvalidParam3);
     check_useful_c_str!(id, ErrorCode::CommonInvalidParam4);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
 
     trace!("indy_delete_wallet_record: entities >>> wallet_handle: {:?}, type_: {:?}, id: {:?}", wallet_handle, type_, id);
 
-    let result = CommandExecutor::instance()
-        .send(Command::NonSecrets(
+    let result = CommandExecutor::submit(Command::NonSecrets(
             NonSecretsCommand::DeleteRecord(
                 wallet_handle,
                 type_,
                 id,
-                Box::new(move |result| {
+                with_timeout(move |result| {
                     let err = prepare_result!(result);
                     trace!("indy_delete_wallet_record:");
                     cb(command_handle, err)
@@ -310,6 +473,60 @@ pub extern fn indy_delete_wallet_record(command_handle: CommandHandle,
     res
 }
 
+/// Insert new or update existing non-secret records in the wallet.
+///
+/// All records are applied in a single storage transaction: either all records are stored
+/// or none of them in case of failure (if wallet storage supports transactions).
+/// Value and tags of existing records are replaced.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context
+/// wallet_handle: wallet handle (created by open_wallet)
+/// type_: allows to separate different record types collections
+/// records_json: list of records to store as json:
+///   [{
+///     "id": <str>, // the id of record
+///     "value": <str>, // the value of record
+///     "tags": <tags json>, // (optional) the record tags (see indy_add_wallet_record)
+///   }]
+/// #Returns
+/// statuses_json: status of each record in the same order as records passed:
+///   [{
+///     "id": <str>, // the id of record
+///     "status": <str>, // "inserted" for new record or "updated" for existing one
+///   }]
+#[no_mangle]
+pub extern fn indy_upsert_wallet_records(command_handle: CommandHandle,
+                                         wallet_handle: WalletHandle,
+                                         type_: *const c_char,
+                                         records_json: *const c_char,
+                                         cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                              statuses_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_upsert_wallet_records: >>> wallet_handle: {:?}, type_: {:?}, records_json: {:?}", wallet_handle, type_, records_json);
+
+    command_params!(wallet_handle, type_, records_json);
+
+    check_useful_c_str!(type_, ErrorCode::CommonInvalidParam3);
+    check_useful_json!(records_json, ErrorCode::CommonInvalidParam4, Vec<UpsertRecord>);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_upsert_wallet_records: entities >>> wallet_handle: {:?}, type_: {:?}, records_json: {:?}", wallet_handle, type_, records_json);
+
+    let result = CommandExecutor::submit(Command::NonSecrets(
+            NonSecretsCommand::UpsertRecords(
+                wallet_handle,
+                type_,
+                records_json,
+                with_timeout(boxed_callback_string!("indy_upsert_wallet_records", cb, command_handle))
+            )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_upsert_wallet_records: <<< res: {:?}", res);
+
+    res
+}
+
 /// Get an wallet record by id
 ///
 /// #Params
@@ -341,6 +558,8 @@ pub  extern fn indy_get_wallet_record(command_handle: CommandHandle,
                                                            record_json: *const c_char)>) -> ErrorCode {
     trace!("indy_get_wallet_record: >>> wallet_handle: {:?}, type_: {:?}, id: {:?}, options_json: {:?}", wallet_handle, type_, id, options_json);
 
+    command_params!(wallet_handle, type_, id, options_json);
+
     check_useful_c_str!(type_, ErrorCode::CommonInvalidParam3);
     check_useful_c_str!(id, ErrorCode::CommonInvalidParam4);
     check_useful_c_str!(options_json, ErrorCode::CommonInvalidParam5);
@@ -348,14 +567,13 @@ pub  extern fn indy_get_wallet_record(command_handle: CommandHandle,
 
     trace!("indy_get_wallet_record: entities >>> wallet_handle: {:?}, type_: {:?}, id: {:?}, options_json: {:?}", wallet_handle, type_, id, options_json);
 
-    let result = CommandExecutor::instance()
-        .send(Command::NonSecrets(
+    let result = CommandExecutor::submit(Command::NonSecrets(
             NonSecretsCommand::GetRecord(
                 wallet_handle,
                 type_,
                 id,
                 options_json,
-                boxed_callback_string!("indy_get_wallet_record", cb, command_handle)
+                with_timeout(boxed_callback_string!("indy_get_wallet_record", cb, command_handle))
             )));
 
     let res = prepare_result!(result);
@@ -365,6 +583,110 @@ pub  extern fn indy_get_wallet_record(command_handle: CommandHandle,
     res
 }
 
+/// Get all record types present in the wallet with count of records of each type.
+///
+/// Allows migration and cleanup tools to discover what the wallet actually contains.
+/// Note that types of records created by libindy itself (prefixed with "Indy::") are listed too.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context
+/// wallet_handle: wallet handle (created by open_wallet)
+///
+/// #Returns
+/// types_json: record types with count of records as json:
+///   {
+///     "type1": <int>, // count of records of type1
+///     ...
+///   }
+#[no_mangle]
+pub extern fn indy_get_wallet_record_types(command_handle: CommandHandle,
+                                           wallet_handle: WalletHandle,
+                                           cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                types_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_get_wallet_record_types: >>> wallet_handle: {:?}", wallet_handle);
+
+    command_params!(wallet_handle);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_get_wallet_record_types: entities >>> wallet_handle: {:?}", wallet_handle);
+
+    let result = CommandExecutor::submit(Command::NonSecrets(
+            NonSecretsCommand::GetRecordTypes(
+                wallet_handle,
+                with_timeout(boxed_callback_string!("indy_get_wallet_record_types", cb, command_handle))
+            )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_get_wallet_record_types: <<< res: {:?}", res);
+
+    res
+}
+
+/// Explain how wallet search with the query is executed by the storage.
+///
+/// Diagnostic call that helps to understand why some searches on large wallets are slow.
+/// Records are only counted, so types of records created by libindy (prefixed with "Indy::") can be explained too.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context
+/// wallet_handle: wallet handle (created by open_wallet)
+/// type_: allows to separate different record types collections
+/// query_json: MongoDB style query to wallet record tags (see indy_open_wallet_search)
+///
+/// #Returns
+/// plan_json: search plan as json:
+///   {
+///     "query": <wql>, // optimised query that is actually executed
+///     "tags": [{ // tags used by the query
+///         "name": string,
+///         "kind": "encrypted" | "plaintext" | "range",
+///         "operators": [string], // for example "$eq", "$like"
+///         "indexed": bool, // whether all conditions on the tag can use tag index
+///     }],
+///     "usesIndex": bool, // false if all records of the type are checked, for example for $neq, $not
+///                        // or $like patterns starting with a wildcard
+///     "recordsOfType": <int>, // count of records of the type
+///     "estimatedScan": <int>, // estimated count of records checked to execute the query
+///     "storagePlan": [string], // (optional) plan reported by storage, `EXPLAIN QUERY PLAN` details for default storage
+///   }
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_explain_wallet_search(command_handle: CommandHandle,
+                                         wallet_handle: WalletHandle,
+                                         type_: *const c_char,
+                                         query_json: *const c_char,
+                                         cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                              plan_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_explain_wallet_search: >>> wallet_handle: {:?}, type_: {:?}, query_json: {:?}", wallet_handle, type_, query_json);
+
+    command_params!(wallet_handle, type_, query_json);
+
+    check_useful_c_str!(type_, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(query_json, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_explain_wallet_search: entities >>> wallet_handle: {:?}, type_: {:?}, query_json: {:?}", wallet_handle, type_, query_json);
+
+    let result = CommandExecutor::submit(Command::NonSecrets(
+            NonSecretsCommand::ExplainSearch(
+                wallet_handle,
+                type_,
+                query_json,
+                with_timeout(boxed_callback_string!("indy_explain_wallet_search", cb, command_handle))
+            )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_explain_wallet_search: <<< res: {:?}", res);
+
+    res
+}
+
 /// Search for wallet records.
 ///
 /// Note instead of immediately returning of fetched records
@@ -389,6 +711,7 @@ pub  extern fn indy_get_wallet_record(command_handle: CommandHandle,
 ///    retrieveType: (optional, false by default) Retrieve record type,
 ///    retrieveValue: (optional, true by default) Retrieve record value,
 ///    retrieveTags: (optional, false by default) Retrieve record tags,
+///      list of tag names can be passed instead of flag to retrieve only these tags: ["tagName1", "~tagName2"]
 ///  }
 /// #Returns
 /// search_handle: Wallet search handle that can be used later
@@ -403,6 +726,8 @@ pub  extern fn indy_open_wallet_search(command_handle: CommandHandle,
                                                             search_handle: SearchHandle)>) -> ErrorCode {
     trace!("indy_open_wallet_search: >>> wallet_handle: {:?}, type_: {:?}, query_json: {:?}, options_json: {:?}", wallet_handle, type_, query_json, options_json);
 
+    command_params!(wallet_handle, type_, query_json, options_json);
+
     check_useful_c_str!(type_, ErrorCode::CommonInvalidParam3);
     check_useful_c_str!(query_json, ErrorCode::CommonInvalidParam4);
     check_useful_c_str!(options_json, ErrorCode::CommonInvalidParam5);
@@ -410,14 +735,13 @@ pub  extern fn indy_open_wallet_search(command_handle: CommandHandle,
 
     trace!("indy_open_wallet_search: entities >>> wallet_handle: {:?}, type_: {:?}, query_json: {:?}, options_json: {:?}", wallet_handle, type_, query_json, options_json);
 
-    let result = CommandExecutor::instance()
-        .send(Command::NonSecrets(
+    let result = CommandExecutor::submit(Command::NonSecrets(
             NonSecretsCommand::OpenSearch(
                 wallet_handle,
                 type_,
                 query_json,
                 options_json,
-                Box::new(move |result| {
+                with_timeout(move |result| {
                     let (err, handle) = prepare_result_1!(result, INVALID_SEARCH_HANDLE);
                     trace!("indy_open_wallet_search: handle: {:?}", handle);
                     cb(command_handle, err, handle)
@@ -460,17 +784,18 @@ pub  extern fn indy_fetch_wallet_search_next_records(command_handle: CommandHand
                                                                           records_json: *const c_char)>) -> ErrorCode {
     trace!("indy_fetch_wallet_search_next_records: >>> wallet_handle: {:?}, wallet_search_handle: {:?}, count: {:?}", wallet_handle, wallet_search_handle, count);
 
+    command_params!(wallet_handle, wallet_search_handle, count);
+
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
 
     trace!("indy_fetch_wallet_search_next_records: entities >>> wallet_handle: {:?}, wallet_search_handle: {:?}, count: {:?}", wallet_handle, wallet_search_handle, count);
 
-    let result = CommandExecutor::instance()
-        .send(Command::NonSecrets(
+    let result = CommandExecutor::submit(Command::NonSecrets(
             NonSecretsCommand::FetchSearchNextRecords(
                 wallet_handle,
                 wallet_search_handle,
                 count,
-                boxed_callback_string!("indy_fetch_wallet_search_next_records", cb, command_handle)
+                with_timeout(boxed_callback_string!("indy_fetch_wallet_search_next_records", cb, command_handle))
             )));
 
     let res = prepare_result!(result);
@@ -490,15 +815,16 @@ pub  extern fn indy_close_wallet_search(command_handle: CommandHandle,
                                         cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode)>) -> ErrorCode {
     trace!("indy_close_wallet_search: >>> wallet_search_handle: {:?}", wallet_search_handle);
 
+    command_params!(wallet_search_handle);
+
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
 
     trace!("indy_close_wallet_search: entities >>> wallet_search_handle: {:?}", wallet_search_handle);
 
-    let result = CommandExecutor::instance()
-        .send(Command::NonSecrets(
+    let result = CommandExecutor::submit(Command::NonSecrets(
             NonSecretsCommand::CloseSearch(
                 wallet_search_handle,
-                Box::new(move |result| {
+                with_timeout(move |result| {
                     let err = prepare_result!(result);
                     trace!("indy_close_wallet_search:");
                     cb(command_handle, err)
@@ -511,3 +837,369 @@ pub  extern fn indy_close_wallet_search(command_handle: CommandHandle,
 
     res
 }
+
+/// Streams wallet records matching the query.
+///
+/// Records are fetched by chunks of `chunk_size` records and every chunk is passed to `records_cb`
+/// as soon as it's fetched, so the result of the search doesn't have to fit into memory at once.
+/// `cb` is called once all the records are streamed or fetching is failed.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet handle (created by open_wallet)
+/// type_: allows to separate different record types collections
+/// query_json: MongoDB style query to wallet record tags (see indy_open_wallet_search)
+/// options_json: search options (see indy_open_wallet_search)
+/// chunk_size: maximum count of records passed to `records_cb` at once
+/// records_cb: Callback that takes a chunk of records in the format of indy_fetch_wallet_search_next_records result.
+///     Chunks without records are not passed.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error code
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub  extern fn indy_stream_wallet_records(command_handle: CommandHandle,
+                                          wallet_handle: WalletHandle,
+                                          type_: *const c_char,
+                                          query_json: *const c_char,
+                                          options_json: *const c_char,
+                                          chunk_size: u32,
+                                          records_cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                       records_json: *const c_char)>,
+                                          cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_stream_wallet_records: >>> wallet_handle: {:?}, type_: {:?}, query_json: {:?}, options_json: {:?}, chunk_size: {:?}",
+           wallet_handle, type_, query_json, options_json, chunk_size);
+
+    command_params!(wallet_handle, type_, query_json, options_json, chunk_size);
+
+    check_useful_c_str!(type_, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(query_json, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(options_json, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(records_cb, ErrorCode::CommonInvalidParam7);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam8);
+
+    if chunk_size == 0 {
+        return IndyError::from_msg(IndyErrorKind::InvalidParam(6), "Chunk size must be positive").into();
+    }
+
+    trace!("indy_stream_wallet_records: entities >>> wallet_handle: {:?}, type_: {:?}, query_json: {:?}, options_json: {:?}, chunk_size: {:?}",
+           wallet_handle, type_, query_json, options_json, chunk_size);
+
+    let result = CommandExecutor::submit(Command::NonSecrets(
+            NonSecretsCommand::StreamRecords(
+                wallet_handle,
+                type_,
+                query_json,
+                options_json,
+                chunk_size as usize,
+                Box::new(move |records_json| {
+                    trace!("indy_stream_wallet_records: records: {:?}", records_json);
+                    let records_json = ctypes::string_to_cstring(records_json);
+                    records_cb(command_handle, records_json.as_ptr())
+                }),
+                // streaming of many records is expected to last longer than call timeout
+                Box::new(move |result| {
+                    let err = prepare_result!(result);
+                    trace!("indy_stream_wallet_records: err: {:?}", err);
+                    cb(command_handle, err)
+                })
+            )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_stream_wallet_records: <<< res: {:?}", res);
+
+    res
+}
+
+/// Enable revision history of wallet records of the type.
+///
+/// Before record of the type is updated (value or tags) or deleted its current state is saved
+/// as the next revision, so prior states can be listed and fetched later.
+/// If history is already enabled its config is replaced.
+///
+/// Besides non-secret records history can be enabled for credentials ("Indy::Credential")
+/// and DID metadata ("Indy::DidMetadata").
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context
+/// wallet_handle: wallet handle (created by open_wallet)
+/// type_: record type
+/// config_json: history config as json:
+///   {
+///     "maxRevisions": <int>, // (optional) count of the latest revisions to keep for each record.
+///                            // Older revisions are pruned on update. All revisions are kept by default
+///   }
+#[no_mangle]
+pub extern fn indy_enable_wallet_record_history(command_handle: CommandHandle,
+                                                wallet_handle: WalletHandle,
+                                                type_: *const c_char,
+                                                config_json: *const c_char,
+                                                cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_enable_wallet_record_history: >>> wallet_handle: {:?}, type_: {:?}, config_json: {:?}", wallet_handle, type_, config_json);
+
+    command_params!(wallet_handle, type_, config_json);
+
+    check_useful_c_str!(type_, ErrorCode::CommonInvalidParam3);
+    check_useful_json!(config_json, ErrorCode::CommonInvalidParam4, RecordHistoryConfig);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_enable_wallet_record_history: entities >>> wallet_handle: {:?}, type_: {:?}, config_json: {:?}", wallet_handle, type_, config_json);
+
+    let result = CommandExecutor::submit(Command::NonSecrets(
+            NonSecretsCommand::EnableRecordHistory(
+                wallet_handle,
+                type_,
+                config_json,
+                with_timeout(move |result| {
+                    let err = prepare_result!(result);
+                    trace!("indy_enable_wallet_record_history:");
+                    cb(command_handle, err)
+                })
+            )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_enable_wallet_record_history: <<< res: {:?}", res);
+
+    res
+}
+
+/// Disable revision history of wallet records of the type.
+/// Already saved revisions are kept until pruned with indy_prune_wallet_record_history.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context
+/// wallet_handle: wallet handle (created by open_wallet)
+/// type_: record type
+#[no_mangle]
+pub extern fn indy_disable_wallet_record_history(command_handle: CommandHandle,
+                                                 wallet_handle: WalletHandle,
+                                                 type_: *const c_char,
+                                                 cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_disable_wallet_record_history: >>> wallet_handle: {:?}, type_: {:?}", wallet_handle, type_);
+
+    command_params!(wallet_handle, type_);
+
+    check_useful_c_str!(type_, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_disable_wallet_record_history: entities >>> wallet_handle: {:?}, type_: {:?}", wallet_handle, type_);
+
+    let result = CommandExecutor::submit(Command::NonSecrets(
+            NonSecretsCommand::DisableRecordHistory(
+                wallet_handle,
+                type_,
+                with_timeout(move |result| {
+                    let err = prepare_result!(result);
+                    trace!("indy_disable_wallet_record_history:");
+                    cb(command_handle, err)
+                })
+            )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_disable_wallet_record_history: <<< res: {:?}", res);
+
+    res
+}
+
+/// Get the list of saved revisions of wallet record (see indy_enable_wallet_record_history).
+/// Revisions of deleted record are available too.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context
+/// wallet_handle: wallet handle (created by open_wallet)
+/// type_: record type
+/// id: the id of record
+///
+/// #Returns
+/// revisions_json: revisions from the oldest to the latest one:
+///   [{
+///     "revision": <int>, // number of revision. Numbers start from 1 and are never reused
+///     "replacedAt": <int>, // time (in seconds since epoch) when the state was replaced by update or deletion
+///   }]
+#[no_mangle]
+pub extern fn indy_get_wallet_record_revisions(command_handle: CommandHandle,
+                                               wallet_handle: WalletHandle,
+                                               type_: *const c_char,
+                                               id: *const c_char,
+                                               cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                    revisions_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_get_wallet_record_revisions: >>> wallet_handle: {:?}, type_: {:?}, id: {:?}", wallet_handle, type_, id);
+
+    command_params!(wallet_handle, type_, id);
+
+    check_useful_c_str!(type_, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(id, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_get_wallet_record_revisions: entities >>> wallet_handle: {:?}, type_: {:?}, id: {:?}", wallet_handle, type_, id);
+
+    let result = CommandExecutor::submit(Command::NonSecrets(
+            NonSecretsCommand::GetRecordRevisions(
+                wallet_handle,
+                type_,
+                id,
+                with_timeout(boxed_callback_string!("indy_get_wallet_record_revisions", cb, command_handle))
+            )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_get_wallet_record_revisions: <<< res: {:?}", res);
+
+    res
+}
+
+/// Get saved revision of wallet record.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context
+/// wallet_handle: wallet handle (created by open_wallet)
+/// type_: record type
+/// id: the id of record
+/// revision: number of revision (see indy_get_wallet_record_revisions)
+///
+/// #Returns
+/// revision_json: prior state of the record:
+///   {
+///     "revision": <int>, // number of revision
+///     "value": <str>, // the value of record
+///     "tags": <tags json>, // the record tags
+///     "replacedAt": <int>, // time (in seconds since epoch) when the state was replaced by update or deletion
+///   }
+///
+/// #Errors
+/// WalletItemNotFound - revision doesn't exist or was pruned
+#[no_mangle]
+pub extern fn indy_get_wallet_record_revision(command_handle: CommandHandle,
+                                              wallet_handle: WalletHandle,
+                                              type_: *const c_char,
+                                              id: *const c_char,
+                                              revision: u32,
+                                              cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                   revision_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_get_wallet_record_revision: >>> wallet_handle: {:?}, type_: {:?}, id: {:?}, revision: {:?}", wallet_handle, type_, id, revision);
+
+    command_params!(wallet_handle, type_, id, revision);
+
+    check_useful_c_str!(type_, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(id, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_get_wallet_record_revision: entities >>> wallet_handle: {:?}, type_: {:?}, id: {:?}, revision: {:?}", wallet_handle, type_, id, revision);
+
+    let result = CommandExecutor::submit(Command::NonSecrets(
+            NonSecretsCommand::GetRecordRevision(
+                wallet_handle,
+                type_,
+                id,
+                revision,
+                with_timeout(boxed_callback_string!("indy_get_wallet_record_revision", cb, command_handle))
+            )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_get_wallet_record_revision: <<< res: {:?}", res);
+
+    res
+}
+
+/// Delete saved revisions of wallet record except the latest ones.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context
+/// wallet_handle: wallet handle (created by open_wallet)
+/// type_: record type
+/// id: the id of record
+/// keep_last: count of the latest revisions to keep. 0 means all revisions are deleted
+#[no_mangle]
+pub extern fn indy_prune_wallet_record_history(command_handle: CommandHandle,
+                                               wallet_handle: WalletHandle,
+                                               type_: *const c_char,
+                                               id: *const c_char,
+                                               keep_last: u32,
+                                               cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_prune_wallet_record_history: >>> wallet_handle: {:?}, type_: {:?}, id: {:?}, keep_last: {:?}", wallet_handle, type_, id, keep_last);
+
+    command_params!(wallet_handle, type_, id, keep_last);
+
+    check_useful_c_str!(type_, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(id, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_prune_wallet_record_history: entities >>> wallet_handle: {:?}, type_: {:?}, id: {:?}, keep_last: {:?}", wallet_handle, type_, id, keep_last);
+
+    let result = CommandExecutor::submit(Command::NonSecrets(
+            NonSecretsCommand::PruneRecordHistory(
+                wallet_handle,
+                type_,
+                id,
+                keep_last as usize,
+                with_timeout(move |result| {
+                    let err = prepare_result!(result);
+                    trace!("indy_prune_wallet_record_history:");
+                    cb(command_handle, err)
+                })
+            )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_prune_wallet_record_history: <<< res: {:?}", res);
+
+    res
+}
+
+context_api! {
+    indy_add_wallet_record_ctx => indy_add_wallet_record(wallet_handle: WalletHandle, type_: *const c_char, id: *const c_char, value: *const c_char, tags_json: *const c_char)
+        -> cb(), CommonInvalidParam7;
+    indy_update_wallet_record_value_ctx => indy_update_wallet_record_value(wallet_handle: WalletHandle, type_: *const c_char, id: *const c_char, value: *const c_char)
+        -> cb(), CommonInvalidParam6;
+    indy_compare_and_swap_wallet_record_value_ctx => indy_compare_and_swap_wallet_record_value(wallet_handle: WalletHandle, type_: *const c_char, id: *const c_char, expected_value_hash: *const c_char, value: *const c_char)
+        -> cb(value_hash: *const c_char), CommonInvalidParam7;
+    indy_update_wallet_record_tags_ctx => indy_update_wallet_record_tags(wallet_handle: WalletHandle, type_: *const c_char, id: *const c_char, tags_json: *const c_char)
+        -> cb(), CommonInvalidParam6;
+    indy_add_wallet_record_tags_ctx => indy_add_wallet_record_tags(wallet_handle: WalletHandle, type_: *const c_char, id: *const c_char, tags_json: *const c_char)
+        -> cb(), CommonInvalidParam6;
+    indy_delete_wallet_record_tags_ctx => indy_delete_wallet_record_tags(wallet_handle: WalletHandle, type_: *const c_char, id: *const c_char, tag_names_json: *const c_char)
+        -> cb(), CommonInvalidParam6;
+    indy_delete_wallet_record_ctx => indy_delete_wallet_record(wallet_handle: WalletHandle, type_: *const c_char, id: *const c_char)
+        -> cb(), CommonInvalidParam5;
+    indy_upsert_wallet_records_ctx => indy_upsert_wallet_records(wallet_handle: WalletHandle, type_: *const c_char, records_json: *const c_char)
+        -> cb(statuses_json: *const c_char), CommonInvalidParam5;
+    indy_get_wallet_record_ctx => indy_get_wallet_record(wallet_handle: WalletHandle, type_: *const c_char, id: *const c_char, options_json: *const c_char)
+        -> cb(record_json: *const c_char), CommonInvalidParam6;
+    indy_get_wallet_record_types_ctx => indy_get_wallet_record_types(wallet_handle: WalletHandle)
+        -> cb(types_json: *const c_char), CommonInvalidParam3;
+    indy_explain_wallet_search_ctx => indy_explain_wallet_search(wallet_handle: WalletHandle, type_: *const c_char, query_json: *const c_char)
+        -> cb(plan_json: *const c_char), CommonInvalidParam5;
+    indy_open_wallet_search_ctx => indy_open_wallet_search(wallet_handle: WalletHandle, type_: *const c_char, query_json: *const c_char, options_json: *const c_char)
+        -> cb(search_handle: SearchHandle), CommonInvalidParam6;
+    indy_fetch_wallet_search_next_records_ctx => indy_fetch_wallet_search_next_records(wallet_handle: WalletHandle, wallet_search_handle: SearchHandle, count: usize)
+        -> cb(records_json: *const c_char), CommonInvalidParam5;
+    indy_close_wallet_search_ctx => indy_close_wallet_search(wallet_search_handle: SearchHandle)
+        -> cb(), CommonInvalidParam3;
+    indy_enable_wallet_record_history_ctx => indy_enable_wallet_record_history(wallet_handle: WalletHandle, type_: *const c_char, config_json: *const c_char)
+        -> cb(), CommonInvalidParam5;
+    indy_disable_wallet_record_history_ctx => indy_disable_wallet_record_history(wallet_handle: WalletHandle, type_: *const c_char)
+        -> cb(), CommonInvalidParam4;
+    indy_get_wallet_record_revisions_ctx => indy_get_wallet_record_revisions(wallet_handle: WalletHandle, type_: *const c_char, id: *const c_char)
+        -> cb(revisions_json: *const c_char), CommonInvalidParam5;
+    indy_get_wallet_record_revision_ctx => indy_get_wallet_record_revision(wallet_handle: WalletHandle, type_: *const c_char, id: *const c_char, revision: u32)
+        -> cb(revision_json: *const c_char), CommonInvalidParam6;
+    indy_prune_wallet_record_history_ctx => indy_prune_wallet_record_history(wallet_handle: WalletHandle, type_: *const c_char, id: *const c_char, keep_last: u32)
+        -> cb(), CommonInvalidParam6;
+}
+
+context_data_api! {
+    indy_modify_wallet_record_value_ctx => indy_modify_wallet_record_value(wallet_handle: WalletHandle, type_: *const c_char, id: *const c_char)
+        -> modify_cb(current_value: *const c_char, new_value_p: *mut *const c_char) -> ErrorCode, CommonInvalidParam5,
+           cb(new_value: *const c_char), CommonInvalidParam6;
+    indy_stream_wallet_records_ctx => indy_stream_wallet_records(wallet_handle: WalletHandle, type_: *const c_char, query_json: *const c_char, options_json: *const c_char, chunk_size: u32)
+        -> records_cb(records_json: *const c_char), CommonInvalidParam7,
+           cb(), CommonInvalidParam8;
+}
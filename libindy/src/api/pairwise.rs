@@ -1,6 +1,8 @@
-use indy_api_types::{ErrorCode, CommandHandle, WalletHandle};
+use indy_api_types::{ErrorCode, CommandHandle, WalletHandle, SearchHandle, INVALID_SEARCH_HANDLE};
 use crate::commands::{Command, CommandExecutor};
+use crate::commands::timeout::with_timeout;
 use crate::commands::pairwise::PairwiseCommand;
+use crate::domain::pairwise::{PairwiseLink, PairwiseMetaUpdate, PairwiseSearchOptions};
 use indy_api_types::errors::prelude::*;
 use indy_utils::ctypes;
 use indy_api_types::validation::Validatable;
@@ -31,16 +33,17 @@ pub  extern fn indy_is_pairwise_exists(command_handle: CommandHandle,
                                                             err: ErrorCode, exists: bool)>) -> ErrorCode {
     trace!("indy_is_pairwise_exists: >>> wallet_handle: {:?}, their_did: {:?}", wallet_handle, their_did);
 
+    command_params!(wallet_handle, their_did);
+
     check_useful_validatable_string!(their_did, ErrorCode::CommonInvalidParam3, DidValue);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     trace!("indy_is_pairwise_exists: entities >>> wallet_handle: {:?}, their_did: {:?}", wallet_handle, their_did);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Pairwise(PairwiseCommand::PairwiseExists(
+    let result = CommandExecutor::submit(Command::Pairwise(PairwiseCommand::PairwiseExists(
             wallet_handle,
             their_did,
-            Box::new(move |result| {
+            with_timeout(move |result| {
                 let (err, exists) = prepare_result_1!(result, false);
                 trace!("indy_is_pairwise_exists: exists: {:?}", exists);
                 cb(command_handle, err, exists)
@@ -65,6 +68,8 @@ pub  extern fn indy_is_pairwise_exists(command_handle: CommandHandle,
 /// metadata Optional: extra information for pairwise
 /// cb: Callback that takes command result as parameter.
 ///
+/// Creation time of the pairwise is stored in its structured meta (see "indy_set_pairwise_meta").
+///
 /// #Returns
 /// Error code
 ///
@@ -81,6 +86,8 @@ pub  extern fn indy_create_pairwise(command_handle: CommandHandle,
                                                          err: ErrorCode)>) -> ErrorCode {
     trace!("indy_create_pairwise: >>> wallet_handle: {:?}, their_did: {:?}, my_did: {:?}, metadata: {:?}", wallet_handle, their_did, my_did, metadata);
 
+    command_params!(wallet_handle, their_did, my_did, metadata);
+
     check_useful_validatable_string!(their_did, ErrorCode::CommonInvalidParam3, DidValue);
     check_useful_validatable_string!(my_did, ErrorCode::CommonInvalidParam4, DidValue);
     check_useful_opt_c_str!(metadata, ErrorCode::CommonInvalidParam5);
@@ -88,13 +95,12 @@ pub  extern fn indy_create_pairwise(command_handle: CommandHandle,
 
     trace!("indy_create_pairwise: entities >>> wallet_handle: {:?}, their_did: {:?}, my_did: {:?}, metadata: {:?}", wallet_handle, their_did, my_did, metadata);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Pairwise(PairwiseCommand::CreatePairwise(
+    let result = CommandExecutor::submit(Command::Pairwise(PairwiseCommand::CreatePairwise(
             wallet_handle,
             their_did,
             my_did,
             metadata,
-            Box::new(move |result| {
+            with_timeout(move |result| {
                 let err = prepare_result!(result);
                 trace!("indy_create_pairwise:");
                 cb(command_handle, err)
@@ -110,6 +116,9 @@ pub  extern fn indy_create_pairwise(command_handle: CommandHandle,
 
 /// Get list of saved pairwise.
 ///
+/// NOTE: This method immediately returns all pairwise stored in the wallet.
+/// Use <indy_open_pairwise_search> to filter pairwise and fetch them by small batches.
+///
 /// #Params
 /// wallet_handle: wallet handler (created by open_wallet).
 /// command_handle: command handle to map callback to user context.
@@ -129,14 +138,15 @@ pub  extern fn indy_list_pairwise(command_handle: CommandHandle,
                                                        list_pairwise: *const c_char)>) -> ErrorCode {
     trace!("indy_list_pairwise: >>> wallet_handle: {:?}", wallet_handle);
 
+    command_params!(wallet_handle);
+
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
 
     trace!("indy_list_pairwise: entities >>> wallet_handle: {:?}", wallet_handle);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Pairwise(PairwiseCommand::ListPairwise(
+    let result = CommandExecutor::submit(Command::Pairwise(PairwiseCommand::ListPairwise(
             wallet_handle,
-            boxed_callback_string!("indy_list_pairwise", cb, command_handle)
+            with_timeout(boxed_callback_string!("indy_list_pairwise", cb, command_handle))
         )));
 
     let res = prepare_result!(result);
@@ -169,16 +179,17 @@ pub  extern fn indy_get_pairwise(command_handle: CommandHandle,
                                                       pairwise_info_json: *const c_char)>) -> ErrorCode {
     trace!("indy_get_pairwise: >>> wallet_handle: {:?}, their_did: {:?}", wallet_handle, their_did);
 
+    command_params!(wallet_handle, their_did);
+
     check_useful_validatable_string!(their_did, ErrorCode::CommonInvalidParam3, DidValue);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     trace!("indy_get_pairwise: entities >>> wallet_handle: {:?}, their_did: {:?}", wallet_handle, their_did);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Pairwise(PairwiseCommand::GetPairwise(
+    let result = CommandExecutor::submit(Command::Pairwise(PairwiseCommand::GetPairwise(
             wallet_handle,
             their_did,
-            boxed_callback_string!("indy_get_pairwise", cb, command_handle)
+            with_timeout(boxed_callback_string!("indy_get_pairwise", cb, command_handle))
         )));
 
     let res = prepare_result!(result);
@@ -212,18 +223,19 @@ pub  extern fn indy_set_pairwise_metadata(command_handle: CommandHandle,
                                                                err: ErrorCode)>) -> ErrorCode {
     trace!("indy_set_pairwise_metadata: >>> wallet_handle: {:?}, their_did: {:?}, metadata: {:?}", wallet_handle, their_did, metadata);
 
+    command_params!(wallet_handle, their_did, metadata);
+
     check_useful_validatable_string!(their_did, ErrorCode::CommonInvalidParam3, DidValue);
     check_useful_opt_c_str!(metadata, ErrorCode::CommonInvalidParam4);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
 
     trace!("indy_set_pairwise_metadata: entities >>> wallet_handle: {:?}, their_did: {:?}, metadata: {:?}", wallet_handle, their_did, metadata);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Pairwise(PairwiseCommand::SetPairwiseMetadata(
+    let result = CommandExecutor::submit(Command::Pairwise(PairwiseCommand::SetPairwiseMetadata(
             wallet_handle,
             their_did,
             metadata,
-            Box::new(move |result| {
+            with_timeout(move |result| {
                 let err = prepare_result!(result);
                 trace!("indy_set_pairwise_metadata:");
                 cb(command_handle, err)
@@ -236,3 +248,576 @@ pub  extern fn indy_set_pairwise_metadata(command_handle: CommandHandle,
 
     res
 }
+
+/// Updates their DID of pairwise, for example after rotation of DID by the other party.
+/// Metadata and structured meta of the pairwise are kept.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// their_did: current their DID of the pairwise
+/// new_their_did: new their DID of the pairwise (must be already stored with indy_store_their_did)
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error code
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_update_pairwise(command_handle: CommandHandle,
+                                   wallet_handle: WalletHandle,
+                                   their_did: *const c_char,
+                                   new_their_did: *const c_char,
+                                   cb: Option<extern fn(command_handle_: CommandHandle,
+                                                        err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_update_pairwise: >>> wallet_handle: {:?}, their_did: {:?}, new_their_did: {:?}", wallet_handle, their_did, new_their_did);
+
+    command_params!(wallet_handle, their_did, new_their_did);
+
+    check_useful_validatable_string!(their_did, ErrorCode::CommonInvalidParam3, DidValue);
+    check_useful_validatable_string!(new_their_did, ErrorCode::CommonInvalidParam4, DidValue);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_update_pairwise: entities >>> wallet_handle: {:?}, their_did: {:?}, new_their_did: {:?}", wallet_handle, their_did, new_their_did);
+
+    let result = CommandExecutor::submit(Command::Pairwise(PairwiseCommand::UpdatePairwise(
+            wallet_handle,
+            their_did,
+            new_their_did,
+            with_timeout(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_update_pairwise:");
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_update_pairwise: <<< res: {:?}", res);
+
+    res
+}
+
+/// Deletes pairwise. Their DID and my DID of the pairwise are kept in the wallet.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// their_did: their DID of the pairwise
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error code
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_delete_pairwise(command_handle: CommandHandle,
+                                   wallet_handle: WalletHandle,
+                                   their_did: *const c_char,
+                                   cb: Option<extern fn(command_handle_: CommandHandle,
+                                                        err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_delete_pairwise: >>> wallet_handle: {:?}, their_did: {:?}", wallet_handle, their_did);
+
+    command_params!(wallet_handle, their_did);
+
+    check_useful_validatable_string!(their_did, ErrorCode::CommonInvalidParam3, DidValue);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_delete_pairwise: entities >>> wallet_handle: {:?}, their_did: {:?}", wallet_handle, their_did);
+
+    let result = CommandExecutor::submit(Command::Pairwise(PairwiseCommand::DeletePairwise(
+            wallet_handle,
+            their_did,
+            with_timeout(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_delete_pairwise:");
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_delete_pairwise: <<< res: {:?}", res);
+
+    res
+}
+
+/// Links wallet record (credential, non-secret item, etc.) to pairwise.
+/// Linked record gets managed tag "pairwise:<their_did>" with value "1",
+/// so records linked to pairwise can be also found with the search of records of given type.
+/// Links are moved by indy_update_pairwise and removed by indy_delete_pairwise.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// their_did: their DID of the pairwise
+/// record_type: type of wallet record to link, for example "Indy::Credential" or type of non-secret record
+/// record_id: id of wallet record to link
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error code
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_link_pairwise_record(command_handle: CommandHandle,
+                                        wallet_handle: WalletHandle,
+                                        their_did: *const c_char,
+                                        record_type: *const c_char,
+                                        record_id: *const c_char,
+                                        cb: Option<extern fn(command_handle_: CommandHandle,
+                                                             err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_link_pairwise_record: >>> wallet_handle: {:?}, their_did: {:?}, record_type: {:?}, record_id: {:?}", wallet_handle, their_did, record_type, record_id);
+
+    command_params!(wallet_handle, their_did, record_type, record_id);
+
+    check_useful_validatable_string!(their_did, ErrorCode::CommonInvalidParam3, DidValue);
+    check_useful_c_str!(record_type, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(record_id, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_link_pairwise_record: entities >>> wallet_handle: {:?}, their_did: {:?}, record_type: {:?}, record_id: {:?}", wallet_handle, their_did, record_type, record_id);
+
+    let result = CommandExecutor::submit(Command::Pairwise(PairwiseCommand::LinkPairwiseRecord(
+            wallet_handle,
+            their_did,
+            PairwiseLink { type_: record_type, id: record_id },
+            with_timeout(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_link_pairwise_record:");
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_link_pairwise_record: <<< res: {:?}", res);
+
+    res
+}
+
+/// Unlinks wallet record from pairwise. Managed tag is removed from the record if it still exists.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// their_did: their DID of the pairwise
+/// record_type: type of linked wallet record
+/// record_id: id of linked wallet record
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error code
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_unlink_pairwise_record(command_handle: CommandHandle,
+                                          wallet_handle: WalletHandle,
+                                          their_did: *const c_char,
+                                          record_type: *const c_char,
+                                          record_id: *const c_char,
+                                          cb: Option<extern fn(command_handle_: CommandHandle,
+                                                               err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_unlink_pairwise_record: >>> wallet_handle: {:?}, their_did: {:?}, record_type: {:?}, record_id: {:?}", wallet_handle, their_did, record_type, record_id);
+
+    command_params!(wallet_handle, their_did, record_type, record_id);
+
+    check_useful_validatable_string!(their_did, ErrorCode::CommonInvalidParam3, DidValue);
+    check_useful_c_str!(record_type, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(record_id, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_unlink_pairwise_record: entities >>> wallet_handle: {:?}, their_did: {:?}, record_type: {:?}, record_id: {:?}", wallet_handle, their_did, record_type, record_id);
+
+    let result = CommandExecutor::submit(Command::Pairwise(PairwiseCommand::UnlinkPairwiseRecord(
+            wallet_handle,
+            their_did,
+            PairwiseLink { type_: record_type, id: record_id },
+            with_timeout(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_unlink_pairwise_record:");
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_unlink_pairwise_record: <<< res: {:?}", res);
+
+    res
+}
+
+/// Get wallet records linked to pairwise with indy_link_pairwise_record.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// their_did: their DID of the pairwise
+/// record_type: (optional) return only linked records of this type
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// links_json: list of linked records
+///   [{
+///     "type": string - type of linked wallet record,
+///     "id": string - id of linked wallet record
+///   }]
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_get_pairwise_links(command_handle: CommandHandle,
+                                      wallet_handle: WalletHandle,
+                                      their_did: *const c_char,
+                                      record_type: *const c_char,
+                                      cb: Option<extern fn(command_handle_: CommandHandle,
+                                                           err: ErrorCode,
+                                                           links_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_get_pairwise_links: >>> wallet_handle: {:?}, their_did: {:?}, record_type: {:?}", wallet_handle, their_did, record_type);
+
+    command_params!(wallet_handle, their_did, record_type);
+
+    check_useful_validatable_string!(their_did, ErrorCode::CommonInvalidParam3, DidValue);
+    check_useful_opt_c_str!(record_type, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_get_pairwise_links: entities >>> wallet_handle: {:?}, their_did: {:?}, record_type: {:?}", wallet_handle, their_did, record_type);
+
+    let result = CommandExecutor::submit(Command::Pairwise(PairwiseCommand::GetPairwiseLinks(
+            wallet_handle,
+            their_did,
+            record_type,
+            with_timeout(boxed_callback_string!("indy_get_pairwise_links", cb, command_handle))
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_get_pairwise_links: <<< res: {:?}", res);
+
+    res
+}
+
+/// Sets structured meta of pairwise: label and state.
+/// Unlike the metadata, structured meta is stored as tags of pairwise record,
+/// so pairwise can be filtered by it with "indy_open_pairwise_search".
+/// Once structured meta is set, "label" and "state" tags no longer come from the metadata fields with these names.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// their_did: encoded Did
+/// meta_json: structured meta to set:
+///   {
+///     "label": (optional) string - human readable label of the pairwise,
+///     "state": (optional) string - state of the connection, for example "invited" or "active"
+///   }
+///   Omitted fields are left unchanged.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error code
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_set_pairwise_meta(command_handle: CommandHandle,
+                                     wallet_handle: WalletHandle,
+                                     their_did: *const c_char,
+                                     meta_json: *const c_char,
+                                     cb: Option<extern fn(command_handle_: CommandHandle,
+                                                          err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_set_pairwise_meta: >>> wallet_handle: {:?}, their_did: {:?}, meta_json: {:?}", wallet_handle, their_did, meta_json);
+
+    command_params!(wallet_handle, their_did, meta_json);
+
+    check_useful_validatable_string!(their_did, ErrorCode::CommonInvalidParam3, DidValue);
+    check_useful_json!(meta_json, ErrorCode::CommonInvalidParam4, PairwiseMetaUpdate);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_set_pairwise_meta: entities >>> wallet_handle: {:?}, their_did: {:?}, meta_json: {:?}", wallet_handle, their_did, meta_json);
+
+    let result = CommandExecutor::submit(Command::Pairwise(PairwiseCommand::SetPairwiseMeta(
+            wallet_handle,
+            their_did,
+            meta_json,
+            with_timeout(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_set_pairwise_meta:");
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_set_pairwise_meta: <<< res: {:?}", res);
+
+    res
+}
+
+/// Migrates pairwise stored in the wallet by older versions of libindy to structured meta.
+///     - missed tags are added to pairwise records.
+///     - "label" and "state" fields of JSON metadata are copied to structured meta if it isn't set yet.
+/// Metadata itself is left unchanged. Creation time of migrated pairwise is unknown.
+///
+/// The call is idempotent and should be done once after the upgrade, before pairwise are searched.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error code
+/// cb:
+/// - report_json: migration report json:
+/// {
+///     total: int, - number of pairwise in the wallet
+///     migrated: int, - number of updated pairwise
+/// }
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_migrate_pairwise_meta(command_handle: CommandHandle,
+                                         wallet_handle: WalletHandle,
+                                         cb: Option<extern fn(command_handle_: CommandHandle,
+                                                              err: ErrorCode,
+                                                              report_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_migrate_pairwise_meta: >>> wallet_handle: {:?}", wallet_handle);
+
+    command_params!(wallet_handle);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_migrate_pairwise_meta: entities >>> wallet_handle: {:?}", wallet_handle);
+
+    let result = CommandExecutor::submit(Command::Pairwise(PairwiseCommand::MigratePairwiseMeta(
+            wallet_handle,
+            with_timeout(boxed_callback_string!("indy_migrate_pairwise_meta", cb, command_handle))
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_migrate_pairwise_meta: <<< res: {:?}", res);
+
+    res
+}
+
+/// Search for pairwise stored in the wallet.
+///
+/// Pairwise are tagged with:
+///     - "my_did"
+///     - "label", "state" and "~created_at" of structured meta (see "indy_set_pairwise_meta")
+///     - all other top level string fields of the metadata if it is a JSON object
+///       (all of them, including "label" and "state", until structured meta is set)
+///       (fields with "~" prefix are stored un-encrypted).
+/// Tags are updated on each "indy_set_pairwise_metadata" and "indy_set_pairwise_meta" call.
+/// Note that "~created_at" is un-encrypted tag, so pairwise can be filtered by creation time
+/// with "$gt", "$gte", "$lt" and "$lte" operators.
+///
+/// Instead of immediately returning of all pairwise (as "indy_list_pairwise" does)
+/// this call returns search_handle that can be used later
+/// to fetch pairwise by small batches (with indy_fetch_pairwise).
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: Wallet handle (created by open_wallet).
+/// query_json: (Optional) Wql query filter for pairwise searching based on tags.
+///     where query: indy-sdk/docs/design/011-wallet-query-language/README.md
+/// options_json: (Optional) Search options:
+///   {
+///     "retrieveVerkeys": (optional, false by default) Retrieve verkeys of my and their DIDs
+///   }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+/// - search_handle: Search handle that can be used later to fetch pairwise by small batches (with indy_fetch_pairwise)
+/// - total_count: Total count of pairwise matching the query
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_open_pairwise_search(command_handle: CommandHandle,
+                                        wallet_handle: WalletHandle,
+                                        query_json: *const c_char,
+                                        options_json: *const c_char,
+                                        cb: Option<extern fn(command_handle_: CommandHandle,
+                                                             err: ErrorCode,
+                                                             search_handle: SearchHandle,
+                                                             total_count: usize)>) -> ErrorCode {
+    trace!("indy_open_pairwise_search: >>> wallet_handle: {:?}, query_json: {:?}, options_json: {:?}", wallet_handle, query_json, options_json);
+
+    command_params!(wallet_handle, query_json, options_json);
+
+    check_useful_opt_c_str!(query_json, ErrorCode::CommonInvalidParam3);
+    check_useful_opt_json!(options_json, ErrorCode::CommonInvalidParam4, PairwiseSearchOptions);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_open_pairwise_search: entities >>> wallet_handle: {:?}, query_json: {:?}, options_json: {:?}", wallet_handle, query_json, options_json);
+
+    let result = CommandExecutor::submit(Command::Pairwise(PairwiseCommand::OpenPairwiseSearch(
+            wallet_handle,
+            query_json,
+            options_json,
+            with_timeout(move |result| {
+                let (err, handle, total_count) = prepare_result_2!(result, INVALID_SEARCH_HANDLE, 0);
+                cb(command_handle, err, handle, total_count)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_open_pairwise_search: <<< res: {:?}", res);
+
+    res
+}
+
+/// Fetch next pairwise for search.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// search_handle: Search handle (created by indy_open_pairwise_search)
+/// count: Count of pairwise to fetch
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+///   list_pairwise:  [{
+///     "my_did": string - my DID of the pairwise,
+///     "their_did": string - their DID of the pairwise,
+///     "metadata": (optional) string - the meta information stored with the pairwise,
+///     "my_verkey": (optional) string - verkey of my DID (only if retrieveVerkeys option is true),
+///     "their_verkey": (optional) string - verkey of their DID (only if retrieveVerkeys option is true),
+///     "label": (optional) string - label of the pairwise (only if structured meta is set),
+///     "state": (optional) string - state of the pairwise (only if structured meta is set),
+///     "created_at": (optional) int - creation time of the pairwise (only if structured meta is set)
+///   }]
+/// NOTE: The list of length less than the requested count means pairwise search iterator is completed.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_fetch_pairwise(command_handle: CommandHandle,
+                                  search_handle: SearchHandle,
+                                  count: usize,
+                                  cb: Option<extern fn(command_handle_: CommandHandle,
+                                                       err: ErrorCode,
+                                                       list_pairwise: *const c_char)>) -> ErrorCode {
+    trace!("indy_fetch_pairwise: >>> search_handle: {:?}, count: {:?}", search_handle, count);
+
+    command_params!(search_handle, count);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_fetch_pairwise: entities >>> search_handle: {:?}, count: {:?}", search_handle, count);
+
+    let result = CommandExecutor::submit(Command::Pairwise(PairwiseCommand::FetchPairwise(
+            search_handle,
+            count,
+            with_timeout(boxed_callback_string!("indy_fetch_pairwise", cb, command_handle))
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_fetch_pairwise: <<< res: {:?}", res);
+
+    res
+}
+
+/// Close pairwise search (make search handle invalid)
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// search_handle: Search handle (created by indy_open_pairwise_search)
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - command_handle_: Command handle to map callback to caller context.
+/// - err: Error code.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_close_pairwise_search(command_handle: CommandHandle,
+                                         search_handle: SearchHandle,
+                                         cb: Option<extern fn(command_handle_: CommandHandle,
+                                                              err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_close_pairwise_search: >>> search_handle: {:?}", search_handle);
+
+    command_params!(search_handle);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_close_pairwise_search: entities >>> search_handle: {:?}", search_handle);
+
+    let result = CommandExecutor::submit(Command::Pairwise(PairwiseCommand::ClosePairwiseSearch(
+            search_handle,
+            with_timeout(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_close_pairwise_search:");
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_close_pairwise_search: <<< res: {:?}", res);
+
+    res
+}
+
+context_api! {
+    indy_is_pairwise_exists_ctx => indy_is_pairwise_exists(wallet_handle: WalletHandle, their_did: *const c_char)
+        -> cb(exists: bool), CommonInvalidParam4;
+    indy_create_pairwise_ctx => indy_create_pairwise(wallet_handle: WalletHandle, their_did: *const c_char, my_did: *const c_char, metadata: *const c_char)
+        -> cb(), CommonInvalidParam6;
+    indy_list_pairwise_ctx => indy_list_pairwise(wallet_handle: WalletHandle)
+        -> cb(list_pairwise: *const c_char), CommonInvalidParam3;
+    indy_get_pairwise_ctx => indy_get_pairwise(wallet_handle: WalletHandle, their_did: *const c_char)
+        -> cb(pairwise_info_json: *const c_char), CommonInvalidParam4;
+    indy_set_pairwise_metadata_ctx => indy_set_pairwise_metadata(wallet_handle: WalletHandle, their_did: *const c_char, metadata: *const c_char)
+        -> cb(), CommonInvalidParam5;
+    indy_update_pairwise_ctx => indy_update_pairwise(wallet_handle: WalletHandle, their_did: *const c_char, new_their_did: *const c_char)
+        -> cb(), CommonInvalidParam5;
+    indy_delete_pairwise_ctx => indy_delete_pairwise(wallet_handle: WalletHandle, their_did: *const c_char)
+        -> cb(), CommonInvalidParam4;
+    indy_link_pairwise_record_ctx => indy_link_pairwise_record(wallet_handle: WalletHandle, their_did: *const c_char, record_type: *const c_char, record_id: *const c_char)
+        -> cb(), CommonInvalidParam6;
+    indy_unlink_pairwise_record_ctx => indy_unlink_pairwise_record(wallet_handle: WalletHandle, their_did: *const c_char, record_type: *const c_char, record_id: *const c_char)
+        -> cb(), CommonInvalidParam6;
+    indy_get_pairwise_links_ctx => indy_get_pairwise_links(wallet_handle: WalletHandle, their_did: *const c_char, record_type: *const c_char)
+        -> cb(links_json: *const c_char), CommonInvalidParam5;
+    indy_set_pairwise_meta_ctx => indy_set_pairwise_meta(wallet_handle: WalletHandle, their_did: *const c_char, meta_json: *const c_char)
+        -> cb(), CommonInvalidParam5;
+    indy_migrate_pairwise_meta_ctx => indy_migrate_pairwise_meta(wallet_handle: WalletHandle)
+        -> cb(report_json: *const c_char), CommonInvalidParam3;
+    indy_open_pairwise_search_ctx => indy_open_pairwise_search(wallet_handle: WalletHandle, query_json: *const c_char, options_json: *const c_char)
+        -> cb(search_handle: SearchHandle, total_count: usize), CommonInvalidParam5;
+    indy_fetch_pairwise_ctx => indy_fetch_pairwise(search_handle: SearchHandle, count: usize)
+        -> cb(list_pairwise: *const c_char), CommonInvalidParam4;
+    indy_close_pairwise_search_ctx => indy_close_pairwise_search(search_handle: SearchHandle)
+        -> cb(), CommonInvalidParam3;
+}
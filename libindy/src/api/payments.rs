@@ -1,8 +1,13 @@
+use std::collections::HashMap;
+
 use libc::c_char;
-use indy_api_types::{ErrorCode, CommandHandle, WalletHandle};
+use indy_api_types::{ErrorCode, CommandHandle, WalletHandle, PoolHandle};
 use crate::commands::{Command, CommandExecutor};
-use crate::commands::payments::PaymentsCommand;
+use crate::commands::timeout::with_timeout;
+use crate::commands::payments::{PaymentsCommand, TaaAcceptance};
 use crate::services::payments::PaymentsMethodCBs;
+#[cfg(feature = "null_payment_method")]
+use crate::services::null_payment_method;
 use indy_api_types::errors::prelude::*;
 use indy_utils::ctypes;
 use crate::services::payments::{RequesterInfo, Fees};
@@ -349,6 +354,97 @@ pub type VerifyWithAddressCB = extern fn (command_handle: CommandHandle, address
                                           signature_raw: *const u8, signature_len: u32,
                                           cb: Option<extern fn(command_handle: CommandHandle, err: ErrorCode, result: u8) -> ErrorCode>) -> ErrorCode;
 
+/// Completion callback of handlers registered with `indy_register_payment_method_v2`.
+///
+/// # Params
+/// command_handle: command handle passed to the handler
+/// err: handler execution result
+/// error_json: (Optional) error details, considered only if err is not Success:
+///   {
+///     message: <str>, // human readable description of the error
+///     <str>: <any>, // any additional payment method specific details
+///   }
+/// result_json: handler result, ignored if err is not Success
+pub type PaymentMethodV2CB = extern fn(command_handle_: CommandHandle,
+                                       err: ErrorCode,
+                                       error_json: *const c_char,
+                                       result_json: *const c_char) -> ErrorCode;
+
+/// Estimates fees that will be charged for the request without adding them to the request.
+///
+/// # Params
+/// command_handle: command handle to map callback to context
+/// wallet_handle: wallet handle
+/// submitter_did: (Optional) DID of request sender
+/// req_json: initial transaction request as json
+/// inputs_json: The list of payment sources as json array:
+///   ["source1", ...]
+///   Note that each source should reference payment address
+/// cb: completion callback
+///
+/// # Return
+/// fees_estimate_json: {
+///     fee: <int>, // amount that will be charged for the request
+///     <str>: <any>, // any additional payment method specific details
+/// }
+pub type EstimateRequestFeesCB = extern fn(command_handle: CommandHandle,
+                                           wallet_handle: WalletHandle,
+                                           submitter_did: *const c_char,
+                                           req_json: *const c_char,
+                                           inputs_json: *const c_char,
+                                           cb: Option<PaymentMethodV2CB>) -> ErrorCode;
+
+/// Verifies a batch of payment receipts.
+///
+/// # Params
+/// command_handle: command handle to map callback to context
+/// wallet_handle: wallet handle
+/// submitter_did: (Optional) DID of request sender
+/// receipts_json: The list of payment receipts as json array:
+///   ["receipt1", ...]
+/// cb: completion callback
+///
+/// # Return
+/// receipts_info_json: [{
+///     receipt: <str>, // verified receipt
+///     verified: <bool>, // whether receipt is confirmed by the ledger
+///     txn: { // (Optional) receipt transaction info in the same format as for parse_verify_payment_response
+///         sources: [<str>, ]
+///         receipts: [ {recipient: <str>, receipt: <str>, amount: <int>}, ]
+///         extra: <str>,
+///     },
+///     error: <str>, // (Optional) reason why receipt isn't verified
+/// }]
+pub type VerifyPaymentReceiptsCB = extern fn(command_handle: CommandHandle,
+                                             wallet_handle: WalletHandle,
+                                             submitter_did: *const c_char,
+                                             receipts_json: *const c_char,
+                                             cb: Option<PaymentMethodV2CB>) -> ErrorCode;
+
+/// Parses reply on verify payment request together with state proofs that confirm the receipt.
+/// Libindy checks returned state proofs and their multi signatures against BLS keys of the pool nodes,
+/// so handler should return state proofs only for the data of the requested receipt.
+///
+/// # Params
+/// command_handle: command handle to map callback to context
+/// receipt: payment receipt to verify
+/// resp_json: response for verify payment request
+/// cb: completion callback
+///
+/// # Return
+/// parsed_json: {
+///     txn: { // receipt transaction info in the same format as for parse_verify_payment_response
+///         sources: [<str>, ]
+///         receipts: [ {recipient: <str>, receipt: <str>, amount: <int>}, ]
+///         extra: <str>,
+///     },
+///     state_proofs: [<object>, ] // state proofs in the same format as returned by `CustomTransactionParser`
+/// }
+pub type ParseVerifyPaymentResponseWithProofCB = extern fn(command_handle: CommandHandle,
+                                                           receipt: *const c_char,
+                                                           resp_json: *const c_char,
+                                                           cb: Option<PaymentMethodV2CB>) -> ErrorCode;
+
 /// Register custom payment implementation.
 ///
 /// It allows library user to provide custom payment method implementation as set of handlers.
@@ -394,6 +490,8 @@ pub extern fn indy_register_payment_method(command_handle: CommandHandle,
                                                                 err: ErrorCode)>) -> ErrorCode {
     trace!("indy_register_payment_method: >>> payment_method: {:?}", payment_method);
 
+    command_params!(payment_method);
+
     check_useful_c_str!(payment_method, ErrorCode::CommonInvalidParam2);
     check_useful_c_callback!(create_payment_address, ErrorCode::CommonInvalidParam3);
     check_useful_c_callback!(add_request_fees, ErrorCode::CommonInvalidParam4);
@@ -432,12 +530,12 @@ pub extern fn indy_register_payment_method(command_handle: CommandHandle,
         verify_with_address
     );
     let result =
-        CommandExecutor::instance().send(
+        CommandExecutor::submit(
             Command::Payments(
                 PaymentsCommand::RegisterMethod(
                     payment_method,
                     cbs,
-                    Box::new(move |result| {
+                    with_timeout(move |result| {
                         cb(command_handle, result.into());
                     }))
             ));
@@ -449,6 +547,353 @@ pub extern fn indy_register_payment_method(command_handle: CommandHandle,
     res
 }
 
+/// Register custom payment implementation with v2 handlers.
+///
+/// In addition to the handlers accepted by `indy_register_payment_method` it allows payment method
+/// to provide fees estimation and batch receipts verification handlers.
+/// v2 handlers complete with `PaymentMethodV2CB` callback that can return structured error details,
+/// these details are available to library user through `indy_get_current_error`.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// payment_method: The type of payment method also used as sub-prefix for fully resolvable payment address format ("sov" - for example)
+/// create_payment_address: "create_payment_address" operation handler
+/// add_request_fees: "add_request_fees" operation handler
+/// parse_response_with_fees: "parse_response_with_fees" operation handler
+/// build_get_payment_sources_request: "build_get_payment_sources_request" operation handler
+/// parse_get_payment_sources_response: "parse_get_payment_sources_response" operation handler
+/// build_payment_req: "build_payment_req" operation handler
+/// parse_payment_response: "parse_payment_response" operation handler
+/// build_mint_req: "build_mint_req" operation handler
+/// build_set_txn_fees_req: "build_set_txn_fees_req" operation handler
+/// build_get_txn_fees_req: "build_get_txn_fees_req" operation handler
+/// parse_get_txn_fees_response: "parse_get_txn_fees_response" operation handler
+/// build_verify_payment_req: "build_verify_payment_req" operation handler
+/// parse_verify_payment_response: "parse_verify_payment_response" operation handler
+/// sign_with_address: "sign_with_address" operation handler
+/// verify_with_address: "verify_with_address" operation handler
+/// estimate_request_fees: "estimate_request_fees" operation handler
+/// verify_payment_receipts: "verify_payment_receipts" operation handler
+///
+/// #Returns
+/// Error code
+#[no_mangle]
+pub extern fn indy_register_payment_method_v2(command_handle: CommandHandle,
+                                              payment_method: *const c_char,
+                                              create_payment_address: Option<CreatePaymentAddressCB>,
+                                              add_request_fees: Option<AddRequestFeesCB>,
+                                              parse_response_with_fees: Option<ParseResponseWithFeesCB>,
+                                              build_get_payment_sources_request: Option<BuildGetPaymentSourcesRequestCB>,
+                                              parse_get_payment_sources_response: Option<ParseGetPaymentSourcesResponseCB>,
+                                              build_payment_req: Option<BuildPaymentReqCB>,
+                                              parse_payment_response: Option<ParsePaymentResponseCB>,
+                                              build_mint_req: Option<BuildMintReqCB>,
+                                              build_set_txn_fees_req: Option<BuildSetTxnFeesReqCB>,
+                                              build_get_txn_fees_req: Option<BuildGetTxnFeesReqCB>,
+                                              parse_get_txn_fees_response: Option<ParseGetTxnFeesResponseCB>,
+                                              build_verify_payment_req: Option<BuildVerifyPaymentReqCB>,
+                                              parse_verify_payment_response: Option<ParseVerifyPaymentResponseCB>,
+                                              sign_with_address: Option<SignWithAddressCB>,
+                                              verify_with_address: Option<VerifyWithAddressCB>,
+                                              estimate_request_fees: Option<EstimateRequestFeesCB>,
+                                              verify_payment_receipts: Option<VerifyPaymentReceiptsCB>,
+                                              cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                   err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_register_payment_method_v2: >>> payment_method: {:?}", payment_method);
+
+    command_params!(payment_method);
+
+    check_useful_c_str!(payment_method, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(create_payment_address, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(add_request_fees, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(parse_response_with_fees, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(build_get_payment_sources_request, ErrorCode::CommonInvalidParam6);
+    check_useful_c_callback!(parse_get_payment_sources_response, ErrorCode::CommonInvalidParam7);
+    check_useful_c_callback!(build_payment_req, ErrorCode::CommonInvalidParam8);
+    check_useful_c_callback!(parse_payment_response, ErrorCode::CommonInvalidParam9);
+    check_useful_c_callback!(build_mint_req, ErrorCode::CommonInvalidParam10);
+    check_useful_c_callback!(build_set_txn_fees_req, ErrorCode::CommonInvalidParam11);
+    check_useful_c_callback!(build_get_txn_fees_req, ErrorCode::CommonInvalidParam12);
+    check_useful_c_callback!(parse_get_txn_fees_response, ErrorCode::CommonInvalidParam13);
+    check_useful_c_callback!(build_verify_payment_req, ErrorCode::CommonInvalidParam14);
+    check_useful_c_callback!(parse_verify_payment_response, ErrorCode::CommonInvalidParam15);
+    check_useful_c_callback!(sign_with_address, ErrorCode::CommonInvalidParam16);
+    check_useful_c_callback!(verify_with_address, ErrorCode::CommonInvalidParam17);
+    check_useful_c_callback!(estimate_request_fees, ErrorCode::CommonInvalidParam18);
+    check_useful_c_callback!(verify_payment_receipts, ErrorCode::CommonInvalidParam19);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam20);
+
+    trace!("indy_register_payment_method_v2: entities >>> payment_method: {:?}", payment_method);
+
+    let cbs = PaymentsMethodCBs::new(
+        create_payment_address,
+        add_request_fees,
+        parse_response_with_fees,
+        build_get_payment_sources_request,
+        parse_get_payment_sources_response,
+        build_payment_req,
+        parse_payment_response,
+        build_mint_req,
+        build_set_txn_fees_req,
+        build_get_txn_fees_req,
+        parse_get_txn_fees_response,
+        build_verify_payment_req,
+        parse_verify_payment_response,
+        sign_with_address,
+        verify_with_address
+    ).with_v2_handlers(
+        estimate_request_fees,
+        verify_payment_receipts
+    );
+    let result =
+        CommandExecutor::submit(
+            Command::Payments(
+                PaymentsCommand::RegisterMethod(
+                    payment_method,
+                    cbs,
+                    with_timeout(move |result| {
+                        cb(command_handle, result.into());
+                    }))
+            ));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_register_payment_method_v2: <<< res: {:?}", res);
+
+    res
+}
+
+/// Builds Indy request for getting transaction history of payment address
+/// according to this payment method.
+///
+/// # Params
+/// command_handle: command handle to map callback to context
+/// wallet_handle: wallet handle
+/// submitter_did: (Optional) DID of request sender
+/// payment_address: target payment address
+/// from: shift to the next slice of payment history or -1 to start from the beginning
+/// cb: completion callback
+///
+/// # Return
+/// get_history_txn_json - Indy request for getting transaction history of payment address
+pub type BuildGetPaymentHistoryRequestCB = extern fn(command_handle: CommandHandle,
+                                                     wallet_handle: WalletHandle,
+                                                     submitter_did: *const c_char,
+                                                     payment_address: *const c_char,
+                                                     from: i64,
+                                                     cb: Option<PaymentMethodV2CB>) -> ErrorCode;
+
+/// Parses response for Indy request for getting transaction history of payment address.
+///
+/// # Params
+/// command_handle: command handle to map callback to context
+/// resp_json: response for Indy request for getting transaction history
+/// cb: completion callback
+///
+/// # Return
+/// history_json: {
+///     txns: [{
+///         receipt: <str>, // receipt of the transaction output related to the address
+///         txnTime: <int>, // (Optional) time when transaction was written to the ledger
+///         sources: [<str>, ] // transaction inputs
+///         receipts: [ {recipient: <str>, receipt: <str>, amount: <int>}, ] // transaction outputs
+///         extra: <str>, // (Optional) extra data of the transaction
+///     }],
+///     next: <int>, // (Optional) shift to the next slice of payment history, not set for the last slice
+/// }
+pub type ParseGetPaymentHistoryResponseCB = extern fn(command_handle: CommandHandle,
+                                                      resp_json: *const c_char,
+                                                      cb: Option<PaymentMethodV2CB>) -> ErrorCode;
+
+/// Register payment address history handlers for already registered payment method.
+///
+/// It allows library user to fetch transaction history of the payment address with
+/// `indy_build_get_payment_history_request` and `indy_parse_get_payment_history_response`.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// payment_method: The type of registered payment method
+/// build_get_payment_history_request: "build_get_payment_history_request" operation handler
+/// parse_get_payment_history_response: "parse_get_payment_history_response" operation handler
+///
+/// #Returns
+/// Error code
+///
+/// #Errors
+/// Common*
+/// Payment*
+#[no_mangle]
+pub extern fn indy_register_payment_history_handlers(command_handle: CommandHandle,
+                                                     payment_method: *const c_char,
+                                                     build_get_payment_history_request: Option<BuildGetPaymentHistoryRequestCB>,
+                                                     parse_get_payment_history_response: Option<ParseGetPaymentHistoryResponseCB>,
+                                                     cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                          err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_register_payment_history_handlers: >>> payment_method: {:?}", payment_method);
+
+    command_params!(payment_method);
+
+    check_useful_c_str!(payment_method, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(build_get_payment_history_request, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(parse_get_payment_history_response, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_register_payment_history_handlers: entities >>> payment_method: {:?}", payment_method);
+
+    let result =
+        CommandExecutor::submit(
+            Command::Payments(
+                PaymentsCommand::RegisterHistoryHandlers(
+                    payment_method,
+                    build_get_payment_history_request,
+                    parse_get_payment_history_response,
+                    with_timeout(move |result| {
+                        cb(command_handle, result.into());
+                    }))
+            ));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_register_payment_history_handlers: <<< res: {:?}", res);
+
+    res
+}
+
+/// Unregister payment method, so its library can be unloaded or registered again.
+/// Payment method can't be unregistered while it has calls in progress.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// payment_method: The type of registered payment method
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error code
+///
+/// #Errors
+/// Common*
+/// Payment*
+#[no_mangle]
+pub extern fn indy_unregister_payment_method(command_handle: CommandHandle,
+                                             payment_method: *const c_char,
+                                             cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                  err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_unregister_payment_method: >>> payment_method: {:?}", payment_method);
+
+    command_params!(payment_method);
+
+    check_useful_c_str!(payment_method, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_unregister_payment_method: entities >>> payment_method: {:?}", payment_method);
+
+    let result =
+        CommandExecutor::submit(
+            Command::Payments(
+                PaymentsCommand::UnregisterMethod(
+                    payment_method,
+                    with_timeout(move |result| {
+                        cb(command_handle, result.into());
+                    }))
+            ));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_unregister_payment_method: <<< res: {:?}", res);
+
+    res
+}
+
+/// Register state proof aware parser of verify payment responses for already registered payment method.
+///
+/// It allows library user to verify payment receipts with `indy_verify_payment_receipt_with_proof`.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// payment_method: The type of registered payment method
+/// parse_verify_payment_response_with_proof: "parse_verify_payment_response_with_proof" operation handler
+///
+/// #Returns
+/// Error code
+///
+/// #Errors
+/// Common*
+/// Payment*
+#[no_mangle]
+pub extern fn indy_register_payment_receipt_proof_parser(command_handle: CommandHandle,
+                                                         payment_method: *const c_char,
+                                                         parse_verify_payment_response_with_proof: Option<ParseVerifyPaymentResponseWithProofCB>,
+                                                         cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                              err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_register_payment_receipt_proof_parser: >>> payment_method: {:?}", payment_method);
+
+    command_params!(payment_method);
+
+    check_useful_c_str!(payment_method, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(parse_verify_payment_response_with_proof, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_register_payment_receipt_proof_parser: entities >>> payment_method: {:?}", payment_method);
+
+    let result =
+        CommandExecutor::submit(
+            Command::Payments(
+                PaymentsCommand::RegisterReceiptProofParser(
+                    payment_method,
+                    parse_verify_payment_response_with_proof,
+                    with_timeout(move |result| {
+                        cb(command_handle, result.into());
+                    }))
+            ));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_register_payment_receipt_proof_parser: <<< res: {:?}", res);
+
+    res
+}
+
+/// Register built-in "null" payment method.
+///
+/// The method works against in-memory ledger and is intended for testing only.
+/// Requests built by the method are applied to the in-memory ledger immediately and
+/// can be passed to the corresponding parse functions instead of ledger replies.
+/// Available only if libindy is built with "null_payment_method" feature.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+///
+/// #Returns
+/// Error code
+#[cfg(feature = "null_payment_method")]
+#[no_mangle]
+pub extern fn indy_register_null_payment_method(command_handle: CommandHandle,
+                                                cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                     err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_register_null_payment_method: >>>");
+
+    command_params!();
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam2);
+
+    let result =
+        CommandExecutor::submit(
+            Command::Payments(
+                PaymentsCommand::RegisterMethod(
+                    null_payment_method::NULL_PAYMENT_METHOD.to_string(),
+                    null_payment_method::method_cbs(),
+                    with_timeout(move |result| {
+                        cb(command_handle, result.into());
+                    }))
+            ));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_register_null_payment_method: <<< res: {:?}", res);
+
+    res
+}
+
 /// Create the payment address for specified payment method
 ///
 ///
@@ -480,6 +925,8 @@ pub extern fn indy_create_payment_address(command_handle: CommandHandle,
                                                                payment_address: *const c_char)>) -> ErrorCode {
     trace!("indy_create_payment_address: >>> wallet_handle: {:?}, payment_method: {:?}, config: {:?}", wallet_handle, payment_method, config);
 
+    command_params!(wallet_handle, payment_method, config);
+
     check_useful_c_str!(payment_method, ErrorCode::CommonInvalidParam3);
     check_useful_c_str!(config, ErrorCode::CommonInvalidParam4);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
@@ -487,13 +934,13 @@ pub extern fn indy_create_payment_address(command_handle: CommandHandle,
     trace!("indy_create_payment_address: entities >>> wallet_handle: {:?}, payment_method: {:?}, config: {:?}", wallet_handle, payment_method, config);
 
     let result =
-        CommandExecutor::instance().send(
+        CommandExecutor::submit(
             Command::Payments(
                 PaymentsCommand::CreateAddress(
                     wallet_handle,
                     payment_method,
                     config,
-                    boxed_callback_string!("indy_create_payment_address", cb, command_handle)
+                    with_timeout(boxed_callback_string!("indy_create_payment_address", cb, command_handle))
                 )
             ));
 
@@ -520,16 +967,18 @@ pub extern fn indy_list_payment_addresses(command_handle: CommandHandle,
                                                                payment_addresses_json: *const c_char)>) -> ErrorCode {
     trace!("indy_list_payment_address: >>> wallet_handle: {:?}", wallet_handle);
 
+    command_params!(wallet_handle);
+
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
 
     trace!("indy_list_payment_address: entities >>> wallet_handle: {:?}", wallet_handle);
 
     let result =
-        CommandExecutor::instance().send(
+        CommandExecutor::submit(
             Command::Payments(
                 PaymentsCommand::ListAddresses(
                     wallet_handle,
-                    boxed_callback_string!("indy_list_payment_address", cb, command_handle)
+                    with_timeout(boxed_callback_string!("indy_list_payment_address", cb, command_handle))
                 )
             )
         );
@@ -541,6 +990,64 @@ pub extern fn indy_list_payment_addresses(command_handle: CommandHandle,
     res
 }
 
+/// Fetches sources of all payment addresses that are stored in the wallet and
+/// returns consolidated balances summary.
+///
+/// Sources of the addresses are requested from the ledger concurrently (all pages of sources are fetched).
+/// A failure for some address doesn't fail whole operation but is reported in the address entry.
+///
+/// #Params
+/// command_handle: command handle to map callback to context
+/// wallet_handle: wallet to search for payment_addresses in
+/// pool_handle: pool handle (created by open_pool_ledger).
+///
+/// #Returns
+/// balances_json - consolidated balances as json:
+///   {
+///     balances: { // total balance of successfully fetched addresses per payment method
+///         <str>: <int>,
+///     },
+///     addresses: [{
+///         address: <str>, // payment address
+///         paymentMethod: <str>, // (Optional) payment method of the address
+///         balance: <int>, // sum of the address sources amounts
+///         sources: [{source: <str>, paymentAddress: <str>, amount: <int>, extra: <str>}],
+///         error: <str>, // (Optional) reason why sources of the address can't be fetched
+///     }]
+///   }
+#[no_mangle]
+pub extern fn indy_get_payment_balances(command_handle: CommandHandle,
+                                        wallet_handle: WalletHandle,
+                                        pool_handle: PoolHandle,
+                                        cb: Option<extern fn(command_handle_: CommandHandle,
+                                                             err: ErrorCode,
+                                                             balances_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_get_payment_balances: >>> wallet_handle: {:?}, pool_handle: {:?}", wallet_handle, pool_handle);
+
+    command_params!(wallet_handle, pool_handle);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_get_payment_balances: entities >>> wallet_handle: {:?}, pool_handle: {:?}", wallet_handle, pool_handle);
+
+    let result =
+        CommandExecutor::submit(
+            Command::Payments(
+                PaymentsCommand::GetPaymentBalances(
+                    wallet_handle,
+                    pool_handle,
+                    with_timeout(boxed_callback_string!("indy_get_payment_balances", cb, command_handle))
+                )
+            )
+        );
+
+    let res = prepare_result!(result);
+
+    trace!("indy_get_payment_balances: <<< res: {:?}", res);
+
+    res
+}
+
 /// Modifies Indy request by adding information how to pay fees for this transaction
 /// according to this payment method.
 ///
@@ -585,6 +1092,8 @@ pub extern fn indy_add_request_fees(command_handle: CommandHandle,
                                                          payment_method: *const c_char)>) -> ErrorCode {
     trace!("indy_add_request_fees: >>> wallet_handle: {:?}, submitter_did: {:?}, req_json: {:?}, inputs_json: {:?}, outputs_json: {:?}, extra: {:?}",
            wallet_handle, submitter_did, req_json, inputs_json, outputs_json, extra);
+
+    command_params!(wallet_handle, submitter_did, req_json, inputs_json, outputs_json, extra);
     check_useful_validatable_opt_string!(submitter_did, ErrorCode::CommonInvalidParam3, DidValue);
     check_useful_c_str!(req_json, ErrorCode::CommonInvalidParam4);
     check_useful_c_str!(inputs_json, ErrorCode::CommonInvalidParam5);
@@ -596,7 +1105,7 @@ pub extern fn indy_add_request_fees(command_handle: CommandHandle,
            wallet_handle, submitter_did, req_json, inputs_json, outputs_json, extra);
 
     let result =
-        CommandExecutor::instance().send(
+        CommandExecutor::submit(
             Command::Payments(
                 PaymentsCommand::AddRequestFees(
                     wallet_handle,
@@ -605,7 +1114,7 @@ pub extern fn indy_add_request_fees(command_handle: CommandHandle,
                     inputs_json,
                     outputs_json,
                     extra,
-                    Box::new(move |result| {
+                    with_timeout(move |result| {
                         let (err, req_with_fees_json, payment_method) = prepare_result_2!(result, String::new(), String::new());
                         trace!("indy_add_request_fees: req_with_fees_json: {:?}, payment_method: {:?}", req_with_fees_json, payment_method);
                         let req_with_fees_json = ctypes::string_to_cstring(req_with_fees_json);
@@ -621,6 +1130,124 @@ pub extern fn indy_add_request_fees(command_handle: CommandHandle,
     res
 }
 
+/// Prepares Indy write request for sending with fees in one call.
+///
+/// This method performs the following steps in the proper order:
+///  - appends TAA acceptance data to the request (if acceptance mechanism is passed)
+///  - fetches fees set on the ledger using the payment method determined from inputs
+///  - adds fees information to the request (see `indy_add_request_fees`)
+///  - multi signs the request by submitter DID
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: wallet handle
+/// pool_handle: pool handle (created by open_pool_ledger) used to fetch fees.
+/// submitter_did: DID of request sender
+/// req_json: initial transaction request as json
+/// inputs_json: The list of payment sources as json array:
+///   ["source1", ...]
+///     - each input should reference paymentAddress
+///     - this param will be used to determine payment_method
+/// outputs_json: The list of outputs as json array:
+///   [{
+///     recipient: <str>, // payment address of recipient
+///     amount: <int>, // amount
+///   }]
+/// extra: // optional information for payment operation
+/// text and version - (optional) raw data about TAA from ledger.
+///     These parameters should be passed together.
+///     These parameters are required if taa_digest parameter is omitted.
+/// taa_digest - (optional) digest on text and version.
+///     This parameter is required if text and version parameters are omitted.
+/// mechanism - (optional) mechanism how user has accepted the TAA. TAA acceptance isn't appended if omitted.
+/// time - UTC timestamp when user has accepted the TAA
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// req_with_fees_json - Indy request with TAA acceptance and fees signed by submitter DID
+/// receipt_json - expected payment of the request:
+///   {
+///     paymentMethod: <str>, // used payment method
+///     txnType: <str>, // type of the request transaction
+///     fee: <int>, // fee set on the ledger for the transaction type (0 if not set)
+///     inputs: [<str>, ...], // consumed payment sources
+///     outputs: [{recipient: <str>, amount: <int>}, ...] // outputs of the payment
+///   }
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Ledger*
+/// Payment*
+#[no_mangle]
+pub extern fn indy_prepare_request_with_fees(command_handle: CommandHandle,
+                                             wallet_handle: WalletHandle,
+                                             pool_handle: PoolHandle,
+                                             submitter_did: *const c_char,
+                                             req_json: *const c_char,
+                                             inputs_json: *const c_char,
+                                             outputs_json: *const c_char,
+                                             extra: *const c_char,
+                                             text: *const c_char,
+                                             version: *const c_char,
+                                             taa_digest: *const c_char,
+                                             mechanism: *const c_char,
+                                             time: u64,
+                                             cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                  err: ErrorCode,
+                                                                  req_with_fees_json: *const c_char,
+                                                                  receipt_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_prepare_request_with_fees: >>> wallet_handle: {:?}, pool_handle: {:?}, submitter_did: {:?}, req_json: {:?}, inputs_json: {:?}, outputs_json: {:?}, \
+        extra: {:?}, text: {:?}, version: {:?}, taa_digest: {:?}, mechanism: {:?}, time: {:?}",
+           wallet_handle, pool_handle, submitter_did, req_json, inputs_json, outputs_json, extra, text, version, taa_digest, mechanism, time);
+
+    command_params!(wallet_handle, pool_handle, submitter_did, req_json, inputs_json, outputs_json, extra, text, version, taa_digest, mechanism, time);
+
+    check_useful_validatable_string!(submitter_did, ErrorCode::CommonInvalidParam4, DidValue);
+    check_useful_c_str!(req_json, ErrorCode::CommonInvalidParam5);
+    check_useful_c_str!(inputs_json, ErrorCode::CommonInvalidParam6);
+    check_useful_c_str!(outputs_json, ErrorCode::CommonInvalidParam7);
+    check_useful_opt_c_str!(extra, ErrorCode::CommonInvalidParam8);
+    check_useful_opt_c_str!(text, ErrorCode::CommonInvalidParam9);
+    check_useful_opt_c_str!(version, ErrorCode::CommonInvalidParam10);
+    check_useful_opt_c_str!(taa_digest, ErrorCode::CommonInvalidParam11);
+    check_useful_opt_c_str!(mechanism, ErrorCode::CommonInvalidParam12);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam14);
+
+    trace!("indy_prepare_request_with_fees: entities >>> wallet_handle: {:?}, pool_handle: {:?}, submitter_did: {:?}, req_json: {:?}, inputs_json: {:?}, outputs_json: {:?}, \
+        extra: {:?}, text: {:?}, version: {:?}, taa_digest: {:?}, mechanism: {:?}, time: {:?}",
+           wallet_handle, pool_handle, submitter_did, req_json, inputs_json, outputs_json, extra, text, version, taa_digest, mechanism, time);
+
+    let taa_acceptance = mechanism.map(|mechanism| TaaAcceptance { text, version, taa_digest, mechanism, time });
+
+    let result =
+        CommandExecutor::submit(
+            Command::Payments(
+                PaymentsCommand::PrepareRequestWithFees(
+                    wallet_handle,
+                    pool_handle,
+                    submitter_did,
+                    req_json,
+                    inputs_json,
+                    outputs_json,
+                    extra,
+                    taa_acceptance,
+                    with_timeout(move |result| {
+                        let (err, req_with_fees_json, receipt_json) = prepare_result_2!(result, String::new(), String::new());
+                        trace!("indy_prepare_request_with_fees: req_with_fees_json: {:?}, receipt_json: {:?}", req_with_fees_json, receipt_json);
+                        let req_with_fees_json = ctypes::string_to_cstring(req_with_fees_json);
+                        let receipt_json = ctypes::string_to_cstring(receipt_json);
+                        cb(command_handle, err, req_with_fees_json.as_ptr(), receipt_json.as_ptr());
+                    }))
+            ));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_prepare_request_with_fees: <<< res: {:?}", res);
+
+    res
+}
+
 /// Parses response for Indy request with fees.
 ///
 /// #Params
@@ -644,6 +1271,8 @@ pub extern fn indy_parse_response_with_fees(command_handle: CommandHandle,
                                                                  err: ErrorCode,
                                                                  receipts_json: *const c_char)>) -> ErrorCode {
     trace!("indy_parse_response_with_fees: >>> payment_method: {:?}, resp_json: {:?}", payment_method, resp_json);
+
+    command_params!(payment_method, resp_json);
     check_useful_c_str!(payment_method, ErrorCode::CommonInvalidParam2);
     check_useful_c_str!(resp_json, ErrorCode::CommonInvalidParam3);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
@@ -651,12 +1280,12 @@ pub extern fn indy_parse_response_with_fees(command_handle: CommandHandle,
     trace!("indy_parse_response_with_fees: entities >>> payment_method: {:?}, resp_json: {:?}", payment_method, resp_json);
 
     let result =
-        CommandExecutor::instance().send(
+        CommandExecutor::submit(
             Command::Payments(
                 PaymentsCommand::ParseResponseWithFees(
                     payment_method,
                     resp_json,
-                    boxed_callback_string!("indy_parse_response_with_fees", cb, command_handle))));
+                    with_timeout(boxed_callback_string!("indy_parse_response_with_fees", cb, command_handle)))));
     let res = prepare_result!(result);
 
     trace!("indy_parse_response_with_fees: <<< res: {:?}", res);
@@ -687,6 +1316,8 @@ pub extern fn indy_build_get_payment_sources_request(command_handle: CommandHand
                                                                           get_sources_txn_json: *const c_char,
                                                                           payment_method: *const c_char)>) -> ErrorCode {
     trace!("indy_build_get_payment_sources_request: >>> wallet_handle: {:?}, submitter_did: {:?}, payment_address: {:?}", wallet_handle, submitter_did, payment_address);
+
+    command_params!(wallet_handle, submitter_did, payment_address);
     check_useful_validatable_opt_string!(submitter_did, ErrorCode::CommonInvalidParam3, DidValue);
     check_useful_c_str!(payment_address, ErrorCode::CommonInvalidParam4);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
@@ -694,14 +1325,14 @@ pub extern fn indy_build_get_payment_sources_request(command_handle: CommandHand
     trace!("indy_build_get_payment_sources_request: entities >>> wallet_handle: {:?}, submitter_did: {:?}, payment_address: {:?}", wallet_handle, submitter_did, payment_address);
 
     let result =
-        CommandExecutor::instance().send(
+        CommandExecutor::submit(
             Command::Payments(
                 PaymentsCommand::BuildGetPaymentSourcesRequest(
                     wallet_handle,
                     submitter_did,
                     payment_address,
                     None,
-                    Box::new(move |result| {
+                    with_timeout(move |result| {
                         let (err, get_sources_txn_json, payment_method) = prepare_result_2!(result, String::new(), String::new());
                         trace!("indy_build_get_payment_sources_request: get_sources_txn_json: {:?}, payment_method: {:?}", get_sources_txn_json, payment_method);
                         let get_sources_txn_json = ctypes::string_to_cstring(get_sources_txn_json);
@@ -741,6 +1372,8 @@ pub extern fn indy_parse_get_payment_sources_response(command_handle: CommandHan
                                                                            err: ErrorCode,
                                                                            sources_json: *const c_char)>) -> ErrorCode {
     trace!("indy_parse_get_payment_sources_response: >>> payment_method: {:?}, resp_json: {:?}", payment_method, resp_json);
+
+    command_params!(payment_method, resp_json);
     check_useful_c_str!(payment_method, ErrorCode::CommonInvalidParam2);
     check_useful_c_str!(resp_json, ErrorCode::CommonInvalidParam3);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
@@ -748,12 +1381,12 @@ pub extern fn indy_parse_get_payment_sources_response(command_handle: CommandHan
     trace!("indy_parse_get_payment_sources_response: entities >>> payment_method: {:?}, resp_json: {:?}", payment_method, resp_json);
 
     let result =
-        CommandExecutor::instance().send(
+        CommandExecutor::submit(
             Command::Payments(
                 PaymentsCommand::ParseGetPaymentSourcesResponse(
                     payment_method,
                     resp_json,
-                    Box::new(move |result| {
+                    with_timeout(move |result| {
                         let (err, sources_json, _) = prepare_result_2!(result, String::new(), -1);
                         trace!("indy_parse_get_payment_sources_response: sources_json: {:?}", sources_json);
                         let sources_json = ctypes::string_to_cstring(sources_json);
@@ -806,6 +1439,8 @@ pub extern fn indy_build_payment_req(command_handle: CommandHandle,
                                                           payment_method: *const c_char)>) -> ErrorCode {
     trace!("indy_build_payment_req: >>> wallet_handle: {:?}, submitter_did: {:?}, inputs_json: {:?}, outputs_json: {:?}, extra: {:?}",
            wallet_handle, submitter_did, inputs_json, outputs_json, extra);
+
+    command_params!(wallet_handle, submitter_did, inputs_json, outputs_json, extra);
     check_useful_validatable_opt_string!(submitter_did, ErrorCode::CommonInvalidParam3, DidValue);
     check_useful_c_str!(inputs_json, ErrorCode::CommonInvalidParam4);
     check_useful_c_str!(outputs_json, ErrorCode::CommonInvalidParam5);
@@ -816,7 +1451,7 @@ pub extern fn indy_build_payment_req(command_handle: CommandHandle,
            wallet_handle, submitter_did, inputs_json, outputs_json, extra);
 
     let result =
-        CommandExecutor::instance().send(
+        CommandExecutor::submit(
             Command::Payments(
                 PaymentsCommand::BuildPaymentReq(
                     wallet_handle,
@@ -824,7 +1459,7 @@ pub extern fn indy_build_payment_req(command_handle: CommandHandle,
                     inputs_json,
                     outputs_json,
                     extra,
-                    Box::new(move |result| {
+                    with_timeout(move |result| {
                         let (err, payment_req_json, payment_method) = prepare_result_2!(result, String::new(), String::new());
                         trace!("indy_build_payment_req: payment_req_json: {:?}, payment_method: {:?}", payment_req_json, payment_method);
                         let payment_req_json = ctypes::string_to_cstring(payment_req_json);
@@ -863,6 +1498,8 @@ pub extern fn indy_parse_payment_response(command_handle: CommandHandle,
                                                                err: ErrorCode,
                                                                receipts_json: *const c_char)>) -> ErrorCode {
     trace!("indy_parse_payment_response: >>> payment_method: {:?}, resp_json: {:?}", payment_method, resp_json);
+
+    command_params!(payment_method, resp_json);
     check_useful_c_str!(payment_method, ErrorCode::CommonInvalidParam2);
     check_useful_c_str!(resp_json, ErrorCode::CommonInvalidParam3);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
@@ -870,12 +1507,12 @@ pub extern fn indy_parse_payment_response(command_handle: CommandHandle,
     trace!("indy_parse_payment_response: entities >>> payment_method: {:?}, resp_json: {:?}", payment_method, resp_json);
 
     let result =
-        CommandExecutor::instance().send(
+        CommandExecutor::submit(
             Command::Payments(
                 PaymentsCommand::ParsePaymentResponse(
                     payment_method,
                     resp_json,
-                    boxed_callback_string!("indy_parse_payment_response", cb, command_handle))));
+                    with_timeout(boxed_callback_string!("indy_parse_payment_response", cb, command_handle)))));
 
     let res = prepare_result!(result);
 
@@ -924,6 +1561,8 @@ pub extern fn indy_prepare_payment_extra_with_acceptance_data(command_handle: Co
         mechanism: {:?}, time: {:?}",
            extra_json, text, version, taa_digest, mechanism, time);
 
+    command_params!(extra_json, text, version, taa_digest, mechanism, time);
+
     check_useful_opt_c_str!(extra_json, ErrorCode::CommonInvalidParam2);
     check_useful_opt_c_str!(text, ErrorCode::CommonInvalidParam3);
     check_useful_opt_c_str!(version, ErrorCode::CommonInvalidParam4);
@@ -935,8 +1574,7 @@ pub extern fn indy_prepare_payment_extra_with_acceptance_data(command_handle: Co
         mechanism: {:?}, time: {:?}",
            extra_json, text, version, taa_digest, mechanism, time);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Payments(
+    let result = CommandExecutor::submit(Command::Payments(
             PaymentsCommand::AppendTxnAuthorAgreementAcceptanceToExtra(
                 extra_json,
                 text,
@@ -944,7 +1582,7 @@ pub extern fn indy_prepare_payment_extra_with_acceptance_data(command_handle: Co
                 taa_digest,
                 mechanism,
                 time,
-                boxed_callback_string!("indy_prepare_payment_extra_with_acceptance_data", cb, command_handle)
+                with_timeout(boxed_callback_string!("indy_prepare_payment_extra_with_acceptance_data", cb, command_handle))
             )));
 
     let res = prepare_result!(result);
@@ -982,6 +1620,8 @@ pub extern fn indy_build_mint_req(command_handle: CommandHandle,
                                                        mint_req_json: *const c_char,
                                                        payment_method: *const c_char)>) -> ErrorCode {
     trace!("indy_build_mint_req: >>> wallet_handle: {:?}, submitter_did: {:?}, outputs_json: {:?}, extra: {:?}", wallet_handle, submitter_did, outputs_json, extra);
+
+    command_params!(wallet_handle, submitter_did, outputs_json, extra);
     check_useful_validatable_opt_string!(submitter_did, ErrorCode::CommonInvalidParam3, DidValue);
     check_useful_c_str!(outputs_json, ErrorCode::CommonInvalidParam4);
     check_useful_opt_c_str!(extra, ErrorCode::CommonInvalidParam5);
@@ -990,14 +1630,14 @@ pub extern fn indy_build_mint_req(command_handle: CommandHandle,
     trace!("indy_build_mint_req: entities >>> wallet_handle: {:?}, submitter_did: {:?}, outputs_json: {:?}, extra: {:?}", wallet_handle, submitter_did, outputs_json, extra);
 
     let result =
-        CommandExecutor::instance().send(
+        CommandExecutor::submit(
             Command::Payments(
                 PaymentsCommand::BuildMintReq(
                     wallet_handle,
                     submitter_did,
                     outputs_json,
                     extra,
-                    Box::new(move |result| {
+                    with_timeout(move |result| {
                         let (err, mint_req_json, payment_method) = prepare_result_2!(result, String::new(), String::new());
                         trace!("indy_build_mint_req: mint_req_json: {:?}, payment_method: {:?}", mint_req_json, payment_method);
                         let mint_req_json = ctypes::string_to_cstring(mint_req_json);
@@ -1038,6 +1678,8 @@ pub extern fn indy_build_set_txn_fees_req(command_handle: CommandHandle,
                                                                err: ErrorCode,
                                                                set_txn_fees_json: *const c_char)>) -> ErrorCode {
     trace!("indy_build_set_txn_fees_req: >>> wallet_handle: {:?}, submitter_did: {:?}, payment_method: {:?}, fees_json: {:?}", wallet_handle, submitter_did, payment_method, fees_json);
+
+    command_params!(wallet_handle, submitter_did, payment_method, fees_json);
     check_useful_validatable_opt_string!(submitter_did, ErrorCode::CommonInvalidParam3, DidValue);
     check_useful_c_str!(payment_method, ErrorCode::CommonInvalidParam4);
     check_useful_c_str!(fees_json, ErrorCode::CommonInvalidParam5);
@@ -1046,14 +1688,14 @@ pub extern fn indy_build_set_txn_fees_req(command_handle: CommandHandle,
     trace!("indy_build_set_txn_fees_req: entitites >>> wallet_handle: {:?}, submitter_did: {:?}, payment_method: {:?}, fees_json: {:?}", wallet_handle, submitter_did, payment_method, fees_json);
 
     let result =
-        CommandExecutor::instance().send(
+        CommandExecutor::submit(
             Command::Payments(
                 PaymentsCommand::BuildSetTxnFeesReq(
                     wallet_handle,
                     submitter_did,
                     payment_method,
                     fees_json,
-                    boxed_callback_string!("indy_build_set_txn_fees_req", cb, command_handle))));
+                    with_timeout(boxed_callback_string!("indy_build_set_txn_fees_req", cb, command_handle)))));
 
     let res = prepare_result!(result);
 
@@ -1081,6 +1723,8 @@ pub extern fn indy_build_get_txn_fees_req(command_handle: CommandHandle,
                                                                err: ErrorCode,
                                                                get_txn_fees_json: *const c_char)>) -> ErrorCode {
     trace!("indy_build_get_txn_fees_req: >>> wallet_handle: {:?}, submitter_did: {:?}, payment_method: {:?}", wallet_handle, submitter_did, payment_method);
+
+    command_params!(wallet_handle, submitter_did, payment_method);
     check_useful_validatable_opt_string!(submitter_did, ErrorCode::CommonInvalidParam3, DidValue);
     check_useful_c_str!(payment_method, ErrorCode::CommonInvalidParam4);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
@@ -1088,13 +1732,13 @@ pub extern fn indy_build_get_txn_fees_req(command_handle: CommandHandle,
     trace!("indy_build_get_txn_fees_req: entities >>> wallet_handle: {:?}, submitter_did: {:?}, payment_method: {:?}", wallet_handle, submitter_did, payment_method);
 
     let result =
-        CommandExecutor::instance().send(
+        CommandExecutor::submit(
             Command::Payments(
                 PaymentsCommand::BuildGetTxnFeesReq(
                     wallet_handle,
                     submitter_did,
                     payment_method,
-                    boxed_callback_string!("indy_build_get_txn_fees_req", cb, command_handle))));
+                    with_timeout(boxed_callback_string!("indy_build_get_txn_fees_req", cb, command_handle)))));
 
     let res = prepare_result!(result);
 
@@ -1125,6 +1769,8 @@ pub extern fn indy_parse_get_txn_fees_response(command_handle: CommandHandle,
                                                                     err: ErrorCode,
                                                                     fees_json: *const c_char)>) -> ErrorCode {
     trace!("indy_parse_get_txn_fees_response: >>> payment_method: {:?}, resp_json: {:?}", payment_method, resp_json);
+
+    command_params!(payment_method, resp_json);
     check_useful_c_str!(payment_method, ErrorCode::CommonInvalidParam2);
     check_useful_c_str!(resp_json, ErrorCode::CommonInvalidParam3);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
@@ -1132,12 +1778,11 @@ pub extern fn indy_parse_get_txn_fees_response(command_handle: CommandHandle,
     trace!("indy_parse_get_txn_fees_response: entities >>> payment_method: {:?}, resp_json: {:?}", payment_method, resp_json);
 
     let result =
-        CommandExecutor::instance()
-            .send(Command::Payments(
+        CommandExecutor::submit(Command::Payments(
                 PaymentsCommand::ParseGetTxnFeesResponse(
                     payment_method,
                     resp_json,
-                    boxed_callback_string!("indy_parse_get_txn_fees_response", cb, command_handle))));
+                    with_timeout(boxed_callback_string!("indy_parse_get_txn_fees_response", cb, command_handle)))));
 
     let res = prepare_result!(result);
 
@@ -1167,19 +1812,20 @@ pub extern fn indy_build_verify_payment_req(command_handle: CommandHandle,
                                                                  verify_txn_json: *const c_char,
                                                                  payment_method: *const c_char)>) -> ErrorCode {
     trace!("indy_build_verify_payment_req: >>> wallet_handle {:?}, submitter_did: {:?}, receipt: {:?}", wallet_handle, submitter_did, receipt);
+
+    command_params!(wallet_handle, submitter_did, receipt);
     check_useful_validatable_opt_string!(submitter_did, ErrorCode::CommonInvalidParam3, DidValue);
     check_useful_c_str!(receipt, ErrorCode::CommonInvalidParam4);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
 
     trace!("indy_build_verify_payment_req: entities >>> wallet_handle {:?}, submitter_did: {:?}, receipt: {:?}", wallet_handle, submitter_did, receipt);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Payments(
+    let result = CommandExecutor::submit(Command::Payments(
             PaymentsCommand::BuildVerifyPaymentReq(
                 wallet_handle,
                 submitter_did,
                 receipt,
-                Box::new(move |result| {
+                with_timeout(move |result| {
                     let (err, verify_txn_json, payment_method) = prepare_result_2!(result, String::new(), String::new());
                     trace!("indy_build_verify_payment_req: verify_txn_json: {:?}, payment_method: {:?}", verify_txn_json, payment_method);
                     let verify_txn_json = ctypes::string_to_cstring(verify_txn_json);
@@ -1220,18 +1866,19 @@ pub extern fn indy_parse_verify_payment_response(command_handle: CommandHandle,
                                                                       err: ErrorCode,
                                                                       txn_json: *const c_char)>) -> ErrorCode {
     trace!("indy_parse_verify_payment_response: >>> resp_json: {:?}", resp_json);
+
+    command_params!(payment_method, resp_json);
     check_useful_c_str!(payment_method, ErrorCode::CommonInvalidParam2);
     check_useful_c_str!(resp_json, ErrorCode::CommonInvalidParam3);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     trace!("indy_parse_verify_payment_response: entities >>> resp_json: {:?}", resp_json);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Payments(
+    let result = CommandExecutor::submit(Command::Payments(
             PaymentsCommand::ParseVerifyPaymentResponse(
                 payment_method,
                 resp_json,
-                boxed_callback_string!("indy_parse_verify_payment_response", cb, command_handle)
+                with_timeout(boxed_callback_string!("indy_parse_verify_payment_response", cb, command_handle))
             )));
 
     let result = prepare_result!(result);
@@ -1282,6 +1929,8 @@ pub extern fn indy_get_request_info(command_handle: CommandHandle,
     trace!("indy_get_request_info: >>> get_auth_rule_response_json: {:?}, requester_info_json: {:?}, fees_json: {:?}",
            get_auth_rule_response_json, requester_info_json, fees_json);
 
+    command_params!(get_auth_rule_response_json, requester_info_json, fees_json);
+
     check_useful_c_str!(get_auth_rule_response_json, ErrorCode::CommonInvalidParam2);
     check_useful_json!(requester_info_json, ErrorCode::CommonInvalidParam3, RequesterInfo);
     check_useful_json!(fees_json, ErrorCode::CommonInvalidParam4, Fees);
@@ -1290,13 +1939,12 @@ pub extern fn indy_get_request_info(command_handle: CommandHandle,
     trace!("indy_get_request_info: entities >>> get_auth_rule_response_json: {:?}, requester_info_json: {:?}, fees_json: {:?}",
            get_auth_rule_response_json, requester_info_json, fees_json);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Payments(
+    let result = CommandExecutor::submit(Command::Payments(
             PaymentsCommand::GetRequestInfo(
                 get_auth_rule_response_json,
                 requester_info_json,
                 fees_json,
-                boxed_callback_string!("indy_get_request_info", cb, command_handle)
+                with_timeout(boxed_callback_string!("indy_get_request_info", cb, command_handle))
             )));
 
     let result = prepare_result!(result);
@@ -1335,6 +1983,8 @@ pub extern fn indy_sign_with_address(command_handle: CommandHandle,
                                                           signature_len: u32)>) -> ErrorCode {
     trace!("indy_sign_with_address: >>> wallet_handle: {:?}, address: {:?}, message_raw: {:?}, message_len: {:?}",
            wallet_handle, address, message_raw, message_len);
+
+    command_params!(wallet_handle, address, (message_raw, message_len));
     check_useful_c_str!(address, ErrorCode::CommonInvalidParam3);
     check_useful_c_byte_array!(message_raw, message_len, ErrorCode::CommonInvalidParam4, ErrorCode::CommonInvalidParam5);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
@@ -1342,12 +1992,11 @@ pub extern fn indy_sign_with_address(command_handle: CommandHandle,
     trace!("indy_sign_with_address: entities >>> wallet_handle: {:?}, address: {:?}, message_raw: {:?}, message_len: {:?}",
            wallet_handle, address, message_raw, message_len);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Payments(
+    let result = CommandExecutor::submit(Command::Payments(
             PaymentsCommand::SignWithAddressReq(wallet_handle,
                                                 address,
                                                 message_raw,
-                                                Box::new(move |result| {
+                                                with_timeout(move |result| {
                                                     let (err, signature) = prepare_result_1!(result, Vec::new());
                                                     trace!("indy_sign_with_address: signature: {:?}", signature);
                                                     let (signature_raw, signature_len) = ctypes::vec_to_pointer(&signature);
@@ -1395,6 +2044,8 @@ pub extern fn indy_verify_with_address(command_handle: CommandHandle,
     trace!("indy_verify_with_address: >>> address: {:?}, message_raw: {:?}, message_len: {:?}, signature_raw: {:?}, signature_len: {:?}",
            address, message_raw, message_len, signature_raw, signature_len);
 
+    command_params!(address, (message_raw, message_len), (signature_raw, signature_len));
+
     check_useful_c_str!(address, ErrorCode::CommonInvalidParam2);
     check_useful_c_byte_array!(message_raw, message_len, ErrorCode::CommonInvalidParam3, ErrorCode::CommonInvalidParam4);
     check_useful_c_byte_array!(signature_raw, signature_len, ErrorCode::CommonInvalidParam5, ErrorCode::CommonInvalidParam6);
@@ -1403,12 +2054,11 @@ pub extern fn indy_verify_with_address(command_handle: CommandHandle,
     trace!("indy_verify_with_address: entities >>> address: {:?}, message_raw: {:?}, message_len: {:?}, signature_raw: {:?}, signature_len: {:?}",
            address, message_raw, message_len, signature_raw, signature_len);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Payments(PaymentsCommand::VerifyWithAddressReq(
+    let result = CommandExecutor::submit(Command::Payments(PaymentsCommand::VerifyWithAddressReq(
             address,
             message_raw,
             signature_raw,
-            Box::new(move |result| {
+            with_timeout(move |result| {
                 let (err, valid) = prepare_result_1!(result, false);
                 trace!("indy_verify_with_address: valid: {:?}", valid);
                 cb(command_handle, err, valid)
@@ -1421,3 +2071,245 @@ pub extern fn indy_verify_with_address(command_handle: CommandHandle,
 
     res
 }
+
+/// Estimates fees that will be charged for the request.
+///
+/// Payment method is resolved from inputs and must be registered with `indy_register_payment_method_v2`.
+/// Request is not modified, use `indy_add_request_fees` to add fees to the request.
+///
+/// # Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: wallet handle
+/// submitter_did: (Optional) DID of request sender
+/// req_json: initial transaction request as json
+/// inputs_json: The list of payment sources as json array:
+///   ["source1", ...]
+///   Note that each source should reference payment address
+/// cb: Callback that takes command result as parameter.
+///
+/// # Return
+/// fees_estimate_json: {
+///     fee: <int>, // amount that will be charged for the request
+///     <str>: <any>, // any additional payment method specific details
+/// }
+#[no_mangle]
+pub extern fn indy_estimate_request_fees(command_handle: CommandHandle,
+                                         wallet_handle: WalletHandle,
+                                         submitter_did: *const c_char,
+                                         req_json: *const c_char,
+                                         inputs_json: *const c_char,
+                                         cb: Option<extern fn(command_handle_: CommandHandle,
+                                                              err: ErrorCode,
+                                                              fees_estimate_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_estimate_request_fees: >>> wallet_handle: {:?}, submitter_did: {:?}, req_json: {:?}, inputs_json: {:?}",
+           wallet_handle, submitter_did, req_json, inputs_json);
+
+    command_params!(wallet_handle, submitter_did, req_json, inputs_json);
+    check_useful_validatable_opt_string!(submitter_did, ErrorCode::CommonInvalidParam3, DidValue);
+    check_useful_c_str!(req_json, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(inputs_json, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_estimate_request_fees: entities >>> wallet_handle: {:?}, submitter_did: {:?}, req_json: {:?}, inputs_json: {:?}",
+           wallet_handle, submitter_did, req_json, inputs_json);
+
+    let result =
+        CommandExecutor::submit(Command::Payments(
+                PaymentsCommand::EstimateRequestFees(
+                    wallet_handle,
+                    submitter_did,
+                    req_json,
+                    inputs_json,
+                    with_timeout(boxed_callback_string!("indy_estimate_request_fees", cb, command_handle)))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_estimate_request_fees: <<< res: {:?}", res);
+
+    res
+}
+
+/// Verifies a batch of payment receipts.
+///
+/// Payment method is resolved from receipts and must be registered with `indy_register_payment_method_v2`.
+/// All receipts must belong to the same payment method.
+///
+/// # Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: wallet handle
+/// submitter_did: (Optional) DID of request sender
+/// receipts_json: The list of payment receipts as json array:
+///   ["receipt1", ...]
+/// cb: Callback that takes command result as parameter.
+///
+/// # Return
+/// receipts_info_json: [{
+///     receipt: <str>, // verified receipt
+///     verified: <bool>, // whether receipt is confirmed by the ledger
+///     txn: { // (Optional) receipt transaction info
+///         sources: [<str>, ]
+///         receipts: [ {recipient: <str>, receipt: <str>, amount: <int>}, ]
+///         extra: <str>,
+///     },
+///     error: <str>, // (Optional) reason why receipt isn't verified
+/// }]
+#[no_mangle]
+pub extern fn indy_verify_payment_receipts(command_handle: CommandHandle,
+                                           wallet_handle: WalletHandle,
+                                           submitter_did: *const c_char,
+                                           receipts_json: *const c_char,
+                                           cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                err: ErrorCode,
+                                                                receipts_info_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_verify_payment_receipts: >>> wallet_handle: {:?}, submitter_did: {:?}, receipts_json: {:?}",
+           wallet_handle, submitter_did, receipts_json);
+
+    command_params!(wallet_handle, submitter_did, receipts_json);
+    check_useful_validatable_opt_string!(submitter_did, ErrorCode::CommonInvalidParam3, DidValue);
+    check_useful_c_str!(receipts_json, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_verify_payment_receipts: entities >>> wallet_handle: {:?}, submitter_did: {:?}, receipts_json: {:?}",
+           wallet_handle, submitter_did, receipts_json);
+
+    let result =
+        CommandExecutor::submit(Command::Payments(
+                PaymentsCommand::VerifyPaymentReceipts(
+                    wallet_handle,
+                    submitter_did,
+                    receipts_json,
+                    with_timeout(boxed_callback_string!("indy_verify_payment_receipts", cb, command_handle)))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_verify_payment_receipts: <<< res: {:?}", res);
+
+    res
+}
+
+/// Verifies that payment receipt is included into the ledger using state proof of the reply.
+///
+/// Payment method is resolved from the receipt and must have a parser registered with
+/// `indy_register_payment_receipt_proof_parser`. The parser extracts receipt info and state proofs
+/// from the reply, but state proofs and their multi signatures are checked by libindy against
+/// BLS keys of the pool nodes, so the reply can be received from a single node.
+/// Freshness of the reply isn't checked.
+///
+/// # Params
+/// command_handle: Command handle to map callback to caller context.
+/// receipt: payment receipt to verify
+/// resp_json: response for the request built by `indy_build_verify_payment_req`
+/// bls_keys_json: BLS keys of the pool nodes:
+///   {
+///     <str>: <str>, // node alias -> base58 encoded BLS key
+///   }
+/// cb: Callback that takes command result as parameter.
+///
+/// # Return
+/// receipt_info_json: receipt transaction info confirmed by state proof
+///   {
+///     sources: [<str>, ]
+///     receipts: [ {recipient: <str>, receipt: <str>, amount: <int>}, ]
+///     extra: <str>,
+///   }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+/// Payment*
+#[no_mangle]
+pub extern fn indy_verify_payment_receipt_with_proof(command_handle: CommandHandle,
+                                                     receipt: *const c_char,
+                                                     resp_json: *const c_char,
+                                                     bls_keys_json: *const c_char,
+                                                     cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                          err: ErrorCode,
+                                                                          receipt_info_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_verify_payment_receipt_with_proof: >>> receipt: {:?}, resp_json: {:?}, bls_keys_json: {:?}",
+           receipt, resp_json, bls_keys_json);
+
+    command_params!(receipt, resp_json, bls_keys_json);
+    check_useful_c_str!(receipt, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(resp_json, ErrorCode::CommonInvalidParam3);
+    check_useful_json!(bls_keys_json, ErrorCode::CommonInvalidParam4, HashMap<String, String>);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_verify_payment_receipt_with_proof: entities >>> receipt: {:?}, resp_json: {:?}, bls_keys_json: {:?}",
+           receipt, resp_json, bls_keys_json);
+
+    let result =
+        CommandExecutor::submit(Command::Payments(
+                PaymentsCommand::VerifyPaymentReceiptWithProof(
+                    receipt,
+                    resp_json,
+                    bls_keys_json,
+                    with_timeout(boxed_callback_string!("indy_verify_payment_receipt_with_proof", cb, command_handle)))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_verify_payment_receipt_with_proof: <<< res: {:?}", res);
+
+    res
+}
+
+context_api! {
+    indy_register_payment_method_ctx => indy_register_payment_method(payment_method: *const c_char, create_payment_address: Option<CreatePaymentAddressCB>, add_request_fees: Option<AddRequestFeesCB>, parse_response_with_fees: Option<ParseResponseWithFeesCB>, build_get_payment_sources_request: Option<BuildGetPaymentSourcesRequestCB>, parse_get_payment_sources_response: Option<ParseGetPaymentSourcesResponseCB>, build_payment_req: Option<BuildPaymentReqCB>, parse_payment_response: Option<ParsePaymentResponseCB>, build_mint_req: Option<BuildMintReqCB>, build_set_txn_fees_req: Option<BuildSetTxnFeesReqCB>, build_get_txn_fees_req: Option<BuildGetTxnFeesReqCB>, parse_get_txn_fees_response: Option<ParseGetTxnFeesResponseCB>, build_verify_payment_req: Option<BuildVerifyPaymentReqCB>, parse_verify_payment_response: Option<ParseVerifyPaymentResponseCB>, sign_with_address: Option<SignWithAddressCB>, verify_with_address: Option<VerifyWithAddressCB>)
+        -> cb(), CommonInvalidParam18;
+    indy_register_payment_method_v2_ctx => indy_register_payment_method_v2(payment_method: *const c_char, create_payment_address: Option<CreatePaymentAddressCB>, add_request_fees: Option<AddRequestFeesCB>, parse_response_with_fees: Option<ParseResponseWithFeesCB>, build_get_payment_sources_request: Option<BuildGetPaymentSourcesRequestCB>, parse_get_payment_sources_response: Option<ParseGetPaymentSourcesResponseCB>, build_payment_req: Option<BuildPaymentReqCB>, parse_payment_response: Option<ParsePaymentResponseCB>, build_mint_req: Option<BuildMintReqCB>, build_set_txn_fees_req: Option<BuildSetTxnFeesReqCB>, build_get_txn_fees_req: Option<BuildGetTxnFeesReqCB>, parse_get_txn_fees_response: Option<ParseGetTxnFeesResponseCB>, build_verify_payment_req: Option<BuildVerifyPaymentReqCB>, parse_verify_payment_response: Option<ParseVerifyPaymentResponseCB>, sign_with_address: Option<SignWithAddressCB>, verify_with_address: Option<VerifyWithAddressCB>, estimate_request_fees: Option<EstimateRequestFeesCB>, verify_payment_receipts: Option<VerifyPaymentReceiptsCB>)
+        -> cb(), CommonInvalidParam20;
+    indy_register_payment_history_handlers_ctx => indy_register_payment_history_handlers(payment_method: *const c_char, build_get_payment_history_request: Option<BuildGetPaymentHistoryRequestCB>, parse_get_payment_history_response: Option<ParseGetPaymentHistoryResponseCB>)
+        -> cb(), CommonInvalidParam5;
+    indy_unregister_payment_method_ctx => indy_unregister_payment_method(payment_method: *const c_char)
+        -> cb(), CommonInvalidParam3;
+    indy_register_payment_receipt_proof_parser_ctx => indy_register_payment_receipt_proof_parser(payment_method: *const c_char, parse_verify_payment_response_with_proof: Option<ParseVerifyPaymentResponseWithProofCB>)
+        -> cb(), CommonInvalidParam4;
+    #[cfg(feature = "null_payment_method")]
+    indy_register_null_payment_method_ctx => indy_register_null_payment_method()
+        -> cb(), CommonInvalidParam2;
+    indy_create_payment_address_ctx => indy_create_payment_address(wallet_handle: WalletHandle, payment_method: *const c_char, config: *const c_char)
+        -> cb(payment_address: *const c_char), CommonInvalidParam5;
+    indy_list_payment_addresses_ctx => indy_list_payment_addresses(wallet_handle: WalletHandle)
+        -> cb(payment_addresses_json: *const c_char), CommonInvalidParam3;
+    indy_get_payment_balances_ctx => indy_get_payment_balances(wallet_handle: WalletHandle, pool_handle: PoolHandle)
+        -> cb(balances_json: *const c_char), CommonInvalidParam4;
+    indy_add_request_fees_ctx => indy_add_request_fees(wallet_handle: WalletHandle, submitter_did: *const c_char, req_json: *const c_char, inputs_json: *const c_char, outputs_json: *const c_char, extra: *const c_char)
+        -> cb(req_with_fees_json: *const c_char, payment_method: *const c_char), CommonInvalidParam8;
+    indy_prepare_request_with_fees_ctx => indy_prepare_request_with_fees(wallet_handle: WalletHandle, pool_handle: PoolHandle, submitter_did: *const c_char, req_json: *const c_char, inputs_json: *const c_char, outputs_json: *const c_char, extra: *const c_char, text: *const c_char, version: *const c_char, taa_digest: *const c_char, mechanism: *const c_char, time: u64)
+        -> cb(req_with_fees_json: *const c_char, receipt_json: *const c_char), CommonInvalidParam14;
+    indy_parse_response_with_fees_ctx => indy_parse_response_with_fees(payment_method: *const c_char, resp_json: *const c_char)
+        -> cb(receipts_json: *const c_char), CommonInvalidParam4;
+    indy_build_get_payment_sources_request_ctx => indy_build_get_payment_sources_request(wallet_handle: WalletHandle, submitter_did: *const c_char, payment_address: *const c_char)
+        -> cb(get_sources_txn_json: *const c_char, payment_method: *const c_char), CommonInvalidParam5;
+    indy_parse_get_payment_sources_response_ctx => indy_parse_get_payment_sources_response(payment_method: *const c_char, resp_json: *const c_char)
+        -> cb(sources_json: *const c_char), CommonInvalidParam4;
+    indy_build_payment_req_ctx => indy_build_payment_req(wallet_handle: WalletHandle, submitter_did: *const c_char, inputs_json: *const c_char, outputs_json: *const c_char, extra: *const c_char)
+        -> cb(payment_req_json: *const c_char, payment_method: *const c_char), CommonInvalidParam7;
+    indy_parse_payment_response_ctx => indy_parse_payment_response(payment_method: *const c_char, resp_json: *const c_char)
+        -> cb(receipts_json: *const c_char), CommonInvalidParam4;
+    indy_prepare_payment_extra_with_acceptance_data_ctx => indy_prepare_payment_extra_with_acceptance_data(extra_json: *const c_char, text: *const c_char, version: *const c_char, taa_digest: *const c_char, mechanism: *const c_char, time: u64)
+        -> cb(extra_with_acceptance: *const c_char), CommonInvalidParam8;
+    indy_build_mint_req_ctx => indy_build_mint_req(wallet_handle: WalletHandle, submitter_did: *const c_char, outputs_json: *const c_char, extra: *const c_char)
+        -> cb(mint_req_json: *const c_char, payment_method: *const c_char), CommonInvalidParam6;
+    indy_build_set_txn_fees_req_ctx => indy_build_set_txn_fees_req(wallet_handle: WalletHandle, submitter_did: *const c_char, payment_method: *const c_char, fees_json: *const c_char)
+        -> cb(set_txn_fees_json: *const c_char), CommonInvalidParam6;
+    indy_build_get_txn_fees_req_ctx => indy_build_get_txn_fees_req(wallet_handle: WalletHandle, submitter_did: *const c_char, payment_method: *const c_char)
+        -> cb(get_txn_fees_json: *const c_char), CommonInvalidParam5;
+    indy_parse_get_txn_fees_response_ctx => indy_parse_get_txn_fees_response(payment_method: *const c_char, resp_json: *const c_char)
+        -> cb(fees_json: *const c_char), CommonInvalidParam4;
+    indy_build_verify_payment_req_ctx => indy_build_verify_payment_req(wallet_handle: WalletHandle, submitter_did: *const c_char, receipt: *const c_char)
+        -> cb(verify_txn_json: *const c_char, payment_method: *const c_char), CommonInvalidParam5;
+    indy_parse_verify_payment_response_ctx => indy_parse_verify_payment_response(payment_method: *const c_char, resp_json: *const c_char)
+        -> cb(txn_json: *const c_char), CommonInvalidParam4;
+    indy_get_request_info_ctx => indy_get_request_info(get_auth_rule_response_json: *const c_char, requester_info_json: *const c_char, fees_json: *const c_char)
+        -> cb(request_info_json: *const c_char), CommonInvalidParam5;
+    indy_sign_with_address_ctx => indy_sign_with_address(wallet_handle: WalletHandle, address: *const c_char, message_raw: *const u8, message_len: u32)
+        -> cb(signature_raw: *const u8, signature_len: u32), CommonInvalidParam6;
+    indy_verify_with_address_ctx => indy_verify_with_address(address: *const c_char, message_raw: *const u8, message_len: u32, signature_raw: *const u8, signature_len: u32)
+        -> cb(result: bool), CommonInvalidParam7;
+    indy_estimate_request_fees_ctx => indy_estimate_request_fees(wallet_handle: WalletHandle, submitter_did: *const c_char, req_json: *const c_char, inputs_json: *const c_char)
+        -> cb(fees_estimate_json: *const c_char), CommonInvalidParam6;
+    indy_verify_payment_receipts_ctx => indy_verify_payment_receipts(wallet_handle: WalletHandle, submitter_did: *const c_char, receipts_json: *const c_char)
+        -> cb(receipts_info_json: *const c_char), CommonInvalidParam5;
+    indy_verify_payment_receipt_with_proof_ctx => indy_verify_payment_receipt_with_proof(receipt: *const c_char, resp_json: *const c_char, bls_keys_json: *const c_char)
+        -> cb(receipt_info_json: *const c_char), CommonInvalidParam5;
+}
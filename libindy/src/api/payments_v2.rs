@@ -4,6 +4,7 @@ use indy_api_types::ErrorCode;
 use libc::c_char;
 use crate::commands::CommandExecutor;
 use crate::commands::Command;
+use crate::commands::timeout::with_timeout;
 use crate::commands::payments::PaymentsCommand;
 use indy_utils::ctypes;
 use indy_api_types::errors::prelude::*;
@@ -34,6 +35,8 @@ pub extern fn indy_build_get_payment_sources_with_from_request(command_handle: C
                                                                                     get_sources_txn_json: *const c_char,
                                                                                     payment_method: *const c_char)>) -> ErrorCode {
     trace!("indy_build_get_payment_sources_with_from_request: >>> wallet_handle: {:?}, submitter_did: {:?}, payment_address: {:?}", wallet_handle, submitter_did, payment_address);
+
+    command_params!(wallet_handle, submitter_did, payment_address, from);
     check_useful_validatable_opt_string!(submitter_did, ErrorCode::CommonInvalidParam3, DidValue);
     check_useful_c_str!(payment_address, ErrorCode::CommonInvalidParam4);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
@@ -43,14 +46,14 @@ pub extern fn indy_build_get_payment_sources_with_from_request(command_handle: C
     trace!("indy_build_get_payment_sources_with_from_request: entities >>> wallet_handle: {:?}, submitter_did: {:?}, payment_address: {:?}, from: {:?}", wallet_handle, submitter_did, payment_address, from);
 
     let result =
-        CommandExecutor::instance().send(
+        CommandExecutor::submit(
             Command::Payments(
                 PaymentsCommand::BuildGetPaymentSourcesRequest(
                     wallet_handle,
                     submitter_did,
                     payment_address,
                     from,
-                    Box::new(move |result| {
+                    with_timeout(move |result| {
                         let (err, get_sources_txn_json, payment_method) = prepare_result_2!(result, String::new(), String::new());
                         trace!("indy_build_get_payment_sources_with_from_request: get_sources_txn_json: {:?}, payment_method: {:?}", get_sources_txn_json, payment_method);
                         let get_sources_txn_json = ctypes::string_to_cstring(get_sources_txn_json);
@@ -91,6 +94,8 @@ pub extern fn indy_parse_get_payment_sources_with_from_response(command_handle:
                                                                                      sources_json: *const c_char,
                                                                                      next: i64)>) -> ErrorCode {
     trace!("indy_parse_get_payment_sources_with_from_response: >>> payment_method: {:?}, resp_json: {:?}", payment_method, resp_json);
+
+    command_params!(payment_method, resp_json);
     check_useful_c_str!(payment_method, ErrorCode::CommonInvalidParam2);
     check_useful_c_str!(resp_json, ErrorCode::CommonInvalidParam3);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
@@ -98,12 +103,12 @@ pub extern fn indy_parse_get_payment_sources_with_from_response(command_handle:
     trace!("indy_parse_get_payment_sources_with_from_response: entities >>> payment_method: {:?}, resp_json: {:?}", payment_method, resp_json);
 
     let result =
-        CommandExecutor::instance().send(
+        CommandExecutor::submit(
             Command::Payments(
                 PaymentsCommand::ParseGetPaymentSourcesResponse(
                     payment_method,
                     resp_json,
-                    Box::new(move |result| {
+                    with_timeout(move |result| {
                         let (err, sources_json, next) = prepare_result_2!(result, String::new(), -1);
                         trace!("indy_parse_get_payment_sources_with_from_response: sources_json: {:?}", sources_json);
                         let sources_json = ctypes::string_to_cstring(sources_json);
@@ -116,4 +121,142 @@ pub extern fn indy_parse_get_payment_sources_with_from_response(command_handle:
     trace!("indy_parse_get_payment_sources_with_from_response: <<< res: {:?}", res);
 
     res
-}
\ No newline at end of file
+}
+
+/// Builds Indy request for getting transaction history of payment address
+/// according to specified payment method.
+///
+/// Payment method must have history handlers registered with `indy_register_payment_history_handlers`.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// wallet_handle: wallet handle
+/// submitter_did: (Optional) DID of request sender
+/// payment_address: target payment address
+/// from: shift to the next slice of payment history (as returned by `indy_parse_get_payment_history_response`)
+///       or -1 to start from the beginning
+///
+/// #Returns
+/// get_history_txn_json - Indy request for getting transaction history of payment address
+/// payment_method - used payment method
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Payment*
+#[no_mangle]
+pub extern fn indy_build_get_payment_history_request(command_handle: CommandHandle,
+                                                     wallet_handle: WalletHandle,
+                                                     submitter_did: *const c_char,
+                                                     payment_address: *const c_char,
+                                                     from: i64,
+                                                     cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                          err: ErrorCode,
+                                                                          get_history_txn_json: *const c_char,
+                                                                          payment_method: *const c_char)>) -> ErrorCode {
+    trace!("indy_build_get_payment_history_request: >>> wallet_handle: {:?}, submitter_did: {:?}, payment_address: {:?}, from: {:?}",
+           wallet_handle, submitter_did, payment_address, from);
+
+    command_params!(wallet_handle, submitter_did, payment_address, from);
+    check_useful_validatable_opt_string!(submitter_did, ErrorCode::CommonInvalidParam3, DidValue);
+    check_useful_c_str!(payment_address, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    let from: Option<i64> = if from == -1 { None } else { Some(from) };
+
+    trace!("indy_build_get_payment_history_request: entities >>> wallet_handle: {:?}, submitter_did: {:?}, payment_address: {:?}, from: {:?}",
+           wallet_handle, submitter_did, payment_address, from);
+
+    let result =
+        CommandExecutor::submit(
+            Command::Payments(
+                PaymentsCommand::BuildGetPaymentHistoryRequest(
+                    wallet_handle,
+                    submitter_did,
+                    payment_address,
+                    from,
+                    with_timeout(move |result| {
+                        let (err, get_history_txn_json, payment_method) = prepare_result_2!(result, String::new(), String::new());
+                        trace!("indy_build_get_payment_history_request: get_history_txn_json: {:?}, payment_method: {:?}", get_history_txn_json, payment_method);
+                        let get_history_txn_json = ctypes::string_to_cstring(get_history_txn_json);
+                        let payment_method = ctypes::string_to_cstring(payment_method);
+                        cb(command_handle, err, get_history_txn_json.as_ptr(), payment_method.as_ptr());
+                    }))
+            ));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_build_get_payment_history_request: <<< res: {:?}", res);
+
+    res
+}
+
+/// Parses response for Indy request for getting transaction history of payment address.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// payment_method: payment method to use.
+/// resp_json: response for Indy request for getting transaction history
+///
+/// #Returns
+/// history_json - parsed (payment method and node version agnostic) transactions as json:
+///   [{
+///      receipt: <str>, // receipt of the transaction output related to the address
+///      txnTime: <int>, // (Optional) time when transaction was written to the ledger
+///      sources: [<str>, ] // transaction inputs
+///      receipts: [ {recipient: <str>, receipt: <str>, amount: <int>}, ] // transaction outputs
+///      extra: <str>, // (Optional) extra data of the transaction
+///   }]
+/// next - shift to the next slice of payment history or -1 if there are no more transactions
+///
+/// #Errors
+/// Common*
+/// Payment*
+#[no_mangle]
+pub extern fn indy_parse_get_payment_history_response(command_handle: CommandHandle,
+                                                      payment_method: *const c_char,
+                                                      resp_json: *const c_char,
+                                                      cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                           err: ErrorCode,
+                                                                           history_json: *const c_char,
+                                                                           next: i64)>) -> ErrorCode {
+    trace!("indy_parse_get_payment_history_response: >>> payment_method: {:?}, resp_json: {:?}", payment_method, resp_json);
+
+    command_params!(payment_method, resp_json);
+    check_useful_c_str!(payment_method, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(resp_json, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_parse_get_payment_history_response: entities >>> payment_method: {:?}, resp_json: {:?}", payment_method, resp_json);
+
+    let result =
+        CommandExecutor::submit(
+            Command::Payments(
+                PaymentsCommand::ParseGetPaymentHistoryResponse(
+                    payment_method,
+                    resp_json,
+                    with_timeout(move |result| {
+                        let (err, history_json, next) = prepare_result_2!(result, String::new(), -1);
+                        trace!("indy_parse_get_payment_history_response: history_json: {:?}, next: {:?}", history_json, next);
+                        let history_json = ctypes::string_to_cstring(history_json);
+                        cb(command_handle, err, history_json.as_ptr(), next);
+                    }))
+            ));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_parse_get_payment_history_response: <<< res: {:?}", res);
+
+    res
+}
+
+context_api! {
+    indy_build_get_payment_sources_with_from_request_ctx => indy_build_get_payment_sources_with_from_request(wallet_handle: WalletHandle, submitter_did: *const c_char, payment_address: *const c_char, from: i64)
+        -> cb(get_sources_txn_json: *const c_char, payment_method: *const c_char), CommonInvalidParam6;
+    indy_parse_get_payment_sources_with_from_response_ctx => indy_parse_get_payment_sources_with_from_response(payment_method: *const c_char, resp_json: *const c_char)
+        -> cb(sources_json: *const c_char, next: i64), CommonInvalidParam4;
+    indy_build_get_payment_history_request_ctx => indy_build_get_payment_history_request(wallet_handle: WalletHandle, submitter_did: *const c_char, payment_address: *const c_char, from: i64)
+        -> cb(get_history_txn_json: *const c_char, payment_method: *const c_char), CommonInvalidParam6;
+    indy_parse_get_payment_history_response_ctx => indy_parse_get_payment_history_response(payment_method: *const c_char, resp_json: *const c_char)
+        -> cb(history_json: *const c_char, next: i64), CommonInvalidParam4;
+}
@@ -1,6 +1,7 @@
 
 use indy_api_types::{ErrorCode, CommandHandle, PoolHandle, INVALID_POOL_HANDLE};
 use crate::commands::{Command, CommandExecutor};
+use crate::commands::timeout::with_timeout;
 use crate::commands::pool::PoolCommand;
 use crate::domain::pool::{PoolConfig, PoolOpenConfig};
 use indy_api_types::errors::prelude::*;
@@ -34,17 +35,18 @@ pub extern fn indy_create_pool_ledger_config(command_handle: CommandHandle,
                                                                   err: ErrorCode)>) -> ErrorCode {
     trace!("indy_create_pool_ledger_config: >>> config_name: {:?}, config: {:?}", config_name, config);
 
+    command_params!(config_name, config);
+
     check_useful_c_str!(config_name, ErrorCode::CommonInvalidParam2);
     check_useful_opt_json!(config, ErrorCode::CommonInvalidParam3, PoolConfig);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     trace!("indy_create_pool_ledger_config: entities >>> config_name: {:?}, config: {:?}", config_name, config);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Pool(PoolCommand::Create(
+    let result = CommandExecutor::submit(Command::Pool(PoolCommand::Create(
             config_name,
             config,
-            Box::new(move |result| {
+            with_timeout(move |result| {
                 let err = prepare_result!(result);
                 trace!("indy_create_pool_ledger_config:");
                 cb(command_handle, err)
@@ -80,6 +82,20 @@ pub extern fn indy_create_pool_ledger_config(command_handle: CommandHandle,
 ///         By default Libindy sends a read requests to 2 nodes in the pool.
 ///         If response isn't received or `state proof` is invalid Libindy sends the request again but to 2 (`number_read_nodes`) * 2 = 4 nodes and so far until completion.
 ///     "socks_proxy": string (optional) - ZMQ socks proxy host name and port (example: proxy1.intranet.company.com:1080)
+///     "max_in_flight_requests": int (optional) - maximum number of requests processed by the pool at once,
+///         the next requests wait in the pool worker queue until the previous ones complete (0 - unbounded by default)
+///     "max_sockets": int (optional) - maximum number of node sockets opened by the pool handle.
+///         Every pool connection can open a socket to each node, so when the limit doesn't allow a new connection
+///         the requests share the most recent one. At least one connection is always opened. (0 - unbounded by default)
+///     "conn_count_per_node": int (optional) - number of sockets opened to each node for new requests.
+///         Requests are distributed over them round-robin, so they are pipelined rather than serialized on a single socket
+///         per node. Sockets still count against `max_sockets`. (1 by default)
+///     "reply_capture_size": int (optional) - number of the last requests recorded with raw node replies to them
+///         for debugging, see `indy_get_pool_reply_capture` (0 - disabled by default)
+///     "read_connections": int (optional) - number of connections to the pool, each with its own worker thread and node sockets.
+///         Read requests are distributed over the connections round-robin, other requests are sent by the first one.
+///         Additional connections are opened in background after the pool is opened, replies of them aren't captured. (1 by default)
+///     Note: pool worker thread is named `indy-pool-<config_name>`.
 /// }
 ///
 /// #Returns
@@ -97,17 +113,18 @@ pub extern fn indy_open_pool_ledger(command_handle: CommandHandle,
                                                          pool_handle: PoolHandle)>) -> ErrorCode {
     trace!("indy_open_pool_ledger: >>> config_name: {:?}, config: {:?}", config_name, config);
 
+    command_params!(config_name, config);
+
     check_useful_c_str!(config_name, ErrorCode::CommonInvalidParam2);
     check_useful_opt_validatable_json!(config, ErrorCode::CommonInvalidParam3, PoolOpenConfig);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     trace!("indy_open_pool_ledger: entities >>> config_name: {:?}, config: {:?}", config_name, config);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Pool(PoolCommand::Open(
+    let result = CommandExecutor::submit(Command::Pool(PoolCommand::Open(
             config_name,
             config,
-            Box::new(move |result| {
+            with_timeout(move |result| {
                 let (err, pool_handle) = prepare_result_1!(result, INVALID_POOL_HANDLE);
                 trace!("indy_open_pool_ledger: pool_handle: {:?}", pool_handle);
                 cb(command_handle, err, pool_handle)
@@ -139,14 +156,15 @@ pub extern fn indy_refresh_pool_ledger(command_handle: CommandHandle,
                                                             err: ErrorCode)>) -> ErrorCode {
     trace!("indy_refresh_pool_ledger: >>> handle: {:?}", handle);
 
+    command_params!(handle);
+
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
 
     trace!("indy_refresh_pool_ledger: entities >>> handle: {:?}", handle);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Pool(PoolCommand::Refresh(
+    let result = CommandExecutor::submit(Command::Pool(PoolCommand::Refresh(
             handle,
-            Box::new(move |result| {
+            with_timeout(move |result| {
                 let err = prepare_result!(result);
                 trace!("indy_refresh_pool_ledger:");
                 cb(command_handle, err)
@@ -175,12 +193,13 @@ pub extern fn indy_list_pools(command_handle: CommandHandle,
                                                    pools: *const c_char)>) -> ErrorCode {
     trace!("indy_list_pools: >>>");
 
+    command_params!();
+
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam2);
 
     trace!("indy_list_pools: entities >>>");
 
-    let result = CommandExecutor::instance()
-        .send(Command::Pool(PoolCommand::List(boxed_callback_string!("indy_list_pools", cb, command_handle))));
+    let result = CommandExecutor::submit(Command::Pool(PoolCommand::List(with_timeout(boxed_callback_string!("indy_list_pools", cb, command_handle)))));
 
     let res = prepare_result!(result);
 
@@ -207,14 +226,15 @@ pub extern fn indy_close_pool_ledger(command_handle: CommandHandle,
                                                           err: ErrorCode)>) -> ErrorCode {
     trace!("indy_close_pool_ledger: >>> handle: {:?}", handle);
 
+    command_params!(handle);
+
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
 
     trace!("indy_close_pool_ledger: entities >>> handle: {:?}", handle);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Pool(PoolCommand::Close(
+    let result = CommandExecutor::submit(Command::Pool(PoolCommand::Close(
             handle,
-            Box::new(move |result| {
+            with_timeout(move |result| {
                 let err = prepare_result!(result);
                 trace!("indy_close_pool_ledger:");
                 cb(command_handle, err)
@@ -246,15 +266,16 @@ pub extern fn indy_delete_pool_ledger_config(command_handle: CommandHandle,
                                                                   err: ErrorCode)>) -> ErrorCode {
     trace!("indy_delete_pool_ledger_config: >>> config_name: {:?}", config_name);
 
+    command_params!(config_name);
+
     check_useful_c_str!(config_name, ErrorCode::CommonInvalidParam2);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
 
     trace!("indy_delete_pool_ledger_config: entities >>> config_name: {:?}", config_name);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Pool(PoolCommand::Delete(
+    let result = CommandExecutor::submit(Command::Pool(PoolCommand::Delete(
             config_name,
-            Box::new(move |result| {
+            with_timeout(move |result| {
                 let err = prepare_result!(result);
                 trace!("indy_delete_pool_ledger_config:");
                 cb(command_handle, err)
@@ -292,15 +313,16 @@ pub extern fn indy_set_protocol_version(command_handle: CommandHandle,
                                                              err: ErrorCode)>) -> ErrorCode {
     trace!("indy_set_protocol_version: >>> protocol_version: {:?}", protocol_version);
 
+    command_params!(protocol_version);
+
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
 
     trace!("indy_set_protocol_version: entities >>> protocol_version: {:?}", protocol_version);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Pool(
+    let result = CommandExecutor::submit(Command::Pool(
             PoolCommand::SetProtocolVersion(
             protocol_version,
-            Box::new(move |result| {
+            with_timeout(move |result| {
                 let err = prepare_result!(result);
                 trace!("indy_set_protocol_version:");
                 cb(command_handle, err)
@@ -313,3 +335,77 @@ pub extern fn indy_set_protocol_version(command_handle: CommandHandle,
 
     res
 }
+
+/// Returns the last requests sent by the pool with raw node replies to them.
+///
+/// Requests are recorded only if the pool is opened with `reply_capture_size` option.
+/// The capture is dropped when the pool is closed.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// handle: pool handle returned by indy_open_pool_ledger.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Captured requests json ordered from the oldest to the newest:
+/// [{
+///     "reqId": string - request id,
+///     "request": string - raw request,
+///     "size": int - request size in bytes,
+///     "timestamp": int - time the request was received by the pool worker (milliseconds since epoch),
+///     "replies": [{
+///         "node": string - alias of the replied node,
+///         "op": string - (optional) type of the reply (REQACK, REQNACK, REPLY, REJECT),
+///         "reply": string - raw reply,
+///         "size": int - reply size in bytes,
+///         "timestamp": int - time the reply was received (milliseconds since epoch)
+///     }]
+/// }]
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_get_pool_reply_capture(command_handle: CommandHandle,
+                                          handle: PoolHandle,
+                                          cb: Option<extern fn(command_handle_: CommandHandle,
+                                                               err: ErrorCode,
+                                                               captured_requests_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_get_pool_reply_capture: >>> handle: {:?}", handle);
+
+    command_params!(handle);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_get_pool_reply_capture: entities >>> handle: {:?}", handle);
+
+    let result = CommandExecutor::submit(Command::Pool(PoolCommand::GetReplyCapture(
+            handle,
+            with_timeout(boxed_callback_string!("indy_get_pool_reply_capture", cb, command_handle))
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_get_pool_reply_capture: <<< res: {:?}", res);
+
+    res
+}
+
+context_api! {
+    indy_create_pool_ledger_config_ctx => indy_create_pool_ledger_config(config_name: *const c_char, config: *const c_char)
+        -> cb(), CommonInvalidParam4;
+    indy_open_pool_ledger_ctx => indy_open_pool_ledger(config_name: *const c_char, config: *const c_char)
+        -> cb(pool_handle: PoolHandle), CommonInvalidParam4;
+    indy_refresh_pool_ledger_ctx => indy_refresh_pool_ledger(handle: PoolHandle)
+        -> cb(), CommonInvalidParam3;
+    indy_list_pools_ctx => indy_list_pools()
+        -> cb(pools: *const c_char), CommonInvalidParam2;
+    indy_close_pool_ledger_ctx => indy_close_pool_ledger(handle: PoolHandle)
+        -> cb(), CommonInvalidParam3;
+    indy_delete_pool_ledger_config_ctx => indy_delete_pool_ledger_config(config_name: *const c_char)
+        -> cb(), CommonInvalidParam3;
+    indy_set_protocol_version_ctx => indy_set_protocol_version(protocol_version: usize)
+        -> cb(), CommonInvalidParam3;
+    indy_get_pool_reply_capture_ctx => indy_get_pool_reply_capture(handle: PoolHandle)
+        -> cb(captured_requests_json: *const c_char), CommonInvalidParam3;
+}
@@ -1,8 +1,9 @@
 
 use indy_api_types::{ErrorCode, CommandHandle, WalletHandle, INVALID_WALLET_HANDLE};
 use crate::commands::{Command, CommandExecutor};
+use crate::commands::timeout::with_timeout;
 use crate::commands::wallet::WalletCommand;
-use indy_api_types::domain::wallet::{Config, Credentials, ExportConfig, KeyConfig};
+use indy_api_types::domain::wallet::{Config, Credentials, ExportBufferConfig, ExportConfig, KeyConfig};
 use indy_api_types::wallet::*;
 use indy_api_types::errors::prelude::*;
 use indy_utils::ctypes;
@@ -10,6 +11,7 @@ use indy_api_types::validation::Validatable;
 
 use serde_json;
 use libc::c_char;
+use std::ptr;
 
 
 /// Register custom wallet storage implementation.
@@ -75,6 +77,8 @@ pub extern fn indy_register_wallet_storage(command_handle: CommandHandle,
            command_handle, type_, cb); // TODO: Log all params
 
     check_useful_c_str!(type_, ErrorCode::CommonInvalidParam2);
+
+    command_params!(type_);
     check_useful_c_callback!(create, ErrorCode::CommonInvalidParam3);
     check_useful_c_callback!(open, ErrorCode::CommonInvalidParam4);
     check_useful_c_callback!(close, ErrorCode::CommonInvalidParam5);
@@ -103,8 +107,7 @@ pub extern fn indy_register_wallet_storage(command_handle: CommandHandle,
 
     trace!("indy_register_wallet_type: params type_: {:?}", type_);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Wallet(
+    let result = CommandExecutor::submit(Command::Wallet(
             WalletCommand::RegisterWalletType(
                 type_,
                 create,
@@ -131,7 +134,7 @@ pub extern fn indy_register_wallet_storage(command_handle: CommandHandle,
                 get_search_total_count,
                 fetch_search_next_record,
                 free_search,
-                Box::new(move |result| {
+                with_timeout(move |result| {
                     let err = prepare_result!(result);
                     trace!("indy_register_wallet_type: cb command_handle: {:?}, err: {:?}", command_handle, err);
                     cb(command_handle, err)
@@ -143,6 +146,99 @@ pub extern fn indy_register_wallet_storage(command_handle: CommandHandle,
     res
 }
 
+/// Unregister custom wallet storage implementation, so its library can be unloaded or registered again.
+/// Storage type can't be unregistered while wallets that use it are open.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// type_: Storage type name.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error code
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_unregister_wallet_storage(command_handle: CommandHandle,
+                                             type_: *const c_char,
+                                             cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                  err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_unregister_wallet_storage: >>> command_handle: {:?}, type_: {:?}", command_handle, type_);
+
+    command_params!(type_);
+
+    check_useful_c_str!(type_, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_unregister_wallet_storage: params type_: {:?}", type_);
+
+    let result = CommandExecutor::submit(Command::Wallet(
+            WalletCommand::UnregisterWalletType(
+                type_,
+                with_timeout(move |result| {
+                    let err = prepare_result!(result);
+                    trace!("indy_unregister_wallet_storage: cb command_handle: {:?}, err: {:?}", command_handle, err);
+                    cb(command_handle, err)
+                })
+            )));
+
+    let res = prepare_result!(result);
+    trace!("indy_unregister_wallet_storage: <<< res: {:?}", res);
+    res
+}
+
+/// Sets extended WQL operators supported by custom wallet storage implementation.
+/// Queries using extended operators (any operator starting with `$` that isn't a part of WQL,
+/// for example `$contains` or `$match`) are rejected unless wallet storage supports them.
+/// Supported operators are passed to storage search as is and can be used with unencrypted tags only.
+/// Applies to wallets opened after the call.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// type_: Storage type name.
+/// extensions_json: JSON array of operator names, for example ["$contains", "$match"].
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error code
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_set_wallet_storage_query_extensions(command_handle: CommandHandle,
+                                                       type_: *const c_char,
+                                                       extensions_json: *const c_char,
+                                                       cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                            err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_set_wallet_storage_query_extensions: >>> command_handle: {:?}, type_: {:?}, extensions_json: {:?}", command_handle, type_, extensions_json);
+
+    command_params!(type_, extensions_json);
+
+    check_useful_c_str!(type_, ErrorCode::CommonInvalidParam2);
+    check_useful_json!(extensions_json, ErrorCode::CommonInvalidParam3, Vec<String>);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_set_wallet_storage_query_extensions: params type_: {:?}, extensions_json: {:?}", type_, extensions_json);
+
+    let result = CommandExecutor::submit(Command::Wallet(
+            WalletCommand::SetStorageQueryExtensions(
+                type_,
+                extensions_json,
+                with_timeout(move |result| {
+                    let err = prepare_result!(result);
+                    trace!("indy_set_wallet_storage_query_extensions: cb command_handle: {:?}, err: {:?}", command_handle, err);
+                    cb(command_handle, err)
+                })
+            )));
+
+    let res = prepare_result!(result);
+    trace!("indy_set_wallet_storage_query_extensions: <<< res: {:?}", res);
+    res
+}
+
 /// Create a new secure wallet.
 ///
 /// #Params
@@ -191,6 +287,8 @@ pub extern fn indy_create_wallet(command_handle: CommandHandle,
     trace!("indy_create_wallet: >>> command_handle: {:?}, config: {:?}, credentials: {:?}, cb: {:?}",
            command_handle, config, credentials, cb);
 
+    command_params!(config, credentials);
+
     check_useful_validatable_json!(config, ErrorCode::CommonInvalidParam2, Config);
     check_useful_json!(credentials, ErrorCode::CommonInvalidParam3, Credentials);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
@@ -198,11 +296,10 @@ pub extern fn indy_create_wallet(command_handle: CommandHandle,
     trace!("indy_create_wallet: params config: {:?}, credentials: {:?}",
            config, secret!(&credentials));
 
-    let result = CommandExecutor::instance()
-        .send(Command::Wallet(WalletCommand::Create(
+    let result = CommandExecutor::submit(Command::Wallet(WalletCommand::Create(
             config,
             credentials,
-            Box::new(move |result| {
+            with_timeout(move |result| {
                 let err = prepare_result!(result);
                 trace!("indy_create_wallet: cb command_handle: {:?}, err: {:?}", command_handle, err);
                 cb(command_handle, err)
@@ -233,6 +330,12 @@ pub extern fn indy_create_wallet(command_handle: CommandHandle,
 ///              "path": optional<string>, Path to the directory with wallet files.
 ///                      Defaults to $HOME/.indy_client/wallet.
 ///                      Wallet will be stored in the file {path}/{id}/sqlite.db
+///              "journal_mode": optional<string>, SQLite journal mode: "delete", "truncate", "persist", "memory", "wal" or "off".
+///                              Defaults to "wal".
+///              "synchronous": optional<string>, SQLite synchronous level: "off", "normal", "full" or "extra".
+///                             Defaults to "full" for "wal" journal mode and to SQLite default otherwise.
+///              "cache_size": optional<int>, SQLite page cache size: number of pages if positive, size in KiB if negative.
+///              "busy_timeout": optional<int>, Time in milliseconds to wait for locked database.
 ///           }
 ///
 ///   }
@@ -273,6 +376,8 @@ pub extern fn indy_open_wallet(command_handle: CommandHandle,
     trace!("indy_open_wallet: >>> command_handle: {:?}, config: {:?}, credentials: {:?}, cb: {:?}",
            command_handle, config, credentials, cb);
 
+    command_params!(config, credentials);
+
     check_useful_validatable_json!(config, ErrorCode::CommonInvalidParam2, Config);
     check_useful_json!(credentials, ErrorCode::CommonInvalidParam3, Credentials);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
@@ -280,11 +385,10 @@ pub extern fn indy_open_wallet(command_handle: CommandHandle,
     trace!("indy_open_wallet: params config: {:?}, credentials: {:?}",
            config, secret!(&credentials));
 
-    let result = CommandExecutor::instance()
-        .send(Command::Wallet(WalletCommand::Open(
+    let result = CommandExecutor::submit(Command::Wallet(WalletCommand::Open(
             config,
             credentials,
-            Box::new(move |result| {
+            with_timeout(move |result| {
                 let (err, handle) = prepare_result_1!(result, INVALID_WALLET_HANDLE);
                 trace!("indy_open_wallet: cb command_handle: {:?} err: {:?}, handle: {:?}",
                        command_handle, err, handle);
@@ -327,16 +431,17 @@ pub extern fn indy_export_wallet(command_handle: CommandHandle,
                                                       err: ErrorCode)>) -> ErrorCode {
     trace!("indy_export_wallet: >>> wallet_handle: {:?}, export_config: {:?}", wallet_handle, export_config);
 
+    command_params!(wallet_handle, export_config);
+
     check_useful_json!(export_config, ErrorCode::CommonInvalidParam3, ExportConfig);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     trace!("indy_export_wallet: params wallet_handle: {:?}, export_config: {:?}", wallet_handle, secret!(&export_config));
 
-    let result = CommandExecutor::instance()
-        .send(Command::Wallet(WalletCommand::Export(
+    let result = CommandExecutor::submit(Command::Wallet(WalletCommand::Export(
             wallet_handle,
             export_config,
-            Box::new(move |result| {
+            with_timeout(move |result| {
                 let err = prepare_result!(result);
                 trace!("indy_export_wallet: cb command_handle: {:?} err: {:?}", command_handle, err);
                 cb(command_handle, err)
@@ -348,6 +453,118 @@ pub extern fn indy_export_wallet(command_handle: CommandHandle,
     res
 }
 
+/// Exports opened wallet to a buffer owned by the application instead of a file.
+///
+/// #Params:
+/// wallet_handle: wallet handle returned by indy_open_wallet
+/// export_config: JSON containing settings for input operation.
+///   {
+///     "key": <string>, Key or passphrase used for wallet export key derivation.
+///     "key_derivation_method": optional<string> Algorithm to use for wallet export key derivation
+///                              (see indy_export_wallet).
+///   }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Exported wallet content in the same format as the file written by indy_export_wallet.
+/// The buffer must be released with `indy_free_buffer`. On error NULL buffer is passed.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_export_wallet_to_buffer(command_handle: CommandHandle,
+                                           wallet_handle: WalletHandle,
+                                           export_config: *const c_char,
+                                           cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                err: ErrorCode,
+                                                                data: *mut u8,
+                                                                data_len: u32)>) -> ErrorCode {
+    trace!("indy_export_wallet_to_buffer: >>> wallet_handle: {:?}, export_config: {:?}", wallet_handle, export_config);
+
+    command_params!(wallet_handle, export_config);
+
+    check_useful_json!(export_config, ErrorCode::CommonInvalidParam3, ExportBufferConfig);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_export_wallet_to_buffer: params wallet_handle: {:?}, export_config: {:?}", wallet_handle, secret!(&export_config));
+
+    let result = CommandExecutor::submit(Command::Wallet(WalletCommand::ExportToBuffer(
+            wallet_handle,
+            export_config,
+            with_timeout(move |result| {
+                let result = result.map(Some);
+                let (err, data) = prepare_result_1!(result, None);
+                trace!("indy_export_wallet_to_buffer: cb command_handle: {:?} err: {:?}", command_handle, err);
+                let (data, data_len) = data.map(ctypes::vec_into_owned_pointer).unwrap_or((ptr::null_mut(), 0));
+                cb(command_handle, err, data, data_len)
+            })
+        )));
+
+    let res = prepare_result!(result);
+    trace!("indy_export_wallet_to_buffer: <<< res: {:?}", res);
+    res
+}
+
+/// Streams export of opened wallet to the application instead of a file.
+///
+/// Exported content is passed to `data_cb` by chunks as soon as they are produced,
+/// so the whole export doesn't have to fit into memory at once.
+/// `cb` is called once the export is completed or failed.
+///
+/// #Params:
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet handle returned by indy_open_wallet
+/// export_config: JSON containing settings for input operation (see indy_export_wallet_to_buffer).
+/// data_cb: Callback that takes next chunk of exported wallet content.
+///     Concatenated chunks have the same format as the file written by indy_export_wallet.
+///     Pointer is valid only during the callback.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error code
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_stream_export_wallet(command_handle: CommandHandle,
+                                        wallet_handle: WalletHandle,
+                                        export_config: *const c_char,
+                                        data_cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                  data: *const u8,
+                                                                  data_len: u32)>,
+                                        cb: Option<extern fn(command_handle_: CommandHandle,
+                                                             err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_stream_export_wallet: >>> wallet_handle: {:?}, export_config: {:?}", wallet_handle, export_config);
+
+    command_params!(wallet_handle, export_config);
+
+    check_useful_json!(export_config, ErrorCode::CommonInvalidParam3, ExportBufferConfig);
+    check_useful_c_callback!(data_cb, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_stream_export_wallet: params wallet_handle: {:?}, export_config: {:?}", wallet_handle, secret!(&export_config));
+
+    let result = CommandExecutor::submit(Command::Wallet(WalletCommand::StreamExport(
+            wallet_handle,
+            export_config,
+            Box::new(move |data| {
+                trace!("indy_stream_export_wallet: data_len: {:?}", data.len());
+                data_cb(command_handle, data.as_ptr(), data.len() as u32)
+            }),
+            with_timeout(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_stream_export_wallet: cb command_handle: {:?} err: {:?}", command_handle, err);
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+    trace!("indy_stream_export_wallet: <<< res: {:?}", res);
+    res
+}
+
 
 /// Creates a new secure wallet and then imports its content
 /// according to fields provided in import_config
@@ -405,6 +622,8 @@ pub extern fn indy_import_wallet(command_handle: CommandHandle,
     trace!("indy_import_wallet: >>> command_handle: {:?}, config: {:?}, credentials: {:?}, import_config: {:?}, cb: {:?}",
            command_handle, config, credentials, import_config, cb);
 
+    command_params!(config, credentials, import_config);
+
     check_useful_validatable_json!(config, ErrorCode::CommonInvalidParam2, Config);
     check_useful_json!(credentials, ErrorCode::CommonInvalidParam3, Credentials);
     check_useful_json!(import_config, ErrorCode::CommonInvalidParam4, ExportConfig);
@@ -413,12 +632,11 @@ pub extern fn indy_import_wallet(command_handle: CommandHandle,
     trace!("indy_import_wallet: params config: {:?}, credentials: {:?}, import_config: {:?}",
            config, secret!(&credentials), secret!(&import_config));
 
-    let result = CommandExecutor::instance()
-        .send(Command::Wallet(WalletCommand::Import(
+    let result = CommandExecutor::submit(Command::Wallet(WalletCommand::Import(
             config,
             credentials,
             import_config,
-            Box::new(move |result| {
+            with_timeout(move |result| {
                 let err = prepare_result!(result);
                 trace!("indy_import_wallet: cb command_handle: {:?}, err: {:?}", command_handle, err);
                 cb(command_handle, err)
@@ -450,14 +668,15 @@ pub extern fn indy_close_wallet(command_handle: CommandHandle,
     trace!("indy_close_wallet: >>> command_handle: {:?}, wallet_handle: {:?}, cb: {:?}",
            command_handle, wallet_handle, cb);
 
+    command_params!(wallet_handle);
+
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
 
     trace!("indy_close_wallet: params wallet_handle: {:?}", wallet_handle);
 
-    let result = CommandExecutor::instance()
-        .send(Command::Wallet(WalletCommand::Close(
+    let result = CommandExecutor::submit(Command::Wallet(WalletCommand::Close(
             wallet_handle,
-            Box::new(move |result| {
+            with_timeout(move |result| {
                 let err = prepare_result!(result);
                 trace!("indy_close_wallet: cb command_handle: {:?}, err: {:?}", command_handle, err);
                 cb(command_handle, err)
@@ -517,17 +736,18 @@ pub extern fn indy_delete_wallet(command_handle: CommandHandle,
     trace!("indy_delete_wallet: >>> command_handle: {:?}, config: {:?}, credentials: {:?}, cb: {:?}",
            command_handle, config, credentials, cb);
 
+    command_params!(config, credentials);
+
     check_useful_validatable_json!(config, ErrorCode::CommonInvalidParam2, Config);
     check_useful_json!(credentials, ErrorCode::CommonInvalidParam3, Credentials);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
     trace!("indy_delete_wallet: params config: {:?}, credentials: {:?}", config, secret!(&credentials));
 
-    let result = CommandExecutor::instance()
-        .send(Command::Wallet(WalletCommand::Delete(
+    let result = CommandExecutor::submit(Command::Wallet(WalletCommand::Delete(
             config,
             credentials,
-            Box::new(move |result| {
+            with_timeout(move |result| {
                 let err = prepare_result!(result);
                 trace!("indy_delete_wallet: cb command_handle: {:?}, err: {:?}", command_handle, err);
                 cb(command_handle, err)
@@ -565,18 +785,50 @@ pub extern fn indy_generate_wallet_key(command_handle: CommandHandle,
     trace!("indy_generate_wallet_key: >>> command_handle: {:?}, config: {:?}, cb: {:?}",
            command_handle, config, cb);
 
+    command_params!(config);
+
     check_useful_opt_json!(config, ErrorCode::CommonInvalidParam2, KeyConfig);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
 
     trace!("indy_generate_wallet_key: params config: {:?}", secret!(config.as_ref()));
 
-    let result = CommandExecutor::instance()
-        .send(Command::Wallet(WalletCommand::GenerateKey(
+    let result = CommandExecutor::submit(Command::Wallet(WalletCommand::GenerateKey(
             config,
-            boxed_callback_string!("indy_generate_wallet_key", cb, command_handle)
+            with_timeout(boxed_callback_string!("indy_generate_wallet_key", cb, command_handle))
         )));
 
     let res = prepare_result!(result);
     trace!("indy_generate_wallet_key: <<< res: {:?}", res);
     res
 }
+
+context_api! {
+    indy_register_wallet_storage_ctx => indy_register_wallet_storage(type_: *const c_char, create: Option<WalletCreate>, open: Option<WalletOpen>, close: Option<WalletClose>, delete: Option<WalletDelete>, add_record: Option<WalletAddRecord>, update_record_value: Option<WalletUpdateRecordValue>, update_record_tags: Option<WalletUpdateRecordTags>, add_record_tags: Option<WalletAddRecordTags>, delete_record_tags: Option<WalletDeleteRecordTags>, delete_record: Option<WalletDeleteRecord>, get_record: Option<WalletGetRecord>, get_record_id: Option<WalletGetRecordId>, get_record_type: Option<WalletGetRecordType>, get_record_value: Option<WalletGetRecordValue>, get_record_tags: Option<WalletGetRecordTags>, free_record: Option<WalletFreeRecord>, get_storage_metadata: Option<WalletGetStorageMetadata>, set_storage_metadata: Option<WalletSetStorageMetadata>, free_storage_metadata: Option<WalletFreeStorageMetadata>, search_records: Option<WalletSearchRecords>, search_all_records: Option<WalletSearchAllRecords>, get_search_total_count: Option<WalletGetSearchTotalCount>, fetch_search_next_record: Option<WalletFetchSearchNextRecord>, free_search: Option<WalletFreeSearch>)
+        -> cb(), CommonInvalidParam27;
+    indy_unregister_wallet_storage_ctx => indy_unregister_wallet_storage(type_: *const c_char)
+        -> cb(), CommonInvalidParam3;
+    indy_set_wallet_storage_query_extensions_ctx => indy_set_wallet_storage_query_extensions(type_: *const c_char, extensions_json: *const c_char)
+        -> cb(), CommonInvalidParam4;
+    indy_create_wallet_ctx => indy_create_wallet(config: *const c_char, credentials: *const c_char)
+        -> cb(), CommonInvalidParam4;
+    indy_open_wallet_ctx => indy_open_wallet(config: *const c_char, credentials: *const c_char)
+        -> cb(wallet_handle: WalletHandle), CommonInvalidParam4;
+    indy_export_wallet_ctx => indy_export_wallet(wallet_handle: WalletHandle, export_config: *const c_char)
+        -> cb(), CommonInvalidParam4;
+    indy_export_wallet_to_buffer_ctx => indy_export_wallet_to_buffer(wallet_handle: WalletHandle, export_config: *const c_char)
+        -> cb(data: *mut u8, data_len: u32), CommonInvalidParam4;
+    indy_import_wallet_ctx => indy_import_wallet(config: *const c_char, credentials: *const c_char, import_config: *const c_char)
+        -> cb(), CommonInvalidParam5;
+    indy_close_wallet_ctx => indy_close_wallet(wallet_handle: WalletHandle)
+        -> cb(), CommonInvalidParam3;
+    indy_delete_wallet_ctx => indy_delete_wallet(config: *const c_char, credentials: *const c_char)
+        -> cb(), CommonInvalidParam4;
+    indy_generate_wallet_key_ctx => indy_generate_wallet_key(config: *const c_char)
+        -> cb(key: *const c_char), CommonInvalidParam3;
+}
+
+context_data_api! {
+    indy_stream_export_wallet_ctx => indy_stream_export_wallet(wallet_handle: WalletHandle, export_config: *const c_char)
+        -> data_cb(data: *const u8, data_len: u32), CommonInvalidParam4,
+           cb(), CommonInvalidParam5;
+}
@@ -3,7 +3,6 @@ use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
 
 use ursa::cl::{
-    new_nonce,
     RevocationRegistryDelta as CryptoRevocationRegistryDelta,
     Witness,
 };
@@ -50,9 +49,10 @@ use indy_api_types::domain::wallet::Tags;
 use indy_api_types::errors::prelude::*;
 use crate::services::anoncreds::AnoncredsService;
 use crate::services::anoncreds::helpers::parse_cred_rev_id;
+use crate::services::anoncreds::test_mode::new_nonce;
 use crate::services::blob_storage::BlobStorageService;
 use crate::services::crypto::CryptoService;
-use crate::services::pool::PoolService;
+use crate::utils::audit;
 use indy_wallet::{RecordOptions, WalletService};
 
 use super::tails::{SDKTailsAccessor, store_tails_from_generator};
@@ -146,7 +146,6 @@ pub enum IssuerCommand {
 pub struct IssuerCommandExecutor {
     pub anoncreds_service: Rc<AnoncredsService>,
     pub blob_storage_service: Rc<BlobStorageService>,
-    pub pool_service: Rc<PoolService>,
     pub wallet_service: Rc<WalletService>,
     pub crypto_service: Rc<CryptoService>,
     pending_str_str_callbacks: RefCell<HashMap<CommandHandle, BoxedCallbackStringStringSend>>,
@@ -155,13 +154,11 @@ pub struct IssuerCommandExecutor {
 
 impl IssuerCommandExecutor {
     pub fn new(anoncreds_service: Rc<AnoncredsService>,
-               pool_service: Rc<PoolService>,
                blob_storage_service: Rc<BlobStorageService>,
                wallet_service: Rc<WalletService>,
                crypto_service: Rc<CryptoService>) -> IssuerCommandExecutor {
         IssuerCommandExecutor {
             anoncreds_service,
-            pool_service,
             blob_storage_service,
             wallet_service,
             crypto_service,
@@ -791,6 +788,8 @@ impl IssuerCommandExecutor {
 
         let cred_rev_id = rev_reg_info.map(|r_reg_info| r_reg_info.curr_id.to_string());
 
+        audit::record(audit::CREDENTIAL_ISSUED, wallet_handle, json!({"cred_def_id": cred_def_id, "rev_reg_id": rev_reg_id, "cred_rev_id": cred_rev_id}));
+
         debug!("new_credential <<< cred_json: {:?}, cred_rev_id: {:?}, rev_reg_delta_json: {:?}", secret!(&cred_json), secret!(&cred_rev_id), rev_reg_delta_json);
 
         Ok((cred_json, cred_rev_id, rev_reg_delta_json))
@@ -3,15 +3,18 @@ pub mod prover;
 pub mod verifier;
 mod tails;
 
+pub use self::tails::set_cache_size as set_tails_cache_size;
+
 use crate::commands::anoncreds::issuer::{IssuerCommand, IssuerCommandExecutor};
 use crate::commands::anoncreds::prover::{ProverCommand, ProverCommandExecutor};
 use crate::commands::anoncreds::verifier::{VerifierCommand, VerifierCommandExecutor};
 
 use crate::services::anoncreds::AnoncredsService;
 use crate::services::blob_storage::BlobStorageService;
-use crate::services::pool::PoolService;
 use indy_wallet::WalletService;
 use crate::services::crypto::CryptoService;
+#[cfg(feature = "ledger")]
+use crate::services::pool::PoolService;
 use crate::services::anoncreds::helpers::to_unqualified;
 
 use indy_api_types::errors::prelude::*;
@@ -36,15 +39,15 @@ pub struct AnoncredsCommandExecutor {
 impl AnoncredsCommandExecutor {
     pub fn new(anoncreds_service: Rc<AnoncredsService>,
                blob_storage_service: Rc<BlobStorageService>,
-               pool_service: Rc<PoolService>,
                wallet_service: Rc<WalletService>,
-               crypto_service: Rc<CryptoService>) -> AnoncredsCommandExecutor {
+               crypto_service: Rc<CryptoService>,
+               #[cfg(feature = "ledger")] pool_service: Rc<PoolService>) -> AnoncredsCommandExecutor {
         AnoncredsCommandExecutor {
             issuer_command_cxecutor: IssuerCommandExecutor::new(
-                anoncreds_service.clone(), pool_service.clone(),
-                blob_storage_service.clone(), wallet_service.clone(), crypto_service.clone()),
+                anoncreds_service.clone(), blob_storage_service.clone(), wallet_service.clone(), crypto_service.clone()),
             prover_command_cxecutor: ProverCommandExecutor::new(
-                anoncreds_service.clone(), wallet_service.clone(), crypto_service.clone(), blob_storage_service.clone()),
+                anoncreds_service.clone(), wallet_service.clone(), crypto_service.clone(), blob_storage_service.clone(),
+                #[cfg(feature = "ledger")] pool_service),
             verifier_command_cxecutor: VerifierCommandExecutor::new(
                 anoncreds_service.clone()),
         }
@@ -70,4 +73,8 @@ impl AnoncredsCommandExecutor {
             }
         };
     }
+
+    pub fn close_all_searches(&self) {
+        self.prover_command_cxecutor.close_all_searches();
+    }
 }
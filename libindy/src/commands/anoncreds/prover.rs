@@ -1,8 +1,12 @@
 use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
+#[cfg(feature = "ledger")]
+use std::thread;
+#[cfg(feature = "ledger")]
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-use ursa::cl::{new_nonce, RevocationRegistry, Witness};
+use ursa::cl::{RevocationRegistry, Witness};
 
 use serde_json::Value;
 
@@ -16,22 +20,43 @@ use crate::domain::anoncreds::master_secret::MasterSecret;
 use crate::domain::anoncreds::proof_request::{NonRevocedInterval, PredicateInfo, ProofRequest, ProofRequestExtraQuery};
 use crate::domain::anoncreds::requested_credential::RequestedCredentials;
 use crate::domain::anoncreds::revocation_registry_definition::{RevocationRegistryDefinition, RevocationRegistryDefinitionV1};
+#[cfg(feature = "ledger")]
+use crate::domain::anoncreds::revocation_registry_definition::RevocationRegistryId;
 use crate::domain::anoncreds::revocation_registry_delta::{RevocationRegistryDelta, RevocationRegistryDeltaV1};
 use crate::domain::anoncreds::revocation_state::{RevocationState, RevocationStates};
+#[cfg(feature = "ledger")]
+use crate::domain::anoncreds::revocation_state::{CredentialRevocationState, RevocationStateUpdaterConfig, DEFAULT_REVOCATION_STATE_UPDATE_INTERVAL};
 use crate::domain::anoncreds::schema::{schemas_map_to_schemas_v1_map, SchemaV1, SchemaId, Schemas};
+use crate::domain::anoncreds::w3c_credential::W3CCredential;
 use crate::domain::crypto::did::DidValue;
 use indy_api_types::errors::prelude::*;
 use crate::services::anoncreds::AnoncredsService;
 use crate::services::anoncreds::helpers::{parse_cred_rev_id, get_non_revoc_interval};
+use crate::services::anoncreds::prover::Prover;
+use crate::services::anoncreds::test_mode::new_nonce;
 use crate::services::blob_storage::BlobStorageService;
 use crate::services::crypto::CryptoService;
+#[cfg(feature = "ledger")]
+use crate::services::pool::PoolService;
 use indy_wallet::{RecordOptions, SearchOptions, WalletRecord, WalletSearch, WalletService};
 use indy_utils::{next_search_handle};
+#[cfg(feature = "ledger")]
+use indy_utils::next_command_handle;
 use crate::utils::wql::Query;
+use crate::utils::handles;
 
 use super::tails::SDKTailsAccessor;
 use indy_api_types::{WalletHandle, SearchHandle};
+use indy_api_types::domain::wallet::Tags;
+#[cfg(feature = "ledger")]
+use indy_api_types::{IndyHandle, PoolHandle};
 use crate::commands::BoxedCallbackStringStringSend;
+#[cfg(feature = "ledger")]
+use crate::commands::{Command, CommandExecutor};
+#[cfg(feature = "ledger")]
+use crate::commands::anoncreds::AnoncredsCommand;
+#[cfg(feature = "ledger")]
+use crate::commands::ledger::LedgerCommand;
 
 pub enum ProverCommand {
     CreateMasterSecret(
@@ -45,6 +70,10 @@ pub enum ProverCommand {
         CredentialDefinition, // credential def
         String, // master secret name
         BoxedCallbackStringStringSend),
+    ParseCredentialOffer(
+        CredentialOffer, // credential offer
+        CredentialDefinition, // credential def
+        Box<dyn Fn(IndyResult<String>) + Send>),
     SetCredentialAttrTagPolicy(
         WalletHandle,
         CredentialDefinitionId, // credential definition id
@@ -62,6 +91,15 @@ pub enum ProverCommand {
         Credential, // credentials
         CredentialDefinition, // credential definition
         Option<RevocationRegistryDefinition>, // revocation registry definition
+        Option<Tags>, // custom credential tags
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    ImportW3CCredential(
+        WalletHandle,
+        Option<String>, // credential id
+        CredentialRequestMetadata, // credential request metadata
+        W3CCredential, // W3C credential
+        CredentialDefinition, // credential definition
+        Option<RevocationRegistryDefinition>, // revocation registry definition
         Box<dyn Fn(IndyResult<String>) + Send>),
     GetCredentials(
         WalletHandle,
@@ -126,7 +164,35 @@ pub enum ProverCommand {
         RevocationRegistryDelta, // revocation registry delta
         u64, //timestamp
         String, //credential revocation id
-        Box<dyn Fn(IndyResult<String>) + Send>)
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    #[cfg(feature = "ledger")]
+    StartRevocationStateUpdater(
+        WalletHandle,
+        PoolHandle,
+        i32, // blob storage reader handle
+        RevocationStateUpdaterConfig, // updater config
+        Box<dyn Fn(IndyResult<IndyHandle>) + Send>),
+    #[cfg(feature = "ledger")]
+    StopRevocationStateUpdater(
+        IndyHandle, // updater handle
+        Box<dyn Fn(IndyResult<()>) + Send>),
+    #[cfg(feature = "ledger")]
+    GetUpdatedRevocationStates(
+        IndyHandle, // updater handle
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    #[cfg(feature = "ledger")]
+    UpdateRevocationStates(
+        IndyHandle), // updater handle
+    #[cfg(feature = "ledger")]
+    UpdateRevocationStatesDefAck(
+        IndyHandle, // updater handle
+        RevocationRegistryId,
+        IndyResult<(String, String)>), // revocation registry definition
+    #[cfg(feature = "ledger")]
+    UpdateRevocationStatesDeltaAck(
+        IndyHandle, // updater handle
+        RevocationRegistryId,
+        IndyResult<(String, String, u64)>), // revocation registry delta
 }
 
 struct SearchForProofRequest {
@@ -147,27 +213,55 @@ impl SearchForProofRequest {
     }
 }
 
+#[cfg(feature = "ledger")]
+struct RevocationStateUpdater {
+    pool_handle: PoolHandle,
+    blob_storage_reader_handle: i32,
+    update_interval: u64,
+    registries: HashMap<RevocationRegistryId, UpdatedRevocationRegistry>,
+    // Count of registries not updated yet in the current round
+    pending: usize,
+}
+
+#[cfg(feature = "ledger")]
+struct UpdatedRevocationRegistry {
+    rev_reg_def: Option<RevocationRegistryDefinitionV1>,
+    // credential id -> (credential revocation id, revocation state)
+    credentials: HashMap<String, (String, Option<RevocationState>)>,
+    // Timestamp of the registry delta revocation states are computed for
+    timestamp: Option<u64>,
+}
+
 pub struct ProverCommandExecutor {
     anoncreds_service: Rc<AnoncredsService>,
     wallet_service: Rc<WalletService>,
     crypto_service: Rc<CryptoService>,
     blob_storage_service: Rc<BlobStorageService>,
+    #[cfg(feature = "ledger")]
+    pool_service: Rc<PoolService>,
     searches: RefCell<HashMap<SearchHandle, Box<WalletSearch>>>,
     searches_for_proof_requests: RefCell<HashMap<SearchHandle, Box<HashMap<String, SearchForProofRequest>>>>,
+    #[cfg(feature = "ledger")]
+    revocation_state_updaters: RefCell<HashMap<IndyHandle, RevocationStateUpdater>>,
 }
 
 impl ProverCommandExecutor {
     pub fn new(anoncreds_service: Rc<AnoncredsService>,
                wallet_service: Rc<WalletService>,
                crypto_service: Rc<CryptoService>,
-               blob_storage_service: Rc<BlobStorageService>) -> ProverCommandExecutor {
+               blob_storage_service: Rc<BlobStorageService>,
+               #[cfg(feature = "ledger")] pool_service: Rc<PoolService>) -> ProverCommandExecutor {
         ProverCommandExecutor {
             anoncreds_service,
             wallet_service,
             crypto_service,
             blob_storage_service,
+            #[cfg(feature = "ledger")]
+            pool_service,
             searches: RefCell::new(HashMap::new()),
             searches_for_proof_requests: RefCell::new(HashMap::new()),
+            #[cfg(feature = "ledger")]
+            revocation_state_updaters: RefCell::new(HashMap::new()),
         }
     }
 
@@ -183,6 +277,10 @@ impl ProverCommandExecutor {
                 cb(self.create_credential_request(wallet_handle, &prover_did, &credential_offer,
                                                   &CredentialDefinitionV1::from(credential_def), &master_secret_name));
             }
+            ProverCommand::ParseCredentialOffer(credential_offer, credential_def, cb) => {
+                debug!(target: "prover_command_executor", "ParseCredentialOffer command received");
+                cb(self.parse_credential_offer(&credential_offer, &CredentialDefinitionV1::from(credential_def)));
+            }
             ProverCommand::SetCredentialAttrTagPolicy(wallet_handle, cred_def_id, catpol, retroactive, cb) => {
                 debug!(target: "prover_command_executor", "SetCredentialAttrTagPolicy command received");
                 cb(self.set_credential_attr_tag_policy(wallet_handle, &cred_def_id, catpol.as_ref(), retroactive));
@@ -191,12 +289,20 @@ impl ProverCommandExecutor {
                 debug!(target: "prover_command_executor", "GetCredentialAttrTagPolicy command received");
                 cb(self.get_credential_attr_tag_policy(wallet_handle, &cred_def_id));
             }
-            ProverCommand::StoreCredential(wallet_handle, cred_id, cred_req_metadata, mut cred, cred_def, rev_reg_def, cb) => {
+            ProverCommand::StoreCredential(wallet_handle, cred_id, cred_req_metadata, mut cred, cred_def, rev_reg_def, tags, cb) => {
                 debug!(target: "prover_command_executor", "StoreCredential command received");
                 cb(self.store_credential(wallet_handle, cred_id.as_ref().map(String::as_str),
                                          &cred_req_metadata, &mut cred,
                                          &CredentialDefinitionV1::from(cred_def),
-                                         rev_reg_def.map(RevocationRegistryDefinitionV1::from).as_ref()));
+                                         rev_reg_def.map(RevocationRegistryDefinitionV1::from).as_ref(),
+                                         tags.as_ref()));
+            }
+            ProverCommand::ImportW3CCredential(wallet_handle, cred_id, cred_req_metadata, w3c_cred, cred_def, rev_reg_def, cb) => {
+                debug!(target: "prover_command_executor", "ImportW3CCredential command received");
+                cb(self.import_w3c_credential(wallet_handle, cred_id.as_deref(),
+                                              &cred_req_metadata, &w3c_cred,
+                                              &CredentialDefinitionV1::from(cred_def),
+                                              rev_reg_def.map(RevocationRegistryDefinitionV1::from).as_ref()));
             }
             ProverCommand::GetCredentials(wallet_handle, filter_json, cb) => {
                 debug!(target: "prover_command_executor", "GetCredentials command received");
@@ -241,10 +347,10 @@ impl ProverCommandExecutor {
             ProverCommand::CreateProof(wallet_handle, proof_req, requested_credentials, master_secret_name,
                                        schemas, cred_defs, rev_states, cb) => {
                 debug!(target: "prover_command_executor", "CreateProof command received");
-                cb(self.create_proof(wallet_handle, &proof_req, &requested_credentials, &master_secret_name,
-                                     &schemas_map_to_schemas_v1_map(schemas),
-                                     &cred_defs_map_to_cred_defs_v1_map(cred_defs),
-                                     &rev_states));
+                self.create_proof(wallet_handle, proof_req, requested_credentials, &master_secret_name,
+                                  schemas_map_to_schemas_v1_map(schemas),
+                                  cred_defs_map_to_cred_defs_v1_map(cred_defs),
+                                  rev_states, cb);
             }
             ProverCommand::CreateRevocationState(blob_storage_reader_handle, rev_reg_def, rev_reg_delta, timestamp, cred_rev_id, cb) => {
                 debug!(target: "prover_command_executor", "CreateRevocationState command received");
@@ -254,6 +360,36 @@ impl ProverCommandExecutor {
                 debug!(target: "prover_command_executor", "UpdateRevocationState command received");
                 cb(self.update_revocation_state(blob_storage_reader_handle, rev_state, rev_reg_def, rev_reg_delta, timestamp, &cred_rev_id));
             }
+            #[cfg(feature = "ledger")]
+            ProverCommand::StartRevocationStateUpdater(wallet_handle, pool_handle, blob_storage_reader_handle, config, cb) => {
+                debug!(target: "prover_command_executor", "StartRevocationStateUpdater command received");
+                self.start_revocation_state_updater(wallet_handle, pool_handle, blob_storage_reader_handle, config, cb);
+            }
+            #[cfg(feature = "ledger")]
+            ProverCommand::StopRevocationStateUpdater(updater_handle, cb) => {
+                debug!(target: "prover_command_executor", "StopRevocationStateUpdater command received");
+                cb(self.stop_revocation_state_updater(updater_handle));
+            }
+            #[cfg(feature = "ledger")]
+            ProverCommand::GetUpdatedRevocationStates(updater_handle, cb) => {
+                debug!(target: "prover_command_executor", "GetUpdatedRevocationStates command received");
+                cb(self.get_updated_revocation_states(updater_handle));
+            }
+            #[cfg(feature = "ledger")]
+            ProverCommand::UpdateRevocationStates(updater_handle) => {
+                debug!(target: "prover_command_executor", "UpdateRevocationStates command received");
+                self._update_revocation_states(updater_handle);
+            }
+            #[cfg(feature = "ledger")]
+            ProverCommand::UpdateRevocationStatesDefAck(updater_handle, rev_reg_id, res) => {
+                debug!(target: "prover_command_executor", "UpdateRevocationStatesDefAck command received");
+                self._update_revocation_states_def_ack(updater_handle, rev_reg_id, res);
+            }
+            #[cfg(feature = "ledger")]
+            ProverCommand::UpdateRevocationStatesDeltaAck(updater_handle, rev_reg_id, res) => {
+                debug!(target: "prover_command_executor", "UpdateRevocationStatesDeltaAck command received");
+                self._update_revocation_states_delta_ack(updater_handle, rev_reg_id, res);
+            }
         };
     }
 
@@ -326,6 +462,21 @@ impl ProverCommandExecutor {
         Ok((cred_req_json, cred_req_metadata_json))
     }
 
+    fn parse_credential_offer(&self,
+                              cred_offer: &CredentialOffer,
+                              cred_def: &CredentialDefinitionV1) -> IndyResult<String> {
+        debug!("parse_credential_offer >>> cred_offer: {:?}, cred_def: {:?}", cred_offer, cred_def);
+
+        let parsed_offer = self.anoncreds_service.prover.parse_credential_offer(cred_offer, cred_def)?;
+
+        let res = serde_json::to_string(&parsed_offer)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize ParsedCredentialOffer")?;
+
+        debug!("parse_credential_offer <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
     fn set_credential_attr_tag_policy(&self,
                                       wallet_handle: WalletHandle,
                                       cred_def_id: &CredentialDefinitionId,
@@ -347,11 +498,19 @@ impl ProverCommandExecutor {
         // Cascade whether we updated policy or not: could be a retroactive cred attr tags reset to existing policy
         if retroactive {
             let query_json = format!(r#"{{"cred_def_id": "{}"}}"#, cred_def_id.0);
-            let mut credentials_search = self.wallet_service.search_indy_records::<Credential>(wallet_handle, query_json.as_str(), &SearchOptions::id_value())?;
+            let mut credentials_search = self.wallet_service.search_indy_records::<Credential>(wallet_handle, query_json.as_str(), &SearchOptions::id_value_tags())?;
 
             while let Some(credential_record) = credentials_search.fetch_next_record()? {
                 let (_, credential) = self._get_credential(&credential_record)?;
-                let cred_tags = self.anoncreds_service.prover.build_credential_tags(&credential, catpol)?;
+                let mut cred_tags = self.anoncreds_service.prover.build_credential_tags(&credential, catpol)?;
+
+                // Custom tags added on store aren't affected by the policy
+                if let Some(tags) = credential_record.get_tags() {
+                    cred_tags.extend(tags.iter()
+                        .filter(|(name, _)| Prover::is_custom_credential_tag(name))
+                        .map(|(name, value)| (name.clone(), value.clone())));
+                }
+
                 self.wallet_service.update_record_tags(wallet_handle, self.wallet_service.add_prefix("Credential").as_str(), credential_record.get_id(), &cred_tags)?;
             }
         }
@@ -385,9 +544,14 @@ impl ProverCommandExecutor {
                         cred_req_metadata: &CredentialRequestMetadata,
                         credential: &mut Credential,
                         cred_def: &CredentialDefinitionV1,
-                        rev_reg_def: Option<&RevocationRegistryDefinitionV1>) -> IndyResult<String> {
+                        rev_reg_def: Option<&RevocationRegistryDefinitionV1>,
+                        tags: Option<&Tags>) -> IndyResult<String> {
         debug!("store_credential >>> wallet_handle: {:?}, cred_id: {:?}, cred_req_metadata: {:?}, credential: {:?}, cred_def: {:?}, \
-        rev_reg_def: {:?}", wallet_handle, cred_id, cred_req_metadata, credential, cred_def, rev_reg_def);
+        rev_reg_def: {:?}, tags: {:?}", wallet_handle, cred_id, cred_req_metadata, credential, cred_def, rev_reg_def, tags);
+
+        if let Some(tags) = tags {
+            self.anoncreds_service.prover.validate_custom_credential_tags(tags)?;
+        }
 
         let master_secret: MasterSecret = self._wallet_get_master_secret(wallet_handle, &cred_req_metadata.master_secret_name)?;
 
@@ -409,7 +573,10 @@ impl ProverCommandExecutor {
             None
         };
 
-        let cred_tags = self.anoncreds_service.prover.build_credential_tags(&credential, catpol.as_ref())?;
+        let mut cred_tags = self.anoncreds_service.prover.build_credential_tags(&credential, catpol.as_ref())?;
+        if let Some(tags) = tags {
+            cred_tags.extend(tags.iter().map(|(name, value)| (name.clone(), value.clone())));
+        }
         self.wallet_service.add_indy_object(wallet_handle, &out_cred_id, credential, &cred_tags)?;
 
         debug!("store_credential <<< out_cred_id: {:?}", out_cred_id);
@@ -417,6 +584,25 @@ impl ProverCommandExecutor {
         Ok(out_cred_id)
     }
 
+    fn import_w3c_credential(&self,
+                             wallet_handle: WalletHandle,
+                             cred_id: Option<&str>,
+                             cred_req_metadata: &CredentialRequestMetadata,
+                             w3c_credential: &W3CCredential,
+                             cred_def: &CredentialDefinitionV1,
+                             rev_reg_def: Option<&RevocationRegistryDefinitionV1>) -> IndyResult<String> {
+        debug!("import_w3c_credential >>> wallet_handle: {:?}, cred_id: {:?}, cred_req_metadata: {:?}, w3c_credential: {:?}, cred_def: {:?}, \
+        rev_reg_def: {:?}", wallet_handle, cred_id, cred_req_metadata, w3c_credential, cred_def, rev_reg_def);
+
+        let mut credential = self.anoncreds_service.prover.convert_w3c_credential(w3c_credential)?;
+
+        let res = self.store_credential(wallet_handle, cred_id, cred_req_metadata, &mut credential, cred_def, rev_reg_def, None)?;
+
+        debug!("import_w3c_credential <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
     fn get_credentials(&self,
                        wallet_handle: WalletHandle,
                        filter_json: Option<&str>) -> IndyResult<String> {
@@ -471,6 +657,7 @@ impl ProverCommandExecutor {
         let handle : SearchHandle = next_search_handle();
 
         self.searches.borrow_mut().insert(handle, Box::new(credentials_search));
+        handles::opened(handles::CREDENTIALS_SEARCH, handle.0, None);
 
         let res = (handle, total_count);
 
@@ -515,6 +702,7 @@ impl ProverCommandExecutor {
             Some(_) => Ok(()),
             None => Err(err_msg(IndyErrorKind::InvalidWalletHandle, format!("Unknown CredentialsSearch handle: {:?}", search_handle)))
         }?;
+        handles::closed(handles::CREDENTIALS_SEARCH, search_handle.0);
 
         trace!("close_credentials_search <<< res: ()");
 
@@ -618,6 +806,7 @@ impl ProverCommandExecutor {
 
         let search_handle = next_search_handle();
         self.searches_for_proof_requests.borrow_mut().insert(search_handle, Box::new(credentials_for_proof_request_search));
+        handles::opened(handles::CREDENTIALS_FOR_PROOF_REQUEST_SEARCH, search_handle.0, None);
 
         debug!("search_credentials_for_proof_req <<< credentials_for_proof_request_json: {:?}", search_handle);
 
@@ -651,12 +840,20 @@ impl ProverCommandExecutor {
             Some(_) => Ok(()),
             None => Err(err_msg(IndyErrorKind::InvalidWalletHandle, format!("Unknown CredentialsSearch handle: {:?}", search_handle)))
         }?;
+        handles::closed(handles::CREDENTIALS_FOR_PROOF_REQUEST_SEARCH, search_handle.0);
 
         trace!("close_credentials_search_for_proof_req <<< res: ()");
 
         Ok(())
     }
 
+    pub fn close_all_searches(&self) {
+        self.searches.borrow_mut().clear();
+        self.searches_for_proof_requests.borrow_mut().clear();
+        handles::closed_all(handles::CREDENTIALS_SEARCH);
+        handles::closed_all(handles::CREDENTIALS_FOR_PROOF_REQUEST_SEARCH);
+    }
+
     fn delete_credential(&self,
                          wallet_handle: WalletHandle,
                          cred_id: &str) -> IndyResult<()> {
@@ -669,19 +866,23 @@ impl ProverCommandExecutor {
         self.wallet_service.delete_indy_record::<Credential>(wallet_handle, cred_id)
     }
 
+    /// Reads credentials from the wallet and creates proof on the anoncreds workers
+    /// so proof math doesn't block other commands.
+    #[allow(clippy::too_many_arguments)]
     fn create_proof(&self,
                     wallet_handle: WalletHandle,
-                    proof_req: &ProofRequest,
-                    requested_credentials: &RequestedCredentials,
+                    proof_req: ProofRequest,
+                    requested_credentials: RequestedCredentials,
                     master_secret_id: &str,
-                    schemas: &HashMap<SchemaId, SchemaV1>,
-                    cred_defs: &HashMap<CredentialDefinitionId, CredentialDefinitionV1>,
-                    rev_states: &RevocationStates) -> IndyResult<String> {
+                    schemas: HashMap<SchemaId, SchemaV1>,
+                    cred_defs: HashMap<CredentialDefinitionId, CredentialDefinitionV1>,
+                    rev_states: RevocationStates,
+                    cb: Box<dyn Fn(IndyResult<String>) + Send>) {
         debug!("create_proof >>> wallet_handle: {:?}, proof_req: {:?}, requested_credentials: {:?}, master_secret_id: {:?}, schemas: {:?}, \
         cred_defs: {:?}, rev_states: {:?}",
                wallet_handle, proof_req, requested_credentials, master_secret_id, schemas, cred_defs, rev_states);
 
-        let master_secret: MasterSecret = self._wallet_get_master_secret(wallet_handle, &master_secret_id)?;
+        let master_secret: MasterSecret = try_cb!(self._wallet_get_master_secret(wallet_handle, &master_secret_id), cb);
 
         let cred_refs_for_attrs =
             requested_credentials.requested_attributes
@@ -700,24 +901,39 @@ impl ProverCommandExecutor {
         let mut credentials: HashMap<String, Credential> = HashMap::with_capacity(cred_referents.len());
 
         for cred_referent in cred_referents.into_iter() {
-            let credential: Credential = self.wallet_service.get_indy_object(wallet_handle, &cred_referent, &RecordOptions::id_value())?;
+            let credential: Credential = try_cb!(self.wallet_service.get_indy_object(wallet_handle, &cred_referent, &RecordOptions::id_value()), cb);
             credentials.insert(cred_referent, credential);
         }
 
-        let proof = self.anoncreds_service.prover.create_proof(&credentials,
-                                                               &proof_req,
-                                                               &requested_credentials,
-                                                               &master_secret.value,
-                                                               schemas,
-                                                               cred_defs,
-                                                               rev_states)?;
-
-        let proof_json = serde_json::to_string(&proof)
-            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize FullProof")?;
+        let prover = self.anoncreds_service.prover.clone();
 
-        debug!("create_proof <<< proof_json: {:?}", proof_json);
+        crate::commands::execute_anoncreds_job(Some(wallet_handle), move || {
+            let res = ProverCommandExecutor::_create_proof(&prover, &credentials, &proof_req, &requested_credentials,
+                                                           &master_secret, &schemas, &cred_defs, &rev_states);
+            debug!("create_proof <<< res: {:?}", res);
+            res
+        }, cb);
+    }
 
-        Ok(proof_json)
+    #[allow(clippy::too_many_arguments)]
+    fn _create_proof(prover: &Prover,
+                     credentials: &HashMap<String, Credential>,
+                     proof_req: &ProofRequest,
+                     requested_credentials: &RequestedCredentials,
+                     master_secret: &MasterSecret,
+                     schemas: &HashMap<SchemaId, SchemaV1>,
+                     cred_defs: &HashMap<CredentialDefinitionId, CredentialDefinitionV1>,
+                     rev_states: &RevocationStates) -> IndyResult<String> {
+        let proof = prover.create_proof(credentials,
+                                        proof_req,
+                                        requested_credentials,
+                                        &master_secret.value,
+                                        schemas,
+                                        cred_defs,
+                                        rev_states)?;
+
+        serde_json::to_string(&proof)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize FullProof")
     }
 
     fn create_revocation_state(&self,
@@ -729,23 +945,11 @@ impl ProverCommandExecutor {
         debug!("create_revocation_state >>> , blob_storage_reader_handle: {:?}, revoc_reg_def: {:?}, rev_reg_delta: {:?}, timestamp: {:?}, cred_rev_id: {:?}",
                blob_storage_reader_handle, revoc_reg_def, rev_reg_delta, timestamp, cred_rev_id);
 
-        let revoc_reg_def = RevocationRegistryDefinitionV1::from(revoc_reg_def);
-
-        let rev_idx = parse_cred_rev_id(cred_rev_id)?;
-
-        let sdk_tails_accessor = SDKTailsAccessor::new(self.blob_storage_service.clone(),
-                                                       blob_storage_reader_handle,
-                                                       &revoc_reg_def)?;
-
-        let rev_reg_delta = RevocationRegistryDeltaV1::from(rev_reg_delta);
-
-        let witness = Witness::new(rev_idx, revoc_reg_def.value.max_cred_num, revoc_reg_def.value.issuance_type.to_bool(), &rev_reg_delta.value, &sdk_tails_accessor)?;
-
-        let revocation_state = RevocationState {
-            witness,
-            rev_reg: RevocationRegistry::from(rev_reg_delta.value),
-            timestamp,
-        };
+        let revocation_state = self._new_revocation_state(blob_storage_reader_handle,
+                                                          &RevocationRegistryDefinitionV1::from(revoc_reg_def),
+                                                          &RevocationRegistryDeltaV1::from(rev_reg_delta),
+                                                          timestamp,
+                                                          cred_rev_id)?;
 
         let revocation_state_json = serde_json::to_string(&revocation_state)
             .to_indy(IndyErrorKind::InvalidState, "Cannot serialize RevocationState")?;
@@ -765,27 +969,340 @@ impl ProverCommandExecutor {
         debug!("update_revocation_state >>> blob_storage_reader_handle: {:?}, rev_state: {:?}, rev_reg_def: {:?}, rev_reg_delta: {:?}, timestamp: {:?}, cred_rev_id: {:?}",
                blob_storage_reader_handle, rev_state, rev_reg_def, rev_reg_delta, timestamp, cred_rev_id);
 
-        let revocation_registry_definition = RevocationRegistryDefinitionV1::from(rev_reg_def);
+        self._update_revocation_state(blob_storage_reader_handle,
+                                      &mut rev_state,
+                                      &RevocationRegistryDefinitionV1::from(rev_reg_def),
+                                      &RevocationRegistryDeltaV1::from(rev_reg_delta),
+                                      timestamp,
+                                      cred_rev_id)?;
+
+        let rev_state_json = serde_json::to_string(&rev_state)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize RevocationState")?;
+
+        debug!("update_revocation_state <<< rev_state: {:?}", rev_state_json);
+
+        Ok(rev_state_json)
+    }
+
+    fn _new_revocation_state(&self,
+                             blob_storage_reader_handle: i32,
+                             rev_reg_def: &RevocationRegistryDefinitionV1,
+                             rev_reg_delta: &RevocationRegistryDeltaV1,
+                             timestamp: u64,
+                             cred_rev_id: &str) -> IndyResult<RevocationState> {
+        let rev_idx = parse_cred_rev_id(cred_rev_id)?;
+
+        let sdk_tails_accessor = SDKTailsAccessor::new(self.blob_storage_service.clone(),
+                                                       blob_storage_reader_handle,
+                                                       rev_reg_def)?;
 
-        let rev_reg_delta = RevocationRegistryDeltaV1::from(rev_reg_delta);
+        let witness = Witness::new(rev_idx, rev_reg_def.value.max_cred_num, rev_reg_def.value.issuance_type.to_bool(), &rev_reg_delta.value, &sdk_tails_accessor)?;
 
+        Ok(RevocationState {
+            witness,
+            rev_reg: RevocationRegistry::from(rev_reg_delta.value.clone()),
+            timestamp,
+        })
+    }
+
+    fn _update_revocation_state(&self,
+                                blob_storage_reader_handle: i32,
+                                rev_state: &mut RevocationState,
+                                rev_reg_def: &RevocationRegistryDefinitionV1,
+                                rev_reg_delta: &RevocationRegistryDeltaV1,
+                                timestamp: u64,
+                                cred_rev_id: &str) -> IndyResult<()> {
         let rev_idx = parse_cred_rev_id(cred_rev_id)?;
 
         let sdk_tails_accessor = SDKTailsAccessor::new(self.blob_storage_service.clone(),
                                                        blob_storage_reader_handle,
-                                                       &revocation_registry_definition)?;
+                                                       rev_reg_def)?;
 
-        rev_state.witness.update(rev_idx, revocation_registry_definition.value.max_cred_num, &rev_reg_delta.value, &sdk_tails_accessor)?;
+        rev_state.witness.update(rev_idx, rev_reg_def.value.max_cred_num, &rev_reg_delta.value, &sdk_tails_accessor)?;
 
-        rev_state.rev_reg = RevocationRegistry::from(rev_reg_delta.value);
+        rev_state.rev_reg = RevocationRegistry::from(rev_reg_delta.value.clone());
         rev_state.timestamp = timestamp;
 
-        let rev_state_json = serde_json::to_string(&rev_state)
-            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize RevocationState")?;
+        Ok(())
+    }
 
-        debug!("update_revocation_state <<< rev_state: {:?}", rev_state_json);
+    #[cfg(feature = "ledger")]
+    fn start_revocation_state_updater(&self,
+                                      wallet_handle: WalletHandle,
+                                      pool_handle: PoolHandle,
+                                      blob_storage_reader_handle: i32,
+                                      config: RevocationStateUpdaterConfig,
+                                      cb: Box<dyn Fn(IndyResult<IndyHandle>) + Send>) {
+        debug!("start_revocation_state_updater >>> wallet_handle: {:?}, pool_handle: {:?}, blob_storage_reader_handle: {:?}, config: {:?}",
+               wallet_handle, pool_handle, blob_storage_reader_handle, config);
+
+        try_cb!(self.pool_service.check_handle(pool_handle), cb);
+
+        let mut registries: HashMap<RevocationRegistryId, UpdatedRevocationRegistry> = HashMap::new();
+
+        for cred_id in config.cred_ids {
+            let credential: Credential = try_cb!(self.wallet_service.get_indy_object(wallet_handle, &cred_id, &RecordOptions::id_value()), cb);
+
+            let (rev_reg_id, cred_rev_id) = match (credential.rev_reg_id, credential.signature.extract_index()) {
+                (Some(rev_reg_id), Some(cred_rev_id)) => (rev_reg_id, cred_rev_id.to_string()),
+                _ => return cb(Err(err_msg(IndyErrorKind::InvalidStructure, format!("Credential {:?} isn't revocable", cred_id))))
+            };
+
+            registries.entry(rev_reg_id)
+                .or_insert_with(|| UpdatedRevocationRegistry {
+                    rev_reg_def: None,
+                    credentials: HashMap::new(),
+                    timestamp: None,
+                })
+                .credentials.insert(cred_id, (cred_rev_id, None));
+        }
 
-        Ok(rev_state_json)
+        let updater_handle = next_command_handle();
+
+        self.revocation_state_updaters.borrow_mut().insert(updater_handle, RevocationStateUpdater {
+            pool_handle,
+            blob_storage_reader_handle,
+            update_interval: config.update_interval.unwrap_or(DEFAULT_REVOCATION_STATE_UPDATE_INTERVAL),
+            registries,
+            pending: 0,
+        });
+
+        debug!("start_revocation_state_updater <<< updater_handle: {:?}", updater_handle);
+
+        cb(Ok(updater_handle));
+
+        self._update_revocation_states(updater_handle);
+    }
+
+    #[cfg(feature = "ledger")]
+    fn stop_revocation_state_updater(&self, updater_handle: IndyHandle) -> IndyResult<()> {
+        debug!("stop_revocation_state_updater >>> updater_handle: {:?}", updater_handle);
+
+        self.revocation_state_updaters.borrow_mut().remove(&updater_handle)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, format!("Unknown revocation state updater handle: {}", updater_handle)))?;
+
+        debug!("stop_revocation_state_updater <<<");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "ledger")]
+    fn get_updated_revocation_states(&self, updater_handle: IndyHandle) -> IndyResult<String> {
+        debug!("get_updated_revocation_states >>> updater_handle: {:?}", updater_handle);
+
+        let updaters = self.revocation_state_updaters.borrow();
+
+        let updater = updaters.get(&updater_handle)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, format!("Unknown revocation state updater handle: {}", updater_handle)))?;
+
+        let rev_states: HashMap<&str, CredentialRevocationState> =
+            updater.registries.iter()
+                .flat_map(|(rev_reg_id, registry)|
+                    registry.credentials.iter()
+                        .filter_map(move |(cred_id, (cred_rev_id, rev_state))|
+                            rev_state.as_ref().map(|rev_state| (cred_id.as_str(), CredentialRevocationState {
+                                rev_reg_id: rev_reg_id.clone(),
+                                cred_rev_id: cred_rev_id.clone(),
+                                timestamp: rev_state.timestamp,
+                                rev_state: rev_state.clone(),
+                            }))))
+                .collect();
+
+        let rev_states_json = serde_json::to_string(&rev_states)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize revocation states")?;
+
+        debug!("get_updated_revocation_states <<< rev_states_json: {:?}", rev_states_json);
+
+        Ok(rev_states_json)
+    }
+
+    // Each round fetches deltas of all registries since timestamps of the current states.
+    // The next round is scheduled when all registries of the round are processed.
+    #[cfg(feature = "ledger")]
+    fn _update_revocation_states(&self, updater_handle: IndyHandle) {
+        let mut updaters = self.revocation_state_updaters.borrow_mut();
+
+        let updater = match updaters.get_mut(&updater_handle) {
+            Some(updater) => updater,
+            // Updater is already stopped
+            None => return
+        };
+
+        updater.pending = updater.registries.len();
+
+        for (rev_reg_id, registry) in updater.registries.iter() {
+            if registry.rev_reg_def.is_some() {
+                ProverCommandExecutor::_get_revocation_registry_delta(updater.pool_handle, updater_handle, rev_reg_id.clone(), registry.timestamp);
+            } else {
+                ProverCommandExecutor::_get_revocation_registry_definition(updater.pool_handle, updater_handle, rev_reg_id.clone());
+            }
+        }
+    }
+
+    #[cfg(feature = "ledger")]
+    fn _get_revocation_registry_definition(pool_handle: PoolHandle, updater_handle: IndyHandle, rev_reg_id: RevocationRegistryId) {
+        CommandExecutor::instance()
+            .send(Command::Ledger(LedgerCommand::GetRevocRegDef(
+                pool_handle,
+                None,
+                rev_reg_id.clone(),
+                Box::new(move |res| {
+                    CommandExecutor::instance()
+                        .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::UpdateRevocationStatesDefAck(updater_handle, rev_reg_id.clone(), res))))
+                        .unwrap();
+                }),
+            ))).unwrap();
+    }
+
+    #[cfg(feature = "ledger")]
+    fn _get_revocation_registry_delta(pool_handle: PoolHandle, updater_handle: IndyHandle, rev_reg_id: RevocationRegistryId, from: Option<u64>) {
+        let to = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as i64;
+
+        CommandExecutor::instance()
+            .send(Command::Ledger(LedgerCommand::GetRevocRegDelta(
+                pool_handle,
+                None,
+                rev_reg_id.clone(),
+                from.map(|from| from as i64),
+                to,
+                Box::new(move |res| {
+                    CommandExecutor::instance()
+                        .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::UpdateRevocationStatesDeltaAck(updater_handle, rev_reg_id.clone(), res))))
+                        .unwrap();
+                }),
+            ))).unwrap();
+    }
+
+    #[cfg(feature = "ledger")]
+    fn _update_revocation_states_def_ack(&self, updater_handle: IndyHandle, rev_reg_id: RevocationRegistryId, res: IndyResult<(String, String)>) {
+        let res = res
+            .and_then(|(_, rev_reg_def_json)|
+                serde_json::from_str::<RevocationRegistryDefinition>(&rev_reg_def_json)
+                    .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize RevocationRegistryDefinition"))
+            .map(|rev_reg_def| {
+                let mut updaters = self.revocation_state_updaters.borrow_mut();
+
+                updaters.get_mut(&updater_handle).map(|updater| {
+                    if let Some(registry) = updater.registries.get_mut(&rev_reg_id) {
+                        registry.rev_reg_def = Some(RevocationRegistryDefinitionV1::from(rev_reg_def));
+                    }
+                    updater.pool_handle
+                })
+            });
+
+        match res {
+            Ok(Some(pool_handle)) => ProverCommandExecutor::_get_revocation_registry_delta(pool_handle, updater_handle, rev_reg_id, None),
+            // Updater is already stopped
+            Ok(None) => (),
+            Err(err) => self._finish_revocation_registry_update(updater_handle, &rev_reg_id, Err(err))
+        }
+    }
+
+    #[cfg(feature = "ledger")]
+    fn _update_revocation_states_delta_ack(&self, updater_handle: IndyHandle, rev_reg_id: RevocationRegistryId, res: IndyResult<(String, String, u64)>) {
+        let res = res
+            .and_then(|(_, rev_reg_delta_json, timestamp)| {
+                let rev_reg_delta = serde_json::from_str::<RevocationRegistryDelta>(&rev_reg_delta_json)
+                    .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize RevocationRegistryDelta")?;
+
+                self._update_registry_revocation_states(updater_handle, &rev_reg_id, &RevocationRegistryDeltaV1::from(rev_reg_delta), timestamp)
+            });
+
+        self._finish_revocation_registry_update(updater_handle, &rev_reg_id, res);
+    }
+
+    #[cfg(feature = "ledger")]
+    fn _update_registry_revocation_states(&self,
+                                          updater_handle: IndyHandle,
+                                          rev_reg_id: &RevocationRegistryId,
+                                          rev_reg_delta: &RevocationRegistryDeltaV1,
+                                          timestamp: u64) -> IndyResult<()> {
+        let mut updaters = self.revocation_state_updaters.borrow_mut();
+
+        let updater = match updaters.get_mut(&updater_handle) {
+            Some(updater) => updater,
+            // Updater is already stopped
+            None => return Ok(())
+        };
+
+        let blob_storage_reader_handle = updater.blob_storage_reader_handle;
+
+        let registry = updater.registries.get_mut(rev_reg_id)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, format!("Unknown revocation registry: {:?}", rev_reg_id)))?;
+
+        let rev_reg_def = registry.rev_reg_def.as_ref()
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, format!("Revocation registry definition isn't fetched: {:?}", rev_reg_id)))?;
+
+        let rev_states = registry.credentials.iter()
+            .map(|(cred_id, (cred_rev_id, rev_state))| {
+                let rev_state = match (registry.timestamp, rev_state) {
+                    (Some(_), Some(rev_state)) => {
+                        let mut rev_state = rev_state.clone();
+                        self._update_revocation_state(blob_storage_reader_handle, &mut rev_state, rev_reg_def, rev_reg_delta, timestamp, cred_rev_id)?;
+                        rev_state
+                    }
+                    _ => self._new_revocation_state(blob_storage_reader_handle, rev_reg_def, rev_reg_delta, timestamp, cred_rev_id)?
+                };
+
+                Ok((cred_id.clone(), rev_state))
+            })
+            .collect::<IndyResult<Vec<(String, RevocationState)>>>();
+
+        // States of a registry are replaced all together, so all of them correspond to the same timestamp.
+        // If any state can't be updated, the next round computes them from scratch.
+        match rev_states {
+            Ok(rev_states) => {
+                for (cred_id, rev_state) in rev_states {
+                    if let Some(credential) = registry.credentials.get_mut(&cred_id) {
+                        credential.1 = Some(rev_state);
+                    }
+                }
+                registry.timestamp = Some(timestamp);
+                Ok(())
+            }
+            Err(err) => {
+                registry.timestamp = None;
+                Err(err)
+            }
+        }
+    }
+
+    #[cfg(feature = "ledger")]
+    fn _finish_revocation_registry_update(&self, updater_handle: IndyHandle, rev_reg_id: &RevocationRegistryId, res: IndyResult<()>) {
+        let mut updaters = self.revocation_state_updaters.borrow_mut();
+
+        if !updaters.contains_key(&updater_handle) {
+            return;
+        }
+
+        match res {
+            Ok(()) => (),
+            Err(ref err) if err.kind() == IndyErrorKind::InvalidPoolHandle => {
+                warn!("Revocation state updater {:?} is stopped as the pool is closed", updater_handle);
+                updaters.remove(&updater_handle);
+                return;
+            }
+            Err(err) => warn!("Can't update revocation states of registry {:?} for updater {:?}: {:?}", rev_reg_id, updater_handle, err)
+        }
+
+        if let Some(updater) = updaters.get_mut(&updater_handle) {
+            updater.pending -= 1;
+
+            if updater.pending == 0 {
+                ProverCommandExecutor::_schedule_revocation_states_update(updater_handle, updater.update_interval);
+            }
+        }
+    }
+
+    #[cfg(feature = "ledger")]
+    fn _schedule_revocation_states_update(updater_handle: IndyHandle, update_interval: u64) {
+        thread::spawn(move || {
+            thread::sleep(Duration::from_secs(update_interval));
+
+            CommandExecutor::instance()
+                .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::UpdateRevocationStates(updater_handle))))
+                .unwrap_or_else(|err| warn!("Can't schedule revocation states update for updater {:?}: {:?}", updater_handle, err));
+        });
     }
 
     fn _get_credential_info(&self,
@@ -6,15 +6,104 @@ use ursa::cl::{Tail, RevocationTailsAccessor, RevocationTailsGenerator};
 use ursa::errors::prelude::{UrsaCryptoError, UrsaCryptoErrorKind};
 
 use rust_base58::{ToBase58, FromBase58};
+use indy_utils::crypto::hash::Hash;
 
+use std::collections::HashMap;
+use std::mem;
 use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 const TAILS_BLOB_TAG_SZ: u8 = 2;
 const TAIL_SIZE: usize = Tail::BYTES_REPR_SIZE;
+const TAILS_READ_CHUNK: usize = TAIL_SIZE * 1024;
+
+static CACHE_SIZE: AtomicUsize = AtomicUsize::new(32 * 1024 * 1024);
+
+lazy_static! {
+    static ref TAILS_CACHE: Mutex<TailsCache> = Mutex::new(TailsCache::new());
+}
+
+/// Sets total size in bytes of parsed tails kept by cache of opened tails, 0 disables the cache.
+pub fn set_cache_size(size: usize) {
+    CACHE_SIZE.store(size, Ordering::SeqCst);
+
+    if let Ok(mut cache) = TAILS_CACHE.lock() {
+        cache.evict(size);
+    }
+}
+
+fn cache_size() -> usize {
+    CACHE_SIZE.load(Ordering::SeqCst)
+}
+
+/// Parsed tails of verified blobs indexed by tails hash, least recently used ones are evicted first.
+/// Size of entry is the memory taken by parsed tails, which is bigger than the size of the blob.
+struct TailsCache {
+    entries: HashMap<Vec<u8>, CachedTails>,
+    size: usize,
+    clock: u64,
+}
+
+struct CachedTails {
+    tails: Arc<Vec<Tail>>,
+    size: usize,
+    last_used: u64,
+}
+
+impl TailsCache {
+    fn new() -> TailsCache {
+        TailsCache { entries: HashMap::new(), size: 0, clock: 0 }
+    }
+
+    fn get(&mut self, hash: &[u8]) -> Option<Arc<Vec<Tail>>> {
+        self.clock += 1;
+        let clock = self.clock;
+
+        self.entries.get_mut(hash).map(|entry| {
+            entry.last_used = clock;
+            entry.tails.clone()
+        })
+    }
+
+    fn insert(&mut self, hash: Vec<u8>, tails: Arc<Vec<Tail>>, size: usize, capacity: usize) {
+        if size > capacity {
+            return;
+        }
+
+        if let Some(entry) = self.entries.remove(&hash) {
+            self.size -= entry.size;
+        }
+
+        self.evict(capacity - size);
+
+        self.clock += 1;
+        self.size += size;
+        self.entries.insert(hash, CachedTails { tails, size, last_used: self.clock });
+    }
+
+    fn evict(&mut self, capacity: usize) {
+        while self.size > capacity {
+            let lru = self.entries.iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(hash, _)| hash.clone());
+
+            match lru.and_then(|hash| self.entries.remove(&hash)) {
+                Some(entry) => self.size -= entry.size,
+                None => break
+            }
+        }
+    }
+}
+
+enum Tails {
+    Cached(Arc<Vec<Tail>>),
+    Blob(i32 /* tails reader handle */),
+}
 
 pub struct SDKTailsAccessor {
     tails_service: Rc<BlobStorageService>,
-    tails_reader_handle: i32,
+    tails: Tails,
 }
 
 impl SDKTailsAccessor {
@@ -24,24 +113,99 @@ impl SDKTailsAccessor {
         let tails_hash = rev_reg_def.value.tails_hash.from_base58()
             .map_err(|_| err_msg(IndyErrorKind::InvalidState, "Invalid base58 for Tails hash"))?;
 
+        let cached = TAILS_CACHE.lock()
+            .map_err(|_| err_msg(IndyErrorKind::InvalidState, "Tails cache is poisoned"))?
+            .get(&tails_hash);
+
+        if let Some(tails) = cached {
+            return Ok(SDKTailsAccessor {
+                tails_service,
+                tails: Tails::Cached(tails),
+            });
+        }
+
         let tails_reader_handle = tails_service.open_blob(tails_reader_handle,
                                                           &rev_reg_def.value.tails_location,
                                                           tails_hash.as_slice())?;
 
-        Ok(SDKTailsAccessor {
+        let mut accessor = SDKTailsAccessor {
             tails_service,
-            tails_reader_handle
-        })
+            tails: Tails::Blob(tails_reader_handle),
+        };
+
+        let capacity = cache_size();
+
+        if capacity > 0 {
+            // Tails that are too big for the cache or don't match the hash are read from the blob on each access
+            if let Some((tails, size)) = accessor._load(tails_reader_handle, &tails_hash, capacity)? {
+                let tails = Arc::new(tails);
+
+                TAILS_CACHE.lock()
+                    .map_err(|_| err_msg(IndyErrorKind::InvalidState, "Tails cache is poisoned"))?
+                    .insert(tails_hash, tails.clone(), size, capacity);
+
+                accessor.tails_service.close(tails_reader_handle)?;
+                accessor.tails = Tails::Cached(tails);
+            }
+        }
+
+        Ok(accessor)
+    }
+
+    fn _load(&self, tails_reader_handle: i32, tails_hash: &[u8], capacity: usize) -> IndyResult<Option<(Vec<Tail>, usize)>> {
+        let mut bytes = Vec::new();
+
+        loop {
+            let chunk = self.tails_service.read(tails_reader_handle, TAILS_READ_CHUNK, bytes.len())?;
+
+            if chunk.is_empty() {
+                break;
+            }
+
+            if _parsed_size(bytes.len() + chunk.len()) > capacity {
+                return Ok(None);
+            }
+
+            bytes.extend_from_slice(&chunk);
+        }
+
+        let mut hasher = Hash::new_context()?;
+        hasher.update(&bytes)?;
+
+        if hasher.finish()?.to_vec() != tails_hash || bytes.len() < TAILS_BLOB_TAG_SZ as usize {
+            return Ok(None);
+        }
+
+        let tails = bytes[TAILS_BLOB_TAG_SZ as usize..]
+            .chunks(TAIL_SIZE)
+            .map(Tail::from_bytes)
+            .collect::<Result<Vec<Tail>, UrsaCryptoError>>();
+
+        match tails {
+            Ok(tails) => {
+                let size = mem::size_of::<Tail>() * tails.len();
+                Ok(Some((tails, size)))
+            }
+            Err(_) => Ok(None)
+        }
     }
 }
 
+/// Memory taken by tails parsed from blob of the given size.
+fn _parsed_size(blob_size: usize) -> usize {
+    let tails_count = (blob_size.saturating_sub(TAILS_BLOB_TAG_SZ as usize) + TAIL_SIZE - 1) / TAIL_SIZE;
+    mem::size_of::<Tail>() * tails_count
+}
+
 impl Drop for SDKTailsAccessor {
     fn drop(&mut self) {
-        #[allow(unused_must_use)] //TODO
-            {
-                self.tails_service.close(self.tails_reader_handle)
-                    .map_err(map_err_err!());
-            }
+        if let Tails::Blob(tails_reader_handle) = self.tails {
+            #[allow(unused_must_use)] //TODO
+                {
+                    self.tails_service.close(tails_reader_handle)
+                        .map_err(map_err_err!());
+                }
+        }
     }
 }
 
@@ -49,15 +213,24 @@ impl RevocationTailsAccessor for SDKTailsAccessor {
     fn access_tail(&self, tail_id: u32, accessor: &mut dyn FnMut(&Tail)) -> Result<(), UrsaCryptoError> {
         debug!("access_tail >>> tail_id: {:?}", tail_id);
 
-        let tail_bytes = self.tails_service
-            .read(self.tails_reader_handle,
-                  TAIL_SIZE,
-                  TAIL_SIZE * tail_id as usize + TAILS_BLOB_TAG_SZ as usize)
-            .map_err(|_|
-                UrsaCryptoError::from_msg(UrsaCryptoErrorKind::InvalidState, "Can't read tail bytes from blob storage"))?; // FIXME: IO error should be returned
+        match self.tails {
+            Tails::Cached(ref tails) => {
+                let tail = tails.get(tail_id as usize)
+                    .ok_or_else(|| UrsaCryptoError::from_msg(UrsaCryptoErrorKind::InvalidState, "Tail isn't found in tails"))?;
+                accessor(tail);
+            }
+            Tails::Blob(tails_reader_handle) => {
+                let tail_bytes = self.tails_service
+                    .read(tails_reader_handle,
+                          TAIL_SIZE,
+                          TAIL_SIZE * tail_id as usize + TAILS_BLOB_TAG_SZ as usize)
+                    .map_err(|_|
+                        UrsaCryptoError::from_msg(UrsaCryptoErrorKind::InvalidState, "Can't read tail bytes from blob storage"))?; // FIXME: IO error should be returned
 
-        let tail = Tail::from_bytes(tail_bytes.as_slice())?;
-        accessor(&tail);
+                let tail = Tail::from_bytes(tail_bytes.as_slice())?;
+                accessor(&tail);
+            }
+        }
 
         debug!("access_tail <<< res: ()");
         Ok(())
@@ -84,3 +257,112 @@ pub fn store_tails_from_generator(service: Rc<BlobStorageService>,
     debug!("store_tails_from_generator <<< res: {:?}", res);
     Ok(res)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn _tails() -> Arc<Vec<Tail>> {
+        Arc::new(Vec::new())
+    }
+
+    #[test]
+    fn tails_cache_evicts_least_recently_used() {
+        let mut cache = TailsCache::new();
+
+        cache.insert(vec![1], _tails(), 40, 100);
+        cache.insert(vec![2], _tails(), 40, 100);
+        assert!(cache.get(&[1]).is_some());
+
+        cache.insert(vec![3], _tails(), 40, 100);
+
+        assert!(cache.get(&[1]).is_some());
+        assert!(cache.get(&[2]).is_none());
+        assert!(cache.get(&[3]).is_some());
+        assert_eq!(80, cache.size);
+    }
+
+    #[test]
+    fn tails_cache_skips_tails_bigger_than_capacity() {
+        let mut cache = TailsCache::new();
+
+        cache.insert(vec![1], _tails(), 40, 100);
+        cache.insert(vec![2], _tails(), 101, 100);
+
+        assert!(cache.get(&[1]).is_some());
+        assert!(cache.get(&[2]).is_none());
+        assert_eq!(40, cache.size);
+    }
+
+    #[test]
+    fn sdk_tails_accessor_new_works_for_cached_tails() {
+        use ursa::cl::issuer::Issuer as CryptoIssuer;
+
+        let tails_service = Rc::new(BlobStorageService::new());
+
+        let mut credential_schema_builder = CryptoIssuer::new_credential_schema_builder().unwrap();
+        credential_schema_builder.add_attr("name").unwrap();
+        let credential_schema = credential_schema_builder.finalize().unwrap();
+
+        let mut non_credential_schema_builder = CryptoIssuer::new_non_credential_schema_builder().unwrap();
+        non_credential_schema_builder.add_attr("master_secret").unwrap();
+        let non_credential_schema = non_credential_schema_builder.finalize().unwrap();
+
+        let (credential_pub_key, _, _) = CryptoIssuer::new_credential_def(&credential_schema, &non_credential_schema, true).unwrap();
+        let (revoc_key_pub, _, _, mut tails_generator) = CryptoIssuer::new_revocation_registry_def(&credential_pub_key, 5, true).unwrap();
+
+        let writer_handle = tails_service.open_writer("memory", "{}").unwrap();
+        let (tails_location, tails_hash) = store_tails_from_generator(tails_service.clone(), writer_handle, &mut tails_generator).unwrap();
+
+        let rev_reg_def: RevocationRegistryDefinitionV1 = serde_json::from_value(json!({
+            "id": "NcYxiDXkpYi6ov5FcYDi1e:4:NcYxiDXkpYi6ov5FcYDi1e:3:CL:1:tag:CL_ACCUM:tag",
+            "revocDefType": "CL_ACCUM",
+            "tag": "tag",
+            "credDefId": "NcYxiDXkpYi6ov5FcYDi1e:3:CL:1:tag",
+            "value": {
+                "issuanceType": "ISSUANCE_BY_DEFAULT",
+                "maxCredNum": 5,
+                "publicKeys": {"accumKey": revoc_key_pub},
+                "tailsHash": tails_hash,
+                "tailsLocation": tails_location,
+            }
+        })).unwrap();
+
+        let reader_handle = tails_service.open_reader("memory", "{}").unwrap();
+
+        // miss loads tails into the cache
+        let accessor = SDKTailsAccessor::new(tails_service.clone(), reader_handle, &rev_reg_def).unwrap();
+        let tails = match accessor.tails {
+            Tails::Cached(ref tails) => tails.clone(),
+            Tails::Blob(_) => panic!("Tails aren't cached"),
+        };
+
+        // hit doesn't open the blob, so the reader isn't used
+        let accessor = SDKTailsAccessor::new(tails_service.clone(), -1, &rev_reg_def).unwrap();
+        match accessor.tails {
+            Tails::Cached(ref cached) => assert!(Arc::ptr_eq(&tails, cached)),
+            Tails::Blob(_) => panic!("Tails aren't cached"),
+        }
+
+        accessor.access_tail(0, &mut |_| {}).unwrap();
+
+        let size = TAILS_CACHE.lock().unwrap().entries[&tails_hash.from_base58().unwrap()].size;
+        assert_eq!(mem::size_of::<Tail>() * tails.len(), size);
+    }
+
+    #[test]
+    fn tails_cache_evict_works() {
+        let mut cache = TailsCache::new();
+
+        cache.insert(vec![1], _tails(), 40, 100);
+        cache.insert(vec![2], _tails(), 40, 100);
+        cache.evict(50);
+
+        assert!(cache.get(&[1]).is_none());
+        assert!(cache.get(&[2]).is_some());
+
+        cache.evict(0);
+        assert!(cache.entries.is_empty());
+        assert_eq!(0, cache.size);
+    }
+}
@@ -1,14 +1,40 @@
+#[cfg(feature = "ledger")]
+use std::cell::RefCell;
 use std::collections::HashMap;
+#[cfg(feature = "ledger")]
+use std::collections::HashSet;
 use std::rc::Rc;
 
 use crate::domain::anoncreds::credential_definition::{cred_defs_map_to_cred_defs_v1_map, CredentialDefinitionV1, CredentialDefinitionId, CredentialDefinitions};
 use crate::domain::anoncreds::proof::Proof;
-use crate::domain::anoncreds::proof_request::{ProofRequest, ProofRequestPayload};
+use crate::domain::anoncreds::proof_request::ProofRequest;
 use crate::domain::anoncreds::revocation_registry::{rev_regs_map_to_rev_regs_local_map, RevocationRegistryV1, RevocationRegistries};
 use crate::domain::anoncreds::revocation_registry_definition::{rev_reg_defs_map_to_rev_reg_defs_v1_map, RevocationRegistryDefinitionV1, RevocationRegistryId, RevocationRegistryDefinitions};
 use crate::domain::anoncreds::schema::{schemas_map_to_schemas_v1_map, SchemaV1, SchemaId, Schemas};
+#[cfg(feature = "ledger")]
+use crate::domain::crypto::did::DidValue;
 use indy_api_types::errors::prelude::*;
 use crate::services::anoncreds::AnoncredsService;
+#[cfg(feature = "ledger")]
+use crate::domain::anoncreds::credential_definition::CredentialDefinition;
+#[cfg(feature = "ledger")]
+use crate::domain::anoncreds::revocation_registry::RevocationRegistry;
+#[cfg(feature = "ledger")]
+use crate::domain::anoncreds::revocation_registry_definition::RevocationRegistryDefinition;
+#[cfg(feature = "ledger")]
+use crate::domain::anoncreds::schema::Schema;
+#[cfg(feature = "ledger")]
+use crate::domain::anoncreds::verifier_pools::VerifierPools;
+#[cfg(feature = "ledger")]
+use crate::commands::{Command, CommandExecutor};
+#[cfg(feature = "ledger")]
+use crate::commands::anoncreds::AnoncredsCommand;
+#[cfg(feature = "ledger")]
+use crate::commands::ledger::LedgerCommand;
+#[cfg(feature = "ledger")]
+use indy_api_types::{IndyHandle, PoolHandle};
+#[cfg(feature = "ledger")]
+use indy_utils::next_command_handle;
 
 pub enum VerifierCommand {
     VerifyProof(
@@ -20,17 +46,99 @@ pub enum VerifierCommand {
         RevocationRegistries, // rev reg entries
         Box<dyn Fn(IndyResult<bool>) + Send>),
     GenerateNonce(
-        Box<dyn Fn(IndyResult<String>) + Send>)
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    ValidateProofRequest(
+        ProofRequest, // proof request
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    #[cfg(feature = "ledger")]
+    VerifyProofWithPools(
+        ProofRequest, // proof request
+        Proof, // proof
+        VerifierPools, // pools to fetch ledger objects from
+        Box<dyn Fn(IndyResult<bool>) + Send>),
+    #[cfg(feature = "ledger")]
+    VerifyProofWithPoolsAck(
+        IndyHandle, // verification handle
+        ProofLedgerObject, // fetched object
+        IndyResult<String>), // object json
+}
+
+/// Ledger object a proof is verified against.
+#[cfg(feature = "ledger")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ProofLedgerObject {
+    Schema(SchemaId),
+    CredDef(CredentialDefinitionId),
+    RevRegDef(RevocationRegistryId),
+    RevReg(RevocationRegistryId, u64),
+}
+
+#[cfg(feature = "ledger")]
+impl ProofLedgerObject {
+    fn issuer_did(&self) -> Option<DidValue> {
+        match *self {
+            ProofLedgerObject::Schema(ref id) => id.parts().map(|(did, _, _)| did),
+            ProofLedgerObject::CredDef(ref id) => id.issuer_did(),
+            ProofLedgerObject::RevRegDef(ref id) | ProofLedgerObject::RevReg(ref id, _) => id.parts().map(|(did, _, _, _)| did),
+        }
+    }
+}
+
+/// Proof waiting for the ledger objects it references to be fetched.
+#[cfg(feature = "ledger")]
+struct PendingVerification {
+    proof_req: ProofRequest,
+    proof: Proof,
+    schemas: HashMap<SchemaId, SchemaV1>,
+    cred_defs: HashMap<CredentialDefinitionId, CredentialDefinitionV1>,
+    rev_reg_defs: HashMap<RevocationRegistryId, RevocationRegistryDefinitionV1>,
+    rev_regs: HashMap<RevocationRegistryId, HashMap<u64, RevocationRegistryV1>>,
+    pending: usize,
+    cb: Box<dyn Fn(IndyResult<bool>) + Send>,
+}
+
+#[cfg(feature = "ledger")]
+impl PendingVerification {
+    fn add(&mut self, object: ProofLedgerObject, json: &str) -> IndyResult<()> {
+        match object {
+            ProofLedgerObject::Schema(id) => {
+                let schema = serde_json::from_str::<Schema>(json)
+                    .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize Schema")?;
+                self.schemas.insert(id, SchemaV1::from(schema));
+            }
+            ProofLedgerObject::CredDef(id) => {
+                let cred_def = serde_json::from_str::<CredentialDefinition>(json)
+                    .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize CredentialDefinition")?;
+                self.cred_defs.insert(id, CredentialDefinitionV1::from(cred_def));
+            }
+            ProofLedgerObject::RevRegDef(id) => {
+                let rev_reg_def = serde_json::from_str::<RevocationRegistryDefinition>(json)
+                    .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize RevocationRegistryDefinition")?;
+                self.rev_reg_defs.insert(id, RevocationRegistryDefinitionV1::from(rev_reg_def));
+            }
+            ProofLedgerObject::RevReg(id, timestamp) => {
+                let rev_reg = serde_json::from_str::<RevocationRegistry>(json)
+                    .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize RevocationRegistry")?;
+                self.rev_regs.entry(id).or_insert_with(HashMap::new).insert(timestamp, RevocationRegistryV1::from(rev_reg));
+            }
+        }
+
+        Ok(())
+    }
 }
 
 pub struct VerifierCommandExecutor {
     anoncreds_service: Rc<AnoncredsService>,
+    #[cfg(feature = "ledger")]
+    pending_verifications: RefCell<HashMap<IndyHandle, PendingVerification>>,
 }
 
 impl VerifierCommandExecutor {
     pub fn new(anoncreds_service: Rc<AnoncredsService>) -> VerifierCommandExecutor {
         VerifierCommandExecutor {
             anoncreds_service,
+            #[cfg(feature = "ledger")]
+            pending_verifications: RefCell::new(HashMap::new()),
         }
     }
 
@@ -38,40 +146,182 @@ impl VerifierCommandExecutor {
         match command {
             VerifierCommand::VerifyProof(proof_request, proof, schemas, credential_defs, rev_reg_defs, rev_regs, cb) => {
                 debug!(target: "verifier_command_executor", "VerifyProof command received");
-                cb(self.verify_proof(&proof_request.value(), proof,
-                                     &schemas_map_to_schemas_v1_map(schemas),
-                                     &cred_defs_map_to_cred_defs_v1_map(credential_defs),
-                                     &rev_reg_defs_map_to_rev_reg_defs_v1_map(rev_reg_defs),
-                                     &rev_regs_map_to_rev_regs_local_map(rev_regs)));
+                self.verify_proof(proof_request, proof,
+                                  schemas_map_to_schemas_v1_map(schemas),
+                                  cred_defs_map_to_cred_defs_v1_map(credential_defs),
+                                  rev_reg_defs_map_to_rev_reg_defs_v1_map(rev_reg_defs),
+                                  rev_regs_map_to_rev_regs_local_map(rev_regs),
+                                  cb);
             }
             VerifierCommand::GenerateNonce(cb) => {
                 debug!(target: "verifier_command_executor", "GenerateNonce command received");
                 cb(self.generate_nonce());
             }
+            VerifierCommand::ValidateProofRequest(proof_request, cb) => {
+                debug!(target: "verifier_command_executor", "ValidateProofRequest command received");
+                cb(self.validate_proof_request(&proof_request));
+            }
+            #[cfg(feature = "ledger")]
+            VerifierCommand::VerifyProofWithPools(proof_request, proof, pools, cb) => {
+                debug!(target: "verifier_command_executor", "VerifyProofWithPools command received");
+                self.verify_proof_with_pools(proof_request, proof, &pools, cb);
+            }
+            #[cfg(feature = "ledger")]
+            VerifierCommand::VerifyProofWithPoolsAck(verification_handle, object, res) => {
+                debug!(target: "verifier_command_executor", "VerifyProofWithPoolsAck command received");
+                self._verify_proof_with_pools_ack(verification_handle, object, res);
+            }
         };
     }
 
+    /// Verifies proof on the anoncreds workers so proof math doesn't block other commands.
+    #[allow(clippy::too_many_arguments)]
     fn verify_proof(&self,
-                    proof_req: &ProofRequestPayload,
+                    proof_req: ProofRequest,
                     proof: Proof,
-                    schemas: &HashMap<SchemaId, SchemaV1>,
-                    cred_defs: &HashMap<CredentialDefinitionId, CredentialDefinitionV1>,
-                    rev_reg_defs: &HashMap<RevocationRegistryId, RevocationRegistryDefinitionV1>,
-                    rev_regs: &HashMap<RevocationRegistryId, HashMap<u64, RevocationRegistryV1>>) -> IndyResult<bool> {
+                    schemas: HashMap<SchemaId, SchemaV1>,
+                    cred_defs: HashMap<CredentialDefinitionId, CredentialDefinitionV1>,
+                    rev_reg_defs: HashMap<RevocationRegistryId, RevocationRegistryDefinitionV1>,
+                    rev_regs: HashMap<RevocationRegistryId, HashMap<u64, RevocationRegistryV1>>,
+                    cb: Box<dyn Fn(IndyResult<bool>) + Send>) {
         debug!("verify_proof >>> proof_req: {:?}, proof: {:?}, schemas: {:?}, cred_defs: {:?},  \
                rev_reg_defs: {:?}, rev_regs: {:?}",
                proof_req, proof, schemas, cred_defs, rev_reg_defs, rev_regs);
 
-        let result = self.anoncreds_service.verifier.verify(&proof,
-                                                            &proof_req,
-                                                            schemas,
-                                                            cred_defs,
-                                                            rev_reg_defs,
-                                                            rev_regs)?;
+        let verifier = self.anoncreds_service.verifier.clone();
 
-        debug!("verify_proof <<< result: {:?}", result);
+        crate::commands::execute_anoncreds_job(None, move || {
+            let result = verifier.verify(&proof,
+                                         proof_req.value(),
+                                         &schemas,
+                                         &cred_defs,
+                                         &rev_reg_defs,
+                                         &rev_regs);
 
-        Ok(result)
+            debug!("verify_proof <<< result: {:?}", result);
+
+            result
+        }, cb);
+    }
+
+    /// Fetches schemas, credential definitions and revocation registries referenced by the proof
+    /// from the pools of their issuers and verifies the proof when all of them are received.
+    #[cfg(feature = "ledger")]
+    fn verify_proof_with_pools(&self,
+                               proof_req: ProofRequest,
+                               proof: Proof,
+                               pools: &VerifierPools,
+                               cb: Box<dyn Fn(IndyResult<bool>) + Send>) {
+        debug!("verify_proof_with_pools >>> proof_req: {:?}, proof: {:?}, pools: {:?}", proof_req, proof, pools);
+
+        let mut objects = HashSet::new();
+
+        for identifier in proof.identifiers.iter() {
+            objects.insert(ProofLedgerObject::Schema(identifier.schema_id.clone()));
+            objects.insert(ProofLedgerObject::CredDef(identifier.cred_def_id.clone()));
+
+            if let Some(ref rev_reg_id) = identifier.rev_reg_id {
+                objects.insert(ProofLedgerObject::RevRegDef(rev_reg_id.clone()));
+
+                if let Some(timestamp) = identifier.timestamp {
+                    objects.insert(ProofLedgerObject::RevReg(rev_reg_id.clone(), timestamp));
+                }
+            }
+        }
+
+        let objects = objects.into_iter()
+            .map(|object| {
+                object.issuer_did()
+                    .and_then(|issuer_did| pools.resolve(&issuer_did))
+                    .map(|pool_handle| (object.clone(), pool_handle))
+                    .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, format!("No pool to fetch {:?} from", object)))
+            })
+            .collect::<IndyResult<Vec<(ProofLedgerObject, PoolHandle)>>>();
+        let objects = try_cb!(objects, cb);
+
+        let verification = PendingVerification {
+            proof_req,
+            proof,
+            schemas: HashMap::new(),
+            cred_defs: HashMap::new(),
+            rev_reg_defs: HashMap::new(),
+            rev_regs: HashMap::new(),
+            pending: objects.len(),
+            cb,
+        };
+
+        if objects.is_empty() {
+            return self._finish_verification(verification);
+        }
+
+        let verification_handle = next_command_handle();
+        self.pending_verifications.borrow_mut().insert(verification_handle, verification);
+
+        for (object, pool_handle) in objects {
+            VerifierCommandExecutor::_fetch_ledger_object(pool_handle, verification_handle, object);
+        }
+    }
+
+    #[cfg(feature = "ledger")]
+    fn _fetch_ledger_object(pool_handle: PoolHandle, verification_handle: IndyHandle, object: ProofLedgerObject) {
+        let ack = move |object: ProofLedgerObject, res: IndyResult<String>| {
+            CommandExecutor::instance()
+                .send(Command::Anoncreds(AnoncredsCommand::Verifier(VerifierCommand::VerifyProofWithPoolsAck(verification_handle, object, res))))
+                .unwrap();
+        };
+
+        let command = match object.clone() {
+            ProofLedgerObject::Schema(id) =>
+                LedgerCommand::GetSchema(pool_handle, None, id,
+                                         Box::new(move |res| ack(object.clone(), res.map(|(_, json)| json)))),
+            ProofLedgerObject::CredDef(id) =>
+                LedgerCommand::GetCredDef(pool_handle, None, id,
+                                          Box::new(move |res| ack(object.clone(), res.map(|(_, json)| json)))),
+            ProofLedgerObject::RevRegDef(id) =>
+                LedgerCommand::GetRevocRegDef(pool_handle, None, id,
+                                              Box::new(move |res| ack(object.clone(), res.map(|(_, json)| json)))),
+            ProofLedgerObject::RevReg(id, timestamp) =>
+                LedgerCommand::GetRevocReg(pool_handle, None, id, timestamp as i64,
+                                           Box::new(move |res| ack(object.clone(), res.map(|(_, json, _)| json)))),
+        };
+
+        CommandExecutor::instance().send(Command::Ledger(command)).unwrap();
+    }
+
+    #[cfg(feature = "ledger")]
+    fn _verify_proof_with_pools_ack(&self, verification_handle: IndyHandle, object: ProofLedgerObject, res: IndyResult<String>) {
+        let mut verifications = self.pending_verifications.borrow_mut();
+
+        let verification = match verifications.get_mut(&verification_handle) {
+            Some(verification) => verification,
+            // Verification is already failed on another object
+            None => return
+        };
+
+        let description = format!("Cannot fetch {:?}", object);
+
+        match res.and_then(|json| verification.add(object, &json)) {
+            Ok(()) => {
+                verification.pending -= 1;
+
+                if verification.pending == 0 {
+                    let verification = verifications.remove(&verification_handle).unwrap();
+                    drop(verifications);
+                    self._finish_verification(verification);
+                }
+            }
+            Err(err) => {
+                let verification = verifications.remove(&verification_handle).unwrap();
+                (verification.cb)(Err(err.extend(description)));
+            }
+        }
+    }
+
+    #[cfg(feature = "ledger")]
+    fn _finish_verification(&self, verification: PendingVerification) {
+        let PendingVerification { proof_req, proof, schemas, cred_defs, rev_reg_defs, rev_regs, cb, .. } = verification;
+
+        self.verify_proof(proof_req, proof, schemas, cred_defs, rev_reg_defs, rev_regs, cb);
     }
 
     fn generate_nonce(&self) -> IndyResult<String> {
@@ -84,6 +334,18 @@ impl VerifierCommandExecutor {
 
         debug!("generate_nonce <<< result: {:?}", result);
 
+        Ok(result)
+    }
+    fn validate_proof_request(&self, proof_request: &ProofRequest) -> IndyResult<String> {
+        debug!("validate_proof_request >>> proof_request: {:?}", proof_request);
+
+        let errors = proof_request.validation_errors();
+
+        let result = serde_json::to_string(&errors)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize proof request validation errors")?;
+
+        debug!("validate_proof_request <<< result: {:?}", result);
+
         Ok(result)
     }
 }
@@ -1,6 +1,9 @@
-use crate::services::blob_storage::BlobStorageService;
+use crate::services::blob_storage::{BlobStorageService, PluggedReaderType, PluggedWriterType};
+use crate::utils::handles;
 use std::rc::Rc;
 
+use rust_base58::FromBase58;
+
 use indy_api_types::errors::prelude::*;
 
 pub enum BlobStorageCommand {
@@ -12,6 +15,25 @@ pub enum BlobStorageCommand {
         String, // writer type
         String, // writer config JSON
         Box<dyn Fn(IndyResult<i32 /* handle */>) + Send>),
+    CloseReader(
+        i32, // handle
+        Box<dyn Fn(IndyResult<()>) + Send>),
+    CloseWriter(
+        i32, // handle
+        Box<dyn Fn(IndyResult<()>) + Send>),
+    ReadTails(
+        i32, // reader handle
+        String, // tails location
+        String, // tails hash
+        Box<dyn Fn(IndyResult<Vec<u8>>) + Send>),
+    RegisterReader(
+        String, // type
+        PluggedReaderType,
+        Box<dyn Fn(IndyResult<()>) + Send>),
+    RegisterWriter(
+        String, // type
+        PluggedWriterType,
+        Box<dyn Fn(IndyResult<()>) + Send>),
 }
 
 pub struct BlobStorageCommandExecutor {
@@ -35,6 +57,26 @@ impl BlobStorageCommandExecutor {
                 debug!("OpenWriter command received");
                 cb(self.open_writer(&writer_type, &writer_config));
             }
+            BlobStorageCommand::CloseReader(handle, cb) => {
+                debug!("CloseReader command received");
+                cb(self.close_reader(handle));
+            }
+            BlobStorageCommand::CloseWriter(handle, cb) => {
+                debug!("CloseWriter command received");
+                cb(self.close_writer(handle));
+            }
+            BlobStorageCommand::ReadTails(handle, location, hash, cb) => {
+                debug!("ReadTails command received");
+                cb(self.read_tails(handle, &location, &hash));
+            }
+            BlobStorageCommand::RegisterReader(type_, reader_type, cb) => {
+                debug!("RegisterReader command received");
+                cb(self.register_reader(&type_, reader_type));
+            }
+            BlobStorageCommand::RegisterWriter(type_, writer_type, cb) => {
+                debug!("RegisterWriter command received");
+                cb(self.register_writer(&type_, writer_type));
+            }
         }
     }
 
@@ -43,6 +85,10 @@ impl BlobStorageCommandExecutor {
 
         let res = self.blob_storage_service.open_reader(type_, config).map_err(IndyError::from);
 
+        if let Ok(handle) = res {
+            handles::opened(handles::BLOB_STORAGE_READER, handle, Some(type_.to_string()));
+        }
+
         debug!("open_reader << res: {:?}", res);
 
         res
@@ -53,8 +99,69 @@ impl BlobStorageCommandExecutor {
 
         let res = self.blob_storage_service.open_writer(type_, config).map_err(IndyError::from);
 
+        if let Ok(handle) = res {
+            handles::opened(handles::BLOB_STORAGE_WRITER, handle, Some(type_.to_string()));
+        }
+
         debug!("open_writer << res: {:?}", res);
 
         res
     }
+
+    fn close_reader(&self, handle: i32) -> IndyResult<()> {
+        debug!("close_reader >>> handle: {:?}", handle);
+
+        let res = self.blob_storage_service.close_reader(handle);
+
+        handles::closed(handles::BLOB_STORAGE_READER, handle);
+
+        debug!("close_reader << res: {:?}", res);
+
+        res
+    }
+
+    fn close_writer(&self, handle: i32) -> IndyResult<()> {
+        debug!("close_writer >>> handle: {:?}", handle);
+
+        let res = self.blob_storage_service.close_writer(handle);
+
+        handles::closed(handles::BLOB_STORAGE_WRITER, handle);
+
+        debug!("close_writer << res: {:?}", res);
+
+        res
+    }
+
+    fn read_tails(&self, handle: i32, location: &str, hash: &str) -> IndyResult<Vec<u8>> {
+        debug!("read_tails >>> handle: {:?}, location: {:?}, hash: {:?}", handle, location, hash);
+
+        let hash = hash.from_base58()
+            .map_err(|_| err_msg(IndyErrorKind::InvalidStructure, "Invalid base58 for Tails hash"))?;
+
+        let res = self.blob_storage_service.read_blob(handle, location, &hash);
+
+        debug!("read_tails << res len: {:?}", res.as_ref().map(Vec::len));
+
+        res
+    }
+
+    fn register_reader(&self, type_: &str, reader_type: PluggedReaderType) -> IndyResult<()> {
+        debug!("register_reader >>> type_: {:?}", type_);
+
+        let res = self.blob_storage_service.register_reader_type(type_, reader_type);
+
+        debug!("register_reader << res: {:?}", res);
+
+        res
+    }
+
+    fn register_writer(&self, type_: &str, writer_type: PluggedWriterType) -> IndyResult<()> {
+        debug!("register_writer >>> type_: {:?}", type_);
+
+        let res = self.blob_storage_service.register_writer_type(type_, writer_type);
+
+        debug!("register_writer << res: {:?}", res);
+
+        res
+    }
 }
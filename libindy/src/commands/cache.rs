@@ -1,23 +1,72 @@
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::rc::Rc;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use indy_api_types::domain::wallet::Tags;
-use crate::domain::anoncreds::schema::SchemaId;
-use crate::domain::anoncreds::credential_definition::CredentialDefinitionId;
+use crate::domain::anoncreds::schema::{Schema, SchemaV1, SchemaId};
+use crate::domain::anoncreds::credential_definition::{CredentialDefinition, CredentialDefinitionV1, CredentialDefinitionId};
+use crate::domain::anoncreds::revocation_registry_definition::{RevocationRegistryDefinition, RevocationRegistryDefinitionV1, RevocationRegistryId};
+use crate::domain::anoncreds::revocation_registry_delta::{RevocationRegistryDelta, RevocationRegistryDeltaV1};
 use indy_api_types::errors::prelude::*;
 use indy_wallet::{WalletService, WalletRecord};
+use crate::services::pool::PoolService;
 use indy_api_types::{WalletHandle, PoolHandle, CommandHandle};
-use crate::commands::{Command, CommandExecutor};
+use crate::commands::{Command, CommandExecutor, BoxedCallbackStringStringSend, BoxedCallbackStringStringU64Send};
 use crate::commands::ledger::LedgerCommand;
-use crate::domain::cache::{GetCacheOptions, PurgeOptions};
+use crate::domain::cache::{GetCacheOptions, PurgeOptions, PrefetchIds, CacheStats, CacheConfig, EvictionPolicy};
 use crate::domain::crypto::did::DidValue;
 
 use indy_utils::next_command_handle;
 
 const CRED_DEF_CACHE: &str = "cred_def_cache";
 const SCHEMA_CACHE: &str = "schema_cache";
+const REV_REG_DEF_CACHE: &str = "rev_reg_def_cache";
+const REV_REG_CACHE: &str = "rev_reg_cache";
+const REV_REG_DELTA_CACHE: &str = "rev_reg_delta_cache";
+const TIMESTAMP_TAG: &str = "timestamp";
+const EXPIRES_TAG: &str = "~expires";
+const ACCESSED_TAG: &str = "~accessed";
+const HITS_TAG: &str = "~hits";
+const REV_REG_ID_TAG: &str = "rev_reg_id";
+const FROM_TAG: &str = "~from";
+const TO_TAG: &str = "~to";
+const TXN_TIME_TAG: &str = "~txn_time";
+const NOT_FOUND_TAG: &str = "~not_found";
+const POOL_TAG: &str = "~pool";
+const DEFAULT_NOT_FOUND_TTL: i32 = 60;
+const LOCAL_SCHEMA_REGISTRY: &str = "local_schema_registry";
+const LOCAL_CRED_DEF_REGISTRY: &str = "local_cred_def_registry";
+const LOCAL_REV_REG_DEF_REGISTRY: &str = "local_rev_reg_def_registry";
+
+// cache record types and names of object kinds they are reported under in cache statistics
+const CACHES: [(&str, &str); 5] = [
+    (SCHEMA_CACHE, "schemas"),
+    (CRED_DEF_CACHE, "credDefs"),
+    (REV_REG_DEF_CACHE, "revRegDefs"),
+    (REV_REG_CACHE, "revRegs"),
+    (REV_REG_DELTA_CACHE, "revRegDeltas"),
+];
+
+/// Parameters of GET_REVOC_REG_DELTA request kept while ledger response is pending.
+/// `valid_to` is the moment up to which received delta is known to be actual.
+#[derive(Clone)]
+pub struct RevocRegDeltaRequest {
+    pool_handle: PoolHandle,
+    pool_name: String,
+    submitter_did: DidValue,
+    id: RevocationRegistryId,
+    from: Option<i64>,
+    to: i64,
+    valid_to: i64,
+}
+
+struct PendingPrefetch {
+    cb: Box<dyn Fn(IndyResult<String>)>,
+    pool_name: String,
+    pending: usize,
+    failed: HashMap<String, String>,
+}
 
 pub enum CacheCommand {
     GetSchema(PoolHandle,
@@ -28,6 +77,8 @@ pub enum CacheCommand {
               Box<dyn Fn(IndyResult<String>) + Send>),
     GetSchemaContinue(
         WalletHandle,
+        String,                       // id
+        String,                       // pool_name
         IndyResult<(String, String)>, // ledger_response
         GetCacheOptions,              // options
         CommandHandle,                          // cb_id
@@ -40,50 +91,142 @@ pub enum CacheCommand {
                Box<dyn Fn(IndyResult<String>) + Send>),
     GetCredDefContinue(
         WalletHandle,
+        String,                       // id
+        String,                       // pool_name
+        IndyResult<(String, String)>, // ledger_response
+        GetCacheOptions,              // options
+        CommandHandle,                          // cb_id
+    ),
+    GetRevocRegDef(PoolHandle,
+                   WalletHandle,
+                   DidValue, // submitter_did
+                   RevocationRegistryId, // id
+                   GetCacheOptions, // options
+                   Box<dyn Fn(IndyResult<String>) + Send>),
+    GetRevocRegDefContinue(
+        WalletHandle,
+        String,                       // id
+        String,                       // pool_name
         IndyResult<(String, String)>, // ledger_response
         GetCacheOptions,              // options
         CommandHandle,                          // cb_id
     ),
+    GetRevocReg(PoolHandle,
+                WalletHandle,
+                DidValue, // submitter_did
+                RevocationRegistryId, // id
+                i64, // timestamp
+                GetCacheOptions, // options
+                BoxedCallbackStringStringU64Send),
+    GetRevocRegContinue(
+        WalletHandle,
+        String,                              // pool_name
+        i64,                                 // valid_to
+        IndyResult<(String, String, u64)>, // ledger_response
+        GetCacheOptions,                     // options
+        CommandHandle,                       // cb_id
+    ),
+    GetRevocRegDelta(PoolHandle,
+                     WalletHandle,
+                     DidValue, // submitter_did
+                     RevocationRegistryId, // id
+                     Option<i64>, // from
+                     i64, // to
+                     GetCacheOptions, // options
+                     BoxedCallbackStringStringU64Send),
+    GetRevocRegDeltaContinue(
+        WalletHandle,
+        RevocRegDeltaRequest,                // request
+        Option<String>,                      // cached delta to merge with
+        IndyResult<(String, String, u64)>, // ledger_response
+        GetCacheOptions,                     // options
+        CommandHandle,                       // cb_id
+    ),
     PurgeSchemaCache(WalletHandle,
                      PurgeOptions, // options
                      Box<dyn Fn(IndyResult<()>) + Send>),
     PurgeCredDefCache(WalletHandle,
                       PurgeOptions, // options
                       Box<dyn Fn(IndyResult<()>) + Send>),
+    PurgeRevocRegCache(WalletHandle,
+                       PurgeOptions, // options
+                       Box<dyn Fn(IndyResult<()>) + Send>),
+    PrefetchLedgerCache(PoolHandle,
+                        WalletHandle,
+                        PrefetchIds, // ids
+                        Box<dyn Fn(IndyResult<String>) + Send>),
+    PrefetchLedgerCacheContinue(
+        WalletHandle,
+        &'static str,                 // which_cache
+        String,                       // requested id
+        IndyResult<(String, String)>, // ledger_response
+        CommandHandle,                // prefetch_id
+    ),
+    GetCacheStats(WalletHandle,
+                  Box<dyn Fn(IndyResult<String>) + Send>),
+    SetCacheConfig(WalletHandle,
+                   CacheConfig,
+                   Box<dyn Fn(IndyResult<()>) + Send>),
+    RegisterLocalSchema(WalletHandle,
+                        Schema,
+                        Box<dyn Fn(IndyResult<()>) + Send>),
+    RegisterLocalCredDef(WalletHandle,
+                         CredentialDefinition,
+                         Box<dyn Fn(IndyResult<()>) + Send>),
+    RegisterLocalRevocRegDef(WalletHandle,
+                             RevocationRegistryDefinition,
+                             Box<dyn Fn(IndyResult<()>) + Send>),
+    // sent by wallet command executor on wallet close
+    WalletClosed(WalletHandle),
+}
+
+#[derive(Default)]
+struct LookupCounters {
+    hits: u64,
+    misses: u64,
+    evictions: u64,
+    oversized: u64,
 }
 
 pub struct CacheCommandExecutor {
     wallet_service: Rc<WalletService>,
+    pool_service: Rc<PoolService>,
 
     pending_callbacks: RefCell<HashMap<CommandHandle, Box<dyn Fn(IndyResult<String>)>>>,
+    pending_rev_reg_callbacks: RefCell<HashMap<CommandHandle, BoxedCallbackStringStringU64Send>>,
+    pending_prefetches: RefCell<HashMap<CommandHandle, PendingPrefetch>>,
+    lookups: RefCell<HashMap<String, LookupCounters>>,
+    configs: RefCell<HashMap<WalletHandle, CacheConfig>>,
 }
 
 macro_rules! check_cache {
-    ($cache: ident, $options: ident, $cb: ident) => {
+    ($self: ident, $cache: ident, $options: ident, $which_cache: expr, $cb: ident) => {
     if let Some(cache) = $cache {
-            let min_fresh = $options.min_fresh.unwrap_or(-1);
-            if min_fresh >= 0 {
-                let ts = match CacheCommandExecutor::get_seconds_since_epoch() {
-                    Ok(ts) => ts,
-                    Err(err) => {
-                        return $cb(Err(err))
-                    }
-                };
-                if ts - min_fresh <= cache.get_tags().unwrap_or(&Tags::new()).get("timestamp").unwrap_or(&"-1".to_string()).parse().unwrap_or(-1) {
+            if try_cb!(CacheCommandExecutor::is_fresh(&cache, &$options), $cb) {
+                if !CacheCommandExecutor::is_not_found(&cache) {
+                    $self._count_lookup($which_cache, true);
                     return $cb(Ok(cache.get_value().unwrap_or("").to_string()))
                 }
-            } else {
-                return $cb(Ok(cache.get_value().unwrap_or("").to_string()))
+                if !$options.no_negative_cache.unwrap_or(false) {
+                    $self._count_lookup($which_cache, true);
+                    return $cb(Err(IndyError::from_msg(IndyErrorKind::LedgerItemNotFound, "Object is cached as not found on the ledger")))
+                }
             }
         }
+        $self._count_lookup($which_cache, false);
     };
 }
 
 impl CacheCommandExecutor {
-    pub fn new(wallet_service: Rc<WalletService>) -> CacheCommandExecutor {
+    pub fn new(wallet_service: Rc<WalletService>, pool_service: Rc<PoolService>) -> CacheCommandExecutor {
         CacheCommandExecutor {
             wallet_service,
+            pool_service,
             pending_callbacks: RefCell::new(HashMap::new()),
+            pending_rev_reg_callbacks: RefCell::new(HashMap::new()),
+            pending_prefetches: RefCell::new(HashMap::new()),
+            lookups: RefCell::new(HashMap::new()),
+            configs: RefCell::new(HashMap::new()),
         }
     }
 
@@ -93,17 +236,41 @@ impl CacheCommandExecutor {
                 debug!(target: "non_secrets_command_executor", "GetSchema command received");
                 self.get_schema(pool_handle, wallet_handle, &submitter_did, &id, options, cb);
             }
-            CacheCommand::GetSchemaContinue(wallet_handle, ledger_response, options, cb_id) => {
+            CacheCommand::GetSchemaContinue(wallet_handle, id, pool_name, ledger_response, options, cb_id) => {
                 debug!(target: "non_secrets_command_executor", "GetSchemaContinue command received");
-                self._get_schema_continue(wallet_handle, ledger_response, options, cb_id);
+                self._get_schema_continue(wallet_handle, id, pool_name, ledger_response, options, cb_id);
             }
             CacheCommand::GetCredDef(pool_handle, wallet_handle, submitter_did, id, options, cb) => {
                 debug!(target: "non_secrets_command_executor", "GetCredDef command received");
                 self.get_cred_def(pool_handle, wallet_handle, &submitter_did, &id, options, cb);
             }
-            CacheCommand::GetCredDefContinue(wallet_handle, ledger_response, options, cb_id) => {
+            CacheCommand::GetCredDefContinue(wallet_handle, id, pool_name, ledger_response, options, cb_id) => {
                 debug!(target: "non_secrets_command_executor", "GetCredDefContinue command received");
-                self._get_cred_def_continue(wallet_handle, ledger_response, options, cb_id);
+                self._get_cred_def_continue(wallet_handle, id, pool_name, ledger_response, options, cb_id);
+            }
+            CacheCommand::GetRevocRegDef(pool_handle, wallet_handle, submitter_did, id, options, cb) => {
+                debug!(target: "non_secrets_command_executor", "GetRevocRegDef command received");
+                self.get_revoc_reg_def(pool_handle, wallet_handle, &submitter_did, &id, options, cb);
+            }
+            CacheCommand::GetRevocRegDefContinue(wallet_handle, id, pool_name, ledger_response, options, cb_id) => {
+                debug!(target: "non_secrets_command_executor", "GetRevocRegDefContinue command received");
+                self._get_revoc_reg_def_continue(wallet_handle, id, pool_name, ledger_response, options, cb_id);
+            }
+            CacheCommand::GetRevocReg(pool_handle, wallet_handle, submitter_did, id, timestamp, options, cb) => {
+                debug!(target: "non_secrets_command_executor", "GetRevocReg command received");
+                self.get_revoc_reg(pool_handle, wallet_handle, &submitter_did, &id, timestamp, options, cb);
+            }
+            CacheCommand::GetRevocRegContinue(wallet_handle, pool_name, valid_to, ledger_response, options, cb_id) => {
+                debug!(target: "non_secrets_command_executor", "GetRevocRegContinue command received");
+                self._get_revoc_reg_continue(wallet_handle, pool_name, valid_to, ledger_response, options, cb_id);
+            }
+            CacheCommand::GetRevocRegDelta(pool_handle, wallet_handle, submitter_did, id, from, to, options, cb) => {
+                debug!(target: "non_secrets_command_executor", "GetRevocRegDelta command received");
+                self.get_revoc_reg_delta(pool_handle, wallet_handle, &submitter_did, &id, from, to, options, cb);
+            }
+            CacheCommand::GetRevocRegDeltaContinue(wallet_handle, request, cached_delta, ledger_response, options, cb_id) => {
+                debug!(target: "non_secrets_command_executor", "GetRevocRegDeltaContinue command received");
+                self._get_revoc_reg_delta_continue(wallet_handle, request, cached_delta, ledger_response, options, cb_id);
             }
             CacheCommand::PurgeSchemaCache(wallet_handle, options, cb) => {
                 debug!(target: "non_secrets_command_executor", "PurgeSchemaCache command received");
@@ -113,6 +280,42 @@ impl CacheCommandExecutor {
                 debug!(target: "non_secrets_command_executor", "PurgeCredDefCache command received");
                 cb(self.purge_cred_def_cache(wallet_handle, options));
             }
+            CacheCommand::PurgeRevocRegCache(wallet_handle, options, cb) => {
+                debug!(target: "non_secrets_command_executor", "PurgeRevocRegCache command received");
+                cb(self.purge_revoc_reg_cache(wallet_handle, options));
+            }
+            CacheCommand::PrefetchLedgerCache(pool_handle, wallet_handle, ids, cb) => {
+                debug!(target: "non_secrets_command_executor", "PrefetchLedgerCache command received");
+                self.prefetch_ledger_cache(pool_handle, wallet_handle, ids, cb);
+            }
+            CacheCommand::PrefetchLedgerCacheContinue(wallet_handle, which_cache, id, ledger_response, prefetch_id) => {
+                debug!(target: "non_secrets_command_executor", "PrefetchLedgerCacheContinue command received");
+                self._prefetch_ledger_cache_continue(wallet_handle, which_cache, id, ledger_response, prefetch_id);
+            }
+            CacheCommand::GetCacheStats(wallet_handle, cb) => {
+                debug!(target: "non_secrets_command_executor", "GetCacheStats command received");
+                cb(self.get_cache_stats(wallet_handle));
+            }
+            CacheCommand::SetCacheConfig(wallet_handle, config, cb) => {
+                debug!(target: "non_secrets_command_executor", "SetCacheConfig command received");
+                cb(self.set_cache_config(wallet_handle, config));
+            }
+            CacheCommand::RegisterLocalSchema(wallet_handle, schema, cb) => {
+                debug!(target: "non_secrets_command_executor", "RegisterLocalSchema command received");
+                cb(self.register_local_schema(wallet_handle, schema));
+            }
+            CacheCommand::RegisterLocalCredDef(wallet_handle, cred_def, cb) => {
+                debug!(target: "non_secrets_command_executor", "RegisterLocalCredDef command received");
+                cb(self.register_local_cred_def(wallet_handle, cred_def));
+            }
+            CacheCommand::RegisterLocalRevocRegDef(wallet_handle, rev_reg_def, cb) => {
+                debug!(target: "non_secrets_command_executor", "RegisterLocalRevocRegDef command received");
+                cb(self.register_local_revoc_reg_def(wallet_handle, rev_reg_def));
+            }
+            CacheCommand::WalletClosed(wallet_handle) => {
+                debug!(target: "non_secrets_command_executor", "WalletClosed command received");
+                self.wallet_closed(wallet_handle);
+            }
         }
     }
 
@@ -126,18 +329,28 @@ impl CacheCommandExecutor {
         trace!("get_schema >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, id: {:?}, options: {:?}",
                pool_handle, wallet_handle, submitter_did, id, options);
 
+        if let Some(local_json) = try_cb!(self.get_from_local_registry(wallet_handle, &id.0, SCHEMA_CACHE), cb) {
+            return cb(Ok(local_json));
+        }
+
+        let options = self._with_config(wallet_handle, SCHEMA_CACHE, options);
+
         let cache = self.get_record_from_cache(wallet_handle, &id.0, &options, SCHEMA_CACHE);
         let cache = try_cb!(cache, cb);
 
-        check_cache!(cache, options, cb);
+        check_cache!(self, cache, options, SCHEMA_CACHE, cb);
 
         if options.no_update.unwrap_or(false) {
             return cb(Err(IndyError::from(IndyErrorKind::LedgerItemNotFound)));
         }
 
+        let pool_name = try_cb!(self.pool_service.get_pool_name(pool_handle), cb);
+
         let cb_id = next_command_handle();
         self.pending_callbacks.borrow_mut().insert(cb_id, cb);
 
+        let requested_id = id.0.clone();
+
         CommandExecutor::instance().send(
             Command::Ledger(
                 LedgerCommand::GetSchema(
@@ -149,6 +362,8 @@ impl CacheCommandExecutor {
                             Command::Cache(
                                 CacheCommand::GetSchemaContinue(
                                     wallet_handle,
+                                    requested_id.clone(),
+                                    pool_name.clone(),
                                     ledger_response,
                                     options.clone(),
                                     cb_id,
@@ -166,10 +381,17 @@ impl CacheCommandExecutor {
                               options: GetCacheOptions,
                               schema_id: &str,
                               schema_json: &str,
-                              which_cache: &str) -> IndyResult<()>
+                              which_cache: &str,
+                              mut tags: Tags) -> IndyResult<()>
     {
         if !options.no_store.unwrap_or(false) {
-            let mut tags = Tags::new();
+            if options.max_entry_size.map(|max_entry_size| schema_json.len() > max_entry_size).unwrap_or(false) {
+                // the previously cached data is outdated anyway
+                let _ignore = self.wallet_service.delete_record(wallet_handle, which_cache, &schema_id);
+                self._update_counters(which_cache, |counters| counters.oversized += 1);
+                return Ok(());
+            }
+
             let ts = match SystemTime::now().duration_since(UNIX_EPOCH) {
                 Ok(ts) => ts.as_secs() as i32,
                 Err(err) => {
@@ -177,22 +399,108 @@ impl CacheCommandExecutor {
                     0
                 }
             };
-            tags.insert("timestamp".to_string(), ts.to_string());
+            tags.insert(TIMESTAMP_TAG.to_string(), ts.to_string());
+            tags.insert(ACCESSED_TAG.to_string(), ts.to_string());
+            let ttl = options.ttl.unwrap_or(-1);
+            if ttl >= 0 {
+                tags.insert(EXPIRES_TAG.to_string(), (ts + ttl).to_string());
+            }
             let _ignore = self.wallet_service.delete_record(wallet_handle, which_cache, &schema_id);
-            self.wallet_service.add_record(wallet_handle, which_cache, &schema_id, &schema_json, &tags)?
+            self.wallet_service.add_record(wallet_handle, which_cache, schema_id, schema_json, &tags)?;
+
+            if let Some(max_entries) = options.max_entries {
+                let eviction_policy = options.eviction_policy.unwrap_or(EvictionPolicy::Lru);
+                self._evict_entries(wallet_handle, which_cache, schema_id, max_entries, eviction_policy)?;
+            }
         }
         Ok(())
     }
 
+    fn _store_not_found(&self,
+                        wallet_handle: WalletHandle,
+                        options: GetCacheOptions,
+                        id: &str,
+                        which_cache: &str,
+                        pool_name: &str,
+                        err: IndyError) -> IndyError {
+        if err.kind() != IndyErrorKind::LedgerItemNotFound {
+            return err;
+        }
+
+        let ttl = options.not_found_ttl.unwrap_or(DEFAULT_NOT_FOUND_TTL);
+        if ttl <= 0 {
+            return err;
+        }
+
+        let mut tags = CacheCommandExecutor::pool_tags(pool_name);
+        tags.insert(NOT_FOUND_TAG.to_string(), true.to_string());
+
+        let options = GetCacheOptions { ttl: Some(ttl), ..options };
+
+        if let Err(store_err) = self._delete_and_add_record(wallet_handle, options, id, "", which_cache, tags) {
+            warn!("Cannot cache not found result for {}: {:?}", id, store_err);
+        }
+
+        err
+    }
+
+    fn _evict_entries(&self,
+                      wallet_handle: WalletHandle,
+                      which_cache: &str,
+                      stored_id: &str,
+                      max_entries: usize,
+                      eviction_policy: EvictionPolicy) -> IndyResult<()> {
+        let options_json = json!({
+            "retrieveType": false,
+            "retrieveValue": false,
+            "retrieveTags": true,
+        }).to_string();
+
+        let mut search = self.wallet_service.search_records(wallet_handle, which_cache, "{}", &options_json)?;
+
+        // entries are evicted in the order of (hits, accessed), hits are not taken into account by LRU
+        let mut entries: Vec<(i64, i32, String)> = Vec::new();
+        while let Some(record) = search.fetch_next_record()? {
+            if record.get_id() != stored_id {
+                let hits = match eviction_policy {
+                    EvictionPolicy::Lfu => CacheCommandExecutor::get_tag_i64(&record, HITS_TAG).unwrap_or(0),
+                    EvictionPolicy::Lru => 0,
+                };
+                let accessed = CacheCommandExecutor::get_tag_i32(&record, ACCESSED_TAG)
+                    .or_else(|| CacheCommandExecutor::get_tag_i32(&record, TIMESTAMP_TAG))
+                    .unwrap_or(0);
+                entries.push((hits, accessed, record.get_id().to_string()));
+            }
+        }
+
+        // the stored entry is always kept
+        let keep = max_entries.saturating_sub(1);
+        if entries.len() > keep {
+            entries.sort();
+            let evict_count = entries.len() - keep;
+            for (_, _, id) in entries.into_iter().take(evict_count) {
+                self.wallet_service.delete_record(wallet_handle, which_cache, &id)?;
+            }
+            self._update_counters(which_cache, |counters| counters.evictions += evict_count as u64);
+        }
+
+        Ok(())
+    }
+
     fn _get_schema_continue(&self,
                             wallet_handle: WalletHandle,
+                            id: String,
+                            pool_name: String,
                             ledger_response: IndyResult<(String, String)>,
                             options: GetCacheOptions, cb_id: CommandHandle) {
         let cb = self.pending_callbacks.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
 
-        let (schema_id, schema_json) = try_cb!(ledger_response, cb);
+        let (schema_id, schema_json) = match ledger_response {
+            Ok(res) => res,
+            Err(err) => return cb(Err(self._store_not_found(wallet_handle, options, &id, SCHEMA_CACHE, &pool_name, err)))
+        };
 
-        match self._delete_and_add_record(wallet_handle, options, &schema_id, &schema_json, SCHEMA_CACHE) {
+        match self._delete_and_add_record(wallet_handle, options, &schema_id, &schema_json, SCHEMA_CACHE, CacheCommandExecutor::pool_tags(&pool_name)) {
             Ok(_) => cb(Ok(schema_json)),
             Err(err) => cb(Err(IndyError::from_msg(IndyErrorKind::InvalidState, format!("get_schema_continue failed: {:?}", err))))
         }
@@ -208,18 +516,28 @@ impl CacheCommandExecutor {
         trace!("get_cred_def >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, id: {:?}, options: {:?}",
                pool_handle, wallet_handle, submitter_did, id, options);
 
+        if let Some(local_json) = try_cb!(self.get_from_local_registry(wallet_handle, &id.0, CRED_DEF_CACHE), cb) {
+            return cb(Ok(local_json));
+        }
+
+        let options = self._with_config(wallet_handle, CRED_DEF_CACHE, options);
+
         let cache = self.get_record_from_cache(wallet_handle, &id.0, &options, CRED_DEF_CACHE);
         let cache = try_cb!(cache, cb);
 
-        check_cache!(cache, options, cb);
+        check_cache!(self, cache, options, CRED_DEF_CACHE, cb);
 
         if options.no_update.unwrap_or(false) {
             return cb(Err(IndyError::from(IndyErrorKind::LedgerItemNotFound)));
         }
 
+        let pool_name = try_cb!(self.pool_service.get_pool_name(pool_handle), cb);
+
         let cb_id = next_command_handle();
         self.pending_callbacks.borrow_mut().insert(cb_id, cb);
 
+        let requested_id = id.0.clone();
+
         CommandExecutor::instance().send(
             Command::Ledger(
                 LedgerCommand::GetCredDef(
@@ -231,6 +549,8 @@ impl CacheCommandExecutor {
                             Command::Cache(
                                 CacheCommand::GetCredDefContinue(
                                     wallet_handle,
+                                    requested_id.clone(),
+                                    pool_name.clone(),
                                     ledger_response,
                                     options.clone(),
                                     cb_id,
@@ -250,24 +570,822 @@ impl CacheCommandExecutor {
                 "retrieveValue": true,
                 "retrieveTags": true,
             }).to_string();
-            match self.wallet_service.get_record(wallet_handle, which_cache, &id, &options_json) {
-                Ok(record) => Ok(Some(record)),
-                Err(err) => if err.kind() == IndyErrorKind::WalletItemNotFound { Ok(None) } else { Err(err) }
+            let record = match self.wallet_service.get_record(wallet_handle, which_cache, &id, &options_json) {
+                Ok(record) => record,
+                Err(err) => return if err.kind() == IndyErrorKind::WalletItemNotFound { Ok(None) } else { Err(err) }
+            };
+
+            self._check_expiration(wallet_handle, record, which_cache)
+        } else { Ok(None) }
+    }
+
+    fn search_record_in_cache(&self, wallet_handle: WalletHandle, query_json: &str, options: &GetCacheOptions, which_cache: &str) -> Result<Option<WalletRecord>, IndyError> {
+        if !options.no_cache.unwrap_or(false) {
+            let options_json = json!({
+                "retrieveType": false,
+                "retrieveValue": true,
+                "retrieveTags": true,
+            }).to_string();
+
+            let mut search = self.wallet_service.search_records(wallet_handle, which_cache, query_json, &options_json)?;
+
+            while let Some(record) = search.fetch_next_record()? {
+                if let Some(record) = self._check_expiration(wallet_handle, record, which_cache)? {
+                    return Ok(Some(record));
+                }
             }
+
+            Ok(None)
         } else { Ok(None) }
     }
 
-    fn _get_cred_def_continue(&self, wallet_handle: WalletHandle, ledger_response: IndyResult<(String, String)>, options: GetCacheOptions, cb_id: CommandHandle) {
+    fn _check_expiration(&self, wallet_handle: WalletHandle, record: WalletRecord, which_cache: &str) -> Result<Option<WalletRecord>, IndyError> {
+        let ts = CacheCommandExecutor::get_seconds_since_epoch()?;
+
+        if CacheCommandExecutor::get_tag_i32(&record, EXPIRES_TAG).map(|expires| expires <= ts).unwrap_or(false) {
+            self.wallet_service.delete_record(wallet_handle, which_cache, record.get_id())?;
+            return Ok(None);
+        }
+
+        let hits = CacheCommandExecutor::get_tag_i64(&record, HITS_TAG).unwrap_or(0);
+
+        let mut accessed = Tags::new();
+        accessed.insert(ACCESSED_TAG.to_string(), ts.to_string());
+        accessed.insert(HITS_TAG.to_string(), (hits + 1).to_string());
+        self.wallet_service.add_record_tags(wallet_handle, which_cache, record.get_id(), &accessed)?;
+
+        Ok(Some(record))
+    }
+
+    fn pool_tags(pool_name: &str) -> Tags {
+        let mut tags = Tags::new();
+        tags.insert(POOL_TAG.to_string(), pool_name.to_string());
+        tags
+    }
+
+    fn is_not_found(record: &WalletRecord) -> bool {
+        record.get_tags()
+            .map(|tags| tags.contains_key(NOT_FOUND_TAG))
+            .unwrap_or(false)
+    }
+
+    fn is_fresh(record: &WalletRecord, options: &GetCacheOptions) -> IndyResult<bool> {
+        let min_fresh = options.min_fresh.unwrap_or(-1);
+        if min_fresh >= 0 {
+            let ts = CacheCommandExecutor::get_seconds_since_epoch()?;
+            Ok(ts - min_fresh <= CacheCommandExecutor::get_tag_i32(record, TIMESTAMP_TAG).unwrap_or(-1))
+        } else {
+            Ok(true)
+        }
+    }
+
+    fn get_tag_i32(record: &WalletRecord, name: &str) -> Option<i32> {
+        record.get_tags()
+            .and_then(|tags| tags.get(name))
+            .and_then(|value| value.parse().ok())
+    }
+
+    fn get_tag_i64(record: &WalletRecord, name: &str) -> Option<i64> {
+        record.get_tags()
+            .and_then(|tags| tags.get(name))
+            .and_then(|value| value.parse().ok())
+    }
+
+    fn _get_cred_def_continue(&self, wallet_handle: WalletHandle, id: String, pool_name: String, ledger_response: IndyResult<(String, String)>, options: GetCacheOptions, cb_id: CommandHandle) {
         let cb = self.pending_callbacks.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
 
-        let (cred_def_id, cred_def_json) = try_cb!(ledger_response, cb);
+        let (cred_def_id, cred_def_json) = match ledger_response {
+            Ok(res) => res,
+            Err(err) => return cb(Err(self._store_not_found(wallet_handle, options, &id, CRED_DEF_CACHE, &pool_name, err)))
+        };
 
-        match self._delete_and_add_record(wallet_handle, options, &cred_def_id, &cred_def_json, CRED_DEF_CACHE) {
+        match self._delete_and_add_record(wallet_handle, options, &cred_def_id, &cred_def_json, CRED_DEF_CACHE, CacheCommandExecutor::pool_tags(&pool_name)) {
             Ok(_) => cb(Ok(cred_def_json)),
             Err(err) => cb(Err(IndyError::from_msg(IndyErrorKind::InvalidState, format!("get_cred_def_continue failed: {:?}", err))))
         }
     }
 
+    fn get_revoc_reg_def(&self,
+                         pool_handle: PoolHandle,
+                         wallet_handle: WalletHandle,
+                         submitter_did: &DidValue,
+                         id: &RevocationRegistryId,
+                         options: GetCacheOptions,
+                         cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        trace!("get_revoc_reg_def >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, id: {:?}, options: {:?}",
+               pool_handle, wallet_handle, submitter_did, id, options);
+
+        if let Some(local_json) = try_cb!(self.get_from_local_registry(wallet_handle, &id.0, REV_REG_DEF_CACHE), cb) {
+            return cb(Ok(local_json));
+        }
+
+        let options = self._with_config(wallet_handle, REV_REG_DEF_CACHE, options);
+
+        let cache = self.get_record_from_cache(wallet_handle, &id.0, &options, REV_REG_DEF_CACHE);
+        let cache = try_cb!(cache, cb);
+
+        check_cache!(self, cache, options, REV_REG_DEF_CACHE, cb);
+
+        if options.no_update.unwrap_or(false) {
+            return cb(Err(IndyError::from(IndyErrorKind::LedgerItemNotFound)));
+        }
+
+        let pool_name = try_cb!(self.pool_service.get_pool_name(pool_handle), cb);
+
+        let cb_id = next_command_handle();
+        self.pending_callbacks.borrow_mut().insert(cb_id, cb);
+
+        let requested_id = id.0.clone();
+
+        CommandExecutor::instance().send(
+            Command::Ledger(
+                LedgerCommand::GetRevocRegDef(
+                    pool_handle,
+                    Some(submitter_did.clone()),
+                    id.clone(),
+                    Box::new(move |ledger_response| {
+                        CommandExecutor::instance().send(
+                            Command::Cache(
+                                CacheCommand::GetRevocRegDefContinue(
+                                    wallet_handle,
+                                    requested_id.clone(),
+                                    pool_name.clone(),
+                                    ledger_response,
+                                    options.clone(),
+                                    cb_id,
+                                )
+                            )
+                        ).unwrap();
+                    })
+                )
+            )
+        ).unwrap();
+    }
+
+    fn _get_revoc_reg_def_continue(&self, wallet_handle: WalletHandle, id: String, pool_name: String, ledger_response: IndyResult<(String, String)>, options: GetCacheOptions, cb_id: CommandHandle) {
+        let cb = self.pending_callbacks.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
+
+        let (revoc_reg_def_id, revoc_reg_def_json) = match ledger_response {
+            Ok(res) => res,
+            Err(err) => return cb(Err(self._store_not_found(wallet_handle, options, &id, REV_REG_DEF_CACHE, &pool_name, err)))
+        };
+
+        match self._delete_and_add_record(wallet_handle, options, &revoc_reg_def_id, &revoc_reg_def_json, REV_REG_DEF_CACHE, CacheCommandExecutor::pool_tags(&pool_name)) {
+            Ok(_) => cb(Ok(revoc_reg_def_json)),
+            Err(err) => cb(Err(IndyError::from_msg(IndyErrorKind::InvalidState, format!("get_revoc_reg_def_continue failed: {:?}", err))))
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn get_revoc_reg(&self,
+                     pool_handle: PoolHandle,
+                     wallet_handle: WalletHandle,
+                     submitter_did: &DidValue,
+                     id: &RevocationRegistryId,
+                     timestamp: i64,
+                     options: GetCacheOptions,
+                     cb: BoxedCallbackStringStringU64Send) {
+        trace!("get_revoc_reg >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, id: {:?}, timestamp: {:?}, options: {:?}",
+               pool_handle, wallet_handle, submitter_did, id, timestamp, options);
+
+        // cached state covers the interval between its transaction time and the latest moment it was requested for
+        let query_json = json!({
+            REV_REG_ID_TAG: id.0,
+            FROM_TAG: {"$lte": CacheCommandExecutor::pad_timestamp(timestamp)},
+            TO_TAG: {"$gte": CacheCommandExecutor::pad_timestamp(timestamp)},
+        }).to_string();
+
+        let options = self._with_config(wallet_handle, REV_REG_CACHE, options);
+
+        let cache = self.search_record_in_cache(wallet_handle, &query_json, &options, REV_REG_CACHE);
+        let cache = try_cb!(cache, cb);
+
+        if let Some(cache) = cache {
+            if try_cb!(CacheCommandExecutor::is_fresh(&cache, &options), cb) {
+                let txn_time = CacheCommandExecutor::get_tag_i64(&cache, FROM_TAG).unwrap_or(0);
+                self._count_lookup(REV_REG_CACHE, true);
+                return cb(Ok((id.0.clone(), cache.get_value().unwrap_or("").to_string(), txn_time as u64)));
+            }
+        }
+
+        self._count_lookup(REV_REG_CACHE, false);
+
+        if options.no_update.unwrap_or(false) {
+            return cb(Err(IndyError::from(IndyErrorKind::LedgerItemNotFound)));
+        }
+
+        let now = try_cb!(CacheCommandExecutor::get_seconds_since_epoch(), cb);
+        let valid_to = ::std::cmp::min(timestamp, i64::from(now));
+        let pool_name = try_cb!(self.pool_service.get_pool_name(pool_handle), cb);
+
+        let cb_id = next_command_handle();
+        self.pending_rev_reg_callbacks.borrow_mut().insert(cb_id, cb);
+
+        CommandExecutor::instance().send(
+            Command::Ledger(
+                LedgerCommand::GetRevocReg(
+                    pool_handle,
+                    Some(submitter_did.clone()),
+                    id.clone(),
+                    timestamp,
+                    Box::new(move |ledger_response| {
+                        CommandExecutor::instance().send(
+                            Command::Cache(
+                                CacheCommand::GetRevocRegContinue(
+                                    wallet_handle,
+                                    pool_name.clone(),
+                                    valid_to,
+                                    ledger_response,
+                                    options.clone(),
+                                    cb_id,
+                                )
+                            )
+                        ).unwrap();
+                    })
+                )
+            )
+        ).unwrap();
+    }
+
+    fn _get_revoc_reg_continue(&self,
+                               wallet_handle: WalletHandle,
+                               pool_name: String,
+                               valid_to: i64,
+                               ledger_response: IndyResult<(String, String, u64)>,
+                               options: GetCacheOptions,
+                               cb_id: CommandHandle) {
+        let cb = self.pending_rev_reg_callbacks.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
+
+        let (revoc_reg_def_id, revoc_reg_json, txn_time) = try_cb!(ledger_response, cb);
+
+        match self._store_revoc_reg(wallet_handle, options, &pool_name, &revoc_reg_def_id, &revoc_reg_json, txn_time as i64, valid_to) {
+            Ok(_) => cb(Ok((revoc_reg_def_id, revoc_reg_json, txn_time))),
+            Err(err) => cb(Err(IndyError::from_msg(IndyErrorKind::InvalidState, format!("get_revoc_reg_continue failed: {:?}", err))))
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn _store_revoc_reg(&self,
+                        wallet_handle: WalletHandle,
+                        options: GetCacheOptions,
+                        pool_name: &str,
+                        revoc_reg_def_id: &str,
+                        revoc_reg_json: &str,
+                        txn_time: i64,
+                        valid_to: i64) -> IndyResult<()> {
+        let record_id = format!("{}:{}", revoc_reg_def_id, txn_time);
+
+        // the same state may be already known to be actual for a longer interval
+        let options_json = json!({
+            "retrieveType": false,
+            "retrieveValue": false,
+            "retrieveTags": true,
+        }).to_string();
+
+        let known_to = match self.wallet_service.get_record(wallet_handle, REV_REG_CACHE, &record_id, &options_json) {
+            Ok(record) => CacheCommandExecutor::get_tag_i64(&record, TO_TAG),
+            Err(ref err) if err.kind() == IndyErrorKind::WalletItemNotFound => None,
+            Err(err) => return Err(err)
+        };
+
+        let valid_to = ::std::cmp::max(::std::cmp::max(valid_to, txn_time), known_to.unwrap_or(txn_time));
+
+        let mut tags = CacheCommandExecutor::pool_tags(pool_name);
+        tags.insert(REV_REG_ID_TAG.to_string(), revoc_reg_def_id.to_string());
+        tags.insert(FROM_TAG.to_string(), CacheCommandExecutor::pad_timestamp(txn_time));
+        tags.insert(TO_TAG.to_string(), CacheCommandExecutor::pad_timestamp(valid_to));
+
+        self._delete_and_add_record(wallet_handle, options, &record_id, revoc_reg_json, REV_REG_CACHE, tags)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn get_revoc_reg_delta(&self,
+                           pool_handle: PoolHandle,
+                           wallet_handle: WalletHandle,
+                           submitter_did: &DidValue,
+                           id: &RevocationRegistryId,
+                           from: Option<i64>,
+                           to: i64,
+                           options: GetCacheOptions,
+                           cb: BoxedCallbackStringStringU64Send) {
+        trace!("get_revoc_reg_delta >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, id: {:?}, from: {:?}, to: {:?}, options: {:?}",
+               pool_handle, wallet_handle, submitter_did, id, from, to, options);
+
+        let record_id = CacheCommandExecutor::revoc_reg_delta_record_id(&id.0, from);
+
+        let options = self._with_config(wallet_handle, REV_REG_DELTA_CACHE, options);
+
+        let cache = self.get_record_from_cache(wallet_handle, &record_id, &options, REV_REG_DELTA_CACHE);
+        let cache = try_cb!(cache, cb);
+
+        let mut cached_delta = None;
+
+        if let Some(cache) = cache {
+            let txn_time = CacheCommandExecutor::get_tag_i64(&cache, TXN_TIME_TAG).unwrap_or(0);
+            let valid_to = CacheCommandExecutor::get_tag_i64(&cache, TO_TAG).unwrap_or(txn_time);
+
+            if txn_time <= to && to <= valid_to && try_cb!(CacheCommandExecutor::is_fresh(&cache, &options), cb) {
+                self._count_lookup(REV_REG_DELTA_CACHE, true);
+                return cb(Ok((id.0.clone(), cache.get_value().unwrap_or("").to_string(), txn_time as u64)));
+            }
+
+            // only the tail of the requested range is missing, so the cached delta can be extended
+            if valid_to < to {
+                cached_delta = Some((valid_to, cache.get_value().unwrap_or("").to_string()));
+            }
+        }
+
+        self._count_lookup(REV_REG_DELTA_CACHE, false);
+
+        if options.no_update.unwrap_or(false) {
+            return cb(Err(IndyError::from(IndyErrorKind::LedgerItemNotFound)));
+        }
+
+        let now = try_cb!(CacheCommandExecutor::get_seconds_since_epoch(), cb);
+        let pool_name = try_cb!(self.pool_service.get_pool_name(pool_handle), cb);
+
+        let request = RevocRegDeltaRequest {
+            pool_handle,
+            pool_name,
+            submitter_did: submitter_did.clone(),
+            id: id.clone(),
+            from,
+            to,
+            valid_to: ::std::cmp::min(to, i64::from(now)),
+        };
+
+        let cb_id = next_command_handle();
+        self.pending_rev_reg_callbacks.borrow_mut().insert(cb_id, cb);
+
+        self._send_get_revoc_reg_delta(wallet_handle, request, cached_delta, options, cb_id);
+    }
+
+    fn _send_get_revoc_reg_delta(&self,
+                                 wallet_handle: WalletHandle,
+                                 request: RevocRegDeltaRequest,
+                                 cached_delta: Option<(i64, String)>,
+                                 options: GetCacheOptions,
+                                 cb_id: CommandHandle) {
+        let (from, cached_delta) = match cached_delta {
+            Some((valid_to, cached_delta)) => (Some(valid_to), Some(cached_delta)),
+            None => (request.from, None)
+        };
+
+        let pool_handle = request.pool_handle;
+        let submitter_did = request.submitter_did.clone();
+        let id = request.id.clone();
+        let to = request.to;
+
+        CommandExecutor::instance().send(
+            Command::Ledger(
+                LedgerCommand::GetRevocRegDelta(
+                    pool_handle,
+                    Some(submitter_did),
+                    id,
+                    from,
+                    to,
+                    Box::new(move |ledger_response| {
+                        CommandExecutor::instance().send(
+                            Command::Cache(
+                                CacheCommand::GetRevocRegDeltaContinue(
+                                    wallet_handle,
+                                    request.clone(),
+                                    cached_delta.clone(),
+                                    ledger_response,
+                                    options.clone(),
+                                    cb_id,
+                                )
+                            )
+                        ).unwrap();
+                    })
+                )
+            )
+        ).unwrap();
+    }
+
+    fn _get_revoc_reg_delta_continue(&self,
+                                     wallet_handle: WalletHandle,
+                                     request: RevocRegDeltaRequest,
+                                     cached_delta: Option<String>,
+                                     ledger_response: IndyResult<(String, String, u64)>,
+                                     options: GetCacheOptions,
+                                     cb_id: CommandHandle) {
+        let (revoc_reg_def_id, revoc_reg_delta_json, txn_time) = match ledger_response {
+            Ok(res) => res,
+            Err(err) => {
+                let cb = self.pending_rev_reg_callbacks.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
+                return cb(Err(err));
+            }
+        };
+
+        let revoc_reg_delta_json = match cached_delta {
+            Some(cached_delta) => match CacheCommandExecutor::merge_revoc_reg_deltas(&cached_delta, &revoc_reg_delta_json) {
+                Ok(merged) => merged,
+                Err(err) => {
+                    warn!("Cannot extend cached revocation registry delta, requesting the whole range: {:?}", err);
+                    return self._send_get_revoc_reg_delta(wallet_handle, request, None, options, cb_id);
+                }
+            },
+            None => revoc_reg_delta_json
+        };
+
+        let cb = self.pending_rev_reg_callbacks.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
+
+        match self._store_revoc_reg_delta(wallet_handle, options, &request, &revoc_reg_delta_json, txn_time as i64) {
+            Ok(_) => cb(Ok((revoc_reg_def_id, revoc_reg_delta_json, txn_time))),
+            Err(err) => cb(Err(IndyError::from_msg(IndyErrorKind::InvalidState, format!("get_revoc_reg_delta_continue failed: {:?}", err))))
+        }
+    }
+
+    fn _store_revoc_reg_delta(&self,
+                              wallet_handle: WalletHandle,
+                              options: GetCacheOptions,
+                              request: &RevocRegDeltaRequest,
+                              revoc_reg_delta_json: &str,
+                              txn_time: i64) -> IndyResult<()> {
+        let record_id = CacheCommandExecutor::revoc_reg_delta_record_id(&request.id.0, request.from);
+
+        // never replace cached delta by an older one
+        let options_json = json!({
+            "retrieveType": false,
+            "retrieveValue": false,
+            "retrieveTags": true,
+        }).to_string();
+
+        match self.wallet_service.get_record(wallet_handle, REV_REG_DELTA_CACHE, &record_id, &options_json) {
+            Ok(ref record) if CacheCommandExecutor::get_tag_i64(record, TXN_TIME_TAG).map(|cached| cached > txn_time).unwrap_or(false) => return Ok(()),
+            Ok(_) => {}
+            Err(ref err) if err.kind() == IndyErrorKind::WalletItemNotFound => {}
+            Err(err) => return Err(err)
+        };
+
+        let mut tags = CacheCommandExecutor::pool_tags(&request.pool_name);
+        tags.insert(REV_REG_ID_TAG.to_string(), request.id.0.clone());
+        tags.insert(TXN_TIME_TAG.to_string(), CacheCommandExecutor::pad_timestamp(txn_time));
+        tags.insert(TO_TAG.to_string(), CacheCommandExecutor::pad_timestamp(::std::cmp::max(request.valid_to, txn_time)));
+
+        self._delete_and_add_record(wallet_handle, options, &record_id, revoc_reg_delta_json, REV_REG_DELTA_CACHE, tags)
+    }
+
+    fn merge_revoc_reg_deltas(cached_delta: &str, delta: &str) -> IndyResult<String> {
+        let mut cached_delta = RevocationRegistryDeltaV1::from(
+            serde_json::from_str::<RevocationRegistryDelta>(cached_delta)
+                .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize cached RevocationRegistryDelta")?);
+
+        let delta = RevocationRegistryDeltaV1::from(
+            serde_json::from_str::<RevocationRegistryDelta>(delta)
+                .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize RevocationRegistryDelta")?);
+
+        cached_delta.value.merge(&delta.value)?;
+
+        serde_json::to_string(&RevocationRegistryDelta::RevocationRegistryDeltaV1(cached_delta))
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize RevocationRegistryDelta")
+    }
+
+    fn prefetch_ledger_cache(&self,
+                             pool_handle: PoolHandle,
+                             wallet_handle: WalletHandle,
+                             ids: PrefetchIds,
+                             cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        trace!("prefetch_ledger_cache >>> pool_handle: {:?}, wallet_handle: {:?}, ids: {:?}", pool_handle, wallet_handle, ids);
+
+        let options = GetCacheOptions::default();
+
+        // already cached and locally registered objects are skipped, so lookups are done before any ledger request is sent.
+        // objects cached as not found are requested again.
+        let mut schemas = Vec::new();
+        for id in ids.schemas {
+            if !self._is_cached(wallet_handle, SCHEMA_CACHE) {
+                break;
+            }
+            if try_cb!(self.get_from_local_registry(wallet_handle, &id.0, SCHEMA_CACHE), cb).is_some() {
+                continue;
+            }
+            if try_cb!(self.get_record_from_cache(wallet_handle, &id.0, &options, SCHEMA_CACHE), cb).filter(|record| !CacheCommandExecutor::is_not_found(record)).is_none() {
+                schemas.push(id);
+            }
+        }
+
+        let mut cred_defs = Vec::new();
+        for id in ids.cred_defs {
+            if !self._is_cached(wallet_handle, CRED_DEF_CACHE) {
+                break;
+            }
+            if try_cb!(self.get_from_local_registry(wallet_handle, &id.0, CRED_DEF_CACHE), cb).is_some() {
+                continue;
+            }
+            if try_cb!(self.get_record_from_cache(wallet_handle, &id.0, &options, CRED_DEF_CACHE), cb).filter(|record| !CacheCommandExecutor::is_not_found(record)).is_none() {
+                cred_defs.push(id);
+            }
+        }
+
+        let mut rev_reg_defs = Vec::new();
+        for id in ids.rev_reg_defs {
+            if !self._is_cached(wallet_handle, REV_REG_DEF_CACHE) {
+                break;
+            }
+            if try_cb!(self.get_from_local_registry(wallet_handle, &id.0, REV_REG_DEF_CACHE), cb).is_some() {
+                continue;
+            }
+            if try_cb!(self.get_record_from_cache(wallet_handle, &id.0, &options, REV_REG_DEF_CACHE), cb).filter(|record| !CacheCommandExecutor::is_not_found(record)).is_none() {
+                rev_reg_defs.push(id);
+            }
+        }
+
+        let pending = schemas.len() + cred_defs.len() + rev_reg_defs.len();
+
+        if pending == 0 {
+            return cb(Ok(json!({}).to_string()));
+        }
+
+        let pool_name = try_cb!(self.pool_service.get_pool_name(pool_handle), cb);
+
+        let prefetch_id = next_command_handle();
+        self.pending_prefetches.borrow_mut().insert(prefetch_id, PendingPrefetch { cb, pool_name, pending, failed: HashMap::new() });
+
+        for id in schemas {
+            let requested_id = id.0.clone();
+            CommandExecutor::instance().send(
+                Command::Ledger(
+                    LedgerCommand::GetSchema(
+                        pool_handle,
+                        None,
+                        id,
+                        CacheCommandExecutor::_prefetch_callback(wallet_handle, SCHEMA_CACHE, requested_id, prefetch_id),
+                    )
+                )
+            ).unwrap();
+        }
+
+        for id in cred_defs {
+            let requested_id = id.0.clone();
+            CommandExecutor::instance().send(
+                Command::Ledger(
+                    LedgerCommand::GetCredDef(
+                        pool_handle,
+                        None,
+                        id,
+                        CacheCommandExecutor::_prefetch_callback(wallet_handle, CRED_DEF_CACHE, requested_id, prefetch_id),
+                    )
+                )
+            ).unwrap();
+        }
+
+        for id in rev_reg_defs {
+            let requested_id = id.0.clone();
+            CommandExecutor::instance().send(
+                Command::Ledger(
+                    LedgerCommand::GetRevocRegDef(
+                        pool_handle,
+                        None,
+                        id,
+                        CacheCommandExecutor::_prefetch_callback(wallet_handle, REV_REG_DEF_CACHE, requested_id, prefetch_id),
+                    )
+                )
+            ).unwrap();
+        }
+    }
+
+    fn _prefetch_callback(wallet_handle: WalletHandle,
+                          which_cache: &'static str,
+                          requested_id: String,
+                          prefetch_id: CommandHandle) -> BoxedCallbackStringStringSend {
+        Box::new(move |ledger_response| {
+            CommandExecutor::instance().send(
+                Command::Cache(
+                    CacheCommand::PrefetchLedgerCacheContinue(
+                        wallet_handle,
+                        which_cache,
+                        requested_id.clone(),
+                        ledger_response,
+                        prefetch_id,
+                    )
+                )
+            ).unwrap();
+        })
+    }
+
+    fn _prefetch_ledger_cache_continue(&self,
+                                       wallet_handle: WalletHandle,
+                                       which_cache: &str,
+                                       requested_id: String,
+                                       ledger_response: IndyResult<(String, String)>,
+                                       prefetch_id: CommandHandle) {
+        let pool_name = self.pending_prefetches.borrow().get(&prefetch_id).expect("FIXME INVALID STATE").pool_name.clone();
+
+        let options = self._with_config(wallet_handle, which_cache, GetCacheOptions::default());
+
+        let res = match ledger_response {
+            Ok((id, json)) => self._delete_and_add_record(wallet_handle, options, &id, &json, which_cache, CacheCommandExecutor::pool_tags(&pool_name)),
+            Err(err) => Err(self._store_not_found(wallet_handle, options, &requested_id, which_cache, &pool_name, err))
+        };
+
+        let prefetch = {
+            let mut pending_prefetches = self.pending_prefetches.borrow_mut();
+            let prefetch = pending_prefetches.get_mut(&prefetch_id).expect("FIXME INVALID STATE");
+
+            if let Err(err) = res {
+                prefetch.failed.insert(requested_id, err.to_string());
+            }
+
+            prefetch.pending -= 1;
+
+            if prefetch.pending > 0 {
+                return;
+            }
+
+            pending_prefetches.remove(&prefetch_id).expect("FIXME INVALID STATE")
+        };
+
+        let res = serde_json::to_string(&prefetch.failed)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize prefetch result");
+
+        trace!("prefetch_ledger_cache <<< res: {:?}", res);
+
+        (prefetch.cb)(res)
+    }
+
+    fn _count_lookup(&self, which_cache: &str, hit: bool) {
+        self._update_counters(which_cache, |counters| if hit {
+            counters.hits += 1;
+        } else {
+            counters.misses += 1;
+        });
+    }
+
+    fn _update_counters<F: FnOnce(&mut LookupCounters)>(&self, which_cache: &str, update: F) {
+        let mut lookups = self.lookups.borrow_mut();
+        update(lookups.entry(which_cache.to_string()).or_insert_with(LookupCounters::default));
+    }
+
+    fn _with_config(&self, wallet_handle: WalletHandle, which_cache: &str, options: GetCacheOptions) -> GetCacheOptions {
+        match self.configs.borrow().get(&wallet_handle) {
+            Some(config) if !config.is_included(CacheCommandExecutor::cache_kind(which_cache)) =>
+                GetCacheOptions { no_cache: Some(true), no_store: Some(true), ..options },
+            Some(config) => options.with_defaults(config),
+            None => options
+        }
+    }
+
+    fn _is_cached(&self, wallet_handle: WalletHandle, which_cache: &str) -> bool {
+        self.configs.borrow().get(&wallet_handle)
+            .map(|config| config.is_included(CacheCommandExecutor::cache_kind(which_cache)))
+            .unwrap_or(true)
+    }
+
+    fn cache_kind(which_cache: &str) -> &'static str {
+        CACHES.iter()
+            .find(|(cache, _)| *cache == which_cache)
+            .map(|(_, kind)| *kind)
+            .unwrap_or("")
+    }
+
+    fn set_cache_config(&self, wallet_handle: WalletHandle, config: CacheConfig) -> IndyResult<()> {
+        trace!("set_cache_config >>> wallet_handle: {:?}, config: {:?}", wallet_handle, config);
+
+        self.wallet_service.check(wallet_handle)?;
+
+        if let Some(ref include) = config.include {
+            if let Some(unknown) = include.iter().find(|kind| !CACHES.iter().any(|(_, known)| known == kind)) {
+                return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Unknown cached object kind: {}", unknown)));
+            }
+        }
+
+        self.configs.borrow_mut().insert(wallet_handle, config);
+
+        trace!("set_cache_config <<<");
+
+        Ok(())
+    }
+
+    fn wallet_closed(&self, wallet_handle: WalletHandle) {
+        trace!("wallet_closed >>> wallet_handle: {:?}", wallet_handle);
+
+        self.configs.borrow_mut().remove(&wallet_handle);
+
+        trace!("wallet_closed <<<");
+    }
+
+    fn register_local_schema(&self, wallet_handle: WalletHandle, schema: Schema) -> IndyResult<()> {
+        trace!("register_local_schema >>> wallet_handle: {:?}, schema: {:?}", wallet_handle, schema);
+
+        let schema = SchemaV1::from(schema);
+        let id = schema.id.0.clone();
+
+        let schema_json = serde_json::to_string(&Schema::SchemaV1(schema))
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize Schema")?;
+
+        self._register_local(wallet_handle, LOCAL_SCHEMA_REGISTRY, &id, &schema_json)
+    }
+
+    fn register_local_cred_def(&self, wallet_handle: WalletHandle, cred_def: CredentialDefinition) -> IndyResult<()> {
+        trace!("register_local_cred_def >>> wallet_handle: {:?}, cred_def: {:?}", wallet_handle, cred_def);
+
+        let cred_def = CredentialDefinitionV1::from(cred_def);
+        let id = cred_def.id.0.clone();
+
+        let cred_def_json = serde_json::to_string(&CredentialDefinition::CredentialDefinitionV1(cred_def))
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize CredentialDefinition")?;
+
+        self._register_local(wallet_handle, LOCAL_CRED_DEF_REGISTRY, &id, &cred_def_json)
+    }
+
+    fn register_local_revoc_reg_def(&self, wallet_handle: WalletHandle, rev_reg_def: RevocationRegistryDefinition) -> IndyResult<()> {
+        trace!("register_local_revoc_reg_def >>> wallet_handle: {:?}, rev_reg_def: {:?}", wallet_handle, rev_reg_def);
+
+        let rev_reg_def = RevocationRegistryDefinitionV1::from(rev_reg_def);
+        let id = rev_reg_def.id.0.clone();
+
+        let rev_reg_def_json = serde_json::to_string(&RevocationRegistryDefinition::RevocationRegistryDefinitionV1(rev_reg_def))
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize RevocationRegistryDefinition")?;
+
+        self._register_local(wallet_handle, LOCAL_REV_REG_DEF_REGISTRY, &id, &rev_reg_def_json)
+    }
+
+    // Registered object replaces the previously registered one with the same id
+    fn _register_local(&self, wallet_handle: WalletHandle, which_registry: &str, id: &str, object_json: &str) -> IndyResult<()> {
+        let _ignore = self.wallet_service.delete_record(wallet_handle, which_registry, id);
+        self.wallet_service.add_record(wallet_handle, which_registry, id, object_json, &Tags::new())?;
+
+        trace!("_register_local <<< which_registry: {:?}, id: {:?}", which_registry, id);
+
+        Ok(())
+    }
+
+    /// Local registry is trusted, so objects registered in it are returned without cache or ledger lookup.
+    fn get_from_local_registry(&self, wallet_handle: WalletHandle, id: &str, which_cache: &str) -> IndyResult<Option<String>> {
+        let which_registry = match which_cache {
+            SCHEMA_CACHE => LOCAL_SCHEMA_REGISTRY,
+            CRED_DEF_CACHE => LOCAL_CRED_DEF_REGISTRY,
+            REV_REG_DEF_CACHE => LOCAL_REV_REG_DEF_REGISTRY,
+            _ => return Ok(None)
+        };
+
+        let options_json = json!({
+            "retrieveType": false,
+            "retrieveValue": true,
+            "retrieveTags": false,
+        }).to_string();
+
+        match self.wallet_service.get_record(wallet_handle, which_registry, id, &options_json) {
+            Ok(record) => Ok(record.get_value().map(String::from)),
+            Err(err) => if err.kind() == IndyErrorKind::WalletItemNotFound { Ok(None) } else { Err(err) }
+        }
+    }
+
+    fn get_cache_stats(&self, wallet_handle: WalletHandle) -> IndyResult<String> {
+        trace!("get_cache_stats >>> wallet_handle: {:?}", wallet_handle);
+
+        let options_json = json!({
+            "retrieveType": false,
+            "retrieveValue": true,
+            "retrieveTags": true,
+        }).to_string();
+
+        let mut res = BTreeMap::new();
+
+        for (which_cache, kind) in CACHES.iter() {
+            let mut stats = CacheStats::default();
+
+            if let Some(counters) = self.lookups.borrow().get(*which_cache) {
+                stats.hits = counters.hits;
+                stats.misses = counters.misses;
+                stats.evictions = counters.evictions;
+                stats.oversized = counters.oversized;
+            }
+
+            let mut search = self.wallet_service.search_records(wallet_handle, which_cache, "{}", &options_json)?;
+
+            while let Some(record) = search.fetch_next_record()? {
+                stats.entries += 1;
+                stats.size += record.get_value().map(str::len).unwrap_or(0);
+
+                if let Some(ts) = CacheCommandExecutor::get_tag_i32(&record, TIMESTAMP_TAG) {
+                    stats.oldest = Some(stats.oldest.map_or(ts, |oldest| ::std::cmp::min(oldest, ts)));
+                    stats.newest = Some(stats.newest.map_or(ts, |newest| ::std::cmp::max(newest, ts)));
+                }
+            }
+
+            res.insert(*kind, stats);
+        }
+
+        let res = serde_json::to_string(&res)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize cache statistics")?;
+
+        trace!("get_cache_stats <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn revoc_reg_delta_record_id(revoc_reg_def_id: &str, from: Option<i64>) -> String {
+        format!("{}:{}", revoc_reg_def_id, from.unwrap_or(0))
+    }
+
+    fn pad_timestamp(timestamp: i64) -> String {
+        // plain tags are compared as strings
+        format!("{:020}", timestamp)
+    }
+
     fn get_seconds_since_epoch() -> Result<i32, IndyError> {
         match SystemTime::now().duration_since(UNIX_EPOCH) {
             Ok(ts) => Ok(ts.as_secs() as i32),
@@ -278,13 +1396,20 @@ impl CacheCommandExecutor {
         }
     }
 
-    fn build_query_json(max_age: i32) -> Result<String, IndyError> {
+    fn build_query_json(options: &PurgeOptions) -> Result<String, IndyError> {
+        let mut query = serde_json::Map::new();
+
+        let max_age = options.max_age.unwrap_or(-1);
         if max_age >= 0 {
             let ts = CacheCommandExecutor::get_seconds_since_epoch()?;
-            Ok(json!({"timestamp": {"$lt": ts - max_age}}).to_string())
-        } else {
-            Ok("{}".to_string())
+            query.insert("timestamp".to_string(), json!({"$lt": ts - max_age}));
+        }
+
+        if let Some(ref pool_name) = options.pool_name {
+            query.insert(POOL_TAG.to_string(), json!(pool_name));
         }
+
+        Ok(serde_json::Value::Object(query).to_string())
     }
 
     fn purge_schema_cache(&self,
@@ -292,8 +1417,7 @@ impl CacheCommandExecutor {
                           options: PurgeOptions) -> IndyResult<()> {
         trace!("purge_schema_cache >>> wallet_handle: {:?}, options: {:?}", wallet_handle, options);
 
-        let max_age = options.max_age.unwrap_or(-1);
-        let query_json = CacheCommandExecutor::build_query_json(max_age)?;
+        let query_json = CacheCommandExecutor::build_query_json(&options)?;
 
         let options_json = json!({
             "retrieveType": false,
@@ -322,8 +1446,7 @@ impl CacheCommandExecutor {
                             options: PurgeOptions) -> IndyResult<()> {
         trace!("purge_cred_def_cache >>> wallet_handle: {:?}, options: {:?}", wallet_handle, options);
 
-        let max_age = options.max_age.unwrap_or(-1);
-        let query_json = CacheCommandExecutor::build_query_json(max_age)?;
+        let query_json = CacheCommandExecutor::build_query_json(&options)?;
 
         let options_json = json!({
             "retrieveType": false,
@@ -346,4 +1469,35 @@ impl CacheCommandExecutor {
 
         Ok(())
     }
+
+    fn purge_revoc_reg_cache(&self,
+                             wallet_handle: WalletHandle,
+                             options: PurgeOptions) -> IndyResult<()> {
+        trace!("purge_revoc_reg_cache >>> wallet_handle: {:?}, options: {:?}", wallet_handle, options);
+
+        let query_json = CacheCommandExecutor::build_query_json(&options)?;
+
+        let options_json = json!({
+            "retrieveType": false,
+            "retrieveValue": false,
+            "retrieveTags": false,
+        }).to_string();
+
+        for which_cache in &[REV_REG_DEF_CACHE, REV_REG_CACHE, REV_REG_DELTA_CACHE] {
+            let mut search = self.wallet_service.search_records(
+                wallet_handle,
+                which_cache,
+                &query_json,
+                &options_json,
+            )?;
+
+            while let Some(record) = search.fetch_next_record()? {
+                self.wallet_service.delete_record(wallet_handle, which_cache, record.get_id())?;
+            }
+        }
+
+        trace!("purge_revoc_reg_cache <<< res: ()");
+
+        Ok(())
+    }
 }
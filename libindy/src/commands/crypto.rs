@@ -11,6 +11,7 @@ use std::str;
 use indy_utils::crypto::base64;
 use indy_utils::crypto::chacha20poly1305_ietf;
 use crate::domain::crypto::combo_box::ComboBox;
+use crate::utils::audit;
 use indy_api_types::WalletHandle;
 
 pub const PROTECTED_HEADER_ENC: &str = "xchacha20poly1305_ietf";
@@ -161,6 +162,8 @@ impl CryptoCommandExecutor {
         self.wallet_service
             .add_indy_object(wallet_handle, &key.verkey, &key, &HashMap::new())?;
 
+        audit::record(audit::KEY_CREATED, wallet_handle, json!({"verkey": key.verkey}));
+
         let res = key.verkey.to_string();
         debug!("create_key <<< res: {:?}", res);
         Ok(res)
@@ -182,6 +185,8 @@ impl CryptoCommandExecutor {
 
         let res = self.crypto_service.sign(&key, msg)?;
 
+        audit::record(audit::MESSAGE_SIGNED, wallet_handle, json!({"verkey": my_vk}));
+
         trace!("crypto_sign <<< res: {:?}", res);
 
         Ok(res)
@@ -4,21 +4,42 @@ use std::rc::Rc;
 
 use serde_json;
 
-use crate::commands::{Command, CommandExecutor, BoxedCallbackStringStringSend};
+use crate::commands::{BoxedCallbackStringStringSend, BoxedCallbackSearchHandleUsizeSend};
+#[cfg(feature = "ledger")]
+use crate::commands::{Command, CommandExecutor};
+#[cfg(feature = "ledger")]
 use crate::commands::ledger::LedgerCommand;
-use crate::domain::crypto::did::{Did, DidValue, DidMetadata, DidWithMeta, MyDidInfo, TemporaryDid, TheirDid, TheirDidInfo, DidMethod};
-use crate::domain::crypto::key::KeyInfo;
-use crate::domain::ledger::attrib::{AttribData, Endpoint, GetAttrReplyResult};
+use crate::domain::crypto::did::{Did, DidValue, DidMetadata, DidWithMeta, MyDidInfo, TemporaryDid, TheirDid, TheirDidInfo, DidMethod, DidKey, DidKeys, DidService, DidServices, DidSearchOptions, DidDeactivation, QualifyDidsOptions, DidQualification, DidsQualificationReport};
+#[cfg(feature = "ledger")]
+use crate::domain::crypto::did::DidKeyPurpose;
+#[cfg(feature = "ledger")]
+use crate::domain::crypto::did_doc::DidDocument;
+use crate::domain::crypto::did_bundle::{DidBundle, PairwiseBundle, DID_BUNDLE_VERSION};
+use crate::domain::crypto::key::{Key, KeyInfo};
+use crate::domain::ledger::attrib::{Endpoint, NamedEndpoint, NamedEndpoints};
+#[cfg(feature = "ledger")]
+use crate::domain::ledger::attrib::{AttribData, EndpointsAttribData, GetAttrReplyResult, ENDPOINTS_ATTRIB_NAME};
+#[cfg(feature = "ledger")]
 use crate::domain::ledger::nym::{GetNymReplyResult, GetNymResultDataV0};
-use crate::domain::ledger::response::Reply;
-use crate::domain::pairwise::Pairwise;
+#[cfg(feature = "ledger")]
+use crate::domain::ledger::response::{Message, Reply};
+use crate::domain::pairwise::{Pairwise, PairwiseMeta};
 use indy_api_types::errors::prelude::*;
 use crate::services::crypto::CryptoService;
+#[cfg(feature = "ledger")]
 use crate::services::ledger::LedgerService;
-use indy_wallet::{RecordOptions, SearchOptions, WalletService};
-use indy_api_types::{WalletHandle, PoolHandle, CommandHandle};
+use crate::utils::audit;
+use crate::utils::handles;
+use indy_wallet::{AddRecord, RecordOptions, SearchOptions, WalletSearch, WalletService};
+use indy_api_types::domain::wallet::Tags;
+use indy_api_types::{WalletHandle, SearchHandle};
+#[cfg(feature = "ledger")]
+use indy_api_types::{PoolHandle, CommandHandle};
+use indy_utils::next_search_handle;
+#[cfg(feature = "ledger")]
 use indy_utils::next_command_handle;
 use rust_base58::{FromBase58, ToBase58};
+use zeroize::Zeroize;
 
 pub enum DidCommand {
     CreateAndStoreMyDid(
@@ -45,6 +66,7 @@ pub enum DidCommand {
     ListMyDidsWithMeta(
         WalletHandle,
         Box<dyn Fn(IndyResult<String>) + Send>),
+    #[cfg(feature = "ledger")]
     KeyForDid(
         PoolHandle, // pool handle
         WalletHandle,
@@ -59,6 +81,7 @@ pub enum DidCommand {
         DidValue, // did
         Endpoint, // endpoint address and optional verkey
         Box<dyn Fn(IndyResult<()>) + Send>),
+    #[cfg(feature = "ledger")]
     GetEndpointForDid(
         WalletHandle,
         PoolHandle, // pool handle
@@ -78,6 +101,7 @@ pub enum DidCommand {
         String, // verkey
         Box<dyn Fn(IndyResult<String>) + Send>),
     // Internal commands
+    #[cfg(feature = "ledger")]
     GetNymAck(
         WalletHandle,
         DidValue, // did
@@ -85,6 +109,7 @@ pub enum DidCommand {
         CommandHandle, // deferred cmd id
     ),
     // Internal commands
+    #[cfg(feature = "ledger")]
     GetAttribAck(
         WalletHandle,
         IndyResult<String>, // GetAttrib Result
@@ -96,8 +121,138 @@ pub enum DidCommand {
         DidMethod, // method
         Box<dyn Fn(IndyResult<String /*full qualified did*/>) + Send>,
     ),
+    #[cfg(feature = "ledger")]
+    GetDidDoc(
+        WalletHandle,
+        PoolHandle, // pool handle
+        DidValue, // did (my or their)
+        Box<dyn Fn(IndyResult<String /*did doc json*/>) + Send>,
+    ),
+    // Internal commands
+    #[cfg(feature = "ledger")]
+    GetDidDocAttribAck(
+        WalletHandle,
+        IndyResult<String>, // GetAttrib Result
+        CommandHandle, // deferred cmd id
+    ),
+    #[cfg(feature = "ledger")]
+    RotateKey(
+        WalletHandle,
+        PoolHandle, // pool handle
+        DidValue, // my did
+        KeyInfo, // key info
+        Option<DidValue>, // endorser did
+        Box<dyn Fn(IndyResult<String /*new verkey*/>) + Send>,
+    ),
+    AddDidKey(
+        WalletHandle,
+        DidValue, // did (my or their)
+        DidKey, // key
+        Box<dyn Fn(IndyResult<()>) + Send>),
+    RemoveDidKey(
+        WalletHandle,
+        DidValue, // did (my or their)
+        String, // key id
+        Box<dyn Fn(IndyResult<()>) + Send>),
+    AddDidService(
+        WalletHandle,
+        DidValue, // did (my or their)
+        DidService, // service
+        Box<dyn Fn(IndyResult<()>) + Send>),
+    RemoveDidService(
+        WalletHandle,
+        DidValue, // did (my or their)
+        String, // service id
+        Box<dyn Fn(IndyResult<()>) + Send>),
+    SetMyDidTags(
+        WalletHandle,
+        DidValue, // my did
+        Tags, // tags
+        Box<dyn Fn(IndyResult<()>) + Send>),
+    OpenMyDidsSearch(
+        WalletHandle,
+        Option<String>, // query json
+        Option<DidSearchOptions>, // options
+        BoxedCallbackSearchHandleUsizeSend),
+    FetchMyDids(
+        SearchHandle,
+        usize, // count
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    CloseMyDidsSearch(
+        SearchHandle,
+        Box<dyn Fn(IndyResult<()>) + Send>),
+    ExportDid(
+        WalletHandle,
+        DidValue, // my did
+        String, // recipient verkey
+        Box<dyn Fn(IndyResult<Vec<u8>>) + Send>),
+    ImportDid(
+        WalletHandle,
+        String, // recipient verkey
+        Vec<u8>, // encrypted did bundle
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    SetNamedEndpointForDid(
+        WalletHandle,
+        DidValue, // did
+        NamedEndpoint, // endpoint
+        Box<dyn Fn(IndyResult<()>) + Send>),
+    RemoveNamedEndpointForDid(
+        WalletHandle,
+        DidValue, // did
+        String, // endpoint name
+        Box<dyn Fn(IndyResult<()>) + Send>),
+    #[cfg(feature = "ledger")]
+    GetNamedEndpointsForDid(
+        WalletHandle,
+        PoolHandle,
+        DidValue, // did
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    #[cfg(feature = "ledger")]
+    BuildNamedEndpointsAttribRequest(
+        WalletHandle,
+        DidValue, // submitter did
+        DidValue, // target did
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    QualifyAllDids(
+        WalletHandle,
+        DidMethod, // method
+        Option<QualifyDidsOptions>, // options
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    DeactivateDid(
+        WalletHandle,
+        DidValue, // did
+        Box<dyn Fn(IndyResult<()>) + Send>),
+    #[cfg(feature = "ledger")]
+    PublishDidDeactivation(
+        WalletHandle,
+        PoolHandle,
+        DidValue, // my did
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    // Internal commands
+    #[cfg(feature = "ledger")]
+    RotateKeyAck(
+        WalletHandle,
+        DidValue, // my did
+        IndyResult<String>, // Nym submit Result
+        CommandHandle, // deferred cmd id
+    ),
+    #[cfg(feature = "ledger")]
+    PublishDidDeactivationAck(
+        WalletHandle,
+        DidValue, // my did
+        IndyResult<String>, // Nym submit Result
+        CommandHandle, // deferred cmd id
+    ),
+    #[cfg(feature = "ledger")]
+    GetNamedEndpointsAttribAck(
+        WalletHandle,
+        DidValue, // their did
+        IndyResult<String>, // GetAttrib Result
+        CommandHandle, // deferred cmd id
+    ),
 }
 
+#[cfg(feature = "ledger")]
 macro_rules! ensure_their_did {
     ($self_:ident, $wallet_handle:ident, $pool_handle:ident, $their_did:ident, $deferred_cmd:expr, $cb:ident) => (
             match $self_._wallet_get_their_did($wallet_handle, &$their_did) {
@@ -109,22 +264,34 @@ macro_rules! ensure_their_did {
         );
 }
 
+struct MyDidsSearch {
+    wallet_handle: WalletHandle,
+    search: WalletSearch,
+    options: DidSearchOptions,
+}
+
 pub struct DidCommandExecutor {
     wallet_service: Rc<WalletService>,
     crypto_service: Rc<CryptoService>,
+    #[cfg(feature = "ledger")]
     ledger_service: Rc<LedgerService>,
+    #[cfg(feature = "ledger")]
     deferred_commands: RefCell<HashMap<CommandHandle, DidCommand>>,
+    searches: RefCell<HashMap<SearchHandle, MyDidsSearch>>,
 }
 
 impl DidCommandExecutor {
     pub fn new(wallet_service: Rc<WalletService>,
                crypto_service: Rc<CryptoService>,
-               ledger_service: Rc<LedgerService>) -> DidCommandExecutor {
+               #[cfg(feature = "ledger")] ledger_service: Rc<LedgerService>) -> DidCommandExecutor {
         DidCommandExecutor {
             wallet_service,
             crypto_service,
+            #[cfg(feature = "ledger")]
             ledger_service,
+            #[cfg(feature = "ledger")]
             deferred_commands: RefCell::new(HashMap::new()),
+            searches: RefCell::new(HashMap::new()),
         }
     }
 
@@ -154,6 +321,7 @@ impl DidCommandExecutor {
                 debug!("ListMyDidsWithMeta command received");
                 cb(self.list_my_dids_with_meta(wallet_handle));
             }
+            #[cfg(feature = "ledger")]
             DidCommand::KeyForDid(pool_handle, wallet_handle, did, cb) => {
                 debug!("KeyForDid command received");
                 self.key_for_did(pool_handle, wallet_handle, did, cb);
@@ -166,6 +334,7 @@ impl DidCommandExecutor {
                 debug!("SetEndpointForDid command received");
                 cb(self.set_endpoint_for_did(wallet_handle, &did, &endpoint));
             }
+            #[cfg(feature = "ledger")]
             DidCommand::GetEndpointForDid(wallet_handle, pool_handle, did, cb) => {
                 debug!("GetEndpointForDid command received");
                 self.get_endpoint_for_did(wallet_handle, pool_handle, did, cb);
@@ -182,10 +351,12 @@ impl DidCommandExecutor {
                 debug!("AbbreviateVerkey command received");
                 cb(self.abbreviate_verkey(&did, verkey));
             }
+            #[cfg(feature = "ledger")]
             DidCommand::GetNymAck(wallet_handle, did, result, deferred_cmd_id) => {
                 debug!("GetNymAck command received");
                 self.get_nym_ack(wallet_handle, did, result, deferred_cmd_id);
             }
+            #[cfg(feature = "ledger")]
             DidCommand::GetAttribAck(wallet_handle, result, deferred_cmd_id) => {
                 debug!("GetAttribAck command received");
                 self.get_attrib_ack(wallet_handle, result, deferred_cmd_id);
@@ -194,6 +365,107 @@ impl DidCommandExecutor {
                 debug!("QualifyDid command received");
                 cb(self.qualify_did(wallet_handle, &did, &method));
             }
+            #[cfg(feature = "ledger")]
+            DidCommand::GetDidDoc(wallet_handle, pool_handle, did, cb) => {
+                debug!("GetDidDoc command received");
+                self.get_did_doc(wallet_handle, pool_handle, did, cb);
+            }
+            #[cfg(feature = "ledger")]
+            DidCommand::GetDidDocAttribAck(wallet_handle, result, deferred_cmd_id) => {
+                debug!("GetDidDocAttribAck command received");
+                self.get_did_doc_attrib_ack(wallet_handle, result, deferred_cmd_id);
+            }
+            #[cfg(feature = "ledger")]
+            DidCommand::RotateKey(wallet_handle, pool_handle, did, key_info, endorser_did, cb) => {
+                debug!("RotateKey command received");
+                self.rotate_key(wallet_handle, pool_handle, did, key_info, endorser_did, cb);
+            }
+            DidCommand::AddDidKey(wallet_handle, did, key, cb) => {
+                debug!("AddDidKey command received");
+                cb(self.add_did_key(wallet_handle, &did, key));
+            }
+            DidCommand::RemoveDidKey(wallet_handle, did, key_id, cb) => {
+                debug!("RemoveDidKey command received");
+                cb(self.remove_did_key(wallet_handle, &did, &key_id));
+            }
+            DidCommand::AddDidService(wallet_handle, did, service, cb) => {
+                debug!("AddDidService command received");
+                cb(self.add_did_service(wallet_handle, &did, service));
+            }
+            DidCommand::RemoveDidService(wallet_handle, did, service_id, cb) => {
+                debug!("RemoveDidService command received");
+                cb(self.remove_did_service(wallet_handle, &did, &service_id));
+            }
+            DidCommand::SetMyDidTags(wallet_handle, my_did, tags, cb) => {
+                debug!("SetMyDidTags command received");
+                cb(self.set_my_did_tags(wallet_handle, &my_did, &tags));
+            }
+            DidCommand::OpenMyDidsSearch(wallet_handle, query_json, options, cb) => {
+                debug!("OpenMyDidsSearch command received");
+                cb(self.open_my_dids_search(wallet_handle, query_json.as_deref(), options.unwrap_or_default()));
+            }
+            DidCommand::FetchMyDids(search_handle, count, cb) => {
+                debug!("FetchMyDids command received");
+                cb(self.fetch_my_dids(search_handle, count));
+            }
+            DidCommand::CloseMyDidsSearch(search_handle, cb) => {
+                debug!("CloseMyDidsSearch command received");
+                cb(self.close_my_dids_search(search_handle));
+            }
+            DidCommand::ExportDid(wallet_handle, my_did, recipient_vk, cb) => {
+                debug!("ExportDid command received");
+                cb(self.export_did(wallet_handle, &my_did, &recipient_vk));
+            }
+            DidCommand::ImportDid(wallet_handle, recipient_vk, bundle, cb) => {
+                debug!("ImportDid command received");
+                cb(self.import_did(wallet_handle, &recipient_vk, &bundle));
+            }
+            DidCommand::SetNamedEndpointForDid(wallet_handle, did, endpoint, cb) => {
+                debug!("SetNamedEndpointForDid command received");
+                cb(self.set_named_endpoint_for_did(wallet_handle, &did, endpoint));
+            }
+            DidCommand::RemoveNamedEndpointForDid(wallet_handle, did, name, cb) => {
+                debug!("RemoveNamedEndpointForDid command received");
+                cb(self.remove_named_endpoint_for_did(wallet_handle, &did, &name));
+            }
+            #[cfg(feature = "ledger")]
+            DidCommand::GetNamedEndpointsForDid(wallet_handle, pool_handle, did, cb) => {
+                debug!("GetNamedEndpointsForDid command received");
+                self.get_named_endpoints_for_did(wallet_handle, pool_handle, did, cb);
+            }
+            #[cfg(feature = "ledger")]
+            DidCommand::BuildNamedEndpointsAttribRequest(wallet_handle, submitter_did, target_did, cb) => {
+                debug!("BuildNamedEndpointsAttribRequest command received");
+                cb(self.build_named_endpoints_attrib_request(wallet_handle, &submitter_did, &target_did));
+            }
+            DidCommand::QualifyAllDids(wallet_handle, method, options, cb) => {
+                debug!("QualifyAllDids command received");
+                cb(self.qualify_all_dids(wallet_handle, &method, options.unwrap_or_default()));
+            }
+            DidCommand::DeactivateDid(wallet_handle, did, cb) => {
+                debug!("DeactivateDid command received");
+                cb(self.deactivate_did(wallet_handle, &did));
+            }
+            #[cfg(feature = "ledger")]
+            DidCommand::PublishDidDeactivation(wallet_handle, pool_handle, did, cb) => {
+                debug!("PublishDidDeactivation command received");
+                self.publish_did_deactivation(wallet_handle, pool_handle, did, cb);
+            }
+            #[cfg(feature = "ledger")]
+            DidCommand::RotateKeyAck(wallet_handle, did, result, deferred_cmd_id) => {
+                debug!("RotateKeyAck command received");
+                self.rotate_key_ack(wallet_handle, did, result, deferred_cmd_id);
+            }
+            #[cfg(feature = "ledger")]
+            DidCommand::PublishDidDeactivationAck(wallet_handle, did, result, deferred_cmd_id) => {
+                debug!("PublishDidDeactivationAck command received");
+                self.publish_did_deactivation_ack(wallet_handle, did, result, deferred_cmd_id);
+            }
+            #[cfg(feature = "ledger")]
+            DidCommand::GetNamedEndpointsAttribAck(wallet_handle, did, result, deferred_cmd_id) => {
+                debug!("GetNamedEndpointsAttribAck command received");
+                self.get_named_endpoints_attrib_ack(wallet_handle, &did, result, deferred_cmd_id);
+            }
         };
     }
 
@@ -216,6 +488,8 @@ impl DidCommandExecutor {
         self.wallet_service.add_indy_object(wallet_handle, &did.did.0, &did, &HashMap::new())?;
         let _ = self.wallet_service.add_indy_object(wallet_handle, &key.verkey, &key, &HashMap::new()).ok();
 
+        audit::record(audit::DID_CREATED, wallet_handle, json!({"did": did.did, "verkey": did.verkey}));
+
         let res = (did.did.0, did.verkey);
 
         debug!("create_and_store_my_did <<< res: {:?}", res);
@@ -239,6 +513,8 @@ impl DidCommandExecutor {
         self.wallet_service.add_indy_object(wallet_handle, &temporary_key.verkey, &temporary_key, &HashMap::new())?;
         self.wallet_service.add_indy_object(wallet_handle, &my_temporary_did.did.0, &my_temporary_did, &HashMap::new())?;
 
+        audit::record(audit::DID_KEYS_ROTATION_STARTED, wallet_handle, json!({"did": my_temporary_did.did, "verkey": my_temporary_did.verkey}));
+
         let res = my_temporary_did.verkey;
 
         debug!("replace_keys_start <<< res: {:?}", res);
@@ -262,11 +538,298 @@ impl DidCommandExecutor {
         self.wallet_service.update_indy_object(wallet_handle, &my_did.did.0, &my_did)?;
         self.wallet_service.delete_indy_record::<TemporaryDid>(wallet_handle, &my_did.did.0)?;
 
+        audit::record(audit::DID_KEYS_ROTATED, wallet_handle, json!({"did": my_did.did, "verkey": my_did.verkey}));
+
         debug!("replace_keys_apply <<<");
 
         Ok(())
     }
 
+    #[cfg(feature = "ledger")]
+    fn rotate_key(&self,
+                  wallet_handle: WalletHandle,
+                  pool_handle: PoolHandle,
+                  my_did: DidValue,
+                  key_info: KeyInfo,
+                  endorser_did: Option<DidValue>,
+                  cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        debug!("rotate_key >>> wallet_handle: {:?}, pool_handle: {:?}, my_did: {:?}, key_info: {:?}, endorser_did: {:?}",
+               wallet_handle, pool_handle, my_did, secret!(&key_info), endorser_did);
+
+        if let Some(ref endorser_did) = endorser_did {
+            try_cb!(self.crypto_service.validate_did(endorser_did), cb);
+        }
+
+        let new_verkey = try_cb!(self.replace_keys_start(wallet_handle, &key_info, &my_did), cb);
+
+        let nym_request = self.ledger_service.build_nym_request(&my_did, &my_did, Some(&new_verkey), None, None)
+            .and_then(|request| match endorser_did {
+                Some(ref endorser_did) => Self::_append_request_endorser(&request, endorser_did),
+                None => Ok(request)
+            });
+
+        let nym_request = match nym_request {
+            Ok(nym_request) => nym_request,
+            Err(err) => {
+                self._rollback_temporary_key(wallet_handle, &my_did);
+                return cb(Err(err));
+            }
+        };
+
+        // Defer this command until NYM with the new verkey is written to the ledger.
+        let deferred_cmd_id = self._defer_command(
+            DidCommand::RotateKey(wallet_handle, pool_handle, my_did.clone(), key_info, endorser_did.clone(), cb));
+
+        let did = my_did.clone();
+        let ack = move |result: IndyResult<String>| {
+            CommandExecutor::instance()
+                .send(Command::Did(DidCommand::RotateKeyAck(
+                    wallet_handle,
+                    did.clone(),
+                    result,
+                    deferred_cmd_id,
+                ))).unwrap();
+        };
+
+        // Request is signed with the current key as it is not applied yet.
+        match endorser_did {
+            None => {
+                CommandExecutor::instance()
+                    .send(Command::Ledger(LedgerCommand::SignAndSubmitRequest(
+                        pool_handle,
+                        wallet_handle,
+                        my_did,
+                        nym_request,
+                        Box::new(ack),
+                    ))).unwrap();
+            }
+            Some(endorser_did) => {
+                CommandExecutor::instance()
+                    .send(Command::Ledger(LedgerCommand::MultiSignRequest(
+                        wallet_handle,
+                        my_did,
+                        nym_request,
+                        Box::new(move |result| {
+                            let ack = ack.clone();
+                            match result {
+                                Ok(request) => {
+                                    CommandExecutor::instance()
+                                        .send(Command::Ledger(LedgerCommand::MultiSignRequest(
+                                            wallet_handle,
+                                            endorser_did.clone(),
+                                            request,
+                                            Box::new(move |result| {
+                                                let ack = ack.clone();
+                                                match result {
+                                                    Ok(request) => {
+                                                        CommandExecutor::instance()
+                                                            .send(Command::Ledger(LedgerCommand::SubmitRequest(
+                                                                pool_handle,
+                                                                request,
+                                                                Box::new(ack),
+                                                            ))).unwrap();
+                                                    }
+                                                    Err(err) => ack(Err(err))
+                                                }
+                                            }),
+                                        ))).unwrap();
+                                }
+                                Err(err) => ack(Err(err))
+                            }
+                        }),
+                    ))).unwrap();
+            }
+        }
+    }
+
+    #[cfg(feature = "ledger")]
+    fn rotate_key_ack(&self,
+                      wallet_handle: WalletHandle,
+                      my_did: DidValue,
+                      nym_reply_result: IndyResult<String>,
+                      deferred_cmd_id: CommandHandle) {
+        let cmd = self.deferred_commands.borrow_mut().remove(&deferred_cmd_id);
+
+        match cmd {
+            Some(DidCommand::RotateKey(_, _, _, _, _, cb)) => {
+                let res = self._rotate_key_ack(wallet_handle, &my_did, nym_reply_result);
+
+                debug!("rotate_key <<< res: {:?}", res);
+
+                cb(res)
+            }
+            Some(cmd) => self._call_error_cb(cmd, err_msg(IndyErrorKind::InvalidState, "Unexpected deferred command for RotateKeyAck")),
+            None => error!("No deferred command for id: {:?}", deferred_cmd_id)
+        }
+    }
+
+    #[cfg(feature = "ledger")]
+    fn _rotate_key_ack(&self, wallet_handle: WalletHandle, my_did: &DidValue, nym_reply_result: IndyResult<String>) -> IndyResult<String> {
+        trace!("_rotate_key_ack >>> wallet_handle: {:?}, my_did: {:?}, nym_reply_result: {:?}", wallet_handle, my_did, nym_reply_result);
+
+        let nym_reply = nym_reply_result
+            .and_then(|nym_reply| Self::_check_write_reply(&nym_reply));
+
+        if let Err(err) = nym_reply {
+            // On timeout ledger state is unknown. Keep temporary key so rotation can be finished by `replace_keys_apply`.
+            if err.kind() != IndyErrorKind::PoolTimeout {
+                self._rollback_temporary_key(wallet_handle, my_did);
+            }
+            return Err(err);
+        }
+
+        self.replace_keys_apply(wallet_handle, my_did)?;
+
+        let res = self._wallet_get_my_did(wallet_handle, my_did)?.verkey;
+
+        trace!("_rotate_key_ack <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn deactivate_did(&self,
+                      wallet_handle: WalletHandle,
+                      did: &DidValue) -> IndyResult<()> {
+        debug!("deactivate_did >>> wallet_handle: {:?}, did: {:?}", wallet_handle, did);
+
+        self.crypto_service.validate_did(did)?;
+
+        self._ensure_did_stored(wallet_handle, did)?;
+
+        self.wallet_service.upsert_indy_object(wallet_handle, &did.0, &DidDeactivation {})?;
+
+        debug!("deactivate_did <<<");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "ledger")]
+    fn publish_did_deactivation(&self,
+                                wallet_handle: WalletHandle,
+                                pool_handle: PoolHandle,
+                                my_did: DidValue,
+                                cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        debug!("publish_did_deactivation >>> wallet_handle: {:?}, pool_handle: {:?}, my_did: {:?}", wallet_handle, pool_handle, my_did);
+
+        try_cb!(self.crypto_service.validate_did(&my_did), cb);
+        try_cb!(self._wallet_get_my_did(wallet_handle, &my_did), cb);
+
+        let nym_request = try_cb!(self.ledger_service.build_nym_request(&my_did, &my_did, None, None, None)
+            .and_then(|request| Self::_remove_request_verkey(&request)), cb);
+
+        // Defer this command until NYM without verkey is written to the ledger.
+        let deferred_cmd_id = self._defer_command(
+            DidCommand::PublishDidDeactivation(wallet_handle, pool_handle, my_did.clone(), cb));
+
+        let did = my_did.clone();
+        let ack = move |result: IndyResult<String>| {
+            CommandExecutor::instance()
+                .send(Command::Did(DidCommand::PublishDidDeactivationAck(
+                    wallet_handle,
+                    did.clone(),
+                    result,
+                    deferred_cmd_id,
+                ))).unwrap();
+        };
+
+        // DID can be already deactivated in the wallet so request is signed with force.
+        CommandExecutor::instance()
+            .send(Command::Ledger(LedgerCommand::ForceSignRequest(
+                wallet_handle,
+                my_did,
+                nym_request,
+                Box::new(move |result| {
+                    let ack = ack.clone();
+                    match result {
+                        Ok(request) => {
+                            CommandExecutor::instance()
+                                .send(Command::Ledger(LedgerCommand::SubmitRequest(
+                                    pool_handle,
+                                    request,
+                                    Box::new(ack),
+                                ))).unwrap();
+                        }
+                        Err(err) => ack(Err(err))
+                    }
+                }),
+            ))).unwrap();
+    }
+
+    #[cfg(feature = "ledger")]
+    fn publish_did_deactivation_ack(&self,
+                                    wallet_handle: WalletHandle,
+                                    my_did: DidValue,
+                                    nym_reply_result: IndyResult<String>,
+                                    deferred_cmd_id: CommandHandle) {
+        let cmd = self.deferred_commands.borrow_mut().remove(&deferred_cmd_id);
+
+        match cmd {
+            Some(DidCommand::PublishDidDeactivation(_, _, _, cb)) => {
+                let res = nym_reply_result
+                    .and_then(|nym_reply| {
+                        Self::_check_write_reply(&nym_reply)?;
+                        self.deactivate_did(wallet_handle, &my_did)?;
+                        Ok(nym_reply)
+                    });
+
+                debug!("publish_did_deactivation <<< res: {:?}", res);
+
+                cb(res)
+            }
+            Some(cmd) => self._call_error_cb(cmd, err_msg(IndyErrorKind::InvalidState, "Unexpected deferred command for PublishDidDeactivationAck")),
+            None => error!("No deferred command for id: {:?}", deferred_cmd_id)
+        }
+    }
+
+    // Owner can remove verkey from the ledger by NYM with explicit null verkey. It makes DID unusable for signing.
+    #[cfg(feature = "ledger")]
+    fn _remove_request_verkey(request_json: &str) -> IndyResult<String> {
+        let mut request: serde_json::Value = serde_json::from_str(request_json)
+            .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize request")?;
+
+        request["operation"]["verkey"] = serde_json::Value::Null;
+
+        serde_json::to_string(&request)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize request")
+    }
+
+    #[cfg(feature = "ledger")]
+    fn _check_write_reply(reply: &str) -> IndyResult<()> {
+        let message: Message<serde_json::Value> = serde_json::from_str(reply)
+            .to_indy(IndyErrorKind::InvalidTransaction, "Response is invalid json")?;
+
+        match message {
+            Message::Reject(response) | Message::ReqNACK(response) =>
+                Err(err_msg(IndyErrorKind::InvalidTransaction, format!("Transaction has been failed: {:?}", response.reason))),
+            Message::Reply(_) => Ok(())
+        }
+    }
+
+    #[cfg(feature = "ledger")]
+    fn _append_request_endorser(request_json: &str, endorser_did: &DidValue) -> IndyResult<String> {
+        let mut request: serde_json::Value = serde_json::from_str(request_json)
+            .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize request")?;
+
+        request["endorser"] = json!(endorser_did.to_short());
+
+        serde_json::to_string(&request)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize request after adding endorser")
+    }
+
+    #[cfg(feature = "ledger")]
+    fn _rollback_temporary_key(&self, wallet_handle: WalletHandle, my_did: &DidValue) {
+        trace!("_rollback_temporary_key >>> wallet_handle: {:?}, my_did: {:?}", wallet_handle, my_did);
+
+        if let Ok(temporary_did) = self.wallet_service.get_indy_object::<TemporaryDid>(wallet_handle, &my_did.0, &RecordOptions::id_value()) {
+            let _ = self.wallet_service.delete_indy_record::<Key>(wallet_handle, &temporary_did.verkey)
+                .map_err(|err| warn!("Can't remove temporary key for {:?}: {:?}", my_did, err));
+            let _ = self.wallet_service.delete_indy_record::<TemporaryDid>(wallet_handle, &my_did.0)
+                .map_err(|err| warn!("Can't remove temporary DID for {:?}: {:?}", my_did, err));
+        }
+
+        trace!("_rollback_temporary_key <<<");
+    }
+
     fn store_their_did(&self,
                        wallet_handle: WalletHandle,
                        their_did_info: &TheirDidInfo) -> IndyResult<()> {
@@ -285,15 +848,8 @@ impl DidCommandExecutor {
         debug!("get_my_did_with_meta >>> wallet_handle: {:?}, my_did: {:?}", wallet_handle, my_did);
 
         let did = self.wallet_service.get_indy_object::<Did>(wallet_handle, &my_did.0, &RecordOptions::id_value())?;
-        let metadata = self.wallet_service.get_indy_opt_object::<DidMetadata>(wallet_handle, &did.did.0, &RecordOptions::id_value())?;
-        let temp_verkey = self.wallet_service.get_indy_opt_object::<TemporaryDid>(wallet_handle, &did.did.0, &RecordOptions::id_value())?;
 
-        let did_with_meta = DidWithMeta {
-            did: did.did,
-            verkey: did.verkey,
-            temp_verkey: temp_verkey.map(|tv| tv.verkey),
-            metadata: metadata.map(|m| m.value),
-        };
+        let did_with_meta = self._get_did_with_meta(wallet_handle, did, &DidSearchOptions::default())?;
 
         let res = serde_json::to_string(&did_with_meta)
             .to_indy(IndyErrorKind::InvalidState, "Can't serialize DID")?;
@@ -348,12 +904,14 @@ impl DidCommandExecutor {
 
             let temp_verkey = temporarydid_map.remove(&did.did.0);
             let metadata = metadata_map.remove(&did.did.0);
+            let deactivated = self.wallet_service.record_exists::<DidDeactivation>(wallet_handle, &did.did.0)?;
 
             let did_with_meta = DidWithMeta {
                 did: did.did,
                 verkey: did.verkey,
                 temp_verkey: temp_verkey,
                 metadata: metadata,
+                deactivated,
             };
 
             dids.push(did_with_meta);
@@ -367,100 +925,404 @@ impl DidCommandExecutor {
         Ok(res)
     }
 
-    fn key_for_did(&self,
-                   pool_handle: PoolHandle,
-                   wallet_handle: WalletHandle,
-                   did: DidValue,
-                   cb: Box<dyn Fn(IndyResult<String>) + Send>) {
-        debug!("key_for_did >>> pool_handle: {:?}, wallet_handle: {:?}, did: {:?}", pool_handle, wallet_handle, did);
-
-        try_cb!(self.crypto_service.validate_did(&did), cb);
+    fn set_my_did_tags(&self,
+                       wallet_handle: WalletHandle,
+                       my_did: &DidValue,
+                       tags: &Tags) -> IndyResult<()> {
+        debug!("set_my_did_tags >>> wallet_handle: {:?}, my_did: {:?}, tags: {:?}", wallet_handle, my_did, tags);
 
-        // Look to my did
-        match self._wallet_get_my_did(wallet_handle, &did) {
-            Ok(my_did) => return cb(Ok(my_did.verkey)),
-            Err(ref err) if err.kind() == IndyErrorKind::WalletItemNotFound => {}
-            Err(err) => return cb(Err(err))
-        };
+        self.crypto_service.validate_did(my_did)?;
 
-        // look to their did
-        let their_did = ensure_their_did!(self,
-                                          wallet_handle,
-                                          pool_handle,
-                                          did,
-                                          DidCommand::KeyForDid(
-                                              pool_handle,
-                                              wallet_handle,
-                                              did.clone(),
-                                              cb),
-                                           cb);
+        // Metadata fields stay indexed, the given tags take precedence over the fields with the same names
+        let mut all_tags = self.wallet_service.get_indy_opt_object::<DidMetadata>(wallet_handle, &my_did.0, &RecordOptions::id_value())?
+            .map(|metadata| metadata.tags())
+            .unwrap_or_default();
+        all_tags.extend(tags.clone());
 
-        let res = their_did.verkey;
+        self.wallet_service.update_record_tags(wallet_handle, &self.wallet_service.add_prefix("Did"), &my_did.0, &all_tags)?;
 
-        debug!("key_for_did <<< res: {:?}", res);
+        debug!("set_my_did_tags <<<");
 
-        cb(Ok(res))
+        Ok(())
     }
 
-    fn key_for_local_did(&self,
-                         wallet_handle: WalletHandle,
-                         did: &DidValue) -> IndyResult<String> {
-        debug!("key_for_local_did >>> wallet_handle: {:?}, did: {:?}", wallet_handle, did);
+    fn open_my_dids_search(&self,
+                           wallet_handle: WalletHandle,
+                           query_json: Option<&str>,
+                           options: DidSearchOptions) -> IndyResult<(SearchHandle, usize)> {
+        debug!("open_my_dids_search >>> wallet_handle: {:?}, query_json: {:?}, options: {:?}", wallet_handle, query_json, options);
 
-        self.crypto_service.validate_did(&did)?;
+        let search =
+            self.wallet_service.search_indy_records::<Did>(wallet_handle, query_json.unwrap_or("{}"), &SearchOptions::id_value())?;
 
-        // Look to my did
-        match self._wallet_get_my_did(wallet_handle, did) {
-            Ok(my_did) => return Ok(my_did.verkey),
-            Err(err) => match err.kind() {
-                IndyErrorKind::WalletItemNotFound => {}
-                _ => return Err(err)
-            }
-        };
+        let total_count = search.get_total_count()?.unwrap_or(0);
 
-        // look to their did
-        let their_did = self._wallet_get_their_did(wallet_handle, did)?;
+        let handle: SearchHandle = next_search_handle();
 
-        let res = their_did.verkey;
+        self.searches.borrow_mut().insert(handle, MyDidsSearch { wallet_handle, search, options });
+        handles::opened(handles::DID_SEARCH, handle.0, None);
 
-        debug!("key_for_local_did <<< res: {:?}", res);
+        let res = (handle, total_count);
+
+        debug!("open_my_dids_search <<< res: {:?}", res);
 
         Ok(res)
     }
 
-    fn set_endpoint_for_did(&self,
-                            wallet_handle: WalletHandle,
-                            did: &DidValue,
-                            endpoint: &Endpoint) -> IndyResult<()> {
-        debug!("set_endpoint_for_did >>> wallet_handle: {:?}, did: {:?}, endpoint: {:?}", wallet_handle, did, endpoint);
+    fn fetch_my_dids(&self,
+                     search_handle: SearchHandle,
+                     count: usize) -> IndyResult<String> {
+        trace!("fetch_my_dids >>> search_handle: {:?}, count: {:?}", search_handle, count);
 
-        self.crypto_service.validate_did(did)?;
+        let mut searches = self.searches.borrow_mut();
+        let search = searches.get_mut(&search_handle)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidWalletHandle, format!("Unknown MyDidsSearch handle: {:?}", search_handle)))?;
 
-        if endpoint.verkey.is_some() {
-            let transport_key = endpoint.verkey.as_ref().unwrap();
-            self.crypto_service.validate_key(transport_key)?;
+        let mut dids: Vec<DidWithMeta> = Vec::new();
+
+        for _ in 0..count {
+            match search.search.fetch_next_record()? {
+                Some(did_record) => {
+                    let did: Did = did_record.get_value()
+                        .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "No value for DID record"))
+                        .and_then(|did_json| serde_json::from_str(did_json)
+                            .to_indy(IndyErrorKind::InvalidState, format!("Cannot deserialize Did: {:?}", did_record.get_id())))?;
+
+                    dids.push(self._get_did_with_meta(search.wallet_handle, did, &search.options)?);
+                }
+                None => break
+            }
         }
 
-        self.wallet_service.upsert_indy_object(wallet_handle, &did.0, endpoint)?;
+        let res = serde_json::to_string(&dids)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize DIDs list")?;
 
-        debug!("set_endpoint_for_did <<<");
-        Ok(())
+        trace!("fetch_my_dids <<< res: {:?}", res);
+
+        Ok(res)
     }
 
-    fn get_endpoint_for_did(&self,
-                            wallet_handle: WalletHandle,
-                            pool_handle: PoolHandle,
-                            did: DidValue,
-                            cb: Box<dyn Fn(IndyResult<(String, Option<String>)>) + Send>) {
-        debug!("get_endpoint_for_did >>> wallet_handle: {:?}, pool_handle: {:?}, did: {:?}", wallet_handle, pool_handle, did);
+    fn close_my_dids_search(&self, search_handle: SearchHandle) -> IndyResult<()> {
+        trace!("close_my_dids_search >>> search_handle: {:?}", search_handle);
 
-        try_cb!(self.crypto_service.validate_did(&did), cb);
+        match self.searches.borrow_mut().remove(&search_handle) {
+            Some(_) => Ok(()),
+            None => Err(err_msg(IndyErrorKind::InvalidWalletHandle, format!("Unknown MyDidsSearch handle: {:?}", search_handle)))
+        }?;
+        handles::closed(handles::DID_SEARCH, search_handle.0);
 
-        let endpoint =
-            self.wallet_service.get_indy_object::<Endpoint>(wallet_handle, &did.0, &RecordOptions::id_value());
+        trace!("close_my_dids_search <<< res: ()");
 
-        match endpoint {
-            Ok(endpoint) => cb(Ok((endpoint.ha, endpoint.verkey))),
+        Ok(())
+    }
+
+    pub fn close_all_searches(&self) {
+        self.searches.borrow_mut().clear();
+        handles::closed_all(handles::DID_SEARCH);
+    }
+
+    fn _get_did_with_meta(&self, wallet_handle: WalletHandle, did: Did, options: &DidSearchOptions) -> IndyResult<DidWithMeta> {
+        let metadata = if options.retrieve_metadata {
+            self.wallet_service.get_indy_opt_object::<DidMetadata>(wallet_handle, &did.did.0, &RecordOptions::id_value())?
+        } else { None };
+
+        let temp_verkey = if options.retrieve_temp_verkey {
+            self.wallet_service.get_indy_opt_object::<TemporaryDid>(wallet_handle, &did.did.0, &RecordOptions::id_value())?
+        } else { None };
+
+        let deactivated = self.wallet_service.record_exists::<DidDeactivation>(wallet_handle, &did.did.0)?;
+
+        Ok(DidWithMeta {
+            did: did.did,
+            verkey: did.verkey,
+            temp_verkey: temp_verkey.map(|tv| tv.verkey),
+            metadata: metadata.map(|m| m.value),
+            deactivated,
+        })
+    }
+
+    fn export_did(&self,
+                  wallet_handle: WalletHandle,
+                  my_did: &DidValue,
+                  recipient_vk: &str) -> IndyResult<Vec<u8>> {
+        debug!("export_did >>> wallet_handle: {:?}, my_did: {:?}, recipient_vk: {:?}", wallet_handle, my_did, recipient_vk);
+
+        self.crypto_service.validate_did(my_did)?;
+        self.crypto_service.validate_key(recipient_vk)?;
+
+        let did_record = self.wallet_service.get_indy_record::<Did>(wallet_handle, &my_did.0, &json!({"retrieveValue": true, "retrieveTags": true}).to_string())?;
+
+        let did: Did = did_record.get_value()
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "No value for DID record"))
+            .and_then(|did_json| serde_json::from_str(did_json)
+                .to_indy(IndyErrorKind::InvalidState, format!("Cannot deserialize Did: {:?}", my_did)))?;
+
+        let temporary_did = self.wallet_service.get_indy_opt_object::<TemporaryDid>(wallet_handle, &my_did.0, &RecordOptions::id_value())?;
+        let metadata = self.wallet_service.get_indy_opt_object::<DidMetadata>(wallet_handle, &my_did.0, &RecordOptions::id_value())?;
+        let endpoint = self.wallet_service.get_indy_opt_object::<Endpoint>(wallet_handle, &my_did.0, &RecordOptions::id_value())?;
+        let did_keys = self.wallet_service.get_indy_opt_object::<DidKeys>(wallet_handle, &my_did.0, &RecordOptions::id_value())?;
+        let did_services = self.wallet_service.get_indy_opt_object::<DidServices>(wallet_handle, &my_did.0, &RecordOptions::id_value())?;
+        let named_endpoints = self.wallet_service.get_indy_opt_object::<NamedEndpoints>(wallet_handle, &my_did.0, &RecordOptions::id_value())?;
+
+        let mut keys = vec![self.wallet_service.get_indy_object::<Key>(wallet_handle, &did.verkey, &RecordOptions::id_value())?];
+
+        if let Some(ref temporary_did) = temporary_did {
+            keys.push(self.wallet_service.get_indy_object::<Key>(wallet_handle, &temporary_did.verkey, &RecordOptions::id_value())?);
+        }
+
+        // Additional keys can belong to the other party (for their DIDs) so only keys present in the wallet are exported
+        for did_key in did_keys.iter().flat_map(|did_keys| did_keys.value.iter()) {
+            if let Some(key) = self.wallet_service.get_indy_opt_object::<Key>(wallet_handle, &did_key.verkey, &RecordOptions::id_value())? {
+                keys.push(key);
+            }
+        }
+
+        let mut pairwise = Vec::new();
+
+        let mut pairwise_search =
+            self.wallet_service.search_indy_records::<Pairwise>(wallet_handle, "{}", &json!({"retrieveTags": true}).to_string())?;
+
+        while let Some(pairwise_record) = pairwise_search.fetch_next_record()? {
+            let pairwise_item: Pairwise = pairwise_record.get_value()
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "No value for Pairwise record"))
+                .and_then(|pairwise_json| serde_json::from_str(pairwise_json)
+                    .map_err(|err| IndyError::from_msg(IndyErrorKind::InvalidState, format!("Cannot deserialize Pairwise: {:?}", err))))?;
+
+            if pairwise_item.my_did.eq(my_did) {
+                pairwise.push(PairwiseBundle {
+                    their_did: self._wallet_get_their_did(wallet_handle, &pairwise_item.their_did)?,
+                    metadata: pairwise_item.metadata,
+                    meta: PairwiseMeta::from_tags(pairwise_record.get_tags()),
+                });
+            }
+        }
+
+        let bundle = DidBundle {
+            version: DID_BUNDLE_VERSION,
+            did,
+            tags: did_record.get_tags().cloned().unwrap_or_default(),
+            keys,
+            temporary_did,
+            metadata: metadata.map(|metadata| metadata.value),
+            endpoint,
+            did_keys,
+            did_services,
+            named_endpoints,
+            deactivated: self.wallet_service.record_exists::<DidDeactivation>(wallet_handle, &my_did.0)?,
+            pairwise,
+        };
+
+        let mut bundle_json = serde_json::to_string(&bundle)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize DID bundle")?;
+
+        let res = self.crypto_service.crypto_box_seal(recipient_vk, bundle_json.as_bytes());
+
+        bundle_json.zeroize();
+
+        debug!("export_did <<< res: {:?}", res);
+
+        res
+    }
+
+    fn import_did(&self,
+                  wallet_handle: WalletHandle,
+                  recipient_vk: &str,
+                  encrypted_bundle: &[u8]) -> IndyResult<String> {
+        debug!("import_did >>> wallet_handle: {:?}, recipient_vk: {:?}, encrypted_bundle: {:?}", wallet_handle, recipient_vk, encrypted_bundle);
+
+        self.crypto_service.validate_key(recipient_vk)?;
+
+        let recipient_key: Key = self.wallet_service.get_indy_object(wallet_handle, recipient_vk, &RecordOptions::id_value())?;
+
+        let mut bundle_json = self.crypto_service.crypto_box_seal_open(&recipient_key, encrypted_bundle)?;
+
+        let bundle: IndyResult<DidBundle> = serde_json::from_slice(&bundle_json)
+            .to_indy(IndyErrorKind::InvalidStructure, "Invalid DID bundle json");
+
+        bundle_json.zeroize();
+
+        let bundle = bundle?;
+
+        if bundle.version != DID_BUNDLE_VERSION {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Unsupported DID bundle version: {}", bundle.version)));
+        }
+
+        let did = bundle.did.did.clone();
+
+        self.crypto_service.validate_did(&did)?;
+
+        // Check conflicts before any record is written to keep the wallet consistent
+        if self.wallet_service.record_exists::<Did>(wallet_handle, &did.0)? {
+            return Err(err_msg(IndyErrorKind::WalletItemAlreadyExists, format!("DID {} already exists in the wallet", did.0)));
+        }
+
+        for pairwise in bundle.pairwise.iter() {
+            if self.wallet_service.record_exists::<Pairwise>(wallet_handle, &pairwise.their_did.did.0)? {
+                return Err(err_msg(IndyErrorKind::WalletItemAlreadyExists, format!("Pairwise for {} already exists in the wallet", pairwise.their_did.did.0)));
+            }
+
+            if self.wallet_service.record_exists::<TheirDid>(wallet_handle, &pairwise.their_did.did.0)? {
+                return Err(err_msg(IndyErrorKind::WalletItemAlreadyExists, format!("Their DID {} already exists in the wallet", pairwise.their_did.did.0)));
+            }
+        }
+
+        let no_tags = HashMap::new();
+        let mut records: Vec<AddRecord> = Vec::new();
+
+        for key in bundle.keys.iter() {
+            if !self.wallet_service.record_exists::<Key>(wallet_handle, &key.verkey)?
+                && !records.iter().any(|record| record.id == key.verkey) {
+                records.push(self.wallet_service.indy_object_record(&key.verkey, key, &no_tags)?);
+            }
+        }
+
+        records.push(self.wallet_service.indy_object_record(&did.0, &bundle.did, &bundle.tags)?);
+
+        if let Some(temporary_did) = bundle.temporary_did {
+            records.push(self.wallet_service.indy_object_record(&did.0, &temporary_did, &no_tags)?);
+        }
+
+        if let Some(metadata) = bundle.metadata {
+            records.push(self.wallet_service.indy_object_record(&did.0, &DidMetadata { value: metadata }, &no_tags)?);
+        }
+
+        if let Some(endpoint) = bundle.endpoint {
+            records.push(self.wallet_service.indy_object_record(&did.0, &endpoint, &no_tags)?);
+        }
+
+        if let Some(did_keys) = bundle.did_keys {
+            records.push(self.wallet_service.indy_object_record(&did.0, &did_keys, &no_tags)?);
+        }
+
+        if let Some(did_services) = bundle.did_services {
+            records.push(self.wallet_service.indy_object_record(&did.0, &did_services, &no_tags)?);
+        }
+
+        if let Some(named_endpoints) = bundle.named_endpoints {
+            records.push(self.wallet_service.indy_object_record(&did.0, &named_endpoints, &no_tags)?);
+        }
+
+        if bundle.deactivated {
+            records.push(self.wallet_service.indy_object_record(&did.0, &DidDeactivation {}, &no_tags)?);
+        }
+
+        for pairwise in bundle.pairwise {
+            let meta = pairwise.meta;
+            records.push(self.wallet_service.indy_object_record(&pairwise.their_did.did.0, &pairwise.their_did, &no_tags)?);
+
+            let pairwise = Pairwise {
+                my_did: did.clone(),
+                their_did: pairwise.their_did.did,
+                metadata: pairwise.metadata,
+            };
+
+            records.push(self.wallet_service.indy_object_record(&pairwise.their_did.0, &pairwise, &pairwise.tags(&meta))?);
+        }
+
+        // All records are written at once, so a failed import leaves nothing in the wallet
+        self.wallet_service.add_records(wallet_handle, &records)?;
+
+        debug!("import_did <<< res: {:?}", did);
+
+        Ok(did.0)
+    }
+
+    #[cfg(feature = "ledger")]
+    fn key_for_did(&self,
+                   pool_handle: PoolHandle,
+                   wallet_handle: WalletHandle,
+                   did: DidValue,
+                   cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        debug!("key_for_did >>> pool_handle: {:?}, wallet_handle: {:?}, did: {:?}", pool_handle, wallet_handle, did);
+
+        try_cb!(self.crypto_service.validate_did(&did), cb);
+
+        // Look to my did
+        match self._wallet_get_my_did(wallet_handle, &did) {
+            Ok(my_did) => return cb(Ok(my_did.verkey)),
+            Err(ref err) if err.kind() == IndyErrorKind::WalletItemNotFound => {}
+            Err(err) => return cb(Err(err))
+        };
+
+        // look to their did
+        let their_did = ensure_their_did!(self,
+                                          wallet_handle,
+                                          pool_handle,
+                                          did,
+                                          DidCommand::KeyForDid(
+                                              pool_handle,
+                                              wallet_handle,
+                                              did.clone(),
+                                              cb),
+                                           cb);
+
+        let res = their_did.verkey;
+
+        debug!("key_for_did <<< res: {:?}", res);
+
+        cb(Ok(res))
+    }
+
+    fn key_for_local_did(&self,
+                         wallet_handle: WalletHandle,
+                         did: &DidValue) -> IndyResult<String> {
+        debug!("key_for_local_did >>> wallet_handle: {:?}, did: {:?}", wallet_handle, did);
+
+        self.crypto_service.validate_did(&did)?;
+
+        // Look to my did
+        match self._wallet_get_my_did(wallet_handle, did) {
+            Ok(my_did) => return Ok(my_did.verkey),
+            Err(err) => match err.kind() {
+                IndyErrorKind::WalletItemNotFound => {}
+                _ => return Err(err)
+            }
+        };
+
+        // look to their did
+        let their_did = self._wallet_get_their_did(wallet_handle, did)?;
+
+        let res = their_did.verkey;
+
+        debug!("key_for_local_did <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn set_endpoint_for_did(&self,
+                            wallet_handle: WalletHandle,
+                            did: &DidValue,
+                            endpoint: &Endpoint) -> IndyResult<()> {
+        debug!("set_endpoint_for_did >>> wallet_handle: {:?}, did: {:?}, endpoint: {:?}", wallet_handle, did, endpoint);
+
+        self.crypto_service.validate_did(did)?;
+
+        if endpoint.verkey.is_some() {
+            let transport_key = endpoint.verkey.as_ref().unwrap();
+            self.crypto_service.validate_key(transport_key)?;
+        }
+
+        self.wallet_service.upsert_indy_object(wallet_handle, &did.0, endpoint)?;
+
+        debug!("set_endpoint_for_did <<<");
+        Ok(())
+    }
+
+    #[cfg(feature = "ledger")]
+    fn get_endpoint_for_did(&self,
+                            wallet_handle: WalletHandle,
+                            pool_handle: PoolHandle,
+                            did: DidValue,
+                            cb: Box<dyn Fn(IndyResult<(String, Option<String>)>) + Send>) {
+        debug!("get_endpoint_for_did >>> wallet_handle: {:?}, pool_handle: {:?}, did: {:?}", wallet_handle, pool_handle, did);
+
+        try_cb!(self.crypto_service.validate_did(&did), cb);
+
+        let endpoint =
+            self.wallet_service.get_indy_object::<Endpoint>(wallet_handle, &did.0, &RecordOptions::id_value());
+
+        match endpoint {
+            Ok(endpoint) => cb(Ok((endpoint.ha, endpoint.verkey))),
             Err(ref err) if err.kind() == IndyErrorKind::WalletItemNotFound => self._fetch_attrib_from_ledger(wallet_handle,
                                                                                                               pool_handle,
                                                                                                               &did,
@@ -473,6 +1335,163 @@ impl DidCommandExecutor {
         };
     }
 
+    fn set_named_endpoint_for_did(&self,
+                                  wallet_handle: WalletHandle,
+                                  did: &DidValue,
+                                  endpoint: NamedEndpoint) -> IndyResult<()> {
+        debug!("set_named_endpoint_for_did >>> wallet_handle: {:?}, did: {:?}, endpoint: {:?}", wallet_handle, did, endpoint);
+
+        self.crypto_service.validate_did(did)?;
+
+        for key in endpoint.verkey.iter().chain(endpoint.routing_keys.iter()) {
+            self.crypto_service.validate_key(key)?;
+        }
+
+        self._ensure_did_stored(wallet_handle, did)?;
+
+        // Named endpoints and additional services share DID Document service ids
+        let services = self.wallet_service.get_indy_opt_object::<DidServices>(wallet_handle, &did.0, &RecordOptions::id_value())?.unwrap_or_default();
+
+        if services.value.iter().any(|s| s.id == endpoint.name) {
+            return Err(err_msg(IndyErrorKind::WalletItemAlreadyExists, format!("Service with id {} already exists for DID", endpoint.name)));
+        }
+
+        let mut endpoints = self.wallet_service.get_indy_opt_object::<NamedEndpoints>(wallet_handle, &did.0, &RecordOptions::id_value())?.unwrap_or_default();
+
+        match endpoints.value.iter().position(|e| e.name == endpoint.name) {
+            Some(position) => endpoints.value[position] = endpoint,
+            None => endpoints.value.push(endpoint)
+        }
+
+        self.wallet_service.upsert_indy_object(wallet_handle, &did.0, &endpoints)?;
+
+        debug!("set_named_endpoint_for_did <<<");
+
+        Ok(())
+    }
+
+    fn remove_named_endpoint_for_did(&self,
+                                     wallet_handle: WalletHandle,
+                                     did: &DidValue,
+                                     name: &str) -> IndyResult<()> {
+        debug!("remove_named_endpoint_for_did >>> wallet_handle: {:?}, did: {:?}, name: {:?}", wallet_handle, did, name);
+
+        self.crypto_service.validate_did(did)?;
+
+        let mut endpoints = self.wallet_service.get_indy_opt_object::<NamedEndpoints>(wallet_handle, &did.0, &RecordOptions::id_value())?.unwrap_or_default();
+
+        let position = endpoints.value.iter().position(|e| e.name == name)
+            .ok_or_else(|| err_msg(IndyErrorKind::WalletItemNotFound, format!("Endpoint with name {} not found for DID", name)))?;
+
+        endpoints.value.remove(position);
+
+        self.wallet_service.upsert_indy_object(wallet_handle, &did.0, &endpoints)?;
+
+        debug!("remove_named_endpoint_for_did <<<");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "ledger")]
+    fn get_named_endpoints_for_did(&self,
+                                   wallet_handle: WalletHandle,
+                                   pool_handle: PoolHandle,
+                                   did: DidValue,
+                                   cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        debug!("get_named_endpoints_for_did >>> wallet_handle: {:?}, pool_handle: {:?}, did: {:?}", wallet_handle, pool_handle, did);
+
+        try_cb!(self.crypto_service.validate_did(&did), cb);
+
+        let endpoints = try_cb!(self.wallet_service.get_indy_opt_object::<NamedEndpoints>(wallet_handle, &did.0, &RecordOptions::id_value()), cb);
+
+        let endpoints = match endpoints {
+            Some(endpoints) => endpoints,
+            // Endpoints of my did are stored in the wallet only
+            None if try_cb!(self.wallet_service.record_exists::<Did>(wallet_handle, &did.0), cb) => NamedEndpoints::default(),
+            None => return self._fetch_named_endpoints_attrib_from_ledger(wallet_handle,
+                                                                          pool_handle,
+                                                                          &did,
+                                                                          DidCommand::GetNamedEndpointsForDid(
+                                                                              wallet_handle,
+                                                                              pool_handle,
+                                                                              did.clone(),
+                                                                              cb))
+        };
+
+        let res = serde_json::to_string(&endpoints.value)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize named endpoints");
+
+        debug!("get_named_endpoints_for_did <<< res: {:?}", res);
+
+        cb(res)
+    }
+
+    #[cfg(feature = "ledger")]
+    fn build_named_endpoints_attrib_request(&self,
+                                            wallet_handle: WalletHandle,
+                                            submitter_did: &DidValue,
+                                            target_did: &DidValue) -> IndyResult<String> {
+        debug!("build_named_endpoints_attrib_request >>> wallet_handle: {:?}, submitter_did: {:?}, target_did: {:?}", wallet_handle, submitter_did, target_did);
+
+        self.crypto_service.validate_did(submitter_did)?;
+        self.crypto_service.validate_did(target_did)?;
+
+        let endpoints = self.wallet_service.get_indy_opt_object::<NamedEndpoints>(wallet_handle, &target_did.0, &RecordOptions::id_value())?.unwrap_or_default();
+
+        let raw = serde_json::to_string(&EndpointsAttribData { endpoints: endpoints.value })
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize endpoints attrib")?;
+
+        let res = self.ledger_service.build_attrib_request(submitter_did, target_did, None, Some(&serde_json::from_str(&raw).unwrap()), None)?;
+
+        debug!("build_named_endpoints_attrib_request <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    #[cfg(feature = "ledger")]
+    fn get_named_endpoints_attrib_ack(&self,
+                                      wallet_handle: WalletHandle,
+                                      did: &DidValue,
+                                      get_attrib_reply_result: IndyResult<String>,
+                                      deferred_cmd_id: CommandHandle) {
+        let res = self._get_named_endpoints_attrib_ack(wallet_handle, did, get_attrib_reply_result);
+        self._execute_deferred_command(deferred_cmd_id, res.err());
+    }
+
+    #[cfg(feature = "ledger")]
+    fn _get_named_endpoints_attrib_ack(&self, wallet_handle: WalletHandle, did: &DidValue, get_attrib_reply_result: IndyResult<String>) -> IndyResult<()> {
+        trace!("_get_named_endpoints_attrib_ack >>> wallet_handle: {:?}, did: {:?}, get_attrib_reply_result: {:?}", wallet_handle, did, get_attrib_reply_result);
+
+        let get_attrib_reply = get_attrib_reply_result?;
+
+        let get_attrib_reply_json: serde_json::Value = serde_json::from_str(&get_attrib_reply)
+            .to_indy(IndyErrorKind::InvalidState, "Invalid GetAttrReplyResult json")?;
+
+        // No endpoints published on the ledger. Empty list is cached to avoid repeated ledger lookups.
+        let endpoints = if get_attrib_reply_json["result"]["data"].is_null() {
+            NamedEndpoints::default()
+        } else {
+            let get_attrib_reply: Reply<GetAttrReplyResult> = serde_json::from_value(get_attrib_reply_json)
+                .to_indy(IndyErrorKind::InvalidState, "Invalid GetAttrReplyResult json")?;
+
+            let raw = match get_attrib_reply.result() {
+                GetAttrReplyResult::GetAttrReplyResultV0(res) => res.data,
+                GetAttrReplyResult::GetAttrReplyResultV1(res) => res.txn.data.raw
+            };
+
+            let attrib_data: EndpointsAttribData = serde_json::from_str(&raw)
+                .to_indy(IndyErrorKind::InvalidState, "Invalid GetAttReply json")?;
+
+            NamedEndpoints { value: attrib_data.endpoints }
+        };
+
+        self.wallet_service.upsert_indy_object(wallet_handle, &did.0, &endpoints)?;
+
+        trace!("_get_named_endpoints_attrib_ack <<<");
+
+        Ok(())
+    }
+
     fn set_did_metadata(&self,
                         wallet_handle: WalletHandle,
                         did: &DidValue,
@@ -483,6 +1502,10 @@ impl DidCommandExecutor {
 
         let metadata = DidMetadata { value: metadata };
 
+        if self.wallet_service.record_exists::<Did>(wallet_handle, &did.0)? {
+            self._update_did_metadata_tags(wallet_handle, did, &metadata)?;
+        }
+
         self.wallet_service.upsert_indy_object(wallet_handle, &did.0, &metadata)?;
 
         debug!("set_did_metadata >>>");
@@ -490,6 +1513,32 @@ impl DidCommandExecutor {
         Ok(())
     }
 
+    /// Replaces tags of the previous metadata fields of my DID with the fields of the new metadata.
+    /// Tags set by `set_my_did_tags` with other names are kept.
+    fn _update_did_metadata_tags(&self,
+                                 wallet_handle: WalletHandle,
+                                 did: &DidValue,
+                                 metadata: &DidMetadata) -> IndyResult<()> {
+        let did_type = self.wallet_service.add_prefix("Did");
+
+        let prev_tags = self.wallet_service.get_indy_opt_object::<DidMetadata>(wallet_handle, &did.0, &RecordOptions::id_value())?
+            .map(|prev_metadata| prev_metadata.tags())
+            .unwrap_or_default();
+
+        if !prev_tags.is_empty() {
+            let tag_names: Vec<&str> = prev_tags.keys().map(String::as_str).collect();
+            self.wallet_service.delete_record_tags(wallet_handle, &did_type, &did.0, &tag_names)?;
+        }
+
+        let tags = metadata.tags();
+
+        if !tags.is_empty() {
+            self.wallet_service.add_record_tags(wallet_handle, &did_type, &did.0, &tags)?;
+        }
+
+        Ok(())
+    }
+
     fn get_did_metadata(&self,
                         wallet_handle: WalletHandle,
                         did: &DidValue) -> IndyResult<String> {
@@ -506,6 +1555,123 @@ impl DidCommandExecutor {
         Ok(res)
     }
 
+    fn add_did_key(&self,
+                   wallet_handle: WalletHandle,
+                   did: &DidValue,
+                   key: DidKey) -> IndyResult<()> {
+        debug!("add_did_key >>> wallet_handle: {:?}, did: {:?}, key: {:?}", wallet_handle, did, key);
+
+        self.crypto_service.validate_did(did)?;
+        self.crypto_service.validate_key(&key.verkey)?;
+        self._ensure_did_stored(wallet_handle, did)?;
+        self._ensure_fragment_unused(wallet_handle, did, &key.id)?;
+
+        let mut keys = self.wallet_service.get_indy_opt_object::<DidKeys>(wallet_handle, &did.0, &RecordOptions::id_value())?.unwrap_or_default();
+
+        keys.value.push(key);
+
+        self.wallet_service.upsert_indy_object(wallet_handle, &did.0, &keys)?;
+
+        debug!("add_did_key <<<");
+
+        Ok(())
+    }
+
+    fn remove_did_key(&self,
+                      wallet_handle: WalletHandle,
+                      did: &DidValue,
+                      key_id: &str) -> IndyResult<()> {
+        debug!("remove_did_key >>> wallet_handle: {:?}, did: {:?}, key_id: {:?}", wallet_handle, did, key_id);
+
+        self.crypto_service.validate_did(did)?;
+
+        let mut keys = self.wallet_service.get_indy_opt_object::<DidKeys>(wallet_handle, &did.0, &RecordOptions::id_value())?.unwrap_or_default();
+
+        let position = keys.value.iter().position(|k| k.id == key_id)
+            .ok_or_else(|| err_msg(IndyErrorKind::WalletItemNotFound, format!("Key with id {} not found for DID", key_id)))?;
+
+        keys.value.remove(position);
+
+        self.wallet_service.upsert_indy_object(wallet_handle, &did.0, &keys)?;
+
+        debug!("remove_did_key <<<");
+
+        Ok(())
+    }
+
+    fn add_did_service(&self,
+                       wallet_handle: WalletHandle,
+                       did: &DidValue,
+                       service: DidService) -> IndyResult<()> {
+        debug!("add_did_service >>> wallet_handle: {:?}, did: {:?}, service: {:?}", wallet_handle, did, service);
+
+        self.crypto_service.validate_did(did)?;
+
+        for key in service.recipient_keys.iter().chain(service.routing_keys.iter()) {
+            self.crypto_service.validate_key(key)?;
+        }
+
+        self._ensure_did_stored(wallet_handle, did)?;
+        self._ensure_fragment_unused(wallet_handle, did, &service.id)?;
+
+        let mut services = self.wallet_service.get_indy_opt_object::<DidServices>(wallet_handle, &did.0, &RecordOptions::id_value())?.unwrap_or_default();
+
+        services.value.push(service);
+
+        self.wallet_service.upsert_indy_object(wallet_handle, &did.0, &services)?;
+
+        debug!("add_did_service <<<");
+
+        Ok(())
+    }
+
+    fn remove_did_service(&self,
+                          wallet_handle: WalletHandle,
+                          did: &DidValue,
+                          service_id: &str) -> IndyResult<()> {
+        debug!("remove_did_service >>> wallet_handle: {:?}, did: {:?}, service_id: {:?}", wallet_handle, did, service_id);
+
+        self.crypto_service.validate_did(did)?;
+
+        let mut services = self.wallet_service.get_indy_opt_object::<DidServices>(wallet_handle, &did.0, &RecordOptions::id_value())?.unwrap_or_default();
+
+        let position = services.value.iter().position(|s| s.id == service_id)
+            .ok_or_else(|| err_msg(IndyErrorKind::WalletItemNotFound, format!("Service with id {} not found for DID", service_id)))?;
+
+        services.value.remove(position);
+
+        self.wallet_service.upsert_indy_object(wallet_handle, &did.0, &services)?;
+
+        debug!("remove_did_service <<<");
+
+        Ok(())
+    }
+
+    /// Keys and services can be bound only to DIDs already stored in the wallet (my or their).
+    fn _ensure_did_stored(&self, wallet_handle: WalletHandle, did: &DidValue) -> IndyResult<()> {
+        if self.wallet_service.record_exists::<Did>(wallet_handle, &did.0)? ||
+            self.wallet_service.record_exists::<TheirDid>(wallet_handle, &did.0)? {
+            return Ok(());
+        }
+
+        Err(err_msg(IndyErrorKind::WalletItemNotFound, format!("DID {} not found in the wallet", did.0)))
+    }
+
+    /// Keys, services and named endpoints share fragment ids of the DID Document.
+    fn _ensure_fragment_unused(&self, wallet_handle: WalletHandle, did: &DidValue, id: &str) -> IndyResult<()> {
+        let keys = self.wallet_service.get_indy_opt_object::<DidKeys>(wallet_handle, &did.0, &RecordOptions::id_value())?.unwrap_or_default();
+        let services = self.wallet_service.get_indy_opt_object::<DidServices>(wallet_handle, &did.0, &RecordOptions::id_value())?.unwrap_or_default();
+        let named_endpoints = self.wallet_service.get_indy_opt_object::<NamedEndpoints>(wallet_handle, &did.0, &RecordOptions::id_value())?.unwrap_or_default();
+
+        if keys.value.iter().any(|k| k.id == id)
+            || services.value.iter().any(|s| s.id == id)
+            || named_endpoints.value.iter().any(|e| e.name == id) {
+            return Err(err_msg(IndyErrorKind::WalletItemAlreadyExists, format!("Key or service with id {} already exists for DID", id)));
+        }
+
+        Ok(())
+    }
+
     fn abbreviate_verkey(&self,
                          did: &DidValue,
                          verkey: String) -> IndyResult<String> {
@@ -542,12 +1708,17 @@ impl DidCommandExecutor {
 
         self.crypto_service.validate_did(did)?;
 
-        let mut curr_did: Did = self.wallet_service.get_indy_object::<Did>(wallet_handle, &did.0, &RecordOptions::id_value())?;
+        let did_record = self.wallet_service.get_indy_record::<Did>(wallet_handle, &did.0, &json!({"retrieveValue": true, "retrieveTags": true}).to_string())?;
+
+        let mut curr_did: Did = did_record.get_value()
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "No value for DID record"))
+            .and_then(|did_json| serde_json::from_str(did_json)
+                .to_indy(IndyErrorKind::InvalidState, format!("Cannot deserialize Did: {:?}", did)))?;
 
         curr_did.did = DidValue::new(&did.to_short().0, Some(&method.0));
 
         self.wallet_service.delete_indy_record::<Did>(wallet_handle, &did.0)?;
-        self.wallet_service.add_indy_object(wallet_handle, &curr_did.did.0, &curr_did, &HashMap::new())?;
+        self.wallet_service.add_indy_object(wallet_handle, &curr_did.did.0, &curr_did, did_record.get_tags().unwrap_or(&Tags::new()))?;
 
         // move temporary Did
         if let Ok(mut temp_did) = self.wallet_service.get_indy_object::<TemporaryDid>(wallet_handle, &did.0, &RecordOptions::id_value()) {
@@ -562,9 +1733,19 @@ impl DidCommandExecutor {
         // move endpoint
         self.update_dependent_entity_reference::<Endpoint>(wallet_handle, &did.0, &curr_did.did.0)?;
 
+        // move additional keys and services
+        self.update_dependent_entity_reference::<DidKeys>(wallet_handle, &did.0, &curr_did.did.0)?;
+        self.update_dependent_entity_reference::<DidServices>(wallet_handle, &did.0, &curr_did.did.0)?;
+
+        // move named endpoints
+        self.update_dependent_entity_reference::<NamedEndpoints>(wallet_handle, &did.0, &curr_did.did.0)?;
+
+        // move deactivation mark
+        self.update_dependent_entity_reference::<DidDeactivation>(wallet_handle, &did.0, &curr_did.did.0)?;
+
         // move all pairwise
         let mut pairwise_search =
-            self.wallet_service.search_indy_records::<Pairwise>(wallet_handle, "{}", &RecordOptions::id_value())?;
+            self.wallet_service.search_indy_records::<Pairwise>(wallet_handle, "{}", &json!({"retrieveTags": true}).to_string())?;
 
         while let Some(pairwise_record) = pairwise_search.fetch_next_record()? {
             let mut pairwise: Pairwise = pairwise_record.get_value()
@@ -575,6 +1756,8 @@ impl DidCommandExecutor {
             if pairwise.my_did.eq(did) {
                 pairwise.my_did = curr_did.did.clone();
                 self.wallet_service.update_indy_object(wallet_handle, &pairwise.their_did.0, &pairwise)?;
+                self.wallet_service.update_record_tags(wallet_handle, &self.wallet_service.add_prefix("Pairwise"), &pairwise.their_did.0,
+                                                       &pairwise.tags(&PairwiseMeta::from_tags(pairwise_record.get_tags())))?;
             }
         }
 
@@ -583,6 +1766,262 @@ impl DidCommandExecutor {
         Ok(curr_did.did.0)
     }
 
+    fn qualify_all_dids(&self,
+                        wallet_handle: WalletHandle,
+                        method: &DidMethod,
+                        options: QualifyDidsOptions) -> IndyResult<String> {
+        debug!("qualify_all_dids >>> wallet_handle: {:?}, method: {:?}, options: {:?}", wallet_handle, method, options);
+
+        let my_dids = self._plan_dids_qualification::<Did>(wallet_handle, method)?;
+        let their_dids = self._plan_dids_qualification::<TheirDid>(wallet_handle, method)?;
+
+        let mut pairwise_count = 0;
+
+        let mut pairwise_search =
+            self.wallet_service.search_indy_records::<Pairwise>(wallet_handle, "{}", &RecordOptions::id_value())?;
+
+        while let Some(pairwise_record) = pairwise_search.fetch_next_record()? {
+            let pairwise: Pairwise = pairwise_record.get_value()
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "No value for Pairwise record"))
+                .and_then(|pairwise_json| serde_json::from_str(pairwise_json)
+                    .map_err(|err| IndyError::from_msg(IndyErrorKind::InvalidState, format!("Cannot deserialize Pairwise: {:?}", err))))?;
+
+            if my_dids.iter().any(|item| item.did == pairwise.my_did) ||
+                their_dids.iter().any(|item| item.did == pairwise.their_did) {
+                pairwise_count += 1;
+            }
+        }
+
+        // Wallet doesn't support transactions so all conflicts are checked before the first write.
+        for item in my_dids.iter() {
+            if self.wallet_service.record_exists::<Did>(wallet_handle, &item.qualified_did.0)? {
+                return Err(err_msg(IndyErrorKind::WalletItemAlreadyExists, format!("DID {} already exists in the wallet", item.qualified_did.0)));
+            }
+        }
+
+        for item in their_dids.iter() {
+            if self.wallet_service.record_exists::<TheirDid>(wallet_handle, &item.qualified_did.0)? ||
+                self.wallet_service.record_exists::<Pairwise>(wallet_handle, &item.qualified_did.0)? {
+                return Err(err_msg(IndyErrorKind::WalletItemAlreadyExists, format!("Their DID {} already exists in the wallet", item.qualified_did.0)));
+            }
+        }
+
+        if !options.dry_run {
+            for item in my_dids.iter() {
+                self.qualify_did(wallet_handle, &item.did, method)?;
+            }
+
+            for item in their_dids.iter() {
+                self._qualify_their_did(wallet_handle, &item.did, &item.qualified_did)?;
+            }
+        }
+
+        let report = DidsQualificationReport {
+            dry_run: options.dry_run,
+            my_dids,
+            their_dids,
+            pairwise_count,
+        };
+
+        let res = serde_json::to_string(&report)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize DIDs qualification report")?;
+
+        debug!("qualify_all_dids <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn _plan_dids_qualification<T>(&self, wallet_handle: WalletHandle, method: &DidMethod) -> IndyResult<Vec<DidQualification>>
+        where T: ::serde::Serialize + ::serde::de::DeserializeOwned + Sized {
+        let mut res = Vec::new();
+
+        let mut search =
+            self.wallet_service.search_indy_records::<T>(wallet_handle, "{}", &SearchOptions::id_value())?;
+
+        while let Some(record) = search.fetch_next_record()? {
+            let did = DidValue(record.get_id().to_string());
+
+            if !did.is_fully_qualified() {
+                res.push(DidQualification {
+                    qualified_did: DidValue::new(&did.0, Some(&method.0)),
+                    did,
+                });
+            }
+        }
+
+        Ok(res)
+    }
+
+    fn _qualify_their_did(&self, wallet_handle: WalletHandle, did: &DidValue, qualified_did: &DidValue) -> IndyResult<()> {
+        let mut their_did = self._wallet_get_their_did(wallet_handle, did)?;
+        their_did.did = qualified_did.clone();
+
+        self.wallet_service.delete_indy_record::<TheirDid>(wallet_handle, &did.0)?;
+        self.wallet_service.add_indy_object(wallet_handle, &qualified_did.0, &their_did, &HashMap::new())?;
+
+        self.update_dependent_entity_reference::<DidMetadata>(wallet_handle, &did.0, &qualified_did.0)?;
+        self.update_dependent_entity_reference::<Endpoint>(wallet_handle, &did.0, &qualified_did.0)?;
+        self.update_dependent_entity_reference::<DidKeys>(wallet_handle, &did.0, &qualified_did.0)?;
+        self.update_dependent_entity_reference::<DidServices>(wallet_handle, &did.0, &qualified_did.0)?;
+        self.update_dependent_entity_reference::<NamedEndpoints>(wallet_handle, &did.0, &qualified_did.0)?;
+        self.update_dependent_entity_reference::<DidDeactivation>(wallet_handle, &did.0, &qualified_did.0)?;
+
+        // Pairwise record is identified by their DID
+        if self.wallet_service.record_exists::<Pairwise>(wallet_handle, &did.0)? {
+            let pairwise_record = self.wallet_service.get_indy_record::<Pairwise>(wallet_handle, &did.0, &json!({"retrieveValue": true, "retrieveTags": true}).to_string())?;
+
+            let mut pairwise: Pairwise = pairwise_record.get_value()
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "No value for Pairwise record"))
+                .and_then(|pairwise_json| serde_json::from_str(pairwise_json)
+                    .map_err(|err| IndyError::from_msg(IndyErrorKind::InvalidState, format!("Cannot deserialize Pairwise: {:?}", err))))?;
+
+            pairwise.their_did = qualified_did.clone();
+
+            self.wallet_service.delete_indy_record::<Pairwise>(wallet_handle, &did.0)?;
+            self.wallet_service.add_indy_object(wallet_handle, &qualified_did.0, &pairwise, &pairwise.tags(&PairwiseMeta::from_tags(pairwise_record.get_tags())))?;
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "ledger")]
+    fn get_did_doc(&self,
+                   wallet_handle: WalletHandle,
+                   pool_handle: PoolHandle,
+                   did: DidValue,
+                   cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        debug!("get_did_doc >>> wallet_handle: {:?}, pool_handle: {:?}, did: {:?}", wallet_handle, pool_handle, did);
+
+        try_cb!(self.crypto_service.validate_did(&did), cb);
+
+        // Look to my did
+        let (verkey, is_my_did) = match self._wallet_get_my_did(wallet_handle, &did) {
+            Ok(my_did) => (my_did.verkey, true),
+            Err(ref err) if err.kind() == IndyErrorKind::WalletItemNotFound => {
+                // look to their did
+                let their_did = ensure_their_did!(self,
+                                                  wallet_handle,
+                                                  pool_handle,
+                                                  did,
+                                                  DidCommand::GetDidDoc(
+                                                      wallet_handle,
+                                                      pool_handle,
+                                                      did.clone(),
+                                                      cb),
+                                                  cb);
+                (their_did.verkey, false)
+            }
+            Err(err) => return cb(Err(err))
+        };
+
+        let endpoint = try_cb!(self.wallet_service.get_indy_opt_object::<Endpoint>(wallet_handle, &did.0, &RecordOptions::id_value()), cb);
+
+        // Endpoint of their did can be published on the ledger only
+        if endpoint.is_none() && !is_my_did {
+            return self._fetch_did_doc_attrib_from_ledger(wallet_handle,
+                                                          pool_handle,
+                                                          &did,
+                                                          DidCommand::GetDidDoc(
+                                                              wallet_handle,
+                                                              pool_handle,
+                                                              did.clone(),
+                                                              cb));
+        }
+
+        let res = self._build_did_doc(wallet_handle, &did, &verkey, endpoint.as_ref());
+
+        debug!("get_did_doc <<< res: {:?}", res);
+
+        cb(res)
+    }
+
+    #[cfg(feature = "ledger")]
+    fn get_did_doc_attrib_ack(&self,
+                              wallet_handle: WalletHandle,
+                              get_attrib_reply_result: IndyResult<String>,
+                              deferred_cmd_id: CommandHandle) {
+        let cmd = self.deferred_commands.borrow_mut().remove(&deferred_cmd_id);
+
+        match cmd {
+            Some(DidCommand::GetDidDoc(_, _, did, cb)) => {
+                let res = self._get_did_doc_attrib_ack(wallet_handle, get_attrib_reply_result)
+                    .and_then(|endpoint| {
+                        let their_did = self._wallet_get_their_did(wallet_handle, &did)?;
+                        self._build_did_doc(wallet_handle, &did, &their_did.verkey, endpoint.as_ref())
+                    });
+
+                debug!("get_did_doc <<< res: {:?}", res);
+
+                cb(res)
+            }
+            Some(cmd) => self._call_error_cb(cmd, err_msg(IndyErrorKind::InvalidState, "Unexpected deferred command for GetDidDocAttribAck")),
+            None => error!("No deferred command for id: {:?}", deferred_cmd_id)
+        }
+    }
+
+    #[cfg(feature = "ledger")]
+    fn _get_did_doc_attrib_ack(&self, wallet_handle: WalletHandle, get_attrib_reply_result: IndyResult<String>) -> IndyResult<Option<Endpoint>> {
+        trace!("_get_did_doc_attrib_ack >>> wallet_handle: {:?}, get_attrib_reply_result: {:?}", wallet_handle, get_attrib_reply_result);
+
+        let get_attrib_reply = get_attrib_reply_result?;
+
+        let get_attrib_reply_json: serde_json::Value = serde_json::from_str(&get_attrib_reply)
+            .to_indy(IndyErrorKind::InvalidState, "Invalid GetAttrReplyResult json")?;
+
+        // No endpoint published on the ledger. DID Document will be built without services.
+        if get_attrib_reply_json["result"]["data"].is_null() {
+            trace!("_get_did_doc_attrib_ack <<< res: None");
+            return Ok(None);
+        }
+
+        let dest = get_attrib_reply_json["result"]["dest"].as_str()
+            .or_else(|| get_attrib_reply_json["result"]["txn"]["data"]["did"].as_str())
+            .map(String::from)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "Invalid GetAttrReplyResult json: DID not found"))?;
+
+        self._get_attrib_ack(wallet_handle, Ok(get_attrib_reply))?;
+
+        let res = self.wallet_service.get_indy_opt_object::<Endpoint>(wallet_handle, &dest, &RecordOptions::id_value())?;
+
+        trace!("_get_did_doc_attrib_ack <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    #[cfg(feature = "ledger")]
+    fn _build_did_doc(&self, wallet_handle: WalletHandle, did: &DidValue, verkey: &str, endpoint: Option<&Endpoint>) -> IndyResult<String> {
+        let x25519_key = self.crypto_service.convert_verkey_to_x25519(verkey)?;
+
+        let mut did_doc = DidDocument::new(did, verkey, &x25519_key, endpoint);
+
+        let keys = self.wallet_service.get_indy_opt_object::<DidKeys>(wallet_handle, &did.0, &RecordOptions::id_value())?.unwrap_or_default();
+
+        for key in keys.value.iter() {
+            let public_key = match key.purpose {
+                DidKeyPurpose::KeyAgreement => self.crypto_service.convert_verkey_to_x25519(&key.verkey)?,
+                _ => key.verkey.clone()
+            };
+            did_doc.add_key(key, &public_key);
+        }
+
+        let services = self.wallet_service.get_indy_opt_object::<DidServices>(wallet_handle, &did.0, &RecordOptions::id_value())?.unwrap_or_default();
+
+        for service in services.value.iter() {
+            did_doc.add_service(service);
+        }
+
+        let named_endpoints = self.wallet_service.get_indy_opt_object::<NamedEndpoints>(wallet_handle, &did.0, &RecordOptions::id_value())?.unwrap_or_default();
+
+        for endpoint in named_endpoints.value.iter() {
+            did_doc.add_named_endpoint(endpoint, verkey);
+        }
+
+        did_doc.deactivated = self.wallet_service.record_exists::<DidDeactivation>(wallet_handle, &did.0)?;
+
+        serde_json::to_string(&did_doc)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize DID Document")
+    }
+
     fn update_dependent_entity_reference<T>(&self, wallet_handle: WalletHandle, id: &str, new_id: &str) -> IndyResult<()>
         where T: ::serde::Serialize + ::serde::de::DeserializeOwned + Sized {
         if let Ok(record) = self.wallet_service.get_indy_record_value::<T>(wallet_handle, id, "{}") {
@@ -592,6 +2031,7 @@ impl DidCommandExecutor {
         Ok(())
     }
 
+    #[cfg(feature = "ledger")]
     fn get_nym_ack(&self,
                    wallet_handle: WalletHandle,
                    did: DidValue,
@@ -601,6 +2041,7 @@ impl DidCommandExecutor {
         self._execute_deferred_command(deferred_cmd_id, res.err());
     }
 
+    #[cfg(feature = "ledger")]
     fn _get_nym_ack(&self, wallet_handle: WalletHandle, did: DidValue, get_nym_reply_result: IndyResult<String>) -> IndyResult<()> {
         trace!("_get_nym_ack >>> wallet_handle: {:?}, get_nym_reply_result: {:?}", wallet_handle, get_nym_reply_result);
 
@@ -632,6 +2073,7 @@ impl DidCommandExecutor {
         Ok(())
     }
 
+    #[cfg(feature = "ledger")]
     fn get_attrib_ack(&self,
                       wallet_handle: WalletHandle,
                       get_attrib_reply_result: IndyResult<String>,
@@ -640,6 +2082,7 @@ impl DidCommandExecutor {
         self._execute_deferred_command(deferred_cmd_id, res.err());
     }
 
+    #[cfg(feature = "ledger")]
     fn _get_attrib_ack(&self, wallet_handle: WalletHandle, get_attrib_reply_result: IndyResult<String>) -> IndyResult<()> {
         trace!("_get_attrib_ack >>> wallet_handle: {:?}, get_attrib_reply_result: {:?}", wallet_handle, get_attrib_reply_result);
 
@@ -665,12 +2108,14 @@ impl DidCommandExecutor {
         Ok(())
     }
 
+    #[cfg(feature = "ledger")]
     fn _defer_command(&self, cmd: DidCommand) -> CommandHandle {
         let deferred_cmd_id = next_command_handle();
         self.deferred_commands.borrow_mut().insert(deferred_cmd_id, cmd);
         deferred_cmd_id
     }
 
+    #[cfg(feature = "ledger")]
     fn _execute_deferred_command(&self, deferred_cmd_id: CommandHandle, err: Option<IndyError>) {
         if let Some(cmd) = self.deferred_commands.borrow_mut().remove(&deferred_cmd_id) {
             if let Some(err) = err {
@@ -683,6 +2128,7 @@ impl DidCommandExecutor {
         }
     }
 
+    #[cfg(feature = "ledger")]
     fn _call_error_cb(&self, command: DidCommand, err: IndyError) {
         match command {
             DidCommand::CreateAndStoreMyDid(_, _, cb) => {
@@ -703,10 +2149,23 @@ impl DidCommandExecutor {
             DidCommand::GetEndpointForDid(_, _, _, cb) => {
                 cb(Err(err));
             }
+            DidCommand::GetDidDoc(_, _, _, cb) => {
+                cb(Err(err));
+            }
+            DidCommand::RotateKey(_, _, _, _, _, cb) => {
+                cb(Err(err));
+            }
+            DidCommand::PublishDidDeactivation(_, _, _, cb) => {
+                cb(Err(err));
+            }
+            DidCommand::GetNamedEndpointsForDid(_, _, _, cb) => {
+                cb(Err(err));
+            }
             _ => {}
         }
     }
 
+    #[cfg(feature = "ledger")]
     fn _fetch_their_did_from_ledger(&self,
                                     wallet_handle: WalletHandle, pool_handle: PoolHandle,
                                     did: &DidValue, deferred_cmd: DidCommand) {
@@ -715,7 +2174,7 @@ impl DidCommandExecutor {
 
         // TODO we need passing of my_did as identifier
         // TODO: FIXME: Remove this unwrap by sending GetNymAck with the error.
-        let get_nym_request = self.ledger_service.build_get_nym_request(None, did).unwrap();
+        let get_nym_request = self.ledger_service.build_get_nym_request(None, did, None, None).unwrap();
         let did = did.clone();
 
         CommandExecutor::instance()
@@ -734,6 +2193,7 @@ impl DidCommandExecutor {
             ))).unwrap();
     }
 
+    #[cfg(feature = "ledger")]
     fn _fetch_attrib_from_ledger(&self,
                                  wallet_handle: WalletHandle, pool_handle: PoolHandle,
                                  did: &DidValue, deferred_cmd: DidCommand) {
@@ -742,7 +2202,7 @@ impl DidCommandExecutor {
 
         // TODO we need passing of my_did as identifier
         // TODO: FIXME: Remove this unwrap by sending GetAttribAck with the error.
-        let get_attrib_request = self.ledger_service.build_get_attrib_request(None, did, Some("endpoint"), None, None).unwrap();
+        let get_attrib_request = self.ledger_service.build_get_endpoint_request(None, did).unwrap();
 
         CommandExecutor::instance()
             .send(Command::Ledger(LedgerCommand::SubmitRequest(
@@ -759,6 +2219,63 @@ impl DidCommandExecutor {
             ))).unwrap();
     }
 
+    #[cfg(feature = "ledger")]
+    fn _fetch_named_endpoints_attrib_from_ledger(&self,
+                                                 wallet_handle: WalletHandle, pool_handle: PoolHandle,
+                                                 did: &DidValue, deferred_cmd: DidCommand) {
+        // Defer this command until endpoints attrib is fetched from ledger.
+        let deferred_cmd_id = self._defer_command(deferred_cmd);
+
+        let get_attrib_request = match self.ledger_service.build_get_attrib_request(None, did, Some(ENDPOINTS_ATTRIB_NAME), None, None, None, None) {
+            Ok(request) => request,
+            Err(err) => return self._execute_deferred_command(deferred_cmd_id, Some(err))
+        };
+
+        let did = did.clone();
+
+        CommandExecutor::instance()
+            .send(Command::Ledger(LedgerCommand::SubmitRequest(
+                pool_handle,
+                get_attrib_request,
+                Box::new(move |result| {
+                    CommandExecutor::instance()
+                        .send(Command::Did(DidCommand::GetNamedEndpointsAttribAck(
+                            wallet_handle,
+                            did.clone(),
+                            result,
+                            deferred_cmd_id,
+                        ))).unwrap();
+                }),
+            ))).unwrap();
+    }
+
+    #[cfg(feature = "ledger")]
+    fn _fetch_did_doc_attrib_from_ledger(&self,
+                                         wallet_handle: WalletHandle, pool_handle: PoolHandle,
+                                         did: &DidValue, deferred_cmd: DidCommand) {
+        // Defer this command until endpoint attrib is fetched from ledger.
+        let deferred_cmd_id = self._defer_command(deferred_cmd);
+
+        let get_attrib_request = match self.ledger_service.build_get_endpoint_request(None, did) {
+            Ok(request) => request,
+            Err(err) => return self._execute_deferred_command(deferred_cmd_id, Some(err))
+        };
+
+        CommandExecutor::instance()
+            .send(Command::Ledger(LedgerCommand::SubmitRequest(
+                pool_handle,
+                get_attrib_request,
+                Box::new(move |result| {
+                    CommandExecutor::instance()
+                        .send(Command::Did(DidCommand::GetDidDocAttribAck(
+                            wallet_handle,
+                            result,
+                            deferred_cmd_id,
+                        ))).unwrap();
+                }),
+            ))).unwrap();
+    }
+
     fn _wallet_get_my_did(&self, wallet_handle: WalletHandle, my_did: &DidValue) -> IndyResult<Did> {
         self.wallet_service.get_indy_object(wallet_handle, &my_did.0, &RecordOptions::id_value())
     }
@@ -1,30 +1,38 @@
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
 use std::string::ToString;
+use std::thread;
+use std::time::{Duration, Instant};
 
-use indy_api_types::{CommandHandle, PoolHandle, WalletHandle};
+use indy_api_types::{CommandHandle, IndyHandle, PoolHandle, WalletHandle};
 use indy_api_types::errors::prelude::*;
 use indy_utils::next_command_handle;
-use rust_base58::ToBase58;
+use rust_base58::{FromBase58, ToBase58};
 use serde_json;
 use serde_json::Value;
 
 use indy_wallet::{RecordOptions, WalletService};
 
-use crate::api::ledger::{CustomFree, CustomTransactionParser};
-use crate::commands::{BoxedCallbackStringStringSend, Command, CommandExecutor};
+use crate::api::ledger::{CustomFree, CustomReplyMiddleware, CustomRequestMiddleware, CustomResponseParser, CustomTransactionBuilder, CustomTransactionParser};
+use crate::commands::{BoxedCallbackStringStringSend, BoxedCallbackStringStringU64Send, Command, CommandExecutor};
+use crate::commands::timeout;
 use crate::domain::anoncreds::credential_definition::{CredentialDefinition, CredentialDefinitionId, CredentialDefinitionV1};
 use crate::domain::anoncreds::revocation_registry_definition::{RevocationRegistryDefinition, RevocationRegistryDefinitionV1, RevocationRegistryId};
 use crate::domain::anoncreds::revocation_registry_delta::{RevocationRegistryDelta, RevocationRegistryDeltaV1};
 use crate::domain::anoncreds::schema::{Schema, SchemaId, SchemaV1};
-use crate::domain::crypto::did::{Did, DidValue};
+use crate::domain::crypto::did::{Did, DidValue, DidDeactivation};
 use crate::domain::crypto::key::Key;
-use crate::domain::ledger::auth_rule::{AuthRules, Constraint};
-use crate::domain::ledger::author_agreement::{AcceptanceMechanisms, GetTxnAuthorAgreementData};
+use crate::domain::ledger::attrib::Endpoint;
+use crate::domain::ledger::auth_rule::{AuthRule, AuthRuleAction, AuthRules, AuthRulesPageOptions, Constraint, RequestSigner};
+use crate::domain::ledger::author_agreement::{AcceptanceMechanisms, GetTxnAuthorAgreementData, TxnAuthorAgreementState};
 use crate::domain::ledger::node::NodeOperationData;
-use crate::domain::ledger::pool::Schedule;
-use crate::domain::ledger::request::Request;
+use crate::domain::ledger::nym::{NymData, NymRequestData};
+use crate::domain::ledger::pool::{PoolUpgradeScheduleData, Schedule};
+use crate::domain::ledger::request::{Request, TxnAuthrAgrmtAcceptanceData};
+use crate::domain::ledger::response::BatchRequestResult;
+use crate::domain::ledger::txn::{LedgerEventsFilter, DEFAULT_LEDGER_EVENTS_POLL_INTERVAL};
+use crate::domain::pool::SubmitRequestOptions;
 use crate::services::crypto::CryptoService;
 use crate::services::ledger::LedgerService;
 use crate::services::pool::{
@@ -32,6 +40,8 @@ use crate::services::pool::{
     PoolService
 };
 use crate::utils::crypto::signature_serializer::serialize_signature;
+use crate::utils::crypto::verkey_builder::build_full_verkey;
+use crate::utils::audit;
 
 pub enum LedgerCommand {
     SignAndSubmitRequest(
@@ -44,10 +54,71 @@ pub enum LedgerCommand {
         PoolHandle, // pool handle
         String, // request json
         Box<dyn Fn(IndyResult<String>) + Send>),
+    SubmitRequestWithOptions(
+        PoolHandle, // pool handle
+        String, // request json
+        SubmitRequestOptions, // options
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    SubmitRequestIdempotent(
+        PoolHandle, // pool handle
+        String, // request json
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    SubmitRequestIdempotentContinue(
+        PoolHandle, // pool handle
+        String, // get request json
+        IndyResult<String>, // write response
+        CommandHandle,
+    ),
+    SubmitRequestIdempotentFetchAck(
+        String, // write response
+        IndyResult<String>, // get response
+        CommandHandle,
+    ),
     SubmitAck(
         CommandHandle,
         IndyResult<String>, // result json or error
     ),
+    DropRequest(
+        CommandHandle, // request of cancelled call
+    ),
+    SubmitRequests(
+        PoolHandle, // pool handle
+        Vec<Value>, // requests
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    GetTxns(
+        PoolHandle, // pool handle
+        Option<DidValue>, // submitter did
+        Option<String>, // ledger type
+        i32, // from seq no
+        i32, // to seq no
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    StreamTxns(
+        PoolHandle, // pool handle
+        Option<DidValue>, // submitter did
+        Option<String>, // ledger type
+        i32, // from seq no
+        i32, // to seq no
+        Box<dyn Fn(String) + Send>, // txns callback
+        Box<dyn Fn(IndyResult<()>) + Send>),
+    GetTxnsAck(
+        CommandHandle, // txns range handle
+        IndyResult<Vec<IndyResult<String>>>, // GET_TXN responses
+    ),
+    SubscribeLedgerEvents(
+        PoolHandle, // pool handle
+        LedgerEventsFilter, // filter
+        Box<dyn Fn(IndyHandle, String) + Send>, // event callback
+        Box<dyn Fn(IndyResult<IndyHandle>) + Send>),
+    UnsubscribeLedgerEvents(
+        IndyHandle, // subscription handle
+        Box<dyn Fn(IndyResult<()>) + Send>),
+    PollLedgerEvents(
+        IndyHandle, // subscription handle
+    ),
+    PollLedgerEventsAck(
+        IndyHandle, // subscription handle
+        IndyResult<Vec<IndyResult<String>>>, // GET_TXN responses
+    ),
     SubmitAction(
         PoolHandle, // pool handle
         String, // request json
@@ -64,6 +135,18 @@ pub enum LedgerCommand {
         DidValue, // submitter did
         String, // request json
         Box<dyn Fn(IndyResult<String>) + Send>),
+    MergeMultiSignedRequests(
+        Vec<Value>, // requests
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    CheckRequestSignatures(
+        PoolHandle,
+        String, // request json
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    ForceSignRequest(
+        WalletHandle,
+        DidValue, // submitter did
+        String, // request json
+        Box<dyn Fn(IndyResult<String>) + Send>),
     BuildGetDdoRequest(
         Option<DidValue>, // submitter did
         DidValue, // target did
@@ -75,6 +158,11 @@ pub enum LedgerCommand {
         Option<String>, // alias
         Option<String>, // role
         Box<dyn Fn(IndyResult<String>) + Send>),
+    BuildNymRequestWithData(
+        DidValue, // submitter did
+        DidValue, // target did
+        NymRequestData, // nym data
+        Box<dyn Fn(IndyResult<String>) + Send>),
     BuildAttribRequest(
         DidValue, // submitter did
         DidValue, // target did
@@ -82,16 +170,29 @@ pub enum LedgerCommand {
         Option<serde_json::Value>, // raw
         Option<String>, // enc
         Box<dyn Fn(IndyResult<String>) + Send>),
+    BuildSetEndpointRequest(
+        DidValue, // submitter did
+        DidValue, // target did
+        Endpoint, // endpoint
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    BuildGetEndpointRequest(
+        Option<DidValue>, // submitter did
+        DidValue, // target did
+        Box<dyn Fn(IndyResult<String>) + Send>),
     BuildGetAttribRequest(
         Option<DidValue>, // submitter did
         DidValue, // target did
         Option<String>, // raw
         Option<String>, // hash
         Option<String>, // enc
+        Option<u64>, // seq no
+        Option<u64>, // timestamp
         Box<dyn Fn(IndyResult<String>) + Send>),
     BuildGetNymRequest(
         Option<DidValue>, // submitter did
         DidValue, // target did
+        Option<u64>, // seq no
+        Option<u64>, // timestamp
         Box<dyn Fn(IndyResult<String>) + Send>),
     ParseGetNymResponse(
         String, // get nym response json
@@ -154,6 +255,11 @@ pub enum LedgerCommand {
         bool, // force
         Option<String>, // package
         Box<dyn Fn(IndyResult<String>) + Send>),
+    BuildScheduledPoolUpgradeRequest(
+        PoolHandle,
+        DidValue, // submitter did
+        PoolUpgradeScheduleData, // upgrade data
+        Box<dyn Fn(IndyResult<String>) + Send>),
     BuildRevocRegDefRequest(
         DidValue, // submitter did
         RevocationRegistryDefinition, // data
@@ -188,14 +294,69 @@ pub enum LedgerCommand {
     ParseGetRevocRegDeltaResponse(
         String, // get revocation registry delta response
         Box<dyn Fn(IndyResult<(String, String, u64)>) + Send>),
+    ParseGetAttribResponse(
+        String, // get attrib response json
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    ParseGetTxnResponse(
+        String, // get txn response json
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    ParseGetAuthRuleResponse(
+        String, // get auth rule response json
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    ParseGetValidatorInfoResponse(
+        String, // get validator info action response json
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    ParseGetValidatorInfoSummaryResponse(
+        String, // get validator info action response json
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    ParsePoolUpgradeStatusResponse(
+        String, // get validator info action response json
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    ParseGetTxnAuthorAgreementResponse(
+        String, // get txn author agreement response json
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    ParseGetFrozenLedgersResponse(
+        String, // get frozen ledgers response json
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    ParseGetAcceptanceMechanismsResponse(
+        String, // get acceptance mechanisms response json
+        Box<dyn Fn(IndyResult<String>) + Send>),
     RegisterSPParser(
         String, // txn type
         CustomTransactionParser,
         CustomFree,
         Box<dyn Fn(IndyResult<()>) + Send>),
+    RegisterTxnBuilder(
+        String, // txn type
+        CustomTransactionBuilder,
+        Option<CustomResponseParser>,
+        CustomFree,
+        Box<dyn Fn(IndyResult<()>) + Send>),
+    RegisterLedgerMiddleware(
+        Option<CustomRequestMiddleware>,
+        Option<CustomReplyMiddleware>,
+        CustomFree,
+        Box<dyn Fn(IndyResult<IndyHandle>) + Send>),
+    UnregisterLedgerMiddleware(
+        IndyHandle, // middleware handle
+        Box<dyn Fn(IndyResult<()>) + Send>),
+    BuildCustomRequest(
+        Option<DidValue>, // submitter did
+        String, // txn type
+        String, // data json
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    ParseCustomResponse(
+        String, // txn type
+        String, // response json
+        Box<dyn Fn(IndyResult<String>) + Send>),
     GetResponseMetadata(
         String, // response
         Box<dyn Fn(IndyResult<String>) + Send>),
+    VerifyStateProof(
+        String, // request json
+        String, // response json
+        HashMap<String, String>, // bls keys of pool nodes
+        Box<dyn Fn(IndyResult<bool>) + Send>),
     BuildAuthRuleRequest(
         DidValue, // submitter did
         String, // auth type
@@ -217,6 +378,31 @@ pub enum LedgerCommand {
         Option<String>, // old value
         Option<String>, // new value
         Box<dyn Fn(IndyResult<String>) + Send>),
+    BuildRoleConstraint(
+        String, // role
+        u32, // signatures count
+        bool, // need to be owner
+        bool, // off ledger signature
+        Option<Value>, // metadata
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    BuildCombinedConstraint(
+        String, // combination
+        Vec<Constraint>, // constraints
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    EvaluateAuthRules(
+        Vec<AuthRule>, // auth rules
+        AuthRuleAction, // action
+        Vec<RequestSigner>, // signers
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    GetAuthRules(
+        PoolHandle,
+        Option<DidValue>, // submitter did
+        AuthRulesPageOptions, // page options
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    DiffAuthRules(
+        Vec<AuthRule>, // current auth rules
+        Vec<AuthRule>, // proposed auth rules
+        Box<dyn Fn(IndyResult<String>) + Send>),
     GetSchema(
         PoolHandle,
         Option<DidValue>,
@@ -239,6 +425,36 @@ pub enum LedgerCommand {
         IndyResult<String>,
         CommandHandle,
     ),
+    GetRevocRegDef(
+        PoolHandle,
+        Option<DidValue>,
+        RevocationRegistryId,
+        BoxedCallbackStringStringSend,
+    ),
+    GetRevocRegDefContinue(
+        IndyResult<String>,
+        CommandHandle,
+    ),
+    GetRevocReg(
+        PoolHandle,
+        Option<DidValue>,
+        RevocationRegistryId,
+        i64, // timestamp
+        BoxedCallbackStringStringU64Send,
+    ),
+    GetRevocRegDelta(
+        PoolHandle,
+        Option<DidValue>,
+        RevocationRegistryId,
+        Option<i64>, // from
+        i64, // to
+        BoxedCallbackStringStringU64Send,
+    ),
+    GetRevocRegContinue(
+        bool, // is delta
+        IndyResult<String>,
+        CommandHandle,
+    ),
     BuildTxnAuthorAgreementRequest(
         DidValue, // submitter did
         Option<String>, // text
@@ -272,10 +488,21 @@ pub enum LedgerCommand {
         String, // acceptance mechanism type
         u64, // time of acceptance
         Box<dyn Fn(IndyResult<String>) + Send>),
+    PrepareRequestWithTaa(
+        PoolHandle, // pool handle
+        WalletHandle, // wallet handle
+        DidValue, // submitter did
+        String, // request json
+        Option<String>, // acceptance mechanism type
+        Box<dyn Fn(IndyResult<String>) + Send>),
     AppendRequestEndorser(
         String, // request json
         DidValue, // endorser did
         Box<dyn Fn(IndyResult<String>) + Send>),
+    SetRequestProtocolVersion(
+        String, // request json
+        usize, // protocol version
+        Box<dyn Fn(IndyResult<String>) + Send>),
     BuildGetFrozenLedgersRequest(
         DidValue, // submitter did
         Box<dyn Fn(IndyResult<String>) + Send>),
@@ -285,6 +512,49 @@ pub enum LedgerCommand {
         Box<dyn Fn(IndyResult<String>) + Send>),
 }
 
+type BatchSubmissionCallback = Box<dyn Fn(IndyResult<Vec<IndyResult<String>>>)>;
+type IdempotentSubmissionCallback = Box<dyn Fn(IndyResult<String>) + Send>;
+
+// Number of GET_TXN requests sent to the pool at once while fetching a range of transactions
+const GET_TXNS_BATCH_SIZE: i32 = 100;
+
+// Latest agreement is requested from the ledger again once cached one expires
+const TAA_CACHE_TTL: Duration = Duration::from_secs(600);
+
+struct BatchSubmission {
+    results: Vec<Option<IndyResult<String>>>,
+    pending: usize,
+    cb: BatchSubmissionCallback,
+}
+
+enum TxnRangeConsumer {
+    Collect(Vec<Value>, Box<dyn Fn(IndyResult<String>) + Send>),
+    Stream(Box<dyn Fn(String) + Send>, Box<dyn Fn(IndyResult<()>) + Send>),
+}
+
+struct TxnRange {
+    pool_handle: PoolHandle,
+    submitter_did: Option<DidValue>,
+    ledger_type: Option<String>,
+    next: i32,
+    to: i32,
+    consumer: TxnRangeConsumer,
+    call_id: Option<u64>,
+}
+
+struct LedgerMiddleware {
+    request: Option<CustomRequestMiddleware>,
+    reply: Option<CustomReplyMiddleware>,
+    free: CustomFree,
+}
+
+struct LedgerSubscription {
+    pool_handle: PoolHandle,
+    filter: LedgerEventsFilter,
+    next_seq_no: i32,
+    event_cb: Box<dyn Fn(IndyHandle, String) + Send>,
+}
+
 pub struct LedgerCommandExecutor {
     pool_service: Rc<PoolService>,
     crypto_service: Rc<CryptoService>,
@@ -292,7 +562,14 @@ pub struct LedgerCommandExecutor {
     ledger_service: Rc<LedgerService>,
 
     send_callbacks: RefCell<HashMap<CommandHandle, Box<dyn Fn(IndyResult<String>)>>>,
+    dropped_requests: RefCell<HashSet<CommandHandle>>,
     pending_callbacks: RefCell<HashMap<CommandHandle, Box<dyn Fn(IndyResult<(String, String)>)>>>,
+    pending_rev_reg_callbacks: RefCell<HashMap<CommandHandle, BoxedCallbackStringStringU64Send>>,
+    idempotent_callbacks: RefCell<HashMap<CommandHandle, IdempotentSubmissionCallback>>,
+    taa_cache: Rc<RefCell<HashMap<PoolHandle, (TxnAuthorAgreementState, Instant)>>>,
+    txn_ranges: RefCell<HashMap<CommandHandle, TxnRange>>,
+    subscriptions: RefCell<HashMap<IndyHandle, LedgerSubscription>>,
+    middlewares: RefCell<Vec<(IndyHandle, LedgerMiddleware)>>,
 }
 
 impl LedgerCommandExecutor {
@@ -306,7 +583,14 @@ impl LedgerCommandExecutor {
             wallet_service,
             ledger_service,
             send_callbacks: RefCell::new(HashMap::new()),
+            dropped_requests: RefCell::new(HashSet::new()),
             pending_callbacks: RefCell::new(HashMap::new()),
+            pending_rev_reg_callbacks: RefCell::new(HashMap::new()),
+            idempotent_callbacks: RefCell::new(HashMap::new()),
+            taa_cache: Rc::new(RefCell::new(HashMap::new())),
+            txn_ranges: RefCell::new(HashMap::new()),
+            subscriptions: RefCell::new(HashMap::new()),
+            middlewares: RefCell::new(Vec::new()),
         }
     }
 
@@ -320,16 +604,74 @@ impl LedgerCommandExecutor {
                 debug!(target: "ledger_command_executor", "SubmitRequest command received");
                 self.submit_request(handle, &request_json, cb);
             }
+            LedgerCommand::SubmitRequestWithOptions(handle, request_json, options, cb) => {
+                debug!(target: "ledger_command_executor", "SubmitRequestWithOptions command received");
+                self.submit_request_with_options(handle, &request_json, &options, cb);
+            }
+            LedgerCommand::SubmitRequestIdempotent(handle, request_json, cb) => {
+                debug!(target: "ledger_command_executor", "SubmitRequestIdempotent command received");
+                self.submit_request_idempotent(handle, &request_json, cb);
+            }
+            LedgerCommand::SubmitRequestIdempotentContinue(handle, get_request_json, response, cb_id) => {
+                debug!(target: "ledger_command_executor", "SubmitRequestIdempotentContinue command received");
+                self._submit_request_idempotent_continue(handle, get_request_json, response, cb_id);
+            }
+            LedgerCommand::SubmitRequestIdempotentFetchAck(response, get_response, cb_id) => {
+                debug!(target: "ledger_command_executor", "SubmitRequestIdempotentFetchAck command received");
+                self._submit_request_idempotent_fetch_ack(response, get_response, cb_id);
+            }
             LedgerCommand::SubmitAck(handle, result) => {
                 debug!(target: "ledger_command_executor", "SubmitAck command received");
+                if self.dropped_requests.borrow_mut().remove(&handle) {
+                    return debug!("Reply of dropped request {:?} is ignored", handle);
+                }
+                #[cfg(feature = "tracing_spans")]
+                crate::utils::tracing::end_pending_span(handle);
+                self.pool_service.request_completed(handle);
                 match self.send_callbacks.borrow_mut().remove(&handle) {
-                    Some(cb) => cb(result.map_err(IndyError::from)),
+                    Some(cb) => cb(result.map_err(IndyError::from).and_then(|reply| self._apply_reply_middlewares(reply))),
                     None => {
                         error!("Can't process LedgerCommand::SubmitAck for handle {:?} with result {:?} - appropriate callback not found!",
                                handle, result);
                     }
                 }
             }
+            LedgerCommand::DropRequest(handle) => {
+                debug!(target: "ledger_command_executor", "DropRequest command received");
+                self._drop_request(handle);
+            }
+            LedgerCommand::SubmitRequests(handle, requests, cb) => {
+                debug!(target: "ledger_command_executor", "SubmitRequests command received");
+                self.submit_requests(handle, requests, cb);
+            }
+            LedgerCommand::GetTxns(handle, submitter_did, ledger_type, from, to, cb) => {
+                debug!(target: "ledger_command_executor", "GetTxns command received");
+                self.get_txns(handle, submitter_did, ledger_type, from, to, TxnRangeConsumer::Collect(Vec::new(), cb));
+            }
+            LedgerCommand::StreamTxns(handle, submitter_did, ledger_type, from, to, txns_cb, cb) => {
+                debug!(target: "ledger_command_executor", "StreamTxns command received");
+                self.get_txns(handle, submitter_did, ledger_type, from, to, TxnRangeConsumer::Stream(txns_cb, cb));
+            }
+            LedgerCommand::GetTxnsAck(range_handle, responses) => {
+                debug!(target: "ledger_command_executor", "GetTxnsAck command received");
+                self._get_txns_ack(range_handle, responses);
+            }
+            LedgerCommand::SubscribeLedgerEvents(handle, filter, event_cb, cb) => {
+                debug!(target: "ledger_command_executor", "SubscribeLedgerEvents command received");
+                self.subscribe_ledger_events(handle, filter, event_cb, cb);
+            }
+            LedgerCommand::UnsubscribeLedgerEvents(subscription_handle, cb) => {
+                debug!(target: "ledger_command_executor", "UnsubscribeLedgerEvents command received");
+                cb(self.unsubscribe_ledger_events(subscription_handle));
+            }
+            LedgerCommand::PollLedgerEvents(subscription_handle) => {
+                debug!(target: "ledger_command_executor", "PollLedgerEvents command received");
+                self._poll_ledger_events(subscription_handle);
+            }
+            LedgerCommand::PollLedgerEventsAck(subscription_handle, responses) => {
+                debug!(target: "ledger_command_executor", "PollLedgerEventsAck command received");
+                self._poll_ledger_events_ack(subscription_handle, responses);
+            }
             LedgerCommand::SubmitAction(handle, request_json, nodes, timeout, cb) => {
                 debug!(target: "ledger_command_executor", "SubmitRequest command received");
                 self.submit_action(handle, &request_json, nodes.as_ref().map(String::as_str), timeout, cb);
@@ -338,6 +680,26 @@ impl LedgerCommandExecutor {
                 debug!(target: "ledger_command_executor", "RegisterSPParser command received");
                 cb(self.register_sp_parser(&txn_type, parser, free));
             }
+            LedgerCommand::RegisterLedgerMiddleware(request_middleware, reply_middleware, free, cb) => {
+                debug!(target: "ledger_command_executor", "RegisterLedgerMiddleware command received");
+                cb(self.register_ledger_middleware(request_middleware, reply_middleware, free));
+            }
+            LedgerCommand::UnregisterLedgerMiddleware(middleware_handle, cb) => {
+                debug!(target: "ledger_command_executor", "UnregisterLedgerMiddleware command received");
+                cb(self.unregister_ledger_middleware(middleware_handle));
+            }
+            LedgerCommand::RegisterTxnBuilder(txn_type, builder, parser, free, cb) => {
+                debug!(target: "ledger_command_executor", "RegisterTxnBuilder command received");
+                cb(self.register_txn_builder(&txn_type, builder, parser, free));
+            }
+            LedgerCommand::BuildCustomRequest(submitter_did, txn_type, data_json, cb) => {
+                debug!(target: "ledger_command_executor", "BuildCustomRequest command received");
+                cb(self.build_custom_request(submitter_did.as_ref(), &txn_type, &data_json));
+            }
+            LedgerCommand::ParseCustomResponse(txn_type, response_json, cb) => {
+                debug!(target: "ledger_command_executor", "ParseCustomResponse command received");
+                cb(self.parse_custom_response(&txn_type, &response_json));
+            }
             LedgerCommand::SignRequest(wallet_handle, submitter_did, request_json, cb) => {
                 debug!(target: "ledger_command_executor", "SignRequest command received");
                 cb(self.sign_request(wallet_handle, &submitter_did, &request_json));
@@ -346,6 +708,18 @@ impl LedgerCommandExecutor {
                 debug!(target: "ledger_command_executor", "MultiSignRequest command received");
                 cb(self.multi_sign_request(wallet_handle, &submitter_did, &request_json));
             }
+            LedgerCommand::MergeMultiSignedRequests(requests, cb) => {
+                debug!(target: "ledger_command_executor", "MergeMultiSignedRequests command received");
+                cb(self.merge_multi_signed_requests(requests));
+            }
+            LedgerCommand::CheckRequestSignatures(handle, request_json, cb) => {
+                debug!(target: "ledger_command_executor", "CheckRequestSignatures command received");
+                self.check_request_signatures(handle, &request_json, cb);
+            }
+            LedgerCommand::ForceSignRequest(wallet_handle, submitter_did, request_json, cb) => {
+                debug!(target: "ledger_command_executor", "ForceSignRequest command received");
+                cb(self.force_sign_request(wallet_handle, &submitter_did, &request_json));
+            }
             LedgerCommand::BuildGetDdoRequest(submitter_did, target_did, cb) => {
                 debug!(target: "ledger_command_executor", "BuildGetDdoRequest command received");
                 cb(self.build_get_ddo_request(submitter_did.as_ref(), &target_did));
@@ -357,6 +731,10 @@ impl LedgerCommandExecutor {
                                           alias.as_ref().map(String::as_str),
                                           role.as_ref().map(String::as_str)));
             }
+            LedgerCommand::BuildNymRequestWithData(submitter_did, target_did, data, cb) => {
+                debug!(target: "ledger_command_executor", "BuildNymRequestWithData command received");
+                cb(self.build_nym_request_with_data(&submitter_did, &target_did, &data));
+            }
             LedgerCommand::BuildAttribRequest(submitter_did, target_did, hash, raw, enc, cb) => {
                 debug!(target: "ledger_command_executor", "BuildAttribRequest command received");
                 cb(self.build_attrib_request(&submitter_did, &target_did,
@@ -364,16 +742,26 @@ impl LedgerCommandExecutor {
                                              raw.as_ref(),
                                              enc.as_ref().map(String::as_str)));
             }
-            LedgerCommand::BuildGetAttribRequest(submitter_did, target_did, raw, hash, enc, cb) => {
+            LedgerCommand::BuildSetEndpointRequest(submitter_did, target_did, endpoint, cb) => {
+                debug!(target: "ledger_command_executor", "BuildSetEndpointRequest command received");
+                cb(self.build_set_endpoint_request(&submitter_did, &target_did, &endpoint));
+            }
+            LedgerCommand::BuildGetEndpointRequest(submitter_did, target_did, cb) => {
+                debug!(target: "ledger_command_executor", "BuildGetEndpointRequest command received");
+                cb(self.build_get_endpoint_request(submitter_did.as_ref(), &target_did));
+            }
+            LedgerCommand::BuildGetAttribRequest(submitter_did, target_did, raw, hash, enc, seq_no, timestamp, cb) => {
                 debug!(target: "ledger_command_executor", "BuildGetAttribRequest command received");
                 cb(self.build_get_attrib_request(submitter_did.as_ref(), &target_did,
                                                  raw.as_ref().map(String::as_str),
                                                  hash.as_ref().map(String::as_str),
-                                                 enc.as_ref().map(String::as_str)));
+                                                 enc.as_ref().map(String::as_str),
+                                                 seq_no,
+                                                 timestamp));
             }
-            LedgerCommand::BuildGetNymRequest(submitter_did, target_did, cb) => {
+            LedgerCommand::BuildGetNymRequest(submitter_did, target_did, seq_no, timestamp, cb) => {
                 debug!(target: "ledger_command_executor", "BuildGetNymRequest command received");
-                cb(self.build_get_nym_request(submitter_did.as_ref(), &target_did));
+                cb(self.build_get_nym_request(submitter_did.as_ref(), &target_did, seq_no, timestamp));
             }
             LedgerCommand::ParseGetNymResponse(get_nym_response, cb) => {
                 info!(target: "ledger_command_executor", "ParseGetNymResponse command received");
@@ -430,6 +818,10 @@ impl LedgerCommandExecutor {
                                                    justification.as_ref().map(String::as_str),
                                                    reinstall, force, package.as_ref().map(String::as_str)));
             }
+            LedgerCommand::BuildScheduledPoolUpgradeRequest(pool_handle, submitter_did, data, cb) => {
+                debug!(target: "ledger_command_executor", "BuildScheduledPoolUpgradeRequest command received");
+                cb(self.build_scheduled_pool_upgrade_request(pool_handle, &submitter_did, &data));
+            }
             LedgerCommand::BuildRevocRegDefRequest(submitter_did, data, cb) => {
                 debug!(target: "ledger_command_executor", "BuildRevocRegDefRequest command received");
                 cb(self.build_revoc_reg_def_request(&submitter_did, RevocationRegistryDefinitionV1::from(data)));
@@ -462,10 +854,50 @@ impl LedgerCommandExecutor {
                 debug!(target: "ledger_command_executor", "ParseGetRevocRegDeltaResponse command received");
                 cb(self.parse_revoc_reg_delta_response(&get_revoc_reg_delta_response));
             }
+            LedgerCommand::ParseGetAttribResponse(get_attrib_response, cb) => {
+                debug!(target: "ledger_command_executor", "ParseGetAttribResponse command received");
+                cb(self.parse_get_attrib_response(&get_attrib_response));
+            }
+            LedgerCommand::ParseGetTxnResponse(get_txn_response, cb) => {
+                debug!(target: "ledger_command_executor", "ParseGetTxnResponse command received");
+                cb(self.parse_get_txn_response(&get_txn_response));
+            }
+            LedgerCommand::ParseGetAuthRuleResponse(get_auth_rule_response, cb) => {
+                debug!(target: "ledger_command_executor", "ParseGetAuthRuleResponse command received");
+                cb(self.parse_get_auth_rule_response(&get_auth_rule_response));
+            }
+            LedgerCommand::ParseGetValidatorInfoResponse(get_validator_info_response, cb) => {
+                debug!(target: "ledger_command_executor", "ParseGetValidatorInfoResponse command received");
+                cb(self.parse_get_validator_info_response(&get_validator_info_response));
+            }
+            LedgerCommand::ParseGetValidatorInfoSummaryResponse(get_validator_info_response, cb) => {
+                debug!(target: "ledger_command_executor", "ParseGetValidatorInfoSummaryResponse command received");
+                cb(self.parse_get_validator_info_summary_response(&get_validator_info_response));
+            }
+            LedgerCommand::ParsePoolUpgradeStatusResponse(get_validator_info_response, cb) => {
+                debug!(target: "ledger_command_executor", "ParsePoolUpgradeStatusResponse command received");
+                cb(self.parse_pool_upgrade_status_response(&get_validator_info_response));
+            }
+            LedgerCommand::ParseGetTxnAuthorAgreementResponse(get_txn_author_agreement_response, cb) => {
+                debug!(target: "ledger_command_executor", "ParseGetTxnAuthorAgreementResponse command received");
+                cb(self.parse_get_txn_author_agreement_response(&get_txn_author_agreement_response));
+            }
+            LedgerCommand::ParseGetFrozenLedgersResponse(get_frozen_ledgers_response, cb) => {
+                debug!(target: "ledger_command_executor", "ParseGetFrozenLedgersResponse command received");
+                cb(self.parse_get_frozen_ledgers_response(&get_frozen_ledgers_response));
+            }
+            LedgerCommand::ParseGetAcceptanceMechanismsResponse(get_acceptance_mechanisms_response, cb) => {
+                debug!(target: "ledger_command_executor", "ParseGetAcceptanceMechanismsResponse command received");
+                cb(self.parse_get_acceptance_mechanisms_response(&get_acceptance_mechanisms_response));
+            }
             LedgerCommand::GetResponseMetadata(response, cb) => {
                 debug!(target: "ledger_command_executor", "GetResponseMetadata command received");
                 cb(self.get_response_metadata(&response));
             }
+            LedgerCommand::VerifyStateProof(request_json, response_json, bls_keys, cb) => {
+                debug!(target: "ledger_command_executor", "VerifyStateProof command received");
+                cb(self.verify_state_proof(&request_json, &response_json, &bls_keys));
+            }
             LedgerCommand::BuildAuthRuleRequest(submitter_did, txn_type, action, field, old_value, new_value, constraint, cb) => {
                 debug!(target: "ledger_command_executor", "BuildAuthRuleRequest command received");
                 cb(self.build_auth_rule_request(&submitter_did, &txn_type, &action, &field, old_value.as_ref().map(String::as_str), new_value.as_ref().map(String::as_str), constraint));
@@ -483,6 +915,26 @@ impl LedgerCommandExecutor {
                                                     old_value.as_ref().map(String::as_str),
                                                     new_value.as_ref().map(String::as_str)));
             }
+            LedgerCommand::BuildRoleConstraint(role, sig_count, need_to_be_owner, off_ledger_signature, metadata, cb) => {
+                debug!(target: "ledger_command_executor", "BuildRoleConstraint command received");
+                cb(self.build_role_constraint(&role, sig_count, need_to_be_owner, off_ledger_signature, metadata));
+            }
+            LedgerCommand::BuildCombinedConstraint(combination, constraints, cb) => {
+                debug!(target: "ledger_command_executor", "BuildCombinedConstraint command received");
+                cb(self.build_combined_constraint(&combination, constraints));
+            }
+            LedgerCommand::EvaluateAuthRules(auth_rules, action, signers, cb) => {
+                debug!(target: "ledger_command_executor", "EvaluateAuthRules command received");
+                cb(self.evaluate_auth_rules(&auth_rules, &action, &signers));
+            }
+            LedgerCommand::GetAuthRules(handle, submitter_did, options, cb) => {
+                debug!(target: "ledger_command_executor", "GetAuthRules command received");
+                self.get_auth_rules(handle, submitter_did.as_ref(), options, cb);
+            }
+            LedgerCommand::DiffAuthRules(current, proposed, cb) => {
+                debug!(target: "ledger_command_executor", "DiffAuthRules command received");
+                cb(self.diff_auth_rules(&current, &proposed));
+            }
             LedgerCommand::GetSchema(pool_handle, submitter_did, id, cb) => {
                 debug!(target: "ledger_command_executor", "GetSchema command received");
                 self.get_schema(pool_handle, submitter_did.as_ref(), &id, cb);
@@ -499,6 +951,26 @@ impl LedgerCommandExecutor {
                 debug!(target: "ledger_command_executor", "GetCredDefContinue command received");
                 self._get_cred_def_continue(id, pool_response, cb_id);
             }
+            LedgerCommand::GetRevocRegDef(pool_handle, submitter_did, id, cb) => {
+                debug!(target: "ledger_command_executor", "GetRevocRegDef command received");
+                self.get_revoc_reg_def(pool_handle, submitter_did.as_ref(), &id, cb);
+            }
+            LedgerCommand::GetRevocRegDefContinue(pool_response, cb_id) => {
+                debug!(target: "ledger_command_executor", "GetRevocRegDefContinue command received");
+                self._get_revoc_reg_def_continue(pool_response, cb_id);
+            }
+            LedgerCommand::GetRevocReg(pool_handle, submitter_did, id, timestamp, cb) => {
+                debug!(target: "ledger_command_executor", "GetRevocReg command received");
+                self.get_revoc_reg(pool_handle, submitter_did.as_ref(), &id, timestamp, cb);
+            }
+            LedgerCommand::GetRevocRegDelta(pool_handle, submitter_did, id, from, to, cb) => {
+                debug!(target: "ledger_command_executor", "GetRevocRegDelta command received");
+                self.get_revoc_reg_delta(pool_handle, submitter_did.as_ref(), &id, from, to, cb);
+            }
+            LedgerCommand::GetRevocRegContinue(is_delta, pool_response, cb_id) => {
+                debug!(target: "ledger_command_executor", "GetRevocRegContinue command received");
+                self._get_revoc_reg_continue(is_delta, pool_response, cb_id);
+            }
             LedgerCommand::BuildTxnAuthorAgreementRequest(submitter_did, text, version, ratification_ts, retirement_ts, cb) => {
                 debug!(target: "ledger_command_executor", "BuildTxnAuthorAgreementRequest command received");
                 cb(self.build_txn_author_agreement_request(&submitter_did, text.as_ref().map(String::as_str), &version, ratification_ts, retirement_ts));
@@ -530,11 +1002,19 @@ impl LedgerCommandExecutor {
                                                                           &acc_mech_type,
                                                                           time_of_acceptance));
             }
+            LedgerCommand::PrepareRequestWithTaa(pool_handle, wallet_handle, submitter_did, request_json, acc_mech_type, cb) => {
+                debug!(target: "ledger_command_executor", "PrepareRequestWithTaa command received");
+                self.prepare_request_with_taa(pool_handle, wallet_handle, &submitter_did, &request_json, acc_mech_type.as_deref(), cb);
+            }
             LedgerCommand::AppendRequestEndorser(request_json, endorser_did, cb) => {
                 debug!(target: "ledger_command_executor", "AppendRequestEndorser command received");
                 cb(self.append_request_endorser(&request_json,
                                                 &endorser_did));
             }
+            LedgerCommand::SetRequestProtocolVersion(request_json, protocol_version, cb) => {
+                debug!(target: "ledger_command_executor", "SetRequestProtocolVersion command received");
+                cb(self.set_request_protocol_version(&request_json, protocol_version));
+            }
             LedgerCommand::BuildLedgersFreezeRequest(submitter_did, ledgers_ids, cb) => {
                 debug!(target: "ledger_command_executor", "BuildLedgersFreezeRequest command received");
                 cb(self.build_ledgers_freeze_request(&submitter_did, ledgers_ids));
@@ -555,6 +1035,96 @@ impl LedgerCommandExecutor {
             .map_err(IndyError::from)
     }
 
+    fn register_txn_builder(&self, txn_type: &str, builder: CustomTransactionBuilder,
+                            parser: Option<CustomResponseParser>, free: CustomFree) -> IndyResult<()> {
+        debug!("register_txn_builder >>> txn_type: {:?}, builder: {:?}, parser: {:?}, free: {:?}",
+               txn_type, builder, parser, free);
+
+        LedgerService::register_txn_builder(txn_type, builder, parser, free)
+    }
+
+    fn register_ledger_middleware(&self,
+                                  request_middleware: Option<CustomRequestMiddleware>,
+                                  reply_middleware: Option<CustomReplyMiddleware>,
+                                  free: CustomFree) -> IndyResult<IndyHandle> {
+        debug!("register_ledger_middleware >>> request_middleware: {:?}, reply_middleware: {:?}, free: {:?}",
+               request_middleware, reply_middleware, free);
+
+        if request_middleware.is_none() && reply_middleware.is_none() {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "Neither request nor reply middleware is set"));
+        }
+
+        let middleware_handle = next_command_handle();
+
+        self.middlewares.borrow_mut().push((middleware_handle, LedgerMiddleware {
+            request: request_middleware,
+            reply: reply_middleware,
+            free,
+        }));
+
+        debug!("register_ledger_middleware <<< middleware_handle: {:?}", middleware_handle);
+
+        Ok(middleware_handle)
+    }
+
+    fn unregister_ledger_middleware(&self, middleware_handle: IndyHandle) -> IndyResult<()> {
+        debug!("unregister_ledger_middleware >>> middleware_handle: {:?}", middleware_handle);
+
+        let mut middlewares = self.middlewares.borrow_mut();
+
+        let position = middlewares.iter().position(|(handle, _)| *handle == middleware_handle)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, format!("Unknown ledger middleware handle: {}", middleware_handle)))?;
+
+        middlewares.remove(position);
+
+        debug!("unregister_ledger_middleware <<<");
+
+        Ok(())
+    }
+
+    // Request middlewares are applied in order of registration and reply middlewares in reverse order,
+    // so the first registered middleware sees the request as built and the reply as returned to caller.
+    fn _apply_request_middlewares(&self, request_json: &str) -> IndyResult<String> {
+        self.middlewares.borrow().iter()
+            .filter_map(|(_, middleware)| middleware.request.map(|request| (request, middleware.free)))
+            .try_fold(request_json.to_string(), |request_json, (request, free)|
+                self.ledger_service.apply_request_middleware(request, free, &request_json))
+    }
+
+    fn _apply_reply_middlewares(&self, reply_json: String) -> IndyResult<String> {
+        self.middlewares.borrow().iter().rev()
+            .filter_map(|(_, middleware)| middleware.reply.map(|reply| (reply, middleware.free)))
+            .try_fold(reply_json, |reply_json, (reply, free)|
+                self.ledger_service.apply_reply_middleware(reply, free, &reply_json))
+    }
+
+    fn build_custom_request(&self,
+                            submitter_did: Option<&DidValue>,
+                            txn_type: &str,
+                            data_json: &str) -> IndyResult<String> {
+        debug!("build_custom_request >>> submitter_did: {:?}, txn_type: {:?}, data_json: {:?}", submitter_did, txn_type, data_json);
+
+        self.validate_opt_did(submitter_did)?;
+
+        let res = self.ledger_service.build_custom_request(submitter_did, txn_type, data_json)?;
+
+        debug!("build_custom_request <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn parse_custom_response(&self,
+                             txn_type: &str,
+                             response_json: &str) -> IndyResult<String> {
+        debug!("parse_custom_response >>> txn_type: {:?}, response_json: {:?}", txn_type, response_json);
+
+        let res = self.ledger_service.parse_custom_response(txn_type, response_json)?;
+
+        debug!("parse_custom_response <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
     fn sign_and_submit_request(&self,
                                pool_handle: PoolHandle,
                                wallet_handle: WalletHandle,
@@ -564,7 +1134,7 @@ impl LedgerCommandExecutor {
         debug!("sign_and_submit_request >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, request_json: {:?}",
                pool_handle, wallet_handle, submitter_did, request_json);
 
-        match self._sign_request(wallet_handle, submitter_did, request_json, SignatureType::Single) {
+        match self._sign_request(wallet_handle, submitter_did, request_json, SignatureType::Single, false) {
             Ok(signed_request) => self.submit_request(pool_handle, signed_request.as_str(), cb),
             Err(err) => cb(Err(err))
         }
@@ -574,11 +1144,16 @@ impl LedgerCommandExecutor {
                      wallet_handle: WalletHandle,
                      submitter_did: &DidValue,
                      request_json: &str,
-                     signature_type: SignatureType) -> IndyResult<String> {
-        debug!("_sign_request >>> wallet_handle: {:?}, submitter_did: {:?}, request_json: {:?}", wallet_handle, submitter_did, request_json);
+                     signature_type: SignatureType,
+                     force: bool) -> IndyResult<String> {
+        debug!("_sign_request >>> wallet_handle: {:?}, submitter_did: {:?}, request_json: {:?}, force: {:?}", wallet_handle, submitter_did, request_json, force);
 
         let my_did: Did = self.wallet_service.get_indy_object(wallet_handle, &submitter_did.0, &RecordOptions::id_value())?;
 
+        if !force && self.wallet_service.record_exists::<DidDeactivation>(wallet_handle, &submitter_did.0)? {
+            return Err(err_msg(IndyErrorKind::DIDDeactivated, format!("DID {} is deactivated", submitter_did.0)));
+        }
+
         let my_key: Key = self.wallet_service.get_indy_object(wallet_handle, &my_did.verkey, &RecordOptions::id_value())?;
 
         let mut request: Value = serde_json::from_str(request_json)
@@ -613,26 +1188,465 @@ impl LedgerCommandExecutor {
         let res: String = serde_json::to_string(&request)
             .to_indy(IndyErrorKind::InvalidState, "Can't serialize message after signing")?;
 
+        audit::record(audit::REQUEST_SIGNED, wallet_handle, json!({"did": submitter_did, "req_id": request["reqId"]}));
+
         debug!("_sign_request <<< res: {:?}", res);
 
         Ok(res)
     }
 
-    fn submit_request(&self,
-                      handle: PoolHandle,
-                      request_json: &str,
-                      cb: Box<dyn Fn(IndyResult<String>) + Send>) {
-        debug!("submit_request >>> handle: {:?}, request_json: {:?}", handle, request_json);
+    fn submit_request(&self,
+                      handle: PoolHandle,
+                      request_json: &str,
+                      cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        debug!("submit_request >>> handle: {:?}, request_json: {:?}", handle, request_json);
+
+        let request_json = try_cb!(self._apply_request_middlewares(request_json), cb);
+        let request_json = request_json.as_str();
+
+        if let Err(err) = serde_json::from_str::<Request<serde_json::Value>>(request_json) {
+            return cb(Err(IndyError::from_msg(IndyErrorKind::InvalidStructure, format!("Request is invalid json: {:?}", err))));
+        }
+
+        let x: IndyResult<CommandHandle> = self.pool_service.send_tx(handle, request_json);
+        match x {
+            Ok(cmd_id) => { self._add_send_callback(cmd_id, cb); }
+            Err(err) => { cb(Err(err)); }
+        };
+    }
+
+    // Request of the call that fails before the reply comes is dropped, so the reply is ignored
+    fn _add_send_callback(&self, cmd_id: CommandHandle, cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        self.send_callbacks.borrow_mut().insert(cmd_id, cb);
+
+        timeout::on_cancel(timeout::current_call(), Box::new(move || {
+            let _ = CommandExecutor::instance().send(Command::Ledger(LedgerCommand::DropRequest(cmd_id)));
+        }));
+    }
+
+    fn _drop_request(&self, cmd_id: CommandHandle) {
+        if self.send_callbacks.borrow_mut().remove(&cmd_id).is_none() {
+            return;
+        }
+
+        #[cfg(feature = "tracing_spans")]
+        crate::utils::tracing::end_pending_span(cmd_id);
+        self.pool_service.request_completed(cmd_id);
+        self.dropped_requests.borrow_mut().insert(cmd_id);
+    }
+
+    fn submit_request_with_options(&self,
+                                   handle: PoolHandle,
+                                   request_json: &str,
+                                   options: &SubmitRequestOptions,
+                                   cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        debug!("submit_request_with_options >>> handle: {:?}, request_json: {:?}, options: {:?}", handle, request_json, options);
+
+        let request_json = try_cb!(self._apply_request_middlewares(request_json), cb);
+        let request_json = request_json.as_str();
+
+        if let Err(err) = serde_json::from_str::<Request<serde_json::Value>>(request_json) {
+            return cb(Err(IndyError::from_msg(IndyErrorKind::InvalidStructure, format!("Request is invalid json: {:?}", err))));
+        }
+
+        match self.pool_service.send_tx_with_options(handle, request_json, options) {
+            Ok(cmd_id) => { self._add_send_callback(cmd_id, cb); }
+            Err(err) => { cb(Err(err)); }
+        };
+    }
+
+    fn submit_request_idempotent(&self,
+                                 handle: PoolHandle,
+                                 request_json: &str,
+                                 cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        debug!("submit_request_idempotent >>> handle: {:?}, request_json: {:?}", handle, request_json);
+
+        // Read request is built in advance to reject unsupported writes before submission
+        let get_request_json = try_cb!(self.ledger_service.build_get_request_for_write(request_json), cb);
+
+        let cb_id = next_command_handle();
+        self.idempotent_callbacks.borrow_mut().insert(cb_id, cb);
+
+        self.submit_request(handle, request_json, Box::new(move |response| {
+            CommandExecutor::instance().send(
+                Command::Ledger(
+                    LedgerCommand::SubmitRequestIdempotentContinue(
+                        handle,
+                        get_request_json.clone(),
+                        response,
+                        cb_id
+                    )
+                )
+            ).unwrap();
+        }));
+    }
+
+    fn _submit_request_idempotent_continue(&self, handle: PoolHandle, get_request_json: String, response: IndyResult<String>, cb_id: CommandHandle) {
+        let response = match response {
+            Ok(response) if self.ledger_service.is_duplicate_txn_rejection(&response) => response,
+            response => {
+                let cb = self.idempotent_callbacks.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
+                let res = response.and_then(|response| self.ledger_service.build_idempotent_submit_result(&response, false));
+
+                debug!("submit_request_idempotent <<< res: {:?}", res);
+
+                return cb(res);
+            }
+        };
+
+        // Write has been rejected as the entity already exists, so existing transaction is fetched instead
+        debug!("submit_request_idempotent: write has been rejected as duplicate: {:?}", response);
+
+        self.submit_request(handle, &get_request_json, Box::new(move |get_response| {
+            CommandExecutor::instance().send(
+                Command::Ledger(
+                    LedgerCommand::SubmitRequestIdempotentFetchAck(
+                        response.clone(),
+                        get_response,
+                        cb_id
+                    )
+                )
+            ).unwrap();
+        }));
+    }
+
+    fn _submit_request_idempotent_fetch_ack(&self, response: String, get_response: IndyResult<String>, cb_id: CommandHandle) {
+        let cb = self.idempotent_callbacks.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
+
+        // Rejection is returned as is if the entity can't be found on the ledger
+        let res = get_response.and_then(|get_response| {
+            if self.ledger_service.is_txn_found(&get_response) {
+                self.ledger_service.build_idempotent_submit_result(&get_response, true)
+            } else {
+                self.ledger_service.build_idempotent_submit_result(&response, false)
+            }
+        });
+
+        debug!("submit_request_idempotent <<< res: {:?}", res);
+
+        cb(res)
+    }
+
+    fn submit_requests(&self,
+                       handle: PoolHandle,
+                       requests: Vec<Value>,
+                       cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        debug!("submit_requests >>> handle: {:?}, requests: {:?}", handle, requests);
+
+        let requests = requests.iter().map(Value::to_string).collect();
+
+        self._submit_requests(handle, requests, Box::new(move |results| {
+            let res = results.and_then(|results| {
+                let results: Vec<BatchRequestResult> = results.into_iter().map(BatchRequestResult::from).collect();
+
+                serde_json::to_string(&results)
+                    .to_indy(IndyErrorKind::InvalidState, "Can't serialize batch results")
+            });
+
+            debug!("submit_requests <<< res: {:?}", res);
+
+            cb(res)
+        }));
+    }
+
+    fn _submit_requests(&self,
+                        handle: PoolHandle,
+                        requests: Vec<String>,
+                        cb: BatchSubmissionCallback) {
+        try_cb!(self.pool_service.check_handle(handle), cb);
+
+        let batch = Rc::new(RefCell::new(BatchSubmission {
+            results: requests.iter().map(|_| None).collect(),
+            pending: requests.len(),
+            cb,
+        }));
+
+        if requests.is_empty() {
+            return LedgerCommandExecutor::_complete_batch_request(&batch, None);
+        }
+
+        // All requests are sent at once and processed by the pool concurrently.
+        // Failure of a single request doesn't affect other requests of the batch.
+        for (index, request_json) in requests.into_iter().enumerate() {
+            let sent = self._apply_request_middlewares(&request_json)
+                .and_then(|request_json| {
+                    serde_json::from_str::<Request<Value>>(&request_json)
+                        .map_err(|err| IndyError::from_msg(IndyErrorKind::InvalidStructure, format!("Request is invalid json: {:?}", err)))?;
+                    self.pool_service.send_tx(handle, &request_json)
+                });
+
+            match sent {
+                Ok(cmd_id) => {
+                    let batch = batch.clone();
+                    self.send_callbacks.borrow_mut().insert(cmd_id, Box::new(move |result| {
+                        LedgerCommandExecutor::_complete_batch_request(&batch, Some((index, result)))
+                    }));
+                }
+                Err(err) => LedgerCommandExecutor::_complete_batch_request(&batch, Some((index, Err(err))))
+            }
+        }
+    }
+
+    fn _complete_batch_request(batch: &Rc<RefCell<BatchSubmission>>, result: Option<(usize, IndyResult<String>)>) {
+        let mut batch = batch.borrow_mut();
+
+        if let Some((index, result)) = result {
+            batch.results[index] = Some(result);
+            batch.pending -= 1;
+        }
+
+        if batch.pending != 0 {
+            return;
+        }
+
+        let results = batch.results.drain(..).flatten().collect();
+
+        (batch.cb)(Ok(results))
+    }
+
+    fn get_txns(&self,
+                handle: PoolHandle,
+                submitter_did: Option<DidValue>,
+                ledger_type: Option<String>,
+                from: i32,
+                to: i32,
+                consumer: TxnRangeConsumer) {
+        debug!("get_txns >>> handle: {:?}, submitter_did: {:?}, ledger_type: {:?}, from: {:?}, to: {:?}",
+               handle, submitter_did, ledger_type, from, to);
+
+        let range = TxnRange { pool_handle: handle, submitter_did, ledger_type, next: from, to, consumer, call_id: timeout::current_call() };
+
+        let checked = self.validate_opt_did(range.submitter_did.as_ref())
+            .and_then(|_| if from < 1 || to < from {
+                Err(err_msg(IndyErrorKind::InvalidStructure, format!("Invalid range of transactions: {} - {}", from, to)))
+            } else {
+                Ok(())
+            })
+            .and_then(|_| self.pool_service.check_handle(handle));
+
+        if let Err(err) = checked {
+            return LedgerCommandExecutor::_complete_txn_range(range, Err(err));
+        }
+
+        let range_handle = next_command_handle();
+        self.txn_ranges.borrow_mut().insert(range_handle, range);
+        self._get_txns_batch(range_handle);
+    }
+
+    // Transactions are requested by batches so that the pool isn't flooded by requests for large ranges.
+    fn _get_txns_batch(&self, range_handle: CommandHandle) {
+        let mut range = match self.txn_ranges.borrow_mut().remove(&range_handle) {
+            Some(range) => range,
+            None => return error!("Can't get transactions for range handle {:?} - range not found!", range_handle)
+        };
+
+        let batch_end = ::std::cmp::min(range.to, range.next.saturating_add(GET_TXNS_BATCH_SIZE - 1));
+
+        let requests = (range.next..=batch_end)
+            .map(|seq_no| self.ledger_service.build_get_txn_request(range.submitter_did.as_ref(), range.ledger_type.as_deref(), seq_no))
+            .collect::<IndyResult<Vec<String>>>();
+
+        let requests = match requests {
+            Ok(requests) => requests,
+            Err(err) => return LedgerCommandExecutor::_complete_txn_range(range, Err(err))
+        };
+
+        range.next = batch_end.saturating_add(1);
+        let pool_handle = range.pool_handle;
+        self.txn_ranges.borrow_mut().insert(range_handle, range);
+
+        self._submit_requests(pool_handle, requests, Box::new(move |responses| {
+            CommandExecutor::instance()
+                .send(Command::Ledger(LedgerCommand::GetTxnsAck(range_handle, responses)))
+                .unwrap();
+        }));
+    }
+
+    fn _get_txns_ack(&self, range_handle: CommandHandle, responses: IndyResult<Vec<IndyResult<String>>>) {
+        let mut range = match self.txn_ranges.borrow_mut().remove(&range_handle) {
+            Some(range) => range,
+            None => return error!("Can't process LedgerCommand::GetTxnsAck for range handle {:?} - range not found!", range_handle)
+        };
+
+        // Next batches aren't requested for the call that has failed
+        if timeout::is_cancelled(range.call_id) {
+            return LedgerCommandExecutor::_complete_txn_range(range, Err(err_msg(IndyErrorKind::Timeout, "Getting of transactions has been cancelled")));
+        }
+
+        let txns = responses.and_then(|responses| {
+            responses.into_iter()
+                .map(|response| match response.and_then(|response| self.ledger_service.parse_get_txn_response(&response)) {
+                    Ok(txn) => serde_json::from_str::<Value>(&txn)
+                        .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize GET_TXN data")
+                        .map(Some),
+                    Err(ref err) if err.kind() == IndyErrorKind::LedgerItemNotFound => Ok(None),
+                    Err(err) => Err(err)
+                })
+                .collect::<IndyResult<Vec<Option<Value>>>>()
+        });
+
+        let txns: Vec<Value> = match txns {
+            Ok(txns) => txns.into_iter().flatten().collect(),
+            Err(err) => return LedgerCommandExecutor::_complete_txn_range(range, Err(err))
+        };
+
+        // Batch without transactions means that the end of the ledger is reached
+        let completed = txns.is_empty() || range.next > range.to;
+
+        match range.consumer {
+            TxnRangeConsumer::Collect(ref mut collected, _) => collected.extend(txns),
+            TxnRangeConsumer::Stream(ref txns_cb, _) if !txns.is_empty() => {
+                txns_cb(json!(txns).to_string());
+                timeout::keep_alive(range.call_id);
+            }
+            TxnRangeConsumer::Stream(_, _) => {}
+        }
+
+        if completed {
+            return LedgerCommandExecutor::_complete_txn_range(range, Ok(()));
+        }
+
+        self.txn_ranges.borrow_mut().insert(range_handle, range);
+        self._get_txns_batch(range_handle);
+    }
+
+    fn _complete_txn_range(range: TxnRange, result: IndyResult<()>) {
+        debug!("get_txns <<< result: {:?}", result);
+
+        match range.consumer {
+            TxnRangeConsumer::Collect(collected, cb) => cb(result.and_then(|_| serde_json::to_string(&collected)
+                .to_indy(IndyErrorKind::InvalidState, "Cannot serialize transactions"))),
+            TxnRangeConsumer::Stream(_, cb) => cb(result)
+        }
+    }
+
+    fn subscribe_ledger_events(&self,
+                               handle: PoolHandle,
+                               filter: LedgerEventsFilter,
+                               event_cb: Box<dyn Fn(IndyHandle, String) + Send>,
+                               cb: Box<dyn Fn(IndyResult<IndyHandle>) + Send>) {
+        debug!("subscribe_ledger_events >>> handle: {:?}, filter: {:?}", handle, filter);
+
+        try_cb!(self.pool_service.check_handle(handle), cb);
+        try_cb!(self.ledger_service.build_get_txn_request(None, filter.ledger_type.as_deref(), 1), cb);
+
+        let subscription_handle = next_command_handle();
+
+        self.subscriptions.borrow_mut().insert(subscription_handle, LedgerSubscription {
+            pool_handle: handle,
+            next_seq_no: filter.from_seq_no.unwrap_or(1),
+            filter,
+            event_cb,
+        });
+
+        debug!("subscribe_ledger_events <<< subscription_handle: {:?}", subscription_handle);
+
+        cb(Ok(subscription_handle));
+
+        self._poll_ledger_events(subscription_handle);
+    }
+
+    fn unsubscribe_ledger_events(&self, subscription_handle: IndyHandle) -> IndyResult<()> {
+        debug!("unsubscribe_ledger_events >>> subscription_handle: {:?}", subscription_handle);
+
+        self.subscriptions.borrow_mut().remove(&subscription_handle)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, format!("Unknown ledger events subscription handle: {}", subscription_handle)))?;
+
+        debug!("unsubscribe_ledger_events <<<");
+
+        Ok(())
+    }
+
+    // Ledger is polled by batches of GET_TXN requests starting from the first transaction not processed yet.
+    fn _poll_ledger_events(&self, subscription_handle: IndyHandle) {
+        let (pool_handle, requests) = match self.subscriptions.borrow().get(&subscription_handle) {
+            Some(subscription) => {
+                let requests = (subscription.next_seq_no..subscription.next_seq_no.saturating_add(GET_TXNS_BATCH_SIZE))
+                    .map(|seq_no| self.ledger_service.build_get_txn_request(None, subscription.filter.ledger_type.as_deref(), seq_no))
+                    .collect::<IndyResult<Vec<String>>>();
+                (subscription.pool_handle, requests)
+            }
+            // Subscription is already cancelled
+            None => return
+        };
+
+        let requests = match requests {
+            Ok(requests) => requests,
+            Err(err) => return error!("Can't build GET_TXN requests for subscription {:?}: {:?}", subscription_handle, err)
+        };
+
+        self._submit_requests(pool_handle, requests, Box::new(move |responses| {
+            CommandExecutor::instance()
+                .send(Command::Ledger(LedgerCommand::PollLedgerEventsAck(subscription_handle, responses)))
+                .unwrap();
+        }));
+    }
+
+    fn _poll_ledger_events_ack(&self, subscription_handle: IndyHandle, responses: IndyResult<Vec<IndyResult<String>>>) {
+        let mut subscriptions = self.subscriptions.borrow_mut();
+
+        let subscription = match subscriptions.get_mut(&subscription_handle) {
+            Some(subscription) => subscription,
+            None => return
+        };
+
+        let responses = match responses {
+            Ok(responses) => responses,
+            Err(ref err) if err.kind() == IndyErrorKind::InvalidPoolHandle => {
+                warn!("Ledger events subscription {:?} is cancelled as the pool is closed", subscription_handle);
+                subscriptions.remove(&subscription_handle);
+                return;
+            }
+            Err(err) => {
+                warn!("Can't poll ledger events for subscription {:?}: {:?}", subscription_handle, err);
+                Vec::new()
+            }
+        };
+
+        let mut processed = 0;
+
+        // Transactions are processed in order until the first one that isn't written yet or failed to get
+        for response in responses {
+            let txn = match response.and_then(|response| self.ledger_service.parse_get_txn_reply(&response)) {
+                Ok(Some(txn)) => txn,
+                Ok(None) => break,
+                Err(err) => {
+                    warn!("Can't get transaction {:?} for subscription {:?}: {:?}", subscription.next_seq_no, subscription_handle, err);
+                    break;
+                }
+            };
+
+            if subscription.filter.matches(&txn.data) {
+                match serde_json::to_string(&txn) {
+                    Ok(event) => (subscription.event_cb)(subscription_handle, event),
+                    Err(err) => error!("Can't serialize ledger event for subscription {:?}: {:?}", subscription_handle, err)
+                }
+            }
+
+            subscription.next_seq_no += 1;
+            processed += 1;
+        }
+
+        let poll_interval = subscription.filter.poll_interval.unwrap_or(DEFAULT_LEDGER_EVENTS_POLL_INTERVAL);
 
-        if let Err(err) = serde_json::from_str::<Request<serde_json::Value>>(&request_json) {
-            return cb(Err(IndyError::from_msg(IndyErrorKind::InvalidStructure, format!("Request is invalid json: {:?}", err))));
+        drop(subscriptions);
+
+        // Ledger is polled again at once while the subscription catches up with the ledger
+        if processed == GET_TXNS_BATCH_SIZE {
+            self._poll_ledger_events(subscription_handle);
+        } else {
+            LedgerCommandExecutor::_schedule_ledger_events_poll(subscription_handle, poll_interval);
         }
+    }
 
-        let x: IndyResult<CommandHandle> = self.pool_service.send_tx(handle, request_json);
-        match x {
-            Ok(cmd_id) => { self.send_callbacks.borrow_mut().insert(cmd_id, cb); }
-            Err(err) => { cb(Err(err)); }
-        };
+    fn _schedule_ledger_events_poll(subscription_handle: IndyHandle, poll_interval: u64) {
+        thread::spawn(move || {
+            thread::sleep(Duration::from_secs(poll_interval));
+
+            CommandExecutor::instance()
+                .send(Command::Ledger(LedgerCommand::PollLedgerEvents(subscription_handle)))
+                .unwrap_or_else(|err| warn!("Can't schedule ledger events poll for subscription {:?}: {:?}", subscription_handle, err));
+        });
     }
 
     fn submit_action(&self,
@@ -643,13 +1657,16 @@ impl LedgerCommandExecutor {
                      cb: Box<dyn Fn(IndyResult<String>) + Send>) {
         debug!("submit_action >>> handle: {:?}, request_json: {:?}, nodes: {:?}, timeout: {:?}", handle, request_json, nodes, timeout);
 
+        let request_json = try_cb!(self._apply_request_middlewares(request_json), cb);
+        let request_json = request_json.as_str();
+
         if let Err(err) = self.ledger_service.validate_action(request_json) {
             return cb(Err(err));
         }
 
         let x: IndyResult<CommandHandle> = self.pool_service.send_action(handle, request_json, nodes, timeout);
         match x {
-            Ok(cmd_id) => { self.send_callbacks.borrow_mut().insert(cmd_id, cb); }
+            Ok(cmd_id) => { self._add_send_callback(cmd_id, cb); }
             Err(err) => { cb(Err(err)); }
         };
     }
@@ -660,7 +1677,7 @@ impl LedgerCommandExecutor {
                     request_json: &str) -> IndyResult<String> {
         debug!("sign_request >>> wallet_handle: {:?}, submitter_did: {:?}, request_json: {:?}", wallet_handle, submitter_did, request_json);
 
-        let res = self._sign_request(wallet_handle, submitter_did, request_json, SignatureType::Single)?;
+        let res = self._sign_request(wallet_handle, submitter_did, request_json, SignatureType::Single, false)?;
 
         debug!("sign_request <<< res: {:?}", res);
 
@@ -673,12 +1690,114 @@ impl LedgerCommandExecutor {
                           request_json: &str) -> IndyResult<String> {
         debug!("multi_sign_request >>> wallet_handle: {:?}, submitter_did: {:?}, request_json: {:?}", wallet_handle, submitter_did, request_json);
 
-        let res = self._sign_request(wallet_handle, submitter_did, request_json, SignatureType::Multi)?;
+        let res = self._sign_request(wallet_handle, submitter_did, request_json, SignatureType::Multi, false)?;
 
         debug!("multi_sign_request <<< res: {:?}", res);
 
         Ok(res)
     }
+
+    fn merge_multi_signed_requests(&self, requests: Vec<Value>) -> IndyResult<String> {
+        debug!("merge_multi_signed_requests >>> requests: {:?}", requests);
+
+        let res = self.ledger_service.merge_multi_signed_requests(requests)?;
+
+        debug!("merge_multi_signed_requests <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn check_request_signatures(&self,
+                                handle: PoolHandle,
+                                request_json: &str,
+                                cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        debug!("check_request_signatures >>> handle: {:?}, request_json: {:?}", handle, request_json);
+
+        let request: Value = try_cb!(serde_json::from_str(request_json)
+            .to_indy(IndyErrorKind::InvalidStructure, "Request is invalid json"), cb);
+
+        let signatures = try_cb!(self.ledger_service.get_request_signatures(&request), cb);
+        let serialized_request = try_cb!(serialize_signature(request.clone()), cb);
+
+        // Auth rules and NYMs of all signers are requested at once
+        let mut requests = vec![try_cb!(self.ledger_service.build_get_auth_rule_request(None, None, None, None, None, None), cb)];
+
+        for (did, _) in signatures.iter() {
+            requests.push(try_cb!(self.ledger_service.build_get_nym_request(None, &DidValue(did.to_string()), None, None), cb));
+        }
+
+        let ledger_service = self.ledger_service.clone();
+        let crypto_service = self.crypto_service.clone();
+
+        self._submit_requests(handle, requests, Box::new(move |responses| {
+            let res = responses.and_then(|responses| LedgerCommandExecutor::_build_request_signatures_report(
+                &ledger_service, &crypto_service, &request, &serialized_request, &signatures, responses));
+
+            debug!("check_request_signatures <<< res: {:?}", res);
+
+            cb(res)
+        }));
+    }
+
+    fn _build_request_signatures_report(ledger_service: &LedgerService,
+                                        crypto_service: &CryptoService,
+                                        request: &Value,
+                                        serialized_request: &str,
+                                        signatures: &[(String, String)],
+                                        responses: Vec<IndyResult<String>>) -> IndyResult<String> {
+        let mut responses = responses.into_iter();
+
+        let auth_rules = responses.next()
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "GET_AUTH_RULE response not found"))??;
+
+        let auth_rules = ledger_service.parse_get_auth_rule_response(&auth_rules)?;
+
+        let signers = signatures.iter()
+            .zip(responses)
+            .map(|((did, signature), nym_response)| {
+                let nym_data = match nym_response.and_then(|nym_response| ledger_service.parse_get_nym_response(&nym_response)) {
+                    Ok(nym_data) => Some(serde_json::from_str::<NymData>(&nym_data)
+                        .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize NYM data")?),
+                    Err(ref err) if err.kind() == IndyErrorKind::LedgerItemNotFound => None,
+                    Err(err) => return Err(err)
+                };
+
+                let verified = match nym_data {
+                    Some(ref nym_data) => {
+                        let verkey = build_full_verkey(did, nym_data.verkey.as_deref())?;
+                        let signature = signature.from_base58()?;
+                        crypto_service.verify(&verkey, serialized_request.as_bytes(), &signature).unwrap_or(false)
+                    }
+                    None => false
+                };
+
+                Ok(RequestSigner {
+                    did: did.to_string(),
+                    on_ledger: nym_data.is_some(),
+                    role: nym_data.and_then(|nym_data| nym_data.role),
+                    verified,
+                })
+            })
+            .collect::<IndyResult<Vec<RequestSigner>>>()?;
+
+        let report = ledger_service.build_request_signatures_report(request, &auth_rules, signers)?;
+
+        serde_json::to_string(&report)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize request signatures report")
+    }
+
+    fn force_sign_request(&self,
+                          wallet_handle: WalletHandle,
+                          submitter_did: &DidValue,
+                          request_json: &str) -> IndyResult<String> {
+        debug!("force_sign_request >>> wallet_handle: {:?}, submitter_did: {:?}, request_json: {:?}", wallet_handle, submitter_did, request_json);
+
+        let res = self._sign_request(wallet_handle, submitter_did, request_json, SignatureType::Single, true)?;
+
+        debug!("force_sign_request <<< res: {:?}", res);
+
+        Ok(res)
+    }
     fn build_get_ddo_request(&self,
                              submitter_did: Option<&DidValue>,
                              target_did: &DidValue) -> IndyResult<String> {
@@ -717,6 +1836,26 @@ impl LedgerCommandExecutor {
         Ok(res)
     }
 
+    fn build_nym_request_with_data(&self,
+                                   submitter_did: &DidValue,
+                                   target_did: &DidValue,
+                                   data: &NymRequestData) -> IndyResult<String> {
+        debug!("build_nym_request_with_data >>> submitter_did: {:?}, target_did: {:?}, data: {:?}",
+               submitter_did, target_did, data);
+
+        self.crypto_service.validate_did(submitter_did)?;
+        self.crypto_service.validate_did(target_did)?;
+        if let Some(ref vk) = data.verkey {
+            self.crypto_service.validate_key(vk)?;
+        }
+
+        let res = self.ledger_service.build_nym_request_with_data(submitter_did, target_did, data)?;
+
+        debug!("build_nym_request_with_data <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
     fn build_attrib_request(&self,
                             submitter_did: &DidValue,
                             target_did: &DidValue,
@@ -740,14 +1879,52 @@ impl LedgerCommandExecutor {
         Ok(res)
     }
 
+    fn build_set_endpoint_request(&self,
+                                  submitter_did: &DidValue,
+                                  target_did: &DidValue,
+                                  endpoint: &Endpoint) -> IndyResult<String> {
+        debug!("build_set_endpoint_request >>> submitter_did: {:?}, target_did: {:?}, endpoint: {:?}",
+               submitter_did, target_did, endpoint);
+
+        self.crypto_service.validate_did(submitter_did)?;
+        self.crypto_service.validate_did(target_did)?;
+        if let Some(ref transport_key) = endpoint.verkey {
+            self.crypto_service.validate_key(transport_key)?;
+        }
+
+        let res = self.ledger_service.build_set_endpoint_request(submitter_did, target_did, endpoint)?;
+
+        debug!("build_set_endpoint_request <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn build_get_endpoint_request(&self,
+                                  submitter_did: Option<&DidValue>,
+                                  target_did: &DidValue) -> IndyResult<String> {
+        debug!("build_get_endpoint_request >>> submitter_did: {:?}, target_did: {:?}", submitter_did, target_did);
+
+        self.validate_opt_did(submitter_did)?;
+        self.crypto_service.validate_did(target_did)?;
+
+        let res = self.ledger_service.build_get_endpoint_request(submitter_did, target_did)?;
+
+        debug!("build_get_endpoint_request <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    #[allow(clippy::too_many_arguments)]
     fn build_get_attrib_request(&self,
                                 submitter_did: Option<&DidValue>,
                                 target_did: &DidValue,
                                 raw: Option<&str>,
                                 hash: Option<&str>,
-                                enc: Option<&str>) -> IndyResult<String> {
-        debug!("build_get_attrib_request >>> submitter_did: {:?}, target_did: {:?}, raw: {:?}, hash: {:?}, enc: {:?}",
-               submitter_did, target_did, raw, hash, enc);
+                                enc: Option<&str>,
+                                seq_no: Option<u64>,
+                                timestamp: Option<u64>) -> IndyResult<String> {
+        debug!("build_get_attrib_request >>> submitter_did: {:?}, target_did: {:?}, raw: {:?}, hash: {:?}, enc: {:?}, seq_no: {:?}, timestamp: {:?}",
+               submitter_did, target_did, raw, hash, enc, seq_no, timestamp);
 
         self.validate_opt_did(submitter_did)?;
         self.crypto_service.validate_did(target_did)?;
@@ -756,7 +1933,9 @@ impl LedgerCommandExecutor {
                                                                target_did,
                                                                raw,
                                                                hash,
-                                                               enc)?;
+                                                               enc,
+                                                               seq_no,
+                                                               timestamp)?;
 
         debug!("build_get_attrib_request <<< res: {:?}", res);
 
@@ -765,14 +1944,19 @@ impl LedgerCommandExecutor {
 
     fn build_get_nym_request(&self,
                              submitter_did: Option<&DidValue>,
-                             target_did: &DidValue) -> IndyResult<String> {
-        debug!("build_get_nym_request >>> submitter_did: {:?}, target_did: {:?}", submitter_did, target_did);
+                             target_did: &DidValue,
+                             seq_no: Option<u64>,
+                             timestamp: Option<u64>) -> IndyResult<String> {
+        debug!("build_get_nym_request >>> submitter_did: {:?}, target_did: {:?}, seq_no: {:?}, timestamp: {:?}",
+               submitter_did, target_did, seq_no, timestamp);
 
         self.validate_opt_did(submitter_did)?;
         self.crypto_service.validate_did(target_did)?;
 
         let res = self.ledger_service.build_get_nym_request(submitter_did,
-                                                            target_did)?;
+                                                            target_did,
+                                                            seq_no,
+                                                            timestamp)?;
 
         debug!("build_get_attrib_request <<< res: {:?}", res);
 
@@ -969,6 +2153,24 @@ impl LedgerCommandExecutor {
         Ok(res)
     }
 
+    fn build_scheduled_pool_upgrade_request(&self,
+                                            pool_handle: PoolHandle,
+                                            submitter_did: &DidValue,
+                                            data: &PoolUpgradeScheduleData) -> IndyResult<String> {
+        debug!("build_scheduled_pool_upgrade_request >>> pool_handle: {:?}, submitter_did: {:?}, data: {:?}",
+               pool_handle, submitter_did, data);
+
+        self.crypto_service.validate_did(submitter_did)?;
+
+        let nodes = self.pool_service.get_pool_nodes(pool_handle)?;
+
+        let res = self.ledger_service.build_scheduled_pool_upgrade(submitter_did, data, &nodes)?;
+
+        debug!("build_scheduled_pool_upgrade_request <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
     fn build_revoc_reg_def_request(&self,
                                    submitter_did: &DidValue,
                                    data: RevocationRegistryDefinitionV1) -> IndyResult<String> {
@@ -1078,6 +2280,105 @@ impl LedgerCommandExecutor {
         Ok(res)
     }
 
+    fn parse_get_attrib_response(&self,
+                             get_attrib_response: &str) -> IndyResult<String> {
+        debug!("parse_get_attrib_response >>> get_attrib_response: {:?}", get_attrib_response);
+
+        let res = self.ledger_service.parse_get_attrib_response(get_attrib_response)?;
+
+        debug!("parse_get_attrib_response <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn parse_get_txn_response(&self,
+                          get_txn_response: &str) -> IndyResult<String> {
+        debug!("parse_get_txn_response >>> get_txn_response: {:?}", get_txn_response);
+
+        let res = self.ledger_service.parse_get_txn_response(get_txn_response)?;
+
+        debug!("parse_get_txn_response <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn parse_get_auth_rule_response(&self,
+                                get_auth_rule_response: &str) -> IndyResult<String> {
+        debug!("parse_get_auth_rule_response >>> get_auth_rule_response: {:?}", get_auth_rule_response);
+
+        let res = self.ledger_service.parse_get_auth_rule_typed_response(get_auth_rule_response)?;
+
+        debug!("parse_get_auth_rule_response <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn parse_get_validator_info_response(&self,
+                                     get_validator_info_response: &str) -> IndyResult<String> {
+        debug!("parse_get_validator_info_response >>> get_validator_info_response: {:?}", get_validator_info_response);
+
+        let res = self.ledger_service.parse_get_validator_info_response(get_validator_info_response)?;
+
+        debug!("parse_get_validator_info_response <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn parse_get_validator_info_summary_response(&self,
+                                                 get_validator_info_response: &str) -> IndyResult<String> {
+        debug!("parse_get_validator_info_summary_response >>> get_validator_info_response: {:?}", get_validator_info_response);
+
+        let res = self.ledger_service.parse_get_validator_info_summary_response(get_validator_info_response)?;
+
+        debug!("parse_get_validator_info_summary_response <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn parse_pool_upgrade_status_response(&self,
+                                          get_validator_info_response: &str) -> IndyResult<String> {
+        debug!("parse_pool_upgrade_status_response >>> get_validator_info_response: {:?}", get_validator_info_response);
+
+        let res = self.ledger_service.parse_pool_upgrade_status(get_validator_info_response)?;
+
+        debug!("parse_pool_upgrade_status_response <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn parse_get_txn_author_agreement_response(&self,
+                                           get_txn_author_agreement_response: &str) -> IndyResult<String> {
+        debug!("parse_get_txn_author_agreement_response >>> get_txn_author_agreement_response: {:?}", get_txn_author_agreement_response);
+
+        let res = self.ledger_service.parse_get_txn_author_agreement_response(get_txn_author_agreement_response)?;
+
+        debug!("parse_get_txn_author_agreement_response <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn parse_get_frozen_ledgers_response(&self,
+                                         get_frozen_ledgers_response: &str) -> IndyResult<String> {
+        debug!("parse_get_frozen_ledgers_response >>> get_frozen_ledgers_response: {:?}", get_frozen_ledgers_response);
+
+        let res = self.ledger_service.parse_get_frozen_ledgers_response(get_frozen_ledgers_response)?;
+
+        debug!("parse_get_frozen_ledgers_response <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn parse_get_acceptance_mechanisms_response(&self,
+                                            get_acceptance_mechanisms_response: &str) -> IndyResult<String> {
+        debug!("parse_get_acceptance_mechanisms_response >>> get_acceptance_mechanisms_response: {:?}", get_acceptance_mechanisms_response);
+
+        let res = self.ledger_service.parse_get_acceptance_mechanisms_response(get_acceptance_mechanisms_response)?;
+
+        debug!("parse_get_acceptance_mechanisms_response <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
     fn get_response_metadata(&self,
                              response: &str) -> IndyResult<String> {
         debug!("get_response_metadata >>> response: {:?}", response);
@@ -1092,6 +2393,19 @@ impl LedgerCommandExecutor {
         Ok(res)
     }
 
+    fn verify_state_proof(&self,
+                          request_json: &str,
+                          response_json: &str,
+                          bls_keys: &HashMap<String, String>) -> IndyResult<bool> {
+        debug!("verify_state_proof >>> request_json: {:?}, response_json: {:?}, bls_keys: {:?}", request_json, response_json, bls_keys);
+
+        let res = PoolService::verify_state_proof(request_json, response_json, bls_keys)?;
+
+        debug!("verify_state_proof <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
     fn build_auth_rule_request(&self,
                                submitter_did: &DidValue,
                                txn_type: &str,
@@ -1145,6 +2459,91 @@ impl LedgerCommandExecutor {
         Ok(res)
     }
 
+    fn build_role_constraint(&self,
+                             role: &str,
+                             sig_count: u32,
+                             need_to_be_owner: bool,
+                             off_ledger_signature: bool,
+                             metadata: Option<Value>) -> IndyResult<String> {
+        debug!("build_role_constraint >>> role: {:?}, sig_count: {:?}, need_to_be_owner: {:?}, off_ledger_signature: {:?}, metadata: {:?}",
+               role, sig_count, need_to_be_owner, off_ledger_signature, metadata);
+
+        let res = self.ledger_service.build_role_constraint(role, sig_count, need_to_be_owner, off_ledger_signature, metadata)?;
+
+        debug!("build_role_constraint <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn build_combined_constraint(&self,
+                                 combination: &str,
+                                 constraints: Vec<Constraint>) -> IndyResult<String> {
+        debug!("build_combined_constraint >>> combination: {:?}, constraints: {:?}", combination, constraints);
+
+        let res = self.ledger_service.build_combined_constraint(combination, constraints)?;
+
+        debug!("build_combined_constraint <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn evaluate_auth_rules(&self,
+                           auth_rules: &[AuthRule],
+                           action: &AuthRuleAction,
+                           signers: &[RequestSigner]) -> IndyResult<String> {
+        debug!("evaluate_auth_rules >>> auth_rules: {:?}, action: {:?}, signers: {:?}", auth_rules, action, signers);
+
+        let evaluation = self.ledger_service.evaluate_auth_rules(auth_rules, action, signers)?;
+
+        let res = serde_json::to_string(&evaluation)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize auth rules evaluation")?;
+
+        debug!("evaluate_auth_rules <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn get_auth_rules(&self,
+                      handle: PoolHandle,
+                      submitter_did: Option<&DidValue>,
+                      options: AuthRulesPageOptions,
+                      cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        debug!("get_auth_rules >>> handle: {:?}, submitter_did: {:?}, options: {:?}", handle, submitter_did, options);
+
+        let request = try_cb!(self.ledger_service.build_get_auth_rule_request(submitter_did, None, None, None, None, None), cb);
+
+        let ledger_service = self.ledger_service.clone();
+
+        self._submit_requests(handle, vec![request], Box::new(move |responses| {
+            let res = responses
+                .and_then(|responses| responses.into_iter().next()
+                    .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "GET_AUTH_RULE response not found"))?)
+                .and_then(|response| ledger_service.parse_get_auth_rule_response(&response))
+                .and_then(|auth_rules| ledger_service.build_auth_rules_page(auth_rules, &options))
+                .and_then(|page| serde_json::to_string(&page)
+                    .to_indy(IndyErrorKind::InvalidState, "Can't serialize auth rules page"));
+
+            debug!("get_auth_rules <<< res: {:?}", res);
+
+            cb(res)
+        }));
+    }
+
+    fn diff_auth_rules(&self,
+                       current: &[AuthRule],
+                       proposed: &[AuthRule]) -> IndyResult<String> {
+        debug!("diff_auth_rules >>> current: {:?}, proposed: {:?}", current, proposed);
+
+        let diff = self.ledger_service.diff_auth_rules(current, proposed)?;
+
+        let res = serde_json::to_string(&diff)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize auth rules diff")?;
+
+        debug!("diff_auth_rules <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
     fn build_txn_author_agreement_request(&self,
                                           submitter_did: &DidValue,
                                           text: Option<&str>,
@@ -1243,6 +2642,94 @@ impl LedgerCommandExecutor {
         Ok(res)
     }
 
+    fn prepare_request_with_taa(&self,
+                                pool_handle: PoolHandle,
+                                wallet_handle: WalletHandle,
+                                submitter_did: &DidValue,
+                                request_json: &str,
+                                acc_mech_type: Option<&str>,
+                                cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        debug!("prepare_request_with_taa >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, request_json: {:?}, acc_mech_type: {:?}",
+               pool_handle, wallet_handle, submitter_did, request_json, acc_mech_type);
+
+        try_cb!(self.crypto_service.validate_did(submitter_did), cb);
+
+        let request: Value = try_cb!(serde_json::from_str(request_json)
+            .map_err(|err| IndyError::from_msg(IndyErrorKind::InvalidStructure, format!("Cannot deserialize request: {:?}", err))), cb);
+
+        try_cb!(self.wallet_service.get_indy_object::<Did>(wallet_handle, &submitter_did.0, &RecordOptions::id_value()), cb);
+        try_cb!(self.pool_service.check_handle(pool_handle), cb);
+
+        let cached_state = self.taa_cache.borrow().get(&pool_handle)
+            .filter(|(_, fetched)| fetched.elapsed() < TAA_CACHE_TTL)
+            .map(|(state, _)| state.clone());
+
+        if let Some(state) = cached_state {
+            let res = LedgerCommandExecutor::_append_taa_acceptance(&self.ledger_service, &self.wallet_service, wallet_handle, submitter_did, request, acc_mech_type, &state);
+
+            debug!("prepare_request_with_taa <<< res: {:?}", res);
+
+            return cb(res);
+        }
+
+        // Latest agreement and acceptance mechanisms are requested at once
+        let requests = vec![
+            try_cb!(self.ledger_service.build_get_txn_author_agreement_request(None, None), cb),
+            try_cb!(self.ledger_service.build_get_acceptance_mechanisms_request(None, None, None), cb),
+        ];
+
+        let ledger_service = self.ledger_service.clone();
+        let wallet_service = self.wallet_service.clone();
+        let taa_cache = self.taa_cache.clone();
+        let submitter_did = submitter_did.clone();
+        let acc_mech_type = acc_mech_type.map(String::from);
+
+        self._submit_requests(pool_handle, requests, Box::new(move |responses| {
+            let res = responses
+                .and_then(|responses| {
+                    let mut responses = responses.into_iter();
+
+                    let taa_response = responses.next()
+                        .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "GET_TXN_AUTHR_AGRMT response not found"))??;
+                    let aml_response = responses.next()
+                        .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "GET_TXN_AUTHR_AGRMT_AML response not found"))??;
+
+                    ledger_service.parse_txn_author_agreement_state(&taa_response, &aml_response)
+                })
+                .and_then(|state| {
+                    taa_cache.borrow_mut().insert(pool_handle, (state.clone(), Instant::now()));
+
+                    LedgerCommandExecutor::_append_taa_acceptance(&ledger_service, &wallet_service, wallet_handle, &submitter_did,
+                                                                  request.clone(), acc_mech_type.as_deref(), &state)
+                });
+
+            debug!("prepare_request_with_taa <<< res: {:?}", res);
+
+            cb(res)
+        }));
+    }
+
+    fn _append_taa_acceptance(ledger_service: &LedgerService,
+                              wallet_service: &WalletService,
+                              wallet_handle: WalletHandle,
+                              submitter_did: &DidValue,
+                              mut request: Value,
+                              acc_mech_type: Option<&str>,
+                              state: &TxnAuthorAgreementState) -> IndyResult<String> {
+        let accepted = wallet_service.get_indy_opt_object::<TxnAuthrAgrmtAcceptanceData>(wallet_handle, &submitter_did.0, &RecordOptions::id_value())?;
+
+        let now = time::get_time().sec as u64;
+
+        // Request is left unchanged if the ledger doesn't require the agreement acceptance
+        if let Some(acceptance) = ledger_service.prepare_taa_acceptance(state, accepted.as_ref(), acc_mech_type, now)? {
+            wallet_service.upsert_indy_object(wallet_handle, &submitter_did.0, &acceptance)?;
+            request["taaAcceptance"] = json!(acceptance);
+        }
+
+        serde_json::to_string(&request)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize request after adding author agreement acceptance data")
+    }
+
     fn append_request_endorser(&self,
                                request_json: &str,
                                endorser_did: &DidValue) -> IndyResult<String> {
@@ -1265,6 +2752,18 @@ impl LedgerCommandExecutor {
         Ok(res)
     }
 
+    fn set_request_protocol_version(&self,
+                                    request_json: &str,
+                                    protocol_version: usize) -> IndyResult<String> {
+        debug!("set_request_protocol_version >>> request_json: {:?}, protocol_version: {:?}", request_json, protocol_version);
+
+        let res = self.ledger_service.set_request_protocol_version(request_json, protocol_version)?;
+
+        debug!("set_request_protocol_version <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
     fn validate_opt_did(&self, did: Option<&DidValue>) -> IndyResult<()> {
         match did {
             Some(did) => Ok(self.crypto_service.validate_did(did)?),
@@ -1324,6 +2823,67 @@ impl LedgerCommandExecutor {
         cb(self.ledger_service.parse_get_cred_def_response(&pool_response, id.get_method().as_ref().map(String::as_str)))
     }
 
+    fn get_revoc_reg_def(&self, pool_handle: i32, submitter_did: Option<&DidValue>, id: &RevocationRegistryId, cb: BoxedCallbackStringStringSend) {
+        let request_json = try_cb!(self.build_get_revoc_reg_def_request(submitter_did, id), cb);
+
+        let cb_id = next_command_handle();
+        self.pending_callbacks.borrow_mut().insert(cb_id, cb);
+
+        self.submit_request(pool_handle, &request_json, Box::new(move |response| {
+            CommandExecutor::instance().send(
+                Command::Ledger(
+                    LedgerCommand::GetRevocRegDefContinue(
+                        response,
+                        cb_id
+                    )
+                )
+            ).unwrap();
+        }));
+    }
+
+    fn _get_revoc_reg_def_continue(&self, pool_response: IndyResult<String>, cb_id: CommandHandle) {
+        let cb = self.pending_callbacks.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
+        let pool_response = try_cb!(pool_response, cb);
+        cb(self.ledger_service.parse_get_revoc_reg_def_response(&pool_response))
+    }
+
+    fn get_revoc_reg(&self, pool_handle: i32, submitter_did: Option<&DidValue>, id: &RevocationRegistryId, timestamp: i64, cb: BoxedCallbackStringStringU64Send) {
+        let request_json = try_cb!(self.build_get_revoc_reg_request(submitter_did, id, timestamp), cb);
+        self._submit_get_revoc_reg(pool_handle, &request_json, false, cb);
+    }
+
+    fn get_revoc_reg_delta(&self, pool_handle: i32, submitter_did: Option<&DidValue>, id: &RevocationRegistryId, from: Option<i64>, to: i64, cb: BoxedCallbackStringStringU64Send) {
+        let request_json = try_cb!(self.build_get_revoc_reg_delta_request(submitter_did, id, from, to), cb);
+        self._submit_get_revoc_reg(pool_handle, &request_json, true, cb);
+    }
+
+    fn _submit_get_revoc_reg(&self, pool_handle: i32, request_json: &str, is_delta: bool, cb: BoxedCallbackStringStringU64Send) {
+        let cb_id = next_command_handle();
+        self.pending_rev_reg_callbacks.borrow_mut().insert(cb_id, cb);
+
+        self.submit_request(pool_handle, request_json, Box::new(move |response| {
+            CommandExecutor::instance().send(
+                Command::Ledger(
+                    LedgerCommand::GetRevocRegContinue(
+                        is_delta,
+                        response,
+                        cb_id
+                    )
+                )
+            ).unwrap();
+        }));
+    }
+
+    fn _get_revoc_reg_continue(&self, is_delta: bool, pool_response: IndyResult<String>, cb_id: CommandHandle) {
+        let cb = self.pending_rev_reg_callbacks.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
+        let pool_response = try_cb!(pool_response, cb);
+        if is_delta {
+            cb(self.ledger_service.parse_get_revoc_reg_delta_response(&pool_response))
+        } else {
+            cb(self.ledger_service.parse_get_revoc_reg_response(&pool_response))
+        }
+    }
+
     fn build_ledgers_freeze_request(&self, submitter_did: &DidValue, ledgers_ids: Vec<u64>) -> IndyResult<String>{
         debug!("build_ledgers_freeze_request >>> submitter_did: {:?}, ledgers_ids: {:?}", submitter_did, ledgers_ids);
 
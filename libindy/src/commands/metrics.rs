@@ -1,9 +1,16 @@
 use crate::services::metrics::models::MetricsValue;
 use crate::services::metrics::MetricsService;
+#[cfg(feature = "ledger")]
+use crate::services::pool::metrics as pool_metrics;
+#[cfg(feature = "ledger")]
+use crate::services::pool::metrics::PoolEventCounters;
+use crate::utils::handles;
 use indy_api_types::errors::prelude::*;
 use indy_wallet::WalletService;
+use indy_wallet::metrics::OperationCounters;
 use serde_json::{Map, Value};
-use std::collections::HashMap;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
 use std::rc::Rc;
 
 const THREADPOOL_ACTIVE_COUNT: &str = "active";
@@ -15,13 +22,23 @@ const OPENED_WALLET_IDS_COUNT: &str = "opened_ids";
 const PENDING_FOR_IMPORT_WALLETS_COUNT: &str = "pending_for_import";
 const PENDING_FOR_OPEN_WALLETS_COUNT: &str = "pending_for_open";
 
+type OperationCounterGetter = fn(&OperationCounters) -> u128;
+type WalletOperationCounters = BTreeMap<(String, &'static str), OperationCounters>;
+
 pub enum MetricsCommand {
     CollectMetrics(Box<dyn Fn(IndyResult<String>) + Send>),
+    CollectMetricsPrometheus(Box<dyn Fn(IndyResult<String>) + Send>),
+    CollectMetricsDelta(Box<dyn Fn(IndyResult<String>) + Send>),
+    ResetMetrics(Box<dyn Fn(IndyResult<()>) + Send>),
+    ListHandles(Box<dyn Fn(IndyResult<String>) + Send>),
 }
 
 pub struct MetricsCommandExecutor {
     wallet_service: Rc<WalletService>,
     metrics_service: Rc<MetricsService>,
+    wallet_operations_snapshot: RefCell<Option<WalletOperationCounters>>,
+    #[cfg(feature = "ledger")]
+    pool_events_snapshot: RefCell<Option<PoolEventCounters>>,
 }
 
 impl MetricsCommandExecutor {
@@ -32,6 +49,9 @@ impl MetricsCommandExecutor {
         MetricsCommandExecutor {
             wallet_service,
             metrics_service,
+            wallet_operations_snapshot: RefCell::new(None),
+            #[cfg(feature = "ledger")]
+            pool_events_snapshot: RefCell::new(None),
         }
     }
 
@@ -41,6 +61,23 @@ impl MetricsCommandExecutor {
                 debug!(target: "metrics_command_executor", "CollectMetrics command received");
                 cb(self.collect());
             }
+            MetricsCommand::CollectMetricsPrometheus(cb) => {
+                debug!(target: "metrics_command_executor", "CollectMetricsPrometheus command received");
+                cb(self.collect_prometheus());
+            }
+            MetricsCommand::CollectMetricsDelta(cb) => {
+                debug!(target: "metrics_command_executor", "CollectMetricsDelta command received");
+                cb(self.collect_delta());
+            }
+            MetricsCommand::ResetMetrics(cb) => {
+                debug!(target: "metrics_command_executor", "ResetMetrics command received");
+                self.reset();
+                cb(Ok(()));
+            }
+            MetricsCommand::ListHandles(cb) => {
+                debug!(target: "metrics_command_executor", "ListHandles command received");
+                cb(self.list_handles());
+            }
         };
     }
 
@@ -49,6 +86,9 @@ impl MetricsCommandExecutor {
         let mut metrics_map = serde_json::Map::new();
         self.append_threapool_metrics(&mut metrics_map)?;
         self.append_wallet_metrics(&mut metrics_map)?;
+        self.append_wallet_operation_metrics(&mut metrics_map, &self.wallet_service.get_operation_counters())?;
+        #[cfg(feature = "ledger")]
+        self.append_pool_event_metrics(&mut metrics_map, &pool_metrics::get_counters())?;
         self.metrics_service
             .append_command_metrics(&mut metrics_map)?;
         let res = serde_json::to_string(&metrics_map)
@@ -59,6 +99,138 @@ impl MetricsCommandExecutor {
         Ok(res)
     }
 
+    fn collect_delta(&self) -> IndyResult<String> {
+        trace!("_collect_delta >>>");
+        let mut metrics_map = serde_json::Map::new();
+        self.append_threapool_metrics(&mut metrics_map)?;
+        self.append_wallet_metrics(&mut metrics_map)?;
+
+        let wallet_operations = self.wallet_service.get_operation_counters();
+        let wallet_operations_delta = match *self.wallet_operations_snapshot.borrow() {
+            Some(ref snapshot) => wallet_operations.iter()
+                .map(|(key, counters)| (key.clone(), snapshot.get(key).map(|previous| counters.since(previous)).unwrap_or(*counters)))
+                .collect(),
+            None => wallet_operations.clone()
+        };
+        *self.wallet_operations_snapshot.borrow_mut() = Some(wallet_operations);
+
+        self.append_wallet_operation_metrics(&mut metrics_map, &wallet_operations_delta)?;
+
+        #[cfg(feature = "ledger")]
+        self.append_pool_event_metrics_delta(&mut metrics_map)?;
+        self.metrics_service
+            .append_command_metrics_delta(&mut metrics_map)?;
+        let res = serde_json::to_string(&metrics_map)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize a metrics map")?;
+
+        trace!("_collect_delta <<< res: {:?}", res);
+        Ok(res)
+    }
+
+    fn reset(&self) {
+        trace!("_reset >>>");
+        self.metrics_service.reset();
+        self.wallet_service.reset_operation_counters();
+        *self.wallet_operations_snapshot.borrow_mut() = None;
+        #[cfg(feature = "ledger")]
+        {
+            pool_metrics::reset_counters();
+            *self.pool_events_snapshot.borrow_mut() = None;
+        }
+        trace!("_reset <<<");
+    }
+
+    fn list_handles(&self) -> IndyResult<String> {
+        trace!("_list_handles >>>");
+        let res = serde_json::to_string(&handles::list())
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize a handles list")?;
+        trace!("_list_handles <<< res: {:?}", res);
+        Ok(res)
+    }
+
+    fn collect_prometheus(&self) -> IndyResult<String> {
+        trace!("_collect_prometheus >>>");
+        let mut output = String::new();
+
+        {
+            let tp_instance = crate::commands::THREADPOOL.lock().unwrap();
+            MetricsCommandExecutor::append_prometheus_gauge(
+                &mut output,
+                "threadpool_threads_count",
+                "Count of threads in libindy thread pool.",
+                &[
+                    (THREADPOOL_ACTIVE_COUNT, tp_instance.active_count()),
+                    (THREADPOOL_QUEUED_COUNT, tp_instance.queued_count()),
+                    (THREADPOOL_MAX_COUNT, tp_instance.max_count()),
+                    (THREADPOOL_PANIC_COUNT, tp_instance.panic_count()),
+                ],
+            );
+        }
+
+        MetricsCommandExecutor::append_prometheus_gauge(
+            &mut output,
+            "wallet_count",
+            "Count of wallets known by libindy.",
+            &[
+                (OPENED_WALLETS_COUNT, self.wallet_service.get_wallets_count()),
+                (OPENED_WALLET_IDS_COUNT, self.wallet_service.get_wallet_ids_count()),
+                (PENDING_FOR_IMPORT_WALLETS_COUNT, self.wallet_service.get_pending_for_import_count()),
+                (PENDING_FOR_OPEN_WALLETS_COUNT, self.wallet_service.get_pending_for_open_count()),
+            ],
+        );
+
+        self.append_wallet_operation_metrics_prometheus(&mut output);
+
+        #[cfg(feature = "ledger")]
+        self.append_pool_event_metrics_prometheus(&mut output);
+
+        self.metrics_service
+            .append_command_metrics_prometheus(&mut output);
+
+        trace!("_collect_prometheus <<< res: {:?}", output);
+        debug!("collecting prometheus metrics from command thread");
+        Ok(output)
+    }
+
+    fn append_wallet_operation_metrics_prometheus(&self, output: &mut String) {
+        let counters = self.wallet_service.get_operation_counters();
+
+        let metrics: [(&str, &str, OperationCounterGetter); 3] = [
+            ("wallet_operations_total", "Count of wallet record operations.", |counters| counters.count),
+            ("wallet_operations_failed_total", "Count of failed wallet record operations.", |counters| counters.failed_count),
+            ("wallet_operations_duration_ms_sum", "Duration of wallet record operations in milliseconds.", |counters| counters.duration_ms_sum),
+        ];
+
+        for (name, help, value) in metrics.iter() {
+            output.push_str(&format!("# HELP indy_{} {}\n", name, help));
+            output.push_str(&format!("# TYPE indy_{} counter\n", name));
+
+            for ((storage_type, operation), counters) in counters.iter() {
+                output.push_str(&format!("indy_{}{{storage_type=\"{}\",operation=\"{}\"}} {}\n",
+                                         name, storage_type, operation, value(counters)));
+            }
+        }
+    }
+
+    #[cfg(feature = "ledger")]
+    fn append_pool_event_metrics_prometheus(&self, output: &mut String) {
+        output.push_str("# HELP indy_pool_events_total Count of pool catch-up and request handler events.\n");
+        output.push_str("# TYPE indy_pool_events_total counter\n");
+
+        for ((pool_name, event), count) in pool_metrics::get_counters().iter() {
+            output.push_str(&format!("indy_pool_events_total{{pool=\"{}\",event=\"{}\"}} {}\n", pool_name, event, count));
+        }
+    }
+
+    fn append_prometheus_gauge(output: &mut String, name: &str, help: &str, values: &[(&str, usize)]) {
+        output.push_str(&format!("# HELP indy_{} {}\n", name, help));
+        output.push_str(&format!("# TYPE indy_{} gauge\n", name));
+
+        for (label, value) in values {
+            output.push_str(&format!("indy_{}{{label=\"{}\"}} {}\n", name, label, value));
+        }
+    }
+
     fn append_threapool_metrics(&self, metrics_map: &mut Map<String, Value>) -> IndyResult<()> {
         #[derive(Serialize, Deserialize)]
         struct MetricsTags {
@@ -133,10 +305,86 @@ impl MetricsCommandExecutor {
         Ok(())
     }
 
+    fn append_wallet_operation_metrics(&self, metrics_map: &mut Map<String, Value>, counters: &WalletOperationCounters) -> IndyResult<()> {
+        let mut operations_count = Vec::new();
+        let mut operations_failed_count = Vec::new();
+        let mut operations_duration_ms = Vec::new();
+
+        for ((storage_type, operation), counters) in counters.iter() {
+            let tags = MetricsCommandExecutor::get_wallet_operation_tags(storage_type, operation);
+
+            operations_count.push(self.get_tagged_metric_json(counters.count as usize, tags.clone())?);
+            operations_failed_count.push(self.get_tagged_metric_json(counters.failed_count as usize, tags.clone())?);
+            operations_duration_ms.push(self.get_tagged_metric_json(counters.duration_ms_sum as usize, tags)?);
+        }
+
+        metrics_map.insert(
+            String::from("wallet_operations_count"),
+            serde_json::to_value(operations_count)
+                .to_indy(IndyErrorKind::IOError, "Unable to convert json")?,
+        );
+        metrics_map.insert(
+            String::from("wallet_operations_failed_count"),
+            serde_json::to_value(operations_failed_count)
+                .to_indy(IndyErrorKind::IOError, "Unable to convert json")?,
+        );
+        metrics_map.insert(
+            String::from("wallet_operations_duration_ms"),
+            serde_json::to_value(operations_duration_ms)
+                .to_indy(IndyErrorKind::IOError, "Unable to convert json")?,
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "ledger")]
+    fn append_pool_event_metrics_delta(&self, metrics_map: &mut Map<String, Value>) -> IndyResult<()> {
+        let pool_events = pool_metrics::get_counters();
+        let pool_events_delta = match *self.pool_events_snapshot.borrow() {
+            Some(ref snapshot) => pool_metrics::counters_since(&pool_events, snapshot),
+            None => pool_events.clone()
+        };
+        *self.pool_events_snapshot.borrow_mut() = Some(pool_events);
+
+        self.append_pool_event_metrics(metrics_map, &pool_events_delta)
+    }
+
+    #[cfg(feature = "ledger")]
+    fn append_pool_event_metrics(&self, metrics_map: &mut Map<String, Value>, counters: &PoolEventCounters) -> IndyResult<()> {
+        let mut pool_events_count = Vec::new();
+
+        for ((pool_name, event), count) in counters.iter() {
+            let mut tags = HashMap::<String, String>::new();
+            tags.insert(String::from("pool"), pool_name.to_string());
+            tags.insert(String::from("event"), event.to_string());
+
+            pool_events_count.push(self.get_tagged_metric_json(*count as usize, tags)?);
+        }
+
+        metrics_map.insert(
+            String::from("pool_events_count"),
+            serde_json::to_value(pool_events_count)
+                .to_indy(IndyErrorKind::IOError, "Unable to convert json")?,
+        );
+
+        Ok(())
+    }
+
+    fn get_wallet_operation_tags(storage_type: &str, operation: &str) -> HashMap<String, String> {
+        let mut tags = HashMap::<String, String>::new();
+        tags.insert(String::from("storage_type"), storage_type.to_string());
+        tags.insert(String::from("operation"), operation.to_string());
+        tags
+    }
+
     fn get_metric_json(&self, label: &str, value: usize) -> IndyResult<Value> {
         let mut tag = HashMap::<String, String>::new();
         tag.insert(String::from("label"), String::from(label));
-        let res = serde_json::to_value(MetricsValue::new(value, tag))
+        self.get_tagged_metric_json(value, tag)
+    }
+
+    fn get_tagged_metric_json(&self, value: usize, tags: HashMap<String, String>) -> IndyResult<Value> {
+        let res = serde_json::to_value(MetricsValue::new(value, tags))
             .to_indy(IndyErrorKind::IOError, "Unable to convert json")?;
 
         Ok(res)
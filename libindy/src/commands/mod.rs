@@ -1,72 +1,127 @@
 extern crate ursa;
 extern crate threadpool;
 
+use std::any::Any;
+#[cfg(feature = "anoncreds")]
+use std::collections::{HashMap, VecDeque};
 use std::env;
+use std::panic::{self, AssertUnwindSafe};
 use std::rc::Rc;
 use std::sync::{Mutex, MutexGuard};
 use std::sync::mpsc::{channel, Sender};
 use std::thread;
 
-use crate::commands::anoncreds::{AnoncredsCommand, AnoncredsCommandExecutor};
+#[cfg(feature = "anoncreds")]
+use crate::commands::anoncreds::{AnoncredsCommand, AnoncredsCommandExecutor, set_tails_cache_size};
+#[cfg(feature = "anoncreds")]
 use crate::commands::blob_storage::{BlobStorageCommand, BlobStorageCommandExecutor};
 use crate::commands::crypto::{CryptoCommand, CryptoCommandExecutor};
 use crate::commands::did::{DidCommand, DidCommandExecutor};
+#[cfg(feature = "ledger")]
 use crate::commands::ledger::{LedgerCommand, LedgerCommandExecutor};
 use crate::commands::non_secrets::{NonSecretsCommand, NonSecretsCommandExecutor};
 use crate::commands::pairwise::{PairwiseCommand, PairwiseCommandExecutor};
+#[cfg(feature = "payments")]
 use crate::commands::payments::{PaymentsCommand, PaymentsCommandExecutor};
+#[cfg(feature = "ledger")]
 use crate::commands::pool::{PoolCommand, PoolCommandExecutor};
 use crate::commands::wallet::{WalletCommand, WalletCommandExecutor};
+#[cfg(feature = "cache")]
 use crate::commands::cache::{CacheCommand, CacheCommandExecutor};
 use crate::commands::metrics::{MetricsCommand, MetricsCommandExecutor};
 use crate::domain::IndyConfig;
 use indy_api_types::errors::prelude::*;
+use indy_api_types::errors::take_last_error_code;
+use indy_api_types::{ErrorCode, SearchHandle};
+#[cfg(feature = "anoncreds")]
+use indy_api_types::WalletHandle;
+use indy_utils::ctypes;
+use libc::c_char;
+#[cfg(feature = "anoncreds")]
 use crate::services::anoncreds::AnoncredsService;
-use crate::services::blob_storage::BlobStorageService;
+#[cfg(feature = "anoncreds")]
+use crate::services::anoncreds::test_mode::set_seed as set_anoncreds_test_seed;
+#[cfg(feature = "anoncreds")]
+use crate::services::blob_storage::{BlobStorageService, set_buffer_size as set_blob_buffer_size};
 use crate::services::crypto::CryptoService;
-use crate::services::ledger::LedgerService;
+#[cfg(feature = "ledger")]
+use crate::services::ledger::{LedgerService, set_strict_attrib_validation};
+#[cfg(feature = "payments")]
 use crate::services::payments::PaymentsService;
-use crate::services::pool::{PoolService, set_freshness_threshold};
-use crate::services::metrics::MetricsService;
+#[cfg(feature = "ledger")]
+use crate::services::pool::{PoolService, set_freshness_threshold, set_io_threads, set_socket_hwm};
+use crate::services::metrics::{MetricsService, set_buckets_ms};
 use crate::services::metrics::command_metrics::CommandMetric;
 use indy_wallet::WalletService;
+use crate::utils::audit;
+use crate::utils::command_params;
+#[cfg(feature = "tracing_spans")]
+use crate::utils::tracing;
 
 use self::threadpool::ThreadPool;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
+#[cfg(feature = "anoncreds")]
 pub mod anoncreds;
+#[cfg(feature = "anoncreds")]
 pub mod blob_storage;
 pub mod crypto;
+#[cfg(feature = "ledger")]
 pub mod ledger;
+#[cfg(feature = "ledger")]
 pub mod pool;
 pub mod did;
 pub mod wallet;
 pub mod pairwise;
 pub mod non_secrets;
+#[cfg(feature = "payments")]
 pub mod payments;
+#[cfg(feature = "cache")]
 pub mod cache;
 pub mod metrics;
+pub mod queue;
+pub mod timeout;
 
 type BoxedCallbackStringStringSend = Box<dyn Fn(IndyResult<(String, String)>) + Send>;
+#[cfg(feature = "ledger")]
+type BoxedCallbackStringStringU64Send = Box<dyn Fn(IndyResult<(String, String, u64)>) + Send>;
+type BoxedCallbackSearchHandleUsizeSend = Box<dyn Fn(IndyResult<(SearchHandle, usize)>) + Send>;
 
 pub enum Command {
     Exit,
+    Shutdown(Box<dyn Fn(IndyResult<()>) + Send>),
+    #[cfg(feature = "anoncreds")]
     Anoncreds(AnoncredsCommand),
+    #[cfg(feature = "anoncreds")]
     BlobStorage(BlobStorageCommand),
     Crypto(CryptoCommand),
+    #[cfg(feature = "ledger")]
     Ledger(LedgerCommand),
+    #[cfg(feature = "ledger")]
     Pool(PoolCommand),
     Did(DidCommand),
     Wallet(WalletCommand),
     Pairwise(PairwiseCommand),
     NonSecrets(NonSecretsCommand),
+    #[cfg(feature = "payments")]
     Payments(PaymentsCommand),
+    #[cfg(feature = "cache")]
     Cache(CacheCommand),
     Metrics(MetricsCommand),
 }
 
 pub struct InstrumentedCommand {
     pub enqueue_ts: u128,
+    // started when the command is sent, entered while it is executed
+    #[cfg(feature = "tracing_spans")]
+    pub span: ::tracing::Span,
+    pub audit_context: Option<serde_json::Value>,
+    // digest of parameters of application call that sent the command, reported to command hooks
+    pub params_digest: Option<String>,
+    // application call the command belongs to
+    pub call_id: Option<u64>,
+    // sent by application call, not by libindy itself
+    pub submitted: bool,
     pub command: Command
 }
 
@@ -74,6 +129,12 @@ impl InstrumentedCommand {
     pub fn new(command: Command) -> InstrumentedCommand {
         InstrumentedCommand {
             enqueue_ts: get_cur_time(),
+            #[cfg(feature = "tracing_spans")]
+            span: tracing::command_span(&MetricsService::cmd_name(CommandMetric::from(&command) as usize)),
+            audit_context: audit::current_context(),
+            params_digest: command_params::take_current(),
+            call_id: timeout::current_call(),
+            submitted: false,
             command
         }
     }
@@ -82,20 +143,212 @@ impl InstrumentedCommand {
 
 lazy_static! {
     static ref THREADPOOL: Mutex<ThreadPool> = Mutex::new(ThreadPool::new(4));
+    static ref ANONCREDS_THREADPOOL: Mutex<ThreadPool> = Mutex::new(ThreadPool::new(4));
+}
+
+#[cfg(feature = "anoncreds")]
+type AnoncredsJob = Box<dyn FnOnce() + Send>;
+
+#[cfg(feature = "anoncreds")]
+lazy_static! {
+    /// Wallets with a job running on the anoncreds workers and jobs waiting behind it.
+    static ref ANONCREDS_LANES: Mutex<HashMap<WalletHandle, VecDeque<AnoncredsJob>>> = Mutex::new(HashMap::new());
+}
+
+/// Runs CPU-bound anoncreds job on the anoncreds workers so it doesn't block other commands.
+/// Panic of the job is reported to the callback with `InvalidState` error.
+///
+/// Jobs of the same wallet run one by one in submission order, so their callbacks keep the order
+/// of the commands. Jobs without wallet run in parallel.
+///
+/// NOTE: only the job is moved off the command thread. Commands themselves are still executed
+/// one by one on the single command thread that owns the services.
+#[cfg(feature = "anoncreds")]
+pub fn execute_anoncreds_job<T, F>(wallet_handle: Option<WalletHandle>, job: F, cb: Box<dyn Fn(IndyResult<T>) + Send>)
+    where T: 'static,
+          F: FnOnce() -> IndyResult<T> + Send + 'static {
+    let job: AnoncredsJob = Box::new(move || {
+        let result = panic::catch_unwind(AssertUnwindSafe(job))
+            .unwrap_or_else(|payload| {
+                let message = _panic_message(payload.as_ref());
+                error!("Anoncreds job panicked: {}", message);
+                Err(err_msg(IndyErrorKind::InvalidState, format!("Anoncreds job panicked: {}", message)))
+            });
+
+        cb(result)
+    });
+
+    let wallet_handle = match wallet_handle {
+        Some(wallet_handle) => wallet_handle,
+        None => {
+            ANONCREDS_THREADPOOL.lock().unwrap().execute(job);
+            return;
+        }
+    };
+
+    {
+        let mut lanes = ANONCREDS_LANES.lock().unwrap();
+
+        if let Some(queue) = lanes.get_mut(&wallet_handle) {
+            queue.push_back(job);
+            return;
+        }
+
+        lanes.insert(wallet_handle, VecDeque::new());
+    }
+
+    _execute_in_lane(wallet_handle, job);
+}
+
+#[cfg(feature = "anoncreds")]
+fn _execute_in_lane(wallet_handle: WalletHandle, job: AnoncredsJob) {
+    ANONCREDS_THREADPOOL.lock().unwrap().execute(move || {
+        job();
+
+        let next = {
+            let mut lanes = ANONCREDS_LANES.lock().unwrap();
+            let next = lanes.get_mut(&wallet_handle).and_then(VecDeque::pop_front);
+
+            if next.is_none() {
+                lanes.remove(&wallet_handle);
+            }
+
+            next
+        };
+
+        if let Some(next) = next {
+            _execute_in_lane(wallet_handle, next);
+        }
+    });
+}
+
+pub type PreCommandHook = extern fn(command_name: *const c_char, params_digest: *const c_char);
+pub type PostCommandHook = extern fn(command_name: *const c_char, params_digest: *const c_char, duration_ms: u64, err: ErrorCode);
+
+#[derive(Default, Clone, Copy)]
+pub struct CommandHooks {
+    pub pre: Option<PreCommandHook>,
+    pub post: Option<PostCommandHook>,
+}
+
+lazy_static! {
+    static ref COMMAND_HOOKS: Mutex<CommandHooks> = Mutex::new(CommandHooks::default());
+}
+
+pub fn indy_register_command_hook(hooks: CommandHooks) {
+    command_params::set_enabled(hooks.pre.is_some() || hooks.post.is_some());
+    *COMMAND_HOOKS.lock().unwrap() = hooks;
 }
 
 pub fn indy_set_runtime_config(config: IndyConfig) {
     if let Some(crypto_thread_pool_size) = config.crypto_thread_pool_size {
         THREADPOOL.lock().unwrap().set_num_threads(crypto_thread_pool_size);
     }
+    if let Some(anoncreds_worker_threads) = config.anoncreds_worker_threads {
+        ANONCREDS_THREADPOOL.lock().unwrap().set_num_threads(anoncreds_worker_threads);
+    }
     match config.collect_backtrace {
         Some(true) => env::set_var("RUST_BACKTRACE", "1"),
         Some(false) => env::set_var("RUST_BACKTRACE", "0"),
         _ => {}
     }
+    if let Some(collect_backtrace) = config.collect_backtrace {
+        indy_api_types::errors::set_backtrace_reported(collect_backtrace);
+    }
+    #[cfg(feature = "ledger")]
     if let Some(threshold) = config.freshness_threshold {
         set_freshness_threshold(threshold);
     }
+    if let Some(buckets_ms) = config.metrics_buckets_ms {
+        set_buckets_ms(buckets_ms);
+    }
+    if let Some(threshold_ms) = config.slow_operation_threshold_ms {
+        indy_utils::slow_operations::set_threshold_ms(threshold_ms);
+    }
+    if let Some(log_format) = config.log_format {
+        crate::utils::logger::set_log_format(log_format);
+    }
+    if let Some(max_pending) = config.max_pending_commands {
+        queue::set_max_pending(max_pending);
+    }
+    if let Some(timeout_ms) = config.queue_full_timeout_ms {
+        queue::set_full_timeout_ms(timeout_ms);
+    }
+    if let Some(timeout_ms) = config.command_timeout_ms {
+        timeout::set_timeout_ms(timeout_ms);
+    }
+    #[cfg(feature = "ledger")]
+    if let Some(hwm) = config.pool_socket_hwm {
+        set_socket_hwm(hwm);
+    }
+    #[cfg(feature = "ledger")]
+    if let Some(io_threads) = config.pool_zmq_io_threads {
+        set_io_threads(io_threads);
+    }
+    #[cfg(feature = "anoncreds")]
+    if let Some(size) = config.blob_buffer_size {
+        set_blob_buffer_size(size);
+    }
+    #[cfg(feature = "anoncreds")]
+    if let Some(size) = config.tails_cache_size {
+        set_tails_cache_size(size);
+    }
+    #[cfg(feature = "anoncreds")]
+    if let Some(seed) = config.anoncreds_test_seed {
+        set_anoncreds_test_seed(seed);
+    }
+    #[cfg(feature = "ledger")]
+    if let Some(strict) = config.strict_attrib_validation {
+        set_strict_attrib_validation(strict);
+    }
+}
+
+/// Stops accepting application calls and closes searches, wallets and pools once the queued commands are executed.
+/// Forced shutdown, or the one that isn't completed in `timeout_ms`, cancels the queued calls instead of waiting for them.
+pub fn indy_shutdown(force: bool, timeout_ms: u64, cb: Box<dyn Fn(IndyResult<()>) + Send>) -> IndyResult<()> {
+    queue::start_shutdown()?;
+
+    if force {
+        _cancel_queued_calls();
+    } else if timeout_ms != 0 {
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(timeout_ms));
+            _cancel_queued_calls();
+        });
+    }
+
+    CommandExecutor::instance().send(Command::Shutdown(cb))
+}
+
+fn _cancel_queued_calls() {
+    if queue::cancel() {
+        timeout::cancel_pending_calls(err_msg(IndyErrorKind::InvalidState, "Command has been cancelled by shutdown"));
+    }
+}
+
+/// Executes command so its panic doesn't stop the command thread.
+/// Callback of the application call the panicked command belongs to is called with `InvalidState` error.
+fn _execute_isolated<F: FnOnce() -> bool>(call_id: Option<u64>, execute: F) -> bool {
+    match panic::catch_unwind(AssertUnwindSafe(execute)) {
+        Ok(exit) => exit,
+        Err(payload) => {
+            let message = _panic_message(payload.as_ref());
+
+            error!("Command panicked: {}", message);
+
+            if let Some(call_id) = call_id {
+                timeout::fail_call(call_id, err_msg(IndyErrorKind::InvalidState, format!("Command panicked: {}", message)));
+            }
+
+            false
+        }
+    }
+}
+
+fn _panic_message(payload: &(dyn Any + Send)) -> String {
+    payload.downcast_ref::<&str>().map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_string())
 }
 
 fn get_cur_time() -> u128 {
@@ -126,31 +379,45 @@ impl CommandExecutor {
             worker: Some(thread::spawn(move || {
                 info!(target: "command_executor", "Worker thread started");
 
+                #[cfg(feature = "anoncreds")]
                 let anoncreds_service = Rc::new(AnoncredsService::new());
+                #[cfg(feature = "anoncreds")]
                 let blob_storage_service = Rc::new(BlobStorageService::new());
                 let crypto_service = Rc::new(CryptoService::new());
+                #[cfg(feature = "ledger")]
                 let ledger_service = Rc::new(LedgerService::new());
+                #[cfg(feature = "payments")]
                 let payments_service = Rc::new(PaymentsService::new());
+                #[cfg(feature = "ledger")]
                 let pool_service = Rc::new(PoolService::new());
                 let wallet_service = Rc::new(WalletService::new());
                 let metrics_service = Rc::new(MetricsService::new());
 
-                let anoncreds_command_executor = AnoncredsCommandExecutor::new(anoncreds_service.clone(), blob_storage_service.clone(), pool_service.clone(), wallet_service.clone(), crypto_service.clone());
+                #[cfg(feature = "anoncreds")]
+                let anoncreds_command_executor = AnoncredsCommandExecutor::new(anoncreds_service.clone(), blob_storage_service.clone(), wallet_service.clone(), crypto_service.clone(),
+                                                                               #[cfg(feature = "ledger")] pool_service.clone());
                 let crypto_command_executor = CryptoCommandExecutor::new(wallet_service.clone(), crypto_service.clone());
+                #[cfg(feature = "ledger")]
                 let ledger_command_executor = LedgerCommandExecutor::new(pool_service.clone(), crypto_service.clone(), wallet_service.clone(), ledger_service.clone());
+                #[cfg(feature = "ledger")]
                 let pool_command_executor = PoolCommandExecutor::new(pool_service.clone());
-                let did_command_executor = DidCommandExecutor::new(wallet_service.clone(), crypto_service.clone(), ledger_service.clone());
+                let did_command_executor = DidCommandExecutor::new(wallet_service.clone(), crypto_service.clone(),
+                                                                   #[cfg(feature = "ledger")] ledger_service.clone());
                 let wallet_command_executor = WalletCommandExecutor::new(wallet_service.clone(), crypto_service.clone());
                 let pairwise_command_executor = PairwiseCommandExecutor::new(wallet_service.clone());
+                #[cfg(feature = "anoncreds")]
                 let blob_storage_command_executor = BlobStorageCommandExecutor::new(blob_storage_service.clone());
                 let non_secret_command_executor = NonSecretsCommandExecutor::new(wallet_service.clone());
+                #[cfg(feature = "payments")]
                 let payments_command_executor = PaymentsCommandExecutor::new(payments_service.clone(), wallet_service.clone(), crypto_service.clone(), ledger_service.clone());
-                let cache_command_executor = CacheCommandExecutor::new(wallet_service.clone());
+                #[cfg(feature = "cache")]
+                let cache_command_executor = CacheCommandExecutor::new(wallet_service.clone(), pool_service.clone());
                 let metrics_command_executor = MetricsCommandExecutor::new(wallet_service.clone(), metrics_service.clone());
 
                 loop {
                     let instrumented_cmd = match receiver.recv() {
                         Ok(cmd) => {
+                            queue::dequeued();
                             cmd
                         }
                         Err(err) => {
@@ -158,76 +425,202 @@ impl CommandExecutor {
                             panic!("Failed to get command! {:?}", err)
                         }
                     };
+
+                    // Callbacks of the queued application calls have been already called with cancellation error
+                    if instrumented_cmd.submitted && queue::is_cancelled() {
+                        debug!("Command cancelled by shutdown is skipped");
+                        continue;
+                    }
                     let cmd_index: CommandMetric = (&instrumented_cmd.command).into();
                     let start_execution_ts = get_cur_time();
                     metrics_service.cmd_left_queue(cmd_index,
                                                    start_execution_ts - instrumented_cmd.enqueue_ts);
 
-                    match instrumented_cmd.command {
-                        Command::Anoncreds(cmd) => {
-                            debug!("AnoncredsCommand command received");
-                            anoncreds_command_executor.execute(cmd);
-                        }
-                        Command::BlobStorage(cmd) => {
-                            debug!("BlobStorageCommand command received");
-                            blob_storage_command_executor.execute(cmd);
-                        }
-                        Command::Crypto(cmd) => {
-                            debug!("CryptoCommand command received");
-                            crypto_command_executor.execute(cmd);
-                        }
-                        Command::Ledger(cmd) => {
-                            debug!("LedgerCommand command received");
-                            ledger_command_executor.execute(cmd);
-                        }
-                        Command::Pool(cmd) => {
-                            debug!("PoolCommand command received");
-                            pool_command_executor.execute(cmd);
-                        }
-                        Command::Did(cmd) => {
-                            debug!("DidCommand command received");
-                            did_command_executor.execute(cmd);
-                        }
-                        Command::Wallet(cmd) => {
-                            debug!("WalletCommand command received");
-                            wallet_command_executor.execute(cmd);
-                        }
-                        Command::Pairwise(cmd) => {
-                            debug!("PairwiseCommand command received");
-                            pairwise_command_executor.execute(cmd);
-                        }
-                        Command::NonSecrets(cmd) => {
-                            debug!("NonSecretCommand command received");
-                            non_secret_command_executor.execute(cmd);
-                        }
-                        Command::Payments(cmd) => {
-                            debug!("PaymentsCommand command received");
-                            payments_command_executor.execute(cmd);
-                        }
-                        Command::Cache(cmd) => {
-                            debug!("CacheCommand command received");
-                            cache_command_executor.execute(cmd);
-                        }
-                        Command::Metrics(cmd) => {
-                            debug!("MetricsCommand command received");
-                            metrics_command_executor.execute(cmd);
+                    let hooks = *COMMAND_HOOKS.lock().unwrap();
+                    // Continuation commands are reported as a part of the application call they belong to
+                    let continuation = !instrumented_cmd.submitted && instrumented_cmd.call_id.is_some();
+                    // Commands sent by libindy itself have no parameters of application call
+                    let hook_args = if !continuation && (hooks.pre.is_some() || hooks.post.is_some()) {
+                        Some((ctypes::string_to_cstring(MetricsService::cmd_name(cmd_index as usize)),
+                              ctypes::string_to_cstring(instrumented_cmd.params_digest.clone().unwrap_or_default())))
+                    } else {
+                        None
+                    };
+
+                    if let Some((ref cmd_name, ref params_digest)) = hook_args {
+                        if let Some(pre) = hooks.pre {
+                            pre(cmd_name.as_ptr(), params_digest.as_ptr());
                         }
-                        Command::Exit => {
-                            debug!("Exit command received");
-                            break
+                        take_last_error_code();
+                    }
+
+                    // Continuation commands and wallet storage operations become children of the entered span
+                    #[cfg(feature = "tracing_spans")]
+                    let _entered_span = {
+                        let span = instrumented_cmd.span.clone();
+                        span.record("indy.queue_ms", &((start_execution_ts - instrumented_cmd.enqueue_ts) as u64));
+                        span.entered()
+                    };
+
+                    // Continuation commands sent while executing inherit audit context of the command
+                    audit::set_current_context(instrumented_cmd.audit_context);
+
+                    let call_id = instrumented_cmd.call_id;
+                    timeout::set_current_call(call_id);
+
+                    let command = instrumented_cmd.command;
+                    let exit = _execute_isolated(call_id, || {
+                        match command {
+                            #[cfg(feature = "anoncreds")]
+                            Command::Anoncreds(cmd) => {
+                                debug!("AnoncredsCommand command received");
+                                anoncreds_command_executor.execute(cmd);
+                            }
+                            #[cfg(feature = "anoncreds")]
+                            Command::BlobStorage(cmd) => {
+                                debug!("BlobStorageCommand command received");
+                                blob_storage_command_executor.execute(cmd);
+                            }
+                            Command::Crypto(cmd) => {
+                                debug!("CryptoCommand command received");
+                                crypto_command_executor.execute(cmd);
+                            }
+                            #[cfg(feature = "ledger")]
+                            Command::Ledger(cmd) => {
+                                debug!("LedgerCommand command received");
+                                ledger_command_executor.execute(cmd);
+                            }
+                            #[cfg(feature = "ledger")]
+                            Command::Pool(cmd) => {
+                                debug!("PoolCommand command received");
+                                pool_command_executor.execute(cmd);
+                            }
+                            Command::Did(cmd) => {
+                                debug!("DidCommand command received");
+                                did_command_executor.execute(cmd);
+                            }
+                            Command::Wallet(cmd) => {
+                                debug!("WalletCommand command received");
+                                wallet_command_executor.execute(cmd);
+                            }
+                            Command::Pairwise(cmd) => {
+                                debug!("PairwiseCommand command received");
+                                pairwise_command_executor.execute(cmd);
+                            }
+                            Command::NonSecrets(cmd) => {
+                                debug!("NonSecretCommand command received");
+                                non_secret_command_executor.execute(cmd);
+                            }
+                            #[cfg(feature = "payments")]
+                            Command::Payments(cmd) => {
+                                debug!("PaymentsCommand command received");
+                                payments_command_executor.execute(cmd);
+                            }
+                            #[cfg(feature = "cache")]
+                            Command::Cache(cmd) => {
+                                debug!("CacheCommand command received");
+                                cache_command_executor.execute(cmd);
+                            }
+                            Command::Metrics(cmd) => {
+                                debug!("MetricsCommand command received");
+                                metrics_command_executor.execute(cmd);
+                            }
+                            Command::Shutdown(cb) => {
+                                debug!("Shutdown command received");
+                                // Searches hold wallet storage iterators, so they are closed before wallets
+                                #[cfg(feature = "anoncreds")]
+                                anoncreds_command_executor.close_all_searches();
+                                did_command_executor.close_all_searches();
+                                pairwise_command_executor.close_all_searches();
+                                non_secret_command_executor.close_all_searches();
+                                wallet_command_executor.close_all();
+                                #[cfg(feature = "ledger")]
+                                pool_command_executor.close_all();
+                                queue::shut_down();
+                                cb(Ok(()));
+                            }
+                            Command::Exit => {
+                                debug!("Exit command received");
+                                return true;
+                            }
                         }
+                        false
+                    });
+
+                    timeout::set_current_call(None);
+
+                    if exit {
+                        break;
+                    }
+
+                    audit::set_current_context(None);
+
+                    let duration = get_cur_time() - start_execution_ts;
+                    metrics_service.cmd_executed(cmd_index, duration);
+
+                    // Application call reports its result once its callback is called, see `submit`.
+                    // Result code of other commands is the code of error converted while the command was executed.
+                    if let (Some(post), Some((ref cmd_name, ref params_digest)), None) = (hooks.post, &hook_args, call_id) {
+                        post(cmd_name.as_ptr(), params_digest.as_ptr(), duration as u64, take_last_error_code().unwrap_or(ErrorCode::Success));
                     }
-                    metrics_service.cmd_executed(cmd_index,
-                                                 get_cur_time() - start_execution_ts);
                 }
             }))
         }
     }
 
+    /// Sends command of application call. Unlike `send` it respects the limit of pending commands.
+    /// The timeout of the call registered by `with_timeout` starts once the command is queued.
+    /// The call is discarded if the command isn't queued, so its callback is never called
+    /// as the error is returned to the caller at once.
+    ///
+    /// Post command hook of the call is called with the result passed to the callback, so commands
+    /// that complete asynchronously report their final result and duration since submission.
+    pub fn submit(cmd: Command) -> IndyResult<()> {
+        // taken first, so the call isn't attached to the next command of this thread
+        let call_id = timeout::take_current_call();
+
+        if call_id.is_none() {
+            warn!("Command is submitted without wrapped callback, so it can't time out");
+        }
+
+        let cmd = InstrumentedCommand { submitted: true, call_id, ..InstrumentedCommand::new(cmd) };
+
+        if let (Some(call_id), Some(post)) = (call_id, COMMAND_HOOKS.lock().unwrap().post) {
+            let cmd_name = ctypes::string_to_cstring(MetricsService::cmd_name(CommandMetric::from(&cmd.command) as usize));
+            let params_digest = ctypes::string_to_cstring(cmd.params_digest.clone().unwrap_or_default());
+            let submit_ts = cmd.enqueue_ts;
+
+            timeout::on_complete(call_id, Box::new(move |err| {
+                post(cmd_name.as_ptr(), params_digest.as_ptr(), (get_cur_time() - submit_ts) as u64, err)
+            }));
+        }
+
+        let res = queue::check_capacity()
+            .and_then(|_| CommandExecutor::instance()._send(cmd));
+
+        match (&res, call_id) {
+            (Ok(_), Some(call_id)) => timeout::start(call_id),
+            (Err(_), Some(call_id)) => timeout::discard_call(call_id),
+            (_, None) => {}
+        }
+
+        res
+    }
+
     pub fn send(&self, cmd: Command) -> IndyResult<()> {
+        self._send(InstrumentedCommand::new(cmd))
+    }
+
+    fn _send(&self, cmd: InstrumentedCommand) -> IndyResult<()> {
+        // counted before sending as the executor may take the command at once
+        queue::enqueued();
+
         self.sender
-            .send(InstrumentedCommand::new(cmd))
-            .map_err(|err| err_msg(IndyErrorKind::InvalidState, format!("Can't send msg to CommandExecutor: {}", err)))
+            .send(cmd)
+            .map_err(|err| {
+                queue::dequeued();
+                err_msg(IndyErrorKind::InvalidState, format!("Can't send msg to CommandExecutor: {}", err))
+            })
     }
 }
 
@@ -261,6 +654,56 @@ mod tests {
         assert!(true, "No crashes on CommandExecutor::drop");
     }
 
+    #[test]
+    fn execute_isolated_works_for_panic() {
+        let (sender, receiver) = channel();
+        let sender = Mutex::new(sender);
+        let _cb = timeout::with_timeout(move |result: IndyResult<()>| sender.lock().unwrap().send(result).unwrap());
+        let call_id = timeout::take_current_call();
+
+        let exit = _execute_isolated(call_id, || panic!("Malformed input"));
+
+        assert!(!exit);
+        let err = receiver.recv_timeout(Duration::from_secs(5)).unwrap().unwrap_err();
+        assert_eq!(IndyErrorKind::InvalidState, err.kind());
+        assert!(err.to_string().contains("Malformed input"));
+    }
+
+    #[test]
+    #[cfg(feature = "anoncreds")]
+    fn execute_anoncreds_job_works_for_panic() {
+        let (sender, receiver) = channel();
+        let sender = Mutex::new(sender);
+
+        execute_anoncreds_job(None,
+                              || -> IndyResult<()> { panic!("Malformed proof") },
+                              Box::new(move |result| sender.lock().unwrap().send(result).unwrap()));
+
+        let err = receiver.recv_timeout(Duration::from_secs(5)).unwrap().unwrap_err();
+        assert_eq!(IndyErrorKind::InvalidState, err.kind());
+        assert!(err.to_string().contains("Malformed proof"));
+    }
+
+    #[test]
+    #[cfg(feature = "anoncreds")]
+    fn execute_anoncreds_job_works_for_wallet_order() {
+        let wallet_handle = WalletHandle(i32::max_value());
+        let (sender, receiver) = channel();
+
+        for i in 0..3u64 {
+            let sender = Mutex::new(sender.clone());
+            execute_anoncreds_job(Some(wallet_handle),
+                                  move || -> IndyResult<u64> {
+                                      thread::sleep(Duration::from_millis(30 * (3 - i)));
+                                      Ok(i)
+                                  },
+                                  Box::new(move |result| sender.lock().unwrap().send(result.unwrap()).unwrap()));
+        }
+
+        let order = (0..3).map(|_| receiver.recv_timeout(Duration::from_secs(5)).unwrap()).collect::<Vec<u64>>();
+        assert_eq!(vec![0, 1, 2], order);
+    }
+
     #[test]
     fn command_executor_can_get_instance() {
         let ref _command_executor: CommandExecutor = *CommandExecutor::instance();
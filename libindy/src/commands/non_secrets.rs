@@ -4,10 +4,18 @@ use std::rc::Rc;
 
 use indy_api_types::domain::wallet::Tags;
 use indy_api_types::errors::prelude::*;
-use indy_wallet::{RecordOptions, SearchOptions, WalletRecord, WalletSearch, WalletService};
+use crate::commands::timeout;
+use indy_wallet::{RecordHistoryConfig, RecordOptions, RecordRevisionInfo, SearchOptions, UpsertRecord, WalletRecord, WalletSearch, WalletService};
 use indy_utils::next_search_handle;
+use crate::utils::handles;
+use indy_utils::crypto::hash::hash;
 use indy_api_types::{WalletHandle, SearchHandle};
 
+pub type ModifyRecordValueFn = Box<dyn FnMut(&str) -> IndyResult<String> + Send>;
+
+/// Types of libindy own records which history can be enabled for, as their prior states are
+/// needed for audits. They contain no secrets.
+const HISTORY_INDY_TYPES: [&str; 2] = ["Credential", "DidMetadata"];
 
 pub enum NonSecretsCommand {
     AddRecord(WalletHandle,
@@ -21,6 +29,12 @@ pub enum NonSecretsCommand {
                       String, // id
                       String, // value
                       Box<dyn Fn(IndyResult<()>) + Send>),
+    CompareAndSwapRecordValue(WalletHandle,
+                              String, // type
+                              String, // id
+                              String, // expected value hash
+                              String, // value
+                              Box<dyn Fn(IndyResult<String>) + Send>),
     UpdateRecordTags(WalletHandle,
                      String, // type
                      String, // id
@@ -40,11 +54,26 @@ pub enum NonSecretsCommand {
                  String, // type
                  String, // id
                  Box<dyn Fn(IndyResult<()>) + Send>),
+    ModifyRecordValue(WalletHandle,
+                      String, // type
+                      String, // id
+                      ModifyRecordValueFn, // modify
+                      Box<dyn Fn(IndyResult<String>) + Send>),
+    UpsertRecords(WalletHandle,
+                  String, // type
+                  Vec<UpsertRecord>, // records
+                  Box<dyn Fn(IndyResult<String>) + Send>),
     GetRecord(WalletHandle,
               String, // type
               String, // id
               String, // options json
               Box<dyn Fn(IndyResult<String>) + Send>),
+    GetRecordTypes(WalletHandle,
+                   Box<dyn Fn(IndyResult<String>) + Send>),
+    ExplainSearch(WalletHandle,
+                  String, // type
+                  String, // query json
+                  Box<dyn Fn(IndyResult<String>) + Send>),
     OpenSearch(WalletHandle,
                String, // type
                String, // query json
@@ -56,6 +85,34 @@ pub enum NonSecretsCommand {
                            Box<dyn Fn(IndyResult<String>) + Send>),
     CloseSearch(SearchHandle, // wallet search handle
                 Box<dyn Fn(IndyResult<()>) + Send>),
+    StreamRecords(WalletHandle,
+                  String, // type
+                  String, // query json
+                  String, // options json
+                  usize, // chunk size
+                  Box<dyn Fn(String) + Send>, // records cb
+                  Box<dyn Fn(IndyResult<()>) + Send>),
+    EnableRecordHistory(WalletHandle,
+                        String, // type
+                        RecordHistoryConfig, // config
+                        Box<dyn Fn(IndyResult<()>) + Send>),
+    DisableRecordHistory(WalletHandle,
+                         String, // type
+                         Box<dyn Fn(IndyResult<()>) + Send>),
+    GetRecordRevisions(WalletHandle,
+                       String, // type
+                       String, // id
+                       Box<dyn Fn(IndyResult<String>) + Send>),
+    GetRecordRevision(WalletHandle,
+                      String, // type
+                      String, // id
+                      u32, // revision
+                      Box<dyn Fn(IndyResult<String>) + Send>),
+    PruneRecordHistory(WalletHandle,
+                       String, // type
+                       String, // id
+                       usize, // keep last
+                       Box<dyn Fn(IndyResult<()>) + Send>),
 }
 
 pub struct NonSecretsCommandExecutor {
@@ -81,6 +138,10 @@ impl NonSecretsCommandExecutor {
                 debug!(target: "non_secrets_command_executor", "UpdateRecordValue command received");
                 cb(self.update_record_value(handle, &type_, &id, &value));
             }
+            NonSecretsCommand::CompareAndSwapRecordValue(handle, type_, id, expected_value_hash, value, cb) => {
+                debug!(target: "non_secrets_command_executor", "CompareAndSwapRecordValue command received");
+                cb(self.compare_and_swap_record_value(handle, &type_, &id, &expected_value_hash, &value));
+            }
             NonSecretsCommand::UpdateRecordTags(handle, type_, id, tags, cb) => {
                 debug!(target: "non_secrets_command_executor", "UpdateRecordTags command received");
                 cb(self.update_record_tags(handle, &type_, &id, &tags));
@@ -97,10 +158,26 @@ impl NonSecretsCommandExecutor {
                 debug!(target: "non_secrets_command_executor", "DeleteRecord command received");
                 cb(self.delete_record(handle, &type_, &id));
             }
+            NonSecretsCommand::ModifyRecordValue(handle, type_, id, mut modify, cb) => {
+                debug!(target: "non_secrets_command_executor", "ModifyRecordValue command received");
+                cb(self.modify_record_value(handle, &type_, &id, &mut *modify));
+            }
+            NonSecretsCommand::UpsertRecords(handle, type_, records, cb) => {
+                debug!(target: "non_secrets_command_executor", "UpsertRecords command received");
+                cb(self.upsert_records(handle, &type_, &records));
+            }
             NonSecretsCommand::GetRecord(handle, type_, id, options_json, cb) => {
                 debug!(target: "non_secrets_command_executor", "GetRecord command received");
                 cb(self.get_record(handle, &type_, &id, &options_json));
             }
+            NonSecretsCommand::GetRecordTypes(handle, cb) => {
+                debug!(target: "non_secrets_command_executor", "GetRecordTypes command received");
+                cb(self.get_record_types(handle));
+            }
+            NonSecretsCommand::ExplainSearch(handle, type_, query_json, cb) => {
+                debug!(target: "non_secrets_command_executor", "ExplainSearch command received");
+                cb(self.explain_search(handle, &type_, &query_json));
+            }
             NonSecretsCommand::OpenSearch(handle, type_, query_json, options_json, cb) => {
                 debug!(target: "non_secrets_command_executor", "OpenSearch command received");
                 cb(self.open_search(handle, &type_, &query_json, &options_json));
@@ -113,6 +190,30 @@ impl NonSecretsCommandExecutor {
                 debug!(target: "non_secrets_command_executor", "CloseSearch command received");
                 cb(self.close_search(wallet_search_handle));
             }
+            NonSecretsCommand::StreamRecords(handle, type_, query_json, options_json, chunk_size, records_cb, cb) => {
+                debug!(target: "non_secrets_command_executor", "StreamRecords command received");
+                cb(self.stream_records(handle, &type_, &query_json, &options_json, chunk_size, &*records_cb));
+            }
+            NonSecretsCommand::EnableRecordHistory(handle, type_, config, cb) => {
+                debug!(target: "non_secrets_command_executor", "EnableRecordHistory command received");
+                cb(self.enable_record_history(handle, &type_, &config));
+            }
+            NonSecretsCommand::DisableRecordHistory(handle, type_, cb) => {
+                debug!(target: "non_secrets_command_executor", "DisableRecordHistory command received");
+                cb(self.disable_record_history(handle, &type_));
+            }
+            NonSecretsCommand::GetRecordRevisions(handle, type_, id, cb) => {
+                debug!(target: "non_secrets_command_executor", "GetRecordRevisions command received");
+                cb(self.get_record_revisions(handle, &type_, &id));
+            }
+            NonSecretsCommand::GetRecordRevision(handle, type_, id, revision, cb) => {
+                debug!(target: "non_secrets_command_executor", "GetRecordRevision command received");
+                cb(self.get_record_revision(handle, &type_, &id, revision));
+            }
+            NonSecretsCommand::PruneRecordHistory(handle, type_, id, keep_last, cb) => {
+                debug!(target: "non_secrets_command_executor", "PruneRecordHistory command received");
+                cb(self.prune_record_history(handle, &type_, &id, keep_last));
+            }
         };
     }
 
@@ -149,6 +250,36 @@ impl NonSecretsCommandExecutor {
         Ok(())
     }
 
+    fn compare_and_swap_record_value(&self,
+                                     wallet_handle: WalletHandle,
+                                     type_: &str,
+                                     id: &str,
+                                     expected_value_hash: &str,
+                                     value: &str) -> IndyResult<String> {
+        trace!("compare_and_swap_record_value >>> wallet_handle: {:?}, type_: {:?}, id: {:?}, expected_value_hash: {:?}, value: {:?}",
+               wallet_handle, type_, id, expected_value_hash, value);
+
+        self._check_type(type_)?;
+
+        let record = self.wallet_service.get_record(wallet_handle, type_, id, &RecordOptions::id_value())?;
+
+        let current_value_hash = self._value_hash(record.get_value().unwrap_or_default())?;
+
+        if !current_value_hash.eq_ignore_ascii_case(expected_value_hash) {
+            return Err(err_msg(IndyErrorKind::WalletItemConflict,
+                               format!("Wallet item with type: {}, id: {} was modified: expected value hash {}, actual {}",
+                                       type_, id, expected_value_hash, current_value_hash)));
+        }
+
+        self.wallet_service.update_record_value(wallet_handle, type_, id, value)?;
+
+        let res = self._value_hash(value)?;
+
+        trace!("compare_and_swap_record_value <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
     fn update_record_tags(&self,
                           wallet_handle: WalletHandle,
                           type_: &str,
@@ -215,6 +346,48 @@ impl NonSecretsCommandExecutor {
         Ok(())
     }
 
+    fn modify_record_value(&self,
+                           wallet_handle: WalletHandle,
+                           type_: &str,
+                           id: &str,
+                           modify: &mut dyn FnMut(&str) -> IndyResult<String>) -> IndyResult<String> {
+        trace!("modify_record_value >>> wallet_handle: {:?}, type_: {:?}, id: {:?}", wallet_handle, type_, id);
+
+        self._check_type(type_)?;
+
+        let res = self.wallet_service.modify_record_value(wallet_handle, type_, id, modify)?;
+
+        trace!("modify_record_value <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn upsert_records(&self,
+                      wallet_handle: WalletHandle,
+                      type_: &str,
+                      records: &[UpsertRecord]) -> IndyResult<String> {
+        trace!("upsert_records >>> wallet_handle: {:?}, type_: {:?}, records: {:?}", wallet_handle, type_, records);
+
+        self._check_type(type_)?;
+
+        let inserted = self.wallet_service.upsert_records(wallet_handle, type_, records)?;
+
+        let statuses: Vec<UpsertRecordStatus> = records.iter()
+            .zip(inserted)
+            .map(|(record, inserted)| UpsertRecordStatus {
+                id: record.id.clone(),
+                status: if inserted { UpsertStatus::Inserted } else { UpsertStatus::Updated },
+            })
+            .collect();
+
+        let res = serde_json::to_string(&statuses)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize upsert statuses")?;
+
+        trace!("upsert_records <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
     fn get_record(&self,
                   wallet_handle: WalletHandle,
                   type_: &str,
@@ -237,6 +410,37 @@ impl NonSecretsCommandExecutor {
         Ok(res)
     }
 
+    fn get_record_types(&self,
+                        wallet_handle: WalletHandle) -> IndyResult<String> {
+        trace!("get_record_types >>> wallet_handle: {:?}", wallet_handle);
+
+        let types = self.wallet_service.get_record_types(wallet_handle)?;
+
+        let res = serde_json::to_string(&types)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize record types")?;
+
+        trace!("get_record_types <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn explain_search(&self,
+                      wallet_handle: WalletHandle,
+                      type_: &str,
+                      query_json: &str) -> IndyResult<String> {
+        trace!("explain_search >>> wallet_handle: {:?}, type_: {:?}, query_json: {:?}", wallet_handle, type_, query_json);
+
+        // Plan exposes only counts of records, so types of records created by libindy can be explained too
+        let plan = self.wallet_service.explain_search(wallet_handle, type_, query_json)?;
+
+        let res = serde_json::to_string(&plan)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize search plan")?;
+
+        trace!("explain_search <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
     fn open_search(&self,
                    wallet_handle: WalletHandle,
                    type_: &str,
@@ -254,6 +458,7 @@ impl NonSecretsCommandExecutor {
         let search_handle = next_search_handle();
 
         self.searches.borrow_mut().insert(search_handle, Box::new(search));
+        handles::opened(handles::WALLET_SEARCH, search_handle.0, Some(type_.to_string()));
 
         trace!("open_search <<< res: {:?}", search_handle);
 
@@ -266,12 +471,21 @@ impl NonSecretsCommandExecutor {
                                  count: usize) -> IndyResult<String> {
         trace!("fetch_search_next_records >>> wallet_handle: {:?}, wallet_search_handle: {:?}, count: {:?}", wallet_handle, wallet_search_handle, count);
 
+        let call_id = timeout::current_call();
+
         let mut searches = self.searches.borrow_mut();
         let search = searches.get_mut(&wallet_search_handle)
             .ok_or_else(||err_msg(IndyErrorKind::InvalidWalletHandle, format!("Unknown WalletSearch handle: {:?}", wallet_search_handle)))?;
 
         let mut records: Vec<WalletRecord> = Vec::new();
         for _ in 0..count {
+            // Search of failed call is closed, its position can't be known to the application
+            if timeout::is_cancelled(call_id) {
+                searches.remove(&wallet_search_handle);
+                handles::closed(handles::WALLET_SEARCH, wallet_search_handle.0);
+                return Err(err_msg(IndyErrorKind::Timeout, "Fetching of wallet search records has been cancelled"));
+            }
+
             match search.fetch_next_record()? {
                 Some(record) => records.push(record),
                 None => break
@@ -298,18 +512,192 @@ impl NonSecretsCommandExecutor {
             Some(_) => Ok(()),
             None => Err(err_msg(IndyErrorKind::InvalidWalletHandle, format!("Wallet Search Handle is invalid: {:?}", wallet_search_handle)))
         }?;
+        handles::closed(handles::WALLET_SEARCH, wallet_search_handle.0);
 
         trace!("close_search <<< res: ()");
 
         Ok(())
     }
 
+    fn stream_records(&self,
+                      wallet_handle: WalletHandle,
+                      type_: &str,
+                      query_json: &str,
+                      options_json: &str,
+                      chunk_size: usize,
+                      records_cb: &dyn Fn(String)) -> IndyResult<()> {
+        trace!("stream_records >>> wallet_handle: {:?}, type_: {:?}, query_json: {:?}, options_json: {:?}, chunk_size: {:?}",
+               wallet_handle, type_, query_json, options_json, chunk_size);
+
+        self._check_type(type_)?;
+
+        serde_json::from_str::<SearchOptions>(options_json)
+            .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize options")?;
+
+        let mut search = self.wallet_service.search_records(wallet_handle, type_, query_json, options_json)?;
+
+        let call_id = timeout::current_call();
+
+        loop {
+            if timeout::is_cancelled(call_id) {
+                return Err(err_msg(IndyErrorKind::Timeout, "Streaming of wallet records has been cancelled"));
+            }
+
+            let mut records: Vec<WalletRecord> = Vec::with_capacity(chunk_size);
+            while records.len() < chunk_size {
+                match search.fetch_next_record()? {
+                    Some(record) => records.push(record),
+                    None => break
+                }
+            }
+
+            if records.is_empty() {
+                break;
+            }
+
+            let completed = records.len() < chunk_size;
+
+            let search_result = SearchRecords {
+                total_count: search.get_total_count()?,
+                records: Some(records)
+            };
+
+            let records_json = serde_json::to_string(&search_result)
+                .to_indy(IndyErrorKind::InvalidState, "Cannot serialize SearchRecords")?;
+
+            records_cb(records_json);
+            timeout::keep_alive(call_id);
+
+            if completed {
+                break;
+            }
+        }
+
+        trace!("stream_records <<<");
+
+        Ok(())
+    }
+
+    fn enable_record_history(&self,
+                             wallet_handle: WalletHandle,
+                             type_: &str,
+                             config: &RecordHistoryConfig) -> IndyResult<()> {
+        trace!("enable_record_history >>> wallet_handle: {:?}, type_: {:?}, config: {:?}", wallet_handle, type_, config);
+
+        self._check_history_type(type_)?;
+
+        self.wallet_service.enable_record_history(wallet_handle, type_, config)?;
+
+        trace!("enable_record_history <<< res: ()");
+
+        Ok(())
+    }
+
+    fn disable_record_history(&self,
+                              wallet_handle: WalletHandle,
+                              type_: &str) -> IndyResult<()> {
+        trace!("disable_record_history >>> wallet_handle: {:?}, type_: {:?}", wallet_handle, type_);
+
+        self._check_history_type(type_)?;
+
+        self.wallet_service.disable_record_history(wallet_handle, type_)?;
+
+        trace!("disable_record_history <<< res: ()");
+
+        Ok(())
+    }
+
+    fn get_record_revisions(&self,
+                            wallet_handle: WalletHandle,
+                            type_: &str,
+                            id: &str) -> IndyResult<String> {
+        trace!("get_record_revisions >>> wallet_handle: {:?}, type_: {:?}, id: {:?}", wallet_handle, type_, id);
+
+        self._check_history_type(type_)?;
+
+        let revisions: Vec<RecordRevisionInfo> = self.wallet_service.get_record_revisions(wallet_handle, type_, id)?
+            .iter()
+            .map(RecordRevisionInfo::from)
+            .collect();
+
+        let res = serde_json::to_string(&revisions)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize record revisions")?;
+
+        trace!("get_record_revisions <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn get_record_revision(&self,
+                           wallet_handle: WalletHandle,
+                           type_: &str,
+                           id: &str,
+                           revision: u32) -> IndyResult<String> {
+        trace!("get_record_revision >>> wallet_handle: {:?}, type_: {:?}, id: {:?}, revision: {:?}", wallet_handle, type_, id, revision);
+
+        self._check_history_type(type_)?;
+
+        let revision = self.wallet_service.get_record_revision(wallet_handle, type_, id, revision)?;
+
+        let res = serde_json::to_string(&revision)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize record revision")?;
+
+        trace!("get_record_revision <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn prune_record_history(&self,
+                            wallet_handle: WalletHandle,
+                            type_: &str,
+                            id: &str,
+                            keep_last: usize) -> IndyResult<()> {
+        trace!("prune_record_history >>> wallet_handle: {:?}, type_: {:?}, id: {:?}, keep_last: {:?}", wallet_handle, type_, id, keep_last);
+
+        self._check_history_type(type_)?;
+
+        let pruned = self.wallet_service.prune_record_history(wallet_handle, type_, id, keep_last)?;
+
+        trace!("prune_record_history <<< pruned: {:?}", pruned);
+
+        Ok(())
+    }
+
+    pub fn close_all_searches(&self) {
+        self.searches.borrow_mut().clear();
+        handles::closed_all(handles::WALLET_SEARCH);
+    }
+
+    fn _value_hash(&self, value: &str) -> IndyResult<String> {
+        hash(value.as_bytes()).map(hex::encode)
+    }
+
     fn _check_type(&self, type_: &str) -> IndyResult<()> {
         if type_.starts_with(WalletService::PREFIX) {
             return Err(err_msg(IndyErrorKind::WalletAccessFailed, format!("Record of type \"{}\" is not available for fetching", type_)));
         }
         Ok(())
     }
+
+    fn _check_history_type(&self, type_: &str) -> IndyResult<()> {
+        if HISTORY_INDY_TYPES.iter().any(|history_type| self.wallet_service.add_prefix(history_type) == type_) {
+            return Ok(());
+        }
+        self._check_type(type_)
+    }
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum UpsertStatus {
+    Inserted,
+    Updated,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UpsertRecordStatus {
+    pub id: String,
+    pub status: UpsertStatus,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
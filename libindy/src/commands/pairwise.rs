@@ -1,12 +1,18 @@
+use crate::commands::BoxedCallbackSearchHandleUsizeSend;
 use crate::domain::crypto::did::{Did, TheirDid};
-use crate::domain::pairwise::{Pairwise, PairwiseInfo};
+use crate::domain::pairwise::{Pairwise, PairwiseInfo, PairwiseLink, PairwiseMeta, PairwiseMetaUpdate, PairwiseMetaMigrationReport, PairwiseSearchOptions, PairwiseWithVerkeys};
 use indy_api_types::errors::prelude::*;
-use indy_wallet::{RecordOptions, WalletService};
+use indy_wallet::{RecordOptions, WalletRecord, WalletSearch, WalletService};
+use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
 use std::str;
-use indy_api_types::WalletHandle;
+use std::time::{SystemTime, UNIX_EPOCH};
+use indy_api_types::{WalletHandle, SearchHandle};
+use indy_api_types::domain::wallet::Tags;
+use indy_utils::next_search_handle;
 use crate::domain::crypto::did::DidValue;
+use crate::utils::handles;
 
 
 pub enum PairwiseCommand {
@@ -31,17 +37,69 @@ pub enum PairwiseCommand {
         WalletHandle,
         DidValue, // their_did
         Option<String>, // metadata
-        Box<dyn Fn(IndyResult<()>) + Send>)
+        Box<dyn Fn(IndyResult<()>) + Send>),
+    UpdatePairwise(
+        WalletHandle,
+        DidValue, // their_did
+        DidValue, // new_their_did
+        Box<dyn Fn(IndyResult<()>) + Send>),
+    DeletePairwise(
+        WalletHandle,
+        DidValue, // their_did
+        Box<dyn Fn(IndyResult<()>) + Send>),
+    LinkPairwiseRecord(
+        WalletHandle,
+        DidValue, // their_did
+        PairwiseLink, // record
+        Box<dyn Fn(IndyResult<()>) + Send>),
+    UnlinkPairwiseRecord(
+        WalletHandle,
+        DidValue, // their_did
+        PairwiseLink, // record
+        Box<dyn Fn(IndyResult<()>) + Send>),
+    GetPairwiseLinks(
+        WalletHandle,
+        DidValue, // their_did
+        Option<String>, // record type
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    SetPairwiseMeta(
+        WalletHandle,
+        DidValue, // their_did
+        PairwiseMetaUpdate, // meta
+        Box<dyn Fn(IndyResult<()>) + Send>),
+    MigratePairwiseMeta(
+        WalletHandle,
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    OpenPairwiseSearch(
+        WalletHandle,
+        Option<String>, // query json
+        Option<PairwiseSearchOptions>, // options
+        BoxedCallbackSearchHandleUsizeSend),
+    FetchPairwise(
+        SearchHandle,
+        usize, // count
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    ClosePairwiseSearch(
+        SearchHandle,
+        Box<dyn Fn(IndyResult<()>) + Send>),
+}
+
+struct PairwiseSearch {
+    wallet_handle: WalletHandle,
+    search: WalletSearch,
+    options: PairwiseSearchOptions,
 }
 
 pub struct PairwiseCommandExecutor {
-    wallet_service: Rc<WalletService>
+    wallet_service: Rc<WalletService>,
+    searches: RefCell<HashMap<SearchHandle, PairwiseSearch>>,
 }
 
 impl PairwiseCommandExecutor {
     pub fn new(wallet_service: Rc<WalletService>) -> PairwiseCommandExecutor {
         PairwiseCommandExecutor {
-            wallet_service
+            wallet_service,
+            searches: RefCell::new(HashMap::new()),
         }
     }
 
@@ -67,6 +125,46 @@ impl PairwiseCommandExecutor {
                 debug!(target: "pairwise_command_executor", "SetPairwiseMetadata command received");
                 cb(self.set_pairwise_metadata(wallet_handle, &their_did, metadata.as_ref().map(String::as_str)));
             }
+            PairwiseCommand::UpdatePairwise(wallet_handle, their_did, new_their_did, cb) => {
+                debug!(target: "pairwise_command_executor", "UpdatePairwise command received");
+                cb(self.update_pairwise(wallet_handle, &their_did, &new_their_did));
+            }
+            PairwiseCommand::DeletePairwise(wallet_handle, their_did, cb) => {
+                debug!(target: "pairwise_command_executor", "DeletePairwise command received");
+                cb(self.delete_pairwise(wallet_handle, &their_did));
+            }
+            PairwiseCommand::LinkPairwiseRecord(wallet_handle, their_did, link, cb) => {
+                debug!(target: "pairwise_command_executor", "LinkPairwiseRecord command received");
+                cb(self.link_pairwise_record(wallet_handle, &their_did, &link));
+            }
+            PairwiseCommand::UnlinkPairwiseRecord(wallet_handle, their_did, link, cb) => {
+                debug!(target: "pairwise_command_executor", "UnlinkPairwiseRecord command received");
+                cb(self.unlink_pairwise_record(wallet_handle, &their_did, &link));
+            }
+            PairwiseCommand::GetPairwiseLinks(wallet_handle, their_did, type_, cb) => {
+                debug!(target: "pairwise_command_executor", "GetPairwiseLinks command received");
+                cb(self.get_pairwise_links(wallet_handle, &their_did, type_.as_deref()));
+            }
+            PairwiseCommand::SetPairwiseMeta(wallet_handle, their_did, meta, cb) => {
+                debug!(target: "pairwise_command_executor", "SetPairwiseMeta command received");
+                cb(self.set_pairwise_meta(wallet_handle, &their_did, meta));
+            }
+            PairwiseCommand::MigratePairwiseMeta(wallet_handle, cb) => {
+                debug!(target: "pairwise_command_executor", "MigratePairwiseMeta command received");
+                cb(self.migrate_pairwise_meta(wallet_handle));
+            }
+            PairwiseCommand::OpenPairwiseSearch(wallet_handle, query_json, options, cb) => {
+                debug!(target: "pairwise_command_executor", "OpenPairwiseSearch command received");
+                cb(self.open_pairwise_search(wallet_handle, query_json.as_deref(), options.unwrap_or_default()));
+            }
+            PairwiseCommand::FetchPairwise(search_handle, count, cb) => {
+                debug!(target: "pairwise_command_executor", "FetchPairwise command received");
+                cb(self.fetch_pairwise(search_handle, count));
+            }
+            PairwiseCommand::ClosePairwiseSearch(search_handle, cb) => {
+                debug!(target: "pairwise_command_executor", "ClosePairwiseSearch command received");
+                cb(self.close_pairwise_search(search_handle));
+            }
         };
     }
 
@@ -98,7 +196,12 @@ impl PairwiseCommandExecutor {
             metadata: metadata.map(str::to_string)
         };
 
-        self.wallet_service.add_indy_object(wallet_handle, &their_did.0, &pairwise, &HashMap::new())?;
+        let meta = PairwiseMeta {
+            created_at: Some(SystemTime::now().duration_since(UNIX_EPOCH).expect("Time has gone backwards").as_secs()),
+            ..PairwiseMeta::default()
+        };
+
+        self.wallet_service.add_indy_object(wallet_handle, &their_did.0, &pairwise, &pairwise.tags(&meta))?;
 
         debug!("create_pairwise <<<");
 
@@ -155,15 +258,323 @@ impl PairwiseCommandExecutor {
                              metadata: Option<&str>) -> IndyResult<()> {
         debug!("set_pairwise_metadata >>> wallet_handle: {:?}, their_did: {:?}, metadata: {:?}", wallet_handle, their_did, metadata);
 
-        let mut pairwise: Pairwise =
-            self.wallet_service.get_indy_object(wallet_handle, &their_did.0, &RecordOptions::id_value())?;
+        let (mut pairwise, meta) = self._get_pairwise_with_meta(wallet_handle, their_did)?;
 
         pairwise.metadata = metadata.map(str::to_string);
 
         self.wallet_service.update_indy_object(wallet_handle, &their_did.0, &pairwise)?;
+        self.wallet_service.update_record_tags(wallet_handle, &self.wallet_service.add_prefix("Pairwise"), &their_did.0, &pairwise.tags(&meta))?;
 
         debug!("set_pairwise_metadata <<<");
 
         Ok(())
     }
+
+    fn update_pairwise(&self,
+                       wallet_handle: WalletHandle,
+                       their_did: &DidValue,
+                       new_their_did: &DidValue) -> IndyResult<()> {
+        debug!("update_pairwise >>> wallet_handle: {:?}, their_did: {:?}, new_their_did: {:?}", wallet_handle, their_did, new_their_did);
+
+        let (mut pairwise, meta) = self._get_pairwise_with_meta(wallet_handle, their_did)?;
+
+        self.wallet_service.get_indy_record::<TheirDid>(wallet_handle, &new_their_did.0, &RecordOptions::id())?;
+
+        if self.wallet_service.record_exists::<Pairwise>(wallet_handle, &new_their_did.0)? {
+            return Err(err_msg(IndyErrorKind::WalletItemAlreadyExists, format!("Pairwise for {} already exists in the wallet", new_their_did.0)));
+        }
+
+        pairwise.their_did = new_their_did.clone();
+
+        // Pairwise record is identified by their DID, so it is re-created with the same tags
+        self.wallet_service.add_indy_object(wallet_handle, &new_their_did.0, &pairwise, &pairwise.tags(&meta))?;
+        self.wallet_service.delete_indy_record::<Pairwise>(wallet_handle, &their_did.0)?;
+
+        for link in self._get_pairwise_links(wallet_handle, their_did, None)? {
+            self._delete_pairwise_link(wallet_handle, their_did, &link)?;
+            self._add_pairwise_link(wallet_handle, new_their_did, &link)?;
+        }
+
+        debug!("update_pairwise <<<");
+
+        Ok(())
+    }
+
+    fn delete_pairwise(&self,
+                       wallet_handle: WalletHandle,
+                       their_did: &DidValue) -> IndyResult<()> {
+        debug!("delete_pairwise >>> wallet_handle: {:?}, their_did: {:?}", wallet_handle, their_did);
+
+        self.wallet_service.delete_indy_record::<Pairwise>(wallet_handle, &their_did.0)?;
+
+        for link in self._get_pairwise_links(wallet_handle, their_did, None)? {
+            self._delete_pairwise_link(wallet_handle, their_did, &link)?;
+        }
+
+        debug!("delete_pairwise <<<");
+
+        Ok(())
+    }
+
+    fn link_pairwise_record(&self,
+                            wallet_handle: WalletHandle,
+                            their_did: &DidValue,
+                            link: &PairwiseLink) -> IndyResult<()> {
+        debug!("link_pairwise_record >>> wallet_handle: {:?}, their_did: {:?}, link: {:?}", wallet_handle, their_did, link);
+
+        self.wallet_service.get_indy_record::<Pairwise>(wallet_handle, &their_did.0, &RecordOptions::id())?;
+
+        if !self.wallet_service.record_exists::<PairwiseLink>(wallet_handle, &link.link_id(their_did))? {
+            self._add_pairwise_link(wallet_handle, their_did, link)?;
+        }
+
+        debug!("link_pairwise_record <<<");
+
+        Ok(())
+    }
+
+    fn unlink_pairwise_record(&self,
+                              wallet_handle: WalletHandle,
+                              their_did: &DidValue,
+                              link: &PairwiseLink) -> IndyResult<()> {
+        debug!("unlink_pairwise_record >>> wallet_handle: {:?}, their_did: {:?}, link: {:?}", wallet_handle, their_did, link);
+
+        self.wallet_service.get_indy_record::<PairwiseLink>(wallet_handle, &link.link_id(their_did), &RecordOptions::id())?;
+
+        self._delete_pairwise_link(wallet_handle, their_did, link)?;
+
+        debug!("unlink_pairwise_record <<<");
+
+        Ok(())
+    }
+
+    fn get_pairwise_links(&self,
+                          wallet_handle: WalletHandle,
+                          their_did: &DidValue,
+                          type_: Option<&str>) -> IndyResult<String> {
+        debug!("get_pairwise_links >>> wallet_handle: {:?}, their_did: {:?}, type_: {:?}", wallet_handle, their_did, type_);
+
+        self.wallet_service.get_indy_record::<Pairwise>(wallet_handle, &their_did.0, &RecordOptions::id())?;
+
+        let links = self._get_pairwise_links(wallet_handle, their_did, type_)?;
+
+        let res = serde_json::to_string(&links)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize pairwise links")?;
+
+        debug!("get_pairwise_links <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn _get_pairwise_links(&self, wallet_handle: WalletHandle, their_did: &DidValue, type_: Option<&str>) -> IndyResult<Vec<PairwiseLink>> {
+        let query = match type_ {
+            Some(type_) => json!({"their_did": their_did.0, "type": type_}),
+            None => json!({"their_did": their_did.0}),
+        };
+
+        let mut link_search =
+            self.wallet_service.search_indy_records::<PairwiseLink>(wallet_handle, &query.to_string(), &RecordOptions::id_value())?;
+
+        let mut links = Vec::new();
+
+        while let Some(link_record) = link_search.fetch_next_record()? {
+            let link: PairwiseLink = link_record.get_value()
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "No value for PairwiseLink record"))
+                .and_then(|link_json| serde_json::from_str(link_json)
+                    .to_indy(IndyErrorKind::InvalidState, format!("Cannot deserialize PairwiseLink: {:?}", link_record.get_id())))?;
+
+            links.push(link);
+        }
+
+        Ok(links)
+    }
+
+    fn _add_pairwise_link(&self, wallet_handle: WalletHandle, their_did: &DidValue, link: &PairwiseLink) -> IndyResult<()> {
+        let mut tags = Tags::new();
+        tags.insert(PairwiseLink::tag_name(their_did), "1".to_string());
+
+        // Fails with WalletItemNotFound if linked record doesn't exist
+        self.wallet_service.add_record_tags(wallet_handle, &link.type_, &link.id, &tags)?;
+        self.wallet_service.add_indy_object(wallet_handle, &link.link_id(their_did), link, &link.tags(their_did))?;
+
+        Ok(())
+    }
+
+    fn _delete_pairwise_link(&self, wallet_handle: WalletHandle, their_did: &DidValue, link: &PairwiseLink) -> IndyResult<()> {
+        // Linked record can be already deleted
+        match self.wallet_service.delete_record_tags(wallet_handle, &link.type_, &link.id, &[&PairwiseLink::tag_name(their_did)]) {
+            Err(ref err) if err.kind() == IndyErrorKind::WalletItemNotFound => {}
+            res => res?
+        }
+
+        self.wallet_service.delete_indy_record::<PairwiseLink>(wallet_handle, &link.link_id(their_did))
+    }
+
+    fn set_pairwise_meta(&self,
+                         wallet_handle: WalletHandle,
+                         their_did: &DidValue,
+                         update: PairwiseMetaUpdate) -> IndyResult<()> {
+        debug!("set_pairwise_meta >>> wallet_handle: {:?}, their_did: {:?}, update: {:?}", wallet_handle, their_did, update);
+
+        let (pairwise, mut meta) = self._get_pairwise_with_meta(wallet_handle, their_did)?;
+
+        if update.label.is_some() {
+            meta.label = update.label;
+        }
+
+        if update.state.is_some() {
+            meta.state = update.state;
+        }
+
+        self.wallet_service.update_record_tags(wallet_handle, &self.wallet_service.add_prefix("Pairwise"), &their_did.0, &pairwise.tags(&meta))?;
+
+        debug!("set_pairwise_meta <<<");
+
+        Ok(())
+    }
+
+    fn migrate_pairwise_meta(&self,
+                             wallet_handle: WalletHandle) -> IndyResult<String> {
+        debug!("migrate_pairwise_meta >>> wallet_handle: {:?}", wallet_handle);
+
+        let mut pairwise_search =
+            self.wallet_service.search_indy_records::<Pairwise>(wallet_handle, "{}", &json!({"retrieveTags": true}).to_string())?;
+
+        let mut report = PairwiseMetaMigrationReport { total: 0, migrated: 0 };
+
+        while let Some(pairwise_record) = pairwise_search.fetch_next_record()? {
+            let pairwise = Self::_deserialize_pairwise(&pairwise_record)?;
+            let tags = pairwise_record.get_tags().cloned().unwrap_or_default();
+
+            // Label and state of pairwise without structured meta are kept in metadata only
+            let mut meta = PairwiseMeta::from_tags(Some(&tags));
+
+            if !meta.is_structured() {
+                let legacy_meta = pairwise.legacy_meta();
+                meta.label = legacy_meta.label;
+                meta.state = legacy_meta.state;
+            }
+
+            let migrated_tags = pairwise.tags(&meta);
+
+            if migrated_tags != tags {
+                self.wallet_service.update_record_tags(wallet_handle, &self.wallet_service.add_prefix("Pairwise"), pairwise_record.get_id(), &migrated_tags)?;
+                report.migrated += 1;
+            }
+
+            report.total += 1;
+        }
+
+        let res = serde_json::to_string(&report)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize pairwise meta migration report")?;
+
+        debug!("migrate_pairwise_meta <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn open_pairwise_search(&self,
+                            wallet_handle: WalletHandle,
+                            query_json: Option<&str>,
+                            options: PairwiseSearchOptions) -> IndyResult<(SearchHandle, usize)> {
+        debug!("open_pairwise_search >>> wallet_handle: {:?}, query_json: {:?}, options: {:?}", wallet_handle, query_json, options);
+
+        let search =
+            self.wallet_service.search_indy_records::<Pairwise>(wallet_handle, query_json.unwrap_or("{}"),
+                                                                 &json!({"retrieveTotalCount": true, "retrieveTags": true}).to_string())?;
+
+        let total_count = search.get_total_count()?.unwrap_or(0);
+
+        let handle: SearchHandle = next_search_handle();
+
+        self.searches.borrow_mut().insert(handle, PairwiseSearch { wallet_handle, search, options });
+        handles::opened(handles::PAIRWISE_SEARCH, handle.0, None);
+
+        let res = (handle, total_count);
+
+        debug!("open_pairwise_search <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn fetch_pairwise(&self,
+                      search_handle: SearchHandle,
+                      count: usize) -> IndyResult<String> {
+        trace!("fetch_pairwise >>> search_handle: {:?}, count: {:?}", search_handle, count);
+
+        let mut searches = self.searches.borrow_mut();
+        let search = searches.get_mut(&search_handle)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidWalletHandle, format!("Unknown PairwiseSearch handle: {:?}", search_handle)))?;
+
+        let mut list_pairwise: Vec<PairwiseWithVerkeys> = Vec::new();
+
+        for _ in 0..count {
+            match search.search.fetch_next_record()? {
+                Some(pairwise_record) => {
+                    let pairwise = Self::_deserialize_pairwise(&pairwise_record)?;
+                    let meta = PairwiseMeta::from_tags(pairwise_record.get_tags());
+
+                    list_pairwise.push(self._get_pairwise_with_verkeys(search.wallet_handle, pairwise, meta, &search.options)?);
+                }
+                None => break
+            }
+        }
+
+        let res = serde_json::to_string(&list_pairwise)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize pairwise list")?;
+
+        trace!("fetch_pairwise <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn close_pairwise_search(&self, search_handle: SearchHandle) -> IndyResult<()> {
+        trace!("close_pairwise_search >>> search_handle: {:?}", search_handle);
+
+        match self.searches.borrow_mut().remove(&search_handle) {
+            Some(_) => Ok(()),
+            None => Err(err_msg(IndyErrorKind::InvalidWalletHandle, format!("Unknown PairwiseSearch handle: {:?}", search_handle)))
+        }?;
+        handles::closed(handles::PAIRWISE_SEARCH, search_handle.0);
+
+        trace!("close_pairwise_search <<< res: ()");
+
+        Ok(())
+    }
+
+    pub fn close_all_searches(&self) {
+        self.searches.borrow_mut().clear();
+        handles::closed_all(handles::PAIRWISE_SEARCH);
+    }
+
+    fn _get_pairwise_with_meta(&self, wallet_handle: WalletHandle, their_did: &DidValue) -> IndyResult<(Pairwise, PairwiseMeta)> {
+        let pairwise_record =
+            self.wallet_service.get_indy_record::<Pairwise>(wallet_handle, &their_did.0, &json!({"retrieveValue": true, "retrieveTags": true}).to_string())?;
+
+        Ok((Self::_deserialize_pairwise(&pairwise_record)?, PairwiseMeta::from_tags(pairwise_record.get_tags())))
+    }
+
+    fn _deserialize_pairwise(pairwise_record: &WalletRecord) -> IndyResult<Pairwise> {
+        pairwise_record.get_value()
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, format!("Pairwise not found for id: {}", pairwise_record.get_id())))
+            .and_then(|pairwise_json| serde_json::from_str(pairwise_json)
+                .to_indy(IndyErrorKind::InvalidState, format!("Cannot deserialize Pairwise: {:?}", pairwise_record.get_id())))
+    }
+
+    fn _get_pairwise_with_verkeys(&self, wallet_handle: WalletHandle, pairwise: Pairwise, meta: PairwiseMeta, options: &PairwiseSearchOptions) -> IndyResult<PairwiseWithVerkeys> {
+        let (my_verkey, their_verkey) = if options.retrieve_verkeys {
+            let my_did = self.wallet_service.get_indy_opt_object::<Did>(wallet_handle, &pairwise.my_did.0, &RecordOptions::id_value())?;
+            let their_did = self.wallet_service.get_indy_opt_object::<TheirDid>(wallet_handle, &pairwise.their_did.0, &RecordOptions::id_value())?;
+            (my_did.map(|did| did.verkey), their_did.map(|did| did.verkey))
+        } else { (None, None) };
+
+        Ok(PairwiseWithVerkeys {
+            my_did: pairwise.my_did,
+            their_did: pairwise.their_did,
+            metadata: pairwise.metadata,
+            my_verkey,
+            their_verkey,
+            meta: Some(meta).filter(PairwiseMeta::is_structured),
+        })
+    }
 }
@@ -11,20 +11,36 @@ use serde_json;
 use indy_api_types::errors::prelude::*;
 use crate::services::crypto::CryptoService;
 use crate::services::ledger::LedgerService;
-use crate::services::payments::{PaymentsMethodCBs, PaymentsService, RequesterInfo, Fees};
+use crate::services::payments::{PaymentsMethodCBs, PaymentsService, RequesterInfo, Fees, AddressBalance, PaymentBalances};
+use crate::api::payments::{BuildGetPaymentHistoryRequestCB, ParseGetPaymentHistoryResponseCB, ParseVerifyPaymentResponseWithProofCB};
 use indy_wallet::{RecordOptions, WalletService};
-use indy_api_types::{WalletHandle, CommandHandle};
+use indy_api_types::{WalletHandle, CommandHandle, PoolHandle};
 use crate::domain::ledger::auth_rule::AuthRule;
 use crate::domain::crypto::did::DidValue;
 
 use indy_utils::next_command_handle;
-use crate::commands::BoxedCallbackStringStringSend;
+use crate::commands::{BoxedCallbackStringStringSend, Command, CommandExecutor};
+use crate::commands::ledger::LedgerCommand;
+
+type PaymentHistoryCallback = Box<dyn Fn(IndyResult<(String, i64)>) + Send>;
 
 pub enum PaymentsCommand {
     RegisterMethod(
         String, //type
         PaymentsMethodCBs, //method callbacks
         Box<dyn Fn(IndyResult<()>) + Send>),
+    RegisterReceiptProofParser(
+        String, //type
+        ParseVerifyPaymentResponseWithProofCB, //parser
+        Box<dyn Fn(IndyResult<()>) + Send>),
+    RegisterHistoryHandlers(
+        String, //type
+        BuildGetPaymentHistoryRequestCB, //request builder
+        ParseGetPaymentHistoryResponseCB, //response parser
+        Box<dyn Fn(IndyResult<()>) + Send>),
+    UnregisterMethod(
+        String, //type
+        Box<dyn Fn(IndyResult<()>) + Send>),
     CreateAddress(
         WalletHandle,
         String, //type
@@ -48,6 +64,28 @@ pub enum PaymentsCommand {
     AddRequestFeesAck(
         CommandHandle, //handle
         IndyResult<String>),
+    PrepareRequestWithFees(
+        WalletHandle,
+        PoolHandle,
+        DidValue, //submitter did
+        String, //req
+        String, //inputs
+        String, //outputs
+        Option<String>, //extra
+        Option<TaaAcceptance>, //taa acceptance
+        BoxedCallbackStringStringSend),
+    PrepareRequestWithFeesSubmitGetFees(
+        CommandHandle, //preparation handle
+        IndyResult<String>), //get txn fees request
+    PrepareRequestWithFeesParseFees(
+        CommandHandle, //preparation handle
+        IndyResult<String>), //get txn fees response
+    PrepareRequestWithFeesAddFees(
+        CommandHandle, //preparation handle
+        IndyResult<String>), //fees
+    PrepareRequestWithFeesSign(
+        CommandHandle, //preparation handle
+        IndyResult<String>), //req with fees
     ParseResponseWithFees(
         String, //type
         String, //response
@@ -71,6 +109,22 @@ pub enum PaymentsCommand {
     ParseGetPaymentSourcesResponseAck(
         CommandHandle,
         IndyResult<(String, i64)>),
+    BuildGetPaymentHistoryRequest(
+        WalletHandle,
+        Option<DidValue>, //submitter did
+        String, //payment address
+        Option<i64>, //from
+        BoxedCallbackStringStringSend),
+    BuildGetPaymentHistoryRequestAck(
+        CommandHandle,
+        IndyResult<String>),
+    ParseGetPaymentHistoryResponse(
+        String, //type
+        String, //response
+        PaymentHistoryCallback),
+    ParseGetPaymentHistoryResponseAck(
+        CommandHandle,
+        IndyResult<String>),
     BuildPaymentReq(
         WalletHandle,
         Option<DidValue>, //submitter did
@@ -164,7 +218,80 @@ pub enum PaymentsCommand {
         Box<dyn Fn(IndyResult<bool>) + Send>),
     VerifyWithAddressAck(
         CommandHandle,
-        IndyResult<bool>)
+        IndyResult<bool>),
+    EstimateRequestFees(
+        WalletHandle,
+        Option<DidValue>, //submitter_did
+        String, //req
+        String, //inputs
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    EstimateRequestFeesAck(
+        CommandHandle,
+        IndyResult<String>),
+    VerifyPaymentReceipts(
+        WalletHandle,
+        Option<DidValue>, //submitter_did
+        String, //receipts
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    VerifyPaymentReceiptsAck(
+        CommandHandle,
+        IndyResult<String>),
+    VerifyPaymentReceiptWithProof(
+        String, //receipt
+        String, //response
+        HashMap<String, String>, //bls keys
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    VerifyPaymentReceiptWithProofAck(
+        CommandHandle,
+        IndyResult<String>),
+    GetPaymentBalances(
+        WalletHandle,
+        PoolHandle,
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    GetPaymentBalancesSubmit(
+        CommandHandle,
+        usize, //address index
+        IndyResult<String /* get sources request */>),
+    GetPaymentBalancesParse(
+        CommandHandle,
+        usize, //address index
+        IndyResult<String /* get sources response */>),
+    GetPaymentBalancesCollect(
+        CommandHandle,
+        usize, //address index
+        IndyResult<(String /* sources */, i64 /* next */)>)
+}
+
+/// TAA acceptance data appended to the request by `PrepareRequestWithFees`.
+#[derive(Debug)]
+pub struct TaaAcceptance {
+    pub text: Option<String>,
+    pub version: Option<String>,
+    pub taa_digest: Option<String>,
+    pub mechanism: String,
+    pub time: u64,
+}
+
+struct FeesPreparation {
+    wallet_handle: WalletHandle,
+    pool_handle: PoolHandle,
+    submitter_did: DidValue,
+    req: String,
+    inputs: String,
+    outputs: String,
+    extra: Option<String>,
+    method: String,
+    txn_type: String,
+    receipt: Option<String>,
+    cb: BoxedCallbackStringStringSend,
+}
+
+struct BalancesAggregation {
+    wallet_handle: WalletHandle,
+    pool_handle: PoolHandle,
+    addresses: Vec<AddressBalance>,
+    pending: usize,
+    cb: Box<dyn Fn(IndyResult<String>) + Send>,
 }
 
 pub struct PaymentsCommandExecutor {
@@ -176,6 +303,8 @@ pub struct PaymentsCommandExecutor {
     pending_callbacks_str_i64: RefCell<HashMap<i32, Box<dyn Fn(IndyResult<(String, i64)>) + Send>>>,
     pending_array_callbacks: RefCell<HashMap<i32, Box<dyn Fn(IndyResult<Vec<u8>>) + Send>>>,
     pending_bool_callbacks: RefCell<HashMap<i32, Box<dyn Fn(IndyResult<bool>) + Send>>>,
+    pending_fees_preparations: RefCell<HashMap<CommandHandle, FeesPreparation>>,
+    pending_balances_aggregations: RefCell<HashMap<CommandHandle, BalancesAggregation>>,
 }
 
 impl PaymentsCommandExecutor {
@@ -188,7 +317,9 @@ impl PaymentsCommandExecutor {
             pending_callbacks_str: RefCell::new(HashMap::new()),
             pending_callbacks_str_i64: RefCell::new(HashMap::new()),
             pending_array_callbacks: RefCell::new(HashMap::new()),
-            pending_bool_callbacks: RefCell::new(HashMap::new())
+            pending_bool_callbacks: RefCell::new(HashMap::new()),
+            pending_fees_preparations: RefCell::new(HashMap::new()),
+            pending_balances_aggregations: RefCell::new(HashMap::new()),
         }
     }
 
@@ -198,6 +329,18 @@ impl PaymentsCommandExecutor {
                 debug!(target: "payments_command_executor", "RegisterMethod command received");
                 cb(self.register_method(&type_, method_cbs));
             }
+            PaymentsCommand::RegisterReceiptProofParser(type_, parser, cb) => {
+                debug!(target: "payments_command_executor", "RegisterReceiptProofParser command received");
+                cb(self.register_receipt_proof_parser(&type_, parser));
+            }
+            PaymentsCommand::RegisterHistoryHandlers(type_, build_request, parse_response, cb) => {
+                debug!(target: "payments_command_executor", "RegisterHistoryHandlers command received");
+                cb(self.register_history_handlers(&type_, build_request, parse_response));
+            }
+            PaymentsCommand::UnregisterMethod(type_, cb) => {
+                debug!(target: "payments_command_executor", "UnregisterMethod command received");
+                cb(self.unregister_method(&type_));
+            }
             PaymentsCommand::CreateAddress(wallet_handle, type_, config, cb) => {
                 debug!(target: "payments_command_executor", "CreateAddress command received");
                 self.create_address(wallet_handle, &type_, &config, cb);
@@ -218,6 +361,26 @@ impl PaymentsCommandExecutor {
                 debug!(target: "payments_command_executor", "AddRequestFeesAck command received");
                 self.add_request_fees_ack(cmd_handle, result);
             }
+            PaymentsCommand::PrepareRequestWithFees(wallet_handle, pool_handle, submitter_did, req, inputs, outputs, extra, taa_acceptance, cb) => {
+                debug!(target: "payments_command_executor", "PrepareRequestWithFees command received");
+                self.prepare_request_with_fees(wallet_handle, pool_handle, submitter_did, &req, inputs, outputs, extra, taa_acceptance, cb);
+            }
+            PaymentsCommand::PrepareRequestWithFeesSubmitGetFees(handle, result) => {
+                debug!(target: "payments_command_executor", "PrepareRequestWithFeesSubmitGetFees command received");
+                self._prepare_request_with_fees_submit_get_fees(handle, result);
+            }
+            PaymentsCommand::PrepareRequestWithFeesParseFees(handle, result) => {
+                debug!(target: "payments_command_executor", "PrepareRequestWithFeesParseFees command received");
+                self._prepare_request_with_fees_parse_fees(handle, result);
+            }
+            PaymentsCommand::PrepareRequestWithFeesAddFees(handle, result) => {
+                debug!(target: "payments_command_executor", "PrepareRequestWithFeesAddFees command received");
+                self._prepare_request_with_fees_add_fees(handle, result);
+            }
+            PaymentsCommand::PrepareRequestWithFeesSign(handle, result) => {
+                debug!(target: "payments_command_executor", "PrepareRequestWithFeesSign command received");
+                self._prepare_request_with_fees_sign(handle, result);
+            }
             PaymentsCommand::ParseResponseWithFees(type_, response, cb) => {
                 debug!(target: "payments_command_executor", "ParseResponseWithFees command received");
                 self.parse_response_with_fees(&type_, &response, cb);
@@ -242,6 +405,22 @@ impl PaymentsCommandExecutor {
                 debug!(target: "payments_command_executor", "ParseGetPaymentSourcesResponseAck command received");
                 self.parse_get_payment_sources_response_ack(cmd_handle, result);
             }
+            PaymentsCommand::BuildGetPaymentHistoryRequest(wallet_handle, submitter_did, payment_address, from, cb) => {
+                debug!(target: "payments_command_executor", "BuildGetPaymentHistoryRequest command received");
+                self.build_get_payment_history_request(wallet_handle, submitter_did.as_ref(), &payment_address, from, cb);
+            }
+            PaymentsCommand::BuildGetPaymentHistoryRequestAck(cmd_handle, result) => {
+                debug!(target: "payments_command_executor", "BuildGetPaymentHistoryRequestAck command received");
+                self.build_get_payment_history_request_ack(cmd_handle, result);
+            }
+            PaymentsCommand::ParseGetPaymentHistoryResponse(type_, response, cb) => {
+                debug!(target: "payments_command_executor", "ParseGetPaymentHistoryResponse command received");
+                self.parse_get_payment_history_response(&type_, &response, cb);
+            }
+            PaymentsCommand::ParseGetPaymentHistoryResponseAck(cmd_handle, result) => {
+                debug!(target: "payments_command_executor", "ParseGetPaymentHistoryResponseAck command received");
+                self.parse_get_payment_history_response_ack(cmd_handle, result);
+            }
             PaymentsCommand::BuildPaymentReq(wallet_handle, submitter_did, inputs, outputs, extra, cb) => {
                 debug!(target: "payments_command_executor", "BuildPaymentReq command received");
                 self.build_payment_req(wallet_handle, submitter_did.as_ref(), &inputs, &outputs, extra.as_ref().map(String::as_str), cb);
@@ -335,6 +514,46 @@ impl PaymentsCommandExecutor {
                  debug!(target: "payments_command_executor", "VerifyWithAddressAck command received");
                 self.verify_with_address_ack(command_handle, result);
             }
+            PaymentsCommand::EstimateRequestFees(wallet_handle, submitter_did, req, inputs, cb) => {
+                debug!(target: "payments_command_executor", "EstimateRequestFees command received");
+                self.estimate_request_fees(wallet_handle, submitter_did.as_ref(), &req, &inputs, cb);
+            }
+            PaymentsCommand::EstimateRequestFeesAck(command_handle, result) => {
+                debug!(target: "payments_command_executor", "EstimateRequestFeesAck command received");
+                self.estimate_request_fees_ack(command_handle, result);
+            }
+            PaymentsCommand::VerifyPaymentReceipts(wallet_handle, submitter_did, receipts, cb) => {
+                debug!(target: "payments_command_executor", "VerifyPaymentReceipts command received");
+                self.verify_payment_receipts(wallet_handle, submitter_did.as_ref(), &receipts, cb);
+            }
+            PaymentsCommand::VerifyPaymentReceiptsAck(command_handle, result) => {
+                debug!(target: "payments_command_executor", "VerifyPaymentReceiptsAck command received");
+                self.verify_payment_receipts_ack(command_handle, result);
+            }
+            PaymentsCommand::VerifyPaymentReceiptWithProof(receipt, resp_json, bls_keys, cb) => {
+                debug!(target: "payments_command_executor", "VerifyPaymentReceiptWithProof command received");
+                self.verify_payment_receipt_with_proof(&receipt, &resp_json, bls_keys, cb);
+            }
+            PaymentsCommand::VerifyPaymentReceiptWithProofAck(command_handle, result) => {
+                debug!(target: "payments_command_executor", "VerifyPaymentReceiptWithProofAck command received");
+                self.verify_payment_receipt_with_proof_ack(command_handle, result);
+            }
+            PaymentsCommand::GetPaymentBalances(wallet_handle, pool_handle, cb) => {
+                debug!(target: "payments_command_executor", "GetPaymentBalances command received");
+                self.get_payment_balances(wallet_handle, pool_handle, cb);
+            }
+            PaymentsCommand::GetPaymentBalancesSubmit(handle, index, result) => {
+                debug!(target: "payments_command_executor", "GetPaymentBalancesSubmit command received");
+                self._get_payment_balances_submit(handle, index, result);
+            }
+            PaymentsCommand::GetPaymentBalancesParse(handle, index, result) => {
+                debug!(target: "payments_command_executor", "GetPaymentBalancesParse command received");
+                self._get_payment_balances_parse(handle, index, result);
+            }
+            PaymentsCommand::GetPaymentBalancesCollect(handle, index, result) => {
+                debug!(target: "payments_command_executor", "GetPaymentBalancesCollect command received");
+                self._get_payment_balances_collect(handle, index, result);
+            }
         }
     }
 
@@ -349,6 +568,36 @@ impl PaymentsCommandExecutor {
         res
     }
 
+    fn register_receipt_proof_parser(&self, type_: &str, parser: ParseVerifyPaymentResponseWithProofCB) -> IndyResult<()> {
+        trace!("register_receipt_proof_parser >>> type_: {:?}", type_);
+
+        let res = self.payments_service.register_receipt_proof_parser(type_, parser);
+
+        trace!("register_receipt_proof_parser << res: {:?}", res);
+
+        res
+    }
+
+    fn register_history_handlers(&self, type_: &str, build_request: BuildGetPaymentHistoryRequestCB, parse_response: ParseGetPaymentHistoryResponseCB) -> IndyResult<()> {
+        trace!("register_history_handlers >>> type_: {:?}", type_);
+
+        let res = self.payments_service.register_history_handlers(type_, build_request, parse_response);
+
+        trace!("register_history_handlers << res: {:?}", res);
+
+        res
+    }
+
+    fn unregister_method(&self, type_: &str) -> IndyResult<()> {
+        trace!("unregister_method >>> type_: {:?}", type_);
+
+        let res = self.payments_service.unregister_payment_method(type_);
+
+        trace!("unregister_method << res: {:?}", res);
+
+        res
+    }
+
     fn create_address(&self, wallet_handle: WalletHandle, type_: &str, config: &str, cb: Box<dyn Fn(IndyResult<String>) + Send>) {
         trace!("create_address >>> wallet_handle: {:?}, type_: {:?}, config: {:?}", wallet_handle, type_, config);
 
@@ -378,25 +627,27 @@ impl PaymentsCommandExecutor {
     fn list_addresses(&self, wallet_handle: WalletHandle, cb: Box<dyn Fn(IndyResult<String>) + Send>) {
         trace!("list_addresses >>> wallet_handle: {:?}", wallet_handle);
 
-        match self.wallet_service.search_records(wallet_handle, &self.wallet_service.add_prefix("PaymentAddress"), "{}", &RecordOptions::id_value()) {
-            Ok(mut search) => {
-                let mut list_addresses: Vec<String> = Vec::new();
+        let res = self._list_addresses(wallet_handle)
+            .and_then(|list_addresses| serde_json::to_string(&list_addresses)
+                .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize List of Payment Addresses"));
 
-                while let Ok(Some(payment_address)) = search.fetch_next_record() {
-                    match payment_address.get_value() {
-                        Some(value) => list_addresses.push(value.to_string()),
-                        None => cb(Err(err_msg(IndyErrorKind::InvalidState, "Record value not found")))
-                    }
-                }
+        cb(res);
+        trace!("list_addresses <<<");
+    }
 
-                let json_string = serde_json::to_string(&list_addresses)
-                    .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize List of Payment Addresses");
+    fn _list_addresses(&self, wallet_handle: WalletHandle) -> IndyResult<Vec<String>> {
+        let mut search = self.wallet_service.search_records(wallet_handle, &self.wallet_service.add_prefix("PaymentAddress"), "{}", &RecordOptions::id_value())?;
 
-                cb(json_string);
-            }
-            Err(err) => cb(Err(err))
+        let mut list_addresses: Vec<String> = Vec::new();
+
+        while let Some(payment_address) = search.fetch_next_record()? {
+            let value = payment_address.get_value()
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "Record value not found"))?;
+
+            list_addresses.push(value.to_string());
         }
-        trace!("list_addresses <<<");
+
+        Ok(list_addresses)
     }
 
     fn add_request_fees(&self, wallet_handle: WalletHandle, submitter_did: Option<&DidValue>, req: &str, inputs: &str, outputs: &str, extra: Option<&str>, cb: BoxedCallbackStringStringSend) {
@@ -439,6 +690,207 @@ impl PaymentsCommandExecutor {
         trace!("add_request_fees_ack <<<");
     }
 
+    #[allow(clippy::too_many_arguments)]
+    fn prepare_request_with_fees(&self, wallet_handle: WalletHandle, pool_handle: PoolHandle, submitter_did: DidValue, req: &str,
+                                 inputs: String, outputs: String, extra: Option<String>, taa_acceptance: Option<TaaAcceptance>,
+                                 cb: BoxedCallbackStringStringSend) {
+        trace!("prepare_request_with_fees >>> wallet_handle: {:?}, pool_handle: {:?}, submitter_did: {:?}, req: {:?}, inputs: {:?}, outputs: {:?}, extra: {:?}, taa_acceptance: {:?}",
+               wallet_handle, pool_handle, submitter_did, req, inputs, outputs, extra, taa_acceptance);
+
+        let preparation = self.crypto_service.validate_did(&submitter_did).map_err(map_err_err!())
+            .and_then(|_| {
+                let method_from_inputs = self.payments_service.parse_method_from_inputs(&inputs);
+
+                if outputs == "[]" {
+                    method_from_inputs
+                } else {
+                    let method_from_outputs = self.payments_service.parse_method_from_outputs(&outputs);
+                    PaymentsCommandExecutor::_merge_parse_result(method_from_inputs, method_from_outputs)
+                }
+            })
+            .and_then(|method| {
+                let txn_type = self.payments_service.parse_txn_type_from_request(req)?;
+                let req = match taa_acceptance {
+                    Some(taa) => self._append_txn_author_agreement_acceptance_to_request(req, &taa)?,
+                    None => req.to_string()
+                };
+                Ok((method, txn_type, req))
+            });
+
+        let (method, txn_type, req) = match preparation {
+            Ok(preparation) => preparation,
+            Err(err) => return cb(Err(err))
+        };
+
+        let handle = next_command_handle();
+
+        let get_fees_cb = Box::new(move |result| {
+            CommandExecutor::instance()
+                .send(Command::Payments(PaymentsCommand::PrepareRequestWithFeesSubmitGetFees(handle, result)))
+                .unwrap();
+        });
+
+        let res = self.payments_service.build_get_txn_fees_req(handle, &method, wallet_handle, Some(&submitter_did));
+
+        self.pending_fees_preparations.borrow_mut().insert(handle, FeesPreparation {
+            wallet_handle,
+            pool_handle,
+            submitter_did,
+            req,
+            inputs,
+            outputs,
+            extra,
+            method,
+            txn_type,
+            receipt: None,
+            cb,
+        });
+
+        match res {
+            Ok(()) => { self.pending_callbacks_str.borrow_mut().insert(handle, get_fees_cb); }
+            Err(err) => self._prepare_request_with_fees_fail(handle, err)
+        }
+
+        trace!("prepare_request_with_fees <<<");
+    }
+
+    fn _append_txn_author_agreement_acceptance_to_request(&self, req: &str, taa: &TaaAcceptance) -> IndyResult<String> {
+        let mut request: serde_json::Value = serde_json::from_str(req)
+            .map_err(|err| IndyError::from_msg(IndyErrorKind::InvalidStructure, format!("Cannot deserialize request: {:?}", err)))?;
+
+        let acceptance_data = self.ledger_service.prepare_acceptance_data(taa.text.as_deref(), taa.version.as_deref(), taa.taa_digest.as_deref(),
+                                                                          &taa.mechanism, taa.time)?;
+
+        request["taaAcceptance"] = serde_json::to_value(acceptance_data)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize author agreement acceptance data")?;
+
+        Ok(request.to_string())
+    }
+
+    fn _prepare_request_with_fees_submit_get_fees(&self, handle: CommandHandle, result: IndyResult<String>) {
+        trace!("_prepare_request_with_fees_submit_get_fees >>> handle: {:?}, result: {:?}", handle, result);
+
+        let res = result.and_then(|get_fees_req| {
+            let (pool_handle, _) = self._fees_preparation(handle)?;
+
+            CommandExecutor::instance()
+                .send(Command::Ledger(LedgerCommand::SubmitRequest(
+                    pool_handle,
+                    get_fees_req,
+                    Box::new(move |response| {
+                        CommandExecutor::instance()
+                            .send(Command::Payments(PaymentsCommand::PrepareRequestWithFeesParseFees(handle, response)))
+                            .unwrap();
+                    }))))
+        });
+
+        if let Err(err) = res {
+            self._prepare_request_with_fees_fail(handle, err);
+        }
+
+        trace!("_prepare_request_with_fees_submit_get_fees <<<");
+    }
+
+    fn _prepare_request_with_fees_parse_fees(&self, handle: CommandHandle, result: IndyResult<String>) {
+        trace!("_prepare_request_with_fees_parse_fees >>> handle: {:?}, result: {:?}", handle, result);
+
+        let res = result.and_then(|get_fees_response| {
+            let (_, method) = self._fees_preparation(handle)?;
+
+            self.payments_service.parse_get_txn_fees_response(handle, &method, &get_fees_response)?;
+
+            self.pending_callbacks_str.borrow_mut().insert(handle, Box::new(move |result| {
+                CommandExecutor::instance()
+                    .send(Command::Payments(PaymentsCommand::PrepareRequestWithFeesAddFees(handle, result)))
+                    .unwrap();
+            }));
+
+            Ok(())
+        });
+
+        if let Err(err) = res {
+            self._prepare_request_with_fees_fail(handle, err);
+        }
+
+        trace!("_prepare_request_with_fees_parse_fees <<<");
+    }
+
+    fn _prepare_request_with_fees_add_fees(&self, handle: CommandHandle, result: IndyResult<String>) {
+        trace!("_prepare_request_with_fees_add_fees >>> handle: {:?}, result: {:?}", handle, result);
+
+        let res = result.and_then(|fees| {
+            let mut pending_fees_preparations = self.pending_fees_preparations.borrow_mut();
+            let preparation = pending_fees_preparations.get_mut(&handle)
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "Fees preparation not found"))?;
+
+            let receipt = self.payments_service.build_expected_fees_receipt(&preparation.method, &preparation.txn_type, &fees,
+                                                                            &preparation.inputs, &preparation.outputs)?;
+
+            preparation.receipt = Some(serde_json::to_string(&receipt)
+                .to_indy(IndyErrorKind::InvalidState, "Cannot serialize ExpectedFeesReceipt")?);
+
+            self.payments_service.add_request_fees(handle, &preparation.method, preparation.wallet_handle, Some(&preparation.submitter_did),
+                                                   &preparation.req, &preparation.inputs, &preparation.outputs, preparation.extra.as_deref())?;
+
+            self.pending_callbacks_str.borrow_mut().insert(handle, Box::new(move |result| {
+                CommandExecutor::instance()
+                    .send(Command::Payments(PaymentsCommand::PrepareRequestWithFeesSign(handle, result)))
+                    .unwrap();
+            }));
+
+            Ok(())
+        });
+
+        if let Err(err) = res {
+            self._prepare_request_with_fees_fail(handle, err);
+        }
+
+        trace!("_prepare_request_with_fees_add_fees <<<");
+    }
+
+    fn _prepare_request_with_fees_sign(&self, handle: CommandHandle, result: IndyResult<String>) {
+        trace!("_prepare_request_with_fees_sign >>> handle: {:?}, result: {:?}", handle, result);
+
+        let preparation = match self.pending_fees_preparations.borrow_mut().remove(&handle) {
+            Some(preparation) => preparation,
+            None => return error!("Can't process PaymentsCommand::PrepareRequestWithFeesSign for handle {} - appropriate preparation not found!", handle)
+        };
+
+        let req_with_fees = match result {
+            Ok(req_with_fees) => req_with_fees,
+            Err(err) => return (preparation.cb)(Err(err))
+        };
+
+        let receipt = preparation.receipt.unwrap_or_default();
+        let cb = preparation.cb;
+
+        let res = CommandExecutor::instance()
+            .send(Command::Ledger(LedgerCommand::MultiSignRequest(
+                preparation.wallet_handle,
+                preparation.submitter_did,
+                req_with_fees,
+                Box::new(move |signed_req| cb(signed_req.map(|signed_req| (signed_req, receipt.clone())))))));
+
+        if let Err(err) = res {
+            error!("Can't send LedgerCommand::MultiSignRequest for fees preparation {}: {:?}", handle, err);
+        }
+
+        trace!("_prepare_request_with_fees_sign <<<");
+    }
+
+    fn _fees_preparation(&self, handle: CommandHandle) -> IndyResult<(PoolHandle, String)> {
+        self.pending_fees_preparations.borrow().get(&handle)
+            .map(|preparation| (preparation.pool_handle, preparation.method.clone()))
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "Fees preparation not found"))
+    }
+
+    fn _prepare_request_with_fees_fail(&self, handle: CommandHandle, err: IndyError) {
+        match self.pending_fees_preparations.borrow_mut().remove(&handle) {
+            Some(preparation) => (preparation.cb)(Err(err)),
+            None => error!("Can't fail fees preparation for handle {} with error {:?} - appropriate preparation not found!", handle, err)
+        }
+    }
+
     fn parse_response_with_fees(&self, type_: &str, response: &str, cb: Box<dyn Fn(IndyResult<String>) + Send>) {
         trace!("parse_response_with_fees >>> type_: {:?}, response: {:?}", type_, response);
         self._process_method_str(cb, &|i| self.payments_service.parse_response_with_fees(i, type_, response));
@@ -494,6 +946,52 @@ impl PaymentsCommandExecutor {
         trace!("parse_get_payment_sources_response_ack <<<");
     }
 
+    fn build_get_payment_history_request(&self, wallet_handle: WalletHandle, submitter_did: Option<&DidValue>, payment_address: &str, from: Option<i64>, cb: BoxedCallbackStringStringSend) {
+        trace!("build_get_payment_history_request >>> wallet_handle: {:?}, submitter_did: {:?}, payment_address: {:?}, from: {:?}", wallet_handle, submitter_did, payment_address, from);
+        if let Some(did) = submitter_did {
+            if let Err(err) = self.crypto_service.validate_did(did).map_err(map_err_err!()) {
+                return cb(Err(err));
+            }
+        }
+
+        if let Err(err) = self.wallet_service.check(wallet_handle).map_err(map_err_err!()) {
+            return cb(Err(err));
+        }
+
+        let method = match self.payments_service.parse_method_from_payment_address(payment_address) {
+            Ok(method) => method,
+            Err(err) => return cb(Err(err))
+        };
+        let method_copy = method.to_string();
+
+        self._process_method_str(
+            Box::new(move |get_history_txn_json| cb(get_history_txn_json.map(|s| (s, method.to_string())))),
+            &|i| self.payments_service.build_get_payment_history_request(i, &method_copy, wallet_handle, submitter_did, payment_address, from),
+        );
+        trace!("build_get_payment_history_request <<<");
+    }
+
+    fn build_get_payment_history_request_ack(&self, cmd_handle: CommandHandle, result: IndyResult<String>) {
+        trace!("build_get_payment_history_request_ack >>> result: {:?}", result);
+        self._common_ack_payments_str(cmd_handle, result, "BuildGetPaymentHistoryRequestAck");
+        trace!("build_get_payment_history_request_ack <<<");
+    }
+
+    fn parse_get_payment_history_response(&self, type_: &str, response: &str, cb: PaymentHistoryCallback) {
+        trace!("parse_get_payment_history_response >>> type_: {:?}, response: {:?}", type_, response);
+        self._process_method_str(
+            Box::new(move |history| cb(history.and_then(|history| PaymentsService::split_payment_history(&history)))),
+            &|i| self.payments_service.parse_get_payment_history_response(i, type_, response),
+        );
+        trace!("parse_get_payment_history_response <<<");
+    }
+
+    fn parse_get_payment_history_response_ack(&self, cmd_handle: CommandHandle, result: IndyResult<String>) {
+        trace!("parse_get_payment_history_response_ack >>> result: {:?}", result);
+        self._common_ack_payments_str(cmd_handle, result, "ParseGetPaymentHistoryResponseAck");
+        trace!("parse_get_payment_history_response_ack <<<");
+    }
+
     fn build_payment_req(&self, wallet_handle: WalletHandle, submitter_did: Option<&DidValue>, inputs: &str, outputs: &str, extra: Option<&str>, cb: BoxedCallbackStringStringSend) {
         trace!("build_payment_req >>> wallet_handle: {:?}, submitter_did: {:?}, inputs: {:?}, outputs: {:?}, extra: {:?}", wallet_handle, submitter_did, inputs, outputs, extra);
         if let Some(ref did) = submitter_did {
@@ -711,6 +1209,7 @@ impl PaymentsCommandExecutor {
 
     fn sign_with_address_ack(&self, command_handle: CommandHandle, result: IndyResult<Vec<u8>>) {
         trace!("sign_with_address_ack >>> result: {:?}", result);
+        self.payments_service.complete_call(command_handle);
         match self.pending_array_callbacks.borrow_mut().remove(&command_handle) {
             Some(cb) => cb(result),
             None => error!("Can't process PaymentsCommand::SignWithAddressAck for handle {} with result {:?} - appropriate callback not found!", command_handle, result)
@@ -740,6 +1239,7 @@ impl PaymentsCommandExecutor {
 
     fn verify_with_address_ack(&self, command_handle: CommandHandle, result: IndyResult<bool>) {
         trace!("verify_with_address_ack >>> result: {:?}", result);
+        self.payments_service.complete_call(command_handle);
         match self.pending_bool_callbacks.borrow_mut().remove(&command_handle) {
             Some(cb) => cb(result),
             None => error!("Can't process PaymentsCommand::VerifyWithAddressAck for handle {} with result {:?} - appropriate callback not found!", command_handle, result)
@@ -747,6 +1247,257 @@ impl PaymentsCommandExecutor {
         trace!("verify_with_address_ack <<<");
     }
 
+    fn estimate_request_fees(&self, wallet_handle: WalletHandle, submitter_did: Option<&DidValue>, req: &str, inputs: &str, cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        trace!("estimate_request_fees >>> wallet_handle: {:?}, submitter_did: {:?}, req: {:?}, inputs: {:?}", wallet_handle, submitter_did, req, inputs);
+        if let Some(did) = submitter_did {
+            if let Err(err) = self.crypto_service.validate_did(did).map_err(map_err_err!()) {
+                return cb(Err(err));
+            }
+        }
+
+        if let Err(err) = self.wallet_service.check(wallet_handle).map_err(map_err_err!()) {
+            return cb(Err(err));
+        }
+
+        let method = match self.payments_service.parse_method_from_inputs(inputs) {
+            Ok(method) => method,
+            Err(err) => return cb(Err(err))
+        };
+
+        self._process_method_str(cb, &|i| self.payments_service.estimate_request_fees(i, &method, wallet_handle, submitter_did, req, inputs));
+
+        trace!("estimate_request_fees <<<");
+    }
+
+    fn estimate_request_fees_ack(&self, cmd_handle: CommandHandle, result: IndyResult<String>) {
+        trace!("estimate_request_fees_ack >>> result: {:?}", result);
+        self._common_ack_payments_str(cmd_handle, result, "EstimateRequestFeesAck");
+        trace!("estimate_request_fees_ack <<<");
+    }
+
+    fn verify_payment_receipts(&self, wallet_handle: WalletHandle, submitter_did: Option<&DidValue>, receipts: &str, cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        trace!("verify_payment_receipts >>> wallet_handle: {:?}, submitter_did: {:?}, receipts: {:?}", wallet_handle, submitter_did, receipts);
+        if let Some(did) = submitter_did {
+            if let Err(err) = self.crypto_service.validate_did(did).map_err(map_err_err!()) {
+                return cb(Err(err));
+            }
+        }
+
+        if let Err(err) = self.wallet_service.check(wallet_handle).map_err(map_err_err!()) {
+            return cb(Err(err));
+        }
+
+        let method = match self.payments_service.parse_method_from_inputs(receipts) {
+            Ok(method) => method,
+            Err(err) => return cb(Err(err))
+        };
+
+        self._process_method_str(cb, &|i| self.payments_service.verify_payment_receipts(i, &method, wallet_handle, submitter_did, receipts));
+
+        trace!("verify_payment_receipts <<<");
+    }
+
+    fn verify_payment_receipts_ack(&self, cmd_handle: CommandHandle, result: IndyResult<String>) {
+        trace!("verify_payment_receipts_ack >>> result: {:?}", result);
+        self._common_ack_payments_str(cmd_handle, result, "VerifyPaymentReceiptsAck");
+        trace!("verify_payment_receipts_ack <<<");
+    }
+
+    fn verify_payment_receipt_with_proof(&self, receipt: &str, resp_json: &str, bls_keys: HashMap<String, String>, cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        trace!("verify_payment_receipt_with_proof >>> receipt: {:?}, resp_json: {:?}, bls_keys: {:?}", receipt, resp_json, bls_keys);
+
+        let method = match self.payments_service.parse_method_from_payment_address(receipt) {
+            Ok(method) => method,
+            Err(err) => return cb(Err(err))
+        };
+
+        self._process_method_str(
+            Box::new(move |result| cb(result.and_then(|parsed| PaymentsService::check_receipt_proof(&parsed, &bls_keys)))),
+            &|i| self.payments_service.parse_verify_payment_response_with_proof(i, &method, receipt, resp_json),
+        );
+
+        trace!("verify_payment_receipt_with_proof <<<");
+    }
+
+    fn verify_payment_receipt_with_proof_ack(&self, cmd_handle: CommandHandle, result: IndyResult<String>) {
+        trace!("verify_payment_receipt_with_proof_ack >>> result: {:?}", result);
+        self._common_ack_payments_str(cmd_handle, result, "VerifyPaymentReceiptWithProofAck");
+        trace!("verify_payment_receipt_with_proof_ack <<<");
+    }
+
+    fn get_payment_balances(&self, wallet_handle: WalletHandle, pool_handle: PoolHandle, cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        trace!("get_payment_balances >>> wallet_handle: {:?}, pool_handle: {:?}", wallet_handle, pool_handle);
+
+        let addresses = match self._list_addresses(wallet_handle) {
+            Ok(addresses) => addresses,
+            Err(err) => return cb(Err(err))
+        };
+
+        if addresses.is_empty() {
+            return cb(PaymentsCommandExecutor::_serialize_balances(Vec::new()));
+        }
+
+        let handle = next_command_handle();
+        let count = addresses.len();
+
+        self.pending_balances_aggregations.borrow_mut().insert(handle, BalancesAggregation {
+            wallet_handle,
+            pool_handle,
+            addresses: addresses.into_iter().map(AddressBalance::new).collect(),
+            pending: count,
+            cb,
+        });
+
+        // sources of all addresses are requested at once, responses are collected as they come
+        for index in 0..count {
+            self._get_payment_balances_build(handle, index, None);
+        }
+
+        trace!("get_payment_balances <<<");
+    }
+
+    fn _get_payment_balances_build(&self, handle: CommandHandle, index: usize, next: Option<i64>) {
+        trace!("_get_payment_balances_build >>> handle: {:?}, index: {:?}, next: {:?}", handle, index, next);
+
+        let res = self._balances_aggregation_address(handle, index)
+            .and_then(|(wallet_handle, _, address)| {
+                let method = self.payments_service.parse_method_from_payment_address(&address)?;
+
+                if let Some(aggregation) = self.pending_balances_aggregations.borrow_mut().get_mut(&handle) {
+                    aggregation.addresses[index].payment_method = Some(method.clone());
+                }
+
+                let cmd_handle = next_command_handle();
+
+                self.payments_service.build_get_payment_sources_request(cmd_handle, &method, wallet_handle, None, &address, next)?;
+
+                self.pending_callbacks_str.borrow_mut().insert(cmd_handle, Box::new(move |result| {
+                    CommandExecutor::instance()
+                        .send(Command::Payments(PaymentsCommand::GetPaymentBalancesSubmit(handle, index, result)))
+                        .unwrap();
+                }));
+
+                Ok(())
+            });
+
+        if let Err(err) = res {
+            self._get_payment_balances_done(handle, index, Err(err));
+        }
+
+        trace!("_get_payment_balances_build <<<");
+    }
+
+    fn _get_payment_balances_submit(&self, handle: CommandHandle, index: usize, result: IndyResult<String>) {
+        trace!("_get_payment_balances_submit >>> handle: {:?}, index: {:?}, result: {:?}", handle, index, result);
+
+        let res = result.and_then(|get_sources_req| {
+            let (_, pool_handle, _) = self._balances_aggregation_address(handle, index)?;
+
+            CommandExecutor::instance()
+                .send(Command::Ledger(LedgerCommand::SubmitRequest(
+                    pool_handle,
+                    get_sources_req,
+                    Box::new(move |response| {
+                        CommandExecutor::instance()
+                            .send(Command::Payments(PaymentsCommand::GetPaymentBalancesParse(handle, index, response)))
+                            .unwrap();
+                    }))))
+        });
+
+        if let Err(err) = res {
+            self._get_payment_balances_done(handle, index, Err(err));
+        }
+
+        trace!("_get_payment_balances_submit <<<");
+    }
+
+    fn _get_payment_balances_parse(&self, handle: CommandHandle, index: usize, result: IndyResult<String>) {
+        trace!("_get_payment_balances_parse >>> handle: {:?}, index: {:?}, result: {:?}", handle, index, result);
+
+        let res = result.and_then(|get_sources_response| {
+            let method = self.pending_balances_aggregations.borrow().get(&handle)
+                .and_then(|aggregation| aggregation.addresses[index].payment_method.clone())
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "Balances aggregation not found"))?;
+
+            let cmd_handle = next_command_handle();
+
+            self.payments_service.parse_get_payment_sources_response(cmd_handle, &method, &get_sources_response)?;
+
+            self.pending_callbacks_str_i64.borrow_mut().insert(cmd_handle, Box::new(move |result| {
+                CommandExecutor::instance()
+                    .send(Command::Payments(PaymentsCommand::GetPaymentBalancesCollect(handle, index, result)))
+                    .unwrap();
+            }));
+
+            Ok(())
+        });
+
+        if let Err(err) = res {
+            self._get_payment_balances_done(handle, index, Err(err));
+        }
+
+        trace!("_get_payment_balances_parse <<<");
+    }
+
+    fn _get_payment_balances_collect(&self, handle: CommandHandle, index: usize, result: IndyResult<(String, i64)>) {
+        trace!("_get_payment_balances_collect >>> handle: {:?}, index: {:?}, result: {:?}", handle, index, result);
+
+        let res = result.and_then(|(sources, next)| {
+            let mut aggregations = self.pending_balances_aggregations.borrow_mut();
+            let aggregation = aggregations.get_mut(&handle)
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "Balances aggregation not found"))?;
+
+            aggregation.addresses[index].add_sources(&sources)?;
+
+            Ok(next)
+        });
+
+        match res {
+            Ok(next) if next >= 0 => self._get_payment_balances_build(handle, index, Some(next)),
+            Ok(_) => self._get_payment_balances_done(handle, index, Ok(())),
+            Err(err) => self._get_payment_balances_done(handle, index, Err(err))
+        }
+
+        trace!("_get_payment_balances_collect <<<");
+    }
+
+    fn _balances_aggregation_address(&self, handle: CommandHandle, index: usize) -> IndyResult<(WalletHandle, PoolHandle, String)> {
+        self.pending_balances_aggregations.borrow().get(&handle)
+            .map(|aggregation| (aggregation.wallet_handle, aggregation.pool_handle, aggregation.addresses[index].address.clone()))
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "Balances aggregation not found"))
+    }
+
+    fn _get_payment_balances_done(&self, handle: CommandHandle, index: usize, result: IndyResult<()>) {
+        trace!("_get_payment_balances_done >>> handle: {:?}, index: {:?}, result: {:?}", handle, index, result);
+
+        let mut aggregations = self.pending_balances_aggregations.borrow_mut();
+
+        let finished = match aggregations.get_mut(&handle) {
+            Some(aggregation) => {
+                if let Err(err) = result {
+                    aggregation.addresses[index].error = Some(err.to_string());
+                }
+                aggregation.pending -= 1;
+                aggregation.pending == 0
+            }
+            None => return error!("Can't complete balances aggregation for handle {} - appropriate aggregation not found!", handle)
+        };
+
+        if finished {
+            if let Some(aggregation) = aggregations.remove(&handle) {
+                drop(aggregations);
+                (aggregation.cb)(PaymentsCommandExecutor::_serialize_balances(aggregation.addresses));
+            }
+        }
+
+        trace!("_get_payment_balances_done <<<");
+    }
+
+    fn _serialize_balances(addresses: Vec<AddressBalance>) -> IndyResult<String> {
+        serde_json::to_string(&PaymentBalances::new(addresses))
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize PaymentBalances")
+    }
+
     // HELPERS
 
     fn _process_method_str(&self, cb: Box<dyn Fn(IndyResult<String>) + Send>,
@@ -776,6 +1527,7 @@ impl PaymentsCommandExecutor {
     }
 
     fn _common_ack_str(&self, cmd_handle: CommandHandle, result: IndyResult<String>, name: &str) {
+        self.payments_service.complete_call(cmd_handle);
         match self.pending_callbacks_str.borrow_mut().remove(&cmd_handle) {
             Some(cb) => {
                 cb(result)
@@ -790,6 +1542,7 @@ impl PaymentsCommandExecutor {
     }
 
     fn _common_ack_str_i64(&self, cmd_handle: CommandHandle, result: IndyResult<(String, i64)>, name: &str) {
+        self.payments_service.complete_call(cmd_handle);
         match self.pending_callbacks_str_i64.borrow_mut().remove(&cmd_handle) {
             Some(cb) => {
                 cb(result)
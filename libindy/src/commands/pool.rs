@@ -6,6 +6,7 @@ use crate::domain::ledger::request::ProtocolVersion;
 use crate::domain::pool::{PoolConfig, PoolOpenConfig};
 use indy_api_types::errors::prelude::*;
 use crate::services::pool::PoolService;
+use crate::utils::handles;
 use indy_api_types::{PoolHandle, CommandHandle};
 
 pub enum PoolCommand {
@@ -38,6 +39,9 @@ pub enum PoolCommand {
     SetProtocolVersion(
         usize, // protocol version
         Box<dyn Fn(IndyResult<()>) + Send>),
+    GetReplyCapture(
+        PoolHandle, // pool handle
+        Box<dyn Fn(IndyResult<String>) + Send>),
 }
 
 pub struct PoolCommandExecutor {
@@ -77,10 +81,18 @@ impl PoolCommandExecutor {
                     Ok(mut cbs) => {
                         match cbs.remove(&handle) {
                             Some(cb) => {
-                                cb(result.and_then(|_| self.pool_service.add_open_pool(pool_id)))
+                                let result = result.and_then(|_| self.pool_service.add_open_pool(pool_id));
+                                if let Ok(pool_handle) = result {
+                                    handles::opened(handles::POOL, pool_handle, self.pool_service.get_pool_name(pool_handle).ok());
+                                }
+                                cb(result)
                             }
                             None => {
-                                error!("Can't process PoolCommand::OpenAck for handle {:?} with result {:?} - appropriate callback not found!", handle, result);
+                                match self.pool_service.add_read_connection(pool_id, result) {
+                                    Ok(true) => (),
+                                    Ok(false) => error!("Can't process PoolCommand::OpenAck for handle {:?} - appropriate callback not found!", handle),
+                                    Err(err) => error!("{:?}", err)
+                                }
                             }
                         }
                     }
@@ -132,6 +144,10 @@ impl PoolCommandExecutor {
                 debug!(target: "pool_command_executor", "SetProtocolVersion command received");
                 cb(self.set_protocol_version(protocol_version));
             }
+            PoolCommand::GetReplyCapture(handle, cb) => {
+                debug!(target: "pool_command_executor", "GetReplyCapture command received");
+                cb(self.get_reply_capture(handle));
+            }
         };
     }
 
@@ -190,6 +206,7 @@ impl PoolCommandExecutor {
 
         let result = self.pool_service.close(pool_handle)
             .and_then(|cmd_id| {
+                handles::closed(handles::POOL, pool_handle);
                 match self.close_callbacks.try_borrow_mut() {
                     Ok(cbs) => Ok((cbs, cmd_id)),
                     Err(err) => Err(err.into())
@@ -203,6 +220,24 @@ impl PoolCommandExecutor {
         debug!("close <<<");
     }
 
+    pub fn close_all(&self) {
+        let handles = match self.pool_service.open_pool_handles() {
+            Ok(handles) => handles,
+            Err(err) => {
+                warn!("Can't get opened pools: {:?}", err);
+                return;
+            }
+        };
+
+        for handle in handles {
+            self.close(handle, Box::new(move |res| {
+                if let Err(err) = res {
+                    warn!("Can't close pool {:?}: {:?}", handle, err);
+                }
+            }));
+        }
+    }
+
     fn refresh(&self, handle: PoolHandle, cb: Box<dyn Fn(IndyResult<()>) + Send>) {
         debug!("refresh >>> handle: {:?}", handle);
 
@@ -234,4 +269,17 @@ impl PoolCommandExecutor {
 
         Ok(())
     }
+
+    fn get_reply_capture(&self, handle: PoolHandle) -> IndyResult<String> {
+        debug!("get_reply_capture >>> handle: {:?}", handle);
+
+        let res = self.pool_service
+            .get_reply_capture(handle)
+            .and_then(|requests| ::serde_json::to_string(&requests)
+                .to_indy(IndyErrorKind::InvalidState, "Can't serialize captured replies"))?;
+
+        debug!("get_reply_capture << res: {:?}", res);
+
+        Ok(res)
+    }
 }
@@ -0,0 +1,126 @@
+//! Depth limit of the command queue.
+//!
+//! Every sent command is pending until the executor takes it from the queue. While the limit is reached
+//! application calls wait for the queue to drain up to the configured timeout and then fail with `QueueFull`.
+//! Continuation commands sent by libindy itself are never rejected so started operations always complete.
+//!
+//! Once shutdown is started the queue doesn't accept application calls anymore.
+
+use std::sync::{Condvar, Mutex};
+use std::sync::atomic::{AtomicU64, AtomicU8, AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use indy_api_types::errors::prelude::*;
+
+static PENDING: AtomicUsize = AtomicUsize::new(0);
+// 0 means unbounded queue
+static MAX_PENDING: AtomicUsize = AtomicUsize::new(0);
+static FULL_TIMEOUT_MS: AtomicU64 = AtomicU64::new(0);
+
+const RUNNING: u8 = 0;
+const DRAINING: u8 = 1;
+const CANCELLED: u8 = 2;
+const SHUT_DOWN: u8 = 3;
+
+static STATE: AtomicU8 = AtomicU8::new(RUNNING);
+
+lazy_static! {
+    static ref DEQUEUED: (Mutex<()>, Condvar) = (Mutex::new(()), Condvar::new());
+}
+
+pub fn set_max_pending(max_pending: usize) {
+    MAX_PENDING.store(max_pending, Ordering::SeqCst);
+}
+
+pub fn set_full_timeout_ms(timeout_ms: u64) {
+    FULL_TIMEOUT_MS.store(timeout_ms, Ordering::SeqCst);
+}
+
+pub fn enqueued() {
+    PENDING.fetch_add(1, Ordering::SeqCst);
+}
+
+pub fn dequeued() {
+    PENDING.fetch_sub(1, Ordering::SeqCst);
+
+    if MAX_PENDING.load(Ordering::SeqCst) != 0 && FULL_TIMEOUT_MS.load(Ordering::SeqCst) != 0 {
+        let (lock, condvar) = &*DEQUEUED;
+        let _guard = lock.lock().unwrap();
+        condvar.notify_all();
+    }
+}
+
+pub fn start_shutdown() -> IndyResult<()> {
+    STATE.compare_exchange(RUNNING, DRAINING, Ordering::SeqCst, Ordering::SeqCst)
+        .map(|_| ())
+        .map_err(|_| err_msg(IndyErrorKind::InvalidState, "Shutdown has been already started"))
+}
+
+/// Switches draining queue to cancellation of queued application calls. Returns false if shutdown is already completed.
+pub fn cancel() -> bool {
+    STATE.compare_exchange(DRAINING, CANCELLED, Ordering::SeqCst, Ordering::SeqCst).is_ok()
+}
+
+pub fn is_cancelled() -> bool {
+    STATE.load(Ordering::SeqCst) == CANCELLED
+}
+
+pub fn shut_down() {
+    STATE.store(SHUT_DOWN, Ordering::SeqCst);
+}
+
+/// Checks that application call can be queued. The limit is soft: concurrent callers may exceed it slightly.
+pub fn check_capacity() -> IndyResult<()> {
+    if STATE.load(Ordering::SeqCst) != RUNNING {
+        return Err(err_msg(IndyErrorKind::InvalidState, "Libindy is shut down"));
+    }
+
+    _check_capacity(&PENDING, MAX_PENDING.load(Ordering::SeqCst), FULL_TIMEOUT_MS.load(Ordering::SeqCst))
+}
+
+fn _check_capacity(pending: &AtomicUsize, max_pending: usize, timeout_ms: u64) -> IndyResult<()> {
+    if max_pending == 0 || pending.load(Ordering::SeqCst) < max_pending {
+        return Ok(());
+    }
+
+    let deadline = Instant::now() + Duration::from_millis(timeout_ms);
+
+    let (lock, condvar) = &*DEQUEUED;
+    let mut guard = lock.lock().unwrap();
+
+    while pending.load(Ordering::SeqCst) >= max_pending {
+        let now = Instant::now();
+
+        if now >= deadline {
+            return Err(err_msg(IndyErrorKind::QueueFull,
+                               format!("Command queue is full: {} commands are pending", max_pending)));
+        }
+
+        guard = condvar.wait_timeout(guard, deadline - now).unwrap().0;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_capacity_works() {
+        let pending = AtomicUsize::new(2);
+
+        assert!(_check_capacity(&pending, 0, 0).is_ok());
+        assert!(_check_capacity(&pending, 3, 0).is_ok());
+        assert_eq!(IndyErrorKind::QueueFull, _check_capacity(&pending, 2, 0).unwrap_err().kind());
+    }
+
+    #[test]
+    fn check_capacity_works_for_timeout() {
+        let pending = AtomicUsize::new(1);
+
+        let start = Instant::now();
+        assert_eq!(IndyErrorKind::QueueFull, _check_capacity(&pending, 1, 50).unwrap_err().kind());
+        assert!(start.elapsed() >= Duration::from_millis(50));
+    }
+}
@@ -0,0 +1,413 @@
+//! Default timeout and cancellation of application calls.
+//!
+//! Callbacks of API calls are wrapped so they can be called with error before the result is ready:
+//! with `Timeout` error when the timeout is set, even if the executor is stuck in a hung plugin,
+//! or with the error of shutdown. The result that comes after that is dropped.
+//!
+//! The timeout is started by `CommandExecutor::submit` for every call with wrapped callback.
+//! Streaming calls restart it with `keep_alive` on every chunk, so it limits the time between chunks.
+//!
+//! Commands carry id of the call they belong to, so the call can be failed if its command panics.
+//! Failed call is cancelled where possible:
+//! - hooks registered by `on_cancel` are run, f.e. ledger requests are dropped so the reply is ignored;
+//! - long running commands check `is_cancelled` and stop, f.e. wallet searches are closed
+//!   and streaming of records, transactions and wallet export is stopped.
+//!
+//! Completion hook registered by `on_complete` is called once with the code of the result passed to the callback,
+//! f.e. to report the result of the call to the post command hook.
+//!
+//! Operation that is already running in a wallet storage plugin, in key derivation or in anoncreds
+//! can't be cancelled. It keeps the executor busy until it returns and its result is dropped.
+
+use std::cell::Cell;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{channel, RecvTimeoutError, Sender};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use indy_api_types::ErrorCode;
+use indy_api_types::errors::prelude::*;
+
+// 0 means no timeout
+static TIMEOUT_MS: AtomicU64 = AtomicU64::new(0);
+static NEXT_CALL_ID: AtomicU64 = AtomicU64::new(1);
+
+type Expiration = Box<dyn FnOnce(IndyError) + Send>;
+type Cancellation = Box<dyn FnOnce() + Send>;
+type Completion = Box<dyn FnOnce(ErrorCode) + Send>;
+
+struct PendingCall {
+    expiration: Expiration,
+    // deadline and timeout it has been set with, once the timeout is started
+    deadline: Option<(Instant, u64)>,
+    cancellations: Vec<Cancellation>,
+    completion: Option<Completion>,
+}
+
+lazy_static! {
+    static ref TIMER: Mutex<Sender<Deadline>> = Mutex::new(_start_timer());
+    static ref PENDING_CALLS: Mutex<HashMap<u64, PendingCall>> = Mutex::new(HashMap::new());
+}
+
+thread_local! {
+    // Call of the last wrapped callback in application thread or of the executed command in command thread
+    static CURRENT_CALL: Cell<Option<u64>> = Cell::new(None);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Deadline {
+    at: Instant,
+    call_id: u64,
+    timeout_ms: u64,
+}
+
+pub fn set_timeout_ms(timeout_ms: u64) {
+    TIMEOUT_MS.store(timeout_ms, Ordering::SeqCst);
+}
+
+/// Wraps callback of API call, so the call can be failed by timeout, panic or shutdown.
+pub fn with_timeout<T, F>(cb: F) -> Box<dyn Fn(IndyResult<T>) + Send>
+    where T: 'static,
+          F: Fn(IndyResult<T>) + Send + 'static {
+    let call_id = NEXT_CALL_ID.fetch_add(1, Ordering::SeqCst);
+
+    let cb = Arc::new(Mutex::new(Some(cb)));
+    let expired_cb = cb.clone();
+
+    let expiration: Expiration = Box::new(move |err| {
+        let cb = expired_cb.lock().unwrap().take();
+
+        if let Some(cb) = cb {
+            cb(Err(err));
+        }
+    });
+
+    PENDING_CALLS.lock().unwrap().insert(call_id, PendingCall { expiration, deadline: None, cancellations: Vec::new(), completion: None });
+    set_current_call(Some(call_id));
+
+    Box::new(move |result| {
+        let call = PENDING_CALLS.lock().unwrap().remove(&call_id);
+        let err = match result {
+            Ok(_) => ErrorCode::Success,
+            Err(ref err) => err.kind().into(),
+        };
+        let cb = cb.lock().unwrap().take();
+
+        match cb {
+            Some(cb) => cb(result),
+            None => debug!("Result of timed out command is dropped"),
+        }
+
+        if let Some(completion) = call.and_then(|call| call.completion) {
+            completion(err);
+        }
+    })
+}
+
+/// Starts the current timeout of the call. Called once the command of the call is queued.
+pub fn start(call_id: u64) {
+    _start(call_id, TIMEOUT_MS.load(Ordering::SeqCst))
+}
+
+fn _start(call_id: u64, timeout_ms: u64) {
+    if timeout_ms == 0 {
+        return;
+    }
+
+    let deadline = Deadline { at: Instant::now() + Duration::from_millis(timeout_ms), call_id, timeout_ms };
+
+    match PENDING_CALLS.lock().unwrap().get_mut(&call_id) {
+        Some(call) => call.deadline = Some((deadline.at, timeout_ms)),
+        None => return,
+    }
+
+    // nobody can wait for the callback if the timer is gone, so it is just called without timeout
+    let _ = TIMER.lock().unwrap().send(deadline);
+}
+
+/// Restarts the timeout of streaming call that has made progress.
+pub fn keep_alive(call_id: Option<u64>) {
+    let call_id = match call_id {
+        Some(call_id) => call_id,
+        None => return,
+    };
+
+    let timeout_ms = match PENDING_CALLS.lock().unwrap().get(&call_id).and_then(|call| call.deadline) {
+        Some((_, timeout_ms)) => timeout_ms,
+        None => return,
+    };
+
+    _start(call_id, timeout_ms)
+}
+
+/// Registers action that cancels the work of the call when the call fails before its result is ready.
+/// The action is run at once if the call has already failed.
+pub fn on_cancel(call_id: Option<u64>, cancellation: Cancellation) {
+    let call_id = match call_id {
+        Some(call_id) => call_id,
+        None => return,
+    };
+
+    let cancellation = match PENDING_CALLS.lock().unwrap().get_mut(&call_id) {
+        Some(call) => {
+            call.cancellations.push(cancellation);
+            return;
+        }
+        None => cancellation,
+    };
+
+    cancellation()
+}
+
+/// Registers hook called with the code of the result of the call, including the error the call is failed with.
+pub fn on_complete(call_id: u64, completion: Completion) {
+    if let Some(call) = PENDING_CALLS.lock().unwrap().get_mut(&call_id) {
+        call.completion = Some(completion);
+    }
+}
+
+/// Whether the call has failed before its result is ready, so its work can be stopped.
+pub fn is_cancelled(call_id: Option<u64>) -> bool {
+    match call_id {
+        Some(call_id) => !PENDING_CALLS.lock().unwrap().contains_key(&call_id),
+        None => false,
+    }
+}
+
+pub fn set_current_call(call_id: Option<u64>) {
+    CURRENT_CALL.with(|current| current.set(call_id));
+}
+
+pub fn current_call() -> Option<u64> {
+    CURRENT_CALL.with(|current| current.get())
+}
+
+pub fn take_current_call() -> Option<u64> {
+    CURRENT_CALL.with(|current| current.take())
+}
+
+/// Calls callback of pending API call with `err`. Does nothing if the call has already completed.
+pub fn fail_call(call_id: u64, err: IndyError) {
+    let call = PENDING_CALLS.lock().unwrap().remove(&call_id);
+
+    if let Some(call) = call {
+        _fail(call, err);
+    }
+}
+
+/// Forgets pending API call without calling its callback, the call has failed before its command was queued.
+pub fn discard_call(call_id: u64) {
+    PENDING_CALLS.lock().unwrap().remove(&call_id);
+}
+
+/// Calls callbacks of all pending API calls with `err`.
+pub fn cancel_pending_calls(err: IndyError) {
+    let calls: Vec<PendingCall> = PENDING_CALLS.lock().unwrap().drain().map(|(_, call)| call).collect();
+
+    for call in calls {
+        _fail(call, err.clone());
+    }
+}
+
+fn _fail(call: PendingCall, err: IndyError) {
+    let code: ErrorCode = err.kind().into();
+    (call.expiration)(err);
+
+    if let Some(completion) = call.completion {
+        completion(code);
+    }
+
+    for cancellation in call.cancellations {
+        cancellation();
+    }
+}
+
+fn _expire(deadline: Deadline) {
+    let call = {
+        let mut calls = PENDING_CALLS.lock().unwrap();
+
+        // deadline is outdated if the call has been kept alive
+        match calls.get(&deadline.call_id) {
+            Some(call) if call.deadline == Some((deadline.at, deadline.timeout_ms)) => calls.remove(&deadline.call_id),
+            _ => None,
+        }
+    };
+
+    if let Some(call) = call {
+        warn!("Command hasn't completed in {} ms", deadline.timeout_ms);
+        _fail(call, err_msg(IndyErrorKind::Timeout, format!("Command hasn't completed in {} ms", deadline.timeout_ms)));
+    }
+}
+
+fn _start_timer() -> Sender<Deadline> {
+    let (sender, receiver) = channel::<Deadline>();
+
+    thread::spawn(move || {
+        let mut deadlines: BinaryHeap<Reverse<Deadline>> = BinaryHeap::new();
+
+        loop {
+            let received = match deadlines.peek() {
+                Some(Reverse(deadline)) => receiver.recv_timeout(deadline.at.saturating_duration_since(Instant::now())),
+                None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+            };
+
+            match received {
+                Ok(deadline) => deadlines.push(Reverse(deadline)),
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+
+            while let Some(Reverse(deadline)) = deadlines.peek().cloned() {
+                if deadline.at > Instant::now() {
+                    break;
+                }
+                deadlines.pop();
+                _expire(deadline);
+            }
+        }
+    });
+
+    sender
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::mpsc::{channel, Receiver};
+
+    fn _channel_cb(timeout_ms: u64) -> (Box<dyn Fn(IndyResult<()>) + Send>, Receiver<IndyResult<()>>) {
+        let (sender, receiver) = channel();
+        let sender = Mutex::new(sender);
+        let cb = with_timeout(move |result| sender.lock().unwrap().send(result).unwrap());
+        _start(current_call().unwrap(), timeout_ms);
+        (cb, receiver)
+    }
+
+    #[test]
+    fn with_timeout_works() {
+        let (cb, receiver) = _channel_cb(5000);
+
+        cb(Ok(()));
+        cb(Ok(()));
+
+        assert!(receiver.recv().unwrap().is_ok());
+        // callback is called once
+        assert!(receiver.recv_timeout(Duration::from_millis(100)).is_err());
+    }
+
+    #[test]
+    fn with_timeout_works_for_expired_command() {
+        let (cb, receiver) = _channel_cb(50);
+
+        let err = receiver.recv_timeout(Duration::from_secs(5)).unwrap().unwrap_err();
+        assert_eq!(IndyErrorKind::Timeout, err.kind());
+
+        // late result is dropped
+        cb(Ok(()));
+        assert!(receiver.recv_timeout(Duration::from_millis(100)).is_err());
+    }
+
+    #[test]
+    fn keep_alive_works() {
+        let (cb, receiver) = _channel_cb(200);
+        let call_id = current_call();
+
+        for _ in 0..4 {
+            thread::sleep(Duration::from_millis(100));
+            keep_alive(call_id);
+        }
+        assert!(receiver.try_recv().is_err());
+
+        cb(Ok(()));
+        assert!(receiver.recv_timeout(Duration::from_secs(5)).unwrap().is_ok());
+    }
+
+    #[test]
+    fn on_cancel_works_for_expired_command() {
+        let (_cb, receiver) = _channel_cb(50);
+        let call_id = take_current_call();
+        let (cancel_sender, cancel_receiver) = channel();
+        let cancel_sender = Mutex::new(cancel_sender);
+
+        on_cancel(call_id, Box::new(move || cancel_sender.lock().unwrap().send(()).unwrap()));
+        assert!(!is_cancelled(call_id));
+
+        let err = receiver.recv_timeout(Duration::from_secs(5)).unwrap().unwrap_err();
+        assert_eq!(IndyErrorKind::Timeout, err.kind());
+        cancel_receiver.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert!(is_cancelled(call_id));
+    }
+
+    #[test]
+    fn on_cancel_works_for_failed_call() {
+        let (cb, _receiver) = _channel_cb(0);
+        let call_id = take_current_call();
+        fail_call(call_id.unwrap(), err_msg(IndyErrorKind::InvalidState, "Failed"));
+
+        let (cancel_sender, cancel_receiver) = channel();
+        on_cancel(call_id, Box::new(move || cancel_sender.send(()).unwrap()));
+
+        // cancelled call runs the action at once
+        cancel_receiver.try_recv().unwrap();
+        cb(Ok(()));
+    }
+
+    #[test]
+    fn fail_call_works() {
+        let (cb, receiver) = _channel_cb(0);
+        let call_id = take_current_call().unwrap();
+        assert_eq!(None, current_call());
+
+        fail_call(call_id, err_msg(IndyErrorKind::InvalidState, "Failed"));
+
+        let err = receiver.recv_timeout(Duration::from_secs(5)).unwrap().unwrap_err();
+        assert_eq!(IndyErrorKind::InvalidState, err.kind());
+
+        cb(Ok(()));
+        assert!(receiver.recv_timeout(Duration::from_millis(100)).is_err());
+    }
+
+    #[test]
+    fn discard_call_works() {
+        let (_cb, receiver) = _channel_cb(50);
+        let call_id = take_current_call().unwrap();
+
+        discard_call(call_id);
+
+        // expiration doesn't call the callback
+        assert!(receiver.recv_timeout(Duration::from_millis(200)).is_err());
+    }
+
+    #[test]
+    fn on_complete_works() {
+        let (cb, _receiver) = _channel_cb(0);
+        let call_id = take_current_call().unwrap();
+        let (complete_sender, complete_receiver) = channel();
+        let complete_sender = Mutex::new(complete_sender);
+
+        on_complete(call_id, Box::new(move |err| complete_sender.lock().unwrap().send(err).unwrap()));
+
+        cb(Err(err_msg(IndyErrorKind::WalletItemNotFound, "Not found")));
+        cb(Ok(()));
+
+        assert_eq!(ErrorCode::WalletItemNotFound, complete_receiver.recv_timeout(Duration::from_secs(5)).unwrap());
+        // hook is called once
+        assert!(complete_receiver.recv_timeout(Duration::from_millis(100)).is_err());
+    }
+
+    #[test]
+    fn on_complete_works_for_expired_command() {
+        let (_cb, _receiver) = _channel_cb(50);
+        let call_id = take_current_call().unwrap();
+        let (complete_sender, complete_receiver) = channel();
+        let complete_sender = Mutex::new(complete_sender);
+
+        on_complete(call_id, Box::new(move |err| complete_sender.lock().unwrap().send(err).unwrap()));
+
+        assert_eq!(ErrorCode::CommonTimeout, complete_receiver.recv_timeout(Duration::from_secs(5)).unwrap());
+    }
+}
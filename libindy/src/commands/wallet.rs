@@ -1,12 +1,18 @@
 use std::cell::RefCell;
 use std::collections::HashMap;
+use std::io::{self, BufWriter, Write};
 use std::rc::Rc;
 
 use indy_api_types::wallet::*;
 use crate::commands::{Command, CommandExecutor};
-use indy_api_types::domain::wallet::{Config, Credentials, ExportConfig, KeyConfig};
+use crate::commands::timeout;
+#[cfg(feature = "cache")]
+use crate::commands::cache::CacheCommand;
+use indy_api_types::domain::wallet::{Config, Credentials, ExportBufferConfig, ExportConfig, KeyConfig};
 use indy_api_types::errors::prelude::*;
 use crate::services::crypto::CryptoService;
+use crate::utils::audit;
+use crate::utils::handles;
 use indy_wallet::{KeyDerivationData, WalletService, Metadata};
 use indy_utils::crypto::{chacha20poly1305_ietf, randombytes};
 use indy_utils::crypto::chacha20poly1305_ietf::Key as MasterKey;
@@ -15,6 +21,8 @@ use rust_base58::ToBase58;
 
 type DeriveKeyResult<T> = IndyResult<T>;
 
+pub type ExportDataFn = Box<dyn Fn(&[u8]) + Send>;
+
 pub enum WalletCommand {
     RegisterWalletType(String, // type_
                        WalletCreate, // create
@@ -42,6 +50,11 @@ pub enum WalletCommand {
                        WalletFetchSearchNextRecord, // fetch search next record
                        WalletFreeSearch, // free search
                        Box<dyn Fn(IndyResult<()>) + Send>),
+    UnregisterWalletType(String, // type_
+                         Box<dyn Fn(IndyResult<()>) + Send>),
+    SetStorageQueryExtensions(String, // type_
+                              Vec<String>, // extensions
+                              Box<dyn Fn(IndyResult<()>) + Send>),
     Create(Config, // config
            Credentials, // credentials
            Box<dyn Fn(IndyResult<()>) + Send>),
@@ -73,7 +86,20 @@ pub enum WalletCommand {
                    ExportConfig, // export config
                    KeyDerivationData,
                    DeriveKeyResult<MasterKey>,
+                   Option<serde_json::Value>, // audit context
                    CallbackHandle),
+    ExportToBuffer(WalletHandle,
+                   ExportBufferConfig, // export config
+                   Box<dyn Fn(IndyResult<Vec<u8>>) + Send>),
+    StreamExport(WalletHandle,
+                 ExportBufferConfig, // export config
+                 ExportDataFn, // data cb
+                 Box<dyn Fn(IndyResult<()>) + Send>),
+    ExportToConsumerContinue(WalletHandle,
+                             KeyDerivationData,
+                             DeriveKeyResult<MasterKey>,
+                             Option<serde_json::Value>, // audit context
+                             CallbackHandle),
     Import(Config, // config
            Credentials, // credentials
            ExportConfig, // import config
@@ -101,7 +127,35 @@ pub struct WalletCommandExecutor {
     wallet_service: Rc<WalletService>,
     crypto_service: Rc<CryptoService>,
     open_callbacks: RefCell<HashMap<WalletHandle, Box<dyn Fn(IndyResult<WalletHandle>) + Send>>>,
-    pending_callbacks: RefCell<HashMap<CallbackHandle, Box<dyn Fn(IndyResult<()>) + Send>>>
+    pending_callbacks: RefCell<HashMap<CallbackHandle, Box<dyn Fn(IndyResult<()>) + Send>>>,
+    pending_export_consumers: RefCell<HashMap<CallbackHandle, ExportConsumer>>
+}
+
+enum ExportConsumer {
+    Buffer(Box<dyn Fn(IndyResult<Vec<u8>>) + Send>),
+    Stream(ExportDataFn, Box<dyn Fn(IndyResult<()>) + Send>, Option<u64> /* call id */),
+}
+
+// Size of chunks passed to the data callback of streamed export
+const EXPORT_STREAM_CHUNK_SIZE: usize = 64 * 1024;
+
+// Passes written data to the data callback of streamed export, export is stopped once its call is cancelled
+struct ExportStreamWriter<'a>(&'a dyn Fn(&[u8]), Option<u64>);
+
+impl<'a> Write for ExportStreamWriter<'a> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if timeout::is_cancelled(self.1) {
+            return Err(io::Error::new(io::ErrorKind::Other, "Streaming of wallet export has been cancelled"));
+        }
+
+        (self.0)(buf);
+        timeout::keep_alive(self.1);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
 }
 
 impl WalletCommandExecutor {
@@ -110,7 +164,8 @@ impl WalletCommandExecutor {
             wallet_service,
             crypto_service,
             open_callbacks: RefCell::new(HashMap::new()),
-            pending_callbacks: RefCell::new(HashMap::new())
+            pending_callbacks: RefCell::new(HashMap::new()),
+            pending_export_consumers: RefCell::new(HashMap::new())
         }
     }
 
@@ -130,6 +185,14 @@ impl WalletCommandExecutor {
                                        free_storage_metadata, search_records, search_all_records, get_search_total_count,
                                        fetch_search_next_record, free_search));
             }
+            WalletCommand::UnregisterWalletType(type_, cb) => {
+                debug!(target: "wallet_command_executor", "UnregisterWalletType command received");
+                cb(self._unregister_type(&type_));
+            }
+            WalletCommand::SetStorageQueryExtensions(type_, extensions, cb) => {
+                debug!(target: "wallet_command_executor", "SetStorageQueryExtensions command received");
+                cb(self._set_storage_query_extensions(&type_, extensions));
+            }
             WalletCommand::Create(config, credentials, cb) => {
                 debug!(target: "wallet_command_executor", "Create command received");
                 self._create(&config, &credentials, cb)
@@ -162,9 +225,21 @@ impl WalletCommandExecutor {
                 debug!(target: "wallet_command_executor", "Export command received");
                 self._export(wallet_handle, &export_config, cb)
             }
-            WalletCommand::ExportContinue(wallet_handle, export_config, key_data, key_result, cb_id) => {
+            WalletCommand::ExportContinue(wallet_handle, export_config, key_data, key_result, audit_context, cb_id) => {
                 debug!(target: "wallet_command_executor", "ExportContinue command received");
-                self._export_continue(cb_id, wallet_handle, &export_config, key_data, key_result)
+                self._export_continue(cb_id, wallet_handle, &export_config, key_data, key_result, audit_context)
+            }
+            WalletCommand::ExportToBuffer(wallet_handle, export_config, cb) => {
+                debug!(target: "wallet_command_executor", "ExportToBuffer command received");
+                self._export_to_consumer(wallet_handle, &export_config, ExportConsumer::Buffer(cb))
+            }
+            WalletCommand::StreamExport(wallet_handle, export_config, data_cb, cb) => {
+                debug!(target: "wallet_command_executor", "StreamExport command received");
+                self._export_to_consumer(wallet_handle, &export_config, ExportConsumer::Stream(data_cb, cb, timeout::current_call()))
+            }
+            WalletCommand::ExportToConsumerContinue(wallet_handle, key_data, key_result, audit_context, cb_id) => {
+                debug!(target: "wallet_command_executor", "ExportToConsumerContinue command received");
+                self._export_to_consumer_continue(cb_id, wallet_handle, key_data, key_result, audit_context)
             }
             WalletCommand::Import(config, credentials, import_config, cb) => {
                 debug!(target: "wallet_command_executor", "Import command received");
@@ -226,6 +301,24 @@ impl WalletCommandExecutor {
         Ok(())
     }
 
+    fn _unregister_type(&self, type_: &str) -> IndyResult<()> {
+        trace!("_unregister_type >>> type_: {:?}", type_);
+
+        self.wallet_service.unregister_wallet_storage(type_)?;
+
+        trace!("_unregister_type <<< res: ()");
+        Ok(())
+    }
+
+    fn _set_storage_query_extensions(&self, type_: &str, extensions: Vec<String>) -> IndyResult<()> {
+        trace!("_set_storage_query_extensions >>> type_: {:?}, extensions: {:?}", type_, extensions);
+
+        self.wallet_service.set_wallet_storage_query_extensions(type_, extensions)?;
+
+        trace!("_set_storage_query_extensions <<< res: ()");
+        Ok(())
+    }
+
     fn _create(&self,
                config: &Config,
                credentials: &Credentials,
@@ -328,8 +421,14 @@ impl WalletCommandExecutor {
                       wallet_handle: WalletHandle,
                       key_result: DeriveKeyResult<(MasterKey, Option<MasterKey>)>) {
         let cb = self.open_callbacks.borrow_mut().remove(&wallet_handle).unwrap();
-        cb(key_result
-            .and_then(|(key, rekey)| self.wallet_service.open_wallet_continue(wallet_handle, (&key, rekey.as_ref()))))
+        let result = key_result
+            .and_then(|(key, rekey)| self.wallet_service.open_wallet_continue(wallet_handle, (&key, rekey.as_ref())));
+
+        if result.is_ok() {
+            handles::opened(handles::WALLET, wallet_handle.0, self.wallet_service.get_wallet_id(wallet_handle).ok());
+        }
+
+        cb(result)
     }
 
     fn _close(&self,
@@ -337,11 +436,20 @@ impl WalletCommandExecutor {
         trace!("_close >>> handle: {:?}", wallet_handle);
 
         self.wallet_service.close_wallet(wallet_handle)?;
+        handles::closed(handles::WALLET, wallet_handle.0);
+
+        #[cfg(feature = "cache")]
+        CommandExecutor::instance().send(Command::Cache(CacheCommand::WalletClosed(wallet_handle)))?;
 
         trace!("_close <<< res: ()");
         Ok(())
     }
 
+    pub fn close_all(&self) {
+        self.wallet_service.close_all_wallets();
+        handles::closed_all(handles::WALLET);
+    }
+
     fn _delete(&self,
                config: &Config,
                credentials: &Credentials,
@@ -399,6 +507,8 @@ impl WalletCommandExecutor {
         self.pending_callbacks.borrow_mut().insert(cb_id, cb);
 
         let export_config = export_config.clone();
+        // Key is derived in threadpool, so audit context of the call is passed explicitly
+        let audit_context = audit::current_context();
 
         CommandExecutor::instance().send(
             Command::Wallet(WalletCommand::DeriveKey(
@@ -409,6 +519,7 @@ impl WalletCommandExecutor {
                         export_config.clone(),
                         key_data.clone(),
                         master_key_res,
+                        audit_context.clone(),
                         cb_id,
                     ))).unwrap();
                 })
@@ -423,10 +534,88 @@ impl WalletCommandExecutor {
                         wallet_handle: WalletHandle,
                         export_config: &ExportConfig,
                         key_data: KeyDerivationData,
-                        key_result: DeriveKeyResult<MasterKey>) {
+                        key_result: DeriveKeyResult<MasterKey>,
+                        audit_context: Option<serde_json::Value>) {
         let cb = get_cb!(self, cb_id);
-        cb(key_result
-            .and_then(|key| self.wallet_service.export_wallet(wallet_handle, export_config, 0, (&key_data,& key)))) // TODO - later add proper versioning
+
+        let res = key_result
+            .and_then(|key| self.wallet_service.export_wallet(wallet_handle, export_config, 0, (&key_data,& key))); // TODO - later add proper versioning
+
+        if res.is_ok() {
+            audit::set_current_context(audit_context);
+            audit::record(audit::WALLET_EXPORTED, wallet_handle, json!({"path": export_config.path}));
+        }
+
+        cb(res)
+    }
+
+    fn _export_to_consumer(&self,
+                           wallet_handle: WalletHandle,
+                           export_config: &ExportBufferConfig,
+                           consumer: ExportConsumer) {
+        trace!("_export_to_consumer >>> handle: {:?}, export_config: {:?}", wallet_handle, secret!(export_config));
+
+        let key_data = KeyDerivationData::from_passphrase_with_new_salt(&export_config.key, &export_config.key_derivation_method);
+
+        let cb_id = indy_utils::sequence::get_next_id();
+        self.pending_export_consumers.borrow_mut().insert(cb_id, consumer);
+
+        // Key is derived in threadpool, so audit context of the call is passed explicitly
+        let audit_context = audit::current_context();
+
+        CommandExecutor::instance().send(
+            Command::Wallet(WalletCommand::DeriveKey(
+                key_data.clone(),
+                Box::new(move |master_key_res| {
+                    CommandExecutor::instance().send(Command::Wallet(WalletCommand::ExportToConsumerContinue(
+                        wallet_handle,
+                        key_data.clone(),
+                        master_key_res,
+                        audit_context.clone(),
+                        cb_id,
+                    ))).unwrap();
+                })
+            ))
+        ).unwrap();
+
+        trace!("_export_to_consumer <<<");
+    }
+
+    fn _export_to_consumer_continue(&self,
+                                    cb_id: CallbackHandle,
+                                    wallet_handle: WalletHandle,
+                                    key_data: KeyDerivationData,
+                                    key_result: DeriveKeyResult<MasterKey>,
+                                    audit_context: Option<serde_json::Value>) {
+        let consumer = match self.pending_export_consumers.borrow_mut().remove(&cb_id) {
+            Some(consumer) => consumer,
+            None => return error!("No pending command for id: {}", cb_id)
+        };
+
+        let record_audit = |exported: bool| if exported {
+            audit::set_current_context(audit_context.clone());
+            audit::record(audit::WALLET_EXPORTED, wallet_handle, json!({"path": null}));
+        };
+
+        match consumer {
+            ExportConsumer::Buffer(cb) => {
+                let res = key_result
+                    .and_then(|key| self.wallet_service.export_wallet_to_buffer(wallet_handle, 0, (&key_data, &key)));
+                record_audit(res.is_ok());
+                cb(res)
+            }
+            ExportConsumer::Stream(data_cb, cb, call_id) => {
+                let res = key_result
+                    .and_then(|key| {
+                        let mut writer = BufWriter::with_capacity(EXPORT_STREAM_CHUNK_SIZE, ExportStreamWriter(&*data_cb, call_id));
+                        self.wallet_service.export_wallet_to_writer(wallet_handle, &mut writer, 0, (&key_data, &key))?;
+                        writer.flush()?;
+                        Ok(())
+                    });
+                record_audit(res.is_ok());
+                cb(res)
+            }
+        }
     }
 
     fn _import(&self,
@@ -503,6 +692,13 @@ impl WalletCommandExecutor {
     }
 
     fn _derive_key(&self, key_data: KeyDerivationData, cb: Box<dyn Fn(DeriveKeyResult<MasterKey>) + Send>){
-        crate::commands::THREADPOOL.lock().unwrap().execute(move || cb(key_data.calc_master_key()));
+        // continuation command sent by the callback belongs to the same application call
+        let call_id = timeout::current_call();
+
+        crate::commands::THREADPOOL.lock().unwrap().execute(move || {
+            timeout::set_current_call(call_id);
+            cb(key_data.calc_master_key());
+            timeout::set_current_call(None);
+        });
     }
 }
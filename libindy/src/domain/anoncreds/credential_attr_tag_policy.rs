@@ -1,40 +1,93 @@
 use std::collections::HashSet;
 
-use serde::ser::{Serialize, Serializer, SerializeSeq};
+use serde::ser::{Serialize, Serializer, SerializeSeq, SerializeMap};
 use serde::de::{Deserializer, Deserialize};
 
+/// Attributes to tag stored credentials with.
+///
+/// `taggable` attributes get both `attr::<name>::marker` and `attr::<name>::value` tags (all attributes if not set),
+/// `marker_only` attributes get only the marker tag, so their raw values are not exposed in tags.
 #[derive(Debug)]
 pub struct CredentialAttrTagPolicy {
-    pub taggable: HashSet<String>
+    pub taggable: Option<HashSet<String>>,
+    pub marker_only: HashSet<String>,
 }
 
 impl CredentialAttrTagPolicy {
     pub fn is_taggable(&self, attr_name: &str) -> bool {
-        self.taggable.contains(&attr_name.to_string().replace(" ", "").to_lowercase())
+        self.taggable.as_ref()
+            .map(|taggable| taggable.contains(&_attr_common_view(attr_name)))
+            .unwrap_or(true)
     }
+
+    pub fn is_value_taggable(&self, attr_name: &str) -> bool {
+        self.is_taggable(attr_name) && !self.marker_only.contains(&_attr_common_view(attr_name))
+    }
+}
+
+fn _attr_common_view(attr_name: &str) -> String {
+    attr_name.replace(" ", "").to_lowercase()
+}
+
+fn _attr_common_views(attr_names: Vec<String>) -> HashSet<String> {
+    attr_names.iter().map(|a| _attr_common_view(a)).collect()
 }
 
 impl From<Vec<String>> for CredentialAttrTagPolicy {
     fn from(taggables: Vec<String>) -> Self {
         CredentialAttrTagPolicy {
-            taggable: taggables.into_iter().map(|a| a.replace(" ", "").to_lowercase()).collect()
+            taggable: Some(_attr_common_views(taggables)),
+            marker_only: HashSet::new(),
         }
     }
 }
 
 impl Serialize for CredentialAttrTagPolicy {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer, {
-        let mut seq = serializer.serialize_seq(Some(self.taggable.len()))?;
-        for ref element in &self.taggable {
-            seq.serialize_element(&element)?;
+        match self.taggable {
+            // Keep the plain list form for the policies that can be expressed by it
+            Some(ref taggable) if self.marker_only.is_empty() => {
+                let mut seq = serializer.serialize_seq(Some(taggable.len()))?;
+                for ref element in taggable {
+                    seq.serialize_element(&element)?;
+                }
+                seq.end()
+            }
+            _ => {
+                let mut map = serializer.serialize_map(Some(2))?;
+                if let Some(ref taggable) = self.taggable {
+                    map.serialize_entry("taggable", taggable)?;
+                }
+                map.serialize_entry("marker_only", &self.marker_only)?;
+                map.end()
+            }
         }
-        seq.end()
     }
 }
 
 impl<'de> Deserialize<'de> for CredentialAttrTagPolicy {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de>, {
-        let attr_names = Vec::deserialize(deserializer)?;
-        Ok(CredentialAttrTagPolicy::from(attr_names))
+        #[derive(Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct Helper {
+            taggable: Option<Vec<String>>,
+            #[serde(default)]
+            marker_only: Vec<String>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Policy {
+            AttrNames(Vec<String>),
+            Full(Helper),
+        }
+
+        match Policy::deserialize(deserializer)? {
+            Policy::AttrNames(attr_names) => Ok(CredentialAttrTagPolicy::from(attr_names)),
+            Policy::Full(helper) => Ok(CredentialAttrTagPolicy {
+                taggable: helper.taggable.map(_attr_common_views),
+                marker_only: _attr_common_views(helper.marker_only),
+            })
+        }
     }
 }
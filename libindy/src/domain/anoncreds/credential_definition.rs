@@ -9,10 +9,14 @@ use crate::utils::qualifier;
 use ursa::cl::{
     CredentialPrimaryPublicKey,
     CredentialRevocationPublicKey,
+};
+#[cfg(feature = "anoncreds")]
+use ursa::cl::{
     CredentialPrivateKey,
     CredentialKeyCorrectnessProof
 };
 
+#[cfg(feature = "anoncreds")]
 use std::collections::HashMap;
 
 pub const CL_SIGNATURE_TYPE: &str = "CL";
@@ -30,12 +34,14 @@ impl SignatureType {
     }
 }
 
+#[cfg(feature = "anoncreds")]
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct CredentialDefinitionConfig {
     #[serde(default)]
     pub support_revocation: bool
 }
 
+#[cfg(feature = "anoncreds")]
 impl Default for CredentialDefinitionConfig {
     fn default() -> Self {
         CredentialDefinitionConfig {
@@ -69,6 +75,7 @@ pub enum CredentialDefinition {
     CredentialDefinitionV1(CredentialDefinitionV1)
 }
 
+#[cfg(feature = "anoncreds")]
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TemporaryCredentialDefinition {
     pub cred_def: CredentialDefinition,
@@ -76,6 +83,7 @@ pub struct TemporaryCredentialDefinition {
     pub cred_def_correctness_proof: CredentialDefinitionCorrectnessProof
 }
 
+#[cfg(feature = "anoncreds")]
 impl CredentialDefinition {
     pub fn to_unqualified(self) -> CredentialDefinition {
         match self {
@@ -100,8 +108,10 @@ impl From<CredentialDefinition> for CredentialDefinitionV1 {
     }
 }
 
+#[cfg(feature = "anoncreds")]
 pub type CredentialDefinitions = HashMap<CredentialDefinitionId, CredentialDefinition>;
 
+#[cfg(feature = "anoncreds")]
 pub fn cred_defs_map_to_cred_defs_v1_map(cred_defs: CredentialDefinitions) -> HashMap<CredentialDefinitionId, CredentialDefinitionV1> {
     cred_defs
         .into_iter()
@@ -109,11 +119,13 @@ pub fn cred_defs_map_to_cred_defs_v1_map(cred_defs: CredentialDefinitions) -> Ha
         .collect()
 }
 
+#[cfg(feature = "anoncreds")]
 #[derive(Debug, Serialize, Deserialize)]
 pub struct CredentialDefinitionPrivateKey {
     pub value: CredentialPrivateKey
 }
 
+#[cfg(feature = "anoncreds")]
 #[derive(Debug, Serialize, Deserialize)]
 pub struct CredentialDefinitionCorrectnessProof {
     pub value: CredentialKeyCorrectnessProof
@@ -210,6 +222,7 @@ impl CredentialDefinitionId {
         None
     }
 
+    #[cfg(feature = "anoncreds")]
     pub fn issuer_did(&self) -> Option<DidValue> {
         self.parts().map(|(did, _, _, _)| did)
     }
@@ -240,6 +253,7 @@ impl Validatable for CredentialDefinitionId {
     }
 }
 
+#[cfg(feature = "anoncreds")]
 impl Validatable for CredentialDefinitionConfig {}
 
 #[cfg(test)]
@@ -1,10 +1,14 @@
 use ursa::cl::{CredentialKeyCorrectnessProof, Nonce};
 
 use super::schema::SchemaId;
-use super::credential_definition::CredentialDefinitionId;
+use super::credential_definition::{CredentialDefinitionId, CredentialDefinitionV1};
+use crate::domain::crypto::did::DidValue;
 
+use indy_api_types::errors::prelude::*;
 use indy_api_types::validation::Validatable;
 
+const MAX_NONCE_BITS: u32 = 80;
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct CredentialOffer {
     pub schema_id: SchemaId,
@@ -26,6 +30,44 @@ impl CredentialOffer {
             nonce: self.nonce,
         }
     }
+
+    /// Checks the offer fields that can be checked without cryptography against the referenced credential definition.
+    pub fn check_with_cred_def(&self, cred_def: &CredentialDefinitionV1) -> IndyResult<()> {
+        let nonce = self.nonce.to_dec()?;
+
+        match nonce.parse::<u128>() {
+            Ok(value) if value > 0 && value >> MAX_NONCE_BITS == 0 => {}
+            _ => return Err(err_msg(IndyErrorKind::InvalidStructure,
+                                    format!("Invalid credential offer nonce {}, must be positive and fit in {} bits", nonce, MAX_NONCE_BITS)))
+        }
+
+        if self.cred_def_id.to_unqualified() != cred_def.id.to_unqualified() {
+            return Err(err_msg(IndyErrorKind::InvalidStructure,
+                               format!("Credential offer references credential definition {:?}, but {:?} is provided", self.cred_def_id.0, cred_def.id.0)));
+        }
+
+        Ok(())
+    }
+}
+
+/// Credential offer checked by prover and flattened into the values usually needed to decide on it.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct ParsedCredentialOffer {
+    pub schema_id: SchemaId,
+    pub cred_def_id: CredentialDefinitionId,
+    pub issuer_did: DidValue,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_issuer_did: Option<DidValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_version: Option<String>,
+    pub cred_def_tag: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method_name: Option<String>,
+    pub nonce: String,
+    pub attr_names: Vec<String>,
+    pub revocable: bool,
 }
 
 impl Validatable for CredentialOffer {
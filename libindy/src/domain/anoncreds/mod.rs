@@ -1,17 +1,31 @@
+#[cfg(feature = "anoncreds")]
 pub mod credential;
+#[cfg(feature = "anoncreds")]
 pub mod credential_attr_tag_policy;
 pub mod credential_definition;
+#[cfg(feature = "anoncreds")]
 pub mod credential_for_proof_request;
+#[cfg(feature = "anoncreds")]
 pub mod credential_offer;
+#[cfg(feature = "anoncreds")]
 pub mod credential_request;
+#[cfg(feature = "anoncreds")]
 pub mod proof;
+#[cfg(feature = "anoncreds")]
 pub mod proof_request;
+#[cfg(feature = "anoncreds")]
 pub mod requested_credential;
 pub mod revocation_registry_definition;
 pub mod revocation_registry_delta;
 pub mod revocation_registry;
+#[cfg(feature = "anoncreds")]
 pub mod revocation_state;
 pub mod schema;
+#[cfg(feature = "anoncreds")]
+pub mod verifier_pools;
+#[cfg(feature = "anoncreds")]
+pub mod w3c_credential;
+#[cfg(feature = "anoncreds")]
 pub mod master_secret;
 
 pub const DELIMITER: &str = ":";
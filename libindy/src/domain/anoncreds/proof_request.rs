@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 use std::fmt;
+use regex::Regex;
 use ursa::cl::Nonce;
 
 use indy_api_types::validation::Validatable;
@@ -317,6 +318,9 @@ fn _process_operator(restriction_op: &Query, version: &ProofRequestsVersion) ->
         Query::Not(ref operator) => {
             _process_operator(operator, version)
         }
+        Query::Ext(ref tag_name, ref operator, _) => {
+            Err(format!("Proof Request validation failed: extended operator {} on {:?} can't be used in restrictions", operator, tag_name))
+        }
     }
 }
 
@@ -330,6 +334,184 @@ fn _check_restriction(tag_name: &str, tag_value: &str, version: &ProofRequestsVe
     Ok(())
 }
 
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ProofRequestValidationErrorCode {
+    EmptyRequest,
+    DuplicateReferent,
+    EmptyAttributeName,
+    AttributeNameConflict,
+    InvalidIdentifier,
+    QualifiedIdentifierInV1,
+    UnsupportedOperator,
+    UnsupportedRestriction,
+    InvalidInterval,
+}
+
+/// Problem of a proof request found by static validation.
+/// `referent` is absent for the problems related to the whole proof request.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct ProofRequestValidationError {
+    pub referent: Option<String>,
+    pub code: ProofRequestValidationErrorCode,
+    pub message: String,
+}
+
+impl ProofRequestValidationError {
+    fn new(referent: Option<&str>, code: ProofRequestValidationErrorCode, message: String) -> ProofRequestValidationError {
+        ProofRequestValidationError {
+            referent: referent.map(String::from),
+            code,
+            message,
+        }
+    }
+}
+
+const RESTRICTION_TAGS: [&str; 7] = ["schema_id", "schema_issuer_did", "schema_name", "schema_version", "issuer_did", "cred_def_id", "rev_reg_id"];
+
+lazy_static! {
+    static ref ATTR_RESTRICTION_TAG_MATCHER: Regex = Regex::new("^attr::([^:]+)::(value|marker)$").unwrap();
+}
+
+impl ProofRequest {
+    /// Collects all problems of the proof request instead of stopping on the first one like `validate` does.
+    pub fn validation_errors(&self) -> Vec<ProofRequestValidationError> {
+        let value = self.value();
+        let version = self.version();
+
+        let mut errors = Vec::new();
+
+        if value.requested_attributes.is_empty() && value.requested_predicates.is_empty() {
+            errors.push(ProofRequestValidationError::new(None, ProofRequestValidationErrorCode::EmptyRequest,
+                                                         String::from("Both `requested_attributes` and `requested_predicates` are empty")));
+        }
+
+        if let Some(ref interval) = value.non_revoked {
+            _check_interval(None, interval, &mut errors);
+        }
+
+        let mut referents: Vec<&String> = value.requested_attributes.keys().collect();
+        referents.sort();
+
+        for referent in referents {
+            let requested_attribute = &value.requested_attributes[referent];
+
+            let has_name = !requested_attribute.name.as_ref().map(String::is_empty).unwrap_or(true);
+            let has_names = !requested_attribute.names.as_ref().map(Vec::is_empty).unwrap_or(true);
+
+            if !has_name && !has_names {
+                errors.push(ProofRequestValidationError::new(Some(referent), ProofRequestValidationErrorCode::EmptyAttributeName,
+                                                             String::from("Requested attribute has neither `name` nor `names`")));
+            }
+
+            if has_name && has_names {
+                errors.push(ProofRequestValidationError::new(Some(referent), ProofRequestValidationErrorCode::AttributeNameConflict,
+                                                             String::from("Requested attribute has both `name` and `names`")));
+            }
+
+            if value.requested_predicates.contains_key(referent) {
+                errors.push(ProofRequestValidationError::new(Some(referent), ProofRequestValidationErrorCode::DuplicateReferent,
+                                                             String::from("Referent is used for both requested attribute and requested predicate")));
+            }
+
+            if let Some(ref restrictions) = requested_attribute.restrictions {
+                _check_restriction_operator(referent, restrictions, &version, &mut errors);
+            }
+
+            if let Some(ref interval) = requested_attribute.non_revoked {
+                _check_interval(Some(referent), interval, &mut errors);
+            }
+        }
+
+        let mut referents: Vec<&String> = value.requested_predicates.keys().collect();
+        referents.sort();
+
+        for referent in referents {
+            let requested_predicate = &value.requested_predicates[referent];
+
+            if requested_predicate.name.is_empty() {
+                errors.push(ProofRequestValidationError::new(Some(referent), ProofRequestValidationErrorCode::EmptyAttributeName,
+                                                             String::from("Requested predicate has empty `name`")));
+            }
+
+            if let Some(ref restrictions) = requested_predicate.restrictions {
+                _check_restriction_operator(referent, restrictions, &version, &mut errors);
+            }
+
+            if let Some(ref interval) = requested_predicate.non_revoked {
+                _check_interval(Some(referent), interval, &mut errors);
+            }
+        }
+
+        errors
+    }
+}
+
+fn _check_interval(referent: Option<&str>, interval: &NonRevocedInterval, errors: &mut Vec<ProofRequestValidationError>) {
+    if let (Some(from), Some(to)) = (interval.from, interval.to) {
+        if from > to {
+            errors.push(ProofRequestValidationError::new(referent, ProofRequestValidationErrorCode::InvalidInterval,
+                                                         format!("Non-revocation interval `from` {} is greater than `to` {}", from, to)));
+        }
+    }
+}
+
+fn _check_restriction_operator(referent: &str, restriction_op: &Query, version: &ProofRequestsVersion, errors: &mut Vec<ProofRequestValidationError>) {
+    match restriction_op {
+        Query::Eq(ref tag_name, ref tag_value) |
+        Query::Neq(ref tag_name, ref tag_value) => {
+            _check_restriction_value(referent, tag_name, tag_value, version, errors)
+        }
+        Query::In(ref tag_name, ref tag_values) => {
+            for tag_value in tag_values {
+                _check_restriction_value(referent, tag_name, tag_value, version, errors)
+            }
+        }
+        Query::Gt(ref tag_name, _) |
+        Query::Gte(ref tag_name, _) |
+        Query::Lt(ref tag_name, _) |
+        Query::Lte(ref tag_name, _) |
+        Query::Like(ref tag_name, _) |
+        Query::Ext(ref tag_name, _, _) => {
+            errors.push(ProofRequestValidationError::new(Some(referent), ProofRequestValidationErrorCode::UnsupportedOperator,
+                                                         format!("Restriction on {:?} uses an operator that can't be checked by verifier. \
+                                                                  Only $eq, $neq, $in, $and, $or and $not are supported", tag_name)));
+        }
+        Query::And(ref operators) | Query::Or(ref operators) => {
+            for operator in operators {
+                _check_restriction_operator(referent, operator, version, errors)
+            }
+        }
+        Query::Not(ref operator) => {
+            _check_restriction_operator(referent, operator, version, errors)
+        }
+    }
+}
+
+fn _check_restriction_value(referent: &str, tag_name: &str, tag_value: &str, version: &ProofRequestsVersion, errors: &mut Vec<ProofRequestValidationError>) {
+    if !RESTRICTION_TAGS.contains(&tag_name) && !ATTR_RESTRICTION_TAG_MATCHER.is_match(tag_name) {
+        errors.push(ProofRequestValidationError::new(Some(referent), ProofRequestValidationErrorCode::UnsupportedRestriction,
+                                                     format!("Unknown restriction {:?}", tag_name)));
+        return;
+    }
+
+    let res = match tag_name {
+        "schema_id" => SchemaId(tag_value.to_string()).validate(),
+        "cred_def_id" => CredentialDefinitionId(tag_value.to_string()).validate(),
+        "rev_reg_id" if tag_value != "None" => RevocationRegistryId(tag_value.to_string()).validate(),
+        "issuer_did" | "schema_issuer_did" => DidValue(tag_value.to_string()).validate(),
+        _ => Ok(())
+    };
+
+    if let Err(err) = res {
+        errors.push(ProofRequestValidationError::new(Some(referent), ProofRequestValidationErrorCode::InvalidIdentifier,
+                                                     format!("Invalid {:?} restriction value {:?}: {}", tag_name, tag_value, err)));
+    } else if _check_restriction(tag_name, tag_value, version).is_err() {
+        errors.push(ProofRequestValidationError::new(Some(referent), ProofRequestValidationErrorCode::QualifiedIdentifierInV1,
+                                                     format!("Fully qualified {:?} restriction value {:?} can't be used in proof request of the first version", tag_name, tag_value)));
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -448,4 +630,114 @@ mod tests {
             assert_eq!(ProofRequestsVersion::V2, proof_request.version());
         }
     }
-}
\ No newline at end of file
+
+    mod validation_errors {
+        use super::*;
+
+        fn _proof_request(ver: &str, requested_attributes: serde_json::Value, requested_predicates: serde_json::Value) -> ProofRequest {
+            serde_json::from_value(json!({
+                "nonce": "123432421212",
+                "name": "proof_req_1",
+                "version": "0.1",
+                "ver": ver,
+                "requested_attributes": requested_attributes,
+                "requested_predicates": requested_predicates,
+            })).unwrap()
+        }
+
+        fn _codes(proof_request: &ProofRequest) -> Vec<ProofRequestValidationErrorCode> {
+            proof_request.validation_errors().into_iter().map(|error| error.code).collect()
+        }
+
+        #[test]
+        fn validation_errors_works_for_valid_proof_request() {
+            let proof_request = _proof_request("1.0",
+                                               json!({"attr1_referent": {"name": "name", "restrictions": {"schema_id": "NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0"}}}),
+                                               json!({"predicate1_referent": {"name": "age", "p_type": ">=", "p_value": 18, "non_revoked": {"from": 10, "to": 20}}}));
+            assert!(proof_request.validation_errors().is_empty());
+        }
+
+        #[test]
+        fn validation_errors_works_for_empty_request() {
+            let proof_request = _proof_request("1.0", json!({}), json!({}));
+            assert_eq!(vec![ProofRequestValidationErrorCode::EmptyRequest], _codes(&proof_request));
+        }
+
+        #[test]
+        fn validation_errors_works_for_duplicate_referent() {
+            let proof_request = _proof_request("1.0",
+                                               json!({"referent": {"name": "name"}}),
+                                               json!({"referent": {"name": "age", "p_type": ">=", "p_value": 18}}));
+
+            let errors = proof_request.validation_errors();
+            assert_eq!(1, errors.len());
+            assert_eq!(ProofRequestValidationErrorCode::DuplicateReferent, errors[0].code);
+            assert_eq!(Some("referent".to_string()), errors[0].referent);
+        }
+
+        #[test]
+        fn validation_errors_works_for_invalid_identifiers() {
+            let proof_request = _proof_request("1.0",
+                                               json!({"attr1_referent": {"name": "name", "restrictions": {"$or": [{"schema_id": "invalid"}, {"cred_def_id": "invalid"}]}}}),
+                                               json!({}));
+            assert_eq!(vec![ProofRequestValidationErrorCode::InvalidIdentifier, ProofRequestValidationErrorCode::InvalidIdentifier], _codes(&proof_request));
+        }
+
+        #[test]
+        fn validation_errors_works_for_qualified_identifier_in_v1() {
+            let proof_request = _proof_request("1.0",
+                                               json!({"attr1_referent": {"name": "name", "restrictions": {"issuer_did": "did:sov:NcYxiDXkpYi6ov5FcYDi1e"}}}),
+                                               json!({}));
+            assert_eq!(vec![ProofRequestValidationErrorCode::QualifiedIdentifierInV1], _codes(&proof_request));
+
+            let proof_request = _proof_request("2.0",
+                                               json!({"attr1_referent": {"name": "name", "restrictions": {"issuer_did": "did:sov:NcYxiDXkpYi6ov5FcYDi1e"}}}),
+                                               json!({}));
+            assert!(proof_request.validation_errors().is_empty());
+        }
+
+        #[test]
+        fn validation_errors_works_for_unsupported_operator() {
+            let proof_request = _proof_request("1.0",
+                                               json!({"attr1_referent": {"name": "name", "restrictions": {"schema_version": {"$gt": "1.0"}}}}),
+                                               json!({}));
+            assert_eq!(vec![ProofRequestValidationErrorCode::UnsupportedOperator], _codes(&proof_request));
+        }
+
+        #[test]
+        fn validation_errors_works_for_unsupported_restriction() {
+            let proof_request = _proof_request("1.0",
+                                               json!({"attr1_referent": {"name": "name", "restrictions": {"unknown": "value"}}}),
+                                               json!({}));
+            assert_eq!(vec![ProofRequestValidationErrorCode::UnsupportedRestriction], _codes(&proof_request));
+        }
+
+        #[test]
+        fn validation_errors_works_for_attr_restrictions() {
+            let proof_request = _proof_request("1.0",
+                                               json!({"attr1_referent": {"name": "name", "restrictions": {"attr::name::value": "Alex", "attr::sex::marker": "1"}}}),
+                                               json!({}));
+            assert!(proof_request.validation_errors().is_empty());
+        }
+
+        #[test]
+        fn validation_errors_works_for_invalid_interval() {
+            let proof_request = _proof_request("1.0",
+                                               json!({"attr1_referent": {"name": "name", "non_revoked": {"from": 20, "to": 10}}}),
+                                               json!({}));
+
+            let errors = proof_request.validation_errors();
+            assert_eq!(1, errors.len());
+            assert_eq!(ProofRequestValidationErrorCode::InvalidInterval, errors[0].code);
+            assert_eq!(Some("attr1_referent".to_string()), errors[0].referent);
+        }
+
+        #[test]
+        fn validation_errors_works_for_attribute_names() {
+            let proof_request = _proof_request("1.0",
+                                               json!({"attr1_referent": {"names": []}, "attr2_referent": {"name": "name", "names": ["name", "age"]}}),
+                                               json!({}));
+            assert_eq!(vec![ProofRequestValidationErrorCode::EmptyAttributeName, ProofRequestValidationErrorCode::AttributeNameConflict], _codes(&proof_request));
+        }
+    }
+}
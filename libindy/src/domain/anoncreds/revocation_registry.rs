@@ -1,9 +1,11 @@
 use ursa::cl::RevocationRegistry as CryptoRevocationRegistry;
 
+#[cfg(feature = "anoncreds")]
 use std::collections::HashMap;
 
 use indy_api_types::validation::Validatable;
 
+#[cfg(feature = "anoncreds")]
 use super::revocation_registry_definition::RevocationRegistryId;
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -26,9 +28,11 @@ impl From<RevocationRegistry> for RevocationRegistryV1 {
     }
 }
 
+#[cfg(feature = "anoncreds")]
 pub type RevocationRegistries = HashMap<RevocationRegistryId, HashMap<u64, RevocationRegistry>>;
 
 
+#[cfg(feature = "anoncreds")]
 pub fn rev_regs_map_to_rev_regs_local_map(rev_regs: RevocationRegistries) -> HashMap<RevocationRegistryId, HashMap<u64, RevocationRegistryV1>> {
     rev_regs
         .into_iter()
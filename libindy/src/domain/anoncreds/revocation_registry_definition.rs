@@ -1,9 +1,12 @@
-use ursa::cl::{RevocationKeyPublic, RevocationKeyPrivate};
+use ursa::cl::RevocationKeyPublic;
+#[cfg(feature = "anoncreds")]
+use ursa::cl::RevocationKeyPrivate;
 
 use super::DELIMITER;
 use super::credential_definition::CredentialDefinitionId;
 use super::super::crypto::did::DidValue;
 
+#[cfg(feature = "anoncreds")]
 use std::collections::{HashMap, HashSet};
 
 use indy_api_types::validation::Validatable;
@@ -18,6 +21,7 @@ lazy_static! {
     static ref QUALIFIED_REV_REG_ID: Regex = Regex::new("(^revreg:(?P<method>[a-z0-9]+):)?(?P<did>.+):4:(?P<cred_def_id>.+):(?P<rev_reg_type>.+):(?P<tag>.+)$").unwrap();
 }
 
+#[cfg(feature = "anoncreds")]
 #[derive(Deserialize, Debug, Serialize)]
 pub struct RevocationRegistryConfig {
     pub issuance_type: Option<IssuanceType>,
@@ -31,6 +35,7 @@ pub enum IssuanceType {
     ISSUANCE_ON_DEMAND
 }
 
+#[cfg(feature = "anoncreds")]
 impl IssuanceType {
     pub fn to_bool(&self) -> bool {
         self.clone() == IssuanceType::ISSUANCE_BY_DEFAULT
@@ -84,6 +89,7 @@ pub enum RevocationRegistryDefinition {
     RevocationRegistryDefinitionV1(RevocationRegistryDefinitionV1)
 }
 
+#[cfg(feature = "anoncreds")]
 impl RevocationRegistryDefinition {
     pub fn to_unqualified(self) -> RevocationRegistryDefinition {
         match self {
@@ -108,8 +114,10 @@ impl From<RevocationRegistryDefinition> for RevocationRegistryDefinitionV1 {
     }
 }
 
+#[cfg(feature = "anoncreds")]
 pub type RevocationRegistryDefinitions = HashMap<RevocationRegistryId, RevocationRegistryDefinition>;
 
+#[cfg(feature = "anoncreds")]
 pub fn rev_reg_defs_map_to_rev_reg_defs_v1_map(rev_reg_defs: RevocationRegistryDefinitions) -> HashMap<RevocationRegistryId, RevocationRegistryDefinitionV1> {
     rev_reg_defs
         .into_iter()
@@ -117,11 +125,13 @@ pub fn rev_reg_defs_map_to_rev_reg_defs_v1_map(rev_reg_defs: RevocationRegistryD
         .collect()
 }
 
+#[cfg(feature = "anoncreds")]
 #[derive(Debug, Serialize, Deserialize)]
 pub struct RevocationRegistryDefinitionPrivate {
     pub value: RevocationKeyPrivate
 }
 
+#[cfg(feature = "anoncreds")]
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct RevocationRegistryInfo {
     pub id: RevocationRegistryId,
@@ -159,6 +169,7 @@ impl RevocationRegistryId {
     }
 }
 
+#[cfg(feature = "anoncreds")]
 impl Validatable for RevocationRegistryConfig {
     fn validate(&self) -> Result<(), String> {
         if let Some(num_) = self.max_cred_num {
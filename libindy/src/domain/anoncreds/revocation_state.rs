@@ -3,6 +3,8 @@ use std::collections::HashMap;
 
 use indy_api_types::validation::Validatable;
 
+use super::revocation_registry_definition::RevocationRegistryId;
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct RevocationState {
     pub witness: Witness,
@@ -20,3 +22,36 @@ impl Validatable for RevocationState {
 }
 
 pub type RevocationStates = HashMap<String, HashMap<u64, RevocationState>>;
+
+pub const DEFAULT_REVOCATION_STATE_UPDATE_INTERVAL: u64 = 60;
+
+/// Credentials to keep revocation states fresh for and interval (in seconds) between updates.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct RevocationStateUpdaterConfig {
+    pub cred_ids: Vec<String>,
+    pub update_interval: Option<u64>,
+}
+
+impl Validatable for RevocationStateUpdaterConfig {
+    fn validate(&self) -> Result<(), String> {
+        if self.cred_ids.is_empty() {
+            return Err(String::from("RevocationStateUpdaterConfig validation failed: `credIds` is empty"));
+        }
+
+        if self.update_interval == Some(0) {
+            return Err(String::from("RevocationStateUpdaterConfig validation failed: `updateInterval` must be positive"));
+        }
+
+        Ok(())
+    }
+}
+
+/// The latest revocation state of credential computed by revocation state updater.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CredentialRevocationState {
+    pub rev_reg_id: RevocationRegistryId,
+    pub cred_rev_id: String,
+    pub timestamp: u64,
+    pub rev_state: RevocationState,
+}
@@ -2,7 +2,9 @@ use super::DELIMITER;
 
 use super::super::crypto::did::DidValue;
 
-use std::collections::{HashMap, HashSet};
+#[cfg(feature = "anoncreds")]
+use std::collections::HashMap;
+use std::collections::HashSet;
 
 use indy_api_types::validation::Validatable;
 use crate::utils::qualifier;
@@ -27,6 +29,7 @@ pub enum Schema {
     SchemaV1(SchemaV1)
 }
 
+#[cfg(feature = "anoncreds")]
 impl Schema {
     pub fn to_unqualified(self) -> Schema {
         match self {
@@ -51,8 +54,10 @@ impl From<Schema> for SchemaV1 {
     }
 }
 
+#[cfg(feature = "anoncreds")]
 pub type Schemas = HashMap<SchemaId, Schema>;
 
+#[cfg(feature = "anoncreds")]
 pub fn schemas_map_to_schemas_v1_map(schemas: Schemas) -> HashMap<SchemaId, SchemaV1> {
     schemas.into_iter().map(|(schema_id, schema)| { (schema_id, SchemaV1::from(schema)) }).collect()
 }
@@ -0,0 +1,72 @@
+use std::collections::HashMap;
+
+use indy_api_types::PoolHandle;
+use indy_api_types::validation::Validatable;
+
+use crate::domain::crypto::did::DidValue;
+
+/// Pools to fetch ledger objects referenced by a proof from.
+///
+/// Object is fetched from the pool mapped to DID of its issuer, then to did:indy namespace
+/// of the issuer DID (`sovrin:staging` for `did:indy:sovrin:staging:<id>`), then from the default pool.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct VerifierPools {
+    #[serde(default)]
+    pub pools: HashMap<String, PoolHandle>,
+    pub default: Option<PoolHandle>,
+}
+
+impl VerifierPools {
+    pub fn resolve(&self, issuer_did: &DidValue) -> Option<PoolHandle> {
+        self.pools.get(&issuer_did.0)
+            .or_else(|| self.pools.get(&issuer_did.to_unqualified().0))
+            .or_else(|| issuer_did.get_namespace().and_then(|namespace| self.pools.get(&namespace)))
+            .cloned()
+            .or(self.default)
+    }
+}
+
+impl Validatable for VerifierPools {
+    fn validate(&self) -> Result<(), String> {
+        if self.pools.is_empty() && self.default.is_none() {
+            return Err(String::from("VerifierPools validation failed: neither `pools` nor `default` is set"));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ISSUER_DID: &str = "NcYxiDXkpYi6ov5FcYDi1e";
+
+    fn _pools(json: &str) -> VerifierPools {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn resolve_works_for_issuer_did() {
+        let pools = _pools(r#"{"pools": {"NcYxiDXkpYi6ov5FcYDi1e": 2}, "default": 1}"#);
+
+        assert_eq!(Some(2), pools.resolve(&DidValue(ISSUER_DID.to_string())));
+        assert_eq!(Some(2), pools.resolve(&DidValue(format!("did:sov:{}", ISSUER_DID))));
+        assert_eq!(Some(1), pools.resolve(&DidValue("CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW".to_string())));
+    }
+
+    #[test]
+    fn resolve_works_for_indy_namespace() {
+        let pools = _pools(r#"{"pools": {"sovrin:staging": 2, "sovrin": 3}}"#);
+
+        assert_eq!(Some(2), pools.resolve(&DidValue(format!("did:indy:sovrin:staging:{}", ISSUER_DID))));
+        assert_eq!(Some(3), pools.resolve(&DidValue(format!("did:indy:sovrin:{}", ISSUER_DID))));
+        assert_eq!(None, pools.resolve(&DidValue(format!("did:indy:idunion:{}", ISSUER_DID))));
+    }
+
+    #[test]
+    fn validate_fails_for_empty() {
+        assert!(_pools("{}").validate().is_err());
+    }
+}
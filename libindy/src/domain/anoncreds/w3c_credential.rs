@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+
+use ursa::cl::{
+    CredentialSignature,
+    RevocationRegistry,
+    SignatureCorrectnessProof,
+    Witness
+};
+
+use indy_api_types::validation::Validatable;
+
+use super::credential_definition::CredentialDefinitionId;
+use super::revocation_registry_definition::RevocationRegistryId;
+use super::schema::SchemaId;
+
+pub const W3C_CREDENTIAL_TYPE: &str = "VerifiableCredential";
+pub const W3C_ANONCREDS_CREDENTIAL_TYPE: &str = "AnonCredsCredential";
+pub const W3C_ANONCREDS_SCHEMA_TYPE: &str = "AnonCredsDefinition";
+pub const W3C_ANONCREDS_CRYPTOSUITE: &str = "anoncreds-2023";
+pub const W3C_AUTO_ENCODING: &str = "auto";
+
+/// AnonCreds credential wrapped into W3C Verifiable Credential (as issued by AnonCreds 1.0 W3C compatible stacks).
+/// Only fields required to restore the credential in Indy format are parsed.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct W3CCredential {
+    #[serde(rename = "type")]
+    pub type_: Vec<String>,
+    pub issuer: String,
+    pub credential_schema: W3CCredentialSchema,
+    pub credential_subject: W3CCredentialSubject,
+    pub proof: W3CCredentialProofs,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct W3CCredentialSchema {
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub definition: CredentialDefinitionId,
+    pub schema: SchemaId,
+    #[serde(default)]
+    pub revocation_registry: Option<RevocationRegistryId>,
+    #[serde(default = "default_encoding")]
+    pub encoding: String,
+}
+
+fn default_encoding() -> String {
+    W3C_AUTO_ENCODING.to_string()
+}
+
+/// Raw attribute values. Subject "id" isn't an attribute of AnonCreds credential.
+#[derive(Debug, Deserialize)]
+pub struct W3CCredentialSubject {
+    #[serde(default)]
+    pub id: Option<String>,
+    #[serde(flatten)]
+    pub attributes: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum W3CCredentialProofs {
+    Single(W3CDataIntegrityProof),
+    Multiple(Vec<W3CDataIntegrityProof>),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct W3CDataIntegrityProof {
+    #[serde(rename = "type")]
+    pub type_: String,
+    #[serde(default)]
+    pub cryptosuite: Option<String>,
+    #[serde(default)]
+    pub proof_purpose: Option<String>,
+    pub proof_value: String,
+}
+
+/// Content of `proofValue` of AnonCreds signature proof (multibase base64url encoded json).
+#[derive(Debug, Deserialize)]
+pub struct W3CCredentialSignatureProofValue {
+    pub schema_id: SchemaId,
+    pub cred_def_id: CredentialDefinitionId,
+    pub rev_reg_id: Option<RevocationRegistryId>,
+    pub signature: CredentialSignature,
+    pub signature_correctness_proof: SignatureCorrectnessProof,
+    pub rev_reg: Option<RevocationRegistry>,
+    pub witness: Option<Witness>,
+}
+
+impl W3CCredential {
+    /// AnonCreds signature proof. Presentation proofs can be attached too, so proofs of other purposes are skipped.
+    pub fn signature_proof(&self) -> Option<&W3CDataIntegrityProof> {
+        let proofs = match self.proof {
+            W3CCredentialProofs::Single(ref proof) => ::std::slice::from_ref(proof),
+            W3CCredentialProofs::Multiple(ref proofs) => proofs.as_slice(),
+        };
+
+        proofs.iter()
+            .find(|proof| proof.cryptosuite.as_deref() == Some(W3C_ANONCREDS_CRYPTOSUITE)
+                && proof.proof_purpose.as_ref().map_or(true, |purpose| purpose == "assertionMethod"))
+    }
+}
+
+impl Validatable for W3CCredential {
+    fn validate(&self) -> Result<(), String> {
+        for type_ in &[W3C_CREDENTIAL_TYPE, W3C_ANONCREDS_CREDENTIAL_TYPE] {
+            if !self.type_.iter().any(|t| t == type_) {
+                return Err(format!("W3CCredential validation failed: `type` must contain {:?}", type_));
+            }
+        }
+
+        if self.credential_schema.type_ != W3C_ANONCREDS_SCHEMA_TYPE {
+            return Err(format!("W3CCredential validation failed: unsupported `credentialSchema` type {:?}", self.credential_schema.type_));
+        }
+
+        if self.credential_schema.encoding != W3C_AUTO_ENCODING {
+            return Err(format!("W3CCredential validation failed: unsupported attributes encoding {:?}", self.credential_schema.encoding));
+        }
+
+        self.credential_schema.schema.validate()?;
+        self.credential_schema.definition.validate()?;
+
+        if self.credential_subject.attributes.is_empty() {
+            return Err(String::from("W3CCredential validation failed: `credentialSubject` has no attributes"));
+        }
+
+        if self.signature_proof().is_none() {
+            return Err(format!("W3CCredential validation failed: proof with {:?} cryptosuite not found", W3C_ANONCREDS_CRYPTOSUITE));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn _w3c_credential(proof: serde_json::Value) -> serde_json::Value {
+        json!({
+            "@context": ["https://www.w3.org/2018/credentials/v1"],
+            "type": ["VerifiableCredential", "AnonCredsCredential"],
+            "issuer": "NcYxiDXkpYi6ov5FcYDi1e",
+            "issuanceDate": "2024-01-01T00:00:00Z",
+            "credentialSchema": {
+                "type": "AnonCredsDefinition",
+                "definition": "NcYxiDXkpYi6ov5FcYDi1e:3:CL:NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0:tag",
+                "schema": "NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0",
+                "encoding": "auto"
+            },
+            "credentialSubject": {"name": "Alex", "age": 28},
+            "proof": proof
+        })
+    }
+
+    fn _proof(purpose: &str) -> serde_json::Value {
+        json!({
+            "type": "DataIntegrityProof",
+            "cryptosuite": "anoncreds-2023",
+            "proofPurpose": purpose,
+            "verificationMethod": "NcYxiDXkpYi6ov5FcYDi1e:3:CL:NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0:tag",
+            "proofValue": format!("u{}", purpose)
+        })
+    }
+
+    #[test]
+    fn w3c_credential_deserialize_works() {
+        let credential: W3CCredential = serde_json::from_value(_w3c_credential(_proof("assertionMethod"))).unwrap();
+
+        credential.validate().unwrap();
+        assert_eq!(2, credential.credential_subject.attributes.len());
+        assert_eq!("uassertionMethod", credential.signature_proof().unwrap().proof_value);
+    }
+
+    #[test]
+    fn w3c_credential_signature_proof_works_for_multiple_proofs() {
+        let credential: W3CCredential = serde_json::from_value(_w3c_credential(json!([_proof("authentication"), _proof("assertionMethod")]))).unwrap();
+
+        assert_eq!("uassertionMethod", credential.signature_proof().unwrap().proof_value);
+    }
+
+    #[test]
+    fn w3c_credential_validate_works_for_unsupported_encoding() {
+        let mut credential = _w3c_credential(_proof("assertionMethod"));
+        credential["credentialSchema"]["encoding"] = json!("other");
+
+        let credential: W3CCredential = serde_json::from_value(credential).unwrap();
+
+        assert!(credential.validate().is_err());
+    }
+
+    #[test]
+    fn w3c_credential_validate_works_for_not_anoncreds_credential() {
+        let mut credential = _w3c_credential(json!([_proof("authentication")]));
+
+        let res: W3CCredential = serde_json::from_value(credential.clone()).unwrap();
+        assert!(res.validate().is_err());
+
+        credential["type"] = json!(["VerifiableCredential"]);
+        credential["proof"] = _proof("assertionMethod");
+
+        let res: W3CCredential = serde_json::from_value(credential).unwrap();
+        assert!(res.validate().is_err());
+    }
+}
@@ -1,14 +1,110 @@
+use indy_api_types::validation::Validatable;
+
+use super::anoncreds::schema::SchemaId;
+use super::anoncreds::credential_definition::CredentialDefinitionId;
+use super::anoncreds::revocation_registry_definition::RevocationRegistryId;
+
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PurgeOptions {
     pub max_age: Option<i32>,
+    pub pool_name: Option<String>,
 }
 
-#[derive(Debug, Deserialize, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct GetCacheOptions {
     pub no_cache: Option<bool>,     // Skip usage of cache,
     pub no_update: Option<bool>,    // Use only cached data, do not try to update.
     pub no_store: Option<bool>,     // Skip storing fresh data if updated
     pub min_fresh: Option<i32>,     // Return cached data if not older than this many seconds. -1 means do not check age.
-}
\ No newline at end of file
+    pub ttl: Option<i32>,           // Time to live of stored data in seconds. Expired data is removed on read. -1 means never expire.
+    pub max_entries: Option<usize>, // Max count of entries in cache. Entries are evicted on store according to eviction policy.
+    pub eviction_policy: Option<EvictionPolicy>, // Which entries are evicted when max entries is exceeded.
+    pub max_entry_size: Option<usize>, // Data bigger than this many bytes is not stored.
+    pub not_found_ttl: Option<i32>,   // Time to live of cached "not found" results in seconds. 0 disables negative caching.
+    pub no_negative_cache: Option<bool>, // Ignore cached "not found" results.
+}
+
+impl GetCacheOptions {
+    /// Fills options that are not set by the call with defaults of wallet cache config.
+    pub fn with_defaults(self, config: &CacheConfig) -> GetCacheOptions {
+        let negative_cache = config.negative_cache.unwrap_or(true);
+
+        GetCacheOptions {
+            ttl: self.ttl.or(config.ttl),
+            max_entries: self.max_entries.or(config.max_entries),
+            eviction_policy: self.eviction_policy.or(config.eviction_policy),
+            max_entry_size: self.max_entry_size.or(config.max_entry_size),
+            not_found_ttl: self.not_found_ttl.or(if negative_cache { config.not_found_ttl } else { Some(0) }),
+            no_negative_cache: self.no_negative_cache.or(if negative_cache { None } else { Some(true) }),
+            ..self
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum EvictionPolicy {
+    Lru, // Least recently used entries are evicted
+    Lfu, // Least frequently used entries are evicted, least recently used ones among equally used
+}
+
+/// Defaults of ledger cache options for all calls with the wallet.
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct CacheConfig {
+    pub include: Option<Vec<String>>, // Object kinds to cache, all by default.
+    pub ttl: Option<i32>,
+    pub max_entries: Option<usize>,
+    pub eviction_policy: Option<EvictionPolicy>,
+    pub max_entry_size: Option<usize>,
+    pub negative_cache: Option<bool>, // false disables storing and usage of "not found" results.
+    pub not_found_ttl: Option<i32>,
+}
+
+impl CacheConfig {
+    pub fn is_included(&self, kind: &str) -> bool {
+        self.include.as_ref()
+            .map(|include| include.iter().any(|included| included == kind))
+            .unwrap_or(true)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PrefetchIds {
+    #[serde(default)]
+    pub schemas: Vec<SchemaId>,
+    #[serde(default)]
+    pub cred_defs: Vec<CredentialDefinitionId>,
+    #[serde(default)]
+    pub rev_reg_defs: Vec<RevocationRegistryId>,
+}
+
+impl Validatable for PrefetchIds {
+    fn validate(&self) -> Result<(), String> {
+        for id in self.schemas.iter() {
+            id.validate()?;
+        }
+        for id in self.cred_defs.iter() {
+            id.validate()?;
+        }
+        for id in self.rev_reg_defs.iter() {
+            id.validate()?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize, Default)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub evictions: u64,
+    pub oversized: u64,
+    pub entries: usize,
+    pub size: usize,
+    pub oldest: Option<i32>,
+    pub newest: Option<i32>,
+}
@@ -1,18 +1,30 @@
 use regex::Regex;
 use rust_base58::FromBase58;
 
+use indy_api_types::domain::wallet::Tags;
 use indy_api_types::validation::Validatable;
 use crate::utils::qualifier;
+use super::did_doc::RESERVED_FRAGMENTS;
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct DidMethod(pub String);
 
+impl DidMethod {
+    /// Namespaced did:indy method: `indy:<namespace>[:<sub-namespace>]`.
+    /// Plain `indy` method (without namespace) is kept as regular one for backward compatibility.
+    pub fn is_indy(&self) -> bool {
+        self.0.starts_with("indy:")
+    }
+}
+
 impl Validatable for DidMethod {
     fn validate(&self) -> Result<(), String> {
         lazy_static! {
                 static ref REGEX_METHOD_NAME: Regex = Regex::new("^[a-z0-9]+$").unwrap();
+                // indy method must be followed by the namespace: indy:<namespace>[:<sub-namespace>]
+                static ref REGEX_INDY_METHOD_NAME: Regex = Regex::new("^indy(:[a-z0-9_-]+)+$").unwrap();
             }
-        if !REGEX_METHOD_NAME.is_match(&self.0) {
+        if !REGEX_METHOD_NAME.is_match(&self.0) && !REGEX_INDY_METHOD_NAME.is_match(&self.0) {
             return Err(format!("Invalid default name: {}. It does not match the DID method name format.", self.0));
         }
         Ok(())
@@ -99,6 +111,16 @@ impl DidValue {
         DidValue(qualifier::to_unqualified(&self.0))
     }
 
+    /// Returns namespace of did:indy DID (`sovrin:staging` for `did:indy:sovrin:staging:<id>`).
+    pub fn get_namespace(&self) -> Option<String> {
+        self.get_method()
+            .and_then(|method| method.strip_prefix("indy:").map(String::from))
+    }
+
+    pub fn is_indy(&self) -> bool {
+        self.get_namespace().is_some()
+    }
+
     pub fn is_abbreviatable(&self) -> bool {
         match self.get_method() {
             Some(ref method) if method.starts_with("sov") => true,
@@ -110,7 +132,15 @@ impl DidValue {
 
 impl Validatable for DidValue {
     fn validate(&self) -> Result<(), String> {
-        if self.is_fully_qualified() {
+        if self.is_indy() {
+            let did = self.to_unqualified().0.from_base58()
+                .map_err(|err| err.to_string())?;
+
+            if did.len() != 16 {
+                return Err(format!("Trying to use did:indy DID with unexpected identifier length: {}. \
+                               The identifier should be 16-byte number encoded as base58.", did.len()));
+            }
+        } else if self.is_fully_qualified() {
             // pass
         } else {
             let did = self.0.from_base58()
@@ -156,6 +186,110 @@ pub struct DidMetadata {
     pub value: String
 }
 
+impl DidMetadata {
+    /// Tags my DID is searched by: all top level string fields of metadata if it is a JSON object.
+    /// Fields with "~" prefix are stored un-encrypted.
+    pub fn tags(&self) -> Tags {
+        let mut tags = Tags::new();
+
+        if let Ok(serde_json::Value::Object(values)) = serde_json::from_str(&self.value) {
+            for (name, value) in values {
+                if let serde_json::Value::String(value) = value {
+                    tags.insert(name, value);
+                }
+            }
+        }
+
+        tags
+    }
+}
+
+/// Marks DID as deactivated. Presence of the record is the only information.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct DidDeactivation {}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum DidKeyPurpose {
+    Authentication,
+    AssertionMethod,
+    KeyAgreement,
+}
+
+/// Additional verification key bound to the DID (besides DID verkey).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DidKey {
+    pub id: String,
+    pub purpose: DidKeyPurpose,
+    pub verkey: String,
+}
+
+impl Validatable for DidKey {
+    fn validate(&self) -> Result<(), String> {
+        validate_fragment(&self.id)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct DidKeys {
+    pub value: Vec<DidKey>
+}
+
+/// Additional service endpoint bound to the DID (besides endpoint attrib).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DidService {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub service_endpoint: String,
+    #[serde(default)]
+    pub recipient_keys: Vec<String>,
+    #[serde(default)]
+    pub routing_keys: Vec<String>,
+    #[serde(default)]
+    pub priority: u32,
+}
+
+impl Validatable for DidService {
+    fn validate(&self) -> Result<(), String> {
+        validate_fragment(&self.id)?;
+
+        if self.type_.is_empty() {
+            return Err("Empty service type".to_string());
+        }
+
+        if self.service_endpoint.is_empty() {
+            return Err("Empty service endpoint".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct DidServices {
+    pub value: Vec<DidService>
+}
+
+/// Ids of keys and services are DID URL fragments (without leading `#`).
+/// Fragments used for DID verkey and endpoint attrib are reserved.
+pub fn validate_fragment(id: &str) -> Result<(), String> {
+    lazy_static! {
+            static ref REGEX_FRAGMENT: Regex = Regex::new("^[a-zA-Z0-9._-]+$").unwrap();
+        }
+
+    if !REGEX_FRAGMENT.is_match(id) {
+        return Err(format!("Invalid id: {}. It must be non-empty DID URL fragment without leading `#`.", id));
+    }
+
+    if RESERVED_FRAGMENTS.contains(&id) {
+        return Err(format!("Invalid id: {}. It is reserved for DID verkey or endpoint.", id));
+    }
+
+    Ok(())
+}
+
 #[derive(Serialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct DidWithMeta {
@@ -163,6 +297,54 @@ pub struct DidWithMeta {
     pub verkey: String,
     pub temp_verkey: Option<String>,
     pub metadata: Option<String>,
+    #[serde(skip_serializing_if = "::std::ops::Not::not")]
+    pub deactivated: bool,
+}
+
+fn default_true() -> bool { true }
+
+/// Options of my DIDs search. Skipping of metadata and temporary verkey
+/// allows to avoid additional wallet lookups for each fetched DID.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DidSearchOptions {
+    #[serde(default = "default_true")]
+    pub retrieve_metadata: bool,
+    #[serde(default = "default_true")]
+    pub retrieve_temp_verkey: bool,
+}
+
+impl Default for DidSearchOptions {
+    fn default() -> DidSearchOptions {
+        DidSearchOptions {
+            retrieve_metadata: true,
+            retrieve_temp_verkey: true,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct QualifyDidsOptions {
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DidQualification {
+    pub did: DidValue,
+    pub qualified_did: DidValue,
+}
+
+/// Report of wallet-wide DIDs qualification. Nothing is written to the wallet in dry run mode.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DidsQualificationReport {
+    pub dry_run: bool,
+    pub my_dids: Vec<DidQualification>,
+    pub their_dids: Vec<DidQualification>,
+    pub pairwise_count: usize,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -185,3 +367,120 @@ impl From<TemporaryDid> for Did {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ID: &str = "NcYxiDXkpYi6ov5FcYDi1e";
+
+    #[test]
+    fn did_value_works_for_indy_did() {
+        let did = DidValue(format!("did:indy:sovrin:{}", ID));
+
+        assert!(did.is_fully_qualified());
+        assert!(did.is_indy());
+        assert_eq!(Some("indy:sovrin".to_string()), did.get_method());
+        assert_eq!(Some("sovrin".to_string()), did.get_namespace());
+        assert_eq!(ID, did.to_unqualified().0);
+        assert_eq!(ID, did.to_short().0);
+        assert!(!did.is_abbreviatable());
+        assert!(did.validate().is_ok());
+    }
+
+    #[test]
+    fn did_value_works_for_indy_did_with_sub_namespace() {
+        let did = DidValue(format!("did:indy:sovrin:staging:{}", ID));
+
+        assert_eq!(Some("indy:sovrin:staging".to_string()), did.get_method());
+        assert_eq!(Some("sovrin:staging".to_string()), did.get_namespace());
+        assert_eq!(ID, did.to_unqualified().0);
+    }
+
+    #[test]
+    fn did_value_qualify_works_for_indy_method() {
+        let did = DidValue(ID.to_string()).qualify("indy:sovrin:staging");
+        assert_eq!(format!("did:indy:sovrin:staging:{}", ID), did.0);
+    }
+
+    #[test]
+    fn did_value_works_for_sov_did() {
+        let did = DidValue(format!("did:sov:{}", ID));
+
+        assert!(!did.is_indy());
+        assert_eq!(Some("sov".to_string()), did.get_method());
+        assert_eq!(None, did.get_namespace());
+        assert_eq!(ID, did.to_unqualified().0);
+    }
+
+    #[test]
+    fn did_value_validate_not_works_for_invalid_indy_identifier() {
+        let did = DidValue("did:indy:sovrin:CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW".to_string());
+        assert!(did.validate().is_err());
+    }
+
+    #[test]
+    fn did_key_validate_works() {
+        let key = |id: &str| DidKey { id: id.to_string(), purpose: DidKeyPurpose::Authentication, verkey: ID.to_string() };
+
+        assert!(key("key-2").validate().is_ok());
+        assert!(key("").validate().is_err());
+        assert!(key("#key-2").validate().is_err());
+        assert!(key("key-1").validate().is_err());
+    }
+
+    #[test]
+    fn did_service_deserialize_works_for_defaults() {
+        let service: DidService = serde_json::from_str(r#"{"id":"agent","type":"did-communication","serviceEndpoint":"http://localhost:8080"}"#).unwrap();
+
+        assert!(service.validate().is_ok());
+        assert!(service.recipient_keys.is_empty());
+        assert!(service.routing_keys.is_empty());
+        assert_eq!(0, service.priority);
+    }
+
+    #[test]
+    fn did_service_validate_not_works_for_reserved_id() {
+        let service: DidService = serde_json::from_str(r#"{"id":"did-communication","type":"did-communication","serviceEndpoint":"http://localhost:8080"}"#).unwrap();
+        assert!(service.validate().is_err());
+    }
+
+    #[test]
+    fn did_metadata_tags_works() {
+        let metadata = DidMetadata { value: r#"{"label":"Alice","~peer":"bob","count":1}"#.to_string() };
+        let tags = metadata.tags();
+
+        assert_eq!(2, tags.len());
+        assert_eq!("Alice", tags["label"]);
+        assert_eq!("bob", tags["~peer"]);
+
+        assert!(DidMetadata { value: "not json".to_string() }.tags().is_empty());
+    }
+
+    #[test]
+    fn did_search_options_deserialize_works_for_defaults() {
+        let options: DidSearchOptions = serde_json::from_str(r#"{"retrieveTempVerkey":false}"#).unwrap();
+
+        assert!(options.retrieve_metadata);
+        assert!(!options.retrieve_temp_verkey);
+    }
+
+    #[test]
+    fn qualify_dids_options_deserialize_works_for_defaults() {
+        let options: QualifyDidsOptions = serde_json::from_str("{}").unwrap();
+        assert!(!options.dry_run);
+
+        let options: QualifyDidsOptions = serde_json::from_str(r#"{"dryRun":true}"#).unwrap();
+        assert!(options.dry_run);
+    }
+
+    #[test]
+    fn did_method_validate_works() {
+        assert!(DidMethod("sov".to_string()).validate().is_ok());
+        assert!(DidMethod("indy:sovrin".to_string()).validate().is_ok());
+        assert!(DidMethod("indy:sovrin:staging".to_string()).validate().is_ok());
+        assert!(DidMethod("indy".to_string()).validate().is_ok());
+        assert!(DidMethod("indy:".to_string()).validate().is_err());
+        assert!(DidMethod("sov:sovrin".to_string()).validate().is_err());
+    }
+}
@@ -0,0 +1,68 @@
+use std::collections::HashMap;
+
+use super::did::{Did, DidKeys, DidServices, TemporaryDid, TheirDid};
+use super::key::Key;
+use super::super::ledger::attrib::{Endpoint, NamedEndpoints};
+use super::super::pairwise::PairwiseMeta;
+
+pub const DID_BUNDLE_VERSION: u32 = 1;
+
+/// My DID with all related wallet records.
+/// It is moved between wallets as anonymously encrypted json (see "indy_export_did" and "indy_import_did").
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidBundle {
+    pub version: u32,
+    pub did: Did,
+    #[serde(default)]
+    pub tags: HashMap<String, String>,
+    /// Private parts of DID verkey, temporary verkey and additional keys stored in the wallet.
+    pub keys: Vec<Key>,
+    pub temporary_did: Option<TemporaryDid>,
+    pub metadata: Option<String>,
+    pub endpoint: Option<Endpoint>,
+    pub did_keys: Option<DidKeys>,
+    pub did_services: Option<DidServices>,
+    #[serde(default)]
+    pub named_endpoints: Option<NamedEndpoints>,
+    #[serde(default)]
+    pub deactivated: bool,
+    #[serde(default)]
+    pub pairwise: Vec<PairwiseBundle>,
+}
+
+/// Pairwise of the exported DID with their DID record.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PairwiseBundle {
+    pub their_did: TheirDid,
+    pub metadata: Option<String>,
+    #[serde(default, skip_serializing_if = "PairwiseMeta::is_empty")]
+    pub meta: PairwiseMeta,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::did::DidValue;
+
+    #[test]
+    fn did_bundle_deserialize_works_for_minimal_bundle() {
+        let bundle = json!({
+            "version": DID_BUNDLE_VERSION,
+            "did": {"did": "NcYxiDXkpYi6ov5FcYDi1e", "verkey": "CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW"},
+            "keys": [],
+            "temporaryDid": null,
+            "metadata": null,
+            "endpoint": null,
+            "didKeys": null,
+            "didServices": null
+        });
+
+        let bundle: DidBundle = serde_json::from_value(bundle).unwrap();
+
+        assert_eq!(DidValue("NcYxiDXkpYi6ov5FcYDi1e".to_string()), bundle.did.did);
+        assert!(bundle.tags.is_empty());
+        assert!(bundle.pairwise.is_empty());
+    }
+}
@@ -0,0 +1,270 @@
+#[cfg(feature = "ledger")]
+use super::did::{DidValue, DidKey, DidKeyPurpose, DidService};
+#[cfg(feature = "ledger")]
+use super::super::ledger::attrib::{Endpoint, NamedEndpoint};
+
+#[cfg(feature = "ledger")]
+pub const DID_DOC_CONTEXT: &str = "https://www.w3.org/ns/did/v1";
+#[cfg(feature = "ledger")]
+pub const DEFAULT_DID_DOC_METHOD: &str = "sov";
+#[cfg(feature = "ledger")]
+pub const ED25519_VERIFICATION_KEY_TYPE: &str = "Ed25519VerificationKey2018";
+#[cfg(feature = "ledger")]
+pub const X25519_KEY_AGREEMENT_KEY_TYPE: &str = "X25519KeyAgreementKey2019";
+#[cfg(feature = "ledger")]
+pub const DID_COMMUNICATION_SERVICE_TYPE: &str = "did-communication";
+pub const DID_KEY_FRAGMENT: &str = "key-1";
+pub const DID_KEY_AGREEMENT_FRAGMENT: &str = "key-agreement-1";
+pub const DID_COMMUNICATION_FRAGMENT: &str = "did-communication";
+pub const RESERVED_FRAGMENTS: [&str; 3] = [DID_KEY_FRAGMENT, DID_KEY_AGREEMENT_FRAGMENT, DID_COMMUNICATION_FRAGMENT];
+
+#[cfg(feature = "ledger")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationMethod {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub controller: String,
+    pub public_key_base58: String,
+}
+
+#[cfg(feature = "ledger")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DidDocService {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub service_endpoint: String,
+    pub recipient_keys: Vec<String>,
+    pub routing_keys: Vec<String>,
+    pub priority: u32,
+}
+
+#[cfg(feature = "ledger")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DidDocument {
+    #[serde(rename = "@context")]
+    pub context: String,
+    pub id: String,
+    pub verification_method: Vec<VerificationMethod>,
+    pub authentication: Vec<String>,
+    pub assertion_method: Vec<String>,
+    pub key_agreement: Vec<VerificationMethod>,
+    pub service: Vec<DidDocService>,
+    #[serde(default, skip_serializing_if = "::std::ops::Not::not")]
+    pub deactivated: bool,
+}
+
+#[cfg(feature = "ledger")]
+impl DidDocument {
+    /// Builds DID Document from DID verkey, X25519 key derived from it and optional endpoint.
+    /// Unqualified DIDs are represented with `did:sov` prefix as required by W3C DID syntax.
+    /// Endpoint transport key (if set) is used as service recipient key instead of DID verkey.
+    pub fn new(did: &DidValue, verkey: &str, x25519_key: &str, endpoint: Option<&Endpoint>) -> DidDocument {
+        let id = if did.is_fully_qualified() {
+            did.0.clone()
+        } else {
+            did.qualify(DEFAULT_DID_DOC_METHOD).0
+        };
+
+        let key_id = format!("{}#{}", id, DID_KEY_FRAGMENT);
+
+        let verification_method = VerificationMethod {
+            id: key_id.clone(),
+            type_: ED25519_VERIFICATION_KEY_TYPE.to_string(),
+            controller: id.clone(),
+            public_key_base58: verkey.to_string(),
+        };
+
+        let key_agreement = VerificationMethod {
+            id: format!("{}#{}", id, DID_KEY_AGREEMENT_FRAGMENT),
+            type_: X25519_KEY_AGREEMENT_KEY_TYPE.to_string(),
+            controller: id.clone(),
+            public_key_base58: x25519_key.to_string(),
+        };
+
+        let service = endpoint
+            .map(|endpoint| vec![DidDocService {
+                id: format!("{}#{}", id, DID_COMMUNICATION_FRAGMENT),
+                type_: DID_COMMUNICATION_SERVICE_TYPE.to_string(),
+                service_endpoint: endpoint.ha.clone(),
+                recipient_keys: vec![endpoint.verkey.clone().unwrap_or_else(|| verkey.to_string())],
+                routing_keys: Vec::new(),
+                priority: 0,
+            }])
+            .unwrap_or_default();
+
+        DidDocument {
+            context: DID_DOC_CONTEXT.to_string(),
+            id,
+            verification_method: vec![verification_method],
+            authentication: vec![key_id.clone()],
+            assertion_method: vec![key_id],
+            key_agreement: vec![key_agreement],
+            service,
+            deactivated: false,
+        }
+    }
+
+    /// Adds additional key stored for the DID.
+    /// `public_key` is expected to be already converted to X25519 for key agreement keys.
+    pub fn add_key(&mut self, key: &DidKey, public_key: &str) {
+        let key_id = format!("{}#{}", self.id, key.id);
+
+        let type_ = match key.purpose {
+            DidKeyPurpose::KeyAgreement => X25519_KEY_AGREEMENT_KEY_TYPE,
+            _ => ED25519_VERIFICATION_KEY_TYPE
+        };
+
+        let verification_method = VerificationMethod {
+            id: key_id.clone(),
+            type_: type_.to_string(),
+            controller: self.id.clone(),
+            public_key_base58: public_key.to_string(),
+        };
+
+        match key.purpose {
+            DidKeyPurpose::Authentication => {
+                self.verification_method.push(verification_method);
+                self.authentication.push(key_id);
+            }
+            DidKeyPurpose::AssertionMethod => {
+                self.verification_method.push(verification_method);
+                self.assertion_method.push(key_id);
+            }
+            DidKeyPurpose::KeyAgreement => self.key_agreement.push(verification_method)
+        }
+    }
+
+    /// Adds additional service stored for the DID.
+    pub fn add_service(&mut self, service: &DidService) {
+        self.service.push(DidDocService {
+            id: format!("{}#{}", self.id, service.id),
+            type_: service.type_.clone(),
+            service_endpoint: service.service_endpoint.clone(),
+            recipient_keys: service.recipient_keys.clone(),
+            routing_keys: service.routing_keys.clone(),
+            priority: service.priority,
+        });
+    }
+
+    /// Adds named endpoint stored for the DID as DIDComm service.
+    /// DID verkey is used as recipient key if endpoint verkey isn't set.
+    pub fn add_named_endpoint(&mut self, endpoint: &NamedEndpoint, verkey: &str) {
+        self.service.push(DidDocService {
+            id: format!("{}#{}", self.id, endpoint.name),
+            type_: DID_COMMUNICATION_SERVICE_TYPE.to_string(),
+            service_endpoint: endpoint.endpoint.clone(),
+            recipient_keys: vec![endpoint.verkey.clone().unwrap_or_else(|| verkey.to_string())],
+            routing_keys: endpoint.routing_keys.clone(),
+            priority: endpoint.priority,
+        });
+    }
+}
+
+#[cfg(all(test, feature = "ledger"))]
+mod tests {
+    use super::*;
+    use super::super::super::ledger::attrib::EndpointTransport;
+
+    const DID: &str = "NcYxiDXkpYi6ov5FcYDi1e";
+    const VERKEY: &str = "CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW";
+    const X25519_KEY: &str = "9Z5bRmBNtYJAnb3UnAvEvawGaYcu9gRHZsdxkQY9HkVz";
+
+    #[test]
+    fn did_doc_new_works_for_unqualified_did() {
+        let doc = DidDocument::new(&DidValue(DID.to_string()), VERKEY, X25519_KEY, None);
+
+        assert_eq!(doc.id, format!("did:sov:{}", DID));
+        assert_eq!(doc.verification_method[0].id, format!("did:sov:{}#key-1", DID));
+        assert_eq!(doc.verification_method[0].public_key_base58, VERKEY);
+        assert_eq!(doc.key_agreement[0].public_key_base58, X25519_KEY);
+        assert_eq!(doc.authentication, vec![format!("did:sov:{}#key-1", DID)]);
+        assert!(doc.service.is_empty());
+    }
+
+    #[test]
+    fn did_doc_new_works_for_fully_qualified_did_and_endpoint() {
+        let did = DidValue(format!("did:peer:{}", DID));
+        let endpoint = Endpoint::new("127.0.0.1:9700".to_string(), Some(X25519_KEY.to_string()));
+
+        let doc = DidDocument::new(&did, VERKEY, X25519_KEY, Some(&endpoint));
+
+        assert_eq!(doc.id, did.0);
+        assert_eq!(doc.service.len(), 1);
+        assert_eq!(doc.service[0].service_endpoint, "127.0.0.1:9700");
+        assert_eq!(doc.service[0].recipient_keys, vec![X25519_KEY.to_string()]);
+        assert!(doc.service[0].routing_keys.is_empty());
+    }
+
+    #[test]
+    fn did_doc_add_key_works() {
+        let mut doc = DidDocument::new(&DidValue(DID.to_string()), VERKEY, X25519_KEY, None);
+
+        doc.add_key(&DidKey { id: "key-2".to_string(), purpose: DidKeyPurpose::AssertionMethod, verkey: VERKEY.to_string() }, VERKEY);
+        doc.add_key(&DidKey { id: "key-3".to_string(), purpose: DidKeyPurpose::KeyAgreement, verkey: VERKEY.to_string() }, X25519_KEY);
+
+        assert_eq!(doc.verification_method.len(), 2);
+        assert_eq!(doc.verification_method[1].id, format!("did:sov:{}#key-2", DID));
+        assert_eq!(doc.assertion_method, vec![format!("did:sov:{}#key-1", DID), format!("did:sov:{}#key-2", DID)]);
+        assert_eq!(doc.authentication.len(), 1);
+        assert_eq!(doc.key_agreement.len(), 2);
+        assert_eq!(doc.key_agreement[1].type_, X25519_KEY_AGREEMENT_KEY_TYPE);
+        assert_eq!(doc.key_agreement[1].public_key_base58, X25519_KEY);
+    }
+
+    #[test]
+    fn did_doc_add_service_works() {
+        let mut doc = DidDocument::new(&DidValue(DID.to_string()), VERKEY, X25519_KEY, None);
+
+        doc.add_service(&DidService {
+            id: "mediator".to_string(),
+            type_: DID_COMMUNICATION_SERVICE_TYPE.to_string(),
+            service_endpoint: "https://mediator.example.com".to_string(),
+            recipient_keys: vec![VERKEY.to_string()],
+            routing_keys: vec![X25519_KEY.to_string()],
+            priority: 1,
+        });
+
+        assert_eq!(doc.service.len(), 1);
+        assert_eq!(doc.service[0].id, format!("did:sov:{}#mediator", DID));
+        assert_eq!(doc.service[0].routing_keys, vec![X25519_KEY.to_string()]);
+        assert_eq!(doc.service[0].priority, 1);
+    }
+
+    #[test]
+    fn did_doc_add_named_endpoint_works() {
+        let mut doc = DidDocument::new(&DidValue(DID.to_string()), VERKEY, X25519_KEY, None);
+
+        doc.add_named_endpoint(&NamedEndpoint {
+            name: "agent".to_string(),
+            endpoint: "wss://agent.example.com".to_string(),
+            transport: EndpointTransport::Wss,
+            verkey: None,
+            routing_keys: vec![X25519_KEY.to_string()],
+            priority: 2,
+        }, VERKEY);
+
+        assert_eq!(doc.service.len(), 1);
+        assert_eq!(doc.service[0].id, format!("did:sov:{}#agent", DID));
+        assert_eq!(doc.service[0].type_, DID_COMMUNICATION_SERVICE_TYPE);
+        assert_eq!(doc.service[0].recipient_keys, vec![VERKEY.to_string()]);
+        assert_eq!(doc.service[0].routing_keys, vec![X25519_KEY.to_string()]);
+        assert_eq!(doc.service[0].priority, 2);
+    }
+
+    #[test]
+    fn did_doc_serialization_uses_w3c_field_names() {
+        let doc = DidDocument::new(&DidValue(DID.to_string()), VERKEY, X25519_KEY, None);
+        let json = serde_json::to_value(&doc).unwrap();
+
+        assert_eq!(json["@context"], DID_DOC_CONTEXT);
+        assert_eq!(json["verificationMethod"][0]["type"], ED25519_VERIFICATION_KEY_TYPE);
+        assert_eq!(json["keyAgreement"][0]["type"], X25519_KEY_AGREEMENT_KEY_TYPE);
+        assert!(json["verificationMethod"][0]["publicKeyBase58"].is_string());
+        assert!(json.get("deactivated").is_none());
+    }
+}
@@ -1,4 +1,6 @@
 pub mod key;
 pub mod did;
+pub mod did_doc;
+pub mod did_bundle;
 pub mod combo_box;
 pub mod pack;
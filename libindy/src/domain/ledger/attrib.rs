@@ -1,7 +1,27 @@
+use indy_api_types::validation::Validatable;
+
+#[cfg(feature = "ledger")]
 use super::constants::{ATTRIB, GET_ATTR};
-use super::response::GetReplyResultV1;
+#[cfg(feature = "ledger")]
+use super::response::{GetReplyResultV1, ReplyType};
+#[cfg(feature = "ledger")]
 use super::super::crypto::did::ShortDidValue;
+use super::super::crypto::did::validate_fragment;
+
+#[cfg(feature = "ledger")]
+pub const ENDPOINT_ATTRIB_NAME: &str = "endpoint";
+#[cfg(feature = "ledger")]
+pub const ENDPOINTS_ATTRIB_NAME: &str = "endpoints";
+#[cfg(feature = "ledger")]
+pub const DIDDOC_CONTENT_ATTRIB_NAME: &str = "diddocContent";
+
+#[cfg(feature = "ledger")]
+const ENDPOINT_ATTRIB_FIELDS: [&str; 5] = ["ha", "verkey", "endpoint", "routingKeys", "types"];
+#[cfg(feature = "ledger")]
+const DIDDOC_CONTENT_ARRAY_FIELDS: [&str; 7] = ["service", "verificationMethod", "authentication", "assertionMethod",
+    "keyAgreement", "capabilityInvocation", "capabilityDelegation"];
 
+#[cfg(feature = "ledger")]
 #[derive(Serialize, PartialEq, Debug)]
 pub struct AttribOperation {
     #[serde(rename = "type")]
@@ -15,6 +35,7 @@ pub struct AttribOperation {
     pub enc: Option<String>
 }
 
+#[cfg(feature = "ledger")]
 impl AttribOperation {
     pub fn new(dest: ShortDidValue, hash: Option<String>, raw: Option<String>,
                enc: Option<String>) -> AttribOperation {
@@ -28,7 +49,9 @@ impl AttribOperation {
     }
 }
 
+#[cfg(feature = "ledger")]
 #[derive(Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct GetAttribOperation {
     #[serde(rename = "type")]
     pub _type: String,
@@ -38,21 +61,30 @@ pub struct GetAttribOperation {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub hash: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub enc: Option<String>
+    pub enc: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seq_no: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<u64>,
 }
 
+#[cfg(feature = "ledger")]
 impl GetAttribOperation {
-    pub fn new(dest: ShortDidValue, raw: Option<&str>, hash: Option<&str>, enc: Option<&str>) -> GetAttribOperation {
+    pub fn new(dest: ShortDidValue, raw: Option<&str>, hash: Option<&str>, enc: Option<&str>,
+               seq_no: Option<u64>, timestamp: Option<u64>) -> GetAttribOperation {
         GetAttribOperation {
             _type: GET_ATTR.to_string(),
             dest,
             raw: raw.map(String::from),
             hash: hash.map(String::from),
-            enc: enc.map(String::from)
+            enc: enc.map(String::from),
+            seq_no,
+            timestamp,
         }
     }
 }
 
+#[cfg(feature = "ledger")]
 #[derive(Debug, Deserialize)]
 #[serde(untagged)]
 pub enum GetAttrReplyResult {
@@ -60,6 +92,7 @@ pub enum GetAttrReplyResult {
     GetAttrReplyResultV1(GetReplyResultV1<GetAttResultDataV1>)
 }
 
+#[cfg(feature = "ledger")]
 #[derive(Deserialize, Eq, PartialEq, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct GetAttResultV0 {
@@ -69,6 +102,7 @@ pub struct GetAttResultV0 {
     pub  raw: String
 }
 
+#[cfg(feature = "ledger")]
 #[derive(Deserialize, Eq, PartialEq, Debug)]
 pub struct GetAttResultDataV1 {
     pub ver: String,
@@ -77,6 +111,54 @@ pub struct GetAttResultDataV1 {
     pub raw: String,
 }
 
+/// GET_ATTRIB reply covering raw, hash and enc attribs.
+#[cfg(feature = "ledger")]
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum GetAttribReplyResult {
+    GetAttribReplyResultV0(GetAttribResultV0),
+    GetAttribReplyResultV1(GetReplyResultV1<GetAttribResultDataV1>)
+}
+
+#[cfg(feature = "ledger")]
+impl ReplyType for GetAttribReplyResult {
+    fn get_type<'a>() -> &'a str {
+        GET_ATTR
+    }
+}
+
+#[cfg(feature = "ledger")]
+#[derive(Deserialize, Eq, PartialEq, Debug)]
+pub struct GetAttribResultV0 {
+    pub dest: ShortDidValue,
+    pub raw: Option<String>,
+    pub hash: Option<String>,
+    pub enc: Option<String>,
+    pub data: Option<String>,
+}
+
+#[cfg(feature = "ledger")]
+#[derive(Deserialize, Eq, PartialEq, Debug)]
+pub struct GetAttribResultDataV1 {
+    pub did: ShortDidValue,
+    pub raw: Option<String>,
+    pub hash: Option<String>,
+    pub enc: Option<String>,
+}
+
+#[cfg(feature = "ledger")]
+#[derive(Serialize, Eq, PartialEq, Debug)]
+pub struct AttribReplyData {
+    pub did: ShortDidValue,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enc: Option<String>,
+}
+
+#[cfg(feature = "ledger")]
 #[derive(Deserialize, Debug)]
 pub struct AttribData {
     pub endpoint: Endpoint
@@ -95,4 +177,305 @@ impl Endpoint {
             verkey
         }
     }
+
+    /// Raw data of `endpoint` attrib published on the ledger.
+    /// Verkey is omitted if it isn't set.
+    #[cfg(feature = "ledger")]
+    pub fn to_attrib_raw(&self) -> serde_json::Value {
+        let mut endpoint = json!({"ha": self.ha});
+
+        if let Some(ref verkey) = self.verkey {
+            endpoint["verkey"] = json!(verkey);
+        }
+
+        json!({ENDPOINT_ATTRIB_NAME: endpoint})
+    }
+}
+
+impl Validatable for Endpoint {
+    fn validate(&self) -> Result<(), String> {
+        self.ha.parse::<::std::net::SocketAddr>()
+            .map_err(|_| format!("Invalid endpoint address {}: ip-address:port expected", self.ha))?;
+
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum EndpointTransport {
+    Http,
+    Https,
+    Ws,
+    Wss,
+}
+
+impl EndpointTransport {
+    pub fn scheme(&self) -> &'static str {
+        match self {
+            EndpointTransport::Http => "http",
+            EndpointTransport::Https => "https",
+            EndpointTransport::Ws => "ws",
+            EndpointTransport::Wss => "wss",
+        }
+    }
+}
+
+/// Named endpoint of DID with transport profile (in addition to the legacy `endpoint` attrib).
+/// Verkey is the recipient key of the endpoint (DID verkey is used if it is not set).
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NamedEndpoint {
+    pub name: String,
+    pub endpoint: String,
+    pub transport: EndpointTransport,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verkey: Option<String>,
+    #[serde(default)]
+    pub routing_keys: Vec<String>,
+    #[serde(default)]
+    pub priority: u32,
+}
+
+impl Validatable for NamedEndpoint {
+    fn validate(&self) -> Result<(), String> {
+        validate_fragment(&self.name)?;
+
+        if !self.endpoint.starts_with(&format!("{}://", self.transport.scheme())) {
+            return Err(format!("Endpoint {} doesn't match {} transport", self.endpoint, self.transport.scheme()));
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct NamedEndpoints {
+    pub value: Vec<NamedEndpoint>
+}
+
+/// Raw data of `endpoints` attrib published on the ledger.
+#[cfg(feature = "ledger")]
+#[derive(Serialize, Deserialize, Debug)]
+pub struct EndpointsAttribData {
+    pub endpoints: Vec<NamedEndpoint>
+}
+
+/// Strict validation of `raw` attrib data against the known attrib structures.
+///
+/// Raw data must be an object with a single attribute. `endpoint`, `endpoints` and `diddocContent`
+/// attributes are checked to match their structures, other attributes are accepted as is.
+#[cfg(feature = "ledger")]
+pub fn validate_attrib_raw(raw: &serde_json::Value) -> Result<(), String> {
+    let raw = raw.as_object()
+        .ok_or_else(|| String::from("Raw attrib must be a JSON object"))?;
+
+    if raw.len() != 1 {
+        return Err(format!("Raw attrib must contain exactly one attribute, {} found", raw.len()));
+    }
+
+    let (name, value) = raw.iter().next().unwrap();
+
+    match name.as_str() {
+        ENDPOINT_ATTRIB_NAME => _validate_endpoint_attrib(value),
+        ENDPOINTS_ATTRIB_NAME => _validate_endpoints_attrib(value),
+        DIDDOC_CONTENT_ATTRIB_NAME => _validate_diddoc_content_attrib(value),
+        _ => Ok(())
+    }
+}
+
+#[cfg(feature = "ledger")]
+fn _validate_endpoint_attrib(value: &serde_json::Value) -> Result<(), String> {
+    let endpoint = value.as_object()
+        .ok_or_else(|| String::from("`endpoint` attrib must be a JSON object"))?;
+
+    if let Some(field) = endpoint.keys().find(|field| !ENDPOINT_ATTRIB_FIELDS.contains(&field.as_str())) {
+        return Err(format!("Unknown field `{}` of `endpoint` attrib", field));
+    }
+
+    match (endpoint.get("ha"), endpoint.get("endpoint")) {
+        (None, None) => return Err(String::from("`endpoint` attrib must contain either `ha` or `endpoint` field")),
+        (Some(ha), _) => {
+            let ha = ha.as_str()
+                .ok_or_else(|| String::from("`endpoint.ha` must be a string"))?;
+            Endpoint::new(ha.to_string(), None).validate()?;
+        }
+        _ => {}
+    }
+
+    if let Some(url) = endpoint.get("endpoint") {
+        let url = url.as_str()
+            .ok_or_else(|| String::from("`endpoint.endpoint` must be a string"))?;
+        if !url.contains("://") {
+            return Err(format!("`endpoint.endpoint` must be URL with scheme, {} found", url));
+        }
+    }
+
+    if let Some(verkey) = endpoint.get("verkey") {
+        if !verkey.is_string() && !verkey.is_null() {
+            return Err(String::from("`endpoint.verkey` must be a string"));
+        }
+    }
+
+    for field in &["routingKeys", "types"] {
+        if let Some(values) = endpoint.get(*field) {
+            _validate_strings_array(values, &format!("endpoint.{}", field))?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "ledger")]
+fn _validate_endpoints_attrib(value: &serde_json::Value) -> Result<(), String> {
+    let endpoints: Vec<NamedEndpoint> = serde_json::from_value(value.clone())
+        .map_err(|err| format!("`endpoints` attrib must be an array of named endpoints: {}", err))?;
+
+    for endpoint in endpoints.iter() {
+        endpoint.validate()?;
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "ledger")]
+fn _validate_diddoc_content_attrib(value: &serde_json::Value) -> Result<(), String> {
+    let content = value.as_object()
+        .ok_or_else(|| String::from("`diddocContent` attrib must be a JSON object"))?;
+
+    if content.contains_key("id") {
+        return Err(String::from("`diddocContent` must not contain `id`, it is derived from the DID"));
+    }
+
+    if let Some(context) = content.get("@context") {
+        if !context.is_string() {
+            _validate_strings_array(context, "diddocContent.@context")?;
+        }
+    }
+
+    for field in DIDDOC_CONTENT_ARRAY_FIELDS.iter() {
+        if let Some(values) = content.get(*field) {
+            if !values.is_array() {
+                return Err(format!("`diddocContent.{}` must be an array", field));
+            }
+        }
+    }
+
+    if let Some(services) = content.get("service").and_then(serde_json::Value::as_array) {
+        for (index, service) in services.iter().enumerate() {
+            let id = service.get("id").and_then(serde_json::Value::as_str);
+            let type_ = service.get("type");
+
+            if id.is_none() {
+                return Err(format!("`diddocContent.service[{}].id` must be a string", index));
+            }
+            if !type_.map(|type_| type_.is_string() || type_.is_array()).unwrap_or(false) {
+                return Err(format!("`diddocContent.service[{}].type` must be a string or an array", index));
+            }
+            if service.get("serviceEndpoint").map(serde_json::Value::is_null).unwrap_or(true) {
+                return Err(format!("`diddocContent.service[{}].serviceEndpoint` is missing", index));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "ledger")]
+fn _validate_strings_array(values: &serde_json::Value, name: &str) -> Result<(), String> {
+    match values.as_array() {
+        Some(values) if values.iter().all(serde_json::Value::is_string) => Ok(()),
+        _ => Err(format!("`{}` must be an array of strings", name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn _named_endpoint(name: &str, endpoint: &str, transport: EndpointTransport) -> NamedEndpoint {
+        NamedEndpoint {
+            name: name.to_string(),
+            endpoint: endpoint.to_string(),
+            transport,
+            verkey: None,
+            routing_keys: Vec::new(),
+            priority: 0,
+        }
+    }
+
+    #[test]
+    fn named_endpoint_validate_works() {
+        assert!(_named_endpoint("agent", "https://agent.example.com", EndpointTransport::Https).validate().is_ok());
+        assert!(_named_endpoint("agent", "wss://agent.example.com/ws", EndpointTransport::Wss).validate().is_ok());
+        assert!(_named_endpoint("agent", "http://agent.example.com", EndpointTransport::Https).validate().is_err());
+        assert!(_named_endpoint("#agent", "https://agent.example.com", EndpointTransport::Https).validate().is_err());
+        assert!(_named_endpoint("key-1", "https://agent.example.com", EndpointTransport::Https).validate().is_err());
+    }
+
+    #[test]
+    fn endpoint_validate_works() {
+        assert!(Endpoint::new("127.0.0.1:9700".to_string(), None).validate().is_ok());
+        assert!(Endpoint::new("[::1]:9700".to_string(), None).validate().is_ok());
+        assert!(Endpoint::new("127.0.0.1".to_string(), None).validate().is_err());
+        assert!(Endpoint::new("agent.example.com:9700".to_string(), None).validate().is_err());
+        assert!(Endpoint::new("https://agent.example.com".to_string(), None).validate().is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "ledger")]
+    fn endpoint_to_attrib_raw_works() {
+        assert_eq!(json!({"endpoint": {"ha": "127.0.0.1:9700"}}),
+                   Endpoint::new("127.0.0.1:9700".to_string(), None).to_attrib_raw());
+        assert_eq!(json!({"endpoint": {"ha": "127.0.0.1:9700", "verkey": "verkey"}}),
+                   Endpoint::new("127.0.0.1:9700".to_string(), Some("verkey".to_string())).to_attrib_raw());
+    }
+
+    #[test]
+    fn named_endpoint_deserialize_works_for_defaults() {
+        let endpoint: NamedEndpoint = serde_json::from_str(r#"{"name":"agent","endpoint":"ws://127.0.0.1:8080","transport":"ws"}"#).unwrap();
+
+        assert_eq!(_named_endpoint("agent", "ws://127.0.0.1:8080", EndpointTransport::Ws), endpoint);
+    }
+
+    #[test]
+    #[cfg(feature = "ledger")]
+    fn validate_attrib_raw_works_for_endpoint() {
+        assert!(validate_attrib_raw(&json!({"endpoint": {"ha": "127.0.0.1:9700", "verkey": "verkey"}})).is_ok());
+        assert!(validate_attrib_raw(&json!({"endpoint": {"endpoint": "https://agent.example.com", "routingKeys": ["key"], "types": ["DIDComm"]}})).is_ok());
+        assert!(validate_attrib_raw(&json!({"endpoint": {"ha": "agent.example.com"}})).is_err());
+        assert!(validate_attrib_raw(&json!({"endpoint": {"verkey": "verkey"}})).is_err());
+        assert!(validate_attrib_raw(&json!({"endpoint": {"endpoint": "agent.example.com"}})).is_err());
+        assert!(validate_attrib_raw(&json!({"endpoint": {"endpoint": "https://agent.example.com", "routingKeys": "key"}})).is_err());
+        assert!(validate_attrib_raw(&json!({"endpoint": {"ha": "127.0.0.1:9700", "port": 9700}})).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "ledger")]
+    fn validate_attrib_raw_works_for_endpoints() {
+        assert!(validate_attrib_raw(&json!({"endpoints": [{"name": "agent", "endpoint": "https://agent.example.com", "transport": "https"}]})).is_ok());
+        assert!(validate_attrib_raw(&json!({"endpoints": [{"name": "agent", "endpoint": "http://agent.example.com", "transport": "https"}]})).is_err());
+        assert!(validate_attrib_raw(&json!({"endpoints": {"name": "agent"}})).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "ledger")]
+    fn validate_attrib_raw_works_for_diddoc_content() {
+        let service = json!({"id": "#agent", "type": "DIDCommMessaging", "serviceEndpoint": "https://agent.example.com"});
+
+        assert!(validate_attrib_raw(&json!({"diddocContent": {"@context": ["https://www.w3.org/ns/did/v1"], "service": [service]}})).is_ok());
+        assert!(validate_attrib_raw(&json!({"diddocContent": {"id": "did:indy:sovrin:123"}})).is_err());
+        assert!(validate_attrib_raw(&json!({"diddocContent": {"service": service}})).is_err());
+        assert!(validate_attrib_raw(&json!({"diddocContent": {"service": [{"id": "#agent", "type": "DIDCommMessaging"}]}})).is_err());
+        assert!(validate_attrib_raw(&json!({"diddocContent": {"@context": 1}})).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "ledger")]
+    fn validate_attrib_raw_works_for_structure() {
+        assert!(validate_attrib_raw(&json!({"name": "value"})).is_ok());
+        assert!(validate_attrib_raw(&json!(["endpoint"])).is_err());
+        assert!(validate_attrib_raw(&json!({})).is_err());
+        assert!(validate_attrib_raw(&json!({"name": "value", "other": "value"})).is_err());
+    }
 }
@@ -2,14 +2,24 @@ use serde_json::Value;
 use std::ops::Not;
 
 use super::constants::{AUTH_RULE, AUTH_RULES, GET_AUTH_RULE};
+use super::response::ReplyType;
 
 #[allow(non_camel_case_types)]
-#[derive(Deserialize, Debug, Serialize, PartialEq)]
+#[derive(Deserialize, Debug, Serialize, PartialEq, Clone)]
 pub enum AuthAction {
     ADD,
     EDIT
 }
 
+impl AuthAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuthAction::ADD => "ADD",
+            AuthAction::EDIT => "EDIT"
+        }
+    }
+}
+
 /**
    Enum of the constraint type within the GAT_AUTH_RULE result data
     # parameters
@@ -226,6 +236,12 @@ pub struct GetAuthRuleResult {
     pub data: Vec<AuthRule>
 }
 
+impl ReplyType for GetAuthRuleResult {
+    fn get_type<'a>() -> &'a str {
+        GET_AUTH_RULE
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub struct AuthRule {
     pub auth_type: String,
@@ -247,4 +263,210 @@ impl AuthRulesOperation {
     pub fn new(rules: AuthRules) -> AuthRulesOperation {
         AuthRulesOperation { _type: AUTH_RULES.to_string(), rules }
     }
-}
\ No newline at end of file
+}
+pub const ANY_FIELD: &str = "*";
+pub const ANY_VALUE: &str = "*";
+pub const ANY_ROLE: &str = "*";
+pub const IDENTITY_OWNER_ROLE: &str = "";
+pub const EMPTY_VALUE: &str = "";
+
+/**
+   The signer of the request
+    # parameters
+   did - DID of the signer.
+   role - The role of the signer on the ledger (None for identity owner).
+   on_ledger - The flag specifying if signer DID is written on the ledger.
+   verified - The flag specifying if signature is verified against the ledger verkey.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestSigner {
+    pub did: String,
+    pub role: Option<String>,
+    #[serde(default = "default_true")]
+    pub on_ledger: bool,
+    #[serde(default = "default_true")]
+    pub verified: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/**
+   Signatures which are still required to satisfy the constraint
+    # parameters
+   role - The role of the required signers.
+   count - The number of the required signatures.
+   need_to_be_owner - The flag specifying if signer must be an owner of the transaction.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MissingSignature {
+    pub role: Option<String>,
+    pub count: u32,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Not::not")]
+    pub need_to_be_owner: bool,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestSignaturesReport {
+    pub txn_type: String,
+    pub auth_rule: AuthRule,
+    pub signers: Vec<RequestSigner>,
+    pub missing_signers: Vec<String>,
+    pub missing_signatures: Vec<MissingSignature>,
+    pub forbidden: bool,
+    pub complete: bool,
+}
+
+impl RoleConstraint {
+    fn is_matched_by(&self, signer: &RequestSigner, owner: Option<&str>) -> bool {
+        if self.need_to_be_owner && owner != Some(signer.did.as_str()) {
+            return false;
+        }
+
+        if !signer.on_ledger {
+            return self.off_ledger_signature && self.role.as_deref() == Some(ANY_ROLE);
+        }
+
+        if !signer.verified {
+            return false;
+        }
+
+        match self.role.as_deref() {
+            Some(ANY_ROLE) => true,
+            None | Some(IDENTITY_OWNER_ROLE) => signer.role.as_deref().unwrap_or(IDENTITY_OWNER_ROLE) == IDENTITY_OWNER_ROLE,
+            role => signer.role.as_deref() == role
+        }
+    }
+}
+
+impl Constraint {
+    /// Returns signatures which are still missing to satisfy the constraint or None if it can't be satisfied.
+    /// For OR constraint the alternative with the smallest number of missing signatures is taken.
+    pub fn missing_signatures(&self, signers: &[RequestSigner], owner: Option<&str>) -> Option<Vec<MissingSignature>> {
+        match self {
+            Constraint::RoleConstraint(constraint) => {
+                let count = signers.iter().filter(|signer| constraint.is_matched_by(signer, owner)).count() as u32;
+
+                if count >= constraint.sig_count {
+                    return Some(Vec::new());
+                }
+
+                Some(vec![MissingSignature {
+                    role: constraint.role.clone(),
+                    count: constraint.sig_count - count,
+                    need_to_be_owner: constraint.need_to_be_owner,
+                }])
+            }
+            Constraint::AndConstraint(constraint) => {
+                constraint.auth_constraints.iter()
+                    .map(|constraint| constraint.missing_signatures(signers, owner))
+                    .collect::<Option<Vec<Vec<MissingSignature>>>>()
+                    .map(|missing| missing.into_iter().flatten().collect())
+            }
+            Constraint::OrConstraint(constraint) => {
+                constraint.auth_constraints.iter()
+                    .filter_map(|constraint| constraint.missing_signatures(signers, owner))
+                    .min_by_key(|missing| missing.iter().map(|missing| missing.count).sum::<u32>())
+            }
+            Constraint::ForbiddenConstraint(_) => None
+        }
+    }
+}
+
+/**
+   The prospective ledger write to be evaluated against auth rules
+    # parameters
+   auth_type - The type of the transaction (alias or code).
+   auth_action - The type of the action.
+   field - The changed field of the transaction.
+   old_value - The old value of the field (for EDIT action).
+   new_value - The new value of the field.
+   owner - DID of the transaction owner.
+*/
+#[derive(Deserialize, PartialEq, Debug)]
+pub struct AuthRuleAction {
+    pub auth_type: String,
+    pub auth_action: AuthAction,
+    pub field: String,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+    pub owner: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthRuleEvaluation {
+    pub allowed: bool,
+    pub auth_rule: AuthRule,
+    pub missing_signatures: Vec<MissingSignature>,
+    pub forbidden: bool,
+}
+
+/**
+   Client-side pagination of auth rules fetched from the ledger
+    # parameters
+   offset - The number of rules to skip (0 by default).
+   limit - The maximum number of rules in the page (all remaining rules by default).
+*/
+#[derive(Deserialize, PartialEq, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct AuthRulesPageOptions {
+    pub offset: Option<usize>,
+    pub limit: Option<usize>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthRulesPage {
+    pub rules: Vec<AuthRule>,
+    pub total: usize,
+    pub next_offset: Option<usize>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct AuthRuleChange {
+    pub current: AuthRule,
+    pub proposed: AuthRule,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthRulesDiff {
+    pub added: Vec<AuthRule>,
+    pub removed: Vec<AuthRule>,
+    pub changed: Vec<AuthRuleChange>,
+    pub unchanged: usize,
+}
+
+impl Constraint {
+    /// Compares constraints ignoring the difference between absent and empty metadata.
+    pub fn is_equivalent(&self, other: &Constraint) -> bool {
+        match (self, other) {
+            (Constraint::RoleConstraint(constraint), Constraint::RoleConstraint(other)) => {
+                fn _metadata(metadata: &Option<Value>) -> Option<&Value> {
+                    metadata.as_ref().filter(|metadata| metadata.as_object().map(|map| !map.is_empty()).unwrap_or(true))
+                }
+
+                constraint.sig_count == other.sig_count &&
+                    constraint.role == other.role &&
+                    constraint.need_to_be_owner == other.need_to_be_owner &&
+                    constraint.off_ledger_signature == other.off_ledger_signature &&
+                    _metadata(&constraint.metadata) == _metadata(&other.metadata)
+            }
+            (Constraint::AndConstraint(constraint), Constraint::AndConstraint(other)) |
+            (Constraint::OrConstraint(constraint), Constraint::OrConstraint(other)) => {
+                constraint.auth_constraints.len() == other.auth_constraints.len() &&
+                    constraint.auth_constraints.iter()
+                        .zip(other.auth_constraints.iter())
+                        .all(|(constraint, other)| constraint.is_equivalent(other))
+            }
+            (Constraint::ForbiddenConstraint(_), Constraint::ForbiddenConstraint(_)) => true,
+            _ => false
+        }
+    }
+}
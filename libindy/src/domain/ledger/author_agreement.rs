@@ -2,6 +2,7 @@ use std::collections::HashMap;
 
 use indy_api_types::validation::Validatable;
 
+use super::response::ReplyType;
 use super::constants::{GET_TXN_AUTHR_AGRMT, GET_TXN_AUTHR_AGRMT_AML, TXN_AUTHR_AGRMT, TXN_AUTHR_AGRMT_AML, DISABLE_ALL_TXN_AUTHR_AGRMTS};
 
 #[derive(Serialize, PartialEq, Debug)]
@@ -144,4 +145,56 @@ impl GetAcceptanceMechanismOperation {
             version,
         }
     }
-}
\ No newline at end of file
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GetTxnAuthorAgreementResult {
+    pub data: Option<TxnAuthorAgreementData>
+}
+
+impl ReplyType for GetTxnAuthorAgreementResult {
+    fn get_type<'a>() -> &'a str {
+        GET_TXN_AUTHR_AGRMT
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct TxnAuthorAgreementData {
+    pub text: Option<String>,
+    pub version: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub digest: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ratification_ts: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retirement_ts: Option<u64>,
+}
+
+/// Latest Transaction Author Agreement and Acceptance Mechanisms set on the ledger.
+/// `taa_time` is the time of the transaction that has set the agreement.
+#[derive(Debug, Clone)]
+pub struct TxnAuthorAgreementState {
+    pub taa: Option<TxnAuthorAgreementData>,
+    pub taa_time: Option<u64>,
+    pub aml: Option<AcceptanceMechanisms>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GetAcceptanceMechanismsResult {
+    pub data: Option<AcceptanceMechanismsData>
+}
+
+impl ReplyType for GetAcceptanceMechanismsResult {
+    fn get_type<'a>() -> &'a str {
+        GET_TXN_AUTHR_AGRMT_AML
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct AcceptanceMechanismsData {
+    pub aml: AcceptanceMechanisms,
+    pub version: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aml_context: Option<String>,
+}
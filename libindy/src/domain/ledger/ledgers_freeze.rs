@@ -1,4 +1,7 @@
+use std::collections::BTreeMap;
+
 use super::constants::{LEDGERS_FREEZE, GET_FROZEN_LEDGERS};
+use super::response::ReplyType;
 
 #[derive(Serialize, PartialEq, Debug)]
 pub struct LedgersFreezeOperation {
@@ -29,3 +32,31 @@ impl GetFrozenLedgersOperation {
         }
     }
 }
+
+#[derive(Deserialize, Debug)]
+pub struct GetFrozenLedgersResult {
+    pub data: Option<BTreeMap<String, FrozenLedgerData>>,
+}
+
+impl ReplyType for GetFrozenLedgersResult {
+    fn get_type<'a>() -> &'a str {
+        GET_FROZEN_LEDGERS
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct FrozenLedgerData {
+    pub ledger: String,
+    pub state: String,
+    pub seq_no: u64,
+}
+
+/// Frozen ledger state: the ledger keeps its roots and size at the moment it was frozen.
+#[derive(Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct FrozenLedger {
+    pub ledger_id: u64,
+    pub ledger_root: String,
+    pub state_root: String,
+    pub seq_no: u64,
+}
@@ -1,17 +1,33 @@
+#[cfg(any(feature = "anoncreds", feature = "ledger"))]
 pub mod request;
+#[cfg(feature = "ledger")]
 pub mod nym;
 pub mod attrib;
+#[cfg(feature = "ledger")]
 pub mod schema;
+#[cfg(feature = "ledger")]
 pub mod cred_def;
+#[cfg(feature = "ledger")]
 pub mod node;
+#[cfg(feature = "ledger")]
 pub mod ddo;
+#[cfg(feature = "ledger")]
 pub mod txn;
+#[cfg(feature = "ledger")]
 pub mod pool;
+#[cfg(feature = "ledger")]
 pub mod rev_reg_def;
+#[cfg(feature = "ledger")]
 pub mod rev_reg;
+#[cfg(feature = "ledger")]
 pub mod response;
+#[cfg(feature = "ledger")]
 pub mod validator_info;
+#[cfg(feature = "ledger")]
 pub mod constants;
+#[cfg(feature = "ledger")]
 pub mod auth_rule;
+#[cfg(feature = "ledger")]
 pub mod author_agreement;
+#[cfg(feature = "ledger")]
 pub mod ledgers_freeze;
@@ -1,7 +1,12 @@
-use super::constants::{NYM, GET_NYM};
+use indy_api_types::validation::Validatable;
+
+use super::constants::{NYM, GET_NYM, TRUSTEE, STEWARD, ENDORSER, NETWORK_MONITOR};
 use super::response::{GetReplyResultV0, GetReplyResultV1, ReplyType};
 use super::super::crypto::did::ShortDidValue;
 
+pub const MAX_DIDDOC_CONTENT_SIZE: usize = 10 * 1024;
+pub const MAX_NYM_VERSION: u8 = 2;
+
 #[derive(Serialize, PartialEq, Debug)]
 pub struct NymOperation {
     #[serde(rename = "type")]
@@ -13,32 +18,106 @@ pub struct NymOperation {
     pub alias: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub role: Option<::serde_json::Value>,
+    #[serde(rename = "diddocContent", skip_serializing_if = "Option::is_none")]
+    pub diddoc_content: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<u8>,
 }
 
 impl NymOperation {
-    pub fn new(dest: ShortDidValue, verkey: Option<String>, alias: Option<String>, role: Option<::serde_json::Value>) -> NymOperation {
+    pub fn new(dest: ShortDidValue, verkey: Option<String>, alias: Option<String>, role: Option<::serde_json::Value>,
+               diddoc_content: Option<String>, version: Option<u8>) -> NymOperation {
         NymOperation {
             _type: NYM.to_string(),
             dest,
             verkey,
             alias,
             role,
+            diddoc_content,
+            version,
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum NymRole {
+    Trustee,
+    Steward,
+    #[serde(alias = "TRUST_ANCHOR")]
+    Endorser,
+    NetworkMonitor,
+    /// Resets the role to common USER
+    Reset,
+}
+
+impl NymRole {
+    pub fn to_value(self) -> ::serde_json::Value {
+        match self {
+            NymRole::Trustee => json!(TRUSTEE),
+            NymRole::Steward => json!(STEWARD),
+            NymRole::Endorser => json!(ENDORSER),
+            NymRole::NetworkMonitor => json!(NETWORK_MONITOR),
+            NymRole::Reset => ::serde_json::Value::Null,
+        }
+    }
+}
+
+/// NYM transaction data with the fields introduced by did:indy method.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct NymRequestData {
+    pub verkey: Option<String>,
+    pub alias: Option<String>,
+    pub role: Option<NymRole>,
+    pub diddoc_content: Option<::serde_json::Value>,
+    pub version: Option<u8>,
+}
+
+impl Validatable for NymRequestData {
+    fn validate(&self) -> Result<(), String> {
+        if let Some(ref diddoc_content) = self.diddoc_content {
+            let content = diddoc_content.as_object()
+                .ok_or_else(|| String::from("`diddocContent` must be a JSON object"))?;
+
+            if content.contains_key("id") {
+                return Err(String::from("`diddocContent` must not contain `id`: it's generated from the DID"));
+            }
+
+            if diddoc_content.to_string().len() > MAX_DIDDOC_CONTENT_SIZE {
+                return Err(format!("`diddocContent` must not exceed {} bytes", MAX_DIDDOC_CONTENT_SIZE));
+            }
         }
+
+        if let Some(version) = self.version {
+            if version > MAX_NYM_VERSION {
+                return Err(format!("Unsupported NYM `version` {}: 0, 1 or 2 expected", version));
+            }
+        }
+
+        Ok(())
     }
 }
 
 #[derive(Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct GetNymOperation {
     #[serde(rename = "type")]
     pub _type: String,
-    pub dest: ShortDidValue
+    pub dest: ShortDidValue,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seq_no: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<u64>,
 }
 
 impl GetNymOperation {
-    pub fn new(dest: ShortDidValue) -> GetNymOperation {
+    pub fn new(dest: ShortDidValue, seq_no: Option<u64>, timestamp: Option<u64>) -> GetNymOperation {
         GetNymOperation {
             _type: GET_NYM.to_string(),
-            dest
+            dest,
+            seq_no,
+            timestamp,
         }
     }
 }
@@ -74,8 +153,13 @@ pub struct GetNymResultDataV1 {
 }
 
 #[derive(Serialize, Deserialize, Eq, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct NymData {
     pub did: ShortDidValue,
     pub verkey: Option<String>,
     pub role: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seq_no: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub txn_time: Option<u64>,
 }
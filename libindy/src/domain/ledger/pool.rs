@@ -1,6 +1,8 @@
 use super::constants::{POOL_CONFIG, POOL_UPGRADE, POOL_RESTART};
 
-use std::collections::HashMap;
+use indy_api_types::validation::Validatable;
+
+use std::collections::{BTreeMap, HashMap};
 
 #[derive(Serialize, PartialEq, Debug)]
 pub struct PoolConfigOperation {
@@ -81,3 +83,44 @@ impl PoolUpgradeOperation {
 }
 
 pub type Schedule = HashMap<String, String>;
+
+/// Simplified description of POOL_UPGRADE starting the upgrade.
+/// Nodes are referenced by aliases and the upgrade time of every node is set as offset from `startTime`.
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct PoolUpgradeScheduleData {
+    pub name: String,
+    pub version: String,
+    pub sha256: String,
+    pub package: Option<String>,
+    pub start_time: u64,
+    pub node_offsets: BTreeMap<String, u64>,
+    pub timeout: Option<u32>,
+    pub justification: Option<String>,
+    #[serde(default)]
+    pub reinstall: bool,
+    #[serde(default)]
+    pub force: bool,
+}
+
+impl Validatable for PoolUpgradeScheduleData {
+    fn validate(&self) -> Result<(), String> {
+        if self.name.is_empty() || self.version.is_empty() {
+            return Err(String::from("Upgrade name and version must be set"));
+        }
+
+        if self.sha256.len() != 64 || !self.sha256.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(format!("Invalid sha256 of the upgrade package: {}", self.sha256));
+        }
+
+        if self.node_offsets.is_empty() {
+            return Err(String::from("Upgrade schedule doesn't contain any node"));
+        }
+
+        if self.node_offsets.values().any(|offset| self.start_time.checked_add(*offset).map_or(true, |time| time > i64::MAX as u64)) {
+            return Err(String::from("Upgrade time of the node is out of range"));
+        }
+
+        Ok(())
+    }
+}
@@ -1,11 +1,17 @@
+#[cfg(feature = "ledger")]
 use serde;
+#[cfg(feature = "ledger")]
 use serde_json;
+#[cfg(feature = "ledger")]
 use time;
 
+#[cfg(feature = "ledger")]
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicUsize, Ordering};
+#[cfg(feature = "ledger")]
 use super::super::crypto::did::{DidValue, ShortDidValue};
 
+#[cfg(feature = "ledger")]
 pub const DEFAULT_LIBIDY_DID: &str = "LibindyDid111111111111";
 
 pub struct ProtocolVersion {}
@@ -15,6 +21,7 @@ lazy_static! {
 }
 
 impl ProtocolVersion {
+    #[cfg(feature = "ledger")]
     pub fn set(version: usize) {
         PROTOCOL_VERSION.store(version, Ordering::Relaxed);
     }
@@ -28,7 +35,8 @@ impl ProtocolVersion {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[cfg(feature = "ledger")]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct TxnAuthrAgrmtAcceptanceData {
     pub mechanism: String,
@@ -36,10 +44,12 @@ pub struct TxnAuthrAgrmtAcceptanceData {
     pub time: u64
 }
 
+#[cfg(feature = "ledger")]
 fn get_req_id() -> u64 {
     time::get_time().sec as u64 * (1e9 as u64) + time::get_time().nsec as u64
 }
 
+#[cfg(feature = "ledger")]
 #[derive(Serialize, Deserialize, PartialEq, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct Request<T: serde::Serialize> {
@@ -58,6 +68,7 @@ pub struct Request<T: serde::Serialize> {
     pub endorser: Option<ShortDidValue>
 }
 
+#[cfg(feature = "ledger")]
 impl<T: serde::Serialize> Request<T> {
     pub fn new(req_id: u64, identifier: ShortDidValue, operation: T, protocol_version: usize) -> Request<T> {
         Request {
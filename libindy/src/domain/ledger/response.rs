@@ -1,3 +1,6 @@
+use indy_api_types::ErrorCode;
+use indy_api_types::errors::prelude::*;
+
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct Response {
@@ -71,6 +74,58 @@ pub enum Message<T> {
     Reject(Response)
 }
 
+/// Result of the single request of the batch submitted at once.
+/// Either reply of the pool or error happened on the request submission is set.
+#[derive(Serialize, Debug, PartialEq)]
+pub struct BatchRequestResult {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<BatchRequestError>,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+pub struct BatchRequestError {
+    pub code: i32,
+    pub message: String,
+}
+
+impl From<IndyResult<String>> for BatchRequestResult {
+    fn from(result: IndyResult<String>) -> Self {
+        match result {
+            Ok(response) => BatchRequestResult { response: Some(response), error: None },
+            Err(err) => BatchRequestResult {
+                response: None,
+                error: Some(BatchRequestError {
+                    code: ErrorCode::from(err.kind()) as i32,
+                    message: err.to_string().trim_end().to_string(),
+                }),
+            }
+        }
+    }
+}
+
+/// Result of idempotent submission of the write request.
+/// If the ledger entity already exists the reply of the read request fetching it is returned instead of rejection.
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct IdempotentSubmitResult {
+    pub already_exists: bool,
+    pub response: serde_json::Value,
+}
+
+/// Result of typed parsing of ledger read reply.
+/// State proof and multi-signature of the reply are omitted.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ParsedReply<T> {
+    pub data: T,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seq_no: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub txn_time: Option<u64>,
+}
+
 pub trait ReplyType {
     fn get_type<'a>() -> &'a str;
 }
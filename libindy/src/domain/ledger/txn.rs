@@ -1,4 +1,10 @@
-use super::constants::GET_TXN;
+use serde_json::Value;
+
+use indy_api_types::validation::Validatable;
+
+use super::constants::{GET_TXN, txn_name_to_code};
+use super::response::ReplyType;
+use super::super::crypto::did::DidValue;
 
 #[derive(Serialize, PartialEq, Debug)]
 pub struct GetTxnOperation {
@@ -19,6 +25,42 @@ impl GetTxnOperation {
     }
 }
 
+#[derive(Deserialize, Debug)]
+pub struct GetTxnReplyResult {
+    pub data: Option<GetTxnResultData>
+}
+
+impl ReplyType for GetTxnReplyResult {
+    fn get_type<'a>() -> &'a str {
+        GET_TXN
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTxnResultData {
+    pub txn: TxnReplyData,
+    #[serde(default)]
+    pub txn_metadata: GetTxnResultMetadata,
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTxnResultMetadata {
+    pub seq_no: Option<u64>,
+    pub txn_time: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+pub struct TxnReplyData {
+    #[serde(rename = "type")]
+    pub type_: String,
+    #[serde(default)]
+    pub data: Value,
+    #[serde(default)]
+    pub metadata: Value,
+}
+
 #[derive(Deserialize, Debug)]
 pub enum LedgerType {
     POOL = 0,
@@ -35,3 +77,129 @@ impl LedgerType {
         }
     }
 }
+
+pub const DEFAULT_LEDGER_EVENTS_POLL_INTERVAL: u64 = 10;
+
+/// Filter of transactions to notify about by a ledger events subscription.
+/// Empty `dids` or `txn_types` match any transaction.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LedgerEventsFilter {
+    #[serde(default)]
+    pub dids: Vec<DidValue>,
+    #[serde(default)]
+    pub txn_types: Vec<String>,
+    pub ledger_type: Option<String>,
+    pub from_seq_no: Option<i32>,
+    pub poll_interval: Option<u64>,
+}
+
+impl LedgerEventsFilter {
+    /// Transaction matches DIDs filter if either its author or its target is one of the DIDs.
+    pub fn matches(&self, txn: &TxnReplyData) -> bool {
+        let type_matched = self.txn_types.is_empty() ||
+            self.txn_types.iter().any(|type_| txn_name_to_code(type_) == Some(txn.type_.as_str()));
+
+        let did_matched = self.dids.is_empty() || {
+            let from = txn.metadata["from"].as_str();
+            let dest = txn.data["dest"].as_str();
+
+            self.dids.iter()
+                .map(|did| did.to_unqualified().0)
+                .any(|did| from == Some(did.as_str()) || dest == Some(did.as_str()))
+        };
+
+        type_matched && did_matched
+    }
+}
+
+impl Validatable for LedgerEventsFilter {
+    fn validate(&self) -> Result<(), String> {
+        for did in self.dids.iter() {
+            did.validate()?;
+        }
+
+        if self.from_seq_no.map(|seq_no| seq_no < 1).unwrap_or(false) {
+            return Err(String::from("Sequence number to start from must be positive"));
+        }
+
+        if self.poll_interval == Some(0) {
+            return Err(String::from("Poll interval must be positive"));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use super::super::constants::{NYM, SCHEMA};
+
+    const DID: &str = "NcYxiDXkpYi6ov5FcYDi1e";
+    const OTHER_DID: &str = "VsKV7grR1BUE29mG2Fm2kX";
+
+    fn _nym_txn(from: &str, dest: &str) -> TxnReplyData {
+        TxnReplyData {
+            type_: NYM.to_string(),
+            data: json!({"dest": dest}),
+            metadata: json!({"from": from}),
+        }
+    }
+
+    fn _filter(dids: Vec<&str>, txn_types: Vec<&str>) -> LedgerEventsFilter {
+        LedgerEventsFilter {
+            dids: dids.into_iter().map(|did| DidValue(did.to_string())).collect(),
+            txn_types: txn_types.into_iter().map(String::from).collect(),
+            ledger_type: None,
+            from_seq_no: None,
+            poll_interval: None,
+        }
+    }
+
+    #[test]
+    fn ledger_events_filter_matches_works_for_empty_filter() {
+        assert!(_filter(vec![], vec![]).matches(&_nym_txn(OTHER_DID, OTHER_DID)));
+    }
+
+    #[test]
+    fn ledger_events_filter_matches_works_for_author_and_target() {
+        let filter = _filter(vec![DID], vec![]);
+
+        assert!(filter.matches(&_nym_txn(DID, OTHER_DID)));
+        assert!(filter.matches(&_nym_txn(OTHER_DID, DID)));
+        assert!(!filter.matches(&_nym_txn(OTHER_DID, OTHER_DID)));
+    }
+
+    #[test]
+    fn ledger_events_filter_matches_works_for_fully_qualified_did() {
+        let filter = _filter(vec!["did:sov:NcYxiDXkpYi6ov5FcYDi1e"], vec![]);
+
+        assert!(filter.matches(&_nym_txn(OTHER_DID, DID)));
+    }
+
+    #[test]
+    fn ledger_events_filter_matches_works_for_txn_types() {
+        assert!(_filter(vec![], vec!["NYM"]).matches(&_nym_txn(DID, DID)));
+        assert!(_filter(vec![], vec![NYM]).matches(&_nym_txn(DID, DID)));
+        assert!(!_filter(vec![DID], vec!["SCHEMA"]).matches(&_nym_txn(DID, DID)));
+        assert!(!_filter(vec![], vec![SCHEMA]).matches(&_nym_txn(DID, DID)));
+    }
+
+    #[test]
+    fn ledger_events_filter_validate_works_for_zero_poll_interval() {
+        let mut filter = _filter(vec![], vec!["NYM"]);
+        filter.poll_interval = Some(0);
+        assert!(filter.validate().is_err());
+    }
+
+    #[test]
+    fn ledger_events_filter_validate_works_for_invalid_from_seq_no() {
+        let mut filter = _filter(vec![DID], vec!["NYM"]);
+        assert!(filter.validate().is_ok());
+
+        filter.from_seq_no = Some(0);
+        assert!(filter.validate().is_err());
+    }
+}
@@ -1,4 +1,9 @@
+use std::collections::BTreeMap;
+
+use serde_json::Value;
+
 use super::constants::GET_VALIDATOR_INFO;
+use super::response::ReplyType;
 
 #[derive(Serialize, PartialEq, Debug)]
 pub struct GetValidatorInfoOperation {
@@ -14,3 +19,220 @@ impl GetValidatorInfoOperation {
     }
 }
 
+#[derive(Deserialize, Debug)]
+pub struct GetValidatorInfoResult {
+    pub data: Option<Value>
+}
+
+impl ReplyType for GetValidatorInfoResult {
+    fn get_type<'a>() -> &'a str {
+        GET_VALIDATOR_INFO
+    }
+}
+
+/// Reply of the single node on GET_VALIDATOR_INFO action.
+/// Error is set instead of data if the node replied with reject or didn't reply at all.
+#[derive(Serialize, PartialEq, Debug)]
+pub struct ValidatorInfoNodeReply {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Validator info of the single node normalized to the fields that are useful for pool monitoring.
+/// Layout of validator info differs between indy-node versions, so fields missing
+/// in the node reply are omitted instead of failing the whole parsing.
+#[derive(Serialize, PartialEq, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidatorInfoSummary {
+    pub alias: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub node_version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uptime: Option<u64>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub ledger_sizes: BTreeMap<String, u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pool: Option<PoolMembershipInfo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Pool membership as seen by the node.
+#[derive(Serialize, PartialEq, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolMembershipInfo {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_nodes: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub f: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+    pub reachable_nodes: Vec<String>,
+    pub unreachable_nodes: Vec<String>,
+    pub blacklisted_nodes: Vec<String>,
+}
+
+impl ValidatorInfoSummary {
+    /// Builds summary from validator info json of the node.
+    /// `alias` is used if the node doesn't report its name.
+    pub fn new(alias: &str, data: &Value) -> ValidatorInfoSummary {
+        let node_info = &data["Node_info"];
+
+        // Metrics were moved into Node_info in newer node versions
+        let metrics = if node_info["Metrics"].is_object() { &node_info["Metrics"] } else { &data["Metrics"] };
+
+        let ledger_sizes = metrics["transaction-count"].as_object()
+            .map(|counts| counts.iter()
+                .filter_map(|(ledger, count)| _as_u64(count).map(|count| (ledger.clone(), count)))
+                .collect())
+            .unwrap_or_default();
+
+        ValidatorInfoSummary {
+            alias: node_info["Name"].as_str().unwrap_or(alias).to_string(),
+            node_version: _node_version(&data["Software"]),
+            mode: node_info["Mode"].as_str().map(String::from),
+            uptime: _as_u64(&metrics["uptime"]),
+            ledger_sizes,
+            pool: _pool_membership(&data["Pool_info"]),
+            timestamp: _as_u64(&data["timestamp"]),
+            error: None,
+        }
+    }
+
+    pub fn error(alias: &str, error: String) -> ValidatorInfoSummary {
+        ValidatorInfoSummary {
+            alias: alias.to_string(),
+            error: Some(error),
+            ..ValidatorInfoSummary::default()
+        }
+    }
+}
+
+/// Upgrade status of the single node extracted from its validator info.
+#[derive(Serialize, PartialEq, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeUpgradeStatus {
+    pub alias: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub node_version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_event: Option<UpgradeLogEvent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Record of the node upgrade log: "<logged at>\t<event>\t<scheduled at>\t<version>\t<upgrade id>\t<package>".
+/// Older nodes don't log upgrade id and package.
+#[derive(Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct UpgradeLogEvent {
+    pub logged_at: String,
+    pub event: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheduled_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upgrade_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub package: Option<String>,
+}
+
+impl NodeUpgradeStatus {
+    /// Builds status from validator info json of the node.
+    /// `alias` is used if the node doesn't report its name.
+    pub fn new(alias: &str, data: &Value) -> NodeUpgradeStatus {
+        let last_event = data["Extractions"]["upgrade_log"].as_array()
+            .and_then(|records| records.iter()
+                .filter_map(Value::as_str)
+                .filter(|record| !record.trim().is_empty())
+                .last())
+            .and_then(UpgradeLogEvent::parse);
+
+        NodeUpgradeStatus {
+            alias: data["Node_info"]["Name"].as_str().unwrap_or(alias).to_string(),
+            node_version: _node_version(&data["Software"]),
+            last_event,
+            error: None,
+        }
+    }
+
+    pub fn error(alias: &str, error: String) -> NodeUpgradeStatus {
+        NodeUpgradeStatus {
+            alias: alias.to_string(),
+            error: Some(error),
+            ..NodeUpgradeStatus::default()
+        }
+    }
+}
+
+impl UpgradeLogEvent {
+    pub fn parse(record: &str) -> Option<UpgradeLogEvent> {
+        let mut fields = record.trim().split('\t').map(str::trim).map(String::from);
+
+        let logged_at = fields.next()?;
+        let event = fields.next()?;
+
+        Some(UpgradeLogEvent {
+            logged_at,
+            event,
+            scheduled_at: fields.next(),
+            version: fields.next(),
+            upgrade_id: fields.next(),
+            package: fields.next(),
+        })
+    }
+}
+
+fn _as_u64(value: &Value) -> Option<u64> {
+    value.as_u64()
+        .or_else(|| value.as_f64().filter(|value| *value >= 0.0).map(|value| value as u64))
+        .or_else(|| value.as_str().and_then(|value| value.parse().ok()))
+}
+
+fn _node_version(software: &Value) -> Option<String> {
+    if let Some(version) = software["indy-node"].as_str() {
+        return Some(version.to_string());
+    }
+
+    // Older nodes report only the list of installed packages in `dpkg -l` format: "ii  indy-node  1.6.78  amd64  ..."
+    ["Indy_packages", "Installed_packages"].iter()
+        .filter_map(|field| software[field].as_array())
+        .flatten()
+        .filter_map(Value::as_str)
+        .find_map(|package| {
+            let mut parts = package.split_whitespace();
+            parts.position(|part| part == "indy-node").and_then(|_| parts.next()).map(String::from)
+        })
+}
+
+fn _pool_membership(pool_info: &Value) -> Option<PoolMembershipInfo> {
+    if !pool_info.is_object() {
+        return None;
+    }
+
+    Some(PoolMembershipInfo {
+        total_nodes: _as_u64(&pool_info["Total_nodes_count"]),
+        f: _as_u64(&pool_info["f_value"]),
+        read_only: pool_info["Read_only"].as_bool(),
+        reachable_nodes: _node_names(&pool_info["Reachable_nodes"]),
+        unreachable_nodes: _node_names(&pool_info["Unreachable_nodes"]),
+        blacklisted_nodes: _node_names(&pool_info["Blacklisted_nodes"]),
+    })
+}
+
+// Nodes are listed either by names or as [name, replica rank] pairs
+fn _node_names(nodes: &Value) -> Vec<String> {
+    nodes.as_array()
+        .map(|nodes| nodes.iter()
+            .filter_map(|node| node.as_str().or_else(|| node[0].as_str()))
+            .map(String::from)
+            .collect())
+        .unwrap_or_default()
+}
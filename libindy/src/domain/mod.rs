@@ -1,8 +1,11 @@
+#[cfg(any(feature = "anoncreds", feature = "ledger"))]
 pub mod anoncreds;
 pub mod crypto;
 pub mod ledger;
 pub mod pairwise;
+#[cfg(feature = "ledger")]
 pub mod pool;
+#[cfg(feature = "cache")]
 pub mod cache;
 
 use indy_api_types::validation::Validatable;
@@ -10,8 +13,62 @@ use indy_api_types::validation::Validatable;
 #[derive(Debug, Serialize, Deserialize)]
 pub struct IndyConfig {
     pub crypto_thread_pool_size: Option<usize>,
+    pub anoncreds_worker_threads: Option<usize>,
     pub collect_backtrace: Option<bool>,
-    pub freshness_threshold: Option<u64>
+    pub freshness_threshold: Option<u64>,
+    pub metrics_buckets_ms: Option<Vec<f64>>,
+    pub log_format: Option<LogFormat>,
+    pub slow_operation_threshold_ms: Option<u64>,
+    pub max_pending_commands: Option<usize>,
+    pub queue_full_timeout_ms: Option<u64>,
+    pub command_timeout_ms: Option<u64>,
+    pub pool_socket_hwm: Option<i32>,
+    pub pool_zmq_io_threads: Option<i32>,
+    pub blob_buffer_size: Option<usize>,
+    pub tails_cache_size: Option<usize>,
+    pub anoncreds_test_seed: Option<String>,
+    pub strict_attrib_validation: Option<bool>,
 }
 
-impl Validatable for IndyConfig {}
\ No newline at end of file
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    Text,
+    Json,
+}
+
+impl Validatable for IndyConfig {
+    fn validate(&self) -> Result<(), String> {
+        if self.crypto_thread_pool_size == Some(0) {
+            return Err(String::from("Crypto thread pool size must be positive"));
+        }
+        if self.anoncreds_worker_threads == Some(0) {
+            return Err(String::from("Anoncreds worker threads count must be positive"));
+        }
+        if let Some(ref buckets) = self.metrics_buckets_ms {
+            if buckets.is_empty() {
+                return Err(String::from("Metrics buckets list is empty"));
+            }
+            if buckets.iter().any(|bucket| !bucket.is_finite() || *bucket <= 0.0) {
+                return Err(String::from("Metrics buckets must be positive numbers"));
+            }
+            if buckets.windows(2).any(|pair| pair[0] >= pair[1]) {
+                return Err(String::from("Metrics buckets must be sorted in increasing order"));
+            }
+        }
+        if let Some(hwm) = self.pool_socket_hwm {
+            if hwm < 0 {
+                return Err(String::from("Pool socket high water mark can't be negative"));
+            }
+        }
+        if let Some(io_threads) = self.pool_zmq_io_threads {
+            if io_threads <= 0 {
+                return Err(String::from("Pool ZMQ IO threads count must be positive"));
+            }
+        }
+        if self.blob_buffer_size == Some(0) {
+            return Err(String::from("Blob buffer size must be positive"));
+        }
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -1,4 +1,5 @@
 use super::crypto::did::DidValue;
+use indy_api_types::domain::wallet::Tags;
 
 #[derive(Serialize, Deserialize)]
 pub struct Pairwise {
@@ -8,6 +9,157 @@ pub struct Pairwise {
     pub metadata: Option<String>,
 }
 
+pub const LABEL_TAG: &str = "label";
+pub const STATE_TAG: &str = "state";
+pub const CREATED_AT_TAG: &str = "~created_at";
+/// Marks pairwise which "label" and "state" tags hold structured meta, not metadata fields.
+pub const STRUCTURED_META_TAG: &str = "~structured_meta";
+
+impl Pairwise {
+    /// Tags used to search pairwise: "my_did", structured meta and all top level string fields
+    /// of metadata if it is a JSON object. Fields with "~" prefix are stored un-encrypted.
+    /// Once structured meta is set, metadata fields can't override its tags.
+    pub fn tags(&self, meta: &PairwiseMeta) -> Tags {
+        let mut tags = Tags::new();
+
+        if let Some(created_at) = meta.created_at {
+            tags.insert(CREATED_AT_TAG.to_string(), created_at.to_string());
+        }
+
+        if meta.is_structured() {
+            for (name, value) in self._metadata_fields() {
+                if ![LABEL_TAG, STATE_TAG, CREATED_AT_TAG].contains(&name.as_str()) {
+                    tags.insert(name, value);
+                }
+            }
+
+            if let Some(ref label) = meta.label {
+                tags.insert(LABEL_TAG.to_string(), label.clone());
+            }
+
+            if let Some(ref state) = meta.state {
+                tags.insert(STATE_TAG.to_string(), state.clone());
+            }
+
+            tags.insert(STRUCTURED_META_TAG.to_string(), "true".to_string());
+        } else {
+            // Pairwise is searched by all its metadata fields as before structured meta was introduced
+            tags.extend(self._metadata_fields());
+        }
+
+        tags.insert("my_did".to_string(), self.my_did.0.clone());
+        tags
+    }
+
+    /// Structured meta of pairwise created before it was introduced: label and state
+    /// are taken from the metadata fields with the same names.
+    pub fn legacy_meta(&self) -> PairwiseMeta {
+        let mut fields = self._metadata_fields();
+
+        PairwiseMeta {
+            label: fields.remove(LABEL_TAG),
+            state: fields.remove(STATE_TAG),
+            created_at: None,
+        }
+    }
+
+    fn _metadata_fields(&self) -> Tags {
+        let mut fields = Tags::new();
+
+        if let Some(serde_json::Value::Object(values)) = self.metadata.as_ref()
+            .and_then(|metadata| serde_json::from_str(metadata).ok()) {
+            for (name, value) in values {
+                match value {
+                    serde_json::Value::String(ref value) if name != STRUCTURED_META_TAG => { fields.insert(name, value.clone()); }
+                    _ => {}
+                }
+            }
+        }
+
+        fields
+    }
+}
+
+/// Structured meta of pairwise. It is stored in the tags of pairwise record, so pairwise
+/// can be filtered by label, state and creation time with WQL.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct PairwiseMeta {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<u64>,
+}
+
+impl PairwiseMeta {
+    /// Label and state are read from the tags of pairwise with structured meta only,
+    /// tags with the same names of other pairwise are its metadata fields.
+    pub fn from_tags(tags: Option<&Tags>) -> PairwiseMeta {
+        let tags = match tags {
+            Some(tags) => tags,
+            None => return PairwiseMeta::default()
+        };
+
+        let structured = tags.contains_key(STRUCTURED_META_TAG);
+
+        PairwiseMeta {
+            label: tags.get(LABEL_TAG).filter(|_| structured).cloned(),
+            state: tags.get(STATE_TAG).filter(|_| structured).cloned(),
+            created_at: tags.get(CREATED_AT_TAG).and_then(|created_at| created_at.parse().ok()),
+        }
+    }
+
+    /// Whether label or state has been set, so they override metadata fields with the same names.
+    pub fn is_structured(&self) -> bool {
+        self.label.is_some() || self.state.is_some()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == PairwiseMeta::default()
+    }
+}
+
+/// Changes of structured pairwise meta. Omitted fields are left unchanged.
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct PairwiseMetaUpdate {
+    pub label: Option<String>,
+    pub state: Option<String>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct PairwiseMetaMigrationReport {
+    pub total: usize,
+    pub migrated: usize,
+}
+
+/// Wallet record linked to pairwise. Linked record gets managed tag (see `PairwiseLink::tag_name`),
+/// link itself is stored as separate record, so all records linked to pairwise can be found at once.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PairwiseLink {
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub id: String,
+}
+
+impl PairwiseLink {
+    pub fn tag_name(their_did: &DidValue) -> String {
+        format!("pairwise:{}", their_did.0)
+    }
+
+    pub fn link_id(&self, their_did: &DidValue) -> String {
+        format!("{}:{}:{}", their_did.0, self.type_, self.id)
+    }
+
+    pub fn tags(&self, their_did: &DidValue) -> Tags {
+        let mut tags = Tags::new();
+        tags.insert("their_did".to_string(), their_did.0.clone());
+        tags.insert("type".to_string(), self.type_.clone());
+        tags
+    }
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct PairwiseInfo {
     pub my_did: DidValue,
@@ -22,4 +174,107 @@ impl From<Pairwise> for PairwiseInfo {
             metadata: pairwise.metadata
         }
     }
-}
\ No newline at end of file
+}
+
+/// Options of pairwise search. Resolving of verkeys requires two additional
+/// wallet lookups for each fetched pairwise, so it is disabled by default.
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PairwiseSearchOptions {
+    #[serde(default)]
+    pub retrieve_verkeys: bool,
+}
+
+#[derive(Serialize, Debug)]
+pub struct PairwiseWithVerkeys {
+    pub my_did: DidValue,
+    pub their_did: DidValue,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub my_verkey: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub their_verkey: Option<String>,
+    /// Structured meta is returned once it is set
+    #[serde(flatten)]
+    pub meta: Option<PairwiseMeta>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn _pairwise(metadata: Option<&str>) -> Pairwise {
+        Pairwise {
+            my_did: DidValue("8wZcEriaNLNKtteJvx7f8i".to_string()),
+            their_did: DidValue("VsKV7grR1BUE29mG2Fm2kX".to_string()),
+            metadata: metadata.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn pairwise_tags_works_for_json_metadata() {
+        let tags = _pairwise(Some(r#"{"label":"Alice","~role":"holder","score":5,"my_did":"other"}"#)).tags(&PairwiseMeta::default());
+
+        let expected: Tags = vec![
+            ("my_did".to_string(), "8wZcEriaNLNKtteJvx7f8i".to_string()),
+            ("label".to_string(), "Alice".to_string()),
+            ("~role".to_string(), "holder".to_string()),
+        ].into_iter().collect();
+
+        assert_eq!(expected, tags);
+    }
+
+    #[test]
+    fn pairwise_tags_works_for_plain_metadata() {
+        let expected: Tags = vec![("my_did".to_string(), "8wZcEriaNLNKtteJvx7f8i".to_string())].into_iter().collect();
+
+        assert_eq!(expected, _pairwise(Some("some metadata")).tags(&PairwiseMeta::default()));
+        assert_eq!(expected, _pairwise(None).tags(&PairwiseMeta::default()));
+    }
+
+    #[test]
+    fn pairwise_tags_works_for_created_at() {
+        let meta = PairwiseMeta { created_at: Some(1602667200), ..PairwiseMeta::default() };
+
+        let tags = _pairwise(Some(r#"{"label":"Bob","state":"invited"}"#)).tags(&meta);
+
+        let expected: Tags = vec![
+            ("my_did".to_string(), "8wZcEriaNLNKtteJvx7f8i".to_string()),
+            ("label".to_string(), "Bob".to_string()),
+            ("state".to_string(), "invited".to_string()),
+            ("~created_at".to_string(), "1602667200".to_string()),
+        ].into_iter().collect();
+
+        assert_eq!(expected, tags);
+        // label and state are metadata fields until structured meta is set
+        assert_eq!(meta, PairwiseMeta::from_tags(Some(&tags)));
+    }
+
+    #[test]
+    fn pairwise_tags_works_for_meta() {
+        let meta = PairwiseMeta { label: Some("Alice".to_string()), state: None, created_at: Some(1602667200) };
+
+        let tags = _pairwise(Some(r#"{"label":"Bob","state":"invited","nickname":"Al"}"#)).tags(&meta);
+
+        // metadata fields with the names of structured meta are ignored, even if the meta field isn't set
+        let expected: Tags = vec![
+            ("my_did".to_string(), "8wZcEriaNLNKtteJvx7f8i".to_string()),
+            ("label".to_string(), "Alice".to_string()),
+            ("nickname".to_string(), "Al".to_string()),
+            ("~created_at".to_string(), "1602667200".to_string()),
+            ("~structured_meta".to_string(), "true".to_string()),
+        ].into_iter().collect();
+
+        assert_eq!(expected, tags);
+        assert_eq!(meta, PairwiseMeta::from_tags(Some(&tags)));
+    }
+
+    #[test]
+    fn pairwise_legacy_meta_works() {
+        let meta = _pairwise(Some(r#"{"label":"Bob","state":"invited","other":"value"}"#)).legacy_meta();
+
+        assert_eq!(PairwiseMeta { label: Some("Bob".to_string()), state: Some("invited".to_string()), created_at: None }, meta);
+        assert!(_pairwise(Some("some metadata")).legacy_meta().is_empty());
+    }
+}
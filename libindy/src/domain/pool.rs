@@ -35,6 +35,16 @@ pub struct PoolOpenConfig {
     pub number_read_nodes: u8,
     #[serde(default = "PoolOpenConfig::default_socks_proxy")]
     pub socks_proxy: String,
+    #[serde(default)]
+    pub max_in_flight_requests: usize,
+    #[serde(default)]
+    pub max_sockets: usize,
+    #[serde(default = "PoolOpenConfig::default_conn_count_per_node")]
+    pub conn_count_per_node: usize,
+    #[serde(default)]
+    pub reply_capture_size: usize,
+    #[serde(default)]
+    pub read_connections: usize,
 }
 
 impl Validatable for PoolOpenConfig {
@@ -54,6 +64,9 @@ impl Validatable for PoolOpenConfig {
         if self.number_read_nodes == 0 {
             return Err(String::from("`number_read_nodes` must be greater than 0"));
         }
+        if self.conn_count_per_node == 0 {
+            return Err(String::from("`conn_count_per_node` must be greater than 0"));
+        }
         Ok(())
     }
 }
@@ -68,6 +81,11 @@ impl Default for PoolOpenConfig {
             preordered_nodes: PoolOpenConfig::default_preordered_nodes(),
             number_read_nodes: PoolOpenConfig::default_number_read_nodes(),
             socks_proxy: PoolOpenConfig::default_socks_proxy(),
+            max_in_flight_requests: 0,
+            max_sockets: 0,
+            conn_count_per_node: PoolOpenConfig::default_conn_count_per_node(),
+            reply_capture_size: 0,
+            read_connections: 0,
         }
     }
 }
@@ -96,4 +114,70 @@ impl PoolOpenConfig {
     fn default_number_read_nodes() -> u8 { NUMBER_READ_NODES }
 
     fn default_socks_proxy() -> String { String::new() }
+
+    fn default_conn_count_per_node() -> usize { 1 }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RequestPriority {
+    #[default]
+    Normal,
+    High,
+}
+
+impl RequestPriority {
+    pub fn to_byte(self) -> u8 {
+        match self {
+            RequestPriority::Normal => 0,
+            RequestPriority::High => 1,
+        }
+    }
+
+    pub fn from_byte(byte: u8) -> Self {
+        match byte {
+            1 => RequestPriority::High,
+            _ => RequestPriority::Normal,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct SubmitRequestOptions {
+    pub timeout: Option<i32>,
+    #[serde(default)]
+    pub priority: RequestPriority,
+}
+
+impl Validatable for SubmitRequestOptions {
+    fn validate(&self) -> Result<(), String> {
+        if let Some(timeout) = self.timeout {
+            if timeout <= 0 {
+                return Err(String::from("`timeout` must be greater than 0"));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Raw request sent by the pool with the raw node replies to it.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CapturedRequest {
+    pub req_id: String,
+    pub request: String,
+    pub size: usize,
+    pub timestamp: u64,
+    pub replies: Vec<CapturedReply>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CapturedReply {
+    pub node: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub op: Option<String>,
+    pub reply: String,
+    pub size: usize,
+    pub timestamp: u64,
 }
@@ -61,6 +61,8 @@ extern crate indy_wallet;
 mod utils;
 
 pub mod api;
+#[cfg(feature = "rust-api")]
+pub mod rust_api;
 mod commands;
 mod services;
 mod domain;
@@ -0,0 +1,185 @@
+use futures::Future;
+
+use indy_api_types::WalletHandle;
+
+use crate::commands::Command;
+use crate::commands::anoncreds::AnoncredsCommand;
+use crate::commands::anoncreds::issuer::IssuerCommand;
+use crate::commands::anoncreds::prover::ProverCommand;
+use crate::commands::anoncreds::verifier::VerifierCommand;
+use crate::domain::anoncreds::credential::{Credential, CredentialValues};
+use crate::domain::anoncreds::credential_definition::{CredentialDefinition, CredentialDefinitionConfig, CredentialDefinitionId, CredentialDefinitions};
+use crate::domain::anoncreds::credential_offer::CredentialOffer;
+use crate::domain::anoncreds::credential_request::{CredentialRequest, CredentialRequestMetadata};
+use crate::domain::anoncreds::proof::Proof;
+use crate::domain::anoncreds::proof_request::ProofRequest;
+use crate::domain::anoncreds::requested_credential::RequestedCredentials;
+use crate::domain::anoncreds::revocation_registry::RevocationRegistries;
+use crate::domain::anoncreds::revocation_registry_definition::{RevocationRegistryDefinition, RevocationRegistryDefinitions};
+use crate::domain::anoncreds::revocation_state::RevocationStates;
+use crate::domain::anoncreds::schema::{AttributeNames, Schema, Schemas};
+use crate::domain::crypto::did::DidValue;
+
+use super::{IndyFuture, Wallet, parse_json, parse_opt_json, send, validate};
+
+/// Anoncreds operations of issuer, prover and verifier.
+#[derive(Debug)]
+pub struct Anoncreds {
+    wallet_handle: WalletHandle,
+}
+
+impl Anoncreds {
+    pub fn new(wallet: &Wallet) -> Anoncreds {
+        Anoncreds { wallet_handle: wallet.handle() }
+    }
+
+    /// Creates credential schema. Resolves to `(schema_id, schema_json)`.
+    pub fn issuer_create_schema(issuer_did: &str, name: &str, version: &str, attrs: &str) -> IndyFuture<(String, String)> {
+        send(|cb| {
+            let issuer_did = validate(DidValue(issuer_did.to_string()))?;
+            let attrs: AttributeNames = validate(parse_json(attrs, "AttributeNames")?)?;
+            Ok(Command::Anoncreds(AnoncredsCommand::Issuer(
+                IssuerCommand::CreateSchema(issuer_did, name.to_string(), version.to_string(), attrs, cb))))
+        })
+    }
+
+    /// Creates credential definition and stores its private part in the wallet.
+    /// Resolves to `(cred_def_id, cred_def_json)`. See `indy_issuer_create_and_store_credential_def` for formats.
+    pub fn issuer_create_and_store_credential_def(&self, issuer_did: &str, schema_json: &str, tag: &str,
+                                                  signature_type: Option<&str>, config_json: Option<&str>) -> IndyFuture<(String, String)> {
+        let wallet_handle = self.wallet_handle;
+
+        send(move |cb| {
+            let issuer_did = validate(DidValue(issuer_did.to_string()))?;
+            let schema: Schema = validate(parse_json(schema_json, "Schema")?)?;
+            let config: Option<CredentialDefinitionConfig> = parse_opt_json(config_json, "CredentialDefinitionConfig")?
+                .map(validate)
+                .transpose()?;
+            Ok(Command::Anoncreds(AnoncredsCommand::Issuer(
+                IssuerCommand::CreateAndStoreCredentialDefinition(wallet_handle, issuer_did, schema, tag.to_string(),
+                                                                  signature_type.map(String::from), config, cb))))
+        })
+    }
+
+    pub fn issuer_create_credential_offer(&self, cred_def_id: &str) -> IndyFuture<String> {
+        let wallet_handle = self.wallet_handle;
+
+        send(move |cb| {
+            let cred_def_id = validate(CredentialDefinitionId(cred_def_id.to_string()))?;
+            Ok(Command::Anoncreds(AnoncredsCommand::Issuer(
+                IssuerCommand::CreateCredentialOffer(wallet_handle, cred_def_id, cb))))
+        })
+    }
+
+    /// Creates master secret with the given or generated id. Resolves to the id.
+    pub fn prover_create_master_secret(&self, master_secret_id: Option<&str>) -> IndyFuture<String> {
+        let wallet_handle = self.wallet_handle;
+
+        send(move |cb| Ok(Command::Anoncreds(AnoncredsCommand::Prover(
+            ProverCommand::CreateMasterSecret(wallet_handle, master_secret_id.map(String::from), cb)))))
+    }
+
+    /// Issues credential without revocation support. Resolves to credential json.
+    /// See `indy_issuer_create_credential` for formats.
+    pub fn issuer_create_credential(&self, cred_offer_json: &str, cred_req_json: &str, cred_values_json: &str) -> IndyFuture<String> {
+        let wallet_handle = self.wallet_handle;
+
+        let created = send(move |cb| {
+            let cred_offer: CredentialOffer = validate(parse_json(cred_offer_json, "CredentialOffer")?)?;
+            let cred_req: CredentialRequest = validate(parse_json(cred_req_json, "CredentialRequest")?)?;
+            let cred_values: CredentialValues = validate(parse_json(cred_values_json, "CredentialValues")?)?;
+            Ok(Command::Anoncreds(AnoncredsCommand::Issuer(
+                IssuerCommand::CreateCredential(wallet_handle, cred_offer, cred_req, cred_values, None, None, cb))))
+        });
+
+        Box::new(created.map(|(cred_json, _, _)| cred_json))
+    }
+
+    /// Creates credential request for the offer. Resolves to `(cred_req_json, cred_req_metadata_json)`.
+    pub fn prover_create_credential_req(&self, prover_did: &str, cred_offer_json: &str, cred_def_json: &str,
+                                        master_secret_id: &str) -> IndyFuture<(String, String)> {
+        let wallet_handle = self.wallet_handle;
+
+        send(move |cb| {
+            let prover_did = validate(DidValue(prover_did.to_string()))?;
+            let cred_offer: CredentialOffer = validate(parse_json(cred_offer_json, "CredentialOffer")?)?;
+            let cred_def: CredentialDefinition = validate(parse_json(cred_def_json, "CredentialDefinition")?)?;
+            Ok(Command::Anoncreds(AnoncredsCommand::Prover(
+                ProverCommand::CreateCredentialRequest(wallet_handle, prover_did, cred_offer, cred_def,
+                                                       master_secret_id.to_string(), cb))))
+        })
+    }
+
+    /// Stores the credential in the wallet. Resolves to the credential id.
+    pub fn prover_store_credential(&self, cred_id: Option<&str>, cred_req_metadata_json: &str, cred_json: &str,
+                                   cred_def_json: &str, rev_reg_def_json: Option<&str>) -> IndyFuture<String> {
+        let wallet_handle = self.wallet_handle;
+
+        send(move |cb| {
+            let cred_req_metadata: CredentialRequestMetadata = validate(parse_json(cred_req_metadata_json, "CredentialRequestMetadata")?)?;
+            let cred: Credential = validate(parse_json(cred_json, "Credential")?)?;
+            let cred_def: CredentialDefinition = validate(parse_json(cred_def_json, "CredentialDefinition")?)?;
+            let rev_reg_def: Option<RevocationRegistryDefinition> = parse_opt_json(rev_reg_def_json, "RevocationRegistryDefinition")?
+                .map(validate)
+                .transpose()?;
+            Ok(Command::Anoncreds(AnoncredsCommand::Prover(
+                ProverCommand::StoreCredential(wallet_handle, cred_id.map(String::from), cred_req_metadata, cred, cred_def,
+                                               rev_reg_def, None, cb))))
+        })
+    }
+
+    /// Resolves to human readable credential json. See `indy_prover_get_credential` for format.
+    pub fn prover_get_credential(&self, cred_id: &str) -> IndyFuture<String> {
+        let wallet_handle = self.wallet_handle;
+
+        send(move |cb| Ok(Command::Anoncreds(AnoncredsCommand::Prover(
+            ProverCommand::GetCredential(wallet_handle, cred_id.to_string(), cb)))))
+    }
+
+    /// Resolves to credentials matching the proof request. See `indy_prover_get_credentials_for_proof_req` for format.
+    pub fn prover_get_credentials_for_proof_req(&self, proof_request_json: &str) -> IndyFuture<String> {
+        let wallet_handle = self.wallet_handle;
+
+        send(move |cb| {
+            let proof_request: ProofRequest = validate(parse_json(proof_request_json, "ProofRequest")?)?;
+            Ok(Command::Anoncreds(AnoncredsCommand::Prover(
+                ProverCommand::GetCredentialsForProofReq(wallet_handle, proof_request, cb))))
+        })
+    }
+
+    /// Creates proof for the proof request. Resolves to proof json. See `indy_prover_create_proof` for formats.
+    pub fn prover_create_proof(&self, proof_request_json: &str, requested_credentials_json: &str, master_secret_id: &str,
+                               schemas_json: &str, credential_defs_json: &str, rev_states_json: &str) -> IndyFuture<String> {
+        let wallet_handle = self.wallet_handle;
+
+        send(move |cb| {
+            let proof_request: ProofRequest = validate(parse_json(proof_request_json, "ProofRequest")?)?;
+            let requested_credentials: RequestedCredentials = validate(parse_json(requested_credentials_json, "RequestedCredentials")?)?;
+            let schemas: Schemas = parse_json(schemas_json, "Schemas")?;
+            let credential_defs: CredentialDefinitions = parse_json(credential_defs_json, "CredentialDefinitions")?;
+            let rev_states: RevocationStates = parse_json(rev_states_json, "RevocationStates")?;
+            Ok(Command::Anoncreds(AnoncredsCommand::Prover(
+                ProverCommand::CreateProof(wallet_handle, proof_request, requested_credentials, master_secret_id.to_string(),
+                                           schemas, credential_defs, rev_states, cb))))
+        })
+    }
+
+    /// Verifies the proof. See `indy_verifier_verify_proof` for formats.
+    pub fn verifier_verify_proof(proof_request_json: &str, proof_json: &str, schemas_json: &str, credential_defs_json: &str,
+                                 rev_reg_defs_json: &str, rev_regs_json: &str) -> IndyFuture<bool> {
+        send(|cb| {
+            let proof_request: ProofRequest = validate(parse_json(proof_request_json, "ProofRequest")?)?;
+            let proof: Proof = validate(parse_json(proof_json, "Proof")?)?;
+            let schemas: Schemas = parse_json(schemas_json, "Schemas")?;
+            let credential_defs: CredentialDefinitions = parse_json(credential_defs_json, "CredentialDefinitions")?;
+            let rev_reg_defs: RevocationRegistryDefinitions = parse_json(rev_reg_defs_json, "RevocationRegistryDefinitions")?;
+            let rev_regs: RevocationRegistries = parse_json(rev_regs_json, "RevocationRegistries")?;
+            Ok(Command::Anoncreds(AnoncredsCommand::Verifier(
+                VerifierCommand::VerifyProof(proof_request, proof, schemas, credential_defs, rev_reg_defs, rev_regs, cb))))
+        })
+    }
+
+    pub fn generate_nonce() -> IndyFuture<String> {
+        send(|cb| Ok(Command::Anoncreds(AnoncredsCommand::Verifier(VerifierCommand::GenerateNonce(cb)))))
+    }
+}
@@ -0,0 +1,90 @@
+use indy_api_types::WalletHandle;
+use indy_api_types::errors::prelude::*;
+
+use crate::commands::Command;
+use crate::commands::crypto::CryptoCommand;
+use crate::domain::crypto::key::KeyInfo;
+use crate::domain::crypto::pack::JWE;
+
+use super::{IndyFuture, Wallet, parse_json, send};
+
+/// Crypto operations with the keys stored in the wallet.
+#[derive(Debug)]
+pub struct Crypto {
+    wallet_handle: WalletHandle,
+}
+
+impl Crypto {
+    pub fn new(wallet: &Wallet) -> Crypto {
+        Crypto { wallet_handle: wallet.handle() }
+    }
+
+    /// Creates a key and stores it in the wallet. Resolves to its verkey.
+    /// See `indy_create_key` for `key_json` format.
+    pub fn create_key(&self, key_json: &str) -> IndyFuture<String> {
+        let wallet_handle = self.wallet_handle;
+
+        send(move |cb| {
+            let key_info: KeyInfo = parse_json(key_json, "KeyInfo")?;
+            Ok(Command::Crypto(CryptoCommand::CreateKey(wallet_handle, key_info, cb)))
+        })
+    }
+
+    pub fn sign(&self, my_vk: &str, message: &[u8]) -> IndyFuture<Vec<u8>> {
+        let wallet_handle = self.wallet_handle;
+
+        send(move |cb| Ok(Command::Crypto(CryptoCommand::CryptoSign(wallet_handle, my_vk.to_string(), message.to_vec(), cb))))
+    }
+
+    pub fn verify(their_vk: &str, message: &[u8], signature: &[u8]) -> IndyFuture<bool> {
+        send(|cb| Ok(Command::Crypto(CryptoCommand::CryptoVerify(their_vk.to_string(), message.to_vec(), signature.to_vec(), cb))))
+    }
+
+    pub fn auth_crypt(&self, my_vk: &str, their_vk: &str, message: &[u8]) -> IndyFuture<Vec<u8>> {
+        let wallet_handle = self.wallet_handle;
+
+        send(move |cb| Ok(Command::Crypto(CryptoCommand::AuthenticatedEncrypt(
+            wallet_handle, my_vk.to_string(), their_vk.to_string(), message.to_vec(), cb))))
+    }
+
+    /// Resolves to `(sender_vk, message)`.
+    pub fn auth_decrypt(&self, my_vk: &str, encrypted_message: &[u8]) -> IndyFuture<(String, Vec<u8>)> {
+        let wallet_handle = self.wallet_handle;
+
+        send(move |cb| Ok(Command::Crypto(CryptoCommand::AuthenticatedDecrypt(
+            wallet_handle, my_vk.to_string(), encrypted_message.to_vec(), cb))))
+    }
+
+    pub fn anon_crypt(their_vk: &str, message: &[u8]) -> IndyFuture<Vec<u8>> {
+        send(|cb| Ok(Command::Crypto(CryptoCommand::AnonymousEncrypt(their_vk.to_string(), message.to_vec(), cb))))
+    }
+
+    pub fn anon_decrypt(&self, my_vk: &str, encrypted_message: &[u8]) -> IndyFuture<Vec<u8>> {
+        let wallet_handle = self.wallet_handle;
+
+        send(move |cb| Ok(Command::Crypto(CryptoCommand::AnonymousDecrypt(
+            wallet_handle, my_vk.to_string(), encrypted_message.to_vec(), cb))))
+    }
+
+    /// Packs the message in JWE-like format. See `indy_pack_message` for `receiver_keys` format.
+    pub fn pack_message(&self, message: &[u8], receiver_keys: &str, sender_vk: Option<&str>) -> IndyFuture<Vec<u8>> {
+        let wallet_handle = self.wallet_handle;
+
+        send(move |cb| {
+            let receiver_keys: Vec<String> = parse_json(receiver_keys, "receiver keys")?;
+            Ok(Command::Crypto(CryptoCommand::PackMessage(
+                message.to_vec(), receiver_keys, sender_vk.map(String::from), wallet_handle, cb)))
+        })
+    }
+
+    /// Unpacks the message packed by `pack_message`. See `indy_unpack_message` for the result format.
+    pub fn unpack_message(&self, jwe: &[u8]) -> IndyFuture<Vec<u8>> {
+        let wallet_handle = self.wallet_handle;
+
+        send(move |cb| {
+            let jwe: JWE = serde_json::from_slice(jwe)
+                .to_indy(IndyErrorKind::InvalidStructure, "Invalid JWE json has been passed")?;
+            Ok(Command::Crypto(CryptoCommand::UnpackMessage(jwe, wallet_handle, cb)))
+        })
+    }
+}
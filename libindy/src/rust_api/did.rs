@@ -0,0 +1,85 @@
+use indy_api_types::WalletHandle;
+
+use crate::commands::Command;
+use crate::commands::did::DidCommand;
+use crate::domain::crypto::did::{DidValue, MyDidInfo, TheirDidInfo};
+
+use super::{IndyFuture, Wallet, parse_json, send, validate};
+
+/// DID operations over the wallet.
+#[derive(Debug)]
+pub struct Did {
+    wallet_handle: WalletHandle,
+}
+
+impl Did {
+    pub fn new(wallet: &Wallet) -> Did {
+        Did { wallet_handle: wallet.handle() }
+    }
+
+    /// Creates keys for a new DID and stores them in the wallet. Resolves to `(did, verkey)`.
+    /// See `indy_create_and_store_my_did` for `did_info` format.
+    pub fn create_and_store_my_did(&self, did_info: &str) -> IndyFuture<(String, String)> {
+        let wallet_handle = self.wallet_handle;
+
+        send(move |cb| {
+            let did_info: MyDidInfo = validate(parse_json(did_info, "MyDidInfo")?)?;
+            Ok(Command::Did(DidCommand::CreateAndStoreMyDid(wallet_handle, did_info, cb)))
+        })
+    }
+
+    /// Stores their DID. See `indy_store_their_did` for `identity_json` format.
+    pub fn store_their_did(&self, identity_json: &str) -> IndyFuture<()> {
+        let wallet_handle = self.wallet_handle;
+
+        send(move |cb| {
+            let identity: TheirDidInfo = validate(parse_json(identity_json, "TheirDidInfo")?)?;
+            Ok(Command::Did(DidCommand::StoreTheirDid(wallet_handle, identity, cb)))
+        })
+    }
+
+    /// Returns verkey of my or their DID stored in the wallet.
+    pub fn key_for_local_did(&self, did: &str) -> IndyFuture<String> {
+        let wallet_handle = self.wallet_handle;
+
+        send(move |cb| {
+            let did = validate(DidValue(did.to_string()))?;
+            Ok(Command::Did(DidCommand::KeyForLocalDid(wallet_handle, did, cb)))
+        })
+    }
+
+    pub fn set_did_metadata(&self, did: &str, metadata: &str) -> IndyFuture<()> {
+        let wallet_handle = self.wallet_handle;
+
+        send(move |cb| {
+            let did = validate(DidValue(did.to_string()))?;
+            Ok(Command::Did(DidCommand::SetDidMetadata(wallet_handle, did, metadata.to_string(), cb)))
+        })
+    }
+
+    pub fn get_did_metadata(&self, did: &str) -> IndyFuture<String> {
+        let wallet_handle = self.wallet_handle;
+
+        send(move |cb| {
+            let did = validate(DidValue(did.to_string()))?;
+            Ok(Command::Did(DidCommand::GetDidMetadata(wallet_handle, did, cb)))
+        })
+    }
+
+    /// Resolves to my DID info json. See `indy_get_my_did_with_meta` for format.
+    pub fn get_my_did_with_meta(&self, did: &str) -> IndyFuture<String> {
+        let wallet_handle = self.wallet_handle;
+
+        send(move |cb| {
+            let did = validate(DidValue(did.to_string()))?;
+            Ok(Command::Did(DidCommand::GetMyDidWithMeta(wallet_handle, did, cb)))
+        })
+    }
+
+    /// Resolves to json list of my DIDs. See `indy_list_my_dids_with_meta` for format.
+    pub fn list_my_dids_with_meta(&self) -> IndyFuture<String> {
+        let wallet_handle = self.wallet_handle;
+
+        send(move |cb| Ok(Command::Did(DidCommand::ListMyDidsWithMeta(wallet_handle, cb))))
+    }
+}
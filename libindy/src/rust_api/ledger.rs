@@ -0,0 +1,91 @@
+use crate::commands::Command;
+use crate::commands::ledger::LedgerCommand;
+use crate::domain::anoncreds::credential_definition::CredentialDefinitionId;
+use crate::domain::anoncreds::schema::{Schema, SchemaId};
+use crate::domain::crypto::did::DidValue;
+
+use super::{IndyFuture, Pool, Wallet, parse_json, send, validate};
+
+/// Ledger requests. See `indy_build_*_request` functions for formats.
+#[derive(Debug)]
+pub struct Ledger;
+
+impl Ledger {
+    /// Signs the request with the key of `submitter_did` and sends it to the pool.
+    pub fn sign_and_submit_request(pool: &Pool, wallet: &Wallet, submitter_did: &str, request_json: &str) -> IndyFuture<String> {
+        let (pool_handle, wallet_handle) = (pool.handle(), wallet.handle());
+
+        send(move |cb| {
+            let submitter_did = validate(DidValue(submitter_did.to_string()))?;
+            Ok(Command::Ledger(LedgerCommand::SignAndSubmitRequest(
+                pool_handle, wallet_handle, submitter_did, request_json.to_string(), cb)))
+        })
+    }
+
+    /// Sends the request to the pool as is.
+    pub fn submit_request(pool: &Pool, request_json: &str) -> IndyFuture<String> {
+        let pool_handle = pool.handle();
+
+        send(move |cb| Ok(Command::Ledger(LedgerCommand::SubmitRequest(pool_handle, request_json.to_string(), cb))))
+    }
+
+    pub fn sign_request(wallet: &Wallet, submitter_did: &str, request_json: &str) -> IndyFuture<String> {
+        let wallet_handle = wallet.handle();
+
+        send(move |cb| {
+            let submitter_did = validate(DidValue(submitter_did.to_string()))?;
+            Ok(Command::Ledger(LedgerCommand::SignRequest(wallet_handle, submitter_did, request_json.to_string(), cb)))
+        })
+    }
+
+    pub fn multi_sign_request(wallet: &Wallet, submitter_did: &str, request_json: &str) -> IndyFuture<String> {
+        let wallet_handle = wallet.handle();
+
+        send(move |cb| {
+            let submitter_did = validate(DidValue(submitter_did.to_string()))?;
+            Ok(Command::Ledger(LedgerCommand::MultiSignRequest(wallet_handle, submitter_did, request_json.to_string(), cb)))
+        })
+    }
+
+    pub fn build_nym_request(submitter_did: &str, target_did: &str, verkey: Option<&str>,
+                             alias: Option<&str>, role: Option<&str>) -> IndyFuture<String> {
+        send(|cb| {
+            let submitter_did = validate(DidValue(submitter_did.to_string()))?;
+            let target_did = validate(DidValue(target_did.to_string()))?;
+            Ok(Command::Ledger(LedgerCommand::BuildNymRequest(submitter_did, target_did, verkey.map(String::from),
+                                                              alias.map(String::from), role.map(String::from), cb)))
+        })
+    }
+
+    pub fn build_get_nym_request(submitter_did: Option<&str>, target_did: &str) -> IndyFuture<String> {
+        send(|cb| {
+            let submitter_did = submitter_did.map(|did| validate(DidValue(did.to_string()))).transpose()?;
+            let target_did = validate(DidValue(target_did.to_string()))?;
+            Ok(Command::Ledger(LedgerCommand::BuildGetNymRequest(submitter_did, target_did, None, None, cb)))
+        })
+    }
+
+    pub fn build_schema_request(submitter_did: &str, schema_json: &str) -> IndyFuture<String> {
+        send(|cb| {
+            let submitter_did = validate(DidValue(submitter_did.to_string()))?;
+            let schema: Schema = validate(parse_json(schema_json, "Schema")?)?;
+            Ok(Command::Ledger(LedgerCommand::BuildSchemaRequest(submitter_did, schema, cb)))
+        })
+    }
+
+    pub fn build_get_schema_request(submitter_did: Option<&str>, schema_id: &str) -> IndyFuture<String> {
+        send(|cb| {
+            let submitter_did = submitter_did.map(|did| validate(DidValue(did.to_string()))).transpose()?;
+            let schema_id = validate(SchemaId(schema_id.to_string()))?;
+            Ok(Command::Ledger(LedgerCommand::BuildGetSchemaRequest(submitter_did, schema_id, cb)))
+        })
+    }
+
+    pub fn build_get_cred_def_request(submitter_did: Option<&str>, cred_def_id: &str) -> IndyFuture<String> {
+        send(|cb| {
+            let submitter_did = submitter_did.map(|did| validate(DidValue(did.to_string()))).transpose()?;
+            let cred_def_id = validate(CredentialDefinitionId(cred_def_id.to_string()))?;
+            Ok(Command::Ledger(LedgerCommand::BuildGetCredDefRequest(submitter_did, cred_def_id, cb)))
+        })
+    }
+}
@@ -0,0 +1,99 @@
+//! Native asynchronous Rust API.
+//!
+//! Operations are sent to the command executor directly and complete the returned futures,
+//! so Rust applications don't need C callbacks and command handles. Parameters use the same
+//! JSON formats as C API.
+
+use std::sync::{Arc, Mutex};
+
+use futures::Future;
+use futures::sync::oneshot;
+use serde::de::DeserializeOwned;
+
+use indy_api_types::errors::prelude::*;
+use indy_api_types::validation::Validatable;
+
+use crate::commands::{Command, CommandExecutor};
+use crate::commands::timeout::with_timeout;
+
+#[cfg(feature = "anoncreds")]
+pub mod anoncreds;
+pub mod crypto;
+pub mod did;
+#[cfg(feature = "ledger")]
+pub mod ledger;
+pub mod non_secrets;
+#[cfg(feature = "ledger")]
+pub mod pool;
+pub mod wallet;
+
+#[cfg(feature = "anoncreds")]
+pub use self::anoncreds::Anoncreds;
+pub use self::crypto::Crypto;
+pub use self::did::Did;
+#[cfg(feature = "ledger")]
+pub use self::ledger::Ledger;
+pub use self::non_secrets::NonSecrets;
+#[cfg(feature = "ledger")]
+pub use self::pool::Pool;
+pub use self::wallet::Wallet;
+
+pub type IndyFuture<T> = Box<dyn Future<Item=T, Error=IndyError> + Send>;
+
+type ResultCallback<T> = Box<dyn Fn(IndyResult<T>) + Send>;
+
+/// Sends command built by `build_command` and returns future resolved by its callback.
+///
+/// The command is built before its callback is registered with the command timeout, so invalid
+/// parameters don't leave a pending call behind.
+fn send<T, F>(build_command: F) -> IndyFuture<T>
+    where T: Send + 'static,
+          F: FnOnce(ResultCallback<T>) -> IndyResult<Command> {
+    let (sender, receiver) = oneshot::channel();
+    let sender = Mutex::new(Some(sender));
+
+    let slot: Arc<Mutex<Option<ResultCallback<T>>>> = Arc::new(Mutex::new(None));
+    let forward = slot.clone();
+
+    let command = build_command(Box::new(move |result| {
+        if let Some(cb) = forward.lock().unwrap().as_ref() {
+            cb(result)
+        }
+    }));
+
+    let command = match command {
+        Ok(command) => command,
+        Err(err) => return Box::new(futures::future::err(err)),
+    };
+
+    *slot.lock().unwrap() = Some(with_timeout(move |result| {
+        if let Some(sender) = sender.lock().unwrap().take() {
+            // receiver is gone if application dropped the future
+            let _ = sender.send(result);
+        }
+    }));
+
+    if let Err(err) = CommandExecutor::submit(command) {
+        return Box::new(futures::future::err(err));
+    }
+
+    Box::new(receiver.then(|result| match result {
+        Ok(result) => result,
+        Err(_) => Err(err_msg(IndyErrorKind::InvalidState, "Command has been dropped without result")),
+    }))
+}
+
+fn parse_json<T: DeserializeOwned>(json: &str, name: &str) -> IndyResult<T> {
+    serde_json::from_str(json)
+        .to_indy(IndyErrorKind::InvalidStructure, format!("Invalid {} json has been passed", name))
+}
+
+fn parse_opt_json<T: DeserializeOwned>(json: Option<&str>, name: &str) -> IndyResult<Option<T>> {
+    json.map(|json| parse_json(json, name)).transpose()
+}
+
+fn validate<T: Validatable>(value: T) -> IndyResult<T> {
+    value.validate()
+        .map_err(|err| err_msg(IndyErrorKind::InvalidStructure, err))?;
+    Ok(value)
+}
@@ -0,0 +1,98 @@
+use indy_api_types::{SearchHandle, WalletHandle};
+use indy_api_types::domain::wallet::Tags;
+
+use crate::commands::Command;
+use crate::commands::non_secrets::NonSecretsCommand;
+
+use super::{IndyFuture, Wallet, parse_json, parse_opt_json, send};
+
+/// Non-secret records of the wallet. See `indy_add_wallet_record` and related functions for formats.
+#[derive(Debug)]
+pub struct NonSecrets {
+    wallet_handle: WalletHandle,
+}
+
+impl NonSecrets {
+    pub fn new(wallet: &Wallet) -> NonSecrets {
+        NonSecrets { wallet_handle: wallet.handle() }
+    }
+
+    pub fn add_record(&self, type_: &str, id: &str, value: &str, tags_json: Option<&str>) -> IndyFuture<()> {
+        let wallet_handle = self.wallet_handle;
+
+        send(move |cb| {
+            let tags: Option<Tags> = parse_opt_json(tags_json, "Tags")?;
+            Ok(Command::NonSecrets(NonSecretsCommand::AddRecord(
+                wallet_handle, type_.to_string(), id.to_string(), value.to_string(), tags, cb)))
+        })
+    }
+
+    pub fn update_record_value(&self, type_: &str, id: &str, value: &str) -> IndyFuture<()> {
+        let wallet_handle = self.wallet_handle;
+
+        send(move |cb| Ok(Command::NonSecrets(NonSecretsCommand::UpdateRecordValue(
+            wallet_handle, type_.to_string(), id.to_string(), value.to_string(), cb))))
+    }
+
+    pub fn update_record_tags(&self, type_: &str, id: &str, tags_json: &str) -> IndyFuture<()> {
+        let wallet_handle = self.wallet_handle;
+
+        send(move |cb| {
+            let tags: Tags = parse_json(tags_json, "Tags")?;
+            Ok(Command::NonSecrets(NonSecretsCommand::UpdateRecordTags(
+                wallet_handle, type_.to_string(), id.to_string(), tags, cb)))
+        })
+    }
+
+    pub fn add_record_tags(&self, type_: &str, id: &str, tags_json: &str) -> IndyFuture<()> {
+        let wallet_handle = self.wallet_handle;
+
+        send(move |cb| {
+            let tags: Tags = parse_json(tags_json, "Tags")?;
+            Ok(Command::NonSecrets(NonSecretsCommand::AddRecordTags(
+                wallet_handle, type_.to_string(), id.to_string(), tags, cb)))
+        })
+    }
+
+    pub fn delete_record_tags(&self, type_: &str, id: &str, tag_names_json: &str) -> IndyFuture<()> {
+        let wallet_handle = self.wallet_handle;
+
+        send(move |cb| Ok(Command::NonSecrets(NonSecretsCommand::DeleteRecordTags(
+            wallet_handle, type_.to_string(), id.to_string(), tag_names_json.to_string(), cb))))
+    }
+
+    pub fn delete_record(&self, type_: &str, id: &str) -> IndyFuture<()> {
+        let wallet_handle = self.wallet_handle;
+
+        send(move |cb| Ok(Command::NonSecrets(NonSecretsCommand::DeleteRecord(
+            wallet_handle, type_.to_string(), id.to_string(), cb))))
+    }
+
+    /// Resolves to record json. See `indy_get_wallet_record` for `options_json` and result formats.
+    pub fn get_record(&self, type_: &str, id: &str, options_json: &str) -> IndyFuture<String> {
+        let wallet_handle = self.wallet_handle;
+
+        send(move |cb| Ok(Command::NonSecrets(NonSecretsCommand::GetRecord(
+            wallet_handle, type_.to_string(), id.to_string(), options_json.to_string(), cb))))
+    }
+
+    /// Opens search of the records. See `indy_open_wallet_search` for `query_json` and `options_json` formats.
+    pub fn open_search(&self, type_: &str, query_json: &str, options_json: &str) -> IndyFuture<SearchHandle> {
+        let wallet_handle = self.wallet_handle;
+
+        send(move |cb| Ok(Command::NonSecrets(NonSecretsCommand::OpenSearch(
+            wallet_handle, type_.to_string(), query_json.to_string(), options_json.to_string(), cb))))
+    }
+
+    /// Fetches next `count` records of the search. See `indy_fetch_wallet_search_next_records` for the result format.
+    pub fn fetch_search_next_records(&self, search_handle: SearchHandle, count: usize) -> IndyFuture<String> {
+        let wallet_handle = self.wallet_handle;
+
+        send(move |cb| Ok(Command::NonSecrets(NonSecretsCommand::FetchSearchNextRecords(
+            wallet_handle, search_handle, count, cb))))
+    }
+
+    pub fn close_search(search_handle: SearchHandle) -> IndyFuture<()> {
+        send(move |cb| Ok(Command::NonSecrets(NonSecretsCommand::CloseSearch(search_handle, cb))))
+    }
+}
@@ -0,0 +1,54 @@
+use futures::Future;
+
+use indy_api_types::PoolHandle;
+
+use crate::commands::Command;
+use crate::commands::pool::PoolCommand;
+use crate::domain::pool::{PoolConfig, PoolOpenConfig};
+
+use super::{IndyFuture, parse_opt_json, send, validate};
+
+/// Opened pool ledger. It stays open until `close` is called.
+#[derive(Debug)]
+pub struct Pool {
+    handle: PoolHandle,
+}
+
+impl Pool {
+    /// Creates a new pool ledger configuration. See `indy_create_pool_ledger_config` for `config` format.
+    pub fn create_config(name: &str, config: Option<&str>) -> IndyFuture<()> {
+        send(|cb| {
+            let config: Option<PoolConfig> = parse_opt_json(config, "PoolConfig")?;
+            Ok(Command::Pool(PoolCommand::Create(name.to_string(), config, cb)))
+        })
+    }
+
+    pub fn delete_config(name: &str) -> IndyFuture<()> {
+        send(|cb| Ok(Command::Pool(PoolCommand::Delete(name.to_string(), cb))))
+    }
+
+    /// Opens the pool ledger. See `indy_open_pool_ledger` for `config` format.
+    pub fn open(name: &str, config: Option<&str>) -> IndyFuture<Pool> {
+        let opened = send(|cb| {
+            let config: Option<PoolOpenConfig> = parse_opt_json(config, "PoolOpenConfig")?
+                .map(validate)
+                .transpose()?;
+            Ok(Command::Pool(PoolCommand::Open(name.to_string(), config, cb)))
+        });
+
+        Box::new(opened.map(|handle| Pool { handle }))
+    }
+
+    /// Raw handle of the pool, to mix this API with the C API functions in one application.
+    pub fn handle(&self) -> PoolHandle {
+        self.handle
+    }
+
+    pub fn refresh(&self) -> IndyFuture<()> {
+        send(|cb| Ok(Command::Pool(PoolCommand::Refresh(self.handle, cb))))
+    }
+
+    pub fn close(self) -> IndyFuture<()> {
+        send(|cb| Ok(Command::Pool(PoolCommand::Close(self.handle, cb))))
+    }
+}
@@ -0,0 +1,65 @@
+use futures::Future;
+
+use indy_api_types::WalletHandle;
+use indy_api_types::domain::wallet::{Config, Credentials, ExportConfig};
+
+use crate::commands::Command;
+use crate::commands::wallet::WalletCommand;
+
+use super::{IndyFuture, parse_json, send, validate};
+
+/// Opened wallet. It stays open until `close` is called.
+#[derive(Debug)]
+pub struct Wallet {
+    handle: WalletHandle,
+}
+
+impl Wallet {
+    /// Creates a new wallet. See `indy_create_wallet` for `config` and `credentials` formats.
+    pub fn create(config: &str, credentials: &str) -> IndyFuture<()> {
+        send(|cb| {
+            let config: Config = validate(parse_json(config, "Config")?)?;
+            let credentials: Credentials = parse_json(credentials, "Credentials")?;
+            Ok(Command::Wallet(WalletCommand::Create(config, credentials, cb)))
+        })
+    }
+
+    /// Opens the wallet. See `indy_open_wallet` for `config` and `credentials` formats.
+    pub fn open(config: &str, credentials: &str) -> IndyFuture<Wallet> {
+        let opened = send(|cb| {
+            let config: Config = validate(parse_json(config, "Config")?)?;
+            let credentials: Credentials = parse_json(credentials, "Credentials")?;
+            Ok(Command::Wallet(WalletCommand::Open(config, credentials, cb)))
+        });
+
+        Box::new(opened.map(|handle| Wallet { handle }))
+    }
+
+    /// Deletes the wallet. See `indy_delete_wallet` for `config` and `credentials` formats.
+    pub fn delete(config: &str, credentials: &str) -> IndyFuture<()> {
+        send(|cb| {
+            let config: Config = validate(parse_json(config, "Config")?)?;
+            let credentials: Credentials = parse_json(credentials, "Credentials")?;
+            Ok(Command::Wallet(WalletCommand::Delete(config, credentials, cb)))
+        })
+    }
+
+    /// Raw handle of the wallet, to mix this API with the C API functions in one application.
+    pub fn handle(&self) -> WalletHandle {
+        self.handle
+    }
+
+    /// Exports the wallet. See `indy_export_wallet` for `export_config` format.
+    pub fn export(&self, export_config: &str) -> IndyFuture<()> {
+        let handle = self.handle;
+
+        send(move |cb| {
+            let export_config: ExportConfig = parse_json(export_config, "ExportConfig")?;
+            Ok(Command::Wallet(WalletCommand::Export(handle, export_config, cb)))
+        })
+    }
+
+    pub fn close(self) -> IndyFuture<()> {
+        send(|cb| Ok(Command::Wallet(WalletCommand::Close(self.handle, cb))))
+    }
+}
@@ -1,24 +1,28 @@
 pub mod helpers;
 pub mod issuer;
 pub mod prover;
+pub mod test_mode;
 pub mod verifier;
 
+use std::sync::Arc;
+
 use crate::services::anoncreds::issuer::Issuer;
 use crate::services::anoncreds::prover::Prover;
 use crate::services::anoncreds::verifier::Verifier;
 
+/// Prover and verifier are shared with the anoncreds workers.
 pub struct AnoncredsService {
     pub issuer: Issuer,
-    pub prover: Prover,
-    pub verifier: Verifier
+    pub prover: Arc<Prover>,
+    pub verifier: Arc<Verifier>
 }
 
 impl AnoncredsService {
     pub fn new() -> AnoncredsService {
         AnoncredsService {
             issuer: Issuer::new(),
-            prover: Prover::new(),
-            verifier: Verifier::new()
+            prover: Arc::new(Prover::new()),
+            verifier: Arc::new(Verifier::new())
         }
     }
 }
\ No newline at end of file
@@ -9,14 +9,15 @@ use ursa::cl::{
     MasterSecret,
     SubProofRequest,
 };
+use ursa::bn::BigNumber;
 use ursa::cl::issuer::Issuer as CryptoIssuer;
 use ursa::cl::prover::Prover as CryptoProver;
 use ursa::cl::verifier::Verifier as CryptoVerifier;
 
-use crate::domain::anoncreds::credential::{AttributeValues, Credential};
+use crate::domain::anoncreds::credential::{AttributeValues, Credential, CredentialValues};
 use crate::domain::anoncreds::credential_attr_tag_policy::CredentialAttrTagPolicy;
 use crate::domain::anoncreds::credential_definition::{CredentialDefinitionV1 as CredentialDefinition, CredentialDefinitionId};
-use crate::domain::anoncreds::credential_offer::CredentialOffer;
+use crate::domain::anoncreds::credential_offer::{CredentialOffer, ParsedCredentialOffer};
 use crate::domain::anoncreds::credential_request::CredentialRequestMetadata;
 use crate::domain::anoncreds::proof::{Identifier, Proof, RequestedProof, RevealedAttributeInfo, SubProofReferent, RevealedAttributeGroupInfo, AttributeValue};
 use crate::domain::anoncreds::proof_request::{PredicateInfo, PredicateTypes, ProofRequest, ProofRequestPayload, ProofRequestsVersion, RequestedAttributeInfo, RequestedPredicateInfo, ProofRequestExtraQuery};
@@ -25,10 +26,14 @@ use crate::domain::anoncreds::requested_credential::RequestedCredentials;
 use crate::domain::anoncreds::revocation_registry_definition::RevocationRegistryDefinitionV1;
 use crate::domain::anoncreds::revocation_state::RevocationState;
 use crate::domain::anoncreds::schema::{SchemaV1, SchemaId};
+use crate::domain::anoncreds::w3c_credential::{W3CCredential, W3CCredentialSignatureProofValue};
 use indy_api_types::errors::prelude::*;
 use crate::services::anoncreds::helpers::*;
+use crate::services::anoncreds::test_mode::with_seeded_randomness;
 use crate::utils::wql::Query;
 use crate::services::anoncreds::verifier::Verifier;
+use indy_utils::crypto::base64;
+use indy_utils::crypto::hash::hash;
 
 const ATTRIBUTE_EXISTENCE_MARKER: &str = "1";
 
@@ -64,10 +69,10 @@ impl Prover {
         let cred_values = credential_values_builder.finalize()?;
 
         let (blinded_credential_secrets, credential_secrets_blinding_factors, blinded_credential_secrets_correctness_proof) =
-            CryptoProver::blind_credential_secrets(&credential_pub_key,
-                                                   &credential_offer.key_correctness_proof,
-                                                   &cred_values,
-                                                   &credential_offer.nonce)?;
+            with_seeded_randomness(|| CryptoProver::blind_credential_secrets(&credential_pub_key,
+                                                                             &credential_offer.key_correctness_proof,
+                                                                             &cred_values,
+                                                                             &credential_offer.nonce))?;
 
         trace!("new_credential_request <<< blinded_credential_secrets: {:?}, credential_secrets_blinding_factors: {:?}, blinded_credential_secrets_correctness_proof: {:?}",
                blinded_credential_secrets, credential_secrets_blinding_factors, blinded_credential_secrets_correctness_proof);
@@ -75,6 +80,65 @@ impl Prover {
         Ok((blinded_credential_secrets, credential_secrets_blinding_factors, blinded_credential_secrets_correctness_proof))
     }
 
+    pub fn parse_credential_offer(&self,
+                                  credential_offer: &CredentialOffer,
+                                  cred_def: &CredentialDefinition) -> IndyResult<ParsedCredentialOffer> {
+        trace!("parse_credential_offer >>> credential_offer: {:?}, cred_def: {:?}", credential_offer, cred_def);
+
+        credential_offer.check_with_cred_def(cred_def)?;
+
+        // Key correctness proof is checked by ursa only while blinding credential secrets,
+        // so blind a throwaway master secret against the offer.
+        let credential_pub_key = CredentialPublicKey::build_from_parts(&cred_def.value.primary, cred_def.value.revocation.as_ref())?;
+        let mut credential_values_builder = CryptoIssuer::new_credential_values_builder()?;
+        credential_values_builder.add_value_hidden("master_secret", &CryptoProver::new_master_secret()?.value()?)?;
+        let cred_values = credential_values_builder.finalize()?;
+
+        CryptoProver::blind_credential_secrets(&credential_pub_key,
+                                               &credential_offer.key_correctness_proof,
+                                               &cred_values,
+                                               &credential_offer.nonce)
+            .map_err(|err| IndyError::from(err).extend("Invalid key correctness proof of credential offer"))?;
+
+        let issuer_did = credential_offer.cred_def_id.issuer_did()
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, format!("Invalid credential definition id {:?}", credential_offer.cred_def_id.0)))?;
+
+        let (schema_issuer_did, schema_name, schema_version) = match credential_offer.schema_id.parts() {
+            Some((did, name, version)) => (Some(did), Some(name), Some(version)),
+            None => (None, None, None)
+        };
+
+        let cred_def_tag = credential_offer.cred_def_id.parts()
+            .map(|(_, _, _, tag)| tag)
+            .unwrap_or_default();
+
+        let primary = serde_json::to_value(&cred_def.value.primary)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize credential primary public key")?;
+
+        let mut attr_names: Vec<String> = primary["r"].as_object()
+            .map(|r| r.keys().filter(|name| name.as_str() != "master_secret").cloned().collect())
+            .unwrap_or_default();
+        attr_names.sort();
+
+        let parsed_offer = ParsedCredentialOffer {
+            schema_id: credential_offer.schema_id.clone(),
+            cred_def_id: credential_offer.cred_def_id.clone(),
+            issuer_did,
+            schema_issuer_did,
+            schema_name,
+            schema_version,
+            cred_def_tag,
+            method_name: credential_offer.method_name.clone(),
+            nonce: credential_offer.nonce.to_dec()?,
+            attr_names,
+            revocable: cred_def.value.revocation.is_some(),
+        };
+
+        trace!("parse_credential_offer <<< parsed_offer: {:?}", parsed_offer);
+
+        Ok(parsed_offer)
+    }
+
     pub fn process_credential(&self,
                               credential: &mut Credential,
                               cred_request_metadata: &CredentialRequestMetadata,
@@ -102,6 +166,69 @@ impl Prover {
         Ok(())
     }
 
+    /// Restores credential in Indy format from W3C representation. W3C credential contains only raw
+    /// attribute values, so they are encoded as AnonCreds issuers do: 32-bit integers are encoded
+    /// as themselves, any other value as decimal representation of SHA-256 of its raw value.
+    pub fn convert_w3c_credential(&self, w3c_credential: &W3CCredential) -> IndyResult<Credential> {
+        trace!("convert_w3c_credential >>> w3c_credential: {:?}", w3c_credential);
+
+        let proof = w3c_credential.signature_proof()
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "W3C credential doesn't contain AnonCreds signature proof"))?;
+
+        let proof_value = proof.proof_value.strip_prefix('u')
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "Unsupported multibase encoding of W3C credential proof value"))?;
+
+        let proof_value = base64::decode_urlsafe(proof_value.trim_end_matches('='))?;
+
+        let proof_value: W3CCredentialSignatureProofValue = serde_json::from_slice(&proof_value)
+            .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize W3C credential proof value")?;
+
+        let schema = &w3c_credential.credential_schema;
+
+        if proof_value.schema_id != schema.schema
+            || proof_value.cred_def_id != schema.definition
+            || proof_value.rev_reg_id != schema.revocation_registry {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "W3C credential proof value doesn't match credentialSchema"));
+        }
+
+        let mut values = HashMap::new();
+
+        for (attr, value) in &w3c_credential.credential_subject.attributes {
+            let raw = match value {
+                serde_json::Value::String(value) => value.clone(),
+                serde_json::Value::Number(value) => value.to_string(),
+                _ => return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Unsupported value of W3C credential attribute {:?}", attr)))
+            };
+
+            let encoded = Prover::_encode_attribute_value(&raw)?;
+
+            values.insert(attr.clone(), AttributeValues { raw, encoded });
+        }
+
+        let credential = Credential {
+            schema_id: proof_value.schema_id,
+            cred_def_id: proof_value.cred_def_id,
+            rev_reg_id: proof_value.rev_reg_id,
+            values: CredentialValues(values),
+            signature: proof_value.signature,
+            signature_correctness_proof: proof_value.signature_correctness_proof,
+            rev_reg: proof_value.rev_reg,
+            witness: proof_value.witness,
+        };
+
+        trace!("convert_w3c_credential <<< credential: {:?}", credential);
+
+        Ok(credential)
+    }
+
+    fn _encode_attribute_value(raw: &str) -> IndyResult<String> {
+        if let Ok(value) = raw.parse::<i32>() {
+            return Ok(value.to_string());
+        }
+
+        Ok(BigNumber::from_bytes(&hash(raw.as_bytes())?)?.to_dec()?)
+    }
+
     pub fn create_proof(&self,
                         credentials: &HashMap<String, Credential>,
                         proof_req: &ProofRequest,
@@ -305,9 +432,11 @@ impl Prover {
         credential.values.0
             .iter()
             .for_each(|(attr, values)| {
+                // abstain for attrs policy marks untaggable
                 if catpol.map(|cp| cp.is_taggable(attr.as_str())).unwrap_or(true) {
-                    // abstain for attrs policy marks untaggable
                     res.insert(Self::_build_attr_marker_tag(attr), ATTRIBUTE_EXISTENCE_MARKER.to_string());
+                }
+                if catpol.map(|cp| cp.is_value_taggable(attr.as_str())).unwrap_or(true) {
                     res.insert(Self::_build_attr_value_tag(attr), values.raw.clone());
                 }
             });
@@ -317,6 +446,31 @@ impl Prover {
         Ok(res)
     }
 
+    pub fn validate_custom_credential_tags(&self, tags: &HashMap<String, String>) -> IndyResult<()> {
+        trace!("validate_custom_credential_tags >>> tags: {:?}", tags);
+
+        if let Some(tag_name) = tags.keys().find(|tag_name| !Self::is_custom_credential_tag(tag_name)) {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Custom credential tag {:?} clashes with the tags created by libindy", tag_name)));
+        }
+
+        trace!("validate_custom_credential_tags <<<");
+
+        Ok(())
+    }
+
+    /// Whether the tag isn't one of the tags built by `build_credential_tags`.
+    pub fn is_custom_credential_tag(tag_name: &str) -> bool {
+        let tag_name = tag_name.trim_start_matches('~');
+
+        let is_reserved =
+            ["schema_name", "schema_version"].contains(&tag_name) ||
+                Credential::QUALIFIABLE_TAGS.iter()
+                    .any(|tag| *tag == tag_name || Credential::add_extra_tag_suffix(tag) == tag_name) ||
+                tag_name.starts_with("attr::");
+
+        !is_reserved
+    }
+
     fn _build_attr_marker_tag(attr: &str) -> String {
         format!("attr::{}::marker", attr_common_view(&attr))
     }
@@ -681,6 +835,28 @@ mod tests {
             assert_eq!(expected_tags, tags)
         }
 
+        #[test]
+        fn build_credential_tags_works_for_catpol_marker_only() {
+            let ps = Prover::new();
+            let catpol: CredentialAttrTagPolicy = serde_json::from_str(r#"{"marker_only": ["Age"]}"#).unwrap();
+            let tags = ps.build_credential_tags(&_credential(), Some(catpol).as_ref()).unwrap();
+
+            let expected_tags: HashMap<String, String> = hashmap!(
+                    "schema_id".to_string() => SCHEMA_ID.to_string(),
+                    "schema_issuer_did".to_string() => SCHEMA_ISSUER_DID.to_string(),
+                    "schema_name".to_string() => SCHEMA_NAME.to_string(),
+                    "schema_version".to_string() => SCHEMA_VERSION.to_string(),
+                    "issuer_did".to_string() => ISSUER_DID.to_string(),
+                    "cred_def_id".to_string() => CRED_DEF_ID.to_string(),
+                    "rev_reg_id".to_string() => NO_REV_REG_ID.to_string(),
+                    "attr::name::marker".to_string() => ATTRIBUTE_EXISTENCE_MARKER.to_string(),
+                    "attr::name::value".to_string() => "Alex".to_string(),
+                    "attr::age::marker".to_string() => ATTRIBUTE_EXISTENCE_MARKER.to_string()
+                 );
+
+            assert_eq!(expected_tags, tags)
+        }
+
         #[test]
         fn build_credential_tags_works_for_rev_reg_id() {
             let ps = Prover::new();
@@ -744,6 +920,28 @@ mod tests {
         }
     }
 
+    mod validate_custom_credential_tags {
+        use super::*;
+
+        #[test]
+        fn validate_custom_credential_tags_works() {
+            let ps = Prover::new();
+            let tags = hashmap!("wallet_label".to_string() => "work".to_string(), "~folder".to_string() => "inbox".to_string());
+            ps.validate_custom_credential_tags(&tags).unwrap();
+        }
+
+        #[test]
+        fn validate_custom_credential_tags_works_for_reserved_tags() {
+            let ps = Prover::new();
+
+            for tag_name in &["schema_id", "~issuer_did", "cred_def_id_short", "schema_version", "attr::name::value", "attr::age::marker"] {
+                let tags = hashmap!(tag_name.to_string() => "value".to_string());
+                let res = ps.validate_custom_credential_tags(&tags);
+                assert_kind!(IndyErrorKind::InvalidStructure, res);
+            }
+        }
+    }
+
     mod attribute_satisfy_predicate {
         use super::*;
 
@@ -1252,4 +1450,99 @@ mod tests {
             assert_eq!(expected_query, query);
         }
     }
+
+    mod convert_w3c_credential {
+        use super::*;
+
+        fn _proof_value(cred_def_id: &str) -> String {
+            let proof_value = json!({
+                "schema_id": SCHEMA_ID,
+                "cred_def_id": cred_def_id,
+                "signature": json!({
+                    "p_credential": json!({"m_2": "0","a": "0","e": "0","v": "0"})
+                }),
+                "signature_correctness_proof": json!({"se":"0", "c":"0"})
+            });
+
+            format!("u{}", base64::encode_urlsafe(proof_value.to_string().as_bytes()))
+        }
+
+        fn _w3c_credential(subject: serde_json::Value, proof_value: String) -> W3CCredential {
+            serde_json::from_value(json!({
+                "type": ["VerifiableCredential", "AnonCredsCredential"],
+                "issuer": ISSUER_DID,
+                "credentialSchema": {
+                    "type": "AnonCredsDefinition",
+                    "definition": CRED_DEF_ID,
+                    "schema": SCHEMA_ID
+                },
+                "credentialSubject": subject,
+                "proof": {
+                    "type": "DataIntegrityProof",
+                    "cryptosuite": "anoncreds-2023",
+                    "proofPurpose": "assertionMethod",
+                    "proofValue": proof_value
+                }
+            })).unwrap()
+        }
+
+        #[test]
+        fn convert_w3c_credential_works() {
+            let ps = Prover::new();
+
+            let w3c_credential = _w3c_credential(json!({"id": "did:example:holder", "name": "Alex", "age": 28, "height": "175"}), _proof_value(CRED_DEF_ID));
+
+            let credential = ps.convert_w3c_credential(&w3c_credential).unwrap();
+
+            assert_eq!(SCHEMA_ID, credential.schema_id.0);
+            assert_eq!(CRED_DEF_ID, credential.cred_def_id.0);
+            assert_eq!(None, credential.rev_reg_id);
+
+            let expected_values = hashmap!(
+                "name".to_string() => AttributeValues { raw: "Alex".to_string(), encoded: "99262857098057710338306967609588410025648622308394250666849665532448612202874".to_string() },
+                "age".to_string() => AttributeValues { raw: "28".to_string(), encoded: "28".to_string() },
+                "height".to_string() => AttributeValues { raw: "175".to_string(), encoded: "175".to_string() }
+            );
+
+            assert_eq!(expected_values, credential.values.0);
+        }
+
+        #[test]
+        fn convert_w3c_credential_works_for_not_int32_values() {
+            assert_eq!("102987336249554097029535212322581322789799900648198034993379397001115665086549", Prover::_encode_attribute_value("").unwrap());
+            assert_eq!("26221484005389514539852548961319751347124425277437769688639924217837557266135", Prover::_encode_attribute_value("2147483648").unwrap());
+            assert_eq!("-2147483648", Prover::_encode_attribute_value("-2147483648").unwrap());
+        }
+
+        #[test]
+        fn convert_w3c_credential_works_for_mismatched_proof_value() {
+            let ps = Prover::new();
+
+            let w3c_credential = _w3c_credential(json!({"name": "Alex"}), _proof_value("NcYxiDXkpYi6ov5FcYDi1e:3:CL:NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0:other"));
+
+            let res = ps.convert_w3c_credential(&w3c_credential);
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
+        #[test]
+        fn convert_w3c_credential_works_for_invalid_proof_value() {
+            let ps = Prover::new();
+
+            let res = ps.convert_w3c_credential(&_w3c_credential(json!({"name": "Alex"}), "zinvalid".to_string()));
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+
+            let res = ps.convert_w3c_credential(&_w3c_credential(json!({"name": "Alex"}), "u!!!".to_string()));
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
+        #[test]
+        fn convert_w3c_credential_works_for_unsupported_attribute_value() {
+            let ps = Prover::new();
+
+            let w3c_credential = _w3c_credential(json!({"name": {"first": "Alex"}}), _proof_value(CRED_DEF_ID));
+
+            let res = ps.convert_w3c_credential(&w3c_credential);
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+    }
 }
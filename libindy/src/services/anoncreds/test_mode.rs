@@ -0,0 +1,210 @@
+use std::os::raw::{c_int, c_uchar};
+use std::sync::Mutex;
+
+use sha2::{Digest, Sha256};
+use ursa::cl::Nonce;
+
+use indy_api_types::errors::prelude::*;
+
+const NONCE_BYTES: usize = 10; // 80 bit, the same as ursa random nonces
+
+lazy_static! {
+    static ref TEST_SEED: Mutex<Option<SeededNonces>> = Mutex::new(None);
+    // stream read by OpenSSL while seeded randomness is installed
+    static ref SEEDED_STREAM: Mutex<Option<SeededStream>> = Mutex::new(None);
+    // serializes replacements of OpenSSL random generator
+    static ref SEEDED_RANDOMNESS: Mutex<()> = Mutex::new(());
+}
+
+struct SeededNonces {
+    seed: String,
+    counter: u64,
+    random_counter: u64,
+}
+
+struct SeededStream {
+    seed: String,
+    counter: u64,
+}
+
+// RAND_METHOD of OpenSSL, ursa generates its random big numbers with BN_rand that reads bytes through it
+#[repr(C)]
+struct RandMethod {
+    seed: Option<extern fn(buf: *const c_uchar, num: c_int) -> c_int>,
+    bytes: Option<extern fn(buf: *mut c_uchar, num: c_int) -> c_int>,
+    cleanup: Option<extern fn()>,
+    add: Option<extern fn(buf: *const c_uchar, num: c_int, randomness: f64) -> c_int>,
+    pseudorand: Option<extern fn(buf: *mut c_uchar, num: c_int) -> c_int>,
+    status: Option<extern fn() -> c_int>,
+}
+
+static SEEDED_RAND_METHOD: RandMethod = RandMethod {
+    seed: None,
+    bytes: Some(_seeded_bytes),
+    cleanup: None,
+    add: None,
+    pseudorand: Some(_seeded_bytes),
+    status: Some(_seeded_status),
+};
+
+extern {
+    fn RAND_get_rand_method() -> *const RandMethod;
+    fn RAND_set_rand_method(meth: *const RandMethod) -> c_int;
+}
+
+/// Enables deterministic anoncreds test mode with the given seed, empty seed disables it.
+///
+/// In this mode nonces of credential offers, credential requests and proof requests are derived
+/// from the seed and the number of nonces generated since the seed was set, so the same sequence
+/// of calls produces the same nonces. Randomness of operations wrapped with `with_seeded_randomness`
+/// (blinding factors of credential requests) is derived from the seed the same way.
+/// Must never be used in production.
+pub fn set_seed(seed: String) {
+    let seeded = if seed.is_empty() {
+        None
+    } else {
+        warn!("Anoncreds deterministic test mode is enabled, nonces are predictable");
+        Some(SeededNonces { seed, counter: 0, random_counter: 0 })
+    };
+
+    *TEST_SEED.lock().unwrap() = seeded;
+}
+
+/// Generates a random nonce or the next seeded one if test mode is enabled.
+pub fn new_nonce() -> IndyResult<Nonce> {
+    let mut test_seed = TEST_SEED.lock().unwrap();
+
+    match *test_seed {
+        Some(ref mut seeded) => {
+            let nonce = _seeded_nonce(&seeded.seed, seeded.counter)?;
+            seeded.counter += 1;
+            Ok(nonce)
+        }
+        None => Ok(ursa::cl::new_nonce()?)
+    }
+}
+
+/// Runs `f` with OpenSSL random generator replaced by the one derived from the seed if test mode is enabled.
+///
+/// Ursa takes blinding factors from OpenSSL, so this makes them reproducible. The generator is process wide:
+/// OpenSSL random bytes requested by other threads while `f` runs are taken from the seeded stream too.
+pub fn with_seeded_randomness<T, F: FnOnce() -> T>(f: F) -> T {
+    let _replacement = SEEDED_RANDOMNESS.lock().unwrap();
+
+    let stream = TEST_SEED.lock().unwrap().as_ref()
+        .map(|seeded| SeededStream { seed: seeded.seed.clone(), counter: seeded.random_counter });
+
+    let stream = match stream {
+        Some(stream) => stream,
+        None => return f()
+    };
+
+    let seed = stream.seed.clone();
+    *SEEDED_STREAM.lock().unwrap() = Some(stream);
+
+    let res = {
+        let _installed = SeededRandMethod::install();
+        f()
+    };
+
+    let counter = SEEDED_STREAM.lock().unwrap().take().map(|stream| stream.counter);
+
+    // the seed could be changed while `f` was running, so the counter is stored for the same seed only
+    if let (Some(seeded), Some(counter)) = (TEST_SEED.lock().unwrap().as_mut(), counter) {
+        if seeded.seed == seed {
+            seeded.random_counter = counter;
+        }
+    }
+
+    res
+}
+
+struct SeededRandMethod {
+    previous: *const RandMethod,
+}
+
+impl SeededRandMethod {
+    fn install() -> SeededRandMethod {
+        let previous = unsafe {
+            let previous = RAND_get_rand_method();
+            RAND_set_rand_method(&SEEDED_RAND_METHOD);
+            previous
+        };
+
+        SeededRandMethod { previous }
+    }
+}
+
+impl Drop for SeededRandMethod {
+    fn drop(&mut self) {
+        // restored even if `f` panics, so the seeded generator never outlives the call
+        unsafe { RAND_set_rand_method(self.previous); }
+    }
+}
+
+extern fn _seeded_bytes(buf: *mut c_uchar, num: c_int) -> c_int {
+    if buf.is_null() || num < 0 {
+        return 0;
+    }
+
+    let buf = unsafe { std::slice::from_raw_parts_mut(buf, num as usize) };
+
+    match SEEDED_STREAM.lock().unwrap().as_mut() {
+        Some(stream) => {
+            for chunk in buf.chunks_mut(32) {
+                let block = _seeded_block(&stream.seed, stream.counter);
+                chunk.copy_from_slice(&block[..chunk.len()]);
+                stream.counter += 1;
+            }
+            1
+        }
+        None => 0
+    }
+}
+
+extern fn _seeded_status() -> c_int {
+    1
+}
+
+fn _seeded_block(seed: &str, counter: u64) -> Vec<u8> {
+    let mut hasher = Sha256::new();
+    hasher.update(b"randomness");
+    hasher.update(seed.as_bytes());
+    hasher.update(&counter.to_be_bytes());
+    hasher.finalize().to_vec()
+}
+
+fn _seeded_nonce(seed: &str, counter: u64) -> IndyResult<Nonce> {
+    let mut hasher = Sha256::new();
+    hasher.update(seed.as_bytes());
+    hasher.update(&counter.to_be_bytes());
+    let digest = hasher.finalize();
+
+    let value = digest[..NONCE_BYTES].iter()
+        .fold(0u128, |value, byte| (value << 8) | u128::from(*byte));
+
+    Ok(Nonce::from_dec(&value.to_string())?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seeded_nonce_is_deterministic() {
+        assert_eq!(_seeded_nonce("seed", 0).unwrap(), _seeded_nonce("seed", 0).unwrap());
+    }
+
+    #[test]
+    fn seeded_block_depends_on_seed_and_counter() {
+        assert_eq!(_seeded_block("seed", 0), _seeded_block("seed", 0));
+        assert_ne!(_seeded_block("seed", 0), _seeded_block("seed", 1));
+        assert_ne!(_seeded_block("seed", 0), _seeded_block("other", 0));
+    }
+
+    #[test]
+    fn seeded_nonce_depends_on_seed_and_counter() {
+        assert_ne!(_seeded_nonce("seed", 0).unwrap(), _seeded_nonce("seed", 1).unwrap());
+        assert_ne!(_seeded_nonce("seed", 0).unwrap(), _seeded_nonce("other", 0).unwrap());
+    }
+}
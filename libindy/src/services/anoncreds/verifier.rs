@@ -8,9 +8,10 @@ use crate::domain::anoncreds::revocation_registry_definition::{RevocationRegistr
 use crate::domain::anoncreds::schema::{SchemaV1, SchemaId};
 use indy_api_types::errors::prelude::*;
 use crate::services::anoncreds::helpers::*;
+use crate::services::anoncreds::test_mode::new_nonce;
 
 use ursa::bn::BigNumber;
-use ursa::cl::{CredentialPublicKey, new_nonce, Nonce};
+use ursa::cl::{CredentialPublicKey, Nonce};
 use ursa::cl::verifier::Verifier as CryptoVerifier;
 use crate::utils::wql::Query;
 use regex::Regex;
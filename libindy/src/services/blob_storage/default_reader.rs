@@ -45,7 +45,7 @@ impl ReadableBlob for DefaultReader {
     fn verify(&mut self) -> IndyResult<bool> {
         self.file.seek(SeekFrom::Start(0))?;
         let mut hasher = Hash::new_context()?;
-        let mut buf = [0u8; 1024];
+        let mut buf = vec![0u8; super::buffer_size()];
 
         loop {
             let sz = self.file.read(&mut buf)?;
@@ -1,6 +1,6 @@
 use std::fs;
 use std::fs::File;
-use std::io::Write;
+use std::io::{BufWriter, Write};
 use std::path::PathBuf;
 
 use serde_json;
@@ -16,7 +16,7 @@ use rust_base58::ToBase58;
 pub struct DefaultWriter {
     base_dir: PathBuf,
     uri_pattern: String,
-    file: File,
+    file: BufWriter<File>,
     id: i32,
 }
 
@@ -49,7 +49,7 @@ impl Writer for DefaultWriterConfig {
         Ok(Box::new(DefaultWriter {
             base_dir: path,
             uri_pattern: self.uri_pattern.clone(),
-            file,
+            file: BufWriter::with_capacity(super::buffer_size(), file),
             id,
         }))
     }
@@ -70,7 +70,7 @@ impl WritableBlob for DefaultWriter {
         trace!("finalize >>>");
 
         self.file.flush().map_err(map_err_trace!())?;
-        self.file.sync_all().map_err(map_err_trace!())?;
+        self.file.get_ref().sync_all().map_err(map_err_trace!())?;
 
         let mut path = self.base_dir.clone();
         path.push(hash.to_base58());
@@ -0,0 +1,563 @@
+//! Minimal HTTP/1.1 client used by blob storage backends that keep tails on a server.
+
+use indy_api_types::errors::prelude::*;
+
+use openssl::ssl::{SslConnector, SslMethod};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, ErrorKind, Read, Write};
+use std::net::{Ipv6Addr, TcpStream, ToSocketAddrs};
+use std::rc::Rc;
+use std::time::Duration;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Url {
+    pub tls: bool,
+    pub host: String,
+    pub port: u16,
+    pub path: String,
+}
+
+pub struct Response {
+    pub status: u16,
+    pub headers: HashMap<String, String>,
+    pub body: Box<dyn Read>,
+}
+
+impl Response {
+    #[cfg(any(feature = "s3_blob_storage", test))]
+    pub fn read_body(&mut self) -> IndyResult<Vec<u8>> {
+        let mut body = Vec::new();
+        self.body.read_to_end(&mut body)?;
+        Ok(body)
+    }
+}
+
+impl Url {
+    pub fn parse(url: &str) -> IndyResult<Url> {
+        let (tls, rest) = if let Some(rest) = url.strip_prefix("https://") {
+            (true, rest)
+        } else if let Some(rest) = url.strip_prefix("http://") {
+            (false, rest)
+        } else {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Unsupported URL {}", url)));
+        };
+
+        let (authority, path) = match rest.find('/') {
+            Some(idx) => (&rest[..idx], &rest[idx..]),
+            None => (rest, "/"),
+        };
+
+        // IPv6 address is enclosed in brackets as its colons can't be told apart from port separator otherwise
+        let (host, port) = if let Some(rest) = authority.strip_prefix('[') {
+            let end = rest.find(']')
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, format!("Unclosed IPv6 address in URL {}", url)))?;
+
+            rest[..end].parse::<Ipv6Addr>()
+                .to_indy(IndyErrorKind::InvalidStructure, format!("Invalid IPv6 address in URL {}", url))?;
+
+            (&rest[..end], &rest[end + 1..])
+        } else {
+            match authority.find(':') {
+                Some(idx) => (&authority[..idx], &authority[idx..]),
+                None => (authority, ""),
+            }
+        };
+
+        let port = match port {
+            "" => if tls { 443 } else { 80 },
+            port => port.strip_prefix(':')
+                .and_then(|port| port.parse::<u16>().ok())
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, format!("Invalid port in URL {}", url)))?
+        };
+
+        if host.is_empty() {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Host is missed in URL {}", url)));
+        }
+
+        Ok(Url { tls, host: host.to_string(), port, path: path.to_string() })
+    }
+
+    /// Value of Host header, port is omitted if it is default one for the scheme.
+    pub fn authority(&self) -> String {
+        let host = if self.host.contains(':') { format!("[{}]", self.host) } else { self.host.clone() };
+
+        if self.port == if self.tls { 443 } else { 80 } {
+            host
+        } else {
+            format!("{}:{}", host, self.port)
+        }
+    }
+}
+
+type Stream = BufReader<Box<dyn ReadWrite>>;
+
+/// Persistent connection to the server of URL.
+///
+/// Connection is returned to idle state when body of the response is read to the end,
+/// so the next request reuses it. Otherwise the next request establishes a new connection.
+pub struct Connection {
+    url: Url,
+    timeout: Duration,
+    idle: Rc<RefCell<Option<Stream>>>,
+}
+
+impl Connection {
+    pub fn new(url: &Url, timeout: Duration) -> Connection {
+        Connection { url: url.clone(), timeout, idle: Rc::new(RefCell::new(None)) }
+    }
+
+    /// Sends request for the path of URL on the same server.
+    pub fn request(&self, method: &str, path: &str, headers: &[(String, String)], body: &[u8]) -> IndyResult<Response> {
+        let request = self._request(method, path, headers, body);
+
+        let idle = self.idle.borrow_mut().take();
+
+        if let Some(stream) = idle {
+            // Server may close idle connection at any time, so the request is repeated over a new one
+            if let Some(response) = self._send(stream, &request, body)? {
+                return Ok(response);
+            }
+        }
+
+        self._send(self._connect()?, &request, body)?
+            .ok_or_else(|| err_msg(IndyErrorKind::IOError, format!("{} closed connection without response", self.url.host)))
+    }
+
+    fn _connect(&self) -> IndyResult<Stream> {
+        let mut last_err = None;
+        let mut stream = None;
+
+        for addr in (self.url.host.as_str(), self.url.port).to_socket_addrs()? {
+            match TcpStream::connect_timeout(&addr, self.timeout) {
+                Ok(s) => {
+                    stream = Some(s);
+                    break;
+                }
+                Err(err) => last_err = Some(err),
+            }
+        }
+
+        let stream = match (stream, last_err) {
+            (Some(stream), _) => stream,
+            (None, Some(err)) => return Err(err.into()),
+            (None, None) => return Err(err_msg(IndyErrorKind::IOError, format!("Can't resolve {}", self.url.host))),
+        };
+
+        stream.set_read_timeout(Some(self.timeout))?;
+        stream.set_write_timeout(Some(self.timeout))?;
+
+        let stream: Box<dyn ReadWrite> = if self.url.tls {
+            let connector = SslConnector::builder(SslMethod::tls())
+                .to_indy(IndyErrorKind::IOError, "Can't create TLS connector")?
+                .build();
+            Box::new(connector.connect(&self.url.host, stream)
+                .to_indy(IndyErrorKind::IOError, format!("Can't establish TLS connection to {}", self.url.host))?)
+        } else {
+            Box::new(stream)
+        };
+
+        Ok(BufReader::new(stream))
+    }
+
+    fn _request(&self, method: &str, path: &str, headers: &[(String, String)], body: &[u8]) -> String {
+        let mut request = format!("{} {} HTTP/1.1\r\nHost: {}\r\n", method, path, self.url.authority());
+
+        for (name, value) in headers {
+            request.push_str(&format!("{}: {}\r\n", name, value));
+        }
+
+        if !body.is_empty() || method == "PUT" || method == "POST" {
+            request.push_str(&format!("Content-Length: {}\r\n", body.len()));
+        }
+
+        request.push_str("\r\n");
+        request
+    }
+
+    /// Returns `None` if server closed connection before the response.
+    fn _send(&self, mut stream: Stream, request: &str, body: &[u8]) -> IndyResult<Option<Response>> {
+        let sent = stream.get_mut().write_all(request.as_bytes())
+            .and_then(|_| stream.get_mut().write_all(body))
+            .and_then(|_| stream.get_mut().flush());
+
+        match sent {
+            Err(ref err) if _is_closed(err) => return Ok(None),
+            sent => sent?
+        }
+
+        _parse_response(stream, self.idle.clone())
+    }
+}
+
+fn _is_closed(err: &std::io::Error) -> bool {
+    matches!(err.kind(), ErrorKind::BrokenPipe | ErrorKind::ConnectionReset | ErrorKind::ConnectionAborted | ErrorKind::UnexpectedEof)
+}
+
+trait ReadWrite: Read + Write {}
+
+impl<T: Read + Write> ReadWrite for T {}
+
+fn _parse_response(mut stream: Stream, idle: Rc<RefCell<Option<Stream>>>) -> IndyResult<Option<Response>> {
+    let mut line = String::new();
+
+    match stream.read_line(&mut line) {
+        Ok(0) => return Ok(None),
+        Err(ref err) if _is_closed(err) => return Ok(None),
+        read => read?
+    };
+
+    let mut status_line = line.split_whitespace();
+    let version = status_line.next().unwrap_or_default().to_string();
+    let status = status_line.next()
+        .and_then(|status| status.parse::<u16>().ok())
+        .ok_or_else(|| err_msg(IndyErrorKind::IOError, format!("Invalid HTTP status line {:?}", line.trim_end())))?;
+
+    let mut headers = HashMap::new();
+
+    loop {
+        line.clear();
+
+        if stream.read_line(&mut line)? == 0 || line.trim_end().is_empty() {
+            break;
+        }
+
+        let mut header = line.splitn(2, ':');
+        let name = header.next().unwrap_or_default().trim().to_lowercase();
+        let value = header.next().unwrap_or_default().trim().to_string();
+        headers.insert(name, value);
+    }
+
+    let chunked = headers.get("transfer-encoding").map(|value| value.to_lowercase().contains("chunked")).unwrap_or(false);
+    let content_length = headers.get("content-length").and_then(|value| value.parse::<u64>().ok());
+
+    let framing = match (status, chunked, content_length) {
+        (204, _, _) | (304, _, _) => Framing::Length(0),
+        (_, true, _) => Framing::Chunked { remaining: 0, done: false },
+        (_, false, Some(length)) => Framing::Length(length),
+        (_, false, None) => Framing::Close,
+    };
+
+    let keep_alive = version == "HTTP/1.1"
+        && framing != Framing::Close
+        && !headers.get("connection").map(|value| value.eq_ignore_ascii_case("close")).unwrap_or(false);
+
+    let mut body = Body { stream: Some(stream), framing, keep_alive, idle };
+    body._release_if_complete();
+
+    Ok(Some(Response { status, headers, body: Box::new(body) }))
+}
+
+#[derive(Debug, PartialEq)]
+enum Framing {
+    Length(u64),
+    Chunked { remaining: u64, done: bool },
+    Close,
+}
+
+/// Body of the response that returns connection to idle state once it is read to the end.
+struct Body {
+    stream: Option<Stream>,
+    framing: Framing,
+    keep_alive: bool,
+    idle: Rc<RefCell<Option<Stream>>>,
+}
+
+impl Body {
+    fn _release_if_complete(&mut self) {
+        let complete = match self.framing {
+            Framing::Length(remaining) => remaining == 0,
+            Framing::Chunked { done, .. } => done,
+            Framing::Close => false,
+        };
+
+        if complete {
+            if let Some(stream) = self.stream.take() {
+                if self.keep_alive {
+                    *self.idle.borrow_mut() = Some(stream);
+                }
+            }
+        }
+    }
+}
+
+impl Read for Body {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let stream = match self.stream.as_mut() {
+            Some(stream) if !buf.is_empty() => stream,
+            _ => return Ok(0),
+        };
+
+        let sz = match self.framing {
+            Framing::Length(ref mut remaining) => {
+                let max = std::cmp::min(buf.len() as u64, *remaining) as usize;
+                let sz = stream.read(&mut buf[..max])?;
+
+                if sz == 0 {
+                    return Err(std::io::Error::new(ErrorKind::UnexpectedEof, "HTTP body isn't complete"));
+                }
+
+                *remaining -= sz as u64;
+                sz
+            }
+            Framing::Chunked { ref mut remaining, ref mut done } => _read_chunked(stream, remaining, done, buf)?,
+            Framing::Close => stream.read(buf)?,
+        };
+
+        self._release_if_complete();
+
+        Ok(sz)
+    }
+}
+
+fn _read_chunked(stream: &mut Stream, remaining: &mut u64, done: &mut bool, buf: &mut [u8]) -> std::io::Result<usize> {
+    if *remaining == 0 {
+        let mut line = String::new();
+        stream.read_line(&mut line)?;
+
+        let size = line.trim().split(';').next().unwrap_or_default();
+        *remaining = u64::from_str_radix(size, 16)
+            .map_err(|_| std::io::Error::new(ErrorKind::InvalidData, "Invalid HTTP chunk size"))?;
+
+        if *remaining == 0 {
+            // Trailer section ends with empty line
+            loop {
+                line.clear();
+
+                if stream.read_line(&mut line)? == 0 || line.trim_end().is_empty() {
+                    break;
+                }
+            }
+
+            *done = true;
+            return Ok(0);
+        }
+    }
+
+    let max = std::cmp::min(buf.len() as u64, *remaining) as usize;
+    let sz = stream.read(&mut buf[..max])?;
+
+    if sz == 0 {
+        return Err(std::io::Error::new(ErrorKind::UnexpectedEof, "HTTP chunk isn't complete"));
+    }
+
+    *remaining -= sz as u64;
+
+    if *remaining == 0 {
+        let mut crlf = String::new();
+        stream.read_line(&mut crlf)?;
+    }
+
+    Ok(sz)
+}
+
+/// Tails cache of blob read from server in large blocks.
+///
+/// Reads of tails are small and close to each other, so a block is requested once
+/// instead of request per read that would stall command thread on network round trips.
+pub struct BlockCache {
+    block_size: usize,
+    offset: usize,
+    block: Vec<u8>,
+    // block is the last one of the blob
+    eof: bool,
+}
+
+impl BlockCache {
+    pub fn new(block_size: usize) -> BlockCache {
+        BlockCache { block_size, offset: 0, block: Vec::new(), eof: false }
+    }
+
+    /// Reads bytes from the cache, `fetch` reads `size` bytes at `offset` from the server on miss.
+    pub fn read<F>(&mut self, size: usize, offset: usize, fetch: F) -> IndyResult<Vec<u8>>
+        where F: FnOnce(usize, usize) -> IndyResult<Vec<u8>> {
+        let end = offset + size;
+
+        if offset < self.offset || (end > self.offset + self.block.len() && !self.eof) {
+            let start = offset - offset % self.block_size;
+            let len = std::cmp::max(self.block_size, end - start);
+
+            self.block = fetch(start, len)?;
+            self.offset = start;
+            self.eof = self.block.len() < len;
+        }
+
+        let from = std::cmp::min(offset - self.offset, self.block.len());
+        let to = std::cmp::min(end - self.offset, self.block.len());
+
+        Ok(self.block[from..to].to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::Cursor;
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    fn _parse(response: &[u8]) -> (Response, Rc<RefCell<Option<Stream>>>) {
+        let stream: Box<dyn ReadWrite> = Box::new(Cursor::new(response.to_vec()));
+        let idle = Rc::new(RefCell::new(None));
+        (_parse_response(BufReader::new(stream), idle.clone()).unwrap().unwrap(), idle)
+    }
+
+    #[test]
+    fn url_parse_works() {
+        assert_eq!(Url { tls: true, host: "tails.example.com".to_string(), port: 443, path: "/".to_string() },
+                   Url::parse("https://tails.example.com").unwrap());
+        assert_eq!(Url { tls: false, host: "127.0.0.1".to_string(), port: 8080, path: "/tails/abc".to_string() },
+                   Url::parse("http://127.0.0.1:8080/tails/abc").unwrap());
+        assert_kind!(IndyErrorKind::InvalidStructure, Url::parse("ftp://tails.example.com"));
+        assert_kind!(IndyErrorKind::InvalidStructure, Url::parse("http://:8080/tails"));
+        assert_kind!(IndyErrorKind::InvalidStructure, Url::parse("http://tails.example.com:port/tails"));
+    }
+
+    #[test]
+    fn url_parse_works_for_ipv6() {
+        assert_eq!(Url { tls: false, host: "::1".to_string(), port: 8080, path: "/tails".to_string() },
+                   Url::parse("http://[::1]:8080/tails").unwrap());
+        assert_eq!(Url { tls: true, host: "2001:db8::1".to_string(), port: 443, path: "/".to_string() },
+                   Url::parse("https://[2001:db8::1]").unwrap());
+        assert_kind!(IndyErrorKind::InvalidStructure, Url::parse("http://::1:8080/tails"));
+        assert_kind!(IndyErrorKind::InvalidStructure, Url::parse("http://[::1:8080/tails"));
+        assert_kind!(IndyErrorKind::InvalidStructure, Url::parse("http://[tails.example.com]/tails"));
+    }
+
+    #[test]
+    fn url_authority_works() {
+        assert_eq!("tails.example.com", Url::parse("https://tails.example.com/tails").unwrap().authority());
+        assert_eq!("tails.example.com:8080", Url::parse("http://tails.example.com:8080/tails").unwrap().authority());
+        assert_eq!("[::1]:8080", Url::parse("http://[::1]:8080/tails").unwrap().authority());
+    }
+
+    #[test]
+    fn parse_response_works_for_chunked_body() {
+        let (mut response, idle) = _parse(b"HTTP/1.1 200 OK\r\nETag: \"abc\"\r\nTransfer-Encoding: chunked\r\n\r\n5\r\nHello\r\n6\r\n World\r\n0\r\n\r\n");
+
+        assert_eq!(200, response.status);
+        assert_eq!("\"abc\"", response.headers["etag"]);
+        assert_eq!(b"Hello World".to_vec(), response.read_body().unwrap());
+        assert!(idle.borrow().is_some());
+    }
+
+    #[test]
+    fn parse_response_works_for_content_length() {
+        let (mut response, idle) = _parse(b"HTTP/1.1 206 Partial Content\r\nContent-Length: 5\r\n\r\nHello World");
+
+        assert_eq!(206, response.status);
+        assert!(idle.borrow().is_none());
+        assert_eq!(b"Hello".to_vec(), response.read_body().unwrap());
+        assert!(idle.borrow().is_some());
+    }
+
+    #[test]
+    fn parse_response_works_for_connection_close() {
+        let (mut response, idle) = _parse(b"HTTP/1.1 200 OK\r\nConnection: close\r\nContent-Length: 5\r\n\r\nHello");
+
+        assert_eq!(b"Hello".to_vec(), response.read_body().unwrap());
+        assert!(idle.borrow().is_none());
+    }
+
+    // Serves requests over each accepted connection until client closes it, returns number of connections
+    fn _serve(response: &'static [u8]) -> (Url, Arc<Mutex<usize>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = Url::parse(&format!("http://{}/tails", listener.local_addr().unwrap())).unwrap();
+        let connections = Arc::new(Mutex::new(0));
+        let server_connections = connections.clone();
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                *server_connections.lock().unwrap() += 1;
+
+                loop {
+                    let mut line = String::new();
+                    if reader.read_line(&mut line).unwrap_or(0) == 0 {
+                        break;
+                    }
+                    if line.trim_end().is_empty() {
+                        stream.write_all(response).unwrap();
+                    }
+                }
+            }
+        });
+
+        (url, connections)
+    }
+
+    #[test]
+    fn connection_request_works_for_keep_alive() {
+        let (url, connections) = _serve(b"HTTP/1.1 200 OK\r\nContent-Length: 5\r\n\r\nHello");
+        let connection = Connection::new(&url, Duration::from_secs(5));
+
+        for _ in 0..3 {
+            let mut response = connection.request("GET", &url.path, &[], &[]).unwrap();
+            assert_eq!(b"Hello".to_vec(), response.read_body().unwrap());
+        }
+
+        assert_eq!(1, *connections.lock().unwrap());
+    }
+
+    #[test]
+    fn connection_request_works_for_unread_body() {
+        let (url, connections) = _serve(b"HTTP/1.1 200 OK\r\nContent-Length: 5\r\n\r\nHello");
+        let connection = Connection::new(&url, Duration::from_secs(5));
+
+        connection.request("GET", &url.path, &[], &[]).unwrap();
+        let mut response = connection.request("GET", &url.path, &[], &[]).unwrap();
+
+        assert_eq!(b"Hello".to_vec(), response.read_body().unwrap());
+        assert_eq!(2, *connections.lock().unwrap());
+    }
+
+    #[test]
+    fn connection_request_works_for_connection_closed_by_server() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = Url::parse(&format!("http://{}/tails", listener.local_addr().unwrap())).unwrap();
+
+        // Server closes connection after each response without notice
+        thread::spawn(move || {
+            for stream in listener.incoming().take(2) {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut line = String::new();
+                while reader.read_line(&mut line).unwrap() > 2 {
+                    line.clear();
+                }
+                stream.write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 5\r\n\r\nHello").unwrap();
+            }
+        });
+
+        let connection = Connection::new(&url, Duration::from_secs(5));
+
+        for _ in 0..2 {
+            let mut response = connection.request("GET", &url.path, &[], &[]).unwrap();
+            assert_eq!(b"Hello".to_vec(), response.read_body().unwrap());
+        }
+    }
+
+    #[test]
+    fn block_cache_read_works() {
+        let content: Vec<u8> = (0..20).collect();
+        let fetches = RefCell::new(Vec::new());
+
+        let fetch = |offset: usize, size: usize| {
+            fetches.borrow_mut().push((offset, size));
+            Ok(content.iter().skip(offset).take(size).cloned().collect())
+        };
+
+        let mut cache = BlockCache::new(8);
+
+        assert_eq!(vec![2, 3, 4, 5], cache.read(4, 2, fetch).unwrap());
+        assert_eq!(vec![4, 5, 6, 7], cache.read(4, 4, fetch).unwrap());
+        assert_eq!(vec![6, 7, 8, 9], cache.read(4, 6, fetch).unwrap());
+        assert_eq!(vec![18, 19], cache.read(4, 18, fetch).unwrap());
+        assert!(cache.read(4, 20, fetch).unwrap().is_empty());
+
+        assert_eq!(vec![(0, 8), (0, 10), (16, 8)], *fetches.borrow());
+    }
+}
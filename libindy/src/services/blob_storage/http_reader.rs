@@ -0,0 +1,283 @@
+use rust_base58::ToBase58;
+
+use super::{ReadableBlob, Reader, ReaderType};
+use super::http::{BlockCache, Connection, Response, Url};
+use indy_api_types::errors::prelude::*;
+
+use serde_json;
+use std::io::Read;
+use std::time::Duration;
+use indy_utils::crypto::hash::Hash;
+
+const DEFAULT_TIMEOUT: u64 = 30;
+const DEFAULT_BLOCK_SIZE: usize = 1024 * 1024;
+
+/// Reads tails served over HTTP(S) with range requests, so tails don't have to be downloaded before use.
+pub struct HttpReader {
+    url: Url,
+    connection: Connection,
+    cache: BlockCache,
+    hash: Vec<u8>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct HttpReaderConfig {
+    base_url: Option<String>,
+    timeout: Option<u64>,
+    block_size: Option<usize>,
+}
+
+impl ReaderType for HttpReaderType {
+    fn open(&self, config: &str) -> IndyResult<Box<dyn Reader>> {
+        let config: HttpReaderConfig = serde_json::from_str(config)
+            .to_indy(IndyErrorKind::InvalidStructure, "Can't deserialize HttpReaderConfig")?;
+
+        if let Some(ref base_url) = config.base_url {
+            Url::parse(base_url)?;
+        }
+
+        if config.block_size == Some(0) {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "block_size must be positive"));
+        }
+
+        Ok(Box::new(config))
+    }
+}
+
+impl Reader for HttpReaderConfig {
+    fn open(&self, hash: &[u8], location: &str) -> IndyResult<Box<dyn ReadableBlob>> {
+        // Tails location of revocation registry definition is used if it is URL, otherwise file is looked up by its hash
+        let url = if location.starts_with("http://") || location.starts_with("https://") {
+            location.to_string()
+        } else {
+            let base_url = self.base_url.as_ref()
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, format!("Tails location {} isn't URL and base_url isn't set", location)))?;
+            format!("{}/{}", base_url.trim_end_matches('/'), hash.to_base58())
+        };
+
+        let url = Url::parse(&url)?;
+        let connection = Connection::new(&url, Duration::from_secs(self.timeout.unwrap_or(DEFAULT_TIMEOUT)));
+
+        Ok(Box::new(HttpReader {
+            url,
+            connection,
+            cache: BlockCache::new(self.block_size.unwrap_or(DEFAULT_BLOCK_SIZE)),
+            hash: hash.to_owned(),
+        }))
+    }
+}
+
+impl ReadableBlob for HttpReader {
+    fn verify(&mut self) -> IndyResult<bool> {
+        let mut response = HttpReader::_get(&self.url, &self.connection, None)?;
+
+        if response.status != 200 {
+            return Err(err_msg(IndyErrorKind::IOError, format!("Tails server responded with status {}", response.status)));
+        }
+
+        let mut hasher = Hash::new_context()?;
+        let mut buf = vec![0u8; super::buffer_size()];
+
+        loop {
+            let sz = response.body.read(&mut buf)?;
+
+            if sz == 0 {
+                return Ok(hasher.finish()?.to_vec().eq(&self.hash));
+            }
+
+            hasher.update(&buf[0..sz])?;
+        }
+    }
+
+    fn close(&self) -> IndyResult<()> {
+        /* nothing to do */
+        Ok(())
+    }
+
+    fn read(&mut self, size: usize, offset: usize) -> IndyResult<Vec<u8>> {
+        if size == 0 {
+            return Ok(Vec::new());
+        }
+
+        let (url, connection) = (&self.url, &self.connection);
+        self.cache.read(size, offset, |offset, size| HttpReader::_read(url, connection, size, offset))
+    }
+}
+
+impl HttpReader {
+    fn _read(url: &Url, connection: &Connection, size: usize, offset: usize) -> IndyResult<Vec<u8>> {
+        let mut response = HttpReader::_get(url, connection, Some((offset, size)))?;
+
+        let mut buf = Vec::with_capacity(size);
+
+        match response.status {
+            206 => {
+                response.body.read_to_end(&mut buf)?;
+                buf.truncate(size);
+            }
+            // Server ignored range so the part is cut out of the whole content
+            200 => {
+                std::io::copy(&mut response.body.by_ref().take(offset as u64), &mut std::io::sink())?;
+                response.body.take(size as u64).read_to_end(&mut buf)?;
+            }
+            // Offset is beyond the end as for files
+            416 => {}
+            status => return Err(err_msg(IndyErrorKind::IOError, format!("Tails server responded with status {}", status)))
+        }
+
+        Ok(buf)
+    }
+
+    fn _get(url: &Url, connection: &Connection, range: Option<(usize, usize)>) -> IndyResult<Response> {
+        let headers = match range {
+            Some((offset, size)) => vec![("Range".to_string(), format!("bytes={}-{}", offset, offset + size - 1))],
+            None => vec![],
+        };
+
+        connection.request("GET", &url.path, &headers, &[])
+    }
+}
+
+pub struct HttpReaderType {}
+
+impl HttpReaderType {
+    pub fn new() -> Self {
+        HttpReaderType {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    const CONTENT: &[u8] = b"0123456789abcdefghij";
+
+    // Serves CONTENT honoring range header over persistent connections, returns number of requests
+    fn _serve() -> (String, Arc<Mutex<Vec<Option<(usize, usize)>>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let requests: Arc<Mutex<Vec<Option<(usize, usize)>>>> = Default::default();
+        let server_requests = requests.clone();
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let requests = server_requests.clone();
+
+                thread::spawn(move || {
+                    let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                    loop {
+                        let mut range = None;
+
+                        loop {
+                            let mut line = String::new();
+                            if reader.read_line(&mut line).unwrap_or(0) == 0 {
+                                return;
+                            }
+                            if line.trim_end().is_empty() {
+                                break;
+                            }
+                            if let Some(value) = line.strip_prefix("Range: bytes=") {
+                                let bounds: Vec<usize> = value.trim_end().split('-').map(|b| b.parse().unwrap()).collect();
+                                range = Some((bounds[0], bounds[1]));
+                            }
+                        }
+
+                        requests.lock().unwrap().push(range);
+
+                        match range {
+                            Some((start, _)) if start >= CONTENT.len() => {
+                                stream.write_all(b"HTTP/1.1 416 Range Not Satisfiable\r\nContent-Length: 0\r\n\r\n").unwrap();
+                            }
+                            Some((start, end)) => {
+                                let part = &CONTENT[start..std::cmp::min(end + 1, CONTENT.len())];
+                                stream.write_all(format!("HTTP/1.1 206 Partial Content\r\nContent-Length: {}\r\n\r\n", part.len()).as_bytes()).unwrap();
+                                stream.write_all(part).unwrap();
+                            }
+                            None => {
+                                stream.write_all(b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n").unwrap();
+                                for chunk in CONTENT.chunks(7) {
+                                    stream.write_all(format!("{:x}\r\n", chunk.len()).as_bytes()).unwrap();
+                                    stream.write_all(chunk).unwrap();
+                                    stream.write_all(b"\r\n").unwrap();
+                                }
+                                stream.write_all(b"0\r\n\r\n").unwrap();
+                            }
+                        }
+                    }
+                });
+            }
+        });
+
+        (format!("http://{}/tails", addr), requests)
+    }
+
+    fn _hash(content: &[u8]) -> Vec<u8> {
+        let mut hasher = Hash::new_context().unwrap();
+        hasher.update(content).unwrap();
+        hasher.finish().unwrap().to_vec()
+    }
+
+    fn _open(config: serde_json::Value, hash: &[u8]) -> Box<dyn ReadableBlob> {
+        HttpReaderType::new()
+            .open(&config.to_string()).unwrap()
+            .open(hash, "").unwrap()
+    }
+
+    #[test]
+    fn http_reader_read_works() {
+        let (base_url, requests) = _serve();
+        let mut blob = _open(json!({"base_url": base_url}), &_hash(CONTENT));
+
+        assert_eq!(b"2345".to_vec(), blob.read(4, 2).unwrap());
+        assert_eq!(b"ij".to_vec(), blob.read(4, 18).unwrap());
+        assert!(blob.read(4, 20).unwrap().is_empty());
+
+        // The whole tails is smaller than block
+        assert_eq!(vec![Some((0, 1024 * 1024 - 1))], *requests.lock().unwrap());
+    }
+
+    #[test]
+    fn http_reader_read_works_for_block_size() {
+        let (base_url, requests) = _serve();
+        let mut blob = _open(json!({"base_url": base_url, "block_size": 8}), &_hash(CONTENT));
+
+        assert_eq!(b"2345".to_vec(), blob.read(4, 2).unwrap());
+        assert_eq!(b"4567".to_vec(), blob.read(4, 4).unwrap());
+        assert_eq!(b"ij".to_vec(), blob.read(4, 18).unwrap());
+        assert!(blob.read(4, 20).unwrap().is_empty());
+
+        assert_eq!(vec![Some((0, 7)), Some((16, 23))], *requests.lock().unwrap());
+    }
+
+    #[test]
+    fn http_reader_open_works_for_zero_block_size() {
+        assert_kind!(IndyErrorKind::InvalidStructure, HttpReaderType::new().open(r#"{"block_size": 0}"#));
+    }
+
+    #[test]
+    fn http_reader_verify_works() {
+        let (base_url, _) = _serve();
+
+        let mut blob = _open(json!({"base_url": base_url}), &_hash(CONTENT));
+        assert!(blob.verify().unwrap());
+        assert_eq!(b"2345".to_vec(), blob.read(4, 2).unwrap());
+
+        let mut blob = _open(json!({"base_url": base_url}), &_hash(b"other content"));
+        assert!(!blob.verify().unwrap());
+    }
+
+    #[test]
+    fn http_reader_open_works_for_location_without_base_url() {
+        let reader = HttpReaderType::new().open("{}").unwrap();
+
+        assert_kind!(IndyErrorKind::InvalidStructure, reader.open(&_hash(CONTENT), "tails_dir/file"));
+        assert!(reader.open(&_hash(CONTENT), "https://tails.example.com/file").is_ok());
+    }
+}
@@ -0,0 +1,150 @@
+use rust_base58::ToBase58;
+
+use super::{ReadableBlob, Reader, ReaderType, WritableBlob, Writer, WriterType};
+use indy_api_types::errors::prelude::*;
+
+use serde_json;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use indy_utils::crypto::hash::Hash;
+
+lazy_static! {
+    // Blobs are shared by all writers and readers of the process and indexed by hash of their content
+    static ref BLOBS: Mutex<HashMap<Vec<u8>, Arc<Vec<u8>>>> = Default::default();
+}
+
+#[derive(Serialize, Deserialize)]
+struct MemoryConfig {}
+
+/// Keeps tails in process memory, so they are lost when the process is finished.
+pub struct MemoryWriter {
+    content: Vec<u8>,
+}
+
+pub struct MemoryReader {
+    content: Arc<Vec<u8>>,
+    hash: Vec<u8>,
+}
+
+impl WriterType for MemoryWriterType {
+    fn open(&self, config: &str) -> IndyResult<Box<dyn Writer>> {
+        let config: MemoryConfig = serde_json::from_str(config)
+            .to_indy(IndyErrorKind::InvalidStructure, "Can't deserialize MemoryConfig")?;
+
+        Ok(Box::new(config))
+    }
+}
+
+impl Writer for MemoryConfig {
+    fn create(&self, _id: i32) -> IndyResult<Box<dyn WritableBlob>> {
+        Ok(Box::new(MemoryWriter { content: Vec::new() }))
+    }
+}
+
+impl WritableBlob for MemoryWriter {
+    fn append(&mut self, bytes: &[u8]) -> IndyResult<usize> {
+        self.content.extend_from_slice(bytes);
+        Ok(bytes.len())
+    }
+
+    fn finalize(&mut self, hash: &[u8]) -> IndyResult<String> {
+        let content = std::mem::take(&mut self.content);
+
+        BLOBS.lock()
+            .map_err(|_| err_msg(IndyErrorKind::InvalidState, "Memory blob storage is poisoned"))?
+            .insert(hash.to_vec(), Arc::new(content));
+
+        Ok(format!("memory://{}", hash.to_base58()))
+    }
+}
+
+impl ReaderType for MemoryReaderType {
+    fn open(&self, config: &str) -> IndyResult<Box<dyn Reader>> {
+        let config: MemoryConfig = serde_json::from_str(config)
+            .to_indy(IndyErrorKind::InvalidStructure, "Can't deserialize MemoryConfig")?;
+
+        Ok(Box::new(config))
+    }
+}
+
+impl Reader for MemoryConfig {
+    fn open(&self, hash: &[u8], _location: &str) -> IndyResult<Box<dyn ReadableBlob>> {
+        let content = BLOBS.lock()
+            .map_err(|_| err_msg(IndyErrorKind::InvalidState, "Memory blob storage is poisoned"))?
+            .get(hash).cloned()
+            .ok_or_else(|| err_msg(IndyErrorKind::IOError, format!("Blob {} isn't found in memory", hash.to_base58())))?;
+
+        Ok(Box::new(MemoryReader { content, hash: hash.to_owned() }))
+    }
+}
+
+impl ReadableBlob for MemoryReader {
+    fn read(&mut self, size: usize, offset: usize) -> IndyResult<Vec<u8>> {
+        let start = std::cmp::min(offset, self.content.len());
+        let end = std::cmp::min(start.saturating_add(size), self.content.len());
+
+        Ok(self.content[start..end].to_vec())
+    }
+
+    fn verify(&mut self) -> IndyResult<bool> {
+        let mut hasher = Hash::new_context()?;
+        hasher.update(&self.content)?;
+        Ok(hasher.finish()?.to_vec().eq(&self.hash))
+    }
+
+    fn close(&self) -> IndyResult<()> {
+        /* nothing to do */
+        Ok(())
+    }
+}
+
+pub struct MemoryWriterType {}
+
+impl MemoryWriterType {
+    pub fn new() -> Self {
+        MemoryWriterType {}
+    }
+}
+
+pub struct MemoryReaderType {}
+
+impl MemoryReaderType {
+    pub fn new() -> Self {
+        MemoryReaderType {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn _hash(content: &[u8]) -> Vec<u8> {
+        let mut hasher = Hash::new_context().unwrap();
+        hasher.update(content).unwrap();
+        hasher.finish().unwrap().to_vec()
+    }
+
+    #[test]
+    fn memory_blob_storage_works() {
+        let content = b"memory_blob_storage_works";
+        let hash = _hash(content);
+
+        let mut blob = MemoryWriterType::new().open("{}").unwrap().create(1).unwrap();
+        blob.append(&content[..6]).unwrap();
+        blob.append(&content[6..]).unwrap();
+        assert_eq!(format!("memory://{}", hash.to_base58()), blob.finalize(&hash).unwrap());
+
+        let mut blob = MemoryReaderType::new().open("{}").unwrap().open(&hash, "").unwrap();
+        assert_eq!(b"blob".to_vec(), blob.read(4, 7).unwrap());
+        assert_eq!(b"works".to_vec(), blob.read(10, 20).unwrap());
+        assert!(blob.read(4, 100).unwrap().is_empty());
+        assert!(blob.verify().unwrap());
+    }
+
+    #[test]
+    fn memory_reader_open_works_for_unknown_blob() {
+        let reader = MemoryReaderType::new().open("{}").unwrap();
+
+        assert_kind!(IndyErrorKind::IOError, reader.open(&_hash(b"memory_reader_open_works_for_unknown_blob"), ""));
+    }
+}
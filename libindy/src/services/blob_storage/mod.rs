@@ -1,5 +1,6 @@
 use std::cell::RefCell;
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 use indy_api_types::errors::prelude::*;
 use indy_utils::sequence;
@@ -9,6 +10,31 @@ use sha2::digest::{FixedOutput, Update};
 
 mod default_writer;
 mod default_reader;
+#[cfg(any(feature = "http_blob_storage", feature = "s3_blob_storage"))]
+mod http;
+#[cfg(feature = "http_blob_storage")]
+mod http_reader;
+mod memory;
+mod plugged;
+#[cfg(feature = "s3_blob_storage")]
+mod s3;
+#[cfg(feature = "s3_blob_storage")]
+mod s3_reader;
+#[cfg(feature = "s3_blob_storage")]
+mod s3_writer;
+
+pub use self::plugged::{PluggedReaderType, PluggedWriterType};
+
+static BUFFER_SIZE: AtomicUsize = AtomicUsize::new(8192);
+
+/// Sets size of buffer used by default blob storage to write and verify tails files opened afterwards.
+pub fn set_buffer_size(size: usize) {
+    BUFFER_SIZE.store(size, Ordering::SeqCst);
+}
+
+fn buffer_size() -> usize {
+    BUFFER_SIZE.load(Ordering::SeqCst)
+}
 
 trait WriterType {
     fn open(&self, config: &str) -> IndyResult<Box<dyn Writer>>;
@@ -16,6 +42,10 @@ trait WriterType {
 
 trait Writer {
     fn create(&self, id: i32) -> IndyResult<Box<dyn WritableBlob>>;
+
+    fn close(&self) -> IndyResult<()> {
+        Ok(())
+    }
 }
 
 trait WritableBlob {
@@ -29,6 +59,10 @@ trait ReaderType {
 
 trait Reader {
     fn open(&self, hash: &[u8], location: &str) -> IndyResult<Box<dyn ReadableBlob>>;
+
+    fn close(&self) -> IndyResult<()> {
+        Ok(())
+    }
 }
 
 trait ReadableBlob {
@@ -51,8 +85,16 @@ impl BlobStorageService {
     pub fn new() -> BlobStorageService {
         let mut writer_types: HashMap<String, Box<dyn WriterType>> = HashMap::new();
         writer_types.insert("default".to_owned(), Box::new(default_writer::DefaultWriterType::new()));
+        writer_types.insert("memory".to_owned(), Box::new(memory::MemoryWriterType::new()));
+        #[cfg(feature = "s3_blob_storage")]
+        writer_types.insert("s3".to_owned(), Box::new(s3_writer::S3WriterType::new()));
         let mut reader_types: HashMap<String, Box<dyn ReaderType>> = HashMap::new();
         reader_types.insert("default".to_owned(), Box::new(default_reader::DefaultReaderType::new()));
+        #[cfg(feature = "http_blob_storage")]
+        reader_types.insert("http".to_owned(), Box::new(http_reader::HttpReaderType::new()));
+        reader_types.insert("memory".to_owned(), Box::new(memory::MemoryReaderType::new()));
+        #[cfg(feature = "s3_blob_storage")]
+        reader_types.insert("s3".to_owned(), Box::new(s3_reader::S3ReaderType::new()));
 
         BlobStorageService {
             writer_types: RefCell::new(writer_types),
@@ -68,6 +110,17 @@ impl BlobStorageService {
 
 /* Writer */
 impl BlobStorageService {
+    pub fn register_writer_type(&self, type_: &str, writer_type: PluggedWriterType) -> IndyResult<()> {
+        let mut writer_types = self.writer_types.try_borrow_mut()?;
+
+        if writer_types.contains_key(type_) {
+            return Err(err_msg(IndyErrorKind::InvalidState, format!("BlobStorage Writer is already registered for type: {}", type_)));
+        }
+
+        writer_types.insert(type_.to_string(), Box::new(writer_type));
+        Ok(())
+    }
+
     pub fn open_writer(&self, type_: &str, config: &str) -> IndyResult<i32> {
         let writer_config = self.writer_types.try_borrow()?
             .get(type_).ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "Unknown BlobStorage Writer type"))?
@@ -79,6 +132,12 @@ impl BlobStorageService {
         Ok(config_handle)
     }
 
+    pub fn close_writer(&self, config_handle: i32) -> IndyResult<()> {
+        self.writer_configs.try_borrow_mut()?
+            .remove(&config_handle).ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "Invalid BlobStorage config handle"))?
+            .close()
+    }
+
     pub fn create_blob(&self, config_handle: i32) -> IndyResult<i32> {
         let blob_handle = sequence::get_next_id();
         let writer = self.writer_configs.try_borrow()?
@@ -113,6 +172,17 @@ impl BlobStorageService {
 
 /* Reader */
 impl BlobStorageService {
+    pub fn register_reader_type(&self, type_: &str, reader_type: PluggedReaderType) -> IndyResult<()> {
+        let mut reader_types = self.reader_types.try_borrow_mut()?;
+
+        if reader_types.contains_key(type_) {
+            return Err(err_msg(IndyErrorKind::InvalidState, format!("BlobStorage Reader is already registered for type: {}", type_)));
+        }
+
+        reader_types.insert(type_.to_string(), Box::new(reader_type));
+        Ok(())
+    }
+
     pub fn open_reader(&self, type_: &str, config: &str) -> IndyResult<i32> {
         let reader_config = self.reader_types.try_borrow()?
             .get(type_).ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "Invalid BlobStorage Reader type"))? // FIXME: Review error kind
@@ -124,6 +194,12 @@ impl BlobStorageService {
         Ok(config_handle)
     }
 
+    pub fn close_reader(&self, config_handle: i32) -> IndyResult<()> {
+        self.reader_configs.try_borrow_mut()?
+            .remove(&config_handle).ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "Invalid BlobStorage config handle"))?
+            .close()
+    }
+
     pub fn open_blob(&self, config_handle: i32, location: &str, hash: &[u8]) -> IndyResult<i32> {
         let reader = self.reader_configs.try_borrow()?
             .get(&config_handle).ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "Invalid BlobStorage config handle"))? // FIXME: Review error kind
@@ -141,6 +217,38 @@ impl BlobStorageService {
             .read(size, offset)
     }
 
+    /// Reads the whole blob and checks it against the hash, so tails can be passed to the application at once.
+    pub fn read_blob(&self, config_handle: i32, location: &str, hash: &[u8]) -> IndyResult<Vec<u8>> {
+        let handle = self.open_blob(config_handle, location, hash)?;
+
+        let res = self._read_all(handle);
+        self.close(handle)?;
+        let bytes = res?;
+
+        let mut hasher = Sha256::default();
+        hasher.update(&bytes);
+
+        if hasher.finalize_fixed().as_slice() != hash {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "Blob content doesn't match the hash"));
+        }
+
+        Ok(bytes)
+    }
+
+    fn _read_all(&self, handle: i32) -> IndyResult<Vec<u8>> {
+        let mut bytes = Vec::new();
+
+        loop {
+            let chunk = self.read(handle, buffer_size(), bytes.len())?;
+
+            if chunk.is_empty() {
+                return Ok(bytes);
+            }
+
+            bytes.extend_from_slice(&chunk);
+        }
+    }
+
     pub fn _verify(&self, handle: i32) -> IndyResult<bool> {
         self.reader_blobs.try_borrow_mut()?
             .get_mut(&handle).ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "Invalid BlobStorage handle"))? // FIXME: Review error kind
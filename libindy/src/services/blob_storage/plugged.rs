@@ -0,0 +1,376 @@
+use std::convert::TryFrom;
+use std::ffi::{CStr, CString};
+use std::ptr;
+
+use libc::c_char;
+
+use super::{ReadableBlob, Reader, ReaderType, WritableBlob, Writer, WriterType};
+use indy_api_types::{ErrorCode, IndyHandle};
+use indy_api_types::blob_storage::*;
+use indy_api_types::errors::prelude::*;
+use indy_utils::crypto::hash::Hash;
+
+/// Reader type implemented by application and registered with indy_register_blob_storage_reader.
+#[derive(Clone, Copy)]
+pub struct PluggedReaderType {
+    open_reader_handler: BlobStorageOpenReader,
+    open_blob_handler: BlobStorageOpenBlob,
+    read_handler: BlobStorageRead,
+    close_blob_handler: BlobStorageCloseBlob,
+    close_reader_handler: Option<BlobStorageCloseReader>,
+}
+
+impl PluggedReaderType {
+    pub fn new(open_reader_handler: BlobStorageOpenReader,
+               open_blob_handler: BlobStorageOpenBlob,
+               read_handler: BlobStorageRead,
+               close_blob_handler: BlobStorageCloseBlob,
+               close_reader_handler: Option<BlobStorageCloseReader>) -> Self {
+        PluggedReaderType {
+            open_reader_handler,
+            open_blob_handler,
+            read_handler,
+            close_blob_handler,
+            close_reader_handler,
+        }
+    }
+}
+
+struct PluggedReader {
+    handle: IndyHandle,
+    reader_type: PluggedReaderType,
+}
+
+struct PluggedReadableBlob {
+    handle: IndyHandle,
+    hash: Vec<u8>,
+    reader_type: PluggedReaderType,
+}
+
+impl ReaderType for PluggedReaderType {
+    fn open(&self, config: &str) -> IndyResult<Box<dyn Reader>> {
+        let config = CString::new(config)
+            .to_indy(IndyErrorKind::InvalidStructure, "Invalid BlobStorage Reader config")?;
+
+        let mut handle: IndyHandle = -1;
+        let err = (self.open_reader_handler)(config.as_ptr(), &mut handle);
+        _check(err)?;
+
+        Ok(Box::new(PluggedReader { handle, reader_type: *self }))
+    }
+}
+
+impl Reader for PluggedReader {
+    fn open(&self, hash: &[u8], location: &str) -> IndyResult<Box<dyn ReadableBlob>> {
+        let location = CString::new(location)
+            .to_indy(IndyErrorKind::InvalidStructure, "Invalid BlobStorage location")?;
+
+        let mut handle: IndyHandle = -1;
+        let err = (self.reader_type.open_blob_handler)(self.handle,
+                                                       hash.as_ptr(),
+                                                       _len(hash)?,
+                                                       location.as_ptr(),
+                                                       &mut handle);
+        _check(err)?;
+
+        Ok(Box::new(PluggedReadableBlob { handle, hash: hash.to_owned(), reader_type: self.reader_type }))
+    }
+
+    fn close(&self) -> IndyResult<()> {
+        match self.reader_type.close_reader_handler {
+            Some(close_reader_handler) => _check(close_reader_handler(self.handle)),
+            None => Ok(())
+        }
+    }
+}
+
+impl ReadableBlob for PluggedReadableBlob {
+    fn read(&mut self, size: usize, offset: usize) -> IndyResult<Vec<u8>> {
+        let size_u32 = u32::try_from(size)
+            .to_indy(IndyErrorKind::InvalidStructure, format!("BlobStorage read size is too big: {}", size))?;
+
+        let mut buf = vec![0u8; size];
+        let mut read_len: u32 = 0;
+
+        let err = (self.reader_type.read_handler)(self.handle,
+                                                  offset as u64,
+                                                  size_u32,
+                                                  buf.as_mut_ptr(),
+                                                  &mut read_len);
+        _check(err)?;
+
+        if read_len > size_u32 {
+            return Err(err_msg(IndyErrorKind::InvalidState, "BlobStorage Reader read more bytes than requested"));
+        }
+
+        buf.truncate(read_len as usize);
+        Ok(buf)
+    }
+
+    fn verify(&mut self) -> IndyResult<bool> {
+        let mut hasher = Hash::new_context()?;
+        let buffer_size = super::buffer_size();
+        let mut offset = 0;
+
+        loop {
+            let buf = self.read(buffer_size, offset)?;
+
+            if buf.is_empty() {
+                return Ok(hasher.finish()?.to_vec().eq(&self.hash));
+            }
+
+            hasher.update(&buf)?;
+            offset += buf.len();
+        }
+    }
+
+    fn close(&self) -> IndyResult<()> {
+        _check((self.reader_type.close_blob_handler)(self.handle))
+    }
+}
+
+/// Writer type implemented by application and registered with indy_register_blob_storage_writer.
+#[derive(Clone, Copy)]
+pub struct PluggedWriterType {
+    open_writer_handler: BlobStorageOpenWriter,
+    create_blob_handler: BlobStorageCreateBlob,
+    append_handler: BlobStorageAppend,
+    finalize_handler: BlobStorageFinalize,
+    close_blob_handler: BlobStorageCloseBlob,
+    close_writer_handler: Option<BlobStorageCloseWriter>,
+}
+
+impl PluggedWriterType {
+    pub fn new(open_writer_handler: BlobStorageOpenWriter,
+               create_blob_handler: BlobStorageCreateBlob,
+               append_handler: BlobStorageAppend,
+               finalize_handler: BlobStorageFinalize,
+               close_blob_handler: BlobStorageCloseBlob,
+               close_writer_handler: Option<BlobStorageCloseWriter>) -> Self {
+        PluggedWriterType {
+            open_writer_handler,
+            create_blob_handler,
+            append_handler,
+            finalize_handler,
+            close_blob_handler,
+            close_writer_handler,
+        }
+    }
+}
+
+struct PluggedWriter {
+    handle: IndyHandle,
+    writer_type: PluggedWriterType,
+}
+
+struct PluggedWritableBlob {
+    handle: IndyHandle,
+    writer_type: PluggedWriterType,
+}
+
+impl WriterType for PluggedWriterType {
+    fn open(&self, config: &str) -> IndyResult<Box<dyn Writer>> {
+        let config = CString::new(config)
+            .to_indy(IndyErrorKind::InvalidStructure, "Invalid BlobStorage Writer config")?;
+
+        let mut handle: IndyHandle = -1;
+        let err = (self.open_writer_handler)(config.as_ptr(), &mut handle);
+        _check(err)?;
+
+        Ok(Box::new(PluggedWriter { handle, writer_type: *self }))
+    }
+}
+
+impl Writer for PluggedWriter {
+    fn create(&self, _id: i32) -> IndyResult<Box<dyn WritableBlob>> {
+        let mut handle: IndyHandle = -1;
+        let err = (self.writer_type.create_blob_handler)(self.handle, &mut handle);
+        _check(err)?;
+
+        Ok(Box::new(PluggedWritableBlob { handle, writer_type: self.writer_type }))
+    }
+
+    fn close(&self) -> IndyResult<()> {
+        match self.writer_type.close_writer_handler {
+            Some(close_writer_handler) => _check(close_writer_handler(self.handle)),
+            None => Ok(())
+        }
+    }
+}
+
+impl WritableBlob for PluggedWritableBlob {
+    fn append(&mut self, bytes: &[u8]) -> IndyResult<usize> {
+        let err = (self.writer_type.append_handler)(self.handle, bytes.as_ptr(), _len(bytes)?);
+        _check(err)?;
+
+        Ok(bytes.len())
+    }
+
+    fn finalize(&mut self, hash: &[u8]) -> IndyResult<String> {
+        let mut location_p: *const c_char = ptr::null();
+
+        let err = (self.writer_type.finalize_handler)(self.handle, hash.as_ptr(), _len(hash)?, &mut location_p);
+
+        // Location is owned by the plugin until the blob is closed, so it is copied before
+        let res = _check(err).and_then(|()| {
+            if location_p.is_null() {
+                return Err(err_msg(IndyErrorKind::InvalidState, "BlobStorage Writer returned no location"));
+            }
+
+            unsafe { CStr::from_ptr(location_p) }.to_str()
+                .map(str::to_string)
+                .to_indy(IndyErrorKind::InvalidState, "BlobStorage Writer returned invalid location")
+        });
+
+        let close_res = _check((self.writer_type.close_blob_handler)(self.handle));
+
+        let location = res?;
+        close_res?;
+        Ok(location)
+    }
+}
+
+fn _check(err: ErrorCode) -> IndyResult<()> {
+    err.into()
+}
+
+fn _len(bytes: &[u8]) -> IndyResult<u32> {
+    u32::try_from(bytes.len())
+        .to_indy(IndyErrorKind::InvalidStructure, format!("BlobStorage buffer is too big: {}", bytes.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::{HashMap, HashSet};
+    use std::slice;
+    use std::sync::Mutex;
+
+    use indy_utils::sequence;
+
+    lazy_static! {
+        static ref BLOBS: Mutex<HashMap<IndyHandle, Vec<u8>>> = Default::default();
+        static ref OPENED: Mutex<HashSet<IndyHandle>> = Default::default();
+        static ref LOCATION: CString = CString::new("inmem").unwrap();
+    }
+
+    extern fn _open(_config: *const c_char, handle_p: *mut IndyHandle) -> ErrorCode {
+        let handle = sequence::get_next_id();
+        OPENED.lock().unwrap().insert(handle);
+        unsafe { *handle_p = handle };
+        ErrorCode::Success
+    }
+
+    extern fn _close(handle: IndyHandle) -> ErrorCode {
+        match OPENED.lock().unwrap().remove(&handle) {
+            true => ErrorCode::Success,
+            false => ErrorCode::CommonInvalidState
+        }
+    }
+
+    extern fn _create_blob(_writer_handle: IndyHandle, blob_handle_p: *mut IndyHandle) -> ErrorCode {
+        let handle = sequence::get_next_id();
+        BLOBS.lock().unwrap().insert(handle, Vec::new());
+        unsafe { *blob_handle_p = handle };
+        ErrorCode::Success
+    }
+
+    extern fn _append(blob_handle: IndyHandle, bytes: *const u8, bytes_len: u32) -> ErrorCode {
+        let bytes = unsafe { slice::from_raw_parts(bytes, bytes_len as usize) };
+        BLOBS.lock().unwrap().get_mut(&blob_handle).unwrap().extend_from_slice(bytes);
+        ErrorCode::Success
+    }
+
+    extern fn _finalize(_blob_handle: IndyHandle, _hash: *const u8, _hash_len: u32, location_p: *mut *const c_char) -> ErrorCode {
+        unsafe { *location_p = LOCATION.as_ptr() };
+        ErrorCode::Success
+    }
+
+    extern fn _close_blob(_blob_handle: IndyHandle) -> ErrorCode {
+        ErrorCode::Success
+    }
+
+    // Opened blob is the written one with the same handle
+    extern fn _open_blob(_reader_handle: IndyHandle, _hash: *const u8, _hash_len: u32, _location: *const c_char, blob_handle_p: *mut IndyHandle) -> ErrorCode {
+        unsafe { *blob_handle_p = *BLOBS.lock().unwrap().keys().max().unwrap() };
+        ErrorCode::Success
+    }
+
+    extern fn _read(blob_handle: IndyHandle, offset: u64, size: u32, buf: *mut u8, read_len_p: *mut u32) -> ErrorCode {
+        let blobs = BLOBS.lock().unwrap();
+        let blob = match blobs.get(&blob_handle) {
+            Some(blob) => blob,
+            None => return ErrorCode::CommonInvalidState
+        };
+
+        let start = std::cmp::min(offset as usize, blob.len());
+        let end = std::cmp::min(start + size as usize, blob.len());
+        unsafe {
+            ptr::copy_nonoverlapping(blob[start..end].as_ptr(), buf, end - start);
+            *read_len_p = (end - start) as u32;
+        }
+        ErrorCode::Success
+    }
+
+    extern fn _read_too_much(_blob_handle: IndyHandle, _offset: u64, size: u32, _buf: *mut u8, read_len_p: *mut u32) -> ErrorCode {
+        unsafe { *read_len_p = size + 1 };
+        ErrorCode::Success
+    }
+
+    fn _hash(content: &[u8]) -> Vec<u8> {
+        let mut hasher = Hash::new_context().unwrap();
+        hasher.update(content).unwrap();
+        hasher.finish().unwrap().to_vec()
+    }
+
+    #[test]
+    fn plugged_blob_storage_works() {
+        let content = b"0123456789".repeat(2000);
+
+        let writer_type = PluggedWriterType::new(_open, _create_blob, _append, _finalize, _close_blob, Some(_close));
+        let writer = writer_type.open("{}").unwrap();
+        let mut blob = writer.create(1).unwrap();
+        blob.append(&content[..5000]).unwrap();
+        blob.append(&content[5000..]).unwrap();
+        assert_eq!("inmem", blob.finalize(&_hash(&content)).unwrap());
+        writer.close().unwrap();
+        assert!(writer.close().is_err());
+
+        let reader_type = PluggedReaderType::new(_open, _open_blob, _read, _close_blob, Some(_close));
+        let reader = reader_type.open("{}").unwrap();
+
+        let mut blob = reader.open(&_hash(&content), "inmem").unwrap();
+        assert_eq!(b"2345".to_vec(), blob.read(4, 12).unwrap());
+        assert_eq!(b"89".to_vec(), blob.read(4, 19998).unwrap());
+        assert!(blob.verify().unwrap());
+        blob.close().unwrap();
+
+        let mut blob = reader.open(&_hash(b"other content"), "inmem").unwrap();
+        assert!(!blob.verify().unwrap());
+
+        reader.close().unwrap();
+        assert!(reader.close().is_err());
+    }
+
+    #[test]
+    fn plugged_blob_storage_works_without_close_handlers() {
+        let writer_type = PluggedWriterType::new(_open, _create_blob, _append, _finalize, _close_blob, None);
+        writer_type.open("{}").unwrap().close().unwrap();
+
+        let reader_type = PluggedReaderType::new(_open, _open_blob, _read, _close_blob, None);
+        reader_type.open("{}").unwrap().close().unwrap();
+    }
+
+    #[test]
+    fn plugged_blob_storage_read_works_for_invalid_size() {
+        let reader_type = PluggedReaderType::new(_open, _open_blob, _read_too_much, _close_blob, None);
+        let mut blob = PluggedReadableBlob { handle: 1, hash: Vec::new(), reader_type };
+
+        let err = blob.read(std::u32::MAX as usize + 1, 0).unwrap_err();
+        assert_eq!(IndyErrorKind::InvalidStructure, err.kind());
+
+        let err = blob.read(4, 0).unwrap_err();
+        assert_eq!(IndyErrorKind::InvalidState, err.kind());
+    }
+}
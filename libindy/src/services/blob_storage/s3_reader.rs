@@ -0,0 +1,185 @@
+use rust_base58::ToBase58;
+
+use super::{ReadableBlob, Reader, ReaderType};
+use super::http::BlockCache;
+use super::s3::{S3Client, S3Config};
+use indy_api_types::errors::prelude::*;
+
+use serde_json;
+use std::io::Read;
+use indy_utils::crypto::hash::Hash;
+
+const DEFAULT_BLOCK_SIZE: usize = 1024 * 1024;
+
+/// Reads tails from the object named by tails hash with ranged requests.
+pub struct S3Reader {
+    client: S3Client,
+    key: String,
+    cache: BlockCache,
+    hash: Vec<u8>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct S3ReaderConfig {
+    #[serde(flatten)]
+    s3: S3Config,
+    block_size: Option<usize>,
+}
+
+impl ReaderType for S3ReaderType {
+    fn open(&self, config: &str) -> IndyResult<Box<dyn Reader>> {
+        let config: S3ReaderConfig = serde_json::from_str(config)
+            .to_indy(IndyErrorKind::InvalidStructure, "Can't deserialize S3ReaderConfig")?;
+
+        S3Client::new(config.s3.clone())?;
+
+        if config.block_size == Some(0) {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "block_size must be positive"));
+        }
+
+        Ok(Box::new(config))
+    }
+}
+
+impl Reader for S3ReaderConfig {
+    fn open(&self, hash: &[u8], _location: &str) -> IndyResult<Box<dyn ReadableBlob>> {
+        let client = S3Client::new(self.s3.clone())?;
+        let key = client.key(&hash.to_base58());
+
+        Ok(Box::new(S3Reader {
+            client,
+            key,
+            cache: BlockCache::new(self.block_size.unwrap_or(DEFAULT_BLOCK_SIZE)),
+            hash: hash.to_owned(),
+        }))
+    }
+}
+
+impl ReadableBlob for S3Reader {
+    fn verify(&mut self) -> IndyResult<bool> {
+        let mut response = self.client.request("GET", &self.key, &[], &[], &[])?;
+
+        let mut hasher = Hash::new_context()?;
+        let mut buf = vec![0u8; super::buffer_size()];
+
+        loop {
+            let sz = response.body.read(&mut buf)?;
+
+            if sz == 0 {
+                return Ok(hasher.finish()?.to_vec().eq(&self.hash));
+            }
+
+            hasher.update(&buf[0..sz])?;
+        }
+    }
+
+    fn close(&self) -> IndyResult<()> {
+        /* nothing to do */
+        Ok(())
+    }
+
+    fn read(&mut self, size: usize, offset: usize) -> IndyResult<Vec<u8>> {
+        if size == 0 {
+            return Ok(Vec::new());
+        }
+
+        let (client, key) = (&self.client, &self.key);
+        self.cache.read(size, offset, |offset, size| S3Reader::_read(client, key, size, offset))
+    }
+}
+
+impl S3Reader {
+    fn _read(client: &S3Client, key: &str, size: usize, offset: usize) -> IndyResult<Vec<u8>> {
+        let range = format!("bytes={}-{}", offset, offset + size - 1);
+        let mut response = client.request("GET", key, &[], &[("Range", &range)], &[])?;
+
+        let mut buf = Vec::with_capacity(size);
+
+        // Offset is beyond the end as for files
+        if response.status != 416 {
+            response.body.read_to_end(&mut buf)?;
+            buf.truncate(size);
+        }
+
+        Ok(buf)
+    }
+}
+
+pub struct S3ReaderType {}
+
+impl S3ReaderType {
+    pub fn new() -> Self {
+        S3ReaderType {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use super::super::{WriterType, s3::tests};
+    use super::super::s3_writer::S3WriterType;
+
+    fn _hash(content: &[u8]) -> Vec<u8> {
+        let mut hasher = Hash::new_context().unwrap();
+        hasher.update(content).unwrap();
+        hasher.finish().unwrap().to_vec()
+    }
+
+    #[test]
+    fn s3_writer_and_reader_work() {
+        let content = b"0123456789abcdefghij";
+        let hash = _hash(content);
+
+        let (endpoint, objects, requests) = tests::serve();
+        let mut config = serde_json::to_value(tests::config(&endpoint)).unwrap();
+        config["part_size"] = json!(8);
+        config["block_size"] = json!(8);
+
+        let mut blob = S3WriterType::new().open(&config.to_string()).unwrap().create(1).unwrap();
+        blob.append(&content[..5]).unwrap();
+        blob.append(&content[5..]).unwrap();
+        let location = blob.finalize(&hash).unwrap();
+
+        let key = format!("/tails/revocation/{}", hash.to_base58());
+        assert_eq!(format!("{}{}", endpoint, key), location);
+        assert_eq!(vec![key.clone()], objects.lock().unwrap().keys().cloned().collect::<Vec<String>>());
+        assert_eq!(content.to_vec(), objects.lock().unwrap()[&key]);
+
+        let mut blob = S3ReaderType::new().open(&config.to_string()).unwrap().open(&hash, &location).unwrap();
+        assert_eq!(b"2345".to_vec(), blob.read(4, 2).unwrap());
+        assert_eq!(b"4567".to_vec(), blob.read(4, 4).unwrap());
+        assert_eq!(b"ij".to_vec(), blob.read(4, 18).unwrap());
+        assert!(blob.read(4, 20).unwrap().is_empty());
+        assert!(blob.verify().unwrap());
+
+        // initiate, 3 parts, complete, copy, delete, 2 block reads, verify
+        assert_eq!(10, *requests.lock().unwrap());
+    }
+
+    #[test]
+    fn s3_writer_aborts_upload_for_failed_part() {
+        let (endpoint, objects, _) = tests::serve();
+        let mut config = serde_json::to_value(tests::config(&endpoint)).unwrap();
+        config["part_size"] = json!(4);
+
+        let mut blob = S3WriterType::new().open(&config.to_string()).unwrap().create(1).unwrap();
+        blob.append(b"fail").unwrap_err();
+
+        assert!(objects.lock().unwrap().contains_key("/tails/revocation/s3_storage_tmp_1?aborted"));
+    }
+
+    #[test]
+    fn s3_writer_aborts_upload_for_dropped_blob() {
+        let (endpoint, objects, requests) = tests::serve();
+        let config = serde_json::to_value(tests::config(&endpoint)).unwrap();
+
+        let mut blob = S3WriterType::new().open(&config.to_string()).unwrap().create(2).unwrap();
+        blob.append(b"0123").unwrap();
+        drop(blob);
+
+        assert!(objects.lock().unwrap().contains_key("/tails/revocation/s3_storage_tmp_2?aborted"));
+        // initiate, abort
+        assert_eq!(2, *requests.lock().unwrap());
+    }
+}
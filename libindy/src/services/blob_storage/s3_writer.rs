@@ -0,0 +1,207 @@
+use serde_json;
+
+use indy_api_types::errors::prelude::*;
+
+use super::{WritableBlob, Writer, WriterType};
+use super::http::Response;
+use super::s3::{self, S3Client, S3Config};
+
+use rust_base58::ToBase58;
+
+// Minimal size of multipart upload part except the last one required by S3
+const DEFAULT_PART_SIZE: usize = 5 * 1024 * 1024;
+
+/// Uploads tails to temporary object with multipart upload and copies it to the object named by tails hash on finalize.
+/// Multipart upload is aborted if appending or finalizing fails or the blob is dropped unfinished,
+/// so uploaded parts aren't left in the bucket.
+pub struct S3Writer {
+    client: S3Client,
+    tmp_key: String,
+    upload_id: String,
+    part_size: usize,
+    buffer: Vec<u8>,
+    etags: Vec<String>,
+    // multipart upload is completed or aborted
+    closed: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+struct S3WriterConfig {
+    #[serde(flatten)]
+    s3: S3Config,
+    part_size: Option<usize>,
+}
+
+impl WriterType for S3WriterType {
+    fn open(&self, config: &str) -> IndyResult<Box<dyn Writer>> {
+        let config: S3WriterConfig = serde_json::from_str(config)
+            .to_indy(IndyErrorKind::InvalidStructure, "Can't deserialize S3WriterConfig")?;
+
+        S3Client::new(config.s3.clone())?;
+
+        if config.part_size == Some(0) {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "part_size must be positive"));
+        }
+
+        Ok(Box::new(config))
+    }
+}
+
+impl Writer for S3WriterConfig {
+    fn create(&self, id: i32) -> IndyResult<Box<dyn WritableBlob>> {
+        let client = S3Client::new(self.s3.clone())?;
+        let tmp_key = client.key(&format!("s3_storage_tmp_{}", id));
+
+        let body = _read_body(&mut client.request("POST", &tmp_key, &[("uploads", "")], &[], &[])?)?;
+        let upload_id = s3::xml_element(&String::from_utf8_lossy(&body), "UploadId")
+            .ok_or_else(|| err_msg(IndyErrorKind::IOError, "S3 didn't return UploadId of multipart upload"))?
+            .to_string();
+
+        let part_size = self.part_size.unwrap_or(DEFAULT_PART_SIZE);
+
+        Ok(Box::new(S3Writer {
+            client,
+            tmp_key,
+            upload_id,
+            part_size,
+            buffer: Vec::with_capacity(part_size),
+            etags: Vec::new(),
+            closed: false,
+        }))
+    }
+}
+
+impl WritableBlob for S3Writer {
+    fn append(&mut self, bytes: &[u8]) -> IndyResult<usize> {
+        trace!("append >>>");
+
+        self.buffer.extend_from_slice(bytes);
+
+        while self.buffer.len() >= self.part_size {
+            let part: Vec<u8> = self.buffer.drain(..self.part_size).collect();
+            let res = self._upload_part(&part);
+            self._abort_on_err(res)?;
+        }
+
+        let res = bytes.len();
+
+        trace!("append <<< {}", res);
+        Ok(res)
+    }
+
+    fn finalize(&mut self, hash: &[u8]) -> IndyResult<String> {
+        trace!("finalize >>>");
+
+        let res = self._complete();
+        self._abort_on_err(res)?;
+
+        let key = self.client.key(&hash.to_base58());
+        let copy_source = self.client.copy_source(&self.tmp_key);
+
+        let copied = self.client.request("PUT", &key, &[], &[("x-amz-copy-source", &copy_source)], &[])
+            .and_then(|mut response| _read_body(&mut response));
+
+        // Temporary object is deleted even if it wasn't copied
+        let deleted = self.client.request("DELETE", &self.tmp_key, &[], &[], &[])
+            .and_then(|mut response| _read_body(&mut response));
+
+        if let Err(err) = deleted {
+            warn!("Can't delete temporary S3 object {}: {}", self.tmp_key, err);
+        }
+
+        copied?;
+
+        let res = self.client.url(&key);
+
+        trace!("finalize <<< {}", res);
+        Ok(res)
+    }
+}
+
+impl S3Writer {
+    fn _upload_part(&mut self, part: &[u8]) -> IndyResult<()> {
+        let part_number = (self.etags.len() + 1).to_string();
+
+        let mut response = self.client.request("PUT", &self.tmp_key, &[("partNumber", &part_number), ("uploadId", &self.upload_id)], &[], part)?;
+        _read_body(&mut response)?;
+
+        let etag = response.headers.get("etag")
+            .ok_or_else(|| err_msg(IndyErrorKind::IOError, "S3 didn't return ETag of uploaded part"))?;
+
+        self.etags.push(etag.clone());
+        Ok(())
+    }
+
+    fn _complete(&mut self) -> IndyResult<()> {
+        if !self.buffer.is_empty() || self.etags.is_empty() {
+            let part = std::mem::take(&mut self.buffer);
+            self._upload_part(&part)?;
+        }
+
+        let parts: String = self.etags.iter().enumerate()
+            .map(|(idx, etag)| format!("<Part><PartNumber>{}</PartNumber><ETag>{}</ETag></Part>", idx + 1, etag))
+            .collect();
+        let complete = format!("<CompleteMultipartUpload>{}</CompleteMultipartUpload>", parts);
+
+        _read_body(&mut self.client.request("POST", &self.tmp_key, &[("uploadId", &self.upload_id)], &[], complete.as_bytes())?)?;
+        self.closed = true;
+
+        Ok(())
+    }
+
+    fn _abort_on_err<T>(&mut self, res: IndyResult<T>) -> IndyResult<T> {
+        if res.is_err() {
+            self._abort();
+        }
+
+        res
+    }
+
+    fn _abort(&mut self) {
+        if self.closed {
+            return;
+        }
+
+        self.closed = true;
+
+        let res = self.client.request("DELETE", &self.tmp_key, &[("uploadId", &self.upload_id)], &[], &[])
+            .and_then(|mut response| _read_body(&mut response));
+
+        if let Err(err) = res {
+            warn!("Can't abort S3 multipart upload {}: {}", self.upload_id, err);
+        }
+    }
+}
+
+impl Drop for S3Writer {
+    fn drop(&mut self) {
+        self._abort();
+    }
+}
+
+/// Reads body of response to request that succeeds with 2xx status only.
+/// Complete and copy requests may also fail after 200 status is sent.
+fn _read_body(response: &mut Response) -> IndyResult<Vec<u8>> {
+    let body = response.read_body()?;
+
+    if response.status < 200 || response.status >= 300 {
+        return Err(err_msg(IndyErrorKind::IOError, format!("S3 request failed with status {}", response.status)));
+    }
+
+    let text = String::from_utf8_lossy(&body);
+
+    if text.contains("<Error>") {
+        let message = s3::xml_element(&text, "Message").unwrap_or(&text);
+        return Err(err_msg(IndyErrorKind::IOError, format!("S3 request failed: {}", message)));
+    }
+
+    Ok(body)
+}
+
+pub struct S3WriterType {}
+
+impl S3WriterType {
+    pub fn new() -> Self {
+        S3WriterType {}
+    }
+}
@@ -4,7 +4,7 @@ use std::collections::HashMap;
 use std::str;
 
 use crate::domain::crypto::combo_box::ComboBox;
-use crate::domain::crypto::did::{Did, DidValue, MyDidInfo, TheirDid, TheirDidInfo};
+use crate::domain::crypto::did::{Did, DidMethod, DidValue, MyDidInfo, TheirDid, TheirDidInfo};
 use crate::domain::crypto::key::{Key, KeyInfo};
 use indy_api_types::errors::prelude::*;
 use indy_utils::crypto::base64;
@@ -12,6 +12,7 @@ use indy_utils::crypto::ed25519_box;
 use indy_utils::crypto::chacha20poly1305_ietf;
 use indy_utils::crypto::chacha20poly1305_ietf::gen_nonce_and_encrypt_detached;
 use indy_utils::crypto::ed25519_sign;
+use indy_utils::crypto::hash::hash as openssl_hash;
 use crate::utils::crypto::verkey_builder::{build_full_verkey, split_verkey, verkey_get_cryptoname};
 
 use self::ed25519::ED25519CryptoType;
@@ -96,8 +97,13 @@ impl CryptoService {
 
         let seed = self.convert_seed(my_did_info.seed.as_ref().map(String::as_ref))?;
         let (vk, sk) = crypto_type.create_key(seed.as_ref())?;
+        let is_indy_method = my_did_info.method_name.as_ref().map(DidMethod::is_indy).unwrap_or(false);
+
         let did = match my_did_info.did {
             Some(ref did) => did.clone(),
+            // did:indy identifier is always self-certifying based on the verkey hash
+            _ if is_indy_method =>
+                DidValue::new(&Self::_indy_did_identifier(&vk[..])?, my_did_info.method_name.as_ref().map(|method| method.0.as_str())),
             _ if my_did_info.cid == Some(true) =>
                 DidValue::new(&vk[..].to_vec().to_base58(), my_did_info.method_name.as_ref().map(|method| method.0.as_str())),
             _ =>
@@ -112,6 +118,10 @@ impl CryptoService {
             vk = format!("{}:{}", vk, crypto_type_name);
         }
 
+        if did.is_indy() {
+            self.validate_self_certifying_did(&did, &vk)?;
+        }
+
         let did = (Did::new(did, vk.clone()), Key::new(vk, sk));
 
         trace!("create_my_did <<< did: {:?}", did);
@@ -365,6 +375,50 @@ impl CryptoService {
         Ok(())
     }
 
+    #[cfg(feature = "ledger")]
+    pub fn convert_verkey_to_x25519(&self, vk: &str) -> IndyResult<String> {
+        trace!("convert_verkey_to_x25519 >>> vk: {:?}", vk);
+
+        let (vk, crypto_type_name) = split_verkey(vk);
+
+        if crypto_type_name != DEFAULT_CRYPTO_TYPE {
+            return Err(err_msg(IndyErrorKind::UnknownCrypto, format!("Trying to convert key with unsupported crypto: {}", crypto_type_name)));
+        }
+
+        let vk = ed25519_sign::PublicKey::from_slice(vk.from_base58()?.as_slice())?;
+
+        let res = ed25519_sign::vk_to_curve25519(&vk)?[..].to_base58();
+
+        trace!("convert_verkey_to_x25519 <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    /// Checks that did:indy DID identifier is derived from the verkey: the first 16 bytes of SHA256 hash of the verkey.
+    /// Legacy identifiers (the first 16 bytes of the verkey) are accepted as well.
+    pub fn validate_self_certifying_did(&self, did: &DidValue, vk: &str) -> IndyResult<()> {
+        trace!("validate_self_certifying_did >>> did: {:?}, vk: {:?}", did, vk);
+
+        let (vk, _) = split_verkey(vk);
+        let vk = vk.from_base58()?;
+
+        let id = did.to_unqualified().0;
+
+        if id != Self::_indy_did_identifier(&vk)? && id != vk[0..16].to_base58() {
+            return Err(err_msg(IndyErrorKind::InvalidStructure,
+                               format!("DID {} is not self-certifying: its identifier is not derived from the verkey", did.0)));
+        }
+
+        trace!("validate_self_certifying_did <<<");
+
+        Ok(())
+    }
+
+    fn _indy_did_identifier(vk: &[u8]) -> IndyResult<String> {
+        let hash = openssl_hash(vk)?;
+        Ok(hash[0..16].to_base58())
+    }
+
     pub fn validate_did(&self, did: &DidValue) -> IndyResult<()> {
         trace!("validate_did >>> did: {:?}", did);
         // Useful method, huh?
@@ -497,6 +551,40 @@ mod tests {
         assert_ne!(did_with_seed.verkey, did_without_seed.verkey)
     }
 
+    #[test]
+    fn create_my_did_works_for_indy_method() {
+        let service = CryptoService::new();
+        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None, method_name: Some(DidMethod("indy:sovrin:staging".to_string())) };
+
+        let (my_did, _) = service.create_my_did(&did_info).unwrap();
+        assert!(my_did.did.0.starts_with("did:indy:sovrin:staging:"));
+        assert_eq!(Some("sovrin:staging".to_string()), my_did.did.get_namespace());
+
+        let vk = my_did.verkey.from_base58().unwrap();
+        assert_eq!(openssl_hash(&vk).unwrap()[0..16].to_base58(), my_did.did.to_unqualified().0);
+    }
+
+    #[test]
+    fn create_my_did_not_works_for_not_self_certifying_indy_did() {
+        let service = CryptoService::new();
+
+        let did = DidValue("did:indy:sovrin:NcYxiDXkpYi6ov5FcYDi1e".to_string());
+        let did_info = MyDidInfo { did: Some(did), cid: None, seed: None, crypto_type: None, method_name: None };
+
+        let res = service.create_my_did(&did_info);
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    #[test]
+    fn validate_self_certifying_did_works_for_legacy_identifier() {
+        let service = CryptoService::new();
+        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None, method_name: None };
+        let (my_did, _) = service.create_my_did(&did_info).unwrap();
+
+        let did = DidValue(format!("did:indy:sovrin:{}", my_did.did.0));
+        service.validate_self_certifying_did(&did, &my_did.verkey).unwrap();
+    }
+
     #[test]
     fn create_their_did_works_without_verkey() {
         let service = CryptoService::new();
@@ -667,6 +755,29 @@ mod tests {
         assert_eq!(msg.as_bytes().to_vec(), decrypted_message);
     }
 
+    #[cfg(feature = "ledger")]
+    #[test]
+    fn convert_verkey_to_x25519_works() {
+        let service = CryptoService::new();
+        let did_info = MyDidInfo { did: None, cid: None, seed: None, crypto_type: None, method_name: None };
+        let (did, _) = service.create_my_did(&did_info).unwrap();
+
+        let x25519_key = service.convert_verkey_to_x25519(&did.verkey).unwrap();
+        assert_eq!(32, x25519_key.from_base58().unwrap().len());
+        assert_ne!(did.verkey, x25519_key);
+
+        let x25519_key_for_crypto_type = service.convert_verkey_to_x25519(&format!("{}:ed25519", did.verkey)).unwrap();
+        assert_eq!(x25519_key, x25519_key_for_crypto_type);
+    }
+
+    #[cfg(feature = "ledger")]
+    #[test]
+    fn convert_verkey_to_x25519_not_works_for_unknown_crypto_type() {
+        let service = CryptoService::new();
+        let res = service.convert_verkey_to_x25519("CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW:crypto_type");
+        assert_kind!(IndyErrorKind::UnknownCrypto, res);
+    }
+
     #[test]
     fn crypto_box_seal_works() {
         let service = CryptoService::new();
@@ -11,29 +11,64 @@ use crate::domain::anoncreds::revocation_registry_definition::{RevocationRegistr
 use crate::domain::anoncreds::revocation_registry_delta::{RevocationRegistryDelta, RevocationRegistryDeltaV1};
 use crate::domain::anoncreds::schema::{Schema, SchemaV1, SchemaId};
 use crate::domain::crypto::did::DidValue;
-use crate::domain::ledger::attrib::{AttribOperation, GetAttribOperation};
-use crate::domain::ledger::constants::{GET_VALIDATOR_INFO, POOL_RESTART, ROLE_REMOVE, STEWARD, ENDORSER, TRUSTEE, NETWORK_MONITOR, ROLES, txn_name_to_code};
+use crate::domain::ledger::attrib::{AttribOperation, AttribReplyData, Endpoint, GetAttribOperation, GetAttribReplyResult, ENDPOINT_ATTRIB_NAME, validate_attrib_raw};
+use crate::domain::ledger::constants::{CRED_DEF, GET_VALIDATOR_INFO, POOL_RESTART, REQUESTS, ROLE_REMOVE, SCHEMA, STEWARD, ENDORSER, TRUSTEE, NETWORK_MONITOR, ROLES, txn_name_to_code};
 use crate::domain::ledger::cred_def::{CredDefOperation, GetCredDefOperation, GetCredDefReplyResult};
 use crate::domain::ledger::ddo::GetDdoOperation;
 use crate::domain::ledger::node::{NodeOperation, NodeOperationData};
-use crate::domain::ledger::nym::{GetNymOperation, GetNymReplyResult, GetNymResultDataV0, NymData, NymOperation};
-use crate::domain::ledger::pool::{PoolConfigOperation, PoolRestartOperation, PoolUpgradeOperation, Schedule};
+use crate::domain::ledger::nym::{GetNymOperation, GetNymReplyResult, GetNymResultDataV0, NymData, NymOperation, NymRequestData, NymRole};
+use crate::domain::ledger::pool::{PoolConfigOperation, PoolRestartOperation, PoolUpgradeOperation, PoolUpgradeScheduleData, Schedule};
 use crate::domain::ledger::request::{TxnAuthrAgrmtAcceptanceData, Request};
-use crate::domain::ledger::response::{Message, Reply, ReplyType};
+use crate::domain::ledger::response::{IdempotentSubmitResult, Message, ParsedReply, Reply, ReplyType};
 use crate::domain::ledger::rev_reg::{GetRevocRegDeltaReplyResult, GetRevocRegReplyResult, GetRevRegDeltaOperation, GetRevRegOperation, RevRegEntryOperation};
 use crate::domain::ledger::rev_reg_def::{GetRevocRegDefReplyResult, GetRevRegDefOperation, RevRegDefOperation};
 use crate::domain::ledger::schema::{GetSchemaOperation, GetSchemaOperationData, GetSchemaReplyResult, SchemaOperation, SchemaOperationData};
-use crate::domain::ledger::txn::{GetTxnOperation, LedgerType};
-use crate::domain::ledger::validator_info::GetValidatorInfoOperation;
+use crate::domain::ledger::txn::{GetTxnOperation, GetTxnReplyResult, LedgerType, TxnReplyData};
+use crate::domain::ledger::validator_info::{GetValidatorInfoOperation, GetValidatorInfoResult, NodeUpgradeStatus, ValidatorInfoNodeReply, ValidatorInfoSummary};
 use crate::domain::ledger::auth_rule::*;
 use crate::domain::ledger::author_agreement::*;
-use crate::domain::ledger::ledgers_freeze::{LedgersFreezeOperation, GetFrozenLedgersOperation};
+use crate::domain::ledger::ledgers_freeze::{FrozenLedger, GetFrozenLedgersOperation, GetFrozenLedgersResult, LedgersFreezeOperation};
+use crate::services::pool::parse_response_metadata;
 use indy_api_types::errors::prelude::*;
+use indy_api_types::validation::Validatable;
+use indy_api_types::ErrorCode;
+use libc::c_char;
 use indy_utils::crypto::hash::hash as openssl_hash;
+use regex::Regex;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::ffi::{CStr, CString};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use crate::api::ledger::{CustomFree, CustomReplyMiddleware, CustomRequestMiddleware, CustomResponseParser, CustomTransactionBuilder};
 
 pub mod merkletree;
 
+type TxnBuilderCallbacks = (CustomTransactionBuilder, Option<CustomResponseParser>, CustomFree);
+type AuthRuleKey = (String, String, String, Option<String>, Option<String>);
+
+// Parts of the rejection reasons sent by the pool nodes for the write of already existing ledger entity
+const DUPLICATE_TXN_REASONS: [&str; 2] = ["can have one and only one", "already exists"];
+
+lazy_static! {
+    static ref REGISTERED_TXN_BUILDERS: Mutex<HashMap<String, TxnBuilderCallbacks>> = Mutex::new(HashMap::new());
+    // did:indy DID of the issuer in qualified schema, credential definition or revocation registry ID
+    static ref ID_INDY_DID_REGEX: Regex = Regex::new("did:indy(?::[a-z0-9_-]+)+:[1-9A-HJ-NP-Za-km-z]{21,44}").unwrap();
+}
+
+static STRICT_ATTRIB_VALIDATION: AtomicBool = AtomicBool::new(false);
+
+/// Enables validation of `raw` data of ATTRIB requests against the known attrib structures.
+pub fn set_strict_attrib_validation(strict: bool) {
+    STRICT_ATTRIB_VALIDATION.store(strict, Ordering::SeqCst);
+}
+
+// DIDs listed in `refs` must belong to the did:indy namespace of the submitter
 macro_rules! build_result {
+        ($operation:ident, $submitter_did:expr, refs [$($did:expr),*], $($params:tt)*) => ({
+            LedgerService::_check_indy_namespaces($submitter_did, &[$($did),*])?;
+            build_result!($operation, $submitter_did, $($params)*)
+        });
         ($operation:ident, $submitter_did:expr) => ({
             let operation = $operation::new();
 
@@ -77,21 +112,38 @@ impl LedgerService {
             )
         } else { None };
 
-        build_result!(NymOperation, Some(identifier), dest.to_short(),
+        build_result!(NymOperation, Some(identifier), refs [Some(dest)], dest.to_short(),
                                                       verkey.map(String::from),
                                                       alias.map(String::from),
-                                                      role)
+                                                      role,
+                                                      None,
+                                                      None)
     }
 
     #[logfn(Info)]
-    pub fn build_get_nym_request(&self, identifier: Option<&DidValue>, dest: &DidValue) -> IndyResult<String> {
-        build_result!(GetNymOperation, identifier, dest.to_short())
+    pub fn build_nym_request_with_data(&self, identifier: &DidValue, dest: &DidValue, data: &NymRequestData) -> IndyResult<String> {
+        build_result!(NymOperation, Some(identifier), refs [Some(dest)], dest.to_short(),
+                                                      data.verkey.clone(),
+                                                      data.alias.clone(),
+                                                      data.role.map(NymRole::to_value),
+                                                      data.diddoc_content.as_ref().map(ToString::to_string),
+                                                      data.version)
+    }
+
+    #[logfn(Info)]
+    pub fn build_get_nym_request(&self, identifier: Option<&DidValue>, dest: &DidValue,
+                                 seq_no: Option<u64>, timestamp: Option<u64>) -> IndyResult<String> {
+        LedgerService::_check_point_in_time(seq_no, timestamp)?;
+        build_result!(GetNymOperation, identifier, refs [Some(dest)], dest.to_short(), seq_no, timestamp)
     }
 
     #[logfn(Info)]
     pub fn parse_get_nym_response(&self, get_nym_response: &str) -> IndyResult<String> {
         let reply: Reply<GetNymReplyResult> = LedgerService::parse_response(get_nym_response)?;
 
+        // Historical queries return the NYM state at the requested point, so it's useful to know when it was written
+        let metadata = parse_response_metadata(get_nym_response)?;
+
         let nym_data = match reply.result() {
             GetNymReplyResult::GetNymReplyResultV0(res) => {
                 let data: GetNymResultDataV0 = res.data
@@ -104,13 +156,17 @@ impl LedgerService {
                     did: data.dest,
                     verkey: data.verkey,
                     role: data.role,
+                    seq_no: metadata.seq_no,
+                    txn_time: metadata.txn_time,
                 }
             }
             GetNymReplyResult::GetNymReplyResultV1(res) => {
                 NymData {
                     did: res.txn.data.did,
                     verkey: res.txn.data.verkey,
-                    role: res.txn.data.role
+                    role: res.txn.data.role,
+                    seq_no: metadata.seq_no,
+                    txn_time: metadata.txn_time,
                 }
             }
         };
@@ -123,42 +179,68 @@ impl LedgerService {
 
     #[logfn(Info)]
     pub fn build_get_ddo_request(&self, identifier: Option<&DidValue>, dest: &DidValue) -> IndyResult<String> {
-        build_result!(GetDdoOperation, identifier, dest.to_short())
+        build_result!(GetDdoOperation, identifier, refs [Some(dest)], dest.to_short())
     }
 
     #[logfn(Info)]
     pub fn build_attrib_request(&self, identifier: &DidValue, dest: &DidValue, hash: Option<&str>,
                                 raw: Option<&serde_json::Value>, enc: Option<&str>) -> IndyResult<String> {
-        build_result!(AttribOperation, Some(identifier), dest.to_short(),
+        if let Some(raw) = raw {
+            if STRICT_ATTRIB_VALIDATION.load(Ordering::SeqCst) {
+                validate_attrib_raw(raw)
+                    .map_err(|err| err_msg(IndyErrorKind::InvalidStructure, format!("Invalid raw attrib: {}", err)))?;
+            }
+        }
+
+        build_result!(AttribOperation, Some(identifier), refs [Some(dest)], dest.to_short(),
                                                          hash.map(String::from),
                                                          raw.map(serde_json::Value::to_string),
                                                          enc.map(String::from))
     }
 
+    /// Endpoint is always published as `raw` attrib as it has to be resolvable by other parties.
+    #[logfn(Info)]
+    pub fn build_set_endpoint_request(&self, identifier: &DidValue, dest: &DidValue, endpoint: &Endpoint) -> IndyResult<String> {
+        endpoint.validate()
+            .map_err(|err| err_msg(IndyErrorKind::InvalidStructure, err))?;
+
+        self.build_attrib_request(identifier, dest, None, Some(&endpoint.to_attrib_raw()), None)
+    }
+
+    #[logfn(Info)]
+    pub fn build_get_endpoint_request(&self, identifier: Option<&DidValue>, dest: &DidValue) -> IndyResult<String> {
+        self.build_get_attrib_request(identifier, dest, Some(ENDPOINT_ATTRIB_NAME), None, None, None, None)
+    }
+
     #[logfn(Info)]
+    #[allow(clippy::too_many_arguments)]
     pub fn build_get_attrib_request(&self, identifier: Option<&DidValue>, dest: &DidValue, raw: Option<&str>, hash: Option<&str>,
-                                    enc: Option<&str>) -> IndyResult<String> {
-        build_result!(GetAttribOperation, identifier, dest.to_short(), raw, hash, enc)
+                                    enc: Option<&str>, seq_no: Option<u64>, timestamp: Option<u64>) -> IndyResult<String> {
+        LedgerService::_check_point_in_time(seq_no, timestamp)?;
+        build_result!(GetAttribOperation, identifier, refs [Some(dest)], dest.to_short(), raw, hash, enc, seq_no, timestamp)
     }
 
     #[logfn(Info)]
     pub fn build_schema_request(&self, identifier: &DidValue, schema: SchemaV1) -> IndyResult<String> {
+        let issuer_did = LedgerService::_id_indy_did(&schema.id.0);
         let schema_data = SchemaOperationData::new(schema.name, schema.version, schema.attr_names.into());
-        build_result!(SchemaOperation, Some(identifier), schema_data)
+        build_result!(SchemaOperation, Some(identifier), refs [issuer_did.as_ref()], schema_data)
     }
 
     #[logfn(Info)]
     pub fn build_get_schema_request(&self, identifier: Option<&DidValue>, id: &SchemaId) -> IndyResult<String> {
+        let issuer_did = LedgerService::_id_indy_did(&id.0);
         let id = id.to_unqualified();
         let (dest, name, version) = id.parts()
             .ok_or(IndyError::from_msg(IndyErrorKind::InvalidStructure, format!("Schema ID `{}` cannot be used to build request: invalid number of parts", id.0)))?;
 
         let data = GetSchemaOperationData::new(name, version);
-        build_result!(GetSchemaOperation, identifier, dest.to_short(), data)
+        build_result!(GetSchemaOperation, identifier, refs [issuer_did.as_ref()], dest.to_short(), data)
     }
 
     #[logfn(Info)]
     pub fn build_cred_def_request(&self, identifier: &DidValue, cred_def: CredentialDefinitionV1) -> IndyResult<String> {
+        let issuer_did = LedgerService::_id_indy_did(&cred_def.id.0);
         let cred_def: CredentialDefinitionV1 = CredentialDefinitionV1 {
             id: cred_def.id.to_unqualified(),
             schema_id: cred_def.schema_id.to_unqualified(),
@@ -166,11 +248,12 @@ impl LedgerService {
             tag: cred_def.tag,
             value: cred_def.value,
         };
-        build_result!(CredDefOperation, Some(identifier), cred_def)
+        build_result!(CredDefOperation, Some(identifier), refs [issuer_did.as_ref()], cred_def)
     }
 
     #[logfn(Info)]
     pub fn build_get_cred_def_request(&self, identifier: Option<&DidValue>, id: &CredentialDefinitionId) -> IndyResult<String> {
+        let issuer_did = LedgerService::_id_indy_did(&id.0);
         let id = id.to_unqualified();
         let (origin, signature_type, schema_id, tag) = id.parts()
             .ok_or(IndyError::from_msg(IndyErrorKind::InvalidStructure, format!("Credential Definition ID `{}` cannot be used to build request: invalid number of parts", id.0)))?;
@@ -179,12 +262,12 @@ impl LedgerService {
             .parse::<i32>()
             .to_indy(IndyErrorKind::InvalidStructure, format!("Schema ID is invalid number in: {:?}", id))?;
 
-        build_result!(GetCredDefOperation, identifier, ref_, signature_type, origin.to_short(), Some(tag))
+        build_result!(GetCredDefOperation, identifier, refs [issuer_did.as_ref()], ref_, signature_type, origin.to_short(), Some(tag))
     }
 
     #[logfn(Info)]
     pub fn build_node_request(&self, identifier: &DidValue, dest: &DidValue, data: NodeOperationData) -> IndyResult<String> {
-        build_result!(NodeOperation, Some(identifier), dest.to_short(), data)
+        build_result!(NodeOperation, Some(identifier), refs [Some(dest)], dest.to_short(), data)
     }
 
     #[logfn(Info)]
@@ -226,36 +309,61 @@ impl LedgerService {
         build_result!(PoolUpgradeOperation, Some(identifier), name, version, action, sha256, timeout, schedule, justification, reinstall, force, package)
     }
 
+    /// Builds POOL_UPGRADE starting the upgrade by the schedule referencing nodes by aliases.
+    /// `nodes` maps aliases of the pool nodes to their DIDs.
+    #[logfn(Info)]
+    pub fn build_scheduled_pool_upgrade(&self, identifier: &DidValue, data: &PoolUpgradeScheduleData,
+                                        nodes: &HashMap<String, String>) -> IndyResult<String> {
+        let schedule = data.node_offsets.iter()
+            .map(|(alias, offset)| {
+                let dest = nodes.get(alias)
+                    .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, format!("Node {} isn't a validator of the pool", alias)))?;
+
+                let time = time::at_utc(time::Timespec::new((data.start_time + offset) as i64, 0));
+
+                Ok((dest.clone(), time.rfc3339().to_string()))
+            })
+            .collect::<IndyResult<Schedule>>()?;
+
+        self.build_pool_upgrade(identifier, &data.name, &data.version, "start", &data.sha256, data.timeout, Some(schedule),
+                                data.justification.as_deref(), data.reinstall, data.force, data.package.as_deref())
+    }
+
     #[logfn(Info)]
     pub fn build_revoc_reg_def_request(&self, identifier: &DidValue, mut rev_reg_def: RevocationRegistryDefinitionV1) -> IndyResult<String> {
+        let issuer_did = LedgerService::_id_indy_did(&rev_reg_def.id.0);
         rev_reg_def.id = rev_reg_def.id.to_unqualified();
         rev_reg_def.cred_def_id = rev_reg_def.cred_def_id.to_unqualified();
-        build_result!(RevRegDefOperation, Some(identifier), rev_reg_def)
+        build_result!(RevRegDefOperation, Some(identifier), refs [issuer_did.as_ref()], rev_reg_def)
     }
 
     #[logfn(Info)]
     pub fn build_get_revoc_reg_def_request(&self, identifier: Option<&DidValue>, id: &RevocationRegistryId) -> IndyResult<String> {
+        let issuer_did = LedgerService::_id_indy_did(&id.0);
         let id = id.to_unqualified();
-        build_result!(GetRevRegDefOperation, identifier, &id)
+        build_result!(GetRevRegDefOperation, identifier, refs [issuer_did.as_ref()], &id)
     }
 
     #[logfn(Info)]
     pub fn build_revoc_reg_entry_request(&self, identifier: &DidValue, revoc_reg_def_id: &RevocationRegistryId,
                                          revoc_def_type: &str, rev_reg_entry: RevocationRegistryDeltaV1) -> IndyResult<String> {
+        let issuer_did = LedgerService::_id_indy_did(&revoc_reg_def_id.0);
         let revoc_reg_def_id = revoc_reg_def_id.to_unqualified();
-        build_result!(RevRegEntryOperation, Some(identifier), revoc_def_type, &revoc_reg_def_id, rev_reg_entry)
+        build_result!(RevRegEntryOperation, Some(identifier), refs [issuer_did.as_ref()], revoc_def_type, &revoc_reg_def_id, rev_reg_entry)
     }
 
     #[logfn(Info)]
     pub fn build_get_revoc_reg_request(&self, identifier: Option<&DidValue>, revoc_reg_def_id: &RevocationRegistryId, timestamp: i64) -> IndyResult<String> {
+        let issuer_did = LedgerService::_id_indy_did(&revoc_reg_def_id.0);
         let revoc_reg_def_id = revoc_reg_def_id.to_unqualified();
-        build_result!(GetRevRegOperation, identifier, &revoc_reg_def_id, timestamp)
+        build_result!(GetRevRegOperation, identifier, refs [issuer_did.as_ref()], &revoc_reg_def_id, timestamp)
     }
 
     #[logfn(Info)]
     pub fn build_get_revoc_reg_delta_request(&self, identifier: Option<&DidValue>, revoc_reg_def_id: &RevocationRegistryId, from: Option<i64>, to: i64) -> IndyResult<String> {
+        let issuer_did = LedgerService::_id_indy_did(&revoc_reg_def_id.0);
         let revoc_reg_def_id = revoc_reg_def_id.to_unqualified();
-        build_result!(GetRevRegDeltaOperation, identifier, &revoc_reg_def_id, from, to)
+        build_result!(GetRevRegDeltaOperation, identifier, refs [issuer_did.as_ref()], &revoc_reg_def_id, from, to)
     }
 
     #[logfn(Info)]
@@ -405,6 +513,32 @@ impl LedgerService {
         build_result!(GetFrozenLedgersOperation, Some(submitter_did))
     }
 
+    /// Empty list is returned if there are no frozen ledgers.
+    #[logfn(Info)]
+    pub fn parse_get_frozen_ledgers_response(&self, get_frozen_ledgers_response: &str) -> IndyResult<String> {
+        let reply: Reply<GetFrozenLedgersResult> = LedgerService::parse_response(get_frozen_ledgers_response)?;
+
+        let mut data = reply.result().data
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(ledger_id, ledger)| {
+                let ledger_id = ledger_id.parse::<u64>()
+                    .to_indy(IndyErrorKind::InvalidTransaction, format!("Invalid frozen ledger id: {}", ledger_id))?;
+
+                Ok(FrozenLedger {
+                    ledger_id,
+                    ledger_root: ledger.ledger,
+                    state_root: ledger.state,
+                    seq_no: ledger.seq_no,
+                })
+            })
+            .collect::<IndyResult<Vec<FrozenLedger>>>()?;
+
+        data.sort_by_key(|ledger| ledger.ledger_id);
+
+        LedgerService::_serialize_parsed_reply(get_frozen_ledgers_response, data)
+    }
+
     #[logfn(Info)]
     pub fn build_auth_rules_request(&self, submitter_did: &DidValue, rules: AuthRules) -> IndyResult<String> {
         build_result!(AuthRulesOperation, Some(submitter_did), rules)
@@ -498,8 +632,83 @@ impl LedgerService {
         }
     }
 
+    #[logfn(Info)]
+    pub fn build_get_request_for_write(&self, request_json: &str) -> IndyResult<String> {
+        let request: Request<serde_json::Value> = serde_json::from_str(request_json)
+            .map_err(|err| IndyError::from_msg(IndyErrorKind::InvalidStructure, format!("Request is invalid json: {:?}", err)))?;
+
+        let identifier = request.identifier
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "Request doesn't contain identifier"))?;
+        let submitter_did = DidValue(identifier.0.clone());
+        let operation = &request.operation;
+
+        match operation["type"].as_str() {
+            Some(SCHEMA) => {
+                let data: GetSchemaOperationData = serde_json::from_value(operation["data"].clone())
+                    .to_indy(IndyErrorKind::InvalidStructure, "Invalid SCHEMA request data")?;
+                build_result!(GetSchemaOperation, Some(&submitter_did), identifier, data)
+            }
+            Some(CRED_DEF) => {
+                let ref_ = operation["ref"].as_i64()
+                    .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "Invalid CRED_DEF request ref"))?;
+                let signature_type = operation["signature_type"].as_str()
+                    .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "Invalid CRED_DEF request signature type"))?;
+                let tag = operation["tag"].as_str().map(String::from);
+                build_result!(GetCredDefOperation, Some(&submitter_did), ref_ as i32, signature_type.to_string(), identifier, tag)
+            }
+            Some(_) => Err(err_msg(IndyErrorKind::InvalidStructure, "Request does not match any type of idempotent writes: SCHEMA, CRED_DEF")),
+            None => Err(err_msg(IndyErrorKind::InvalidStructure, "No valid type field in request"))
+        }
+    }
+
+    #[logfn(Info)]
+    pub fn is_duplicate_txn_rejection(&self, response: &str) -> bool {
+        let message: Message<serde_json::Value> = match serde_json::from_str(response) {
+            Ok(message) => message,
+            Err(_) => return false
+        };
+
+        match message {
+            Message::Reject(response) | Message::ReqNACK(response) => {
+                let reason = response.reason.to_lowercase();
+                DUPLICATE_TXN_REASONS.iter().any(|pattern| reason.contains(pattern))
+            }
+            Message::Reply(_) => false
+        }
+    }
+
+    #[logfn(Info)]
+    pub fn is_txn_found(&self, get_response: &str) -> bool {
+        // GET_SCHEMA reply contains requested name and version as data even if schema isn't found,
+        // so sequence number of the transaction is checked instead.
+        serde_json::from_str::<serde_json::Value>(get_response)
+            .map(|response| response["op"] == json!("REPLY") && response["result"]["seqNo"].is_u64())
+            .unwrap_or(false)
+    }
+
+    #[logfn(Info)]
+    pub fn build_idempotent_submit_result(&self, response: &str, already_exists: bool) -> IndyResult<String> {
+        let response: serde_json::Value = serde_json::from_str(response)
+            .to_indy(IndyErrorKind::InvalidTransaction, "Response is invalid json")?;
+
+        let result = IdempotentSubmitResult { already_exists, response };
+
+        serde_json::to_string(&result)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize IdempotentSubmitResult")
+    }
+
     #[logfn(Info)]
     pub fn prepare_acceptance_data(&self, text: Option<&str>, version: Option<&str>, hash: Option<&str>, mechanism: &str, time: u64) -> IndyResult<TxnAuthrAgrmtAcceptanceData> {
+        let acceptance_data = TxnAuthrAgrmtAcceptanceData {
+            mechanism: mechanism.to_string(),
+            taa_digest: self._get_taa_digest(text, version, hash)?,
+            time: LedgerService::datetime_to_date_timestamp(time),
+        };
+
+        Ok(acceptance_data)
+    }
+
+    fn _get_taa_digest(&self, text: Option<&str>, version: Option<&str>, hash: Option<&str>) -> IndyResult<String> {
         let taa_digest = match (text, version, hash) {
             (None, None, None) => {
                 return Err(err_msg(IndyErrorKind::InvalidStructure, "Invalid combination of params: Either combination `text` + `version` or `taa_digest` must be passed."));
@@ -519,13 +728,67 @@ impl LedgerService {
             }
         };
 
-        let acceptance_data = TxnAuthrAgrmtAcceptanceData {
-            mechanism: mechanism.to_string(),
-            taa_digest,
-            time: LedgerService::datetime_to_date_timestamp(time),
+        Ok(taa_digest)
+    }
+
+    /// Extracts latest Transaction Author Agreement and Acceptance Mechanisms
+    /// from GET_TXN_AUTHR_AGRMT and GET_TXN_AUTHR_AGRMT_AML responses.
+    pub fn parse_txn_author_agreement_state(&self, taa_response: &str, aml_response: &str) -> IndyResult<TxnAuthorAgreementState> {
+        let taa_reply: Reply<GetTxnAuthorAgreementResult> = LedgerService::parse_response(taa_response)?;
+        let aml_reply: Reply<GetAcceptanceMechanismsResult> = LedgerService::parse_response(aml_response)?;
+
+        // Ledgers of old versions disable the agreement by setting an empty text
+        let taa = taa_reply.result().data
+            .filter(|taa| taa.text.as_ref().map(|text| !text.is_empty()).unwrap_or(true));
+
+        Ok(TxnAuthorAgreementState {
+            taa_time: taa.as_ref().and_then(|_| parse_response_metadata(taa_response).ok()).and_then(|metadata| metadata.txn_time),
+            taa,
+            aml: aml_reply.result().data.map(|aml| aml.aml),
+        })
+    }
+
+    /// Prepares acceptance of the latest Transaction Author Agreement. Returns `None` if no agreement is set on the ledger.
+    ///
+    /// Previous acceptance of the same agreement is reused to keep time of acceptance unchanged.
+    /// Mechanism can be omitted if it was used for the previous acceptance or if the ledger allows the only one.
+    /// Time of acceptance can't precede the agreement transaction, otherwise the ledger rejects the request.
+    pub fn prepare_taa_acceptance(&self,
+                                  state: &TxnAuthorAgreementState,
+                                  accepted: Option<&TxnAuthrAgrmtAcceptanceData>,
+                                  mechanism: Option<&str>,
+                                  now: u64) -> IndyResult<Option<TxnAuthrAgrmtAcceptanceData>> {
+        let taa = match state.taa {
+            Some(ref taa) => taa,
+            None => return Ok(None)
         };
 
-        Ok(acceptance_data)
+        let version = taa.text.as_ref().map(|_| taa.version.as_str());
+        let taa_digest = self._get_taa_digest(taa.text.as_deref(), version, taa.digest.as_deref())?;
+
+        let accepted = accepted.filter(|accepted| accepted.taa_digest == taa_digest);
+
+        let mechanism = match (mechanism, accepted, state.aml.as_ref()) {
+            (Some(mechanism), _, _) => mechanism.to_string(),
+            (None, Some(accepted), _) => accepted.mechanism.clone(),
+            (None, None, Some(aml)) if aml.0.len() == 1 => aml.0.keys().next().cloned().unwrap_or_default(),
+            (None, None, _) =>
+                return Err(err_msg(IndyErrorKind::InvalidStructure, "Acceptance mechanism must be specified as the ledger allows several ones"))
+        };
+
+        if let Some(ref aml) = state.aml {
+            if !aml.0.contains_key(&mechanism) {
+                return Err(err_msg(IndyErrorKind::InvalidStructure,
+                                   format!("Acceptance mechanism {} isn't allowed by the ledger", mechanism)));
+            }
+        }
+
+        let time = match accepted {
+            Some(accepted) if accepted.mechanism == mechanism => accepted.time,
+            _ => ::std::cmp::max(now, state.taa_time.unwrap_or(0))
+        };
+
+        self.prepare_acceptance_data(None, None, Some(&taa_digest), &mechanism, time).map(Some)
     }
 
     fn datetime_to_date_timestamp(time: u64) -> u64 {
@@ -533,6 +796,28 @@ impl LedgerService {
         time / SEC_IN_DAY * SEC_IN_DAY
     }
 
+    // Ledger belongs to the single did:indy namespace so submitter and DIDs referenced by the request
+    // can't be taken from different ones.
+    fn _check_indy_namespaces(identifier: Option<&DidValue>, dids: &[Option<&DidValue>]) -> IndyResult<()> {
+        let mut namespaced = identifier.into_iter()
+            .chain(dids.iter().filter_map(|did| *did))
+            .filter_map(|did| did.get_namespace().map(|namespace| (did, namespace)));
+
+        if let Some((did, namespace)) = namespaced.next() {
+            if let Some((other_did, _)) = namespaced.find(|(_, other_namespace)| *other_namespace != namespace) {
+                return Err(err_msg(IndyErrorKind::InvalidStructure,
+                                   format!("DIDs {} and {} of the request belong to different did:indy namespaces", did.0, other_did.0)));
+            }
+        }
+
+        Ok(())
+    }
+
+    // Qualified IDs keep only "indy" method in the prefix, so the namespace is taken from the issuer DID inside
+    fn _id_indy_did(id: &str) -> Option<DidValue> {
+        ID_INDY_DID_REGEX.find(id).map(|did| DidValue(did.as_str().to_string()))
+    }
+
     fn _calculate_hash(&self, text: &str, version: &str) -> IndyResult<Vec<u8>> {
         let content: String = version.to_string() + text;
         openssl_hash(content.as_bytes())
@@ -564,412 +849,1412 @@ impl LedgerService {
 
         Ok(res)
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use crate::domain::anoncreds::schema::AttributeNames;
-    use crate::domain::ledger::constants::*;
-    use crate::domain::ledger::node::Services;
-    use crate::domain::ledger::request::ProtocolVersion;
 
-    use super::*;
+    pub fn register_txn_builder(txn_type: &str, builder: CustomTransactionBuilder,
+                                parser: Option<CustomResponseParser>, free: CustomFree) -> IndyResult<()> {
+        if REQUESTS.contains(&txn_type) || txn_name_to_code(txn_type) != Some(txn_type) {
+            return Err(err_msg(IndyErrorKind::InvalidStructure,
+                               format!("Try to override builder for default TXN_TYPE {}", txn_type)));
+        }
 
-    const IDENTIFIER: &str = "NcYxiDXkpYi6ov5FcYDi1e";
-    const DEST: &str = "VsKV7grR1BUE29mG2Fm2kX";
-    const VERKEY: &str = "CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW";
+        REGISTERED_TXN_BUILDERS.lock()
+            .map(|mut map| {
+                map.insert(txn_type.to_owned(), (builder, parser, free));
+            })
+            .unwrap();
 
-    fn identifier() -> DidValue {
-        DidValue(IDENTIFIER.to_string())
+        Ok(())
     }
 
-    fn dest() -> DidValue {
-        DidValue(DEST.to_string())
+    fn _get_txn_builder(txn_type: &str) -> IndyResult<TxnBuilderCallbacks> {
+        REGISTERED_TXN_BUILDERS.lock().unwrap()
+            .get(txn_type)
+            .cloned()
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, format!("No builder registered for TXN_TYPE {}", txn_type)))
     }
 
-    #[test]
-    fn build_nym_request_works_for_only_required_fields() {
-        let ledger_service = LedgerService::new();
+    /// Calls plugged callback and copies returned string before deallocating it by `free` callback.
+    fn _call_txn_plugin(plugin: extern fn(*const c_char, *mut *const c_char) -> ErrorCode,
+                        free: CustomFree, input: &str) -> IndyResult<String> {
+        let input = CString::new(input)
+            .to_indy(IndyErrorKind::InvalidStructure, "Plugin input contains null character")?;
 
-        let expected_result = json!({
-            "type": NYM,
-            "dest": DEST
-        });
+        let mut output = ::std::ptr::null();
+        let err = plugin(input.as_ptr(), &mut output);
 
-        let request = ledger_service.build_nym_request(&identifier(), &dest(), None, None, None).unwrap();
-        check_request(&request, expected_result);
-    }
+        if err != ErrorCode::Success {
+            return Err(err.into());
+        }
 
-    #[test]
-    fn build_nym_request_works_for_empty_role() {
-        let ledger_service = LedgerService::new();
+        if output.is_null() {
+            return Err(err_msg(IndyErrorKind::InvalidState, "Plugged transaction callback returned null"));
+        }
 
-        let expected_result = json!({
-            "type": NYM,
-            "dest": DEST,
-            "role": serde_json::Value::Null,
-        });
+        let res = unsafe { CStr::from_ptr(output) }.to_str()
+            .map(String::from)
+            .to_indy(IndyErrorKind::InvalidState, "Plugged transaction callback returned invalid string");
 
-        let request = ledger_service.build_nym_request(&identifier(), &dest(), None, None, Some("")).unwrap();
-        check_request(&request, expected_result);
+        let err = free(output);
+        trace!("_call_txn_plugin: plugin free res {:?}", err);
+
+        res
     }
 
-    #[test]
-    fn build_nym_request_works_for_optional_fields() {
-        let ledger_service = LedgerService::new();
+    #[logfn(Info)]
+    pub fn build_custom_request(&self, identifier: Option<&DidValue>, txn_type: &str, data_json: &str) -> IndyResult<String> {
+        let (builder, _, free) = LedgerService::_get_txn_builder(txn_type)?;
 
-        let expected_result = json!({
-            "type": NYM,
-            "dest": DEST,
-            "role": serde_json::Value::Null,
-            "alias": "some_alias",
-            "verkey": VERKEY,
-        });
+        let operation = LedgerService::_call_txn_plugin(builder, free, data_json)?;
 
-        let request = ledger_service.build_nym_request(&identifier(), &dest(), Some(VERKEY), Some("some_alias"), Some("")).unwrap();
-        check_request(&request, expected_result);
-    }
+        let mut operation: Value = serde_json::from_str(&operation)
+            .to_indy(IndyErrorKind::InvalidState, "Plugged transaction builder returned invalid json")?;
 
-    #[test]
-    fn build_get_nym_request_works() {
-        let ledger_service = LedgerService::new();
+        if !operation.is_object() {
+            return Err(err_msg(IndyErrorKind::InvalidState, "Plugged transaction builder returned not an object"));
+        }
 
-        let expected_result = json!({
-            "type": GET_NYM,
-            "dest": DEST
-        });
+        operation["type"] = json!(txn_type);
 
-        let request = ledger_service.build_get_nym_request(Some(&identifier()), &dest()).unwrap();
-        check_request(&request, expected_result);
+        Request::build_request(identifier, operation)
+            .map_err(|err| IndyError::from_msg(IndyErrorKind::InvalidState, err))
     }
 
-    #[test]
-    fn build_get_ddo_request_works() {
-        let ledger_service = LedgerService::new();
+    #[logfn(Info)]
+    pub fn parse_custom_response(&self, txn_type: &str, response: &str) -> IndyResult<String> {
+        let (_, parser, free) = LedgerService::_get_txn_builder(txn_type)?;
 
-        let expected_result = json!({
-            "type": GET_DDO,
-            "dest": DEST
-        });
+        let parser = parser
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, format!("No response parser registered for TXN_TYPE {}", txn_type)))?;
 
-        let request = ledger_service.build_get_ddo_request(Some(&identifier()), &dest()).unwrap();
-        check_request(&request, expected_result);
+        let message: Message<Value> = serde_json::from_str(response)
+            .to_indy(IndyErrorKind::InvalidTransaction, "Response is invalid json")?;
+
+        match message {
+            Message::Reject(response) | Message::ReqNACK(response) =>
+                return Err(err_msg(IndyErrorKind::InvalidTransaction, format!("Transaction has been failed: {:?}", response.reason))),
+            Message::Reply(Reply::ReplyV0(ref reply)) if reply.result["type"] != json!(txn_type) =>
+                return Err(err_msg(IndyErrorKind::InvalidTransaction, "Invalid response type")),
+            Message::Reply(_) => {}
+        }
+
+        LedgerService::_call_txn_plugin(parser, free, response)
     }
 
-    #[test]
-    fn build_attrib_request_works_for_hash_field() {
-        let ledger_service = LedgerService::new();
+    /// Calls plugged request middleware and checks it returned a valid request.
+    pub fn apply_request_middleware(&self, middleware: CustomRequestMiddleware, free: CustomFree, request_json: &str) -> IndyResult<String> {
+        let request_json = LedgerService::_call_txn_plugin(middleware, free, request_json)?;
 
-        let expected_result = json!({
-            "type": ATTRIB,
-            "dest": DEST,
-            "hash": "hash"
-        });
+        serde_json::from_str::<Request<Value>>(&request_json)
+            .to_indy(IndyErrorKind::InvalidState, "Plugged request middleware returned invalid request")?;
 
-        let request = ledger_service.build_attrib_request(&identifier(), &dest(), Some("hash"), None, None).unwrap();
-        check_request(&request, expected_result);
+        Ok(request_json)
     }
 
-    #[test]
-    fn build_get_attrib_request_works_for_raw_value() {
-        let ledger_service = LedgerService::new();
+    /// Calls plugged reply middleware and checks it returned a valid json.
+    pub fn apply_reply_middleware(&self, middleware: CustomReplyMiddleware, free: CustomFree, reply_json: &str) -> IndyResult<String> {
+        let reply_json = LedgerService::_call_txn_plugin(middleware, free, reply_json)?;
 
-        let expected_result = json!({
-            "type": GET_ATTR,
-            "dest": DEST,
-            "raw": "raw"
-        });
+        serde_json::from_str::<Value>(&reply_json)
+            .to_indy(IndyErrorKind::InvalidState, "Plugged reply middleware returned invalid json")?;
 
-        let request = ledger_service.build_get_attrib_request(Some(&identifier()), &dest(), Some("raw"), None, None).unwrap();
-        check_request(&request, expected_result);
+        Ok(reply_json)
     }
 
-    #[test]
-    fn build_get_attrib_request_works_for_hash_value() {
-        let ledger_service = LedgerService::new();
+    /// Sets protocol version of a built request so it doesn't depend on the global PROTOCOL_VERSION.
+    /// Credential definition tag isn't supported by protocol version 1 and is dropped.
+    #[logfn(Info)]
+    pub fn set_request_protocol_version(&self, request_json: &str, protocol_version: usize) -> IndyResult<String> {
+        if protocol_version != 1 && protocol_version != 2 {
+            return Err(err_msg(IndyErrorKind::PoolIncompatibleProtocolVersion, format!("Unsupported Protocol version: {}", protocol_version)));
+        }
 
-        let expected_result = json!({
-            "type": GET_ATTR,
-            "dest": DEST,
-            "hash": "hash"
-        });
+        let mut request: Value = serde_json::from_str(request_json)
+            .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize request")?;
 
-        let request = ledger_service.build_get_attrib_request(Some(&identifier()), &dest(), None, Some("hash"), None).unwrap();
-        check_request(&request, expected_result);
-    }
+        if !request["signature"].is_null() || !request["signatures"].is_null() {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "Protocol version of signed request can't be changed"));
+        }
 
-    #[test]
-    fn build_get_attrib_request_works_for_enc_value() {
-        let ledger_service = LedgerService::new();
+        if request["operation"]["type"].as_str() == Some(CRED_DEF) {
+            match (protocol_version, request["operation"].as_object_mut()) {
+                (1, Some(operation)) => { operation.remove("tag"); }
+                (_, Some(operation)) if !operation.contains_key("tag") =>
+                    return Err(err_msg(IndyErrorKind::InvalidStructure, "Credential definition request doesn't contain tag")),
+                _ => {}
+            }
+        }
 
-        let expected_result = json!({
-            "type": GET_ATTR,
-            "dest": DEST,
-            "enc": "enc"
-        });
+        request["protocolVersion"] = json!(protocol_version);
 
-        let request = ledger_service.build_get_attrib_request(Some(&identifier()), &dest(), None, None, Some("enc")).unwrap();
-        check_request(&request, expected_result);
+        serde_json::to_string(&request)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize request")
     }
 
-    #[test]
-    fn build_schema_request_works() {
-        let ledger_service = LedgerService::new();
-
-        let mut attr_names: AttributeNames = AttributeNames::new();
-        attr_names.0.insert("male".to_string());
+    #[logfn(Info)]
+    pub fn merge_multi_signed_requests(&self, requests: Vec<Value>) -> IndyResult<String> {
+        let mut merged: Option<Value> = None;
+        let mut signatures = serde_json::Map::new();
+
+        for mut request in requests {
+            for (did, signature) in self.get_request_signatures(&request)? {
+                match signatures.get(&did) {
+                    Some(existing) if existing != &Value::String(signature.clone()) =>
+                        return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Requests contain different signatures of {}", did))),
+                    _ => { signatures.insert(did, Value::String(signature)); }
+                }
+            }
 
-        let data = SchemaV1 {
-            id: SchemaId::new(&identifier(), "name", "1.0"),
-            name: "name".to_string(),
-            version: "1.0".to_string(),
-            attr_names,
-            seq_no: None,
-        };
+            if let Some(request) = request.as_object_mut() {
+                request.remove("signature");
+                request.remove("signatures");
+            }
 
-        let expected_result = json!({
-            "type": SCHEMA,
-            "data": {
-                "name": "name",
-                "version": "1.0",
-                "attr_names": ["male"]
+            match merged {
+                Some(ref merged) if merged != &request =>
+                    return Err(err_msg(IndyErrorKind::InvalidStructure, "Requests to merge have different content")),
+                Some(_) => {}
+                None => merged = Some(request)
             }
-        });
+        }
 
-        let request = ledger_service.build_schema_request(&identifier(), data).unwrap();
-        check_request(&request, expected_result);
+        let mut merged = merged
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "No requests to merge"))?;
+
+        merged["signatures"] = Value::Object(signatures);
+
+        serde_json::to_string(&merged)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize merged request")
     }
 
-    #[test]
-    fn build_get_schema_request_works_for_valid_id() {
-        let ledger_service = LedgerService::new();
+    /// Returns pairs of signer DID and signature for both single and multi signed requests.
+    pub fn get_request_signatures(&self, request: &Value) -> IndyResult<Vec<(String, String)>> {
+        let request = request.as_object()
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "Request isn't json object"))?;
 
-        let id = SchemaId::new(&identifier(), "name", "1.0");
+        let mut signatures: Vec<(String, String)> = Vec::new();
 
-        let expected_result = json!({
-            "type": GET_SCHEMA,
-            "dest": IDENTIFIER,
-            "data": {
-                "name": "name",
-                "version": "1.0"
+        if let Some(signature) = request.get("signature") {
+            let identifier = request.get("identifier").and_then(Value::as_str)
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "Signed request doesn't contain identifier"))?;
+
+            let signature = signature.as_str()
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "Request signature isn't string"))?;
+
+            signatures.push((identifier.to_string(), signature.to_string()));
+        }
+
+        if let Some(multi_signatures) = request.get("signatures") {
+            let multi_signatures = multi_signatures.as_object()
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "Request signatures isn't json object"))?;
+
+            for (did, signature) in multi_signatures {
+                let signature = signature.as_str()
+                    .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, format!("Request signature of {} isn't string", did)))?;
+
+                if !signatures.iter().any(|(signer, _)| signer == did) {
+                    signatures.push((did.to_string(), signature.to_string()));
+                }
             }
-        });
+        }
 
-        let request = ledger_service.build_get_schema_request(Some(&identifier()), &id).unwrap();
-        check_request(&request, expected_result);
+        Ok(signatures)
     }
 
-    #[test]
-    fn build_get_cred_def_request_works() {
-        ProtocolVersion::set(2);
+    /// Checks signers of the request against ADD auth rule of the request transaction type.
+    /// Request author (identifier) is considered as an owner of the transaction.
+    pub fn build_request_signatures_report(&self, request: &Value, auth_rules: &[AuthRule], signers: Vec<RequestSigner>) -> IndyResult<RequestSignaturesReport> {
+        trace!("build_request_signatures_report >>> request: {:?}, auth_rules: {:?}, signers: {:?}", request, auth_rules, signers);
 
-        let ledger_service = LedgerService::new();
+        let txn_type = request["operation"]["type"].as_str()
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "Request doesn't contain transaction type"))?;
 
-        let id = CredentialDefinitionId::new(&identifier(), &SchemaId("1".to_string()), "signature_type", "tag");
+        let auth_rule = LedgerService::_find_add_auth_rule(auth_rules, txn_type, &request["operation"])
+            .ok_or_else(|| err_msg(IndyErrorKind::LedgerItemNotFound, format!("Auth rule not found for transaction type {}", txn_type)))?;
 
-        let expected_result = json!({
-            "type": GET_CRED_DEF,
-            "ref": 1,
-            "signature_type": "signature_type",
-            "origin": IDENTIFIER,
-            "tag":"tag"
-        });
+        let identifier = request["identifier"].as_str();
 
-        let request = ledger_service.build_get_cred_def_request(Some(&identifier()), &id).unwrap();
-        check_request(&request, expected_result);
+        let missing_signers: Vec<String> = identifier.into_iter()
+            .chain(request["endorser"].as_str())
+            .filter(|did| !signers.iter().any(|signer| signer.did == *did))
+            .map(String::from)
+            .collect();
+
+        let missing_signatures = auth_rule.constraint.missing_signatures(&signers, identifier);
+        let forbidden = missing_signatures.is_none();
+        let missing_signatures = missing_signatures.unwrap_or_default();
+
+        let complete = !forbidden
+            && missing_signers.is_empty()
+            && missing_signatures.is_empty()
+            && signers.iter().all(|signer| !signer.on_ledger || signer.verified);
+
+        let res = RequestSignaturesReport {
+            txn_type: txn_type.to_string(),
+            auth_rule: auth_rule.clone(),
+            signers,
+            missing_signers,
+            missing_signatures,
+            forbidden,
+            complete,
+        };
+
+        trace!("build_request_signatures_report <<< {:?}", res);
+
+        Ok(res)
     }
 
-    #[test]
-    fn build_node_request_works() {
-        let ledger_service = LedgerService::new();
+    fn _find_add_auth_rule<'a>(auth_rules: &'a [AuthRule], txn_type: &str, operation: &Value) -> Option<&'a AuthRule> {
+        let field_value = |field: &str| match operation.get(field) {
+            None | Some(Value::Null) => EMPTY_VALUE.to_string(),
+            Some(Value::String(value)) => value.to_string(),
+            Some(value) => value.to_string()
+        };
 
-        let data = NodeOperationData {
-            node_ip: Some("ip".to_string()),
-            node_port: Some(1),
-            client_ip: Some("ip".to_string()),
-            client_port: Some(1),
-            alias: "some".to_string(),
-            services: Some(vec![Services::VALIDATOR]),
-            blskey: Some("blskey".to_string()),
-            blskey_pop: Some("pop".to_string()),
+        auth_rules.iter()
+            .filter(|rule| rule.auth_type == txn_type && rule.auth_action == AuthAction::ADD.as_str() && rule.field != ANY_FIELD)
+            .find_map(|rule| LedgerService::_find_auth_rule(auth_rules, txn_type, &AuthAction::ADD, &rule.field, None, Some(&field_value(&rule.field)))
+                .filter(|rule| rule.field != ANY_FIELD))
+            .or_else(|| LedgerService::_find_auth_rule(auth_rules, txn_type, &AuthAction::ADD, ANY_FIELD, None, None))
+    }
+
+    /// Finds the most specific auth rule for the action.
+    /// Rules with exact field values take precedence over rules with `*` values and rules for any field.
+    fn _find_auth_rule<'a>(auth_rules: &'a [AuthRule], auth_type: &str, auth_action: &AuthAction, field: &str,
+                           old_value: Option<&str>, new_value: Option<&str>) -> Option<&'a AuthRule> {
+        let value_matches = |rule_value: &Option<String>, value: Option<&str>, wildcard: bool| {
+            let rule_value = rule_value.as_deref().unwrap_or(EMPTY_VALUE);
+            (wildcard && rule_value == ANY_VALUE) || rule_value == value.unwrap_or(EMPTY_VALUE)
         };
 
-        let expected_result = json!({
-            "type": NODE,
-            "dest": DEST,
-            "data": {
-                "node_ip": "ip",
-                "node_port": 1,
-                "client_ip": "ip",
-                "client_port": 1,
-                "alias": "some",
-                "services": ["VALIDATOR"],
-                "blskey": "blskey",
-                "blskey_pop": "pop"
-            }
-        });
+        let rules: Vec<&AuthRule> = auth_rules.iter()
+            .filter(|rule| rule.auth_type == auth_type && rule.auth_action == auth_action.as_str())
+            .collect();
 
-        let request = ledger_service.build_node_request(&identifier(), &dest(), data).unwrap();
-        check_request(&request, expected_result);
+        let find = |wildcard: bool| rules.iter()
+            .find(|rule| rule.field == field
+                && value_matches(&rule.new_value, new_value, wildcard)
+                && (*auth_action == AuthAction::ADD || value_matches(&rule.old_value, old_value, wildcard)))
+            .copied();
+
+        find(false)
+            .or_else(|| find(true))
+            .or_else(|| rules.iter().find(|rule| rule.field == ANY_FIELD).copied())
     }
 
-    #[test]
-    fn build_get_txn_request_works() {
-        let ledger_service = LedgerService::new();
+    #[logfn(Info)]
+    pub fn evaluate_auth_rules(&self, auth_rules: &[AuthRule], action: &AuthRuleAction, signers: &[RequestSigner]) -> IndyResult<AuthRuleEvaluation> {
+        let auth_type = txn_name_to_code(&action.auth_type)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, format!("Unsupported `auth_type`: {}", action.auth_type)))?;
+
+        let auth_rule = LedgerService::_find_auth_rule(auth_rules, auth_type, &action.auth_action, &action.field,
+                                                       action.old_value.as_deref(), action.new_value.as_deref())
+            .ok_or_else(|| err_msg(IndyErrorKind::LedgerItemNotFound, format!("Auth rule not found for {} action of transaction type {}",
+                                                                             action.auth_action.as_str(), auth_type)))?;
+
+        let missing_signatures = auth_rule.constraint.missing_signatures(signers, action.owner.as_deref());
+        let forbidden = missing_signatures.is_none();
+        let missing_signatures = missing_signatures.unwrap_or_default();
+
+        Ok(AuthRuleEvaluation {
+            allowed: !forbidden && missing_signatures.is_empty(),
+            auth_rule: auth_rule.clone(),
+            missing_signatures,
+            forbidden,
+        })
+    }
 
-        let expected_result = json!({
-            "type": GET_TXN,
-            "data": 1,
-            "ledgerId": 1
-        });
+    #[logfn(Info)]
+    pub fn build_auth_rules_page(&self, mut auth_rules: Vec<AuthRule>, options: &AuthRulesPageOptions) -> IndyResult<AuthRulesPage> {
+        if options.limit == Some(0) {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "Auth rules page limit must be positive"));
+        }
 
-        let request = ledger_service.build_get_txn_request(Some(&identifier()), None, 1).unwrap();
-        check_request(&request, expected_result);
+        // Ledger doesn't guarantee the order of rules, so pages are built over the sorted list
+        auth_rules.sort_by(|rule, other| LedgerService::_auth_rule_key(rule).cmp(&LedgerService::_auth_rule_key(other)));
+
+        let total = auth_rules.len();
+        let offset = options.offset.unwrap_or(0).min(total);
+        let end = options.limit.map(|limit| offset.saturating_add(limit).min(total)).unwrap_or(total);
+
+        let rules: Vec<AuthRule> = auth_rules.drain(offset..end).collect();
+
+        Ok(AuthRulesPage {
+            rules,
+            total,
+            next_offset: if end < total { Some(end) } else { None },
+        })
     }
 
-    #[test]
-    fn build_get_txn_request_works_for_ledger_type_as_predefined_string_constant() {
-        let ledger_service = LedgerService::new();
+    #[logfn(Info)]
+    pub fn diff_auth_rules(&self, current: &[AuthRule], proposed: &[AuthRule]) -> IndyResult<AuthRulesDiff> {
+        let current = LedgerService::_auth_rules_by_key(current, "current")?;
+        let proposed = LedgerService::_auth_rules_by_key(proposed, "proposed")?;
+
+        let mut diff = AuthRulesDiff { added: Vec::new(), removed: Vec::new(), changed: Vec::new(), unchanged: 0 };
+
+        for (key, proposed_rule) in proposed.iter() {
+            match current.get(key) {
+                Some(current_rule) if current_rule.constraint.is_equivalent(&proposed_rule.constraint) => diff.unchanged += 1,
+                Some(current_rule) => diff.changed.push(AuthRuleChange {
+                    current: (*current_rule).clone(),
+                    proposed: (*proposed_rule).clone(),
+                }),
+                None => diff.added.push((*proposed_rule).clone())
+            }
+        }
 
-        let expected_result = json!({
-            "type": GET_TXN,
-            "data": 1,
-            "ledgerId": 0
-        });
+        diff.removed = current.iter()
+            .filter(|(key, _)| !proposed.contains_key(*key))
+            .map(|(_, rule)| (*rule).clone())
+            .collect();
 
-        let request = ledger_service.build_get_txn_request(Some(&identifier()), Some("POOL"), 1).unwrap();
-        check_request(&request, expected_result);
+        Ok(diff)
     }
 
-    #[test]
-    fn build_get_txn_request_works_for_ledger_type_as_number() {
-        let ledger_service = LedgerService::new();
+    fn _auth_rules_by_key<'a>(auth_rules: &'a [AuthRule], name: &str) -> IndyResult<BTreeMap<AuthRuleKey, &'a AuthRule>> {
+        let mut rules = BTreeMap::new();
 
-        let expected_result = json!({
-            "type": GET_TXN,
-            "data": 1,
-            "ledgerId": 10
-        });
+        for rule in auth_rules {
+            if rules.insert(LedgerService::_auth_rule_key(rule), rule).is_some() {
+                return Err(err_msg(IndyErrorKind::InvalidStructure,
+                                   format!("Duplicate {} auth rule for {} action of transaction type {} on field {}",
+                                           name, rule.auth_action, rule.auth_type, rule.field)));
+            }
+        }
 
-        let request = ledger_service.build_get_txn_request(Some(&identifier()), Some("10"), 1).unwrap();
-        check_request(&request, expected_result);
+        Ok(rules)
     }
 
-    #[test]
-    fn build_get_txn_request_works_for_invalid_type() {
-        let ledger_service = LedgerService::new();
+    // Transaction aliases are replaced with codes and old value is ignored for ADD action
+    fn _auth_rule_key(rule: &AuthRule) -> AuthRuleKey {
+        let old_value = if rule.auth_action == AuthAction::EDIT.as_str() { rule.old_value.clone() } else { None };
 
-        let res = ledger_service.build_get_txn_request(Some(&identifier()), Some("type"), 1);
-        assert_kind!(IndyErrorKind::InvalidStructure, res);
+        (txn_name_to_code(&rule.auth_type).unwrap_or(rule.auth_type.as_str()).to_string(),
+         rule.auth_action.clone(),
+         rule.field.clone(),
+         old_value,
+         rule.new_value.clone())
     }
 
-    #[test]
-    fn validate_action_works_for_pool_restart() {
-        let ledger_service = LedgerService::new();
-        let request = ledger_service.build_pool_restart(&identifier(), "start", None).unwrap();
-        ledger_service.validate_action(&request).unwrap();
+    #[logfn(Info)]
+    pub fn build_role_constraint(&self, role: &str, sig_count: u32, need_to_be_owner: bool, off_ledger_signature: bool,
+                                 metadata: Option<Value>) -> IndyResult<String> {
+        let role = match role {
+            "STEWARD" => STEWARD,
+            "TRUSTEE" => TRUSTEE,
+            "TRUST_ANCHOR" | "ENDORSER" => ENDORSER,
+            "NETWORK_MONITOR" => NETWORK_MONITOR,
+            "IDENTITY_OWNER" | IDENTITY_OWNER_ROLE => IDENTITY_OWNER_ROLE,
+            ANY_ROLE => ANY_ROLE,
+            role if ROLES.contains(&role) => role,
+            role => return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Invalid role: {}", role)))
+        };
+
+        if off_ledger_signature && role != ANY_ROLE {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "Off ledger signature can be allowed only for any role"));
+        }
+
+        LedgerService::_serialize_constraint(Constraint::RoleConstraint(RoleConstraint {
+            sig_count,
+            role: Some(role.to_string()),
+            metadata,
+            need_to_be_owner,
+            off_ledger_signature,
+        }))
     }
 
-    #[test]
-    fn validate_action_works_for_get_validator_info() {
-        let ledger_service = LedgerService::new();
-        let request = ledger_service.build_get_validator_info_request(&identifier()).unwrap();
-        ledger_service.validate_action(&request).unwrap();
+    #[logfn(Info)]
+    pub fn build_combined_constraint(&self, combination: &str, constraints: Vec<Constraint>) -> IndyResult<String> {
+        if constraints.is_empty() {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "Combined constraint must contain at least one constraint"));
+        }
+
+        let constraints = CombinationConstraint { auth_constraints: constraints };
+
+        let constraint = match combination {
+            "AND" => Constraint::AndConstraint(constraints),
+            "OR" => Constraint::OrConstraint(constraints),
+            combination => return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Invalid constraint combination: {}", combination)))
+        };
+
+        LedgerService::_serialize_constraint(constraint)
     }
 
-    #[test]
-    fn build_ledgers_freeze_request_work_with_valid_data() {
-        let ledger_service = LedgerService::new();
-        let ledgers_ids = vec![0,1,50,873];
-        let res = ledger_service.build_ledgers_freeze_request(&identifier(), ledgers_ids);
+    fn _serialize_constraint(constraint: Constraint) -> IndyResult<String> {
+        serde_json::to_string(&constraint)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize constraint")
+    }
 
-        match res {
-            Ok(_) => {},
-            Err(ec) => {
-                assert!(false, "build_ledgers_freeze_request_work_with_valid_data returned error_code {:?}", ec);
+    #[logfn(Info)]
+    pub fn parse_get_attrib_response(&self, get_attrib_response: &str) -> IndyResult<String> {
+        let reply: Reply<GetAttribReplyResult> = LedgerService::parse_response(get_attrib_response)?;
+
+        let data = match reply.result() {
+            GetAttribReplyResult::GetAttribReplyResultV0(res) => {
+                let data = res.data
+                    .ok_or_else(|| err_msg(IndyErrorKind::LedgerItemNotFound, "Attrib not found"))?;
+
+                // V0 reply contains requested attrib name (or hash) in the field matching attrib kind
+                let (raw, hash, enc) = match (res.raw, res.hash, res.enc) {
+                    (Some(_), _, _) => (Some(data), None, None),
+                    (None, Some(_), _) => (None, Some(data), None),
+                    (None, None, Some(_)) => (None, None, Some(data)),
+                    (None, None, None) => return Err(err_msg(IndyErrorKind::InvalidTransaction, "GET_ATTRIB response doesn't contain attrib kind"))
+                };
+
+                AttribReplyData { did: res.dest, raw, hash, enc }
             }
-        }
+            GetAttribReplyResult::GetAttribReplyResultV1(res) => {
+                AttribReplyData {
+                    did: res.txn.data.did,
+                    raw: res.txn.data.raw,
+                    hash: res.txn.data.hash,
+                    enc: res.txn.data.enc,
+                }
+            }
+        };
+
+        LedgerService::_serialize_parsed_reply(get_attrib_response, data)
     }
 
-    #[test]
-    fn build_get_frozen_ledgers_request_work_with_valid_data() {
-        let ledger_service = LedgerService::new();
-        let res = ledger_service.build_get_frozen_ledgers_request(&identifier());
+    #[logfn(Info)]
+    pub fn parse_get_txn_response(&self, get_txn_response: &str) -> IndyResult<String> {
+        let reply = self.parse_get_txn_reply(get_txn_response)?
+            .ok_or_else(|| err_msg(IndyErrorKind::LedgerItemNotFound, "Transaction not found"))?;
 
-        match res {
-            Ok(_) => {},
-            Err(ec) => {
-                assert!(false, "build_get_frozen_ledgers_request_work_with_valid_data returned error_code {:?}", ec);
-            }
-        }
+        serde_json::to_string(&reply)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize GET_TXN data")
     }
 
-    #[test]
-    fn build_ledgers_freeze_request_work_with_empty_data() {
-        let ledger_service = LedgerService::new();
-        let ledgers_ids = vec![];
-        let res = ledger_service.build_ledgers_freeze_request(&identifier(), ledgers_ids);
+    /// Returns `None` if the requested transaction isn't written to the ledger.
+    pub fn parse_get_txn_reply(&self, get_txn_response: &str) -> IndyResult<Option<ParsedReply<TxnReplyData>>> {
+        let reply: Reply<GetTxnReplyResult> = LedgerService::parse_response(get_txn_response)?;
 
-        match res {
-            Ok(_) => {},
-            Err(ec) => {
-                assert!(false, "build_ledgers_freeze_request_work_with_empty_data returned error_code {:?}", ec);
-            }
-        }
+        // Metadata of the reply itself doesn't contain transaction time
+        Ok(reply.result().data.map(|data| ParsedReply {
+            data: data.txn,
+            seq_no: data.txn_metadata.seq_no,
+            txn_time: data.txn_metadata.txn_time,
+        }))
     }
 
-    mod auth_rule {
-        use super::*;
+    #[logfn(Info)]
+    pub fn parse_get_auth_rule_typed_response(&self, get_auth_rule_response: &str) -> IndyResult<String> {
+        let reply: Reply<GetAuthRuleResult> = LedgerService::parse_response(get_auth_rule_response)?;
 
-        const ADD_AUTH_ACTION: &str = "ADD";
-        const EDIT_AUTH_ACTION: &str = "EDIT";
-        const FIELD: &str = "role";
-        const OLD_VALUE: &str = "0";
-        const NEW_VALUE: &str = "101";
+        LedgerService::_serialize_parsed_reply(get_auth_rule_response, reply.result().data)
+    }
 
-        fn _role_constraint() -> Constraint {
-            Constraint::RoleConstraint(RoleConstraint {
-                sig_count: 0,
-                metadata: None,
-                role: Some(String::new()),
-                need_to_be_owner: false,
-                off_ledger_signature: false,
+    #[logfn(Info)]
+    pub fn parse_get_txn_author_agreement_response(&self, get_txn_author_agreement_response: &str) -> IndyResult<String> {
+        let reply: Reply<GetTxnAuthorAgreementResult> = LedgerService::parse_response(get_txn_author_agreement_response)?;
+
+        let data = reply.result().data
+            .ok_or_else(|| err_msg(IndyErrorKind::LedgerItemNotFound, "Transaction Author Agreement not found"))?;
+
+        LedgerService::_serialize_parsed_reply(get_txn_author_agreement_response, data)
+    }
+
+    #[logfn(Info)]
+    pub fn parse_get_acceptance_mechanisms_response(&self, get_acceptance_mechanisms_response: &str) -> IndyResult<String> {
+        let reply: Reply<GetAcceptanceMechanismsResult> = LedgerService::parse_response(get_acceptance_mechanisms_response)?;
+
+        let data = reply.result().data
+            .ok_or_else(|| err_msg(IndyErrorKind::LedgerItemNotFound, "Acceptance Mechanisms not found"))?;
+
+        LedgerService::_serialize_parsed_reply(get_acceptance_mechanisms_response, data)
+    }
+
+    #[logfn(Info)]
+    pub fn parse_get_validator_info_response(&self, get_validator_info_response: &str) -> IndyResult<String> {
+        let data = LedgerService::_parse_validator_info_node_replies(get_validator_info_response)?;
+
+        let reply = ParsedReply { data, seq_no: None, txn_time: None };
+
+        serde_json::to_string(&reply)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize GET_VALIDATOR_INFO data")
+    }
+
+    #[logfn(Info)]
+    pub fn parse_pool_upgrade_status(&self, get_validator_info_response: &str) -> IndyResult<String> {
+        let data: BTreeMap<String, NodeUpgradeStatus> = LedgerService::_parse_validator_info_node_replies(get_validator_info_response)?
+            .into_iter()
+            .map(|(node, node_reply)| {
+                let status = match (node_reply.data, node_reply.error) {
+                    (_, Some(error)) => NodeUpgradeStatus::error(&node, error),
+                    (Some(data), None) => NodeUpgradeStatus::new(&node, &data),
+                    (None, None) => NodeUpgradeStatus::error(&node, "Validator info is empty".to_string())
+                };
+
+                (node, status)
             })
-        }
+            .collect();
 
-        fn _role_constraint_json() -> String {
-            serde_json::to_string(&_role_constraint()).unwrap()
-        }
+        let reply = ParsedReply { data, seq_no: None, txn_time: None };
 
-        #[test]
-        fn build_auth_rule_request_works_for_role_constraint() {
-            let ledger_service = LedgerService::new();
+        serde_json::to_string(&reply)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize pool upgrade status")
+    }
 
-            let expected_result = json!({
-                "type": AUTH_RULE,
-                "auth_type": NYM,
-                "field": FIELD,
-                "new_value": NEW_VALUE,
-                "auth_action": AuthAction::ADD,
-                "constraint": _role_constraint(),
-            });
+    #[logfn(Info)]
+    pub fn parse_get_validator_info_summary_response(&self, get_validator_info_response: &str) -> IndyResult<String> {
+        let data: BTreeMap<String, ValidatorInfoSummary> = LedgerService::_parse_validator_info_node_replies(get_validator_info_response)?
+            .into_iter()
+            .map(|(node, node_reply)| {
+                let summary = match (node_reply.data, node_reply.error) {
+                    (_, Some(error)) => ValidatorInfoSummary::error(&node, error),
+                    (Some(data), None) => ValidatorInfoSummary::new(&node, &data),
+                    (None, None) => ValidatorInfoSummary::error(&node, "Validator info is empty".to_string())
+                };
+
+                (node, summary)
+            })
+            .collect();
 
-            let request = ledger_service.build_auth_rule_request(&identifier(), NYM, ADD_AUTH_ACTION, FIELD,
-                                                                 None, Some(NEW_VALUE),
-                                                                 _role_constraint()).unwrap();
-            check_request(&request, expected_result);
+        let reply = ParsedReply { data, seq_no: None, txn_time: None };
+
+        serde_json::to_string(&reply)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize GET_VALIDATOR_INFO summary")
+    }
+
+    fn _parse_validator_info_node_replies(get_validator_info_response: &str) -> IndyResult<BTreeMap<String, ValidatorInfoNodeReply>> {
+        let node_replies: BTreeMap<String, String> = serde_json::from_str(get_validator_info_response)
+            .to_indy(IndyErrorKind::InvalidStructure, "Invalid GET_VALIDATOR_INFO action response json")?;
+
+        let data = node_replies
+            .into_iter()
+            .map(|(node, node_reply)| {
+                // Not a json reply means that node didn't reply (f.e. "timeout")
+                let node_reply = if serde_json::from_str::<Value>(&node_reply).is_err() {
+                    ValidatorInfoNodeReply { data: None, error: Some(node_reply) }
+                } else {
+                    match LedgerService::parse_response::<GetValidatorInfoResult>(&node_reply) {
+                        Ok(reply) => ValidatorInfoNodeReply { data: reply.result().data, error: None },
+                        Err(err) => ValidatorInfoNodeReply { data: None, error: Some(err.to_string().trim_end().to_string()) }
+                    }
+                };
+
+                (node, node_reply)
+            })
+            .collect();
+
+        Ok(data)
+    }
+
+    fn _check_point_in_time(seq_no: Option<u64>, timestamp: Option<u64>) -> IndyResult<()> {
+        if seq_no.is_some() && timestamp.is_some() {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "Either seqNo or timestamp can be specified for historical query"));
         }
 
-        #[test]
-        fn build_auth_rule_request_works_for_combination_constraints() {
-            let ledger_service = LedgerService::new();
+        Ok(())
+    }
 
-            let constraint = Constraint::AndConstraint(
-                CombinationConstraint {
-                    auth_constraints: vec![
-                        _role_constraint(),
-                        Constraint::OrConstraint(
-                            CombinationConstraint {
+    fn _serialize_parsed_reply<T>(response: &str, data: T) -> IndyResult<String> where T: Serialize {
+        let metadata = parse_response_metadata(response)?;
+
+        let reply = ParsedReply {
+            data,
+            seq_no: metadata.seq_no,
+            txn_time: metadata.txn_time,
+        };
+
+        serde_json::to_string(&reply)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize parsed reply")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::anoncreds::schema::AttributeNames;
+    use crate::domain::ledger::constants::*;
+    use crate::domain::ledger::node::Services;
+    use crate::domain::ledger::nym::MAX_DIDDOC_CONTENT_SIZE;
+    use crate::domain::ledger::request::ProtocolVersion;
+
+    use super::*;
+
+    const IDENTIFIER: &str = "NcYxiDXkpYi6ov5FcYDi1e";
+    const DEST: &str = "VsKV7grR1BUE29mG2Fm2kX";
+    const VERKEY: &str = "CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW";
+
+    fn identifier() -> DidValue {
+        DidValue(IDENTIFIER.to_string())
+    }
+
+    fn dest() -> DidValue {
+        DidValue(DEST.to_string())
+    }
+
+    #[test]
+    fn build_nym_request_works_for_only_required_fields() {
+        let ledger_service = LedgerService::new();
+
+        let expected_result = json!({
+            "type": NYM,
+            "dest": DEST
+        });
+
+        let request = ledger_service.build_nym_request(&identifier(), &dest(), None, None, None).unwrap();
+        check_request(&request, expected_result);
+    }
+
+    #[test]
+    fn build_nym_request_works_for_empty_role() {
+        let ledger_service = LedgerService::new();
+
+        let expected_result = json!({
+            "type": NYM,
+            "dest": DEST,
+            "role": serde_json::Value::Null,
+        });
+
+        let request = ledger_service.build_nym_request(&identifier(), &dest(), None, None, Some("")).unwrap();
+        check_request(&request, expected_result);
+    }
+
+    #[test]
+    fn build_nym_request_works_for_optional_fields() {
+        let ledger_service = LedgerService::new();
+
+        let expected_result = json!({
+            "type": NYM,
+            "dest": DEST,
+            "role": serde_json::Value::Null,
+            "alias": "some_alias",
+            "verkey": VERKEY,
+        });
+
+        let request = ledger_service.build_nym_request(&identifier(), &dest(), Some(VERKEY), Some("some_alias"), Some("")).unwrap();
+        check_request(&request, expected_result);
+    }
+
+    #[test]
+    fn build_nym_request_works_for_indy_dids() {
+        let ledger_service = LedgerService::new();
+
+        let expected_result = json!({
+            "type": NYM,
+            "dest": DEST,
+            "verkey": VERKEY,
+        });
+
+        let identifier = DidValue(format!("did:indy:sovrin:staging:{}", IDENTIFIER));
+        let dest = DidValue(format!("did:indy:sovrin:staging:{}", DEST));
+
+        let request = ledger_service.build_nym_request(&identifier, &dest, Some(VERKEY), None, None).unwrap();
+        check_request(&request, expected_result);
+
+        let request: serde_json::Value = serde_json::from_str(&request).unwrap();
+        assert_eq!(IDENTIFIER, request["identifier"]);
+    }
+
+    #[test]
+    fn build_nym_request_works_for_different_indy_namespaces() {
+        let ledger_service = LedgerService::new();
+
+        let identifier = DidValue(format!("did:indy:sovrin:{}", IDENTIFIER));
+        let dest = DidValue(format!("did:indy:idunion:{}", DEST));
+
+        let res = ledger_service.build_nym_request(&identifier, &dest, None, None, None);
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    fn indy_schema(did: &DidValue) -> SchemaV1 {
+        let mut attr_names: AttributeNames = AttributeNames::new();
+        attr_names.0.insert("male".to_string());
+
+        SchemaV1 {
+            id: SchemaId::new(did, "name", "1.0"),
+            name: "name".to_string(),
+            version: "1.0".to_string(),
+            attr_names,
+            seq_no: None,
+        }
+    }
+
+    #[test]
+    fn build_schema_request_works_for_same_indy_namespace() {
+        let ledger_service = LedgerService::new();
+
+        let identifier = DidValue(format!("did:indy:sovrin:{}", IDENTIFIER));
+
+        ledger_service.build_schema_request(&identifier, indy_schema(&identifier)).unwrap();
+    }
+
+    #[test]
+    fn build_schema_request_works_for_different_indy_namespaces() {
+        let ledger_service = LedgerService::new();
+
+        let identifier = DidValue(format!("did:indy:sovrin:{}", IDENTIFIER));
+        let issuer = DidValue(format!("did:indy:idunion:{}", DEST));
+
+        let res = ledger_service.build_schema_request(&identifier, indy_schema(&issuer));
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    #[test]
+    fn build_get_schema_request_works_for_different_indy_namespaces() {
+        let ledger_service = LedgerService::new();
+
+        let identifier = DidValue(format!("did:indy:sovrin:{}", IDENTIFIER));
+        let id = SchemaId::new(&DidValue(format!("did:indy:idunion:{}", DEST)), "name", "1.0");
+
+        let res = ledger_service.build_get_schema_request(Some(&identifier), &id);
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    #[test]
+    fn build_get_cred_def_request_works_for_different_indy_namespaces() {
+        let ledger_service = LedgerService::new();
+
+        let identifier = DidValue(format!("did:indy:sovrin:{}", IDENTIFIER));
+        let id = CredentialDefinitionId(format!("creddef:indy:did:indy:idunion:{}:3:CL:1:tag", DEST));
+
+        let res = ledger_service.build_get_cred_def_request(Some(&identifier), &id);
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    fn nym_request_data(json: serde_json::Value) -> NymRequestData {
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn build_nym_request_with_data_works_for_diddoc_content_and_version() {
+        let ledger_service = LedgerService::new();
+
+        let diddoc_content = json!({"service": [{"id": "#did-communication", "type": "did-communication", "serviceEndpoint": "https://agent.example.com"}]});
+
+        let expected_result = json!({
+            "type": NYM,
+            "dest": DEST,
+            "verkey": VERKEY,
+            "role": ENDORSER,
+            "diddocContent": diddoc_content.to_string(),
+            "version": 2,
+        });
+
+        let data = nym_request_data(json!({"verkey": VERKEY, "role": "ENDORSER", "diddocContent": diddoc_content, "version": 2}));
+
+        let request = ledger_service.build_nym_request_with_data(&identifier(), &dest(), &data).unwrap();
+        check_request(&request, expected_result);
+    }
+
+    #[test]
+    fn build_nym_request_with_data_works_for_reset_role() {
+        let ledger_service = LedgerService::new();
+
+        let expected_result = json!({
+            "type": NYM,
+            "dest": DEST,
+            "role": serde_json::Value::Null,
+        });
+
+        let data = nym_request_data(json!({"role": "RESET"}));
+
+        let request = ledger_service.build_nym_request_with_data(&identifier(), &dest(), &data).unwrap();
+        check_request(&request, expected_result);
+    }
+
+    #[test]
+    fn nym_request_data_works_for_trust_anchor_alias() {
+        assert_eq!(Some(NymRole::Endorser), nym_request_data(json!({"role": "TRUST_ANCHOR"})).role);
+    }
+
+    #[test]
+    fn nym_request_data_works_for_unknown_role() {
+        assert!(serde_json::from_value::<NymRequestData>(json!({"role": "101"})).is_err());
+        assert!(serde_json::from_value::<NymRequestData>(json!({"role": ""})).is_err());
+    }
+
+    #[test]
+    fn nym_request_data_validate_works_for_invalid_diddoc_content() {
+        assert!(nym_request_data(json!({"diddocContent": "content"})).validate().is_err());
+        assert!(nym_request_data(json!({"diddocContent": {"id": "did:indy:sovrin:123"}})).validate().is_err());
+        assert!(nym_request_data(json!({"diddocContent": {"service": "a".repeat(MAX_DIDDOC_CONTENT_SIZE)}})).validate().is_err());
+        assert!(nym_request_data(json!({"diddocContent": {"service": []}})).validate().is_ok());
+    }
+
+    #[test]
+    fn nym_request_data_validate_works_for_version() {
+        assert!(nym_request_data(json!({"version": 0})).validate().is_ok());
+        assert!(nym_request_data(json!({"version": 2})).validate().is_ok());
+        assert!(nym_request_data(json!({"version": 3})).validate().is_err());
+    }
+
+    #[test]
+    fn build_set_endpoint_request_works() {
+        let ledger_service = LedgerService::new();
+
+        let expected_result = json!({
+            "type": ATTRIB,
+            "dest": DEST,
+            "raw": r#"{"endpoint":{"ha":"127.0.0.1:9700","verkey":"CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW"}}"#
+        });
+
+        let endpoint = Endpoint::new("127.0.0.1:9700".to_string(), Some(VERKEY.to_string()));
+
+        let request = ledger_service.build_set_endpoint_request(&identifier(), &dest(), &endpoint).unwrap();
+        check_request(&request, expected_result);
+    }
+
+    #[test]
+    fn build_set_endpoint_request_works_for_invalid_address() {
+        let ledger_service = LedgerService::new();
+
+        let endpoint = Endpoint::new("https://agent.example.com".to_string(), None);
+
+        let res = ledger_service.build_set_endpoint_request(&identifier(), &dest(), &endpoint);
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    #[test]
+    fn build_get_endpoint_request_works() {
+        let ledger_service = LedgerService::new();
+
+        let expected_result = json!({
+            "type": GET_ATTR,
+            "dest": DEST,
+            "raw": "endpoint"
+        });
+
+        let request = ledger_service.build_get_endpoint_request(Some(&identifier()), &dest()).unwrap();
+        check_request(&request, expected_result);
+    }
+
+    #[test]
+    fn build_get_nym_request_works() {
+        let ledger_service = LedgerService::new();
+
+        let expected_result = json!({
+            "type": GET_NYM,
+            "dest": DEST
+        });
+
+        let request = ledger_service.build_get_nym_request(Some(&identifier()), &dest(), None, None).unwrap();
+        check_request(&request, expected_result);
+    }
+
+    #[test]
+    fn build_get_nym_request_works_for_seq_no() {
+        let ledger_service = LedgerService::new();
+
+        let expected_result = json!({
+            "type": GET_NYM,
+            "dest": DEST,
+            "seqNo": 10
+        });
+
+        let request = ledger_service.build_get_nym_request(Some(&identifier()), &dest(), Some(10), None).unwrap();
+        check_request(&request, expected_result);
+    }
+
+    #[test]
+    fn build_get_nym_request_works_for_timestamp() {
+        let ledger_service = LedgerService::new();
+
+        let expected_result = json!({
+            "type": GET_NYM,
+            "dest": DEST,
+            "timestamp": 1589000000
+        });
+
+        let request = ledger_service.build_get_nym_request(Some(&identifier()), &dest(), None, Some(1589000000)).unwrap();
+        check_request(&request, expected_result);
+    }
+
+    #[test]
+    fn build_get_nym_request_works_for_seq_no_and_timestamp() {
+        let ledger_service = LedgerService::new();
+
+        let res = ledger_service.build_get_nym_request(Some(&identifier()), &dest(), Some(10), Some(1589000000));
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    #[test]
+    fn build_get_ddo_request_works() {
+        let ledger_service = LedgerService::new();
+
+        let expected_result = json!({
+            "type": GET_DDO,
+            "dest": DEST
+        });
+
+        let request = ledger_service.build_get_ddo_request(Some(&identifier()), &dest()).unwrap();
+        check_request(&request, expected_result);
+    }
+
+    #[test]
+    fn build_attrib_request_works_for_hash_field() {
+        let ledger_service = LedgerService::new();
+
+        let expected_result = json!({
+            "type": ATTRIB,
+            "dest": DEST,
+            "hash": "hash"
+        });
+
+        let request = ledger_service.build_attrib_request(&identifier(), &dest(), Some("hash"), None, None).unwrap();
+        check_request(&request, expected_result);
+    }
+
+    #[test]
+    fn build_get_attrib_request_works_for_raw_value() {
+        let ledger_service = LedgerService::new();
+
+        let expected_result = json!({
+            "type": GET_ATTR,
+            "dest": DEST,
+            "raw": "raw"
+        });
+
+        let request = ledger_service.build_get_attrib_request(Some(&identifier()), &dest(), Some("raw"), None, None, None, None).unwrap();
+        check_request(&request, expected_result);
+    }
+
+    #[test]
+    fn build_get_attrib_request_works_for_hash_value() {
+        let ledger_service = LedgerService::new();
+
+        let expected_result = json!({
+            "type": GET_ATTR,
+            "dest": DEST,
+            "hash": "hash"
+        });
+
+        let request = ledger_service.build_get_attrib_request(Some(&identifier()), &dest(), None, Some("hash"), None, None, None).unwrap();
+        check_request(&request, expected_result);
+    }
+
+    #[test]
+    fn build_get_attrib_request_works_for_timestamp() {
+        let ledger_service = LedgerService::new();
+
+        let expected_result = json!({
+            "type": GET_ATTR,
+            "dest": DEST,
+            "raw": "raw",
+            "timestamp": 1589000000
+        });
+
+        let request = ledger_service.build_get_attrib_request(Some(&identifier()), &dest(), Some("raw"), None, None, None, Some(1589000000)).unwrap();
+        check_request(&request, expected_result);
+    }
+
+    #[test]
+    fn build_get_attrib_request_works_for_seq_no_and_timestamp() {
+        let ledger_service = LedgerService::new();
+
+        let res = ledger_service.build_get_attrib_request(Some(&identifier()), &dest(), Some("raw"), None, None, Some(10), Some(1589000000));
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    #[test]
+    fn build_get_attrib_request_works_for_enc_value() {
+        let ledger_service = LedgerService::new();
+
+        let expected_result = json!({
+            "type": GET_ATTR,
+            "dest": DEST,
+            "enc": "enc"
+        });
+
+        let request = ledger_service.build_get_attrib_request(Some(&identifier()), &dest(), None, None, Some("enc"), None, None).unwrap();
+        check_request(&request, expected_result);
+    }
+
+    #[test]
+    fn build_schema_request_works() {
+        let ledger_service = LedgerService::new();
+
+        let mut attr_names: AttributeNames = AttributeNames::new();
+        attr_names.0.insert("male".to_string());
+
+        let data = SchemaV1 {
+            id: SchemaId::new(&identifier(), "name", "1.0"),
+            name: "name".to_string(),
+            version: "1.0".to_string(),
+            attr_names,
+            seq_no: None,
+        };
+
+        let expected_result = json!({
+            "type": SCHEMA,
+            "data": {
+                "name": "name",
+                "version": "1.0",
+                "attr_names": ["male"]
+            }
+        });
+
+        let request = ledger_service.build_schema_request(&identifier(), data).unwrap();
+        check_request(&request, expected_result);
+    }
+
+    #[test]
+    fn build_get_schema_request_works_for_valid_id() {
+        let ledger_service = LedgerService::new();
+
+        let id = SchemaId::new(&identifier(), "name", "1.0");
+
+        let expected_result = json!({
+            "type": GET_SCHEMA,
+            "dest": IDENTIFIER,
+            "data": {
+                "name": "name",
+                "version": "1.0"
+            }
+        });
+
+        let request = ledger_service.build_get_schema_request(Some(&identifier()), &id).unwrap();
+        check_request(&request, expected_result);
+    }
+
+    #[test]
+    fn build_get_cred_def_request_works() {
+        ProtocolVersion::set(2);
+
+        let ledger_service = LedgerService::new();
+
+        let id = CredentialDefinitionId::new(&identifier(), &SchemaId("1".to_string()), "signature_type", "tag");
+
+        let expected_result = json!({
+            "type": GET_CRED_DEF,
+            "ref": 1,
+            "signature_type": "signature_type",
+            "origin": IDENTIFIER,
+            "tag":"tag"
+        });
+
+        let request = ledger_service.build_get_cred_def_request(Some(&identifier()), &id).unwrap();
+        check_request(&request, expected_result);
+    }
+
+    #[test]
+    fn build_node_request_works() {
+        let ledger_service = LedgerService::new();
+
+        let data = NodeOperationData {
+            node_ip: Some("ip".to_string()),
+            node_port: Some(1),
+            client_ip: Some("ip".to_string()),
+            client_port: Some(1),
+            alias: "some".to_string(),
+            services: Some(vec![Services::VALIDATOR]),
+            blskey: Some("blskey".to_string()),
+            blskey_pop: Some("pop".to_string()),
+        };
+
+        let expected_result = json!({
+            "type": NODE,
+            "dest": DEST,
+            "data": {
+                "node_ip": "ip",
+                "node_port": 1,
+                "client_ip": "ip",
+                "client_port": 1,
+                "alias": "some",
+                "services": ["VALIDATOR"],
+                "blskey": "blskey",
+                "blskey_pop": "pop"
+            }
+        });
+
+        let request = ledger_service.build_node_request(&identifier(), &dest(), data).unwrap();
+        check_request(&request, expected_result);
+    }
+
+    #[test]
+    fn build_get_txn_request_works() {
+        let ledger_service = LedgerService::new();
+
+        let expected_result = json!({
+            "type": GET_TXN,
+            "data": 1,
+            "ledgerId": 1
+        });
+
+        let request = ledger_service.build_get_txn_request(Some(&identifier()), None, 1).unwrap();
+        check_request(&request, expected_result);
+    }
+
+    #[test]
+    fn build_get_txn_request_works_for_ledger_type_as_predefined_string_constant() {
+        let ledger_service = LedgerService::new();
+
+        let expected_result = json!({
+            "type": GET_TXN,
+            "data": 1,
+            "ledgerId": 0
+        });
+
+        let request = ledger_service.build_get_txn_request(Some(&identifier()), Some("POOL"), 1).unwrap();
+        check_request(&request, expected_result);
+    }
+
+    #[test]
+    fn build_get_txn_request_works_for_ledger_type_as_number() {
+        let ledger_service = LedgerService::new();
+
+        let expected_result = json!({
+            "type": GET_TXN,
+            "data": 1,
+            "ledgerId": 10
+        });
+
+        let request = ledger_service.build_get_txn_request(Some(&identifier()), Some("10"), 1).unwrap();
+        check_request(&request, expected_result);
+    }
+
+    #[test]
+    fn build_get_txn_request_works_for_invalid_type() {
+        let ledger_service = LedgerService::new();
+
+        let res = ledger_service.build_get_txn_request(Some(&identifier()), Some("type"), 1);
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    fn pool_upgrade_schedule_data() -> PoolUpgradeScheduleData {
+        serde_json::from_value(json!({
+            "name": "upgrade",
+            "version": "1.12.3",
+            "sha256": "f284bdc3c1c9e24a494e285cb387c69510f28de51c15bb93179d9c7f28705398",
+            "startTime": 1580221800,
+            "nodeOffsets": {"Node1": 0, "Node2": 300}
+        })).unwrap()
+    }
+
+    #[test]
+    fn build_scheduled_pool_upgrade_works() {
+        let ledger_service = LedgerService::new();
+
+        let nodes: HashMap<String, String> = vec![
+            ("Node1".to_string(), "Gw6pDLhcBcoQesN72qfotTgFa7cbuqZpkX3Xo6pLhPhv".to_string()),
+            ("Node2".to_string(), "8ECVSk179mjsjKRLWiQtssMLgp6EPhWXtaYyStWPSGAb".to_string()),
+            ("Node3".to_string(), "DKVxG2fXXTU8yT5N7hGEbXB3dfdAnYv1JczDUHpmDxya".to_string()),
+        ].into_iter().collect();
+
+        let expected_result = json!({
+            "type": POOL_UPGRADE,
+            "name": "upgrade",
+            "version": "1.12.3",
+            "action": "start",
+            "sha256": "f284bdc3c1c9e24a494e285cb387c69510f28de51c15bb93179d9c7f28705398",
+            "schedule": {
+                "Gw6pDLhcBcoQesN72qfotTgFa7cbuqZpkX3Xo6pLhPhv": "2020-01-28T14:30:00Z",
+                "8ECVSk179mjsjKRLWiQtssMLgp6EPhWXtaYyStWPSGAb": "2020-01-28T14:35:00Z"
+            },
+            "reinstall": false,
+            "force": false
+        });
+
+        let request = ledger_service.build_scheduled_pool_upgrade(&identifier(), &pool_upgrade_schedule_data(), &nodes).unwrap();
+        check_request(&request, expected_result);
+    }
+
+    #[test]
+    fn build_scheduled_pool_upgrade_works_for_unknown_alias() {
+        let ledger_service = LedgerService::new();
+
+        let nodes: HashMap<String, String> = vec![
+            ("Node1".to_string(), "Gw6pDLhcBcoQesN72qfotTgFa7cbuqZpkX3Xo6pLhPhv".to_string()),
+        ].into_iter().collect();
+
+        let res = ledger_service.build_scheduled_pool_upgrade(&identifier(), &pool_upgrade_schedule_data(), &nodes);
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    #[test]
+    fn validate_action_works_for_pool_restart() {
+        let ledger_service = LedgerService::new();
+        let request = ledger_service.build_pool_restart(&identifier(), "start", None).unwrap();
+        ledger_service.validate_action(&request).unwrap();
+    }
+
+    #[test]
+    fn validate_action_works_for_get_validator_info() {
+        let ledger_service = LedgerService::new();
+        let request = ledger_service.build_get_validator_info_request(&identifier()).unwrap();
+        ledger_service.validate_action(&request).unwrap();
+    }
+
+    #[test]
+    fn build_get_request_for_write_works_for_schema() {
+        let ledger_service = LedgerService::new();
+
+        let request = json!({
+            "reqId": 1, "identifier": IDENTIFIER, "protocolVersion": 2,
+            "operation": {"type": SCHEMA, "data": {"name": "name", "version": "1.0", "attr_names": ["male"]}}
+        }).to_string();
+
+        let expected_result = json!({
+            "type": GET_SCHEMA,
+            "dest": IDENTIFIER,
+            "data": {
+                "name": "name",
+                "version": "1.0"
+            }
+        });
+
+        let request = ledger_service.build_get_request_for_write(&request).unwrap();
+        check_request(&request, expected_result);
+    }
+
+    #[test]
+    fn build_get_request_for_write_works_for_cred_def() {
+        let ledger_service = LedgerService::new();
+
+        let request = json!({
+            "reqId": 1, "identifier": IDENTIFIER, "protocolVersion": 2,
+            "operation": {"type": CRED_DEF, "ref": 1, "signature_type": "CL", "tag": "tag", "data": {}}
+        }).to_string();
+
+        let expected_result = json!({
+            "type": GET_CRED_DEF,
+            "ref": 1,
+            "signature_type": "CL",
+            "origin": IDENTIFIER,
+            "tag": "tag"
+        });
+
+        let request = ledger_service.build_get_request_for_write(&request).unwrap();
+        check_request(&request, expected_result);
+    }
+
+    #[test]
+    fn build_get_request_for_write_works_for_unsupported_type() {
+        let ledger_service = LedgerService::new();
+        let request = ledger_service.build_nym_request(&identifier(), &dest(), None, None, None).unwrap();
+        let res = ledger_service.build_get_request_for_write(&request);
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    #[test]
+    fn is_duplicate_txn_rejection_works() {
+        let ledger_service = LedgerService::new();
+
+        let duplicate = json!({"op": "REJECT", "reqId": 1, "reason": "client request invalid: InvalidClientRequest('NcYxiDXkpYi6ov5FcYDi1e can have one and only one SCHEMA with name name and version 1.0',)"}).to_string();
+        assert!(ledger_service.is_duplicate_txn_rejection(&duplicate));
+
+        let rejection = json!({"op": "REJECT", "reqId": 1, "reason": "client request invalid: UnauthorizedClientRequest('Not enough signatures')"}).to_string();
+        assert!(!ledger_service.is_duplicate_txn_rejection(&rejection));
+
+        let reply = json!({"op": "REPLY", "result": {"seqNo": 1}}).to_string();
+        assert!(!ledger_service.is_duplicate_txn_rejection(&reply));
+    }
+
+    #[test]
+    fn is_txn_found_works() {
+        let ledger_service = LedgerService::new();
+
+        let found = json!({"op": "REPLY", "result": {"seqNo": 10, "data": {"attr_names": ["male"]}}}).to_string();
+        assert!(ledger_service.is_txn_found(&found));
+
+        let not_found = json!({"op": "REPLY", "result": {"seqNo": null, "data": {"name": "name", "version": "1.0"}}}).to_string();
+        assert!(!ledger_service.is_txn_found(&not_found));
+    }
+
+    #[test]
+    fn build_idempotent_submit_result_works() {
+        let ledger_service = LedgerService::new();
+
+        let response = json!({"op": "REPLY", "result": {"seqNo": 10}});
+        let result = ledger_service.build_idempotent_submit_result(&response.to_string(), true).unwrap();
+        assert_eq!(json!({"alreadyExists": true, "response": response}), serde_json::from_str::<serde_json::Value>(&result).unwrap());
+    }
+
+    #[test]
+    fn build_ledgers_freeze_request_work_with_valid_data() {
+        let ledger_service = LedgerService::new();
+        let ledgers_ids = vec![0,1,50,873];
+        let res = ledger_service.build_ledgers_freeze_request(&identifier(), ledgers_ids);
+
+        match res {
+            Ok(_) => {},
+            Err(ec) => {
+                assert!(false, "build_ledgers_freeze_request_work_with_valid_data returned error_code {:?}", ec);
+            }
+        }
+    }
+
+    #[test]
+    fn build_get_frozen_ledgers_request_work_with_valid_data() {
+        let ledger_service = LedgerService::new();
+        let res = ledger_service.build_get_frozen_ledgers_request(&identifier());
+
+        match res {
+            Ok(_) => {},
+            Err(ec) => {
+                assert!(false, "build_get_frozen_ledgers_request_work_with_valid_data returned error_code {:?}", ec);
+            }
+        }
+    }
+
+    #[test]
+    fn build_ledgers_freeze_request_work_with_empty_data() {
+        let ledger_service = LedgerService::new();
+        let ledgers_ids = vec![];
+        let res = ledger_service.build_ledgers_freeze_request(&identifier(), ledgers_ids);
+
+        match res {
+            Ok(_) => {},
+            Err(ec) => {
+                assert!(false, "build_ledgers_freeze_request_work_with_empty_data returned error_code {:?}", ec);
+            }
+        }
+    }
+
+    mod auth_rule {
+        use super::*;
+
+        const ADD_AUTH_ACTION: &str = "ADD";
+        const EDIT_AUTH_ACTION: &str = "EDIT";
+        const FIELD: &str = "role";
+        const OLD_VALUE: &str = "0";
+        const NEW_VALUE: &str = "101";
+
+        fn _role_constraint() -> Constraint {
+            Constraint::RoleConstraint(RoleConstraint {
+                sig_count: 0,
+                metadata: None,
+                role: Some(String::new()),
+                need_to_be_owner: false,
+                off_ledger_signature: false,
+            })
+        }
+
+        fn _role_constraint_json() -> String {
+            serde_json::to_string(&_role_constraint()).unwrap()
+        }
+
+        #[test]
+        fn build_auth_rule_request_works_for_role_constraint() {
+            let ledger_service = LedgerService::new();
+
+            let expected_result = json!({
+                "type": AUTH_RULE,
+                "auth_type": NYM,
+                "field": FIELD,
+                "new_value": NEW_VALUE,
+                "auth_action": AuthAction::ADD,
+                "constraint": _role_constraint(),
+            });
+
+            let request = ledger_service.build_auth_rule_request(&identifier(), NYM, ADD_AUTH_ACTION, FIELD,
+                                                                 None, Some(NEW_VALUE),
+                                                                 _role_constraint()).unwrap();
+            check_request(&request, expected_result);
+        }
+
+        #[test]
+        fn build_auth_rule_request_works_for_combination_constraints() {
+            let ledger_service = LedgerService::new();
+
+            let constraint = Constraint::AndConstraint(
+                CombinationConstraint {
+                    auth_constraints: vec![
+                        _role_constraint(),
+                        Constraint::OrConstraint(
+                            CombinationConstraint {
                                 auth_constraints: vec![
                                     _role_constraint(), _role_constraint(), ],
                             }
@@ -977,310 +2262,1354 @@ mod tests {
                     ],
                 });
 
-            let expected_result = json!({
-                "type": AUTH_RULE,
+            let expected_result = json!({
+                "type": AUTH_RULE,
+                "auth_type": NYM,
+                "field": FIELD,
+                "new_value": NEW_VALUE,
+                "auth_action": AuthAction::ADD,
+                "constraint": constraint,
+            });
+
+            let request = ledger_service.build_auth_rule_request(&identifier(), NYM, ADD_AUTH_ACTION, FIELD,
+                                                                 None, Some(NEW_VALUE),
+                                                                 constraint).unwrap();
+
+            check_request(&request, expected_result);
+        }
+
+        #[test]
+        fn build_auth_rule_request_works_for_edit_auth_action() {
+            let ledger_service = LedgerService::new();
+
+            let expected_result = json!({
+                "type": AUTH_RULE,
+                "auth_type": NYM,
+                "field": FIELD,
+                "old_value": OLD_VALUE,
+                "new_value": NEW_VALUE,
+                "auth_action": AuthAction::EDIT,
+                "constraint": _role_constraint(),
+            });
+
+            let request = ledger_service.build_auth_rule_request(&identifier(), NYM, EDIT_AUTH_ACTION, FIELD,
+                                                                 Some(OLD_VALUE), Some(NEW_VALUE),
+                                                                 _role_constraint()).unwrap();
+            check_request(&request, expected_result);
+        }
+
+        #[test]
+        fn build_auth_rule_request_works_for_invalid_auth_action() {
+            let ledger_service = LedgerService::new();
+
+            let res = ledger_service.build_auth_rule_request(&identifier(), NYM, "WRONG", FIELD, None, Some(NEW_VALUE), _role_constraint());
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
+        #[test]
+        fn build_get_auth_rule_request_works_for_add_action() {
+            let ledger_service = LedgerService::new();
+
+            let expected_result = json!({
+                "type": GET_AUTH_RULE,
+                "auth_type": NYM,
+                "field": FIELD,
+                "new_value": NEW_VALUE,
+                "auth_action": AuthAction::ADD,
+            });
+
+            let request = ledger_service.build_get_auth_rule_request(Some(&identifier()), Some(NYM),
+                                                                     Some(ADD_AUTH_ACTION), Some(FIELD),
+                                                                     None, Some(NEW_VALUE)).unwrap();
+            check_request(&request, expected_result);
+        }
+
+        #[test]
+        fn build_get_auth_rule_request_works_for_edit_action() {
+            let ledger_service = LedgerService::new();
+
+            let expected_result = json!({
+                "type": GET_AUTH_RULE,
+                "auth_type": NYM,
+                "field": FIELD,
+                "old_value": OLD_VALUE,
+                "new_value": NEW_VALUE,
+                "auth_action": AuthAction::EDIT,
+            });
+
+            let request = ledger_service.build_get_auth_rule_request(Some(&identifier()), Some(NYM),
+                                                                     Some(EDIT_AUTH_ACTION), Some(FIELD),
+                                                                     Some(OLD_VALUE), Some(NEW_VALUE)).unwrap();
+            check_request(&request, expected_result);
+        }
+
+        #[test]
+        fn build_get_auth_rule_request_works_for_none_params() {
+            let ledger_service = LedgerService::new();
+
+            let expected_result = json!({
+                "type": GET_AUTH_RULE,
+            });
+
+            let request = ledger_service.build_get_auth_rule_request(Some(&identifier()), None,
+                                                                     None, None,
+                                                                     None, None).unwrap();
+            check_request(&request, expected_result);
+        }
+
+        #[test]
+        fn build_get_auth_rule_request_works_for_some_fields_are_specified() {
+            let ledger_service = LedgerService::new();
+
+            let res = ledger_service.build_get_auth_rule_request(Some(&identifier()), Some(NYM),
+                                                                 None, Some(FIELD),
+                                                                 None, None);
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
+        #[test]
+        fn build_get_auth_rule_request_works_for_invalid_auth_action() {
+            let ledger_service = LedgerService::new();
+
+            let res = ledger_service.build_get_auth_rule_request(Some(&identifier()), None, Some("WRONG"), None, None, None);
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
+        #[test]
+        fn build_get_auth_rule_request_works_for_invalid_auth_type() {
+            let ledger_service = LedgerService::new();
+
+            let res = ledger_service.build_get_auth_rule_request(Some(&identifier()), Some("WRONG"), None, None, None, None);
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
+        #[test]
+        fn build_auth_rules_request_works() {
+            let ledger_service = LedgerService::new();
+
+            let mut data = AuthRules::new();
+            data.push(AuthRuleData::Add(AddAuthRuleData {
+                auth_type: NYM.to_string(),
+                field: FIELD.to_string(),
+                new_value: Some(NEW_VALUE.to_string()),
+                constraint: _role_constraint(),
+            }));
+
+            data.push(AuthRuleData::Edit(EditAuthRuleData {
+                auth_type: NYM.to_string(),
+                field: FIELD.to_string(),
+                old_value: Some(OLD_VALUE.to_string()),
+                new_value: Some(NEW_VALUE.to_string()),
+                constraint: _role_constraint(),
+            }));
+
+            let expected_result = json!({
+                "type": AUTH_RULES,
+                "rules": data.clone(),
+            });
+
+            let request = ledger_service.build_auth_rules_request(&identifier(), data).unwrap();
+            check_request(&request, expected_result);
+        }
+
+        #[test]
+        fn build_role_constraint_works() {
+            let ledger_service = LedgerService::new();
+
+            let constraint = ledger_service.build_role_constraint("ENDORSER", 2, true, false, Some(json!({"fees": "1"}))).unwrap();
+
+            assert_eq!(json!({
+                "constraint_id": "ROLE",
+                "role": ENDORSER,
+                "sig_count": 2,
+                "need_to_be_owner": true,
+                "metadata": {"fees": "1"}
+            }), serde_json::from_str::<serde_json::Value>(&constraint).unwrap());
+        }
+
+        #[test]
+        fn build_role_constraint_works_for_identity_owner_and_any_role() {
+            let ledger_service = LedgerService::new();
+
+            let constraint: Constraint = serde_json::from_str(&ledger_service.build_role_constraint("IDENTITY_OWNER", 1, false, false, None).unwrap()).unwrap();
+            assert_eq!(_role_constraint_with("", false), constraint);
+
+            let constraint: Constraint = serde_json::from_str(&ledger_service.build_role_constraint("*", 1, false, true, None).unwrap()).unwrap();
+            assert_eq!(_role_constraint_with("*", true), constraint);
+        }
+
+        #[test]
+        fn build_role_constraint_works_for_invalid_role() {
+            let ledger_service = LedgerService::new();
+
+            let res = ledger_service.build_role_constraint("ADMIN", 1, false, false, None);
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
+        #[test]
+        fn build_role_constraint_works_for_off_ledger_signature_and_specific_role() {
+            let ledger_service = LedgerService::new();
+
+            let res = ledger_service.build_role_constraint(TRUSTEE, 1, false, true, None);
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
+        #[test]
+        fn build_combined_constraint_works() {
+            let ledger_service = LedgerService::new();
+
+            let constraint = ledger_service.build_combined_constraint("OR", vec![_role_constraint(), _role_constraint()]).unwrap();
+
+            assert_eq!(Constraint::OrConstraint(CombinationConstraint { auth_constraints: vec![_role_constraint(), _role_constraint()] }),
+                       serde_json::from_str::<Constraint>(&constraint).unwrap());
+        }
+
+        #[test]
+        fn build_combined_constraint_works_for_invalid_combination() {
+            let ledger_service = LedgerService::new();
+
+            let res = ledger_service.build_combined_constraint("XOR", vec![_role_constraint()]);
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
+        #[test]
+        fn build_combined_constraint_works_for_empty_constraints() {
+            let ledger_service = LedgerService::new();
+
+            let res = ledger_service.build_combined_constraint("AND", Vec::new());
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
+        fn _role_constraint_with(role: &str, off_ledger_signature: bool) -> Constraint {
+            Constraint::RoleConstraint(RoleConstraint {
+                sig_count: 1,
+                metadata: None,
+                role: Some(role.to_string()),
+                need_to_be_owner: false,
+                off_ledger_signature,
+            })
+        }
+
+        fn _auth_rules() -> Vec<AuthRule> {
+            let rule = |auth_action: &str, field: &str, old_value: Option<&str>, new_value: &str, constraint: Constraint| AuthRule {
+                auth_type: NYM.to_string(),
+                auth_action: auth_action.to_string(),
+                field: field.to_string(),
+                old_value: old_value.map(String::from),
+                new_value: Some(new_value.to_string()),
+                constraint,
+            };
+
+            vec![
+                rule(EDIT_AUTH_ACTION, FIELD, Some(OLD_VALUE), NEW_VALUE, Constraint::AndConstraint(CombinationConstraint {
+                    auth_constraints: vec![
+                        _role_constraint_with(TRUSTEE, false),
+                        _role_constraint_with(STEWARD, false),
+                    ]
+                })),
+                rule(EDIT_AUTH_ACTION, FIELD, Some("*"), "*", _role_constraint_with(TRUSTEE, false)),
+                rule(EDIT_AUTH_ACTION, "verkey", Some("*"), "*", Constraint::RoleConstraint(RoleConstraint {
+                    sig_count: 1,
+                    metadata: None,
+                    role: Some("*".to_string()),
+                    need_to_be_owner: true,
+                    off_ledger_signature: false,
+                })),
+                rule(ADD_AUTH_ACTION, FIELD, None, NETWORK_MONITOR, Constraint::ForbiddenConstraint(ForbiddenConstraint {})),
+            ]
+        }
+
+        fn _action(auth_action: AuthAction, field: &str, old_value: Option<&str>, new_value: Option<&str>, owner: Option<&str>) -> AuthRuleAction {
+            AuthRuleAction {
+                auth_type: "NYM".to_string(),
+                auth_action,
+                field: field.to_string(),
+                old_value: old_value.map(String::from),
+                new_value: new_value.map(String::from),
+                owner: owner.map(String::from),
+            }
+        }
+
+        fn _signer(did: &str, role: Option<&str>) -> RequestSigner {
+            RequestSigner { did: did.to_string(), role: role.map(String::from), on_ledger: true, verified: true }
+        }
+
+        #[test]
+        fn evaluate_auth_rules_works_for_exact_values() {
+            let ledger_service = LedgerService::new();
+
+            let action = _action(AuthAction::EDIT, FIELD, Some(OLD_VALUE), Some(NEW_VALUE), None);
+
+            let evaluation = ledger_service.evaluate_auth_rules(&_auth_rules(), &action, &[_signer(IDENTIFIER, Some(TRUSTEE))]).unwrap();
+
+            assert!(!evaluation.allowed);
+            assert_eq!(Some(OLD_VALUE.to_string()), evaluation.auth_rule.old_value);
+            assert_eq!(vec![MissingSignature { role: Some(STEWARD.to_string()), count: 1, need_to_be_owner: false }], evaluation.missing_signatures);
+
+            let evaluation = ledger_service.evaluate_auth_rules(&_auth_rules(), &action, &[_signer(IDENTIFIER, Some(TRUSTEE)), _signer(DEST, Some(STEWARD))]).unwrap();
+
+            assert!(evaluation.allowed);
+            assert!(evaluation.missing_signatures.is_empty());
+        }
+
+        #[test]
+        fn evaluate_auth_rules_works_for_wildcard_values() {
+            let ledger_service = LedgerService::new();
+
+            let action = _action(AuthAction::EDIT, FIELD, Some(STEWARD), Some(ENDORSER), None);
+
+            let evaluation = ledger_service.evaluate_auth_rules(&_auth_rules(), &action, &[_signer(IDENTIFIER, Some(TRUSTEE))]).unwrap();
+
+            assert!(evaluation.allowed);
+            assert_eq!(Some("*".to_string()), evaluation.auth_rule.old_value);
+        }
+
+        #[test]
+        fn evaluate_auth_rules_works_for_owner() {
+            let ledger_service = LedgerService::new();
+
+            let action = _action(AuthAction::EDIT, "verkey", Some(VERKEY), Some(VERKEY), Some(DEST));
+
+            let evaluation = ledger_service.evaluate_auth_rules(&_auth_rules(), &action, &[_signer(IDENTIFIER, Some(TRUSTEE))]).unwrap();
+            assert!(!evaluation.allowed);
+            assert!(evaluation.missing_signatures[0].need_to_be_owner);
+
+            let evaluation = ledger_service.evaluate_auth_rules(&_auth_rules(), &action, &[_signer(DEST, None)]).unwrap();
+            assert!(evaluation.allowed);
+        }
+
+        #[test]
+        fn evaluate_auth_rules_works_for_off_ledger_and_not_verified_signers() {
+            let ledger_service = LedgerService::new();
+
+            let action = _action(AuthAction::EDIT, FIELD, Some(STEWARD), Some(ENDORSER), None);
+
+            let mut signer = _signer(IDENTIFIER, Some(TRUSTEE));
+            signer.verified = false;
+
+            let evaluation = ledger_service.evaluate_auth_rules(&_auth_rules(), &action, &[signer.clone()]).unwrap();
+            assert!(!evaluation.allowed);
+
+            signer.verified = true;
+            signer.on_ledger = false;
+
+            let evaluation = ledger_service.evaluate_auth_rules(&_auth_rules(), &action, &[signer]).unwrap();
+            assert!(!evaluation.allowed);
+        }
+
+        #[test]
+        fn evaluate_auth_rules_works_for_forbidden_action() {
+            let ledger_service = LedgerService::new();
+
+            let action = _action(AuthAction::ADD, FIELD, None, Some(NETWORK_MONITOR), None);
+
+            let evaluation = ledger_service.evaluate_auth_rules(&_auth_rules(), &action, &[_signer(IDENTIFIER, Some(TRUSTEE))]).unwrap();
+
+            assert!(evaluation.forbidden);
+            assert!(!evaluation.allowed);
+        }
+
+        #[test]
+        fn evaluate_auth_rules_works_for_not_found_rule() {
+            let ledger_service = LedgerService::new();
+
+            let action = _action(AuthAction::ADD, FIELD, None, Some(TRUSTEE), None);
+
+            let res = ledger_service.evaluate_auth_rules(&_auth_rules(), &action, &[]);
+            assert_kind!(IndyErrorKind::LedgerItemNotFound, res);
+        }
+
+        #[test]
+        fn build_auth_rules_page_works() {
+            let ledger_service = LedgerService::new();
+
+            let all = ledger_service.build_auth_rules_page(_auth_rules(), &AuthRulesPageOptions::default()).unwrap();
+            assert_eq!(4, all.total);
+            assert_eq!(4, all.rules.len());
+            assert_eq!(None, all.next_offset);
+
+            let first = ledger_service.build_auth_rules_page(_auth_rules(), &AuthRulesPageOptions { offset: None, limit: Some(3) }).unwrap();
+            assert_eq!(all.rules[..3].to_vec(), first.rules);
+            assert_eq!(Some(3), first.next_offset);
+
+            let last = ledger_service.build_auth_rules_page(_auth_rules(), &AuthRulesPageOptions { offset: first.next_offset, limit: Some(3) }).unwrap();
+            assert_eq!(all.rules[3..].to_vec(), last.rules);
+            assert_eq!(None, last.next_offset);
+        }
+
+        #[test]
+        fn build_auth_rules_page_works_for_zero_limit() {
+            let ledger_service = LedgerService::new();
+
+            let res = ledger_service.build_auth_rules_page(_auth_rules(), &AuthRulesPageOptions { offset: None, limit: Some(0) });
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
+        #[test]
+        fn diff_auth_rules_works() {
+            let ledger_service = LedgerService::new();
+
+            let current = _auth_rules();
+
+            let mut proposed = _auth_rules();
+            proposed.remove(0);
+            proposed[0].constraint = _role_constraint_with(STEWARD, false);
+            proposed[1].auth_type = "NYM".to_string();
+            proposed.push(AuthRule {
+                auth_type: NYM.to_string(),
+                auth_action: ADD_AUTH_ACTION.to_string(),
+                field: FIELD.to_string(),
+                old_value: None,
+                new_value: Some(TRUSTEE.to_string()),
+                constraint: _role_constraint_with(TRUSTEE, false),
+            });
+
+            let diff = ledger_service.diff_auth_rules(&current, &proposed).unwrap();
+
+            assert_eq!(vec![proposed[3].clone()], diff.added);
+            assert_eq!(vec![current[0].clone()], diff.removed);
+            assert_eq!(vec![AuthRuleChange { current: current[1].clone(), proposed: proposed[0].clone() }], diff.changed);
+            assert_eq!(2, diff.unchanged);
+        }
+
+        #[test]
+        fn diff_auth_rules_works_for_duplicate_rules() {
+            let ledger_service = LedgerService::new();
+
+            let mut proposed = _auth_rules();
+            proposed.push(proposed[0].clone());
+
+            let res = ledger_service.diff_auth_rules(&_auth_rules(), &proposed);
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+    }
+
+    mod author_agreement {
+        use super::*;
+
+        const TEXT: &str = "indy agreement";
+        const VERSION: &str = "1.0.0";
+
+        #[test]
+        fn build_txn_author_agreement_request() {
+            let ledger_service = LedgerService::new();
+
+            let expected_result = json!({
+                "type": TXN_AUTHR_AGRMT,
+                "text": TEXT,
+                "version": VERSION
+            });
+
+            let request = ledger_service.build_txn_author_agreement_request(&identifier(), Some(TEXT), VERSION, None, None).unwrap();
+            check_request(&request, expected_result);
+        }
+
+        #[test]
+        fn build_txn_author_agreement_request_works_for_retired_wo_text() {
+            let ledger_service = LedgerService::new();
+
+            let expected_result = json!({
+                "type": TXN_AUTHR_AGRMT,
+                "version": VERSION,
+                "ratification_ts": 12345,
+                "retirement_ts": 54321,
+            });
+
+            let request = ledger_service.build_txn_author_agreement_request(&identifier(), None, VERSION, Some(12345), Some(54321)).unwrap();
+            check_request(&request, expected_result);
+        }
+
+        #[test]
+        fn build_get_txn_author_agreement_request_works() {
+            let ledger_service = LedgerService::new();
+
+            let expected_result = json!({
+                "type": GET_TXN_AUTHR_AGRMT
+            });
+
+            let request = ledger_service.build_get_txn_author_agreement_request(Some(&identifier()), None).unwrap();
+            check_request(&request, expected_result);
+        }
+
+        #[test]
+        fn build_get_txn_author_agreement_request_for_specific_version() {
+            let ledger_service = LedgerService::new();
+
+            let expected_result = json!({
+                "type": GET_TXN_AUTHR_AGRMT,
+                "version": VERSION
+            });
+
+            let data = GetTxnAuthorAgreementData {
+                digest: None,
+                version: Some(VERSION.to_string()),
+                timestamp: None,
+            };
+
+            let request = ledger_service.build_get_txn_author_agreement_request(Some(&identifier()), Some(&data)).unwrap();
+            check_request(&request, expected_result);
+        }
+
+        const MECHANISM: &str = "at_submission";
+        const TAA_TIME: u64 = 1589000000;
+        const SEC_IN_DAY: u64 = 86400;
+
+        fn _taa_state(aml: &[&str]) -> TxnAuthorAgreementState {
+            TxnAuthorAgreementState {
+                taa: Some(TxnAuthorAgreementData {
+                    text: Some(TEXT.to_string()),
+                    version: VERSION.to_string(),
+                    digest: None,
+                    ratification_ts: None,
+                    retirement_ts: None,
+                }),
+                taa_time: Some(TAA_TIME),
+                aml: Some(AcceptanceMechanisms(aml.iter().map(|label| (label.to_string(), json!("description"))).collect())),
+            }
+        }
+
+        #[test]
+        fn parse_txn_author_agreement_state_works() {
+            let ledger_service = LedgerService::new();
+
+            let taa_response = json!({
+                "op": "REPLY",
+                "result": {"type": GET_TXN_AUTHR_AGRMT, "reqId": 1, "txnTime": TAA_TIME, "data": {"text": TEXT, "version": VERSION}}
+            }).to_string();
+            let aml_response = json!({
+                "op": "REPLY",
+                "result": {"type": GET_TXN_AUTHR_AGRMT_AML, "reqId": 2, "data": {"aml": {MECHANISM: "description"}, "version": VERSION}}
+            }).to_string();
+
+            let state = ledger_service.parse_txn_author_agreement_state(&taa_response, &aml_response).unwrap();
+
+            assert_eq!(state.taa.unwrap().text, Some(TEXT.to_string()));
+            assert_eq!(state.taa_time, Some(TAA_TIME));
+            assert!(state.aml.unwrap().0.contains_key(MECHANISM));
+        }
+
+        #[test]
+        fn parse_txn_author_agreement_state_works_for_disabled_agreement() {
+            let ledger_service = LedgerService::new();
+
+            let taa_response = json!({
+                "op": "REPLY",
+                "result": {"type": GET_TXN_AUTHR_AGRMT, "reqId": 1, "txnTime": TAA_TIME, "data": {"text": "", "version": VERSION}}
+            }).to_string();
+            let aml_response = json!({
+                "op": "REPLY",
+                "result": {"type": GET_TXN_AUTHR_AGRMT_AML, "reqId": 2, "data": null}
+            }).to_string();
+
+            let state = ledger_service.parse_txn_author_agreement_state(&taa_response, &aml_response).unwrap();
+
+            assert!(state.taa.is_none());
+            assert!(state.aml.is_none());
+        }
+
+        #[test]
+        fn prepare_taa_acceptance_works() {
+            let ledger_service = LedgerService::new();
+
+            let now = TAA_TIME + 3 * SEC_IN_DAY + 100;
+
+            let acceptance = ledger_service.prepare_taa_acceptance(&_taa_state(&[MECHANISM]), None, None, now).unwrap().unwrap();
+
+            assert_eq!(acceptance.mechanism, MECHANISM);
+            assert_eq!(acceptance.taa_digest, hex::encode(ledger_service._calculate_hash(TEXT, VERSION).unwrap()));
+            assert_eq!(acceptance.time, now / SEC_IN_DAY * SEC_IN_DAY);
+        }
+
+        #[test]
+        fn prepare_taa_acceptance_works_for_time_preceding_agreement() {
+            let ledger_service = LedgerService::new();
+
+            let acceptance = ledger_service.prepare_taa_acceptance(&_taa_state(&[MECHANISM]), None, None, TAA_TIME - 1000).unwrap().unwrap();
+
+            assert_eq!(acceptance.time, TAA_TIME / SEC_IN_DAY * SEC_IN_DAY);
+        }
+
+        #[test]
+        fn prepare_taa_acceptance_works_for_previous_acceptance() {
+            let ledger_service = LedgerService::new();
+            let state = _taa_state(&[MECHANISM, "for_session"]);
+
+            let accepted = ledger_service.prepare_taa_acceptance(&state, None, Some(MECHANISM), TAA_TIME).unwrap().unwrap();
+            let acceptance = ledger_service.prepare_taa_acceptance(&state, Some(&accepted), None, TAA_TIME + 10 * SEC_IN_DAY).unwrap().unwrap();
+
+            assert_eq!(accepted, acceptance);
+        }
+
+        #[test]
+        fn prepare_taa_acceptance_works_for_previous_acceptance_of_other_agreement() {
+            let ledger_service = LedgerService::new();
+
+            let accepted = TxnAuthrAgrmtAcceptanceData { mechanism: MECHANISM.to_string(), taa_digest: "other digest".to_string(), time: 0 };
+            let now = TAA_TIME + SEC_IN_DAY;
+
+            let acceptance = ledger_service.prepare_taa_acceptance(&_taa_state(&[MECHANISM]), Some(&accepted), None, now).unwrap().unwrap();
+
+            assert_eq!(acceptance.time, now / SEC_IN_DAY * SEC_IN_DAY);
+        }
+
+        #[test]
+        fn prepare_taa_acceptance_works_for_not_set_agreement() {
+            let ledger_service = LedgerService::new();
+
+            let state = TxnAuthorAgreementState { taa: None, taa_time: None, aml: None };
+
+            assert!(ledger_service.prepare_taa_acceptance(&state, None, None, TAA_TIME).unwrap().is_none());
+        }
+
+        #[test]
+        fn prepare_taa_acceptance_works_for_several_mechanisms() {
+            let ledger_service = LedgerService::new();
+
+            let res = ledger_service.prepare_taa_acceptance(&_taa_state(&[MECHANISM, "for_session"]), None, None, TAA_TIME);
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
+        #[test]
+        fn prepare_taa_acceptance_works_for_unknown_mechanism() {
+            let ledger_service = LedgerService::new();
+
+            let res = ledger_service.prepare_taa_acceptance(&_taa_state(&[MECHANISM]), None, Some("unknown"), TAA_TIME);
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+    }
+
+    mod acceptance_mechanism {
+        use super::*;
+
+        const LABEL: &str = "label";
+        const VERSION: &str = "1.0.0";
+        const CONTEXT: &str = "some context";
+        const TIMESTAMP: u64 = 123456789;
+
+        fn _aml() -> AcceptanceMechanisms {
+            let mut aml: AcceptanceMechanisms = AcceptanceMechanisms::new();
+            aml.0.insert(LABEL.to_string(), json!({"text": "This is description for acceptance mechanism"}));
+            aml
+        }
+
+        #[test]
+        fn build_acceptance_mechanisms_request() {
+            let ledger_service = LedgerService::new();
+
+            let expected_result = json!({
+                "type": TXN_AUTHR_AGRMT_AML,
+                "aml":  _aml(),
+                "version":  VERSION,
+            });
+
+            let request = ledger_service.build_acceptance_mechanisms_request(&identifier(), _aml(), VERSION, None).unwrap();
+            check_request(&request, expected_result);
+        }
+
+        #[test]
+        fn build_acceptance_mechanisms_request_with_context() {
+            let ledger_service = LedgerService::new();
+
+            let expected_result = json!({
+                "type": TXN_AUTHR_AGRMT_AML,
+                "aml":  _aml(),
+                "version":  VERSION,
+                "amlContext": CONTEXT.to_string(),
+            });
+
+            let request = ledger_service.build_acceptance_mechanisms_request(&identifier(), _aml(), VERSION, Some(CONTEXT)).unwrap();
+            check_request(&request, expected_result);
+        }
+
+        #[test]
+        fn build_get_acceptance_mechanisms_request() {
+            let ledger_service = LedgerService::new();
+
+            let expected_result = json!({
+                "type": GET_TXN_AUTHR_AGRMT_AML,
+            });
+
+            let request = ledger_service.build_get_acceptance_mechanisms_request(None, None, None).unwrap();
+            check_request(&request, expected_result);
+        }
+
+        #[test]
+        fn build_get_acceptance_mechanisms_request_for_timestamp() {
+            let ledger_service = LedgerService::new();
+
+            let expected_result = json!({
+                "type": GET_TXN_AUTHR_AGRMT_AML,
+                "timestamp": TIMESTAMP,
+            });
+
+            let request = ledger_service.build_get_acceptance_mechanisms_request(None, Some(TIMESTAMP), None).unwrap();
+            check_request(&request, expected_result);
+        }
+
+        #[test]
+        fn build_get_acceptance_mechanisms_request_for_version() {
+            let ledger_service = LedgerService::new();
+
+            let expected_result = json!({
+                "type": GET_TXN_AUTHR_AGRMT_AML,
+                "version": VERSION,
+            });
+
+            let request = ledger_service.build_get_acceptance_mechanisms_request(None, None, Some(VERSION)).unwrap();
+            check_request(&request, expected_result);
+        }
+
+        #[test]
+        fn build_get_acceptance_mechanisms_request_for_timestamp_and_version() {
+            let ledger_service = LedgerService::new();
+
+            let res = ledger_service.build_get_acceptance_mechanisms_request(None, Some(TIMESTAMP), Some(VERSION));
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+    }
+
+    mod parse_typed_responses {
+        use super::*;
+
+        #[test]
+        fn parse_get_nym_response_works_for_v0_reply() {
+            let ledger_service = LedgerService::new();
+
+            let response = json!({
+                "op": "REPLY",
+                "result": {
+                    "type": GET_NYM,
+                    "dest": DEST,
+                    "seqNo": 10,
+                    "txnTime": 1589000000,
+                    "data": json!({"dest": DEST, "identifier": IDENTIFIER, "role": null, "verkey": VERKEY}).to_string()
+                }
+            }).to_string();
+
+            let res = ledger_service.parse_get_nym_response(&response).unwrap();
+
+            assert_eq!(json!({
+                "did": DEST,
+                "verkey": VERKEY,
+                "role": null,
+                "seqNo": 10,
+                "txnTime": 1589000000
+            }), serde_json::from_str::<serde_json::Value>(&res).unwrap());
+        }
+
+        #[test]
+        fn parse_get_attrib_response_works_for_v1_reply() {
+            let ledger_service = LedgerService::new();
+
+            let response = json!({
+                "op": "REPLY",
+                "result": {
+                    "type": GET_ATTR,
+                    "ver": "1",
+                    "txn": {"data": {"did": IDENTIFIER, "raw": r#"{"endpoint":{"ha":"127.0.0.1:5555"}}"#}},
+                    "txnMetadata": {"seqNo": 10, "txnTime": 1589000000, "creationTime": 1589000000},
+                    "multiSignature": {}
+                }
+            }).to_string();
+
+            let res = ledger_service.parse_get_attrib_response(&response).unwrap();
+
+            assert_eq!(json!({
+                "data": {"did": IDENTIFIER, "raw": r#"{"endpoint":{"ha":"127.0.0.1:5555"}}"#},
+                "seqNo": 10,
+                "txnTime": 1589000000
+            }), serde_json::from_str::<serde_json::Value>(&res).unwrap());
+        }
+
+        #[test]
+        fn parse_get_txn_response_works() {
+            let ledger_service = LedgerService::new();
+
+            let response = json!({
+                "op": "REPLY",
+                "result": {
+                    "type": GET_TXN,
+                    "seqNo": 7,
+                    "data": {
+                        "txn": {"type": NYM, "data": {"dest": DEST, "verkey": VERKEY}, "metadata": {"from": IDENTIFIER}},
+                        "txnMetadata": {"seqNo": 7, "txnTime": 1589000000},
+                        "reqSignature": {},
+                        "ver": "1"
+                    }
+                }
+            }).to_string();
+
+            let res = ledger_service.parse_get_txn_response(&response).unwrap();
+
+            assert_eq!(json!({
+                "data": {"type": NYM, "data": {"dest": DEST, "verkey": VERKEY}, "metadata": {"from": IDENTIFIER}},
+                "seqNo": 7,
+                "txnTime": 1589000000
+            }), serde_json::from_str::<serde_json::Value>(&res).unwrap());
+        }
+
+        #[test]
+        fn parse_get_txn_response_works_for_not_found() {
+            let ledger_service = LedgerService::new();
+
+            let response = json!({"op": "REPLY", "result": {"type": GET_TXN, "seqNo": 7, "data": null}}).to_string();
+
+            let res = ledger_service.parse_get_txn_response(&response);
+            assert_kind!(IndyErrorKind::LedgerItemNotFound, res);
+        }
+
+        #[test]
+        fn parse_get_frozen_ledgers_response_works() {
+            let ledger_service = LedgerService::new();
+
+            let response = json!({
+                "op": "REPLY",
+                "result": {
+                    "type": GET_FROZEN_LEDGERS,
+                    "seqNo": 12,
+                    "txnTime": 1589000000,
+                    "data": {
+                        "1000": {"ledger": "ledger_root_1000", "state": "state_root_1000", "seq_no": 55},
+                        "23": {"ledger": "ledger_root_23", "state": "state_root_23", "seq_no": 7}
+                    }
+                }
+            }).to_string();
+
+            let res = ledger_service.parse_get_frozen_ledgers_response(&response).unwrap();
+
+            assert_eq!(json!({
+                "data": [
+                    {"ledgerId": 23, "ledgerRoot": "ledger_root_23", "stateRoot": "state_root_23", "seqNo": 7},
+                    {"ledgerId": 1000, "ledgerRoot": "ledger_root_1000", "stateRoot": "state_root_1000", "seqNo": 55}
+                ],
+                "seqNo": 12,
+                "txnTime": 1589000000
+            }), serde_json::from_str::<serde_json::Value>(&res).unwrap());
+        }
+
+        #[test]
+        fn parse_get_frozen_ledgers_response_works_for_no_frozen_ledgers() {
+            let ledger_service = LedgerService::new();
+
+            let response = json!({"op": "REPLY", "result": {"type": GET_FROZEN_LEDGERS, "data": null}}).to_string();
+
+            let res = ledger_service.parse_get_frozen_ledgers_response(&response).unwrap();
+            assert_eq!(json!({"data": []}), serde_json::from_str::<serde_json::Value>(&res).unwrap());
+        }
+
+        #[test]
+        fn parse_get_frozen_ledgers_response_works_for_invalid_ledger_id() {
+            let ledger_service = LedgerService::new();
+
+            let response = json!({
+                "op": "REPLY",
+                "result": {"type": GET_FROZEN_LEDGERS, "data": {"domain": {"ledger": "root", "state": "root", "seq_no": 1}}}
+            }).to_string();
+
+            let res = ledger_service.parse_get_frozen_ledgers_response(&response);
+            assert_kind!(IndyErrorKind::InvalidTransaction, res);
+        }
+
+        #[test]
+        fn parse_get_auth_rule_typed_response_works() {
+            let ledger_service = LedgerService::new();
+
+            let rule = json!({
                 "auth_type": NYM,
-                "field": FIELD,
-                "new_value": NEW_VALUE,
-                "auth_action": AuthAction::ADD,
-                "constraint": constraint,
+                "auth_action": "ADD",
+                "field": "role",
+                "old_value": null,
+                "new_value": "0",
+                "constraint": {"constraint_id": "ROLE", "role": "0", "sig_count": 1, "need_to_be_owner": false, "metadata": {}}
             });
 
-            let request = ledger_service.build_auth_rule_request(&identifier(), NYM, ADD_AUTH_ACTION, FIELD,
-                                                                 None, Some(NEW_VALUE),
-                                                                 constraint).unwrap();
+            let response = json!({"op": "REPLY", "result": {"type": GET_AUTH_RULE, "data": [rule.clone()]}}).to_string();
 
-            check_request(&request, expected_result);
+            let res = ledger_service.parse_get_auth_rule_typed_response(&response).unwrap();
+            assert_eq!(json!({"data": [rule]}), serde_json::from_str::<serde_json::Value>(&res).unwrap());
         }
 
         #[test]
-        fn build_auth_rule_request_works_for_edit_auth_action() {
+        fn parse_get_validator_info_response_works() {
             let ledger_service = LedgerService::new();
 
-            let expected_result = json!({
-                "type": AUTH_RULE,
-                "auth_type": NYM,
-                "field": FIELD,
-                "old_value": OLD_VALUE,
-                "new_value": NEW_VALUE,
-                "auth_action": AuthAction::EDIT,
-                "constraint": _role_constraint(),
-            });
+            let node1_reply = json!({"op": "REPLY", "result": {"type": GET_VALIDATOR_INFO, "data": {"alias": "Node1"}}}).to_string();
+            let node2_reply = json!({"op": "REQNACK", "reqId": 1, "reason": "client request invalid"}).to_string();
 
-            let request = ledger_service.build_auth_rule_request(&identifier(), NYM, EDIT_AUTH_ACTION, FIELD,
-                                                                 Some(OLD_VALUE), Some(NEW_VALUE),
-                                                                 _role_constraint()).unwrap();
-            check_request(&request, expected_result);
+            let response = json!({"Node1": node1_reply, "Node2": node2_reply, "Node3": "timeout"}).to_string();
+
+            let res = ledger_service.parse_get_validator_info_response(&response).unwrap();
+            let res: serde_json::Value = serde_json::from_str(&res).unwrap();
+
+            assert_eq!(json!({"data": {"alias": "Node1"}}), res["data"]["Node1"]);
+            assert!(res["data"]["Node2"]["data"].is_null());
+            assert!(res["data"]["Node2"]["error"].as_str().unwrap().contains("client request invalid"));
+            assert_eq!(json!({"error": "timeout"}), res["data"]["Node3"]);
         }
 
         #[test]
-        fn build_auth_rule_request_works_for_invalid_auth_action() {
+        fn parse_get_validator_info_summary_response_works() {
             let ledger_service = LedgerService::new();
 
-            let res = ledger_service.build_auth_rule_request(&identifier(), NYM, "WRONG", FIELD, None, Some(NEW_VALUE), _role_constraint());
-            assert_kind!(IndyErrorKind::InvalidStructure, res);
+            let node1_data = json!({
+                "timestamp": 1589000000,
+                "Node_info": {
+                    "Name": "Node1",
+                    "Mode": "participating",
+                    "Metrics": {"uptime": 125, "transaction-count": {"ledger": 10, "pool": 4, "config": 0, "audit": 12}}
+                },
+                "Pool_info": {
+                    "Read_only": false,
+                    "Total_nodes_count": 4,
+                    "f_value": 1,
+                    "Reachable_nodes": [["Node1", 0], ["Node2", 1], ["Node3", null]],
+                    "Unreachable_nodes": [["Node4", null]],
+                    "Blacklisted_nodes": []
+                },
+                "Software": {"indy-node": "1.12.2"}
+            });
+            let node1_reply = json!({"op": "REPLY", "result": {"type": GET_VALIDATOR_INFO, "data": node1_data}}).to_string();
+            let node2_reply = json!({"op": "REQNACK", "reqId": 1, "reason": "client request invalid"}).to_string();
+
+            let response = json!({"Node1": node1_reply, "Node2": node2_reply}).to_string();
+
+            let res = ledger_service.parse_get_validator_info_summary_response(&response).unwrap();
+            let res: serde_json::Value = serde_json::from_str(&res).unwrap();
+
+            assert_eq!(json!({
+                "alias": "Node1",
+                "nodeVersion": "1.12.2",
+                "mode": "participating",
+                "uptime": 125,
+                "ledgerSizes": {"ledger": 10, "pool": 4, "config": 0, "audit": 12},
+                "pool": {
+                    "totalNodes": 4,
+                    "f": 1,
+                    "readOnly": false,
+                    "reachableNodes": ["Node1", "Node2", "Node3"],
+                    "unreachableNodes": ["Node4"],
+                    "blacklistedNodes": []
+                },
+                "timestamp": 1589000000
+            }), res["data"]["Node1"]);
+            assert_eq!("Node2", res["data"]["Node2"]["alias"]);
+            assert!(res["data"]["Node2"]["error"].as_str().unwrap().contains("client request invalid"));
         }
 
         #[test]
-        fn build_get_auth_rule_request_works_for_add_action() {
+        fn parse_get_validator_info_summary_response_works_for_old_node_version() {
             let ledger_service = LedgerService::new();
 
-            let expected_result = json!({
-                "type": GET_AUTH_RULE,
-                "auth_type": NYM,
-                "field": FIELD,
-                "new_value": NEW_VALUE,
-                "auth_action": AuthAction::ADD,
+            // Older nodes keep metrics on the top level and report only installed packages
+            let node_data = json!({
+                "Metrics": {"uptime": 125.7, "transaction-count": {"ledger": 10, "pool": 4}},
+                "Node_info": {"Name": "Node1"},
+                "Software": {"Indy_packages": ["hi  indy-plenum  1.6.53  amd64  Plenum", "hi  indy-node  1.6.78  amd64  Indy node"]}
             });
+            let node_reply = json!({"op": "REPLY", "result": {"type": GET_VALIDATOR_INFO, "data": node_data}}).to_string();
 
-            let request = ledger_service.build_get_auth_rule_request(Some(&identifier()), Some(NYM),
-                                                                     Some(ADD_AUTH_ACTION), Some(FIELD),
-                                                                     None, Some(NEW_VALUE)).unwrap();
-            check_request(&request, expected_result);
+            let response = json!({"Node1": node_reply}).to_string();
+
+            let res = ledger_service.parse_get_validator_info_summary_response(&response).unwrap();
+            let res: serde_json::Value = serde_json::from_str(&res).unwrap();
+
+            assert_eq!(json!({
+                "alias": "Node1",
+                "nodeVersion": "1.6.78",
+                "uptime": 125,
+                "ledgerSizes": {"ledger": 10, "pool": 4}
+            }), res["data"]["Node1"]);
         }
 
         #[test]
-        fn build_get_auth_rule_request_works_for_edit_action() {
+        fn parse_pool_upgrade_status_works() {
             let ledger_service = LedgerService::new();
 
-            let expected_result = json!({
-                "type": GET_AUTH_RULE,
-                "auth_type": NYM,
-                "field": FIELD,
-                "old_value": OLD_VALUE,
-                "new_value": NEW_VALUE,
-                "auth_action": AuthAction::EDIT,
+            let node1_data = json!({
+                "Node_info": {"Name": "Node1"},
+                "Software": {"indy-node": "1.12.2"},
+                "Extractions": {"upgrade_log": [
+                    "2020-01-28 14:29:57.391391\tscheduled\t2020-01-28 14:40:00+00:00\t1.12.3\t1580221797391391000\tindy-node",
+                    "2020-01-28 14:40:01.012345\tstarted\t2020-01-28 14:40:00+00:00\t1.12.3\t1580221797391391000\tindy-node",
+                    ""
+                ]}
             });
+            let node2_data = json!({"Node_info": {"Name": "Node2"}, "Software": {"indy-node": "1.12.2"}});
+            let node1_reply = json!({"op": "REPLY", "result": {"type": GET_VALIDATOR_INFO, "data": node1_data}}).to_string();
+            let node2_reply = json!({"op": "REPLY", "result": {"type": GET_VALIDATOR_INFO, "data": node2_data}}).to_string();
+
+            let response = json!({"Node1": node1_reply, "Node2": node2_reply, "Node3": "timeout"}).to_string();
+
+            let res = ledger_service.parse_pool_upgrade_status(&response).unwrap();
+            let res: serde_json::Value = serde_json::from_str(&res).unwrap();
+
+            assert_eq!(json!({
+                "alias": "Node1",
+                "nodeVersion": "1.12.2",
+                "lastEvent": {
+                    "loggedAt": "2020-01-28 14:40:01.012345",
+                    "event": "started",
+                    "scheduledAt": "2020-01-28 14:40:00+00:00",
+                    "version": "1.12.3",
+                    "upgradeId": "1580221797391391000",
+                    "package": "indy-node"
+                }
+            }), res["data"]["Node1"]);
+            assert_eq!(json!({"alias": "Node2", "nodeVersion": "1.12.2"}), res["data"]["Node2"]);
+            assert_eq!(json!({"alias": "Node3", "error": "timeout"}), res["data"]["Node3"]);
+        }
+    }
 
-            let request = ledger_service.build_get_auth_rule_request(Some(&identifier()), Some(NYM),
-                                                                     Some(EDIT_AUTH_ACTION), Some(FIELD),
-                                                                     Some(OLD_VALUE), Some(NEW_VALUE)).unwrap();
-            check_request(&request, expected_result);
+    mod multi_signatures {
+        use super::*;
+
+        const ENDORSER_DID: &str = "V4SGRU86Z58d6TV7PBUe6f";
+
+        fn _request() -> serde_json::Value {
+            json!({
+                "reqId": 1,
+                "identifier": IDENTIFIER,
+                "endorser": ENDORSER_DID,
+                "operation": {"type": SCHEMA, "data": {"name": "gvt"}},
+                "protocolVersion": 2
+            })
+        }
+
+        fn _signed_request(signatures: serde_json::Value) -> serde_json::Value {
+            let mut request = _request();
+            request["signatures"] = signatures;
+            request
+        }
+
+        fn _auth_rules() -> Vec<AuthRule> {
+            serde_json::from_value(json!([
+                {
+                    "auth_type": SCHEMA,
+                    "auth_action": "ADD",
+                    "field": "*",
+                    "old_value": null,
+                    "new_value": "*",
+                    "constraint": {
+                        "constraint_id": "OR",
+                        "auth_constraints": [
+                            {"constraint_id": "ROLE", "role": "0", "sig_count": 1, "need_to_be_owner": false},
+                            {"constraint_id": "ROLE", "role": "101", "sig_count": 1, "need_to_be_owner": false}
+                        ]
+                    }
+                },
+                {
+                    "auth_type": NYM,
+                    "auth_action": "ADD",
+                    "field": "role",
+                    "old_value": null,
+                    "new_value": "0",
+                    "constraint": {"constraint_id": "ROLE", "role": "0", "sig_count": 2, "need_to_be_owner": false}
+                },
+                {
+                    "auth_type": NYM,
+                    "auth_action": "ADD",
+                    "field": "role",
+                    "old_value": null,
+                    "new_value": "",
+                    "constraint": {"constraint_id": "ROLE", "role": "*", "sig_count": 1, "need_to_be_owner": false}
+                },
+                {
+                    "auth_type": POOL_UPGRADE,
+                    "auth_action": "ADD",
+                    "field": "action",
+                    "old_value": null,
+                    "new_value": "start",
+                    "constraint": {"constraint_id": "FORBIDDEN"}
+                }
+            ])).unwrap()
+        }
+
+        fn _signer(did: &str, role: Option<&str>) -> RequestSigner {
+            RequestSigner {
+                did: did.to_string(),
+                role: role.map(String::from),
+                on_ledger: true,
+                verified: true,
+            }
         }
 
         #[test]
-        fn build_get_auth_rule_request_works_for_none_params() {
+        fn merge_multi_signed_requests_works() {
             let ledger_service = LedgerService::new();
 
-            let expected_result = json!({
-                "type": GET_AUTH_RULE,
-            });
+            let mut author_request = _request();
+            author_request["signature"] = json!("author_signature");
 
-            let request = ledger_service.build_get_auth_rule_request(Some(&identifier()), None,
-                                                                     None, None,
-                                                                     None, None).unwrap();
-            check_request(&request, expected_result);
+            let endorser_request = _signed_request(json!({ENDORSER_DID: "endorser_signature"}));
+
+            let res = ledger_service.merge_multi_signed_requests(vec![author_request, endorser_request]).unwrap();
+            let res: serde_json::Value = serde_json::from_str(&res).unwrap();
+
+            assert_eq!(_signed_request(json!({IDENTIFIER: "author_signature", ENDORSER_DID: "endorser_signature"})), res);
         }
 
         #[test]
-        fn build_get_auth_rule_request_works_for_some_fields_are_specified() {
+        fn merge_multi_signed_requests_works_for_different_requests() {
             let ledger_service = LedgerService::new();
 
-            let res = ledger_service.build_get_auth_rule_request(Some(&identifier()), Some(NYM),
-                                                                 None, Some(FIELD),
-                                                                 None, None);
+            let mut other_request = _signed_request(json!({ENDORSER_DID: "endorser_signature"}));
+            other_request["reqId"] = json!(2);
+
+            let res = ledger_service.merge_multi_signed_requests(vec![_signed_request(json!({IDENTIFIER: "author_signature"})), other_request]);
             assert_kind!(IndyErrorKind::InvalidStructure, res);
         }
 
         #[test]
-        fn build_get_auth_rule_request_works_for_invalid_auth_action() {
+        fn merge_multi_signed_requests_works_for_conflicting_signatures() {
             let ledger_service = LedgerService::new();
 
-            let res = ledger_service.build_get_auth_rule_request(Some(&identifier()), None, Some("WRONG"), None, None, None);
+            let res = ledger_service.merge_multi_signed_requests(vec![
+                _signed_request(json!({IDENTIFIER: "author_signature"})),
+                _signed_request(json!({IDENTIFIER: "other_signature"}))
+            ]);
             assert_kind!(IndyErrorKind::InvalidStructure, res);
         }
 
         #[test]
-        fn build_get_auth_rule_request_works_for_invalid_auth_type() {
+        fn merge_multi_signed_requests_works_for_empty_list() {
             let ledger_service = LedgerService::new();
 
-            let res = ledger_service.build_get_auth_rule_request(Some(&identifier()), Some("WRONG"), None, None, None, None);
+            let res = ledger_service.merge_multi_signed_requests(Vec::new());
             assert_kind!(IndyErrorKind::InvalidStructure, res);
         }
 
         #[test]
-        fn build_auth_rules_request_works() {
+        fn build_request_signatures_report_works_for_complete_request() {
             let ledger_service = LedgerService::new();
 
-            let mut data = AuthRules::new();
-            data.push(AuthRuleData::Add(AddAuthRuleData {
-                auth_type: NYM.to_string(),
-                field: FIELD.to_string(),
-                new_value: Some(NEW_VALUE.to_string()),
-                constraint: _role_constraint(),
-            }));
-
-            data.push(AuthRuleData::Edit(EditAuthRuleData {
-                auth_type: NYM.to_string(),
-                field: FIELD.to_string(),
-                old_value: Some(OLD_VALUE.to_string()),
-                new_value: Some(NEW_VALUE.to_string()),
-                constraint: _role_constraint(),
-            }));
+            let signers = vec![_signer(IDENTIFIER, None), _signer(ENDORSER_DID, Some(ENDORSER))];
 
-            let expected_result = json!({
-                "type": AUTH_RULES,
-                "rules": data.clone(),
-            });
+            let report = ledger_service.build_request_signatures_report(&_request(), &_auth_rules(), signers).unwrap();
 
-            let request = ledger_service.build_auth_rules_request(&identifier(), data).unwrap();
-            check_request(&request, expected_result);
+            assert_eq!(SCHEMA, report.txn_type);
+            assert!(report.missing_signers.is_empty());
+            assert!(report.missing_signatures.is_empty());
+            assert!(!report.forbidden);
+            assert!(report.complete);
         }
-    }
 
-    mod author_agreement {
-        use super::*;
+        #[test]
+        fn build_request_signatures_report_works_for_missing_endorser() {
+            let ledger_service = LedgerService::new();
 
-        const TEXT: &str = "indy agreement";
-        const VERSION: &str = "1.0.0";
+            let report = ledger_service.build_request_signatures_report(&_request(), &_auth_rules(), vec![_signer(IDENTIFIER, None)]).unwrap();
+
+            assert_eq!(vec![ENDORSER_DID.to_string()], report.missing_signers);
+            assert_eq!(vec![MissingSignature { role: Some(TRUSTEE.to_string()), count: 1, need_to_be_owner: false }], report.missing_signatures);
+            assert!(!report.complete);
+        }
 
         #[test]
-        fn build_txn_author_agreement_request() {
+        fn build_request_signatures_report_works_for_not_verified_signature() {
             let ledger_service = LedgerService::new();
 
-            let expected_result = json!({
-                "type": TXN_AUTHR_AGRMT,
-                "text": TEXT,
-                "version": VERSION
-            });
+            let mut endorser = _signer(ENDORSER_DID, Some(ENDORSER));
+            endorser.verified = false;
 
-            let request = ledger_service.build_txn_author_agreement_request(&identifier(), Some(TEXT), VERSION, None, None).unwrap();
-            check_request(&request, expected_result);
+            let report = ledger_service.build_request_signatures_report(&_request(), &_auth_rules(), vec![_signer(IDENTIFIER, None), endorser]).unwrap();
+
+            assert_eq!(1, report.missing_signatures.len());
+            assert!(!report.complete);
         }
 
         #[test]
-        fn build_txn_author_agreement_request_works_for_retired_wo_text() {
+        fn build_request_signatures_report_works_for_field_value_rule() {
             let ledger_service = LedgerService::new();
 
-            let expected_result = json!({
-                "type": TXN_AUTHR_AGRMT,
-                "version": VERSION,
-                "ratification_ts": 12345,
-                "retirement_ts": 54321,
+            let request = json!({
+                "reqId": 1,
+                "identifier": IDENTIFIER,
+                "operation": {"type": NYM, "dest": DEST, "role": TRUSTEE},
+                "protocolVersion": 2
             });
 
-            let request = ledger_service.build_txn_author_agreement_request(&identifier(), None, VERSION, Some(12345), Some(54321)).unwrap();
-            check_request(&request, expected_result);
+            let report = ledger_service.build_request_signatures_report(&request, &_auth_rules(), vec![_signer(IDENTIFIER, Some(TRUSTEE))]).unwrap();
+
+            assert_eq!(Some("0".to_string()), report.auth_rule.new_value);
+            assert_eq!(vec![MissingSignature { role: Some(TRUSTEE.to_string()), count: 1, need_to_be_owner: false }], report.missing_signatures);
         }
 
         #[test]
-        fn build_get_txn_author_agreement_request_works() {
+        fn build_request_signatures_report_works_for_forbidden_action() {
             let ledger_service = LedgerService::new();
 
-            let expected_result = json!({
-                "type": GET_TXN_AUTHR_AGRMT
+            let request = json!({
+                "reqId": 1,
+                "identifier": IDENTIFIER,
+                "operation": {"type": POOL_UPGRADE, "action": "start"},
+                "protocolVersion": 2
             });
 
-            let request = ledger_service.build_get_txn_author_agreement_request(Some(&identifier()), None).unwrap();
-            check_request(&request, expected_result);
+            let report = ledger_service.build_request_signatures_report(&request, &_auth_rules(), vec![_signer(IDENTIFIER, Some(TRUSTEE))]).unwrap();
+
+            assert!(report.forbidden);
+            assert!(!report.complete);
         }
 
         #[test]
-        fn build_get_txn_author_agreement_request_for_specific_version() {
+        fn build_request_signatures_report_works_for_unknown_txn_type() {
             let ledger_service = LedgerService::new();
 
-            let expected_result = json!({
-                "type": GET_TXN_AUTHR_AGRMT,
-                "version": VERSION
-            });
-
-            let data = GetTxnAuthorAgreementData {
-                digest: None,
-                version: Some(VERSION.to_string()),
-                timestamp: None,
-            };
+            let request = json!({"reqId": 1, "identifier": IDENTIFIER, "operation": {"type": ATTRIB}, "protocolVersion": 2});
 
-            let request = ledger_service.build_get_txn_author_agreement_request(Some(&identifier()), Some(&data)).unwrap();
-            check_request(&request, expected_result);
+            let res = ledger_service.build_request_signatures_report(&request, &_auth_rules(), Vec::new());
+            assert_kind!(IndyErrorKind::LedgerItemNotFound, res);
         }
     }
 
-    mod acceptance_mechanism {
+    mod custom_transactions {
         use super::*;
 
-        const LABEL: &str = "label";
-        const VERSION: &str = "1.0.0";
-        const CONTEXT: &str = "some context";
-        const TIMESTAMP: u64 = 123456789;
+        extern fn _builder(data_json: *const c_char, operation_json: *mut *const c_char) -> ErrorCode {
+            let data: serde_json::Value = serde_json::from_str(unsafe { CStr::from_ptr(data_json) }.to_str().unwrap()).unwrap();
+            let operation = CString::new(json!({"config": data}).to_string()).unwrap();
+            unsafe { *operation_json = operation.into_raw(); }
+            ErrorCode::Success
+        }
 
-        fn _aml() -> AcceptanceMechanisms {
-            let mut aml: AcceptanceMechanisms = AcceptanceMechanisms::new();
-            aml.0.insert(LABEL.to_string(), json!({"text": "This is description for acceptance mechanism"}));
-            aml
+        extern fn _parser(_response_json: *const c_char, parsed_json: *mut *const c_char) -> ErrorCode {
+            unsafe { *parsed_json = CString::new(r#"{"parsed":true}"#).unwrap().into_raw(); }
+            ErrorCode::Success
+        }
+
+        extern fn _failed_builder(_data_json: *const c_char, _operation_json: *mut *const c_char) -> ErrorCode {
+            ErrorCode::CommonInvalidStructure
+        }
+
+        extern fn _free(data: *const c_char) -> ErrorCode {
+            drop(unsafe { CString::from_raw(data as *mut c_char) });
+            ErrorCode::Success
         }
 
         #[test]
-        fn build_acceptance_mechanisms_request() {
+        fn build_custom_request_works() {
+            LedgerService::register_txn_builder("1001", _builder, None, _free).unwrap();
+
             let ledger_service = LedgerService::new();
+            let request = ledger_service.build_custom_request(Some(&identifier()), "1001", r#"{"param":1}"#).unwrap();
 
-            let expected_result = json!({
-                "type": TXN_AUTHR_AGRMT_AML,
-                "aml":  _aml(),
-                "version":  VERSION,
-            });
+            check_request(&request, json!({"type": "1001", "config": {"param": 1}}));
+        }
 
-            let request = ledger_service.build_acceptance_mechanisms_request(&identifier(), _aml(), VERSION, None).unwrap();
-            check_request(&request, expected_result);
+        #[test]
+        fn build_custom_request_works_for_builder_error() {
+            LedgerService::register_txn_builder("1002", _failed_builder, None, _free).unwrap();
+
+            let ledger_service = LedgerService::new();
+            let res = ledger_service.build_custom_request(None, "1002", "{}");
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
         }
 
         #[test]
-        fn build_acceptance_mechanisms_request_with_context() {
+        fn build_custom_request_works_for_unregistered_type() {
             let ledger_service = LedgerService::new();
+            let res = ledger_service.build_custom_request(None, "1003", "{}");
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
 
-            let expected_result = json!({
-                "type": TXN_AUTHR_AGRMT_AML,
-                "aml":  _aml(),
-                "version":  VERSION,
-                "amlContext": CONTEXT.to_string(),
-            });
+        #[test]
+        fn register_txn_builder_works_for_default_type() {
+            let res = LedgerService::register_txn_builder(NYM, _builder, None, _free);
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
 
-            let request = ledger_service.build_acceptance_mechanisms_request(&identifier(), _aml(), VERSION, Some(CONTEXT)).unwrap();
-            check_request(&request, expected_result);
+            let res = LedgerService::register_txn_builder("NYM", _builder, None, _free);
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
         }
 
         #[test]
-        fn build_get_acceptance_mechanisms_request() {
+        fn parse_custom_response_works() {
+            LedgerService::register_txn_builder("1004", _builder, Some(_parser), _free).unwrap();
+
             let ledger_service = LedgerService::new();
+            let response = json!({"op": "REPLY", "result": {"type": "1004", "data": {}}}).to_string();
 
-            let expected_result = json!({
-                "type": GET_TXN_AUTHR_AGRMT_AML,
-            });
+            let parsed = ledger_service.parse_custom_response("1004", &response).unwrap();
+            assert_eq!(r#"{"parsed":true}"#, parsed);
+        }
 
-            let request = ledger_service.build_get_acceptance_mechanisms_request(None, None, None).unwrap();
-            check_request(&request, expected_result);
+        #[test]
+        fn parse_custom_response_works_for_other_type_and_reject() {
+            LedgerService::register_txn_builder("1005", _builder, Some(_parser), _free).unwrap();
+
+            let ledger_service = LedgerService::new();
+
+            let response = json!({"op": "REPLY", "result": {"type": "1", "data": {}}}).to_string();
+            let res = ledger_service.parse_custom_response("1005", &response);
+            assert_kind!(IndyErrorKind::InvalidTransaction, res);
+
+            let response = json!({"op": "REJECT", "reqId": 1, "reason": "reason"}).to_string();
+            let res = ledger_service.parse_custom_response("1005", &response);
+            assert_kind!(IndyErrorKind::InvalidTransaction, res);
         }
 
         #[test]
-        fn build_get_acceptance_mechanisms_request_for_timestamp() {
+        fn parse_custom_response_works_for_no_parser() {
+            LedgerService::register_txn_builder("1006", _builder, None, _free).unwrap();
+
             let ledger_service = LedgerService::new();
+            let response = json!({"op": "REPLY", "result": {"type": "1006", "data": {}}}).to_string();
 
-            let expected_result = json!({
-                "type": GET_TXN_AUTHR_AGRMT_AML,
-                "timestamp": TIMESTAMP,
-            });
+            let res = ledger_service.parse_custom_response("1006", &response);
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+    }
 
-            let request = ledger_service.build_get_acceptance_mechanisms_request(None, Some(TIMESTAMP), None).unwrap();
-            check_request(&request, expected_result);
+    mod protocol_version {
+        use super::*;
+
+        fn _request(operation: serde_json::Value) -> String {
+            json!({
+                "reqId": 1,
+                "identifier": IDENTIFIER,
+                "operation": operation,
+                "protocolVersion": 2
+            }).to_string()
         }
 
         #[test]
-        fn build_get_acceptance_mechanisms_request_for_version() {
+        fn set_request_protocol_version_works() {
             let ledger_service = LedgerService::new();
 
-            let expected_result = json!({
-                "type": GET_TXN_AUTHR_AGRMT_AML,
-                "version": VERSION,
-            });
+            let request = ledger_service.set_request_protocol_version(&_request(json!({"type": SCHEMA})), 1).unwrap();
+            let request: serde_json::Value = serde_json::from_str(&request).unwrap();
 
-            let request = ledger_service.build_get_acceptance_mechanisms_request(None, None, Some(VERSION)).unwrap();
-            check_request(&request, expected_result);
+            assert_eq!(json!(1), request["protocolVersion"]);
+            assert_eq!(json!({"type": SCHEMA}), request["operation"]);
         }
 
         #[test]
-        fn build_get_acceptance_mechanisms_request_for_timestamp_and_version() {
+        fn set_request_protocol_version_works_for_cred_def_tag() {
             let ledger_service = LedgerService::new();
 
-            let res = ledger_service.build_get_acceptance_mechanisms_request(None, Some(TIMESTAMP), Some(VERSION));
+            let request = ledger_service.set_request_protocol_version(&_request(json!({"type": CRED_DEF, "tag": "tag"})), 1).unwrap();
+            let request: serde_json::Value = serde_json::from_str(&request).unwrap();
+            assert!(request["operation"].get("tag").is_none());
+
+            let res = ledger_service.set_request_protocol_version(&request.to_string(), 2);
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
+        #[test]
+        fn set_request_protocol_version_works_for_unsupported_version() {
+            let ledger_service = LedgerService::new();
+
+            let res = ledger_service.set_request_protocol_version(&_request(json!({"type": SCHEMA})), 3);
+            assert_kind!(IndyErrorKind::PoolIncompatibleProtocolVersion, res);
+        }
+
+        #[test]
+        fn set_request_protocol_version_works_for_signed_request() {
+            let ledger_service = LedgerService::new();
+
+            let mut request: serde_json::Value = serde_json::from_str(&_request(json!({"type": SCHEMA}))).unwrap();
+            request["signature"] = json!("signature");
+
+            let res = ledger_service.set_request_protocol_version(&request.to_string(), 1);
             assert_kind!(IndyErrorKind::InvalidStructure, res);
         }
     }
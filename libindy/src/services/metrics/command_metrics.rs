@@ -1,18 +1,27 @@
 use variant_count::VariantCount;
 use crate::commands::Command;
+#[cfg(feature = "ledger")]
 use crate::commands::ledger::LedgerCommand;
+#[cfg(feature = "anoncreds")]
 use crate::commands::anoncreds::AnoncredsCommand;
+#[cfg(feature = "anoncreds")]
 use crate::commands::anoncreds::issuer::IssuerCommand;
+#[cfg(feature = "anoncreds")]
 use crate::commands::anoncreds::prover::ProverCommand;
+#[cfg(feature = "anoncreds")]
 use crate::commands::anoncreds::verifier::VerifierCommand;
+#[cfg(feature = "anoncreds")]
 use crate::commands::blob_storage::BlobStorageCommand;
 use crate::commands::crypto::CryptoCommand;
+#[cfg(feature = "ledger")]
 use crate::commands::pool::PoolCommand;
 use crate::commands::did::DidCommand;
 use crate::commands::wallet::WalletCommand;
 use crate::commands::pairwise::PairwiseCommand;
 use crate::commands::non_secrets::NonSecretsCommand;
+#[cfg(feature = "payments")]
 use crate::commands::payments::PaymentsCommand;
+#[cfg(feature = "cache")]
 use crate::commands::cache::CacheCommand;
 use std::fmt;
 use crate::commands::metrics::MetricsCommand;
@@ -34,6 +43,7 @@ impl From<usize> for CommandMetric {
     }
 }
 
+#[cfg(feature = "anoncreds")]
 impl From<&IssuerCommand> for CommandMetric {
     fn from(cmd: &IssuerCommand) -> Self {
         match cmd {
@@ -74,14 +84,17 @@ impl From<&IssuerCommand> for CommandMetric {
     }
 }
 
+#[cfg(feature = "anoncreds")]
 impl From<&ProverCommand> for CommandMetric {
     fn from(cmd: &ProverCommand) -> Self {
         match cmd {
             ProverCommand::CreateMasterSecret(_, _, _) => { CommandMetric::ProverCommandCreateMasterSecret }
             ProverCommand::CreateCredentialRequest(_, _, _, _, _, _) => { CommandMetric::ProverCommandCreateCredentialRequest }
+            ProverCommand::ParseCredentialOffer(_, _, _) => { CommandMetric::ProverCommandParseCredentialOffer }
             ProverCommand::SetCredentialAttrTagPolicy(_, _, _, _, _) => { CommandMetric::ProverCommandSetCredentialAttrTagPolicy }
             ProverCommand::GetCredentialAttrTagPolicy(_, _, _) => { CommandMetric::ProverCommandGetCredentialAttrTagPolicy }
-            ProverCommand::StoreCredential(_, _, _, _, _, _, _) => { CommandMetric::ProverCommandStoreCredential }
+            ProverCommand::StoreCredential(_, _, _, _, _, _, _, _) => { CommandMetric::ProverCommandStoreCredential }
+            ProverCommand::ImportW3CCredential(_, _, _, _, _, _, _) => { CommandMetric::ProverCommandImportW3CCredential }
             ProverCommand::GetCredentials(_, _, _) => { CommandMetric::ProverCommandGetCredentials }
             ProverCommand::GetCredential(_, _, _) => { CommandMetric::ProverCommandGetCredential }
             ProverCommand::DeleteCredential(_, _, _) => { CommandMetric::ProverCommandDeleteCredential }
@@ -95,15 +108,33 @@ impl From<&ProverCommand> for CommandMetric {
             ProverCommand::CreateProof(_, _, _, _, _, _, _, _) => { CommandMetric::ProverCommandCreateProof }
             ProverCommand::CreateRevocationState(_, _, _, _, _, _) => { CommandMetric::ProverCommandCreateRevocationState }
             ProverCommand::UpdateRevocationState(_, _, _, _, _, _, _) => { CommandMetric::ProverCommandUpdateRevocationState }
+            #[cfg(feature = "ledger")]
+            ProverCommand::StartRevocationStateUpdater(_, _, _, _, _) => { CommandMetric::ProverCommandStartRevocationStateUpdater }
+            #[cfg(feature = "ledger")]
+            ProverCommand::StopRevocationStateUpdater(_, _) => { CommandMetric::ProverCommandStopRevocationStateUpdater }
+            #[cfg(feature = "ledger")]
+            ProverCommand::GetUpdatedRevocationStates(_, _) => { CommandMetric::ProverCommandGetUpdatedRevocationStates }
+            #[cfg(feature = "ledger")]
+            ProverCommand::UpdateRevocationStates(_) => { CommandMetric::ProverCommandUpdateRevocationStates }
+            #[cfg(feature = "ledger")]
+            ProverCommand::UpdateRevocationStatesDefAck(_, _, _) => { CommandMetric::ProverCommandUpdateRevocationStatesDefAck }
+            #[cfg(feature = "ledger")]
+            ProverCommand::UpdateRevocationStatesDeltaAck(_, _, _) => { CommandMetric::ProverCommandUpdateRevocationStatesDeltaAck }
         }
     }
 }
 
+#[cfg(feature = "anoncreds")]
 impl From<&VerifierCommand> for CommandMetric {
     fn from(cmd: &VerifierCommand) -> Self {
         match cmd {
             VerifierCommand::VerifyProof(_, _, _, _, _, _, _) => { CommandMetric::VerifierCommandVerifyProof }
             VerifierCommand::GenerateNonce(_) => { CommandMetric::VerifierCommandGenerateNonce }
+            VerifierCommand::ValidateProofRequest(_, _) => { CommandMetric::VerifierCommandValidateProofRequest }
+            #[cfg(feature = "ledger")]
+            VerifierCommand::VerifyProofWithPools(_, _, _, _) => { CommandMetric::VerifierCommandVerifyProofWithPools }
+            #[cfg(feature = "ledger")]
+            VerifierCommand::VerifyProofWithPoolsAck(_, _, _) => { CommandMetric::VerifierCommandVerifyProofWithPoolsAck }
         }
     }
 }
@@ -112,6 +143,8 @@ impl From<&Command> for CommandMetric {
     fn from(cmd: &Command) -> Self {
         match cmd {
             Command::Exit => { CommandMetric::Exit }
+            Command::Shutdown(_) => { CommandMetric::Shutdown }
+            #[cfg(feature = "anoncreds")]
             Command::Anoncreds(cmd) => {
                 match cmd {
                     AnoncredsCommand::Issuer(cmd) => { cmd.into() }
@@ -120,10 +153,16 @@ impl From<&Command> for CommandMetric {
                     AnoncredsCommand::ToUnqualified(_, _) => { CommandMetric::AnoncredsCommandToUnqualified }
                 }
             }
+            #[cfg(feature = "anoncreds")]
             Command::BlobStorage(cmd) => {
                 match cmd {
                     BlobStorageCommand::OpenReader(_, _, _) => { CommandMetric::BlobStorageCommandOpenReader }
                     BlobStorageCommand::OpenWriter(_, _, _) => { CommandMetric::BlobStorageCommandOpenWriter }
+                    BlobStorageCommand::CloseReader(_, _) => { CommandMetric::BlobStorageCommandCloseReader }
+                    BlobStorageCommand::CloseWriter(_, _) => { CommandMetric::BlobStorageCommandCloseWriter }
+                    BlobStorageCommand::ReadTails(_, _, _, _) => { CommandMetric::BlobStorageCommandReadTails }
+                    BlobStorageCommand::RegisterReader(_, _, _) => { CommandMetric::BlobStorageCommandRegisterReader }
+                    BlobStorageCommand::RegisterWriter(_, _, _) => { CommandMetric::BlobStorageCommandRegisterWriter }
                 }
             }
             Command::Crypto(cmd) => {
@@ -141,19 +180,39 @@ impl From<&Command> for CommandMetric {
                     CryptoCommand::UnpackMessage(_, _, _) => { CommandMetric::CryptoCommandUnpackMessage }
                 }
             }
+            #[cfg(feature = "ledger")]
             Command::Ledger(cmd) => {
                 match cmd {
                     LedgerCommand::SignAndSubmitRequest(_, _, _, _, _) => { CommandMetric::LedgerCommandSignAndSubmitRequest }
                     LedgerCommand::SubmitRequest(_, _, _) => { CommandMetric::LedgerCommandSubmitRequest }
+                    LedgerCommand::SubmitRequestIdempotent(_, _, _) => { CommandMetric::LedgerCommandSubmitRequestIdempotent }
+                    LedgerCommand::SubmitRequestIdempotentContinue(_, _, _, _) => { CommandMetric::LedgerCommandSubmitRequestIdempotentContinue }
+                    LedgerCommand::SubmitRequestIdempotentFetchAck(_, _, _) => { CommandMetric::LedgerCommandSubmitRequestIdempotentFetchAck }
                     LedgerCommand::SubmitAck(_, _) => { CommandMetric::LedgerCommandSubmitAck }
+                    LedgerCommand::DropRequest(_) => { CommandMetric::LedgerCommandDropRequest }
+                    LedgerCommand::SubmitRequests(_, _, _) => { CommandMetric::LedgerCommandSubmitRequests }
+                    LedgerCommand::GetTxns(_, _, _, _, _, _) => { CommandMetric::LedgerCommandGetTxns }
+                    LedgerCommand::StreamTxns(_, _, _, _, _, _, _) => { CommandMetric::LedgerCommandStreamTxns }
+                    LedgerCommand::GetTxnsAck(_, _) => { CommandMetric::LedgerCommandGetTxnsAck }
+                    LedgerCommand::SubscribeLedgerEvents(_, _, _, _) => { CommandMetric::LedgerCommandSubscribeLedgerEvents }
+                    LedgerCommand::UnsubscribeLedgerEvents(_, _) => { CommandMetric::LedgerCommandUnsubscribeLedgerEvents }
+                    LedgerCommand::PollLedgerEvents(_) => { CommandMetric::LedgerCommandPollLedgerEvents }
+                    LedgerCommand::PollLedgerEventsAck(_, _) => { CommandMetric::LedgerCommandPollLedgerEventsAck }
                     LedgerCommand::SubmitAction(_, _, _, _, _) => { CommandMetric::LedgerCommandSubmitAction }
+                    LedgerCommand::SubmitRequestWithOptions(_, _, _, _) => { CommandMetric::LedgerCommandSubmitRequestWithOptions }
                     LedgerCommand::SignRequest(_, _, _, _) => { CommandMetric::LedgerCommandSignRequest }
                     LedgerCommand::MultiSignRequest(_, _, _, _) => { CommandMetric::LedgerCommandMultiSignRequest }
+                    LedgerCommand::MergeMultiSignedRequests(_, _) => { CommandMetric::LedgerCommandMergeMultiSignedRequests }
+                    LedgerCommand::CheckRequestSignatures(_, _, _) => { CommandMetric::LedgerCommandCheckRequestSignatures }
+                    LedgerCommand::ForceSignRequest(_, _, _, _) => { CommandMetric::LedgerCommandForceSignRequest }
                     LedgerCommand::BuildGetDdoRequest(_, _, _) => { CommandMetric::LedgerCommandBuildGetDdoRequest }
                     LedgerCommand::BuildNymRequest(_, _, _, _, _, _) => { CommandMetric::LedgerCommandBuildNymRequest }
+                    LedgerCommand::BuildNymRequestWithData(_, _, _, _) => { CommandMetric::LedgerCommandBuildNymRequestWithData }
                     LedgerCommand::BuildAttribRequest(_, _, _, _, _, _) => { CommandMetric::LedgerCommandBuildAttribRequest }
-                    LedgerCommand::BuildGetAttribRequest(_, _, _, _, _, _) => { CommandMetric::LedgerCommandBuildGetAttribRequest }
-                    LedgerCommand::BuildGetNymRequest(_, _, _) => { CommandMetric::LedgerCommandBuildGetNymRequest }
+                    LedgerCommand::BuildSetEndpointRequest(_, _, _, _) => { CommandMetric::LedgerCommandBuildSetEndpointRequest }
+                    LedgerCommand::BuildGetEndpointRequest(_, _, _) => { CommandMetric::LedgerCommandBuildGetEndpointRequest }
+                    LedgerCommand::BuildGetAttribRequest(_, _, _, _, _, _, _, _) => { CommandMetric::LedgerCommandBuildGetAttribRequest }
+                    LedgerCommand::BuildGetNymRequest(_, _, _, _, _) => { CommandMetric::LedgerCommandBuildGetNymRequest }
                     LedgerCommand::ParseGetNymResponse(_, _) => { CommandMetric::LedgerCommandParseGetNymResponse }
                     LedgerCommand::BuildSchemaRequest(_, _, _) => { CommandMetric::LedgerCommandBuildSchemaRequest }
                     LedgerCommand::BuildGetSchemaRequest(_, _, _) => { CommandMetric::LedgerCommandBuildGetSchemaRequest }
@@ -167,6 +226,7 @@ impl From<&Command> for CommandMetric {
                     LedgerCommand::BuildPoolConfigRequest(_, _, _, _) => { CommandMetric::LedgerCommandBuildPoolConfigRequest }
                     LedgerCommand::BuildPoolRestartRequest(_, _, _, _) => { CommandMetric::LedgerCommandBuildPoolRestartRequest }
                     LedgerCommand::BuildPoolUpgradeRequest(_, _, _, _, _, _, _, _, _, _, _, _) => { CommandMetric::LedgerCommandBuildPoolUpgradeRequest }
+                    LedgerCommand::BuildScheduledPoolUpgradeRequest(_, _, _, _) => { CommandMetric::LedgerCommandBuildScheduledPoolUpgradeRequest }
                     LedgerCommand::BuildRevocRegDefRequest(_, _, _) => { CommandMetric::LedgerCommandBuildRevocRegDefRequest }
                     LedgerCommand::BuildGetRevocRegDefRequest(_, _, _) => { CommandMetric::LedgerCommandBuildGetRevocRegDefRequest }
                     LedgerCommand::ParseGetRevocRegDefResponse(_, _) => { CommandMetric::LedgerCommandParseGetRevocRegDefResponse }
@@ -175,26 +235,54 @@ impl From<&Command> for CommandMetric {
                     LedgerCommand::ParseGetRevocRegResponse(_, _) => { CommandMetric::LedgerCommandParseGetRevocRegResponse }
                     LedgerCommand::BuildGetRevocRegDeltaRequest(_, _, _, _, _) => { CommandMetric::LedgerCommandBuildGetRevocRegDeltaRequest }
                     LedgerCommand::ParseGetRevocRegDeltaResponse(_, _) => { CommandMetric::LedgerCommandParseGetRevocRegDeltaResponse }
+                    LedgerCommand::ParseGetAttribResponse(_, _) => { CommandMetric::LedgerCommandParseGetAttribResponse }
+                    LedgerCommand::ParseGetTxnResponse(_, _) => { CommandMetric::LedgerCommandParseGetTxnResponse }
+                    LedgerCommand::ParseGetAuthRuleResponse(_, _) => { CommandMetric::LedgerCommandParseGetAuthRuleResponse }
+                    LedgerCommand::ParseGetValidatorInfoResponse(_, _) => { CommandMetric::LedgerCommandParseGetValidatorInfoResponse }
+                    LedgerCommand::ParseGetValidatorInfoSummaryResponse(_, _) => { CommandMetric::LedgerCommandParseGetValidatorInfoSummaryResponse }
+                    LedgerCommand::ParsePoolUpgradeStatusResponse(_, _) => { CommandMetric::LedgerCommandParsePoolUpgradeStatusResponse }
+                    LedgerCommand::ParseGetTxnAuthorAgreementResponse(_, _) => { CommandMetric::LedgerCommandParseGetTxnAuthorAgreementResponse }
+                    LedgerCommand::ParseGetFrozenLedgersResponse(_, _) => { CommandMetric::LedgerCommandParseGetFrozenLedgersResponse }
+                    LedgerCommand::ParseGetAcceptanceMechanismsResponse(_, _) => { CommandMetric::LedgerCommandParseGetAcceptanceMechanismsResponse }
                     LedgerCommand::RegisterSPParser(_, _, _, _) => { CommandMetric::LedgerCommandRegisterSPParser }
+                    LedgerCommand::RegisterTxnBuilder(_, _, _, _, _) => { CommandMetric::LedgerCommandRegisterTxnBuilder }
+                    LedgerCommand::RegisterLedgerMiddleware(_, _, _, _) => { CommandMetric::LedgerCommandRegisterLedgerMiddleware }
+                    LedgerCommand::UnregisterLedgerMiddleware(_, _) => { CommandMetric::LedgerCommandUnregisterLedgerMiddleware }
+                    LedgerCommand::BuildCustomRequest(_, _, _, _) => { CommandMetric::LedgerCommandBuildCustomRequest }
+                    LedgerCommand::ParseCustomResponse(_, _, _) => { CommandMetric::LedgerCommandParseCustomResponse }
                     LedgerCommand::GetResponseMetadata(_, _) => { CommandMetric::LedgerCommandGetResponseMetadata }
+                    LedgerCommand::VerifyStateProof(_, _, _, _) => { CommandMetric::LedgerCommandVerifyStateProof }
                     LedgerCommand::BuildAuthRuleRequest(_, _, _, _, _, _, _, _) => { CommandMetric::LedgerCommandBuildAuthRuleRequest }
                     LedgerCommand::BuildAuthRulesRequest(_, _, _) => { CommandMetric::LedgerCommandBuildAuthRulesRequest }
                     LedgerCommand::BuildGetAuthRuleRequest(_, _, _, _, _, _, _) => { CommandMetric::LedgerCommandBuildGetAuthRuleRequest }
+                    LedgerCommand::BuildRoleConstraint(_, _, _, _, _, _) => { CommandMetric::LedgerCommandBuildRoleConstraint }
+                    LedgerCommand::BuildCombinedConstraint(_, _, _) => { CommandMetric::LedgerCommandBuildCombinedConstraint }
+                    LedgerCommand::EvaluateAuthRules(_, _, _, _) => { CommandMetric::LedgerCommandEvaluateAuthRules }
+                    LedgerCommand::GetAuthRules(_, _, _, _) => { CommandMetric::LedgerCommandGetAuthRules }
+                    LedgerCommand::DiffAuthRules(_, _, _) => { CommandMetric::LedgerCommandDiffAuthRules }
                     LedgerCommand::GetSchema(_, _, _, _) => { CommandMetric::LedgerCommandGetSchema }
                     LedgerCommand::GetSchemaContinue(_, _, _) => { CommandMetric::LedgerCommandGetSchemaContinue }
                     LedgerCommand::GetCredDef(_, _, _, _) => { CommandMetric::LedgerCommandGetCredDef }
                     LedgerCommand::GetCredDefContinue(_, _, _) => { CommandMetric::LedgerCommandGetCredDefContinue }
+                    LedgerCommand::GetRevocRegDef(_, _, _, _) => { CommandMetric::LedgerCommandGetRevocRegDef }
+                    LedgerCommand::GetRevocRegDefContinue(_, _) => { CommandMetric::LedgerCommandGetRevocRegDefContinue }
+                    LedgerCommand::GetRevocReg(_, _, _, _, _) => { CommandMetric::LedgerCommandGetRevocReg }
+                    LedgerCommand::GetRevocRegDelta(_, _, _, _, _, _) => { CommandMetric::LedgerCommandGetRevocRegDelta }
+                    LedgerCommand::GetRevocRegContinue(_, _, _) => { CommandMetric::LedgerCommandGetRevocRegContinue }
                     LedgerCommand::BuildTxnAuthorAgreementRequest(_, _, _, _, _, _) => { CommandMetric::LedgerCommandBuildTxnAuthorAgreementRequest }
                     LedgerCommand::BuildDisableAllTxnAuthorAgreementsRequest(_, _) => { CommandMetric::LedgerCommandBuildDisableAllTxnAuthorAgreementsRequest }
                     LedgerCommand::BuildGetTxnAuthorAgreementRequest(_, _, _) => { CommandMetric::LedgerCommandBuildGetTxnAuthorAgreementRequest }
                     LedgerCommand::BuildAcceptanceMechanismRequests(_, _, _, _, _) => { CommandMetric::LedgerCommandBuildAcceptanceMechanismRequests }
                     LedgerCommand::BuildGetAcceptanceMechanismsRequest(_, _, _, _) => { CommandMetric::LedgerCommandBuildGetAcceptanceMechanismsRequest }
                     LedgerCommand::AppendTxnAuthorAgreementAcceptanceToRequest(_, _, _, _, _, _, _) => { CommandMetric::LedgerCommandAppendTxnAuthorAgreementAcceptanceToRequest }
+                    LedgerCommand::PrepareRequestWithTaa(_, _, _, _, _, _) => { CommandMetric::LedgerCommandPrepareRequestWithTaa }
                     LedgerCommand::AppendRequestEndorser(_, _, _) => { CommandMetric::LedgerCommandAppendRequestEndorser }
+                    LedgerCommand::SetRequestProtocolVersion(_, _, _) => { CommandMetric::LedgerCommandSetRequestProtocolVersion }
                     LedgerCommand::BuildGetFrozenLedgersRequest(_,_,) => { CommandMetric::LedgerCommandBuildGetFrozenLedgersRequest }
                     LedgerCommand::BuildLedgersFreezeRequest(_,_,_,) => { CommandMetric::LedgerCommandBuildLedgersFreezeRequest }
                 }
             }
+            #[cfg(feature = "ledger")]
             Command::Pool(cmd) => {
                 match cmd {
                     PoolCommand::Create(_, _, _) => { CommandMetric::PoolCommandCreate }
@@ -207,6 +295,7 @@ impl From<&Command> for CommandMetric {
                     PoolCommand::Refresh(_, _) => { CommandMetric::PoolCommandRefresh }
                     PoolCommand::RefreshAck(_, _) => { CommandMetric::PoolCommandRefreshAck }
                     PoolCommand::SetProtocolVersion(_, _) => { CommandMetric::PoolCommandSetProtocolVersion }
+                    PoolCommand::GetReplyCapture(_, _) => { CommandMetric::PoolCommandGetReplyCapture }
                 }
             }
             Command::Did(cmd) => {
@@ -217,21 +306,59 @@ impl From<&Command> for CommandMetric {
                     DidCommand::StoreTheirDid(_, _, _) => { CommandMetric::DidCommandStoreTheirDid }
                     DidCommand::GetMyDidWithMeta(_, _, _) => { CommandMetric::DidCommandGetMyDidWithMeta }
                     DidCommand::ListMyDidsWithMeta(_, _) => { CommandMetric::DidCommandListMyDidsWithMeta }
+                    #[cfg(feature = "ledger")]
                     DidCommand::KeyForDid(_, _, _, _) => { CommandMetric::DidCommandKeyForDid }
                     DidCommand::KeyForLocalDid(_, _, _) => { CommandMetric::DidCommandKeyForLocalDid }
                     DidCommand::SetEndpointForDid(_, _, _, _) => { CommandMetric::DidCommandSetEndpointForDid }
+                    #[cfg(feature = "ledger")]
                     DidCommand::GetEndpointForDid(_, _, _, _) => { CommandMetric::DidCommandGetEndpointForDid }
                     DidCommand::SetDidMetadata(_, _, _, _) => { CommandMetric::DidCommandSetDidMetadata }
                     DidCommand::GetDidMetadata(_, _, _) => { CommandMetric::DidCommandGetDidMetadata }
                     DidCommand::AbbreviateVerkey(_, _, _) => { CommandMetric::DidCommandAbbreviateVerkey }
+                    #[cfg(feature = "ledger")]
                     DidCommand::GetNymAck(_, _, _, _) => { CommandMetric::DidCommandGetNymAck }
+                    #[cfg(feature = "ledger")]
                     DidCommand::GetAttribAck(_, _, _) => { CommandMetric::DidCommandGetAttribAck }
                     DidCommand::QualifyDid(_, _, _, _) => { CommandMetric::DidCommandQualifyDid }
+                    #[cfg(feature = "ledger")]
+                    DidCommand::GetDidDoc(_, _, _, _) => { CommandMetric::DidCommandGetDidDoc }
+                    #[cfg(feature = "ledger")]
+                    DidCommand::GetDidDocAttribAck(_, _, _) => { CommandMetric::DidCommandGetDidDocAttribAck }
+                    #[cfg(feature = "ledger")]
+                    DidCommand::RotateKey(_, _, _, _, _, _) => { CommandMetric::DidCommandRotateKey }
+                    #[cfg(feature = "ledger")]
+                    DidCommand::RotateKeyAck(_, _, _, _) => { CommandMetric::DidCommandRotateKeyAck }
+                    #[cfg(feature = "ledger")]
+                    DidCommand::PublishDidDeactivationAck(_, _, _, _) => { CommandMetric::DidCommandPublishDidDeactivationAck }
+                    #[cfg(feature = "ledger")]
+                    DidCommand::GetNamedEndpointsAttribAck(_, _, _, _) => { CommandMetric::DidCommandGetNamedEndpointsAttribAck }
+                    DidCommand::AddDidKey(_, _, _, _) => { CommandMetric::DidCommandAddDidKey }
+                    DidCommand::RemoveDidKey(_, _, _, _) => { CommandMetric::DidCommandRemoveDidKey }
+                    DidCommand::AddDidService(_, _, _, _) => { CommandMetric::DidCommandAddDidService }
+                    DidCommand::RemoveDidService(_, _, _, _) => { CommandMetric::DidCommandRemoveDidService }
+                    DidCommand::SetMyDidTags(_, _, _, _) => { CommandMetric::DidCommandSetMyDidTags }
+                    DidCommand::OpenMyDidsSearch(_, _, _, _) => { CommandMetric::DidCommandOpenMyDidsSearch }
+                    DidCommand::FetchMyDids(_, _, _) => { CommandMetric::DidCommandFetchMyDids }
+                    DidCommand::CloseMyDidsSearch(_, _) => { CommandMetric::DidCommandCloseMyDidsSearch }
+                    DidCommand::ExportDid(_, _, _, _) => { CommandMetric::DidCommandExportDid }
+                    DidCommand::ImportDid(_, _, _, _) => { CommandMetric::DidCommandImportDid }
+                    DidCommand::SetNamedEndpointForDid(_, _, _, _) => { CommandMetric::DidCommandSetNamedEndpointForDid }
+                    DidCommand::RemoveNamedEndpointForDid(_, _, _, _) => { CommandMetric::DidCommandRemoveNamedEndpointForDid }
+                    #[cfg(feature = "ledger")]
+                    DidCommand::GetNamedEndpointsForDid(_, _, _, _) => { CommandMetric::DidCommandGetNamedEndpointsForDid }
+                    #[cfg(feature = "ledger")]
+                    DidCommand::BuildNamedEndpointsAttribRequest(_, _, _, _) => { CommandMetric::DidCommandBuildNamedEndpointsAttribRequest }
+                    DidCommand::QualifyAllDids(_, _, _, _) => { CommandMetric::DidCommandQualifyAllDids }
+                    DidCommand::DeactivateDid(_, _, _) => { CommandMetric::DidCommandDeactivateDid }
+                    #[cfg(feature = "ledger")]
+                    DidCommand::PublishDidDeactivation(_, _, _, _) => { CommandMetric::DidCommandPublishDidDeactivation }
                 }
             }
             Command::Wallet(cmd) => {
                 match cmd {
                     WalletCommand::RegisterWalletType(_, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _) => { CommandMetric::WalletCommandRegisterWalletType }
+                    WalletCommand::UnregisterWalletType(_, _) => { CommandMetric::WalletCommandUnregisterWalletType }
+                    WalletCommand::SetStorageQueryExtensions(_, _, _) => { CommandMetric::WalletCommandSetStorageQueryExtensions }
                     WalletCommand::Create(_, _, _) => { CommandMetric::WalletCommandCreate }
                     WalletCommand::CreateContinue(_, _, _, _, _) => { CommandMetric::WalletCommandCreateContinue }
                     WalletCommand::Open(_, _, _) => { CommandMetric::WalletCommandOpen }
@@ -240,7 +367,10 @@ impl From<&Command> for CommandMetric {
                     WalletCommand::Delete(_, _, _) => { CommandMetric::WalletCommandDelete }
                     WalletCommand::DeleteContinue(_, _, _, _, _) => { CommandMetric::WalletCommandDeleteContinue }
                     WalletCommand::Export(_, _, _) => { CommandMetric::WalletCommandExport }
-                    WalletCommand::ExportContinue(_, _, _, _, _) => { CommandMetric::WalletCommandExportContinue }
+                    WalletCommand::ExportContinue(_, _, _, _, _, _) => { CommandMetric::WalletCommandExportContinue }
+                    WalletCommand::ExportToBuffer(_, _, _) => { CommandMetric::WalletCommandExportToBuffer }
+                    WalletCommand::StreamExport(_, _, _, _) => { CommandMetric::WalletCommandStreamExport }
+                    WalletCommand::ExportToConsumerContinue(_, _, _, _, _) => { CommandMetric::WalletCommandExportToConsumerContinue }
                     WalletCommand::Import(_, _, _, _) => { CommandMetric::WalletCommandImport }
                     WalletCommand::ImportContinue(_, _, _, _, _) => { CommandMetric::WalletCommandImportContinue }
                     WalletCommand::GenerateKey(_, _) => { CommandMetric::WalletCommandGenerateKey }
@@ -254,22 +384,44 @@ impl From<&Command> for CommandMetric {
                     PairwiseCommand::ListPairwise(_, _) => { CommandMetric::PairwiseCommandListPairwise }
                     PairwiseCommand::GetPairwise(_, _, _) => { CommandMetric::PairwiseCommandGetPairwise }
                     PairwiseCommand::SetPairwiseMetadata(_, _, _, _) => { CommandMetric::PairwiseCommandSetPairwiseMetadata }
+                    PairwiseCommand::UpdatePairwise(_, _, _, _) => { CommandMetric::PairwiseCommandUpdatePairwise }
+                    PairwiseCommand::DeletePairwise(_, _, _) => { CommandMetric::PairwiseCommandDeletePairwise }
+                    PairwiseCommand::LinkPairwiseRecord(_, _, _, _) => { CommandMetric::PairwiseCommandLinkPairwiseRecord }
+                    PairwiseCommand::UnlinkPairwiseRecord(_, _, _, _) => { CommandMetric::PairwiseCommandUnlinkPairwiseRecord }
+                    PairwiseCommand::GetPairwiseLinks(_, _, _, _) => { CommandMetric::PairwiseCommandGetPairwiseLinks }
+                    PairwiseCommand::SetPairwiseMeta(_, _, _, _) => { CommandMetric::PairwiseCommandSetPairwiseMeta }
+                    PairwiseCommand::MigratePairwiseMeta(_, _) => { CommandMetric::PairwiseCommandMigratePairwiseMeta }
+                    PairwiseCommand::OpenPairwiseSearch(_, _, _, _) => { CommandMetric::PairwiseCommandOpenPairwiseSearch }
+                    PairwiseCommand::FetchPairwise(_, _, _) => { CommandMetric::PairwiseCommandFetchPairwise }
+                    PairwiseCommand::ClosePairwiseSearch(_, _) => { CommandMetric::PairwiseCommandClosePairwiseSearch }
                 }
             }
             Command::NonSecrets(cmd) => {
                 match cmd {
                     NonSecretsCommand::AddRecord(_, _, _, _, _, _) => { CommandMetric::NonSecretsCommandAddRecord }
                     NonSecretsCommand::UpdateRecordValue(_, _, _, _, _) => { CommandMetric::NonSecretsCommandUpdateRecordValue }
+                    NonSecretsCommand::CompareAndSwapRecordValue(_, _, _, _, _, _) => { CommandMetric::NonSecretsCommandCompareAndSwapRecordValue }
                     NonSecretsCommand::UpdateRecordTags(_, _, _, _, _) => { CommandMetric::NonSecretsCommandUpdateRecordTags }
                     NonSecretsCommand::AddRecordTags(_, _, _, _, _) => { CommandMetric::NonSecretsCommandAddRecordTags }
                     NonSecretsCommand::DeleteRecordTags(_, _, _, _, _) => { CommandMetric::NonSecretsCommandDeleteRecordTags }
                     NonSecretsCommand::DeleteRecord(_, _, _, _) => { CommandMetric::NonSecretsCommandDeleteRecord }
+                    NonSecretsCommand::ModifyRecordValue(_, _, _, _, _) => { CommandMetric::NonSecretsCommandModifyRecordValue }
+                    NonSecretsCommand::UpsertRecords(_, _, _, _) => { CommandMetric::NonSecretsCommandUpsertRecords }
                     NonSecretsCommand::GetRecord(_, _, _, _, _) => { CommandMetric::NonSecretsCommandGetRecord }
+                    NonSecretsCommand::GetRecordTypes(_, _) => { CommandMetric::NonSecretsCommandGetRecordTypes }
+                    NonSecretsCommand::ExplainSearch(_, _, _, _) => { CommandMetric::NonSecretsCommandExplainSearch }
                     NonSecretsCommand::OpenSearch(_, _, _, _, _) => { CommandMetric::NonSecretsCommandOpenSearch }
                     NonSecretsCommand::FetchSearchNextRecords(_, _, _, _) => { CommandMetric::NonSecretsCommandFetchSearchNextRecords }
                     NonSecretsCommand::CloseSearch(_, _) => { CommandMetric::NonSecretsCommandCloseSearch }
+                    NonSecretsCommand::StreamRecords(_, _, _, _, _, _, _) => { CommandMetric::NonSecretsCommandStreamRecords }
+                    NonSecretsCommand::EnableRecordHistory(_, _, _, _) => { CommandMetric::NonSecretsCommandEnableRecordHistory }
+                    NonSecretsCommand::DisableRecordHistory(_, _, _) => { CommandMetric::NonSecretsCommandDisableRecordHistory }
+                    NonSecretsCommand::GetRecordRevisions(_, _, _, _) => { CommandMetric::NonSecretsCommandGetRecordRevisions }
+                    NonSecretsCommand::GetRecordRevision(_, _, _, _, _) => { CommandMetric::NonSecretsCommandGetRecordRevision }
+                    NonSecretsCommand::PruneRecordHistory(_, _, _, _, _) => { CommandMetric::NonSecretsCommandPruneRecordHistory }
                 }
             }
+            #[cfg(feature = "payments")]
             Command::Payments(cmd) => {
                 match cmd {
                     PaymentsCommand::RegisterMethod(_, _, _) => { CommandMetric::PaymentsCommandRegisterMethod }
@@ -278,6 +430,11 @@ impl From<&Command> for CommandMetric {
                     PaymentsCommand::ListAddresses(_, _) => { CommandMetric::PaymentsCommandListAddresses }
                     PaymentsCommand::AddRequestFees(_, _, _, _, _, _, _) => { CommandMetric::PaymentsCommandAddRequestFees }
                     PaymentsCommand::AddRequestFeesAck(_, _) => { CommandMetric::PaymentsCommandAddRequestFeesAck }
+                    PaymentsCommand::PrepareRequestWithFees(_, _, _, _, _, _, _, _, _) => { CommandMetric::PaymentsCommandPrepareRequestWithFees }
+                    PaymentsCommand::PrepareRequestWithFeesSubmitGetFees(_, _) => { CommandMetric::PaymentsCommandPrepareRequestWithFeesSubmitGetFees }
+                    PaymentsCommand::PrepareRequestWithFeesParseFees(_, _) => { CommandMetric::PaymentsCommandPrepareRequestWithFeesParseFees }
+                    PaymentsCommand::PrepareRequestWithFeesAddFees(_, _) => { CommandMetric::PaymentsCommandPrepareRequestWithFeesAddFees }
+                    PaymentsCommand::PrepareRequestWithFeesSign(_, _) => { CommandMetric::PaymentsCommandPrepareRequestWithFeesSign }
                     PaymentsCommand::ParseResponseWithFees(_, _, _) => { CommandMetric::PaymentsCommandParseResponseWithFees }
                     PaymentsCommand::ParseResponseWithFeesAck(_, _) => { CommandMetric::PaymentsCommandParseResponseWithFeesAck }
                     PaymentsCommand::BuildGetPaymentSourcesRequest(_, _, _, _, _) => { CommandMetric::PaymentsCommandBuildGetPaymentSourcesRequest }
@@ -306,20 +463,59 @@ impl From<&Command> for CommandMetric {
                     PaymentsCommand::SignWithAddressAck(_, _) => { CommandMetric::PaymentsCommandSignWithAddressAck }
                     PaymentsCommand::VerifyWithAddressReq(_, _, _, _) => { CommandMetric::PaymentsCommandVerifyWithAddressReq }
                     PaymentsCommand::VerifyWithAddressAck(_, _) => { CommandMetric::PaymentsCommandVerifyWithAddressAck }
+                    PaymentsCommand::EstimateRequestFees(_, _, _, _, _) => { CommandMetric::PaymentsCommandEstimateRequestFees }
+                    PaymentsCommand::EstimateRequestFeesAck(_, _) => { CommandMetric::PaymentsCommandEstimateRequestFeesAck }
+                    PaymentsCommand::VerifyPaymentReceipts(_, _, _, _) => { CommandMetric::PaymentsCommandVerifyPaymentReceipts }
+                    PaymentsCommand::VerifyPaymentReceiptsAck(_, _) => { CommandMetric::PaymentsCommandVerifyPaymentReceiptsAck }
+                    PaymentsCommand::RegisterReceiptProofParser(_, _, _) => { CommandMetric::PaymentsCommandRegisterReceiptProofParser }
+                    PaymentsCommand::VerifyPaymentReceiptWithProof(_, _, _, _) => { CommandMetric::PaymentsCommandVerifyPaymentReceiptWithProof }
+                    PaymentsCommand::VerifyPaymentReceiptWithProofAck(_, _) => { CommandMetric::PaymentsCommandVerifyPaymentReceiptWithProofAck }
+                    PaymentsCommand::GetPaymentBalances(_, _, _) => { CommandMetric::PaymentsCommandGetPaymentBalances }
+                    PaymentsCommand::GetPaymentBalancesSubmit(_, _, _) => { CommandMetric::PaymentsCommandGetPaymentBalancesSubmit }
+                    PaymentsCommand::GetPaymentBalancesParse(_, _, _) => { CommandMetric::PaymentsCommandGetPaymentBalancesParse }
+                    PaymentsCommand::GetPaymentBalancesCollect(_, _, _) => { CommandMetric::PaymentsCommandGetPaymentBalancesCollect }
+                    PaymentsCommand::RegisterHistoryHandlers(_, _, _, _) => { CommandMetric::PaymentsCommandRegisterHistoryHandlers }
+                    PaymentsCommand::UnregisterMethod(_, _) => { CommandMetric::PaymentsCommandUnregisterMethod }
+                    PaymentsCommand::BuildGetPaymentHistoryRequest(_, _, _, _, _) => { CommandMetric::PaymentsCommandBuildGetPaymentHistoryRequest }
+                    PaymentsCommand::BuildGetPaymentHistoryRequestAck(_, _) => { CommandMetric::PaymentsCommandBuildGetPaymentHistoryRequestAck }
+                    PaymentsCommand::ParseGetPaymentHistoryResponse(_, _, _) => { CommandMetric::PaymentsCommandParseGetPaymentHistoryResponse }
+                    PaymentsCommand::ParseGetPaymentHistoryResponseAck(_, _) => { CommandMetric::PaymentsCommandParseGetPaymentHistoryResponseAck }
                 }
             }
+            #[cfg(feature = "cache")]
             Command::Cache(cmd) => {
                 match cmd {
                     CacheCommand::GetSchema(_, _, _, _, _, _) => { CommandMetric::CacheCommandGetSchema }
-                    CacheCommand::GetSchemaContinue(_, _, _, _) => { CommandMetric::CacheCommandGetSchemaContinue }
+                    CacheCommand::GetSchemaContinue(_, _, _, _, _, _) => { CommandMetric::CacheCommandGetSchemaContinue }
                     CacheCommand::GetCredDef(_, _, _, _, _, _) => { CommandMetric::CacheCommandGetCredDef }
-                    CacheCommand::GetCredDefContinue(_, _, _, _) => { CommandMetric::CacheCommandGetCredDefContinue }
+                    CacheCommand::GetCredDefContinue(_, _, _, _, _, _) => { CommandMetric::CacheCommandGetCredDefContinue }
                     CacheCommand::PurgeSchemaCache(_, _, _) => { CommandMetric::CacheCommandPurgeSchemaCache }
+                    CacheCommand::GetRevocRegDef(_, _, _, _, _, _) => { CommandMetric::CacheCommandGetRevocRegDef }
+                    CacheCommand::GetRevocRegDefContinue(_, _, _, _, _, _) => { CommandMetric::CacheCommandGetRevocRegDefContinue }
+                    CacheCommand::GetRevocReg(_, _, _, _, _, _, _) => { CommandMetric::CacheCommandGetRevocReg }
+                    CacheCommand::GetRevocRegContinue(_, _, _, _, _, _) => { CommandMetric::CacheCommandGetRevocRegContinue }
+                    CacheCommand::GetRevocRegDelta(_, _, _, _, _, _, _, _) => { CommandMetric::CacheCommandGetRevocRegDelta }
+                    CacheCommand::GetRevocRegDeltaContinue(_, _, _, _, _, _) => { CommandMetric::CacheCommandGetRevocRegDeltaContinue }
+                    CacheCommand::PurgeRevocRegCache(_, _, _) => { CommandMetric::CacheCommandPurgeRevocRegCache }
+                    CacheCommand::PrefetchLedgerCache(_, _, _, _) => { CommandMetric::CacheCommandPrefetchLedgerCache }
+                    CacheCommand::PrefetchLedgerCacheContinue(_, _, _, _, _) => { CommandMetric::CacheCommandPrefetchLedgerCacheContinue }
+                    CacheCommand::GetCacheStats(_, _) => { CommandMetric::CacheCommandGetCacheStats }
+                    CacheCommand::SetCacheConfig(_, _, _) => { CommandMetric::CacheCommandSetCacheConfig }
+                    CacheCommand::RegisterLocalSchema(_, _, _) => { CommandMetric::CacheCommandRegisterLocalSchema }
+                    CacheCommand::RegisterLocalCredDef(_, _, _) => { CommandMetric::CacheCommandRegisterLocalCredDef }
+                    CacheCommand::RegisterLocalRevocRegDef(_, _, _) => { CommandMetric::CacheCommandRegisterLocalRevocRegDef }
                     CacheCommand::PurgeCredDefCache(_, _, _) => { CommandMetric::CacheCommandPurgeCredDefCache }
+                    CacheCommand::WalletClosed(_) => { CommandMetric::CacheCommandWalletClosed }
                 }
             }
             Command::Metrics(cmd) => {
-                match cmd { MetricsCommand::CollectMetrics(_) => { CommandMetric::MetricsCommandCollectMetrics } }
+                match cmd {
+                    MetricsCommand::CollectMetrics(_) => { CommandMetric::MetricsCommandCollectMetrics }
+                    MetricsCommand::CollectMetricsPrometheus(_) => { CommandMetric::MetricsCommandCollectMetricsPrometheus }
+                    MetricsCommand::CollectMetricsDelta(_) => { CommandMetric::MetricsCommandCollectMetricsDelta }
+                    MetricsCommand::ResetMetrics(_) => { CommandMetric::MetricsCommandResetMetrics }
+                    MetricsCommand::ListHandles(_) => { CommandMetric::MetricsCommandListHandles }
+                }
             }
         }
     }
@@ -344,9 +540,11 @@ pub enum CommandMetric {
     // ProverCommand
     ProverCommandCreateMasterSecret,
     ProverCommandCreateCredentialRequest,
+    ProverCommandParseCredentialOffer,
     ProverCommandSetCredentialAttrTagPolicy,
     ProverCommandGetCredentialAttrTagPolicy,
     ProverCommandStoreCredential,
+    ProverCommandImportW3CCredential,
     ProverCommandGetCredentials,
     ProverCommandGetCredential,
     ProverCommandDeleteCredential,
@@ -360,14 +558,28 @@ pub enum CommandMetric {
     ProverCommandCreateProof,
     ProverCommandCreateRevocationState,
     ProverCommandUpdateRevocationState,
+    ProverCommandStartRevocationStateUpdater,
+    ProverCommandStopRevocationStateUpdater,
+    ProverCommandGetUpdatedRevocationStates,
+    ProverCommandUpdateRevocationStates,
+    ProverCommandUpdateRevocationStatesDefAck,
+    ProverCommandUpdateRevocationStatesDeltaAck,
     // VerifierCommand
     VerifierCommandVerifyProof,
     VerifierCommandGenerateNonce,
+    VerifierCommandValidateProofRequest,
+    VerifierCommandVerifyProofWithPools,
+    VerifierCommandVerifyProofWithPoolsAck,
     // AnoncredsCommand
     AnoncredsCommandToUnqualified,
     // BlobStorage
     BlobStorageCommandOpenReader,
     BlobStorageCommandOpenWriter,
+    BlobStorageCommandCloseReader,
+    BlobStorageCommandCloseWriter,
+    BlobStorageCommandReadTails,
+    BlobStorageCommandRegisterReader,
+    BlobStorageCommandRegisterWriter,
     // CryptoCommand
     CryptoCommandCreateKey,
     CryptoCommandSetKeyMetadata,
@@ -383,13 +595,32 @@ pub enum CommandMetric {
     LedgerCommandSignAndSubmitRequest,
     // LedgerCommand
     LedgerCommandSubmitRequest,
+    LedgerCommandSubmitRequestIdempotent,
+    LedgerCommandSubmitRequestIdempotentContinue,
+    LedgerCommandSubmitRequestIdempotentFetchAck,
     LedgerCommandSubmitAck,
+    LedgerCommandDropRequest,
+    LedgerCommandSubmitRequests,
+    LedgerCommandGetTxns,
+    LedgerCommandStreamTxns,
+    LedgerCommandGetTxnsAck,
+    LedgerCommandSubscribeLedgerEvents,
+    LedgerCommandUnsubscribeLedgerEvents,
+    LedgerCommandPollLedgerEvents,
+    LedgerCommandPollLedgerEventsAck,
     LedgerCommandSubmitAction,
+    LedgerCommandSubmitRequestWithOptions,
     LedgerCommandSignRequest,
     LedgerCommandMultiSignRequest,
+    LedgerCommandMergeMultiSignedRequests,
+    LedgerCommandCheckRequestSignatures,
+    LedgerCommandForceSignRequest,
     LedgerCommandBuildGetDdoRequest,
     LedgerCommandBuildNymRequest,
+    LedgerCommandBuildNymRequestWithData,
     LedgerCommandBuildAttribRequest,
+    LedgerCommandBuildSetEndpointRequest,
+    LedgerCommandBuildGetEndpointRequest,
     LedgerCommandBuildGetAttribRequest,
     LedgerCommandBuildGetNymRequest,
     LedgerCommandParseGetNymResponse,
@@ -405,6 +636,7 @@ pub enum CommandMetric {
     LedgerCommandBuildPoolConfigRequest,
     LedgerCommandBuildPoolRestartRequest,
     LedgerCommandBuildPoolUpgradeRequest,
+    LedgerCommandBuildScheduledPoolUpgradeRequest,
     LedgerCommandBuildRevocRegDefRequest,
     LedgerCommandBuildGetRevocRegDefRequest,
     LedgerCommandParseGetRevocRegDefResponse,
@@ -413,22 +645,49 @@ pub enum CommandMetric {
     LedgerCommandParseGetRevocRegResponse,
     LedgerCommandBuildGetRevocRegDeltaRequest,
     LedgerCommandParseGetRevocRegDeltaResponse,
+    LedgerCommandParseGetAttribResponse,
+    LedgerCommandParseGetTxnResponse,
+    LedgerCommandParseGetAuthRuleResponse,
+    LedgerCommandParseGetValidatorInfoResponse,
+    LedgerCommandParseGetValidatorInfoSummaryResponse,
+    LedgerCommandParsePoolUpgradeStatusResponse,
+    LedgerCommandParseGetTxnAuthorAgreementResponse,
+    LedgerCommandParseGetFrozenLedgersResponse,
+    LedgerCommandParseGetAcceptanceMechanismsResponse,
     LedgerCommandRegisterSPParser,
+    LedgerCommandRegisterTxnBuilder,
+    LedgerCommandRegisterLedgerMiddleware,
+    LedgerCommandUnregisterLedgerMiddleware,
+    LedgerCommandBuildCustomRequest,
+    LedgerCommandParseCustomResponse,
     LedgerCommandGetResponseMetadata,
+    LedgerCommandVerifyStateProof,
     LedgerCommandBuildAuthRuleRequest,
     LedgerCommandBuildAuthRulesRequest,
     LedgerCommandBuildGetAuthRuleRequest,
+    LedgerCommandBuildRoleConstraint,
+    LedgerCommandBuildCombinedConstraint,
+    LedgerCommandEvaluateAuthRules,
+    LedgerCommandGetAuthRules,
+    LedgerCommandDiffAuthRules,
     LedgerCommandGetSchema,
     LedgerCommandGetSchemaContinue,
     LedgerCommandGetCredDef,
     LedgerCommandGetCredDefContinue,
+    LedgerCommandGetRevocRegDef,
+    LedgerCommandGetRevocRegDefContinue,
+    LedgerCommandGetRevocReg,
+    LedgerCommandGetRevocRegDelta,
+    LedgerCommandGetRevocRegContinue,
     LedgerCommandBuildTxnAuthorAgreementRequest,
     LedgerCommandBuildDisableAllTxnAuthorAgreementsRequest,
     LedgerCommandBuildGetTxnAuthorAgreementRequest,
     LedgerCommandBuildAcceptanceMechanismRequests,
     LedgerCommandBuildGetAcceptanceMechanismsRequest,
     LedgerCommandAppendTxnAuthorAgreementAcceptanceToRequest,
+    LedgerCommandPrepareRequestWithTaa,
     LedgerCommandAppendRequestEndorser,
+    LedgerCommandSetRequestProtocolVersion,
     LedgerCommandBuildGetFrozenLedgersRequest,
     LedgerCommandBuildLedgersFreezeRequest,
     // PoolCommand
@@ -442,6 +701,7 @@ pub enum CommandMetric {
     PoolCommandRefresh,
     PoolCommandRefreshAck,
     PoolCommandSetProtocolVersion,
+    PoolCommandGetReplyCapture,
     // DidCommand
     DidCommandCreateAndStoreMyDid,
     DidCommandReplaceKeysStart,
@@ -459,8 +719,33 @@ pub enum CommandMetric {
     DidCommandGetNymAck,
     DidCommandGetAttribAck,
     DidCommandQualifyDid,
+    DidCommandGetDidDoc,
+    DidCommandGetDidDocAttribAck,
+    DidCommandRotateKey,
+    DidCommandRotateKeyAck,
+    DidCommandPublishDidDeactivationAck,
+    DidCommandGetNamedEndpointsAttribAck,
+    DidCommandAddDidKey,
+    DidCommandRemoveDidKey,
+    DidCommandAddDidService,
+    DidCommandRemoveDidService,
+    DidCommandSetMyDidTags,
+    DidCommandOpenMyDidsSearch,
+    DidCommandFetchMyDids,
+    DidCommandCloseMyDidsSearch,
+    DidCommandExportDid,
+    DidCommandImportDid,
+    DidCommandSetNamedEndpointForDid,
+    DidCommandRemoveNamedEndpointForDid,
+    DidCommandGetNamedEndpointsForDid,
+    DidCommandBuildNamedEndpointsAttribRequest,
+    DidCommandQualifyAllDids,
+    DidCommandDeactivateDid,
+    DidCommandPublishDidDeactivation,
     // WalletCommand
     WalletCommandRegisterWalletType,
+    WalletCommandUnregisterWalletType,
+    WalletCommandSetStorageQueryExtensions,
     WalletCommandCreate,
     WalletCommandCreateContinue,
     WalletCommandOpen,
@@ -470,6 +755,9 @@ pub enum CommandMetric {
     WalletCommandDeleteContinue,
     WalletCommandExport,
     WalletCommandExportContinue,
+    WalletCommandExportToBuffer,
+    WalletCommandStreamExport,
+    WalletCommandExportToConsumerContinue,
     WalletCommandImport,
     WalletCommandImportContinue,
     WalletCommandGenerateKey,
@@ -480,17 +768,38 @@ pub enum CommandMetric {
     PairwiseCommandListPairwise,
     PairwiseCommandGetPairwise,
     PairwiseCommandSetPairwiseMetadata,
+    PairwiseCommandUpdatePairwise,
+    PairwiseCommandDeletePairwise,
+    PairwiseCommandLinkPairwiseRecord,
+    PairwiseCommandUnlinkPairwiseRecord,
+    PairwiseCommandGetPairwiseLinks,
+    PairwiseCommandSetPairwiseMeta,
+    PairwiseCommandMigratePairwiseMeta,
+    PairwiseCommandOpenPairwiseSearch,
+    PairwiseCommandFetchPairwise,
+    PairwiseCommandClosePairwiseSearch,
     // NonSecretsCommand
     NonSecretsCommandAddRecord,
     NonSecretsCommandUpdateRecordValue,
+    NonSecretsCommandCompareAndSwapRecordValue,
     NonSecretsCommandUpdateRecordTags,
     NonSecretsCommandAddRecordTags,
     NonSecretsCommandDeleteRecordTags,
     NonSecretsCommandDeleteRecord,
+    NonSecretsCommandModifyRecordValue,
+    NonSecretsCommandUpsertRecords,
     NonSecretsCommandGetRecord,
+    NonSecretsCommandGetRecordTypes,
+    NonSecretsCommandExplainSearch,
     NonSecretsCommandOpenSearch,
     NonSecretsCommandFetchSearchNextRecords,
     NonSecretsCommandCloseSearch,
+    NonSecretsCommandStreamRecords,
+    NonSecretsCommandEnableRecordHistory,
+    NonSecretsCommandDisableRecordHistory,
+    NonSecretsCommandGetRecordRevisions,
+    NonSecretsCommandGetRecordRevision,
+    NonSecretsCommandPruneRecordHistory,
     // PaymentsCommand
     PaymentsCommandRegisterMethod,
     PaymentsCommandCreateAddress,
@@ -498,6 +807,11 @@ pub enum CommandMetric {
     PaymentsCommandListAddresses,
     PaymentsCommandAddRequestFees,
     PaymentsCommandAddRequestFeesAck,
+    PaymentsCommandPrepareRequestWithFees,
+    PaymentsCommandPrepareRequestWithFeesSubmitGetFees,
+    PaymentsCommandPrepareRequestWithFeesParseFees,
+    PaymentsCommandPrepareRequestWithFeesAddFees,
+    PaymentsCommandPrepareRequestWithFeesSign,
     PaymentsCommandParseResponseWithFees,
     PaymentsCommandParseResponseWithFeesAck,
     PaymentsCommandBuildGetPaymentSourcesRequest,
@@ -526,6 +840,23 @@ pub enum CommandMetric {
     PaymentsCommandSignWithAddressAck,
     PaymentsCommandVerifyWithAddressReq,
     PaymentsCommandVerifyWithAddressAck,
+    PaymentsCommandEstimateRequestFees,
+    PaymentsCommandEstimateRequestFeesAck,
+    PaymentsCommandVerifyPaymentReceipts,
+    PaymentsCommandVerifyPaymentReceiptsAck,
+    PaymentsCommandRegisterReceiptProofParser,
+    PaymentsCommandVerifyPaymentReceiptWithProof,
+    PaymentsCommandVerifyPaymentReceiptWithProofAck,
+    PaymentsCommandGetPaymentBalances,
+    PaymentsCommandGetPaymentBalancesSubmit,
+    PaymentsCommandGetPaymentBalancesParse,
+    PaymentsCommandGetPaymentBalancesCollect,
+    PaymentsCommandRegisterHistoryHandlers,
+    PaymentsCommandUnregisterMethod,
+    PaymentsCommandBuildGetPaymentHistoryRequest,
+    PaymentsCommandBuildGetPaymentHistoryRequestAck,
+    PaymentsCommandParseGetPaymentHistoryResponse,
+    PaymentsCommandParseGetPaymentHistoryResponseAck,
     // CacheCommand
     CacheCommandGetSchema,
     CacheCommandGetSchemaContinue,
@@ -533,8 +864,29 @@ pub enum CommandMetric {
     CacheCommandGetCredDefContinue,
     CacheCommandPurgeSchemaCache,
     CacheCommandPurgeCredDefCache,
+    CacheCommandGetRevocRegDef,
+    CacheCommandGetRevocRegDefContinue,
+    CacheCommandGetRevocReg,
+    CacheCommandGetRevocRegContinue,
+    CacheCommandGetRevocRegDelta,
+    CacheCommandGetRevocRegDeltaContinue,
+    CacheCommandPurgeRevocRegCache,
+    CacheCommandPrefetchLedgerCache,
+    CacheCommandPrefetchLedgerCacheContinue,
+    CacheCommandGetCacheStats,
+    CacheCommandSetCacheConfig,
+    CacheCommandRegisterLocalSchema,
+    CacheCommandRegisterLocalCredDef,
+    CacheCommandRegisterLocalRevocRegDef,
+    CacheCommandWalletClosed,
     // MetricsCommand
     MetricsCommandCollectMetrics,
+    MetricsCommandCollectMetricsPrometheus,
+    MetricsCommandCollectMetricsDelta,
+    MetricsCommandResetMetrics,
+    MetricsCommandListHandles,
+    // Shutdown
+    Shutdown,
     // Exit
     Exit,
 }
\ No newline at end of file
@@ -1,35 +1,63 @@
 use crate::services::metrics::command_metrics::CommandMetric;
 use convert_case::{Case, Casing};
 use indy_api_types::errors::{IndyErrorKind, IndyResult, IndyResultExt};
-use models::{MetricsValue, CommandCounters};
+use models::{MetricsValue, CommandCounters, LIST_LE};
 use serde_json::{Map, Value};
 use std::cell::RefCell;
 use std::collections::HashMap;
+use std::sync::Mutex;
 
 pub mod command_metrics;
 pub mod models;
 
 const COMMANDS_COUNT: usize = MetricsService::commands_count();
+const PERCENTILES: [(&str, f64); 3] = [("p50", 0.5), ("p95", 0.95), ("p99", 0.99)];
+
+lazy_static! {
+    static ref BUCKETS_MS: Mutex<Vec<f64>> = Mutex::new(LIST_LE.to_vec());
+}
+
+/// Sets upper bounds of command duration histogram buckets.
+/// Takes effect for metrics service created after the call.
+pub fn set_buckets_ms(buckets_ms: Vec<f64>) {
+    let mut buckets = BUCKETS_MS.lock().unwrap();
+    *buckets = buckets_ms;
+}
 
 pub struct MetricsService {
-    queued_counters: RefCell<[CommandCounters; COMMANDS_COUNT]>,
-    executed_counters: RefCell<[CommandCounters; COMMANDS_COUNT]>,
+    buckets_ms: Vec<f64>,
+    queued_counters: RefCell<Vec<CommandCounters>>,
+    executed_counters: RefCell<Vec<CommandCounters>>,
+    snapshot: RefCell<Option<(Vec<CommandCounters>, Vec<CommandCounters>)>>,
 }
 
 impl MetricsService {
     pub fn new() -> Self {
+        MetricsService::with_buckets(BUCKETS_MS.lock().unwrap().clone())
+    }
+
+    fn with_buckets(buckets_ms: Vec<f64>) -> Self {
         MetricsService {
-            queued_counters: RefCell::new([CommandCounters::new(); COMMANDS_COUNT]),
-            executed_counters: RefCell::new([CommandCounters::new(); COMMANDS_COUNT]),
+            queued_counters: RefCell::new(vec![CommandCounters::new(&buckets_ms); COMMANDS_COUNT]),
+            executed_counters: RefCell::new(vec![CommandCounters::new(&buckets_ms); COMMANDS_COUNT]),
+            snapshot: RefCell::new(None),
+            buckets_ms,
         }
     }
 
+    /// Sets all command counters to zero and drops the snapshot used for deltas.
+    pub fn reset(&self) {
+        *self.queued_counters.borrow_mut() = vec![CommandCounters::new(&self.buckets_ms); COMMANDS_COUNT];
+        *self.executed_counters.borrow_mut() = vec![CommandCounters::new(&self.buckets_ms); COMMANDS_COUNT];
+        *self.snapshot.borrow_mut() = None;
+    }
+
     pub fn cmd_left_queue(&self, command_metric: CommandMetric, duration: u128) {
-        self.queued_counters.borrow_mut()[command_metric as usize].add(duration);
+        self.queued_counters.borrow_mut()[command_metric as usize].add(&self.buckets_ms, duration);
     }
 
     pub fn cmd_executed(&self, command_metric: CommandMetric, duration: u128) {
-        self.executed_counters.borrow_mut()[command_metric as usize].add(duration);
+        self.executed_counters.borrow_mut()[command_metric as usize].add(&self.buckets_ms, duration);
     }
 
     pub fn cmd_name(index: usize) -> String {
@@ -51,9 +79,37 @@ impl MetricsService {
     }
 
     pub fn append_command_metrics(&self, metrics_map: &mut Map<String, Value>) -> IndyResult<()> {
+        self._append_command_metrics(&self.queued_counters.borrow(), &self.executed_counters.borrow(), metrics_map)
+    }
+
+    /// Appends command metrics accumulated since the previous call (or since reset) and takes a new snapshot.
+    pub fn append_command_metrics_delta(&self, metrics_map: &mut Map<String, Value>) -> IndyResult<()> {
+        let queued = self.queued_counters.borrow().clone();
+        let executed = self.executed_counters.borrow().clone();
+
+        let (queued_delta, executed_delta) = match *self.snapshot.borrow() {
+            Some((ref queued_snapshot, ref executed_snapshot)) =>
+                (MetricsService::_since(&queued, queued_snapshot), MetricsService::_since(&executed, executed_snapshot)),
+            None => (queued.clone(), executed.clone())
+        };
+
+        *self.snapshot.borrow_mut() = Some((queued, executed));
+
+        self._append_command_metrics(&queued_delta, &executed_delta, metrics_map)
+    }
+
+    fn _since(counters: &[CommandCounters], snapshot: &[CommandCounters]) -> Vec<CommandCounters> {
+        counters.iter().zip(snapshot.iter()).map(|(counters, snapshot)| counters.since(snapshot)).collect()
+    }
+
+    fn _append_command_metrics(&self,
+                               queued_counters: &[CommandCounters],
+                               executed_counters: &[CommandCounters],
+                               metrics_map: &mut Map<String, Value>) -> IndyResult<()> {
         let mut commands_count = Vec::new();
         let mut commands_duration_ms = Vec::new();
         let mut commands_duration_ms_bucket = Vec::new();
+        let mut commands_duration_ms_percentile = Vec::new();
 
         for index in (0..MetricsService::commands_count()).rev() {
             let command_name = MetricsService::cmd_name(index);
@@ -66,19 +122,32 @@ impl MetricsService {
                 String::from("queued"),
             );
 
-            commands_count.push(self.get_metric_json(self.executed_counters.borrow()[index].count as usize, tags_executed.clone())?);
-            commands_count.push(self.get_metric_json(self.queued_counters.borrow()[index].count as usize, tags_queued.clone())?);
+            commands_count.push(self.get_metric_json(executed_counters[index].count as usize, tags_executed.clone())?);
+            commands_count.push(self.get_metric_json(queued_counters[index].count as usize, tags_queued.clone())?);
 
-            commands_duration_ms.push(self.get_metric_json(self.executed_counters.borrow()[index].duration_ms_sum as usize, tags_executed.clone())?);
-            commands_duration_ms.push(self.get_metric_json(self.queued_counters.borrow()[index].duration_ms_sum as usize,tags_queued.clone())?);
+            commands_duration_ms.push(self.get_metric_json(executed_counters[index].duration_ms_sum as usize, tags_executed.clone())?);
+            commands_duration_ms.push(self.get_metric_json(queued_counters[index].duration_ms_sum as usize,tags_queued.clone())?);
 
-            for index_bucket in (0..self.executed_counters.borrow()[index].duration_ms_bucket.len()).rev() {
-                let executed_bucket = self.executed_counters.borrow()[index].duration_ms_bucket[index_bucket];
-                let queued_bucket = self.queued_counters.borrow()[index].duration_ms_bucket[index_bucket];
+            for index_bucket in (0..executed_counters[index].duration_ms_bucket.len()).rev() {
+                let executed_bucket = executed_counters[index].duration_ms_bucket[index_bucket];
+                let queued_bucket = queued_counters[index].duration_ms_bucket[index_bucket];
 
                 commands_duration_ms_bucket.push(self.get_metric_json(executed_bucket as usize, tags_executed.clone())?);
                 commands_duration_ms_bucket.push(self.get_metric_json(queued_bucket as usize, tags_queued.clone())?);
             }
+
+            for (percentile, quantile) in PERCENTILES.iter() {
+                let executed_percentile = executed_counters[index].percentile(&self.buckets_ms, *quantile);
+                let queued_percentile = queued_counters[index].percentile(&self.buckets_ms, *quantile);
+
+                let mut tags_executed = tags_executed.clone();
+                tags_executed.insert("percentile".to_owned(), percentile.to_string());
+                let mut tags_queued = tags_queued.clone();
+                tags_queued.insert("percentile".to_owned(), percentile.to_string());
+
+                commands_duration_ms_percentile.push(self.get_metric_json(executed_percentile.round() as usize, tags_executed)?);
+                commands_duration_ms_percentile.push(self.get_metric_json(queued_percentile.round() as usize, tags_queued)?);
+            }
         }
 
         metrics_map.insert(
@@ -96,10 +165,39 @@ impl MetricsService {
             serde_json::to_value(commands_duration_ms_bucket)
                 .to_indy(IndyErrorKind::IOError, "Unable to convert json")?,
         );
+        metrics_map.insert(
+            String::from("commands_duration_ms_percentile"),
+            serde_json::to_value(commands_duration_ms_percentile)
+                .to_indy(IndyErrorKind::IOError, "Unable to convert json")?,
+        );
 
         Ok(())
     }
 
+    pub fn append_command_metrics_prometheus(&self, output: &mut String) {
+        output.push_str("# HELP indy_commands_duration_ms Duration of libindy commands in milliseconds.\n");
+        output.push_str("# TYPE indy_commands_duration_ms histogram\n");
+
+        for index in 0..MetricsService::commands_count() {
+            let command_name = MetricsService::cmd_name(index);
+
+            for (stage, counters) in &[("executed", &self.executed_counters), ("queued", &self.queued_counters)] {
+                let counters = &counters.borrow()[index];
+                let labels = format!("command=\"{}\",stage=\"{}\"", command_name, stage);
+
+                for (le_index, le_value) in self.buckets_ms.iter().enumerate() {
+                    output.push_str(&format!("indy_commands_duration_ms_bucket{{{},le=\"{}\"}} {}\n",
+                                             labels, le_value, counters.duration_ms_bucket[le_index]));
+                }
+
+                output.push_str(&format!("indy_commands_duration_ms_bucket{{{},le=\"+Inf\"}} {}\n",
+                                         labels, counters.duration_ms_bucket[counters.duration_ms_bucket.len() - 1]));
+                output.push_str(&format!("indy_commands_duration_ms_sum{{{}}} {}\n", labels, counters.duration_ms_sum));
+                output.push_str(&format!("indy_commands_duration_ms_count{{{}}} {}\n", labels, counters.count));
+            }
+        }
+    }
+
     fn get_metric_json(&self, value: usize, tags: HashMap<String, String>) -> IndyResult<Value> {
         let res = serde_json::to_value(MetricsValue::new(
             value,
@@ -194,6 +292,7 @@ mod test {
         assert!(metrics_map.contains_key("commands_count"));
         assert!(metrics_map.contains_key("commands_duration_ms"));
         assert!(metrics_map.contains_key("commands_duration_ms_bucket"));
+        assert!(metrics_map.contains_key("commands_duration_ms_percentile"));
 
         assert_eq!(
             metrics_map
@@ -222,6 +321,15 @@ mod test {
                 .len(),
             COMMANDS_COUNT * 32
         );
+        assert_eq!(
+            metrics_map
+                .get("commands_duration_ms_percentile")
+                .unwrap()
+                .as_array()
+                .unwrap()
+                .len(),
+            COMMANDS_COUNT * 6
+        );
 
         let commands_count = metrics_map
             .get("commands_count")
@@ -273,6 +381,95 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_append_command_metrics_prometheus() {
+        let metrics_service = MetricsService::new();
+        metrics_service.cmd_executed(CommandMetric::IssuerCommandCreateSchema, 3);
+
+        let mut output = String::new();
+        metrics_service.append_command_metrics_prometheus(&mut output);
+
+        assert!(output.starts_with("# HELP indy_commands_duration_ms"));
+        assert!(output.contains("# TYPE indy_commands_duration_ms histogram\n"));
+
+        let labels = r#"command="issuer_command_create_schema",stage="executed""#;
+        assert!(output.contains(&format!("indy_commands_duration_ms_bucket{{{},le=\"2\"}} 0\n", labels)));
+        assert!(output.contains(&format!("indy_commands_duration_ms_bucket{{{},le=\"5\"}} 1\n", labels)));
+        assert!(output.contains(&format!("indy_commands_duration_ms_bucket{{{},le=\"+Inf\"}} 1\n", labels)));
+        assert!(output.contains(&format!("indy_commands_duration_ms_sum{{{}}} 3\n", labels)));
+        assert!(output.contains(&format!("indy_commands_duration_ms_count{{{}}} 1\n", labels)));
+        assert!(output.contains("indy_commands_duration_ms_count{command=\"issuer_command_create_schema\",stage=\"queued\"} 0\n"));
+    }
+
+    #[test]
+    fn test_append_command_metrics_percentiles_for_custom_buckets() {
+        let metrics_service = MetricsService::with_buckets(vec![10.0, 20.0]);
+        let index = CommandMetric::IssuerCommandCreateSchema;
+
+        for duration in &[5, 5, 15, 15] {
+            metrics_service.cmd_executed(index, *duration);
+        }
+
+        let mut metrics_map = serde_json::Map::new();
+        metrics_service.append_command_metrics(&mut metrics_map).unwrap();
+
+        let percentiles = metrics_map
+            .get("commands_duration_ms_percentile")
+            .unwrap()
+            .as_array()
+            .unwrap();
+
+        assert!(percentiles.contains(&json!({"tags":{"command": "issuer_command_create_schema", "stage": "executed", "percentile": "p50"}, "value": 10})));
+        assert!(percentiles.contains(&json!({"tags":{"command": "issuer_command_create_schema", "stage": "executed", "percentile": "p99"}, "value": 20})));
+        assert!(percentiles.contains(&json!({"tags":{"command": "issuer_command_create_schema", "stage": "queued", "percentile": "p95"}, "value": 0})));
+
+        let commands_duration_ms_bucket = metrics_map
+            .get("commands_duration_ms_bucket")
+            .unwrap()
+            .as_array()
+            .unwrap();
+        assert_eq!(commands_duration_ms_bucket.len(), COMMANDS_COUNT * 6);
+    }
+
+    #[test]
+    fn test_append_command_metrics_delta() {
+        let metrics_service = MetricsService::new();
+        let index = CommandMetric::IssuerCommandCreateSchema;
+
+        metrics_service.cmd_executed(index, 3);
+
+        let mut metrics_map = serde_json::Map::new();
+        metrics_service.append_command_metrics_delta(&mut metrics_map).unwrap();
+        assert!(metrics_map["commands_count"].as_array().unwrap()
+            .contains(&generate_json("issuer_command_create_schema", "executed", 1)));
+
+        metrics_service.cmd_executed(index, 4);
+        metrics_service.cmd_executed(index, 5);
+
+        let mut metrics_map = serde_json::Map::new();
+        metrics_service.append_command_metrics_delta(&mut metrics_map).unwrap();
+        assert!(metrics_map["commands_count"].as_array().unwrap()
+            .contains(&generate_json("issuer_command_create_schema", "executed", 2)));
+        assert!(metrics_map["commands_duration_ms"].as_array().unwrap()
+            .contains(&generate_json("issuer_command_create_schema", "executed", 9)));
+    }
+
+    #[test]
+    fn test_reset() {
+        let metrics_service = MetricsService::new();
+        let index = CommandMetric::IssuerCommandCreateSchema;
+
+        metrics_service.cmd_executed(index, 3);
+        metrics_service.cmd_left_queue(index, 1);
+        metrics_service.append_command_metrics_delta(&mut serde_json::Map::new()).unwrap();
+
+        metrics_service.reset();
+
+        assert_eq!(metrics_service.executed_counters.borrow()[index as usize].count, 0);
+        assert_eq!(metrics_service.queued_counters.borrow()[index as usize].count, 0);
+        assert!(metrics_service.snapshot.borrow().is_none());
+    }
+
     fn generate_json(command: &str, stage: &str, value: usize) -> Value {
         json!({"tags":{"command": command, "stage": stage} ,"value": value})
     }
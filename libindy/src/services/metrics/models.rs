@@ -1,8 +1,8 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-const BUCKET_COUNT: usize = 16;
-const LIST_LE: [f64; BUCKET_COUNT-1] = [0.5, 1.0, 2.0, 5.0, 10.0, 20.0, 50.0, 100.0, 200.0, 500.0, 1000.0, 2000.0, 5000.0, 10000.0, 20000.0];
+pub const BUCKET_COUNT: usize = 16;
+pub const LIST_LE: [f64; BUCKET_COUNT-1] = [0.5, 1.0, 2.0, 5.0, 10.0, 20.0, 50.0, 100.0, 200.0, 500.0, 1000.0, 2000.0, 5000.0, 10000.0, 20000.0];
 
 #[derive(Serialize, Deserialize)]
 pub struct MetricsValue {
@@ -16,31 +16,73 @@ impl MetricsValue {
     }
 }
 
-#[derive(Serialize, Deserialize, Copy, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct CommandCounters {
     pub count: u128,
     pub duration_ms_sum: u128,
-    pub duration_ms_bucket: [u128; BUCKET_COUNT],
+    pub duration_ms_bucket: Vec<u128>,
 }
 
 impl CommandCounters {
-    pub fn new() -> Self {
-        CommandCounters {count: 0, duration_ms_sum: 0, duration_ms_bucket: [0; BUCKET_COUNT]}
+    /// Creates counters for histogram with given upper bounds plus implicit +Inf bucket.
+    pub fn new(list_le: &[f64]) -> Self {
+        CommandCounters {count: 0, duration_ms_sum: 0, duration_ms_bucket: vec![0; list_le.len() + 1]}
     }
 
-    pub fn add(&mut self, duration: u128) {
+    pub fn add(&mut self, list_le: &[f64], duration: u128) {
         self.count += 1;
         self.duration_ms_sum += duration;
-        self.add_buckets(duration);
+        self.add_buckets(list_le, duration);
     }
 
-    fn add_buckets(&mut self, duration: u128) {
-        for (le_index, le_value) in LIST_LE.iter().enumerate() {
-            if duration <= *le_value as u128 {
+    fn add_buckets(&mut self, list_le: &[f64], duration: u128) {
+        for (le_index, le_value) in list_le.iter().enumerate() {
+            if duration as f64 <= *le_value {
                 self.duration_ms_bucket[le_index] += 1;
             }
         }
-        self.duration_ms_bucket[self.duration_ms_bucket.len()-1] += 1;
+        let last = self.duration_ms_bucket.len() - 1;
+        self.duration_ms_bucket[last] += 1;
+    }
+
+    /// Returns counters accumulated after `previous` snapshot. Counters lower than in the snapshot
+    /// mean that they were reset in between, so they are returned as is.
+    pub fn since(&self, previous: &CommandCounters) -> CommandCounters {
+        if self.count < previous.count || self.duration_ms_bucket.len() != previous.duration_ms_bucket.len() {
+            return self.clone();
+        }
+
+        CommandCounters {
+            count: self.count - previous.count,
+            duration_ms_sum: self.duration_ms_sum.saturating_sub(previous.duration_ms_sum),
+            duration_ms_bucket: self.duration_ms_bucket.iter()
+                .zip(previous.duration_ms_bucket.iter())
+                .map(|(current, previous)| current.saturating_sub(*previous))
+                .collect(),
+        }
+    }
+
+    /// Estimates quantile of durations by linear interpolation inside of the matching bucket.
+    /// Durations that fall into +Inf bucket are reported as the highest finite bound.
+    pub fn percentile(&self, list_le: &[f64], quantile: f64) -> f64 {
+        if self.count == 0 {
+            return 0.0;
+        }
+
+        let rank = quantile * self.count as f64;
+        let mut prev_count = 0u128;
+        let mut prev_le = 0.0;
+
+        for (le_index, le_value) in list_le.iter().enumerate() {
+            let count = self.duration_ms_bucket[le_index];
+            if count as f64 >= rank {
+                return prev_le + (le_value - prev_le) * (rank - prev_count as f64) / (count - prev_count) as f64;
+            }
+            prev_count = count;
+            prev_le = *le_value;
+        }
+
+        prev_le
     }
 }
 
@@ -50,9 +92,70 @@ mod test {
 
     #[test]
     fn test_counters_are_initialized_as_zeros() {
-        let command_counters = CommandCounters::new();
+        let command_counters = CommandCounters::new(&LIST_LE);
         assert_eq!(command_counters.count, 0);
         assert_eq!(command_counters.duration_ms_sum, 0);
-        assert_eq!(command_counters.duration_ms_bucket, [0; BUCKET_COUNT]);
+        assert_eq!(command_counters.duration_ms_bucket, vec![0; BUCKET_COUNT]);
+    }
+
+    #[test]
+    fn test_add_uses_custom_buckets() {
+        let list_le = [10.0, 100.0];
+        let mut command_counters = CommandCounters::new(&list_le);
+
+        command_counters.add(&list_le, 5);
+        command_counters.add(&list_le, 50);
+        command_counters.add(&list_le, 500);
+
+        assert_eq!(command_counters.duration_ms_bucket, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_percentile_works() {
+        let list_le = [10.0, 20.0, 40.0];
+        let mut command_counters = CommandCounters::new(&list_le);
+
+        assert_eq!(command_counters.percentile(&list_le, 0.5), 0.0);
+
+        for duration in &[1, 2, 3, 4, 5, 11, 12, 13, 30, 100] {
+            command_counters.add(&list_le, *duration);
+        }
+
+        assert_eq!(command_counters.percentile(&list_le, 0.5), 10.0);
+        assert_eq!(command_counters.percentile(&list_le, 0.8), 20.0);
+        assert_eq!(command_counters.percentile(&list_le, 0.85), 30.0);
+        assert_eq!(command_counters.percentile(&list_le, 0.99), 40.0);
+    }
+
+    #[test]
+    fn test_since_works() {
+        let list_le = [10.0, 100.0];
+        let mut command_counters = CommandCounters::new(&list_le);
+
+        command_counters.add(&list_le, 5);
+        let snapshot = command_counters.clone();
+        command_counters.add(&list_le, 50);
+
+        let delta = command_counters.since(&snapshot);
+
+        assert_eq!(delta.count, 1);
+        assert_eq!(delta.duration_ms_sum, 50);
+        assert_eq!(delta.duration_ms_bucket, vec![0, 1, 1]);
+    }
+
+    #[test]
+    fn test_since_works_for_reset_counters() {
+        let list_le = [10.0, 100.0];
+        let mut snapshot = CommandCounters::new(&list_le);
+        snapshot.add(&list_le, 5);
+        snapshot.add(&list_le, 50);
+
+        let mut command_counters = CommandCounters::new(&list_le);
+        command_counters.add(&list_le, 500);
+
+        let delta = command_counters.since(&snapshot);
+
+        assert_eq!(delta.count, 1);
+        assert_eq!(delta.duration_ms_sum, 500);
     }
 }
@@ -1,7 +1,14 @@
+#[cfg(feature = "anoncreds")]
 pub mod anoncreds;
+#[cfg(feature = "anoncreds")]
 pub mod blob_storage;
 pub mod crypto;
+#[cfg(feature = "ledger")]
 pub mod ledger;
+#[cfg(feature = "null_payment_method")]
+pub mod null_payment_method;
+#[cfg(feature = "payments")]
 pub mod payments;
+#[cfg(feature = "ledger")]
 pub mod pool;
 pub mod metrics;
\ No newline at end of file
@@ -0,0 +1,750 @@
+//! Built-in `null` payment method.
+//!
+//! Implements the whole payment plugin interface in-process against an in-memory ledger so payment
+//! related code paths can be exercised without loading an external payment library.
+//! Transactions are applied to the in-memory ledger as soon as request is built. Built request
+//! carries `reqId` that can be passed to the corresponding `parse_*` handler instead of ledger reply.
+//! Handlers are called from the command executor thread and complete synchronously.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use libc::c_char;
+use rand::Rng;
+use rand::distributions::Alphanumeric;
+use serde_json;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use indy_api_types::{CommandHandle, ErrorCode, WalletHandle};
+use indy_api_types::errors::prelude::*;
+use indy_utils::ctypes;
+
+use crate::services::payments::PaymentsMethodCBs;
+
+pub const NULL_PAYMENT_METHOD: &str = "null";
+
+const PAYMENT_ADDRESS_PREFIX: &str = "pay:null:";
+const SOURCE_PREFIX: &str = "txo:null:";
+const PAYMENT_ADDRESS_LENGTH: usize = 15;
+
+pub const MINT: &str = "null_mint";
+pub const XFER: &str = "null_xfer";
+pub const GET_SOURCES: &str = "null_get_sources";
+pub const SET_FEES: &str = "null_set_fees";
+pub const GET_FEES: &str = "null_get_fees";
+pub const VERIFY: &str = "null_verify";
+
+type StrCB = extern fn(command_handle_: CommandHandle, err: ErrorCode, c_str: *const c_char) -> ErrorCode;
+type StrI64CB = extern fn(command_handle_: CommandHandle, err: ErrorCode, c_str: *const c_char, num: i64) -> ErrorCode;
+type ByteArrayCB = extern fn(command_handle_: CommandHandle, err: ErrorCode, raw: *const u8, len: u32) -> ErrorCode;
+type BoolCB = extern fn(command_handle_: CommandHandle, err: ErrorCode, result: u8) -> ErrorCode;
+type V2CB = extern fn(command_handle_: CommandHandle, err: ErrorCode, error_json: *const c_char, result_json: *const c_char) -> ErrorCode;
+
+lazy_static! {
+    static ref LEDGER: Mutex<NullLedger> = Default::default();
+}
+
+/// Returns handlers of the built-in `null` payment method.
+pub fn method_cbs() -> PaymentsMethodCBs {
+    PaymentsMethodCBs::new(
+        create_payment_address,
+        add_request_fees,
+        parse_response_with_fees,
+        build_get_payment_sources_request,
+        parse_get_payment_sources_response,
+        build_payment_req,
+        parse_payment_response,
+        build_mint_req,
+        build_set_txn_fees_req,
+        build_get_txn_fees_req,
+        parse_get_txn_fees_response,
+        build_verify_payment_req,
+        parse_verify_payment_response,
+        sign_with_address,
+        verify_with_address,
+    ).with_v2_handlers(
+        estimate_request_fees,
+        verify_payment_receipts,
+    )
+}
+
+#[derive(Deserialize, Debug)]
+struct Output {
+    recipient: String,
+    amount: u64,
+}
+
+#[derive(Debug, Clone)]
+struct Source {
+    payment_address: String,
+    amount: u64,
+    extra: Option<String>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct Receipt {
+    pub receipt: String,
+    pub recipient: String,
+    pub amount: u64,
+    pub extra: Option<String>,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SourceInfo {
+    pub source: String,
+    pub payment_address: String,
+    pub amount: u64,
+    pub extra: Option<String>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct ReceiptTxn {
+    pub sources: Vec<String>,
+    pub receipts: Vec<Receipt>,
+    pub extra: Option<String>,
+}
+
+#[derive(Default)]
+pub struct NullLedger {
+    seq_no: u64,
+    req_id: u64,
+    sources: HashMap<String, Source>,
+    txns: HashMap<String, ReceiptTxn>,
+    fees: HashMap<String, u64>,
+    responses: HashMap<u64, String>,
+}
+
+impl NullLedger {
+    pub fn mint(&mut self, outputs: &str, extra: Option<&str>) -> IndyResult<Vec<Receipt>> {
+        let outputs = NullLedger::_parse_outputs(outputs)?;
+        Ok(self._add_txn(Vec::new(), outputs, extra))
+    }
+
+    /// Spends `inputs` to `outputs`. Inputs must cover outputs and fee exactly.
+    pub fn transfer(&mut self, inputs: &str, outputs: &str, extra: Option<&str>, fee: u64) -> IndyResult<Vec<Receipt>> {
+        let inputs: Vec<String> = serde_json::from_str(inputs)
+            .to_indy(IndyErrorKind::InvalidStructure, "Unable to parse inputs")?;
+        let outputs = NullLedger::_parse_outputs(outputs)?;
+
+        let mut total_inputs = 0;
+        for input in inputs.iter() {
+            let source = self.sources.get(input)
+                .ok_or_else(|| err_msg(IndyErrorKind::PaymentSourceDoesNotExist, format!("Source {} does not exist", input)))?;
+            total_inputs += source.amount;
+        }
+
+        let total_outputs = outputs.iter().map(|output| output.amount).sum::<u64>() + fee;
+
+        if total_inputs < total_outputs {
+            return Err(err_msg(IndyErrorKind::PaymentInsufficientFunds,
+                               format!("Inputs amount {} is less than outputs amount with fee {}", total_inputs, total_outputs)));
+        }
+
+        if total_inputs > total_outputs {
+            return Err(err_msg(IndyErrorKind::PaymentExtraFunds,
+                               format!("Inputs amount {} is greater than outputs amount with fee {}", total_inputs, total_outputs)));
+        }
+
+        for input in inputs.iter() {
+            self.sources.remove(input);
+        }
+
+        Ok(self._add_txn(inputs, outputs, extra))
+    }
+
+    pub fn get_sources(&self, payment_address: &str) -> Vec<SourceInfo> {
+        let mut sources: Vec<SourceInfo> = self.sources.iter()
+            .filter(|(_, source)| source.payment_address == payment_address)
+            .map(|(id, source)| SourceInfo {
+                source: id.clone(),
+                payment_address: source.payment_address.clone(),
+                amount: source.amount,
+                extra: source.extra.clone(),
+            })
+            .collect();
+        sources.sort_by(|a, b| a.source.cmp(&b.source));
+        sources
+    }
+
+    pub fn get_txn(&self, receipt: &str) -> IndyResult<ReceiptTxn> {
+        self.txns.get(receipt)
+            .cloned()
+            .ok_or_else(|| err_msg(IndyErrorKind::PaymentSourceDoesNotExist, format!("Receipt {} does not exist", receipt)))
+    }
+
+    pub fn set_fees(&mut self, fees: &str) -> IndyResult<()> {
+        let fees: HashMap<String, u64> = serde_json::from_str(fees)
+            .to_indy(IndyErrorKind::InvalidStructure, "Unable to parse fees")?;
+        self.fees.extend(fees);
+        Ok(())
+    }
+
+    pub fn get_fee(&self, txn_type: &str) -> u64 {
+        self.fees.get(txn_type).cloned().unwrap_or(0)
+    }
+
+    /// Stores reply for the request and returns in-process request that references it.
+    pub fn build_request(&mut self, type_: &str, submitter_did: Option<&str>, data: Value, response: String) -> String {
+        self.req_id += 1;
+        self.responses.insert(self.req_id, response);
+
+        json!({
+            "reqId": self.req_id,
+            "identifier": submitter_did,
+            "protocolVersion": 2,
+            "operation": {
+                "type": type_,
+                "data": data
+            }
+        }).to_string()
+    }
+
+    /// Stores reply for externally built request that already has `reqId`.
+    pub fn store_response(&mut self, request: &str, response: String) -> IndyResult<()> {
+        let req_id = NullLedger::_parse_req_id(request)?;
+        self.responses.insert(req_id, response);
+        Ok(())
+    }
+
+    /// Returns reply stored for the request referenced by ledger reply or request itself.
+    pub fn take_response(&mut self, response: &str) -> IndyResult<String> {
+        let req_id = NullLedger::_parse_req_id(response)?;
+        self.responses.remove(&req_id)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidTransaction, format!("No reply found for request {}", req_id)))
+    }
+
+    fn _add_txn(&mut self, inputs: Vec<String>, outputs: Vec<Output>, extra: Option<&str>) -> Vec<Receipt> {
+        self.seq_no += 1;
+        let seq_no = self.seq_no;
+
+        let receipts: Vec<Receipt> = outputs.into_iter()
+            .enumerate()
+            .map(|(i, output)| {
+                let receipt = format!("{}{}_{}", SOURCE_PREFIX, seq_no, i);
+                self.sources.insert(receipt.clone(), Source {
+                    payment_address: output.recipient.clone(),
+                    amount: output.amount,
+                    extra: extra.map(String::from),
+                });
+                Receipt {
+                    receipt,
+                    recipient: output.recipient,
+                    amount: output.amount,
+                    extra: extra.map(String::from),
+                }
+            })
+            .collect();
+
+        let txn = ReceiptTxn {
+            sources: inputs,
+            receipts: receipts.clone(),
+            extra: extra.map(String::from),
+        };
+
+        for receipt in receipts.iter() {
+            self.txns.insert(receipt.receipt.clone(), txn.clone());
+        }
+
+        receipts
+    }
+
+    fn _parse_outputs(outputs: &str) -> IndyResult<Vec<Output>> {
+        serde_json::from_str(outputs)
+            .to_indy(IndyErrorKind::InvalidStructure, "Unable to parse outputs")
+    }
+
+    fn _parse_req_id(json: &str) -> IndyResult<u64> {
+        let json: Value = serde_json::from_str(json)
+            .to_indy(IndyErrorKind::InvalidStructure, "Unable to parse json")?;
+
+        json["result"]["reqId"].as_u64()
+            .or_else(|| json["reqId"].as_u64())
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "reqId not found"))
+    }
+}
+
+fn _parse_txn_type(req: &str) -> IndyResult<String> {
+    let req: Value = serde_json::from_str(req)
+        .to_indy(IndyErrorKind::InvalidStructure, "Unable to parse request")?;
+
+    match req["operation"]["type"] {
+        Value::String(ref type_) => Ok(type_.clone()),
+        _ => Err(err_msg(IndyErrorKind::InvalidStructure, "Request doesn't contain operation type"))
+    }
+}
+
+fn _signature(address: &str, message: &[u8]) -> Vec<u8> {
+    let mut hasher = Sha256::new();
+    hasher.update(address.as_bytes());
+    hasher.update(message);
+    hasher.finalize().to_vec()
+}
+
+fn _c_str(value: *const c_char) -> IndyResult<String> {
+    _opt_c_str(value)?
+        .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "Null string passed to null payment method"))
+}
+
+fn _opt_c_str(value: *const c_char) -> IndyResult<Option<String>> {
+    ctypes::c_str_to_string(value)
+        .to_indy(IndyErrorKind::InvalidStructure, "Invalid string passed to null payment method")
+        .map(|value| value.map(String::from))
+}
+
+fn _to_json<T: ::serde::Serialize>(value: &T) -> IndyResult<String> {
+    serde_json::to_string(value)
+        .to_indy(IndyErrorKind::InvalidState, "Unable to serialize null payment method result")
+}
+
+fn _ledger<T>(f: impl FnOnce(&mut NullLedger) -> IndyResult<T>) -> IndyResult<T> {
+    let mut ledger = LEDGER.lock()
+        .map_err(|_| err_msg(IndyErrorKind::InvalidState, "Null payment ledger is poisoned"))?;
+    f(&mut ledger)
+}
+
+fn _complete_str(cmd_handle: CommandHandle, cb: Option<StrCB>, res: IndyResult<String>) -> ErrorCode {
+    let cb = match cb {
+        Some(cb) => cb,
+        None => return ErrorCode::CommonInvalidState
+    };
+
+    match res {
+        Ok(res) => {
+            let res = ctypes::string_to_cstring(res);
+            cb(cmd_handle, ErrorCode::Success, res.as_ptr());
+        }
+        Err(err) => {
+            error!("Null payment method error: {}", err);
+            let res = ctypes::str_to_cstring("");
+            cb(cmd_handle, err.kind().into(), res.as_ptr());
+        }
+    }
+
+    ErrorCode::Success
+}
+
+fn _complete_v2(cmd_handle: CommandHandle, cb: Option<V2CB>, res: IndyResult<String>) -> ErrorCode {
+    let cb = match cb {
+        Some(cb) => cb,
+        None => return ErrorCode::CommonInvalidState
+    };
+
+    match res {
+        Ok(res) => {
+            let res = ctypes::string_to_cstring(res);
+            cb(cmd_handle, ErrorCode::Success, ::std::ptr::null(), res.as_ptr());
+        }
+        Err(err) => {
+            let error_json = ctypes::string_to_cstring(json!({"message": err.to_string()}).to_string());
+            let res = ctypes::str_to_cstring("");
+            cb(cmd_handle, err.kind().into(), error_json.as_ptr(), res.as_ptr());
+        }
+    }
+
+    ErrorCode::Success
+}
+
+extern fn create_payment_address(command_handle: CommandHandle,
+                                 _wallet_handle: WalletHandle,
+                                 config: *const c_char,
+                                 cb: Option<StrCB>) -> ErrorCode {
+    trace!("null_payment_method::create_payment_address >>>");
+
+    let res = _c_str(config)
+        .and_then(|config| serde_json::from_str::<Value>(&config)
+            .to_indy(IndyErrorKind::InvalidStructure, "Unable to parse payment address config"))
+        .map(|config| {
+            let address = match config["seed"].as_str() {
+                Some(seed) => hex::encode(Sha256::digest(seed.as_bytes()))[..PAYMENT_ADDRESS_LENGTH].to_string(),
+                None => rand::thread_rng().sample_iter(&Alphanumeric).take(PAYMENT_ADDRESS_LENGTH).collect()
+            };
+            format!("{}{}", PAYMENT_ADDRESS_PREFIX, address)
+        });
+
+    _complete_str(command_handle, cb, res)
+}
+
+extern fn add_request_fees(command_handle: CommandHandle,
+                           _wallet_handle: WalletHandle,
+                           _submitter_did: *const c_char,
+                           req_json: *const c_char,
+                           inputs_json: *const c_char,
+                           outputs_json: *const c_char,
+                           extra: *const c_char,
+                           cb: Option<StrCB>) -> ErrorCode {
+    trace!("null_payment_method::add_request_fees >>>");
+
+    let res = (|| {
+        let req = _c_str(req_json)?;
+        let inputs = _c_str(inputs_json)?;
+        let outputs = _c_str(outputs_json)?;
+        let extra = _opt_c_str(extra)?;
+        let txn_type = _parse_txn_type(&req)?;
+
+        _ledger(|ledger| {
+            let fee = ledger.get_fee(&txn_type);
+            let receipts = ledger.transfer(&inputs, &outputs, extra.as_deref(), fee)?;
+            ledger.store_response(&req, _to_json(&receipts)?)?;
+            Ok(req)
+        })
+    })();
+
+    _complete_str(command_handle, cb, res)
+}
+
+extern fn parse_response_with_fees(command_handle: CommandHandle,
+                                   resp_json: *const c_char,
+                                   cb: Option<StrCB>) -> ErrorCode {
+    trace!("null_payment_method::parse_response_with_fees >>>");
+    _complete_str(command_handle, cb, _parse_response(resp_json))
+}
+
+extern fn build_get_payment_sources_request(command_handle: CommandHandle,
+                                            _wallet_handle: WalletHandle,
+                                            submitter_did: *const c_char,
+                                            payment_address: *const c_char,
+                                            _from: i64,
+                                            cb: Option<StrCB>) -> ErrorCode {
+    trace!("null_payment_method::build_get_payment_sources_request >>>");
+
+    let res = (|| {
+        let submitter_did = _opt_c_str(submitter_did)?;
+        let payment_address = _c_str(payment_address)?;
+
+        _ledger(|ledger| {
+            let sources = _to_json(&ledger.get_sources(&payment_address))?;
+            Ok(ledger.build_request(GET_SOURCES, submitter_did.as_deref(), json!({"address": payment_address}), sources))
+        })
+    })();
+
+    _complete_str(command_handle, cb, res)
+}
+
+extern fn parse_get_payment_sources_response(command_handle: CommandHandle,
+                                             resp_json: *const c_char,
+                                             cb: Option<StrI64CB>) -> ErrorCode {
+    trace!("null_payment_method::parse_get_payment_sources_response >>>");
+
+    let cb = match cb {
+        Some(cb) => cb,
+        None => return ErrorCode::CommonInvalidState
+    };
+
+    match _parse_response(resp_json) {
+        Ok(sources) => {
+            let sources = ctypes::string_to_cstring(sources);
+            cb(command_handle, ErrorCode::Success, sources.as_ptr(), -1);
+        }
+        Err(err) => {
+            let sources = ctypes::str_to_cstring("");
+            cb(command_handle, err.kind().into(), sources.as_ptr(), -1);
+        }
+    }
+
+    ErrorCode::Success
+}
+
+extern fn build_payment_req(command_handle: CommandHandle,
+                            _wallet_handle: WalletHandle,
+                            submitter_did: *const c_char,
+                            inputs_json: *const c_char,
+                            outputs_json: *const c_char,
+                            extra: *const c_char,
+                            cb: Option<StrCB>) -> ErrorCode {
+    trace!("null_payment_method::build_payment_req >>>");
+
+    let res = (|| {
+        let submitter_did = _opt_c_str(submitter_did)?;
+        let inputs = _c_str(inputs_json)?;
+        let outputs = _c_str(outputs_json)?;
+        let extra = _opt_c_str(extra)?;
+
+        _ledger(|ledger| {
+            let receipts = _to_json(&ledger.transfer(&inputs, &outputs, extra.as_deref(), 0)?)?;
+            Ok(ledger.build_request(XFER, submitter_did.as_deref(), json!({"inputs": inputs, "outputs": outputs}), receipts))
+        })
+    })();
+
+    _complete_str(command_handle, cb, res)
+}
+
+extern fn parse_payment_response(command_handle: CommandHandle,
+                                 resp_json: *const c_char,
+                                 cb: Option<StrCB>) -> ErrorCode {
+    trace!("null_payment_method::parse_payment_response >>>");
+    _complete_str(command_handle, cb, _parse_response(resp_json))
+}
+
+extern fn build_mint_req(command_handle: CommandHandle,
+                         _wallet_handle: WalletHandle,
+                         submitter_did: *const c_char,
+                         outputs_json: *const c_char,
+                         extra: *const c_char,
+                         cb: Option<StrCB>) -> ErrorCode {
+    trace!("null_payment_method::build_mint_req >>>");
+
+    let res = (|| {
+        let submitter_did = _opt_c_str(submitter_did)?;
+        let outputs = _c_str(outputs_json)?;
+        let extra = _opt_c_str(extra)?;
+
+        _ledger(|ledger| {
+            let receipts = _to_json(&ledger.mint(&outputs, extra.as_deref())?)?;
+            Ok(ledger.build_request(MINT, submitter_did.as_deref(), json!({"outputs": outputs}), receipts))
+        })
+    })();
+
+    _complete_str(command_handle, cb, res)
+}
+
+extern fn build_set_txn_fees_req(command_handle: CommandHandle,
+                                 _wallet_handle: WalletHandle,
+                                 submitter_did: *const c_char,
+                                 fees_json: *const c_char,
+                                 cb: Option<StrCB>) -> ErrorCode {
+    trace!("null_payment_method::build_set_txn_fees_req >>>");
+
+    let res = (|| {
+        let submitter_did = _opt_c_str(submitter_did)?;
+        let fees = _c_str(fees_json)?;
+
+        _ledger(|ledger| {
+            ledger.set_fees(&fees)?;
+            Ok(ledger.build_request(SET_FEES, submitter_did.as_deref(), json!({"fees": fees}), String::new()))
+        })
+    })();
+
+    _complete_str(command_handle, cb, res)
+}
+
+extern fn build_get_txn_fees_req(command_handle: CommandHandle,
+                                 _wallet_handle: WalletHandle,
+                                 submitter_did: *const c_char,
+                                 cb: Option<StrCB>) -> ErrorCode {
+    trace!("null_payment_method::build_get_txn_fees_req >>>");
+
+    let res = (|| {
+        let submitter_did = _opt_c_str(submitter_did)?;
+
+        _ledger(|ledger| {
+            let fees = _to_json(&ledger.fees)?;
+            Ok(ledger.build_request(GET_FEES, submitter_did.as_deref(), Value::Null, fees))
+        })
+    })();
+
+    _complete_str(command_handle, cb, res)
+}
+
+extern fn parse_get_txn_fees_response(command_handle: CommandHandle,
+                                      resp_json: *const c_char,
+                                      cb: Option<StrCB>) -> ErrorCode {
+    trace!("null_payment_method::parse_get_txn_fees_response >>>");
+    _complete_str(command_handle, cb, _parse_response(resp_json))
+}
+
+extern fn build_verify_payment_req(command_handle: CommandHandle,
+                                   _wallet_handle: WalletHandle,
+                                   submitter_did: *const c_char,
+                                   receipt: *const c_char,
+                                   cb: Option<StrCB>) -> ErrorCode {
+    trace!("null_payment_method::build_verify_payment_req >>>");
+
+    let res = (|| {
+        let submitter_did = _opt_c_str(submitter_did)?;
+        let receipt = _c_str(receipt)?;
+
+        _ledger(|ledger| {
+            let txn = _to_json(&ledger.get_txn(&receipt)?)?;
+            Ok(ledger.build_request(VERIFY, submitter_did.as_deref(), json!({"receipt": receipt}), txn))
+        })
+    })();
+
+    _complete_str(command_handle, cb, res)
+}
+
+extern fn parse_verify_payment_response(command_handle: CommandHandle,
+                                        resp_json: *const c_char,
+                                        cb: Option<StrCB>) -> ErrorCode {
+    trace!("null_payment_method::parse_verify_payment_response >>>");
+    _complete_str(command_handle, cb, _parse_response(resp_json))
+}
+
+extern fn sign_with_address(command_handle: CommandHandle,
+                            _wallet_handle: WalletHandle,
+                            address: *const c_char,
+                            message_raw: *const u8,
+                            message_len: u32,
+                            cb: Option<ByteArrayCB>) -> ErrorCode {
+    trace!("null_payment_method::sign_with_address >>>");
+
+    let cb = match cb {
+        Some(cb) => cb,
+        None => return ErrorCode::CommonInvalidState
+    };
+
+    let address = match _c_str(address) {
+        Ok(address) => address,
+        Err(err) => return err.kind().into()
+    };
+
+    let message = unsafe { ::std::slice::from_raw_parts(message_raw, message_len as usize) };
+    let signature = _signature(&address, message);
+
+    cb(command_handle, ErrorCode::Success, signature.as_ptr(), signature.len() as u32);
+
+    ErrorCode::Success
+}
+
+extern fn verify_with_address(command_handle: CommandHandle,
+                              address: *const c_char,
+                              message_raw: *const u8,
+                              message_len: u32,
+                              signature_raw: *const u8,
+                              signature_len: u32,
+                              cb: Option<BoolCB>) -> ErrorCode {
+    trace!("null_payment_method::verify_with_address >>>");
+
+    let cb = match cb {
+        Some(cb) => cb,
+        None => return ErrorCode::CommonInvalidState
+    };
+
+    let address = match _c_str(address) {
+        Ok(address) => address,
+        Err(err) => return err.kind().into()
+    };
+
+    let message = unsafe { ::std::slice::from_raw_parts(message_raw, message_len as usize) };
+    let signature = unsafe { ::std::slice::from_raw_parts(signature_raw, signature_len as usize) };
+    let valid = _signature(&address, message).as_slice() == signature;
+
+    cb(command_handle, ErrorCode::Success, valid as u8);
+
+    ErrorCode::Success
+}
+
+extern fn estimate_request_fees(command_handle: CommandHandle,
+                                _wallet_handle: WalletHandle,
+                                _submitter_did: *const c_char,
+                                req_json: *const c_char,
+                                _inputs_json: *const c_char,
+                                cb: Option<V2CB>) -> ErrorCode {
+    trace!("null_payment_method::estimate_request_fees >>>");
+
+    let res = _c_str(req_json)
+        .and_then(|req| _parse_txn_type(&req))
+        .and_then(|txn_type| _ledger(|ledger| Ok(json!({"fee": ledger.get_fee(&txn_type)}).to_string())));
+
+    _complete_v2(command_handle, cb, res)
+}
+
+extern fn verify_payment_receipts(command_handle: CommandHandle,
+                                  _wallet_handle: WalletHandle,
+                                  _submitter_did: *const c_char,
+                                  receipts_json: *const c_char,
+                                  cb: Option<V2CB>) -> ErrorCode {
+    trace!("null_payment_method::verify_payment_receipts >>>");
+
+    let res = _c_str(receipts_json)
+        .and_then(|receipts| serde_json::from_str::<Vec<String>>(&receipts)
+            .to_indy(IndyErrorKind::InvalidStructure, "Unable to parse receipts"))
+        .and_then(|receipts| _ledger(|ledger| {
+            let infos: Vec<Value> = receipts.into_iter()
+                .map(|receipt| match ledger.get_txn(&receipt) {
+                    Ok(txn) => json!({"receipt": receipt, "verified": true, "txn": txn}),
+                    Err(_) => json!({"receipt": receipt, "verified": false, "error": "Receipt does not exist"})
+                })
+                .collect();
+            _to_json(&infos)
+        }));
+
+    _complete_v2(command_handle, cb, res)
+}
+
+fn _parse_response(resp_json: *const c_char) -> IndyResult<String> {
+    let resp_json = _c_str(resp_json)?;
+    _ledger(|ledger| ledger.take_response(&resp_json))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ADDRESS_1: &str = "pay:null:1";
+    const ADDRESS_2: &str = "pay:null:2";
+
+    fn _outputs(outputs: &[(&str, u64)]) -> String {
+        let outputs: Vec<Value> = outputs.iter()
+            .map(|(recipient, amount)| json!({"recipient": recipient, "amount": amount}))
+            .collect();
+        json!(outputs).to_string()
+    }
+
+    #[test]
+    fn null_ledger_mint_and_transfer_works() {
+        let mut ledger = NullLedger::default();
+
+        let minted = ledger.mint(&_outputs(&[(ADDRESS_1, 10)]), Some("extra")).unwrap();
+        assert_eq!(1, minted.len());
+        assert_eq!(10, ledger.get_sources(ADDRESS_1)[0].amount);
+
+        let inputs = json!([minted[0].receipt]).to_string();
+        let receipts = ledger.transfer(&inputs, &_outputs(&[(ADDRESS_1, 3), (ADDRESS_2, 5)]), None, 2).unwrap();
+
+        assert_eq!(2, receipts.len());
+        assert_eq!(3, ledger.get_sources(ADDRESS_1)[0].amount);
+        assert_eq!(5, ledger.get_sources(ADDRESS_2)[0].amount);
+        assert_eq!(vec![minted[0].receipt.clone()], ledger.get_txn(&receipts[1].receipt).unwrap().sources);
+    }
+
+    #[test]
+    fn null_ledger_transfer_works_for_spent_source() {
+        let mut ledger = NullLedger::default();
+
+        let minted = ledger.mint(&_outputs(&[(ADDRESS_1, 10)]), None).unwrap();
+        let inputs = json!([minted[0].receipt]).to_string();
+        ledger.transfer(&inputs, &_outputs(&[(ADDRESS_2, 10)]), None, 0).unwrap();
+
+        let res = ledger.transfer(&inputs, &_outputs(&[(ADDRESS_2, 10)]), None, 0);
+        assert_eq!(IndyErrorKind::PaymentSourceDoesNotExist, res.unwrap_err().kind());
+    }
+
+    #[test]
+    fn null_ledger_transfer_works_for_unbalanced_amounts() {
+        let mut ledger = NullLedger::default();
+
+        let minted = ledger.mint(&_outputs(&[(ADDRESS_1, 10)]), None).unwrap();
+        let inputs = json!([minted[0].receipt]).to_string();
+
+        let res = ledger.transfer(&inputs, &_outputs(&[(ADDRESS_2, 10)]), None, 1);
+        assert_eq!(IndyErrorKind::PaymentInsufficientFunds, res.unwrap_err().kind());
+
+        let res = ledger.transfer(&inputs, &_outputs(&[(ADDRESS_2, 5)]), None, 1);
+        assert_eq!(IndyErrorKind::PaymentExtraFunds, res.unwrap_err().kind());
+
+        assert_eq!(10, ledger.get_sources(ADDRESS_1)[0].amount);
+    }
+
+    #[test]
+    fn null_ledger_responses_work() {
+        let mut ledger = NullLedger::default();
+
+        let request = ledger.build_request(GET_FEES, None, Value::Null, "{}".to_string());
+        let reply = json!({"op": "REPLY", "result": {"reqId": serde_json::from_str::<Value>(&request).unwrap()["reqId"]}}).to_string();
+
+        assert_eq!("{}", ledger.take_response(&reply).unwrap());
+        assert_eq!(IndyErrorKind::InvalidTransaction, ledger.take_response(&request).unwrap_err().kind());
+    }
+
+    #[test]
+    fn null_ledger_fees_work() {
+        let mut ledger = NullLedger::default();
+
+        ledger.set_fees(r#"{"1": 5}"#).unwrap();
+
+        assert_eq!(5, ledger.get_fee("1"));
+        assert_eq!(0, ledger.get_fee("101"));
+    }
+}
@@ -15,9 +15,11 @@ use indy_utils::ctypes;
 
 use crate::domain::ledger::auth_rule::{Constraint, RoleConstraint, CombinationConstraint};
 use crate::domain::crypto::did::DidValue;
+use crate::services::pool::PoolService;
 
 pub struct PaymentsService {
-    methods: RefCell<HashMap<String, PaymentsMethod>>
+    methods: RefCell<HashMap<String, PaymentsMethod>>,
+    calls: RefCell<HashMap<CommandHandle, String>>
 }
 
 #[derive(Debug)]
@@ -36,7 +38,12 @@ pub struct PaymentsMethod {
     build_verify_payment_req: BuildVerifyPaymentReqCB,
     parse_verify_payment_response: ParseVerifyPaymentResponseCB,
     sign_with_address: SignWithAddressCB,
-    verify_with_address: VerifyWithAddressCB
+    verify_with_address: VerifyWithAddressCB,
+    estimate_request_fees: Option<EstimateRequestFeesCB>,
+    verify_payment_receipts: Option<VerifyPaymentReceiptsCB>,
+    parse_verify_payment_response_with_proof: Option<ParseVerifyPaymentResponseWithProofCB>,
+    build_get_payment_history_request: Option<BuildGetPaymentHistoryRequestCB>,
+    parse_get_payment_history_response: Option<ParseGetPaymentHistoryResponseCB>
 }
 
 pub type PaymentsMethodCBs = PaymentsMethod;
@@ -72,9 +79,23 @@ impl PaymentsMethodCBs {
             build_verify_payment_req,
             parse_verify_payment_response,
             sign_with_address,
-            verify_with_address
+            verify_with_address,
+            estimate_request_fees: None,
+            verify_payment_receipts: None,
+            parse_verify_payment_response_with_proof: None,
+            build_get_payment_history_request: None,
+            parse_get_payment_history_response: None
         }
     }
+
+    /// Attaches handlers that are available only for payment methods registered with v2 API.
+    pub fn with_v2_handlers(mut self,
+                            estimate_request_fees: EstimateRequestFeesCB,
+                            verify_payment_receipts: VerifyPaymentReceiptsCB) -> Self {
+        self.estimate_request_fees = Some(estimate_request_fees);
+        self.verify_payment_receipts = Some(verify_payment_receipts);
+        self
+    }
 }
 
 impl PaymentsMethod {}
@@ -82,7 +103,8 @@ impl PaymentsMethod {}
 impl PaymentsService {
     pub fn new() -> Self {
         PaymentsService {
-            methods: RefCell::new(HashMap::new())
+            methods: RefCell::new(HashMap::new()),
+            calls: RefCell::new(HashMap::new())
         }
     }
 
@@ -93,6 +115,52 @@ impl PaymentsService {
         trace!("register_payment_method <<<");
     }
 
+    pub fn register_receipt_proof_parser(&self, method_type: &str, parser: ParseVerifyPaymentResponseWithProofCB) -> IndyResult<()> {
+        trace!("register_receipt_proof_parser >>> method_type: {:?}", method_type);
+        self.methods.borrow_mut().get_mut(method_type)
+            .ok_or_else(|| err_msg(IndyErrorKind::UnknownPaymentMethodType, format!("Unknown payment method {}", method_type)))?
+            .parse_verify_payment_response_with_proof = Some(parser);
+        trace!("register_receipt_proof_parser <<<");
+        Ok(())
+    }
+
+    pub fn register_history_handlers(&self, method_type: &str, build_request: BuildGetPaymentHistoryRequestCB, parse_response: ParseGetPaymentHistoryResponseCB) -> IndyResult<()> {
+        trace!("register_history_handlers >>> method_type: {:?}", method_type);
+        let mut methods = self.methods.borrow_mut();
+        let method = methods.get_mut(method_type)
+            .ok_or_else(|| err_msg(IndyErrorKind::UnknownPaymentMethodType, format!("Unknown payment method {}", method_type)))?;
+        method.build_get_payment_history_request = Some(build_request);
+        method.parse_get_payment_history_response = Some(parse_response);
+        trace!("register_history_handlers <<<");
+        Ok(())
+    }
+
+    /// Removes payment method, so its library can be unloaded or registered again.
+    /// Method can't be removed while it still has to answer calls made to it.
+    pub fn unregister_payment_method(&self, method_type: &str) -> IndyResult<()> {
+        trace!("unregister_payment_method >>> method_type: {:?}", method_type);
+
+        if !self.methods.borrow().contains_key(method_type) {
+            return Err(err_msg(IndyErrorKind::UnknownPaymentMethodType, format!("Unknown payment method {}", method_type)));
+        }
+
+        let pending = self.calls.borrow().values().filter(|method| method.as_str() == method_type).count();
+
+        if pending > 0 {
+            return Err(err_msg(IndyErrorKind::InvalidState, format!("Payment method {} has {} calls in progress", method_type, pending)));
+        }
+
+        self.methods.borrow_mut().remove(method_type);
+
+        trace!("unregister_payment_method <<<");
+        Ok(())
+    }
+
+    /// Marks call made to payment method as answered.
+    pub fn complete_call(&self, cmd_handle: CommandHandle) {
+        self.calls.borrow_mut().remove(&cmd_handle);
+    }
+
     pub fn create_address(&self, cmd_handle: CommandHandle, wallet_handle: WalletHandle, method_type: &str, config: &str) -> IndyResult<()> {
         trace!("create_address >>> wallet_handle: {:?}, method_type: {:?}, config: {:?}", wallet_handle, method_type, config);
         let create_address: CreatePaymentAddressCB = self.methods.borrow().get(method_type)
@@ -102,7 +170,7 @@ impl PaymentsService {
 
         let err = create_address(cmd_handle, wallet_handle, config.as_ptr(), cbs::create_address_cb(cmd_handle, wallet_handle));
 
-        let res = err.into();
+        let res = self._track_call(cmd_handle, method_type, err);
         trace!("create_address <<< result: {:?}", res);
         res
     }
@@ -128,7 +196,7 @@ impl PaymentsService {
                                    extra.as_ref().map(|s| s.as_ptr()).unwrap_or(null()),
                                    cbs::add_request_fees_cb(cmd_handle));
 
-        let res = err.into();
+        let res = self._track_call(cmd_handle, method_type, err);
         trace!("add_request_fees <<< result: {:?}", res);
         res
     }
@@ -141,7 +209,7 @@ impl PaymentsService {
 
         let err = parse_response_with_fees(cmd_handle, response.as_ptr(), cbs::parse_response_with_fees_cb(cmd_handle));
 
-        let res = err.into();
+        let res = self._track_call(cmd_handle, type_, err);
         trace!("parse_response_with_fees <<< result: {:?}", res);
         res
     }
@@ -162,7 +230,7 @@ impl PaymentsService {
                                                               next.unwrap_or(-1),
                                                               cb);
 
-        let res = err.into();
+        let res = self._track_call(cmd_handle, type_, err);
         trace!("build_get_payment_sources_request <<< result: {:?}", res);
         res
     }
@@ -176,11 +244,59 @@ impl PaymentsService {
         let response = CString::new(response)?;
         let err = parse_get_payment_sources_response(cmd_handle, response.as_ptr(), cbs::parse_get_payment_sources_response_cb(cmd_handle));
 
-        let res = err.into();
+        let res = self._track_call(cmd_handle, type_, err);
         trace!("parse_get_payment_sources_response <<< result: {:?}", res);
         res
     }
 
+    pub fn build_get_payment_history_request(&self, cmd_handle: CommandHandle, type_: &str, wallet_handle: WalletHandle, submitter_did: Option<&DidValue>, address: &str, from: Option<i64>) -> IndyResult<()> {
+        trace!("build_get_payment_history_request >>> type_: {:?}, wallet_handle: {:?}, submitter_did: {:?}, address: {:?}, from: {:?}", type_, wallet_handle, submitter_did, address, from);
+        let build_get_payment_history_request: BuildGetPaymentHistoryRequestCB = self.methods.borrow().get(type_)
+            .ok_or_else(|| err_msg(IndyErrorKind::UnknownPaymentMethodType, format!("Unknown payment method {}", type_)))?.build_get_payment_history_request
+            .ok_or_else(|| err_msg(IndyErrorKind::PaymentOperationNotSupported, format!("Payment method {} doesn't support payment history", type_)))?;
+
+        let submitter_did = submitter_did.map(|did| ctypes::str_to_cstring(&did.0));
+        let address = CString::new(address)?;
+
+        let err = build_get_payment_history_request(cmd_handle,
+                                                    wallet_handle,
+                                                    submitter_did.as_ref().map(|s| s.as_ptr()).unwrap_or(null()),
+                                                    address.as_ptr(),
+                                                    from.unwrap_or(-1),
+                                                    cbs::build_get_payment_history_request_cb(cmd_handle));
+
+        let res = self._track_call(cmd_handle, type_, err);
+        trace!("build_get_payment_history_request <<< result: {:?}", res);
+        res
+    }
+
+    pub fn parse_get_payment_history_response(&self, cmd_handle: CommandHandle, type_: &str, resp_json: &str) -> IndyResult<()> {
+        trace!("parse_get_payment_history_response >>> type_: {:?}, resp_json: {:?}", type_, resp_json);
+        let parse_get_payment_history_response: ParseGetPaymentHistoryResponseCB = self.methods.borrow().get(type_)
+            .ok_or_else(|| err_msg(IndyErrorKind::UnknownPaymentMethodType, format!("Unknown payment method {}", type_)))?.parse_get_payment_history_response
+            .ok_or_else(|| err_msg(IndyErrorKind::PaymentOperationNotSupported, format!("Payment method {} doesn't support payment history", type_)))?;
+
+        let resp_json = CString::new(resp_json)?;
+
+        let err = parse_get_payment_history_response(cmd_handle, resp_json.as_ptr(), cbs::parse_get_payment_history_response_cb(cmd_handle));
+
+        let res = self._track_call(cmd_handle, type_, err);
+        trace!("parse_get_payment_history_response <<< result: {:?}", res);
+        res
+    }
+
+    /// Splits payment history returned by payment method into transactions json and shift to the next slice.
+    /// -1 is returned as shift for the last slice.
+    pub fn split_payment_history(history: &str) -> IndyResult<(String, i64)> {
+        let history: PaymentHistory = serde_json::from_str(history)
+            .to_indy(IndyErrorKind::InvalidStructure, "Invalid payment history returned by payment method")?;
+
+        let txns = serde_json::to_string(&history.txns)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize payment history")?;
+
+        Ok((txns, history.next.unwrap_or(-1)))
+    }
+
     pub fn build_payment_req(&self, cmd_handle: CommandHandle, type_: &str, wallet_handle: WalletHandle, submitter_did: Option<&DidValue>, inputs: &str, outputs: &str, extra: Option<&str>) -> IndyResult<()> {
         trace!("build_payment_req >>> type_: {:?}, wallet_handle: {:?}, submitter_did: {:?}, inputs: {:?}, outputs: {:?}, extra: {:?}", type_, wallet_handle, submitter_did, inputs, outputs, extra);
         let build_payment_req: BuildPaymentReqCB = self.methods.borrow().get(type_)
@@ -199,7 +315,7 @@ impl PaymentsService {
                                     extra.as_ref().map(|s| s.as_ptr()).unwrap_or(null()),
                                     cbs::build_payment_req_cb(cmd_handle));
 
-        let res = err.into();
+        let res = self._track_call(cmd_handle, type_, err);
         trace!("build_payment_req <<< result: {:?}", res);
         res
     }
@@ -213,7 +329,7 @@ impl PaymentsService {
 
         let err = parse_payment_response(cmd_handle, response.as_ptr(), cbs::parse_payment_response_cb(cmd_handle));
 
-        let res = err.into();
+        let res = self._track_call(cmd_handle, type_, err);
         trace!("parse_payment_response <<< result: {:?}", res);
         res
     }
@@ -234,7 +350,7 @@ impl PaymentsService {
                                  extra.as_ref().map(|s| s.as_ptr()).unwrap_or(null()),
                                  cbs::build_mint_req_cb(cmd_handle));
 
-        let res = err.into();
+        let res = self._track_call(cmd_handle, type_, err);
         trace!("build_mint_req <<< result: {:?}", res);
         res
     }
@@ -253,7 +369,7 @@ impl PaymentsService {
                                          fees.as_ptr(),
                                          cbs::build_set_txn_fees_req_cb(cmd_handle));
 
-        let res = err.into();
+        let res = self._track_call(cmd_handle, type_, err);
         trace!("build_set_txn_fees_req <<< result: {:?}", res);
         res
     }
@@ -270,7 +386,7 @@ impl PaymentsService {
                                          submitter_did.as_ref().map(|s| s.as_ptr()).unwrap_or(null()),
                                          cbs::build_get_txn_fees_req(cmd_handle));
 
-        let res = err.into();
+        let res = self._track_call(cmd_handle, type_, err);
         trace!("build_get_txn_fees_req <<< result: {:?}", res);
         res
     }
@@ -284,7 +400,7 @@ impl PaymentsService {
 
         let err = parse_get_txn_fees_response(cmd_handle, response.as_ptr(), cbs::parse_get_txn_fees_response(cmd_handle));
 
-        let res = err.into();
+        let res = self._track_call(cmd_handle, type_, err);
         trace!("parse_get_txn_fees_response <<< result: {:?}", res);
         res
     }
@@ -303,7 +419,7 @@ impl PaymentsService {
                                            receipt.as_ptr(),
                                            cbs::build_verify_payment_req(cmd_handle));
 
-        let res = err.into();
+        let res = self._track_call(cmd_handle, type_, err);
         trace!("build_verify_payment_req <<< result: {:?}", res);
         res
     }
@@ -317,11 +433,45 @@ impl PaymentsService {
 
         let err = parse_verify_payment_response(cmd_handle, resp_json.as_ptr(), cbs::parse_verify_payment_response(cmd_handle));
 
-        let res = err.into();
+        let res = self._track_call(cmd_handle, type_, err);
         trace!("parse_verify_payment_response <<< result: {:?}", res);
         res
     }
 
+    pub fn parse_verify_payment_response_with_proof(&self, cmd_handle: CommandHandle, type_: &str, receipt: &str, resp_json: &str) -> IndyResult<()> {
+        trace!("parse_verify_payment_response_with_proof >>> type_: {:?}, receipt: {:?}, resp_json: {:?}", type_, receipt, resp_json);
+        let parse_verify_payment_response_with_proof: ParseVerifyPaymentResponseWithProofCB = self.methods.borrow().get(type_)
+            .ok_or_else(|| err_msg(IndyErrorKind::UnknownPaymentMethodType, format!("Unknown payment method {}", type_)))?.parse_verify_payment_response_with_proof
+            .ok_or_else(|| err_msg(IndyErrorKind::PaymentOperationNotSupported, format!("Payment method {} doesn't support receipts verification with state proofs", type_)))?;
+
+        let receipt = CString::new(receipt)?;
+        let resp_json = CString::new(resp_json)?;
+
+        let err = parse_verify_payment_response_with_proof(cmd_handle, receipt.as_ptr(), resp_json.as_ptr(),
+                                                           cbs::parse_verify_payment_response_with_proof_cb(cmd_handle));
+
+        let res = self._track_call(cmd_handle, type_, err);
+        trace!("parse_verify_payment_response_with_proof <<< result: {:?}", res);
+        res
+    }
+
+    /// Checks state proofs returned by payment method parser and returns receipt transaction info
+    /// only if they are confirmed by multi signature of the pool nodes.
+    pub fn check_receipt_proof(parsed: &str, bls_keys: &HashMap<String, String>) -> IndyResult<String> {
+        trace!("check_receipt_proof >>> parsed: {:?}, bls_keys: {:?}", parsed, bls_keys);
+
+        let parsed: ReceiptWithProof = serde_json::from_str(parsed)
+            .to_indy(IndyErrorKind::InvalidStructure, "Invalid response with proof returned by payment method")?;
+
+        if !PoolService::verify_parsed_state_proofs(&parsed.state_proofs.to_string(), bls_keys)? {
+            return Err(err_msg(IndyErrorKind::InvalidTransaction, "Payment receipt isn't confirmed by state proof"));
+        }
+
+        let res = parsed.txn.to_string();
+        trace!("check_receipt_proof <<< res: {:?}", res);
+        Ok(res)
+    }
+
     pub fn parse_method_from_inputs(&self, inputs: &str) -> IndyResult<String> {
         trace!("parse_method_from_inputs >>> inputs: {:?}", inputs);
 
@@ -424,6 +574,44 @@ impl PaymentsService {
         res
     }
 
+    pub fn parse_txn_type_from_request(&self, request: &str) -> IndyResult<String> {
+        trace!("parse_txn_type_from_request >>> request: {:?}", request);
+
+        let request: serde_json::Value = serde_json::from_str(request)
+            .to_indy(IndyErrorKind::InvalidStructure, "Unable to parse request")?;
+
+        let res = request["operation"]["type"].as_str()
+            .map(String::from)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "Request doesn't contain transaction type"));
+
+        trace!("parse_txn_type_from_request <<< result: {:?}", res);
+        res
+    }
+
+    pub fn build_expected_fees_receipt(&self, method: &str, txn_type: &str, fees: &str, inputs: &str, outputs: &str) -> IndyResult<ExpectedFeesReceipt> {
+        trace!("build_expected_fees_receipt >>> method: {:?}, txn_type: {:?}, fees: {:?}, inputs: {:?}, outputs: {:?}", method, txn_type, fees, inputs, outputs);
+
+        let fees: Fees = serde_json::from_str(fees)
+            .to_indy(IndyErrorKind::InvalidStructure, "Unable to parse fees")?;
+
+        let inputs: Vec<String> = serde_json::from_str(inputs)
+            .to_indy(IndyErrorKind::InvalidStructure, "Unable to parse inputs")?;
+
+        let outputs: Vec<serde_json::Value> = serde_json::from_str(outputs)
+            .to_indy(IndyErrorKind::InvalidStructure, "Unable to parse outputs")?;
+
+        let res = ExpectedFeesReceipt {
+            payment_method: method.to_string(),
+            txn_type: txn_type.to_string(),
+            fee: fees.get(txn_type).cloned().unwrap_or(0),
+            inputs,
+            outputs,
+        };
+
+        trace!("build_expected_fees_receipt <<< result: {:?}", res);
+        Ok(res)
+    }
+
     pub fn get_request_info_with_min_price(&self, constraint: &Constraint, requester_info: &RequesterInfo, fees: &Fees) -> IndyResult<RequestInfo> {
         trace!("get_request_info_with_min_price >>> constraint: {:?}, requester_info: {:?}, fees: {:?}", constraint, requester_info, fees);
 
@@ -583,7 +771,7 @@ impl PaymentsService {
 
         let err = sign_with_address(cmd_handle, wallet_handle, address.as_ptr(), message.as_ptr() as *const u8, message.len() as u32, cbs::sign_with_address_cb(cmd_handle));
 
-        let res = err.into();
+        let res = self._track_call(cmd_handle, method, err);
         trace!("sign_with_address <<< result: {:?}", res);
         res
     }
@@ -597,10 +785,65 @@ impl PaymentsService {
 
         let err = verify_with_address(cmd_handle, address.as_ptr(), message.as_ptr() as *const u8, message.len() as u32, signature.as_ptr() as *const u8, signature.len() as u32, cbs::verify_with_address_cb(cmd_handle));
 
-        let res = err.into();
+        let res = self._track_call(cmd_handle, method, err);
         trace!("verify_with_address <<< result: {:?}", res);
         res
     }
+
+    pub fn estimate_request_fees(&self, cmd_handle: CommandHandle, method_type: &str, wallet_handle: WalletHandle, submitter_did: Option<&DidValue>, req: &str, inputs: &str) -> IndyResult<()> {
+        trace!("estimate_request_fees >>> method_type: {:?}, wallet_handle: {:?}, submitter_did: {:?}, req: {:?}, inputs: {:?}",
+               method_type, wallet_handle, submitter_did, req, inputs);
+        let estimate_request_fees: EstimateRequestFeesCB = self.methods.borrow().get(method_type)
+            .ok_or_else(|| err_msg(IndyErrorKind::UnknownPaymentMethodType, format!("Unknown payment method {}", method_type)))?.estimate_request_fees
+            .ok_or_else(|| err_msg(IndyErrorKind::PaymentOperationNotSupported, format!("Payment method {} doesn't support fees estimation", method_type)))?;
+
+        let submitter_did = submitter_did.map(|did| ctypes::str_to_cstring(&did.0));
+        let req = CString::new(req)?;
+        let inputs = CString::new(inputs)?;
+
+        let err = estimate_request_fees(cmd_handle,
+                                        wallet_handle,
+                                        submitter_did.as_ref().map(|s| s.as_ptr()).unwrap_or(null()),
+                                        req.as_ptr(),
+                                        inputs.as_ptr(),
+                                        cbs::estimate_request_fees_cb(cmd_handle));
+
+        let res = self._track_call(cmd_handle, method_type, err);
+        trace!("estimate_request_fees <<< result: {:?}", res);
+        res
+    }
+
+    pub fn verify_payment_receipts(&self, cmd_handle: CommandHandle, method_type: &str, wallet_handle: WalletHandle, submitter_did: Option<&DidValue>, receipts: &str) -> IndyResult<()> {
+        trace!("verify_payment_receipts >>> method_type: {:?}, wallet_handle: {:?}, submitter_did: {:?}, receipts: {:?}",
+               method_type, wallet_handle, submitter_did, receipts);
+        let verify_payment_receipts: VerifyPaymentReceiptsCB = self.methods.borrow().get(method_type)
+            .ok_or_else(|| err_msg(IndyErrorKind::UnknownPaymentMethodType, format!("Unknown payment method {}", method_type)))?.verify_payment_receipts
+            .ok_or_else(|| err_msg(IndyErrorKind::PaymentOperationNotSupported, format!("Payment method {} doesn't support batch receipts verification", method_type)))?;
+
+        let submitter_did = submitter_did.map(|did| ctypes::str_to_cstring(&did.0));
+        let receipts = CString::new(receipts)?;
+
+        let err = verify_payment_receipts(cmd_handle,
+                                          wallet_handle,
+                                          submitter_did.as_ref().map(|s| s.as_ptr()).unwrap_or(null()),
+                                          receipts.as_ptr(),
+                                          cbs::verify_payment_receipts_cb(cmd_handle));
+
+        let res = self._track_call(cmd_handle, method_type, err);
+        trace!("verify_payment_receipts <<< result: {:?}", res);
+        res
+    }
+
+    // Payment method answers accepted call with ack callback later, so the call is tracked until then
+    fn _track_call(&self, cmd_handle: CommandHandle, method_type: &str, err: ErrorCode) -> IndyResult<()> {
+        let res: IndyResult<()> = err.into();
+
+        if res.is_ok() {
+            self.calls.borrow_mut().insert(cmd_handle, method_type.to_string());
+        }
+
+        res
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -708,6 +951,26 @@ mod cbs {
         send_bool_ack(cmd_handle, Box::new(PaymentsCommand::VerifyWithAddressAck))
     }
 
+    pub fn estimate_request_fees_cb(cmd_handle: CommandHandle) -> Option<PaymentMethodV2CB> {
+        send_ack_v2(cmd_handle, Box::new(PaymentsCommand::EstimateRequestFeesAck))
+    }
+
+    pub fn verify_payment_receipts_cb(cmd_handle: CommandHandle) -> Option<PaymentMethodV2CB> {
+        send_ack_v2(cmd_handle, Box::new(PaymentsCommand::VerifyPaymentReceiptsAck))
+    }
+
+    pub fn build_get_payment_history_request_cb(cmd_handle: CommandHandle) -> Option<PaymentMethodV2CB> {
+        send_ack_v2(cmd_handle, Box::new(PaymentsCommand::BuildGetPaymentHistoryRequestAck))
+    }
+
+    pub fn parse_get_payment_history_response_cb(cmd_handle: CommandHandle) -> Option<PaymentMethodV2CB> {
+        send_ack_v2(cmd_handle, Box::new(PaymentsCommand::ParseGetPaymentHistoryResponseAck))
+    }
+
+    pub fn parse_verify_payment_response_with_proof_cb(cmd_handle: CommandHandle) -> Option<PaymentMethodV2CB> {
+        send_ack_v2(cmd_handle, Box::new(PaymentsCommand::VerifyPaymentReceiptWithProofAck))
+    }
+
     fn send_ack_str(cmd_handle: CommandHandle, builder: Box<dyn Fn(CommandHandle, IndyResult<String>) -> PaymentsCommand + Send>) -> Option<extern fn(command_handle: CommandHandle,
                                                                                                                               err: ErrorCode,
                                                                                                                               c_str: *const c_char) -> ErrorCode> {
@@ -764,6 +1027,65 @@ mod cbs {
         }))
     }
 
+    fn send_ack_v2(cmd_handle: CommandHandle, builder: Box<dyn Fn(CommandHandle, IndyResult<String>) -> PaymentsCommand + Send>) -> Option<PaymentMethodV2CB> {
+        cbs::_closure_to_cb_v2(cmd_handle, Box::new(move |err, error_json, result_json| -> ErrorCode {
+            let result = if err == ErrorCode::Success {
+                Ok(result_json)
+            } else {
+                Err(_structured_error(err, error_json.as_deref()))
+            };
+            CommandExecutor::instance().send(Command::Payments(
+                builder(cmd_handle, result))).into()
+        }))
+    }
+
+    /// Builds error from the code and optional error details returned by v2 handler.
+    /// Details are expected to be json with `message` field, other fields are kept in the error message as is.
+    pub fn _structured_error(err: ErrorCode, error_json: Option<&str>) -> IndyError {
+        let error_json = match error_json {
+            Some(error_json) => error_json,
+            None => return err.into()
+        };
+
+        let message = match serde_json::from_str::<serde_json::Value>(error_json) {
+            Ok(serde_json::Value::Object(mut details)) => {
+                let message = details.remove("message")
+                    .and_then(|message| message.as_str().map(String::from))
+                    .unwrap_or_else(|| "Plugin returned error".to_string());
+
+                if details.is_empty() {
+                    message
+                } else {
+                    format!("{} (details: {})", message, serde_json::Value::Object(details))
+                }
+            }
+            _ => error_json.to_string()
+        };
+
+        err_msg(err.into(), message)
+    }
+
+    type V2Closure = Box<dyn FnMut(ErrorCode, Option<String>, String) -> ErrorCode + Send>;
+
+    pub fn _closure_to_cb_v2(command_handle: CommandHandle, closure: V2Closure) -> Option<PaymentMethodV2CB> {
+        lazy_static! {
+            static ref CALLBACKS_V2: Mutex<HashMap<CommandHandle, V2Closure>> = Default::default();
+        }
+
+        extern "C" fn _callback(command_handle_: CommandHandle, err: ErrorCode, error_json: *const c_char, result_json: *const c_char) -> ErrorCode {
+            let mut callbacks = CALLBACKS_V2.lock().unwrap();
+            let mut cb = callbacks.remove(&command_handle_).unwrap();
+            let error_json = ctypes::c_str_to_string(error_json).ok().and_then(|s| s.map(String::from));
+            let result_json = ctypes::c_str_to_string(result_json).ok().and_then(|s| s.map(String::from)).unwrap_or_default();
+            cb(err, error_json, result_json)
+        }
+
+        let mut callbacks = CALLBACKS_V2.lock().unwrap();
+        callbacks.insert(command_handle, closure);
+
+        Some(_callback)
+    }
+
     pub fn _closure_to_cb_str(command_handle: CommandHandle, closure: Box<dyn FnMut(ErrorCode, String) -> ErrorCode + Send>)
                               -> Option<extern fn(command_handle: CommandHandle,
                                                   err: ErrorCode,
@@ -864,6 +1186,95 @@ pub struct RequestInfo {
     pub requirements: Vec<Requirement>,
 }
 
+/// Describes the payment a write request prepared with fees is expected to make.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExpectedFeesReceipt {
+    pub payment_method: String,
+    pub txn_type: String,
+    pub fee: u64,
+    pub inputs: Vec<String>,
+    pub outputs: Vec<serde_json::Value>,
+}
+
+/// Sources and balance of the single wallet payment address.
+#[derive(Serialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressBalance {
+    pub address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method: Option<String>,
+    pub balance: u64,
+    pub sources: Vec<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl AddressBalance {
+    pub fn new(address: String) -> AddressBalance {
+        AddressBalance {
+            address,
+            payment_method: None,
+            balance: 0,
+            sources: Vec::new(),
+            error: None,
+        }
+    }
+
+    /// Appends slice of sources in the format returned by `parse_get_payment_sources_response`.
+    pub fn add_sources(&mut self, sources: &str) -> IndyResult<()> {
+        let sources: Vec<serde_json::Value> = serde_json::from_str(sources)
+            .to_indy(IndyErrorKind::InvalidStructure, "Invalid sources json returned by payment method")?;
+
+        for source in sources {
+            let amount = source["amount"].as_u64()
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "Source amount not found"))?;
+
+            self.balance += amount;
+            self.sources.push(source);
+        }
+
+        Ok(())
+    }
+}
+
+/// Consolidated balances of the wallet payment addresses.
+#[derive(Serialize, Debug, PartialEq, Clone)]
+pub struct PaymentBalances {
+    /// Total balance of successfully fetched addresses per payment method
+    pub balances: HashMap<String, u64>,
+    pub addresses: Vec<AddressBalance>,
+}
+
+impl PaymentBalances {
+    pub fn new(addresses: Vec<AddressBalance>) -> PaymentBalances {
+        let mut balances: HashMap<String, u64> = HashMap::new();
+
+        for address in addresses.iter().filter(|address| address.error.is_none()) {
+            if let Some(ref payment_method) = address.payment_method {
+                *balances.entry(payment_method.clone()).or_insert(0) += address.balance;
+            }
+        }
+
+        PaymentBalances { balances, addresses }
+    }
+}
+
+/// Slice of payment address transactions returned by payment method.
+#[derive(Deserialize, Debug)]
+pub struct PaymentHistory {
+    pub txns: Vec<serde_json::Value>,
+    #[serde(default)]
+    pub next: Option<i64>,
+}
+
+/// Receipt transaction info returned by payment method together with state proofs confirming it.
+#[derive(Deserialize, Debug)]
+pub struct ReceiptWithProof {
+    pub txn: serde_json::Value,
+    pub state_proofs: serde_json::Value,
+}
+
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
 pub struct Requirement {
     pub role: Option<String>,
@@ -1231,4 +1642,143 @@ mod test {
         let res = payment_service.get_request_info_with_min_price(&constraint, &requester_info, &fees);
         assert!(res.is_err());
     }
+
+    #[test]
+    fn test_parse_txn_type_from_request() {
+        let payment_service = PaymentsService::new();
+
+        let request = json!({"reqId": 1, "operation": {"type": "101", "data": {}}}).to_string();
+
+        assert_eq!("101", payment_service.parse_txn_type_from_request(&request).unwrap());
+    }
+
+    #[test]
+    fn test_parse_txn_type_from_request_for_missed_type() {
+        let payment_service = PaymentsService::new();
+
+        let request = json!({"reqId": 1, "operation": {"data": {}}}).to_string();
+
+        let res = payment_service.parse_txn_type_from_request(&request);
+        assert_eq!(IndyErrorKind::InvalidStructure, res.unwrap_err().kind());
+    }
+
+    #[test]
+    fn test_build_expected_fees_receipt() {
+        let payment_service = PaymentsService::new();
+
+        let fees = json!({"1": 20, "101": 5}).to_string();
+        let inputs = json!(["pay:null:1"]).to_string();
+        let outputs = json!([{"recipient": "pay:null:2", "amount": 15}]).to_string();
+
+        let receipt = payment_service.build_expected_fees_receipt("null", "101", &fees, &inputs, &outputs).unwrap();
+        assert_eq!(5, receipt.fee);
+        assert_eq!(vec!["pay:null:1".to_string()], receipt.inputs);
+
+        let receipt = payment_service.build_expected_fees_receipt("null", "102", &fees, &inputs, &outputs).unwrap();
+        assert_eq!(0, receipt.fee);
+    }
+
+    #[test]
+    fn test_estimate_request_fees_for_unknown_method() {
+        let payment_service = PaymentsService::new();
+
+        let res = payment_service.estimate_request_fees(1, "unknown", WalletHandle(1), None, "{}", "[]");
+        assert_eq!(IndyErrorKind::UnknownPaymentMethodType, res.unwrap_err().kind());
+    }
+
+    #[test]
+    fn test_structured_error_uses_message_and_details() {
+        let error_json = json!({"message": "Not enough tokens", "balance": 5}).to_string();
+
+        let err = cbs::_structured_error(ErrorCode::PaymentInsufficientFundsError, Some(&error_json));
+        assert_eq!(IndyErrorKind::PaymentInsufficientFunds, err.kind());
+        assert!(err.to_string().contains("Not enough tokens (details: {\"balance\":5})"));
+    }
+
+    #[test]
+    fn test_structured_error_for_plain_text_and_missed_details() {
+        let err = cbs::_structured_error(ErrorCode::CommonInvalidStructure, Some("Malformed receipt"));
+        assert_eq!(IndyErrorKind::InvalidStructure, err.kind());
+        assert!(err.to_string().contains("Malformed receipt"));
+
+        let err = cbs::_structured_error(ErrorCode::CommonInvalidStructure, None);
+        assert_eq!(IndyErrorKind::InvalidStructure, err.kind());
+    }
+
+    #[test]
+    fn test_address_balance_add_sources() {
+        let mut balance = AddressBalance::new("pay:null:1".to_string());
+
+        balance.add_sources(&json!([{"source": "txo:null:1", "paymentAddress": "pay:null:1", "amount": 10}]).to_string()).unwrap();
+        balance.add_sources(&json!([{"source": "txo:null:2", "paymentAddress": "pay:null:1", "amount": 5}]).to_string()).unwrap();
+
+        assert_eq!(15, balance.balance);
+        assert_eq!(2, balance.sources.len());
+
+        let res = balance.add_sources(r#"[{"source": "txo:null:3"}]"#);
+        assert_eq!(IndyErrorKind::InvalidStructure, res.unwrap_err().kind());
+    }
+
+    #[test]
+    fn test_payment_balances_skips_failed_addresses() {
+        let mut balance_1 = AddressBalance::new("pay:null:1".to_string());
+        balance_1.payment_method = Some("null".to_string());
+        balance_1.balance = 10;
+
+        let mut balance_2 = AddressBalance::new("pay:null:2".to_string());
+        balance_2.payment_method = Some("null".to_string());
+        balance_2.balance = 5;
+        balance_2.error = Some("Pool timeout".to_string());
+
+        let balances = PaymentBalances::new(vec![balance_1, balance_2]);
+
+        assert_eq!(Some(&10), balances.balances.get("null"));
+        assert_eq!(2, balances.addresses.len());
+    }
+
+    #[test]
+    fn test_split_payment_history() {
+        let history = json!({"txns": [{"receipt": "pay:null:1", "sources": [], "receipts": []}], "next": 10}).to_string();
+
+        let (txns, next) = PaymentsService::split_payment_history(&history).unwrap();
+        assert_eq!(json!([{"receipt": "pay:null:1", "sources": [], "receipts": []}]).to_string(), txns);
+        assert_eq!(10, next);
+
+        let (txns, next) = PaymentsService::split_payment_history(r#"{"txns": []}"#).unwrap();
+        assert_eq!("[]", txns);
+        assert_eq!(-1, next);
+    }
+
+    #[test]
+    fn test_split_payment_history_for_invalid_json() {
+        let res = PaymentsService::split_payment_history(r#"[]"#);
+        assert_eq!(IndyErrorKind::InvalidStructure, res.unwrap_err().kind());
+    }
+
+    #[test]
+    fn test_register_receipt_proof_parser_for_unknown_method() {
+        extern fn parser(_: CommandHandle, _: *const libc::c_char, _: *const libc::c_char, _: Option<PaymentMethodV2CB>) -> ErrorCode { ErrorCode::Success }
+
+        let payment_service = PaymentsService::new();
+
+        let res = payment_service.register_receipt_proof_parser("unknown", parser);
+        assert_eq!(IndyErrorKind::UnknownPaymentMethodType, res.unwrap_err().kind());
+    }
+
+    #[test]
+    fn test_check_receipt_proof_for_missed_state_proofs() {
+        let mut bls_keys = HashMap::new();
+        bls_keys.insert("Node1".to_string(), "37rAPpXVoxzKhz7d9gkUe52XuXryuLXoM6P6LbWDB7LSbG62Lsb33sfG7zqS8TK1MXwuCHj1FKNzVpsnafmqLG1vXN88rt38mNFs9TENzm4QHdBzsvCuoBnPH7rpYYDo9DZNJePaDvRvqJKByCabubJz3XXKbEeshzpz4Ma5QYpJqjk".to_string());
+
+        let parsed = json!({"txn": {"sources": []}, "state_proofs": []}).to_string();
+
+        let res = PaymentsService::check_receipt_proof(&parsed, &bls_keys);
+        assert_eq!(IndyErrorKind::InvalidTransaction, res.unwrap_err().kind());
+    }
+
+    #[test]
+    fn test_check_receipt_proof_for_invalid_json() {
+        let res = PaymentsService::check_receipt_proof(r#"{"txn":{}}"#, &HashMap::new());
+        assert_eq!(IndyErrorKind::InvalidStructure, res.unwrap_err().kind());
+    }
 }
\ No newline at end of file
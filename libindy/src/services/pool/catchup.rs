@@ -7,6 +7,7 @@ use failure::Context;
 use indy_api_types::errors::prelude::*;
 use crate::services::ledger::merkletree::merkletree::MerkleTree;
 use crate::services::pool::merkle_tree_factory;
+use crate::services::pool::metrics;
 use rust_base58::{FromBase58, ToBase58};
 use crate::services::pool::types::{CatchupReq, Message};
 
@@ -66,6 +67,7 @@ pub fn check_nodes_responses_on_status(nodes_votes: &HashMap<(String, usize, Opt
     if let Some((most_popular_not_timeout_vote, votes_cnt)) = most_popular_not_timeout {
         if *votes_cnt == f + 1 {
             return _try_to_catch_up(most_popular_not_timeout_vote, merkle_tree).or_else(|err| {
+                metrics::increment(pool_name, metrics::MERKLE_CONSISTENCY_FAILURE);
                 if merkle_tree_factory::drop_cache(pool_name).is_ok() {
                     let merkle_tree = merkle_tree_factory::create(pool_name)?;
                     _try_to_catch_up(most_popular_not_timeout_vote, &merkle_tree)
@@ -1,5 +1,6 @@
 use indy_api_types::errors::prelude::*;
 use crate::services::pool::events::PoolEvent;
+use crate::domain::pool::RequestPriority;
 
 use super::zmq;
 
@@ -18,12 +19,53 @@ impl Commander {
         }
     }
 
+    #[cfg(test)]
     pub fn fetch_events(&self) -> Option<PoolEvent> {
-        let cmd_parts = self.cmd_socket.recv_multipart(zmq::DONTWAIT)
+        let cmd_parts = self._recv_cmd()?;
+        self._parse_cmd(cmd_parts).map(|(pe, _)| pe)
+    }
+
+    /// Fetches all the commands waiting in the socket.
+    /// High priority requests are moved before the normal ones received after the last
+    /// pool command (close, refresh, connect), so the order of pool commands is kept.
+    pub fn fetch_all_events(&self) -> Vec<PoolEvent> {
+        let mut events = Vec::new();
+        let mut first_normal_request: Option<usize> = None;
+
+        while let Some(cmd_parts) = self._recv_cmd() {
+            match self._parse_cmd(cmd_parts) {
+                Some((pe @ PoolEvent::SendRequest(..), RequestPriority::High)) => {
+                    match first_normal_request {
+                        Some(idx) => {
+                            events.insert(idx, pe);
+                            first_normal_request = Some(idx + 1);
+                        }
+                        None => events.push(pe)
+                    }
+                }
+                Some((pe @ PoolEvent::SendRequest(..), RequestPriority::Normal)) => {
+                    first_normal_request.get_or_insert(events.len());
+                    events.push(pe);
+                }
+                Some((pe, _)) => {
+                    first_normal_request = None;
+                    events.push(pe);
+                }
+                None => ()
+            }
+        }
+
+        events
+    }
+
+    fn _recv_cmd(&self) -> Option<Vec<Vec<u8>>> {
+        self.cmd_socket.recv_multipart(zmq::DONTWAIT)
             .to_indy(IndyErrorKind::IOError, "ZMQ socket error on fetching pool events")
             .map_err(map_err_trace!())
-            .ok()?;
+            .ok()
+    }
 
+    fn _parse_cmd(&self, cmd_parts: Vec<Vec<u8>>) -> Option<(PoolEvent, RequestPriority)> {
         trace!("cmd_parts {:?}", cmd_parts);
 
         let cmd_s = String::from_utf8(cmd_parts[0].clone())
@@ -34,16 +76,16 @@ impl Commander {
             .unwrap_or(INVALID_COMMAND_HANDLE);
 
         if COMMAND_EXIT.eq(cmd_s.as_str()) {
-            Some(PoolEvent::Close(id))
+            Some((PoolEvent::Close(id), RequestPriority::Normal))
         } else if COMMAND_REFRESH.eq(cmd_s.as_str()) {
-            Some(PoolEvent::Refresh(id))
+            Some((PoolEvent::Refresh(id), RequestPriority::Normal))
         } else if COMMAND_CONNECT.eq(cmd_s.as_str()) {
-            Some(PoolEvent::CheckCache(id))
+            Some((PoolEvent::CheckCache(id), RequestPriority::Normal))
         } else {
             let timeout = LittleEndian::read_i32(cmd_parts[2].as_slice());
             let timeout = if timeout == -1 { None } else { Some(timeout) };
 
-            let nodes = if let Some(nodes) = cmd_parts.get(3) {
+            let nodes = if let Some(nodes) = cmd_parts.get(3).filter(|nodes| !nodes.is_empty()) {
                 Some(String::from_utf8(nodes.clone())
                     .to_indy(IndyErrorKind::InvalidState, "Invalid utf8 sequence in command") // FIXME: review kind
                     .map_err(map_err_trace!()).ok()?)
@@ -51,7 +93,12 @@ impl Commander {
                 None
             };
 
-            Some(PoolEvent::SendRequest(id, cmd_s, timeout, nodes))
+            let priority = cmd_parts.get(4)
+                .and_then(|priority| priority.first())
+                .map(|priority| RequestPriority::from_byte(*priority))
+                .unwrap_or_default();
+
+            Some((PoolEvent::SendRequest(id, cmd_s, timeout, nodes), priority))
         }
     }
 
@@ -66,9 +113,10 @@ mod commander_tests {
     use indy_api_types::{CommandHandle};
     use indy_utils::next_command_handle;
     use crate::services::pool::{COMMAND_REFRESH, COMMAND_EXIT, pool_create_pair_of_sockets};
+    use crate::services::pool::networker::new_zmq_context;
 
     fn new_commander() -> Commander {
-        let zmq_ctx = zmq::Context::new();
+        let zmq_ctx = new_zmq_context();
         let cmd_sock = zmq_ctx.socket(zmq::SocketType::PAIR).unwrap();
         Commander::new(cmd_sock)
     }
@@ -156,4 +204,66 @@ mod commander_tests {
                       msg_, msg);
     }
 
+    fn _send_request(sock: &zmq::Socket, cmd_id: CommandHandle, priority: Option<RequestPriority>) {
+        let mut buf = [0u8; 4];
+        LittleEndian::write_i32(&mut buf, cmd_id);
+        let mut buf_to = [0u8; 4];
+        LittleEndian::write_i32(&mut buf_to, -1);
+        match priority {
+            Some(priority) => sock.send_multipart(&[b"test" as &[u8], &buf, &buf_to, &[], &[priority.to_byte()]], zmq::DONTWAIT).unwrap(),
+            None => sock.send_multipart(&[b"test" as &[u8], &buf, &buf_to], zmq::DONTWAIT).unwrap(),
+        }
+    }
+
+    fn _cmd_ids(events: Vec<PoolEvent>) -> Vec<CommandHandle> {
+        events.into_iter()
+            .map(|pe| match pe {
+                PoolEvent::SendRequest(cmd_id, _, _, _) | PoolEvent::Refresh(cmd_id) => cmd_id,
+                pe => panic!("Unexpected event {:?}", pe)
+            })
+            .collect()
+    }
+
+    #[test]
+    pub fn commander_fetch_send_request_event_works_for_priority() {
+        let (send_cmd_sock, recv_cmd_sock) = pool_create_pair_of_sockets("send_request_priority");
+
+        let cmd = Commander::new(recv_cmd_sock);
+
+        let cmd_id: CommandHandle = next_command_handle();
+        _send_request(&send_cmd_sock, cmd_id, Some(RequestPriority::High));
+        assert_match!(Some(PoolEvent::SendRequest(cmd_id_, _, None, None)), cmd.fetch_events(), cmd_id_, cmd_id);
+    }
+
+    #[test]
+    pub fn commander_fetch_all_events_works_for_high_priority_requests() {
+        let (send_cmd_sock, recv_cmd_sock) = pool_create_pair_of_sockets("fetch_all_priority");
+
+        let cmd = Commander::new(recv_cmd_sock);
+
+        _send_request(&send_cmd_sock, 1, None);
+        _send_request(&send_cmd_sock, 2, Some(RequestPriority::Normal));
+        _send_request(&send_cmd_sock, 3, Some(RequestPriority::High));
+        _send_request(&send_cmd_sock, 4, Some(RequestPriority::High));
+
+        assert_eq!(vec![3, 4, 1, 2], _cmd_ids(cmd.fetch_all_events()));
+        assert!(cmd.fetch_all_events().is_empty());
+    }
+
+    #[test]
+    pub fn commander_fetch_all_events_works_for_pool_command_between_requests() {
+        let (send_cmd_sock, recv_cmd_sock) = pool_create_pair_of_sockets("fetch_all_pool_command");
+
+        let cmd = Commander::new(recv_cmd_sock);
+
+        _send_request(&send_cmd_sock, 1, None);
+        let mut buf = [0u8; 4];
+        LittleEndian::write_i32(&mut buf, 2);
+        send_cmd_sock.send_multipart(&[COMMAND_REFRESH.as_bytes(), &buf], zmq::DONTWAIT).unwrap();
+        _send_request(&send_cmd_sock, 3, None);
+        _send_request(&send_cmd_sock, 4, Some(RequestPriority::High));
+
+        assert_eq!(vec![1, 2, 4, 3], _cmd_ids(cmd.fetch_all_events()));
+    }
+
 }
\ No newline at end of file
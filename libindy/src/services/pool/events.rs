@@ -237,9 +237,9 @@ impl Into<Option<RequestEvent>> for PoolEvent {
                 if let Ok((ref req, ref req_id, ref op)) = parsed_req {
                     if REQUEST_FOR_FULL.contains(&op.as_str()) {
                         Some(RequestEvent::CustomFullRequest(msg, req_id.clone(), timeout, nodes))
-                    } else if timeout.is_some() || nodes.is_some() {
-                        error!("Timeout {:?} or nodes {:?} is specified for non-supported request operation type {}",
-                               timeout, nodes, op);
+                    } else if nodes.is_some() {
+                        error!("Nodes {:?} is specified for non-supported request operation type {}",
+                               nodes, op);
                         None
                     } else if REQUESTS_FOR_STATE_PROOFS.contains(&op.as_str()) {
                         let key = super::state_proof::parse_key_from_request_for_builtin_sp(&req);
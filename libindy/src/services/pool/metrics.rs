@@ -0,0 +1,69 @@
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+pub const CATCHUP_ROUND: &str = "catchup_round";
+pub const MERKLE_CONSISTENCY_FAILURE: &str = "merkle_consistency_failure";
+pub const REPLY_MISMATCH: &str = "reply_mismatch";
+pub const STATE_PROOF_REJECTION: &str = "state_proof_rejection";
+
+pub type PoolEventCounters = BTreeMap<(String /* pool name */, &'static str /* event */), u128>;
+
+lazy_static! {
+    static ref COUNTERS: Mutex<PoolEventCounters> = Mutex::new(BTreeMap::new());
+}
+
+/// Counts an event of catch-up or request handler state machine of the pool.
+pub fn increment(pool_name: &str, event: &'static str) {
+    *COUNTERS.lock().unwrap()
+        .entry((pool_name.to_string(), event))
+        .or_insert(0) += 1;
+}
+
+pub fn get_counters() -> PoolEventCounters {
+    COUNTERS.lock().unwrap().clone()
+}
+
+pub fn reset_counters() {
+    COUNTERS.lock().unwrap().clear();
+}
+
+/// Returns counters accumulated after `snapshot` was taken, counters reset in between are returned as is.
+pub fn counters_since(counters: &PoolEventCounters, snapshot: &PoolEventCounters) -> PoolEventCounters {
+    counters.iter()
+        .map(|(key, count)| {
+            let previous = snapshot.get(key).cloned().unwrap_or(0);
+            (key.clone(), if *count < previous { *count } else { count - previous })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn increment_works() {
+        let pool_name = "pool_metrics_increment_works";
+
+        increment(pool_name, CATCHUP_ROUND);
+        increment(pool_name, CATCHUP_ROUND);
+        increment(pool_name, REPLY_MISMATCH);
+
+        let counters = get_counters();
+
+        assert_eq!(Some(&2), counters.get(&(pool_name.to_string(), CATCHUP_ROUND)));
+        assert_eq!(Some(&1), counters.get(&(pool_name.to_string(), REPLY_MISMATCH)));
+        assert_eq!(None, counters.get(&(pool_name.to_string(), STATE_PROOF_REJECTION)));
+    }
+
+    #[test]
+    fn counters_since_works() {
+        let key = ("pool".to_string(), CATCHUP_ROUND);
+
+        let snapshot: PoolEventCounters = vec![(key.clone(), 2)].into_iter().collect();
+        let counters: PoolEventCounters = vec![(key.clone(), 5)].into_iter().collect();
+
+        assert_eq!(Some(&3), counters_since(&counters, &snapshot).get(&key));
+        assert_eq!(Some(&2), counters_since(&snapshot, &counters).get(&key));
+    }
+}
@@ -13,13 +13,14 @@ use std::cell::RefCell;
 use std::collections::HashMap;
 use std::io::Write;
 use std::sync::Mutex;
+use std::time::Instant;
 
 use serde_json;
 use serde::de::DeserializeOwned;
 
 use crate::api::ledger::{CustomFree, CustomTransactionParser};
 use crate::domain::{
-    pool::{PoolConfig, PoolOpenConfig},
+    pool::{CapturedRequest, PoolConfig, PoolOpenConfig, RequestPriority, SubmitRequestOptions},
     ledger::response::{
         Message,
         Reply,
@@ -32,18 +33,26 @@ use crate::utils::environment;
 use crate::services::pool::events::{COMMAND_EXIT, COMMAND_CONNECT, COMMAND_REFRESH};
 use indy_api_types::{CommandHandle, PoolHandle};
 use indy_utils::{next_command_handle, next_pool_handle};
-use ursa::bls::VerKey;
+use indy_utils::slow_operations;
+use ursa::bls::{Generator, VerKey};
+use rust_base58::FromBase58;
+use crate::services::pool::request_handler::DEFAULT_GENERATOR;
+use crate::services::pool::types::ParsedSP;
 
 mod catchup;
 mod commander;
 mod events;
 mod merkle_tree_factory;
+pub mod metrics;
 mod networker;
 mod pool;
+mod reply_capture;
 mod request_handler;
 mod state_proof;
 mod types;
 
+pub use self::networker::{set_io_threads, set_socket_hwm};
+
 lazy_static! {
     static ref REGISTERED_SP_PARSERS: Mutex<HashMap<String, (CustomTransactionParser, CustomFree)>> = Mutex::new(HashMap::new());
 }
@@ -53,6 +62,32 @@ type Nodes = HashMap<String, Option<VerKey>>;
 pub struct PoolService {
     open_pools: RefCell<HashMap<PoolHandle, ZMQPool>>,
     pending_pools: RefCell<HashMap<PoolHandle, ZMQPool>>,
+    pending_requests: RefCell<HashMap<CommandHandle, PendingRequest>>,
+    read_connections: RefCell<HashMap<PoolHandle, ReadConnections>>,
+    pending_read_connections: RefCell<HashMap<PoolHandle /* connection */, (PoolHandle /* pool */, ZMQPool)>>,
+    read_connection_configs: RefCell<HashMap<PoolHandle, PoolOpenConfig>>,
+}
+
+/// Additional connections of the pool opened with `read_connections` option.
+/// Read requests are distributed round-robin over the pool connection itself and these ones.
+struct ReadConnections {
+    connections: Vec<ZMQPool>,
+    next: usize,
+}
+
+impl ReadConnections {
+    /// Returns index of the connection to send the next read request to, `None` means the pool connection itself.
+    fn next(&mut self) -> Option<usize> {
+        let idx = self.next % (self.connections.len() + 1);
+        self.next = self.next.wrapping_add(1);
+        idx.checked_sub(1)
+    }
+}
+
+struct PendingRequest {
+    start: Instant,
+    txn_type: String,
+    params_digest: String,
 }
 
 impl PoolService {
@@ -60,6 +95,10 @@ impl PoolService {
         PoolService {
             open_pools: RefCell::new(HashMap::new()),
             pending_pools: RefCell::new(HashMap::new()),
+            pending_requests: RefCell::new(HashMap::new()),
+            read_connections: RefCell::new(HashMap::new()),
+            pending_read_connections: RefCell::new(HashMap::new()),
+            read_connection_configs: RefCell::new(HashMap::new()),
         }
     }
 
@@ -154,12 +193,18 @@ impl PoolService {
         let config = config.unwrap_or_default();
 
         let pool_handle: PoolHandle = next_pool_handle();
+
+        if config.read_connections > 1 {
+            // additional connections are opened when the pool is, so they start with the pool ledger already caught up
+            self.read_connection_configs.try_borrow_mut()?.insert(pool_handle, config.clone());
+        }
+
         let mut new_pool = Pool::new(name, pool_handle, config);
 
         let (send_cmd_sock, recv_cmd_sock) = pool_create_pair_of_sockets(&format!("pool_{}", name));
 
-        new_pool.work(recv_cmd_sock);
-        self._send_msg(pool_handle, COMMAND_CONNECT, &send_cmd_sock, None, None)?;
+        new_pool.work(recv_cmd_sock)?;
+        self._send_msg(pool_handle, COMMAND_CONNECT, &send_cmd_sock, None, None, RequestPriority::Normal)?;
 
         self.pending_pools.try_borrow_mut()?
             .insert(new_pool.get_id(), ZMQPool::new(new_pool, send_cmd_sock));
@@ -173,26 +218,145 @@ impl PoolService {
 
         self.open_pools.try_borrow_mut()?.insert(pool_id, pool);
 
+        if let Err(err) = self._open_read_connections(pool_id) {
+            warn!("Can't open read connections of pool {:?}: {:?}", pool_id, err);
+        }
+
         Ok(pool_id)
     }
 
+    fn _open_read_connections(&self, pool_id: PoolHandle) -> IndyResult<()> {
+        let config = match self.read_connection_configs.try_borrow_mut()?.remove(&pool_id) {
+            Some(config) => config,
+            None => return Ok(())
+        };
+
+        let name = self.get_pool_name(pool_id)?;
+
+        for _ in 1..config.read_connections {
+            let connection_id: PoolHandle = next_pool_handle();
+            // replies of the read connections aren't captured, they have their own handles
+            let connection_config = PoolOpenConfig { read_connections: 0, reply_capture_size: 0, ..config.clone() };
+            let mut connection = Pool::new(&name, connection_id, connection_config);
+
+            let (send_cmd_sock, recv_cmd_sock) = pool_create_pair_of_sockets(&format!("pool_{}_{}", name, connection_id));
+
+            connection.work(recv_cmd_sock)?;
+            self._send_msg(connection_id, COMMAND_CONNECT, &send_cmd_sock, None, None, RequestPriority::Normal)?;
+
+            self.pending_read_connections.try_borrow_mut()?
+                .insert(connection_id, (pool_id, ZMQPool::new(connection, send_cmd_sock)));
+        }
+
+        Ok(())
+    }
+
+    /// Completes opening of the read connection. Returns false if there is no read connection with requested handle.
+    /// Connection that failed to open or whose pool is already closed is dropped.
+    pub fn add_read_connection(&self, connection_id: PoolHandle, result: IndyResult<()>) -> IndyResult<bool> {
+        let (pool_id, connection) = match self.pending_read_connections.try_borrow_mut()?.remove(&connection_id) {
+            Some(pending) => pending,
+            None => return Ok(false)
+        };
+
+        if let Err(err) = result {
+            warn!("Can't open read connection {:?} of pool {:?}: {:?}", connection_id, pool_id, err);
+            return Ok(true);
+        }
+
+        if self.open_pools.try_borrow()?.contains_key(&pool_id) {
+            self.read_connections.try_borrow_mut()?
+                .entry(pool_id)
+                .or_insert_with(|| ReadConnections { connections: Vec::new(), next: 0 })
+                .connections.push(connection);
+        }
+
+        Ok(true)
+    }
+
+
+    pub fn check_handle(&self, handle: PoolHandle) -> IndyResult<()> {
+        if self.open_pools.try_borrow()?.contains_key(&handle) {
+            Ok(())
+        } else {
+            Err(err_msg(IndyErrorKind::InvalidPoolHandle, format!("No pool with requested handle {:?}", handle)))
+        }
+    }
 
     pub fn send_tx(&self, handle: PoolHandle, msg: &str) -> IndyResult<CommandHandle> {
         self.send_action(handle, msg, None, None)
     }
 
+    /// Sends request with per-request reply timeout and priority.
+    /// High priority requests overtake the normal ones still waiting in the pool worker queue.
+    pub fn send_tx_with_options(&self, handle: PoolHandle, msg: &str, options: &SubmitRequestOptions) -> IndyResult<CommandHandle> {
+        self._send_to_pool(handle, msg, None, options.timeout, options.priority)
+    }
+
     pub fn send_action(&self, handle: PoolHandle, msg: &str, nodes: Option<&str>, timeout: Option<i32>) -> IndyResult<CommandHandle> {
+        self._send_to_pool(handle, msg, nodes, timeout, RequestPriority::Normal)
+    }
+
+    fn _send_to_pool(&self, handle: PoolHandle, msg: &str, nodes: Option<&str>, timeout: Option<i32>, priority: RequestPriority) -> IndyResult<CommandHandle> {
         let pools = self.open_pools.try_borrow()?;
 
         if let Some(ref pool) = pools.get(&handle) {
             let cmd_id: CommandHandle = next_command_handle();
-            self._send_msg(cmd_id, msg, &pool.cmd_socket, nodes, timeout)?;
+
+            let mut read_connections = self.read_connections.try_borrow_mut()?;
+
+            let socket = match read_connections.get_mut(&handle) {
+                Some(connections) if nodes.is_none() && PoolService::_is_read_request(msg) => {
+                    match connections.next() {
+                        Some(idx) => &connections.connections[idx].cmd_socket,
+                        None => &pool.cmd_socket
+                    }
+                }
+                _ => &pool.cmd_socket
+            };
+
+            self._send_msg(cmd_id, msg, socket, nodes, timeout, priority)?;
+            #[cfg(feature = "tracing_spans")]
+            crate::utils::tracing::start_pending_span(cmd_id, "indy.pool.request");
+            if slow_operations::is_enabled() {
+                self._start_request_timer(cmd_id, msg);
+            }
             Ok(cmd_id)
         } else {
             Err(err_msg(IndyErrorKind::InvalidPoolHandle, format!("No pool with requested handle {:?}", handle)))
         }
     }
 
+    fn _is_read_request(msg: &str) -> bool {
+        serde_json::from_str::<serde_json::Value>(msg).ok()
+            .and_then(|request| request["operation"]["type"].as_str().map(String::from))
+            .map(|txn_type| events::REQUESTS_FOR_STATE_PROOFS.contains(&txn_type.as_str()) || PoolService::get_sp_parser(&txn_type).is_some())
+            .unwrap_or(false)
+    }
+
+    fn _start_request_timer(&self, cmd_id: CommandHandle, msg: &str) {
+        let txn_type = serde_json::from_str::<serde_json::Value>(msg).ok()
+            .and_then(|request| request["operation"]["type"].as_str().map(String::from))
+            .unwrap_or_default();
+
+        self.pending_requests.borrow_mut().insert(cmd_id, PendingRequest {
+            start: Instant::now(),
+            txn_type,
+            params_digest: slow_operations::params_digest(&[msg]),
+        });
+    }
+
+    /// Logs request that got the reply later than the slow operation threshold.
+    pub fn request_completed(&self, cmd_id: CommandHandle) {
+        if let Some(request) = self.pending_requests.borrow_mut().remove(&cmd_id) {
+            let elapsed = request.start.elapsed();
+
+            if slow_operations::is_slow(elapsed) {
+                slow_operations::log("pool request", &request.txn_type, &request.params_digest, elapsed);
+            }
+        }
+    }
+
     pub fn register_sp_parser(txn_type: &str,
                               parser: CustomTransactionParser, free: CustomFree) -> IndyResult<()> {
         if events::REQUESTS_FOR_STATE_PROOFS.contains(&txn_type) {
@@ -214,30 +378,143 @@ impl PoolService {
         parsers.get(txn_type).map(Clone::clone)
     }
 
+    /// Verifies state proof and multi signature of the reply on the read request against
+    /// BLS keys of the pool nodes (alias -> base58 BLS key) without any opened pool.
+    /// Freshness of the reply isn't checked.
+    pub fn verify_state_proof(request: &str, response: &str, bls_keys: &HashMap<String, String>) -> IndyResult<bool> {
+        let nodes = PoolService::_nodes_from_bls_keys(bls_keys)?;
+
+        let request: serde_json::Value = serde_json::from_str(request)
+            .to_indy(IndyErrorKind::InvalidStructure, "Request is invalid json")?;
+
+        let response_json: serde_json::Value = serde_json::from_str(response)
+            .to_indy(IndyErrorKind::InvalidStructure, "Response is invalid json")?;
+
+        if response_json["op"].as_str() != Some("REPLY") {
+            return Err(err_msg(IndyErrorKind::InvalidTransaction, "Response isn't a reply"));
+        }
+
+        let sp_key = state_proof::parse_key_from_request_for_builtin_sp(&request);
+
+        let res = match state_proof::parse_generic_reply_for_proof_checking(&response_json["result"], response, sp_key.as_deref()) {
+            Some(parsed_sps) => PoolService::_verify_parsed_sps(parsed_sps, &nodes),
+            None => false
+        };
+
+        Ok(res)
+    }
+
+    /// Verifies state proofs already extracted from the reply (in the format returned by
+    /// custom state proof parsers) against BLS keys of the pool nodes (alias -> base58 BLS key).
+    pub fn verify_parsed_state_proofs(parsed_sps: &str, bls_keys: &HashMap<String, String>) -> IndyResult<bool> {
+        let nodes = PoolService::_nodes_from_bls_keys(bls_keys)?;
+
+        let parsed_sps: Vec<ParsedSP> = serde_json::from_str(parsed_sps)
+            .to_indy(IndyErrorKind::InvalidStructure, "Invalid state proofs json")?;
+
+        if parsed_sps.is_empty() {
+            return Ok(false);
+        }
+
+        Ok(PoolService::_verify_parsed_sps(parsed_sps, &nodes))
+    }
+
+    fn _nodes_from_bls_keys(bls_keys: &HashMap<String, String>) -> IndyResult<Nodes> {
+        if bls_keys.is_empty() {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "BLS keys of pool nodes are not provided"));
+        }
+
+        bls_keys.iter()
+            .map(|(alias, blskey)| {
+                let key = blskey
+                    .from_base58()
+                    .map_err(|err| err_msg(IndyErrorKind::InvalidStructure, format!("Invalid BLS key of node {}: {:?}", alias, err)))?;
+
+                let key = VerKey::from_bytes(&key)
+                    .to_indy(IndyErrorKind::InvalidStructure, format!("Invalid BLS key of node {}", alias))?;
+
+                Ok((alias.clone(), Some(key)))
+            })
+            .collect::<IndyResult<Nodes>>()
+    }
+
+    fn _verify_parsed_sps(parsed_sps: Vec<ParsedSP>, nodes: &Nodes) -> bool {
+        let generator = Generator::from_bytes(&DEFAULT_GENERATOR.from_base58().unwrap()).unwrap();
+
+        state_proof::verify_parsed_sp(parsed_sps, nodes, pool::get_f(nodes.len()), &generator)
+    }
+
     pub fn close(&self, handle: PoolHandle) -> IndyResult<CommandHandle> {
         let cmd_id: CommandHandle = next_command_handle();
 
         let mut pools = self.open_pools.try_borrow_mut()?;
 
         match pools.remove(&handle) {
-            Some(ref pool) => self._send_msg(cmd_id, COMMAND_EXIT, &pool.cmd_socket, None, None)?,
+            Some(ref pool) => self._send_msg(cmd_id, COMMAND_EXIT, &pool.cmd_socket, None, None, RequestPriority::Normal)?,
             None => return Err(err_msg(IndyErrorKind::InvalidPoolHandle, format!("No pool with requested handle {}", handle)))
         }
 
+        // dropped read connections are stopped
+        self.read_connections.try_borrow_mut()?.remove(&handle);
+        self.pending_read_connections.try_borrow_mut()?.retain(|_, (pool_id, _)| *pool_id != handle);
+
         Ok(cmd_id)
     }
 
+    /// Returns requests with node replies recorded by the pool opened with `reply_capture_size` option.
+    pub fn get_reply_capture(&self, handle: PoolHandle) -> IndyResult<Vec<CapturedRequest>> {
+        self.check_handle(handle)?;
+        reply_capture::get(handle)
+    }
+
     pub fn refresh(&self, handle: PoolHandle) -> IndyResult<i32> {
         self.send_action(handle, COMMAND_REFRESH, None, None)
     }
 
-    fn _send_msg(&self, cmd_id: CommandHandle, msg: &str, socket: &Socket, nodes: Option<&str>, timeout: Option<i32>) -> IndyResult<()> {
+    pub fn open_pool_handles(&self) -> IndyResult<Vec<PoolHandle>> {
+        Ok(self.open_pools.try_borrow()?.keys().cloned().collect())
+    }
+
+    /// Returns name of the opened pool ledger config.
+    pub fn get_pool_name(&self, handle: PoolHandle) -> IndyResult<String> {
+        let pools = self.open_pools.try_borrow()?;
+
+        let pool = pools.get(&handle)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidPoolHandle, format!("No pool with requested handle {}", handle)))?;
+
+        Ok(pool.pool.get_name().to_string())
+    }
+
+    /// Returns aliases of the validator nodes of the opened pool mapped to their DIDs.
+    /// Nodes are taken from the locally cached pool ledger.
+    pub fn get_pool_nodes(&self, handle: PoolHandle) -> IndyResult<HashMap<String, String>> {
+        let pools = self.open_pools.try_borrow()?;
+
+        let pool = pools.get(&handle)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidPoolHandle, format!("No pool with requested handle {}", handle)))?;
+
+        let merkle_tree = merkle_tree_factory::create(pool.pool.get_name())?;
+
+        let nodes = merkle_tree_factory::build_node_state(&merkle_tree)?
+            .into_iter()
+            .filter(|(_, txn)| txn.txn.data.data.services.as_ref().map(|services| services.iter().any(|s| s == "VALIDATOR")).unwrap_or(false))
+            .map(|(dest, txn)| (txn.txn.data.data.alias, dest))
+            .collect();
+
+        Ok(nodes)
+    }
+
+    fn _send_msg(&self, cmd_id: CommandHandle, msg: &str, socket: &Socket, nodes: Option<&str>, timeout: Option<i32>, priority: RequestPriority) -> IndyResult<()> {
         let mut buf = [0u8; 4];
         let mut buf_to = [0u8; 4];
         LittleEndian::write_i32(&mut buf, cmd_id);
         let timeout = timeout.unwrap_or(-1);
         LittleEndian::write_i32(&mut buf_to, timeout);
-        if let Some(nodes) = nodes {
+        if priority != RequestPriority::Normal {
+            // empty nodes part keeps the position of the priority part
+            let buf_priority = [priority.to_byte()];
+            Ok(socket.send_multipart([msg.as_bytes(), &buf, &buf_to, nodes.unwrap_or("").as_bytes(), &buf_priority], zmq::DONTWAIT)?)
+        } else if let Some(nodes) = nodes {
             Ok(socket.send_multipart(&[msg.as_bytes(), &buf, &buf_to, nodes.as_bytes()], zmq::DONTWAIT)?)
         } else {
             Ok(socket.send_multipart(&[msg.as_bytes(), &buf, &buf_to], zmq::DONTWAIT)?)
@@ -326,7 +603,7 @@ fn _parse_transaction_metadata_v1(message: &serde_json::Value) -> ResponseMetada
 }
 
 pub fn pool_create_pair_of_sockets(addr: &str) -> (zmq::Socket, zmq::Socket) {
-    let zmq_ctx = zmq::Context::new();
+    let zmq_ctx = networker::new_zmq_context();
     let send_cmd_sock = zmq_ctx.socket(zmq::SocketType::PAIR).unwrap();
     let recv_cmd_sock = zmq_ctx.socket(zmq::SocketType::PAIR).unwrap();
 
@@ -407,6 +684,25 @@ mod tests {
             assert_eq!(cmd_id, LittleEndian::read_i32(recv[1].as_slice()));
         }
 
+        #[test]
+        fn pool_service_send_tx_with_options_works() {
+            test::cleanup_storage("pool_service_send_tx_with_options_works");
+
+            let ps = PoolService::new();
+            let pool_id = next_pool_handle();
+            let (send_cmd_sock, recv_cmd_sock) = pool_create_pair_of_sockets("pool_service_send_tx_with_options_works");
+            ps.open_pools.borrow_mut().insert(pool_id, ZMQPool::new(Pool::new("", pool_id, PoolOpenConfig::default()), send_cmd_sock));
+            let options = SubmitRequestOptions { timeout: Some(5), priority: RequestPriority::High };
+            let cmd_id = ps.send_tx_with_options(pool_id, "test_data", &options).unwrap();
+            let recv = recv_cmd_sock.recv_multipart(zmq::DONTWAIT).unwrap();
+            assert_eq!(recv.len(), 5);
+            assert_eq!("test_data", String::from_utf8(recv[0].clone()).unwrap());
+            assert_eq!(cmd_id, LittleEndian::read_i32(recv[1].as_slice()));
+            assert_eq!(5, LittleEndian::read_i32(recv[2].as_slice()));
+            assert!(recv[3].is_empty());
+            assert_eq!(RequestPriority::High, RequestPriority::from_byte(recv[4][0]));
+        }
+
         #[test]
         fn pool_service_delete_works() {
             test::cleanup_storage("pool_service_delete_works");
@@ -547,6 +843,66 @@ mod tests {
             assert_eq!(None, PoolService::get_sp_parser("test"));
         }
 
+        fn _bls_keys() -> HashMap<String, String> {
+            let node = nodes_emulator::node();
+            let mut bls_keys = HashMap::new();
+            bls_keys.insert(node.txn.data.data.alias, node.txn.data.data.blskey.unwrap());
+            bls_keys
+        }
+
+        const GET_NYM_REQUEST: &str = r#"{"reqId":1,"identifier":"Th7MpTaRZVRYnPiabds81Y","operation":{"type":"105","dest":"Th7MpTaRZVRYnPiabds81Y"},"protocolVersion":2}"#;
+
+        #[test]
+        fn pool_verify_state_proof_works_for_reply_without_state_proof() {
+            let response = r#"{"op":"REPLY","result":{"type":"105","dest":"Th7MpTaRZVRYnPiabds81Y","seqNo":1,"txnTime":1,"data":null}}"#;
+            assert!(!PoolService::verify_state_proof(GET_NYM_REQUEST, response, &_bls_keys()).unwrap());
+        }
+
+        #[test]
+        fn pool_verify_state_proof_works_for_empty_bls_keys() {
+            let response = r#"{"op":"REPLY","result":{}}"#;
+            let res = PoolService::verify_state_proof(GET_NYM_REQUEST, response, &HashMap::new());
+            assert_eq!(IndyErrorKind::InvalidStructure, res.unwrap_err().kind());
+        }
+
+        #[test]
+        fn pool_verify_state_proof_works_for_invalid_bls_key() {
+            let response = r#"{"op":"REPLY","result":{}}"#;
+            let mut bls_keys = HashMap::new();
+            bls_keys.insert("n1".to_string(), "invalid".to_string());
+            let res = PoolService::verify_state_proof(GET_NYM_REQUEST, response, &bls_keys);
+            assert_eq!(IndyErrorKind::InvalidStructure, res.unwrap_err().kind());
+        }
+
+        #[test]
+        fn pool_verify_state_proof_works_for_not_reply() {
+            let response = r#"{"op":"REQNACK","reason":"some reason"}"#;
+            let res = PoolService::verify_state_proof(GET_NYM_REQUEST, response, &_bls_keys());
+            assert_eq!(IndyErrorKind::InvalidTransaction, res.unwrap_err().kind());
+        }
+
+        #[test]
+        fn pool_verify_parsed_state_proofs_works_for_empty_list() {
+            assert!(!PoolService::verify_parsed_state_proofs("[]", &_bls_keys()).unwrap());
+        }
+
+        #[test]
+        fn pool_verify_parsed_state_proofs_works_for_invalid_multi_signature() {
+            let parsed_sps = json!([{
+                "proof_nodes": "",
+                "root_hash": "",
+                "kvs_to_verify": {"type": "Simple", "kvs": []},
+                "multi_signature": {}
+            }]).to_string();
+            assert!(!PoolService::verify_parsed_state_proofs(&parsed_sps, &_bls_keys()).unwrap());
+        }
+
+        #[test]
+        fn pool_verify_parsed_state_proofs_works_for_invalid_json() {
+            let res = PoolService::verify_parsed_state_proofs("{}", &_bls_keys());
+            assert_eq!(IndyErrorKind::InvalidStructure, res.unwrap_err().kind());
+        }
+
         #[test]
         pub fn pool_add_open_pool_works() {
             test::cleanup_storage("pool_add_open_pool_works");
@@ -593,7 +949,7 @@ mod tests {
 
             let pool_id = next_pool_handle();
             let mut pool = Pool::new(pool_name, pool_id, PoolOpenConfig::default());
-            pool.work(recv_cmd_sock);
+            pool.work(recv_cmd_sock).unwrap();
             ps.open_pools.borrow_mut().insert(pool_id, ZMQPool::new(pool, send_cmd_sock));
             thread::sleep(time::Duration::from_secs(1));
             ps.close(pool_id).unwrap();
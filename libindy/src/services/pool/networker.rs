@@ -1,5 +1,6 @@
 use std::cell::RefCell;
 use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::atomic::{AtomicI32, Ordering};
 
 use rand::thread_rng;
 use rand::prelude::SliceRandom;
@@ -16,12 +17,42 @@ use super::time::Duration;
 use super::zmq::PollItem;
 use super::zmq::Socket as ZSocket;
 
+// Negative value keeps ZMQ default
+static SOCKET_HWM: AtomicI32 = AtomicI32::new(-1);
+
+pub fn set_socket_hwm(hwm: i32) {
+    SOCKET_HWM.store(hwm, Ordering::SeqCst);
+}
+
+// Zero keeps ZMQ default
+static IO_THREADS: AtomicI32 = AtomicI32::new(0);
+
+pub fn set_io_threads(io_threads: i32) {
+    IO_THREADS.store(io_threads, Ordering::SeqCst);
+}
+
+/// Creates ZMQ context with the configured number of IO threads. It must be set before sockets are created.
+pub fn new_zmq_context() -> zmq::Context {
+    let ctx = zmq::Context::new();
+    let io_threads = IO_THREADS.load(Ordering::SeqCst);
+
+    if io_threads > 0 {
+        if let Err(err) = ctx.set_io_threads(io_threads) {
+            warn!("Can't set number of ZMQ IO threads to {}: {:?}", io_threads, err);
+        }
+    }
+
+    ctx
+}
+
 pub trait Networker {
     fn new(active_timeout: i64, conn_limit: usize, preordered_nodes: Vec<String>, socks_proxy: String) -> Self;
     fn fetch_events(&self, poll_items: &[PollItem]) -> Vec<PoolEvent>;
     fn process_event(&mut self, pe: Option<NetworkerEvent>) -> Option<RequestEvent>;
     fn get_timeout(&self) -> ((String, String), i64);
     fn get_poll_items(&self) -> Vec<PollItem>;
+    fn set_max_sockets(&mut self, _max_sockets: usize) {}
+    fn set_conn_count_per_node(&mut self, _conn_count_per_node: usize) {}
 }
 
 pub struct ZMQNetworker {
@@ -32,6 +63,46 @@ pub struct ZMQNetworker {
     conn_limit: usize,
     preordered_nodes: Vec<String>,
     socks_proxy: String,
+    max_sockets: usize,
+    conn_count_per_node: usize,
+    next_connection: usize,
+}
+
+impl ZMQNetworker {
+    // Every connection can open a socket to each node, at least one connection is always allowed
+    fn is_socket_limit_reached(&self) -> bool {
+        self.max_sockets != 0 && !self.pool_connections.is_empty() &&
+            (self.pool_connections.len() + 1) * self.nodes.len() > self.max_sockets
+    }
+
+    // New requests are distributed round-robin over the last `conn_count_per_node` connections,
+    // so every node gets requests by this many sockets. None means a new connection is required.
+    fn select_connection(&mut self) -> Option<i32> {
+        let nodes = self.nodes.iter().collect::<HashSet<&RemoteNode>>();
+
+        let available: Vec<i32> = self.pool_connections.iter()
+            .rev()
+            .take(self.conn_count_per_node)
+            .filter(|(_, pc)| pc.is_active() && pc.req_cnt < self.conn_limit
+                && pc.nodes.iter().collect::<HashSet<&RemoteNode>>().eq(&nodes))
+            .map(|(pc_idx, _)| *pc_idx)
+            .collect();
+
+        if available.len() < self.conn_count_per_node && !self.is_socket_limit_reached() {
+            trace!("existing connection unavailable");
+            return None;
+        }
+
+        if available.is_empty() {
+            trace!("sockets limit reached, existing connection is reused");
+            return self.pool_connections.keys().next_back().copied();
+        }
+
+        trace!("existing connection available");
+        let pc_idx = available[self.next_connection % available.len()];
+        self.next_connection = self.next_connection.wrapping_add(1);
+        Some(pc_idx)
+    }
 }
 
 impl Networker for ZMQNetworker {
@@ -44,9 +115,20 @@ impl Networker for ZMQNetworker {
             conn_limit,
             preordered_nodes,
             socks_proxy,
+            max_sockets: 0,
+            conn_count_per_node: 1,
+            next_connection: 0,
         }
     }
 
+    fn set_max_sockets(&mut self, max_sockets: usize) {
+        self.max_sockets = max_sockets;
+    }
+
+    fn set_conn_count_per_node(&mut self, conn_count_per_node: usize) {
+        self.conn_count_per_node = conn_count_per_node.max(1);
+    }
+
     fn fetch_events(&self, poll_items: &[PollItem]) -> Vec<PoolEvent> {
         let mut cnt = 0;
         self.pool_connections.iter().map(|(_, pc)| {
@@ -59,20 +141,13 @@ impl Networker for ZMQNetworker {
     fn process_event(&mut self, pe: Option<NetworkerEvent>) -> Option<RequestEvent> {
         match pe.clone() {
             Some(NetworkerEvent::SendAllRequest(_, req_id, _, _)) | Some(NetworkerEvent::SendOneRequest(_, req_id, _)) | Some(NetworkerEvent::Resend(req_id, _)) => {
-                let num = self.req_id_mappings.get(&req_id).copied().or_else(|| {
-                    trace!("sending new request");
-                    self.pool_connections.iter().next_back().and_then(|(pc_idx, pc)| {
-                        if pc.is_active() && pc.req_cnt < self.conn_limit
-                            && pc.nodes.iter().collect::<HashSet<&RemoteNode>>().eq(
-                            &self.nodes.iter().collect::<HashSet<&RemoteNode>>()) {
-                            trace!("existing connection available");
-                            Some(*pc_idx)
-                        } else {
-                            trace!("existing connection unavailable");
-                            None
-                        }
-                    })
-                });
+                let num = match self.req_id_mappings.get(&req_id).copied() {
+                    Some(idx) => Some(idx),
+                    None => {
+                        trace!("sending new request");
+                        self.select_connection()
+                    }
+                };
                 match num {
                     Some(idx) => {
                         trace!("send request in existing conn");
@@ -201,7 +276,7 @@ impl PoolConnection {
         PoolConnection {
             nodes,
             sockets,
-            ctx: zmq::Context::new(),
+            ctx: new_zmq_context(),
             key_pair: zmq::CurveKeyPair::new().expect("FIXME"),
             resend: RefCell::new(HashMap::new()),
             time_created: time::now(),
@@ -353,6 +428,12 @@ impl RemoteNode {
             .as_bytes())?;
         s.set_linger(0)?; //TODO set correct timeout
 
+        let hwm = SOCKET_HWM.load(Ordering::SeqCst);
+        if hwm >= 0 {
+            s.set_sndhwm(hwm)?;
+            s.set_rcvhwm(hwm)?;
+        }
+
         if !socks_proxy.is_empty() {
             // let proxy = socks_proxy.unwrap();
             debug!("Use socks proxy: {}", socks_proxy);
@@ -478,6 +559,41 @@ pub mod networker_tests {
             assert!(nodes_emulator::next(&handle).is_none());
         }
 
+        #[test]
+        fn networker_process_send_request_event_works_for_sockets_limit() {
+            let mut txn = nodes_emulator::node();
+            let _handle = nodes_emulator::start(&mut txn);
+            let rn = _remote_node(&txn);
+
+            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, 1, vec![], String::new());
+            networker.set_max_sockets(1);
+            networker.process_event(Some(NetworkerEvent::NodesStateUpdated(vec![rn])));
+
+            networker.process_event(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), "1".to_string(), POOL_ACK_TIMEOUT)));
+            networker.process_event(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), "2".to_string(), POOL_ACK_TIMEOUT)));
+
+            assert_eq!(1, networker.pool_connections.len());
+            assert_eq!(2, networker.req_id_mappings.len());
+        }
+
+        #[test]
+        fn networker_process_send_request_event_works_for_conn_count_per_node() {
+            let mut txn = nodes_emulator::node();
+            let _handle = nodes_emulator::start(&mut txn);
+            let rn = _remote_node(&txn);
+
+            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], String::new());
+            networker.set_conn_count_per_node(2);
+            networker.process_event(Some(NetworkerEvent::NodesStateUpdated(vec![rn])));
+
+            for i in 0..4 {
+                networker.process_event(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), i.to_string(), POOL_ACK_TIMEOUT)));
+            }
+
+            assert_eq!(2, networker.pool_connections.len());
+            assert!(networker.pool_connections.values().all(|pc| pc.req_cnt == 2));
+        }
+
         #[test]
         fn networker_process_send_all_request_event_works() {
             let mut txn_1 = nodes_emulator::node();
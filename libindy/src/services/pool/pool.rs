@@ -20,6 +20,7 @@ use crate::services::pool::commander::Commander;
 use crate::services::pool::events::*;
 use crate::services::pool::{merkle_tree_factory, Nodes};
 use crate::services::pool::networker::{Networker, ZMQNetworker};
+use crate::services::pool::reply_capture;
 use crate::services::pool::request_handler::{RequestHandler, RequestHandlerImpl};
 use rust_base58::{FromBase58, ToBase58};
 use crate::services::pool::types::{LedgerStatus, RemoteNode};
@@ -310,7 +311,7 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                     PoolEvent::CatchupRestart(merkle_tree) => {
                         if let Ok((nodes, remotes)) = _get_nodes_and_remotes(&merkle_tree) {
                             state.networker.borrow_mut().process_event(Some(NetworkerEvent::NodesStateUpdated(remotes)));
-                            state.request_handler = R::new(state.networker.clone(), _get_f(nodes.len()), &[], &nodes, &pool_name, timeout, extended_timeout, number_read_nodes);
+                            state.request_handler = R::new(state.networker.clone(), get_f(nodes.len()), &[], &nodes, &pool_name, timeout, extended_timeout, number_read_nodes);
                             let ls = _ledger_status(&merkle_tree);
                             state.request_handler.process_event(Some(RequestEvent::LedgerStatus(ls, None, Some(merkle_tree))));
                             PoolState::GettingCatchupTarget(state)
@@ -321,7 +322,7 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                     PoolEvent::CatchupTargetFound(target_mt_root, target_mt_size, merkle_tree) => {
                         if let Ok((nodes, remotes)) = _get_nodes_and_remotes(&merkle_tree) {
                             state.networker.borrow_mut().process_event(Some(NetworkerEvent::NodesStateUpdated(remotes)));
-                            let mut request_handler = R::new(state.networker.clone(), _get_f(nodes.len()), &[], &nodes, &pool_name, timeout, extended_timeout, number_read_nodes);
+                            let mut request_handler = R::new(state.networker.clone(), get_f(nodes.len()), &[], &nodes, &pool_name, timeout, extended_timeout, number_read_nodes);
                             request_handler.process_event(Some(RequestEvent::CatchupReq(merkle_tree, target_mt_size, target_mt_root)));
                             PoolState::SyncCatchup((request_handler, state).into())
                         } else {
@@ -379,12 +380,13 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                             PoolState::Terminated(state.into())
                         }
                     }
-                    PoolEvent::SendRequest(cmd_id, _, _, _) => {
+                    PoolEvent::SendRequest(cmd_id, _, req_timeout, _) => {
                         trace!("received request to send");
                         let re: Option<RequestEvent> = pe.into();
                         match re.as_ref().map(|r| r.get_req_id()) {
                             Some(req_id) => {
-                                let mut request_handler = R::new(state.networker.clone(), _get_f(state.nodes.len()), &[cmd_id], &state.nodes, &pool_name, timeout, extended_timeout, number_read_nodes);
+                                let req_timeout = req_timeout.map(i64::from).unwrap_or(timeout);
+                                let mut request_handler = R::new(state.networker.clone(), get_f(state.nodes.len()), &[cmd_id], &state.nodes, &pool_name, req_timeout, extended_timeout, number_read_nodes);
                                 request_handler.process_event(re);
                                 state.request_handlers.insert(req_id.to_string(), request_handler); //FIXME check already exists
                             }
@@ -452,6 +454,13 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
         PoolSM::step(pool_name, id, timeout, extended_timeout, number_read_nodes, state)
     }
 
+    pub fn in_flight_requests(&self) -> usize {
+        match self.state {
+            PoolState::Active(ref state) => state.request_handlers.len(),
+            _ => 0
+        }
+    }
+
     pub fn is_terminal(&self) -> bool {
         match self.state {
             PoolState::Initialization(_) |
@@ -476,6 +485,10 @@ pub struct Pool<S: Networker, R: RequestHandler<S>> {
     preordered_nodes: Vec<String>,
     number_read_nodes: u8,
     socks_proxy: String,
+    max_in_flight_requests: usize,
+    max_sockets: usize,
+    conn_count_per_node: usize,
+    reply_capture_size: usize,
 }
 
 impl<S: Networker, R: RequestHandler<S>> Pool<S, R> {
@@ -493,10 +506,14 @@ impl<S: Networker, R: RequestHandler<S>> Pool<S, R> {
             preordered_nodes: config.preordered_nodes,
             number_read_nodes: config.number_read_nodes,
             socks_proxy: config.socks_proxy,
+            max_in_flight_requests: config.max_in_flight_requests,
+            max_sockets: config.max_sockets,
+            conn_count_per_node: config.conn_count_per_node,
+            reply_capture_size: config.reply_capture_size,
         }
     }
 
-    pub fn work(&mut self, cmd_socket: zmq::Socket) {
+    pub fn work(&mut self, cmd_socket: zmq::Socket) -> IndyResult<()> {
         let name = self.name.as_str().to_string();
         let id = self.id;
         let timeout = self.timeout;
@@ -506,15 +523,32 @@ impl<S: Networker, R: RequestHandler<S>> Pool<S, R> {
         let preordered_nodes = self.preordered_nodes.clone();
         let number_read_nodes = self.number_read_nodes;
         let socks_proxy = self.socks_proxy.clone();
-        self.worker = Some(thread::spawn(move || {
-            let mut pool_thread: PoolThread<S, R> = PoolThread::new(cmd_socket, name, id,
-                                                                    timeout, extended_timeout,
-                                                                    active_timeout, conn_limit,
-                                                                    preordered_nodes,
-                                                                    number_read_nodes,
-                                                                    socks_proxy);
-            pool_thread.work();
-        }));
+        let max_in_flight_requests = self.max_in_flight_requests;
+        let max_sockets = self.max_sockets;
+        let conn_count_per_node = self.conn_count_per_node;
+        let capture_replies = self.reply_capture_size != 0;
+
+        if capture_replies {
+            reply_capture::start(id, self.reply_capture_size);
+        }
+
+        let worker = thread::Builder::new()
+            .name(format!("indy-pool-{}", name))
+            .spawn(move || {
+                let mut pool_thread: PoolThread<S, R> = PoolThread::new(cmd_socket, name, id,
+                                                                        timeout, extended_timeout,
+                                                                        active_timeout, conn_limit,
+                                                                        preordered_nodes,
+                                                                        number_read_nodes,
+                                                                        socks_proxy);
+                pool_thread.set_limits(max_in_flight_requests, max_sockets, conn_count_per_node);
+                pool_thread.capture_replies = capture_replies;
+                pool_thread.work();
+                reply_capture::stop(id);
+            })
+            .to_indy(IndyErrorKind::IOError, "Can't spawn pool worker thread")?;
+        self.worker = Some(worker);
+        Ok(())
     }
 
     pub fn get_name(&self) -> &str {
@@ -527,8 +561,12 @@ impl<S: Networker, R: RequestHandler<S>> Pool<S, R> {
 }
 
 struct PoolThread<S: Networker, R: RequestHandler<S>> {
+    id: PoolHandle,
+    capture_replies: bool,
     pool_sm: Option<PoolSM<S, R>>,
     events: VecDeque<PoolEvent>,
+    deferred_requests: VecDeque<PoolEvent>,
+    max_in_flight_requests: usize,
     commander: Commander,
     networker: Rc<RefCell<S>>,
 }
@@ -538,13 +576,24 @@ impl<S: Networker, R: RequestHandler<S>> PoolThread<S, R> {
                preordered_nodes: Vec<String>, number_read_nodes: u8, socks_proxy: String) -> Self {
         let networker = Rc::new(RefCell::new(S::new(active_timeout, conn_limit, preordered_nodes, socks_proxy)));
         PoolThread {
+            id,
+            capture_replies: false,
             pool_sm: Some(PoolSM::new(networker.clone(), &name, id, timeout, extended_timeout, number_read_nodes)),
             events: VecDeque::new(),
+            deferred_requests: VecDeque::new(),
+            max_in_flight_requests: 0,
             commander: Commander::new(cmd_socket),
             networker,
         }
     }
 
+    /// Limits of 0 mean unbounded.
+    pub fn set_limits(&mut self, max_in_flight_requests: usize, max_sockets: usize, conn_count_per_node: usize) {
+        self.max_in_flight_requests = max_in_flight_requests;
+        self.networker.borrow_mut().set_max_sockets(max_sockets);
+        self.networker.borrow_mut().set_conn_count_per_node(conn_count_per_node);
+    }
+
     pub fn work(&mut self) {
         loop {
             self._poll();
@@ -559,14 +608,72 @@ impl<S: Networker, R: RequestHandler<S>> PoolThread<S, R> {
         while !self.events.is_empty() {
             let pe = self.events.pop_front();
             trace!("received pool event: {:?}", pe);
+            if let (true, Some(pe)) = (self.capture_replies, pe.as_ref()) {
+                self._capture(pe);
+            }
             match pe {
+                // Requests keep their order, so new ones wait behind the deferred ones
+                Some(pe @ PoolEvent::SendRequest(..)) if !self.deferred_requests.is_empty() || self._is_in_flight_limit_reached() => {
+                    trace!("in-flight requests limit reached, deferring request");
+                    self.deferred_requests.push_back(pe);
+                }
                 Some(pe) => {
                     self.pool_sm = self.pool_sm.take().map(|w| w.handle_event(pe));
                 }
                 _ => ()
             }
         }
-        self.pool_sm.as_ref().map(|w| w.is_terminal()).unwrap_or(true)
+
+        self._resume_deferred_requests();
+
+        let terminal = self.pool_sm.as_ref().map(|w| w.is_terminal()).unwrap_or(true);
+
+        if terminal {
+            self._reject_deferred_requests();
+        }
+
+        terminal
+    }
+
+    fn _capture(&self, pe: &PoolEvent) {
+        match pe {
+            PoolEvent::SendRequest(_, request, _, _) => {
+                let re: Option<RequestEvent> = pe.clone().into();
+                if let Some(req_id) = re.map(|re| re.get_req_id()) {
+                    reply_capture::record_request(self.id, &req_id, request);
+                }
+            }
+            PoolEvent::NodeReply(reply, node) => {
+                let re: Option<RequestEvent> = pe.clone().into();
+                if let Some(req_id) = re.map(|re| re.get_req_id()).filter(|req_id| !req_id.is_empty()) {
+                    let op = serde_json::from_str::<serde_json::Value>(reply).ok()
+                        .and_then(|reply| reply["op"].as_str().map(String::from));
+                    reply_capture::record_reply(self.id, &req_id, node, op, reply);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn _resume_deferred_requests(&mut self) {
+        while !self.deferred_requests.is_empty() && !self._is_in_flight_limit_reached() {
+            if let Some(pe) = self.deferred_requests.pop_front() {
+                self.pool_sm = self.pool_sm.take().map(|w| w.handle_event(pe));
+            }
+        }
+    }
+
+    fn _reject_deferred_requests(&mut self) {
+        for pe in self.deferred_requests.drain(..) {
+            if let PoolEvent::SendRequest(cmd_id, ..) = pe {
+                _send_submit_ack(cmd_id, Err(err_msg(IndyErrorKind::PoolTerminated, "Pool is closed before the request was sent")));
+            }
+        }
+    }
+
+    fn _is_in_flight_limit_reached(&self) -> bool {
+        self.max_in_flight_requests != 0 &&
+            self.pool_sm.as_ref().map(|w| w.in_flight_requests()).unwrap_or(0) >= self.max_in_flight_requests
     }
 
     fn _poll(&mut self) {
@@ -593,7 +700,7 @@ impl<S: Networker, R: RequestHandler<S>> PoolThread<S, R> {
             //            trace!("events: {:?}", events);
             if poll_items[poll_items.len() - 1].is_readable() {
                 //TODO move into fetch events?
-                events.extend(self.commander.fetch_events());
+                events.extend(self.commander.fetch_all_events());
             }
 
             events
@@ -603,7 +710,7 @@ impl<S: Networker, R: RequestHandler<S>> PoolThread<S, R> {
     }
 }
 
-fn _get_f(cnt: usize) -> usize {
+pub(super) fn get_f(cnt: usize) -> usize {
     if cnt < 4 {
         return 0;
     }
@@ -632,7 +739,7 @@ fn _get_request_handler_with_ledger_status_sent<T: Networker, R: RequestHandler<
         }
     };
     networker.borrow_mut().process_event(Some(NetworkerEvent::NodesStateUpdated(remotes)));
-    let mut request_handler = R::new(networker.clone(), _get_f(nodes.len()), &[], &nodes, pool_name, timeout, extended_timeout, number_read_nodes);
+    let mut request_handler = R::new(networker.clone(), get_f(nodes.len()), &[], &nodes, pool_name, timeout, extended_timeout, number_read_nodes);
     let ls = _ledger_status(&merkle);
     request_handler.process_event(Some(RequestEvent::LedgerStatus(ls, None, Some(merkle))));
     Ok(request_handler)
@@ -1404,12 +1511,12 @@ mod tests {
         fn get_f_works() {
             test::cleanup_storage("get_f_works");
 
-            assert_eq!(_get_f(0), 0);
-            assert_eq!(_get_f(3), 0);
-            assert_eq!(_get_f(4), 1);
-            assert_eq!(_get_f(5), 1);
-            assert_eq!(_get_f(6), 1);
-            assert_eq!(_get_f(7), 2);
+            assert_eq!(get_f(0), 0);
+            assert_eq!(get_f(3), 0);
+            assert_eq!(get_f(4), 1);
+            assert_eq!(get_f(5), 1);
+            assert_eq!(get_f(6), 1);
+            assert_eq!(get_f(7), 2);
         }
     }
 }
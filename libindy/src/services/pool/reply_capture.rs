@@ -0,0 +1,112 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use indy_api_types::errors::prelude::*;
+use indy_api_types::PoolHandle;
+
+use crate::domain::pool::{CapturedReply, CapturedRequest};
+
+lazy_static! {
+    static ref CAPTURES: Mutex<HashMap<PoolHandle, ReplyCapture>> = Mutex::new(HashMap::new());
+}
+
+/// Ring buffer of the last requests sent by the pool with the raw replies of the nodes.
+struct ReplyCapture {
+    capacity: usize,
+    requests: VecDeque<CapturedRequest>,
+}
+
+impl ReplyCapture {
+    fn new(capacity: usize) -> ReplyCapture {
+        ReplyCapture { capacity, requests: VecDeque::with_capacity(capacity) }
+    }
+
+    fn add_request(&mut self, request: CapturedRequest) {
+        if self.requests.len() == self.capacity {
+            self.requests.pop_front();
+        }
+        self.requests.push_back(request);
+    }
+
+    fn add_reply(&mut self, req_id: &str, reply: CapturedReply) {
+        // Replies to requests already evicted from the buffer are dropped
+        if let Some(request) = self.requests.iter_mut().rev().find(|request| request.req_id == req_id) {
+            request.replies.push(reply);
+        }
+    }
+}
+
+pub fn start(handle: PoolHandle, capacity: usize) {
+    CAPTURES.lock().unwrap().insert(handle, ReplyCapture::new(capacity));
+}
+
+pub fn stop(handle: PoolHandle) {
+    CAPTURES.lock().unwrap().remove(&handle);
+}
+
+pub fn record_request(handle: PoolHandle, req_id: &str, request: &str) {
+    if let Some(capture) = CAPTURES.lock().unwrap().get_mut(&handle) {
+        capture.add_request(CapturedRequest {
+            req_id: req_id.to_string(),
+            request: request.to_string(),
+            size: request.len(),
+            timestamp: _now_ms(),
+            replies: Vec::new(),
+        });
+    }
+}
+
+pub fn record_reply(handle: PoolHandle, req_id: &str, node: &str, op: Option<String>, reply: &str) {
+    if let Some(capture) = CAPTURES.lock().unwrap().get_mut(&handle) {
+        capture.add_reply(req_id, CapturedReply {
+            node: node.to_string(),
+            op,
+            reply: reply.to_string(),
+            size: reply.len(),
+            timestamp: _now_ms(),
+        });
+    }
+}
+
+pub fn get(handle: PoolHandle) -> IndyResult<Vec<CapturedRequest>> {
+    CAPTURES.lock().unwrap().get(&handle)
+        .map(|capture| capture.requests.iter().cloned().collect())
+        .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, format!("Reply capture isn't enabled for pool {}", handle)))
+}
+
+fn _now_ms() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reply_capture_works() {
+        let handle = 1_000_001;
+
+        start(handle, 2);
+
+        record_request(handle, "1", "request_1");
+        record_reply(handle, "1", "Node1", Some("REPLY".to_string()), "reply_1");
+        record_request(handle, "2", "request_2");
+        record_request(handle, "3", "request_3");
+        record_reply(handle, "1", "Node2", Some("REPLY".to_string()), "reply_1");
+        record_reply(handle, "3", "Node1", None, "reply_3");
+
+        let requests = get(handle).unwrap();
+
+        assert_eq!(vec!["2", "3"], requests.iter().map(|request| request.req_id.as_str()).collect::<Vec<&str>>());
+        assert!(requests[0].replies.is_empty());
+        assert_eq!("Node1", requests[1].replies[0].node);
+        assert_eq!(7, requests[1].replies[0].size);
+
+        stop(handle);
+
+        assert!(get(handle).is_err());
+    }
+}
@@ -23,6 +23,7 @@ use crate::services::pool::events::RequestEvent;
 use crate::services::pool::{get_last_signed_time, Nodes};
 use crate::services::pool::merkle_tree_factory;
 use crate::services::pool::networker::Networker;
+use crate::services::pool::metrics;
 use crate::services::pool::state_proof;
 use crate::services::pool::types::CatchupRep;
 use crate::services::pool::types::HashableValue;
@@ -260,6 +261,7 @@ impl<T: Networker> RequestSM<T> {
                         let ne = Some(NetworkerEvent::SendAllRequest(serde_json::to_string(&super::types::Message::LedgerStatus(ls)).expect("FIXME"),
                                                                      req_id, extended_timeout, None));
                         trace!("start catchup, ne: {:?}", ne);
+                        metrics::increment(&pool_name, metrics::CATCHUP_ROUND);
                         state.networker.borrow_mut().process_event(ne);
                         (RequestState::CatchupConsensus((merkle, state).into()), None)
                     }
@@ -333,6 +335,10 @@ impl<T: Networker> RequestSM<T> {
                         if let Ok((_, result_without_proof)) = _get_msg_result_without_state_proof(&raw_msg) {
                             let hashable = HashableValue { inner: result_without_proof };
 
+                            if !state.replies.is_empty() && !state.replies.contains_key(&hashable) {
+                                metrics::increment(&pool_name, metrics::REPLY_MISMATCH);
+                            }
+
                             let cnt = {
                                 let set = state.replies.entry(hashable).or_insert_with(HashSet::new);
                                 set.insert(node_alias.clone());
@@ -395,6 +401,10 @@ impl<T: Networker> RequestSM<T> {
                         if let Ok((result, result_without_proof)) = _get_msg_result_without_state_proof(&raw_msg) {
                             let hashable = HashableValue { inner: result_without_proof };
 
+                            if !state.replies.is_empty() && !state.replies.contains_key(&hashable) {
+                                metrics::increment(&pool_name, metrics::REPLY_MISMATCH);
+                            }
+
                             let last_write_time = get_last_signed_time(&raw_msg).unwrap_or(0);
 
                             let (cnt, soonest) = {
@@ -407,7 +417,7 @@ impl<T: Networker> RequestSM<T> {
                             };
 
                             if cnt > f
-                                || _check_state_proof(&result, f, &generator, &nodes, &raw_msg, state.sp_key.as_ref().map(Vec::as_slice), state.timestamps, last_write_time) {
+                                || _check_state_proof(&result, f, &generator, &nodes, &raw_msg, state.sp_key.as_ref().map(Vec::as_slice), state.timestamps, last_write_time, &pool_name) {
                                 state.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id, None)));
                                 _send_ok_replies(&cmd_ids, if cnt > f { &soonest } else { &raw_msg });
                                 (RequestState::finish(), None)
@@ -471,6 +481,7 @@ impl<T: Networker> RequestSM<T> {
                                 (RequestState::finish(), Some(PoolEvent::Synced(merkle)))
                             }
                             Err(_) => {
+                                metrics::increment(&pool_name, metrics::MERKLE_CONSISTENCY_FAILURE);
                                 state.networker.borrow_mut().process_event(Some(NetworkerEvent::Resend(state.req_id.clone(), timeout)));
                                 state.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(state.req_id.clone(), Some(node_alias))));
                                 (RequestState::CatchupSingle(state), None)
@@ -740,19 +751,25 @@ fn _get_msg_result_without_state_proof(msg: &str) -> IndyResult<(SJsonValue, SJs
     Ok((msg_result, msg_result_without_proof))
 }
 
-fn _check_state_proof(msg_result: &SJsonValue, f: usize, gen: &Generator, bls_keys: &Nodes, raw_msg: &str, sp_key: Option<&[u8]>, requested_timestamps: (Option<u64>, Option<u64>), last_write_time: u64) -> bool {
+fn _check_state_proof(msg_result: &SJsonValue, f: usize, gen: &Generator, bls_keys: &Nodes, raw_msg: &str, sp_key: Option<&[u8]>, requested_timestamps: (Option<u64>, Option<u64>), last_write_time: u64, pool_name: &str) -> bool {
     debug!("TransactionHandler::process_reply: Try to verify proof and signature >>");
 
-    let proof_checking_res = match state_proof::parse_generic_reply_for_proof_checking(&msg_result, raw_msg, sp_key) {
+    let res = match state_proof::parse_generic_reply_for_proof_checking(&msg_result, raw_msg, sp_key) {
         Some(parsed_sps) => {
             debug!("TransactionHandler::process_reply: Proof and signature are present");
-            state_proof::verify_parsed_sp(parsed_sps, bls_keys, f, gen)
+            let res = state_proof::verify_parsed_sp(parsed_sps, bls_keys, f, gen)
+                && _check_freshness(msg_result, requested_timestamps, last_write_time);
+
+            // Replies without a state proof just wait for more replies, only present but invalid or stale proofs are counted
+            if !res {
+                metrics::increment(pool_name, metrics::STATE_PROOF_REJECTION);
+            }
+
+            res
         }
         None => false
     };
 
-    let res = proof_checking_res && _check_freshness(msg_result, requested_timestamps, last_write_time);
-
     debug!("TransactionHandler::process_reply: Try to verify proof and signature << {}", res);
     res
 }
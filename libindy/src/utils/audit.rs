@@ -0,0 +1,107 @@
+//! Audit log of sensitive operations.
+//!
+//! Audit is disabled until application registers a callback. Every event is passed to the
+//! callback as JSON record together with application provided context of the call.
+//! Events aren't stored by libindy, so the callback is the only audit sink.
+
+use std::cell::RefCell;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use libc::{c_char, c_void};
+use serde_json::Value;
+
+use indy_api_types::WalletHandle;
+use indy_utils::ctypes;
+
+pub const KEY_CREATED: &str = "key_created";
+pub const DID_CREATED: &str = "did_created";
+pub const MESSAGE_SIGNED: &str = "message_signed";
+#[cfg(feature = "ledger")]
+pub const REQUEST_SIGNED: &str = "request_signed";
+pub const DID_KEYS_ROTATION_STARTED: &str = "did_keys_rotation_started";
+pub const DID_KEYS_ROTATED: &str = "did_keys_rotated";
+pub const WALLET_EXPORTED: &str = "wallet_exported";
+#[cfg(feature = "anoncreds")]
+pub const CREDENTIAL_ISSUED: &str = "credential_issued";
+
+pub type AuditCB = extern fn(context: *const c_void, event_json: *const c_char);
+
+#[derive(Clone, Copy)]
+struct AuditLog {
+    context: *const c_void,
+    cb: AuditCB,
+}
+
+unsafe impl Send for AuditLog {}
+
+lazy_static! {
+    static ref AUDIT_LOG: Mutex<Option<AuditLog>> = Mutex::new(None);
+}
+
+thread_local! {
+    static CURRENT_CONTEXT: RefCell<Option<Value>> = RefCell::new(None);
+}
+
+/// Sets callback receiving audit events. `None` disables audit.
+pub fn register_audit_callback(context: *const c_void, cb: Option<AuditCB>) {
+    *AUDIT_LOG.lock().unwrap() = cb.map(|cb| AuditLog { context, cb });
+}
+
+/// Sets application context of the current thread. Commands sent from this thread record it in their events.
+pub fn set_current_context(context: Option<Value>) {
+    CURRENT_CONTEXT.with(|current| *current.borrow_mut() = context);
+}
+
+pub fn current_context() -> Option<Value> {
+    CURRENT_CONTEXT.with(|current| current.borrow().clone())
+}
+
+pub fn record(event: &str, wallet_handle: WalletHandle, details: Value) {
+    let audit_log = match *AUDIT_LOG.lock().unwrap() {
+        Some(audit_log) => audit_log,
+        None => return
+    };
+
+    let event_json = ctypes::string_to_cstring(_build_event(event, wallet_handle, details).to_string());
+    (audit_log.cb)(audit_log.context, event_json.as_ptr());
+}
+
+fn _build_event(event: &str, wallet_handle: WalletHandle, details: Value) -> Value {
+    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).expect("Time has gone backwards").as_secs();
+
+    json!({
+        "event": event,
+        "timestamp": timestamp,
+        "wallet_handle": wallet_handle.0,
+        "context": current_context(),
+        "details": details,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_event_works() {
+        set_current_context(Some(json!({"user": "alice"})));
+
+        let event = _build_event(KEY_CREATED, WalletHandle(2), json!({"verkey": "key"}));
+
+        assert_eq!(event["event"], KEY_CREATED);
+        assert_eq!(event["wallet_handle"], 2);
+        assert_eq!(event["context"], json!({"user": "alice"}));
+        assert_eq!(event["details"], json!({"verkey": "key"}));
+        assert!(event["timestamp"].as_u64().unwrap() > 0);
+
+        set_current_context(None);
+    }
+
+    #[test]
+    fn build_event_works_without_context() {
+        let event = _build_event(WALLET_EXPORTED, WalletHandle(2), json!({}));
+
+        assert!(event["context"].is_null());
+    }
+}
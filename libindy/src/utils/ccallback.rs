@@ -1,3 +1,10 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use libc::c_void;
+
+use indy_api_types::{CommandHandle, ErrorCode};
+
 macro_rules! check_useful_c_callback {
     ($x:ident, $e:expr) => {
         let $x = match $x {
@@ -5,4 +12,199 @@ macro_rules! check_useful_c_callback {
             None => return $e
         };
     }
-}
\ No newline at end of file
+}
+// Callbacks of `_ctx` API variants receive application context instead of command handle.
+// Such variants call the regular function with a trampoline callback that maps command handle back to the context.
+
+/// Defines `_ctx` variants of API functions, for example:
+///
+/// ```ignore
+/// context_api! {
+///     indy_close_wallet_ctx => indy_close_wallet(wallet_handle: WalletHandle)
+///         -> cb(), CommonInvalidParam3;
+/// }
+/// ```
+///
+/// Arguments are the ones of the regular function between command handle and callback, `cb` lists callback
+/// arguments after error code and the error code is returned for missing callback.
+macro_rules! context_api {
+    ($($(#[$meta:meta])* $name:ident => $base:ident($($arg:ident: $arg_ty:ty),*) -> cb($($cb_arg:ident: $cb_ty:ty),*), $err:ident;)*) => {
+        $(
+            #[doc = concat!("Variant of `", stringify!($base), "` that passes application `context` to the callback instead of command handle.")]
+            $(#[$meta])*
+            #[no_mangle]
+            pub extern fn $name(context: *const libc::c_void,
+                                $($arg: $arg_ty,)*
+                                cb: Option<extern fn(context: *const libc::c_void,
+                                                     err: indy_api_types::ErrorCode
+                                                     $(, $cb_arg: $cb_ty)*)>) -> indy_api_types::ErrorCode {
+                check_useful_c_callback!(cb, indy_api_types::ErrorCode::$err);
+
+                let command_handle = $crate::utils::ccallback::register_context_cb(context, cb as usize);
+                let res = $base(command_handle, $($arg,)* Some(context_cb!($($cb_arg)*)));
+
+                $crate::utils::ccallback::check_context_cb(command_handle, res)
+            }
+        )*
+    }
+}
+
+/// Defines `_ctx` variants of API functions that also take a per-command callback called before the final one,
+/// for example:
+///
+/// ```ignore
+/// context_data_api! {
+///     indy_stream_txns_ctx => indy_stream_txns(pool_handle: PoolHandle, ...)
+///         -> txns_cb(txns_json: *const c_char), CommonInvalidParam7, cb(), CommonInvalidParam8;
+/// }
+/// ```
+///
+/// Both callbacks receive application context instead of command handle. The data callback may return a value
+/// and its context stays registered until the final callback is called.
+macro_rules! context_data_api {
+    ($($(#[$meta:meta])* $name:ident => $base:ident($($arg:ident: $arg_ty:ty),*)
+        -> $data_cb:ident($($data_arg:ident: $data_ty:ty),*) $(-> $data_ret:ty)?, $data_err:ident,
+           cb($($cb_arg:ident: $cb_ty:ty),*), $err:ident;)*) => {
+        $(
+            #[doc = concat!("Variant of `", stringify!($base), "` that passes application `context` to the callbacks instead of command handle.")]
+            $(#[$meta])*
+            #[no_mangle]
+            pub extern fn $name(context: *const libc::c_void,
+                                $($arg: $arg_ty,)*
+                                $data_cb: Option<extern fn(context: *const libc::c_void
+                                                           $(, $data_arg: $data_ty)*) $(-> $data_ret)?>,
+                                cb: Option<extern fn(context: *const libc::c_void,
+                                                     err: indy_api_types::ErrorCode
+                                                     $(, $cb_arg: $cb_ty)*)>) -> indy_api_types::ErrorCode {
+                check_useful_c_callback!($data_cb, indy_api_types::ErrorCode::$data_err);
+                check_useful_c_callback!(cb, indy_api_types::ErrorCode::$err);
+
+                let command_handle = $crate::utils::ccallback::register_context_cbs(context, cb as usize, $data_cb as usize);
+                let res = $base(command_handle, $($arg,)* Some(context_data_cb!($($data_arg)*)), Some(context_cb!($($cb_arg)*)));
+
+                $crate::utils::ccallback::check_context_cb(command_handle, res)
+            }
+        )*
+    }
+}
+
+/// Trampoline callback for the given data callback arguments.
+macro_rules! context_data_cb {
+    ($a:ident) => { $crate::utils::ccallback::context_data_cb_1 };
+    ($a:ident $b:ident) => { $crate::utils::ccallback::context_data_cb_2 };
+}
+
+/// Trampoline callback for the given callback arguments.
+macro_rules! context_cb {
+    () => { $crate::utils::ccallback::context_cb_0 };
+    ($a:ident) => { $crate::utils::ccallback::context_cb_1 };
+    ($a:ident $b:ident) => { $crate::utils::ccallback::context_cb_2 };
+    ($a:ident $b:ident $c:ident) => { $crate::utils::ccallback::context_cb_3 };
+}
+
+lazy_static! {
+    static ref CONTEXT_CALLBACKS: Mutex<HashMap<CommandHandle, (usize /* context */, usize /* callback */, usize /* data callback */)>> = Mutex::new(HashMap::new());
+}
+
+/// Registers context callback and returns command handle to pass into the regular API function.
+pub fn register_context_cb(context: *const c_void, cb: usize) -> CommandHandle {
+    register_context_cbs(context, cb, 0)
+}
+
+/// Registers context callback together with data callback called before it.
+pub fn register_context_cbs(context: *const c_void, cb: usize, data_cb: usize) -> CommandHandle {
+    let command_handle = indy_utils::next_command_handle();
+    CONTEXT_CALLBACKS.lock().unwrap().insert(command_handle, (context as usize, cb, data_cb));
+    command_handle
+}
+
+/// Drops context callback if the regular API function failed synchronously and will never call it.
+pub fn check_context_cb(command_handle: CommandHandle, res: ErrorCode) -> ErrorCode {
+    if res != ErrorCode::Success {
+        CONTEXT_CALLBACKS.lock().unwrap().remove(&command_handle);
+    }
+    res
+}
+
+fn _take_context_cb(command_handle: CommandHandle) -> Option<(*const c_void, usize)> {
+    let res = CONTEXT_CALLBACKS.lock().unwrap().remove(&command_handle);
+
+    if res.is_none() {
+        error!("Context callback for command handle {:?} not found", command_handle);
+    }
+
+    res.map(|(context, cb, _)| (context as *const c_void, cb))
+}
+
+/// Data callback stays registered as it may be called many times before the final callback.
+fn _get_context_data_cb(command_handle: CommandHandle) -> Option<(*const c_void, usize)> {
+    let res = CONTEXT_CALLBACKS.lock().unwrap().get(&command_handle).cloned();
+
+    if res.is_none() {
+        // The final callback was already called, for example the command timed out
+        warn!("Context data callback for command handle {:?} not found", command_handle);
+    }
+
+    res.map(|(context, _, data_cb)| (context as *const c_void, data_cb))
+}
+
+/// Result returned by data callback trampoline when context is already gone.
+pub trait ContextDataResult {
+    fn missing() -> Self;
+}
+
+impl ContextDataResult for () {
+    fn missing() -> Self {}
+}
+
+impl ContextDataResult for ErrorCode {
+    fn missing() -> Self { ErrorCode::CommonInvalidState }
+}
+
+pub extern fn context_data_cb_1<A, R: ContextDataResult>(command_handle: CommandHandle, a: A) -> R {
+    match _get_context_data_cb(command_handle) {
+        Some((context, data_cb)) => {
+            let data_cb: extern fn(*const c_void, A) -> R = unsafe { ::std::mem::transmute(data_cb) };
+            data_cb(context, a)
+        }
+        None => R::missing()
+    }
+}
+
+pub extern fn context_data_cb_2<A, B, R: ContextDataResult>(command_handle: CommandHandle, a: A, b: B) -> R {
+    match _get_context_data_cb(command_handle) {
+        Some((context, data_cb)) => {
+            let data_cb: extern fn(*const c_void, A, B) -> R = unsafe { ::std::mem::transmute(data_cb) };
+            data_cb(context, a, b)
+        }
+        None => R::missing()
+    }
+}
+
+pub extern fn context_cb_0(command_handle: CommandHandle, err: ErrorCode) {
+    if let Some((context, cb)) = _take_context_cb(command_handle) {
+        let cb: extern fn(*const c_void, ErrorCode) = unsafe { ::std::mem::transmute(cb) };
+        cb(context, err)
+    }
+}
+
+pub extern fn context_cb_1<A>(command_handle: CommandHandle, err: ErrorCode, a: A) {
+    if let Some((context, cb)) = _take_context_cb(command_handle) {
+        let cb: extern fn(*const c_void, ErrorCode, A) = unsafe { ::std::mem::transmute(cb) };
+        cb(context, err, a)
+    }
+}
+
+pub extern fn context_cb_2<A, B>(command_handle: CommandHandle, err: ErrorCode, a: A, b: B) {
+    if let Some((context, cb)) = _take_context_cb(command_handle) {
+        let cb: extern fn(*const c_void, ErrorCode, A, B) = unsafe { ::std::mem::transmute(cb) };
+        cb(context, err, a, b)
+    }
+}
+
+pub extern fn context_cb_3<A, B, C>(command_handle: CommandHandle, err: ErrorCode, a: A, b: B, c: C) {
+    if let Some((context, cb)) = _take_context_cb(command_handle) {
+        let cb: extern fn(*const c_void, ErrorCode, A, B, C) = unsafe { ::std::mem::transmute(cb) };
+        cb(context, err, a, b, c)
+    }
+}
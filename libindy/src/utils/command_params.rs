@@ -0,0 +1,128 @@
+//! Digest of parameters of application calls reported to command hooks.
+//!
+//! API functions record their parameters as passed by application before they are parsed,
+//! so calls with the same parameters can be correlated by hooks without exposing them.
+
+use std::cell::RefCell;
+use std::ffi::CStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use libc::c_char;
+
+use indy_api_types::WalletHandle;
+use indy_utils::slow_operations;
+
+/// Records digest of parameters of API function that sends command, for example:
+///
+/// ```ignore
+/// command_params!(wallet_handle, did, (message_raw, message_len));
+/// ```
+///
+/// Pair of data pointer and length is recorded as byte array.
+macro_rules! command_params {
+    (@param ($data:ident, $len:ident)) => {
+        $crate::utils::command_params::bytes_param($data, $len as usize)
+    };
+    (@param $param:ident) => {
+        $crate::utils::command_params::CommandParam::to_param(&$param)
+    };
+    ($($param:tt),*) => {
+        if $crate::utils::command_params::is_enabled() {
+            $crate::utils::command_params::set_current(&[$(command_params!(@param $param)),*]);
+        }
+    };
+}
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static CURRENT_DIGEST: RefCell<Option<String>> = RefCell::new(None);
+}
+
+/// Digest is calculated only while command hooks are registered.
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+pub fn set_current(params: &[String]) {
+    let params: Vec<&str> = params.iter().map(String::as_str).collect();
+    let digest = slow_operations::params_digest(&params);
+
+    CURRENT_DIGEST.with(|current| *current.borrow_mut() = Some(digest));
+}
+
+/// Takes digest of the call that sends command from the current thread.
+pub fn take_current() -> Option<String> {
+    CURRENT_DIGEST.with(|current| current.borrow_mut().take())
+}
+
+pub trait CommandParam {
+    fn to_param(&self) -> String;
+}
+
+impl CommandParam for *const c_char {
+    fn to_param(&self) -> String {
+        if self.is_null() {
+            return String::new();
+        }
+
+        unsafe { CStr::from_ptr(*self) }.to_string_lossy().into_owned()
+    }
+}
+
+macro_rules! debug_command_param {
+    ($($type_:ty),*) => {
+        $(impl CommandParam for $type_ {
+            fn to_param(&self) -> String {
+                format!("{:?}", self)
+            }
+        })*
+    }
+}
+
+debug_command_param!(i32, u32, i64, u64, usize, bool, WalletHandle);
+
+pub fn bytes_param(data: *const u8, len: usize) -> String {
+    if data.is_null() {
+        return String::new();
+    }
+
+    hex::encode(unsafe { std::slice::from_raw_parts(data, len) })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::ffi::CString;
+
+    #[test]
+    fn command_params_works() {
+        set_enabled(true);
+
+        let did = CString::new("VsKV7grR1BUE29mG2Fm2kX").unwrap();
+        let (did, wallet_handle) = (did.as_ptr(), WalletHandle(1));
+        let message = b"message";
+        let (message_raw, message_len) = (message.as_ptr(), message.len() as u32);
+
+        command_params!(wallet_handle, did, (message_raw, message_len));
+        let digest = take_current().unwrap();
+        assert_eq!(None, take_current());
+
+        command_params!(wallet_handle, did, (message_raw, message_len));
+        assert_eq!(Some(digest.clone()), take_current());
+
+        let wallet_handle = WalletHandle(2);
+        command_params!(wallet_handle, did, (message_raw, message_len));
+        assert_ne!(Some(digest), take_current());
+
+        set_enabled(false);
+
+        command_params!(wallet_handle);
+        assert_eq!(None, take_current());
+    }
+}
@@ -1,2 +1,3 @@
 pub mod verkey_builder;
+#[cfg(feature = "ledger")]
 pub mod signature_serializer;
@@ -0,0 +1,100 @@
+//! Registry of open handles.
+//!
+//! Command executors record handles they open and close, so application can find the ones
+//! that are never closed. Handles are opened and closed on the command thread only.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::time::Instant;
+
+pub const WALLET: &str = "wallet";
+#[cfg(feature = "ledger")]
+pub const POOL: &str = "pool";
+pub const WALLET_SEARCH: &str = "wallet_search";
+pub const DID_SEARCH: &str = "did_search";
+pub const PAIRWISE_SEARCH: &str = "pairwise_search";
+#[cfg(feature = "anoncreds")]
+pub const CREDENTIALS_SEARCH: &str = "credentials_search";
+#[cfg(feature = "anoncreds")]
+pub const CREDENTIALS_FOR_PROOF_REQUEST_SEARCH: &str = "credentials_for_proof_request_search";
+#[cfg(feature = "anoncreds")]
+pub const BLOB_STORAGE_READER: &str = "blob_storage_reader";
+#[cfg(feature = "anoncreds")]
+pub const BLOB_STORAGE_WRITER: &str = "blob_storage_writer";
+
+struct OpenHandle {
+    name: Option<String>,
+    opened: Instant,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+pub struct HandleInfo {
+    #[serde(rename = "type")]
+    pub type_: &'static str,
+    pub handle: i32,
+    pub name: Option<String>,
+    pub age_ms: u128,
+}
+
+thread_local! {
+    static OPEN_HANDLES: RefCell<HashMap<(&'static str, i32), OpenHandle>> = RefCell::new(HashMap::new());
+}
+
+pub fn opened(type_: &'static str, handle: i32, name: Option<String>) {
+    OPEN_HANDLES.with(|handles| handles.borrow_mut().insert((type_, handle), OpenHandle { name, opened: Instant::now() }));
+}
+
+pub fn closed(type_: &'static str, handle: i32) {
+    OPEN_HANDLES.with(|handles| handles.borrow_mut().remove(&(type_, handle)));
+}
+
+pub fn closed_all(type_: &'static str) {
+    OPEN_HANDLES.with(|handles| handles.borrow_mut().retain(|(open_type, _), _| *open_type != type_));
+}
+
+/// Returns open handles ordered from the oldest one.
+pub fn list() -> Vec<HandleInfo> {
+    let mut handles: Vec<(Instant, HandleInfo)> = OPEN_HANDLES.with(|handles| {
+        handles.borrow().iter()
+            .map(|(&(type_, handle), open)| {
+                (open.opened, HandleInfo { type_, handle, name: open.name.clone(), age_ms: open.opened.elapsed().as_millis() })
+            })
+            .collect()
+    });
+
+    handles.sort_by_key(|(opened, info)| (*opened, info.handle));
+    handles.into_iter().map(|(_, info)| info).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "ledger")]
+    fn list_works() {
+        opened(WALLET, 1, Some("wallet_1".to_string()));
+        opened(WALLET_SEARCH, 2, None);
+        opened(POOL, 3, Some("pool_1".to_string()));
+        closed(WALLET_SEARCH, 2);
+
+        let handles = list();
+
+        assert_eq!(2, handles.len());
+        assert_eq!((WALLET, 1, Some("wallet_1".to_string())), (handles[0].type_, handles[0].handle, handles[0].name.clone()));
+        assert_eq!((POOL, 3, Some("pool_1".to_string())), (handles[1].type_, handles[1].handle, handles[1].name.clone()));
+    }
+
+    #[test]
+    fn closed_all_works() {
+        opened(DID_SEARCH, 1, None);
+        opened(DID_SEARCH, 2, None);
+        opened(WALLET, 3, None);
+
+        closed_all(DID_SEARCH);
+
+        let handles = list();
+        assert_eq!(1, handles.len());
+        assert_eq!(WALLET, handles[0].type_);
+    }
+}
@@ -5,6 +5,7 @@ extern crate log;
 extern crate android_logger;
 
 use self::env_logger::Builder as EnvLoggerBuilder;
+use self::env_logger::filter::{Builder as FilterBuilder, Filter};
 use self::log::{LevelFilter, Level};
 use std::env;
 use std::io::Write;
@@ -15,10 +16,16 @@ use log::{Record, Metadata};
 use libc::{c_void, c_char};
 use std::ffi::CString;
 use std::ptr;
+use std::sync::RwLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use regex::Regex;
+use sha2::{Digest, Sha256};
 
 use indy_api_types::errors::prelude::*;
 use indy_utils::ctypes;
 use indy_api_types::errors::IndyErrorKind::InvalidStructure;
+use crate::domain::LogFormat;
 
 pub static mut LOGGER_STATE: LoggerState = LoggerState::Default;
 
@@ -56,6 +63,76 @@ static mut ENABLED_CB: Option<EnabledCB> = None;
 static mut LOG_CB: Option<LogCB> = None;
 static mut FLUSH_CB: Option<FlushCB> = None;
 
+static JSON_FORMAT: AtomicBool = AtomicBool::new(false);
+
+lazy_static! {
+    static ref LOG_FILTER: RwLock<Option<Filter>> = RwLock::new(None);
+    static ref COMMAND_HANDLE_MATCHER: Regex = Regex::new(r"command_handle: (\d+)").unwrap();
+    static ref WALLET_HANDLE_MATCHER: Regex = Regex::new(r"wallet_handle: (?:WalletHandle\()?(\d+)").unwrap();
+}
+
+pub fn set_log_format(format: LogFormat) {
+    JSON_FORMAT.store(format == LogFormat::Json, Ordering::Relaxed);
+}
+
+fn is_json_format() -> bool {
+    JSON_FORMAT.load(Ordering::Relaxed)
+}
+
+/// Replaces target filter of the installed logger. Filter uses `env_logger` syntax:
+/// comma separated list of `target=level` or `level` directives.
+pub fn set_log_filter(filter: &str) -> IndyResult<()> {
+    _validate_filter(filter)?;
+
+    let filter = FilterBuilder::new().parse(filter).build();
+    log::set_max_level(filter.filter());
+    *LOG_FILTER.write().unwrap() = Some(filter);
+
+    Ok(())
+}
+
+fn _validate_filter(filter: &str) -> IndyResult<()> {
+    let directives = filter.split('/').next().unwrap_or("");
+
+    for directive in directives.split(',').map(str::trim).filter(|directive| !directive.is_empty()) {
+        let parts: Vec<&str> = directive.split('=').collect();
+
+        match parts.as_slice() {
+            [_] => {}
+            [target, level] if !target.is_empty() && level.parse::<LevelFilter>().is_ok() => {}
+            _ => return Err(err_msg(InvalidStructure, format!("Invalid log filter directive: {}", directive)))
+        }
+    }
+
+    Ok(())
+}
+
+fn _filter_enabled(metadata: &Metadata) -> bool {
+    LOG_FILTER.read().unwrap().as_ref().map(|filter| filter.enabled(metadata)).unwrap_or(true)
+}
+
+/// Builds structured record. Command and wallet handles are taken from the message text,
+/// wallet handle is hashed so records of one wallet can be joined without exposing the handle.
+fn format_json_record(record: &Record) -> String {
+    let message = record.args().to_string();
+
+    let command_handle = COMMAND_HANDLE_MATCHER.captures(&message)
+        .and_then(|caps| caps[1].parse::<i64>().ok());
+    let wallet_handle_hash = WALLET_HANDLE_MATCHER.captures(&message)
+        .map(|caps| hex::encode(&Sha256::digest(caps[1].as_bytes())[..8]));
+
+    json!({
+        "target": record.target(),
+        "level": record.level().to_string(),
+        "message": message,
+        "module_path": record.module_path(),
+        "file": record.file(),
+        "line": record.line(),
+        "command_handle": command_handle,
+        "wallet_handle_hash": wallet_handle_hash,
+    }).to_string()
+}
+
 #[cfg(debug_assertions)]
 const DEFAULT_MAX_LEVEL: LevelFilter = LevelFilter::Trace;
 #[cfg(not(debug_assertions))]
@@ -76,6 +153,10 @@ impl LibindyLogger {
 
 impl log::Log for LibindyLogger {
     fn enabled(&self, metadata: &Metadata) -> bool {
+        if !_filter_enabled(metadata) {
+            return false;
+        }
+
         if let Some(enabled_cb) = self.enabled {
             let level = metadata.level() as u32;
             let target = CString::new(metadata.target()).unwrap();
@@ -88,11 +169,16 @@ impl log::Log for LibindyLogger {
     }
 
     fn log(&self, record: &Record) {
+        if !_filter_enabled(record.metadata()) {
+            return;
+        }
+
         let log_cb = self.log;
 
         let level = record.level() as u32;
         let target = CString::new(record.target()).unwrap();
-        let message = CString::new(record.args().to_string()).unwrap();
+        let message = if is_json_format() { format_json_record(record) } else { record.args().to_string() };
+        let message = CString::new(message).unwrap();
 
         let module_path = record.module_path().map(|a| CString::new(a).unwrap());
         let file = record.file().map(|a| CString::new(a).unwrap());
@@ -163,6 +249,27 @@ impl LibindyLogger {
     }
 }
 
+/// `env_logger` logger whose filter can be replaced at runtime by `set_log_filter`.
+struct DefaultLogger {
+    inner: env_logger::Logger,
+}
+
+impl log::Log for DefaultLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        _filter_enabled(metadata)
+    }
+
+    fn log(&self, record: &Record) {
+        if _filter_enabled(record.metadata()) {
+            self.inner.log(record)
+        }
+    }
+
+    fn flush(&self) {
+        self.inner.flush()
+    }
+}
+
 pub struct LibindyDefaultLogger;
 
 impl LibindyDefaultLogger {
@@ -190,11 +297,25 @@ impl LibindyDefaultLogger {
             android_logger::init_once(log_filter);
             info!("Logging for Android");
         } else {
-            EnvLoggerBuilder::new()
-                .format(|buf, record| writeln!(buf, "{:>5}|{:<30}|{:>35}:{:<4}| {}", record.level(), record.target(), record.file().get_or_insert(""), record.line().get_or_insert(0), record.args()))
+            let logger = EnvLoggerBuilder::new()
+                .format(|buf, record| {
+                    if is_json_format() {
+                        writeln!(buf, "{}", format_json_record(record))
+                    } else {
+                        writeln!(buf, "{:>5}|{:<30}|{:>35}:{:<4}| {}", record.level(), record.target(), record.file().get_or_insert(""), record.line().get_or_insert(0), record.args())
+                    }
+                })
+                .filter(None, LevelFilter::Trace)
+                .build();
+
+            let filter = FilterBuilder::new()
                 .filter(None, LevelFilter::Off)
-                .parse_filters(pattern.as_ref().map(String::as_str).unwrap_or(""))
-                .try_init()?;
+                .parse(pattern.as_ref().map(String::as_str).unwrap_or(""))
+                .build();
+
+            log::set_boxed_logger(Box::new(DefaultLogger { inner: logger }))?;
+            log::set_max_level(filter.filter());
+            *LOG_FILTER.write().unwrap() = Some(filter);
         }
         unsafe { LOGGER_STATE = LoggerState::Default };
         Ok(())
@@ -310,4 +431,56 @@ macro_rules! secret {
 #[macro_export]
 macro_rules! secret {
     ($val:expr) => {{ "_" }};
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn _record(message: &str) -> serde_json::Value {
+        let record = format_json_record(&Record::builder()
+            .args(format_args!("{}", message))
+            .level(Level::Debug)
+            .target("indy::api::wallet")
+            .module_path(Some("indy::api::wallet"))
+            .file(Some("src/api/wallet.rs"))
+            .line(Some(10))
+            .build());
+        serde_json::from_str(&record).unwrap()
+    }
+
+    #[test]
+    fn format_json_record_works() {
+        let record = _record("indy_close_wallet: >>> command_handle: 3, wallet_handle: WalletHandle(7)");
+
+        assert_eq!(record["target"], "indy::api::wallet");
+        assert_eq!(record["level"], "DEBUG");
+        assert_eq!(record["file"], "src/api/wallet.rs");
+        assert_eq!(record["line"], 10);
+        assert_eq!(record["command_handle"], 3);
+        assert_eq!(record["wallet_handle_hash"].as_str().unwrap().len(), 16);
+    }
+
+    #[test]
+    fn validate_filter_works() {
+        _validate_filter("indy::services::pool=trace,indy::services::wallet=warn").unwrap();
+        _validate_filter("info,indy::commands").unwrap();
+        _validate_filter("").unwrap();
+    }
+
+    #[test]
+    fn validate_filter_works_for_invalid_level() {
+        _validate_filter("indy::services::pool=verbose").unwrap_err();
+        _validate_filter("=trace").unwrap_err();
+        _validate_filter("indy=trace=debug").unwrap_err();
+    }
+
+    #[test]
+    fn format_json_record_works_for_message_without_handles() {
+        let record = _record("Pool opened");
+
+        assert_eq!(record["message"], "Pool opened");
+        assert!(record["command_handle"].is_null());
+        assert!(record["wallet_handle_hash"].is_null());
+    }
+}
@@ -3,6 +3,9 @@ pub use indy_utils::environment;
 #[macro_use]
 pub mod ccallback;
 
+#[macro_use]
+pub mod command_params;
+
 pub mod crypto;
 #[macro_use]
 pub mod logger;
@@ -23,3 +26,10 @@ pub use indy_utils::wql;
 
 #[macro_use]
 pub mod qualifier;
+
+pub mod audit;
+
+pub mod handles;
+
+#[cfg(feature = "tracing_spans")]
+pub mod tracing;
@@ -2,6 +2,8 @@ use regex::Regex;
 
 lazy_static! {
     pub static ref REGEX: Regex = Regex::new("^[a-z0-9]+:([a-z0-9]+):(.*)$").unwrap();
+    // did:indy:<namespace>[:<sub-namespace>]:<id> - namespace is considered as a part of the method
+    pub static ref INDY_DID_REGEX: Regex = Regex::new("^did:(indy(?::[a-z0-9_-]+)+):([^:]+)$").unwrap();
 }
 
 pub fn qualify(entity: &str, prefix: &str, method: &str) -> String {
@@ -9,6 +11,10 @@ pub fn qualify(entity: &str, prefix: &str, method: &str) -> String {
 }
 
 pub fn to_unqualified(entity: &str) -> String {
+    if let Some(caps) = INDY_DID_REGEX.captures(entity) {
+        return caps.get(2).map(|m| m.as_str().to_string()).unwrap_or(entity.to_string());
+    }
+
     match REGEX.captures(entity) {
         None => entity.to_string(),
         Some(caps) => {
@@ -18,6 +24,10 @@ pub fn to_unqualified(entity: &str) -> String {
 }
 
 pub fn method(entity: &str) -> Option<String> {
+    if let Some(caps) = INDY_DID_REGEX.captures(entity) {
+        return caps.get(1).map(|m| m.as_str().to_string());
+    }
+
     match REGEX.captures(entity) {
         None => None,
         Some(caps) => {
@@ -0,0 +1,237 @@
+//! Spans for the command pipeline.
+//!
+//! Commands are traced with `tracing` spans bridged to OpenTelemetry by `tracing-opentelemetry`.
+//! Span of a command is started by FFI call that submits it and is entered while the command is executed,
+//! so spans of continuation commands, pool round trips and `indy-wallet` storage operations become its children.
+//! Rust applications export spans with their own `tracing` subscriber. For other applications
+//! `init_log_export` installs OpenTelemetry pipeline that emits finished spans as JSON records
+//! through the `indy_tracing` log target.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Mutex, Once};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use ::tracing::{field, info_span};
+use opentelemetry::propagation::TextMapPropagator;
+use opentelemetry::sdk::export::trace::{ExportResult, SpanData, SpanExporter};
+use opentelemetry::sdk::propagation::TraceContextPropagator;
+use opentelemetry::sdk::trace::TracerProvider;
+use opentelemetry::trace::{SpanId, TracerProvider as _};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+use tracing_subscriber::layer::SubscriberExt;
+
+use indy_api_types::CommandHandle;
+use indy_api_types::errors::prelude::*;
+
+pub const TRACING_TARGET: &str = "indy_tracing";
+
+const TRACEPARENT_VERSION: &str = "00";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceContext {
+    pub trace_id: String,
+    pub span_id: String,
+}
+
+impl TraceContext {
+    /// Parses `traceparent` header value: `00-<32 hex trace id>-<16 hex parent span id>-<2 hex flags>`.
+    pub fn from_traceparent(traceparent: &str) -> IndyResult<TraceContext> {
+        let parts: Vec<&str> = traceparent.trim().split('-').collect();
+
+        match parts.as_slice() {
+            [version, trace_id, span_id, flags]
+            if *version == TRACEPARENT_VERSION
+                && TraceContext::_is_id(trace_id, 32)
+                && TraceContext::_is_id(span_id, 16)
+                && flags.len() == 2 && hex::decode(flags).is_ok() =>
+                Ok(TraceContext { trace_id: trace_id.to_lowercase(), span_id: span_id.to_lowercase() }),
+            _ => Err(err_msg(IndyErrorKind::InvalidStructure, format!("Invalid traceparent: {}", traceparent)))
+        }
+    }
+
+    /// OpenTelemetry context with this remote span as the parent.
+    pub fn to_otel_context(&self) -> opentelemetry::Context {
+        let mut carrier = HashMap::new();
+        carrier.insert("traceparent".to_string(), format!("{}-{}-{}-01", TRACEPARENT_VERSION, self.trace_id, self.span_id));
+        TraceContextPropagator::new().extract(&carrier)
+    }
+
+    fn _is_id(id: &str, len: usize) -> bool {
+        id.len() == len && hex::decode(id).is_ok() && id.chars().any(|c| c != '0')
+    }
+}
+
+thread_local! {
+    static CURRENT_CONTEXT: RefCell<Option<TraceContext>> = RefCell::new(None);
+    #[cfg(feature = "ledger")]
+    static PENDING_SPANS: RefCell<HashMap<CommandHandle, ::tracing::Span>> = RefCell::new(HashMap::new());
+}
+
+lazy_static! {
+    // tracers hold weak references to their provider, so it is kept for the process lifetime
+    static ref LOG_EXPORT_PROVIDER: Mutex<Option<TracerProvider>> = Mutex::new(None);
+}
+
+/// Sets trace context of the current thread. Commands sent from this thread become its children.
+pub fn set_current_context(context: Option<TraceContext>) {
+    CURRENT_CONTEXT.with(|current| *current.borrow_mut() = context);
+}
+
+pub fn current_context() -> Option<TraceContext> {
+    CURRENT_CONTEXT.with(|current| current.borrow().clone())
+}
+
+/// Starts span of the command sent from the current thread.
+///
+/// The span is a child of the trace context of the thread if it is set, otherwise of the current `tracing` span.
+/// So commands sent by application become children of its spans and commands sent while another command
+/// is executed become children of that command.
+pub fn command_span(cmd_name: &str) -> ::tracing::Span {
+    let span = info_span!(target: TRACING_TARGET, "indy.command",
+                          otel.name = %format!("indy.{}", cmd_name),
+                          indy.queue_ms = field::Empty);
+
+    if let Some(context) = current_context() {
+        span.set_parent(context.to_otel_context());
+    }
+
+    span
+}
+
+/// Starts span that is finished by `end_pending_span` with the same handle, e.g. on pool reply.
+#[cfg(feature = "ledger")]
+pub fn start_pending_span(handle: CommandHandle, name: &str) {
+    let span = info_span!(target: TRACING_TARGET, "indy.pending", otel.name = %name);
+    PENDING_SPANS.with(|spans| spans.borrow_mut().insert(handle, span));
+}
+
+#[cfg(feature = "ledger")]
+pub fn end_pending_span(handle: CommandHandle) {
+    PENDING_SPANS.with(|spans| spans.borrow_mut().remove(&handle));
+}
+
+/// Installs OpenTelemetry pipeline that emits finished spans through `indy_tracing` log target,
+/// unless the application has already set its own global `tracing` subscriber.
+pub fn init_log_export() {
+    static INIT: Once = Once::new();
+
+    INIT.call_once(|| {
+        if ::tracing::dispatcher::has_been_set() {
+            return;
+        }
+
+        let provider = TracerProvider::builder()
+            .with_simple_exporter(LogSpanExporter)
+            .build();
+
+        let subscriber = tracing_subscriber::registry()
+            .with(tracing_opentelemetry::layer().with_tracer(provider.tracer("libindy")));
+
+        if ::tracing::subscriber::set_global_default(subscriber).is_ok() {
+            *LOG_EXPORT_PROVIDER.lock().unwrap() = Some(provider);
+        }
+    });
+}
+
+#[derive(Debug)]
+struct LogSpanExporter;
+
+impl SpanExporter for LogSpanExporter {
+    fn export(&mut self, batch: Vec<SpanData>) -> Pin<Box<dyn Future<Output=ExportResult> + Send + 'static>> {
+        for span in batch {
+            info!(target: TRACING_TARGET, "{}", span_record(&span));
+        }
+
+        Box::pin(std::future::ready(Ok(())))
+    }
+}
+
+fn span_record(span: &SpanData) -> serde_json::Value {
+    let attributes: HashMap<String, String> = span.attributes.iter()
+        .map(|(key, value)| (key.as_str().to_string(), value.to_string()))
+        .collect();
+
+    json!({
+        "name": span.name,
+        "traceId": format!("{:032x}", span.span_context.trace_id()),
+        "spanId": format!("{:016x}", span.span_context.span_id()),
+        "parentSpanId": if span.parent_span_id == SpanId::INVALID { None } else { Some(format!("{:016x}", span.parent_span_id)) },
+        "startTimeUnixNano": _unix_ns(span.start_time).to_string(),
+        "endTimeUnixNano": _unix_ns(span.end_time).to_string(),
+        "attributes": attributes,
+    })
+}
+
+fn _unix_ns(time: SystemTime) -> u128 {
+    time.duration_since(UNIX_EPOCH).map(|duration| duration.as_nanos()).unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use opentelemetry::trace::TraceContextExt;
+
+    use super::*;
+
+    const TRACEPARENT: &str = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+
+    #[test]
+    fn trace_context_from_traceparent_works() {
+        let context = TraceContext::from_traceparent(TRACEPARENT).unwrap();
+
+        assert_eq!(context.trace_id, "4bf92f3577b34da6a3ce929d0e0e4736");
+        assert_eq!(context.span_id, "00f067aa0ba902b7");
+    }
+
+    #[test]
+    fn trace_context_from_traceparent_works_for_invalid_value() {
+        assert!(TraceContext::from_traceparent("").is_err());
+        assert!(TraceContext::from_traceparent("01-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01").is_err());
+        assert!(TraceContext::from_traceparent("00-00000000000000000000000000000000-00f067aa0ba902b7-01").is_err());
+        assert!(TraceContext::from_traceparent("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba9-01").is_err());
+    }
+
+    #[test]
+    fn trace_context_to_otel_context_works() {
+        let context = TraceContext::from_traceparent(TRACEPARENT).unwrap();
+
+        let otel_context = context.to_otel_context();
+        let span_context = otel_context.span().span_context().clone();
+
+        assert!(span_context.is_remote());
+        assert_eq!(format!("{:032x}", span_context.trace_id()), context.trace_id);
+        assert_eq!(format!("{:016x}", span_context.span_id()), context.span_id);
+    }
+
+    #[test]
+    fn command_span_works_for_current_context() {
+        let provider = TracerProvider::builder().build();
+        let subscriber = tracing_subscriber::registry()
+            .with(tracing_opentelemetry::layer().with_tracer(provider.tracer("test")));
+
+        let parent = TraceContext::from_traceparent(TRACEPARENT).unwrap();
+
+        ::tracing::subscriber::with_default(subscriber, || {
+            set_current_context(Some(parent.clone()));
+            let span = command_span("wallet_command_open");
+            set_current_context(None);
+
+            let span_context = span.context().span().span_context().clone();
+            assert_eq!(format!("{:032x}", span_context.trace_id()), parent.trace_id);
+            assert_ne!(format!("{:016x}", span_context.span_id()), parent.span_id);
+        });
+    }
+
+    #[test]
+    fn current_context_works() {
+        let parent = TraceContext::from_traceparent(TRACEPARENT).unwrap();
+
+        set_current_context(Some(parent.clone()));
+        assert_eq!(current_context(), Some(parent));
+
+        set_current_context(None);
+        assert_eq!(current_context(), None);
+    }
+}
@@ -19,7 +19,7 @@ use crate::utils::domain::anoncreds::credential_for_proof_request::{CredentialsF
 use crate::utils::domain::anoncreds::proof::Proof;
 use crate::utils::domain::crypto::did::DidValue;
 
-use indy::INVALID_WALLET_HANDLE;
+use indy::{INVALID_WALLET_HANDLE, INVALID_POOL_HANDLE};
 
 mod high_cases {
     use super::*;
@@ -83,6 +83,27 @@ mod high_cases {
         }
     }
 
+    mod prover_parse_credential_offer {
+        use super::*;
+
+        #[test]
+        fn prover_parse_credential_offer_works() {
+            let (credential_def, credential_offer, _, _) = anoncreds::init_common_wallet();
+
+            let parsed_offer = anoncreds::prover_parse_credential_offer(credential_offer, credential_def).unwrap();
+            let parsed_offer: serde_json::Value = serde_json::from_str(&parsed_offer).unwrap();
+
+            assert_eq!(json!(anoncreds::issuer_1_gvt_cred_def_id()), parsed_offer["cred_def_id"]);
+            assert_eq!(json!(anoncreds::gvt_schema_id()), parsed_offer["schema_id"]);
+            assert_eq!(json!(ISSUER_DID), parsed_offer["issuer_did"]);
+            assert_eq!(json!(GVT_SCHEMA_NAME), parsed_offer["schema_name"]);
+            assert_eq!(json!(SCHEMA_VERSION), parsed_offer["schema_version"]);
+            assert_eq!(json!(TAG_1), parsed_offer["cred_def_tag"]);
+            assert_eq!(json!(["age", "height", "name", "sex"]), parsed_offer["attr_names"]);
+            assert_eq!(json!(false), parsed_offer["revocable"]);
+        }
+    }
+
     mod issuer_create_credential {
         use super::*;
 
@@ -101,6 +122,82 @@ mod high_cases {
         }
     }
 
+    mod prover_import_w3c_credential {
+        use super::*;
+
+        #[test]
+        fn prover_import_w3c_credential_works() {
+            let (credential_def_json, credential_offer, _, _) = anoncreds::init_common_wallet();
+
+            let issuer_wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+            let (prover_wallet_handle, prover_wallet_config) = wallet::create_and_open_default_wallet("prover_import_w3c_credential_works").unwrap();
+
+            anoncreds::prover_create_master_secret(prover_wallet_handle, COMMON_MASTER_SECRET).unwrap();
+
+            let (credential_req, credential_req_meta) = anoncreds::prover_create_credential_req(prover_wallet_handle,
+                                                                                                DID_MY1,
+                                                                                                &credential_offer,
+                                                                                                credential_def_json,
+                                                                                                COMMON_MASTER_SECRET).unwrap();
+
+            let (credential_json, _, _) = anoncreds::issuer_create_credential(issuer_wallet_handle,
+                                                                              &credential_offer,
+                                                                              &credential_req,
+                                                                              &anoncreds::gvt_auto_encoded_credential_values_json(),
+                                                                              None,
+                                                                              None).unwrap();
+
+            let cred_id = anoncreds::prover_import_w3c_credential(prover_wallet_handle,
+                                                                  CREDENTIAL1_ID,
+                                                                  &credential_req_meta,
+                                                                  &anoncreds::credential_to_w3c(&credential_json),
+                                                                  credential_def_json,
+                                                                  None).unwrap();
+            assert_eq!(CREDENTIAL1_ID, cred_id);
+
+            let credential = anoncreds::prover_get_credential(prover_wallet_handle, CREDENTIAL1_ID).unwrap();
+            let credential: CredentialInfo = serde_json::from_str(&credential).unwrap();
+            assert_eq!(credential, anoncreds::issuer_1_gvt_credential());
+
+            let cred_defs_json = json!({
+                anoncreds::issuer_1_gvt_cred_def_id(): serde_json::from_str::<serde_json::Value>(credential_def_json).unwrap()
+            }).to_string();
+
+            let requested_credentials_json = json!({
+                 "self_attested_attributes": {},
+                 "requested_attributes": {
+                    "attr1_referent": { "cred_id": CREDENTIAL1_ID, "revealed":true }
+                 },
+                 "requested_predicates": {
+                    "predicate1_referent": { "cred_id": CREDENTIAL1_ID }
+                 }
+            }).to_string();
+
+            let proof_json = anoncreds::prover_create_proof(prover_wallet_handle,
+                                                            &anoncreds::proof_request_attr_and_predicate(),
+                                                            &requested_credentials_json,
+                                                            COMMON_MASTER_SECRET,
+                                                            &anoncreds::schemas_for_proof(),
+                                                            &cred_defs_json,
+                                                            "{}").unwrap();
+
+            let proof: Proof = serde_json::from_str(&proof_json).unwrap();
+            assert_eq!("Alex", proof.requested_proof.revealed_attrs.get("attr1_referent").unwrap().raw);
+
+            let valid = anoncreds::verifier_verify_proof(&anoncreds::proof_request_attr_and_predicate(),
+                                                         &proof_json,
+                                                         &anoncreds::schemas_for_proof(),
+                                                         &cred_defs_json,
+                                                         "{}",
+                                                         "{}").unwrap();
+            assert!(valid);
+
+            wallet::close_wallet(issuer_wallet_handle).unwrap();
+            wallet::close_wallet(prover_wallet_handle).unwrap();
+            wallet::delete_wallet(&prover_wallet_config, WALLET_CREDENTIALS).unwrap();
+        }
+    }
+
     mod prover_get_credentials {
         use super::*;
 
@@ -3702,6 +3799,44 @@ mod high_cases {
         }
     }
 
+    mod verifier_validate_proof_request {
+        use super::*;
+
+        #[test]
+        fn verifier_validate_proof_request_works() {
+            let validation_errors_json = anoncreds::verifier_validate_proof_request(&anoncreds::proof_request_attr_and_predicate()).unwrap();
+            let validation_errors: Vec<serde_json::Value> = serde_json::from_str(&validation_errors_json).unwrap();
+            assert!(validation_errors.is_empty());
+        }
+
+        #[test]
+        fn verifier_validate_proof_request_works_for_invalid_proof_request() {
+            let proof_req_json = json!({
+               "nonce":"123432421212",
+               "name":"proof_req_1",
+               "version":"0.1",
+               "requested_attributes": json!({
+                   "attr1_referent": json!({
+                       "name":"name",
+                       "restrictions": json!({"cred_def_id": "invalid", "schema_version": json!({"$like": "1.%"})}),
+                       "non_revoked": json!({"from": 20, "to": 10})
+                   })
+               }),
+               "requested_predicates": json!({
+                   "attr1_referent": json!({ "name":"age", "p_type":">=", "p_value":18 })
+               }),
+            }).to_string();
+
+            let validation_errors_json = anoncreds::verifier_validate_proof_request(&proof_req_json).unwrap();
+            let validation_errors: Vec<serde_json::Value> = serde_json::from_str(&validation_errors_json).unwrap();
+
+            let mut codes: Vec<&str> = validation_errors.iter().map(|error| error["code"].as_str().unwrap()).collect();
+            codes.sort();
+            assert_eq!(vec!["duplicate_referent", "invalid_identifier", "invalid_interval", "unsupported_operator"], codes);
+            assert!(validation_errors.iter().all(|error| error["referent"] == "attr1_referent"));
+        }
+    }
+
     mod issuer_rotate_credential_def {
         use super::*;
 
@@ -4054,6 +4189,43 @@ mod medium_cases {
         }
     }
 
+    mod prover_parse_credential_offer {
+        use super::*;
+
+        #[test]
+        fn prover_parse_credential_offer_works_for_other_credential_def() {
+            let (credential_def, credential_offer, _, _) = anoncreds::init_common_wallet();
+
+            let mut credential_offer: serde_json::Value = serde_json::from_str(credential_offer).unwrap();
+            credential_offer["cred_def_id"] = json!(anoncreds::issuer_2_gvt_cred_def_id());
+
+            let res = anoncreds::prover_parse_credential_offer(&credential_offer.to_string(), credential_def);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn prover_parse_credential_offer_works_for_zero_nonce() {
+            let (credential_def, credential_offer, _, _) = anoncreds::init_common_wallet();
+
+            let mut credential_offer: serde_json::Value = serde_json::from_str(credential_offer).unwrap();
+            credential_offer["nonce"] = json!("0");
+
+            let res = anoncreds::prover_parse_credential_offer(&credential_offer.to_string(), credential_def);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn prover_parse_credential_offer_works_for_invalid_cred_def_id() {
+            let (credential_def, credential_offer, _, _) = anoncreds::init_common_wallet();
+
+            let mut credential_offer: serde_json::Value = serde_json::from_str(credential_offer).unwrap();
+            credential_offer["cred_def_id"] = json!("invalid_cred_def_id");
+
+            let res = anoncreds::prover_parse_credential_offer(&credential_offer.to_string(), credential_def);
+            assert_code!(ErrorCode::CommonInvalidParam2, res);
+        }
+    }
+
     mod prover_create_credential_req {
         use super::*;
 
@@ -4306,6 +4478,90 @@ mod medium_cases {
         }
     }
 
+    mod prover_import_w3c_credential {
+        use super::*;
+
+        fn _w3c_credential(wallet_handle: indy::WalletHandle, credential_offer: &str, credential_def_json: &str) -> (serde_json::Value, String) {
+            let (credential_req, credential_req_meta) = anoncreds::prover_create_credential_req(wallet_handle,
+                                                                                                DID_MY1,
+                                                                                                credential_offer,
+                                                                                                credential_def_json,
+                                                                                                COMMON_MASTER_SECRET).unwrap();
+
+            let (credential_json, _, _) = anoncreds::issuer_create_credential(wallet_handle,
+                                                                              credential_offer,
+                                                                              &credential_req,
+                                                                              &anoncreds::gvt_auto_encoded_credential_values_json(),
+                                                                              None,
+                                                                              None).unwrap();
+
+            (serde_json::from_str(&anoncreds::credential_to_w3c(&credential_json)).unwrap(), credential_req_meta)
+        }
+
+        #[test]
+        fn prover_import_w3c_credential_works_for_not_anoncreds_credential() {
+            let (credential_def_json, credential_offer, _, _) = anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let (mut w3c_credential, credential_req_meta) = _w3c_credential(wallet_handle, &credential_offer, credential_def_json);
+            w3c_credential["type"] = json!(["VerifiableCredential"]);
+
+            let res = anoncreds::prover_import_w3c_credential(wallet_handle,
+                                                              "prover_import_w3c_credential_works_for_not_anoncreds_credential",
+                                                              &credential_req_meta,
+                                                              &w3c_credential.to_string(),
+                                                              credential_def_json,
+                                                              None);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+
+        #[test]
+        fn prover_import_w3c_credential_works_for_unsupported_encoding() {
+            let (credential_def_json, credential_offer, _, _) = anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let (mut w3c_credential, credential_req_meta) = _w3c_credential(wallet_handle, &credential_offer, credential_def_json);
+            w3c_credential["credentialSchema"]["encoding"] = json!("legacy");
+
+            let res = anoncreds::prover_import_w3c_credential(wallet_handle,
+                                                              "prover_import_w3c_credential_works_for_unsupported_encoding",
+                                                              &credential_req_meta,
+                                                              &w3c_credential.to_string(),
+                                                              credential_def_json,
+                                                              None);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+
+        #[test]
+        fn prover_import_w3c_credential_works_for_changed_attribute() {
+            let (credential_def_json, credential_offer, _, _) = anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let (mut w3c_credential, credential_req_meta) = _w3c_credential(wallet_handle, &credential_offer, credential_def_json);
+            w3c_credential["credentialSubject"]["name"] = json!("Alec");
+
+            let res = anoncreds::prover_import_w3c_credential(wallet_handle,
+                                                              "prover_import_w3c_credential_works_for_changed_attribute",
+                                                              &credential_req_meta,
+                                                              &w3c_credential.to_string(),
+                                                              credential_def_json,
+                                                              None);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            let res = anoncreds::prover_get_credential(wallet_handle, "prover_import_w3c_credential_works_for_changed_attribute");
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+    }
+
     mod prover_get_credentials {
         use super::*;
 
@@ -4618,6 +4874,28 @@ mod medium_cases {
         }
     }
 
+    mod verifier_verify_proof_with_pools {
+        use super::*;
+
+        #[test]
+        fn verifier_verify_proof_with_pools_works_for_no_pools() {
+            let res = anoncreds::verifier_verify_proof_with_pools("{}",
+                                                                  &anoncreds::proof_request_attr(),
+                                                                  &anoncreds::proof_json());
+            assert_code!(ErrorCode::CommonInvalidParam2, res);
+        }
+
+        #[test]
+        fn verifier_verify_proof_with_pools_works_for_unmapped_issuer() {
+            let pools_json = json!({"pools": {ISSUER_DID_2: 1}}).to_string();
+
+            let res = anoncreds::verifier_verify_proof_with_pools(&pools_json,
+                                                                  &anoncreds::proof_request_attr(),
+                                                                  &anoncreds::proof_json());
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
+
     mod verifier_verify_proof {
         use super::*;
 
@@ -4672,6 +4950,32 @@ mod medium_cases {
         }
     }
 
+    mod verifier_validate_proof_request {
+        use super::*;
+
+        #[test]
+        fn verifier_validate_proof_request_works_for_empty_request() {
+            let proof_req_json = json!({
+               "nonce":"123432421212",
+               "name":"proof_req_1",
+               "version":"0.1",
+               "requested_attributes": json!({}),
+               "requested_predicates": json!({}),
+            }).to_string();
+
+            let validation_errors_json = anoncreds::verifier_validate_proof_request(&proof_req_json).unwrap();
+            let validation_errors: Vec<serde_json::Value> = serde_json::from_str(&validation_errors_json).unwrap();
+            assert_eq!(1, validation_errors.len());
+            assert_eq!("empty_request", validation_errors[0]["code"]);
+        }
+
+        #[test]
+        fn verifier_validate_proof_request_works_for_invalid_json() {
+            let res = anoncreds::verifier_validate_proof_request(r#"{"nonce":"123432421212"}"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
+
     mod verifier_verify_proof_with_proof_req_restrictions {
         use super::*;
 
@@ -4895,4 +5199,49 @@ mod medium_cases {
             assert_code!(ErrorCode::AnoncredsProofRejected , valid);
         }
     }
+
+    mod revocation_state_updater {
+        use super::*;
+        use crate::utils::blob_storage;
+
+        #[test]
+        fn start_revocation_state_updater_works_for_empty_cred_ids() {
+            anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+            let blob_storage_reader_handle = blob_storage::open_reader(TYPE, &anoncreds::tails_writer_config()).unwrap();
+
+            let res = anoncreds::start_revocation_state_updater(wallet_handle, INVALID_POOL_HANDLE, blob_storage_reader_handle,
+                                                                &json!({"credIds": []}).to_string());
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+
+        #[test]
+        fn start_revocation_state_updater_works_for_invalid_pool_handle() {
+            anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+            let blob_storage_reader_handle = blob_storage::open_reader(TYPE, &anoncreds::tails_writer_config()).unwrap();
+
+            let res = anoncreds::start_revocation_state_updater(wallet_handle, INVALID_POOL_HANDLE, blob_storage_reader_handle,
+                                                                &json!({"credIds": [CREDENTIAL1_ID], "updateInterval": 1}).to_string());
+            assert_code!(ErrorCode::PoolLedgerInvalidPoolHandle, res);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+
+        #[test]
+        fn stop_revocation_state_updater_works_for_unknown_handle() {
+            let res = anoncreds::stop_revocation_state_updater(-1);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn get_updated_revocation_states_works_for_unknown_handle() {
+            let res = anoncreds::get_updated_revocation_states(-1);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
 }
@@ -10,7 +10,7 @@ extern crate indyrs as api;
 mod demos {
     use super::*;
 
-    use crate::utils::{wallet, anoncreds};
+    use crate::utils::{wallet, anoncreds, blob_storage};
     use crate::utils::anoncreds::{COMMON_MASTER_SECRET, CREDENTIAL1_ID, CREDENTIAL2_ID, CREDENTIAL3_ID};
 
     use indy::ErrorCode;
@@ -864,6 +864,151 @@ mod demos {
         wallet::close_and_delete_wallet(prover_wallet_handle, &prover_wallet_config).unwrap();
     }
 
+    #[test]
+    fn anoncreds_works_for_plugged_blob_storage() {
+        Setup::empty();
+
+        blob_storage::register_inmem_blob_storage(INMEM_TYPE).unwrap();
+
+        //1. Issuer creates wallet, gets wallet handle
+        let (issuer_wallet_handle, issuer_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_plugged_blob_storage").unwrap();
+
+        //2. Prover creates wallet, gets wallet handle
+        let (prover_wallet_handle, prover_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_plugged_blob_storage").unwrap();
+
+        //3. Issuer creates Schema and Credential Definition
+        let (schema_id, schema_json) = anoncreds::issuer_create_schema(ISSUER_DID,
+                                                                       GVT_SCHEMA_NAME,
+                                                                       SCHEMA_VERSION,
+                                                                       GVT_SCHEMA_ATTRIBUTES).unwrap();
+
+        let (cred_def_id, cred_def_json) = anoncreds::issuer_create_credential_definition(issuer_wallet_handle,
+                                                                                          ISSUER_DID,
+                                                                                          &schema_json,
+                                                                                          TAG_1,
+                                                                                          None,
+                                                                                          Some(&anoncreds::revocation_cred_def_config())).unwrap();
+
+        //4. Issuer creates Revocation Registry with tails kept by plugged blob storage
+        let tails_writer_handle = blob_storage::open_writer(INMEM_TYPE, "{}").unwrap();
+        let (rev_reg_id, revoc_reg_def_json, _) =
+            anoncreds::issuer_create_and_store_revoc_reg(issuer_wallet_handle,
+                                                         ISSUER_DID,
+                                                         None,
+                                                         TAG_1,
+                                                         &cred_def_id,
+                                                         r#"{"max_cred_num":5, "issuance_type":"ISSUANCE_ON_DEMAND"}"#,
+                                                         tails_writer_handle).unwrap();
+
+        let revoc_reg_def: serde_json::Value = serde_json::from_str(&revoc_reg_def_json).unwrap();
+        assert!(revoc_reg_def["value"]["tailsLocation"].as_str().unwrap().starts_with("inmem://"));
+
+        let blob_storage_reader_handle = blob_storage::open_reader(INMEM_TYPE, "{}").unwrap();
+
+        //5. Prover creates Master Secret
+        anoncreds::prover_create_master_secret(prover_wallet_handle, COMMON_MASTER_SECRET).unwrap();
+
+        //6. Issuance Credential
+        let (cred_rev_id, revoc_reg_delta_json) = anoncreds::multi_steps_create_revocation_credential(
+            COMMON_MASTER_SECRET,
+            prover_wallet_handle,
+            issuer_wallet_handle,
+            CREDENTIAL1_ID,
+            &anoncreds::gvt_credential_values_json(),
+            &cred_def_id,
+            &cred_def_json,
+            &rev_reg_id,
+            &revoc_reg_def_json,
+            blob_storage_reader_handle,
+        );
+        let revoc_reg_delta_json = revoc_reg_delta_json.unwrap();
+
+        //7. Prover gets Credentials for Proof Request
+        let proof_request = json!({
+           "nonce":"123432421212",
+           "name":"proof_req_1",
+           "version":"0.1",
+           "requested_attributes": json!({
+               "attr1_referent": json!({
+                   "name":"name"
+               })
+           }),
+           "requested_predicates": json!({}),
+           "non_revoked": json!({ "from":80, "to":100 })
+        }).to_string();
+
+        let credentials_json = anoncreds::prover_get_credentials_for_proof_req(prover_wallet_handle, &proof_request).unwrap();
+        let credential = anoncreds::get_credential_for_attr_referent(&credentials_json, "attr1_referent");
+
+        //8. Prover creates RevocationState from tails read by plugged blob storage
+        let timestamp = 100;
+        let rev_state_json = anoncreds::create_revocation_state(blob_storage_reader_handle,
+                                                                &revoc_reg_def_json,
+                                                                &revoc_reg_delta_json,
+                                                                timestamp,
+                                                                &cred_rev_id).unwrap();
+
+        //9. Prover creates Proof
+        let requested_credentials_json = json!({
+             "self_attested_attributes": json!({}),
+             "requested_attributes": json!({
+                "attr1_referent": json!({ "cred_id": credential.referent, "timestamp": timestamp,  "revealed":true })
+             }),
+             "requested_predicates": json!({})
+        }).to_string();
+
+        let schemas_json = json!({
+            schema_id.clone(): serde_json::from_str::<Schema>(&schema_json).unwrap()
+        }).to_string();
+
+        let credential_defs_json = json!({
+            cred_def_id.clone(): serde_json::from_str::<CredentialDefinition>(&cred_def_json).unwrap()
+        }).to_string();
+
+        let rev_states_json = json!({
+            rev_reg_id.clone(): json!({
+                timestamp.to_string(): serde_json::from_str::<RevocationState>(&rev_state_json).unwrap()
+            })
+        }).to_string();
+
+        let proof_json = anoncreds::prover_create_proof(prover_wallet_handle,
+                                                        &proof_request,
+                                                        &requested_credentials_json,
+                                                        COMMON_MASTER_SECRET,
+                                                        &schemas_json,
+                                                        &credential_defs_json,
+                                                        &rev_states_json).unwrap();
+
+        //10. Verifier verifies proof
+        let rev_reg_defs_json = json!({
+            rev_reg_id.clone(): serde_json::from_str::<RevocationRegistryDefinition>(&revoc_reg_def_json).unwrap()
+        }).to_string();
+
+        let rev_regs_json = json!({
+            rev_reg_id.clone(): json!({
+                timestamp.to_string(): serde_json::from_str::<RevocationRegistry>(&revoc_reg_delta_json).unwrap()
+            })
+        }).to_string();
+
+        let valid = anoncreds::verifier_verify_proof(&proof_request,
+                                                     &proof_json,
+                                                     &schemas_json,
+                                                     &credential_defs_json,
+                                                     &rev_reg_defs_json,
+                                                     &rev_regs_json).unwrap();
+        assert!(valid);
+
+        //11. Reader and writer are closed by plugged blob storage
+        blob_storage::close_reader(blob_storage_reader_handle).unwrap();
+        blob_storage::close_writer(tails_writer_handle).unwrap();
+
+        let res = blob_storage::close_reader(blob_storage_reader_handle);
+        assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+        wallet::close_and_delete_wallet(issuer_wallet_handle, &issuer_wallet_config).unwrap();
+        wallet::close_and_delete_wallet(prover_wallet_handle, &prover_wallet_config).unwrap();
+    }
+
     #[cfg(feature = "revocation_tests")]
     #[test]
     fn anoncreds_works_for_revocation_proof_issuance_by_default() {
@@ -2278,8 +2423,7 @@ mod demos {
                                                                               Some(&anoncreds::revocation_cred_def_config())).unwrap();
 
         //4. Issuer creates revocation registry for 2 Credentials
-        let tails_writer_config = anoncreds::tails_writer_config();
-        let tails_writer_handle = utils::blob_storage::open_writer("default", &tails_writer_config).unwrap();
+        let tails_writer_handle = utils::blob_storage::open_writer(MEMORY_TYPE, "{}").unwrap();
 
         let (rev_reg_id, _, _) =
             anoncreds::issuer_create_and_store_revoc_reg(issuer_wallet_handle,
@@ -2290,7 +2434,7 @@ mod demos {
                                                          r#"{"max_cred_num":2, "issuance_type":"ISSUANCE_ON_DEMAND"}"#,
                                                          tails_writer_handle).unwrap();
 
-        let blob_storage_reader_handle = utils::blob_storage::open_reader(TYPE, &tails_writer_config).unwrap();
+        let blob_storage_reader_handle = utils::blob_storage::open_reader(MEMORY_TYPE, "{}").unwrap();
 
         //5. Issuer revokes Credential by not issued id
         let cred_rev_id = "100";
@@ -2532,6 +2676,80 @@ mod demos {
         wallet::close_and_delete_wallet(prover_wallet_handle, &prover_wallet_config).unwrap();
     }
 
+    #[test]
+    fn anoncreds_works_for_credential_attr_tag_policy_marker_only_and_custom_tags() {
+        Setup::empty();
+
+        //1. Create Issuer wallet, gets wallet handle
+        let (issuer_wallet_handle, issuer_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_credential_attr_tag_policy_marker_only_and_custom_tags").unwrap();
+
+        //2. Create Prover wallet, gets wallet handle
+        let (prover_wallet_handle, prover_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_credential_attr_tag_policy_marker_only_and_custom_tags").unwrap();
+
+        //3. Issuer creates Schema and Credential Definition
+        let (_schema_id, _schema_json, cred_def_id, cred_def_json) = anoncreds::multi_steps_issuer_preparation(issuer_wallet_handle,
+                                                                                                               ISSUER_DID,
+                                                                                                               GVT_SCHEMA_NAME,
+                                                                                                               GVT_SCHEMA_ATTRIBUTES);
+
+        //4. Prover creates Master Secret
+        anoncreds::prover_create_master_secret(prover_wallet_handle, COMMON_MASTER_SECRET).unwrap();
+
+        //5. Prover sets credential attr tag policy keeping value of `age` out of tags
+        anoncreds::prover_set_credential_attr_tag_policy(prover_wallet_handle, &cred_def_id, Some(r#"{"marker_only": ["age"]}"#), false).unwrap();
+
+        let catpol_json = anoncreds::prover_get_credential_attr_tag_policy(prover_wallet_handle, &cred_def_id).unwrap();
+        let catpol = serde_json::from_str::<CredentialAttrTagPolicy>(&catpol_json).unwrap();
+        assert!(catpol.is_taggable("age"));
+        assert!(!catpol.is_value_taggable("age"));
+        assert!(catpol.is_value_taggable("name"));
+
+        //6. Issuance credential for Prover
+        let cred_offer_json = anoncreds::issuer_create_credential_offer(issuer_wallet_handle, &cred_def_id).unwrap();
+
+        let (cred_req_json, cred_req_metadata_json) = anoncreds::prover_create_credential_req(prover_wallet_handle,
+                                                                                              DID_MY1,
+                                                                                              &cred_offer_json,
+                                                                                              &cred_def_json,
+                                                                                              COMMON_MASTER_SECRET).unwrap();
+
+        let (cred_json, _, _) = anoncreds::issuer_create_credential(issuer_wallet_handle,
+                                                                    &cred_offer_json,
+                                                                    &cred_req_json,
+                                                                    &anoncreds::gvt_credential_values_json(),
+                                                                    None,
+                                                                    None).unwrap();
+
+        //7. Prover stores credential with custom tags
+        let res = anoncreds::prover_store_credential_with_tags(prover_wallet_handle, CREDENTIAL1_ID, &cred_req_metadata_json, &cred_json,
+                                                               &cred_def_json, None, &json!({"attr::age::value": "28"}).to_string());
+        assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+        anoncreds::prover_store_credential_with_tags(prover_wallet_handle, CREDENTIAL1_ID, &cred_req_metadata_json, &cred_json,
+                                                     &cred_def_json, None, &json!({"folder": "work"}).to_string()).unwrap();
+
+        //8. Prover searches on marker and value tags
+        let search_count = |filter_json: serde_json::Value| {
+            let (search_handle, count) = anoncreds::prover_search_credentials(prover_wallet_handle, &filter_json.to_string()).unwrap();
+            anoncreds::prover_close_credentials_search(search_handle).unwrap();
+            count
+        };
+
+        assert_eq!(1, search_count(json!({"attr::age::marker": "1"})));
+        assert_eq!(0, search_count(json!({"attr::age::value": "28"})));
+        assert_eq!(1, search_count(json!({"attr::name::value": "Alex"})));
+        assert_eq!(1, search_count(json!({"folder": "work"})));
+
+        //9. Prover clears credential attr tag policy retroactively, custom tags are kept
+        anoncreds::prover_set_credential_attr_tag_policy(prover_wallet_handle, &cred_def_id, None, true).unwrap();
+
+        assert_eq!(1, search_count(json!({"attr::age::value": "28"})));
+        assert_eq!(1, search_count(json!({"folder": "work"})));
+
+        wallet::close_and_delete_wallet(issuer_wallet_handle, &issuer_wallet_config).unwrap();
+        wallet::close_and_delete_wallet(prover_wallet_handle, &prover_wallet_config).unwrap();
+    }
+
     #[test]
     fn anoncreds_works_for_credential_deletion() {
         Setup::empty();
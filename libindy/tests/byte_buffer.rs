@@ -0,0 +1,155 @@
+extern crate indyrs as indy;
+extern crate indy_sys;
+extern crate futures;
+#[macro_use]
+extern crate lazy_static;
+#[macro_use]
+extern crate serde_json;
+extern crate rust_base58;
+extern crate sha2;
+
+use std::ffi::CString;
+use std::slice;
+use std::sync::Mutex;
+use std::sync::mpsc::{channel, Sender};
+use std::time::Duration;
+
+use futures::Future;
+use indy::WalletHandle;
+use rust_base58::ToBase58;
+use sha2::{Digest, Sha256};
+
+const WALLET_CREDENTIALS: &str = r#"{"key":"8dvfYSt5d1taSd6yJdpjq4emkwsPDDLYxkNFysFD2cZY", "key_derivation_method":"RAW"}"#;
+const EXPORT_CONFIG: &str = r#"{"key":"export_key", "key_derivation_method":"ARGON2I_INT"}"#;
+const MESSAGE: &[u8] = b"Hello World";
+
+lazy_static! {
+    static ref RESULTS: Mutex<Option<Sender<(i32, Option<Vec<u8>>)>>> = Mutex::new(None);
+    // tests share the callback, so they are run one by one
+    static ref TEST_LOCK: Mutex<()> = Mutex::new(());
+}
+
+// Takes the data over from libindy, so the buffer is released here right after the copy is made
+extern fn _buffer_cb(_command_handle: i32, err: i32, data: *mut u8, data_len: u32) {
+    let buffer = if data.is_null() {
+        None
+    } else {
+        let buffer = unsafe { slice::from_raw_parts(data, data_len as usize) }.to_vec();
+        unsafe { indy_sys::indy_free_buffer(data, data_len) };
+        Some(buffer)
+    };
+    RESULTS.lock().unwrap().as_ref().unwrap().send((err, buffer)).unwrap();
+}
+
+fn _open_wallet(name: &str) -> (String, WalletHandle) {
+    let path = std::env::temp_dir().join("indy_byte_buffer");
+    let config = json!({"id": name, "storage_config": {"path": path.to_str().unwrap()}}).to_string();
+    indy::wallet::delete_wallet(&config, WALLET_CREDENTIALS).wait().ok();
+
+    indy::wallet::create_wallet(&config, WALLET_CREDENTIALS).wait().unwrap();
+    let wallet_handle = indy::wallet::open_wallet(&config, WALLET_CREDENTIALS).wait().unwrap();
+    (config, wallet_handle)
+}
+
+#[test]
+fn buffer_api_works() {
+    let _lock = TEST_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+    let (sender, receiver) = channel();
+    *RESULTS.lock().unwrap() = Some(sender);
+    let wait = || receiver.recv_timeout(Duration::from_secs(20)).unwrap();
+
+    let (config, wallet_handle) = _open_wallet("buffer_api_works");
+    let verkey = indy::crypto::create_key(wallet_handle, None).wait().unwrap();
+    let receiver_keys = CString::new(json!([verkey]).to_string()).unwrap();
+
+    // pack and unpack
+    let err = unsafe {
+        indy_sys::crypto::indy_pack_message_buffer(1, wallet_handle, MESSAGE.as_ptr(), MESSAGE.len() as u32,
+                                                   receiver_keys.as_ptr(), std::ptr::null(), Some(_buffer_cb))
+    };
+    assert_eq!(0, err);
+    let (err, jwe) = wait();
+    assert_eq!(0, err);
+    let jwe = jwe.unwrap();
+
+    let err = unsafe { indy_sys::crypto::indy_unpack_message_buffer(2, wallet_handle, jwe.as_ptr(), jwe.len() as u32, Some(_buffer_cb)) };
+    assert_eq!(0, err);
+    let (err, unpacked) = wait();
+    assert_eq!(0, err);
+    let unpacked: serde_json::Value = serde_json::from_slice(&unpacked.unwrap()).unwrap();
+    assert_eq!(String::from_utf8(MESSAGE.to_vec()).unwrap(), unpacked["message"].as_str().unwrap());
+    assert_eq!(verkey, unpacked["recipient_verkey"].as_str().unwrap());
+
+    // export
+    let export_config = CString::new(EXPORT_CONFIG).unwrap();
+    let err = unsafe { indy_sys::wallet::indy_export_wallet_to_buffer(3, wallet_handle, export_config.as_ptr(), Some(_buffer_cb)) };
+    assert_eq!(0, err);
+    let (err, exported) = wait();
+    assert_eq!(0, err);
+
+    indy::wallet::close_wallet(wallet_handle).wait().unwrap();
+    indy::wallet::delete_wallet(&config, WALLET_CREDENTIALS).wait().unwrap();
+
+    // exported content is importable from a file
+    let export_path = std::env::temp_dir().join("indy_byte_buffer").join("buffer_api_works_export");
+    std::fs::write(&export_path, exported.unwrap()).unwrap();
+    let import_config = json!({"path": export_path.to_str().unwrap(), "key": "export_key", "key_derivation_method": "ARGON2I_INT"}).to_string();
+    indy::wallet::import_wallet(&config, WALLET_CREDENTIALS, &import_config).wait().unwrap();
+
+    let wallet_handle = indy::wallet::open_wallet(&config, WALLET_CREDENTIALS).wait().unwrap();
+    indy::crypto::sign(wallet_handle, &verkey, MESSAGE).wait().unwrap();
+
+    indy::wallet::close_wallet(wallet_handle).wait().unwrap();
+    indy::wallet::delete_wallet(&config, WALLET_CREDENTIALS).wait().unwrap();
+    std::fs::remove_file(export_path).unwrap();
+
+    // NULL buffer is passed on error
+    let err = unsafe { indy_sys::wallet::indy_export_wallet_to_buffer(4, wallet_handle, export_config.as_ptr(), Some(_buffer_cb)) };
+    assert_eq!(0, err);
+    let (err, exported) = wait();
+    assert_eq!(indy::ErrorCode::WalletInvalidHandle as i32, err);
+    assert!(exported.is_none());
+
+    unsafe { indy_sys::indy_free_buffer(std::ptr::null_mut(), 0) };
+}
+
+#[test]
+fn read_tails_to_buffer_works() {
+    let _lock = TEST_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+    let (sender, receiver) = channel();
+    *RESULTS.lock().unwrap() = Some(sender);
+    let wait = || receiver.recv_timeout(Duration::from_secs(20)).unwrap();
+
+    let tails: Vec<u8> = (0..100_000u32).map(|i| i as u8).collect();
+    let tails_hash = Sha256::digest(&tails).to_base58();
+
+    let base_dir = std::env::temp_dir().join("indy_byte_buffer").join("read_tails_to_buffer_works");
+    std::fs::create_dir_all(&base_dir).unwrap();
+    std::fs::write(base_dir.join(&tails_hash), &tails).unwrap();
+
+    let config = json!({"base_dir": base_dir.to_str().unwrap()}).to_string();
+    let reader_handle = indy::blob_storage::open_reader("default", &config).wait().unwrap();
+
+    let tails_location = CString::new("").unwrap();
+    let c_tails_hash = CString::new(tails_hash.as_str()).unwrap();
+    let err = unsafe {
+        indy_sys::blob_storage::indy_read_tails_to_buffer(5, reader_handle, tails_location.as_ptr(), c_tails_hash.as_ptr(), Some(_buffer_cb))
+    };
+    assert_eq!(0, err);
+    let (err, data) = wait();
+    assert_eq!(0, err);
+    assert_eq!(tails, data.unwrap());
+
+    // content that doesn't match the hash isn't passed
+    std::fs::write(base_dir.join(&tails_hash), b"other tails").unwrap();
+    let err = unsafe {
+        indy_sys::blob_storage::indy_read_tails_to_buffer(6, reader_handle, tails_location.as_ptr(), c_tails_hash.as_ptr(), Some(_buffer_cb))
+    };
+    assert_eq!(0, err);
+    let (err, data) = wait();
+    assert_eq!(indy::ErrorCode::CommonInvalidStructure as i32, err);
+    assert!(data.is_none());
+
+    indy::blob_storage::close_reader(reader_handle).wait().unwrap();
+    std::fs::remove_dir_all(base_dir).unwrap();
+}
@@ -13,6 +13,18 @@ use crate::utils::domain::crypto::did::DidValue;
 use self::indy::ErrorCode;
 
 pub const FORBIDDEN_TYPE: &'static str = "Indy::Test";
+pub const SCHEMA_CACHE: &'static str = "schema_cache";
+pub const REV_REG_CACHE: &'static str = "rev_reg_cache";
+pub const REV_REG_DELTA_CACHE: &'static str = "rev_reg_delta_cache";
+pub const REV_REG_DATA: &'static str = r#"{"ver":"1.0","value":{"accum":"cached"}}"#;
+
+fn _now() -> i64 {
+    std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_secs() as i64
+}
+
+fn _pad(timestamp: i64) -> String {
+    format!("{:020}", timestamp)
+}
 
 mod high_cases {
     use super::*;
@@ -210,6 +222,195 @@ mod high_cases {
             assert_code!(ErrorCode::LedgerNotFound, res);
         }
 
+        #[test]
+        fn indy_get_schema_ttl_works() {
+            let setup = Setup::wallet();
+
+            let schema_id = SchemaId::new(&DidValue(DID.to_string()), "cached_schema", "1.0");
+            let now = _now();
+
+            let tags_json = json!({"timestamp": now.to_string(), "~accessed": now.to_string(), "~expires": (now + 1000).to_string()}).to_string();
+            utils::non_secrets::add_wallet_record(setup.wallet_handle, SCHEMA_CACHE, &schema_id.0, SCHEMA_DATA, Some(&tags_json)).unwrap();
+
+            let options_json = json!({"noUpdate": true}).to_string();
+            let schema_json = get_schema_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                &schema_id.0,
+                &options_json
+            ).unwrap();
+            assert_eq!(SCHEMA_DATA, schema_json);
+        }
+
+        #[test]
+        fn indy_get_schema_ttl_works_for_expired_data() {
+            let setup = Setup::wallet();
+
+            let schema_id = SchemaId::new(&DidValue(DID.to_string()), "cached_schema", "1.0");
+            let now = _now();
+
+            let tags_json = json!({"timestamp": (now - 10).to_string(), "~accessed": (now - 10).to_string(), "~expires": (now - 1).to_string()}).to_string();
+            utils::non_secrets::add_wallet_record(setup.wallet_handle, SCHEMA_CACHE, &schema_id.0, SCHEMA_DATA, Some(&tags_json)).unwrap();
+
+            // expired entry is not returned and removed from cache
+            let options_json = json!({"noUpdate": true}).to_string();
+            let res = get_schema_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                &schema_id.0,
+                &options_json
+            );
+            assert_code!(ErrorCode::LedgerNotFound, res);
+
+            let res = utils::non_secrets::get_wallet_record(setup.wallet_handle, SCHEMA_CACHE, &schema_id.0, "{}");
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+
+        #[test]
+        fn indy_get_schema_works_for_cached_not_found() {
+            let setup = Setup::wallet_and_pool();
+
+            let schema_id = SchemaId::new(&DidValue(DID.to_string()), "not_found_schema", "1.0");
+
+            let res = get_schema_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                &schema_id.0,
+                "{}");
+            assert_code!(ErrorCode::LedgerNotFound, res);
+
+            let record = utils::non_secrets::get_wallet_record(setup.wallet_handle, SCHEMA_CACHE, &schema_id.0, &json!({"retrieveTags": true}).to_string()).unwrap();
+            let record: serde_json::Value = serde_json::from_str(&record).unwrap();
+            assert_eq!("true", record["tags"]["~not_found"]);
+        }
+
+        #[test]
+        fn indy_get_schema_not_found_cache_works() {
+            let setup = Setup::wallet();
+
+            let schema_id = SchemaId::new(&DidValue(DID.to_string()), "not_found_schema", "1.0");
+            let now = _now();
+
+            let tags_json = json!({"timestamp": now.to_string(), "~accessed": now.to_string(), "~expires": (now + 60).to_string(), "~not_found": "true"}).to_string();
+            utils::non_secrets::add_wallet_record(setup.wallet_handle, SCHEMA_CACHE, &schema_id.0, SCHEMA_DATA, Some(&tags_json)).unwrap();
+
+            // the ledger isn't queried
+            let res = get_schema_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                &schema_id.0,
+                "{}");
+            assert_code!(ErrorCode::LedgerNotFound, res);
+        }
+
+        #[test]
+        fn indy_get_schema_no_negative_cache_works() {
+            let setup = Setup::wallet();
+
+            let schema_id = SchemaId::new(&DidValue(DID.to_string()), "not_found_schema", "1.0");
+            let now = _now();
+
+            let tags_json = json!({"timestamp": now.to_string(), "~accessed": now.to_string(), "~expires": (now + 60).to_string(), "~not_found": "true"}).to_string();
+            utils::non_secrets::add_wallet_record(setup.wallet_handle, SCHEMA_CACHE, &schema_id.0, SCHEMA_DATA, Some(&tags_json)).unwrap();
+
+            // cached result is skipped and the ledger is queried
+            let options_json = json!({"noNegativeCache": true}).to_string();
+            let res = get_schema_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                &schema_id.0,
+                &options_json);
+            assert_code!(ErrorCode::PoolLedgerInvalidPoolHandle, res);
+        }
+
+        #[test]
+        fn indy_get_schema_max_entries_works() {
+            let setup = Setup::wallet_and_pool();
+
+            let (schema_id, _, _) = utils::ledger::post_entities();
+
+            let recently_used_id = SchemaId::new(&DidValue(DID.to_string()), "recently_used_schema", "1.0");
+            let least_recently_used_id = SchemaId::new(&DidValue(DID.to_string()), "least_recently_used_schema", "1.0");
+            let now = _now();
+
+            let tags_json = json!({"timestamp": (now - 100).to_string(), "~accessed": (now - 100).to_string()}).to_string();
+            utils::non_secrets::add_wallet_record(setup.wallet_handle, SCHEMA_CACHE, &least_recently_used_id.0, SCHEMA_DATA, Some(&tags_json)).unwrap();
+
+            let tags_json = json!({"timestamp": (now - 100).to_string(), "~accessed": (now - 10).to_string()}).to_string();
+            utils::non_secrets::add_wallet_record(setup.wallet_handle, SCHEMA_CACHE, &recently_used_id.0, SCHEMA_DATA, Some(&tags_json)).unwrap();
+
+            let options_json = json!({"maxEntries": 2}).to_string();
+            get_schema_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                schema_id,
+                &options_json
+            ).unwrap();
+
+            utils::non_secrets::get_wallet_record(setup.wallet_handle, SCHEMA_CACHE, schema_id, "{}").unwrap();
+            utils::non_secrets::get_wallet_record(setup.wallet_handle, SCHEMA_CACHE, &recently_used_id.0, "{}").unwrap();
+
+            let res = utils::non_secrets::get_wallet_record(setup.wallet_handle, SCHEMA_CACHE, &least_recently_used_id.0, "{}");
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+
+        #[test]
+        fn indy_get_schema_lfu_eviction_policy_works() {
+            let setup = Setup::wallet_and_pool();
+
+            let (schema_id, _, _) = utils::ledger::post_entities();
+
+            let frequently_used_id = SchemaId::new(&DidValue(DID.to_string()), "frequently_used_schema", "1.0");
+            let rarely_used_id = SchemaId::new(&DidValue(DID.to_string()), "rarely_used_schema", "1.0");
+            let now = _now();
+
+            let tags_json = json!({"timestamp": (now - 100).to_string(), "~accessed": (now - 100).to_string(), "~hits": "5"}).to_string();
+            utils::non_secrets::add_wallet_record(setup.wallet_handle, SCHEMA_CACHE, &frequently_used_id.0, SCHEMA_DATA, Some(&tags_json)).unwrap();
+
+            let tags_json = json!({"timestamp": (now - 100).to_string(), "~accessed": (now - 10).to_string(), "~hits": "1"}).to_string();
+            utils::non_secrets::add_wallet_record(setup.wallet_handle, SCHEMA_CACHE, &rarely_used_id.0, SCHEMA_DATA, Some(&tags_json)).unwrap();
+
+            let options_json = json!({"maxEntries": 2, "evictionPolicy": "lfu"}).to_string();
+            get_schema_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                schema_id,
+                &options_json
+            ).unwrap();
+
+            utils::non_secrets::get_wallet_record(setup.wallet_handle, SCHEMA_CACHE, schema_id, "{}").unwrap();
+            utils::non_secrets::get_wallet_record(setup.wallet_handle, SCHEMA_CACHE, &frequently_used_id.0, "{}").unwrap();
+
+            let res = utils::non_secrets::get_wallet_record(setup.wallet_handle, SCHEMA_CACHE, &rarely_used_id.0, "{}");
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+
+        #[test]
+        fn indy_get_schema_max_entry_size_works() {
+            let setup = Setup::wallet_and_pool();
+
+            let (schema_id, _, _) = utils::ledger::post_entities();
+
+            let options_json = json!({"maxEntrySize": 1}).to_string();
+            get_schema_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                schema_id,
+                &options_json
+            ).unwrap();
+
+            let res = utils::non_secrets::get_wallet_record(setup.wallet_handle, SCHEMA_CACHE, schema_id, "{}");
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+
         #[test]
         fn indy_purge_schema_cache_no_options() {
             let setup = Setup::wallet();
@@ -227,6 +428,23 @@ mod high_cases {
             let setup = Setup::wallet();
             purge_schema_cache(setup.wallet_handle, &json!({"minFresh": 1000}).to_string()).unwrap();
         }
+
+        #[test]
+        fn indy_purge_schema_cache_for_pool_name() {
+            let setup = Setup::wallet();
+
+            let tags_1 = json!({"~pool": "pool_1"}).to_string();
+            let tags_2 = json!({"~pool": "pool_2"}).to_string();
+            utils::non_secrets::add_wallet_record(setup.wallet_handle, SCHEMA_CACHE, "schema_1", SCHEMA_DATA, Some(&tags_1)).unwrap();
+            utils::non_secrets::add_wallet_record(setup.wallet_handle, SCHEMA_CACHE, "schema_2", SCHEMA_DATA, Some(&tags_2)).unwrap();
+
+            purge_schema_cache(setup.wallet_handle, &json!({"poolName": "pool_1"}).to_string()).unwrap();
+
+            let res = utils::non_secrets::get_wallet_record(setup.wallet_handle, SCHEMA_CACHE, "schema_1", "{}");
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+
+            utils::non_secrets::get_wallet_record(setup.wallet_handle, SCHEMA_CACHE, "schema_2", "{}").unwrap();
+        }
     }
 
     mod cred_def_cache {
@@ -426,4 +644,411 @@ mod high_cases {
             purge_cred_def_cache(setup.wallet_handle, &json!({"minFresh": 1000}).to_string()).unwrap();
         }
     }
-}
\ No newline at end of file
+    mod rev_reg_cache {
+        use super::*;
+        use crate::utils::constants::*;
+
+        #[test]
+        fn indy_get_rev_reg_def_cache_works() {
+            let setup = Setup::wallet_and_pool();
+
+            let (_, _, rev_reg_def_id) = utils::ledger::post_entities();
+
+            let rev_reg_def_json = get_rev_reg_def_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                rev_reg_def_id,
+                "{}"
+            ).unwrap();
+
+            let options_json = json!({"noUpdate": true}).to_string();
+            let cached_rev_reg_def_json = get_rev_reg_def_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                rev_reg_def_id,
+                &options_json
+            ).unwrap();
+
+            assert_eq!(rev_reg_def_json, cached_rev_reg_def_json);
+        }
+
+        #[test]
+        fn indy_get_rev_reg_cache_works() {
+            let setup = Setup::wallet_and_pool();
+
+            let (_, _, rev_reg_def_id) = utils::ledger::post_entities();
+            let now = _now();
+
+            let (_, rev_reg_json, timestamp) = get_rev_reg_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                rev_reg_def_id,
+                now,
+                "{}"
+            ).unwrap();
+
+            let options_json = json!({"noUpdate": true}).to_string();
+            let (cached_rev_reg_def_id, cached_rev_reg_json, cached_timestamp) = get_rev_reg_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                rev_reg_def_id,
+                now,
+                &options_json
+            ).unwrap();
+
+            assert_eq!(rev_reg_def_id, cached_rev_reg_def_id);
+            assert_eq!(rev_reg_json, cached_rev_reg_json);
+            assert_eq!(timestamp, cached_timestamp);
+        }
+
+        #[test]
+        fn indy_get_rev_reg_delta_cache_works() {
+            let setup = Setup::wallet_and_pool();
+
+            let (_, _, rev_reg_def_id) = utils::ledger::post_entities();
+            let now = _now();
+
+            let (_, rev_reg_delta_json, timestamp) = get_rev_reg_delta_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                rev_reg_def_id,
+                -1,
+                now,
+                "{}"
+            ).unwrap();
+
+            let options_json = json!({"noUpdate": true}).to_string();
+            let (_, cached_rev_reg_delta_json, cached_timestamp) = get_rev_reg_delta_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                rev_reg_def_id,
+                -1,
+                now,
+                &options_json
+            ).unwrap();
+
+            assert_eq!(rev_reg_delta_json, cached_rev_reg_delta_json);
+            assert_eq!(timestamp, cached_timestamp);
+        }
+
+        #[test]
+        fn indy_get_rev_reg_works_for_cached_interval() {
+            let setup = Setup::wallet();
+
+            let rev_reg_def_id = utils::anoncreds::gvt_rev_reg_id();
+            let now = _now();
+
+            let tags_json = json!({
+                "rev_reg_id": rev_reg_def_id,
+                "~from": _pad(100),
+                "~to": _pad(200),
+                "timestamp": now.to_string(),
+                "~accessed": now.to_string(),
+            }).to_string();
+            utils::non_secrets::add_wallet_record(setup.wallet_handle, REV_REG_CACHE, &format!("{}:100", rev_reg_def_id), REV_REG_DATA, Some(&tags_json)).unwrap();
+
+            let options_json = json!({"noUpdate": true}).to_string();
+            let (cached_rev_reg_def_id, rev_reg_json, timestamp) = get_rev_reg_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                &rev_reg_def_id,
+                150,
+                &options_json
+            ).unwrap();
+
+            assert_eq!(rev_reg_def_id, cached_rev_reg_def_id);
+            assert_eq!(REV_REG_DATA, rev_reg_json);
+            assert_eq!(100, timestamp);
+
+            // cached state isn't known to be actual after 200
+            let res = get_rev_reg_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                &rev_reg_def_id,
+                250,
+                &options_json
+            );
+            assert_code!(ErrorCode::LedgerNotFound, res);
+        }
+
+        #[test]
+        fn indy_get_rev_reg_delta_works_for_cached_range() {
+            let setup = Setup::wallet();
+
+            let rev_reg_def_id = utils::anoncreds::gvt_rev_reg_id();
+            let now = _now();
+
+            let tags_json = json!({
+                "rev_reg_id": rev_reg_def_id,
+                "~txn_time": _pad(100),
+                "~to": _pad(200),
+                "timestamp": now.to_string(),
+                "~accessed": now.to_string(),
+            }).to_string();
+            utils::non_secrets::add_wallet_record(setup.wallet_handle, REV_REG_DELTA_CACHE, &format!("{}:0", rev_reg_def_id), REV_REG_DATA, Some(&tags_json)).unwrap();
+
+            let options_json = json!({"noUpdate": true}).to_string();
+            let (_, rev_reg_delta_json, timestamp) = get_rev_reg_delta_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                &rev_reg_def_id,
+                -1,
+                150,
+                &options_json
+            ).unwrap();
+
+            assert_eq!(REV_REG_DATA, rev_reg_delta_json);
+            assert_eq!(100, timestamp);
+
+            // the tail of requested range has to be fetched from the ledger
+            let res = get_rev_reg_delta_cache(
+                setup.pool_handle,
+                setup.wallet_handle,
+                DID_MY1,
+                &rev_reg_def_id,
+                -1,
+                250,
+                &options_json
+            );
+            assert_code!(ErrorCode::LedgerNotFound, res);
+        }
+
+        #[test]
+        fn indy_purge_rev_reg_cache_all_data() {
+            let setup = Setup::wallet();
+
+            let rev_reg_def_id = utils::anoncreds::gvt_rev_reg_id();
+            let record_id = format!("{}:100", rev_reg_def_id);
+            utils::non_secrets::add_wallet_record(setup.wallet_handle, REV_REG_CACHE, &record_id, REV_REG_DATA, None).unwrap();
+
+            purge_rev_reg_cache(setup.wallet_handle, "{}").unwrap();
+
+            let res = utils::non_secrets::get_wallet_record(setup.wallet_handle, REV_REG_CACHE, &record_id, "{}");
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+    }
+
+    mod prefetch_ledger_cache {
+        use super::*;
+        use crate::utils::domain::anoncreds::schema::SchemaId;
+        use crate::utils::constants::*;
+
+        #[test]
+        fn indy_prefetch_ledger_cache_works() {
+            let setup = Setup::wallet_and_pool();
+
+            let (schema_id, cred_def_id, rev_reg_def_id) = utils::ledger::post_entities();
+
+            let ids_json = json!({
+                "schemas": [schema_id],
+                "credDefs": [cred_def_id],
+                "revRegDefs": [rev_reg_def_id],
+            }).to_string();
+
+            let failed_json = prefetch_ledger_cache(setup.pool_handle, setup.wallet_handle, &ids_json).unwrap();
+            assert_eq!("{}", failed_json);
+
+            let options_json = json!({"noUpdate": true}).to_string();
+            get_schema_cache(setup.pool_handle, setup.wallet_handle, DID_MY1, schema_id, &options_json).unwrap();
+            get_cred_def_cache(setup.pool_handle, setup.wallet_handle, DID_MY1, cred_def_id, &options_json).unwrap();
+            get_rev_reg_def_cache(setup.pool_handle, setup.wallet_handle, DID_MY1, rev_reg_def_id, &options_json).unwrap();
+        }
+
+        #[test]
+        fn indy_prefetch_ledger_cache_works_for_cached_data() {
+            let setup = Setup::wallet();
+
+            let schema_id = SchemaId::new(&DidValue(DID.to_string()), "cached_schema", "1.0");
+            let now = _now();
+
+            let tags_json = json!({"timestamp": now.to_string(), "~accessed": now.to_string()}).to_string();
+            utils::non_secrets::add_wallet_record(setup.wallet_handle, SCHEMA_CACHE, &schema_id.0, SCHEMA_DATA, Some(&tags_json)).unwrap();
+
+            // nothing is requested from the ledger
+            let ids_json = json!({"schemas": [schema_id.0]}).to_string();
+            let failed_json = prefetch_ledger_cache(setup.pool_handle, setup.wallet_handle, &ids_json).unwrap();
+            assert_eq!("{}", failed_json);
+        }
+
+        #[test]
+        fn indy_prefetch_ledger_cache_works_for_invalid_id() {
+            let setup = Setup::wallet();
+
+            let ids_json = json!({"schemas": ["invalid"]}).to_string();
+            let res = prefetch_ledger_cache(setup.pool_handle, setup.wallet_handle, &ids_json);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
+
+    mod cache_stats {
+        use super::*;
+        use crate::utils::domain::anoncreds::schema::SchemaId;
+        use crate::utils::constants::*;
+
+        #[test]
+        fn indy_get_cache_stats_works_for_empty_cache() {
+            let setup = Setup::wallet();
+
+            let stats = get_cache_stats(setup.wallet_handle).unwrap();
+            let stats: serde_json::Value = serde_json::from_str(&stats).unwrap();
+
+            for kind in &["schemas", "credDefs", "revRegDefs", "revRegs", "revRegDeltas"] {
+                assert_eq!(0, stats[kind]["entries"].as_u64().unwrap());
+                assert_eq!(0, stats[kind]["size"].as_u64().unwrap());
+                assert!(stats[kind]["oldest"].is_null());
+            }
+        }
+
+        #[test]
+        fn indy_get_cache_stats_works() {
+            let setup = Setup::wallet();
+
+            let schema_id = SchemaId::new(&DidValue(DID.to_string()), "cached_schema", "1.0");
+            let other_schema_id = SchemaId::new(&DidValue(DID.to_string()), "other_cached_schema", "1.0");
+            let now = _now();
+
+            let tags_json = json!({"timestamp": (now - 100).to_string(), "~accessed": now.to_string()}).to_string();
+            utils::non_secrets::add_wallet_record(setup.wallet_handle, SCHEMA_CACHE, &schema_id.0, SCHEMA_DATA, Some(&tags_json)).unwrap();
+
+            let tags_json = json!({"timestamp": now.to_string(), "~accessed": now.to_string()}).to_string();
+            utils::non_secrets::add_wallet_record(setup.wallet_handle, SCHEMA_CACHE, &other_schema_id.0, SCHEMA_DATA, Some(&tags_json)).unwrap();
+
+            let stats = get_cache_stats(setup.wallet_handle).unwrap();
+            let stats: serde_json::Value = serde_json::from_str(&stats).unwrap();
+            let hits = stats["schemas"]["hits"].as_u64().unwrap();
+
+            assert_eq!(2, stats["schemas"]["entries"].as_u64().unwrap());
+            assert_eq!(2 * SCHEMA_DATA.len() as u64, stats["schemas"]["size"].as_u64().unwrap());
+            assert_eq!(now - 100, stats["schemas"]["oldest"].as_i64().unwrap());
+            assert_eq!(now, stats["schemas"]["newest"].as_i64().unwrap());
+
+            let options_json = json!({"noUpdate": true}).to_string();
+            get_schema_cache(setup.pool_handle, setup.wallet_handle, DID_MY1, &schema_id.0, &options_json).unwrap();
+
+            // counters are shared with concurrently running tests
+            let stats = get_cache_stats(setup.wallet_handle).unwrap();
+            let stats: serde_json::Value = serde_json::from_str(&stats).unwrap();
+            assert!(stats["schemas"]["hits"].as_u64().unwrap() > hits);
+        }
+    }
+
+    mod cache_config {
+        use super::*;
+        use crate::utils::domain::anoncreds::schema::SchemaId;
+        use crate::utils::constants::*;
+
+        #[test]
+        fn indy_set_cache_config_works() {
+            let setup = Setup::wallet();
+
+            let config_json = json!({"include": ["schemas", "credDefs"], "ttl": 100, "evictionPolicy": "lfu", "negativeCache": false}).to_string();
+            set_cache_config(setup.wallet_handle, &config_json).unwrap();
+        }
+
+        #[test]
+        fn indy_set_cache_config_works_for_excluded_kind() {
+            let setup = Setup::wallet();
+
+            let schema_id = SchemaId::new(&DidValue(DID.to_string()), "excluded_schema", "1.0");
+            let tags_json = json!({"timestamp": _now().to_string()}).to_string();
+            utils::non_secrets::add_wallet_record(setup.wallet_handle, SCHEMA_CACHE, &schema_id.0, SCHEMA_DATA, Some(&tags_json)).unwrap();
+
+            let config_json = json!({"include": ["credDefs"]}).to_string();
+            set_cache_config(setup.wallet_handle, &config_json).unwrap();
+
+            let options_json = json!({"noUpdate": true}).to_string();
+            let res = get_schema_cache(setup.pool_handle, setup.wallet_handle, DID_MY1, &schema_id.0, &options_json);
+            assert_code!(ErrorCode::LedgerNotFound, res);
+        }
+
+        #[test]
+        fn indy_set_cache_config_works_for_unknown_kind() {
+            let setup = Setup::wallet();
+
+            let config_json = json!({"include": ["nyms"]}).to_string();
+            let res = set_cache_config(setup.wallet_handle, &config_json);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_set_cache_config_works_for_unknown_field() {
+            let setup = Setup::wallet();
+
+            let config_json = json!({"maxAge": 100}).to_string();
+            let res = set_cache_config(setup.wallet_handle, &config_json);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
+
+    mod local_registry {
+        use super::*;
+        use crate::utils::domain::anoncreds::schema::{Schema, SchemaV1};
+        use crate::utils::domain::anoncreds::credential_definition::CredentialDefinitionV1;
+        use crate::utils::constants::*;
+
+        #[test]
+        fn indy_register_local_schema_works() {
+            let setup = Setup::wallet();
+
+            register_local_schema(setup.wallet_handle, &utils::anoncreds::gvt_schema_json()).unwrap();
+
+            // pool handle is invalid, so registered schema is returned without ledger request
+            let schema_json = get_schema_cache(setup.pool_handle, setup.wallet_handle, DID_MY1, &utils::anoncreds::gvt_schema_id(), "{}").unwrap();
+            let schema: SchemaV1 = serde_json::from_str(&schema_json).unwrap();
+            assert_eq!(utils::anoncreds::gvt_schema_id(), schema.id.0);
+        }
+
+        #[test]
+        fn indy_register_local_schema_works_for_replace() {
+            let setup = Setup::wallet();
+
+            register_local_schema(setup.wallet_handle, &utils::anoncreds::gvt_schema_json()).unwrap();
+
+            let mut schema = utils::anoncreds::gvt_schema();
+            schema.seq_no = Some(1);
+            register_local_schema(setup.wallet_handle, &serde_json::to_string(&Schema::SchemaV1(schema)).unwrap()).unwrap();
+
+            let schema_json = get_schema_cache(setup.pool_handle, setup.wallet_handle, DID_MY1, &utils::anoncreds::gvt_schema_id(), "{}").unwrap();
+            let schema: SchemaV1 = serde_json::from_str(&schema_json).unwrap();
+            assert_eq!(Some(1), schema.seq_no);
+        }
+
+        #[test]
+        fn indy_register_local_schema_works_for_invalid_json() {
+            let setup = Setup::wallet();
+
+            let res = register_local_schema(setup.wallet_handle, r#"{"id":"1"}"#);
+            assert_code!(ErrorCode::CommonInvalidParam3, res);
+        }
+
+        #[test]
+        fn indy_register_local_cred_def_works() {
+            let setup = Setup::wallet();
+
+            register_local_cred_def(setup.wallet_handle, &utils::anoncreds::issuer_1_gvt_cred_def_json()).unwrap();
+
+            let cred_def_json = get_cred_def_cache(setup.pool_handle, setup.wallet_handle, DID_MY1, &utils::anoncreds::issuer_1_gvt_cred_def_id(), "{}").unwrap();
+            let cred_def: CredentialDefinitionV1 = serde_json::from_str(&cred_def_json).unwrap();
+            assert_eq!(utils::anoncreds::issuer_1_gvt_cred_def_id(), cred_def.id.0);
+        }
+
+        #[test]
+        fn indy_get_schema_works_for_not_registered_schema() {
+            let setup = Setup::wallet();
+
+            register_local_schema(setup.wallet_handle, &utils::anoncreds::gvt_schema_json()).unwrap();
+
+            let res = get_schema_cache(setup.pool_handle, setup.wallet_handle, DID_MY1, &utils::anoncreds::xyz_schema_id(), "{}");
+            assert_code!(ErrorCode::PoolLedgerInvalidPoolHandle, res);
+        }
+    }
+}
@@ -0,0 +1,132 @@
+extern crate libc;
+extern crate indyrs as indy;
+
+use std::ffi::CString;
+use std::ptr;
+use std::sync::mpsc::{channel, Sender};
+use std::time::Duration;
+
+use self::libc::{c_char, c_void};
+
+const WALLET_CREDENTIALS: &str = r#"{"key":"8dvfYSt5d1taSd6yJdpjq4emkwsPDDLYxkNFysFD2cZY", "key_derivation_method":"RAW"}"#;
+
+type Results = Sender<(i32 /* err */, i32 /* handle */)>;
+
+// Test thread may drop the context as soon as it receives the result, so the sender is cloned before sending
+fn _send(context: *const c_void, result: (i32, i32)) {
+    let sender = unsafe { &*(context as *const Results) }.clone();
+    sender.send(result).unwrap();
+}
+
+extern fn _empty_cb(context: *const c_void, err: i32) {
+    _send(context, (err, 0));
+}
+
+extern fn _handle_cb(context: *const c_void, err: i32, handle: i32) {
+    _send(context, (err, handle));
+}
+
+// Chunks of streamed data are reported with -1 in place of error code
+extern fn _records_cb(context: *const c_void, records_json: *const c_char) {
+    let len = unsafe { std::ffi::CStr::from_ptr(records_json) }.to_bytes().len();
+    _send(context, (-1, len as i32));
+}
+
+extern fn _string_cb(context: *const c_void, err: i32, string: *const c_char) {
+    let len = if string.is_null() { 0 } else { unsafe { std::ffi::CStr::from_ptr(string) }.to_bytes().len() };
+    _send(context, (err, len as i32));
+}
+
+#[test]
+fn ctx_callbacks_work() {
+    let (sender, receiver): (Results, _) = channel();
+    let context = &sender as *const Results as *const c_void;
+    let wait = || receiver.recv_timeout(Duration::from_secs(10)).unwrap();
+
+    let path = std::env::temp_dir().join("indy_callback_context");
+    let config = format!(r#"{{"id": "ctx_callbacks_work", "storage_config": {{"path": "{}"}}}}"#, path.to_str().unwrap());
+    let config = CString::new(config).unwrap();
+    let credentials = CString::new(WALLET_CREDENTIALS).unwrap();
+
+    unsafe { indy_delete_wallet_ctx(context, config.as_ptr(), credentials.as_ptr(), Some(_empty_cb)) };
+    wait();
+
+    assert_eq!(0, unsafe { indy_create_wallet_ctx(context, config.as_ptr(), credentials.as_ptr(), Some(_empty_cb)) });
+    assert_eq!(0, wait().0);
+
+    assert_eq!(0, unsafe { indy_open_wallet_ctx(context, config.as_ptr(), credentials.as_ptr(), Some(_handle_cb)) });
+    let (err, wallet_handle) = wait();
+    assert_eq!(0, err);
+
+    let type_ = CString::new("ctx_type").unwrap();
+    let id = CString::new("ctx_id").unwrap();
+    let value = CString::new("ctx_value").unwrap();
+    let empty_json = CString::new("{}").unwrap();
+
+    assert_eq!(0, unsafe { indy_add_wallet_record_ctx(context, wallet_handle, type_.as_ptr(), id.as_ptr(), value.as_ptr(), empty_json.as_ptr(), Some(_empty_cb)) });
+    assert_eq!(0, wait().0);
+
+    assert_eq!(0, unsafe { indy_stream_wallet_records_ctx(context, wallet_handle, type_.as_ptr(), empty_json.as_ptr(), empty_json.as_ptr(), 10, Some(_records_cb), Some(_empty_cb)) });
+    let (marker, records_len) = wait();
+    assert_eq!(-1, marker);
+    assert!(records_len > 0);
+    assert_eq!((0, 0), wait());
+
+    assert_eq!(0, unsafe { indy_close_wallet_ctx(context, wallet_handle, Some(_empty_cb)) });
+    assert_eq!(0, wait().0);
+
+    assert_eq!(0, unsafe { indy_delete_wallet_ctx(context, config.as_ptr(), credentials.as_ptr(), Some(_empty_cb)) });
+    assert_eq!(0, wait().0);
+}
+
+#[test]
+fn ctx_callbacks_work_for_sync_error() {
+    let credentials = CString::new(WALLET_CREDENTIALS).unwrap();
+
+    // CommonInvalidParam2
+    assert_eq!(101, unsafe { indy_open_wallet_ctx(ptr::null(), ptr::null(), credentials.as_ptr(), Some(_handle_cb)) });
+    // CommonInvalidParam4
+    assert_eq!(103, unsafe { indy_open_wallet_ctx(ptr::null(), ptr::null(), credentials.as_ptr(), None) });
+}
+
+#[cfg(feature = "anoncreds")]
+#[test]
+fn ctx_callbacks_work_for_generated_variant() {
+    let (sender, receiver): (Results, _) = channel();
+    let context = &sender as *const Results as *const c_void;
+
+    assert_eq!(0, unsafe { indy_generate_nonce_ctx(context, Some(_string_cb)) });
+    let (err, nonce_len) = receiver.recv_timeout(Duration::from_secs(10)).unwrap();
+    assert_eq!(0, err);
+    assert!(nonce_len > 0);
+
+    // CommonInvalidParam2
+    assert_eq!(101, unsafe { indy_generate_nonce_ctx(context, None) });
+}
+
+extern {
+    pub fn indy_create_wallet_ctx(context: *const c_void, config: *const c_char, credentials: *const c_char,
+                                  cb: Option<extern fn(context: *const c_void, err: i32)>) -> i32;
+
+    pub fn indy_open_wallet_ctx(context: *const c_void, config: *const c_char, credentials: *const c_char,
+                                cb: Option<extern fn(context: *const c_void, err: i32, wallet_handle: i32)>) -> i32;
+
+    pub fn indy_close_wallet_ctx(context: *const c_void, wallet_handle: i32,
+                                 cb: Option<extern fn(context: *const c_void, err: i32)>) -> i32;
+
+    pub fn indy_add_wallet_record_ctx(context: *const c_void, wallet_handle: i32, type_: *const c_char, id: *const c_char,
+                                      value: *const c_char, tags_json: *const c_char,
+                                      cb: Option<extern fn(context: *const c_void, err: i32)>) -> i32;
+
+    pub fn indy_stream_wallet_records_ctx(context: *const c_void, wallet_handle: i32, type_: *const c_char, query_json: *const c_char,
+                                          options_json: *const c_char, chunk_size: u32,
+                                          records_cb: Option<extern fn(context: *const c_void, records_json: *const c_char)>,
+                                          cb: Option<extern fn(context: *const c_void, err: i32)>) -> i32;
+
+    pub fn indy_delete_wallet_ctx(context: *const c_void, config: *const c_char, credentials: *const c_char,
+                                  cb: Option<extern fn(context: *const c_void, err: i32)>) -> i32;
+
+    #[cfg(feature = "anoncreds")]
+    pub fn indy_generate_nonce_ctx(context: *const c_void,
+                                   cb: Option<extern fn(context: *const c_void, err: i32, nonce: *const c_char)>) -> i32;
+}
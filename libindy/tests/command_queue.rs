@@ -0,0 +1,67 @@
+extern crate indyrs as indy;
+extern crate futures;
+
+use std::ffi::CString;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::Duration;
+
+use futures::Future;
+use indy::{CommandHandle, ErrorCode, WalletHandle};
+use libc::c_char;
+
+static REJECTED_CALLBACKS: AtomicUsize = AtomicUsize::new(0);
+
+// Keeps the executor busy so the next commands stay in the queue
+extern fn _slow_pre_command_hook(_command_name: *const c_char, _params_digest: *const c_char) {
+    thread::sleep(Duration::from_millis(300));
+}
+
+extern fn _rejected_cb(_command_handle: CommandHandle, _err: ErrorCode, _record_json: *const c_char) {
+    REJECTED_CALLBACKS.fetch_add(1, Ordering::SeqCst);
+}
+
+fn _get_record() -> Box<dyn Future<Item=String, Error=indy::IndyError>> {
+    indy::wallet::get_wallet_record(WalletHandle(-1), "type", "id", "{}")
+}
+
+#[test]
+fn max_pending_commands_works() {
+    assert_eq!(ErrorCode::Success, indy::set_runtime_config(r#"{"max_pending_commands": 1, "queue_full_timeout_ms": 0}"#));
+    assert_eq!(ErrorCode::Success, indy::register_command_hook(Some(_slow_pre_command_hook), None));
+
+    let executed = _get_record();
+    thread::sleep(Duration::from_millis(100));
+    let queued = _get_record();
+
+    let rejected = _get_record().wait().unwrap_err();
+    assert_eq!(ErrorCode::CommonQueueFull, rejected.error_code);
+
+    // callback of rejected call is never called: neither by timeout nor by the result
+    assert_eq!(ErrorCode::Success, indy::set_runtime_config(r#"{"command_timeout_ms": 100}"#));
+    let (type_, id, options) = (CString::new("type").unwrap(), CString::new("id").unwrap(), CString::new("{}").unwrap());
+    let err = unsafe { indy_get_wallet_record(1, WalletHandle(-1), type_.as_ptr(), id.as_ptr(), options.as_ptr(), Some(_rejected_cb)) };
+    assert_eq!(ErrorCode::CommonQueueFull, err);
+    assert_eq!(ErrorCode::Success, indy::set_runtime_config(r#"{"command_timeout_ms": 0}"#));
+
+    // waits for the queued command to be taken by the executor
+    assert_eq!(ErrorCode::Success, indy::set_runtime_config(r#"{"queue_full_timeout_ms": 5000}"#));
+    let waited = _get_record();
+
+    assert_eq!(ErrorCode::WalletInvalidHandle, executed.wait().unwrap_err().error_code);
+    assert_eq!(ErrorCode::WalletInvalidHandle, queued.wait().unwrap_err().error_code);
+    assert_eq!(ErrorCode::WalletInvalidHandle, waited.wait().unwrap_err().error_code);
+
+    thread::sleep(Duration::from_millis(200));
+    assert_eq!(0, REJECTED_CALLBACKS.load(Ordering::SeqCst));
+
+    indy::register_command_hook(None, None);
+    indy::set_runtime_config(r#"{"max_pending_commands": 0}"#);
+}
+
+extern {
+    #[no_mangle]
+    fn indy_get_wallet_record(command_handle: CommandHandle, wallet_handle: WalletHandle, type_: *const c_char, id: *const c_char,
+                              options_json: *const c_char,
+                              cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode, record_json: *const c_char)>) -> ErrorCode;
+}
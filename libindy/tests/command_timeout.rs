@@ -0,0 +1,68 @@
+extern crate indyrs as indy;
+extern crate futures;
+#[macro_use]
+extern crate lazy_static;
+
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+use futures::Future;
+use indy::{ErrorCode, WalletHandle};
+use libc::c_char;
+
+lazy_static! {
+    // Runtime config and command hooks are global, so the tests are run one by one
+    static ref CONFIG_LOCK: Mutex<()> = Mutex::new(());
+}
+
+// Emulates hung plugin blocking the executor
+extern fn _slow_pre_command_hook(_command_name: *const c_char, _params_digest: *const c_char) {
+    thread::sleep(Duration::from_millis(500));
+}
+
+#[test]
+fn command_timeout_works() {
+    let _lock = CONFIG_LOCK.lock().unwrap();
+
+    assert_eq!(ErrorCode::Success, indy::set_runtime_config(r#"{"command_timeout_ms": 100}"#));
+    assert_eq!(ErrorCode::Success, indy::register_command_hook(Some(_slow_pre_command_hook), None));
+
+    let res = indy::wallet::get_wallet_record(WalletHandle(-1), "type", "id", "{}").wait();
+    assert_eq!(ErrorCode::CommonTimeout, res.unwrap_err().error_code);
+
+    indy::register_command_hook(None, None);
+    indy::set_runtime_config(r#"{"command_timeout_ms": 0}"#);
+
+    let res = indy::wallet::get_wallet_record(WalletHandle(-1), "type", "id", "{}").wait();
+    assert_eq!(ErrorCode::WalletInvalidHandle, res.unwrap_err().error_code);
+}
+
+#[test]
+fn command_timeout_works_for_closing_wallet_search() {
+    let _lock = CONFIG_LOCK.lock().unwrap();
+
+    let config = r#"{"id":"command_timeout_search_wallet"}"#;
+    let credentials = r#"{"key":"8dvfYSt5d1taSd6yJdpjq4emkwsPDDLYxkNFysFD2cZY", "key_derivation_method":"RAW"}"#;
+    let _ = indy::wallet::delete_wallet(config, credentials).wait();
+    indy::wallet::create_wallet(config, credentials).wait().unwrap();
+    let wallet_handle = indy::wallet::open_wallet(config, credentials).wait().unwrap();
+    indy::wallet::add_wallet_record(wallet_handle, "type", "id", "value", None).wait().unwrap();
+    let search_handle = indy::wallet::open_wallet_search(wallet_handle, "type", "{}", "{}").wait().unwrap();
+
+    assert_eq!(ErrorCode::Success, indy::set_runtime_config(r#"{"command_timeout_ms": 100}"#));
+    assert_eq!(ErrorCode::Success, indy::register_command_hook(Some(_slow_pre_command_hook), None));
+
+    let res = indy::wallet::fetch_wallet_search_next_records(wallet_handle, search_handle, 1).wait();
+    assert_eq!(ErrorCode::CommonTimeout, res.unwrap_err().error_code);
+
+    indy::register_command_hook(None, None);
+    indy::set_runtime_config(r#"{"command_timeout_ms": 0}"#);
+
+    // search is closed once the fetching of the cancelled call is executed
+    let res = indy::wallet::fetch_wallet_search_next_records(wallet_handle, search_handle, 1).wait();
+    assert_eq!(ErrorCode::WalletInvalidHandle, res.unwrap_err().error_code);
+
+    indy::wallet::close_wallet(wallet_handle).wait().unwrap();
+    indy::wallet::delete_wallet(config, credentials).wait().unwrap();
+}
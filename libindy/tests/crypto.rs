@@ -104,6 +104,54 @@ mod high_cases {
         }
     }
 
+    mod audit {
+        use super::*;
+        use std::ffi::CStr;
+        use std::ptr;
+        use std::sync::Mutex;
+        use libc::{c_char, c_void};
+        use serde_json::Value;
+
+        lazy_static! {
+            static ref AUDIT_EVENTS: Mutex<Vec<Value>> = Mutex::new(Vec::new());
+        }
+
+        extern fn _audit_cb(_context: *const c_void, event_json: *const c_char) {
+            let event_json = unsafe { CStr::from_ptr(event_json) }.to_str().unwrap();
+            AUDIT_EVENTS.lock().unwrap().push(serde_json::from_str(event_json).unwrap());
+        }
+
+        #[test]
+        fn indy_audit_works_for_create_key_and_sign() {
+            let setup = Setup::wallet();
+            let context = json!({"request_id": "indy_audit_works_for_create_key_and_sign"});
+
+            assert_eq!(ErrorCode::Success, indy::register_audit_callback(ptr::null(), Some(_audit_cb)));
+            assert_eq!(ErrorCode::Success, indy::set_audit_context(Some(&context.to_string())));
+
+            let my_vk = crypto::create_key(setup.wallet_handle, Some(MY1_SEED)).unwrap();
+            crypto::sign(setup.wallet_handle, &my_vk, MESSAGE.as_bytes()).unwrap();
+
+            indy::set_audit_context(None);
+
+            let events: Vec<Value> = AUDIT_EVENTS.lock().unwrap().iter()
+                .filter(|event| event["context"] == context)
+                .cloned()
+                .collect();
+
+            assert_eq!(2, events.len());
+            assert_eq!("key_created", events[0]["event"]);
+            assert_eq!(my_vk, events[0]["details"]["verkey"]);
+            assert_eq!("message_signed", events[1]["event"]);
+            assert_eq!(setup.wallet_handle.0, events[1]["wallet_handle"]);
+        }
+
+        #[test]
+        fn indy_set_audit_context_works_for_invalid_json() {
+            assert_eq!(ErrorCode::CommonInvalidStructure, indy::set_audit_context(Some("{not json")));
+        }
+    }
+
     mod crypto_verify {
         use super::*;
 
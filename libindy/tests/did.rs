@@ -6,7 +6,7 @@ inject_indy_dependencies!();
 extern crate indyrs as indy;
 extern crate indyrs as api;
 
-use crate::utils::{did, pool, ledger};
+use crate::utils::{did, pool, ledger, crypto, pairwise, wallet};
 use crate::utils::constants::*;
 use crate::utils::types::ResponseType;
 use crate::utils::Setup;
@@ -402,6 +402,27 @@ mod high_cases {
             assert_eq!(my_verkey.from_base58().unwrap().len(), 32);
         }
 
+        #[test]
+        fn indy_create_my_did_works_for_indy_namespace() {
+            let setup = Setup::wallet();
+
+            let my_did_json = json!({"method_name": "indy:sovrin:staging"}).to_string();
+            let (my_did, my_verkey) = did::create_my_did(setup.wallet_handle, &my_did_json).unwrap();
+
+            assert!(my_did.starts_with("did:indy:sovrin:staging:"));
+            assert_eq!(my_did.replace("did:indy:sovrin:staging:", "").from_base58().unwrap().len(), 16);
+            assert_eq!(my_verkey, did::key_for_local_did(setup.wallet_handle, &my_did).unwrap());
+        }
+
+        #[test]
+        fn indy_create_my_did_works_for_not_self_certifying_indy_did() {
+            let setup = Setup::wallet();
+
+            let my_did_json = json!({"did": format!("did:indy:sovrin:{}", DID)}).to_string();
+            let res = did::create_my_did(setup.wallet_handle, &my_did_json);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
         #[test]
         fn indy_create_my_did_works_for_several_dids_but_different_methods() {
             let setup = Setup::wallet();
@@ -619,6 +640,75 @@ mod high_cases {
         }
     }
 
+    mod rotate_key {
+        use super::*;
+
+        #[test]
+        fn indy_rotate_key_works() {
+            let setup = Setup::new_identity();
+
+            let new_verkey = did::rotate_key(setup.wallet_handle, setup.pool_handle, &setup.did, "{}", None).unwrap();
+            assert_ne!(setup.verkey, new_verkey);
+
+            let verkey = did::key_for_local_did(setup.wallet_handle, &setup.did).unwrap();
+            assert_eq!(new_verkey, verkey);
+
+            let schema_request = ledger::build_schema_request(&setup.did, SCHEMA_DATA).unwrap();
+            let response = ledger::sign_and_submit_request(setup.pool_handle, setup.wallet_handle, &setup.did, &schema_request).unwrap();
+            pool::check_response_type(&response, ResponseType::REPLY);
+        }
+
+        #[test]
+        fn indy_rotate_key_works_for_endorser() {
+            let setup = Setup::new_identity();
+
+            let (author_did, author_verkey) = did::create_store_and_publish_did(setup.wallet_handle, setup.pool_handle, "TRUSTEE", None).unwrap();
+
+            let new_verkey = did::rotate_key(setup.wallet_handle, setup.pool_handle, &author_did, "{}", Some(&setup.did)).unwrap();
+            assert_ne!(author_verkey, new_verkey);
+
+            let verkey = did::key_for_local_did(setup.wallet_handle, &author_did).unwrap();
+            assert_eq!(new_verkey, verkey);
+        }
+
+        #[test]
+        fn indy_rotate_key_works_for_rollback_on_failed_ledger_write() {
+            let setup = Setup::did();
+
+            let res = did::rotate_key(setup.wallet_handle, INVALID_POOL_HANDLE, &setup.did, "{}", None);
+            assert_code!(ErrorCode::PoolLedgerInvalidPoolHandle, res);
+
+            let verkey = did::key_for_local_did(setup.wallet_handle, &setup.did).unwrap();
+            assert_eq!(setup.verkey, verkey);
+
+            let did_with_meta = did::get_my_did_with_metadata(setup.wallet_handle, &setup.did).unwrap();
+            let did_with_meta: serde_json::Value = serde_json::from_str(&did_with_meta).unwrap();
+            assert!(did_with_meta["tempVerkey"].is_null());
+
+            let res = did::replace_keys_apply(setup.wallet_handle, &setup.did);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+
+            // rotation can be started again
+            did::replace_keys_start(setup.wallet_handle, &setup.did, "{}").unwrap();
+        }
+
+        #[test]
+        fn indy_rotate_key_works_for_unknown_did() {
+            let setup = Setup::wallet();
+
+            let res = did::rotate_key(setup.wallet_handle, INVALID_POOL_HANDLE, DID, "{}", None);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+
+        #[test]
+        fn indy_rotate_key_works_for_invalid_endorser_did() {
+            let setup = Setup::did();
+
+            let res = did::rotate_key(setup.wallet_handle, INVALID_POOL_HANDLE, &setup.did, "{}", Some(INVALID_BASE58_DID));
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
+
     mod abbreviate_verkey {
         use super::*;
 
@@ -748,341 +838,1412 @@ mod high_cases {
             }
         }
     }
-}
-
-#[cfg(not(feature = "only_high_cases"))]
-mod medium_cases {
-    use super::*;
 
-    mod key_for_did {
+    mod get_did_doc {
         use super::*;
 
         #[test]
-        fn indy_key_for_did_works_for_invalid_pool_handle() {
-            let setup = Setup::wallet();
+        fn indy_get_did_doc_works_for_my_did() {
+            let setup = Setup::did();
 
-            let res = did::key_for_did(INVALID_POOL_HANDLE, setup.wallet_handle, DID_TRUSTEE);
-            assert_code!(ErrorCode::PoolLedgerInvalidPoolHandle, res);
+            let did_doc = did::get_did_doc(setup.wallet_handle, INVALID_POOL_HANDLE, &setup.did).unwrap();
+            let did_doc: serde_json::Value = serde_json::from_str(&did_doc).unwrap();
+
+            let id = format!("{}{}", DEFAULT_PREFIX, setup.did);
+            assert_eq!(json!("https://www.w3.org/ns/did/v1"), did_doc["@context"]);
+            assert_eq!(json!(id), did_doc["id"]);
+            assert_eq!(json!(setup.verkey), did_doc["verificationMethod"][0]["publicKeyBase58"]);
+            assert_eq!(json!("Ed25519VerificationKey2018"), did_doc["verificationMethod"][0]["type"]);
+            assert_eq!(json!([format!("{}#key-1", id)]), did_doc["authentication"]);
+            assert_eq!(json!("X25519KeyAgreementKey2019"), did_doc["keyAgreement"][0]["type"]);
+            assert_ne!(json!(setup.verkey), did_doc["keyAgreement"][0]["publicKeyBase58"]);
+            assert_eq!(json!([]), did_doc["service"]);
         }
 
         #[test]
-        fn indy_key_for_did_works_for_invalid_wallet_handle() {
-            Setup::empty();
-
-            let res = did::key_for_did(-1, INVALID_WALLET_HANDLE, DID);
-            assert_code!(ErrorCode::WalletInvalidHandle, res);
-        }
-    }
+        fn indy_get_did_doc_works_for_my_did_with_endpoint() {
+            let setup = Setup::did();
 
-    mod key_for_local_did {
-        use super::*;
+            did::set_endpoint_for_did(setup.wallet_handle, &setup.did, ENDPOINT, VERKEY).unwrap();
 
-        #[test]
-        fn indy_key_for_local_did_works_for_invalid_wallet_handle() {
-            Setup::empty();
+            let did_doc = did::get_did_doc(setup.wallet_handle, INVALID_POOL_HANDLE, &setup.did).unwrap();
+            let did_doc: serde_json::Value = serde_json::from_str(&did_doc).unwrap();
 
-            let res = did::key_for_local_did(INVALID_WALLET_HANDLE, DID_TRUSTEE);
-            assert_code!(ErrorCode::WalletInvalidHandle, res);
+            assert_eq!(json!(ENDPOINT), did_doc["service"][0]["serviceEndpoint"]);
+            assert_eq!(json!("did-communication"), did_doc["service"][0]["type"]);
+            assert_eq!(json!([VERKEY]), did_doc["service"][0]["recipientKeys"]);
         }
-    }
-
-    mod set_endpoint_for_did {
-        use super::*;
 
         #[test]
-        fn indy_set_endpoint_for_did_works_for_replace() {
-            let setup = Setup::wallet_and_pool();
+        fn indy_get_did_doc_works_for_their_did_with_endpoint() {
+            let setup = Setup::wallet();
 
-            did::set_endpoint_for_did(setup.wallet_handle, DID, ENDPOINT, VERKEY).unwrap();
-            let (endpoint, key) = did::get_endpoint_for_did(setup.wallet_handle, setup.pool_handle, DID).unwrap();
-            assert_eq!(ENDPOINT, endpoint);
-            assert_eq!(VERKEY, key.unwrap());
+            did::store_their_did(setup.wallet_handle, &json!({"did": DID, "verkey": VERKEY}).to_string()).unwrap();
+            did::set_endpoint_for_did(setup.wallet_handle, DID, ENDPOINT, VERKEY_TRUSTEE).unwrap();
 
-            let new_endpoint = "10.10.10.1:9710";
-            did::set_endpoint_for_did(setup.wallet_handle, DID, new_endpoint, VERKEY_MY2).unwrap();
-            let (updated_endpoint, updated_key) = did::get_endpoint_for_did(setup.wallet_handle, setup.pool_handle, DID).unwrap();
-            assert_eq!(new_endpoint, updated_endpoint);
-            assert_eq!(VERKEY_MY2, updated_key.unwrap());
-        }
-    }
+            let did_doc = did::get_did_doc(setup.wallet_handle, INVALID_POOL_HANDLE, DID).unwrap();
+            let did_doc: serde_json::Value = serde_json::from_str(&did_doc).unwrap();
 
-    mod get_did_metadata {
-        use super::*;
+            assert_eq!(json!(format!("{}{}", DEFAULT_PREFIX, DID)), did_doc["id"]);
+            assert_eq!(json!(VERKEY), did_doc["verificationMethod"][0]["publicKeyBase58"]);
+            assert_eq!(json!([VERKEY_TRUSTEE]), did_doc["service"][0]["recipientKeys"]);
+        }
 
         #[test]
-        fn indy_get_did_metadata_works_for_empty_string() {
-            let setup = Setup::did();
+        fn indy_get_did_doc_works_for_fully_qualified_did() {
+            let setup = Setup::wallet();
 
-            did::set_did_metadata(setup.wallet_handle, &setup.did, "").unwrap();
-            let metadata = did::get_did_metadata(setup.wallet_handle, &setup.did).unwrap();
-            assert_eq!("", metadata);
+            did::store_their_did(setup.wallet_handle, &json!({"did": DID_V1, "verkey": VERKEY}).to_string()).unwrap();
+            did::set_endpoint_for_did(setup.wallet_handle, DID_V1, ENDPOINT, VERKEY).unwrap();
+
+            let did_doc = did::get_did_doc(setup.wallet_handle, INVALID_POOL_HANDLE, DID_V1).unwrap();
+            let did_doc: serde_json::Value = serde_json::from_str(&did_doc).unwrap();
+
+            assert_eq!(json!(DID_V1), did_doc["id"]);
+            assert_eq!(json!(format!("{}#key-1", DID_V1)), did_doc["verificationMethod"][0]["id"]);
         }
 
         #[test]
-        fn indy_get_did_metadata_works_for_invalid_handle() {
-            Setup::empty();
+        fn indy_get_did_doc_works_from_ledger() {
+            let setup = Setup::new_identity();
 
-            let res = did::get_did_metadata(INVALID_WALLET_HANDLE, DID);
-            assert_code!(ErrorCode::WalletInvalidHandle, res);
-        }
-    }
+            let attrib_data = json!({"endpoint": {"ha": ENDPOINT}}).to_string();
+            let attrib_request = ledger::build_attrib_request(&setup.did, &setup.did, None, Some(&attrib_data), None).unwrap();
+            ledger::sign_and_submit_request(setup.pool_handle, setup.wallet_handle, &setup.did, &attrib_request).unwrap();
 
-    mod create_my_did {
-        use super::*;
+            thread::sleep(std::time::Duration::from_secs(1));
 
-        #[test]
-        fn indy_create_my_did_works_as_cid() {
-            let setup = Setup::wallet();
+            let (wallet_handle, wallet_config) = utils::wallet::create_and_open_default_wallet("indy_get_did_doc_works_from_ledger").unwrap();
 
-            let (my_did, my_verkey) = did::create_my_did(setup.wallet_handle, r#"{"seed":"aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa","cid":true}"#).unwrap();
-            assert_eq!(my_did, VERKEY);
-            assert_eq!(my_verkey, VERKEY);
-        }
+            let did_doc = did::get_did_doc(wallet_handle, setup.pool_handle, &setup.did).unwrap();
+            let did_doc: serde_json::Value = serde_json::from_str(&did_doc).unwrap();
 
-        #[test]
-        fn indy_create_my_did_works_with_passed_did() {
-            let setup = Setup::wallet();
+            assert_eq!(json!(setup.verkey), did_doc["verificationMethod"][0]["publicKeyBase58"]);
+            assert_eq!(json!(ENDPOINT), did_doc["service"][0]["serviceEndpoint"]);
 
-            let (my_did, my_verkey) = did::create_my_did(setup.wallet_handle, &format!(r#"{{"did":"{}","seed":"aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"}}"#, DID)).unwrap();
-            assert_eq!(my_did, DID);
-            assert_eq!(my_verkey, VERKEY);
+            utils::wallet::close_and_delete_wallet(wallet_handle, &wallet_config).unwrap();
         }
 
         #[test]
-        fn indy_create_my_did_works_for_exists_crypto_type() {
+        fn indy_get_did_doc_works_invalid_pool_handle_for_unknown_did() {
             let setup = Setup::wallet();
 
-            did::create_my_did(setup.wallet_handle, r#"{"crypto_type":"ed25519"}"#).unwrap();
+            let res = did::get_did_doc(setup.wallet_handle, INVALID_POOL_HANDLE, DID);
+            assert_code!(ErrorCode::PoolLedgerInvalidPoolHandle, res);
         }
 
         #[test]
-        fn indy_create_my_did_works_for_invalid_wallet_handle() {
+        fn indy_get_did_doc_works_invalid_wallet_handle() {
             Setup::empty();
 
-            let res = did::create_my_did(INVALID_WALLET_HANDLE, "{}");
+            let res = did::get_did_doc(INVALID_WALLET_HANDLE, INVALID_POOL_HANDLE, DID);
             assert_code!(ErrorCode::WalletInvalidHandle, res);
         }
     }
 
-    mod replace_keys_start {
+    mod did_keys_and_services {
         use super::*;
 
         #[test]
-        fn indy_replace_keys_start_works_for_invalid_wallet_handle() {
-            Setup::empty();
+        fn indy_add_did_key_works() {
+            let setup = Setup::did();
 
-            let res = did::replace_keys_start(INVALID_WALLET_HANDLE, DID, "{}");
-            assert_code!(ErrorCode::WalletInvalidHandle, res);
+            let key_json = json!({"id": "key-2", "purpose": "assertionMethod", "verkey": VERKEY_MY1}).to_string();
+            did::add_did_key(setup.wallet_handle, &setup.did, &key_json).unwrap();
+
+            let key_json = json!({"id": "key-3", "purpose": "keyAgreement", "verkey": VERKEY_MY1}).to_string();
+            did::add_did_key(setup.wallet_handle, &setup.did, &key_json).unwrap();
+
+            let did_doc = did::get_did_doc(setup.wallet_handle, INVALID_POOL_HANDLE, &setup.did).unwrap();
+            let did_doc: serde_json::Value = serde_json::from_str(&did_doc).unwrap();
+
+            let id = format!("{}{}", DEFAULT_PREFIX, setup.did);
+            assert_eq!(json!(format!("{}#key-2", id)), did_doc["verificationMethod"][1]["id"]);
+            assert_eq!(json!(VERKEY_MY1), did_doc["verificationMethod"][1]["publicKeyBase58"]);
+            assert_eq!(json!([format!("{}#key-1", id), format!("{}#key-2", id)]), did_doc["assertionMethod"]);
+            assert_eq!(json!([format!("{}#key-1", id)]), did_doc["authentication"]);
+            assert_eq!(json!(format!("{}#key-3", id)), did_doc["keyAgreement"][1]["id"]);
+            assert_ne!(json!(VERKEY_MY1), did_doc["keyAgreement"][1]["publicKeyBase58"]);
         }
 
         #[test]
-        fn indy_replace_keys_start_works_for_seed() {
+        fn indy_remove_did_key_works() {
             let setup = Setup::did();
 
-            let new_verkey = did::replace_keys_start(setup.wallet_handle, &setup.did, r#"{"seed":"aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"}"#).unwrap();
-            assert_eq!(new_verkey, VERKEY);
-            assert_ne!(setup.verkey, new_verkey);
-        }
-    }
+            let key_json = json!({"id": "key-2", "purpose": "authentication", "verkey": VERKEY_MY1}).to_string();
+            did::add_did_key(setup.wallet_handle, &setup.did, &key_json).unwrap();
+            did::remove_did_key(setup.wallet_handle, &setup.did, "key-2").unwrap();
 
-    mod replace_keys_apply {
-        use super::*;
+            let did_doc = did::get_did_doc(setup.wallet_handle, INVALID_POOL_HANDLE, &setup.did).unwrap();
+            let did_doc: serde_json::Value = serde_json::from_str(&did_doc).unwrap();
+
+            assert_eq!(1, did_doc["verificationMethod"].as_array().unwrap().len());
+            assert_eq!(1, did_doc["authentication"].as_array().unwrap().len());
+        }
 
         #[test]
-        fn indy_replace_keys_apply_works_for_invalid_wallet_handle() {
-            Setup::empty();
+        fn indy_add_did_service_works() {
+            let setup = Setup::did();
 
-            let res = did::replace_keys_apply(INVALID_WALLET_HANDLE, DID);
-            assert_code!(ErrorCode::WalletInvalidHandle, res);
-        }
-    }
+            did::set_endpoint_for_did(setup.wallet_handle, &setup.did, ENDPOINT, VERKEY).unwrap();
 
-    mod store_their_did {
-        use super::*;
+            let service_json = json!({
+                "id": "mediator",
+                "type": "did-communication",
+                "serviceEndpoint": "https://mediator.example.com",
+                "recipientKeys": [VERKEY_MY1],
+                "routingKeys": [VERKEY_MY2],
+                "priority": 1
+            }).to_string();
+            did::add_did_service(setup.wallet_handle, &setup.did, &service_json).unwrap();
 
-        #[test]
-        fn indy_store_their_did_works_for_verkey_with_crypto_type() {
-            let setup = Setup::wallet();
+            let did_doc = did::get_did_doc(setup.wallet_handle, INVALID_POOL_HANDLE, &setup.did).unwrap();
+            let did_doc: serde_json::Value = serde_json::from_str(&did_doc).unwrap();
 
-            let identity_json = json!({"did": DID, "verkey": VERKEY.to_owned() + ":ed25519"}).to_string();
-            did::store_their_did(setup.wallet_handle, &identity_json).unwrap();
+            assert_eq!(2, did_doc["service"].as_array().unwrap().len());
+            assert_eq!(json!(ENDPOINT), did_doc["service"][0]["serviceEndpoint"]);
+            assert_eq!(json!(format!("{}{}#mediator", DEFAULT_PREFIX, setup.did)), did_doc["service"][1]["id"]);
+            assert_eq!(json!([VERKEY_MY2]), did_doc["service"][1]["routingKeys"]);
+            assert_eq!(json!(1), did_doc["service"][1]["priority"]);
         }
 
         #[test]
-        fn indy_create_my_did_works_for_invalid_seed() {
+        fn indy_add_did_service_works_for_their_did() {
             let setup = Setup::wallet();
 
-            let res = did::create_my_did(setup.wallet_handle, r#"{"seed":"seed"}"#);
-            assert_code!(ErrorCode::CommonInvalidStructure, res);
-        }
+            did::store_their_did(setup.wallet_handle, &json!({"did": DID, "verkey": VERKEY}).to_string()).unwrap();
+            did::set_endpoint_for_did(setup.wallet_handle, DID, ENDPOINT, VERKEY).unwrap();
 
-        #[test]
-        fn indy_store_their_did_works_for_invalid_wallet_handle() {
-            Setup::empty();
+            let service_json = json!({"id": "agent", "type": "IndyAgent", "serviceEndpoint": "http://localhost:8080"}).to_string();
+            did::add_did_service(setup.wallet_handle, DID, &service_json).unwrap();
 
-            let identity_json = json!({"did": DID}).to_string();
-            let res = did::store_their_did(INVALID_WALLET_HANDLE, &identity_json);
-            assert_code!(ErrorCode::WalletInvalidHandle, res);
+            let did_doc = did::get_did_doc(setup.wallet_handle, INVALID_POOL_HANDLE, DID).unwrap();
+            let did_doc: serde_json::Value = serde_json::from_str(&did_doc).unwrap();
+
+            assert_eq!(json!("IndyAgent"), did_doc["service"][1]["type"]);
+            assert_eq!(json!([]), did_doc["service"][1]["recipientKeys"]);
         }
 
         #[test]
-        fn indy_store_their_did_works_for_abbreviated_verkey() {
-            let setup = Setup::wallet();
+        fn indy_remove_did_service_works() {
+            let setup = Setup::did();
 
-            let identity_json = r#"{"did":"8wZcEriaNLNKtteJvx7f8i", "verkey":"~NcYxiDXkpYi6ov5FcYDi1e"}"#;
-            did::store_their_did(setup.wallet_handle, identity_json).unwrap();
+            let service_json = json!({"id": "agent", "type": "IndyAgent", "serviceEndpoint": "http://localhost:8080"}).to_string();
+            did::add_did_service(setup.wallet_handle, &setup.did, &service_json).unwrap();
+            did::remove_did_service(setup.wallet_handle, &setup.did, "agent").unwrap();
+
+            let did_doc = did::get_did_doc(setup.wallet_handle, INVALID_POOL_HANDLE, &setup.did).unwrap();
+            let did_doc: serde_json::Value = serde_json::from_str(&did_doc).unwrap();
+
+            assert_eq!(json!([]), did_doc["service"]);
         }
 
         #[test]
-        fn indy_store_their_did_works_for_abbreviated_verkey_for_fully_qualified() {
-            let setup = Setup::wallet();
+        fn indy_did_keys_and_services_work_after_qualify_did() {
+            let setup = Setup::did();
 
-            let identity_json = r#"{"did":"did:sov:8wZcEriaNLNKtteJvx7f8i", "verkey":"~NcYxiDXkpYi6ov5FcYDi1e"}"#;
-            did::store_their_did(setup.wallet_handle, identity_json).unwrap();
+            let key_json = json!({"id": "key-2", "purpose": "authentication", "verkey": VERKEY_MY1}).to_string();
+            did::add_did_key(setup.wallet_handle, &setup.did, &key_json).unwrap();
+
+            let full_qualified_did = did::qualify_did(setup.wallet_handle, &setup.did, DEFAULT_METHOD_NAME).unwrap();
+
+            let did_doc = did::get_did_doc(setup.wallet_handle, INVALID_POOL_HANDLE, &full_qualified_did).unwrap();
+            let did_doc: serde_json::Value = serde_json::from_str(&did_doc).unwrap();
+
+            assert_eq!(json!(format!("{}#key-2", full_qualified_did)), did_doc["verificationMethod"][1]["id"]);
         }
+    }
+
+    mod my_dids_search {
+        use super::*;
 
         #[test]
-        fn indy_create_my_did_works_for_invalid_json() {
+        fn indy_my_dids_search_works() {
             let setup = Setup::wallet();
 
-            let res = did::create_my_did(setup.wallet_handle, r#"{"seed":123}"#);
-            assert_code!(ErrorCode::CommonInvalidStructure, res);
+            for _ in 0..5 {
+                did::create_my_did(setup.wallet_handle, "{}").unwrap();
+            }
+
+            let (search_handle, total_count) = did::open_my_dids_search(setup.wallet_handle, None, None).unwrap();
+            assert_eq!(5, total_count);
+
+            let dids = did::fetch_my_dids(search_handle, 3).unwrap();
+            let dids: serde_json::Value = serde_json::from_str(&dids).unwrap();
+            assert_eq!(3, dids.as_array().unwrap().len());
+
+            let dids = did::fetch_my_dids(search_handle, 3).unwrap();
+            let dids: serde_json::Value = serde_json::from_str(&dids).unwrap();
+            assert_eq!(2, dids.as_array().unwrap().len());
+
+            did::close_my_dids_search(search_handle).unwrap();
         }
 
         #[test]
-        fn indy_store_their_did_works_for_invalid_did() {
+        fn indy_my_dids_search_works_for_query() {
             let setup = Setup::wallet();
 
-            let identity_json = json!({"did": INVALID_BASE58_DID}).to_string();
-            let res = did::store_their_did(setup.wallet_handle, &identity_json);
-            assert_code!(ErrorCode::CommonInvalidStructure, res);
+            let (did_1, verkey_1) = did::create_my_did(setup.wallet_handle, "{}").unwrap();
+            let (did_2, _) = did::create_my_did(setup.wallet_handle, "{}").unwrap();
+            did::create_my_did(setup.wallet_handle, "{}").unwrap();
+
+            did::set_my_did_tags(setup.wallet_handle, &did_1, &json!({"role": "pairwise", "~peer": "alice"}).to_string()).unwrap();
+            did::set_my_did_tags(setup.wallet_handle, &did_2, &json!({"role": "public"}).to_string()).unwrap();
+            did::set_did_metadata(setup.wallet_handle, &did_1, METADATA).unwrap();
+
+            let query = json!({"role": "pairwise", "~peer": {"$like": "ali%"}}).to_string();
+            let (search_handle, total_count) = did::open_my_dids_search(setup.wallet_handle, Some(&query), None).unwrap();
+            assert_eq!(1, total_count);
+
+            let dids = did::fetch_my_dids(search_handle, 10).unwrap();
+            let dids: serde_json::Value = serde_json::from_str(&dids).unwrap();
+            assert_eq!(json!([{"did": did_1, "verkey": verkey_1, "tempVerkey": null, "metadata": METADATA}]), dids);
+
+            did::close_my_dids_search(search_handle).unwrap();
         }
 
         #[test]
-        fn indy_store_their_did_works_for_invalid_verkey() {
+        fn indy_my_dids_search_works_for_metadata_query() {
             let setup = Setup::wallet();
 
-            let identity_json = json!({"did": "did", "verkey":"invalid_base58string"}).to_string();
-            let res = did::store_their_did(setup.wallet_handle, &identity_json);
-            assert_code!(ErrorCode::CommonInvalidStructure, res);
+            let (did_1, _) = did::create_my_did(setup.wallet_handle, "{}").unwrap();
+            let (did_2, _) = did::create_my_did(setup.wallet_handle, "{}").unwrap();
+
+            did::set_did_metadata(setup.wallet_handle, &did_1, &json!({"label": "Alice", "~peer": "alice"}).to_string()).unwrap();
+            did::set_did_metadata(setup.wallet_handle, &did_2, &json!({"label": "Bob"}).to_string()).unwrap();
+            did::set_my_did_tags(setup.wallet_handle, &did_1, &json!({"role": "pairwise"}).to_string()).unwrap();
+
+            let query = json!({"label": "Alice", "~peer": "alice", "role": "pairwise"}).to_string();
+            let (search_handle, total_count) = did::open_my_dids_search(setup.wallet_handle, Some(&query), None).unwrap();
+            assert_eq!(1, total_count);
+
+            let dids = did::fetch_my_dids(search_handle, 10).unwrap();
+            let dids: serde_json::Value = serde_json::from_str(&dids).unwrap();
+            assert_eq!(json!(did_1), dids[0]["did"]);
+            did::close_my_dids_search(search_handle).unwrap();
+
+            // tags of the previous metadata fields are replaced
+            did::set_did_metadata(setup.wallet_handle, &did_1, &json!({"label": "Carol"}).to_string()).unwrap();
+
+            let query = json!({"label": "Alice"}).to_string();
+            let (search_handle, total_count) = did::open_my_dids_search(setup.wallet_handle, Some(&query), None).unwrap();
+            assert_eq!(0, total_count);
+            did::close_my_dids_search(search_handle).unwrap();
+
+            let query = json!({"label": "Carol", "role": "pairwise"}).to_string();
+            let (search_handle, total_count) = did::open_my_dids_search(setup.wallet_handle, Some(&query), None).unwrap();
+            assert_eq!(1, total_count);
+            did::close_my_dids_search(search_handle).unwrap();
         }
 
         #[test]
-        fn indy_store_their_did_works_for_verkey_with_invalid_crypto_type() {
-            let setup = Setup::wallet();
+        fn indy_my_dids_search_works_for_skipped_metadata_and_temp_verkey() {
+            let setup = Setup::did();
 
-            let identity_json = json!({"did": DID, "verkey": VERKEY.to_owned() + ":crypto_type"}).to_string();
-            let res = did::store_their_did(setup.wallet_handle, &identity_json);
-            assert_code!(ErrorCode::UnknownCryptoTypeError, res);
+            did::set_did_metadata(setup.wallet_handle, &setup.did, METADATA).unwrap();
+            did::replace_keys_start(setup.wallet_handle, &setup.did, "{}").unwrap();
+
+            let options = json!({"retrieveMetadata": false, "retrieveTempVerkey": false}).to_string();
+            let (search_handle, _) = did::open_my_dids_search(setup.wallet_handle, None, Some(&options)).unwrap();
+
+            let dids = did::fetch_my_dids(search_handle, 10).unwrap();
+            let dids: serde_json::Value = serde_json::from_str(&dids).unwrap();
+            assert_eq!(json!(setup.did), dids[0]["did"]);
+            assert!(dids[0]["metadata"].is_null());
+            assert!(dids[0]["tempVerkey"].is_null());
+
+            did::close_my_dids_search(search_handle).unwrap();
         }
 
         #[test]
-        fn indy_store_my_did_works_for_is_802() {
-            let setup = Setup::wallet();
+        fn indy_my_dids_search_works_for_tags_after_qualify_did() {
+            let setup = Setup::did();
 
-            let identity_json = json!({"did": DID}).to_string();
+            did::set_my_did_tags(setup.wallet_handle, &setup.did, &json!({"role": "pairwise"}).to_string()).unwrap();
+            let full_qualified_did = did::qualify_did(setup.wallet_handle, &setup.did, DEFAULT_METHOD_NAME).unwrap();
 
-            // 1. Try 'createAndStoreMyDid' operation with say did1 and verkey1
-            did::create_my_did(setup.wallet_handle, &identity_json).unwrap();
+            let query = json!({"role": "pairwise"}).to_string();
+            let (search_handle, _) = did::open_my_dids_search(setup.wallet_handle, Some(&query), None).unwrap();
+
+            let dids = did::fetch_my_dids(search_handle, 10).unwrap();
+            let dids: serde_json::Value = serde_json::from_str(&dids).unwrap();
+            assert_eq!(json!(full_qualified_did), dids[0]["did"]);
+
+            did::close_my_dids_search(search_handle).unwrap();
+        }
+    }
+
+    mod export_import_did {
+        use super::*;
+
+        #[test]
+        fn indy_export_import_did_works() {
+            let setup = Setup::did();
+
+            let (wallet_handle, wallet_config) = wallet::create_and_open_default_wallet("indy_export_import_did_works").unwrap();
+            let recipient_vk = crypto::create_key(wallet_handle, None).unwrap();
+
+            did::set_did_metadata(setup.wallet_handle, &setup.did, METADATA).unwrap();
+            did::set_endpoint_for_did(setup.wallet_handle, &setup.did, ENDPOINT, VERKEY).unwrap();
+            did::set_my_did_tags(setup.wallet_handle, &setup.did, &json!({"role": "pairwise"}).to_string()).unwrap();
+            did::store_their_did_from_parts(setup.wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+            pairwise::create_pairwise(setup.wallet_handle, DID_TRUSTEE, &setup.did, Some(METADATA)).unwrap();
+            pairwise::set_pairwise_meta(setup.wallet_handle, DID_TRUSTEE, r#"{"state":"active"}"#).unwrap();
+
+            let bundle = did::export_did(setup.wallet_handle, &setup.did, &recipient_vk).unwrap();
+
+            let imported_did = did::import_did(wallet_handle, &recipient_vk, &bundle).unwrap();
+            assert_eq!(setup.did, imported_did);
+
+            let verkey = did::key_for_local_did(wallet_handle, &setup.did).unwrap();
+            assert_eq!(setup.verkey, verkey);
+
+            let metadata = did::get_did_metadata(wallet_handle, &setup.did).unwrap();
+            assert_eq!(METADATA, metadata);
+
+            let (endpoint, _) = did::get_endpoint_for_did(wallet_handle, -1, &setup.did).unwrap();
+            assert_eq!(ENDPOINT, endpoint);
+
+            let query = json!({"role": "pairwise"}).to_string();
+            let (search_handle, total_count) = did::open_my_dids_search(wallet_handle, Some(&query), None).unwrap();
+            assert_eq!(1, total_count);
+            did::close_my_dids_search(search_handle).unwrap();
+
+            let pairwise_json = pairwise::get_pairwise(wallet_handle, DID_TRUSTEE).unwrap();
+            assert_eq!(format!(r#"{{"my_did":"{}","metadata":"{}"}}"#, setup.did, METADATA), pairwise_json);
+
+            let (search_handle, total_count) = pairwise::open_pairwise_search(wallet_handle, Some(r#"{"state":"active"}"#), None).unwrap();
+            assert_eq!(1, total_count);
+            pairwise::close_pairwise_search(search_handle).unwrap();
+
+            let their_verkey = did::key_for_local_did(wallet_handle, DID_TRUSTEE).unwrap();
+            assert_eq!(VERKEY_TRUSTEE, their_verkey);
+
+            wallet::close_and_delete_wallet(wallet_handle, &wallet_config).unwrap();
+        }
+
+        #[test]
+        fn indy_export_import_did_works_for_signing_with_imported_key() {
+            let setup = Setup::did();
+
+            let (wallet_handle, wallet_config) = wallet::create_and_open_default_wallet("indy_export_import_did_works_for_signing_with_imported_key").unwrap();
+            let recipient_vk = crypto::create_key(wallet_handle, None).unwrap();
+
+            let bundle = did::export_did(setup.wallet_handle, &setup.did, &recipient_vk).unwrap();
+            did::import_did(wallet_handle, &recipient_vk, &bundle).unwrap();
+
+            let signature = crypto::sign(wallet_handle, &setup.verkey, MESSAGE.as_bytes()).unwrap();
+            assert!(crypto::verify(&setup.verkey, MESSAGE.as_bytes(), &signature).unwrap());
+
+            wallet::close_and_delete_wallet(wallet_handle, &wallet_config).unwrap();
+        }
+    }
+
+    mod deactivate_did {
+        use super::*;
+
+        #[test]
+        fn indy_deactivate_did_works() {
+            let setup = Setup::did();
+
+            did::deactivate_did(setup.wallet_handle, &setup.did).unwrap();
+
+            let res = ledger::sign_request(setup.wallet_handle, &setup.did, REQUEST);
+            assert_code!(ErrorCode::DidDeactivatedError, res);
+
+            let did_with_meta = did::get_my_did_with_metadata(setup.wallet_handle, &setup.did).unwrap();
+            let did_with_meta: serde_json::Value = serde_json::from_str(&did_with_meta).unwrap();
+            assert_eq!(json!(true), did_with_meta["deactivated"]);
+        }
+
+        #[test]
+        fn indy_force_sign_request_works_for_deactivated_did() {
+            let setup = Setup::did();
+
+            did::deactivate_did(setup.wallet_handle, &setup.did).unwrap();
+
+            let signed_request = ledger::force_sign_request(setup.wallet_handle, &setup.did, REQUEST).unwrap();
+            let signed_request: serde_json::Value = serde_json::from_str(&signed_request).unwrap();
+            assert!(signed_request["signature"].is_string());
+        }
+
+        #[test]
+        fn indy_get_did_doc_works_for_deactivated_did() {
+            let setup = Setup::did();
+
+            let did_doc = did::get_did_doc(setup.wallet_handle, INVALID_POOL_HANDLE, &setup.did).unwrap();
+            let did_doc: serde_json::Value = serde_json::from_str(&did_doc).unwrap();
+            assert!(did_doc.get("deactivated").is_none());
+
+            did::deactivate_did(setup.wallet_handle, &setup.did).unwrap();
+
+            let did_doc = did::get_did_doc(setup.wallet_handle, INVALID_POOL_HANDLE, &setup.did).unwrap();
+            let did_doc: serde_json::Value = serde_json::from_str(&did_doc).unwrap();
+            assert_eq!(json!(true), did_doc["deactivated"]);
+        }
+
+        #[test]
+        fn indy_publish_did_deactivation_works() {
+            let setup = Setup::new_identity();
+
+            let (did, _) = did::create_store_and_publish_did(setup.wallet_handle, setup.pool_handle, "TRUSTEE", None).unwrap();
+
+            let response = did::publish_did_deactivation(setup.wallet_handle, setup.pool_handle, &did).unwrap();
+            pool::check_response_type(&response, ResponseType::REPLY);
+
+            let res = ledger::sign_request(setup.wallet_handle, &did, REQUEST);
+            assert_code!(ErrorCode::DidDeactivatedError, res);
+        }
+    }
+
+    mod qualify_all_dids {
+        use super::*;
+
+        #[test]
+        fn qualify_all_dids_works() {
+            let setup = Setup::did();
+
+            let (did_2, verkey_2) = did::create_my_did(setup.wallet_handle, "{}").unwrap();
+            did::store_their_did_from_parts(setup.wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+            pairwise::create_pairwise(setup.wallet_handle, DID_TRUSTEE, &setup.did, Some(METADATA)).unwrap();
+            pairwise::set_pairwise_meta(setup.wallet_handle, DID_TRUSTEE, r#"{"state":"active"}"#).unwrap();
+            did::set_did_metadata(setup.wallet_handle, DID_TRUSTEE, METADATA).unwrap();
+
+            let report = did::qualify_all_dids(setup.wallet_handle, DEFAULT_METHOD_NAME, None).unwrap();
+            let report: serde_json::Value = serde_json::from_str(&report).unwrap();
+
+            let qualified_did = format!("{}{}", DEFAULT_PREFIX, setup.did);
+            let qualified_did_2 = format!("{}{}", DEFAULT_PREFIX, did_2);
+            let qualified_their_did = format!("{}{}", DEFAULT_PREFIX, DID_TRUSTEE);
+
+            assert_eq!(json!(false), report["dryRun"]);
+            assert_eq!(2, report["myDids"].as_array().unwrap().len());
+            assert_eq!(json!([{"did": DID_TRUSTEE, "qualifiedDid": qualified_their_did}]), report["theirDids"]);
+            assert_eq!(json!(1), report["pairwiseCount"]);
+
+            assert_eq!(setup.verkey, did::key_for_local_did(setup.wallet_handle, &qualified_did).unwrap());
+            assert_eq!(verkey_2, did::key_for_local_did(setup.wallet_handle, &qualified_did_2).unwrap());
+            assert_eq!(VERKEY_TRUSTEE, did::key_for_local_did(setup.wallet_handle, &qualified_their_did).unwrap());
+            assert_eq!(METADATA, did::get_did_metadata(setup.wallet_handle, &qualified_their_did).unwrap());
+
+            let pairwise_json = pairwise::get_pairwise(setup.wallet_handle, &qualified_their_did).unwrap();
+            assert_eq!(format!(r#"{{"my_did":"{}","metadata":"{}"}}"#, qualified_did, METADATA), pairwise_json);
+
+            let query = json!({"state": "active", "my_did": qualified_did}).to_string();
+            let (search_handle, total_count) = pairwise::open_pairwise_search(setup.wallet_handle, Some(&query), None).unwrap();
+            assert_eq!(1, total_count);
+            pairwise::close_pairwise_search(search_handle).unwrap();
+
+            let res = did::key_for_local_did(setup.wallet_handle, &setup.did);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+
+        #[test]
+        fn qualify_all_dids_works_for_dry_run() {
+            let setup = Setup::did();
+
+            let report = did::qualify_all_dids(setup.wallet_handle, DEFAULT_METHOD_NAME, Some(r#"{"dryRun": true}"#)).unwrap();
+            let report: serde_json::Value = serde_json::from_str(&report).unwrap();
+
+            assert_eq!(json!(true), report["dryRun"]);
+            assert_eq!(json!([{"did": setup.did, "qualifiedDid": format!("{}{}", DEFAULT_PREFIX, setup.did)}]), report["myDids"]);
+
+            assert_eq!(setup.verkey, did::key_for_local_did(setup.wallet_handle, &setup.did).unwrap());
+
+            let res = did::key_for_local_did(setup.wallet_handle, &format!("{}{}", DEFAULT_PREFIX, setup.did));
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+    }
+
+    mod named_endpoints {
+        use super::*;
+
+        fn named_endpoint_json(name: &str) -> String {
+            json!({
+                "name": name,
+                "endpoint": "wss://agent.example.com",
+                "transport": "wss",
+                "routingKeys": [VERKEY_MY2],
+                "priority": 1
+            }).to_string()
+        }
+
+        #[test]
+        fn indy_set_named_endpoint_for_did_works() {
+            let setup = Setup::did();
+
+            did::set_named_endpoint_for_did(setup.wallet_handle, &setup.did, &named_endpoint_json("agent")).unwrap();
+
+            let endpoints = did::get_named_endpoints_for_did(setup.wallet_handle, INVALID_POOL_HANDLE, &setup.did).unwrap();
+            let endpoints: serde_json::Value = serde_json::from_str(&endpoints).unwrap();
+
+            assert_eq!(json!([{
+                "name": "agent",
+                "endpoint": "wss://agent.example.com",
+                "transport": "wss",
+                "routingKeys": [VERKEY_MY2],
+                "priority": 1
+            }]), endpoints);
+        }
+
+        #[test]
+        fn indy_set_named_endpoint_for_did_works_for_replace() {
+            let setup = Setup::did();
+
+            did::set_named_endpoint_for_did(setup.wallet_handle, &setup.did, &named_endpoint_json("agent")).unwrap();
+
+            let endpoint_json = json!({"name": "agent", "endpoint": "https://agent.example.com", "transport": "https", "verkey": VERKEY_MY1}).to_string();
+            did::set_named_endpoint_for_did(setup.wallet_handle, &setup.did, &endpoint_json).unwrap();
+
+            let endpoints = did::get_named_endpoints_for_did(setup.wallet_handle, INVALID_POOL_HANDLE, &setup.did).unwrap();
+            let endpoints: serde_json::Value = serde_json::from_str(&endpoints).unwrap();
+
+            assert_eq!(1, endpoints.as_array().unwrap().len());
+            assert_eq!(json!("https://agent.example.com"), endpoints[0]["endpoint"]);
+            assert_eq!(json!(VERKEY_MY1), endpoints[0]["verkey"]);
+        }
+
+        #[test]
+        fn indy_remove_named_endpoint_for_did_works() {
+            let setup = Setup::did();
+
+            did::set_named_endpoint_for_did(setup.wallet_handle, &setup.did, &named_endpoint_json("agent")).unwrap();
+            did::set_named_endpoint_for_did(setup.wallet_handle, &setup.did, &named_endpoint_json("backup")).unwrap();
+            did::remove_named_endpoint_for_did(setup.wallet_handle, &setup.did, "agent").unwrap();
+
+            let endpoints = did::get_named_endpoints_for_did(setup.wallet_handle, INVALID_POOL_HANDLE, &setup.did).unwrap();
+            let endpoints: serde_json::Value = serde_json::from_str(&endpoints).unwrap();
+
+            assert_eq!(1, endpoints.as_array().unwrap().len());
+            assert_eq!(json!("backup"), endpoints[0]["name"]);
+        }
+
+        #[test]
+        fn indy_get_did_doc_works_for_named_endpoints() {
+            let setup = Setup::did();
+
+            did::set_named_endpoint_for_did(setup.wallet_handle, &setup.did, &named_endpoint_json("agent")).unwrap();
+
+            let did_doc = did::get_did_doc(setup.wallet_handle, INVALID_POOL_HANDLE, &setup.did).unwrap();
+            let did_doc: serde_json::Value = serde_json::from_str(&did_doc).unwrap();
+
+            assert_eq!(json!([{
+                "id": format!("{}{}#agent", DEFAULT_PREFIX, setup.did),
+                "type": "did-communication",
+                "serviceEndpoint": "wss://agent.example.com",
+                "recipientKeys": [setup.verkey],
+                "routingKeys": [VERKEY_MY2],
+                "priority": 1
+            }]), did_doc["service"]);
+        }
+
+        #[test]
+        fn indy_build_named_endpoints_attrib_request_works() {
+            let setup = Setup::did();
+
+            did::set_named_endpoint_for_did(setup.wallet_handle, &setup.did, &named_endpoint_json("agent")).unwrap();
+
+            let request = did::build_named_endpoints_attrib_request(setup.wallet_handle, &setup.did, &setup.did).unwrap();
+            let request: serde_json::Value = serde_json::from_str(&request).unwrap();
+
+            assert_eq!(json!("100"), request["operation"]["type"]);
+            assert_eq!(json!(setup.did), request["operation"]["dest"]);
+
+            let raw: serde_json::Value = serde_json::from_str(request["operation"]["raw"].as_str().unwrap()).unwrap();
+            assert_eq!(json!("agent"), raw["endpoints"][0]["name"]);
+            assert_eq!(json!("wss"), raw["endpoints"][0]["transport"]);
+        }
+
+        #[test]
+        fn indy_get_named_endpoints_for_did_works_from_ledger() {
+            let setup = Setup::new_identity();
+
+            did::set_named_endpoint_for_did(setup.wallet_handle, &setup.did, &named_endpoint_json("agent")).unwrap();
+
+            let attrib_request = did::build_named_endpoints_attrib_request(setup.wallet_handle, &setup.did, &setup.did).unwrap();
+            ledger::sign_and_submit_request(setup.pool_handle, setup.wallet_handle, &setup.did, &attrib_request).unwrap();
+
+            thread::sleep(std::time::Duration::from_secs(1));
+
+            let (wallet_handle, wallet_config) = wallet::create_and_open_default_wallet("indy_get_named_endpoints_for_did_works_from_ledger").unwrap();
+
+            let endpoints = did::get_named_endpoints_for_did(wallet_handle, setup.pool_handle, &setup.did).unwrap();
+            let endpoints: serde_json::Value = serde_json::from_str(&endpoints).unwrap();
+
+            assert_eq!(json!("agent"), endpoints[0]["name"]);
+            assert_eq!(json!("wss://agent.example.com"), endpoints[0]["endpoint"]);
+
+            wallet::close_and_delete_wallet(wallet_handle, &wallet_config).unwrap();
+        }
+    }
+}
+
+#[cfg(not(feature = "only_high_cases"))]
+mod medium_cases {
+    use super::*;
+
+    mod key_for_did {
+        use super::*;
+
+        #[test]
+        fn indy_key_for_did_works_for_invalid_pool_handle() {
+            let setup = Setup::wallet();
+
+            let res = did::key_for_did(INVALID_POOL_HANDLE, setup.wallet_handle, DID_TRUSTEE);
+            assert_code!(ErrorCode::PoolLedgerInvalidPoolHandle, res);
+        }
+
+        #[test]
+        fn indy_key_for_did_works_for_invalid_wallet_handle() {
+            Setup::empty();
+
+            let res = did::key_for_did(-1, INVALID_WALLET_HANDLE, DID);
+            assert_code!(ErrorCode::WalletInvalidHandle, res);
+        }
+    }
+
+    mod key_for_local_did {
+        use super::*;
+
+        #[test]
+        fn indy_key_for_local_did_works_for_invalid_wallet_handle() {
+            Setup::empty();
+
+            let res = did::key_for_local_did(INVALID_WALLET_HANDLE, DID_TRUSTEE);
+            assert_code!(ErrorCode::WalletInvalidHandle, res);
+        }
+    }
+
+    mod set_endpoint_for_did {
+        use super::*;
+
+        #[test]
+        fn indy_set_endpoint_for_did_works_for_replace() {
+            let setup = Setup::wallet_and_pool();
+
+            did::set_endpoint_for_did(setup.wallet_handle, DID, ENDPOINT, VERKEY).unwrap();
+            let (endpoint, key) = did::get_endpoint_for_did(setup.wallet_handle, setup.pool_handle, DID).unwrap();
+            assert_eq!(ENDPOINT, endpoint);
+            assert_eq!(VERKEY, key.unwrap());
+
+            let new_endpoint = "10.10.10.1:9710";
+            did::set_endpoint_for_did(setup.wallet_handle, DID, new_endpoint, VERKEY_MY2).unwrap();
+            let (updated_endpoint, updated_key) = did::get_endpoint_for_did(setup.wallet_handle, setup.pool_handle, DID).unwrap();
+            assert_eq!(new_endpoint, updated_endpoint);
+            assert_eq!(VERKEY_MY2, updated_key.unwrap());
+        }
+    }
+
+    mod get_did_metadata {
+        use super::*;
+
+        #[test]
+        fn indy_get_did_metadata_works_for_empty_string() {
+            let setup = Setup::did();
+
+            did::set_did_metadata(setup.wallet_handle, &setup.did, "").unwrap();
+            let metadata = did::get_did_metadata(setup.wallet_handle, &setup.did).unwrap();
+            assert_eq!("", metadata);
+        }
+
+        #[test]
+        fn indy_get_did_metadata_works_for_invalid_handle() {
+            Setup::empty();
+
+            let res = did::get_did_metadata(INVALID_WALLET_HANDLE, DID);
+            assert_code!(ErrorCode::WalletInvalidHandle, res);
+        }
+    }
+
+    mod create_my_did {
+        use super::*;
+
+        #[test]
+        fn indy_create_my_did_works_as_cid() {
+            let setup = Setup::wallet();
+
+            let (my_did, my_verkey) = did::create_my_did(setup.wallet_handle, r#"{"seed":"aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa","cid":true}"#).unwrap();
+            assert_eq!(my_did, VERKEY);
+            assert_eq!(my_verkey, VERKEY);
+        }
+
+        #[test]
+        fn indy_create_my_did_works_with_passed_did() {
+            let setup = Setup::wallet();
+
+            let (my_did, my_verkey) = did::create_my_did(setup.wallet_handle, &format!(r#"{{"did":"{}","seed":"aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"}}"#, DID)).unwrap();
+            assert_eq!(my_did, DID);
+            assert_eq!(my_verkey, VERKEY);
+        }
+
+        #[test]
+        fn indy_create_my_did_works_for_exists_crypto_type() {
+            let setup = Setup::wallet();
+
+            did::create_my_did(setup.wallet_handle, r#"{"crypto_type":"ed25519"}"#).unwrap();
+        }
+
+        #[test]
+        fn indy_create_my_did_works_for_invalid_wallet_handle() {
+            Setup::empty();
+
+            let res = did::create_my_did(INVALID_WALLET_HANDLE, "{}");
+            assert_code!(ErrorCode::WalletInvalidHandle, res);
+        }
+    }
+
+    mod replace_keys_start {
+        use super::*;
+
+        #[test]
+        fn indy_replace_keys_start_works_for_invalid_wallet_handle() {
+            Setup::empty();
+
+            let res = did::replace_keys_start(INVALID_WALLET_HANDLE, DID, "{}");
+            assert_code!(ErrorCode::WalletInvalidHandle, res);
+        }
+
+        #[test]
+        fn indy_replace_keys_start_works_for_seed() {
+            let setup = Setup::did();
+
+            let new_verkey = did::replace_keys_start(setup.wallet_handle, &setup.did, r#"{"seed":"aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"}"#).unwrap();
+            assert_eq!(new_verkey, VERKEY);
+            assert_ne!(setup.verkey, new_verkey);
+        }
+    }
+
+    mod replace_keys_apply {
+        use super::*;
+
+        #[test]
+        fn indy_replace_keys_apply_works_for_invalid_wallet_handle() {
+            Setup::empty();
+
+            let res = did::replace_keys_apply(INVALID_WALLET_HANDLE, DID);
+            assert_code!(ErrorCode::WalletInvalidHandle, res);
+        }
+    }
+
+    mod store_their_did {
+        use super::*;
+
+        #[test]
+        fn indy_store_their_did_works_for_verkey_with_crypto_type() {
+            let setup = Setup::wallet();
+
+            let identity_json = json!({"did": DID, "verkey": VERKEY.to_owned() + ":ed25519"}).to_string();
+            did::store_their_did(setup.wallet_handle, &identity_json).unwrap();
+        }
+
+        #[test]
+        fn indy_create_my_did_works_for_invalid_seed() {
+            let setup = Setup::wallet();
+
+            let res = did::create_my_did(setup.wallet_handle, r#"{"seed":"seed"}"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_store_their_did_works_for_invalid_wallet_handle() {
+            Setup::empty();
+
+            let identity_json = json!({"did": DID}).to_string();
+            let res = did::store_their_did(INVALID_WALLET_HANDLE, &identity_json);
+            assert_code!(ErrorCode::WalletInvalidHandle, res);
+        }
+
+        #[test]
+        fn indy_store_their_did_works_for_abbreviated_verkey() {
+            let setup = Setup::wallet();
+
+            let identity_json = r#"{"did":"8wZcEriaNLNKtteJvx7f8i", "verkey":"~NcYxiDXkpYi6ov5FcYDi1e"}"#;
+            did::store_their_did(setup.wallet_handle, identity_json).unwrap();
+        }
+
+        #[test]
+        fn indy_store_their_did_works_for_abbreviated_verkey_for_fully_qualified() {
+            let setup = Setup::wallet();
+
+            let identity_json = r#"{"did":"did:sov:8wZcEriaNLNKtteJvx7f8i", "verkey":"~NcYxiDXkpYi6ov5FcYDi1e"}"#;
+            did::store_their_did(setup.wallet_handle, identity_json).unwrap();
+        }
+
+        #[test]
+        fn indy_create_my_did_works_for_invalid_json() {
+            let setup = Setup::wallet();
+
+            let res = did::create_my_did(setup.wallet_handle, r#"{"seed":123}"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_store_their_did_works_for_invalid_did() {
+            let setup = Setup::wallet();
+
+            let identity_json = json!({"did": INVALID_BASE58_DID}).to_string();
+            let res = did::store_their_did(setup.wallet_handle, &identity_json);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_store_their_did_works_for_invalid_verkey() {
+            let setup = Setup::wallet();
+
+            let identity_json = json!({"did": "did", "verkey":"invalid_base58string"}).to_string();
+            let res = did::store_their_did(setup.wallet_handle, &identity_json);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_store_their_did_works_for_verkey_with_invalid_crypto_type() {
+            let setup = Setup::wallet();
+
+            let identity_json = json!({"did": DID, "verkey": VERKEY.to_owned() + ":crypto_type"}).to_string();
+            let res = did::store_their_did(setup.wallet_handle, &identity_json);
+            assert_code!(ErrorCode::UnknownCryptoTypeError, res);
+        }
+
+        #[test]
+        fn indy_store_my_did_works_for_is_802() {
+            let setup = Setup::wallet();
+
+            let identity_json = json!({"did": DID}).to_string();
+
+            // 1. Try 'createAndStoreMyDid' operation with say did1 and verkey1
+            did::create_my_did(setup.wallet_handle, &identity_json).unwrap();
 
             // 2. Repeat above operation (with same did and ver key used in #1)
             // but this time catch and swallow the exception (it will throw the exception WalletItemAlreadyExistsException)
             let res = did::create_my_did(setup.wallet_handle, &identity_json);
             assert_code!(ErrorCode::DidAlreadyExistsError, res);
 
-            // 3. Then, now if you try 'createAndStoreMyDid' operation
-            // (either with same did and verkey or you can choose different did and verkey),
-            // in IS-802 it fails with error 'Storage error occurred during wallet operation.'
-            let res = did::create_my_did
-                (setup.wallet_handle, &identity_json);
-            assert_code!(ErrorCode::DidAlreadyExistsError, res);
+            // 3. Then, now if you try 'createAndStoreMyDid' operation
+            // (either with same did and verkey or you can choose different did and verkey),
+            // in IS-802 it fails with error 'Storage error occurred during wallet operation.'
+            let res = did::create_my_did
+                (setup.wallet_handle, &identity_json);
+            assert_code!(ErrorCode::DidAlreadyExistsError, res);
+        }
+    }
+
+    mod replace_keys {
+        use super::*;
+
+        #[test]
+        fn indy_replace_keys_without_nym_transaction() {
+            let setup = Setup::wallet_and_pool();
+
+            let (my_did, _) = did::create_store_and_publish_my_did_from_trustee(setup.wallet_handle, setup.pool_handle).unwrap();
+
+            did::replace_keys_start(setup.wallet_handle, &my_did, "{}").unwrap();
+            did::replace_keys_apply(setup.wallet_handle, &my_did).unwrap();
+
+            let schema_request = ledger::build_schema_request(&my_did, SCHEMA_DATA).unwrap();
+            let response = ledger::sign_and_submit_request(setup.pool_handle, setup.wallet_handle, &my_did, &schema_request).unwrap();
+            pool::check_response_type(&response, ResponseType::REQNACK);
+        }
+    }
+
+
+    mod abbreviate_verkey {
+        use super::*;
+
+        #[test]
+        fn indy_abbreviate_verkey_works_for_invalid_did() {
+            let res = did::abbreviate_verkey(INVALID_BASE58_DID, VERKEY_TRUSTEE);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_abbreviate_verkey_works_for_invalid_verkey() {
+            let res = did::abbreviate_verkey(DID_TRUSTEE, INVALID_BASE58_VERKEY);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
+
+    mod list_my_dids_with_meta{
+        use super::*;
+
+        #[test]
+        fn indy_list_dids(){
+            let setup = Setup::did();
+            let dids = did::list_my_dids_with_meta(setup.wallet_handle).unwrap();
+            let info_list: serde_json::Value = serde_json::from_str(&dids).unwrap();
+            assert_eq!(info_list.as_array().unwrap().len(), 1);
+            assert!(info_list[0]["metadata"].is_null());
+            assert_eq!(setup.verkey, info_list[0]["verkey"].as_str().unwrap().to_string());
+        }
+
+        #[test]
+        fn indy_list_dids_after_creating_dids(){
+            let setup = Setup::wallet();
+            let mut did2verkey: HashMap<String, String> = HashMap::new();
+            for _x in 0..10{
+                let (my_did, my_verkey) = did::create_my_did(setup.wallet_handle, "{}").unwrap();
+                did::set_did_metadata(setup.wallet_handle, &my_did, METADATA).unwrap();
+                did2verkey.insert(String::from(my_did), String::from(my_verkey));
+            }
+            let dids = did::list_my_dids_with_meta(setup.wallet_handle).unwrap();
+            let info_list: serde_json::Value = serde_json::from_str(&dids).unwrap();
+            assert_eq!(info_list.as_array().unwrap().len(), 10);
+            for info in info_list.as_array().unwrap() {
+                assert_eq!(info["metadata"].as_str().unwrap().to_string(),
+                           METADATA.to_string());
+                assert_eq!(&info["verkey"].as_str().unwrap().to_string(),
+                           did2verkey.get(&(info["did"]).as_str().unwrap().to_string()).unwrap());
+            }
+        }
+
+        #[test]
+        fn indy_list_dids_after_replace_keys_start(){
+            let setup = Setup::wallet();
+            let mut did2tempverkey: HashMap<String, String> = HashMap::new();
+            for _x in 0..10{
+                let (my_did, _my_verkey) = did::create_my_did(setup.wallet_handle, "{}").unwrap();
+                let temp_verkey = did::replace_keys_start(setup.wallet_handle, &my_did, "{}").unwrap();
+                did2tempverkey.insert(String::from(&my_did), temp_verkey);
+            }
+            let dids = did::list_my_dids_with_meta(setup.wallet_handle).unwrap();
+            let info_list: serde_json::Value = serde_json::from_str(&dids).unwrap();
+            for info in info_list.as_array().unwrap() {
+                let did = info["did"].as_str().unwrap().to_string();
+                assert_eq!(&info["tempVerkey"].as_str().unwrap().to_string(),
+                           did2tempverkey.get(&did).unwrap());
+            }
+        }
+    }
+
+    mod did_keys_and_services {
+        use super::*;
+
+        #[test]
+        fn indy_add_did_key_works_for_unknown_did() {
+            let setup = Setup::wallet();
+
+            let key_json = json!({"id": "key-2", "purpose": "authentication", "verkey": VERKEY_MY1}).to_string();
+            let res = did::add_did_key(setup.wallet_handle, DID, &key_json);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+
+        #[test]
+        fn indy_add_did_key_works_for_duplicate_id() {
+            let setup = Setup::did();
+
+            let key_json = json!({"id": "key-2", "purpose": "authentication", "verkey": VERKEY_MY1}).to_string();
+            did::add_did_key(setup.wallet_handle, &setup.did, &key_json).unwrap();
+
+            let res = did::add_did_key(setup.wallet_handle, &setup.did, &key_json);
+            assert_code!(ErrorCode::WalletItemAlreadyExists, res);
+        }
+
+        #[test]
+        fn indy_add_did_key_works_for_reserved_id() {
+            let setup = Setup::did();
+
+            let key_json = json!({"id": "key-1", "purpose": "authentication", "verkey": VERKEY_MY1}).to_string();
+            let res = did::add_did_key(setup.wallet_handle, &setup.did, &key_json);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_add_did_key_works_for_invalid_purpose() {
+            let setup = Setup::did();
+
+            let key_json = json!({"id": "key-2", "purpose": "unknown", "verkey": VERKEY_MY1}).to_string();
+            let res = did::add_did_key(setup.wallet_handle, &setup.did, &key_json);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_add_did_key_works_for_invalid_verkey() {
+            let setup = Setup::did();
+
+            let key_json = json!({"id": "key-2", "purpose": "authentication", "verkey": INVALID_BASE58_VERKEY}).to_string();
+            let res = did::add_did_key(setup.wallet_handle, &setup.did, &key_json);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_remove_did_key_works_for_unknown_key() {
+            let setup = Setup::did();
+
+            let res = did::remove_did_key(setup.wallet_handle, &setup.did, "key-2");
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+
+        #[test]
+        fn indy_add_did_service_works_for_missed_endpoint() {
+            let setup = Setup::did();
+
+            let service_json = json!({"id": "agent", "type": "IndyAgent"}).to_string();
+            let res = did::add_did_service(setup.wallet_handle, &setup.did, &service_json);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_add_did_service_works_for_duplicate_id() {
+            let setup = Setup::did();
+
+            let service_json = json!({"id": "agent", "type": "IndyAgent", "serviceEndpoint": "http://localhost:8080"}).to_string();
+            did::add_did_service(setup.wallet_handle, &setup.did, &service_json).unwrap();
+
+            let res = did::add_did_service(setup.wallet_handle, &setup.did, &service_json);
+            assert_code!(ErrorCode::WalletItemAlreadyExists, res);
+        }
+
+        #[test]
+        fn indy_add_did_key_and_service_works_for_same_id() {
+            let setup = Setup::did();
+
+            let key_json = json!({"id": "agent", "purpose": "authentication", "verkey": VERKEY_MY1}).to_string();
+            did::add_did_key(setup.wallet_handle, &setup.did, &key_json).unwrap();
+
+            let service_json = json!({"id": "agent", "type": "IndyAgent", "serviceEndpoint": "http://localhost:8080"}).to_string();
+            let res = did::add_did_service(setup.wallet_handle, &setup.did, &service_json);
+            assert_code!(ErrorCode::WalletItemAlreadyExists, res);
+
+            let service_json = json!({"id": "service-1", "type": "IndyAgent", "serviceEndpoint": "http://localhost:8080"}).to_string();
+            did::add_did_service(setup.wallet_handle, &setup.did, &service_json).unwrap();
+
+            let key_json = json!({"id": "service-1", "purpose": "authentication", "verkey": VERKEY_MY1}).to_string();
+            let res = did::add_did_key(setup.wallet_handle, &setup.did, &key_json);
+            assert_code!(ErrorCode::WalletItemAlreadyExists, res);
+        }
+
+        #[test]
+        fn indy_remove_did_service_works_for_unknown_service() {
+            let setup = Setup::did();
+
+            let res = did::remove_did_service(setup.wallet_handle, &setup.did, "agent");
+            assert_code!(ErrorCode::WalletItemNotFound, res);
         }
     }
 
-    mod replace_keys {
+    mod my_dids_search {
         use super::*;
 
+        const INVALID_SEARCH_HANDLE: indy::SearchHandle = 0;
+
         #[test]
-        fn indy_replace_keys_without_nym_transaction() {
-            let setup = Setup::wallet_and_pool();
+        fn indy_set_my_did_tags_works_for_unknown_did() {
+            let setup = Setup::wallet();
 
-            let (my_did, _) = did::create_store_and_publish_my_did_from_trustee(setup.wallet_handle, setup.pool_handle).unwrap();
+            let res = did::set_my_did_tags(setup.wallet_handle, DID, &json!({"role": "pairwise"}).to_string());
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
 
-            did::replace_keys_start(setup.wallet_handle, &my_did, "{}").unwrap();
-            did::replace_keys_apply(setup.wallet_handle, &my_did).unwrap();
+        #[test]
+        fn indy_set_my_did_tags_works_for_invalid_tags() {
+            let setup = Setup::did();
 
-            let schema_request = ledger::build_schema_request(&my_did, SCHEMA_DATA).unwrap();
-            let response = ledger::sign_and_submit_request(setup.pool_handle, setup.wallet_handle, &my_did, &schema_request).unwrap();
-            pool::check_response_type(&response, ResponseType::REQNACK);
+            let res = did::set_my_did_tags(setup.wallet_handle, &setup.did, r#"{"role": 1}"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
         }
-    }
 
+        #[test]
+        fn indy_open_my_dids_search_works_for_invalid_query() {
+            let setup = Setup::wallet();
 
-    mod abbreviate_verkey {
+            let res = did::open_my_dids_search(setup.wallet_handle, Some(r#"{"role": {"$unknown": "a"}}"#), None);
+            assert_code!(ErrorCode::WalletQueryError, res);
+        }
+
+        #[test]
+        fn indy_fetch_my_dids_works_for_invalid_search_handle() {
+            Setup::empty();
+
+            let res = did::fetch_my_dids(INVALID_SEARCH_HANDLE, 1);
+            assert_code!(ErrorCode::WalletInvalidHandle, res);
+        }
+
+        #[test]
+        fn indy_close_my_dids_search_works_for_twice() {
+            let setup = Setup::wallet();
+
+            let (search_handle, _) = did::open_my_dids_search(setup.wallet_handle, None, None).unwrap();
+            did::close_my_dids_search(search_handle).unwrap();
+
+            let res = did::close_my_dids_search(search_handle);
+            assert_code!(ErrorCode::WalletInvalidHandle, res);
+        }
+    }
+
+    mod export_import_did {
         use super::*;
 
         #[test]
-        fn indy_abbreviate_verkey_works_for_invalid_did() {
-            let res = did::abbreviate_verkey(INVALID_BASE58_DID, VERKEY_TRUSTEE);
+        fn indy_export_did_works_for_unknown_did() {
+            let setup = Setup::key();
+
+            let res = did::export_did(setup.wallet_handle, DID, &setup.verkey);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+
+        #[test]
+        fn indy_export_did_works_for_invalid_recipient_vk() {
+            let setup = Setup::did();
+
+            let res = did::export_did(setup.wallet_handle, &setup.did, INVALID_BASE58_VERKEY);
             assert_code!(ErrorCode::CommonInvalidStructure, res);
         }
 
         #[test]
-        fn indy_abbreviate_verkey_works_for_invalid_verkey() {
-            let res = did::abbreviate_verkey(DID_TRUSTEE, INVALID_BASE58_VERKEY);
+        fn indy_import_did_works_for_existing_did() {
+            let setup = Setup::did();
+
+            let recipient_vk = crypto::create_key(setup.wallet_handle, None).unwrap();
+
+            let bundle = did::export_did(setup.wallet_handle, &setup.did, &recipient_vk).unwrap();
+
+            let res = did::import_did(setup.wallet_handle, &recipient_vk, &bundle);
+            assert_code!(ErrorCode::WalletItemAlreadyExists, res);
+        }
+
+        #[test]
+        fn indy_import_did_works_for_existing_their_did() {
+            let setup = Setup::did();
+
+            let (wallet_handle, wallet_config) = wallet::create_and_open_default_wallet("indy_import_did_works_for_existing_their_did").unwrap();
+            let recipient_vk = crypto::create_key(wallet_handle, None).unwrap();
+
+            did::store_their_did_from_parts(setup.wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+            pairwise::create_pairwise(setup.wallet_handle, DID_TRUSTEE, &setup.did, None).unwrap();
+
+            let bundle = did::export_did(setup.wallet_handle, &setup.did, &recipient_vk).unwrap();
+
+            did::store_their_did_from_parts(wallet_handle, DID_TRUSTEE, VERKEY_MY1).unwrap();
+
+            let res = did::import_did(wallet_handle, &recipient_vk, &bundle);
+            assert_code!(ErrorCode::WalletItemAlreadyExists, res);
+
+            // nothing from the bundle is written and their DID is kept
+            let res = did::get_my_did_with_metadata(wallet_handle, &setup.did);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+
+            let their_verkey = did::key_for_local_did(wallet_handle, DID_TRUSTEE).unwrap();
+            assert_eq!(VERKEY_MY1, their_verkey);
+
+            wallet::close_and_delete_wallet(wallet_handle, &wallet_config).unwrap();
+        }
+
+        #[test]
+        fn indy_import_did_works_for_other_recipient_key() {
+            let setup = Setup::did();
+
+            let recipient_vk = crypto::create_key(setup.wallet_handle, None).unwrap();
+            let other_vk = crypto::create_key(setup.wallet_handle, None).unwrap();
+
+            let bundle = did::export_did(setup.wallet_handle, &setup.did, &recipient_vk).unwrap();
+
+            let res = did::import_did(setup.wallet_handle, &other_vk, &bundle);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_import_did_works_for_invalid_bundle() {
+            let setup = Setup::key();
+
+            let bundle = crypto::anon_crypt(&setup.verkey, MESSAGE.as_bytes()).unwrap();
+
+            let res = did::import_did(setup.wallet_handle, &setup.verkey, &bundle);
             assert_code!(ErrorCode::CommonInvalidStructure, res);
         }
     }
 
-    mod list_my_dids_with_meta{
+    mod deactivate_did {
         use super::*;
 
         #[test]
-        fn indy_list_dids(){
+        fn indy_deactivate_did_works_for_unknown_did() {
+            let setup = Setup::wallet();
+
+            let res = did::deactivate_did(setup.wallet_handle, DID);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+
+        #[test]
+        fn indy_deactivate_did_works_for_their_did() {
+            let setup = Setup::wallet();
+
+            did::store_their_did_from_parts(setup.wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+
+            did::deactivate_did(setup.wallet_handle, DID_TRUSTEE).unwrap();
+        }
+
+        #[test]
+        fn indy_multi_sign_request_works_for_deactivated_did() {
             let setup = Setup::did();
-            let dids = did::list_my_dids_with_meta(setup.wallet_handle).unwrap();
-            let info_list: serde_json::Value = serde_json::from_str(&dids).unwrap();
-            assert_eq!(info_list.as_array().unwrap().len(), 1);
-            assert!(info_list[0]["metadata"].is_null());
-            assert_eq!(setup.verkey, info_list[0]["verkey"].as_str().unwrap().to_string());
+
+            did::deactivate_did(setup.wallet_handle, &setup.did).unwrap();
+
+            let res = ledger::multi_sign_request(setup.wallet_handle, &setup.did, REQUEST);
+            assert_code!(ErrorCode::DidDeactivatedError, res);
         }
 
         #[test]
-        fn indy_list_dids_after_creating_dids(){
+        fn indy_publish_did_deactivation_works_for_unknown_did() {
             let setup = Setup::wallet();
-            let mut did2verkey: HashMap<String, String> = HashMap::new();
-            for _x in 0..10{
-                let (my_did, my_verkey) = did::create_my_did(setup.wallet_handle, "{}").unwrap();
-                did::set_did_metadata(setup.wallet_handle, &my_did, METADATA).unwrap();
-                did2verkey.insert(String::from(my_did), String::from(my_verkey));
-            }
-            let dids = did::list_my_dids_with_meta(setup.wallet_handle).unwrap();
-            let info_list: serde_json::Value = serde_json::from_str(&dids).unwrap();
-            assert_eq!(info_list.as_array().unwrap().len(), 10);
-            for info in info_list.as_array().unwrap() {
-                assert_eq!(info["metadata"].as_str().unwrap().to_string(),
-                           METADATA.to_string());
-                assert_eq!(&info["verkey"].as_str().unwrap().to_string(),
-                           did2verkey.get(&(info["did"]).as_str().unwrap().to_string()).unwrap());
-            }
+
+            let res = did::publish_did_deactivation(setup.wallet_handle, INVALID_POOL_HANDLE, DID);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
         }
 
         #[test]
-        fn indy_list_dids_after_replace_keys_start(){
+        fn indy_export_import_did_works_for_deactivated_did() {
+            let setup = Setup::did();
+
+            let (wallet_handle, wallet_config) = wallet::create_and_open_default_wallet("indy_export_import_did_works_for_deactivated_did").unwrap();
+            let recipient_vk = crypto::create_key(wallet_handle, None).unwrap();
+
+            did::deactivate_did(setup.wallet_handle, &setup.did).unwrap();
+
+            let bundle = did::export_did(setup.wallet_handle, &setup.did, &recipient_vk).unwrap();
+            did::import_did(wallet_handle, &recipient_vk, &bundle).unwrap();
+
+            let res = ledger::sign_request(wallet_handle, &setup.did, REQUEST);
+            assert_code!(ErrorCode::DidDeactivatedError, res);
+
+            wallet::close_and_delete_wallet(wallet_handle, &wallet_config).unwrap();
+        }
+    }
+
+    mod qualify_all_dids {
+        use super::*;
+
+        #[test]
+        fn qualify_all_dids_works_for_already_qualified_dids() {
+            let setup = Setup::did_fully_qualified();
+
+            let report = did::qualify_all_dids(setup.wallet_handle, DEFAULT_METHOD_NAME, None).unwrap();
+            let report: serde_json::Value = serde_json::from_str(&report).unwrap();
+
+            assert_eq!(json!([]), report["myDids"]);
+            assert_eq!(json!([]), report["theirDids"]);
+            assert_eq!(json!(0), report["pairwiseCount"]);
+        }
+
+        #[test]
+        fn qualify_all_dids_works_for_conflict() {
             let setup = Setup::wallet();
-            let mut did2tempverkey: HashMap<String, String> = HashMap::new();
-            for _x in 0..10{
-                let (my_did, _my_verkey) = did::create_my_did(setup.wallet_handle, "{}").unwrap();
-                let temp_verkey = did::replace_keys_start(setup.wallet_handle, &my_did, "{}").unwrap();
-                did2tempverkey.insert(String::from(&my_did), temp_verkey);
-            }
-            let dids = did::list_my_dids_with_meta(setup.wallet_handle).unwrap();
-            let info_list: serde_json::Value = serde_json::from_str(&dids).unwrap();
-            for info in info_list.as_array().unwrap() {
-                let did = info["did"].as_str().unwrap().to_string();
-                assert_eq!(&info["tempVerkey"].as_str().unwrap().to_string(),
-                           did2tempverkey.get(&did).unwrap());
-            }
+
+            let (did, _) = did::create_and_store_my_did(setup.wallet_handle, Some(MY1_SEED)).unwrap();
+            did::create_and_store_my_did_v1(setup.wallet_handle, Some(MY1_SEED)).unwrap();
+            let (did_2, _) = did::create_my_did(setup.wallet_handle, "{}").unwrap();
+
+            let res = did::qualify_all_dids(setup.wallet_handle, DEFAULT_METHOD_NAME, None);
+            assert_code!(ErrorCode::WalletItemAlreadyExists, res);
+
+            // Wallet is not changed
+            did::key_for_local_did(setup.wallet_handle, &did).unwrap();
+            did::key_for_local_did(setup.wallet_handle, &did_2).unwrap();
+        }
+
+        #[test]
+        fn qualify_all_dids_works_for_invalid_method() {
+            let setup = Setup::did();
+
+            let res = did::qualify_all_dids(setup.wallet_handle, "indy:", None);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn qualify_all_dids_works_for_invalid_options() {
+            let setup = Setup::did();
+
+            let res = did::qualify_all_dids(setup.wallet_handle, DEFAULT_METHOD_NAME, Some(r#"{"dryRun": "yes"}"#));
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
+
+    mod named_endpoints {
+        use super::*;
+
+        #[test]
+        fn indy_set_named_endpoint_for_did_works_for_unknown_did() {
+            let setup = Setup::wallet();
+
+            let endpoint_json = json!({"name": "agent", "endpoint": "wss://agent.example.com", "transport": "wss"}).to_string();
+            let res = did::set_named_endpoint_for_did(setup.wallet_handle, DID, &endpoint_json);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+
+        #[test]
+        fn indy_set_named_endpoint_for_did_works_for_transport_mismatch() {
+            let setup = Setup::did();
+
+            let endpoint_json = json!({"name": "agent", "endpoint": "http://agent.example.com", "transport": "wss"}).to_string();
+            let res = did::set_named_endpoint_for_did(setup.wallet_handle, &setup.did, &endpoint_json);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_set_named_endpoint_for_did_works_for_unknown_transport() {
+            let setup = Setup::did();
+
+            let endpoint_json = json!({"name": "agent", "endpoint": "tcp://agent.example.com", "transport": "tcp"}).to_string();
+            let res = did::set_named_endpoint_for_did(setup.wallet_handle, &setup.did, &endpoint_json);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_set_named_endpoint_for_did_works_for_reserved_name() {
+            let setup = Setup::did();
+
+            let endpoint_json = json!({"name": "did-communication", "endpoint": "wss://agent.example.com", "transport": "wss"}).to_string();
+            let res = did::set_named_endpoint_for_did(setup.wallet_handle, &setup.did, &endpoint_json);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_set_named_endpoint_for_did_works_for_invalid_routing_key() {
+            let setup = Setup::did();
+
+            let endpoint_json = json!({"name": "agent", "endpoint": "wss://agent.example.com", "transport": "wss", "routingKeys": [INVALID_BASE58_VERKEY]}).to_string();
+            let res = did::set_named_endpoint_for_did(setup.wallet_handle, &setup.did, &endpoint_json);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_set_named_endpoint_for_did_works_for_service_id_conflict() {
+            let setup = Setup::did();
+
+            let service_json = json!({"id": "agent", "type": "did-communication", "serviceEndpoint": "https://agent.example.com"}).to_string();
+            did::add_did_service(setup.wallet_handle, &setup.did, &service_json).unwrap();
+
+            let endpoint_json = json!({"name": "agent", "endpoint": "wss://agent.example.com", "transport": "wss"}).to_string();
+            let res = did::set_named_endpoint_for_did(setup.wallet_handle, &setup.did, &endpoint_json);
+            assert_code!(ErrorCode::WalletItemAlreadyExists, res);
+
+            did::remove_did_service(setup.wallet_handle, &setup.did, "agent").unwrap();
+            did::set_named_endpoint_for_did(setup.wallet_handle, &setup.did, &endpoint_json).unwrap();
+
+            let res = did::add_did_service(setup.wallet_handle, &setup.did, &service_json);
+            assert_code!(ErrorCode::WalletItemAlreadyExists, res);
+        }
+
+        #[test]
+        fn indy_remove_named_endpoint_for_did_works_for_unknown_name() {
+            let setup = Setup::did();
+
+            let res = did::remove_named_endpoint_for_did(setup.wallet_handle, &setup.did, "agent");
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+
+        #[test]
+        fn indy_get_named_endpoints_for_did_works_for_my_did_without_endpoints() {
+            let setup = Setup::did();
+
+            let endpoints = did::get_named_endpoints_for_did(setup.wallet_handle, INVALID_POOL_HANDLE, &setup.did).unwrap();
+            assert_eq!("[]", endpoints);
+        }
+
+        #[test]
+        fn indy_get_named_endpoints_for_did_works_invalid_pool_handle_for_unknown_did() {
+            let setup = Setup::wallet();
+
+            let res = did::get_named_endpoints_for_did(setup.wallet_handle, INVALID_POOL_HANDLE, DID);
+            assert_code!(ErrorCode::PoolLedgerInvalidPoolHandle, res);
         }
     }
 }
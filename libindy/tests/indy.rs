@@ -1,6 +1,159 @@
 extern crate indyrs as indy;
+extern crate futures;
+#[macro_use]
+extern crate lazy_static;
+
+use std::ffi::CStr;
+use std::sync::Mutex;
+
+use futures::Future;
+use libc::c_char;
+
+lazy_static! {
+    static ref HOOK_CALLS: Mutex<Vec<(String, String, Option<i32>)>> = Mutex::new(Vec::new());
+    // anoncreds test seed is global, so tests that set it must not interleave
+    static ref ANONCREDS_TEST_SEED: Mutex<()> = Mutex::new(());
+}
+
+const WALLET_CREDENTIALS: &str = r#"{"key":"8dvfYSt5d1taSd6yJdpjq4emkwsPDDLYxkNFysFD2cZY", "key_derivation_method":"RAW"}"#;
+
+extern fn _pre_command_hook(command_name: *const c_char, params_digest: *const c_char) {
+    let command_name = unsafe { CStr::from_ptr(command_name) }.to_str().unwrap().to_string();
+    let params_digest = unsafe { CStr::from_ptr(params_digest) }.to_str().unwrap().to_string();
+    HOOK_CALLS.lock().unwrap().push((command_name, params_digest, None));
+}
+
+extern fn _post_command_hook(command_name: *const c_char, params_digest: *const c_char, _duration_ms: u64, err: i32) {
+    let command_name = unsafe { CStr::from_ptr(command_name) }.to_str().unwrap().to_string();
+    let params_digest = unsafe { CStr::from_ptr(params_digest) }.to_str().unwrap().to_string();
+    HOOK_CALLS.lock().unwrap().push((command_name, params_digest, Some(err)));
+}
 
 #[test]
 fn set_runtime_config_works() {
     indy::set_runtime_config(r#"{"crypto_thread_pool_size": 2}"#);
-}
\ No newline at end of file
+}
+
+#[test]
+fn set_runtime_config_works_for_metrics_buckets() {
+    assert_eq!(indy::ErrorCode::Success, indy::set_runtime_config(r#"{"metrics_buckets_ms": [1.5, 10, 100]}"#));
+}
+
+#[test]
+fn set_runtime_config_works_for_unsorted_metrics_buckets() {
+    assert_eq!(indy::ErrorCode::CommonInvalidStructure, indy::set_runtime_config(r#"{"metrics_buckets_ms": [10, 1]}"#));
+}
+
+#[test]
+fn set_runtime_config_works_for_slow_operation_threshold() {
+    assert_eq!(indy::ErrorCode::Success, indy::set_runtime_config(r#"{"slow_operation_threshold_ms": 250}"#));
+}
+
+#[test]
+fn set_runtime_config_works_for_resource_limits() {
+    assert_eq!(indy::ErrorCode::Success,
+               indy::set_runtime_config(r#"{"pool_socket_hwm": 100, "pool_zmq_io_threads": 2, "blob_buffer_size": 4096, "tails_cache_size": 33554432}"#));
+}
+
+#[test]
+fn set_runtime_config_works_for_invalid_resource_limits() {
+    assert_eq!(indy::ErrorCode::CommonInvalidStructure, indy::set_runtime_config(r#"{"crypto_thread_pool_size": 0}"#));
+    assert_eq!(indy::ErrorCode::CommonInvalidStructure, indy::set_runtime_config(r#"{"pool_socket_hwm": -1}"#));
+    assert_eq!(indy::ErrorCode::CommonInvalidStructure, indy::set_runtime_config(r#"{"pool_zmq_io_threads": 0}"#));
+    assert_eq!(indy::ErrorCode::CommonInvalidStructure, indy::set_runtime_config(r#"{"blob_buffer_size": 0}"#));
+}
+
+#[test]
+fn set_runtime_config_works_for_anoncreds_test_seed() {
+    let _seed = ANONCREDS_TEST_SEED.lock().unwrap();
+
+    assert_eq!(indy::ErrorCode::Success, indy::set_runtime_config(r#"{"anoncreds_test_seed": "test seed"}"#));
+    let nonce_1 = indy::anoncreds::generate_nonce().wait().unwrap();
+    let nonce_2 = indy::anoncreds::generate_nonce().wait().unwrap();
+    assert_ne!(nonce_1, nonce_2);
+
+    assert_eq!(indy::ErrorCode::Success, indy::set_runtime_config(r#"{"anoncreds_test_seed": "test seed"}"#));
+    assert_eq!(nonce_1, indy::anoncreds::generate_nonce().wait().unwrap());
+    assert_eq!(nonce_2, indy::anoncreds::generate_nonce().wait().unwrap());
+
+    assert_eq!(indy::ErrorCode::Success, indy::set_runtime_config(r#"{"anoncreds_test_seed": ""}"#));
+    assert_ne!(nonce_1, indy::anoncreds::generate_nonce().wait().unwrap());
+}
+
+#[test]
+fn set_runtime_config_works_for_anoncreds_test_seed_and_credential_request() {
+    let _seed = ANONCREDS_TEST_SEED.lock().unwrap();
+
+    let config = r#"{"id": "anoncreds_test_seed_credential_request"}"#;
+    indy::wallet::delete_wallet(config, WALLET_CREDENTIALS).wait().ok();
+    indy::wallet::create_wallet(config, WALLET_CREDENTIALS).wait().unwrap();
+    let wallet_handle = indy::wallet::open_wallet(config, WALLET_CREDENTIALS).wait().unwrap();
+
+    let issuer_did = "NcYxiDXkpYi6ov5FcYDi1e";
+    let (_, schema_json) = indy::anoncreds::issuer_create_schema(issuer_did, "gvt", "1.0", r#"["name", "age"]"#).wait().unwrap();
+    let (cred_def_id, cred_def_json) = indy::anoncreds::issuer_create_and_store_credential_def(wallet_handle, issuer_did, &schema_json, "tag", None, "{}").wait().unwrap();
+    let cred_offer = indy::anoncreds::issuer_create_credential_offer(wallet_handle, &cred_def_id).wait().unwrap();
+    let master_secret_id = indy::anoncreds::prover_create_master_secret(wallet_handle, None).wait().unwrap();
+
+    let create_credential_request = || {
+        assert_eq!(indy::ErrorCode::Success, indy::set_runtime_config(r#"{"anoncreds_test_seed": "test seed"}"#));
+        indy::anoncreds::prover_create_credential_req(wallet_handle, "VsKV7grR1BUE29mG2Fm2kX", &cred_offer, &cred_def_json, &master_secret_id).wait().unwrap()
+    };
+
+    let (cred_req_1, cred_req_metadata_1) = create_credential_request();
+    let (cred_req_2, cred_req_metadata_2) = create_credential_request();
+    assert_eq!(cred_req_1, cred_req_2);
+    assert_eq!(cred_req_metadata_1, cred_req_metadata_2);
+
+    assert_eq!(indy::ErrorCode::Success, indy::set_runtime_config(r#"{"anoncreds_test_seed": ""}"#));
+    let (cred_req_3, _) = indy::anoncreds::prover_create_credential_req(wallet_handle, "VsKV7grR1BUE29mG2Fm2kX", &cred_offer, &cred_def_json, &master_secret_id).wait().unwrap();
+    assert_ne!(cred_req_1, cred_req_3);
+
+    indy::wallet::close_wallet(wallet_handle).wait().unwrap();
+    indy::wallet::delete_wallet(config, WALLET_CREDENTIALS).wait().unwrap();
+}
+
+#[test]
+fn register_command_hook_works() {
+    let config = r#"{"id": "register_command_hook"}"#;
+    let other_credentials = r#"{"key":"6nxtSiXFvBd593Y2DCed2dYvRY1PGK9WMtxCBjLzKgbw", "key_derivation_method":"RAW"}"#;
+    indy::wallet::delete_wallet(config, WALLET_CREDENTIALS).wait().ok();
+    indy::wallet::create_wallet(config, WALLET_CREDENTIALS).wait().unwrap();
+
+    assert_eq!(indy::ErrorCode::Success, indy::register_command_hook(Some(_pre_command_hook), Some(_post_command_hook)));
+
+    indy::metrics::collect_metrics().wait().unwrap();
+    indy::wallet::get_wallet_record(indy::WalletHandle(-1), "type", "id", "{}").wait().unwrap_err();
+    indy::wallet::get_wallet_record(indy::WalletHandle(-1), "type", "id", "{}").wait().unwrap_err();
+    indy::wallet::get_wallet_record(indy::WalletHandle(-1), "type", "other_id", "{}").wait().unwrap_err();
+    // Wallet is opened after key derivation, so the result is known only when the callback is called
+    indy::wallet::open_wallet(config, other_credentials).wait().unwrap_err();
+
+    // Callbacks are called on the command thread, so post hook of the previous call is called before the next command
+    indy::metrics::collect_metrics().wait().unwrap();
+
+    assert_eq!(indy::ErrorCode::Success, indy::register_command_hook(None, None));
+    indy::wallet::delete_wallet(config, WALLET_CREDENTIALS).wait().unwrap();
+
+    let calls = HOOK_CALLS.lock().unwrap().clone();
+    let calls_of = |command_name: &str, err: Option<i32>| -> Vec<String> {
+        calls.iter()
+            .filter(|(name, _, call_err)| name == command_name && *call_err == err)
+            .map(|(_, params_digest, _)| params_digest.clone())
+            .collect()
+    };
+
+    assert!(!calls_of("metrics_command_collect_metrics", None).is_empty());
+    assert!(!calls_of("metrics_command_collect_metrics", Some(indy::ErrorCode::Success as i32)).is_empty());
+
+    let get_record_digests = calls_of("non_secrets_command_get_record", Some(indy::ErrorCode::WalletInvalidHandle as i32));
+    assert_eq!(3, get_record_digests.len());
+    assert!(!get_record_digests[0].is_empty());
+    assert_eq!(get_record_digests[0], get_record_digests[1]);
+    assert_ne!(get_record_digests[0], get_record_digests[2]);
+    assert_eq!(get_record_digests, calls_of("non_secrets_command_get_record", None));
+
+    assert_eq!(1, calls_of("wallet_command_open", Some(indy::ErrorCode::WalletAccessFailed as i32)).len());
+    // continuation commands are reported as a part of the call
+    assert!(calls_of("wallet_command_open_continue", None).is_empty());
+}
@@ -717,6 +717,125 @@ fn anoncreds_revocation_interaction_test_issuance_by_demand_three_credentials_po
     pool.close();
 }
 
+#[cfg(feature = "revocation_tests")]
+#[cfg(any(feature = "force_full_interaction_tests", not(target_os = "android")))]
+#[cfg(not(feature = "only_high_cases"))]
+#[test]
+fn anoncreds_revocation_interaction_test_revocation_state_updater() {
+    let setup = Setup::empty();
+
+    let pool = Pool::new(&setup.name);
+
+    let mut issuer = Issuer::new(&pool);
+
+    let mut prover = Prover::new(None);
+
+    // ISSUER post to Ledger Schema, CredentialDefinition, RevocationRegistry
+    issuer.create_initial_ledger_state(&pool, r#"{"max_cred_num":5, "issuance_type":"ISSUANCE_ON_DEMAND"}"#);
+
+    let (cred_rev_id, _) =
+        multi_steps_create_revocation_credential(&pool, &issuer, &mut prover, &anoncreds::gvt_credential_values_json(), CREDENTIAL1_ID);
+
+    // Prover starts updates of revocation state of the credential
+    let blob_storage_reader_handle = blob_storage::open_reader(TYPE, &anoncreds::tails_writer_config()).unwrap();
+    let updater_handle = anoncreds::start_revocation_state_updater(prover.wallet_handle, pool.pool_handle, blob_storage_reader_handle,
+                                                                   &json!({"credIds": [CREDENTIAL1_ID], "updateInterval": 1}).to_string()).unwrap();
+
+    let rev_state = wait_updated_revocation_state(updater_handle, CREDENTIAL1_ID, 0);
+    assert_eq!(cred_rev_id, rev_state["cred_rev_id"].as_str().unwrap());
+
+    // Issuer revokes the credential, so the registry state changes
+    thread::sleep(std::time::Duration::from_secs(1));
+    issuer.revoke_credential(&pool, &cred_rev_id);
+
+    let updated_rev_state = wait_updated_revocation_state(updater_handle, CREDENTIAL1_ID, rev_state["timestamp"].as_u64().unwrap());
+    assert_ne!(rev_state["rev_state"]["rev_reg"], updated_rev_state["rev_state"]["rev_reg"]);
+
+    anoncreds::stop_revocation_state_updater(updater_handle).unwrap();
+
+    issuer.close();
+    prover.close();
+
+    pool.close();
+}
+
+#[cfg(feature = "revocation_tests")]
+#[cfg(any(feature = "force_full_interaction_tests", not(target_os = "android")))]
+#[cfg(not(feature = "only_high_cases"))]
+#[test]
+fn anoncreds_revocation_interaction_test_verify_proof_with_pools() {
+    let setup = Setup::empty();
+
+    let pool = Pool::new(&setup.name);
+
+    let mut issuer = Issuer::new(&pool);
+
+    let mut prover = Prover::new(None);
+
+    // ISSUER post to Ledger Schema, CredentialDefinition, RevocationRegistry
+    issuer.create_initial_ledger_state(&pool, r#"{"max_cred_num":5, "issuance_type":"ISSUANCE_ON_DEMAND"}"#);
+
+    multi_steps_create_revocation_credential(&pool, &issuer, &mut prover, &anoncreds::gvt_credential_values_json(), CREDENTIAL1_ID);
+
+    thread::sleep(std::time::Duration::from_secs(1));
+
+    let to = time::get_time().sec as u64;
+
+    let proof_request = json!({
+           "nonce":"123432421212",
+           "name":"proof_req_1",
+           "version":"0.1",
+           "requested_attributes": json!({
+               "attr1_referent": json!({
+                   "name":"name"
+               })
+           }),
+           "requested_predicates": json!({}),
+           "non_revoked": json!({ "to": to.clone() })
+        }).to_string();
+
+    let proof_json = prover.make_proof(&pool, &proof_request, "attr1_referent", None, to);
+
+    // Verifier fetches ledger objects from the pool mapped to their issuer DID
+    let pools_json = json!({
+        "pools": { issuer.issuer_did.clone(): pool.pool_handle }
+    }).to_string();
+
+    let valid = anoncreds::verifier_verify_proof_with_pools(&pools_json, &proof_request, &proof_json).unwrap();
+    assert!(valid);
+
+    // Objects of issuers without pool can't be fetched
+    let pools_json = json!({ "pools": { DID_TRUSTEE: pool.pool_handle } }).to_string();
+
+    let res = anoncreds::verifier_verify_proof_with_pools(&pools_json, &proof_request, &proof_json);
+    assert_code!(indy::ErrorCode::CommonInvalidStructure, res);
+
+    issuer.close();
+    prover.close();
+
+    pool.close();
+}
+
+#[cfg(feature = "revocation_tests")]
+#[cfg(any(feature = "force_full_interaction_tests", not(target_os = "android")))]
+#[cfg(not(feature = "only_high_cases"))]
+fn wait_updated_revocation_state(updater_handle: i32, cred_id: &str, after: u64) -> Value {
+    for _ in 0..30 {
+        let rev_states = anoncreds::get_updated_revocation_states(updater_handle).unwrap();
+        let rev_states: Value = serde_json::from_str(&rev_states).unwrap();
+
+        if let Some(rev_state) = rev_states.get(cred_id) {
+            if rev_state["timestamp"].as_u64().unwrap() > after {
+                return rev_state.clone();
+            }
+        }
+
+        thread::sleep(std::time::Duration::from_secs(1));
+    }
+
+    panic!("Revocation state of credential {} isn't updated", cred_id);
+}
+
 #[cfg(feature = "revocation_tests")]
 #[cfg(any(feature = "force_full_interaction_tests", not(target_os = "android")))]
 #[cfg(not(feature = "only_high_cases"))]
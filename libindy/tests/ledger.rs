@@ -31,6 +31,44 @@ inject_indy_dependencies!();
 mod high_cases {
     use super::*;
 
+    mod offline_requests {
+        use super::*;
+
+        #[test]
+        fn indy_set_request_protocol_version_works_for_offline_signing() {
+            let setup = Setup::did();
+
+            let request = ledger::build_nym_request(&setup.did, DID, None, None, None).unwrap();
+            let request = ledger::set_request_protocol_version(&request, 1).unwrap();
+            let request = ledger::sign_request(setup.wallet_handle, &setup.did, &request).unwrap();
+
+            let request: serde_json::Value = serde_json::from_str(&request).unwrap();
+            assert_eq!(json!(1), request["protocolVersion"]);
+            assert!(request["signature"].is_string());
+        }
+
+        #[test]
+        #[cfg(not(feature = "only_high_cases"))]
+        fn indy_set_request_protocol_version_works_for_unsupported_version() {
+            Setup::empty();
+
+            let res = ledger::set_request_protocol_version(REQUEST, 3);
+            assert_code!(ErrorCode::PoolIncompatibleProtocolVersion, res);
+        }
+
+        #[test]
+        #[cfg(not(feature = "only_high_cases"))]
+        fn indy_set_request_protocol_version_works_for_signed_request() {
+            let setup = Setup::did();
+
+            let request = ledger::build_nym_request(&setup.did, DID, None, None, None).unwrap();
+            let request = ledger::sign_request(setup.wallet_handle, &setup.did, &request).unwrap();
+
+            let res = ledger::set_request_protocol_version(&request, 1);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
+
     mod requests {
         use super::*;
 
@@ -126,6 +164,90 @@ mod high_cases {
             let nym_response = ledger::sign_and_submit_request(setup.pool_handle, setup.wallet_handle, &setup.did, &nym_request).unwrap();
             pool::check_response_type(&nym_response, ResponseType::REPLY);
         }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_submit_requests_works() {
+            let setup = Setup::trustee();
+
+            let get_nym_request = ledger::build_get_nym_request(Some(&setup.did), &setup.did).unwrap();
+            let get_schema_request = ledger::build_get_schema_request(Some(&setup.did), &anoncreds::gvt_schema_id()).unwrap();
+            let requests = json!([
+                serde_json::from_str::<serde_json::Value>(&get_nym_request).unwrap(),
+                "request",
+                serde_json::from_str::<serde_json::Value>(&get_schema_request).unwrap()
+            ]).to_string();
+
+            let responses = ledger::submit_requests(setup.pool_handle, &requests).unwrap();
+            let responses: Vec<serde_json::Value> = serde_json::from_str(&responses).unwrap();
+
+            assert_eq!(3, responses.len());
+
+            pool::check_response_type(responses[0]["response"].as_str().unwrap(), ResponseType::REPLY);
+            pool::check_response_type(responses[2]["response"].as_str().unwrap(), ResponseType::REPLY);
+
+            assert!(responses[1].get("response").is_none());
+            assert_eq!(ErrorCode::CommonInvalidStructure as i64, responses[1]["error"]["code"].as_i64().unwrap());
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_submit_requests_works_for_empty_list() {
+            let setup = Setup::pool();
+
+            let responses = ledger::submit_requests(setup.pool_handle, "[]").unwrap();
+            assert_eq!("[]", responses);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_submit_request_with_options_works() {
+            let setup = Setup::trustee();
+
+            let get_nym_request = ledger::build_get_nym_request(Some(&setup.did), &setup.did).unwrap();
+            let options = json!({"timeout": 10, "priority": "high"}).to_string();
+
+            let response = ledger::submit_request_with_options(setup.pool_handle, &get_nym_request, &options).unwrap();
+            pool::check_response_type(&response, ResponseType::REPLY);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_submit_request_with_options_works_for_default_options() {
+            let setup = Setup::trustee();
+
+            let get_nym_request = ledger::build_get_nym_request(Some(&setup.did), &setup.did).unwrap();
+
+            let response = ledger::submit_request_with_options(setup.pool_handle, &get_nym_request, "{}").unwrap();
+            pool::check_response_type(&response, ResponseType::REPLY);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_submit_request_idempotent_works_for_retried_schema() {
+            let setup = Setup::new_identity();
+
+            let (_, schema_json) = anoncreds::issuer_create_schema(&setup.did,
+                                                                   GVT_SCHEMA_NAME,
+                                                                   SCHEMA_VERSION,
+                                                                   GVT_SCHEMA_ATTRIBUTES).unwrap();
+
+            let schema_request = ledger::build_schema_request(&setup.did, &schema_json).unwrap();
+            let schema_request = ledger::sign_request(setup.wallet_handle, &setup.did, &schema_request).unwrap();
+
+            let result = ledger::submit_request_idempotent(setup.pool_handle, &schema_request).unwrap();
+            let result: serde_json::Value = serde_json::from_str(&result).unwrap();
+            assert_eq!(false, result["alreadyExists"]);
+            assert_eq!("REPLY", result["response"]["op"]);
+
+            let retried_request = ledger::build_schema_request(&setup.did, &schema_json).unwrap();
+            let retried_request = ledger::sign_request(setup.wallet_handle, &setup.did, &retried_request).unwrap();
+
+            let result = ledger::submit_request_idempotent(setup.pool_handle, &retried_request).unwrap();
+            let result: serde_json::Value = serde_json::from_str(&result).unwrap();
+            assert_eq!(true, result["alreadyExists"]);
+            assert_eq!(constants::GET_SCHEMA, result["response"]["result"]["type"]);
+        }
     }
 
     mod submit_action {
@@ -228,6 +350,34 @@ mod high_cases {
                 }
             }
         }
+
+        #[test]
+        fn indy_parse_get_frozen_ledgers_response_works() {
+            Setup::empty();
+
+            let response = json!({
+                "op": "REPLY",
+                "result": {
+                    "type": constants::GET_FROZEN_LEDGERS,
+                    "data": {"1000": {"ledger": "ledger_root", "state": "state_root", "seq_no": 10}}
+                }
+            }).to_string();
+
+            let frozen_ledgers = ledger::parse_get_frozen_ledgers_response(&response).unwrap();
+            let frozen_ledgers: serde_json::Value = serde_json::from_str(&frozen_ledgers).unwrap();
+
+            assert_eq!(json!([{"ledgerId": 1000, "ledgerRoot": "ledger_root", "stateRoot": "state_root", "seqNo": 10}]), frozen_ledgers["data"]);
+        }
+
+        #[test]
+        fn indy_parse_get_frozen_ledgers_response_works_for_reject() {
+            Setup::empty();
+
+            let response = json!({"op": "REJECT", "reqId": 1, "reason": "not allowed"}).to_string();
+
+            let res = ledger::parse_get_frozen_ledgers_response(&response);
+            assert_code!(ErrorCode::LedgerInvalidTransaction, res);
+        }
     }
 
     mod multi_sign_request {
@@ -295,6 +445,50 @@ mod high_cases {
             let res = ledger::multi_sign_request(setup.wallet_handle, DID, REQUEST);
             assert_code!(ErrorCode::WalletItemNotFound, res);
         }
+
+        #[test]
+        fn indy_merge_multi_signed_requests_works() {
+            let setup = Setup::wallet();
+
+            let (did1, _) = did::create_and_store_my_did(setup.wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+            let (did2, _) = did::create_and_store_my_did(setup.wallet_handle, Some(MY1_SEED)).unwrap();
+
+            let request1 = ledger::multi_sign_request(setup.wallet_handle, &did1, REQUEST).unwrap();
+            let request2 = ledger::multi_sign_request(setup.wallet_handle, &did2, REQUEST).unwrap();
+
+            let requests = format!("[{}, {}]", request1, request2);
+
+            let message = ledger::merge_multi_signed_requests(&requests).unwrap();
+
+            let msg: serde_json::Value = serde_json::from_str(&message).unwrap();
+            let signatures = msg["signatures"].as_object().unwrap();
+
+            assert_eq!(2, signatures.len());
+            assert_eq!(signatures[DID_TRUSTEE], r#"65hzs4nsdQsTUqLCLy2qisbKLfwYKZSWoyh1C6CU59p5pfG3EHQXGAsjW4Qw4QdwkrvjSgQuyv8qyABcXRBznFKW"#);
+            assert_eq!(signatures[DID_MY1], r#"49aXkbrtTE3e522AefE76J51WzUiakw3ZbxxWzf44cv7RS21n8mMr4vJzi4TymuqDupzCz7wEtuGz6rA94Y73kKR"#);
+        }
+
+        #[test]
+        fn indy_merge_multi_signed_requests_works_for_single_signature() {
+            let setup = Setup::wallet();
+
+            let (did, _) = did::create_and_store_my_did(setup.wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+            let (did2, _) = did::create_and_store_my_did(setup.wallet_handle, Some(MY1_SEED)).unwrap();
+
+            let request1 = ledger::sign_request(setup.wallet_handle, &did, REQUEST_FROM_TRUSTEE).unwrap();
+            let request2 = ledger::multi_sign_request(setup.wallet_handle, &did2, REQUEST_FROM_TRUSTEE).unwrap();
+
+            let requests = format!("[{}, {}]", request1, request2);
+
+            let message = ledger::merge_multi_signed_requests(&requests).unwrap();
+
+            let msg: serde_json::Value = serde_json::from_str(&message).unwrap();
+            let signatures = msg["signatures"].as_object().unwrap();
+
+            assert!(!msg.as_object().unwrap().contains_key("signature"));
+            assert_eq!(signatures[DID_TRUSTEE], r#"3YnLxoUd4utFLzeXUkeGefAqAdHUD7rBprpSx2CJeH7gRYnyjkgJi7tCnFgUiMo62k6M2AyUDtJrkUSgHfcq3vua"#);
+            assert_eq!(signatures[DID_MY1], r#"4EyvSFPoeQCJLziGVqjuMxrbuoWjAWUGPd6LdxeZuG9w3Bcbt7cSvhjrv8SX5e8mGf8jrf3K6xd9kEhXsQLqUg45"#);
+        }
     }
 
     mod nym_requests {
@@ -330,6 +524,46 @@ mod high_cases {
             check_request(&request, expected_result, IDENTIFIER);
         }
 
+        #[test]
+        fn indy_build_nym_request_with_data_works() {
+            Setup::empty();
+
+            let diddoc_content = json!({"service": [{"id": "#agent", "type": "DIDCommMessaging", "serviceEndpoint": "https://agent.example.com"}]});
+
+            let expected_result = json!({
+                "dest": DEST,
+                "role": "2",
+                "type": constants::NYM,
+                "verkey": VERKEY_TRUSTEE,
+                "diddocContent": diddoc_content.to_string(),
+                "version": 1
+            });
+
+            let nym_data = json!({
+                "verkey": VERKEY_TRUSTEE,
+                "role": "STEWARD",
+                "diddocContent": diddoc_content,
+                "version": 1
+            }).to_string();
+
+            let request = ledger::build_nym_request_with_data(&IDENTIFIER, &DEST, &nym_data).unwrap();
+            check_request(&request, expected_result, IDENTIFIER);
+        }
+
+        #[test]
+        fn indy_build_nym_request_with_data_works_for_reset_role() {
+            Setup::empty();
+
+            let expected_result = json!({
+                "dest": DEST,
+                "role": serde_json::Value::Null,
+                "type": constants::NYM
+            });
+
+            let request = ledger::build_nym_request_with_data(&IDENTIFIER, &DEST, r#"{"role": "RESET"}"#).unwrap();
+            check_request(&request, expected_result, IDENTIFIER);
+        }
+
         #[test]
         #[cfg(feature = "local_nodes_pool")]
         fn indy_build_nym_requests_works_for_empty_role() {
@@ -386,6 +620,47 @@ mod high_cases {
             check_default_identifier(&request);
         }
 
+        #[test]
+        fn indy_build_get_historical_nym_request_works_for_timestamp() {
+            let expected_result = json!({
+                "type": constants::GET_NYM,
+                "dest": DEST,
+                "timestamp": 1589000000
+            });
+
+            let request = ledger::build_get_historical_nym_request(Some(IDENTIFIER), &DEST, None, Some(1589000000)).unwrap();
+            check_request(&request, expected_result, IDENTIFIER);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_historical_nym_works_for_rotated_verkey() {
+            let setup = Setup::trustee();
+
+            let (my_did, my_verkey) = did::create_and_store_my_did(setup.wallet_handle, None).unwrap();
+
+            let nym_request = ledger::build_nym_request(&setup.did, &my_did, Some(&my_verkey), None, None).unwrap();
+            let nym_resp = ledger::sign_and_submit_request(setup.pool_handle, setup.wallet_handle, &setup.did, &nym_request).unwrap();
+            pool::check_response_type(&nym_resp, ResponseType::REPLY);
+
+            let nym_resp_metadata = ledger::get_response_metadata(&nym_resp).unwrap();
+            let nym_resp_metadata: serde_json::Value = serde_json::from_str(&nym_resp_metadata).unwrap();
+            let seq_no = nym_resp_metadata["seqNo"].as_u64().unwrap();
+
+            let new_verkey = did::replace_keys_start(setup.wallet_handle, &my_did, "{}").unwrap();
+            let nym_request = ledger::build_nym_request(&my_did, &my_did, Some(&new_verkey), None, None).unwrap();
+            let rotate_resp = ledger::sign_and_submit_request(setup.pool_handle, setup.wallet_handle, &my_did, &nym_request).unwrap();
+            pool::check_response_type(&rotate_resp, ResponseType::REPLY);
+
+            let get_nym_request = ledger::build_get_historical_nym_request(None, &my_did, Some(seq_no), None).unwrap();
+            let get_nym_response = ledger::submit_request_with_retries(setup.pool_handle, &get_nym_request, &rotate_resp).unwrap();
+            let data = ledger::parse_get_nym_response(&get_nym_response).unwrap();
+
+            let nym_data: NymData = serde_json::from_str(&data).unwrap();
+            assert_eq!(my_verkey, nym_data.verkey.unwrap());
+            assert_eq!(Some(seq_no), nym_data.seq_no);
+        }
+
         #[test]
         #[cfg(feature = "local_nodes_pool")]
         fn indy_send_get_nym_request_works() {
@@ -421,6 +696,42 @@ mod high_cases {
     mod attrib_requests {
         use super::*;
 
+        #[test]
+        fn indy_build_set_endpoint_request_works() {
+            let expected_result = json!({
+                "type": constants::ATTRIB,
+                "dest": DEST,
+                "raw": json!({"endpoint": {"ha": ENDPOINT, "verkey": VERKEY_TRUSTEE}}).to_string()
+            });
+
+            let request = ledger::build_set_endpoint_request(&IDENTIFIER, &DEST, ENDPOINT, Some(VERKEY_TRUSTEE)).unwrap();
+            check_request_operation(&request, expected_result);
+        }
+
+        #[test]
+        fn indy_build_set_endpoint_request_works_for_address_only() {
+            let expected_result = json!({
+                "type": constants::ATTRIB,
+                "dest": DEST,
+                "raw": json!({"endpoint": {"ha": ENDPOINT}}).to_string()
+            });
+
+            let request = ledger::build_set_endpoint_request(&IDENTIFIER, &DEST, ENDPOINT, None).unwrap();
+            check_request_operation(&request, expected_result);
+        }
+
+        #[test]
+        fn indy_build_get_endpoint_request_works() {
+            let expected_result = json!({
+                "type": constants::GET_ATTR,
+                "dest": DEST,
+                "raw": "endpoint"
+            });
+
+            let request = ledger::build_get_endpoint_request(Some(IDENTIFIER), &DEST).unwrap();
+            check_request(&request, expected_result, IDENTIFIER);
+        }
+
         #[test]
         #[cfg(feature = "local_nodes_pool")]
         fn indy_build_attrib_requests_works_for_raw_value() {
@@ -495,6 +806,21 @@ mod high_cases {
             check_request_operation(&request, expected_result);
         }
 
+        #[test]
+        fn indy_build_get_historical_attrib_request_works_for_seq_no() {
+            let raw = "endpoint";
+
+            let expected_result = json!({
+                "type": constants::GET_ATTR,
+                "dest": DEST,
+                "raw": raw,
+                "seqNo": 10
+            });
+
+            let request = ledger::build_get_historical_attrib_request(Some(IDENTIFIER), &DEST, Some(raw), None, None, Some(10), None).unwrap();
+            check_request_operation(&request, expected_result);
+        }
+
         #[test]
         #[cfg(feature = "local_nodes_pool")]
         fn indy_build_get_attrib_requests_works_for_hash_value() {
@@ -561,6 +887,54 @@ mod high_cases {
             let get_attrib_response: Reply<GetAttribReplyResult> = serde_json::from_str(&get_attrib_response).unwrap();
             assert_eq!(get_attrib_response.result.data.unwrap().as_str(), ATTRIB_RAW_DATA);
         }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_parse_get_attrib_response_works_for_raw_value() {
+            let setup = Setup::new_identity();
+
+            let attrib_request = ledger::build_attrib_request(&setup.did, &setup.did, None, Some(ATTRIB_RAW_DATA), None).unwrap();
+            let attrib_req_resp = ledger::sign_and_submit_request(setup.pool_handle, setup.wallet_handle, &setup.did, &attrib_request).unwrap();
+            pool::check_response_type(&attrib_req_resp, ResponseType::REPLY);
+
+            let get_attrib_request = ledger::build_get_attrib_request(Some(&setup.did), &setup.did, Some("endpoint"), None, None).unwrap();
+            let get_attrib_response = ledger::submit_request_with_retries(setup.pool_handle, &get_attrib_request, &attrib_req_resp).unwrap();
+
+            let attrib = ledger::parse_get_attrib_response(&get_attrib_response).unwrap();
+            let attrib: serde_json::Value = serde_json::from_str(&attrib).unwrap();
+
+            assert_eq!(json!(setup.did), attrib["data"]["did"]);
+            assert_eq!(json!(ATTRIB_RAW_DATA), attrib["data"]["raw"]);
+            assert!(attrib["seqNo"].is_u64());
+            assert!(attrib.get("state_proof").is_none());
+        }
+
+        #[test]
+        fn indy_parse_get_attrib_response_works_for_hash_value() {
+            let get_attrib_response = json!({
+                "op": "REPLY",
+                "result": {
+                    "type": constants::GET_ATTR,
+                    "identifier": IDENTIFIER,
+                    "reqId": 1,
+                    "dest": IDENTIFIER,
+                    "hash": ATTRIB_HASH_DATA,
+                    "data": ATTRIB_HASH_DATA,
+                    "seqNo": 10,
+                    "txnTime": 1589000000,
+                    "state_proof": {"root_hash": "7Wdj3rrMCZ1R1M78H4xK5jxikmdUUGW2kbfJQ1HoEpK", "proof_nodes": "", "multi_signature": {}}
+                }
+            }).to_string();
+
+            let attrib = ledger::parse_get_attrib_response(&get_attrib_response).unwrap();
+            let attrib: serde_json::Value = serde_json::from_str(&attrib).unwrap();
+
+            assert_eq!(json!({
+                "data": {"did": IDENTIFIER, "hash": ATTRIB_HASH_DATA},
+                "seqNo": 10,
+                "txnTime": 1589000000
+            }), attrib);
+        }
     }
 
     mod schema_requests {
@@ -831,6 +1205,81 @@ mod high_cases {
                 serde_json::from_str::<Reply<GetValidatorInfoResult>>(value).unwrap();
             }
         }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_parse_get_validator_info_response_works() {
+            let setup = Setup::trustee();
+
+            let get_validator_info_request = ledger::build_get_validator_info_request(&setup.did).unwrap();
+            let get_validator_info_response = ledger::sign_and_submit_request(setup.pool_handle, setup.wallet_handle, &setup.did, &get_validator_info_request).unwrap();
+
+            let validator_info = ledger::parse_get_validator_info_response(&get_validator_info_response).unwrap();
+            let validator_info: serde_json::Value = serde_json::from_str(&validator_info).unwrap();
+
+            for node_reply in validator_info["data"].as_object().unwrap().values() {
+                assert!(node_reply["data"].is_object());
+            }
+        }
+
+        #[test]
+        fn indy_parse_get_validator_info_response_works_for_timeout() {
+            let get_validator_info_response = json!({"Node1": "timeout"}).to_string();
+
+            let validator_info = ledger::parse_get_validator_info_response(&get_validator_info_response).unwrap();
+            let validator_info: serde_json::Value = serde_json::from_str(&validator_info).unwrap();
+
+            assert_eq!(json!({"data": {"Node1": {"error": "timeout"}}}), validator_info);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_parse_get_validator_info_summary_response_works() {
+            let setup = Setup::trustee();
+
+            let get_validator_info_request = ledger::build_get_validator_info_request(&setup.did).unwrap();
+            let get_validator_info_response = ledger::sign_and_submit_request(setup.pool_handle, setup.wallet_handle, &setup.did, &get_validator_info_request).unwrap();
+
+            let summary = ledger::parse_get_validator_info_summary_response(&get_validator_info_response).unwrap();
+            let summary: serde_json::Value = serde_json::from_str(&summary).unwrap();
+
+            for (alias, node_summary) in summary["data"].as_object().unwrap() {
+                assert_eq!(alias, node_summary["alias"].as_str().unwrap());
+                assert!(node_summary["nodeVersion"].is_string());
+                assert!(node_summary["ledgerSizes"]["pool"].as_u64().is_some());
+            }
+        }
+
+        #[test]
+        fn indy_parse_get_validator_info_summary_response_works_for_timeout() {
+            let get_validator_info_response = json!({"Node1": "timeout"}).to_string();
+
+            let summary = ledger::parse_get_validator_info_summary_response(&get_validator_info_response).unwrap();
+            let summary: serde_json::Value = serde_json::from_str(&summary).unwrap();
+
+            assert_eq!(json!({"data": {"Node1": {"alias": "Node1", "error": "timeout"}}}), summary);
+        }
+
+        #[test]
+        fn indy_parse_pool_upgrade_status_response_works() {
+            let node_data = json!({
+                "Node_info": {"Name": "Node1"},
+                "Software": {"indy-node": "1.12.2"},
+                "Extractions": {"upgrade_log": [
+                    "2020-01-28 14:29:57.391391\tscheduled\t2020-01-28 14:40:00+00:00\t1.12.3\t1580221797391391000\tindy-node"
+                ]}
+            });
+            let node_reply = json!({"op": "REPLY", "result": {"type": constants::GET_VALIDATOR_INFO, "data": node_data}}).to_string();
+            let get_validator_info_response = json!({"Node1": node_reply, "Node2": "timeout"}).to_string();
+
+            let status = ledger::parse_pool_upgrade_status_response(&get_validator_info_response).unwrap();
+            let status: serde_json::Value = serde_json::from_str(&status).unwrap();
+
+            assert_eq!("1.12.2", status["data"]["Node1"]["nodeVersion"]);
+            assert_eq!("scheduled", status["data"]["Node1"]["lastEvent"]["event"]);
+            assert_eq!("1.12.3", status["data"]["Node1"]["lastEvent"]["version"]);
+            assert_eq!(json!({"alias": "Node2", "error": "timeout"}), status["data"]["Node2"]);
+        }
     }
 
     mod get_txn_requests {
@@ -914,6 +1363,86 @@ mod high_cases {
             let expected_schema_data: SchemaData = serde_json::from_str(r#"{"name":"gvt","version":"1.0","attr_names":["name", "age", "sex", "height"]}"#).unwrap();
             assert_eq!(expected_schema_data, get_txn_schema_data);
         }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_parse_get_txn_response_works() {
+            let setup = Setup::new_identity();
+
+            let schema_request = ledger::build_schema_request(&setup.did, &anoncreds::gvt_schema_json()).unwrap();
+            let schema_response = ledger::sign_and_submit_request(setup.pool_handle, setup.wallet_handle, &setup.did, &schema_request).unwrap();
+            pool::check_response_type(&schema_response, ResponseType::REPLY);
+
+            let seq_no = ledger::extract_seq_no_from_reply(&schema_response).unwrap() as i32;
+
+            thread::sleep(std::time::Duration::from_secs(1));
+
+            let get_txn_request = ledger::build_get_txn_request(Some(&setup.did), seq_no, None).unwrap();
+            let get_txn_response = ledger::submit_request(setup.pool_handle, &get_txn_request).unwrap();
+
+            let txn = ledger::parse_get_txn_response(&get_txn_response).unwrap();
+            let txn: serde_json::Value = serde_json::from_str(&txn).unwrap();
+
+            assert_eq!(json!(constants::SCHEMA), txn["data"]["type"]);
+            assert_eq!(json!("gvt"), txn["data"]["data"]["data"]["name"]);
+            assert_eq!(json!(seq_no), txn["seqNo"]);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_txns_works() {
+            let setup = Setup::pool();
+
+            // genesis pool ledger contains 4 NODE transactions
+            let txns = ledger::get_txns(setup.pool_handle, None, Some("POOL"), 1, 10).unwrap();
+            let txns: Vec<serde_json::Value> = serde_json::from_str(&txns).unwrap();
+
+            assert_eq!(4, txns.len());
+            for (i, txn) in txns.iter().enumerate() {
+                assert_eq!(json!(i + 1), txn["seqNo"]);
+                assert_eq!(json!(constants::NODE), txn["data"]["type"]);
+            }
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_stream_txns_works() {
+            let setup = Setup::pool();
+
+            let batches = ledger::stream_txns(setup.pool_handle, None, Some("POOL"), 2, i32::MAX).unwrap();
+
+            let txns: Vec<serde_json::Value> = batches.iter()
+                .flat_map(|batch| serde_json::from_str::<Vec<serde_json::Value>>(batch).unwrap())
+                .collect();
+
+            assert_eq!(3, txns.len());
+            assert_eq!(json!(2), txns[0]["seqNo"]);
+        }
+
+        #[test]
+        fn indy_subscribe_ledger_events_works() {
+            let setup = Setup::trustee();
+
+            let (sender, receiver) = std::sync::mpsc::channel();
+            let sender = std::sync::Mutex::new(sender);
+
+            let (did, verkey) = did::create_my_did(setup.wallet_handle, "{}").unwrap();
+
+            let filter = json!({"dids": [did], "txnTypes": ["NYM"], "pollInterval": 1}).to_string();
+            let subscription_handle = ledger::subscribe_ledger_events(setup.pool_handle, &filter, Box::new(move |event_json| {
+                sender.lock().unwrap().send(event_json).unwrap();
+            })).unwrap();
+
+            let nym_request = ledger::build_nym_request(&setup.did, &did, Some(&verkey), None, None).unwrap();
+            let nym_response = ledger::sign_and_submit_request(setup.pool_handle, setup.wallet_handle, &setup.did, &nym_request).unwrap();
+            pool::check_response_type(&nym_response, ResponseType::REPLY);
+
+            let event_json = receiver.recv_timeout(std::time::Duration::from_secs(60)).unwrap();
+            let event: serde_json::Value = serde_json::from_str(&event_json).unwrap();
+            assert_eq!(json!(did), event["data"]["txn"]["data"]["dest"]);
+
+            ledger::unsubscribe_ledger_events(subscription_handle).unwrap();
+        }
     }
 
     mod pool_config {
@@ -1142,6 +1671,28 @@ mod high_cases {
                                                              None).unwrap();
             ledger::sign_and_submit_request(setup.pool_handle, setup.wallet_handle, &setup.did, &request).unwrap();
         }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_build_scheduled_pool_upgrade_request_works() {
+            let setup = Setup::pool();
+
+            let upgrade = json!({
+                "name": "upgrade-libindy",
+                "version": "2.0.0",
+                "sha256": "f284bdc3c1c9e24a494e285cb387c69510f28de51c15bb93179d9c7f28705398",
+                "startTime": time::get_time().sec + 365 * 24 * 60 * 60,
+                "nodeOffsets": {"Node1": 0, "Node2": 3600, "Node3": 7200, "Node4": 10800}
+            }).to_string();
+
+            let request = ledger::build_scheduled_pool_upgrade_request(setup.pool_handle, DID_TRUSTEE, &upgrade).unwrap();
+            let request: serde_json::Value = serde_json::from_str(&request).unwrap();
+
+            assert_eq!("start", request["operation"]["action"]);
+            let schedule = request["operation"]["schedule"].as_object().unwrap();
+            assert_eq!(4, schedule.len());
+            assert!(schedule.contains_key("Gw6pDLhcBcoQesN72qfotTgFa7cbuqZpkX3Xo6pLhPhv"));
+        }
     }
 
     mod revoc_reg_def_requests {
@@ -1318,94 +1869,260 @@ mod high_cases {
         }
     }
 
-    mod get_revoc_reg_delta_request {
+    mod get_revoc_reg_delta_request {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_build_get_revoc_reg_delta_request() {
+            let expected_result = json!({
+                "type": constants::GET_REVOC_REG_DELTA,
+                "revocRegDefId": anoncreds::gvt_rev_reg_id(),
+                "to": 100
+            });
+
+            let request = ledger::build_get_revoc_reg_delta_request(Some(DID), &anoncreds::gvt_rev_reg_id(), None, 100).unwrap();
+            check_request_operation(&request, expected_result);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_build_get_revoc_reg_delta_request_for_fully_qualified() {
+            let expected_result = json!({
+                "type": constants::GET_REVOC_REG_DELTA,
+                "revocRegDefId": anoncreds::gvt_rev_reg_id(),
+                "to": 100
+            });
+
+            let request = ledger::build_get_revoc_reg_delta_request(Some(DID_V1), &anoncreds::gvt_rev_reg_id_fully_qualified(), None, 100).unwrap();
+            check_request_operation(&request, expected_result);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_build_get_revoc_reg_delta_request_for_default_submitter_did() {
+            let request = ledger::build_get_revoc_reg_delta_request(None, &anoncreds::gvt_rev_reg_id(), None, 100).unwrap();
+            check_default_identifier(&request);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_revoc_reg_delta_request_works() {
+            let setup = Setup::pool();
+
+            let (_, _, rev_reg_id) = ledger::post_entities();
+
+            let to = time::get_time().sec as u64 + 300;
+            let get_rev_reg_delta_req = ledger::build_get_revoc_reg_delta_request(Some(DID_MY1), &rev_reg_id, None, to).unwrap();
+            let get_rev_reg_delta_resp = ledger::submit_request(setup.pool_handle, &get_rev_reg_delta_req).unwrap();
+
+            let (_, revoc_reg_delta_json, _) = ledger::parse_get_revoc_reg_delta_response(&get_rev_reg_delta_resp).unwrap();
+
+            let _revoc_reg_delta: RevocationRegistryDeltaV1 = serde_json::from_str(&revoc_reg_delta_json).unwrap();
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_revoc_reg_delta_request_works_for_two_timestamps() {
+            let setup = Setup::pool();
+
+            let (_, _, rev_reg_id) = ledger::post_entities();
+
+            let from = time::get_time().sec as u64;
+            let to = time::get_time().sec as u64 + 300;
+            let get_rev_reg_delta_req = ledger::build_get_revoc_reg_delta_request(Some(DID_MY1), &rev_reg_id, Some(from), to).unwrap();
+            let get_rev_reg_delta_resp = ledger::submit_request(setup.pool_handle, &get_rev_reg_delta_req).unwrap();
+
+            let (_, revoc_reg_delta_json, _) = ledger::parse_get_revoc_reg_delta_response(&get_rev_reg_delta_resp).unwrap();
+
+            let _revoc_reg_delta: RevocationRegistryDeltaV1 = serde_json::from_str(&revoc_reg_delta_json).unwrap();
+        }
+    }
+
+    mod indy_register_transaction_parser_for_sp {
+        extern crate libc;
+
+        use super::*;
+
+        use self::libc::c_char;
+
+        #[test]
+        fn indy_register_transaction_parser_for_sp_works() {
+            Setup::empty();
+
+            extern fn parse(msg: *const c_char, parsed: *mut *const c_char) -> i32 {
+                unsafe { *parsed = msg; }
+                ErrorCode::Success as i32
+            }
+            extern fn free(_buf: *const c_char) -> i32 { ErrorCode::Success as i32 }
+
+            ledger::register_transaction_parser_for_sp("my_txn_type", parse, free).unwrap();
+        }
+    }
+
+    mod indy_register_ledger_transaction_builder {
+        extern crate libc;
+
+        use super::*;
+
+        use self::libc::c_char;
+        use std::ffi::{CStr, CString};
+
+        extern fn build(data: *const c_char, operation: *mut *const c_char) -> i32 {
+            let data = unsafe { CStr::from_ptr(data) }.to_str().unwrap();
+            let data: serde_json::Value = serde_json::from_str(data).unwrap();
+            unsafe { *operation = CString::new(json!({"config": data}).to_string()).unwrap().into_raw(); }
+            ErrorCode::Success as i32
+        }
+
+        extern fn parse(_response: *const c_char, parsed: *mut *const c_char) -> i32 {
+            unsafe { *parsed = CString::new(r#"{"config":"parsed"}"#).unwrap().into_raw(); }
+            ErrorCode::Success as i32
+        }
+
+        extern fn free(buf: *const c_char) -> i32 {
+            drop(unsafe { CString::from_raw(buf as *mut c_char) });
+            ErrorCode::Success as i32
+        }
+
+        #[test]
+        fn indy_build_custom_request_works() {
+            let setup = Setup::did();
+
+            ledger::register_ledger_transaction_builder("10001", build, Some(parse), free).unwrap();
+
+            let request = ledger::build_custom_request(Some(&setup.did), "10001", r#"{"param":"value"}"#).unwrap();
+
+            let expected_result = json!({
+                "type": "10001",
+                "config": {"param": "value"}
+            });
+            check_request(&request, expected_result, &setup.did);
+
+            let request = ledger::sign_request(setup.wallet_handle, &setup.did, &request).unwrap();
+            let request: serde_json::Value = serde_json::from_str(&request).unwrap();
+            assert!(request["signature"].is_string());
+        }
+
+        #[test]
+        fn indy_parse_custom_response_works() {
+            Setup::empty();
+
+            ledger::register_ledger_transaction_builder("10002", build, Some(parse), free).unwrap();
+
+            let response = json!({"op": "REPLY", "result": {"type": "10002", "data": {}}}).to_string();
+
+            let parsed = ledger::parse_custom_response("10002", &response).unwrap();
+            assert_eq!(r#"{"config":"parsed"}"#, parsed);
+        }
+
+        #[test]
+        #[cfg(not(feature = "only_high_cases"))]
+        fn indy_register_ledger_transaction_builder_works_for_default_type() {
+            Setup::empty();
+
+            let res = ledger::register_ledger_transaction_builder(constants::NYM, build, None, free);
+            assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
+        }
+
+        #[test]
+        #[cfg(not(feature = "only_high_cases"))]
+        fn indy_build_custom_request_works_for_unregistered_type() {
+            Setup::empty();
+
+            let res = ledger::build_custom_request(None, "10003", "{}");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
+
+    mod indy_register_ledger_middleware {
+        extern crate libc;
+
         use super::*;
 
-        #[test]
-        #[cfg(feature = "local_nodes_pool")]
-        fn indy_build_get_revoc_reg_delta_request() {
-            let expected_result = json!({
-                "type": constants::GET_REVOC_REG_DELTA,
-                "revocRegDefId": anoncreds::gvt_rev_reg_id(),
-                "to": 100
-            });
+        use self::libc::c_char;
+        use std::ffi::{CStr, CString};
 
-            let request = ledger::build_get_revoc_reg_delta_request(Some(DID), &anoncreds::gvt_rev_reg_id(), None, 100).unwrap();
-            check_request_operation(&request, expected_result);
+        extern fn reject_request(_request: *const c_char, _out_request: *mut *const c_char) -> i32 {
+            ErrorCode::CommonInvalidState as i32
         }
 
-        #[test]
-        #[cfg(feature = "local_nodes_pool")]
-        fn indy_build_get_revoc_reg_delta_request_for_fully_qualified() {
-            let expected_result = json!({
-                "type": constants::GET_REVOC_REG_DELTA,
-                "revocRegDefId": anoncreds::gvt_rev_reg_id(),
-                "to": 100
-            });
+        extern fn decorate_reply(reply: *const c_char, out_reply: *mut *const c_char) -> i32 {
+            let reply = unsafe { CStr::from_ptr(reply) }.to_str().unwrap();
+            let mut reply: serde_json::Value = serde_json::from_str(reply).unwrap();
+            reply["decorated"] = json!(true);
+            unsafe { *out_reply = CString::new(reply.to_string()).unwrap().into_raw(); }
+            ErrorCode::Success as i32
+        }
 
-            let request = ledger::build_get_revoc_reg_delta_request(Some(DID_V1), &anoncreds::gvt_rev_reg_id_fully_qualified(), None, 100).unwrap();
-            check_request_operation(&request, expected_result);
+        extern fn free(buf: *const c_char) -> i32 {
+            drop(unsafe { CString::from_raw(buf as *mut c_char) });
+            ErrorCode::Success as i32
         }
 
         #[test]
-        #[cfg(feature = "local_nodes_pool")]
-        fn indy_build_get_revoc_reg_delta_request_for_default_submitter_did() {
-            let request = ledger::build_get_revoc_reg_delta_request(None, &anoncreds::gvt_rev_reg_id(), None, 100).unwrap();
-            check_default_identifier(&request);
+        fn indy_register_ledger_middleware_works_for_request() {
+            Setup::empty();
+
+            let middleware_handle = ledger::register_ledger_middleware(Some(reject_request), None, free).unwrap();
+
+            let res = ledger::submit_request(INVALID_POOL_HANDLE, REQUEST);
+            assert_code!(ErrorCode::CommonInvalidState, res);
+
+            ledger::unregister_ledger_middleware(middleware_handle).unwrap();
+
+            let res = ledger::submit_request(INVALID_POOL_HANDLE, REQUEST);
+            assert_code!(ErrorCode::PoolLedgerInvalidPoolHandle, res);
         }
 
         #[test]
         #[cfg(feature = "local_nodes_pool")]
-        fn indy_get_revoc_reg_delta_request_works() {
+        fn indy_register_ledger_middleware_works_for_reply() {
             let setup = Setup::pool();
 
-            let (_, _, rev_reg_id) = ledger::post_entities();
+            let middleware_handle = ledger::register_ledger_middleware(None, Some(decorate_reply), free).unwrap();
 
-            let to = time::get_time().sec as u64 + 300;
-            let get_rev_reg_delta_req = ledger::build_get_revoc_reg_delta_request(Some(DID_MY1), &rev_reg_id, None, to).unwrap();
-            let get_rev_reg_delta_resp = ledger::submit_request(setup.pool_handle, &get_rev_reg_delta_req).unwrap();
+            let get_nym_request = ledger::build_get_nym_request(None, DID_TRUSTEE).unwrap();
+            let response = ledger::submit_request(setup.pool_handle, &get_nym_request);
 
-            let (_, revoc_reg_delta_json, _) = ledger::parse_get_revoc_reg_delta_response(&get_rev_reg_delta_resp).unwrap();
+            ledger::unregister_ledger_middleware(middleware_handle).unwrap();
 
-            let _revoc_reg_delta: RevocationRegistryDeltaV1 = serde_json::from_str(&revoc_reg_delta_json).unwrap();
+            let response: serde_json::Value = serde_json::from_str(&response.unwrap()).unwrap();
+            assert_eq!(json!(true), response["decorated"]);
         }
 
         #[test]
-        #[cfg(feature = "local_nodes_pool")]
-        fn indy_get_revoc_reg_delta_request_works_for_two_timestamps() {
-            let setup = Setup::pool();
-
-            let (_, _, rev_reg_id) = ledger::post_entities();
+        #[cfg(not(feature = "only_high_cases"))]
+        fn indy_register_ledger_middleware_works_for_no_callbacks() {
+            Setup::empty();
 
-            let from = time::get_time().sec as u64;
-            let to = time::get_time().sec as u64 + 300;
-            let get_rev_reg_delta_req = ledger::build_get_revoc_reg_delta_request(Some(DID_MY1), &rev_reg_id, Some(from), to).unwrap();
-            let get_rev_reg_delta_resp = ledger::submit_request(setup.pool_handle, &get_rev_reg_delta_req).unwrap();
+            let res = ledger::register_ledger_middleware(None, None, free);
+            assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
+        }
 
-            let (_, revoc_reg_delta_json, _) = ledger::parse_get_revoc_reg_delta_response(&get_rev_reg_delta_resp).unwrap();
+        #[test]
+        #[cfg(not(feature = "only_high_cases"))]
+        fn indy_unregister_ledger_middleware_works_for_unknown_handle() {
+            Setup::empty();
 
-            let _revoc_reg_delta: RevocationRegistryDeltaV1 = serde_json::from_str(&revoc_reg_delta_json).unwrap();
+            let res = ledger::unregister_ledger_middleware(INVALID_POOL_HANDLE);
+            assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
         }
     }
 
-    mod indy_register_transaction_parser_for_sp {
-        extern crate libc;
-
+    mod verify_state_proof {
         use super::*;
 
-        use self::libc::c_char;
-
         #[test]
-        fn indy_register_transaction_parser_for_sp_works() {
-            Setup::empty();
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_verify_state_proof_works_for_get_nym_reply() {
+            let setup = Setup::pool();
 
-            extern fn parse(msg: *const c_char, parsed: *mut *const c_char) -> i32 {
-                unsafe { *parsed = msg; }
-                ErrorCode::Success as i32
-            }
-            extern fn free(_buf: *const c_char) -> i32 { ErrorCode::Success as i32 }
+            let get_nym_request = ledger::build_get_nym_request(None, DID_TRUSTEE).unwrap();
+            let get_nym_response = ledger::submit_request(setup.pool_handle, &get_nym_request).unwrap();
 
-            ledger::register_transaction_parser_for_sp("my_txn_type", parse, free).unwrap();
+            let valid = ledger::verify_state_proof(&get_nym_request, &get_nym_response, &pool::test_pool_bls_keys()).unwrap();
+            assert!(valid);
         }
     }
 
@@ -2240,6 +2957,144 @@ mod high_cases {
             _change_constraint(setup.pool_handle, setup.wallet_handle, &setup.did, action2.0,
                                action2.1, action2.2, action2.3, action2.4, &default_constraint_action_2);
         }
+
+        #[test]
+        fn indy_build_role_constraint_works() {
+            Setup::empty();
+
+            let constraint = ledger::build_role_constraint("TRUSTEE", 1, false, false, Some("{}")).unwrap();
+
+            let expected_constraint: serde_json::Value = serde_json::from_str(ROLE_CONSTRAINT).unwrap();
+            assert_eq!(expected_constraint, serde_json::from_str::<serde_json::Value>(&constraint).unwrap());
+        }
+
+        #[test]
+        fn indy_build_combined_constraint_works() {
+            Setup::empty();
+
+            let trustee_constraint = ledger::build_role_constraint(constants::TRUSTEE, 1, false, false, None).unwrap();
+            let owner_constraint = ledger::build_role_constraint("*", 1, true, false, None).unwrap();
+
+            let constraint = ledger::build_combined_constraint("OR", &format!("[{}, {}]", trustee_constraint, owner_constraint)).unwrap();
+
+            let expected_result = json!({
+                "type": constants::AUTH_RULE,
+                "auth_type": constants::NYM,
+                "auth_action": EDIT_AUTH_ACTION,
+                "field": FIELD,
+                "old_value": VALUE,
+                "new_value": NEW_VALUE,
+                "constraint": {
+                    "constraint_id": "OR",
+                    "auth_constraints": [
+                        {"constraint_id": "ROLE", "role": "0", "sig_count": 1, "need_to_be_owner": false},
+                        {"constraint_id": "ROLE", "role": "*", "sig_count": 1, "need_to_be_owner": true}
+                    ]
+                },
+            });
+
+            let request = ledger::build_auth_rule_request(DID_TRUSTEE, constants::NYM, EDIT_AUTH_ACTION, FIELD,
+                                                          Some(VALUE), Some(NEW_VALUE), &constraint).unwrap();
+            check_request_operation(&request, expected_result);
+        }
+
+        #[test]
+        fn indy_evaluate_auth_rules_works() {
+            Setup::empty();
+
+            let auth_rules = json!([{
+                "auth_type": constants::NYM,
+                "auth_action": ADD_AUTH_ACTION,
+                "field": FIELD,
+                "old_value": null,
+                "new_value": VALUE,
+                "constraint": serde_json::from_str::<serde_json::Value>(ROLE_CONSTRAINT).unwrap()
+            }]).to_string();
+
+            let action = json!({"auth_type": "NYM", "auth_action": ADD_AUTH_ACTION, "field": FIELD, "new_value": VALUE}).to_string();
+
+            let evaluation = ledger::evaluate_auth_rules(&auth_rules, &action, &json!([{"did": DID_TRUSTEE, "role": VALUE}]).to_string()).unwrap();
+            let evaluation: serde_json::Value = serde_json::from_str(&evaluation).unwrap();
+
+            assert!(evaluation["allowed"].as_bool().unwrap());
+            assert_eq!(json!([]), evaluation["missingSignatures"]);
+
+            let evaluation = ledger::evaluate_auth_rules(&auth_rules, &action, &json!([{"did": DID_MY1}]).to_string()).unwrap();
+            let evaluation: serde_json::Value = serde_json::from_str(&evaluation).unwrap();
+
+            assert!(!evaluation["allowed"].as_bool().unwrap());
+            assert_eq!(json!([{"role": VALUE, "count": 1}]), evaluation["missingSignatures"]);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_evaluate_auth_rules_works_for_ledger_rules() {
+            let setup = Setup::pool();
+
+            let get_auth_rule_request = ledger::build_get_auth_rule_request(None, None, None, None, None, None).unwrap();
+            let response = ledger::submit_request(setup.pool_handle, &get_auth_rule_request).unwrap();
+
+            let auth_rules = ledger::parse_get_auth_rule_response(&response).unwrap();
+            let auth_rules: serde_json::Value = serde_json::from_str(&auth_rules).unwrap();
+
+            let action = json!({"auth_type": "NYM", "auth_action": ADD_AUTH_ACTION, "field": FIELD, "new_value": VALUE}).to_string();
+            let signers = json!([{"did": DID_TRUSTEE, "role": constants::TRUSTEE}]).to_string();
+
+            let evaluation = ledger::evaluate_auth_rules(&auth_rules["data"].to_string(), &action, &signers).unwrap();
+            let evaluation: serde_json::Value = serde_json::from_str(&evaluation).unwrap();
+
+            assert!(evaluation["allowed"].as_bool().unwrap());
+        }
+
+        #[test]
+        fn indy_diff_auth_rules_works() {
+            Setup::empty();
+
+            let rule = |new_value: &str, role: &str| json!({
+                "auth_type": constants::NYM,
+                "auth_action": ADD_AUTH_ACTION,
+                "field": FIELD,
+                "old_value": null,
+                "new_value": new_value,
+                "constraint": {"constraint_id": "ROLE", "role": role, "sig_count": 1, "need_to_be_owner": false, "metadata": {}}
+            });
+
+            let current = json!([rule(VALUE, constants::TRUSTEE), rule(NEW_VALUE, constants::TRUSTEE)]).to_string();
+            let proposed = json!([rule(VALUE, constants::STEWARD), rule(NEW_VALUE, constants::TRUSTEE)]).to_string();
+
+            let diff = ledger::diff_auth_rules(&current, &proposed).unwrap();
+            let diff: serde_json::Value = serde_json::from_str(&diff).unwrap();
+
+            assert_eq!(json!([]), diff["added"]);
+            assert_eq!(json!([]), diff["removed"]);
+            assert_eq!(json!(VALUE), diff["changed"][0]["proposed"]["new_value"]);
+            assert_eq!(json!(constants::STEWARD), diff["changed"][0]["proposed"]["constraint"]["role"]);
+            assert_eq!(1, diff["unchanged"].as_u64().unwrap());
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_auth_rules_works() {
+            let setup = Setup::pool();
+
+            let all = ledger::get_auth_rules(setup.pool_handle, None, None).unwrap();
+            let all: serde_json::Value = serde_json::from_str(&all).unwrap();
+
+            let total = all["total"].as_u64().unwrap();
+            assert_eq!(total as usize, all["rules"].as_array().unwrap().len());
+            assert!(all["nextOffset"].is_null());
+
+            let page = ledger::get_auth_rules(setup.pool_handle, None, Some(r#"{"offset": 1, "limit": 2}"#)).unwrap();
+            let page: serde_json::Value = serde_json::from_str(&page).unwrap();
+
+            assert_eq!(all["rules"][1], page["rules"][0]);
+            assert_eq!(all["rules"][2], page["rules"][1]);
+            assert_eq!(json!(3), page["nextOffset"]);
+
+            let diff = ledger::diff_auth_rules(&all["rules"].to_string(), &all["rules"].to_string()).unwrap();
+            let diff: serde_json::Value = serde_json::from_str(&diff).unwrap();
+            assert_eq!(json!(total), diff["unchanged"]);
+        }
     }
 
     mod author_agreement {
@@ -2352,6 +3207,31 @@ mod high_cases {
             let request = ledger::build_get_txn_author_agreement_request(None, Some(&data)).unwrap();
             check_request_operation(&request, expected_result);
         }
+
+        #[test]
+        fn indy_parse_get_txn_author_agreement_response_works() {
+            let get_txn_author_agreement_response = json!({
+                "op": "REPLY",
+                "result": {
+                    "type": constants::GET_TXN_AUTHR_AGRMT,
+                    "identifier": DID_TRUSTEE,
+                    "reqId": 1,
+                    "seqNo": 5,
+                    "txnTime": 1589000000,
+                    "data": {"text": TEXT, "version": VERSION, "digest": TAA_DIGEST, "ratification_ts": 12345},
+                    "state_proof": {"root_hash": "7Wdj3rrMCZ1R1M78H4xK5jxikmdUUGW2kbfJQ1HoEpK", "proof_nodes": "", "multi_signature": {}}
+                }
+            }).to_string();
+
+            let taa = ledger::parse_get_txn_author_agreement_response(&get_txn_author_agreement_response).unwrap();
+            let taa: serde_json::Value = serde_json::from_str(&taa).unwrap();
+
+            assert_eq!(json!({
+                "data": {"text": TEXT, "version": VERSION, "digest": TAA_DIGEST, "ratification_ts": 12345},
+                "seqNo": 5,
+                "txnTime": 1589000000
+            }), taa);
+        }
     }
 
     mod acceptance_mechanism {
@@ -2438,6 +3318,40 @@ mod high_cases {
             let res = ledger::build_get_acceptance_mechanisms_request(None, Some(123456789), Some(VERSION));
             assert_code!(ErrorCode::CommonInvalidStructure, res);
         }
+
+        #[test]
+        fn indy_parse_get_acceptance_mechanisms_response_works() {
+            let aml = json!({"acceptance mechanism label 1": "some acceptance mechanism description 1"});
+
+            let get_acceptance_mechanisms_response = json!({
+                "op": "REPLY",
+                "result": {
+                    "type": constants::GET_TXN_AUTHR_AGRMT_AML,
+                    "identifier": DID_TRUSTEE,
+                    "reqId": 1,
+                    "seqNo": 4,
+                    "txnTime": 1589000000,
+                    "data": {"aml": aml.clone(), "version": VERSION, "amlContext": "some context"}
+                }
+            }).to_string();
+
+            let aml_data = ledger::parse_get_acceptance_mechanisms_response(&get_acceptance_mechanisms_response).unwrap();
+            let aml_data: serde_json::Value = serde_json::from_str(&aml_data).unwrap();
+
+            assert_eq!(json!({"aml": aml, "version": VERSION, "amlContext": "some context"}), aml_data["data"]);
+            assert_eq!(json!(4), aml_data["seqNo"]);
+        }
+
+        #[test]
+        fn indy_parse_get_acceptance_mechanisms_response_works_for_not_found() {
+            let get_acceptance_mechanisms_response = json!({
+                "op": "REPLY",
+                "result": {"type": constants::GET_TXN_AUTHR_AGRMT_AML, "identifier": DID_TRUSTEE, "reqId": 1, "data": null}
+            }).to_string();
+
+            let res = ledger::parse_get_acceptance_mechanisms_response(&get_acceptance_mechanisms_response);
+            assert_code!(ErrorCode::LedgerNotFound, res);
+        }
     }
 
     mod author_agreement_acceptance {
@@ -2702,6 +3616,35 @@ mod high_cases {
             _disable_taa(setup.pool_handle, setup.wallet_handle, &setup.did);
         }
 
+        #[test]
+        fn indy_prepare_request_with_taa_works() {
+            let setup = Setup::trustee();
+
+            let (_, aml_label, _, _) = _set_aml(setup.pool_handle, setup.wallet_handle, &setup.did);
+            let (_, _, taa_digest, _) = _set_taa(setup.pool_handle, setup.wallet_handle, &setup.did);
+
+            let (did_, verkey_) = did::create_and_store_my_did(setup.wallet_handle, None).unwrap();
+
+            let nym_req = ledger::build_nym_request(&setup.did, &did_, Some(&verkey_), None, None).unwrap();
+            let nym_req = ledger::prepare_request_with_taa(setup.pool_handle, setup.wallet_handle, &setup.did, &nym_req, Some(&aml_label)).unwrap();
+
+            let request: serde_json::Value = serde_json::from_str(&nym_req).unwrap();
+            assert_eq!(request["taaAcceptance"]["taaDigest"], json!(taa_digest));
+            assert_eq!(request["taaAcceptance"]["mechanism"], json!(aml_label));
+
+            let nym_resp = ledger::sign_and_submit_request(setup.pool_handle, setup.wallet_handle, &setup.did, &nym_req).unwrap();
+            pool::check_response_type(&nym_resp, ResponseType::REPLY);
+
+            // previous acceptance is reused without specifying the mechanism
+            let attrib_req = ledger::build_attrib_request(&setup.did, &did_, None, Some(ATTRIB_RAW_DATA), None).unwrap();
+            let attrib_req = ledger::prepare_request_with_taa(setup.pool_handle, setup.wallet_handle, &setup.did, &attrib_req, None).unwrap();
+
+            let attrib_request: serde_json::Value = serde_json::from_str(&attrib_req).unwrap();
+            assert_eq!(attrib_request["taaAcceptance"], request["taaAcceptance"]);
+
+            _disable_taa(setup.pool_handle, setup.wallet_handle, &setup.did);
+        }
+
         #[test]
         #[cfg(not(feature = "only_high_cases"))]
         fn indy_reset_author_agreement_works() {
@@ -3014,6 +3957,38 @@ mod high_cases {
             let response = ledger::submit_request(setup.pool_handle, &request_2).unwrap();
             pool::check_response_type(&response, ResponseType::REQNACK);
         }
+
+        #[test]
+        fn indy_check_request_signatures_works() {
+            let setup = Setup::endorser();
+            let my_did = _setup_new_identity(setup.wallet_handle, setup.pool_handle);
+
+            let request = ledger::build_schema_request(&my_did, SCHEMA_DATA).unwrap();
+            let request = ledger::append_request_endorser(&request, &setup.did).unwrap();
+
+            let author_request = ledger::multi_sign_request(setup.wallet_handle, &my_did, &request).unwrap();
+
+            let report = ledger::check_request_signatures(setup.pool_handle, &author_request).unwrap();
+            let report: serde_json::Value = serde_json::from_str(&report).unwrap();
+
+            assert_eq!(json!([setup.did]), report["missingSigners"]);
+            assert!(!report["complete"].as_bool().unwrap());
+
+            let endorser_request = ledger::multi_sign_request(setup.wallet_handle, &setup.did, &request).unwrap();
+
+            let requests = format!("[{}, {}]", author_request, endorser_request);
+            let request = ledger::merge_multi_signed_requests(&requests).unwrap();
+
+            let report = ledger::check_request_signatures(setup.pool_handle, &request).unwrap();
+            let report: serde_json::Value = serde_json::from_str(&report).unwrap();
+
+            assert_eq!(2, report["signers"].as_array().unwrap().len());
+            assert!(report["signers"].as_array().unwrap().iter().all(|signer| signer["verified"].as_bool().unwrap()));
+            assert!(report["complete"].as_bool().unwrap());
+
+            let response = ledger::submit_request(setup.pool_handle, &request).unwrap();
+            pool::check_response_type(&response, ResponseType::REPLY);
+        }
     }
 }
 
@@ -3034,17 +4009,83 @@ mod medium_cases {
         fn indy_sign_and_submit_request_works_for_not_found_signer() {
             let setup = Setup::wallet_and_pool();
 
-            let res = ledger::sign_and_submit_request(setup.pool_handle, setup.wallet_handle, &DID, REQUEST);
-            assert_code!(ErrorCode::WalletItemNotFound, res);
+            let res = ledger::sign_and_submit_request(setup.pool_handle, setup.wallet_handle, &DID, REQUEST);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_submit_request_works_for_invalid_message() {
+            let setup = Setup::pool();
+
+            let res = ledger::submit_request(setup.pool_handle, "request");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_submit_requests_works_for_invalid_pool_handle() {
+            Setup::empty();
+
+            let requests = format!("[{}]", REQUEST);
+
+            let res = ledger::submit_requests(INVALID_POOL_HANDLE, &requests);
+            assert_code!(ErrorCode::PoolLedgerInvalidPoolHandle, res);
+        }
+
+        #[test]
+        fn indy_submit_requests_works_for_invalid_json() {
+            Setup::empty();
+
+            let res = ledger::submit_requests(INVALID_POOL_HANDLE, REQUEST);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_submit_request_with_options_works_for_invalid_timeout() {
+            Setup::empty();
+
+            let res = ledger::submit_request_with_options(INVALID_POOL_HANDLE, REQUEST, r#"{"timeout": 0}"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_submit_request_with_options_works_for_unknown_priority() {
+            Setup::empty();
+
+            let res = ledger::submit_request_with_options(INVALID_POOL_HANDLE, REQUEST, r#"{"priority": "urgent"}"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_submit_request_with_options_works_for_invalid_pool_handle() {
+            Setup::empty();
+
+            let res = ledger::submit_request_with_options(INVALID_POOL_HANDLE, REQUEST, r#"{"priority": "high"}"#);
+            assert_code!(ErrorCode::PoolLedgerInvalidPoolHandle, res);
+        }
+
+        #[test]
+        fn indy_submit_request_idempotent_works_for_unsupported_request_type() {
+            Setup::empty();
+
+            let get_nym_request = ledger::build_get_nym_request(Some(IDENTIFIER), DEST).unwrap();
+
+            let res = ledger::submit_request_idempotent(INVALID_POOL_HANDLE, &get_nym_request);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
         }
 
         #[test]
-        #[cfg(feature = "local_nodes_pool")]
-        fn indy_submit_request_works_for_invalid_message() {
-            let setup = Setup::pool();
+        fn indy_submit_request_idempotent_works_for_invalid_pool_handle() {
+            Setup::empty();
 
-            let res = ledger::submit_request(setup.pool_handle, "request");
-            assert_code!(ErrorCode::CommonInvalidStructure, res);
+            let (_, schema_json) = anoncreds::issuer_create_schema(IDENTIFIER,
+                                                                   GVT_SCHEMA_NAME,
+                                                                   SCHEMA_VERSION,
+                                                                   GVT_SCHEMA_ATTRIBUTES).unwrap();
+            let schema_request = ledger::build_schema_request(IDENTIFIER, &schema_json).unwrap();
+
+            let res = ledger::submit_request_idempotent(INVALID_POOL_HANDLE, &schema_request);
+            assert_code!(ErrorCode::PoolLedgerInvalidPoolHandle, res);
         }
 
         #[test]
@@ -3164,11 +4205,82 @@ mod medium_cases {
             assert_eq!(1, signatures.len());
             assert_eq!(signatures[DID_MY1], r#"49aXkbrtTE3e522AefE76J51WzUiakw3ZbxxWzf44cv7RS21n8mMr4vJzi4TymuqDupzCz7wEtuGz6rA94Y73kKR"#);
         }
+
+        #[test]
+        fn indy_merge_multi_signed_requests_works_for_invalid_json() {
+            Setup::empty();
+
+            let res = ledger::merge_multi_signed_requests(REQUEST);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_merge_multi_signed_requests_works_for_different_requests() {
+            let setup = Setup::wallet();
+
+            let (did, _) = did::create_and_store_my_did(setup.wallet_handle, Some(MY1_SEED)).unwrap();
+
+            let request1 = ledger::multi_sign_request(setup.wallet_handle, &did, REQUEST).unwrap();
+            let request2 = ledger::multi_sign_request(setup.wallet_handle, &did, REQUEST_FROM_TRUSTEE).unwrap();
+
+            let requests = format!("[{}, {}]", request1, request2);
+
+            let res = ledger::merge_multi_signed_requests(&requests);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_check_request_signatures_works_for_invalid_pool_handle() {
+            Setup::empty();
+
+            let res = ledger::check_request_signatures(INVALID_POOL_HANDLE, REQUEST);
+            assert_code!(ErrorCode::PoolLedgerInvalidPoolHandle, res);
+        }
+
+        #[test]
+        fn indy_check_request_signatures_works_for_invalid_message_format() {
+            Setup::empty();
+
+            let res = ledger::check_request_signatures(INVALID_POOL_HANDLE, "1495034346617224651");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
     }
 
     mod nym_requests {
         use super::*;
 
+        #[test]
+        fn indy_build_nym_request_with_data_works_for_unknown_role() {
+            Setup::empty();
+
+            let res = ledger::build_nym_request_with_data(&IDENTIFIER, &DEST, r#"{"role": "ADMIN"}"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_build_nym_request_with_data_works_for_diddoc_content_with_id() {
+            Setup::empty();
+
+            let nym_data = json!({"diddocContent": {"id": format!("did:indy:sovrin:{}", DEST)}}).to_string();
+
+            let res = ledger::build_nym_request_with_data(&IDENTIFIER, &DEST, &nym_data);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_build_nym_request_with_data_works_for_unsupported_version() {
+            Setup::empty();
+
+            let res = ledger::build_nym_request_with_data(&IDENTIFIER, &DEST, r#"{"version": 3}"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_build_get_historical_nym_request_works_for_seq_no_and_timestamp() {
+            let res = ledger::build_get_historical_nym_request(Some(IDENTIFIER), &DEST, Some(10), Some(1589000000));
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
         #[test]
         #[cfg(feature = "local_nodes_pool")]
         fn indy_send_get_nym_request_works_default_submitter_did() {
@@ -3337,6 +4449,18 @@ mod medium_cases {
     mod attrib_requests {
         use super::*;
 
+        #[test]
+        fn indy_build_set_endpoint_request_works_for_invalid_address() {
+            let res = ledger::build_set_endpoint_request(&IDENTIFIER, &DEST, "invalid_address", Some(VERKEY_TRUSTEE));
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_build_set_endpoint_request_works_for_invalid_transport_key() {
+            let res = ledger::build_set_endpoint_request(&IDENTIFIER, &DEST, ENDPOINT, Some(INVALID_BASE58_VERKEY));
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
         #[test]
         #[cfg(feature = "local_nodes_pool")]
         fn indy_attrib_requests_works_for_hash_value() {
@@ -3466,6 +4590,24 @@ mod medium_cases {
             assert_code!(ErrorCode::CommonInvalidStructure, res);
         }
 
+        #[test]
+        fn indy_build_attrib_request_works_for_strict_validation() {
+            Setup::empty();
+
+            let invalid_raw = r#"{"endpoint":{"ha":"agent.example.com"}}"#;
+
+            ledger::build_attrib_request(IDENTIFIER, IDENTIFIER, None, Some(invalid_raw), None).unwrap();
+
+            assert_eq!(ErrorCode::Success, indy::set_runtime_config(r#"{"strict_attrib_validation": true}"#));
+
+            let res = ledger::build_attrib_request(IDENTIFIER, IDENTIFIER, None, Some(invalid_raw), None);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            ledger::build_attrib_request(IDENTIFIER, IDENTIFIER, None, Some(ATTRIB_RAW_DATA), None).unwrap();
+
+            assert_eq!(ErrorCode::Success, indy::set_runtime_config(r#"{"strict_attrib_validation": false}"#));
+        }
+
         #[test]
         #[cfg(feature = "local_nodes_pool")]
         fn indy_build_get_attrib_request_works_for_invalid_submitter_identifier() {
@@ -3479,6 +4621,36 @@ mod medium_cases {
             let res = ledger::build_get_attrib_request(Some(IDENTIFIER), INVALID_IDENTIFIER, Some("endpoint"), None, None);
             assert_code!(ErrorCode::CommonInvalidStructure, res);
         }
+
+        #[test]
+        fn indy_parse_get_attrib_response_works_for_not_found() {
+            let get_attrib_response = json!({
+                "op": "REPLY",
+                "result": {"type": constants::GET_ATTR, "identifier": IDENTIFIER, "reqId": 1, "dest": IDENTIFIER, "raw": "endpoint", "data": null}
+            }).to_string();
+
+            let res = ledger::parse_get_attrib_response(&get_attrib_response);
+            assert_code!(ErrorCode::LedgerNotFound, res);
+        }
+
+        #[test]
+        fn indy_parse_get_attrib_response_works_for_other_response_type() {
+            let get_nym_response = json!({
+                "op": "REPLY",
+                "result": {"type": constants::GET_NYM, "identifier": IDENTIFIER, "reqId": 1, "dest": IDENTIFIER, "data": null}
+            }).to_string();
+
+            let res = ledger::parse_get_attrib_response(&get_nym_response);
+            assert_code!(ErrorCode::LedgerInvalidTransaction, res);
+        }
+
+        #[test]
+        fn indy_parse_get_attrib_response_works_for_reject() {
+            let reject_response = json!({"op": "REJECT", "reqId": 1, "reason": "client request invalid"}).to_string();
+
+            let res = ledger::parse_get_attrib_response(&reject_response);
+            assert_code!(ErrorCode::LedgerInvalidTransaction, res);
+        }
     }
 
     mod schemas_requests {
@@ -3684,6 +4856,49 @@ mod medium_cases {
         }
     }
 
+    mod pool_upgrade {
+        use super::*;
+
+        fn upgrade_json(sha256: &str, node: &str) -> String {
+            json!({
+                "name": "upgrade-libindy",
+                "version": "2.0.0",
+                "sha256": sha256,
+                "startTime": 1580221800,
+                "nodeOffsets": {node: 0}
+            }).to_string()
+        }
+
+        #[test]
+        fn indy_build_scheduled_pool_upgrade_request_works_for_invalid_sha256() {
+            Setup::empty();
+
+            let res = ledger::build_scheduled_pool_upgrade_request(INVALID_POOL_HANDLE, DID_TRUSTEE, &upgrade_json("f284b", "Node1"));
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_build_scheduled_pool_upgrade_request_works_for_invalid_pool_handle() {
+            Setup::empty();
+
+            let upgrade = upgrade_json("f284bdc3c1c9e24a494e285cb387c69510f28de51c15bb93179d9c7f28705398", "Node1");
+
+            let res = ledger::build_scheduled_pool_upgrade_request(INVALID_POOL_HANDLE, DID_TRUSTEE, &upgrade);
+            assert_code!(ErrorCode::PoolLedgerInvalidPoolHandle, res);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_build_scheduled_pool_upgrade_request_works_for_unknown_node() {
+            let setup = Setup::pool();
+
+            let upgrade = upgrade_json("f284bdc3c1c9e24a494e285cb387c69510f28de51c15bb93179d9c7f28705398", "UnknownNode");
+
+            let res = ledger::build_scheduled_pool_upgrade_request(setup.pool_handle, DID_TRUSTEE, &upgrade);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
+
     mod cred_def_requests {
         use super::*;
 
@@ -3849,6 +5064,57 @@ mod medium_cases {
             let expected_data = json!({"dest": setup.did, "enc": encryted_attr});
             assert_eq!(expected_data, data);
         }
+
+        #[test]
+        fn indy_get_txns_works_for_invalid_range() {
+            Setup::empty();
+
+            let res = ledger::get_txns(INVALID_POOL_HANDLE, None, None, 10, 1);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            let res = ledger::get_txns(INVALID_POOL_HANDLE, None, None, 0, 1);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_get_txns_works_for_invalid_pool_handle() {
+            Setup::empty();
+
+            let res = ledger::get_txns(INVALID_POOL_HANDLE, None, None, 1, 10);
+            assert_code!(ErrorCode::PoolLedgerInvalidPoolHandle, res);
+        }
+
+        #[test]
+        fn indy_stream_txns_works_for_invalid_pool_handle() {
+            Setup::empty();
+
+            let res = ledger::stream_txns(INVALID_POOL_HANDLE, None, None, 1, 10);
+            assert_code!(ErrorCode::PoolLedgerInvalidPoolHandle, res);
+        }
+
+        #[test]
+        fn indy_subscribe_ledger_events_works_for_invalid_pool_handle() {
+            Setup::empty();
+
+            let res = ledger::subscribe_ledger_events(INVALID_POOL_HANDLE, "{}", Box::new(|_| {}));
+            assert_code!(ErrorCode::PoolLedgerInvalidPoolHandle, res);
+        }
+
+        #[test]
+        fn indy_subscribe_ledger_events_works_for_invalid_from_seq_no() {
+            Setup::empty();
+
+            let res = ledger::subscribe_ledger_events(INVALID_POOL_HANDLE, r#"{"fromSeqNo": 0}"#, Box::new(|_| {}));
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_unsubscribe_ledger_events_works_for_unknown_handle() {
+            Setup::empty();
+
+            let res = ledger::unsubscribe_ledger_events(-1);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
     }
 
     mod revoc_reg_def_requests {
@@ -3940,6 +5206,121 @@ mod medium_cases {
             assert!(response_metadata["lastSeqNo"].as_u64().is_none());
         }
     }
+
+    mod verify_state_proof {
+        use super::*;
+
+        #[test]
+        fn indy_verify_state_proof_works_for_invalid_bls_keys() {
+            Setup::empty();
+
+            let get_nym_request = ledger::build_get_nym_request(None, DID_TRUSTEE).unwrap();
+
+            let res = ledger::verify_state_proof(&get_nym_request, r#"{"op":"REPLY","result":{}}"#, r#"{"Node1":"invalid"}"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_verify_state_proof_works_for_invalid_bls_keys_json() {
+            Setup::empty();
+
+            let get_nym_request = ledger::build_get_nym_request(None, DID_TRUSTEE).unwrap();
+
+            let res = ledger::verify_state_proof(&get_nym_request, r#"{"op":"REPLY","result":{}}"#, "[]");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_verify_state_proof_works_for_not_reply() {
+            Setup::empty();
+
+            let get_nym_request = ledger::build_get_nym_request(None, DID_TRUSTEE).unwrap();
+
+            let res = ledger::verify_state_proof(&get_nym_request, r#"{"op":"REQNACK","reason":"some reason"}"#, &pool::test_pool_bls_keys());
+            assert_code!(ErrorCode::LedgerInvalidTransaction, res);
+        }
+    }
+
+    mod auth_rule {
+        use super::*;
+
+        #[test]
+        fn indy_build_role_constraint_works_for_invalid_role() {
+            Setup::empty();
+
+            let res = ledger::build_role_constraint("ADMIN", 1, false, false, None);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_build_combined_constraint_works_for_invalid_constraints() {
+            Setup::empty();
+
+            let res = ledger::build_combined_constraint("AND", r#"[{"constraint_id": "UNKNOWN"}]"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_evaluate_auth_rules_works_for_invalid_action() {
+            Setup::empty();
+
+            let res = ledger::evaluate_auth_rules("[]", r#"{"auth_type": "NYM", "auth_action": "DELETE", "field": "role"}"#, "[]");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_evaluate_auth_rules_works_for_not_found_rule() {
+            Setup::empty();
+
+            let res = ledger::evaluate_auth_rules("[]", r#"{"auth_type": "NYM", "auth_action": "ADD", "field": "role"}"#, "[]");
+            assert_code!(ErrorCode::LedgerNotFound, res);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_auth_rules_works_for_zero_limit() {
+            let setup = Setup::pool();
+
+            let res = ledger::get_auth_rules(setup.pool_handle, None, Some(r#"{"limit": 0}"#));
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_diff_auth_rules_works_for_invalid_rules() {
+            Setup::empty();
+
+            let res = ledger::diff_auth_rules("[]", r#"[{"auth_type": "NYM"}]"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
+
+    mod author_agreement_acceptance {
+        use super::*;
+
+        #[test]
+        fn indy_prepare_request_with_taa_works_for_invalid_request() {
+            let setup = Setup::did();
+
+            let res = ledger::prepare_request_with_taa(setup.pool_handle, setup.wallet_handle, &setup.did, "Invalid request string", None);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_prepare_request_with_taa_works_for_unknown_did() {
+            let setup = Setup::wallet();
+
+            let res = ledger::prepare_request_with_taa(setup.pool_handle, setup.wallet_handle, DID, REQUEST, None);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+
+        #[test]
+        fn indy_prepare_request_with_taa_works_for_invalid_pool_handle() {
+            let setup = Setup::did();
+
+            let res = ledger::prepare_request_with_taa(INVALID_POOL_HANDLE, setup.wallet_handle, &setup.did, REQUEST, None);
+            assert_code!(ErrorCode::PoolLedgerInvalidPoolHandle, res);
+        }
+    }
 }
 
 fn check_request(request: &str, expected_operation: serde_json::Value, expected_identifier: &str) {
@@ -0,0 +1,65 @@
+extern crate indyrs as indy;
+extern crate indyrs as api;
+
+use std::sync::Mutex;
+
+use indy::ErrorCode;
+use indy::future::Future;
+use indy_api_types::PoolHandle;
+use log::{Log, Metadata, Record};
+
+#[macro_use]
+mod utils;
+
+inject_indy_dependencies!();
+
+struct LogCounter {}
+
+static LOG_COUNTER: LogCounter = LogCounter {};
+
+lazy_static! {
+    static ref LOG_TARGETS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+}
+
+impl Log for LogCounter {
+    fn enabled(&self, _metadata: &Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record) {
+        LOG_TARGETS.lock().unwrap().push(record.target().to_string());
+    }
+
+    fn flush(&self) {}
+}
+
+fn _count_logs(target: &str) -> usize {
+    LOG_TARGETS.lock().unwrap().iter().filter(|logged| logged.as_str() == target).count()
+}
+
+#[test]
+fn indy_set_log_filter_works() {
+    indy::logger::set_logger(&LOG_COUNTER).unwrap();
+
+    indy::logger::set_log_filter("indy::commands::pool=debug").unwrap();
+    indy::pool::close_pool_ledger(1 as PoolHandle).wait().unwrap_err();
+    let commands_logs = _count_logs("indy::commands::pool");
+    let api_logs = _count_logs("indy::api::pool");
+
+    indy::logger::set_log_filter("indy::commands::pool=warn,indy::api::pool=trace").unwrap();
+    indy::pool::close_pool_ledger(1 as PoolHandle).wait().unwrap_err();
+
+    // make sure only enabled targets were logged
+    assert_ne!(0, commands_logs);
+    assert_eq!(0, api_logs);
+
+    // check switched targets
+    assert_eq!(commands_logs, _count_logs("indy::commands::pool"));
+    assert_ne!(api_logs, _count_logs("indy::api::pool"));
+}
+
+#[test]
+fn indy_set_log_filter_works_for_invalid_level() {
+    let res = indy::logger::set_log_filter("indy::services::pool=verbose");
+    assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err().error_code);
+}
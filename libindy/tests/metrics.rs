@@ -7,6 +7,7 @@ extern crate indyrs as api;
 extern crate indyrs as indy;
 use crate::utils::constants::*;
 use crate::utils::metrics;
+use crate::utils::non_secrets;
 use crate::utils::wallet;
 use crate::utils::Setup;
 
@@ -142,7 +143,120 @@ mod collect {
         assert!(commands_duration_ms_bucket.contains(&json!({"tags":{"command": "payments_command_build_set_txn_fees_req_ack", "stage": "queued"} ,"value": 0})));
     }
 
+    #[test]
+    fn collect_metrics_includes_wallet_operations() {
+        let setup = Setup::wallet();
+        non_secrets::add_wallet_record(setup.wallet_handle, "TestType", "RecordId", "RecordValue", None).unwrap();
+
+        let result_metrics = metrics::collect_metrics().unwrap();
+        let metrics_map = serde_json::from_str::<HashMap<String, Value>>(&result_metrics).unwrap();
+
+        assert!(metrics_map.contains_key("wallet_operations_failed_count"));
+        assert!(metrics_map.contains_key("wallet_operations_duration_ms"));
+
+        let operations_count = metrics_map
+            .get("wallet_operations_count")
+            .unwrap()
+            .as_array()
+            .unwrap();
+
+        assert!(operations_count.iter().any(|metric|
+            metric["tags"] == json!({"storage_type": "default", "operation": "add"}) && metric["value"].as_u64().unwrap() > 0));
+
+        let result_metrics = metrics::collect_metrics_prometheus().unwrap();
+        assert!(result_metrics.contains("# TYPE indy_wallet_operations_total counter\n"));
+        assert!(result_metrics.contains("indy_wallet_operations_total{storage_type=\"default\",operation=\"add\"} "));
+    }
+
+    #[test]
+    #[cfg(feature = "local_nodes_pool")]
+    fn collect_metrics_includes_pool_events() {
+        let setup = Setup::pool();
+
+        let result_metrics = metrics::collect_metrics().unwrap();
+        let metrics_map = serde_json::from_str::<HashMap<String, Value>>(&result_metrics).unwrap();
+
+        let pool_events_count = metrics_map
+            .get("pool_events_count")
+            .unwrap()
+            .as_array()
+            .unwrap();
+
+        assert!(pool_events_count.iter().any(|metric|
+            metric["tags"] == json!({"pool": setup.name, "event": "catchup_round"}) && metric["value"].as_u64().unwrap() > 0));
+
+        let result_metrics = metrics::collect_metrics_prometheus().unwrap();
+        assert!(result_metrics.contains("# TYPE indy_pool_events_total counter\n"));
+        assert!(result_metrics.contains(&format!("indy_pool_events_total{{pool=\"{}\",event=\"catchup_round\"}} ", setup.name)));
+    }
+
+    #[test]
+    fn collect_metrics_prometheus_works() {
+        let result_metrics = metrics::collect_metrics_prometheus().unwrap();
+
+        assert!(result_metrics.contains("# TYPE indy_threadpool_threads_count gauge\n"));
+        assert!(result_metrics.contains("indy_threadpool_threads_count{label=\"active\"} "));
+        assert!(result_metrics.contains("# TYPE indy_wallet_count gauge\n"));
+        assert!(result_metrics.contains("indy_wallet_count{label=\"opened\"} "));
+        assert!(result_metrics.contains("# TYPE indy_commands_duration_ms histogram\n"));
+        assert!(result_metrics.contains("indy_commands_duration_ms_bucket{command=\"pairwise_command_pairwise_exists\",stage=\"executed\",le=\"+Inf\"} 0\n"));
+        assert!(result_metrics.contains("indy_commands_duration_ms_count{command=\"pairwise_command_pairwise_exists\",stage=\"queued\"} 0\n"));
+    }
+
+    #[test]
+    fn collect_metrics_delta_works() {
+        metrics::collect_metrics_delta().unwrap();
+
+        let result_metrics = metrics::collect_metrics_delta().unwrap();
+        let metrics_map = serde_json::from_str::<HashMap<String, Value>>(&result_metrics).unwrap();
+
+        assert!(metrics_map.contains_key("threadpool_threads_count"));
+        assert!(metrics_map.contains_key("wallet_count"));
+        assert!(metrics_map.contains_key("wallet_operations_count"));
+        assert!(metrics_map.contains_key("pool_events_count"));
+
+        let commands_count = metrics_map
+            .get("commands_count")
+            .unwrap()
+            .as_array()
+            .unwrap();
+
+        // only the previous delta collection was executed since the previous snapshot
+        assert!(commands_count.contains(&json!({"tags":{"command": "metrics_command_collect_metrics_delta", "stage": "executed"} ,"value": 1})));
+    }
+
     fn config(name: &str) -> String {
         json!({ "id": name }).to_string()
     }
 }
+
+mod list_handles {
+    use super::*;
+    use serde_json::Value;
+
+    fn _find_handle(handle: i32) -> Option<Value> {
+        let handles = metrics::list_handles().unwrap();
+        let handles: Vec<Value> = serde_json::from_str(&handles).unwrap();
+        handles.into_iter().find(|info| info["handle"] == handle)
+    }
+
+    #[test]
+    fn list_handles_works() {
+        let setup = Setup::wallet();
+        let wallet_id = serde_json::from_str::<Value>(&setup.wallet_config).unwrap()["id"].clone();
+
+        let search_handle = non_secrets::open_wallet_search(setup.wallet_handle, non_secrets::TYPE, non_secrets::QUERY_EMPTY, non_secrets::OPTIONS_EMPTY).unwrap();
+
+        let wallet_info = _find_handle(setup.wallet_handle.0).unwrap();
+        assert_eq!("wallet", wallet_info["type"]);
+        assert_eq!(wallet_id, wallet_info["name"]);
+        assert!(wallet_info["age_ms"].is_u64());
+
+        let search_info = _find_handle(search_handle).unwrap();
+        assert_eq!("wallet_search", search_info["type"]);
+        assert_eq!(non_secrets::TYPE, search_info["name"]);
+
+        non_secrets::close_wallet_search(search_handle).unwrap();
+        assert_eq!(None, _find_handle(search_handle));
+    }
+}
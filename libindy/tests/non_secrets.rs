@@ -7,17 +7,20 @@ extern crate indyrs as indy;
 extern crate indyrs as api;
 
 use crate::utils::constants::WALLET_CREDENTIALS;
-use crate::utils::wallet;
+use crate::utils::{did, wallet};
 use crate::utils::non_secrets::*;
 use crate::utils::types::{WalletRecord, SearchRecords};
 
+use std::cell::RefCell;
 use std::collections::HashMap;
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
 
 use self::indy::ErrorCode;
 
 pub const FORBIDDEN_TYPE: &'static str = "Indy::Test";
 
-use indy::WalletHandle;
+use indy::{CommandHandle, WalletHandle};
 use crate::utils::test::cleanup_wallet;
 use crate::utils::Setup;
 
@@ -84,6 +87,94 @@ mod high_cases {
         }
     }
 
+    mod compare_and_swap_record_value {
+        use super::*;
+
+        #[test]
+        fn indy_compare_and_swap_record_value_works() {
+            let setup = Setup::wallet();
+
+            add_wallet_record(setup.wallet_handle, TYPE, ID, VALUE, None).unwrap();
+
+            let new_value_hash = compare_and_swap_wallet_record_value(setup.wallet_handle, TYPE, ID, &value_hash(VALUE), VALUE_2).unwrap();
+            assert_eq!(value_hash(VALUE_2), new_value_hash);
+            check_record_field(setup.wallet_handle, TYPE, ID, "value", VALUE_2);
+
+            compare_and_swap_wallet_record_value(setup.wallet_handle, TYPE, ID, &new_value_hash, VALUE).unwrap();
+            check_record_field(setup.wallet_handle, TYPE, ID, "value", VALUE);
+        }
+
+        #[test]
+        fn indy_compare_and_swap_record_value_works_for_plugged_wallet() {
+            let setup = Setup::plugged_wallet();
+
+            add_wallet_record(setup.wallet_handle, TYPE, ID, VALUE, None).unwrap();
+
+            compare_and_swap_wallet_record_value(setup.wallet_handle, TYPE, ID, &value_hash(VALUE), VALUE_2).unwrap();
+            check_record_field(setup.wallet_handle, TYPE, ID, "value", VALUE_2);
+        }
+
+        #[test]
+        fn indy_compare_and_swap_record_value_works_for_conflict() {
+            let setup = Setup::wallet();
+
+            add_wallet_record(setup.wallet_handle, TYPE, ID, VALUE, None).unwrap();
+            update_wallet_record_value(setup.wallet_handle, TYPE, ID, VALUE_2).unwrap();
+
+            let res = compare_and_swap_wallet_record_value(setup.wallet_handle, TYPE, ID, &value_hash(VALUE), VALUE_3);
+            assert_code!(ErrorCode::WalletItemConflict, res);
+
+            check_record_field(setup.wallet_handle, TYPE, ID, "value", VALUE_2);
+        }
+    }
+
+    mod modify_record_value {
+        use super::*;
+
+        #[test]
+        fn indy_modify_record_value_works() {
+            let setup = Setup::wallet();
+
+            add_wallet_record(setup.wallet_handle, TYPE, ID, "1", None).unwrap();
+
+            let new_value = modify_wallet_record_value(setup.wallet_handle, TYPE, ID, increment_counter).unwrap();
+            assert_eq!("2", new_value);
+            check_record_field(setup.wallet_handle, TYPE, ID, "value", "2");
+        }
+
+        #[test]
+        fn indy_modify_record_value_works_for_plugged_wallet() {
+            let setup = Setup::plugged_wallet();
+
+            add_wallet_record(setup.wallet_handle, TYPE, ID, "1", None).unwrap();
+
+            modify_wallet_record_value(setup.wallet_handle, TYPE, ID, increment_counter).unwrap();
+            check_record_field(setup.wallet_handle, TYPE, ID, "value", "2");
+        }
+
+        #[test]
+        fn indy_modify_record_value_works_for_concurrent_calls() {
+            let setup = Setup::wallet();
+
+            add_wallet_record(setup.wallet_handle, TYPE, ID, "0", None).unwrap();
+
+            let wallet_handle = setup.wallet_handle;
+            let threads: Vec<_> = (0..4)
+                .map(|_| std::thread::spawn(move || {
+                    for _ in 0..5 {
+                        modify_wallet_record_value(wallet_handle, TYPE, ID, increment_counter).unwrap();
+                    }
+                }))
+                .collect();
+
+            for thread in threads {
+                thread.join().unwrap();
+            }
+
+            check_record_field(setup.wallet_handle, TYPE, ID, "value", "20");
+        }
+    }
+
     mod update_record_tags {
         use super::*;
 
@@ -232,6 +323,53 @@ mod high_cases {
         }
     }
 
+    mod upsert_records {
+        use super::*;
+
+        fn check_upserted_records(wallet_handle: WalletHandle) {
+            add_wallet_record(wallet_handle, TYPE, ID, VALUE, Some(TAGS)).unwrap();
+
+            let records = json!([
+                {"id": ID, "value": VALUE_2, "tags": serde_json::from_str::<serde_json::Value>(TAGS_2).unwrap()},
+                {"id": ID_2, "value": VALUE},
+            ]).to_string();
+
+            let statuses = upsert_wallet_records(wallet_handle, TYPE, &records).unwrap();
+            let statuses: serde_json::Value = serde_json::from_str(&statuses).unwrap();
+            assert_eq!(json!([{"id": ID, "status": "updated"}, {"id": ID_2, "status": "inserted"}]), statuses);
+
+            let record = get_wallet_record(wallet_handle, TYPE, ID, OPTIONS_FULL).unwrap();
+            let record: WalletRecord = serde_json::from_str(&record).unwrap();
+            assert_eq!(WalletRecord { id: ID.to_string(), type_: Some(TYPE.to_string()), value: Some(VALUE_2.to_string()), tags: Some(serde_json::from_str(TAGS_2).unwrap()) }, record);
+
+            let record = get_wallet_record(wallet_handle, TYPE, ID_2, OPTIONS_FULL).unwrap();
+            let record: WalletRecord = serde_json::from_str(&record).unwrap();
+            assert_eq!(WalletRecord { id: ID_2.to_string(), type_: Some(TYPE.to_string()), value: Some(VALUE.to_string()), tags: Some(HashMap::new()) }, record);
+        }
+
+        #[test]
+        fn indy_upsert_wallet_records_works() {
+            let setup = Setup::wallet();
+
+            check_upserted_records(setup.wallet_handle);
+        }
+
+        #[test]
+        fn indy_upsert_wallet_records_works_for_plugged_wallet() {
+            let setup = Setup::plugged_wallet();
+
+            check_upserted_records(setup.wallet_handle);
+        }
+
+        #[test]
+        fn indy_upsert_wallet_records_works_for_empty_list() {
+            let setup = Setup::wallet();
+
+            let statuses = upsert_wallet_records(setup.wallet_handle, TYPE, "[]").unwrap();
+            assert_eq!("[]", statuses);
+        }
+    }
+
     mod get_record {
         use super::*;
 
@@ -345,6 +483,206 @@ mod high_cases {
         }
     }
 
+    mod get_record_types {
+        use super::*;
+
+        #[test]
+        fn indy_get_wallet_record_types_works() {
+            let setup = Setup::wallet();
+
+            let types = get_wallet_record_types(setup.wallet_handle).unwrap();
+            assert_eq!(json!({}), serde_json::from_str::<serde_json::Value>(&types).unwrap());
+
+            add_wallet_record(setup.wallet_handle, TYPE, ID, VALUE, None).unwrap();
+            add_wallet_record(setup.wallet_handle, TYPE, ID_2, VALUE_2, None).unwrap();
+            add_wallet_record(setup.wallet_handle, TYPE_2, ID, VALUE, None).unwrap();
+
+            let types = get_wallet_record_types(setup.wallet_handle).unwrap();
+            assert_eq!(json!({TYPE: 2, TYPE_2: 1}), serde_json::from_str::<serde_json::Value>(&types).unwrap());
+        }
+
+        #[test]
+        fn indy_get_wallet_record_types_works_for_plugged_wallet() {
+            let setup = Setup::plugged_wallet();
+
+            add_wallet_record(setup.wallet_handle, TYPE, ID, VALUE, None).unwrap();
+            add_wallet_record(setup.wallet_handle, TYPE_2, ID, VALUE, None).unwrap();
+
+            let types = get_wallet_record_types(setup.wallet_handle).unwrap();
+            assert_eq!(json!({TYPE: 1, TYPE_2: 1}), serde_json::from_str::<serde_json::Value>(&types).unwrap());
+        }
+    }
+
+    mod explain_search {
+        use super::*;
+
+        #[test]
+        fn indy_explain_wallet_search_works() {
+            let setup = Setup::wallet();
+
+            add_wallet_record(setup.wallet_handle, TYPE, ID, VALUE, Some(TAGS)).unwrap();
+            add_wallet_record(setup.wallet_handle, TYPE, ID_2, VALUE_2, Some(TAGS_2)).unwrap();
+
+            let plan = explain_wallet_search(setup.wallet_handle, TYPE, r#"{"tagName1": "str1"}"#).unwrap();
+            let plan: serde_json::Value = serde_json::from_str(&plan).unwrap();
+
+            assert_eq!(json!(true), plan["usesIndex"]);
+            assert_eq!(json!(2), plan["recordsOfType"]);
+            assert_eq!(json!(1), plan["estimatedScan"]);
+            assert_eq!(json!([{"name": "tagName1", "kind": "encrypted", "operators": ["$eq"], "indexed": true}]), plan["tags"]);
+            assert!(plan["storagePlan"].is_array());
+        }
+
+        #[test]
+        fn indy_explain_wallet_search_works_for_full_scan() {
+            let setup = Setup::wallet();
+
+            add_wallet_record(setup.wallet_handle, TYPE, ID, VALUE, Some(TAGS)).unwrap();
+            add_wallet_record(setup.wallet_handle, TYPE, ID_2, VALUE_2, Some(TAGS_2)).unwrap();
+
+            let plan = explain_wallet_search(setup.wallet_handle, TYPE, r#"{"~tagName2": {"$like": "%str3"}}"#).unwrap();
+            let plan: serde_json::Value = serde_json::from_str(&plan).unwrap();
+
+            assert_eq!(json!(false), plan["usesIndex"]);
+            assert_eq!(json!(2), plan["estimatedScan"]);
+        }
+
+        #[test]
+        fn indy_explain_wallet_search_works_for_plugged_wallet() {
+            let setup = Setup::plugged_wallet();
+
+            add_wallet_record(setup.wallet_handle, TYPE, ID, VALUE, Some(TAGS)).unwrap();
+
+            let plan = explain_wallet_search(setup.wallet_handle, TYPE, QUERY_EMPTY).unwrap();
+            let plan: serde_json::Value = serde_json::from_str(&plan).unwrap();
+
+            assert_eq!(json!(1), plan["recordsOfType"]);
+            assert!(plan.get("storagePlan").is_none());
+        }
+    }
+
+    mod record_history {
+        use super::*;
+
+        fn revision_numbers(revisions_json: &str) -> Vec<u64> {
+            serde_json::from_str::<Vec<serde_json::Value>>(revisions_json).unwrap()
+                .iter()
+                .map(|revision| revision["revision"].as_u64().unwrap())
+                .collect()
+        }
+
+        #[test]
+        fn indy_wallet_record_history_works() {
+            let setup = Setup::wallet();
+
+            enable_wallet_record_history(setup.wallet_handle, TYPE, "{}").unwrap();
+
+            add_wallet_record(setup.wallet_handle, TYPE, ID, VALUE, Some(TAGS)).unwrap();
+            update_wallet_record_value(setup.wallet_handle, TYPE, ID, VALUE_2).unwrap();
+            update_wallet_record_tags(setup.wallet_handle, TYPE, ID, TAGS_2).unwrap();
+
+            let revisions = get_wallet_record_revisions(setup.wallet_handle, TYPE, ID).unwrap();
+            assert_eq!(vec![1, 2], revision_numbers(&revisions));
+
+            let revision: serde_json::Value = serde_json::from_str(&get_wallet_record_revision(setup.wallet_handle, TYPE, ID, 1).unwrap()).unwrap();
+            assert_eq!(VALUE, revision["value"]);
+            assert_eq!(serde_json::from_str::<serde_json::Value>(TAGS).unwrap(), revision["tags"]);
+            assert!(revision["replacedAt"].is_u64());
+
+            let revision: serde_json::Value = serde_json::from_str(&get_wallet_record_revision(setup.wallet_handle, TYPE, ID, 2).unwrap()).unwrap();
+            assert_eq!(VALUE_2, revision["value"]);
+            assert_eq!(serde_json::from_str::<serde_json::Value>(TAGS).unwrap(), revision["tags"]);
+        }
+
+        #[test]
+        fn indy_wallet_record_history_works_for_deleted_record() {
+            let setup = Setup::wallet();
+
+            enable_wallet_record_history(setup.wallet_handle, TYPE, "{}").unwrap();
+
+            add_wallet_record(setup.wallet_handle, TYPE, ID, VALUE, None).unwrap();
+            delete_wallet_record(setup.wallet_handle, TYPE, ID).unwrap();
+
+            let revision: serde_json::Value = serde_json::from_str(&get_wallet_record_revision(setup.wallet_handle, TYPE, ID, 1).unwrap()).unwrap();
+            assert_eq!(VALUE, revision["value"]);
+        }
+
+        #[test]
+        fn indy_wallet_record_history_works_for_max_revisions() {
+            let setup = Setup::wallet();
+
+            enable_wallet_record_history(setup.wallet_handle, TYPE, r#"{"maxRevisions":2}"#).unwrap();
+
+            add_wallet_record(setup.wallet_handle, TYPE, ID, VALUE, None).unwrap();
+            for value in &[VALUE_2, VALUE_3, VALUE_4] {
+                update_wallet_record_value(setup.wallet_handle, TYPE, ID, value).unwrap();
+            }
+
+            let revisions = get_wallet_record_revisions(setup.wallet_handle, TYPE, ID).unwrap();
+            assert_eq!(vec![2, 3], revision_numbers(&revisions));
+        }
+
+        #[test]
+        fn indy_wallet_record_history_works_for_prune() {
+            let setup = Setup::wallet();
+
+            enable_wallet_record_history(setup.wallet_handle, TYPE, "{}").unwrap();
+
+            add_wallet_record(setup.wallet_handle, TYPE, ID, VALUE, None).unwrap();
+            for value in &[VALUE_2, VALUE_3, VALUE_4] {
+                update_wallet_record_value(setup.wallet_handle, TYPE, ID, value).unwrap();
+            }
+
+            prune_wallet_record_history(setup.wallet_handle, TYPE, ID, 1).unwrap();
+            assert_eq!(vec![3], revision_numbers(&get_wallet_record_revisions(setup.wallet_handle, TYPE, ID).unwrap()));
+
+            prune_wallet_record_history(setup.wallet_handle, TYPE, ID, 0).unwrap();
+            assert_eq!("[]", get_wallet_record_revisions(setup.wallet_handle, TYPE, ID).unwrap());
+        }
+
+        #[test]
+        fn indy_wallet_record_history_works_for_disable() {
+            let setup = Setup::wallet();
+
+            enable_wallet_record_history(setup.wallet_handle, TYPE, "{}").unwrap();
+
+            add_wallet_record(setup.wallet_handle, TYPE, ID, VALUE, None).unwrap();
+            update_wallet_record_value(setup.wallet_handle, TYPE, ID, VALUE_2).unwrap();
+
+            disable_wallet_record_history(setup.wallet_handle, TYPE).unwrap();
+            update_wallet_record_value(setup.wallet_handle, TYPE, ID, VALUE_3).unwrap();
+
+            assert_eq!(vec![1], revision_numbers(&get_wallet_record_revisions(setup.wallet_handle, TYPE, ID).unwrap()));
+        }
+
+        #[test]
+        fn indy_wallet_record_history_works_after_reopen() {
+            let mut setup = Setup::wallet();
+
+            enable_wallet_record_history(setup.wallet_handle, TYPE, "{}").unwrap();
+            add_wallet_record(setup.wallet_handle, TYPE, ID, VALUE, None).unwrap();
+
+            wallet::close_wallet(setup.wallet_handle).unwrap();
+            setup.wallet_handle = wallet::open_wallet(&setup.wallet_config, WALLET_CREDENTIALS).unwrap();
+
+            update_wallet_record_value(setup.wallet_handle, TYPE, ID, VALUE_2).unwrap();
+            assert_eq!(vec![1], revision_numbers(&get_wallet_record_revisions(setup.wallet_handle, TYPE, ID).unwrap()));
+        }
+
+        #[test]
+        fn indy_wallet_record_history_works_for_did_metadata() {
+            let setup = Setup::did();
+
+            enable_wallet_record_history(setup.wallet_handle, "Indy::DidMetadata", "{}").unwrap();
+
+            did::set_did_metadata(setup.wallet_handle, &setup.did, "metadata1").unwrap();
+            did::set_did_metadata(setup.wallet_handle, &setup.did, "metadata2").unwrap();
+
+            let revision: serde_json::Value = serde_json::from_str(&get_wallet_record_revision(setup.wallet_handle, "Indy::DidMetadata", &setup.did, 1).unwrap()).unwrap();
+            assert_eq!(json!({"value": "metadata1"}).to_string(), revision["value"].as_str().unwrap());
+        }
+    }
+
     mod search {
         use super::*;
         use indy::{WalletHandle, SearchHandle};
@@ -508,6 +846,61 @@ mod high_cases {
                 cleanup_wallet("indy_wallet_search_for_lte_query");
             }
 
+            #[test]
+            fn indy_wallet_search_for_range_tag_query() {
+                let setup = Setup::wallet();
+                let wallet_handle = setup.wallet_handle;
+
+                add_wallet_record(wallet_handle, TYPE, ID, VALUE, Some(r##"{"#~amount":"5"}"##)).unwrap();
+                add_wallet_record(wallet_handle, TYPE, ID_2, VALUE_2, Some(r##"{"#~amount":"300"}"##)).unwrap();
+                add_wallet_record(wallet_handle, TYPE, ID_3, VALUE_3, Some(r##"{"#~amount":"70000"}"##)).unwrap();
+
+                let query_json = r##"{
+                    "#~amount": {"$gt": "5"},
+                    "$not": {"#~amount": {"$gte": "70000"}}
+                }"##;
+
+                let search_handle = open_wallet_search(wallet_handle, TYPE, query_json, OPTIONS_FULL).unwrap();
+
+                let search_records = fetch_wallet_search_next_records(wallet_handle, search_handle, 5).unwrap();
+                let search_records: serde_json::Value = serde_json::from_str(&search_records).unwrap();
+
+                assert_eq!(json!(1), search_records["totalCount"]);
+                assert_eq!(json!(ID_2), search_records["records"][0]["id"]);
+                assert_eq!(json!({"#~amount": "300"}), search_records["records"][0]["tags"]);
+
+                close_wallet_search(search_handle).unwrap();
+            }
+
+            #[test]
+            fn indy_wallet_search_for_range_tag_query_for_invalid_value() {
+                let setup = Setup::wallet();
+
+                let res = add_wallet_record(setup.wallet_handle, TYPE, ID, VALUE, Some(r##"{"#~amount":"five"}"##));
+                assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+                let res = open_wallet_search(setup.wallet_handle, TYPE, r##"{"#~amount": {"$lt": "five"}}"##, OPTIONS_FULL);
+                assert_code!(ErrorCode::WalletQueryError, res);
+            }
+
+            #[test]
+            fn indy_wallet_search_for_hash_prefixed_tag_query() {
+                let setup = Setup::wallet();
+                let wallet_handle = setup.wallet_handle;
+
+                add_wallet_record(wallet_handle, TYPE, ID, VALUE, Some(r##"{"#amount":"five"}"##)).unwrap();
+
+                let search_handle = open_wallet_search(wallet_handle, TYPE, r##"{"#amount": "five"}"##, OPTIONS_FULL).unwrap();
+
+                let search_records = fetch_wallet_search_next_records(wallet_handle, search_handle, 5).unwrap();
+                let search_records: serde_json::Value = serde_json::from_str(&search_records).unwrap();
+
+                assert_eq!(json!(1), search_records["totalCount"]);
+                assert_eq!(json!({"#amount": "five"}), search_records["records"][0]["tags"]);
+
+                close_wallet_search(search_handle).unwrap();
+            }
+
             #[test]
             fn indy_wallet_search_for_like_query() {
                 const SEARCH_WALLET_CONFIG: &str = r#"{"id":"indy_wallet_search_for_lte_query"}"#;
@@ -745,6 +1138,34 @@ mod high_cases {
                 cleanup_wallet("indy_wallet_search_for_retrieve_id_value_tags");
             }
 
+            #[test]
+            fn indy_wallet_search_for_retrieve_tag_names() {
+                const SEARCH_WALLET_CONFIG: &str = r#"{"id":"indy_wallet_search_for_retrieve_tag_names"}"#;
+                let wallet_handle = setup("indy_wallet_search_for_retrieve_tag_names", SEARCH_WALLET_CONFIG);
+
+                let query_json = r#"{
+                    "tagName1": "str1"
+                }"#;
+
+                let options = json!({
+                    "retrieveTags": ["tagName1", "~tagName2"]
+                }).to_string();
+
+                let search_handle = open_wallet_search(wallet_handle, TYPE, query_json, &options).unwrap();
+
+                let records = fetch_wallet_search_next_records(wallet_handle, search_handle, 5).unwrap();
+
+                let tags_1: HashMap<String, String> = serde_json::from_value(json!({"tagName1": "str1", "~tagName2": "5"})).unwrap();
+                let tags_3: HashMap<String, String> = serde_json::from_value(json!({"tagName1": "str1"})).unwrap();
+
+                check_search_records(&records, vec![
+                    WalletRecord { id: ID.to_string(), type_: None, value: Some(VALUE.to_string()), tags: Some(tags_1) },
+                    WalletRecord { id: ID_3.to_string(), type_: None, value: Some(VALUE_3.to_string()), tags: Some(tags_3) }]);
+
+                tear_down(wallet_handle, search_handle);
+                cleanup_wallet("indy_wallet_search_for_retrieve_tag_names");
+            }
+
             #[test]
             fn indy_wallet_search_for_retrieve_full_record() {
                 const SEARCH_WALLET_CONFIG: &str = r#"{"id":"indy_wallet_search_for_retrieve_full_record"}"#;
@@ -823,6 +1244,51 @@ mod high_cases {
             }
         }
 
+        mod stream {
+            use super::*;
+
+            #[test]
+            fn indy_stream_wallet_records_works() {
+                const SEARCH_WALLET_CONFIG: &str = r#"{"id":"indy_stream_wallet_records_works"}"#;
+                let wallet_handle = setup("indy_stream_wallet_records_works", SEARCH_WALLET_CONFIG);
+
+                let chunks = stream_wallet_records(wallet_handle, TYPE, QUERY_EMPTY, OPTIONS_FULL, 2).unwrap();
+                assert_eq!(3, chunks.len());
+
+                let records: Vec<WalletRecord> = chunks.iter()
+                    .flat_map(|chunk| serde_json::from_str::<SearchRecords>(chunk).unwrap().records.unwrap())
+                    .collect();
+                check_search_records(&json!({"records": records}).to_string(), vec![record_1(),
+                                                                                   record_2(),
+                                                                                   record_3(),
+                                                                                   record_4(),
+                                                                                   record_5()]);
+
+                wallet::close_wallet(wallet_handle).unwrap();
+                cleanup_wallet("indy_stream_wallet_records_works");
+            }
+
+            #[test]
+            fn indy_stream_wallet_records_works_for_no_records() {
+                const SEARCH_WALLET_CONFIG: &str = r#"{"id":"indy_stream_wallet_records_works_for_no_records"}"#;
+                let wallet_handle = setup("indy_stream_wallet_records_works_for_no_records", SEARCH_WALLET_CONFIG);
+
+                let chunks = stream_wallet_records(wallet_handle, TYPE, r#"{"tagName1": "unknown"}"#, OPTIONS_EMPTY, 2).unwrap();
+                assert!(chunks.is_empty());
+
+                wallet::close_wallet(wallet_handle).unwrap();
+                cleanup_wallet("indy_stream_wallet_records_works_for_no_records");
+            }
+
+            #[test]
+            fn indy_stream_wallet_records_works_for_zero_chunk_size() {
+                let setup = Setup::wallet();
+
+                let res = stream_wallet_records(setup.wallet_handle, TYPE, QUERY_EMPTY, OPTIONS_EMPTY, 0);
+                assert_code!(ErrorCode::CommonInvalidParam6, res);
+            }
+        }
+
         mod close {
             use super::*;
 
@@ -958,6 +1424,84 @@ mod medium_cases {
         }
     }
 
+    mod compare_and_swap_record_value {
+        use super::*;
+
+        #[test]
+        fn indy_compare_and_swap_record_value_works_for_invalid_wallet_handle() {
+            Setup::empty();
+
+            let res = compare_and_swap_wallet_record_value(INVALID_WALLET_HANDLE, TYPE, ID, &value_hash(VALUE), VALUE_2);
+            assert_code!(ErrorCode::WalletInvalidHandle, res);
+        }
+
+        #[test]
+        fn indy_compare_and_swap_record_value_works_for_not_found_record() {
+            let setup = Setup::wallet();
+
+            let res = compare_and_swap_wallet_record_value(setup.wallet_handle, TYPE, ID, &value_hash(VALUE), VALUE_2);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+
+        #[test]
+        fn indy_compare_and_swap_record_value_works_for_empty_expected_value_hash() {
+            let setup = Setup::wallet();
+
+            add_wallet_record(setup.wallet_handle, TYPE, ID, VALUE, None).unwrap();
+
+            let res = compare_and_swap_wallet_record_value(setup.wallet_handle, TYPE, ID, "", VALUE_2);
+            assert_code!(ErrorCode::CommonInvalidParam5, res);
+        }
+
+        #[test]
+        fn indy_compare_and_swap_record_value_works_for_invalid_type() {
+            let setup = Setup::wallet();
+
+            let res = compare_and_swap_wallet_record_value(setup.wallet_handle, FORBIDDEN_TYPE, ID, &value_hash(VALUE), VALUE_2);
+            assert_code!(ErrorCode::WalletAccessFailed, res);
+        }
+    }
+
+    mod modify_record_value {
+        use super::*;
+
+        #[test]
+        fn indy_modify_record_value_works_for_invalid_wallet_handle() {
+            Setup::empty();
+
+            let res = modify_wallet_record_value(INVALID_WALLET_HANDLE, TYPE, ID, increment_counter);
+            assert_code!(ErrorCode::WalletInvalidHandle, res);
+        }
+
+        #[test]
+        fn indy_modify_record_value_works_for_not_found_record() {
+            let setup = Setup::wallet();
+
+            let res = modify_wallet_record_value(setup.wallet_handle, TYPE, ID, increment_counter);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+
+        #[test]
+        fn indy_modify_record_value_works_for_callback_error() {
+            let setup = Setup::wallet();
+
+            add_wallet_record(setup.wallet_handle, TYPE, ID, VALUE, None).unwrap();
+
+            let res = modify_wallet_record_value(setup.wallet_handle, TYPE, ID, increment_counter);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            check_record_field(setup.wallet_handle, TYPE, ID, "value", VALUE);
+        }
+
+        #[test]
+        fn indy_modify_record_value_works_for_invalid_type() {
+            let setup = Setup::wallet();
+
+            let res = modify_wallet_record_value(setup.wallet_handle, FORBIDDEN_TYPE, ID, increment_counter);
+            assert_code!(ErrorCode::WalletAccessFailed, res);
+        }
+    }
+
     mod update_record_tags {
         use super::*;
 
@@ -1121,6 +1665,41 @@ mod medium_cases {
         }
     }
 
+    mod upsert_records {
+        use super::*;
+
+        #[test]
+        fn indy_upsert_wallet_records_works_for_invalid_handle() {
+            Setup::empty();
+
+            let records = json!([{"id": ID, "value": VALUE}]).to_string();
+
+            let res = upsert_wallet_records(INVALID_WALLET_HANDLE, TYPE, &records);
+            assert_code!(ErrorCode::WalletInvalidHandle, res);
+        }
+
+        #[test]
+        fn indy_upsert_wallet_records_works_for_invalid_records() {
+            let setup = Setup::wallet();
+
+            let res = upsert_wallet_records(setup.wallet_handle, TYPE, r#"[{"id": "RecordId"}]"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            let res = upsert_wallet_records(setup.wallet_handle, TYPE, "{}");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_upsert_wallet_records_works_for_invalid_type() {
+            let setup = Setup::wallet();
+
+            let records = json!([{"id": ID, "value": VALUE}]).to_string();
+
+            let res = upsert_wallet_records(setup.wallet_handle, FORBIDDEN_TYPE, &records);
+            assert_code!(ErrorCode::WalletAccessFailed, res);
+        }
+    }
+
     mod get_record {
         use super::*;
 
@@ -1143,6 +1722,84 @@ mod medium_cases {
         }
     }
 
+    mod get_record_types {
+        use super::*;
+
+        #[test]
+        fn indy_get_wallet_record_types_works_for_invalid_wallet_handle() {
+            Setup::empty();
+
+            let res = get_wallet_record_types(INVALID_WALLET_HANDLE);
+            assert_code!(ErrorCode::WalletInvalidHandle, res);
+        }
+    }
+
+    mod explain_search {
+        use super::*;
+
+        #[test]
+        fn indy_explain_wallet_search_works_for_invalid_query() {
+            let setup = Setup::wallet();
+
+            let res = explain_wallet_search(setup.wallet_handle, TYPE, "{not_json}");
+            assert_code!(ErrorCode::WalletQueryError, res);
+        }
+
+        #[test]
+        fn indy_explain_wallet_search_works_for_invalid_wallet_handle() {
+            Setup::empty();
+
+            let res = explain_wallet_search(INVALID_WALLET_HANDLE, TYPE, QUERY_EMPTY);
+            assert_code!(ErrorCode::WalletInvalidHandle, res);
+        }
+    }
+
+    mod record_history {
+        use super::*;
+
+        #[test]
+        fn indy_enable_wallet_record_history_works_for_invalid_config() {
+            let setup = Setup::wallet();
+
+            let res = enable_wallet_record_history(setup.wallet_handle, TYPE, r#"{"maxRevisions":0}"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            let res = enable_wallet_record_history(setup.wallet_handle, TYPE, r#"{"unknown":1}"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_enable_wallet_record_history_works_for_not_allowed_type() {
+            let setup = Setup::wallet();
+
+            let res = enable_wallet_record_history(setup.wallet_handle, "Indy::Did", "{}");
+            assert_code!(ErrorCode::WalletAccessFailed, res);
+
+            let res = get_wallet_record_revisions(setup.wallet_handle, "Indy::Key", ID);
+            assert_code!(ErrorCode::WalletAccessFailed, res);
+        }
+
+        #[test]
+        fn indy_get_wallet_record_revision_works_for_not_found_revision() {
+            let setup = Setup::wallet();
+
+            enable_wallet_record_history(setup.wallet_handle, TYPE, "{}").unwrap();
+            add_wallet_record(setup.wallet_handle, TYPE, ID, VALUE, None).unwrap();
+            update_wallet_record_value(setup.wallet_handle, TYPE, ID, VALUE_2).unwrap();
+
+            let res = get_wallet_record_revision(setup.wallet_handle, TYPE, ID, 2);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+
+        #[test]
+        fn indy_get_wallet_record_revisions_works_for_invalid_wallet_handle() {
+            Setup::empty();
+
+            let res = get_wallet_record_revisions(INVALID_WALLET_HANDLE, TYPE, ID);
+            assert_code!(ErrorCode::WalletInvalidHandle, res);
+        }
+    }
+
     mod search {
         use super::*;
         use indy::{WalletHandle, SearchHandle};
@@ -1281,6 +1938,32 @@ fn check_record_field(wallet_handle: WalletHandle, type_: &str, id: &str, field:
     };
 }
 
+fn value_hash(value: &str) -> String {
+    use sha2::Digest;
+    hex::encode(sha2::Sha256::digest(value.as_bytes()))
+}
+
+thread_local! {
+    static MODIFIED_VALUE: RefCell<CString> = RefCell::new(CString::default());
+}
+
+extern fn increment_counter(_command_handle: CommandHandle, current_value: *const c_char, new_value_p: *mut *const c_char) -> i32 {
+    let current_value = unsafe { CStr::from_ptr(current_value) }.to_str().unwrap();
+
+    let counter: u64 = match current_value.parse() {
+        Ok(counter) => counter,
+        Err(_) => return ErrorCode::CommonInvalidStructure as i32
+    };
+
+    MODIFIED_VALUE.with(|value| {
+        let new_value = CString::new((counter + 1).to_string()).unwrap();
+        unsafe { *new_value_p = new_value.as_ptr(); }
+        *value.borrow_mut() = new_value;
+    });
+
+    ErrorCode::Success as i32
+}
+
 fn check_search_records(search_records: &str, expected_records: Vec<WalletRecord>) {
     let search_records: SearchRecords = serde_json::from_str(&search_records).unwrap();
 
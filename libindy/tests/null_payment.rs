@@ -0,0 +1,180 @@
+#![cfg(feature = "null_payment_method")]
+
+#[macro_use]
+mod utils;
+
+inject_indy_dependencies!();
+
+extern crate indyrs as indy;
+extern crate indyrs as api;
+
+use self::indy::ErrorCode;
+use crate::utils::{ledger, payments};
+use crate::utils::constants::*;
+use crate::utils::Setup;
+
+use serde_json::Value;
+
+static PAYMENT_METHOD_NAME: &str = "null";
+static EMPTY_OBJECT: &str = "{}";
+
+fn _outputs(outputs: &[(&str, u64)]) -> String {
+    let outputs: Vec<Value> = outputs.iter()
+        .map(|(recipient, amount)| json!({"recipient": recipient, "amount": amount}))
+        .collect();
+    json!(outputs).to_string()
+}
+
+fn _mint(setup: &Setup, address: &str, amount: u64) -> String {
+    let (mint_req, payment_method) = payments::build_mint_req(setup.wallet_handle, Some(IDENTIFIER), &_outputs(&[(address, amount)]), None).unwrap();
+    assert_eq!(PAYMENT_METHOD_NAME, payment_method);
+
+    let receipts = payments::parse_payment_response(PAYMENT_METHOD_NAME, &mint_req).unwrap();
+    let receipts: Vec<Value> = serde_json::from_str(&receipts).unwrap();
+    receipts[0]["receipt"].as_str().unwrap().to_string()
+}
+
+fn _get_sources(setup: &Setup, address: &str) -> Vec<Value> {
+    let (req, payment_method) = payments::build_get_payment_sources_request(setup.wallet_handle, Some(IDENTIFIER), address).unwrap();
+    let sources = payments::parse_get_payment_sources_response(&payment_method, &req).unwrap();
+    serde_json::from_str(&sources).unwrap()
+}
+
+mod high_cases {
+    use super::*;
+
+    #[test]
+    fn null_payment_method_mint_and_payment_work() {
+        let setup = Setup::wallet();
+        payments::register_null_payment_method().unwrap();
+
+        let address_1 = payments::create_payment_address(setup.wallet_handle, EMPTY_OBJECT, PAYMENT_METHOD_NAME).unwrap();
+        let address_2 = payments::create_payment_address(setup.wallet_handle, EMPTY_OBJECT, PAYMENT_METHOD_NAME).unwrap();
+
+        let source = _mint(&setup, &address_1, 10);
+
+        let sources = _get_sources(&setup, &address_1);
+        assert_eq!(1, sources.len());
+        assert_eq!(source, sources[0]["source"].as_str().unwrap());
+        assert_eq!(10, sources[0]["amount"].as_u64().unwrap());
+
+        let inputs = json!([source]).to_string();
+        let (payment_req, _) = payments::build_payment_req(setup.wallet_handle, Some(IDENTIFIER), &inputs,
+                                                           &_outputs(&[(&address_1, 6), (&address_2, 4)]), Some("extra")).unwrap();
+
+        let receipts = payments::parse_payment_response(PAYMENT_METHOD_NAME, &payment_req).unwrap();
+        let receipts: Vec<Value> = serde_json::from_str(&receipts).unwrap();
+        assert_eq!(2, receipts.len());
+
+        assert_eq!(6, _get_sources(&setup, &address_1)[0]["amount"].as_u64().unwrap());
+        assert_eq!(4, _get_sources(&setup, &address_2)[0]["amount"].as_u64().unwrap());
+
+        let (verify_req, _) = payments::build_verify_payment_req(setup.wallet_handle, Some(IDENTIFIER), receipts[1]["receipt"].as_str().unwrap()).unwrap();
+        let txn = payments::parse_verify_payment_response(PAYMENT_METHOD_NAME, &verify_req).unwrap();
+        let txn: Value = serde_json::from_str(&txn).unwrap();
+        assert_eq!(json!([source]), txn["sources"]);
+        assert_eq!("extra", txn["extra"].as_str().unwrap());
+    }
+
+    #[test]
+    fn null_payment_method_fees_work() {
+        let setup = Setup::wallet();
+        payments::register_null_payment_method().unwrap();
+
+        let address = payments::create_payment_address(setup.wallet_handle, EMPTY_OBJECT, PAYMENT_METHOD_NAME).unwrap();
+        let source = _mint(&setup, &address, 10);
+
+        payments::build_set_txn_fees_req(setup.wallet_handle, Some(IDENTIFIER), PAYMENT_METHOD_NAME, r#"{"1": 2}"#).unwrap();
+
+        let get_fees_req = payments::build_get_txn_fees_req(setup.wallet_handle, Some(IDENTIFIER), PAYMENT_METHOD_NAME).unwrap();
+        let fees = payments::parse_get_txn_fees_response(PAYMENT_METHOD_NAME, &get_fees_req).unwrap();
+        let fees: Value = serde_json::from_str(&fees).unwrap();
+        assert_eq!(2, fees["1"].as_u64().unwrap());
+
+        let nym_req = ledger::build_nym_request(DID_TRUSTEE, IDENTIFIER, None, None, None).unwrap();
+        let inputs = json!([source]).to_string();
+
+        let estimate = payments::estimate_request_fees(setup.wallet_handle, None, &nym_req, &inputs).unwrap();
+        let estimate: Value = serde_json::from_str(&estimate).unwrap();
+        assert_eq!(2, estimate["fee"].as_u64().unwrap());
+
+        let (nym_req_with_fees, _) = payments::add_request_fees(setup.wallet_handle, None, &nym_req, &inputs, &_outputs(&[(&address, 8)]), None).unwrap();
+
+        let receipts = payments::parse_response_with_fees(PAYMENT_METHOD_NAME, &nym_req_with_fees).unwrap();
+        let receipts: Vec<Value> = serde_json::from_str(&receipts).unwrap();
+        assert_eq!(8, receipts[0]["amount"].as_u64().unwrap());
+    }
+
+    #[test]
+    fn null_payment_method_sign_and_verify_work() {
+        let setup = Setup::wallet();
+        payments::register_null_payment_method().unwrap();
+
+        let address = payments::create_payment_address(setup.wallet_handle, EMPTY_OBJECT, PAYMENT_METHOD_NAME).unwrap();
+        let message = vec![1, 2, 3];
+
+        let signature = payments::sign_with_address(setup.wallet_handle, &address, &message).unwrap();
+
+        assert!(payments::verify_with_address(&address, &message, &signature).unwrap());
+        assert!(!payments::verify_with_address(&address, &[3, 2, 1], &signature).unwrap());
+    }
+
+    #[test]
+    fn null_payment_method_verify_payment_receipts_works() {
+        let setup = Setup::wallet();
+        payments::register_null_payment_method().unwrap();
+
+        let address = payments::create_payment_address(setup.wallet_handle, EMPTY_OBJECT, PAYMENT_METHOD_NAME).unwrap();
+        let receipt = _mint(&setup, &address, 10);
+
+        let receipts = json!([receipt, "txo:null:unknown"]).to_string();
+        let infos = payments::verify_payment_receipts(setup.wallet_handle, None, &receipts).unwrap();
+        let infos: Vec<Value> = serde_json::from_str(&infos).unwrap();
+
+        assert_eq!(true, infos[0]["verified"].as_bool().unwrap());
+        assert_eq!(false, infos[1]["verified"].as_bool().unwrap());
+    }
+}
+
+#[cfg(not(feature = "only_high_cases"))]
+mod medium_cases {
+    use super::*;
+
+    #[test]
+    fn null_payment_method_payment_works_for_insufficient_funds() {
+        let setup = Setup::wallet();
+        payments::register_null_payment_method().unwrap();
+
+        let address = payments::create_payment_address(setup.wallet_handle, EMPTY_OBJECT, PAYMENT_METHOD_NAME).unwrap();
+        let inputs = json!([_mint(&setup, &address, 10)]).to_string();
+
+        let res = payments::build_payment_req(setup.wallet_handle, None, &inputs, &_outputs(&[(&address, 11)]), None);
+
+        assert_code!(ErrorCode::PaymentInsufficientFundsError, res);
+    }
+
+    #[test]
+    fn null_payment_method_payment_works_for_spent_source() {
+        let setup = Setup::wallet();
+        payments::register_null_payment_method().unwrap();
+
+        let address = payments::create_payment_address(setup.wallet_handle, EMPTY_OBJECT, PAYMENT_METHOD_NAME).unwrap();
+        let inputs = json!([_mint(&setup, &address, 10)]).to_string();
+        payments::build_payment_req(setup.wallet_handle, None, &inputs, &_outputs(&[(&address, 10)]), None).unwrap();
+
+        let res = payments::build_payment_req(setup.wallet_handle, None, &inputs, &_outputs(&[(&address, 10)]), None);
+
+        assert_code!(ErrorCode::PaymentSourceDoesNotExistError, res);
+    }
+
+    #[test]
+    fn null_payment_method_create_payment_address_works_for_seed() {
+        let setup = Setup::wallet();
+        payments::register_null_payment_method().unwrap();
+
+        let config = json!({"seed": "null_payment_method_seed"}).to_string();
+        let address = payments::create_payment_address(setup.wallet_handle, &config, PAYMENT_METHOD_NAME).unwrap();
+
+        assert!(address.starts_with("pay:null:"));
+    }
+}
@@ -6,7 +6,7 @@ inject_indy_dependencies!();
 extern crate indyrs as indy;
 extern crate indyrs as api;
 
-use crate::utils::{did, pairwise};
+use crate::utils::{did, non_secrets, pairwise};
 use crate::utils::constants::*;
 use crate::utils::Setup;
 
@@ -162,6 +162,393 @@ mod high_cases {
             assert_code!(ErrorCode::WalletItemNotFound, res);
         }
     }
+
+    mod update_pairwise {
+        use super::*;
+
+        #[test]
+        fn indy_update_pairwise_works() {
+            let setup = Setup::did();
+
+            did::store_their_did_from_parts(setup.wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+            did::store_their_did_from_parts(setup.wallet_handle, DID_MY1, VERKEY_MY1).unwrap();
+            pairwise::create_pairwise(setup.wallet_handle, DID_TRUSTEE, &setup.did, Some(METADATA)).unwrap();
+            pairwise::set_pairwise_meta(setup.wallet_handle, DID_TRUSTEE, r#"{"state":"active"}"#).unwrap();
+
+            pairwise::update_pairwise(setup.wallet_handle, DID_TRUSTEE, DID_MY1).unwrap();
+
+            assert!(!pairwise::pairwise_exists(setup.wallet_handle, DID_TRUSTEE).unwrap());
+
+            let pairwise_info_json = pairwise::get_pairwise(setup.wallet_handle, DID_MY1).unwrap();
+            assert_eq!(format!(r#"{{"my_did":"{}","metadata":"{}"}}"#, setup.did, METADATA), pairwise_info_json);
+
+            let (search_handle, _) = pairwise::open_pairwise_search(setup.wallet_handle, Some(r#"{"state":"active"}"#), None).unwrap();
+            let list_pairwise = pairwise::fetch_pairwise(search_handle, 10).unwrap();
+            let list_pairwise: serde_json::Value = serde_json::from_str(&list_pairwise).unwrap();
+            assert_eq!(1, list_pairwise.as_array().unwrap().len());
+            assert_eq!(json!(DID_MY1), list_pairwise[0]["their_did"]);
+            pairwise::close_pairwise_search(search_handle).unwrap();
+        }
+
+        #[test]
+        fn indy_update_pairwise_works_for_not_created_pairwise() {
+            let setup = Setup::wallet();
+
+            did::store_their_did_from_parts(setup.wallet_handle, DID_MY1, VERKEY_MY1).unwrap();
+
+            let res = pairwise::update_pairwise(setup.wallet_handle, DID_TRUSTEE, DID_MY1);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+    }
+
+    mod delete_pairwise {
+        use super::*;
+
+        #[test]
+        fn indy_delete_pairwise_works() {
+            let setup = Setup::did();
+
+            did::store_their_did_from_parts(setup.wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+            pairwise::create_pairwise(setup.wallet_handle, DID_TRUSTEE, &setup.did, None).unwrap();
+
+            pairwise::delete_pairwise(setup.wallet_handle, DID_TRUSTEE).unwrap();
+
+            assert!(!pairwise::pairwise_exists(setup.wallet_handle, DID_TRUSTEE).unwrap());
+            assert_eq!(VERKEY_TRUSTEE, did::key_for_local_did(setup.wallet_handle, DID_TRUSTEE).unwrap());
+        }
+
+        #[test]
+        fn indy_delete_pairwise_works_for_not_created_pairwise() {
+            let setup = Setup::wallet();
+
+            let res = pairwise::delete_pairwise(setup.wallet_handle, DID_TRUSTEE);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+    }
+
+    mod pairwise_links {
+        use super::*;
+
+        fn _record_tags(wallet_handle: indy::WalletHandle, type_: &str, id: &str) -> serde_json::Value {
+            let record = non_secrets::get_wallet_record(wallet_handle, type_, id, r#"{"retrieveTags":true}"#).unwrap();
+            let record: serde_json::Value = serde_json::from_str(&record).unwrap();
+            record["tags"].clone()
+        }
+
+        #[test]
+        fn indy_link_pairwise_record_works() {
+            let setup = Setup::did();
+
+            did::store_their_did_from_parts(setup.wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+            pairwise::create_pairwise(setup.wallet_handle, DID_TRUSTEE, &setup.did, None).unwrap();
+            non_secrets::add_wallet_record(setup.wallet_handle, non_secrets::TYPE, non_secrets::ID, non_secrets::VALUE, None).unwrap();
+
+            pairwise::link_pairwise_record(setup.wallet_handle, DID_TRUSTEE, non_secrets::TYPE, non_secrets::ID).unwrap();
+
+            let links = pairwise::get_pairwise_links(setup.wallet_handle, DID_TRUSTEE, None).unwrap();
+            let links: serde_json::Value = serde_json::from_str(&links).unwrap();
+            assert_eq!(json!([{"type": non_secrets::TYPE, "id": non_secrets::ID}]), links);
+
+            let query = json!({format!("pairwise:{}", DID_TRUSTEE): "1"}).to_string();
+            let search_handle = non_secrets::open_wallet_search(setup.wallet_handle, non_secrets::TYPE, &query, "{}").unwrap();
+            let records = non_secrets::fetch_wallet_search_next_records(setup.wallet_handle, search_handle, 10).unwrap();
+            let records: serde_json::Value = serde_json::from_str(&records).unwrap();
+            assert_eq!(1, records["records"].as_array().unwrap().len());
+            assert_eq!(json!(non_secrets::ID), records["records"][0]["id"]);
+            non_secrets::close_wallet_search(search_handle).unwrap();
+        }
+
+        #[test]
+        fn indy_get_pairwise_links_works_for_record_type() {
+            let setup = Setup::did();
+
+            did::store_their_did_from_parts(setup.wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+            pairwise::create_pairwise(setup.wallet_handle, DID_TRUSTEE, &setup.did, None).unwrap();
+            non_secrets::add_wallet_record(setup.wallet_handle, non_secrets::TYPE, non_secrets::ID, non_secrets::VALUE, None).unwrap();
+            non_secrets::add_wallet_record(setup.wallet_handle, non_secrets::TYPE_2, non_secrets::ID, non_secrets::VALUE, None).unwrap();
+
+            pairwise::link_pairwise_record(setup.wallet_handle, DID_TRUSTEE, non_secrets::TYPE, non_secrets::ID).unwrap();
+            pairwise::link_pairwise_record(setup.wallet_handle, DID_TRUSTEE, non_secrets::TYPE_2, non_secrets::ID).unwrap();
+
+            let links = pairwise::get_pairwise_links(setup.wallet_handle, DID_TRUSTEE, None).unwrap();
+            let links: serde_json::Value = serde_json::from_str(&links).unwrap();
+            assert_eq!(2, links.as_array().unwrap().len());
+
+            let links = pairwise::get_pairwise_links(setup.wallet_handle, DID_TRUSTEE, Some(non_secrets::TYPE_2)).unwrap();
+            let links: serde_json::Value = serde_json::from_str(&links).unwrap();
+            assert_eq!(json!([{"type": non_secrets::TYPE_2, "id": non_secrets::ID}]), links);
+        }
+
+        #[test]
+        fn indy_unlink_pairwise_record_works() {
+            let setup = Setup::did();
+
+            did::store_their_did_from_parts(setup.wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+            pairwise::create_pairwise(setup.wallet_handle, DID_TRUSTEE, &setup.did, None).unwrap();
+            non_secrets::add_wallet_record(setup.wallet_handle, non_secrets::TYPE, non_secrets::ID, non_secrets::VALUE, Some(r#"{"tagName1":"str1"}"#)).unwrap();
+            pairwise::link_pairwise_record(setup.wallet_handle, DID_TRUSTEE, non_secrets::TYPE, non_secrets::ID).unwrap();
+
+            pairwise::unlink_pairwise_record(setup.wallet_handle, DID_TRUSTEE, non_secrets::TYPE, non_secrets::ID).unwrap();
+
+            assert_eq!("[]", pairwise::get_pairwise_links(setup.wallet_handle, DID_TRUSTEE, None).unwrap());
+            assert_eq!(json!({"tagName1": "str1"}), _record_tags(setup.wallet_handle, non_secrets::TYPE, non_secrets::ID));
+        }
+
+        #[test]
+        fn indy_delete_pairwise_works_for_linked_records() {
+            let setup = Setup::did();
+
+            did::store_their_did_from_parts(setup.wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+            pairwise::create_pairwise(setup.wallet_handle, DID_TRUSTEE, &setup.did, None).unwrap();
+            non_secrets::add_wallet_record(setup.wallet_handle, non_secrets::TYPE, non_secrets::ID, non_secrets::VALUE, None).unwrap();
+            pairwise::link_pairwise_record(setup.wallet_handle, DID_TRUSTEE, non_secrets::TYPE, non_secrets::ID).unwrap();
+
+            pairwise::delete_pairwise(setup.wallet_handle, DID_TRUSTEE).unwrap();
+
+            assert_eq!(json!({}), _record_tags(setup.wallet_handle, non_secrets::TYPE, non_secrets::ID));
+
+            pairwise::create_pairwise(setup.wallet_handle, DID_TRUSTEE, &setup.did, None).unwrap();
+            assert_eq!("[]", pairwise::get_pairwise_links(setup.wallet_handle, DID_TRUSTEE, None).unwrap());
+        }
+
+        #[test]
+        fn indy_update_pairwise_works_for_linked_records() {
+            let setup = Setup::did();
+
+            did::store_their_did_from_parts(setup.wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+            did::store_their_did_from_parts(setup.wallet_handle, DID_MY1, VERKEY_MY1).unwrap();
+            pairwise::create_pairwise(setup.wallet_handle, DID_TRUSTEE, &setup.did, None).unwrap();
+            non_secrets::add_wallet_record(setup.wallet_handle, non_secrets::TYPE, non_secrets::ID, non_secrets::VALUE, None).unwrap();
+            pairwise::link_pairwise_record(setup.wallet_handle, DID_TRUSTEE, non_secrets::TYPE, non_secrets::ID).unwrap();
+
+            pairwise::update_pairwise(setup.wallet_handle, DID_TRUSTEE, DID_MY1).unwrap();
+
+            let links = pairwise::get_pairwise_links(setup.wallet_handle, DID_MY1, None).unwrap();
+            let links: serde_json::Value = serde_json::from_str(&links).unwrap();
+            assert_eq!(json!([{"type": non_secrets::TYPE, "id": non_secrets::ID}]), links);
+            assert_eq!(json!({format!("pairwise:{}", DID_MY1): "1"}), _record_tags(setup.wallet_handle, non_secrets::TYPE, non_secrets::ID));
+        }
+    }
+
+    mod set_pairwise_meta {
+        use super::*;
+
+        #[test]
+        fn indy_set_pairwise_meta_works() {
+            let setup = Setup::did();
+
+            did::store_their_did_from_parts(setup.wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+            did::store_their_did_from_parts(setup.wallet_handle, DID_MY1, VERKEY_MY1).unwrap();
+            pairwise::create_pairwise(setup.wallet_handle, DID_TRUSTEE, &setup.did, Some(METADATA)).unwrap();
+            pairwise::create_pairwise(setup.wallet_handle, DID_MY1, &setup.did, None).unwrap();
+
+            pairwise::set_pairwise_meta(setup.wallet_handle, DID_TRUSTEE, r#"{"label":"Alice","state":"invited"}"#).unwrap();
+            pairwise::set_pairwise_meta(setup.wallet_handle, DID_MY1, r#"{"label":"Bob","state":"invited"}"#).unwrap();
+            pairwise::set_pairwise_meta(setup.wallet_handle, DID_TRUSTEE, r#"{"state":"active"}"#).unwrap();
+
+            let (search_handle, total_count) = pairwise::open_pairwise_search(setup.wallet_handle, Some(r#"{"state":"active"}"#), None).unwrap();
+            assert_eq!(1, total_count);
+
+            let list_pairwise = pairwise::fetch_pairwise(search_handle, 10).unwrap();
+            let list_pairwise: serde_json::Value = serde_json::from_str(&list_pairwise).unwrap();
+            assert_eq!(json!(DID_TRUSTEE), list_pairwise[0]["their_did"]);
+            assert_eq!(json!(METADATA), list_pairwise[0]["metadata"]);
+            assert_eq!(json!("Alice"), list_pairwise[0]["label"]);
+            assert_eq!(json!("active"), list_pairwise[0]["state"]);
+            assert!(list_pairwise[0]["created_at"].is_u64());
+
+            pairwise::close_pairwise_search(search_handle).unwrap();
+        }
+
+        #[test]
+        fn indy_set_pairwise_meta_works_for_kept_after_metadata_update() {
+            let setup = Setup::did();
+
+            did::store_their_did_from_parts(setup.wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+            pairwise::create_pairwise(setup.wallet_handle, DID_TRUSTEE, &setup.did, None).unwrap();
+
+            pairwise::set_pairwise_meta(setup.wallet_handle, DID_TRUSTEE, r#"{"state":"active"}"#).unwrap();
+            pairwise::set_pairwise_metadata(setup.wallet_handle, DID_TRUSTEE, Some(r#"{"state":"ignored"}"#)).unwrap();
+
+            let (search_handle, total_count) = pairwise::open_pairwise_search(setup.wallet_handle, Some(r#"{"state":"active"}"#), None).unwrap();
+            assert_eq!(1, total_count);
+            pairwise::close_pairwise_search(search_handle).unwrap();
+        }
+
+        #[test]
+        fn indy_set_pairwise_meta_works_for_overriding_metadata_fields() {
+            let setup = Setup::did();
+
+            did::store_their_did_from_parts(setup.wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+            pairwise::create_pairwise(setup.wallet_handle, DID_TRUSTEE, &setup.did, Some(r#"{"label":"Bob","state":"invited"}"#)).unwrap();
+
+            let (search_handle, total_count) = pairwise::open_pairwise_search(setup.wallet_handle, Some(r#"{"label":"Bob","state":"invited"}"#), None).unwrap();
+            assert_eq!(1, total_count);
+            pairwise::close_pairwise_search(search_handle).unwrap();
+
+            pairwise::set_pairwise_meta(setup.wallet_handle, DID_TRUSTEE, r#"{"label":"Alice"}"#).unwrap();
+
+            let (search_handle, total_count) = pairwise::open_pairwise_search(setup.wallet_handle, Some(r#"{"label":"Alice"}"#), None).unwrap();
+            assert_eq!(1, total_count);
+            pairwise::close_pairwise_search(search_handle).unwrap();
+
+            // metadata fields with names of structured meta are no longer tags
+            let (search_handle, total_count) = pairwise::open_pairwise_search(setup.wallet_handle, Some(r#"{"$or":[{"label":"Bob"},{"state":"invited"}]}"#), None).unwrap();
+            assert_eq!(0, total_count);
+            pairwise::close_pairwise_search(search_handle).unwrap();
+        }
+
+        #[test]
+        fn indy_set_pairwise_meta_works_for_not_created_pairwise() {
+            let setup = Setup::wallet();
+
+            let res = pairwise::set_pairwise_meta(setup.wallet_handle, DID_TRUSTEE, r#"{"state":"active"}"#);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+    }
+
+    mod migrate_pairwise_meta {
+        use super::*;
+
+        #[test]
+        fn indy_migrate_pairwise_meta_works() {
+            let setup = Setup::did();
+
+            did::store_their_did_from_parts(setup.wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+            did::store_their_did_from_parts(setup.wallet_handle, DID_MY1, VERKEY_MY1).unwrap();
+            pairwise::create_pairwise(setup.wallet_handle, DID_TRUSTEE, &setup.did, Some(r#"{"label":"Alice","state":"active"}"#)).unwrap();
+            pairwise::create_pairwise(setup.wallet_handle, DID_MY1, &setup.did, Some(METADATA)).unwrap();
+
+            // metadata fields are searchable before the migration
+            let (search_handle, total_count) = pairwise::open_pairwise_search(setup.wallet_handle, Some(r#"{"state":"active"}"#), None).unwrap();
+            assert_eq!(1, total_count);
+            pairwise::close_pairwise_search(search_handle).unwrap();
+
+            let report = pairwise::migrate_pairwise_meta(setup.wallet_handle).unwrap();
+            assert_eq!(json!({"total": 2, "migrated": 1}), serde_json::from_str::<serde_json::Value>(&report).unwrap());
+
+            let (search_handle, total_count) = pairwise::open_pairwise_search(setup.wallet_handle, Some(r#"{"label":"Alice","state":"active"}"#), None).unwrap();
+            assert_eq!(1, total_count);
+
+            let list_pairwise = pairwise::fetch_pairwise(search_handle, 10).unwrap();
+            let list_pairwise: serde_json::Value = serde_json::from_str(&list_pairwise).unwrap();
+            assert_eq!(json!("Alice"), list_pairwise[0]["label"]);
+            assert_eq!(json!("active"), list_pairwise[0]["state"]);
+            pairwise::close_pairwise_search(search_handle).unwrap();
+
+            let report = pairwise::migrate_pairwise_meta(setup.wallet_handle).unwrap();
+            assert_eq!(json!({"total": 2, "migrated": 0}), serde_json::from_str::<serde_json::Value>(&report).unwrap());
+        }
+    }
+
+    mod pairwise_search {
+        use super::*;
+
+        #[test]
+        fn indy_pairwise_search_works() {
+            let setup = Setup::did();
+
+            for their_did in &[DID_TRUSTEE, DID_MY1, DID_MY2] {
+                did::store_their_did_from_parts(setup.wallet_handle, their_did, VERKEY_TRUSTEE).unwrap();
+                pairwise::create_pairwise(setup.wallet_handle, their_did, &setup.did, None).unwrap();
+            }
+
+            let (search_handle, total_count) = pairwise::open_pairwise_search(setup.wallet_handle, None, None).unwrap();
+            assert_eq!(3, total_count);
+
+            let list_pairwise = pairwise::fetch_pairwise(search_handle, 2).unwrap();
+            let list_pairwise: serde_json::Value = serde_json::from_str(&list_pairwise).unwrap();
+            assert_eq!(2, list_pairwise.as_array().unwrap().len());
+
+            let list_pairwise = pairwise::fetch_pairwise(search_handle, 2).unwrap();
+            let list_pairwise: serde_json::Value = serde_json::from_str(&list_pairwise).unwrap();
+            assert_eq!(1, list_pairwise.as_array().unwrap().len());
+
+            pairwise::close_pairwise_search(search_handle).unwrap();
+        }
+
+        #[test]
+        fn indy_pairwise_search_works_for_metadata_query() {
+            let setup = Setup::did();
+
+            did::store_their_did_from_parts(setup.wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+            did::store_their_did_from_parts(setup.wallet_handle, DID_MY1, VERKEY_MY1).unwrap();
+
+            let metadata = json!({"label": "Alice", "~state": "active"}).to_string();
+            pairwise::create_pairwise(setup.wallet_handle, DID_TRUSTEE, &setup.did, Some(&metadata)).unwrap();
+            pairwise::create_pairwise(setup.wallet_handle, DID_MY1, &setup.did, Some(r#"{"label":"Bob"}"#)).unwrap();
+
+            let query = json!({"label": "Alice", "~state": "active", "my_did": setup.did}).to_string();
+            let (search_handle, total_count) = pairwise::open_pairwise_search(setup.wallet_handle, Some(&query), None).unwrap();
+            assert_eq!(1, total_count);
+
+            let list_pairwise = pairwise::fetch_pairwise(search_handle, 10).unwrap();
+            let list_pairwise: serde_json::Value = serde_json::from_str(&list_pairwise).unwrap();
+            assert_eq!(json!([{"my_did": setup.did, "their_did": DID_TRUSTEE, "metadata": metadata}]), list_pairwise);
+
+            pairwise::close_pairwise_search(search_handle).unwrap();
+        }
+
+        #[test]
+        fn indy_pairwise_search_works_for_updated_metadata() {
+            let setup = Setup::did();
+
+            did::store_their_did_from_parts(setup.wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+            pairwise::create_pairwise(setup.wallet_handle, DID_TRUSTEE, &setup.did, Some(r#"{"~state":"invited"}"#)).unwrap();
+            pairwise::set_pairwise_metadata(setup.wallet_handle, DID_TRUSTEE, Some(r#"{"~state":"active"}"#)).unwrap();
+
+            let (search_handle, total_count) = pairwise::open_pairwise_search(setup.wallet_handle, Some(r#"{"~state":"invited"}"#), None).unwrap();
+            assert_eq!(0, total_count);
+            pairwise::close_pairwise_search(search_handle).unwrap();
+
+            let (search_handle, total_count) = pairwise::open_pairwise_search(setup.wallet_handle, Some(r#"{"~state":"active"}"#), None).unwrap();
+            assert_eq!(1, total_count);
+            pairwise::close_pairwise_search(search_handle).unwrap();
+        }
+
+        #[test]
+        fn indy_pairwise_search_works_for_created_at_query() {
+            let setup = Setup::did();
+
+            did::store_their_did_from_parts(setup.wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+            pairwise::create_pairwise(setup.wallet_handle, DID_TRUSTEE, &setup.did, None).unwrap();
+            // creation time is returned with structured meta
+            pairwise::set_pairwise_meta(setup.wallet_handle, DID_TRUSTEE, r#"{"state":"active"}"#).unwrap();
+
+            let (search_handle, _) = pairwise::open_pairwise_search(setup.wallet_handle, None, None).unwrap();
+            let list_pairwise = pairwise::fetch_pairwise(search_handle, 10).unwrap();
+            let list_pairwise: serde_json::Value = serde_json::from_str(&list_pairwise).unwrap();
+            let created_at = list_pairwise[0]["created_at"].as_u64().unwrap();
+            pairwise::close_pairwise_search(search_handle).unwrap();
+
+            let query = json!({"~created_at": {"$gte": created_at.to_string()}}).to_string();
+            let (search_handle, total_count) = pairwise::open_pairwise_search(setup.wallet_handle, Some(&query), None).unwrap();
+            assert_eq!(1, total_count);
+            pairwise::close_pairwise_search(search_handle).unwrap();
+
+            let query = json!({"~created_at": {"$gt": created_at.to_string()}}).to_string();
+            let (search_handle, total_count) = pairwise::open_pairwise_search(setup.wallet_handle, Some(&query), None).unwrap();
+            assert_eq!(0, total_count);
+            pairwise::close_pairwise_search(search_handle).unwrap();
+        }
+
+        #[test]
+        fn indy_pairwise_search_works_for_retrieve_verkeys() {
+            let setup = Setup::did();
+
+            did::store_their_did_from_parts(setup.wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+            pairwise::create_pairwise(setup.wallet_handle, DID_TRUSTEE, &setup.did, None).unwrap();
+
+            let options = json!({"retrieveVerkeys": true}).to_string();
+            let (search_handle, _) = pairwise::open_pairwise_search(setup.wallet_handle, None, Some(&options)).unwrap();
+
+            let list_pairwise = pairwise::fetch_pairwise(search_handle, 10).unwrap();
+            let list_pairwise: serde_json::Value = serde_json::from_str(&list_pairwise).unwrap();
+            assert_eq!(json!([{"my_did": setup.did, "their_did": DID_TRUSTEE, "my_verkey": setup.verkey, "their_verkey": VERKEY_TRUSTEE}]), list_pairwise);
+
+            pairwise::close_pairwise_search(search_handle).unwrap();
+        }
+    }
 }
 
 #[cfg(not(feature = "only_high_cases"))]
@@ -239,6 +626,74 @@ mod medium_cases {
         }
     }
 
+    mod pairwise_search {
+        use super::*;
+
+        #[test]
+        fn indy_fetch_pairwise_works_for_closed_search() {
+            let setup = Setup::wallet();
+
+            let (search_handle, _) = pairwise::open_pairwise_search(setup.wallet_handle, None, None).unwrap();
+            pairwise::close_pairwise_search(search_handle).unwrap();
+
+            let res = pairwise::fetch_pairwise(search_handle, 1);
+            assert_code!(ErrorCode::WalletInvalidHandle, res);
+        }
+
+        #[test]
+        fn indy_open_pairwise_search_works_for_invalid_options() {
+            let setup = Setup::wallet();
+
+            let res = pairwise::open_pairwise_search(setup.wallet_handle, None, Some(r#"{"retrieveVerkeys":"yes"}"#));
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
+
+    mod update_pairwise {
+        use super::*;
+
+        #[test]
+        fn indy_update_pairwise_works_for_not_found_new_their_did() {
+            let setup = Setup::did();
+
+            did::store_their_did_from_parts(setup.wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+            pairwise::create_pairwise(setup.wallet_handle, DID_TRUSTEE, &setup.did, None).unwrap();
+
+            let res = pairwise::update_pairwise(setup.wallet_handle, DID_TRUSTEE, DID_MY1);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+
+            assert!(pairwise::pairwise_exists(setup.wallet_handle, DID_TRUSTEE).unwrap());
+        }
+
+        #[test]
+        fn indy_update_pairwise_works_for_existing_new_pairwise() {
+            let setup = Setup::did();
+
+            did::store_their_did_from_parts(setup.wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+            did::store_their_did_from_parts(setup.wallet_handle, DID_MY1, VERKEY_MY1).unwrap();
+            pairwise::create_pairwise(setup.wallet_handle, DID_TRUSTEE, &setup.did, None).unwrap();
+            pairwise::create_pairwise(setup.wallet_handle, DID_MY1, &setup.did, None).unwrap();
+
+            let res = pairwise::update_pairwise(setup.wallet_handle, DID_TRUSTEE, DID_MY1);
+            assert_code!(ErrorCode::WalletItemAlreadyExists, res);
+        }
+    }
+
+    mod set_pairwise_meta {
+        use super::*;
+
+        #[test]
+        fn indy_set_pairwise_meta_works_for_invalid_meta() {
+            let setup = Setup::did();
+
+            did::store_their_did_from_parts(setup.wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+            pairwise::create_pairwise(setup.wallet_handle, DID_TRUSTEE, &setup.did, None).unwrap();
+
+            let res = pairwise::set_pairwise_meta(setup.wallet_handle, DID_TRUSTEE, r#"{"created_at":1}"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
+
     mod set_pairwise_metadata {
         use super::*;
 
@@ -268,4 +723,74 @@ mod medium_cases {
             assert_code!(ErrorCode::WalletInvalidHandle, res);
         }
     }
+
+    mod pairwise_links {
+        use super::*;
+
+        #[test]
+        fn indy_link_pairwise_record_works_for_not_created_pairwise() {
+            let setup = Setup::wallet();
+
+            non_secrets::add_wallet_record(setup.wallet_handle, non_secrets::TYPE, non_secrets::ID, non_secrets::VALUE, None).unwrap();
+
+            let res = pairwise::link_pairwise_record(setup.wallet_handle, DID_TRUSTEE, non_secrets::TYPE, non_secrets::ID);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+
+        #[test]
+        fn indy_link_pairwise_record_works_for_not_found_record() {
+            let setup = Setup::did();
+
+            did::store_their_did_from_parts(setup.wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+            pairwise::create_pairwise(setup.wallet_handle, DID_TRUSTEE, &setup.did, None).unwrap();
+
+            let res = pairwise::link_pairwise_record(setup.wallet_handle, DID_TRUSTEE, non_secrets::TYPE, non_secrets::ID);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+
+            assert_eq!("[]", pairwise::get_pairwise_links(setup.wallet_handle, DID_TRUSTEE, None).unwrap());
+        }
+
+        #[test]
+        fn indy_link_pairwise_record_works_for_twice() {
+            let setup = Setup::did();
+
+            did::store_their_did_from_parts(setup.wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+            pairwise::create_pairwise(setup.wallet_handle, DID_TRUSTEE, &setup.did, None).unwrap();
+            non_secrets::add_wallet_record(setup.wallet_handle, non_secrets::TYPE, non_secrets::ID, non_secrets::VALUE, None).unwrap();
+
+            pairwise::link_pairwise_record(setup.wallet_handle, DID_TRUSTEE, non_secrets::TYPE, non_secrets::ID).unwrap();
+            pairwise::link_pairwise_record(setup.wallet_handle, DID_TRUSTEE, non_secrets::TYPE, non_secrets::ID).unwrap();
+
+            let links = pairwise::get_pairwise_links(setup.wallet_handle, DID_TRUSTEE, None).unwrap();
+            let links: serde_json::Value = serde_json::from_str(&links).unwrap();
+            assert_eq!(1, links.as_array().unwrap().len());
+        }
+
+        #[test]
+        fn indy_unlink_pairwise_record_works_for_not_linked_record() {
+            let setup = Setup::did();
+
+            did::store_their_did_from_parts(setup.wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+            pairwise::create_pairwise(setup.wallet_handle, DID_TRUSTEE, &setup.did, None).unwrap();
+            non_secrets::add_wallet_record(setup.wallet_handle, non_secrets::TYPE, non_secrets::ID, non_secrets::VALUE, None).unwrap();
+
+            let res = pairwise::unlink_pairwise_record(setup.wallet_handle, DID_TRUSTEE, non_secrets::TYPE, non_secrets::ID);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+
+        #[test]
+        fn indy_unlink_pairwise_record_works_for_deleted_record() {
+            let setup = Setup::did();
+
+            did::store_their_did_from_parts(setup.wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+            pairwise::create_pairwise(setup.wallet_handle, DID_TRUSTEE, &setup.did, None).unwrap();
+            non_secrets::add_wallet_record(setup.wallet_handle, non_secrets::TYPE, non_secrets::ID, non_secrets::VALUE, None).unwrap();
+            pairwise::link_pairwise_record(setup.wallet_handle, DID_TRUSTEE, non_secrets::TYPE, non_secrets::ID).unwrap();
+            non_secrets::delete_wallet_record(setup.wallet_handle, non_secrets::TYPE, non_secrets::ID).unwrap();
+
+            pairwise::unlink_pairwise_record(setup.wallet_handle, DID_TRUSTEE, non_secrets::TYPE, non_secrets::ID).unwrap();
+
+            assert_eq!("[]", pairwise::get_pairwise_links(setup.wallet_handle, DID_TRUSTEE, None).unwrap());
+        }
+    }
 }
\ No newline at end of file
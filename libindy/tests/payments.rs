@@ -8,7 +8,7 @@ extern crate indyrs as api;
 extern crate sha2;
 
 use self::indy::ErrorCode;
-use crate::utils::payments;
+use crate::utils::{payments, pool};
 use crate::utils::constants::*;
 use crate::utils::Setup;
 
@@ -21,6 +21,7 @@ static CORRECT_FEES: &str = r#"{"txnType1":1, "txnType2":2}"#;
 static TEST_RES_STRING: &str = "test";
 static CORRECT_PAYMENT_ADDRESS: &str = "pay:null:test";
 static EXTRA: &str = "extra_1";
+static V2_INPUTS: &str = r#"["pay:nullv2:1", "pay:nullv2:2"]"#;
 
 mod high_cases {
     use super::*;
@@ -53,6 +54,45 @@ mod high_cases {
         }
     }
 
+    mod unregister_payment_method {
+        use super::*;
+
+        fn _register_mock_method(payment_method: &str) {
+            payments::register_payment_method(payment_method,
+                                              Some(payments::mock_method::create_payment_address::handle),
+                                              Some(payments::mock_method::add_request_fees::handle),
+                                              Some(payments::mock_method::parse_response_with_fees::handle),
+                                              Some(payments::mock_method::build_get_payment_sources_request::handle),
+                                              Some(payments::mock_method::parse_get_payment_sources_response::handle),
+                                              Some(payments::mock_method::build_payment_req::handle),
+                                              Some(payments::mock_method::parse_payment_response::handle),
+                                              Some(payments::mock_method::build_mint_req::handle),
+                                              Some(payments::mock_method::build_set_txn_fees_req::handle),
+                                              Some(payments::mock_method::build_get_txn_fees_req::handle),
+                                              Some(payments::mock_method::parse_get_txn_fees_response::handle),
+                                              Some(payments::mock_method::build_verify_payment_req::handle),
+                                              Some(payments::mock_method::parse_verify_payment_response::handle),
+                                              Some(payments::mock_method::sign_with_address::handle),
+                                              Some(payments::mock_method::verify_with_address::handle)
+            ).unwrap();
+        }
+
+        #[test]
+        fn unregister_payment_method_works() {
+            let setup = Setup::wallet();
+
+            _register_mock_method("unregister_method");
+
+            payments::unregister_payment_method("unregister_method").unwrap();
+
+            let res = payments::create_payment_address(setup.wallet_handle, EMPTY_OBJECT, "unregister_method");
+            assert_code!(ErrorCode::UnknownPaymentMethod, res);
+
+            _register_mock_method("unregister_method");
+            payments::unregister_payment_method("unregister_method").unwrap();
+        }
+    }
+
     mod create_payment_address {
         use super::*;
 
@@ -86,6 +126,20 @@ mod high_cases {
         }
     }
 
+    mod get_payment_balances {
+        use super::*;
+
+        #[test]
+        fn get_payment_balances_works_for_empty_wallet() {
+            let setup = Setup::payment_wallet();
+
+            let balances = payments::get_payment_balances(setup.wallet_handle, setup.pool_handle).unwrap();
+            let balances: serde_json::Value = serde_json::from_str(&balances).unwrap();
+
+            assert_eq!(json!({"balances": {}, "addresses": []}), balances);
+        }
+    }
+
     mod add_request_fees {
         use super::*;
 
@@ -162,6 +216,51 @@ mod high_cases {
         }
     }
 
+    mod prepare_request_with_fees {
+        use super::*;
+        use crate::utils::ledger;
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn prepare_request_with_fees_works() {
+            let setup = Setup::trustee();
+            payments::mock_method::init();
+
+            let get_fees_request = ledger::build_get_txn_request(Some(&setup.did), 1, None).unwrap();
+            let request = ledger::build_nym_request(&setup.did, DID_MY1, None, None, None).unwrap();
+            let mut request_with_fees: serde_json::Value = serde_json::from_str(&request).unwrap();
+            request_with_fees["fees"] = json!([["pay:null:1"], []]);
+
+            payments::mock_method::build_get_txn_fees_req::inject_mock(ErrorCode::Success, &get_fees_request);
+            payments::mock_method::parse_get_txn_fees_response::inject_mock(ErrorCode::Success, r#"{"1": 10}"#);
+            payments::mock_method::add_request_fees::inject_mock(ErrorCode::Success, &request_with_fees.to_string());
+
+            let (request, receipt) = payments::prepare_request_with_fees(setup.wallet_handle,
+                                                                         setup.pool_handle,
+                                                                         &setup.did,
+                                                                         &request,
+                                                                         CORRECT_INPUTS,
+                                                                         EMPTY_ARRAY,
+                                                                         None,
+                                                                         None,
+                                                                         None,
+                                                                         0).unwrap();
+
+            let request: serde_json::Value = serde_json::from_str(&request).unwrap();
+            assert_eq!(request["fees"], request_with_fees["fees"]);
+            assert!(request["signatures"][&setup.did].is_string());
+
+            let receipt: serde_json::Value = serde_json::from_str(&receipt).unwrap();
+            assert_eq!(json!({
+                "paymentMethod": PAYMENT_METHOD_NAME,
+                "txnType": "1",
+                "fee": 10,
+                "inputs": ["pay:null:1", "pay:null:2"],
+                "outputs": []
+            }), receipt);
+        }
+    }
+
     mod parse_response_with_fees {
         use super::*;
 
@@ -290,6 +389,68 @@ mod high_cases {
         }
     }
 
+    mod build_get_payment_history_request {
+        use super::*;
+
+        #[test]
+        fn build_get_payment_history_request_works() {
+            let setup = Setup::payment_wallet();
+            payments::mock_method::init_v2();
+
+            payments::mock_method::build_get_payment_history_request::inject_mock(ErrorCode::Success, None, TEST_RES_STRING);
+
+            let (req, payment_method) = payments::build_get_payment_history_request(setup.wallet_handle, Some(IDENTIFIER), "pay:nullv2:1", Some(1)).unwrap();
+
+            assert_eq!(req, TEST_RES_STRING);
+            assert_eq!(payment_method, "nullv2");
+        }
+
+        #[test]
+        fn build_get_payment_history_request_works_for_no_from_and_empty_submitter_did() {
+            let setup = Setup::payment_wallet();
+            payments::mock_method::init_v2();
+
+            payments::mock_method::build_get_payment_history_request::inject_mock(ErrorCode::Success, None, TEST_RES_STRING);
+
+            let (req, payment_method) = payments::build_get_payment_history_request(setup.wallet_handle, None, "pay:nullv2:1", None).unwrap();
+
+            assert_eq!(req, TEST_RES_STRING);
+            assert_eq!(payment_method, "nullv2");
+        }
+    }
+
+    mod parse_get_payment_history_response {
+        use super::*;
+
+        #[test]
+        fn parse_get_payment_history_response_works() {
+            Setup::empty();
+            payments::mock_method::init_v2();
+
+            let txns = json!([{"receipt": "pay:nullv2:1", "sources": [], "receipts": []}]);
+            payments::mock_method::parse_get_payment_history_response::inject_mock(ErrorCode::Success, None, &json!({"txns": txns}).to_string());
+
+            let (history, next) = payments::parse_get_payment_history_response("nullv2", EMPTY_OBJECT).unwrap();
+
+            let history: serde_json::Value = serde_json::from_str(&history).unwrap();
+            assert_eq!(history, txns);
+            assert_eq!(next, None);
+        }
+
+        #[test]
+        fn parse_get_payment_history_response_works_has_next() {
+            Setup::empty();
+            payments::mock_method::init_v2();
+
+            payments::mock_method::parse_get_payment_history_response::inject_mock(ErrorCode::Success, None, &json!({"txns": [], "next": 5}).to_string());
+
+            let (history, next) = payments::parse_get_payment_history_response("nullv2", EMPTY_OBJECT).unwrap();
+
+            assert_eq!(history, EMPTY_ARRAY);
+            assert_eq!(next, Some(5));
+        }
+    }
+
     mod payment_request {
         use super::*;
 
@@ -742,6 +903,43 @@ mod high_cases {
             assert_eq!(res, vec![ 102, 104, 122, 173, 248, 98, 189, 119, 108, 143, 193, 139, 142, 159, 142, 32, 8, 151, 20, 133, 110, 226, 51, 179, 144, 42, 89, 29, 13, 95, 41, 37 ]);
         }
     }
+
+    mod estimate_request_fees {
+        use super::*;
+
+        #[test]
+        fn estimate_request_fees_works() {
+            let setup = Setup::payment_wallet();
+            payments::mock_method::init_v2();
+
+            let estimate = json!({"fee": 5}).to_string();
+            payments::mock_method::estimate_request_fees::inject_mock(ErrorCode::Success, None, &estimate);
+
+            let res = payments::estimate_request_fees(setup.wallet_handle, Some(IDENTIFIER), EMPTY_OBJECT, V2_INPUTS).unwrap();
+
+            assert_eq!(estimate, res);
+        }
+    }
+
+    mod verify_payment_receipts {
+        use super::*;
+
+        #[test]
+        fn verify_payment_receipts_works() {
+            let setup = Setup::payment_wallet();
+            payments::mock_method::init_v2();
+
+            let receipts_info = json!([
+                {"receipt": "pay:nullv2:1", "verified": true},
+                {"receipt": "pay:nullv2:2", "verified": false, "error": "Unknown receipt"}
+            ]).to_string();
+            payments::mock_method::verify_payment_receipts::inject_mock(ErrorCode::Success, None, &receipts_info);
+
+            let res = payments::verify_payment_receipts(setup.wallet_handle, None, V2_INPUTS).unwrap();
+
+            assert_eq!(receipts_info, res);
+        }
+    }
 }
 
 #[cfg(not(feature="only_high_cases"))]
@@ -789,6 +987,19 @@ mod medium_cases {
         }
     }
 
+    mod unregister_payment_method {
+        use super::*;
+
+        #[test]
+        fn unregister_payment_method_works_for_unknown_method() {
+            Setup::empty();
+
+            let err = payments::unregister_payment_method("unknown_payment_method").unwrap_err();
+
+            assert_eq!(ErrorCode::UnknownPaymentMethod, err);
+        }
+    }
+
     mod create_payment_address {
         use super::*;
 
@@ -999,6 +1210,83 @@ mod medium_cases {
         }
     }
 
+    mod prepare_request_with_fees {
+        use super::*;
+        use crate::api::INVALID_POOL_HANDLE;
+
+        static REQUEST: &str = r#"{"reqId":1, "identifier":"Th7MpTaRZVRYnPiabds81Y", "operation":{"type":"1", "dest":"VsKV7grR1BUE29mG2Fm2kX"}}"#;
+
+        #[test]
+        fn prepare_request_with_fees_works_for_invalid_pool_handle() {
+            let setup = Setup::payment_wallet();
+
+            payments::mock_method::build_get_txn_fees_req::inject_mock(ErrorCode::Success, REQUEST);
+
+            let err = payments::prepare_request_with_fees(setup.wallet_handle,
+                                                          INVALID_POOL_HANDLE,
+                                                          IDENTIFIER,
+                                                          REQUEST,
+                                                          CORRECT_INPUTS,
+                                                          CORRECT_OUTPUTS,
+                                                          None,
+                                                          None,
+                                                          None,
+                                                          0);
+            assert_code!(ErrorCode::PoolLedgerInvalidPoolHandle, err);
+        }
+
+        #[test]
+        fn prepare_request_with_fees_works_for_incompatible_payment_methods() {
+            let setup = Setup::payment_wallet();
+
+            let err = payments::prepare_request_with_fees(setup.wallet_handle,
+                                                          INVALID_POOL_HANDLE,
+                                                          IDENTIFIER,
+                                                          REQUEST,
+                                                          INCOMPATIBLE_INPUTS,
+                                                          CORRECT_OUTPUTS,
+                                                          None,
+                                                          None,
+                                                          None,
+                                                          0);
+            assert_code!(ErrorCode::IncompatiblePaymentError, err);
+        }
+
+        #[test]
+        fn prepare_request_with_fees_works_for_request_without_type() {
+            let setup = Setup::payment_wallet();
+
+            let err = payments::prepare_request_with_fees(setup.wallet_handle,
+                                                          INVALID_POOL_HANDLE,
+                                                          IDENTIFIER,
+                                                          EMPTY_OBJECT,
+                                                          CORRECT_INPUTS,
+                                                          CORRECT_OUTPUTS,
+                                                          None,
+                                                          None,
+                                                          None,
+                                                          0);
+            assert_code!(ErrorCode::CommonInvalidStructure, err);
+        }
+
+        #[test]
+        fn prepare_request_with_fees_works_for_taa_acceptance_without_digest() {
+            let setup = Setup::payment_wallet();
+
+            let err = payments::prepare_request_with_fees(setup.wallet_handle,
+                                                          INVALID_POOL_HANDLE,
+                                                          IDENTIFIER,
+                                                          REQUEST,
+                                                          CORRECT_INPUTS,
+                                                          CORRECT_OUTPUTS,
+                                                          None,
+                                                          None,
+                                                          Some("acceptance type 1"),
+                                                          123379200);
+            assert_code!(ErrorCode::CommonInvalidStructure, err);
+        }
+    }
+
     mod parse_response_with_fees {
         use super::*;
 
@@ -1748,4 +2036,261 @@ mod medium_cases {
             assert_code!(ErrorCode::CommonInvalidParam6, ec);
         }
     }
+    mod estimate_request_fees {
+        use super::*;
+
+        #[test]
+        fn estimate_request_fees_works_for_method_without_v2_handlers() {
+            let setup = Setup::payment_wallet();
+
+            let err = payments::estimate_request_fees(setup.wallet_handle, None, EMPTY_OBJECT, CORRECT_INPUTS);
+
+            assert_code!(ErrorCode::PaymentOperationNotSupportedError, err);
+        }
+
+        #[test]
+        fn estimate_request_fees_works_for_structured_error() {
+            let setup = Setup::payment_wallet();
+            payments::mock_method::init_v2();
+
+            let error_json = json!({"message": "Not enough tokens", "required": 10}).to_string();
+            payments::mock_method::estimate_request_fees::inject_mock(ErrorCode::PaymentInsufficientFundsError, Some(&error_json), "");
+
+            let err = payments::estimate_request_fees(setup.wallet_handle, None, EMPTY_OBJECT, V2_INPUTS).unwrap_err();
+
+            assert_eq!(ErrorCode::PaymentInsufficientFundsError, err.error_code);
+            assert!(err.message.contains("Not enough tokens"));
+            assert!(err.message.contains(r#""required":10"#));
+        }
+
+        #[test]
+        fn estimate_request_fees_works_for_incompatible_inputs() {
+            let setup = Setup::payment_wallet();
+
+            let err = payments::estimate_request_fees(setup.wallet_handle, None, EMPTY_OBJECT, INCOMPATIBLE_INPUTS);
+
+            assert_code!(ErrorCode::IncompatiblePaymentError, err);
+        }
+
+        #[test]
+        fn estimate_request_fees_works_for_invalid_wallet_handle() {
+            Setup::payment();
+
+            let err = payments::estimate_request_fees(INVALID_WALLET_HANDLE, None, EMPTY_OBJECT, V2_INPUTS);
+
+            assert_code!(ErrorCode::WalletInvalidHandle, err);
+        }
+    }
+
+    mod verify_payment_receipts {
+        use super::*;
+
+        #[test]
+        fn verify_payment_receipts_works_for_method_without_v2_handlers() {
+            let setup = Setup::payment_wallet();
+
+            let err = payments::verify_payment_receipts(setup.wallet_handle, None, CORRECT_INPUTS);
+
+            assert_code!(ErrorCode::PaymentOperationNotSupportedError, err);
+        }
+
+        #[test]
+        fn verify_payment_receipts_works_for_empty_receipts() {
+            let setup = Setup::payment_wallet();
+
+            let err = payments::verify_payment_receipts(setup.wallet_handle, None, EMPTY_ARRAY);
+
+            assert_code!(ErrorCode::CommonInvalidStructure, err);
+        }
+
+        #[test]
+        fn verify_payment_receipts_works_for_invalid_submitter_did() {
+            let setup = Setup::payment_wallet();
+
+            let err = payments::verify_payment_receipts(setup.wallet_handle, Some(INVALID_IDENTIFIER), V2_INPUTS);
+
+            assert_code!(ErrorCode::CommonInvalidStructure, err);
+        }
+    }
+
+    mod get_payment_balances {
+        use super::*;
+
+        #[test]
+        fn get_payment_balances_works_for_invalid_pool_handle() {
+            let setup = Setup::payment_wallet();
+
+            payments::mock_method::create_payment_address::inject_mock(ErrorCode::Success, CORRECT_PAYMENT_ADDRESS);
+            payments::create_payment_address(setup.wallet_handle, EMPTY_OBJECT, PAYMENT_METHOD_NAME).unwrap();
+
+            payments::mock_method::build_get_payment_sources_request::inject_mock(ErrorCode::Success, TEST_RES_STRING);
+
+            let balances = payments::get_payment_balances(setup.wallet_handle, setup.pool_handle).unwrap();
+            let balances: serde_json::Value = serde_json::from_str(&balances).unwrap();
+
+            assert_eq!(json!({}), balances["balances"]);
+            assert_eq!(CORRECT_PAYMENT_ADDRESS, balances["addresses"][0]["address"].as_str().unwrap());
+            assert_eq!(PAYMENT_METHOD_NAME, balances["addresses"][0]["paymentMethod"].as_str().unwrap());
+            assert!(balances["addresses"][0]["error"].is_string());
+        }
+
+        #[test]
+        fn get_payment_balances_works_for_plugin_error() {
+            let setup = Setup::payment_wallet();
+
+            payments::mock_method::create_payment_address::inject_mock(ErrorCode::Success, CORRECT_PAYMENT_ADDRESS);
+            payments::create_payment_address(setup.wallet_handle, EMPTY_OBJECT, PAYMENT_METHOD_NAME).unwrap();
+
+            payments::mock_method::build_get_payment_sources_request::inject_mock(ErrorCode::WalletAccessFailed, "");
+
+            let balances = payments::get_payment_balances(setup.wallet_handle, setup.pool_handle).unwrap();
+            let balances: serde_json::Value = serde_json::from_str(&balances).unwrap();
+
+            assert_eq!(1, balances["addresses"].as_array().unwrap().len());
+            assert!(balances["addresses"][0]["error"].is_string());
+        }
+
+        #[test]
+        fn get_payment_balances_works_for_invalid_wallet_handle() {
+            let setup = Setup::payment();
+
+            let res = payments::get_payment_balances(INVALID_WALLET_HANDLE, setup.pool_handle);
+
+            assert_code!(ErrorCode::WalletInvalidHandle, res);
+        }
+    }
+
+    mod verify_payment_receipt_with_proof {
+        use super::*;
+
+        #[test]
+        fn verify_payment_receipt_with_proof_works_for_method_without_proof_parser() {
+            Setup::payment();
+
+            let err = payments::verify_payment_receipt_with_proof(CORRECT_PAYMENT_ADDRESS, EMPTY_OBJECT, &pool::test_pool_bls_keys());
+
+            assert_code!(ErrorCode::PaymentOperationNotSupportedError, err);
+        }
+
+        #[test]
+        fn verify_payment_receipt_with_proof_works_for_invalid_state_proof() {
+            Setup::empty();
+            payments::mock_method::init_v2();
+
+            let parsed = json!({
+                "txn": {"sources": ["pay:nullv2:1"], "receipts": [], "extra": EXTRA},
+                "state_proofs": [{
+                    "proof_nodes": "",
+                    "root_hash": "",
+                    "kvs_to_verify": {"type": "Simple", "kvs": [["pay:nullv2:2", null]]},
+                    "multi_signature": {}
+                }]
+            }).to_string();
+            payments::mock_method::parse_verify_payment_response_with_proof::inject_mock(ErrorCode::Success, None, &parsed);
+
+            let err = payments::verify_payment_receipt_with_proof("pay:nullv2:2", EMPTY_OBJECT, &pool::test_pool_bls_keys());
+
+            assert_code!(ErrorCode::LedgerInvalidTransaction, err);
+        }
+
+        #[test]
+        fn verify_payment_receipt_with_proof_works_for_parser_error() {
+            Setup::empty();
+            payments::mock_method::init_v2();
+
+            let error_json = json!({"message": "Reply doesn't contain state proof"}).to_string();
+            payments::mock_method::parse_verify_payment_response_with_proof::inject_mock(ErrorCode::CommonInvalidStructure, Some(&error_json), EMPTY_OBJECT);
+
+            let err = payments::verify_payment_receipt_with_proof("pay:nullv2:1", EMPTY_OBJECT, &pool::test_pool_bls_keys());
+
+            assert_code!(ErrorCode::CommonInvalidStructure, err);
+        }
+
+        #[test]
+        fn verify_payment_receipt_with_proof_works_for_invalid_bls_keys() {
+            Setup::empty();
+
+            let err = payments::verify_payment_receipt_with_proof("pay:nullv2:1", EMPTY_OBJECT, EMPTY_ARRAY);
+
+            assert_code!(ErrorCode::CommonInvalidStructure, err);
+        }
+    }
+
+    mod build_get_payment_history_request {
+        use super::*;
+
+        #[test]
+        fn build_get_payment_history_request_works_for_method_without_history_handlers() {
+            let setup = Setup::payment_wallet();
+
+            let err = payments::build_get_payment_history_request(setup.wallet_handle, Some(IDENTIFIER), CORRECT_PAYMENT_ADDRESS, None);
+
+            assert_code!(ErrorCode::PaymentOperationNotSupportedError, err);
+        }
+
+        #[test]
+        fn build_get_payment_history_request_works_for_nonexistent_plugin() {
+            let setup = Setup::payment_wallet();
+
+            let err = payments::build_get_payment_history_request(setup.wallet_handle, Some(IDENTIFIER), "pay:null1:test", None);
+
+            assert_code!(ErrorCode::UnknownPaymentMethod, err);
+        }
+
+        #[test]
+        fn build_get_payment_history_request_works_for_invalid_submitter_did() {
+            let setup = Setup::payment_wallet();
+
+            let err = payments::build_get_payment_history_request(setup.wallet_handle, Some(INVALID_IDENTIFIER), "pay:nullv2:1", None);
+
+            assert_code!(ErrorCode::CommonInvalidStructure, err);
+        }
+
+        #[test]
+        fn build_get_payment_history_request_works_for_invalid_wallet_handle() {
+            Setup::payment();
+            payments::mock_method::init_v2();
+
+            let err = payments::build_get_payment_history_request(INVALID_WALLET_HANDLE, Some(IDENTIFIER), "pay:nullv2:1", None);
+
+            assert_code!(ErrorCode::WalletInvalidHandle, err);
+        }
+    }
+
+    mod parse_get_payment_history_response {
+        use super::*;
+
+        #[test]
+        fn parse_get_payment_history_response_works_for_method_without_history_handlers() {
+            Setup::payment();
+
+            let err = payments::parse_get_payment_history_response(PAYMENT_METHOD_NAME, EMPTY_OBJECT);
+
+            assert_code!(ErrorCode::PaymentOperationNotSupportedError, err);
+        }
+
+        #[test]
+        fn parse_get_payment_history_response_works_for_invalid_history() {
+            Setup::empty();
+            payments::mock_method::init_v2();
+
+            payments::mock_method::parse_get_payment_history_response::inject_mock(ErrorCode::Success, None, EMPTY_ARRAY);
+
+            let err = payments::parse_get_payment_history_response("nullv2", EMPTY_OBJECT);
+
+            assert_code!(ErrorCode::CommonInvalidStructure, err);
+        }
+
+        #[test]
+        fn parse_get_payment_history_response_works_for_generic_error() {
+            Setup::empty();
+            payments::mock_method::init_v2();
+
+            payments::mock_method::parse_get_payment_history_response::inject_mock(ErrorCode::WalletAccessFailed, None, EMPTY_OBJECT);
+
+            let err = payments::parse_get_payment_history_response("nullv2", EMPTY_OBJECT);
+
+            assert_code!(ErrorCode::WalletAccessFailed, err);
+        }
+    }
 }
@@ -173,6 +173,37 @@ mod high_cases {
             pool::set_protocol_version(2).unwrap();
         }
     }
+
+    mod get_reply_capture {
+        use super::*;
+        use crate::utils::ledger;
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_pool_reply_capture_works() {
+            let setup = Setup::empty();
+
+            let txn_file_path = pool::create_genesis_txn_file_for_test_pool(&setup.name, None, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+            pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
+
+            let config = json!({"reply_capture_size": 2}).to_string();
+            let pool_handle = pool::open_pool_ledger(&setup.name, Some(&config)).unwrap();
+
+            let request = ledger::build_get_nym_request(None, DID_TRUSTEE).unwrap();
+            ledger::submit_request(pool_handle, &request).unwrap();
+
+            let captured_requests = pool::get_reply_capture(pool_handle).unwrap();
+            let captured_requests: serde_json::Value = serde_json::from_str(&captured_requests).unwrap();
+            let captured_requests = captured_requests.as_array().unwrap();
+
+            let captured_request = captured_requests.last().unwrap();
+            assert!(captured_request["reqId"].is_string());
+            assert!(!captured_request["replies"].as_array().unwrap().is_empty());
+
+            pool::close(pool_handle).unwrap();
+        }
+    }
 }
 
 #[cfg(not(feature = "only_high_cases"))]
@@ -331,6 +362,21 @@ mod medium_cases {
             assert_code!(ErrorCode::CommonInvalidStructure, res);
         }
 
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn open_pool_ledger_works_for_zero_conn_count_per_node() {
+            let setup = Setup::empty();
+
+            let config = json!({"conn_count_per_node": 0}).to_string();
+
+            let txn_file_path = pool::create_genesis_txn_file_for_test_pool(&setup.name, None, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+            pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
+
+            let res = pool::open_pool_ledger(&setup.name, Some(&config));
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
         #[test]
         #[cfg(feature = "local_nodes_pool")]
         fn open_pool_ledger_works_for_incompatible_protocol_version() {
@@ -380,6 +426,64 @@ mod medium_cases {
 
             pool::close(pool_handle).unwrap();
         }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn open_pool_ledger_works_for_resource_limits() {
+            use futures::Future;
+
+            let setup = Setup::empty();
+
+            let config = json!({"max_in_flight_requests": 1, "max_sockets": 4}).to_string();
+
+            let txn_file_path = pool::create_genesis_txn_file_for_test_pool(&setup.name, None, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+            pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
+
+            let pool_handle = pool::open_pool_ledger(&setup.name, Some(&config)).unwrap();
+
+            // Requests exceeding the limits are deferred, not failed
+            let requests = (0..3)
+                .map(|_| {
+                    let request = ledger::build_get_nym_request(None, DID_TRUSTEE).unwrap();
+                    indy::ledger::submit_request(pool_handle, &request)
+                })
+                .collect::<Vec<_>>();
+
+            let responses = futures::future::join_all(requests).wait().unwrap();
+            assert_eq!(3, responses.len());
+
+            pool::close(pool_handle).unwrap();
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn open_pool_ledger_works_for_read_connections() {
+            use futures::Future;
+
+            let setup = Setup::empty();
+
+            let config = json!({"read_connections": 3}).to_string();
+
+            let txn_file_path = pool::create_genesis_txn_file_for_test_pool(&setup.name, None, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+            pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
+
+            let pool_handle = pool::open_pool_ledger(&setup.name, Some(&config)).unwrap();
+
+            // Read requests are distributed over the connections, replies are returned for the pool handle
+            let requests = (0..6)
+                .map(|_| {
+                    let request = ledger::build_get_nym_request(None, DID_TRUSTEE).unwrap();
+                    indy::ledger::submit_request(pool_handle, &request)
+                })
+                .collect::<Vec<_>>();
+
+            let responses = futures::future::join_all(requests).wait().unwrap();
+            assert_eq!(6, responses.len());
+
+            pool::close(pool_handle).unwrap();
+        }
     }
 
     mod close {
@@ -486,4 +590,24 @@ mod medium_cases {
             assert_code!(ErrorCode::PoolIncompatibleProtocolVersion, res);
         }
     }
+
+    mod get_reply_capture {
+        use super::*;
+
+        #[test]
+        fn indy_get_pool_reply_capture_works_for_not_enabled() {
+            let setup = Setup::pool();
+
+            let res = pool::get_reply_capture(setup.pool_handle);
+            assert_code!(ErrorCode::CommonInvalidState, res);
+        }
+
+        #[test]
+        fn indy_get_pool_reply_capture_works_for_invalid_handle() {
+            let setup = Setup::pool();
+
+            let res = pool::get_reply_capture(setup.pool_handle + 1);
+            assert_code!(ErrorCode::PoolLedgerInvalidPoolHandle, res);
+        }
+    }
 }
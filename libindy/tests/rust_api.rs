@@ -0,0 +1,224 @@
+#![cfg(feature = "rust-api")]
+
+#[macro_use]
+extern crate serde_json;
+
+use futures::Future;
+use indy::rust_api::{Anoncreds, Crypto, Did, Ledger, NonSecrets, Pool, Wallet};
+use indy_api_types::errors::IndyErrorKind;
+
+const WALLET_CREDENTIALS: &str = r#"{"key":"8dvfYSt5d1taSd6yJdpjq4emkwsPDDLYxkNFysFD2cZY", "key_derivation_method":"RAW"}"#;
+const ISSUER_DID: &str = "NcYxiDXkpYi6ov5FcYDi1e";
+const PROVER_DID: &str = "VsKV7grR1BUE29mG2Fm2kX";
+const GVT_SCHEMA_ATTRIBUTES: &str = r#"["name", "age", "sex", "height"]"#;
+const MESSAGE: &[u8] = b"Hello World";
+
+fn _wallet_config(name: &str) -> String {
+    let path = std::env::temp_dir().join("indy_rust_api");
+
+    json!({
+        "id": name,
+        "storage_config": {"path": path.to_str().unwrap()}
+    }).to_string()
+}
+
+fn _create_and_open_wallet(config: &str) -> Wallet {
+    Wallet::delete(config, WALLET_CREDENTIALS).wait().ok();
+    Wallet::create(config, WALLET_CREDENTIALS).wait().unwrap();
+    Wallet::open(config, WALLET_CREDENTIALS).wait().unwrap()
+}
+
+#[test]
+fn rust_api_wallet_works() {
+    let config = _wallet_config("rust_api_wallet_works");
+    Wallet::delete(&config, WALLET_CREDENTIALS).wait().ok();
+
+    Wallet::create(&config, WALLET_CREDENTIALS).wait().unwrap();
+    let wallet = Wallet::open(&config, WALLET_CREDENTIALS).wait().unwrap();
+    wallet.close().wait().unwrap();
+    Wallet::delete(&config, WALLET_CREDENTIALS).wait().unwrap();
+
+    let err = Wallet::open(&config, WALLET_CREDENTIALS).wait().unwrap_err();
+    assert_eq!(IndyErrorKind::WalletNotFound, err.kind());
+}
+
+#[test]
+fn rust_api_wallet_works_for_invalid_config() {
+    let err = Wallet::create("{}", WALLET_CREDENTIALS).wait().unwrap_err();
+    assert_eq!(IndyErrorKind::InvalidStructure, err.kind());
+}
+
+#[test]
+fn rust_api_pool_works_for_not_created_config() {
+    let err = Pool::open("rust_api_pool_works_for_not_created_config", None).wait().unwrap_err();
+    assert_eq!(IndyErrorKind::PoolNotCreated, err.kind());
+}
+
+#[test]
+fn rust_api_anoncreds_works() {
+    let config = _wallet_config("rust_api_anoncreds_works");
+    Wallet::delete(&config, WALLET_CREDENTIALS).wait().ok();
+    Wallet::create(&config, WALLET_CREDENTIALS).wait().unwrap();
+    let wallet = Wallet::open(&config, WALLET_CREDENTIALS).wait().unwrap();
+    let anoncreds = Anoncreds::new(&wallet);
+
+    let (_, schema_json) = Anoncreds::issuer_create_schema(ISSUER_DID, "gvt", "1.0", GVT_SCHEMA_ATTRIBUTES).wait().unwrap();
+    let (cred_def_id, _) = anoncreds.issuer_create_and_store_credential_def(ISSUER_DID, &schema_json, "tag", None, None).wait().unwrap();
+    let cred_offer = anoncreds.issuer_create_credential_offer(&cred_def_id).wait().unwrap();
+    let master_secret_id = anoncreds.prover_create_master_secret(Some("master_secret")).wait().unwrap();
+
+    let cred_offer: serde_json::Value = serde_json::from_str(&cred_offer).unwrap();
+    assert_eq!(cred_def_id, cred_offer["cred_def_id"].as_str().unwrap());
+    assert_eq!("master_secret", master_secret_id);
+
+    wallet.close().wait().unwrap();
+    Wallet::delete(&config, WALLET_CREDENTIALS).wait().unwrap();
+}
+
+#[test]
+fn rust_api_anoncreds_works_for_proof() {
+    let config = _wallet_config("rust_api_anoncreds_works_for_proof");
+    let wallet = _create_and_open_wallet(&config);
+    let anoncreds = Anoncreds::new(&wallet);
+
+    let (schema_id, schema_json) = Anoncreds::issuer_create_schema(ISSUER_DID, "gvt", "1.0", GVT_SCHEMA_ATTRIBUTES).wait().unwrap();
+    let (cred_def_id, cred_def_json) = anoncreds.issuer_create_and_store_credential_def(ISSUER_DID, &schema_json, "tag", None, None).wait().unwrap();
+    let cred_offer = anoncreds.issuer_create_credential_offer(&cred_def_id).wait().unwrap();
+    let master_secret_id = anoncreds.prover_create_master_secret(None).wait().unwrap();
+
+    let (cred_req, cred_req_metadata) = anoncreds.prover_create_credential_req(PROVER_DID, &cred_offer, &cred_def_json, &master_secret_id).wait().unwrap();
+    let cred_values = json!({
+        "name": {"raw": "Alex", "encoded": "1139481716457488690172217916278103335"},
+        "age": {"raw": "28", "encoded": "28"},
+        "sex": {"raw": "male", "encoded": "5944657099558967239210949258394887428692050081607692519917050011144233115103"},
+        "height": {"raw": "175", "encoded": "175"}
+    }).to_string();
+    let cred_json = anoncreds.issuer_create_credential(&cred_offer, &cred_req, &cred_values).wait().unwrap();
+    let cred_id = anoncreds.prover_store_credential(Some("cred_id"), &cred_req_metadata, &cred_json, &cred_def_json, None).wait().unwrap();
+
+    let credential: serde_json::Value = serde_json::from_str(&anoncreds.prover_get_credential(&cred_id).wait().unwrap()).unwrap();
+    assert_eq!("Alex", credential["attrs"]["name"].as_str().unwrap());
+
+    let proof_request = json!({
+        "nonce": Anoncreds::generate_nonce().wait().unwrap(),
+        "name": "proof_req_1",
+        "version": "0.1",
+        "requested_attributes": {"attr1_referent": {"name": "name"}},
+        "requested_predicates": {"predicate1_referent": {"name": "age", "p_type": ">=", "p_value": 18}}
+    }).to_string();
+
+    let credentials: serde_json::Value = serde_json::from_str(&anoncreds.prover_get_credentials_for_proof_req(&proof_request).wait().unwrap()).unwrap();
+    assert_eq!(cred_id, credentials["attrs"]["attr1_referent"][0]["cred_info"]["referent"].as_str().unwrap());
+
+    let requested_credentials = json!({
+        "self_attested_attributes": {},
+        "requested_attributes": {"attr1_referent": {"cred_id": cred_id, "revealed": true}},
+        "requested_predicates": {"predicate1_referent": {"cred_id": cred_id}}
+    }).to_string();
+    let schemas = json!({schema_id: serde_json::from_str::<serde_json::Value>(&schema_json).unwrap()}).to_string();
+    let cred_defs = json!({cred_def_id: serde_json::from_str::<serde_json::Value>(&cred_def_json).unwrap()}).to_string();
+
+    let proof = anoncreds.prover_create_proof(&proof_request, &requested_credentials, &master_secret_id, &schemas, &cred_defs, "{}").wait().unwrap();
+    let valid = Anoncreds::verifier_verify_proof(&proof_request, &proof, &schemas, &cred_defs, "{}", "{}").wait().unwrap();
+    assert!(valid);
+
+    wallet.close().wait().unwrap();
+    Wallet::delete(&config, WALLET_CREDENTIALS).wait().unwrap();
+}
+
+#[test]
+fn rust_api_did_and_crypto_work() {
+    let config = _wallet_config("rust_api_did_and_crypto_work");
+    let wallet = _create_and_open_wallet(&config);
+    let did = Did::new(&wallet);
+    let crypto = Crypto::new(&wallet);
+
+    let (my_did, my_vk) = did.create_and_store_my_did("{}").wait().unwrap();
+    assert_eq!(my_vk, did.key_for_local_did(&my_did).wait().unwrap());
+
+    did.set_did_metadata(&my_did, "metadata").wait().unwrap();
+    assert_eq!("metadata", did.get_did_metadata(&my_did).wait().unwrap());
+
+    let their_vk = crypto.create_key("{}").wait().unwrap();
+    did.store_their_did(&json!({"did": PROVER_DID, "verkey": their_vk}).to_string()).wait().unwrap();
+    assert_eq!(their_vk, did.key_for_local_did(PROVER_DID).wait().unwrap());
+
+    let signature = crypto.sign(&my_vk, MESSAGE).wait().unwrap();
+    assert!(Crypto::verify(&my_vk, MESSAGE, &signature).wait().unwrap());
+
+    let encrypted = crypto.auth_crypt(&my_vk, &their_vk, MESSAGE).wait().unwrap();
+    let (sender_vk, decrypted) = crypto.auth_decrypt(&their_vk, &encrypted).wait().unwrap();
+    assert_eq!(my_vk, sender_vk);
+    assert_eq!(MESSAGE, decrypted.as_slice());
+
+    let encrypted = Crypto::anon_crypt(&their_vk, MESSAGE).wait().unwrap();
+    assert_eq!(MESSAGE, crypto.anon_decrypt(&their_vk, &encrypted).wait().unwrap().as_slice());
+
+    let packed = crypto.pack_message(MESSAGE, &json!([their_vk]).to_string(), Some(&my_vk)).wait().unwrap();
+    let unpacked: serde_json::Value = serde_json::from_slice(&crypto.unpack_message(&packed).wait().unwrap()).unwrap();
+    assert_eq!(my_vk, unpacked["sender_verkey"].as_str().unwrap());
+
+    wallet.close().wait().unwrap();
+    Wallet::delete(&config, WALLET_CREDENTIALS).wait().unwrap();
+}
+
+#[test]
+fn rust_api_did_works_for_invalid_did() {
+    let config = _wallet_config("rust_api_did_works_for_invalid_did");
+    let wallet = _create_and_open_wallet(&config);
+
+    let err = Did::new(&wallet).key_for_local_did("invalid_base58_did_0OIl").wait().unwrap_err();
+    assert_eq!(IndyErrorKind::InvalidStructure, err.kind());
+
+    wallet.close().wait().unwrap();
+    Wallet::delete(&config, WALLET_CREDENTIALS).wait().unwrap();
+}
+
+#[test]
+fn rust_api_non_secrets_work() {
+    let config = _wallet_config("rust_api_non_secrets_work");
+    let wallet = _create_and_open_wallet(&config);
+    let non_secrets = NonSecrets::new(&wallet);
+
+    non_secrets.add_record("type", "id1", "value1", Some(r#"{"tagName1":"str1"}"#)).wait().unwrap();
+    non_secrets.update_record_value("type", "id1", "value2").wait().unwrap();
+    non_secrets.add_record_tags("type", "id1", r#"{"tagName2":"str2"}"#).wait().unwrap();
+    non_secrets.delete_record_tags("type", "id1", r#"["tagName1"]"#).wait().unwrap();
+
+    let record: serde_json::Value = serde_json::from_str(&non_secrets.get_record("type", "id1", r#"{"retrieveTags":true}"#).wait().unwrap()).unwrap();
+    assert_eq!("value2", record["value"].as_str().unwrap());
+    assert_eq!(json!({"tagName2": "str2"}), record["tags"]);
+
+    let search_handle = non_secrets.open_search("type", r#"{"tagName2":"str2"}"#, "{}").wait().unwrap();
+    let records: serde_json::Value = serde_json::from_str(&non_secrets.fetch_search_next_records(search_handle, 10).wait().unwrap()).unwrap();
+    assert_eq!("id1", records["records"][0]["id"].as_str().unwrap());
+    NonSecrets::close_search(search_handle).wait().unwrap();
+
+    non_secrets.delete_record("type", "id1").wait().unwrap();
+    let err = non_secrets.get_record("type", "id1", "{}").wait().unwrap_err();
+    assert_eq!(IndyErrorKind::WalletItemNotFound, err.kind());
+
+    let err = non_secrets.add_record("type", "id2", "value", Some("not json")).wait().unwrap_err();
+    assert_eq!(IndyErrorKind::InvalidStructure, err.kind());
+
+    wallet.close().wait().unwrap();
+    Wallet::delete(&config, WALLET_CREDENTIALS).wait().unwrap();
+}
+
+#[test]
+fn rust_api_ledger_works_for_sign_request() {
+    let config = _wallet_config("rust_api_ledger_works_for_sign_request");
+    let wallet = _create_and_open_wallet(&config);
+
+    let (my_did, _) = Did::new(&wallet).create_and_store_my_did("{}").wait().unwrap();
+    let request = Ledger::build_nym_request(&my_did, PROVER_DID, None, None, None).wait().unwrap();
+    let request: serde_json::Value = serde_json::from_str(&Ledger::sign_request(&wallet, &my_did, &request).wait().unwrap()).unwrap();
+    assert!(request["signature"].is_string());
+
+    let request = Ledger::build_get_nym_request(None, PROVER_DID).wait().unwrap();
+    let request: serde_json::Value = serde_json::from_str(&request).unwrap();
+    assert_eq!(PROVER_DID, request["operation"]["dest"].as_str().unwrap());
+
+    wallet.close().wait().unwrap();
+    Wallet::delete(&config, WALLET_CREDENTIALS).wait().unwrap();
+}
@@ -0,0 +1,47 @@
+extern crate indyrs as indy;
+extern crate futures;
+#[macro_use]
+extern crate serde_json;
+
+use std::thread;
+use std::time::Duration;
+
+use futures::Future;
+use indy::ErrorCode;
+use libc::c_char;
+
+const WALLET_CREDENTIALS: &str = r#"{"key":"8dvfYSt5d1taSd6yJdpjq4emkwsPDDLYxkNFysFD2cZY", "key_derivation_method":"RAW"}"#;
+
+// Keeps calls in the queue while shutdown is requested
+extern fn _slow_pre_command_hook(_command_name: *const c_char, _params_digest: *const c_char) {
+    thread::sleep(Duration::from_millis(100));
+}
+
+#[test]
+fn shutdown_works() {
+    let path = std::env::temp_dir().join("indy_shutdown");
+    let config = json!({"id": "shutdown_works", "storage_config": {"path": path.to_str().unwrap()}}).to_string();
+    indy::wallet::delete_wallet(&config, WALLET_CREDENTIALS).wait().ok();
+
+    indy::wallet::create_wallet(&config, WALLET_CREDENTIALS).wait().unwrap();
+    let wallet_handle = indy::wallet::open_wallet(&config, WALLET_CREDENTIALS).wait().unwrap();
+
+    assert_eq!(ErrorCode::Success, indy::register_command_hook(Some(_slow_pre_command_hook), None));
+
+    let queued: Vec<_> = (0..3).map(|_| indy::wallet::get_wallet_record(wallet_handle, "type", "id", "{}")).collect();
+
+    indy::shutdown(false, 0).wait().unwrap();
+
+    // queued calls are executed before the wallet is closed
+    for res in queued {
+        assert_eq!(ErrorCode::WalletItemNotFound, res.wait().unwrap_err().error_code);
+    }
+
+    let res = indy::wallet::get_wallet_record(wallet_handle, "type", "id", "{}").wait();
+    assert_eq!(ErrorCode::CommonInvalidState, res.unwrap_err().error_code);
+
+    let res = indy::shutdown(true, 0).wait();
+    assert_eq!(ErrorCode::CommonInvalidState, res.unwrap_err().error_code);
+
+    indy::register_command_hook(None, None);
+}
@@ -21,7 +21,7 @@ use crate::utils::domain::anoncreds::credential::{AttributeValues, CredentialInf
 use crate::utils::domain::anoncreds::credential_for_proof_request::CredentialsForProofRequest;
 use crate::utils::domain::crypto::did::DidValue;
 
-use indy::WalletHandle;
+use indy::{WalletHandle, PoolHandle, IndyHandle};
 
 pub static mut CREDENTIAL_DEF_JSON: &'static str = "";
 pub static mut CREDENTIAL_OFFER_JSON: &'static str = "";
@@ -97,6 +97,10 @@ pub fn prover_create_credential_req(wallet_handle: WalletHandle, prover_did: &st
     anoncreds::prover_create_credential_req(wallet_handle, prover_did, cred_offer_json, cred_def_json, master_secret_id).wait()
 }
 
+pub fn prover_parse_credential_offer(cred_offer_json: &str, cred_def_json: &str) -> Result<String, IndyError> {
+    anoncreds::prover_parse_credential_offer(cred_offer_json, cred_def_json).wait()
+}
+
 pub fn prover_set_credential_attr_tag_policy(wallet_handle: WalletHandle, cred_def_id: &str, tag_attrs_json: Option<&str>,
                                              retroactive: bool) -> Result<(), IndyError> {
     anoncreds::prover_set_credential_attr_tag_policy(wallet_handle, cred_def_id, tag_attrs_json, retroactive).wait()
@@ -111,6 +115,16 @@ pub fn prover_store_credential(wallet_handle: WalletHandle, cred_id: &str, cred_
     anoncreds::prover_store_credential(wallet_handle, Some(cred_id), cred_req_metadata_json, cred_json, cred_def_json, rev_reg_def_json).wait()
 }
 
+pub fn prover_store_credential_with_tags(wallet_handle: WalletHandle, cred_id: &str, cred_req_metadata_json: &str, cred_json: &str,
+                                         cred_def_json: &str, rev_reg_def_json: Option<&str>, tags_json: &str) -> Result<String, IndyError> {
+    anoncreds::prover_store_credential_with_tags(wallet_handle, Some(cred_id), cred_req_metadata_json, cred_json, cred_def_json, rev_reg_def_json, tags_json).wait()
+}
+
+pub fn prover_import_w3c_credential(wallet_handle: WalletHandle, cred_id: &str, cred_req_metadata_json: &str, w3c_cred_json: &str,
+                                    cred_def_json: &str, rev_reg_def_json: Option<&str>) -> Result<String, IndyError> {
+    anoncreds::prover_import_w3c_credential(wallet_handle, Some(cred_id), cred_req_metadata_json, w3c_cred_json, cred_def_json, rev_reg_def_json).wait()
+}
+
 pub fn prover_delete_credential(wallet_handle: WalletHandle, cred_id: &str) -> Result<(), IndyError> {
     anoncreds::prover_delete_credential(wallet_handle, cred_id).wait()
 }
@@ -165,6 +179,14 @@ pub fn verifier_verify_proof(proof_request_json: &str, proof_json: &str, schemas
     anoncreds::verifier_verify_proof(proof_request_json, proof_json, schemas_json, cred_defs_json, rev_reg_defs_json, rev_regs_json).wait()
 }
 
+pub fn verifier_verify_proof_with_pools(pools_json: &str, proof_request_json: &str, proof_json: &str) -> Result<bool, IndyError> {
+    anoncreds::verifier_verify_proof_with_pools(pools_json, proof_request_json, proof_json).wait()
+}
+
+pub fn verifier_validate_proof_request(proof_request_json: &str) -> Result<String, IndyError> {
+    anoncreds::verifier_validate_proof_request(proof_request_json).wait()
+}
+
 pub fn create_revocation_state(blob_storage_reader_handle: i32, rev_reg_def_json: &str,
                                rev_reg_delta_json: &str, timestamp: u64, cred_rev_id: &str) -> Result<String, IndyError> {
     anoncreds::create_revocation_state(blob_storage_reader_handle, rev_reg_def_json, rev_reg_delta_json, timestamp, cred_rev_id).wait()
@@ -175,6 +197,18 @@ pub fn update_revocation_state(tails_reader_handle: i32, rev_state_json: &str, r
     anoncreds::update_revocation_state(tails_reader_handle, rev_state_json, rev_reg_def_json, rev_reg_delta_json, timestamp, cred_rev_id).wait()
 }
 
+pub fn start_revocation_state_updater(wallet_handle: WalletHandle, pool_handle: PoolHandle, blob_storage_reader_handle: i32, config_json: &str) -> Result<IndyHandle, IndyError> {
+    anoncreds::start_revocation_state_updater(wallet_handle, pool_handle, blob_storage_reader_handle, config_json).wait()
+}
+
+pub fn stop_revocation_state_updater(updater_handle: IndyHandle) -> Result<(), IndyError> {
+    anoncreds::stop_revocation_state_updater(updater_handle).wait()
+}
+
+pub fn get_updated_revocation_states(updater_handle: IndyHandle) -> Result<String, IndyError> {
+    anoncreds::get_updated_revocation_states(updater_handle).wait()
+}
+
 pub fn generate_nonce() -> Result<String, IndyError> {
     anoncreds::generate_nonce().wait()
 }
@@ -364,6 +398,45 @@ pub fn gvt_credential_values_json() -> String {
     serde_json::to_string(&gvt_credential_values()).unwrap()
 }
 
+// values encoded as AnonCreds issuers of W3C credentials do: sha256 of raw value if it isn't 32-bit integer
+pub fn gvt_auto_encoded_credential_values_json() -> String {
+    let mut values = gvt_credential_values();
+    values.get_mut("name").unwrap().encoded = "99262857098057710338306967609588410025648622308394250666849665532448612202874".to_string();
+    serde_json::to_string(&values).unwrap()
+}
+
+// wraps legacy credential into W3C credential with AnonCreds data integrity proof
+pub fn credential_to_w3c(credential_json: &str) -> String {
+    let mut credential: serde_json::Value = serde_json::from_str(credential_json).unwrap();
+
+    let values = credential.as_object_mut().unwrap().remove("values").unwrap();
+    let subject: serde_json::Map<String, serde_json::Value> = values.as_object().unwrap().iter()
+        .map(|(attr, value)| (attr.clone(), value["raw"].clone()))
+        .collect();
+
+    json!({
+        "@context": ["https://www.w3.org/2018/credentials/v1", "https://w3id.org/security/data-integrity/v2"],
+        "type": ["VerifiableCredential", "AnonCredsCredential"],
+        "issuer": ISSUER_DID,
+        "issuanceDate": "2024-01-01T00:00:00Z",
+        "credentialSchema": {
+            "type": "AnonCredsDefinition",
+            "definition": credential["cred_def_id"],
+            "schema": credential["schema_id"],
+            "revocationRegistry": credential["rev_reg_id"],
+            "encoding": "auto"
+        },
+        "credentialSubject": subject,
+        "proof": [{
+            "type": "DataIntegrityProof",
+            "cryptosuite": "anoncreds-2023",
+            "proofPurpose": "assertionMethod",
+            "verificationMethod": credential["cred_def_id"],
+            "proofValue": format!("u{}", indy_utils::crypto::base64::encode_urlsafe(credential.to_string().as_bytes()))
+        }]
+    }).to_string()
+}
+
 pub fn gvt_credential_values_2() -> HashMap<String, AttributeValues> {
     map! {
             "sex".to_string() => AttributeValues {raw: "female".to_string(), encoded: "5944657099558967239210949258394887428692050081607692519917050011144233115103".to_string()},
@@ -1178,8 +1251,7 @@ pub fn multi_steps_issuer_revocation_preparation(wallet_handle: WalletHandle,
                                                                            Some(&revocation_cred_def_config())).unwrap();
 
     // Issuer creates revocation registry
-    let tails_writer_config = tails_writer_config();
-    let tails_writer_handle = blob_storage::open_writer("default", &tails_writer_config).unwrap();
+    let tails_writer_handle = blob_storage::open_writer(MEMORY_TYPE, "{}").unwrap();
 
     let (rev_reg_id, revoc_reg_def_json, revoc_reg_entry_json) =
         issuer_create_and_store_revoc_reg(wallet_handle,
@@ -1190,7 +1262,7 @@ pub fn multi_steps_issuer_revocation_preparation(wallet_handle: WalletHandle,
                                           revoc_reg_def_config,
                                           tails_writer_handle).unwrap();
 
-    let blob_storage_reader_handle = blob_storage::open_reader(TYPE, &tails_writer_config).unwrap();
+    let blob_storage_reader_handle = blob_storage::open_reader(MEMORY_TYPE, "{}").unwrap();
 
     (schema_id, schema_json, cred_def_id, cred_def_json, rev_reg_id, revoc_reg_def_json, revoc_reg_entry_json, blob_storage_reader_handle)
 }
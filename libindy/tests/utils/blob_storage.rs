@@ -1,14 +1,132 @@
 extern crate futures;
 
-use indy::IndyError;
+use indy::{ErrorCode, IndyError};
 use indy::blob_storage;
 
 use self::futures::Future;
 
+use crate::utils::callback;
+use crate::utils::inmem_blob_storage::InmemBlobStorage;
+
+use std::collections::HashSet;
+use std::ffi::CString;
+use std::sync::Mutex;
+use super::libc::c_char;
+
+use indy::CommandHandle;
+
 pub fn open_reader(type_: &str, config_json: &str) -> Result<i32, IndyError> {
     blob_storage::open_reader(type_, config_json).wait()
 }
 
 pub fn open_writer(type_: &str, config_json: &str) -> Result<i32, IndyError> {
     blob_storage::open_writer(type_, config_json).wait()
-}
\ No newline at end of file
+}
+
+pub fn close_reader(handle: i32) -> Result<(), IndyError> {
+    blob_storage::close_reader(handle).wait()
+}
+
+pub fn close_writer(handle: i32) -> Result<(), IndyError> {
+    blob_storage::close_writer(handle).wait()
+}
+
+lazy_static! {
+    static ref REGISTERED_TYPES: Mutex<HashSet<String>> = Default::default();
+}
+
+pub fn register_inmem_blob_storage(xtype: &str) -> Result<(), ErrorCode> {
+    let mut types = REGISTERED_TYPES.lock().unwrap();
+
+    // types can't be registered twice in the same process
+    if types.contains(xtype) {
+        return Ok(());
+    }
+
+    let xxtype = CString::new(xtype).unwrap();
+
+    let (receiver, command_handle, cb) = callback::_closure_to_cb_ec();
+
+    let err = unsafe {
+        indy_register_blob_storage_writer(
+            command_handle,
+            xxtype.as_ptr(),
+            Some(InmemBlobStorage::open),
+            Some(InmemBlobStorage::create_blob),
+            Some(InmemBlobStorage::append),
+            Some(InmemBlobStorage::finalize),
+            Some(InmemBlobStorage::close_writable_blob),
+            Some(InmemBlobStorage::close),
+            cb,
+        )
+    };
+
+    super::results::result_to_empty(err as i32, receiver)?;
+
+    let (receiver, command_handle, cb) = callback::_closure_to_cb_ec();
+
+    let err = unsafe {
+        indy_register_blob_storage_reader(
+            command_handle,
+            xxtype.as_ptr(),
+            Some(InmemBlobStorage::open),
+            Some(InmemBlobStorage::open_blob),
+            Some(InmemBlobStorage::read),
+            Some(InmemBlobStorage::close_readable_blob),
+            Some(InmemBlobStorage::close),
+            cb,
+        )
+    };
+
+    super::results::result_to_empty(err as i32, receiver)?;
+
+    types.insert(xtype.to_string());
+    Ok(())
+}
+
+extern {
+    pub fn indy_register_blob_storage_reader(command_handle: CommandHandle,
+                                             type_: *const c_char,
+                                             open_reader: Option<BlobStorageOpen>,
+                                             open_blob: Option<BlobStorageOpenBlob>,
+                                             read: Option<BlobStorageRead>,
+                                             close_blob: Option<BlobStorageCloseBlob>,
+                                             close_reader: Option<BlobStorageClose>,
+                                             cb: Option<ResponseEmptyCB>) -> ErrorCode;
+
+    pub fn indy_register_blob_storage_writer(command_handle: CommandHandle,
+                                             type_: *const c_char,
+                                             open_writer: Option<BlobStorageOpen>,
+                                             create_blob: Option<BlobStorageCreateBlob>,
+                                             append: Option<BlobStorageAppend>,
+                                             finalize: Option<BlobStorageFinalize>,
+                                             close_blob: Option<BlobStorageCloseBlob>,
+                                             close_writer: Option<BlobStorageClose>,
+                                             cb: Option<ResponseEmptyCB>) -> ErrorCode;
+}
+
+pub type BlobStorageOpen = extern fn(config: *const c_char,
+                                     handle_p: *mut i32) -> ErrorCode;
+pub type BlobStorageClose = extern fn(handle: i32) -> ErrorCode;
+pub type BlobStorageOpenBlob = extern fn(reader_handle: i32,
+                                         hash: *const u8,
+                                         hash_len: u32,
+                                         location: *const c_char,
+                                         blob_handle_p: *mut i32) -> ErrorCode;
+pub type BlobStorageRead = extern fn(blob_handle: i32,
+                                     offset: u64,
+                                     size: u32,
+                                     buf: *mut u8,
+                                     read_len_p: *mut u32) -> ErrorCode;
+pub type BlobStorageCloseBlob = extern fn(blob_handle: i32) -> ErrorCode;
+pub type BlobStorageCreateBlob = extern fn(writer_handle: i32,
+                                           blob_handle_p: *mut i32) -> ErrorCode;
+pub type BlobStorageAppend = extern fn(blob_handle: i32,
+                                       bytes: *const u8,
+                                       bytes_len: u32) -> ErrorCode;
+pub type BlobStorageFinalize = extern fn(blob_handle: i32,
+                                         hash: *const u8,
+                                         hash_len: u32,
+                                         location_p: *mut *const c_char) -> ErrorCode;
+
+pub type ResponseEmptyCB = extern fn(xcommand_handle: i32, err: i32);
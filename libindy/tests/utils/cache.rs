@@ -21,4 +21,43 @@ pub fn purge_schema_cache(wallet_handle: WalletHandle, options_json: &str) -> Re
 
 pub fn purge_cred_def_cache(wallet_handle: WalletHandle, options_json: &str) -> Result<(), IndyError> {
     cache::purge_cred_def_cache(wallet_handle, options_json).wait()
-}
\ No newline at end of file
+}
+pub fn get_rev_reg_def_cache(pool_handle: PoolHandle, wallet_handle: WalletHandle, submitter_did: &str, id: &str, options_json: &str) -> Result<String, IndyError> {
+    cache::get_rev_reg_def(pool_handle, wallet_handle, submitter_did, id, options_json).wait()
+}
+
+pub fn get_rev_reg_cache(pool_handle: PoolHandle, wallet_handle: WalletHandle, submitter_did: &str, id: &str, timestamp: i64, options_json: &str) -> Result<(String, String, u64), IndyError> {
+    cache::get_rev_reg(pool_handle, wallet_handle, submitter_did, id, timestamp, options_json).wait()
+}
+
+pub fn get_rev_reg_delta_cache(pool_handle: PoolHandle, wallet_handle: WalletHandle, submitter_did: &str, id: &str, from: i64, to: i64, options_json: &str) -> Result<(String, String, u64), IndyError> {
+    cache::get_rev_reg_delta(pool_handle, wallet_handle, submitter_did, id, from, to, options_json).wait()
+}
+
+pub fn purge_rev_reg_cache(wallet_handle: WalletHandle, options_json: &str) -> Result<(), IndyError> {
+    cache::purge_rev_reg_cache(wallet_handle, options_json).wait()
+}
+
+pub fn prefetch_ledger_cache(pool_handle: PoolHandle, wallet_handle: WalletHandle, ids_json: &str) -> Result<String, IndyError> {
+    cache::prefetch_ledger_cache(pool_handle, wallet_handle, ids_json).wait()
+}
+
+pub fn get_cache_stats(wallet_handle: WalletHandle) -> Result<String, IndyError> {
+    cache::get_cache_stats(wallet_handle).wait()
+}
+
+pub fn set_cache_config(wallet_handle: WalletHandle, config_json: &str) -> Result<(), IndyError> {
+    cache::set_cache_config(wallet_handle, config_json).wait()
+}
+
+pub fn register_local_schema(wallet_handle: WalletHandle, schema_json: &str) -> Result<(), IndyError> {
+    cache::register_local_schema(wallet_handle, schema_json).wait()
+}
+
+pub fn register_local_cred_def(wallet_handle: WalletHandle, cred_def_json: &str) -> Result<(), IndyError> {
+    cache::register_local_cred_def(wallet_handle, cred_def_json).wait()
+}
+
+pub fn register_local_rev_reg_def(wallet_handle: WalletHandle, revoc_reg_def_json: &str) -> Result<(), IndyError> {
+    cache::register_local_rev_reg_def(wallet_handle, revoc_reg_def_json).wait()
+}
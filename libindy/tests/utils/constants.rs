@@ -2,6 +2,7 @@ pub const SEQ_NO: i32 = 1;
 pub const PROTOCOL_VERSION: usize = 2;
 pub const TYPE: &'static str = "default";
 pub const INMEM_TYPE: &'static str = "inmem";
+pub const MEMORY_TYPE: &'static str = "memory";
 pub const SIGNATURE_TYPE: &'static str = "CL";
 pub const TRUSTEE_SEED: &'static str = "000000000000000000000000Trustee1";
 pub const STEWARD_SEED: &'static str = "000000000000000000000000Steward1";
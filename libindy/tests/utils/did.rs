@@ -7,7 +7,7 @@ use self::futures::Future;
 use crate::utils::{ledger, pool};
 use crate::utils::types::ResponseType;
 use crate::utils::constants::DEFAULT_METHOD_NAME;
-use indy::{WalletHandle, PoolHandle};
+use indy::{WalletHandle, PoolHandle, SearchHandle};
 
 pub fn create_store_and_publish_did(wallet_handle: WalletHandle, pool_handle: PoolHandle, role: &str, method_name: Option<&str>) -> Result<(String, String), IndyError> {
     let my_did_json = json!({"method_name": method_name, "seed": crate::utils::constants::TRUSTEE_SEED}).to_string();
@@ -63,6 +63,10 @@ pub fn replace_keys_apply(wallet_handle: WalletHandle, did: &str) -> Result<(),
     did::replace_keys_apply(wallet_handle, did).wait()
 }
 
+pub fn rotate_key(wallet_handle: WalletHandle, pool_handle: PoolHandle, did: &str, key_info: &str, endorser_did: Option<&str>) -> Result<String, IndyError> {
+    did::rotate_key(wallet_handle, pool_handle, did, key_info, endorser_did).wait()
+}
+
 pub fn replace_keys(pool_handle: PoolHandle, wallet_handle: WalletHandle, did: &str) -> Result<String, IndyError> {
     let verkey = did::replace_keys_start(wallet_handle, did, "{}").wait().unwrap();
 
@@ -113,3 +117,75 @@ pub fn abbreviate_verkey(did: &str, verkey: &str) -> Result<String, IndyError> {
 pub fn qualify_did(wallet_handle: WalletHandle, did: &str, prefix: &str) -> Result<String, IndyError> {
     did::qualify_did(wallet_handle, did, prefix).wait()
 }
+
+pub fn qualify_all_dids(wallet_handle: WalletHandle, method: &str, options_json: Option<&str>) -> Result<String, IndyError> {
+    did::qualify_all_dids(wallet_handle, method, options_json).wait()
+}
+
+pub fn get_did_doc(wallet_handle: WalletHandle, pool_handle: PoolHandle, did: &str) -> Result<String, IndyError> {
+    did::get_did_doc(wallet_handle, pool_handle, did).wait()
+}
+
+pub fn add_did_key(wallet_handle: WalletHandle, did: &str, key_json: &str) -> Result<(), IndyError> {
+    did::add_did_key(wallet_handle, did, key_json).wait()
+}
+
+pub fn remove_did_key(wallet_handle: WalletHandle, did: &str, key_id: &str) -> Result<(), IndyError> {
+    did::remove_did_key(wallet_handle, did, key_id).wait()
+}
+
+pub fn add_did_service(wallet_handle: WalletHandle, did: &str, service_json: &str) -> Result<(), IndyError> {
+    did::add_did_service(wallet_handle, did, service_json).wait()
+}
+
+pub fn remove_did_service(wallet_handle: WalletHandle, did: &str, service_id: &str) -> Result<(), IndyError> {
+    did::remove_did_service(wallet_handle, did, service_id).wait()
+}
+
+pub fn set_named_endpoint_for_did(wallet_handle: WalletHandle, did: &str, endpoint_json: &str) -> Result<(), IndyError> {
+    did::set_named_endpoint_for_did(wallet_handle, did, endpoint_json).wait()
+}
+
+pub fn remove_named_endpoint_for_did(wallet_handle: WalletHandle, did: &str, name: &str) -> Result<(), IndyError> {
+    did::remove_named_endpoint_for_did(wallet_handle, did, name).wait()
+}
+
+pub fn get_named_endpoints_for_did(wallet_handle: WalletHandle, pool_handle: PoolHandle, did: &str) -> Result<String, IndyError> {
+    did::get_named_endpoints_for_did(wallet_handle, pool_handle, did).wait()
+}
+
+pub fn build_named_endpoints_attrib_request(wallet_handle: WalletHandle, submitter_did: &str, target_did: &str) -> Result<String, IndyError> {
+    did::build_named_endpoints_attrib_request(wallet_handle, submitter_did, target_did).wait()
+}
+
+pub fn set_my_did_tags(wallet_handle: WalletHandle, did: &str, tags_json: &str) -> Result<(), IndyError> {
+    did::set_my_did_tags(wallet_handle, did, tags_json).wait()
+}
+
+pub fn open_my_dids_search(wallet_handle: WalletHandle, query_json: Option<&str>, options_json: Option<&str>) -> Result<(SearchHandle, usize), IndyError> {
+    did::open_my_dids_search(wallet_handle, query_json, options_json).wait()
+}
+
+pub fn fetch_my_dids(search_handle: SearchHandle, count: usize) -> Result<String, IndyError> {
+    did::fetch_my_dids(search_handle, count).wait()
+}
+
+pub fn close_my_dids_search(search_handle: SearchHandle) -> Result<(), IndyError> {
+    did::close_my_dids_search(search_handle).wait()
+}
+
+pub fn export_did(wallet_handle: WalletHandle, did: &str, recipient_vk: &str) -> Result<Vec<u8>, IndyError> {
+    did::export_did(wallet_handle, did, recipient_vk).wait()
+}
+
+pub fn import_did(wallet_handle: WalletHandle, recipient_vk: &str, bundle: &[u8]) -> Result<String, IndyError> {
+    did::import_did(wallet_handle, recipient_vk, bundle).wait()
+}
+
+pub fn deactivate_did(wallet_handle: WalletHandle, did: &str) -> Result<(), IndyError> {
+    did::deactivate_did(wallet_handle, did).wait()
+}
+
+pub fn publish_did_deactivation(wallet_handle: WalletHandle, pool_handle: PoolHandle, did: &str) -> Result<String, IndyError> {
+    did::publish_did_deactivation(wallet_handle, pool_handle, did).wait()
+}
@@ -0,0 +1,124 @@
+use super::ErrorCode;
+use super::sequence;
+use super::libc::c_char;
+
+use std::collections::{HashMap, HashSet};
+use std::ffi::{CStr, CString};
+use std::ptr;
+use std::slice;
+use std::sync::Mutex;
+
+/// Blob storage plugin that keeps blobs in memory of the process.
+pub struct InmemBlobStorage {}
+
+struct InmemWritableBlob {
+    content: Vec<u8>,
+    location: Option<CString>,
+}
+
+lazy_static! {
+    static ref INMEM_OPENED: Mutex<HashSet<i32>> = Default::default();
+    static ref INMEM_BLOBS: Mutex<HashMap<String, Vec<u8>>> = Default::default();
+    static ref INMEM_WRITABLE_BLOBS: Mutex<HashMap<i32, InmemWritableBlob>> = Default::default();
+    static ref INMEM_READABLE_BLOBS: Mutex<HashMap<i32, Vec<u8>>> = Default::default();
+}
+
+impl InmemBlobStorage {
+    pub extern "C" fn open(_config: *const c_char, handle_p: *mut i32) -> ErrorCode {
+        let handle = sequence::get_next_id();
+        INMEM_OPENED.lock().unwrap().insert(handle);
+        unsafe { *handle_p = handle };
+        ErrorCode::Success
+    }
+
+    pub extern "C" fn close(handle: i32) -> ErrorCode {
+        match INMEM_OPENED.lock().unwrap().remove(&handle) {
+            true => ErrorCode::Success,
+            false => ErrorCode::CommonInvalidState
+        }
+    }
+
+    pub extern "C" fn create_blob(_writer_handle: i32, blob_handle_p: *mut i32) -> ErrorCode {
+        let handle = sequence::get_next_id();
+        INMEM_WRITABLE_BLOBS.lock().unwrap().insert(handle, InmemWritableBlob { content: Vec::new(), location: None });
+        unsafe { *blob_handle_p = handle };
+        ErrorCode::Success
+    }
+
+    pub extern "C" fn append(blob_handle: i32, bytes: *const u8, bytes_len: u32) -> ErrorCode {
+        let mut blobs = INMEM_WRITABLE_BLOBS.lock().unwrap();
+
+        match blobs.get_mut(&blob_handle) {
+            Some(blob) => {
+                blob.content.extend_from_slice(unsafe { slice::from_raw_parts(bytes, bytes_len as usize) });
+                ErrorCode::Success
+            }
+            None => ErrorCode::CommonInvalidState
+        }
+    }
+
+    pub extern "C" fn finalize(blob_handle: i32, _hash: *const u8, _hash_len: u32, location_p: *mut *const c_char) -> ErrorCode {
+        let mut blobs = INMEM_WRITABLE_BLOBS.lock().unwrap();
+
+        let blob = match blobs.get_mut(&blob_handle) {
+            Some(blob) => blob,
+            None => return ErrorCode::CommonInvalidState
+        };
+
+        let location = format!("inmem://{}", blob_handle);
+        INMEM_BLOBS.lock().unwrap().insert(location.clone(), blob.content.clone());
+
+        let location = CString::new(location).unwrap();
+        unsafe { *location_p = location.as_ptr() };
+        blob.location = Some(location);
+
+        ErrorCode::Success
+    }
+
+    pub extern "C" fn close_writable_blob(blob_handle: i32) -> ErrorCode {
+        match INMEM_WRITABLE_BLOBS.lock().unwrap().remove(&blob_handle) {
+            Some(_) => ErrorCode::Success,
+            None => ErrorCode::CommonInvalidState
+        }
+    }
+
+    pub extern "C" fn open_blob(_reader_handle: i32, _hash: *const u8, _hash_len: u32, location: *const c_char, blob_handle_p: *mut i32) -> ErrorCode {
+        let location = unsafe { CStr::from_ptr(location) }.to_str().unwrap();
+
+        let content = match INMEM_BLOBS.lock().unwrap().get(location) {
+            Some(content) => content.clone(),
+            None => return ErrorCode::CommonIOError
+        };
+
+        let handle = sequence::get_next_id();
+        INMEM_READABLE_BLOBS.lock().unwrap().insert(handle, content);
+        unsafe { *blob_handle_p = handle };
+        ErrorCode::Success
+    }
+
+    pub extern "C" fn read(blob_handle: i32, offset: u64, size: u32, buf: *mut u8, read_len_p: *mut u32) -> ErrorCode {
+        let blobs = INMEM_READABLE_BLOBS.lock().unwrap();
+
+        let content = match blobs.get(&blob_handle) {
+            Some(content) => content,
+            None => return ErrorCode::CommonInvalidState
+        };
+
+        let start = ::std::cmp::min(offset as usize, content.len());
+        let end = ::std::cmp::min(start + size as usize, content.len());
+
+        unsafe {
+            ptr::copy_nonoverlapping(content[start..end].as_ptr(), buf, end - start);
+            *read_len_p = (end - start) as u32;
+        }
+
+        ErrorCode::Success
+    }
+
+    pub extern "C" fn close_readable_blob(blob_handle: i32) -> ErrorCode {
+        match INMEM_READABLE_BLOBS.lock().unwrap().remove(&blob_handle) {
+            Some(_) => ErrorCode::Success,
+            None => ErrorCode::CommonInvalidState
+        }
+    }
+}
@@ -5,16 +5,19 @@ use indy::{IndyError, ErrorCode};
 use indy::ledger;
 use indy_utils::crypto::hash::hash;
 use self::futures::Future;
-use self::indy_sys::ledger::{CustomTransactionParser, CustomFree, indy_register_transaction_parser_for_sp};
+use self::indy_sys::ledger::{CustomTransactionParser, CustomTransactionBuilder, CustomResponseParser, CustomFree,
+                             CustomRequestMiddleware, CustomReplyMiddleware,
+                             indy_register_transaction_parser_for_sp, indy_register_ledger_transaction_builder,
+                             indy_register_ledger_middleware, indy_unregister_ledger_middleware};
 
 use crate::utils::{timeout, anoncreds, blob_storage, did, wallet, pool, callback};
 use crate::utils::constants::*;
 
-use std::sync::{Once};
+use std::sync::{Arc, Mutex, Once};
 use std::mem;
 use std::ffi::CString;
 
-use indy::{WalletHandle, PoolHandle};
+use indy::{WalletHandle, PoolHandle, IndyHandle};
 
 pub static mut SCHEMA_ID: &'static str = "";
 pub static mut SCHEMA_ID_V2: &'static str = "";
@@ -39,6 +42,42 @@ pub fn submit_request(pool_handle: PoolHandle, request_json: &str) -> Result<Str
     ledger::submit_request(pool_handle, request_json).wait()
 }
 
+pub fn submit_request_with_options(pool_handle: PoolHandle, request_json: &str, options_json: &str) -> Result<String, IndyError> {
+    ledger::submit_request_with_options(pool_handle, request_json, options_json).wait()
+}
+
+pub fn submit_request_idempotent(pool_handle: PoolHandle, request_json: &str) -> Result<String, IndyError> {
+    ledger::submit_request_idempotent(pool_handle, request_json).wait()
+}
+
+pub fn submit_requests(pool_handle: PoolHandle, requests_json: &str) -> Result<String, IndyError> {
+    ledger::submit_requests(pool_handle, requests_json).wait()
+}
+
+pub fn get_txns(pool_handle: PoolHandle, submitter_did: Option<&str>, ledger_type: Option<&str>, from: i32, to: i32) -> Result<String, IndyError> {
+    ledger::get_txns(pool_handle, submitter_did, ledger_type, from, to).wait()
+}
+
+pub fn stream_txns(pool_handle: PoolHandle, submitter_did: Option<&str>, ledger_type: Option<&str>, from: i32, to: i32) -> Result<Vec<String>, IndyError> {
+    let batches = Arc::new(Mutex::new(Vec::new()));
+    let batches_ = batches.clone();
+
+    ledger::stream_txns(pool_handle, submitter_did, ledger_type, from, to, Box::new(move |txns_json| {
+        batches_.lock().unwrap().push(txns_json);
+    })).wait()?;
+
+    let batches = batches.lock().unwrap().clone();
+    Ok(batches)
+}
+
+pub fn subscribe_ledger_events(pool_handle: PoolHandle, filter_json: &str, event_cb: Box<dyn Fn(String) + Send>) -> Result<IndyHandle, IndyError> {
+    ledger::subscribe_ledger_events(pool_handle, filter_json, event_cb).wait()
+}
+
+pub fn unsubscribe_ledger_events(subscription_handle: IndyHandle) -> Result<(), IndyError> {
+    ledger::unsubscribe_ledger_events(subscription_handle).wait()
+}
+
 pub fn submit_action(pool_handle: PoolHandle, request_json: &str, nodes: Option<&str>, timeout: Option<i32>) -> Result<String, IndyError> {
     ledger::submit_action(pool_handle, request_json, nodes, timeout).wait()
 }
@@ -47,10 +86,22 @@ pub fn sign_request(wallet_handle: WalletHandle, submitter_did: &str, request_js
     ledger::sign_request(wallet_handle, submitter_did, request_json).wait()
 }
 
+pub fn force_sign_request(wallet_handle: WalletHandle, submitter_did: &str, request_json: &str) -> Result<String, IndyError> {
+    ledger::force_sign_request(wallet_handle, submitter_did, request_json).wait()
+}
+
 pub fn multi_sign_request(wallet_handle: WalletHandle, submitter_did: &str, request_json: &str) -> Result<String, IndyError> {
     ledger::multi_sign_request(wallet_handle, submitter_did, request_json).wait()
 }
 
+pub fn merge_multi_signed_requests(requests_json: &str) -> Result<String, IndyError> {
+    ledger::merge_multi_signed_requests(requests_json).wait()
+}
+
+pub fn check_request_signatures(pool_handle: PoolHandle, request_json: &str) -> Result<String, IndyError> {
+    ledger::check_request_signatures(pool_handle, request_json).wait()
+}
+
 pub fn extract_seq_no_from_reply(reply: &str) -> Result<u64, &'static str> {
     let metadata = get_response_metadata(reply).map_err(|_| "Can not get Metadata from Reply")?;
 
@@ -94,10 +145,46 @@ pub fn build_nym_request(submitter_did: &str, target_did: &str, verkey: Option<&
     ledger::build_nym_request(submitter_did, target_did, verkey, alias, role).wait()
 }
 
+pub fn build_nym_request_with_data(submitter_did: &str, target_did: &str, nym_data_json: &str) -> Result<String, IndyError> {
+    ledger::build_nym_request_with_data(submitter_did, target_did, nym_data_json).wait()
+}
+
 pub fn parse_get_nym_response(get_nym_response: &str) -> Result<String, IndyError> {
     ledger::parse_get_nym_response(get_nym_response).wait()
 }
 
+pub fn parse_get_attrib_response(get_attrib_response: &str) -> Result<String, IndyError> {
+    ledger::parse_get_attrib_response(get_attrib_response).wait()
+}
+
+pub fn parse_get_txn_response(get_txn_response: &str) -> Result<String, IndyError> {
+    ledger::parse_get_txn_response(get_txn_response).wait()
+}
+
+pub fn parse_get_auth_rule_response(get_auth_rule_response: &str) -> Result<String, IndyError> {
+    ledger::parse_get_auth_rule_response(get_auth_rule_response).wait()
+}
+
+pub fn parse_get_validator_info_response(get_validator_info_response: &str) -> Result<String, IndyError> {
+    ledger::parse_get_validator_info_response(get_validator_info_response).wait()
+}
+
+pub fn parse_get_validator_info_summary_response(get_validator_info_response: &str) -> Result<String, IndyError> {
+    ledger::parse_get_validator_info_summary_response(get_validator_info_response).wait()
+}
+
+pub fn parse_pool_upgrade_status_response(get_validator_info_response: &str) -> Result<String, IndyError> {
+    ledger::parse_pool_upgrade_status_response(get_validator_info_response).wait()
+}
+
+pub fn parse_get_txn_author_agreement_response(get_txn_author_agreement_response: &str) -> Result<String, IndyError> {
+    ledger::parse_get_txn_author_agreement_response(get_txn_author_agreement_response).wait()
+}
+
+pub fn parse_get_acceptance_mechanisms_response(get_acceptance_mechanisms_response: &str) -> Result<String, IndyError> {
+    ledger::parse_get_acceptance_mechanisms_response(get_acceptance_mechanisms_response).wait()
+}
+
 pub fn build_attrib_request(submitter_did: &str, target_did: &str, hash: Option<&str>, raw: Option<&str>, enc: Option<&str>) -> Result<String, IndyError> {
     ledger::build_attrib_request(submitter_did, target_did, hash, raw, enc).wait()
 }
@@ -110,6 +197,23 @@ pub fn build_get_nym_request(submitter_did: Option<&str>, target_did: &str) -> R
     ledger::build_get_nym_request(submitter_did, target_did).wait()
 }
 
+pub fn build_get_historical_nym_request(submitter_did: Option<&str>, target_did: &str, seq_no: Option<u64>, timestamp: Option<u64>) -> Result<String, IndyError> {
+    ledger::build_get_historical_nym_request(submitter_did, target_did, seq_no, timestamp).wait()
+}
+
+pub fn build_get_historical_attrib_request(submitter_did: Option<&str>, target_did: &str, raw: Option<&str>, hash: Option<&str>, enc: Option<&str>,
+                                           seq_no: Option<u64>, timestamp: Option<u64>) -> Result<String, IndyError> {
+    ledger::build_get_historical_attrib_request(submitter_did, target_did, raw, hash, enc, seq_no, timestamp).wait()
+}
+
+pub fn build_set_endpoint_request(submitter_did: &str, target_did: &str, address: &str, transport_key: Option<&str>) -> Result<String, IndyError> {
+    ledger::build_set_endpoint_request(submitter_did, target_did, address, transport_key).wait()
+}
+
+pub fn build_get_endpoint_request(submitter_did: Option<&str>, target_did: &str) -> Result<String, IndyError> {
+    ledger::build_get_endpoint_request(submitter_did, target_did).wait()
+}
+
 pub fn build_schema_request(submitter_did: &str, data: &str) -> Result<String, IndyError> {
     ledger::build_schema_request(submitter_did, data).wait()
 }
@@ -154,6 +258,10 @@ pub fn build_pool_upgrade_request(submitter_did: &str, name: &str, version: &str
                                        timeout, schedule, justification, reinstall, force, package).wait()
 }
 
+pub fn build_scheduled_pool_upgrade_request(pool_handle: PoolHandle, submitter_did: &str, upgrade_json: &str) -> Result<String, IndyError> {
+    ledger::build_scheduled_pool_upgrade_request(pool_handle, submitter_did, upgrade_json).wait()
+}
+
 pub fn build_revoc_reg_def_request(submitter_did: &str, data: &str) -> Result<String, IndyError> {
     ledger::build_revoc_reg_def_request(submitter_did, data).wait()
 }
@@ -211,10 +319,73 @@ pub fn register_transaction_parser_for_sp(txn_type: &str, parse: CustomTransacti
     super::results::result_to_empty(err, receiver)
 }
 
+pub fn register_ledger_transaction_builder(txn_type: &str, builder: CustomTransactionBuilder, parser: Option<CustomResponseParser>, free: CustomFree) -> Result<(), ErrorCode> {
+    let (receiver, command_handle, cb) = callback::_closure_to_cb_ec();
+
+    let txn_type = CString::new(txn_type).unwrap();
+
+    let err =
+        unsafe {
+            indy_register_ledger_transaction_builder(command_handle,
+                                                     txn_type.as_ptr(),
+                                                     Some(builder),
+                                                     parser,
+                                                     Some(free),
+                                                     cb)
+        };
+
+    super::results::result_to_empty(err, receiver)
+}
+
+pub fn register_ledger_middleware(request_middleware: Option<CustomRequestMiddleware>, reply_middleware: Option<CustomReplyMiddleware>, free: CustomFree) -> Result<IndyHandle, ErrorCode> {
+    let (receiver, command_handle, cb) = callback::_closure_to_cb_ec_i32();
+
+    let err =
+        unsafe {
+            indy_register_ledger_middleware(command_handle,
+                                            request_middleware,
+                                            reply_middleware,
+                                            Some(free),
+                                            cb)
+        };
+
+    if err != ErrorCode::Success as i32 {
+        return Err(ErrorCode::from(err));
+    }
+
+    let (err, middleware_handle) = receiver.recv().unwrap();
+
+    if err != ErrorCode::Success as i32 {
+        return Err(ErrorCode::from(err));
+    }
+
+    Ok(middleware_handle)
+}
+
+pub fn unregister_ledger_middleware(middleware_handle: IndyHandle) -> Result<(), ErrorCode> {
+    let (receiver, command_handle, cb) = callback::_closure_to_cb_ec();
+
+    let err = unsafe { indy_unregister_ledger_middleware(command_handle, middleware_handle, cb) };
+
+    super::results::result_to_empty(err, receiver)
+}
+
+pub fn build_custom_request(submitter_did: Option<&str>, txn_type: &str, data_json: &str) -> Result<String, IndyError> {
+    ledger::build_custom_request(submitter_did, txn_type, data_json).wait()
+}
+
+pub fn parse_custom_response(txn_type: &str, response_json: &str) -> Result<String, IndyError> {
+    ledger::parse_custom_response(txn_type, response_json).wait()
+}
+
 pub fn get_response_metadata(response: &str) -> Result<String, IndyError> {
     ledger::get_response_metadata(response).wait()
 }
 
+pub fn verify_state_proof(request: &str, response: &str, bls_keys: &str) -> Result<bool, IndyError> {
+    ledger::verify_state_proof(request, response, bls_keys).wait()
+}
+
 pub fn build_auth_rule_request(submitter_did: &str,
                                txn_type: &str,
                                action: &str,
@@ -239,6 +410,26 @@ pub fn build_get_auth_rule_request(submitter_did: Option<&str>,
     ledger::build_get_auth_rule_request(submitter_did, auth_type, auth_action, field, old_value, new_value).wait()
 }
 
+pub fn build_role_constraint(role: &str, sig_count: u32, need_to_be_owner: bool, off_ledger_signature: bool, metadata_json: Option<&str>) -> Result<String, IndyError> {
+    ledger::build_role_constraint(role, sig_count, need_to_be_owner, off_ledger_signature, metadata_json).wait()
+}
+
+pub fn build_combined_constraint(combination: &str, constraints_json: &str) -> Result<String, IndyError> {
+    ledger::build_combined_constraint(combination, constraints_json).wait()
+}
+
+pub fn evaluate_auth_rules(auth_rules_json: &str, action_json: &str, signers_json: &str) -> Result<String, IndyError> {
+    ledger::evaluate_auth_rules(auth_rules_json, action_json, signers_json).wait()
+}
+
+pub fn get_auth_rules(pool_handle: PoolHandle, submitter_did: Option<&str>, page_options_json: Option<&str>) -> Result<String, IndyError> {
+    ledger::get_auth_rules(pool_handle, submitter_did, page_options_json).wait()
+}
+
+pub fn diff_auth_rules(current_auth_rules_json: &str, proposed_auth_rules_json: &str) -> Result<String, IndyError> {
+    ledger::diff_auth_rules(current_auth_rules_json, proposed_auth_rules_json).wait()
+}
+
 pub fn build_txn_author_agreement_request(submitter_did: &str,
                                           text: Option<&str>,
                                           version: &str,
@@ -278,11 +469,24 @@ pub fn append_txn_author_agreement_acceptance_to_request(request_json: &str,
     ledger::append_txn_author_agreement_acceptance_to_request(request_json, text, version, taa_digest, acc_mech_type, time_of_acceptance).wait()
 }
 
+pub fn prepare_request_with_taa(pool_handle: PoolHandle,
+                                wallet_handle: WalletHandle,
+                                submitter_did: &str,
+                                request_json: &str,
+                                acc_mech_type: Option<&str>) -> Result<String, IndyError> {
+    ledger::prepare_request_with_taa(pool_handle, wallet_handle, submitter_did, request_json, acc_mech_type).wait()
+}
+
 pub fn append_request_endorser(request_json: &str,
                                endorser_did: &str) -> Result<String, IndyError> {
     ledger::append_request_endorser(request_json, endorser_did).wait()
 }
 
+pub fn set_request_protocol_version(request_json: &str,
+                                    protocol_version: usize) -> Result<String, IndyError> {
+    ledger::set_request_protocol_version(request_json, protocol_version).wait()
+}
+
 pub fn post_entities() -> (&'static str, &'static str, &'static str) {
     lazy_static! {
                     static ref COMMON_ENTITIES_INIT: Once = Once::new();
@@ -427,4 +631,8 @@ pub fn build_ledgers_freeze_request(submitter_did: &str, ledgers_ids: Vec<u64>)
 
 pub fn get_frozen_ledgers_request(submitter_did: &str) -> Result<String, IndyError> {
     ledger::build_get_frozen_ledgers_request(submitter_did).wait()
-}
\ No newline at end of file
+}
+
+pub fn parse_get_frozen_ledgers_response(get_frozen_ledgers_response: &str) -> Result<String, IndyError> {
+    ledger::parse_get_frozen_ledgers_response(get_frozen_ledgers_response).wait()
+}
@@ -7,4 +7,20 @@ use self::futures::Future;
 
 pub fn collect_metrics() -> Result<String, IndyError> {
     metrics::collect_metrics().wait()
-}
\ No newline at end of file
+}
+
+pub fn collect_metrics_prometheus() -> Result<String, IndyError> {
+    metrics::collect_metrics_prometheus().wait()
+}
+
+pub fn collect_metrics_delta() -> Result<String, IndyError> {
+    metrics::collect_metrics_delta().wait()
+}
+
+pub fn reset_metrics() -> Result<(), IndyError> {
+    metrics::reset_metrics().wait()
+}
+
+pub fn list_handles() -> Result<String, IndyError> {
+    metrics::list_handles().wait()
+}
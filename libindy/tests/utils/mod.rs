@@ -52,6 +52,8 @@ pub(crate) use indy::ErrorCode;
 #[path = "../../indy-utils/src/inmem_wallet.rs"]
 pub mod inmem_wallet;
 
+pub mod inmem_blob_storage;
+
 #[path = "../../indy-utils/src/wql.rs"]
 pub mod wql;
 
@@ -9,7 +9,7 @@ use crate::utils::{test};
 use crate::utils::constants::WALLET_CREDENTIALS;
 use crate::utils::types::WalletRecord;
 
-use std::sync::Once;
+use std::sync::{Arc, Mutex, Once};
 use std::collections::HashMap;
 
 use indy::WalletHandle;
@@ -46,6 +46,14 @@ pub fn update_wallet_record_value(wallet_handle: WalletHandle, type_: &str, id:
     wallet::update_wallet_record_value(wallet_handle, type_, id, value).wait()
 }
 
+pub fn compare_and_swap_wallet_record_value(wallet_handle: WalletHandle, type_: &str, id: &str, expected_value_hash: &str, value: &str) -> Result<String, IndyError> {
+    wallet::compare_and_swap_wallet_record_value(wallet_handle, type_, id, expected_value_hash, value).wait()
+}
+
+pub fn modify_wallet_record_value(wallet_handle: WalletHandle, type_: &str, id: &str, modify_cb: wallet::ModifyWalletRecordValueCB) -> Result<String, IndyError> {
+    wallet::modify_wallet_record_value(wallet_handle, type_, id, modify_cb).wait()
+}
+
 pub fn update_wallet_record_tags(wallet_handle: WalletHandle, type_: &str, id: &str, tags_json: &str) -> Result<(), IndyError> {
     wallet::update_wallet_record_tags(wallet_handle, type_, id, tags_json).wait()
 }
@@ -58,6 +66,10 @@ pub fn delete_wallet_record_tags(wallet_handle: WalletHandle, type_: &str, id: &
     wallet::delete_wallet_record_tags(wallet_handle, type_, id, tag_names_json).wait()
 }
 
+pub fn upsert_wallet_records(wallet_handle: WalletHandle, type_: &str, records_json: &str) -> Result<String, IndyError> {
+    wallet::upsert_wallet_records(wallet_handle, type_, records_json).wait()
+}
+
 pub fn delete_wallet_record(wallet_handle: WalletHandle, type_: &str, id: &str) -> Result<(), IndyError> {
     wallet::delete_wallet_record(wallet_handle, type_, id).wait()
 }
@@ -66,6 +78,14 @@ pub fn get_wallet_record(wallet_handle: WalletHandle, type_: &str, id: &str, opt
     wallet::get_wallet_record(wallet_handle, type_, id, options_json).wait()
 }
 
+pub fn get_wallet_record_types(wallet_handle: WalletHandle) -> Result<String, IndyError> {
+    wallet::get_wallet_record_types(wallet_handle).wait()
+}
+
+pub fn explain_wallet_search(wallet_handle: WalletHandle, type_: &str, query_json: &str) -> Result<String, IndyError> {
+    wallet::explain_wallet_search(wallet_handle, type_, query_json).wait()
+}
+
 pub fn open_wallet_search(wallet_handle: WalletHandle, type_: &str, query_json: &str, options_json: &str) -> Result<i32, IndyError> {
     wallet::open_wallet_search(wallet_handle, type_, query_json, options_json).wait()
 }
@@ -78,6 +98,38 @@ pub fn close_wallet_search(wallet_search_handle: i32) -> Result<(), IndyError> {
     wallet::close_wallet_search(wallet_search_handle).wait()
 }
 
+pub fn stream_wallet_records(wallet_handle: WalletHandle, type_: &str, query_json: &str, options_json: &str, chunk_size: u32) -> Result<Vec<String>, IndyError> {
+    let chunks = Arc::new(Mutex::new(Vec::new()));
+    let chunks_ = chunks.clone();
+
+    wallet::stream_wallet_records(wallet_handle, type_, query_json, options_json, chunk_size, Box::new(move |records_json| {
+        chunks_.lock().unwrap().push(records_json);
+    })).wait()?;
+
+    let chunks = chunks.lock().unwrap().clone();
+    Ok(chunks)
+}
+
+pub fn enable_wallet_record_history(wallet_handle: WalletHandle, type_: &str, config_json: &str) -> Result<(), IndyError> {
+    wallet::enable_wallet_record_history(wallet_handle, type_, config_json).wait()
+}
+
+pub fn disable_wallet_record_history(wallet_handle: WalletHandle, type_: &str) -> Result<(), IndyError> {
+    wallet::disable_wallet_record_history(wallet_handle, type_).wait()
+}
+
+pub fn get_wallet_record_revisions(wallet_handle: WalletHandle, type_: &str, id: &str) -> Result<String, IndyError> {
+    wallet::get_wallet_record_revisions(wallet_handle, type_, id).wait()
+}
+
+pub fn get_wallet_record_revision(wallet_handle: WalletHandle, type_: &str, id: &str, revision: u32) -> Result<String, IndyError> {
+    wallet::get_wallet_record_revision(wallet_handle, type_, id, revision).wait()
+}
+
+pub fn prune_wallet_record_history(wallet_handle: WalletHandle, type_: &str, id: &str, keep_last: u32) -> Result<(), IndyError> {
+    wallet::prune_wallet_record_history(wallet_handle, type_, id, keep_last).wait()
+}
+
 pub fn tags_1() -> HashMap<String, String> {
     serde_json::from_str(TAGS).unwrap()
 }
@@ -4,7 +4,7 @@ use indy::IndyError;
 use indy::pairwise;
 use self::futures::Future;
 
-use indy::WalletHandle;
+use indy::{WalletHandle, SearchHandle};
 
 pub fn pairwise_exists(wallet_handle: WalletHandle, their_did: &str) -> Result<bool, IndyError> {
     pairwise::is_pairwise_exists(wallet_handle, their_did).wait()
@@ -24,4 +24,44 @@ pub fn get_pairwise(wallet_handle: WalletHandle, their_did: &str) -> Result<Stri
 
 pub fn set_pairwise_metadata(wallet_handle: WalletHandle, their_did: &str, metadata: Option<&str>) -> Result<(), IndyError> {
     pairwise::set_pairwise_metadata(wallet_handle, their_did, metadata).wait()
+}
+
+pub fn update_pairwise(wallet_handle: WalletHandle, their_did: &str, new_their_did: &str) -> Result<(), IndyError> {
+    pairwise::update_pairwise(wallet_handle, their_did, new_their_did).wait()
+}
+
+pub fn delete_pairwise(wallet_handle: WalletHandle, their_did: &str) -> Result<(), IndyError> {
+    pairwise::delete_pairwise(wallet_handle, their_did).wait()
+}
+
+pub fn link_pairwise_record(wallet_handle: WalletHandle, their_did: &str, record_type: &str, record_id: &str) -> Result<(), IndyError> {
+    pairwise::link_pairwise_record(wallet_handle, their_did, record_type, record_id).wait()
+}
+
+pub fn unlink_pairwise_record(wallet_handle: WalletHandle, their_did: &str, record_type: &str, record_id: &str) -> Result<(), IndyError> {
+    pairwise::unlink_pairwise_record(wallet_handle, their_did, record_type, record_id).wait()
+}
+
+pub fn get_pairwise_links(wallet_handle: WalletHandle, their_did: &str, record_type: Option<&str>) -> Result<String, IndyError> {
+    pairwise::get_pairwise_links(wallet_handle, their_did, record_type).wait()
+}
+
+pub fn set_pairwise_meta(wallet_handle: WalletHandle, their_did: &str, meta_json: &str) -> Result<(), IndyError> {
+    pairwise::set_pairwise_meta(wallet_handle, their_did, meta_json).wait()
+}
+
+pub fn migrate_pairwise_meta(wallet_handle: WalletHandle) -> Result<String, IndyError> {
+    pairwise::migrate_pairwise_meta(wallet_handle).wait()
+}
+
+pub fn open_pairwise_search(wallet_handle: WalletHandle, query_json: Option<&str>, options_json: Option<&str>) -> Result<(SearchHandle, usize), IndyError> {
+    pairwise::open_pairwise_search(wallet_handle, query_json, options_json).wait()
+}
+
+pub fn fetch_pairwise(search_handle: SearchHandle, count: usize) -> Result<String, IndyError> {
+    pairwise::fetch_pairwise(search_handle, count).wait()
+}
+
+pub fn close_pairwise_search(search_handle: SearchHandle) -> Result<(), IndyError> {
+    pairwise::close_pairwise_search(search_handle).wait()
 }
\ No newline at end of file
@@ -11,7 +11,7 @@ use std::ffi::CString;
 use super::libc::c_char;
 use std::sync::{Once, Mutex};
 
-use indy::{WalletHandle, CommandHandle};
+use indy::{WalletHandle, CommandHandle, PoolHandle};
 use crate::utils::callback;
 
 #[macro_export]
@@ -156,6 +156,59 @@ macro_rules! mocked_handler_bool {
     )
 }
 
+macro_rules! mocked_handler_v2 {
+    ($first_param_name: ident: $first_param_type: ty $(, $param_name: ident: $param_type: ty)*) => (
+        use super::*;
+
+        lazy_static! {
+          static ref INJECTIONS: Mutex<VecDeque<(i32, Option<CString>, CString)>> = Default::default();
+        }
+
+        pub extern fn handle(cmd_handle: CommandHandle,
+                                    $first_param_name: $first_param_type,
+                                    $($param_name: $param_type,)*
+                                    cb: Option<IndyPaymentV2Callback>) -> i32 {
+
+            let cb = cb.unwrap_or_else(|| {
+                panic!("Null passed as callback!")
+            });
+
+            if let Ok(mut injections) = INJECTIONS.lock() {
+                if let Some((err, error_json, res)) = injections.pop_front() {
+                    return (cb)(cmd_handle, err, error_json.as_ref().map(|s| s.as_ptr()).unwrap_or(::std::ptr::null()), res.as_ptr());
+                }
+            } else {
+                panic!("Can't lock injections mutex");
+            }
+
+            panic!("No injections left!");
+        }
+
+        pub fn inject_mock(err: ErrorCode, error_json: Option<&str>, res: &str) {
+            if let Ok(mut injections) = INJECTIONS.lock() {
+                let error_json = error_json.map(|s| CString::new(s).unwrap());
+                let res = CString::new(res).unwrap();
+                injections.push_back((err as i32, error_json, res))
+            } else {
+                panic!("Can't lock injections mutex");
+            }
+        }
+
+        pub fn clear_mocks() {
+            if let Ok(mut injections) = INJECTIONS.lock() {
+                injections.clear();
+            } else {
+                panic!("Can't lock injections mutex");
+            }
+        }
+    )
+}
+
+type IndyPaymentV2Callback = extern fn(command_handle_: CommandHandle,
+                                       err: i32,
+                                       error_json: *const c_char,
+                                       result_json: *const c_char) -> i32;
+
 type IndyPaymentCallback = extern fn(command_handle_: CommandHandle,
                                      err: i32,
                                      payment_address: *const c_char) -> i32;
@@ -167,6 +220,7 @@ type ParsePaymentSourcesCallback = extern fn(command_handle_: CommandHandle,
 
 lazy_static! {
         static ref CREATE_PAYMENT_METHOD_INIT: Once = Once::new();
+        static ref CREATE_PAYMENT_METHOD_V2_INIT: Once = Once::new();
 }
 
 pub mod mock_method {
@@ -202,6 +256,63 @@ pub mod mock_method {
         });
     }
 
+    pub const V2_PAYMENT_METHOD_NAME: &str = "nullv2";
+
+    pub fn init_v2() {
+        CREATE_PAYMENT_METHOD_V2_INIT.call_once(|| {
+            let (receiver, cmd_handle, cb) = callback::_closure_to_cb_ec();
+            let payment_method_name = CString::new(V2_PAYMENT_METHOD_NAME).unwrap();
+            unsafe {
+                payments_sys::indy_register_payment_method_v2(cmd_handle,
+                                                              payment_method_name.as_ptr(),
+                                                              Some(create_payment_address::handle),
+                                                              Some(add_request_fees::handle),
+                                                              Some(parse_response_with_fees::handle),
+                                                              Some(build_get_payment_sources_request::handle),
+                                                              Some(parse_get_payment_sources_response::handle),
+                                                              Some(build_payment_req::handle),
+                                                              Some(parse_payment_response::handle),
+                                                              Some(build_mint_req::handle),
+                                                              Some(build_set_txn_fees_req::handle),
+                                                              Some(build_get_txn_fees_req::handle),
+                                                              Some(parse_get_txn_fees_response::handle),
+                                                              Some(build_verify_payment_req::handle),
+                                                              Some(parse_verify_payment_response::handle),
+                                                              Some(sign_with_address::handle),
+                                                              Some(verify_with_address::handle),
+                                                              Some(estimate_request_fees::handle),
+                                                              Some(verify_payment_receipts::handle),
+                                                              cb,
+                );
+            }
+
+            receiver.recv().unwrap();
+
+            let (receiver, cmd_handle, cb) = callback::_closure_to_cb_ec();
+            unsafe {
+                payments_sys::indy_register_payment_receipt_proof_parser(cmd_handle,
+                                                                         payment_method_name.as_ptr(),
+                                                                         Some(parse_verify_payment_response_with_proof::handle),
+                                                                         cb,
+                );
+            }
+
+            receiver.recv().unwrap();
+
+            let (receiver, cmd_handle, cb) = callback::_closure_to_cb_ec();
+            unsafe {
+                payments_sys::indy_register_payment_history_handlers(cmd_handle,
+                                                                     payment_method_name.as_ptr(),
+                                                                     Some(build_get_payment_history_request::handle),
+                                                                     Some(parse_get_payment_history_response::handle),
+                                                                     cb,
+                );
+            }
+
+            receiver.recv().unwrap();
+        });
+    }
+
     pub mod create_payment_address {
         mocked_handler!(_wallet_handle: WalletHandle, _config: *const c_char);
     }
@@ -300,6 +411,26 @@ pub mod mock_method {
     pub mod verify_with_address {
         mocked_handler_bool!(_address: *const c_char, _message_raw: *const u8, _message_len: u32, _signature: *const u8, _signature_len: u32);
     }
+
+    pub mod estimate_request_fees {
+        mocked_handler_v2!(_wallet_handle: WalletHandle, _submitter_did: *const c_char, _req_json: *const c_char, _inputs_json: *const c_char);
+    }
+
+    pub mod verify_payment_receipts {
+        mocked_handler_v2!(_wallet_handle: WalletHandle, _submitter_did: *const c_char, _receipts_json: *const c_char);
+    }
+
+    pub mod parse_verify_payment_response_with_proof {
+        mocked_handler_v2!(_receipt: *const c_char, _resp_json: *const c_char);
+    }
+
+    pub mod build_get_payment_history_request {
+        mocked_handler_v2!(_wallet_handle: WalletHandle, _submitter_did: *const c_char, _payment_address: *const c_char, _from: i64);
+    }
+
+    pub mod parse_get_payment_history_response {
+        mocked_handler_v2!(_resp_json: *const c_char);
+    }
 }
 
 pub fn register_payment_method(payment_method_name: &str,
@@ -348,6 +479,25 @@ pub fn register_payment_method(payment_method_name: &str,
     super::results::result_to_empty(err, receiver)
 }
 
+pub fn unregister_payment_method(payment_method_name: &str) -> Result<(), ErrorCode> {
+    let (receiver, cmd_handle, cb) = callback::_closure_to_cb_ec();
+
+    let payment_method_name = CString::new(payment_method_name).unwrap();
+
+    let err = unsafe { payments_sys::indy_unregister_payment_method(cmd_handle, payment_method_name.as_ptr(), cb) };
+
+    super::results::result_to_empty(err, receiver)
+}
+
+#[cfg(feature = "null_payment_method")]
+pub fn register_null_payment_method() -> Result<(), ErrorCode> {
+    let (receiver, cmd_handle, cb) = callback::_closure_to_cb_ec();
+
+    let err = unsafe { payments_sys::indy_register_null_payment_method(cmd_handle, cb) };
+
+    super::results::result_to_empty(err, receiver)
+}
+
 pub fn create_payment_address(wallet_handle: WalletHandle, config: &str, payment_method: &str) -> Result<String, IndyError> {
     payments::create_payment_address(wallet_handle, payment_method, config).wait()
 }
@@ -356,10 +506,28 @@ pub fn list_payment_addresses(wallet_handle: WalletHandle) -> Result<String, Ind
     payments::list_payment_addresses(wallet_handle).wait()
 }
 
+pub fn get_payment_balances(wallet_handle: WalletHandle, pool_handle: PoolHandle) -> Result<String, IndyError> {
+    payments::get_payment_balances(wallet_handle, pool_handle).wait()
+}
+
 pub fn add_request_fees(wallet_handle: WalletHandle, submitter_did: Option<&str>, req_json: &str, inputs_json: &str, outputs_json: &str, extra: Option<&str>) -> Result<(String, String), IndyError> {
     payments::add_request_fees(wallet_handle, submitter_did, req_json, inputs_json, outputs_json, extra).wait()
 }
 
+pub fn prepare_request_with_fees(wallet_handle: WalletHandle,
+                                 pool_handle: PoolHandle,
+                                 submitter_did: &str,
+                                 req_json: &str,
+                                 inputs_json: &str,
+                                 outputs_json: &str,
+                                 extra: Option<&str>,
+                                 taa_digest: Option<&str>,
+                                 acc_mech_type: Option<&str>,
+                                 time_of_acceptance: u64) -> Result<(String, String), IndyError> {
+    payments::prepare_request_with_fees(wallet_handle, pool_handle, submitter_did, req_json, inputs_json, outputs_json, extra,
+                                        None, None, taa_digest, acc_mech_type, time_of_acceptance).wait()
+}
+
 #[allow(deprecated)]
 pub fn build_get_payment_sources_request(wallet_handle: WalletHandle, submitter_did: Option<&str>, payment_address: &str) -> Result<(String, String), IndyError> {
     payments::build_get_payment_sources_request(wallet_handle, submitter_did, payment_address).wait()
@@ -386,6 +554,14 @@ pub fn parse_get_payment_sources_with_from_response(payment_method: &str, resp_j
     payments::parse_get_payment_sources_with_from_response(payment_method, resp_json).wait()
 }
 
+pub fn build_get_payment_history_request(wallet_handle: WalletHandle, submitter_did: Option<&str>, payment_address: &str, from: Option<i64>) -> Result<(String, String), IndyError> {
+    payments::build_get_payment_history_request(wallet_handle, submitter_did, payment_address, from).wait()
+}
+
+pub fn parse_get_payment_history_response(payment_method: &str, resp_json: &str) -> Result<(String, Option<i64>), IndyError> {
+    payments::parse_get_payment_history_response(payment_method, resp_json).wait()
+}
+
 pub fn parse_payment_response(payment_method: &str, resp_json: &str) -> Result<String, IndyError> {
     payments::parse_payment_response(payment_method, resp_json).wait()
 }
@@ -434,3 +610,15 @@ pub fn sign_with_address(wallet_handle: WalletHandle, address: &str, message: &[
 pub fn verify_with_address(address: &str, message: &[u8], signature: &[u8]) -> Result<bool, IndyError> {
     payments::verify_with_address(address, message, signature).wait()
 }
+
+pub fn estimate_request_fees(wallet_handle: WalletHandle, submitter_did: Option<&str>, req_json: &str, inputs_json: &str) -> Result<String, IndyError> {
+    payments::estimate_request_fees(wallet_handle, submitter_did, req_json, inputs_json).wait()
+}
+
+pub fn verify_payment_receipts(wallet_handle: WalletHandle, submitter_did: Option<&str>, receipts_json: &str) -> Result<String, IndyError> {
+    payments::verify_payment_receipts(wallet_handle, submitter_did, receipts_json).wait()
+}
+
+pub fn verify_payment_receipt_with_proof(receipt: &str, resp_json: &str, bls_keys_json: &str) -> Result<String, IndyError> {
+    payments::verify_payment_receipt_with_proof(receipt, resp_json, bls_keys_json).wait()
+}
@@ -102,6 +102,18 @@ pub fn create_genesis_txn_file_for_test_pool_with_wrong_ips(pool_name: &str,
 }
 
 // Note that to be config valid it assumes genesis txt file is already exists
+pub fn test_pool_bls_keys() -> String {
+    let bls_keys = test::gen_txns().iter()
+        .map(|txn| {
+            let txn: serde_json::Value = serde_json::from_str(txn).unwrap();
+            let data = &txn["txn"]["data"]["data"];
+            (data["alias"].as_str().unwrap().to_string(), data["blskey"].clone())
+        })
+        .collect::<serde_json::Map<String, serde_json::Value>>();
+
+    serde_json::Value::Object(bls_keys).to_string()
+}
+
 pub fn pool_config_json(txn_file_path: &Path) -> String {
     let config = PoolConfig {
         genesis_txn: txn_file_path.to_string_lossy().to_string()
@@ -186,6 +198,10 @@ pub fn set_protocol_version(protocol_version: usize) -> Result<(), IndyError> {
     pool::set_protocol_version(protocol_version).wait()
 }
 
+pub fn get_reply_capture(pool_handle: PoolHandle) -> Result<String, IndyError> {
+    pool::get_pool_reply_capture(pool_handle).wait()
+}
+
 pub fn check_response_type(response: &str, _type: ResponseType) {
     let response: Response = serde_json::from_str(&response).unwrap();
     assert_eq!(response.op, _type);
@@ -11,7 +11,7 @@ use crate::utils::{callback, sequence, environment};
 use crate::utils::inmem_wallet::InmemWallet;
 
 use std::collections::HashSet;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 use std::ffi::CString;
 use super::libc::c_char;
 
@@ -73,6 +73,14 @@ pub fn register_wallet_storage(xtype: &str, force_create: bool) -> Result<(), Er
     super::results::result_to_empty(err as i32, receiver)
 }
 
+pub fn unregister_wallet_storage(xtype: &str) -> Result<(), IndyError> {
+    wallet::unregister_wallet_storage(xtype).wait()
+}
+
+pub fn set_wallet_storage_query_extensions(xtype: &str, extensions_json: &str) -> Result<(), IndyError> {
+    wallet::set_wallet_storage_query_extensions(xtype, extensions_json).wait()
+}
+
 pub fn create_wallet(config: &str, credentials: &str) -> Result<(), IndyError> {
     wallet::create_wallet(config, credentials).wait()
 }
@@ -121,6 +129,18 @@ pub fn export_wallet(wallet_handle: WalletHandle, export_config_json: &str) -> R
     wallet::export_wallet(wallet_handle, export_config_json).wait()
 }
 
+pub fn stream_export_wallet(wallet_handle: WalletHandle, export_config_json: &str) -> Result<Vec<Vec<u8>>, IndyError> {
+    let chunks = Arc::new(Mutex::new(Vec::new()));
+    let chunks_ = chunks.clone();
+
+    wallet::stream_export_wallet(wallet_handle, export_config_json, Box::new(move |data| {
+        chunks_.lock().unwrap().push(data.to_vec());
+    })).wait()?;
+
+    let chunks = chunks.lock().unwrap().clone();
+    Ok(chunks)
+}
+
 pub fn import_wallet(config: &str, credentials: &str, import_config: &str) -> Result<(), IndyError> {
     wallet::import_wallet(config, credentials, import_config).wait()
 }
@@ -7,7 +7,7 @@ extern crate indyrs as indy;
 extern crate indyrs as api;
 
 use crate::utils::inmem_wallet::InmemWallet;
-use crate::utils::{environment, wallet, test, did};
+use crate::utils::{environment, wallet, test, did, non_secrets};
 use crate::utils::constants::*;
 use crate::utils::Setup;
 
@@ -44,6 +44,96 @@ mod high_cases {
         }
     }
 
+    mod unregister_wallet_storage {
+        use super::*;
+
+        #[test]
+        fn indy_unregister_wallet_storage_works() {
+            let setup = Setup::empty();
+            InmemWallet::cleanup();
+
+            wallet::register_wallet_storage("unregister_inmem", false).unwrap();
+
+            let config = json!({"id": setup.name, "storage_type": "unregister_inmem"}).to_string();
+            wallet::create_wallet(&config, WALLET_CREDENTIALS).unwrap();
+            let wallet_handle = wallet::open_wallet(&config, WALLET_CREDENTIALS).unwrap();
+
+            let res = wallet::unregister_wallet_storage("unregister_inmem");
+            assert_code!(ErrorCode::CommonInvalidState, res);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+            wallet::unregister_wallet_storage("unregister_inmem").unwrap();
+
+            let res = wallet::open_wallet(&config, WALLET_CREDENTIALS);
+            assert_code!(ErrorCode::WalletUnknownTypeError, res);
+
+            InmemWallet::cleanup();
+        }
+
+        #[test]
+        fn indy_unregister_wallet_storage_works_for_default_type() {
+            Setup::empty();
+
+            let res = wallet::unregister_wallet_storage("default");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
+
+    mod set_wallet_storage_query_extensions {
+        use super::*;
+
+        #[test]
+        fn indy_set_wallet_storage_query_extensions_works() {
+            let setup = Setup::empty();
+            InmemWallet::cleanup();
+
+            wallet::register_wallet_storage("query_extensions_inmem", false).unwrap();
+
+            let config = json!({"id": setup.name, "storage_type": "query_extensions_inmem"}).to_string();
+            wallet::create_wallet(&config, WALLET_CREDENTIALS).unwrap();
+
+            let query = json!({"~tags": {"$contains": ["tag"]}}).to_string();
+
+            let wallet_handle = wallet::open_wallet(&config, WALLET_CREDENTIALS).unwrap();
+            let res = non_secrets::open_wallet_search(wallet_handle, TYPE, &query, "{}");
+            assert_code!(ErrorCode::WalletQueryError, res);
+            wallet::close_wallet(wallet_handle).unwrap();
+
+            wallet::set_wallet_storage_query_extensions("query_extensions_inmem", r#"["$contains"]"#).unwrap();
+
+            let wallet_handle = wallet::open_wallet(&config, WALLET_CREDENTIALS).unwrap();
+            let search_handle = non_secrets::open_wallet_search(wallet_handle, TYPE, &query, "{}").unwrap();
+            non_secrets::close_wallet_search(search_handle).unwrap();
+            wallet::close_wallet(wallet_handle).unwrap();
+
+            InmemWallet::cleanup();
+        }
+
+        #[test]
+        fn indy_set_wallet_storage_query_extensions_works_for_default_type() {
+            Setup::empty();
+
+            let res = wallet::set_wallet_storage_query_extensions("default", r#"["$contains"]"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_set_wallet_storage_query_extensions_works_for_unknown_type() {
+            Setup::empty();
+
+            let res = wallet::set_wallet_storage_query_extensions("unknown_query_extensions_type", r#"["$contains"]"#);
+            assert_code!(ErrorCode::WalletUnknownTypeError, res);
+        }
+
+        #[test]
+        fn indy_set_wallet_storage_query_extensions_works_for_invalid_json() {
+            Setup::empty();
+
+            let res = wallet::set_wallet_storage_query_extensions("default", r#"{"$contains": true}"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
+
     mod create_wallet {
         use super::*;
 
@@ -173,6 +263,26 @@ mod high_cases {
             wallet::delete_wallet(&config, WALLET_CREDENTIALS).unwrap();
         }
 
+        #[test]
+        fn indy_open_wallet_works_for_sqlite_pragmas() {
+            let setup = Setup::empty();
+
+            let config = json!({
+                "id": &setup.name,
+                "storage_config": {
+                    "journal_mode": "wal",
+                    "synchronous": "normal",
+                    "cache_size": -8000,
+                    "busy_timeout": 5000
+                }
+            }).to_string();
+
+            wallet::create_wallet(&config, WALLET_CREDENTIALS).unwrap();
+            let wallet_handle = wallet::open_wallet(&config, WALLET_CREDENTIALS).unwrap();
+            wallet::close_wallet(wallet_handle).unwrap();
+            wallet::delete_wallet(&config, WALLET_CREDENTIALS).unwrap();
+        }
+
         #[test]
         fn indy_open_wallet_works_for_plugged() {
             Setup::empty();
@@ -246,6 +356,40 @@ mod high_cases {
         }
     }
 
+    mod stream_export_wallet {
+        use super::*;
+
+        #[test]
+        fn indy_stream_export_wallet_works() {
+            let setup = Setup::empty();
+            let config = config(&setup.name);
+
+            let (wallet_handle, wallet_config) = wallet::create_and_open_default_wallet(&setup.name).unwrap();
+
+            let (did, _) = did::create_my_did(wallet_handle, "{}").unwrap();
+            did::set_did_metadata(wallet_handle, &did, METADATA).unwrap();
+            let did_with_meta = did::get_my_did_with_metadata(wallet_handle, &did).unwrap();
+
+            let chunks = wallet::stream_export_wallet(wallet_handle, r#"{"key": "export_key"}"#).unwrap();
+            assert!(!chunks.is_empty());
+
+            wallet::close_wallet(wallet_handle).unwrap();
+            wallet::delete_wallet(&wallet_config, WALLET_CREDENTIALS).unwrap();
+
+            // streamed content is imported as exported file
+            let path = wallet::export_wallet_path(&setup.name);
+            cleanup_file(&path);
+            fs::write(&path, chunks.concat()).unwrap();
+            wallet::import_wallet(&config, WALLET_CREDENTIALS, &wallet::prepare_export_wallet_config(&path)).unwrap();
+
+            let wallet_handle = wallet::open_wallet(&config, WALLET_CREDENTIALS).unwrap();
+            assert_eq!(did_with_meta, did::get_my_did_with_metadata(wallet_handle, &did).unwrap());
+
+            wallet::close_and_delete_wallet(wallet_handle, &config).unwrap();
+            cleanup_file(&path);
+        }
+    }
+
     mod import_wallet {
         use super::*;
 
@@ -567,6 +711,26 @@ mod medium_cases {
             let res = wallet::open_wallet(config, WALLET_CREDENTIALS);
             assert_code!(ErrorCode::CommonInvalidStructure, res);
         }
+
+        #[test]
+        fn indy_open_wallet_works_for_invalid_sqlite_pragma() {
+            let setup = Setup::empty();
+
+            let config = json!({"id": &setup.name}).to_string();
+            wallet::create_wallet(&config, WALLET_CREDENTIALS).unwrap();
+
+            let invalid_config = json!({
+                "id": &setup.name,
+                "storage_config": {
+                    "synchronous": "fast"
+                }
+            }).to_string();
+
+            let res = wallet::open_wallet(&invalid_config, WALLET_CREDENTIALS);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            wallet::delete_wallet(&config, WALLET_CREDENTIALS).unwrap();
+        }
     }
 
     mod close_wallet {
@@ -81,6 +81,11 @@ extern {
                                              master_secret_id: CString,
                                              cb: Option<ResponseStringStringCB>) -> Error;
 
+    pub fn indy_prover_parse_credential_offer(command_handle: CommandHandle,
+                                              cred_offer_json: CString,
+                                              cred_def_json: CString,
+                                              cb: Option<ResponseStringCB>) -> Error;
+
     pub fn indy_prover_set_credential_attr_tag_policy(command_handle: CommandHandle,
                                                       wallet_handle: WalletHandle,
                                                       cred_def_id: CString,
@@ -102,6 +107,25 @@ extern {
                                         rev_reg_def_json: CString,
                                         cb: Option<ResponseStringCB>) -> Error;
 
+    pub fn indy_prover_store_credential_with_tags(command_handle: CommandHandle,
+                                                  wallet_handle: WalletHandle,
+                                                  cred_id: CString,
+                                                  cred_req_metadata_json: CString,
+                                                  cred_json: CString,
+                                                  cred_def_json: CString,
+                                                  rev_reg_def_json: CString,
+                                                  tags_json: CString,
+                                                  cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_prover_import_w3c_credential(command_handle: CommandHandle,
+                                             wallet_handle: WalletHandle,
+                                             cred_id: CString,
+                                             cred_req_metadata_json: CString,
+                                             w3c_cred_json: CString,
+                                             cred_def_json: CString,
+                                             rev_reg_def_json: CString,
+                                             cb: Option<ResponseStringCB>) -> Error;
+
     pub fn indy_prover_get_credential(command_handle: CommandHandle,
                                       wallet_handle: WalletHandle,
                                       cred_id: CString,
@@ -171,6 +195,16 @@ extern {
                                       rev_regs_json: CString,
                                       cb: Option<ResponseBoolCB>) -> Error;
 
+    pub fn indy_verifier_verify_proof_with_pools(command_handle: CommandHandle,
+                                                 pools_json: CString,
+                                                 proof_request_json: CString,
+                                                 proof_json: CString,
+                                                 cb: Option<ResponseBoolCB>) -> Error;
+
+    pub fn indy_verifier_validate_proof_request(command_handle: CommandHandle,
+                                                proof_request_json: CString,
+                                                cb: Option<ResponseStringCB>) -> Error;
+
     pub fn indy_create_revocation_state(command_handle: CommandHandle,
                                         blob_storage_reader_handle: BlobStorageReaderHandle,
                                         rev_reg_def_json: CString,
@@ -188,6 +222,21 @@ extern {
                                         cred_rev_id: CString,
                                         cb: Option<ResponseStringCB>) -> Error;
 
+    pub fn indy_start_revocation_state_updater(command_handle: CommandHandle,
+                                               wallet_handle: WalletHandle,
+                                               pool_handle: PoolHandle,
+                                               blob_storage_reader_handle: BlobStorageReaderHandle,
+                                               config_json: CString,
+                                               cb: Option<ResponseI32CB>) -> Error;
+
+    pub fn indy_stop_revocation_state_updater(command_handle: CommandHandle,
+                                              updater_handle: IndyHandle,
+                                              cb: Option<ResponseEmptyCB>) -> Error;
+
+    pub fn indy_get_updated_revocation_states(command_handle: CommandHandle,
+                                              updater_handle: IndyHandle,
+                                              cb: Option<ResponseStringCB>) -> Error;
+
     pub fn indy_generate_nonce(command_handle: CommandHandle,
                                cb: Option<ResponseStringCB>) -> Error;
     pub fn indy_to_unqualified(command_handle: CommandHandle,
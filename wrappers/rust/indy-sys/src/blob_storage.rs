@@ -1,6 +1,6 @@
 use super::*;
 
-use {CString, Error, CommandHandle};
+use {BString, CString, Error, CommandHandle, IndyHandle};
 
 extern {
 
@@ -13,5 +13,65 @@ extern {
                                          type_: CString,
                                          config_json: CString,
                                          cb: Option<ResponseI32CB>) -> Error;
+
+    pub fn indy_close_blob_storage_reader(command_handle: CommandHandle,
+                                          handle: IndyHandle,
+                                          cb: Option<ResponseEmptyCB>) -> Error;
+
+    pub fn indy_close_blob_storage_writer(command_handle: CommandHandle,
+                                          handle: IndyHandle,
+                                          cb: Option<ResponseEmptyCB>) -> Error;
+
+    pub fn indy_read_tails_to_buffer(command_handle: CommandHandle,
+                                     handle: IndyHandle,
+                                     tails_location: CString,
+                                     tails_hash: CString,
+                                     cb: Option<ResponseOwnedSliceCB>) -> Error;
+
+    pub fn indy_register_blob_storage_reader(command_handle: CommandHandle,
+                                             type_: CString,
+                                             open_reader: Option<BlobStorageOpenReader>,
+                                             open_blob: Option<BlobStorageOpenBlob>,
+                                             read: Option<BlobStorageRead>,
+                                             close_blob: Option<BlobStorageCloseBlob>,
+                                             close_reader: Option<BlobStorageCloseReader>,
+                                             cb: Option<ResponseEmptyCB>) -> Error;
+
+    pub fn indy_register_blob_storage_writer(command_handle: CommandHandle,
+                                             type_: CString,
+                                             open_writer: Option<BlobStorageOpenWriter>,
+                                             create_blob: Option<BlobStorageCreateBlob>,
+                                             append: Option<BlobStorageAppend>,
+                                             finalize: Option<BlobStorageFinalize>,
+                                             close_blob: Option<BlobStorageCloseBlob>,
+                                             close_writer: Option<BlobStorageCloseWriter>,
+                                             cb: Option<ResponseEmptyCB>) -> Error;
 }
 
+pub type BlobStorageOpenReader = extern fn(config: CString,
+                                           reader_handle_p: *mut IndyHandle) -> Error;
+pub type BlobStorageOpenBlob = extern fn(reader_handle: IndyHandle,
+                                         hash: BString,
+                                         hash_len: u32,
+                                         location: CString,
+                                         blob_handle_p: *mut IndyHandle) -> Error;
+pub type BlobStorageRead = extern fn(blob_handle: IndyHandle,
+                                     offset: u64,
+                                     size: u32,
+                                     buf: *mut u8,
+                                     read_len_p: *mut u32) -> Error;
+pub type BlobStorageCloseBlob = extern fn(blob_handle: IndyHandle) -> Error;
+pub type BlobStorageCloseReader = extern fn(reader_handle: IndyHandle) -> Error;
+pub type BlobStorageOpenWriter = extern fn(config: CString,
+                                           writer_handle_p: *mut IndyHandle) -> Error;
+pub type BlobStorageCreateBlob = extern fn(writer_handle: IndyHandle,
+                                           blob_handle_p: *mut IndyHandle) -> Error;
+pub type BlobStorageAppend = extern fn(blob_handle: IndyHandle,
+                                       bytes: BString,
+                                       bytes_len: u32) -> Error;
+pub type BlobStorageFinalize = extern fn(blob_handle: IndyHandle,
+                                         hash: BString,
+                                         hash_len: u32,
+                                         location_p: *mut CString) -> Error;
+pub type BlobStorageCloseWriter = extern fn(writer_handle: IndyHandle) -> Error;
+
@@ -29,4 +29,66 @@ extern {
                                      wallet_handle: WalletHandle,
                                      options_json: CString,
                                      cb: Option<ResponseEmptyCB>) -> Error;
+
+    pub fn indy_get_rev_reg_def(command_handle: CommandHandle,
+                                pool_handle: PoolHandle,
+                                wallet_handle: WalletHandle,
+                                submitter_did: CString,
+                                id: CString,
+                                options_json: CString,
+                                cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_get_rev_reg(command_handle: CommandHandle,
+                            pool_handle: PoolHandle,
+                            wallet_handle: WalletHandle,
+                            submitter_did: CString,
+                            rev_reg_def_id: CString,
+                            timestamp: i64,
+                            options_json: CString,
+                            cb: Option<ResponseStringStringU64CB>) -> Error;
+
+    pub fn indy_get_rev_reg_delta(command_handle: CommandHandle,
+                                  pool_handle: PoolHandle,
+                                  wallet_handle: WalletHandle,
+                                  submitter_did: CString,
+                                  rev_reg_def_id: CString,
+                                  from: i64,
+                                  to: i64,
+                                  options_json: CString,
+                                  cb: Option<ResponseStringStringU64CB>) -> Error;
+
+    pub fn indy_purge_rev_reg_cache(command_handle: CommandHandle,
+                                    wallet_handle: WalletHandle,
+                                    options_json: CString,
+                                    cb: Option<ResponseEmptyCB>) -> Error;
+
+    pub fn indy_prefetch_ledger_cache(command_handle: CommandHandle,
+                                      pool_handle: PoolHandle,
+                                      wallet_handle: WalletHandle,
+                                      ids_json: CString,
+                                      cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_get_cache_stats(command_handle: CommandHandle,
+                                wallet_handle: WalletHandle,
+                                cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_set_cache_config(command_handle: CommandHandle,
+                                 wallet_handle: WalletHandle,
+                                 config_json: CString,
+                                 cb: Option<ResponseEmptyCB>) -> Error;
+
+    pub fn indy_register_local_schema(command_handle: CommandHandle,
+                                      wallet_handle: WalletHandle,
+                                      schema_json: CString,
+                                      cb: Option<ResponseEmptyCB>) -> Error;
+
+    pub fn indy_register_local_cred_def(command_handle: CommandHandle,
+                                        wallet_handle: WalletHandle,
+                                        cred_def_json: CString,
+                                        cb: Option<ResponseEmptyCB>) -> Error;
+
+    pub fn indy_register_local_rev_reg_def(command_handle: CommandHandle,
+                                           wallet_handle: WalletHandle,
+                                           revoc_reg_def_json: CString,
+                                           cb: Option<ResponseEmptyCB>) -> Error;
 }
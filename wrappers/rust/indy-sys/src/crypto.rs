@@ -76,5 +76,19 @@ extern {
                                jwe_msg: BString,
                                jwe_len: u32,
                                cb: Option<ResponseSliceCB>) -> Error;
+
+    pub fn indy_pack_message_buffer(command_handle: CommandHandle,
+                                    wallet_handle: WalletHandle,
+                                    message: BString,
+                                    message_len: u32,
+                                    receiver_keys: CString,
+                                    sender: CString,
+                                    cb: Option<ResponseOwnedSliceCB>) -> Error;
+
+    pub fn indy_unpack_message_buffer(command_handle: CommandHandle,
+                                      wallet_handle: WalletHandle,
+                                      jwe_msg: BString,
+                                      jwe_len: u32,
+                                      cb: Option<ResponseOwnedSliceCB>) -> Error;
 }
 
@@ -1,6 +1,6 @@
 use super::*;
 
-use {CString, Error, CommandHandle, WalletHandle, PoolHandle};
+use {BString, CString, Error, CommandHandle, WalletHandle, PoolHandle, SearchHandle};
 
 extern {
     pub fn indy_create_and_store_my_did(command_handle: CommandHandle,
@@ -19,6 +19,14 @@ extern {
                                    did: CString,
                                    cb: Option<ResponseEmptyCB>) -> Error;
 
+    pub fn indy_rotate_key(command_handle: CommandHandle,
+                           wallet_handle: WalletHandle,
+                           pool_handle: PoolHandle,
+                           did: CString,
+                           key_info: CString,
+                           endorser_did: CString,
+                           cb: Option<ResponseStringCB>) -> Error;
+
     pub fn indy_store_their_did(command_handle: CommandHandle,
                                 wallet_handle: WalletHandle,
                                 identity_json: CString,
@@ -68,6 +76,27 @@ extern {
                                        wallet_handle: WalletHandle,
                                        cb: Option<ResponseStringCB>) -> Error;
 
+    pub fn indy_set_my_did_tags(command_handle: CommandHandle,
+                                wallet_handle: WalletHandle,
+                                did: CString,
+                                tags_json: CString,
+                                cb: Option<ResponseEmptyCB>) -> Error;
+
+    pub fn indy_open_my_dids_search(command_handle: CommandHandle,
+                                    wallet_handle: WalletHandle,
+                                    query_json: CString,
+                                    options_json: CString,
+                                    cb: Option<ResponseI32UsizeCB>) -> Error;
+
+    pub fn indy_fetch_my_dids(command_handle: CommandHandle,
+                              search_handle: SearchHandle,
+                              count: usize,
+                              cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_close_my_dids_search(command_handle: CommandHandle,
+                                     search_handle: SearchHandle,
+                                     cb: Option<ResponseEmptyCB>) -> Error;
+
     pub fn indy_abbreviate_verkey(command_handle: CommandHandle,
                                   did: CString,
                                   full_verkey: CString,
@@ -78,5 +107,89 @@ extern {
                             method: CString,
                             prefix: CString,
                             cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_qualify_all_dids(command_handle: CommandHandle,
+                                 wallet_handle: WalletHandle,
+                                 method: CString,
+                                 options_json: CString,
+                                 cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_get_did_doc(command_handle: CommandHandle,
+                            wallet_handle: WalletHandle,
+                            pool_handle: PoolHandle,
+                            did: CString,
+                            cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_add_did_key(command_handle: CommandHandle,
+                            wallet_handle: WalletHandle,
+                            did: CString,
+                            key_json: CString,
+                            cb: Option<ResponseEmptyCB>) -> Error;
+
+    pub fn indy_remove_did_key(command_handle: CommandHandle,
+                               wallet_handle: WalletHandle,
+                               did: CString,
+                               key_id: CString,
+                               cb: Option<ResponseEmptyCB>) -> Error;
+
+    pub fn indy_add_did_service(command_handle: CommandHandle,
+                                wallet_handle: WalletHandle,
+                                did: CString,
+                                service_json: CString,
+                                cb: Option<ResponseEmptyCB>) -> Error;
+
+    pub fn indy_remove_did_service(command_handle: CommandHandle,
+                                   wallet_handle: WalletHandle,
+                                   did: CString,
+                                   service_id: CString,
+                                   cb: Option<ResponseEmptyCB>) -> Error;
+
+    pub fn indy_set_named_endpoint_for_did(command_handle: CommandHandle,
+                                           wallet_handle: WalletHandle,
+                                           did: CString,
+                                           endpoint_json: CString,
+                                           cb: Option<ResponseEmptyCB>) -> Error;
+
+    pub fn indy_remove_named_endpoint_for_did(command_handle: CommandHandle,
+                                              wallet_handle: WalletHandle,
+                                              did: CString,
+                                              name: CString,
+                                              cb: Option<ResponseEmptyCB>) -> Error;
+
+    pub fn indy_get_named_endpoints_for_did(command_handle: CommandHandle,
+                                            wallet_handle: WalletHandle,
+                                            pool_handle: PoolHandle,
+                                            did: CString,
+                                            cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_build_named_endpoints_attrib_request(command_handle: CommandHandle,
+                                                     wallet_handle: WalletHandle,
+                                                     submitter_did: CString,
+                                                     target_did: CString,
+                                                     cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_export_did(command_handle: CommandHandle,
+                           wallet_handle: WalletHandle,
+                           did: CString,
+                           recipient_vk: CString,
+                           cb: Option<ResponseSliceCB>) -> Error;
+
+    pub fn indy_import_did(command_handle: CommandHandle,
+                           wallet_handle: WalletHandle,
+                           recipient_vk: CString,
+                           bundle_raw: BString,
+                           bundle_len: u32,
+                           cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_deactivate_did(command_handle: CommandHandle,
+                               wallet_handle: WalletHandle,
+                               did: CString,
+                               cb: Option<ResponseEmptyCB>) -> Error;
+
+    pub fn indy_publish_did_deactivation(command_handle: CommandHandle,
+                                         wallet_handle: WalletHandle,
+                                         pool_handle: PoolHandle,
+                                         did: CString,
+                                         cb: Option<ResponseStringCB>) -> Error;
 }
 
@@ -1,6 +1,6 @@
 use super::*;
 
-use {CString, Error, CommandHandle, WalletHandle, PoolHandle};
+use {CString, Error, CommandHandle, IndyHandle, WalletHandle, PoolHandle};
 
 extern {
     pub fn indy_sign_and_submit_request(command_handle: CommandHandle,
@@ -15,6 +15,49 @@ extern {
                                request_json: CString,
                                cb: Option<ResponseStringCB>) -> Error;
 
+    pub fn indy_submit_request_with_options(command_handle: CommandHandle,
+                                            pool_handle: PoolHandle,
+                                            request_json: CString,
+                                            options_json: CString,
+                                            cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_submit_request_idempotent(command_handle: CommandHandle,
+                                          pool_handle: PoolHandle,
+                                          request_json: CString,
+                                          cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_submit_requests(command_handle: CommandHandle,
+                                pool_handle: PoolHandle,
+                                requests_json: CString,
+                                cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_get_txns(command_handle: CommandHandle,
+                         pool_handle: PoolHandle,
+                         submitter_did: CString,
+                         ledger_type: CString,
+                         from: i32,
+                         to: i32,
+                         cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_stream_txns(command_handle: CommandHandle,
+                            pool_handle: PoolHandle,
+                            submitter_did: CString,
+                            ledger_type: CString,
+                            from: i32,
+                            to: i32,
+                            txns_cb: Option<StreamTxnsCB>,
+                            cb: Option<ResponseEmptyCB>) -> Error;
+
+    pub fn indy_subscribe_ledger_events(command_handle: CommandHandle,
+                                        pool_handle: PoolHandle,
+                                        filter_json: CString,
+                                        event_cb: Option<LedgerEventCB>,
+                                        cb: Option<ResponseI32CB>) -> Error;
+
+    pub fn indy_unsubscribe_ledger_events(command_handle: CommandHandle,
+                                          subscription_handle: IndyHandle,
+                                          cb: Option<ResponseEmptyCB>) -> Error;
+
     pub fn indy_submit_action(command_handle: CommandHandle,
                               pool_handle: PoolHandle,
                               request_json: CString,
@@ -28,12 +71,27 @@ extern {
                              request_json: CString,
                              cb: Option<ResponseStringCB>) -> Error;
 
+    pub fn indy_force_sign_request(command_handle: CommandHandle,
+                                   wallet_handle: WalletHandle,
+                                   submitter_did: CString,
+                                   request_json: CString,
+                                   cb: Option<ResponseStringCB>) -> Error;
+
     pub fn indy_multi_sign_request(command_handle: CommandHandle,
                                    wallet_handle: WalletHandle,
                                    submitter_did: CString,
                                    request_json: CString,
                                    cb: Option<ResponseStringCB>) -> Error;
 
+    pub fn indy_merge_multi_signed_requests(command_handle: CommandHandle,
+                                            requests_json: CString,
+                                            cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_check_request_signatures(command_handle: CommandHandle,
+                                         pool_handle: PoolHandle,
+                                         request_json: CString,
+                                         cb: Option<ResponseStringCB>) -> Error;
+
     pub fn indy_build_get_ddo_request(command_handle: CommandHandle,
                                       submitter_did: CString,
                                       target_did: CString,
@@ -47,15 +105,60 @@ extern {
                                   role: CString,
                                   cb: Option<ResponseStringCB>) -> Error;
 
+    pub fn indy_build_nym_request_with_data(command_handle: CommandHandle,
+                                            submitter_did: CString,
+                                            target_did: CString,
+                                            nym_data_json: CString,
+                                            cb: Option<ResponseStringCB>) -> Error;
+
     pub fn indy_build_get_nym_request(command_handle: CommandHandle,
                                       submitter_did: CString,
                                       target_did: CString,
                                       cb: Option<ResponseStringCB>) -> Error;
 
+    pub fn indy_build_get_historical_nym_request(command_handle: CommandHandle,
+                                                 submitter_did: CString,
+                                                 target_did: CString,
+                                                 seq_no: i64,
+                                                 timestamp: i64,
+                                                 cb: Option<ResponseStringCB>) -> Error;
+
     pub fn indy_parse_get_nym_response(command_handle: CommandHandle,
                                        get_nym_response: CString,
                                        cb: Option<ResponseStringCB>) -> Error;
 
+    pub fn indy_parse_get_attrib_response(command_handle: CommandHandle,
+                                          get_attrib_response: CString,
+                                          cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_parse_get_txn_response(command_handle: CommandHandle,
+                                       get_txn_response: CString,
+                                       cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_parse_get_auth_rule_response(command_handle: CommandHandle,
+                                             get_auth_rule_response: CString,
+                                             cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_parse_get_validator_info_response(command_handle: CommandHandle,
+                                                  get_validator_info_response: CString,
+                                                  cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_parse_get_validator_info_summary_response(command_handle: CommandHandle,
+                                                          get_validator_info_response: CString,
+                                                          cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_parse_pool_upgrade_status_response(command_handle: CommandHandle,
+                                                   get_validator_info_response: CString,
+                                                   cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_parse_get_txn_author_agreement_response(command_handle: CommandHandle,
+                                                        get_txn_author_agreement_response: CString,
+                                                        cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_parse_get_acceptance_mechanisms_response(command_handle: CommandHandle,
+                                                         get_acceptance_mechanisms_response: CString,
+                                                         cb: Option<ResponseStringCB>) -> Error;
+
     pub fn indy_build_attrib_request(command_handle: CommandHandle,
                                      submitter_did: CString,
                                      target_did: CString,
@@ -72,6 +175,28 @@ extern {
                                          enc: CString,
                                          cb: Option<ResponseStringCB>) -> Error;
 
+    pub fn indy_build_get_historical_attrib_request(command_handle: CommandHandle,
+                                                    submitter_did: CString,
+                                                    target_did: CString,
+                                                    raw: CString,
+                                                    hash: CString,
+                                                    enc: CString,
+                                                    seq_no: i64,
+                                                    timestamp: i64,
+                                                    cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_build_set_endpoint_request(command_handle: CommandHandle,
+                                           submitter_did: CString,
+                                           target_did: CString,
+                                           address: CString,
+                                           transport_key: CString,
+                                           cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_build_get_endpoint_request(command_handle: CommandHandle,
+                                           submitter_did: CString,
+                                           target_did: CString,
+                                           cb: Option<ResponseStringCB>) -> Error;
+
     pub fn indy_build_schema_request(command_handle: CommandHandle,
                                      submitter_did: CString,
                                      data: CString,
@@ -142,6 +267,12 @@ extern {
                                            package: CString,
                                            cb: Option<ResponseStringCB>) -> Error;
 
+    pub fn indy_build_scheduled_pool_upgrade_request(command_handle: CommandHandle,
+                                                     pool_handle: PoolHandle,
+                                                     submitter_did: CString,
+                                                     upgrade_json: CString,
+                                                     cb: Option<ResponseStringCB>) -> Error;
+
     pub fn indy_build_revoc_reg_def_request(command_handle: CommandHandle,
                                             submitter_did: CString,
                                             data: CString,
@@ -190,10 +321,44 @@ extern {
                                                    free: Option<CustomFree>,
                                                    cb: Option<ResponseEmptyCB>) -> Error;
 
+    pub fn indy_register_ledger_transaction_builder(command_handle: CommandHandle,
+                                                    txn_type: CString,
+                                                    builder: Option<CustomTransactionBuilder>,
+                                                    parser: Option<CustomResponseParser>,
+                                                    free: Option<CustomFree>,
+                                                    cb: Option<ResponseEmptyCB>) -> Error;
+
+    pub fn indy_register_ledger_middleware(command_handle: CommandHandle,
+                                           request_middleware: Option<CustomRequestMiddleware>,
+                                           reply_middleware: Option<CustomReplyMiddleware>,
+                                           free: Option<CustomFree>,
+                                           cb: Option<ResponseI32CB>) -> Error;
+
+    pub fn indy_unregister_ledger_middleware(command_handle: CommandHandle,
+                                             middleware_handle: IndyHandle,
+                                             cb: Option<ResponseEmptyCB>) -> Error;
+
+    pub fn indy_build_custom_request(command_handle: CommandHandle,
+                                     submitter_did: CString,
+                                     txn_type: CString,
+                                     data_json: CString,
+                                     cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_parse_custom_response(command_handle: CommandHandle,
+                                      txn_type: CString,
+                                      response_json: CString,
+                                      cb: Option<ResponseStringCB>) -> Error;
+
     pub fn indy_get_response_metadata(command_handle: CommandHandle,
                                       response: CString,
                                       cb: Option<ResponseStringCB>) -> Error;
 
+    pub fn indy_verify_state_proof(command_handle: CommandHandle,
+                                   request_json: CString,
+                                   response_json: CString,
+                                   bls_keys_json: CString,
+                                   cb: Option<ResponseBoolCB>) -> Error;
+
     pub fn indy_build_auth_rule_request(command_handle: CommandHandle,
                                         submitter_did: CString,
                                         txn_type: CString,
@@ -218,6 +383,36 @@ extern {
                                             new_value: CString,
                                             cb: Option<ResponseStringCB>) -> Error;
 
+    pub fn indy_build_role_constraint(command_handle: CommandHandle,
+                                      role: CString,
+                                      sig_count: u32,
+                                      need_to_be_owner: bool,
+                                      off_ledger_signature: bool,
+                                      metadata_json: CString,
+                                      cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_build_combined_constraint(command_handle: CommandHandle,
+                                          combination: CString,
+                                          constraints_json: CString,
+                                          cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_evaluate_auth_rules(command_handle: CommandHandle,
+                                    auth_rules_json: CString,
+                                    action_json: CString,
+                                    signers_json: CString,
+                                    cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_get_auth_rules(command_handle: CommandHandle,
+                               pool_handle: PoolHandle,
+                               submitter_did: CString,
+                               page_options_json: CString,
+                               cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_diff_auth_rules(command_handle: CommandHandle,
+                                current_auth_rules_json: CString,
+                                proposed_auth_rules_json: CString,
+                                cb: Option<ResponseStringCB>) -> Error;
+
     pub fn indy_build_txn_author_agreement_request(command_handle: CommandHandle,
                                                    submitter_did: CString,
                                                    text: CString,
@@ -258,11 +453,24 @@ extern {
                                                                   time_of_acceptance: u64,
                                                                   cb: Option<ResponseStringCB>) -> Error;
 
+    pub fn indy_prepare_request_with_taa(command_handle: CommandHandle,
+                                         pool_handle: PoolHandle,
+                                         wallet_handle: WalletHandle,
+                                         submitter_did: CString,
+                                         request_json: CString,
+                                         mechanism: CString,
+                                         cb: Option<ResponseStringCB>) -> Error;
+
     pub fn indy_append_request_endorser(command_handle: CommandHandle,
                                         request_json: CString,
                                         endorser_did: CString,
                                         cb: Option<ResponseStringCB>) -> Error;
 
+    pub fn indy_set_request_protocol_version(command_handle: CommandHandle,
+                                             request_json: CString,
+                                             protocol_version: usize,
+                                             cb: Option<ResponseStringCB>) -> Error;
+
     pub fn indy_build_ledgers_freeze_request(command_handle: CommandHandle,
                                        submitter_did: CString,
                                        ledgers_ids: CString,
@@ -271,7 +479,18 @@ extern {
     pub fn indy_build_get_frozen_ledgers_request(command_handle: CommandHandle,
                                         submitter_did: CString,
                                         cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_parse_get_frozen_ledgers_response(command_handle: CommandHandle,
+                                                  get_frozen_ledgers_response: CString,
+                                                  cb: Option<ResponseStringCB>) -> Error;
 }
 
 pub type CustomTransactionParser = extern fn(reply_from_node: CString, parsed_sp: *mut CString) -> Error;
 pub type CustomFree = extern fn(data: CString) -> Error;
+pub type CustomTransactionBuilder = extern fn(data_json: CString, operation_json: *mut CString) -> Error;
+pub type CustomResponseParser = extern fn(response_json: CString, parsed_json: *mut CString) -> Error;
+pub type CustomRequestMiddleware = extern fn(request_json: CString, out_request_json: *mut CString) -> Error;
+pub type CustomReplyMiddleware = extern fn(reply_json: CString, out_reply_json: *mut CString) -> Error;
+
+pub type StreamTxnsCB = extern fn(xcommand_handle: CommandHandle, txns_json: CString);
+pub type LedgerEventCB = extern fn(subscription_handle: IndyHandle, event_json: CString);
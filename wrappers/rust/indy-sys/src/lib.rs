@@ -59,12 +59,31 @@ pub type ResponseStringCB = extern fn(xcommand_handle: CommandHandle, err: Error
 pub type ResponseStringStringCB = extern fn(xcommand_handle: CommandHandle, err: Error, str1: CString, str2: CString);
 pub type ResponseStringStringStringCB = extern fn(xcommand_handle: CommandHandle, err: Error, str1: CString, str2: CString, str3: CString);
 pub type ResponseSliceCB = extern fn(xcommand_handle: CommandHandle, err: Error, raw: BString, len: u32);
+pub type ResponseOwnedSliceCB = extern fn(xcommand_handle: CommandHandle, err: Error, raw: *mut u8, len: u32);
 pub type ResponseStringSliceCB = extern fn(xcommand_handle: CommandHandle, err: Error, str1: CString, raw: BString, len: u32);
 pub type ResponseStringStringU64CB = extern fn(xcommand_handle: CommandHandle, err: Error, arg1: CString, arg2: CString, arg3: u64);
 pub type ResponseStringI64CB = extern fn(xcommand_handle: CommandHandle, err: Error, arg1: CString, arg3: i64);
+pub type PreCommandHookCB = extern fn(command_name: CString, params_digest: CString);
+pub type PostCommandHookCB = extern fn(command_name: CString, params_digest: CString, duration_ms: u64, err: Error);
+pub type AuditCB = extern fn(context: *const CVoid, event_json: CString);
 
 extern {
     pub fn indy_set_runtime_config(config: CString) -> Error;
 
+    pub fn indy_register_command_hook(pre_cb: Option<PreCommandHookCB>,
+                                      post_cb: Option<PostCommandHookCB>) -> Error;
+
+    pub fn indy_register_audit_callback(context: *const CVoid,
+                                        audit_cb: Option<AuditCB>) -> Error;
+
+    pub fn indy_set_audit_context(context_json: CString) -> Error;
+
+    pub fn indy_shutdown(command_handle: CommandHandle,
+                         force: bool,
+                         timeout_ms: u64,
+                         cb: Option<ResponseEmptyCB>) -> Error;
+
+    pub fn indy_free_buffer(data: *mut u8, data_len: u32);
+
     pub fn indy_get_current_error(error_json_p: *mut CString);
 }
\ No newline at end of file
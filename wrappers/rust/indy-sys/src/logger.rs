@@ -15,6 +15,8 @@ extern {
 
     pub fn indy_set_log_max_lvl(max_lvl: u32) -> Error;
 
+    pub fn indy_set_log_filter(filter: CString) -> Error;
+
     pub fn indy_set_default_logger(pattern: CString) -> Error;
 
     pub fn indy_get_logger(context_p: *mut CVoid,
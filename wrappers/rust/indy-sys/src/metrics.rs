@@ -5,4 +5,16 @@ use {Error, CommandHandle};
 extern {
     pub fn indy_collect_metrics(command_handle: CommandHandle,
                                 cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_collect_metrics_prometheus(command_handle: CommandHandle,
+                                           cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_collect_metrics_delta(command_handle: CommandHandle,
+                                      cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_reset_metrics(command_handle: CommandHandle,
+                              cb: Option<ResponseEmptyCB>) -> Error;
+
+    pub fn indy_list_handles(command_handle: CommandHandle,
+                             cb: Option<ResponseStringCB>) -> Error;
 }
@@ -19,6 +19,21 @@ extern {
                                            value: CString,
                                            cb: Option<ResponseEmptyCB>) -> Error;
 
+    pub fn indy_compare_and_swap_wallet_record_value(command_handle: CommandHandle,
+                                                     wallet_handle: WalletHandle,
+                                                     type_: CString,
+                                                     id: CString,
+                                                     expected_value_hash: CString,
+                                                     value: CString,
+                                                     cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_modify_wallet_record_value(command_handle: CommandHandle,
+                                           wallet_handle: WalletHandle,
+                                           type_: CString,
+                                           id: CString,
+                                           modify_cb: Option<ModifyWalletRecordValueCB>,
+                                           cb: Option<ResponseStringCB>) -> Error;
+
     pub fn indy_update_wallet_record_tags(command_handle: CommandHandle,
                                           wallet_handle: WalletHandle,
                                           type_: CString,
@@ -46,6 +61,12 @@ extern {
                                      id: CString,
                                      cb: Option<ResponseEmptyCB>) -> Error;
 
+    pub fn indy_upsert_wallet_records(command_handle: CommandHandle,
+                                      wallet_handle: WalletHandle,
+                                      type_: CString,
+                                      records_json: CString,
+                                      cb: Option<ResponseStringCB>) -> Error;
+
     pub fn indy_get_wallet_record(command_handle: CommandHandle,
                                   wallet_handle: WalletHandle,
                                   type_: CString,
@@ -53,6 +74,16 @@ extern {
                                   options_json: CString,
                                   cb: Option<ResponseStringCB>) -> Error;
 
+    pub fn indy_get_wallet_record_types(command_handle: CommandHandle,
+                                        wallet_handle: WalletHandle,
+                                        cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_explain_wallet_search(command_handle: CommandHandle,
+                                      wallet_handle: WalletHandle,
+                                      type_: CString,
+                                      query_json: CString,
+                                      cb: Option<ResponseStringCB>) -> Error;
+
     pub fn indy_open_wallet_search(command_handle: CommandHandle,
                                    wallet_handle: WalletHandle,
                                    type_: CString,
@@ -69,4 +100,51 @@ extern {
     pub fn indy_close_wallet_search(command_handle: CommandHandle,
                                     wallet_search_handle: SearchHandle,
                                     cb: Option<ResponseEmptyCB>) -> Error;
+
+    pub fn indy_stream_wallet_records(command_handle: CommandHandle,
+                                      wallet_handle: WalletHandle,
+                                      type_: CString,
+                                      query_json: CString,
+                                      options_json: CString,
+                                      chunk_size: u32,
+                                      records_cb: Option<StreamWalletRecordsCB>,
+                                      cb: Option<ResponseEmptyCB>) -> Error;
+
+    pub fn indy_enable_wallet_record_history(command_handle: CommandHandle,
+                                             wallet_handle: WalletHandle,
+                                             type_: CString,
+                                             config_json: CString,
+                                             cb: Option<ResponseEmptyCB>) -> Error;
+
+    pub fn indy_disable_wallet_record_history(command_handle: CommandHandle,
+                                              wallet_handle: WalletHandle,
+                                              type_: CString,
+                                              cb: Option<ResponseEmptyCB>) -> Error;
+
+    pub fn indy_get_wallet_record_revisions(command_handle: CommandHandle,
+                                            wallet_handle: WalletHandle,
+                                            type_: CString,
+                                            id: CString,
+                                            cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_get_wallet_record_revision(command_handle: CommandHandle,
+                                           wallet_handle: WalletHandle,
+                                           type_: CString,
+                                           id: CString,
+                                           revision: u32,
+                                           cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_prune_wallet_record_history(command_handle: CommandHandle,
+                                            wallet_handle: WalletHandle,
+                                            type_: CString,
+                                            id: CString,
+                                            keep_last: u32,
+                                            cb: Option<ResponseEmptyCB>) -> Error;
 }
+
+pub type StreamWalletRecordsCB = extern fn(command_handle_: CommandHandle,
+                                           records_json: CString);
+
+pub type ModifyWalletRecordValueCB = extern fn(command_handle_: CommandHandle,
+                                               current_value: CString,
+                                               new_value_p: *mut CString) -> Error;
@@ -1,6 +1,6 @@
 use super::*;
 
-use {CString, Error, CommandHandle, WalletHandle};
+use {CString, Error, CommandHandle, WalletHandle, SearchHandle};
 
 extern {
 
@@ -30,5 +30,61 @@ extern {
                                       their_did: CString,
                                       metadata: CString,
                                       cb: Option<ResponseEmptyCB>) -> Error;
+
+    pub fn indy_update_pairwise(command_handle: CommandHandle,
+                                wallet_handle: WalletHandle,
+                                their_did: CString,
+                                new_their_did: CString,
+                                cb: Option<ResponseEmptyCB>) -> Error;
+
+    pub fn indy_delete_pairwise(command_handle: CommandHandle,
+                                wallet_handle: WalletHandle,
+                                their_did: CString,
+                                cb: Option<ResponseEmptyCB>) -> Error;
+
+    pub fn indy_link_pairwise_record(command_handle: CommandHandle,
+                                     wallet_handle: WalletHandle,
+                                     their_did: CString,
+                                     record_type: CString,
+                                     record_id: CString,
+                                     cb: Option<ResponseEmptyCB>) -> Error;
+
+    pub fn indy_unlink_pairwise_record(command_handle: CommandHandle,
+                                       wallet_handle: WalletHandle,
+                                       their_did: CString,
+                                       record_type: CString,
+                                       record_id: CString,
+                                       cb: Option<ResponseEmptyCB>) -> Error;
+
+    pub fn indy_get_pairwise_links(command_handle: CommandHandle,
+                                   wallet_handle: WalletHandle,
+                                   their_did: CString,
+                                   record_type: CString,
+                                   cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_set_pairwise_meta(command_handle: CommandHandle,
+                                  wallet_handle: WalletHandle,
+                                  their_did: CString,
+                                  meta_json: CString,
+                                  cb: Option<ResponseEmptyCB>) -> Error;
+
+    pub fn indy_migrate_pairwise_meta(command_handle: CommandHandle,
+                                      wallet_handle: WalletHandle,
+                                      cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_open_pairwise_search(command_handle: CommandHandle,
+                                     wallet_handle: WalletHandle,
+                                     query_json: CString,
+                                     options_json: CString,
+                                     cb: Option<ResponseI32UsizeCB>) -> Error;
+
+    pub fn indy_fetch_pairwise(command_handle: CommandHandle,
+                               search_handle: SearchHandle,
+                               count: usize,
+                               cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_close_pairwise_search(command_handle: CommandHandle,
+                                      search_handle: SearchHandle,
+                                      cb: Option<ResponseEmptyCB>) -> Error;
 }
 
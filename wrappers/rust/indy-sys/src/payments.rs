@@ -1,6 +1,6 @@
 use super::*;
 
-use {CString, Error, CommandHandle, WalletHandle};
+use {CString, Error, CommandHandle, WalletHandle, PoolHandle};
 
 extern {
     pub fn indy_register_payment_method(command_handle: CommandHandle,
@@ -22,6 +22,39 @@ extern {
                                         verify_with_address: Option<VerifyWithAddressCB>,
                                         cb: Option<ResponseEmptyCB>) -> Error;
 
+    pub fn indy_register_payment_method_v2(command_handle: CommandHandle,
+                                           payment_method: CString,
+                                           create_payment_address: Option<CreatePaymentAddressCB>,
+                                           add_request_fees: Option<AddRequestFeesCB>,
+                                           parse_response_with_fees: Option<ParseResponseWithFeesCB>,
+                                           build_get_payment_sources_request: Option<BuildGetPaymentSourcesRequestCB>,
+                                           parse_get_payment_sources_response: Option<ParseGetPaymentSourcesResponseCB>,
+                                           build_payment_req: Option<BuildPaymentReqCB>,
+                                           parse_payment_response: Option<ParsePaymentResponseCB>,
+                                           build_mint_req: Option<BuildMintReqCB>,
+                                           build_set_txn_fees_req: Option<BuildSetTxnFeesReqCB>,
+                                           build_get_txn_fees_req: Option<BuildGetTxnFeesReqCB>,
+                                           parse_get_txn_fees_response: Option<ParseGetTxnFeesResponseCB>,
+                                           build_verify_payment_req: Option<BuildVerifyPaymentReqCB>,
+                                           parse_verify_payment_response: Option<ParseVerifyPaymentResponseCB>,
+                                           sign_with_address: Option<SignWithAddressCB>,
+                                           verify_with_address: Option<VerifyWithAddressCB>,
+                                           estimate_request_fees: Option<EstimateRequestFeesCB>,
+                                           verify_payment_receipts: Option<VerifyPaymentReceiptsCB>,
+                                           cb: Option<ResponseEmptyCB>) -> Error;
+
+    pub fn indy_register_payment_receipt_proof_parser(command_handle: CommandHandle,
+                                                      payment_method: CString,
+                                                      parse_verify_payment_response_with_proof: Option<ParseVerifyPaymentResponseWithProofCB>,
+                                                      cb: Option<ResponseEmptyCB>) -> Error;
+
+    pub fn indy_unregister_payment_method(command_handle: CommandHandle,
+                                          payment_method: CString,
+                                          cb: Option<ResponseEmptyCB>) -> Error;
+
+    pub fn indy_register_null_payment_method(command_handle: CommandHandle,
+                                             cb: Option<ResponseEmptyCB>) -> Error;
+
     pub fn indy_create_payment_address(command_handle: CommandHandle,
                                        wallet_handle: WalletHandle,
                                        payment_method: CString,
@@ -32,6 +65,11 @@ extern {
                                        wallet_handle: WalletHandle,
                                        cb: Option<ResponseStringCB>) -> Error;
 
+    pub fn indy_get_payment_balances(command_handle: CommandHandle,
+                                     wallet_handle: WalletHandle,
+                                     pool_handle: PoolHandle,
+                                     cb: Option<ResponseStringCB>) -> Error;
+
     pub fn indy_add_request_fees(command_handle: CommandHandle,
                                  wallet_handle: WalletHandle,
                                  submitter_did: CString,
@@ -41,6 +79,21 @@ extern {
                                  extra: CString,
                                  cb: Option<ResponseStringStringCB>) -> Error;
 
+    pub fn indy_prepare_request_with_fees(command_handle: CommandHandle,
+                                          wallet_handle: WalletHandle,
+                                          pool_handle: PoolHandle,
+                                          submitter_did: CString,
+                                          req_json: CString,
+                                          inputs_json: CString,
+                                          outputs_json: CString,
+                                          extra: CString,
+                                          text: CString,
+                                          version: CString,
+                                          taa_digest: CString,
+                                          mechanism: CString,
+                                          time: u64,
+                                          cb: Option<ResponseStringStringCB>) -> Error;
+
     pub fn indy_parse_response_with_fees(command_handle: CommandHandle,
                                          payment_method: CString,
                                          resp_json: CString,
@@ -58,6 +111,24 @@ extern {
                                                    resp_json: CString,
                                                    cb: Option<ResponseStringI64CB>) -> Error;
 
+    pub fn indy_register_payment_history_handlers(command_handle: CommandHandle,
+                                                  payment_method: CString,
+                                                  build_get_payment_history_request: Option<BuildGetPaymentHistoryRequestCB>,
+                                                  parse_get_payment_history_response: Option<ParseGetPaymentHistoryResponseCB>,
+                                                  cb: Option<ResponseEmptyCB>) -> Error;
+
+    pub fn indy_build_get_payment_history_request(command_handle: CommandHandle,
+                                                  wallet_handle: WalletHandle,
+                                                  submitter_did: CString,
+                                                  payment_address: CString,
+                                                  from: i64,
+                                                  cb: Option<ResponseStringStringCB>) -> Error;
+
+    pub fn indy_parse_get_payment_history_response(command_handle: CommandHandle,
+                                                   payment_method: CString,
+                                                   resp_json: CString,
+                                                   cb: Option<ResponseStringI64CB>) -> Error;
+
     pub fn indy_build_get_payment_sources_request(command_handle: CommandHandle,
                                                   wallet_handle: WalletHandle,
                                                   submitter_did: CString,
@@ -145,6 +216,25 @@ extern {
                                     signature_raw: BString,
                                     signature_len: u32,
                                     cb: Option<ResponseBoolCB>) -> Error;
+
+    pub fn indy_estimate_request_fees(command_handle: CommandHandle,
+                                      wallet_handle: WalletHandle,
+                                      submitter_did: CString,
+                                      req_json: CString,
+                                      inputs_json: CString,
+                                      cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_verify_payment_receipts(command_handle: CommandHandle,
+                                        wallet_handle: WalletHandle,
+                                        submitter_did: CString,
+                                        receipts_json: CString,
+                                        cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_verify_payment_receipt_with_proof(command_handle: CommandHandle,
+                                                  receipt: CString,
+                                                  resp_json: CString,
+                                                  bls_keys_json: CString,
+                                                  cb: Option<ResponseStringCB>) -> Error;
     
 }
 
@@ -246,3 +336,36 @@ pub type VerifyWithAddressCB = extern fn (command_handle: i32, address: CString,
                                           signature_raw: BString, signature_len: u32,
                                           cb: Option<extern fn(command_handle: i32, err: Error, result: bool)>) -> Error;
 
+pub type PaymentMethodV2CB = extern fn(command_handle_: CommandHandle,
+                                       err: Error,
+                                       error_json: CString,
+                                       result_json: CString) -> Error;
+
+pub type EstimateRequestFeesCB = extern fn(command_handle: CommandHandle,
+                                           wallet_handle: WalletHandle,
+                                           submitter_did: CString,
+                                           req_json: CString,
+                                           inputs_json: CString,
+                                           cb: Option<PaymentMethodV2CB>) -> Error;
+
+pub type VerifyPaymentReceiptsCB = extern fn(command_handle: CommandHandle,
+                                             wallet_handle: WalletHandle,
+                                             submitter_did: CString,
+                                             receipts_json: CString,
+                                             cb: Option<PaymentMethodV2CB>) -> Error;
+
+pub type BuildGetPaymentHistoryRequestCB = extern fn(command_handle: CommandHandle,
+                                                     wallet_handle: WalletHandle,
+                                                     submitter_did: CString,
+                                                     payment_address: CString,
+                                                     from: i64,
+                                                     cb: Option<PaymentMethodV2CB>) -> Error;
+
+pub type ParseGetPaymentHistoryResponseCB = extern fn(command_handle: CommandHandle,
+                                                      resp_json: CString,
+                                                      cb: Option<PaymentMethodV2CB>) -> Error;
+
+pub type ParseVerifyPaymentResponseWithProofCB = extern fn(command_handle: CommandHandle,
+                                                           receipt: CString,
+                                                           resp_json: CString,
+                                                           cb: Option<PaymentMethodV2CB>) -> Error;
@@ -32,5 +32,9 @@ extern {
     pub fn indy_set_protocol_version(command_handle: CommandHandle,
                                      protocol_version: usize,
                                      cb: Option<ResponseEmptyCB>) -> Error;
+
+    pub fn indy_get_pool_reply_capture(command_handle: CommandHandle,
+                                       handle: PoolHandle,
+                                       cb: Option<ResponseStringCB>) -> Error;
 }
 
@@ -32,6 +32,26 @@ extern {
                                         free_search: Option<WalletFreeSearch>,
                                         cb: Option<ResponseEmptyCB>) -> Error;
 
+    pub fn indy_unregister_wallet_storage(command_handle: CommandHandle,
+                                          type_: CString,
+                                          cb: Option<ResponseEmptyCB>) -> Error;
+
+    pub fn indy_set_wallet_storage_query_extensions(command_handle: CommandHandle,
+                                                    type_: CString,
+                                                    extensions_json: CString,
+                                                    cb: Option<ResponseEmptyCB>) -> Error;
+
+    pub fn indy_export_wallet_to_buffer(command_handle: CommandHandle,
+                                        wallet_handle: WalletHandle,
+                                        export_config: CString,
+                                        cb: Option<ResponseOwnedSliceCB>) -> Error;
+
+    pub fn indy_stream_export_wallet(command_handle: CommandHandle,
+                                     wallet_handle: WalletHandle,
+                                     export_config: CString,
+                                     data_cb: Option<StreamExportWalletCB>,
+                                     cb: Option<ResponseEmptyCB>) -> Error;
+
     pub fn indy_create_wallet(command_handle: CommandHandle,
                               config: CString,
                               credentials: CString,
@@ -147,3 +167,6 @@ pub type WalletFetchSearchNextRecord = extern fn(storage_handle: StorageHandle,
                                                  record_handle_p: *mut RecordHandle) -> Error;
 pub type WalletFreeSearch = extern fn(storage_handle: StorageHandle,
                                       search_handle: SearchHandle) -> Error;
+pub type StreamExportWalletCB = extern fn(command_handle_: CommandHandle,
+                                          data: BString,
+                                          data_len: u32);
@@ -15,7 +15,7 @@ use ffi::{ResponseStringStringCB,
           ResponseI32CB,
           ResponseEmptyCB,
           ResponseBoolCB};
-use {CommandHandle, WalletHandle, SearchHandle, BlobStorageReaderHandle, TailsWriterHandle};
+use {CommandHandle, WalletHandle, PoolHandle, IndyHandle, SearchHandle, BlobStorageReaderHandle, TailsWriterHandle};
 use ffi::BlobStorageReaderCfgHandle;
 
 /*
@@ -596,6 +596,47 @@ fn _prover_create_credential_req(command_handle: CommandHandle, wallet_handle: W
     })
 }
 
+/// Validates a credential offer received from an issuer and parses it into a normalized structure.
+///
+/// Checks identifiers and nonce of the offer, that it references the provided credential definition
+/// and verifies key correctness proof of the offer against the credential definition.
+///
+/// # Arguments
+/// * `cred_offer_json`: credential offer as a json containing information about the issuer and a credential
+/// * `cred_def_json`: credential definition json related to <cred_def_id> in <cred_offer_json>
+///
+/// # Returns
+/// * `parsed_offer_json`: credential offer data
+///     {
+///         "schema_id": string,
+///         "cred_def_id": string,
+///         "issuer_did": string,
+///         "schema_issuer_did": Optional<string>,
+///         "schema_name": Optional<string>,
+///         "schema_version": Optional<string>,
+///         "cred_def_tag": string,
+///         "method_name": Optional<string>,
+///         "nonce": string,
+///         "attr_names": [string],
+///         "revocable": bool
+///     }
+pub fn prover_parse_credential_offer(cred_offer_json: &str, cred_def_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _prover_parse_credential_offer(command_handle, cred_offer_json, cred_def_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _prover_parse_credential_offer(command_handle: CommandHandle, cred_offer_json: &str, cred_def_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let cred_offer_json = c_str!(cred_offer_json);
+    let cred_def_json = c_str!(cred_def_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_prover_parse_credential_offer(command_handle, cred_offer_json.as_ptr(), cred_def_json.as_ptr(), cb)
+    })
+}
+
 /// Set credential attribute tagging policy.
 /// Writes a non-secret record marking attributes to tag, and optionally
 /// updates tags on existing credentials on the credential definition to match.
@@ -622,7 +663,12 @@ fn _prover_create_credential_req(command_handle: CommandHandle, wallet_handle: W
 /// command_handle: command handle to map callback to user context.
 /// wallet_handle: wallet handle (created by Wallet::open_wallet).
 /// cred_def_id: credential definition id
-/// tag_attrs_json: JSON array with names of attributes to tag by policy, or null for all
+/// tag_attrs_json: JSON array with names of attributes to tag by policy, or null for all.
+///     To keep raw values of some attributes out of tags, the policy can be set as object:
+///     {
+///         "taggable": Optional<[string]>, - names of attributes to tag (all attributes if not set)
+///         "marker_only": Optional<[string]>, - names of attributes tagged by marker only without value tag
+///     }
 /// retroactive: boolean, whether to apply policy to existing credentials on credential definition identifier
 pub fn prover_set_credential_attr_tag_policy(wallet_handle: WalletHandle, cred_def_id: &str, tag_attrs_json: Option<&str>, retroactive: bool) -> Box<dyn Future<Item=(), Error=IndyError>> {
     let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
@@ -713,6 +759,82 @@ fn _prover_store_credential(command_handle: CommandHandle, wallet_handle: Wallet
     })
 }
 
+/// Check credential provided by Issuer for the given credential request,
+/// updates the credential by a master secret and stores in a secure wallet with custom tags
+/// in addition to the tags described in `prover_store_credential`.
+///
+/// EXPERIMENTAL
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by open_wallet).
+/// * `cred_id`: (optional, default is a random one) identifier by which credential will be stored in the wallet
+/// * `cred_req_metadata_json`: a credential request metadata created by create_credential_req
+/// * `cred_json`: credential json received from issuer
+/// * `cred_def_json`: credential definition json related to <cred_def_id> in <cred_json>
+/// * `rev_reg_def_json`: revocation registry definition json related to <rev_reg_def_id> in <cred_json>
+/// * `tags_json`: custom tags of the credential, names can't clash with the tags created by libindy:
+///     {
+///         "tagName1": <str>, // string tag (will be stored encrypted)
+///         "~tagName2": <str>, // string tag (will be stored un-encrypted)
+///     }
+///
+/// # Returns
+/// * `out_cred_id` - identifier by which credential is stored in the wallet
+pub fn prover_store_credential_with_tags(wallet_handle: WalletHandle, cred_id: Option<&str>, cred_req_metadata_json: &str, cred_json: &str, cred_def_json: &str, rev_reg_def_json: Option<&str>, tags_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _prover_store_credential_with_tags(command_handle, wallet_handle, cred_id, cred_req_metadata_json, cred_json, cred_def_json, rev_reg_def_json, tags_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _prover_store_credential_with_tags(command_handle: CommandHandle, wallet_handle: WalletHandle, cred_id: Option<&str>, cred_req_metadata_json: &str, cred_json: &str, cred_def_json: &str, rev_reg_def_json: Option<&str>, tags_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let cred_id_str = opt_c_str!(cred_id);
+    let cred_req_metadata_json = c_str!(cred_req_metadata_json);
+    let cred_json = c_str!(cred_json);
+    let cred_def_json = c_str!(cred_def_json);
+    let rev_reg_def_json_str = opt_c_str!(rev_reg_def_json);
+    let tags_json = c_str!(tags_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_prover_store_credential_with_tags(command_handle, wallet_handle, opt_c_ptr!(cred_id, cred_id_str), cred_req_metadata_json.as_ptr(), cred_json.as_ptr(), cred_def_json.as_ptr(), opt_c_ptr!(rev_reg_def_json, rev_reg_def_json_str), tags_json.as_ptr(), cb)
+    })
+}
+
+/// Imports AnonCreds credential issued in W3C Verifiable Credential format into the wallet.
+/// The credential is converted to the legacy format and stored the same way as by `prover_store_credential`,
+/// so it can be used in Indy proof requests.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by open_wallet).
+/// * `cred_id`: (optional, default is a random one) identifier by which credential will be stored in the wallet
+/// * `cred_req_metadata_json`: a credential request metadata created by create_credential_req
+/// * `w3c_cred_json`: W3C credential json with "anoncreds-2023" data integrity proof received from issuer
+/// * `cred_def_json`: credential definition json related to "definition" in <w3c_cred_json> credential schema
+/// * `rev_reg_def_json`: revocation registry definition json related to "revocationRegistry" in <w3c_cred_json> credential schema
+///
+/// # Returns
+/// * `out_cred_id` - identifier by which credential is stored in the wallet
+pub fn prover_import_w3c_credential(wallet_handle: WalletHandle, cred_id: Option<&str>, cred_req_metadata_json: &str, w3c_cred_json: &str, cred_def_json: &str, rev_reg_def_json: Option<&str>) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _prover_import_w3c_credential(command_handle, wallet_handle, cred_id, cred_req_metadata_json, w3c_cred_json, cred_def_json, rev_reg_def_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _prover_import_w3c_credential(command_handle: CommandHandle, wallet_handle: WalletHandle, cred_id: Option<&str>, cred_req_metadata_json: &str, w3c_cred_json: &str, cred_def_json: &str, rev_reg_def_json: Option<&str>, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let cred_id_str = opt_c_str!(cred_id);
+    let cred_req_metadata_json = c_str!(cred_req_metadata_json);
+    let w3c_cred_json = c_str!(w3c_cred_json);
+    let cred_def_json = c_str!(cred_def_json);
+    let rev_reg_def_json_str = opt_c_str!(rev_reg_def_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_prover_import_w3c_credential(command_handle, wallet_handle, opt_c_ptr!(cred_id, cred_id_str), cred_req_metadata_json.as_ptr(), w3c_cred_json.as_ptr(), cred_def_json.as_ptr(), opt_c_ptr!(rev_reg_def_json, rev_reg_def_json_str), cb)
+    })
+}
+
 /// Gets human readable credentials according to the filter.
 /// If filter is NULL, then all credentials are returned.
 /// Credentials can be filtered by Issuer, credential_def and/or Schema.
@@ -1345,6 +1467,74 @@ fn _verifier_verify_proof(command_handle: CommandHandle, proof_request_json: &st
     })
 }
 
+/// Verifies a proof referencing schemas, credential definitions and revocation registries from multiple ledgers.
+///
+/// Ledger objects the proof is built on are fetched from the pools resolved by DIDs of their issuers,
+/// then the proof is verified the same way as `verifier_verify_proof` does.
+///
+/// # Arguments
+/// * `pools_json`: pools to fetch the ledger objects from
+///     {
+///         "pools": { - (Optional) pools mapped by issuer DID or by did:indy namespace (for example `sovrin:staging`)
+///             <issuer did or did:indy namespace>: <pool handle>,
+///         },
+///         "default": <pool handle>, - (Optional) pool for the objects of issuers not matched by `pools`
+///     }
+/// * `proof_request_json`: proof request json
+/// * `proof_json`: created for request proof json
+///
+/// # Returns
+/// * `valid`: true - if signature is valid, false - otherwise
+pub fn verifier_verify_proof_with_pools(pools_json: &str, proof_request_json: &str, proof_json: &str) -> Box<dyn Future<Item=bool, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_bool();
+
+    let err = _verifier_verify_proof_with_pools(command_handle, pools_json, proof_request_json, proof_json, cb);
+
+    ResultHandler::bool(command_handle, err, receiver)
+}
+
+fn _verifier_verify_proof_with_pools(command_handle: CommandHandle, pools_json: &str, proof_request_json: &str, proof_json: &str, cb: Option<ResponseBoolCB>) -> ErrorCode {
+    let pools_json = c_str!(pools_json);
+    let proof_request_json = c_str!(proof_request_json);
+    let proof_json = c_str!(proof_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_verifier_verify_proof_with_pools(command_handle, pools_json.as_ptr(), proof_request_json.as_ptr(), proof_json.as_ptr(), cb)
+    })
+}
+
+/// Statically validates a proof request before it is sent to a prover.
+///
+/// All found problems are reported instead of stopping on the first one.
+///
+/// # Arguments
+/// * `proof_request_json`: proof request json (see `verifier_verify_proof` for the format)
+///
+/// # Returns
+/// * `validation_errors_json`: list of found problems (empty if the proof request is valid):
+/// [{
+///     "referent": Optional<string>, - referent of requested attribute or predicate the problem relates to
+///     "code": string, - "empty_request", "duplicate_referent", "empty_attribute_name", "attribute_name_conflict",
+///                       "invalid_identifier", "qualified_identifier_in_v1", "unsupported_operator",
+///                       "unsupported_restriction" or "invalid_interval"
+///     "message": string - human readable description of the problem
+/// }]
+pub fn verifier_validate_proof_request(proof_request_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _verifier_validate_proof_request(command_handle, proof_request_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _verifier_validate_proof_request(command_handle: CommandHandle, proof_request_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let proof_request_json = c_str!(proof_request_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_verifier_validate_proof_request(command_handle, proof_request_json.as_ptr(), cb)
+    })
+}
+
 
 /// Create revocation state for a credential that corresponds to a particular time.
 ///
@@ -1431,6 +1621,94 @@ fn _update_revocation_state(command_handle: CommandHandle, blob_storage_reader_h
     })
 }
 
+/// Starts background updates of revocation states for the credentials stored in the wallet.
+///
+/// EXPERIMENTAL
+///
+/// Revocation registry definitions and deltas are periodically fetched from the ledger and revocation states
+/// of the credentials are updated, so a proof can be created without a ledger request followed by witness calculation.
+/// Updates are stopped once the updater is stopped by `stop_revocation_state_updater` or the pool is closed.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by open_wallet)
+/// * `pool_handle`: pool handle (created by open_pool_ledger)
+/// * `blob_storage_reader_handle`: configuration of blob storage reader handle that will allow to read revocation tails
+/// * `config_json`: updater config:
+/// {
+///     "credIds": [<string>, ...], - identifiers of revocable credentials in the wallet
+///     "updateInterval": <int>, - (Optional) interval in seconds between updates (60 - used default)
+/// }
+///
+/// # Returns
+/// * `updater_handle`: handle of the updater
+pub fn start_revocation_state_updater(wallet_handle: WalletHandle, pool_handle: PoolHandle, blob_storage_reader_handle: BlobStorageReaderHandle, config_json: &str) -> Box<dyn Future<Item=IndyHandle, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_handle();
+
+    let err = _start_revocation_state_updater(command_handle, wallet_handle, pool_handle, blob_storage_reader_handle, config_json, cb);
+
+    ResultHandler::handle(command_handle, err, receiver)
+}
+
+fn _start_revocation_state_updater(command_handle: CommandHandle, wallet_handle: WalletHandle, pool_handle: PoolHandle, blob_storage_reader_handle: BlobStorageReaderHandle, config_json: &str, cb: Option<ResponseI32CB>) -> ErrorCode {
+    let config_json = c_str!(config_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_start_revocation_state_updater(command_handle, wallet_handle, pool_handle, blob_storage_reader_handle, config_json.as_ptr(), cb)
+    })
+}
+
+/// Stops revocation state updater started by `start_revocation_state_updater`.
+///
+/// EXPERIMENTAL
+///
+/// # Arguments
+/// * `updater_handle`: handle of the updater
+pub fn stop_revocation_state_updater(updater_handle: IndyHandle) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _stop_revocation_state_updater(command_handle, updater_handle, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _stop_revocation_state_updater(command_handle: CommandHandle, updater_handle: IndyHandle, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    ErrorCode::from(unsafe {
+        anoncreds::indy_stop_revocation_state_updater(command_handle, updater_handle, cb)
+    })
+}
+
+/// Gets the latest revocation states computed by revocation state updater.
+///
+/// EXPERIMENTAL
+///
+/// # Arguments
+/// * `updater_handle`: handle of the updater
+///
+/// # Returns
+/// * `rev_states_json`: revocation states of credentials which states are already computed:
+/// {
+///     <credential id>: {
+///         "rev_reg_id": string,
+///         "cred_rev_id": string,
+///         "timestamp": integer,
+///         "rev_state": <revocation state json>
+///     },
+///     ...
+/// }
+pub fn get_updated_revocation_states(updater_handle: IndyHandle) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _get_updated_revocation_states(command_handle, updater_handle, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _get_updated_revocation_states(command_handle: CommandHandle, updater_handle: IndyHandle, cb: Option<ResponseStringCB>) -> ErrorCode {
+    ErrorCode::from(unsafe {
+        anoncreds::indy_get_updated_revocation_states(command_handle, updater_handle, cb)
+    })
+}
+
 /// Generates 80-bit numbers that can be used as a nonce for proof request.
 ///
 /// # Arguments
@@ -5,7 +5,7 @@ use {ErrorCode, IndyError};
 use std::ffi::CString;
 
 use ffi::blob_storage;
-use ffi::ResponseI32CB;
+use ffi::{ResponseI32CB, ResponseEmptyCB};
 
 use utils::callbacks::{ClosureHandler, ResultHandler};
 use {IndyHandle, CommandHandle};
@@ -39,3 +39,112 @@ fn _open_writer(command_handle: CommandHandle, xtype: &str, config_json: &str, c
 
     ErrorCode::from(unsafe { blob_storage::indy_open_blob_storage_writer(command_handle, xtype.as_ptr(), config_json.as_ptr(), cb) })
 }
+
+/// Closes reader of tails files opened with `open_reader`.
+///
+/// # Arguments
+/// * `handle` - reader handle returned by `open_reader`
+pub fn close_reader(handle: IndyHandle) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _close_reader(command_handle, handle, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _close_reader(command_handle: CommandHandle, handle: IndyHandle, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    ErrorCode::from(unsafe { blob_storage::indy_close_blob_storage_reader(command_handle, handle, cb) })
+}
+
+/// Closes writer of tails files opened with `open_writer`.
+///
+/// # Arguments
+/// * `handle` - writer handle returned by `open_writer`
+pub fn close_writer(handle: IndyHandle) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _close_writer(command_handle, handle, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _close_writer(command_handle: CommandHandle, handle: IndyHandle, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    ErrorCode::from(unsafe { blob_storage::indy_close_blob_storage_writer(command_handle, handle, cb) })
+}
+
+/// Registers custom reader of tails files.
+///
+/// # Arguments
+/// * `xtype` - reader type name
+/// * `open_reader` - reader open operation handler
+/// * `open_blob` - reader open blob operation handler
+/// * `read` - blob read operation handler
+/// * `close_blob` - blob close operation handler
+/// * `close_reader` - optional reader close operation handler, it is called by `close_reader`
+pub fn register_reader(xtype: &str,
+                       open_reader: Option<blob_storage::BlobStorageOpenReader>,
+                       open_blob: Option<blob_storage::BlobStorageOpenBlob>,
+                       read: Option<blob_storage::BlobStorageRead>,
+                       close_blob: Option<blob_storage::BlobStorageCloseBlob>,
+                       close_reader: Option<blob_storage::BlobStorageCloseReader>) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _register_reader(command_handle, xtype, open_reader, open_blob, read, close_blob, close_reader, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _register_reader(command_handle: CommandHandle,
+                    xtype: &str,
+                    open_reader: Option<blob_storage::BlobStorageOpenReader>,
+                    open_blob: Option<blob_storage::BlobStorageOpenBlob>,
+                    read: Option<blob_storage::BlobStorageRead>,
+                    close_blob: Option<blob_storage::BlobStorageCloseBlob>,
+                    close_reader: Option<blob_storage::BlobStorageCloseReader>,
+                    cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let xtype = c_str!(xtype);
+
+    ErrorCode::from(unsafe {
+        blob_storage::indy_register_blob_storage_reader(command_handle, xtype.as_ptr(), open_reader, open_blob, read, close_blob, close_reader, cb)
+    })
+}
+
+/// Registers custom writer of tails files.
+///
+/// # Arguments
+/// * `xtype` - writer type name
+/// * `open_writer` - writer open operation handler
+/// * `create_blob` - writer create blob operation handler
+/// * `append` - blob append operation handler
+/// * `finalize` - blob finalize operation handler, returned location must stay valid until the blob is closed
+/// * `close_blob` - blob close operation handler
+/// * `close_writer` - optional writer close operation handler, it is called by `close_writer`
+pub fn register_writer(xtype: &str,
+                       open_writer: Option<blob_storage::BlobStorageOpenWriter>,
+                       create_blob: Option<blob_storage::BlobStorageCreateBlob>,
+                       append: Option<blob_storage::BlobStorageAppend>,
+                       finalize: Option<blob_storage::BlobStorageFinalize>,
+                       close_blob: Option<blob_storage::BlobStorageCloseBlob>,
+                       close_writer: Option<blob_storage::BlobStorageCloseWriter>) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _register_writer(command_handle, xtype, open_writer, create_blob, append, finalize, close_blob, close_writer, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _register_writer(command_handle: CommandHandle,
+                    xtype: &str,
+                    open_writer: Option<blob_storage::BlobStorageOpenWriter>,
+                    create_blob: Option<blob_storage::BlobStorageCreateBlob>,
+                    append: Option<blob_storage::BlobStorageAppend>,
+                    finalize: Option<blob_storage::BlobStorageFinalize>,
+                    close_blob: Option<blob_storage::BlobStorageCloseBlob>,
+                    close_writer: Option<blob_storage::BlobStorageCloseWriter>,
+                    cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let xtype = c_str!(xtype);
+
+    ErrorCode::from(unsafe {
+        blob_storage::indy_register_blob_storage_writer(command_handle, xtype.as_ptr(), open_writer, create_blob, append, finalize, close_blob, close_writer, cb)
+    })
+}
@@ -7,7 +7,7 @@ use std::ffi::CString;
 use utils::callbacks::{ClosureHandler, ResultHandler};
 
 use ffi::cache;
-use ffi::{ResponseEmptyCB, ResponseStringCB};
+use ffi::{ResponseEmptyCB, ResponseStringCB, ResponseStringStringU64CB};
 use {WalletHandle, CommandHandle, PoolHandle};
 
 /// Get schema json data for specified schema id.
@@ -27,6 +27,10 @@ use {WalletHandle, CommandHandle, PoolHandle};
 ///    noUpdate: (bool, optional, false by default) Use only cached data, do not try to update.
 ///    noStore: (bool, optional, false by default) Skip storing fresh data if updated,
 ///    minFresh: (int, optional, -1 by default) Return cached data if not older than this many seconds. -1 means do not check age.
+///    ttl: (int, optional, -1 by default) Time to live of stored data in seconds. Expired data is removed on read. -1 means never expire.
+///    maxEntries: (int, optional, unlimited by default) Max count of entries kept in cache. Least recently used entries are evicted when fresh data is stored.
+///    notFoundTtl: (int, optional, 60 by default) Time to live of cached "not found" ledger results in seconds. 0 means do not cache "not found" results.
+///    noNegativeCache: (bool, optional, false by default) Skip cached "not found" ledger results and query the ledger again.
 ///  }
 /// # Returns
 /// Schema json.
@@ -84,6 +88,10 @@ pub fn _get_schema(command_handle: CommandHandle,
 ///    noUpdate: (bool, optional, false by default) Use only cached data, do not try to update.
 ///    noStore: (bool, optional, false by default) Skip storing fresh data if updated,
 ///    minFresh: (int, optional, -1 by default) Return cached data if not older than this many seconds. -1 means do not check age.
+///    ttl: (int, optional, -1 by default) Time to live of stored data in seconds. Expired data is removed on read. -1 means never expire.
+///    maxEntries: (int, optional, unlimited by default) Max count of entries kept in cache. Least recently used entries are evicted when fresh data is stored.
+///    notFoundTtl: (int, optional, 60 by default) Time to live of cached "not found" ledger results in seconds. 0 means do not cache "not found" results.
+///    noNegativeCache: (bool, optional, false by default) Skip cached "not found" ledger results and query the ledger again.
 ///  }
 /// # Returns
 /// Credential Definition json.
@@ -138,6 +146,7 @@ pub fn _get_cred_def(command_handle: CommandHandle,
 /// * `options_json` -
 ///  {
 ///    maxAge: (int, optional, -1 by default) Purge cached data if older than this many seconds. -1 means purge all.
+///    poolName: (string, optional) Purge only data fetched from the pool ledger with this name. All pools by default.
 ///  }
 pub fn purge_schema_cache(wallet_handle: WalletHandle, options_json: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
     let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
@@ -163,6 +172,7 @@ fn _purge_schema_cache(command_handle: CommandHandle, wallet_handle: WalletHandl
 /// * `options_json` -
 ///  {
 ///    maxAge: (int, optional, -1 by default) Purge cached data if older than this many seconds. -1 means purge all.
+///    poolName: (string, optional) Purge only data fetched from the pool ledger with this name. All pools by default.
 ///  }
 pub fn purge_cred_def_cache(wallet_handle: WalletHandle, options_json: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
     let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
@@ -176,4 +186,337 @@ fn _purge_cred_def_cache(command_handle: CommandHandle, wallet_handle: WalletHan
     let options_json = c_str!(options_json);
 
     ErrorCode::from(unsafe { cache::indy_purge_cred_def_cache(command_handle, wallet_handle, options_json.as_ptr(), cb) })
-}
\ No newline at end of file
+}
+
+/// Get revocation registry definition json data for specified revocation registry definition id.
+/// If data is present inside of cache, cached data is returned.
+/// Otherwise data is fetched from the ledger and stored inside of cache for future use.
+///
+/// EXPERIMENTAL
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by open_pool_ledger).
+/// * `wallet_handle` - wallet handle (created by open_wallet).
+/// * `submitter_did` - DID of the submitter stored in secured Wallet.
+/// * `id` - identifier of revocation registry definition.
+/// * `options_json` - the same as for `get_schema`.
+/// # Returns
+/// Revocation Registry Definition json (the same as `ledger::parse_get_revoc_reg_def_response`).
+pub fn get_rev_reg_def(pool_handle: PoolHandle,
+                       wallet_handle: WalletHandle,
+                       submitter_did: &str,
+                       id: &str,
+                       options_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _get_rev_reg_def(command_handle, pool_handle, wallet_handle, submitter_did, id, options_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _get_rev_reg_def(command_handle: CommandHandle,
+                    pool_handle: PoolHandle,
+                    wallet_handle: WalletHandle,
+                    submitter_did: &str,
+                    id: &str,
+                    options_json: &str,
+                    cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did = c_str!(submitter_did);
+    let id = c_str!(id);
+    let options_json = c_str!(options_json);
+
+    ErrorCode::from(
+        unsafe {
+            cache::indy_get_rev_reg_def(command_handle, pool_handle, wallet_handle, submitter_did.as_ptr(), id.as_ptr(), options_json.as_ptr(), cb)
+        }
+    )
+}
+
+/// Get revocation registry json data for specified revocation registry definition id valid at specified time.
+/// If cached revocation registry state is known to be actual at requested time, cached data is returned.
+/// Otherwise data is fetched from the ledger and stored inside of cache for future use.
+///
+/// EXPERIMENTAL
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by open_pool_ledger).
+/// * `wallet_handle` - wallet handle (created by open_wallet).
+/// * `submitter_did` - DID of the submitter stored in secured Wallet.
+/// * `rev_reg_def_id` - identifier of revocation registry definition.
+/// * `timestamp` - requested time represented as a total number of seconds from Unix Epoch.
+/// * `options_json` - the same as for `get_schema`.
+/// # Returns
+/// Revocation Registry Definition Id, Revocation Registry json and Timestamp (the same as `ledger::parse_get_revoc_reg_response`).
+pub fn get_rev_reg(pool_handle: PoolHandle,
+                   wallet_handle: WalletHandle,
+                   submitter_did: &str,
+                   rev_reg_def_id: &str,
+                   timestamp: i64,
+                   options_json: &str) -> Box<dyn Future<Item=(String, String, u64), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string_string_u64();
+
+    let err = _get_rev_reg(command_handle, pool_handle, wallet_handle, submitter_did, rev_reg_def_id, timestamp, options_json, cb);
+
+    ResultHandler::str_str_u64(command_handle, err, receiver)
+}
+
+fn _get_rev_reg(command_handle: CommandHandle,
+                pool_handle: PoolHandle,
+                wallet_handle: WalletHandle,
+                submitter_did: &str,
+                rev_reg_def_id: &str,
+                timestamp: i64,
+                options_json: &str,
+                cb: Option<ResponseStringStringU64CB>) -> ErrorCode {
+    let submitter_did = c_str!(submitter_did);
+    let rev_reg_def_id = c_str!(rev_reg_def_id);
+    let options_json = c_str!(options_json);
+
+    ErrorCode::from(
+        unsafe {
+            cache::indy_get_rev_reg(command_handle, pool_handle, wallet_handle, submitter_did.as_ptr(), rev_reg_def_id.as_ptr(), timestamp, options_json.as_ptr(), cb)
+        }
+    )
+}
+
+/// Get revocation registry delta json data for specified revocation registry definition id and time interval.
+/// If cached delta covers requested interval, cached data is returned.
+/// If cached delta covers only the beginning of requested interval, only the missing part is fetched
+/// from the ledger and merged with cached delta.
+/// Otherwise data is fetched from the ledger and stored inside of cache for future use.
+///
+/// EXPERIMENTAL
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by open_pool_ledger).
+/// * `wallet_handle` - wallet handle (created by open_wallet).
+/// * `submitter_did` - DID of the submitter stored in secured Wallet.
+/// * `rev_reg_def_id` - identifier of revocation registry definition.
+/// * `from` - requested time represented as a total number of seconds from Unix Epoch (-1 means the whole state till to).
+/// * `to` - requested time represented as a total number of seconds from Unix Epoch.
+/// * `options_json` - the same as for `get_schema`.
+/// # Returns
+/// Revocation Registry Definition Id, Revocation Registry Delta json and Timestamp (the same as `ledger::parse_get_revoc_reg_delta_response`).
+pub fn get_rev_reg_delta(pool_handle: PoolHandle,
+                         wallet_handle: WalletHandle,
+                         submitter_did: &str,
+                         rev_reg_def_id: &str,
+                         from: i64,
+                         to: i64,
+                         options_json: &str) -> Box<dyn Future<Item=(String, String, u64), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string_string_u64();
+
+    let err = _get_rev_reg_delta(command_handle, pool_handle, wallet_handle, submitter_did, rev_reg_def_id, from, to, options_json, cb);
+
+    ResultHandler::str_str_u64(command_handle, err, receiver)
+}
+
+fn _get_rev_reg_delta(command_handle: CommandHandle,
+                      pool_handle: PoolHandle,
+                      wallet_handle: WalletHandle,
+                      submitter_did: &str,
+                      rev_reg_def_id: &str,
+                      from: i64,
+                      to: i64,
+                      options_json: &str,
+                      cb: Option<ResponseStringStringU64CB>) -> ErrorCode {
+    let submitter_did = c_str!(submitter_did);
+    let rev_reg_def_id = c_str!(rev_reg_def_id);
+    let options_json = c_str!(options_json);
+
+    ErrorCode::from(
+        unsafe {
+            cache::indy_get_rev_reg_delta(command_handle, pool_handle, wallet_handle, submitter_did.as_ptr(), rev_reg_def_id.as_ptr(), from, to, options_json.as_ptr(), cb)
+        }
+    )
+}
+
+/// Purge revocation registry definition, revocation registry and revocation registry delta caches.
+///
+/// EXPERIMENTAL
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by open_wallet).
+/// * `options_json` -
+///  {
+///    maxAge: (int, optional, -1 by default) Purge cached data if older than this many seconds. -1 means purge all.
+///    poolName: (string, optional) Purge only data fetched from the pool ledger with this name. All pools by default.
+///  }
+pub fn purge_rev_reg_cache(wallet_handle: WalletHandle, options_json: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _purge_rev_reg_cache(command_handle, wallet_handle, options_json, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _purge_rev_reg_cache(command_handle: CommandHandle, wallet_handle: WalletHandle, options_json: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let options_json = c_str!(options_json);
+
+    ErrorCode::from(unsafe { cache::indy_purge_rev_reg_cache(command_handle, wallet_handle, options_json.as_ptr(), cb) })
+}
+
+/// Fetch schemas, credential definitions and revocation registry definitions from the ledger
+/// and store them inside of cache for future use.
+/// Ledger requests for all objects are sent concurrently; objects already present inside of cache are skipped.
+///
+/// EXPERIMENTAL
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by open_pool_ledger).
+/// * `wallet_handle` - wallet handle (created by open_wallet).
+/// * `ids_json` -
+///  {
+///    schemas: (array of strings, optional) identifiers of schemas,
+///    credDefs: (array of strings, optional) identifiers of credential definitions,
+///    revRegDefs: (array of strings, optional) identifiers of revocation registry definitions,
+///  }
+/// # Returns
+/// Map of identifiers which could not be fetched to error messages.
+pub fn prefetch_ledger_cache(pool_handle: PoolHandle, wallet_handle: WalletHandle, ids_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _prefetch_ledger_cache(command_handle, pool_handle, wallet_handle, ids_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _prefetch_ledger_cache(command_handle: CommandHandle, pool_handle: PoolHandle, wallet_handle: WalletHandle, ids_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let ids_json = c_str!(ids_json);
+
+    ErrorCode::from(unsafe { cache::indy_prefetch_ledger_cache(command_handle, pool_handle, wallet_handle, ids_json.as_ptr(), cb) })
+}
+
+/// Get statistics of ledger cache stored inside of the wallet per object kind.
+/// Lookup counters are collected since library load and are shared among all wallets.
+///
+/// EXPERIMENTAL
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by open_wallet).
+/// # Returns
+/// Cache statistics json.
+/// {
+///   "schemas"|"credDefs"|"revRegDefs"|"revRegs"|"revRegDeltas": {
+///       hits: int - count of lookups served from cache,
+///       misses: int - count of lookups which required the ledger (or failed with noUpdate),
+///       entries: int - count of cached entries,
+///       size: int - total size of cached data in bytes,
+///       oldest: (int, optional) time of storing of the oldest entry in seconds since Unix Epoch,
+///       newest: (int, optional) time of storing of the newest entry in seconds since Unix Epoch,
+///   },
+/// }
+pub fn get_cache_stats(wallet_handle: WalletHandle) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _get_cache_stats(command_handle, wallet_handle, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _get_cache_stats(command_handle: CommandHandle, wallet_handle: WalletHandle, cb: Option<ResponseStringCB>) -> ErrorCode {
+    ErrorCode::from(unsafe { cache::indy_get_cache_stats(command_handle, wallet_handle, cb) })
+}
+
+/// Set defaults of ledger cache options for all calls with the wallet.
+/// Options passed to a call take precedence over the config.
+///
+/// EXPERIMENTAL
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by open_wallet).
+/// * `config_json` -
+///  {
+///    include: (array of strings, optional, all by default) Object kinds to cache: "schemas", "credDefs", "revRegDefs", "revRegs", "revRegDeltas".
+///    ttl: (int, optional) Default ttl option.
+///    maxEntries: (int, optional) Default maxEntries option.
+///    evictionPolicy: (string, optional) Default evictionPolicy option: "lru" or "lfu".
+///    maxEntrySize: (int, optional) Default maxEntrySize option.
+///    negativeCache: (bool, optional, true by default) false disables storing and usage of cached "not found" ledger results.
+///    notFoundTtl: (int, optional) Default notFoundTtl option.
+///  }
+pub fn set_cache_config(wallet_handle: WalletHandle, config_json: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _set_cache_config(command_handle, wallet_handle, config_json, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _set_cache_config(command_handle: CommandHandle, wallet_handle: WalletHandle, config_json: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let config_json = c_str!(config_json);
+
+    ErrorCode::from(unsafe { cache::indy_set_cache_config(command_handle, wallet_handle, config_json.as_ptr(), cb) })
+}
+
+/// Registers schema in the local registry of the wallet.
+/// Registered schema is trusted and returned by get_schema instead of the ledger one,
+/// so anoncreds can be used on private networks without any ledger writes.
+/// Registering schema with the same id again replaces the registered one.
+///
+/// EXPERIMENTAL
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by open_wallet).
+/// * `schema_json` - schema json, as returned by anoncreds::issuer_create_schema.
+pub fn register_local_schema(wallet_handle: WalletHandle, schema_json: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _register_local_schema(command_handle, wallet_handle, schema_json, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _register_local_schema(command_handle: CommandHandle, wallet_handle: WalletHandle, schema_json: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let schema_json = c_str!(schema_json);
+
+    ErrorCode::from(unsafe { cache::indy_register_local_schema(command_handle, wallet_handle, schema_json.as_ptr(), cb) })
+}
+
+/// Registers credential definition in the local registry of the wallet.
+/// Registered credential definition is trusted and returned by get_cred_def instead of the ledger one,
+/// so anoncreds can be used on private networks without any ledger writes.
+/// Registering credential definition with the same id again replaces the registered one.
+///
+/// EXPERIMENTAL
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by open_wallet).
+/// * `cred_def_json` - credential definition json, as returned by anoncreds::issuer_create_and_store_credential_def.
+pub fn register_local_cred_def(wallet_handle: WalletHandle, cred_def_json: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _register_local_cred_def(command_handle, wallet_handle, cred_def_json, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _register_local_cred_def(command_handle: CommandHandle, wallet_handle: WalletHandle, cred_def_json: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let cred_def_json = c_str!(cred_def_json);
+
+    ErrorCode::from(unsafe { cache::indy_register_local_cred_def(command_handle, wallet_handle, cred_def_json.as_ptr(), cb) })
+}
+
+/// Registers revocation registry definition in the local registry of the wallet.
+/// Registered revocation registry definition is trusted and returned by get_revoc_reg_def instead of the ledger one,
+/// so anoncreds can be used on private networks without any ledger writes.
+/// Registering revocation registry definition with the same id again replaces the registered one.
+///
+/// EXPERIMENTAL
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by open_wallet).
+/// * `revoc_reg_def_json` - revocation registry definition json, as returned by anoncreds::issuer_create_and_store_revoc_reg.
+pub fn register_local_rev_reg_def(wallet_handle: WalletHandle, revoc_reg_def_json: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _register_local_rev_reg_def(command_handle, wallet_handle, revoc_reg_def_json, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _register_local_rev_reg_def(command_handle: CommandHandle, wallet_handle: WalletHandle, revoc_reg_def_json: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let revoc_reg_def_json = c_str!(revoc_reg_def_json);
+
+    ErrorCode::from(unsafe { cache::indy_register_local_rev_reg_def(command_handle, wallet_handle, revoc_reg_def_json.as_ptr(), cb) })
+}
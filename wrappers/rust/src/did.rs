@@ -1,16 +1,19 @@
 use {ErrorCode, IndyError};
 
 use std::ffi::CString;
+use std::ptr::null;
 
 use futures::Future;
 
 use ffi::did;
 use ffi::{ResponseEmptyCB,
+          ResponseI32UsizeCB,
+          ResponseSliceCB,
           ResponseStringCB,
           ResponseStringStringCB};
 
 use utils::callbacks::{ClosureHandler, ResultHandler};
-use {CommandHandle, WalletHandle, PoolHandle};
+use {CommandHandle, WalletHandle, PoolHandle, SearchHandle};
 
 /// Creates keys (signing and encryption keys) for a new
 /// DID (owned by the caller of the library).
@@ -106,6 +109,41 @@ fn _replace_keys_apply(command_handle: CommandHandle, wallet_handle: WalletHandl
     ErrorCode::from(unsafe { did::indy_replace_keys_apply(command_handle, wallet_handle, tgt_did.as_ptr(), cb) })
 }
 
+/// Performs complete rotation of keys for an existing DID (owned by the caller of the library):
+/// generates temporary keys, writes NYM with the new verkey to the ledger and applies the keys on success.
+/// Temporary keys are removed if the ledger write fails (except `PoolLedgerTimeout` error).
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handler (created by Wallet::open).
+/// * `pool_handle` - pool handle (created by Pool::open_ledger).
+/// * `tgt_did` - DID to rotate keys.
+/// * `identity_json` - key information as json. Example:
+/// {
+///     "seed": string, (optional) Seed that allows deterministic key creation (if not set random one will be created).
+///                                Can be UTF-8, base64 or hex string.
+///     "crypto_type": string, (optional; if not set then ed25519 curve is used;
+///               currently only 'ed25519' value is supported for this field)
+/// }
+/// * `endorser_did` - (optional) DID of the Endorser that will sign and pay for the transaction.
+///
+/// # Returns
+/// * `verkey` - The DIDs new verification key
+pub fn rotate_key(wallet_handle: WalletHandle, pool_handle: PoolHandle, tgt_did: &str, identity_json: &str, endorser_did: Option<&str>) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _rotate_key(command_handle, wallet_handle, pool_handle, tgt_did, identity_json, endorser_did, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _rotate_key(command_handle: CommandHandle, wallet_handle: WalletHandle, pool_handle: PoolHandle, tgt_did: &str, identity_json: &str, endorser_did: Option<&str>, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let tgt_did = c_str!(tgt_did);
+    let identity_json = c_str!(identity_json);
+    let endorser_did_str = opt_c_str!(endorser_did);
+
+    ErrorCode::from(unsafe { did::indy_rotate_key(command_handle, wallet_handle, pool_handle, tgt_did.as_ptr(), identity_json.as_ptr(), opt_c_ptr!(endorser_did, endorser_did_str), cb) })
+}
+
 /// Saves their DID for a pairwise connection in a secured Wallet,
 /// so that it can be used to verify transaction.
 /// Updates DID associated verkey in case DID already exists in the Wallet.
@@ -252,6 +290,7 @@ fn _get_endpoint_for_did(command_handle: CommandHandle, wallet_handle: WalletHan
 /// * `wallet_handle` - Wallet handle (created by Wallet::open).
 /// * `did` - the DID to store metadata.
 /// * `metadata`  - the meta information that will be store with the DID.
+///   Top level string fields of JSON object metadata are also set as tags of my DID.
 pub fn set_did_metadata(wallet_handle: WalletHandle, tgt_did: &str, metadata: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
     let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
 
@@ -339,6 +378,90 @@ fn _list_my_dids_with_metadata(command_handle: CommandHandle, wallet_handle: Wal
     ErrorCode::from(unsafe { did::indy_list_my_dids_with_meta(command_handle, wallet_handle, cb) })
 }
 
+/// Saves/replaces tags of my DID stored in the wallet.
+///
+/// # Arguments
+/// * `wallet_handle` - Wallet handle (created by Wallet::open).
+/// * `did` - my DID to set tags for.
+/// * `tags_json` - Tags of the DID as json: {"tagName1": <str>, "~tagName2": <str>} (`~` prefix means un-encrypted tag).
+pub fn set_my_did_tags(wallet_handle: WalletHandle, did: &str, tags_json: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _set_my_did_tags(command_handle, wallet_handle, did, tags_json, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _set_my_did_tags(command_handle: CommandHandle, wallet_handle: WalletHandle, did: &str, tags_json: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let did = c_str!(did);
+    let tags_json = c_str!(tags_json);
+
+    ErrorCode::from(unsafe { did::indy_set_my_did_tags(command_handle, wallet_handle, did.as_ptr(), tags_json.as_ptr(), cb) })
+}
+
+/// Search for my DIDs stored in the wallet.
+///
+/// # Arguments
+/// * `wallet_handle` - Wallet handle (created by Wallet::open).
+/// * `query_json` - (Optional) Wql query filter for DIDs searching based on tags.
+/// * `options_json` - (Optional) Search options: {"retrieveMetadata": bool, "retrieveTempVerkey": bool} (both true by default).
+///
+/// # Returns
+/// * `search_handle` - Search handle that can be used later to fetch DIDs by small batches (with `fetch_my_dids`)
+/// * `total_count` - Total count of DIDs matching the query
+pub fn open_my_dids_search(wallet_handle: WalletHandle, query_json: Option<&str>, options_json: Option<&str>) -> Box<dyn Future<Item=(SearchHandle, usize), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_handle_usize();
+
+    let err = _open_my_dids_search(command_handle, wallet_handle, query_json, options_json, cb);
+
+    ResultHandler::handle_usize(command_handle, err, receiver)
+}
+
+fn _open_my_dids_search(command_handle: CommandHandle, wallet_handle: WalletHandle, query_json: Option<&str>, options_json: Option<&str>, cb: Option<ResponseI32UsizeCB>) -> ErrorCode {
+    let query_json_str = opt_c_str!(query_json);
+    let options_json_str = opt_c_str!(options_json);
+
+    ErrorCode::from(unsafe {
+        did::indy_open_my_dids_search(command_handle, wallet_handle, opt_c_ptr!(query_json, query_json_str), opt_c_ptr!(options_json, options_json_str), cb)
+    })
+}
+
+/// Fetch next DIDs for search.
+///
+/// # Arguments
+/// * `search_handle` - Search handle (created by `open_my_dids_search`)
+/// * `count` - Count of DIDs to fetch
+///
+/// # Returns
+/// * `dids` - List of DIDs with meta: [{"did": string, "verkey": string, "tempVerkey": string, "metadata": string}]
+pub fn fetch_my_dids(search_handle: SearchHandle, count: usize) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _fetch_my_dids(command_handle, search_handle, count, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _fetch_my_dids(command_handle: CommandHandle, search_handle: SearchHandle, count: usize, cb: Option<ResponseStringCB>) -> ErrorCode {
+    ErrorCode::from(unsafe { did::indy_fetch_my_dids(command_handle, search_handle, count, cb) })
+}
+
+/// Close my DIDs search (make search handle invalid)
+///
+/// # Arguments
+/// * `search_handle` - Search handle (created by `open_my_dids_search`)
+pub fn close_my_dids_search(search_handle: SearchHandle) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _close_my_dids_search(command_handle, search_handle, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _close_my_dids_search(command_handle: CommandHandle, search_handle: SearchHandle, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    ErrorCode::from(unsafe { did::indy_close_my_dids_search(command_handle, search_handle, cb) })
+}
+
 /// Retrieves abbreviated verkey if it is possible otherwise return full verkey.
 ///
 /// # Arguments
@@ -389,3 +512,321 @@ fn _qualify_did(command_handle: CommandHandle, wallet_handle: WalletHandle, did:
 
     ErrorCode::from(unsafe { did::indy_qualify_did(command_handle, wallet_handle, did.as_ptr(), method.as_ptr(), cb) })
 }
+
+/// Updates all unqualified DIDs (my and their) stored in the wallet to make them fully qualified.
+/// All conflicts are checked before the wallet is changed.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by Wallet::open)
+/// * `method` - method to apply to the DIDs.
+/// * `options_json` - (optional) options json: {"dryRun": bool}
+///
+/// # Returns
+/// qualification report json
+pub fn qualify_all_dids(wallet_handle: WalletHandle, method: &str, options_json: Option<&str>) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _qualify_all_dids(command_handle, wallet_handle, method, options_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _qualify_all_dids(command_handle: CommandHandle, wallet_handle: WalletHandle, method: &str, options_json: Option<&str>, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let method = c_str!(method);
+    let options_json_str = opt_c_str!(options_json);
+
+    ErrorCode::from(unsafe { did::indy_qualify_all_dids(command_handle, wallet_handle, method.as_ptr(), opt_c_ptr!(options_json, options_json_str), cb) })
+}
+
+/// Builds W3C DID Document for the given DID (my or their).
+///
+/// Verkey and endpoint are resolved from the wallet and the ledger in the same way
+/// as `key_for_did` and `get_endpoint_for_did` do.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by Wallet::open)
+/// * `pool_handle` - pool handle (created by Pool::open_ledger)
+/// * `did` - The DID to build DID Document for.
+///
+/// # Returns
+/// DID Document json with verification method, X25519 key agreement key and did-communication service
+pub fn get_did_doc(wallet_handle: WalletHandle, pool_handle: PoolHandle, did: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _get_did_doc(command_handle, wallet_handle, pool_handle, did, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _get_did_doc(command_handle: CommandHandle, wallet_handle: WalletHandle, pool_handle: PoolHandle, did: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let did = c_str!(did);
+
+    ErrorCode::from(unsafe { did::indy_get_did_doc(command_handle, wallet_handle, pool_handle, did.as_ptr(), cb) })
+}
+
+/// Adds additional verification key to the DID (my or their) stored in the wallet.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by Wallet::open)
+/// * `did` - The DID (my or their) stored in the wallet.
+/// * `key_json` - Key information as json: {"id": string, "purpose": "authentication"|"assertionMethod"|"keyAgreement", "verkey": string}
+pub fn add_did_key(wallet_handle: WalletHandle, did: &str, key_json: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _add_did_key(command_handle, wallet_handle, did, key_json, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _add_did_key(command_handle: CommandHandle, wallet_handle: WalletHandle, did: &str, key_json: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let did = c_str!(did);
+    let key_json = c_str!(key_json);
+
+    ErrorCode::from(unsafe { did::indy_add_did_key(command_handle, wallet_handle, did.as_ptr(), key_json.as_ptr(), cb) })
+}
+
+/// Removes additional verification key added by `add_did_key`.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by Wallet::open)
+/// * `did` - The DID (my or their) stored in the wallet.
+/// * `key_id` - Id of the key to remove.
+pub fn remove_did_key(wallet_handle: WalletHandle, did: &str, key_id: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _remove_did_key(command_handle, wallet_handle, did, key_id, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _remove_did_key(command_handle: CommandHandle, wallet_handle: WalletHandle, did: &str, key_id: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let did = c_str!(did);
+    let key_id = c_str!(key_id);
+
+    ErrorCode::from(unsafe { did::indy_remove_did_key(command_handle, wallet_handle, did.as_ptr(), key_id.as_ptr(), cb) })
+}
+
+/// Adds additional service endpoint to the DID (my or their) stored in the wallet.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by Wallet::open)
+/// * `did` - The DID (my or their) stored in the wallet.
+/// * `service_json` - Service information as json: {"id": string, "type": string, "serviceEndpoint": string,
+///                    "recipientKeys": [string] (optional), "routingKeys": [string] (optional), "priority": int (optional)}
+pub fn add_did_service(wallet_handle: WalletHandle, did: &str, service_json: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _add_did_service(command_handle, wallet_handle, did, service_json, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _add_did_service(command_handle: CommandHandle, wallet_handle: WalletHandle, did: &str, service_json: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let did = c_str!(did);
+    let service_json = c_str!(service_json);
+
+    ErrorCode::from(unsafe { did::indy_add_did_service(command_handle, wallet_handle, did.as_ptr(), service_json.as_ptr(), cb) })
+}
+
+/// Removes additional service endpoint added by `add_did_service`.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by Wallet::open)
+/// * `did` - The DID (my or their) stored in the wallet.
+/// * `service_id` - Id of the service to remove.
+pub fn remove_did_service(wallet_handle: WalletHandle, did: &str, service_id: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _remove_did_service(command_handle, wallet_handle, did, service_id, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _remove_did_service(command_handle: CommandHandle, wallet_handle: WalletHandle, did: &str, service_id: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let did = c_str!(did);
+    let service_id = c_str!(service_id);
+
+    ErrorCode::from(unsafe { did::indy_remove_did_service(command_handle, wallet_handle, did.as_ptr(), service_id.as_ptr(), cb) })
+}
+
+/// Sets named endpoint with transport profile for the DID. Endpoint with the same name is replaced.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by Wallet::open)
+/// * `did` - The DID (my or their) stored in the wallet.
+/// * `endpoint_json` - Endpoint information as json: {"name": string, "endpoint": string, "transport": "http"|"https"|"ws"|"wss",
+///                     "verkey": string (optional), "routingKeys": [string] (optional), "priority": int (optional)}
+pub fn set_named_endpoint_for_did(wallet_handle: WalletHandle, did: &str, endpoint_json: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _set_named_endpoint_for_did(command_handle, wallet_handle, did, endpoint_json, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _set_named_endpoint_for_did(command_handle: CommandHandle, wallet_handle: WalletHandle, did: &str, endpoint_json: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let did = c_str!(did);
+    let endpoint_json = c_str!(endpoint_json);
+
+    ErrorCode::from(unsafe { did::indy_set_named_endpoint_for_did(command_handle, wallet_handle, did.as_ptr(), endpoint_json.as_ptr(), cb) })
+}
+
+/// Removes named endpoint set by `set_named_endpoint_for_did`.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by Wallet::open)
+/// * `did` - The DID (my or their) stored in the wallet.
+/// * `name` - Name of the endpoint to remove.
+pub fn remove_named_endpoint_for_did(wallet_handle: WalletHandle, did: &str, name: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _remove_named_endpoint_for_did(command_handle, wallet_handle, did, name, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _remove_named_endpoint_for_did(command_handle: CommandHandle, wallet_handle: WalletHandle, did: &str, name: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let did = c_str!(did);
+    let name = c_str!(name);
+
+    ErrorCode::from(unsafe { did::indy_remove_named_endpoint_for_did(command_handle, wallet_handle, did.as_ptr(), name.as_ptr(), cb) })
+}
+
+/// Returns named endpoints of the DID from the wallet or from the ledger "endpoints" attrib.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by Wallet::open)
+/// * `pool_handle` - pool handle (created by Pool::open_ledger).
+/// * `did` - The DID to resolve endpoints.
+///
+/// # Returns
+/// list of endpoints as json
+pub fn get_named_endpoints_for_did(wallet_handle: WalletHandle, pool_handle: PoolHandle, did: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _get_named_endpoints_for_did(command_handle, wallet_handle, pool_handle, did, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _get_named_endpoints_for_did(command_handle: CommandHandle, wallet_handle: WalletHandle, pool_handle: PoolHandle, did: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let did = c_str!(did);
+
+    ErrorCode::from(unsafe { did::indy_get_named_endpoints_for_did(command_handle, wallet_handle, pool_handle, did.as_ptr(), cb) })
+}
+
+/// Builds an ATTRIB request publishing named endpoints of the DID as "endpoints" raw attrib.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by Wallet::open)
+/// * `submitter_did` - DID of the submitter stored in secured Wallet.
+/// * `target_did` - Target DID whose named endpoints are published.
+///
+/// # Returns
+/// Request result as json.
+pub fn build_named_endpoints_attrib_request(wallet_handle: WalletHandle, submitter_did: &str, target_did: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_named_endpoints_attrib_request(command_handle, wallet_handle, submitter_did, target_did, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_named_endpoints_attrib_request(command_handle: CommandHandle, wallet_handle: WalletHandle, submitter_did: &str, target_did: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did = c_str!(submitter_did);
+    let target_did = c_str!(target_did);
+
+    ErrorCode::from(unsafe { did::indy_build_named_endpoints_attrib_request(command_handle, wallet_handle, submitter_did.as_ptr(), target_did.as_ptr(), cb) })
+}
+
+/// Exports DID with all related wallet records (keys, metadata, tags, endpoint,
+/// additional keys and services, pairwise records) as an encrypted bundle.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by Wallet::open)
+/// * `did` - DID to export.
+/// * `recipient_vk` - verkey of the key stored in the destination wallet.
+///
+/// # Returns
+/// the bundle encrypted by anonymous-encryption scheme
+pub fn export_did(wallet_handle: WalletHandle, did: &str, recipient_vk: &str) -> Box<dyn Future<Item=Vec<u8>, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_slice();
+
+    let err = _export_did(command_handle, wallet_handle, did, recipient_vk, cb);
+
+    ResultHandler::slice(command_handle, err, receiver)
+}
+
+fn _export_did(command_handle: CommandHandle, wallet_handle: WalletHandle, did: &str, recipient_vk: &str, cb: Option<ResponseSliceCB>) -> ErrorCode {
+    let did = c_str!(did);
+    let recipient_vk = c_str!(recipient_vk);
+
+    ErrorCode::from(unsafe { did::indy_export_did(command_handle, wallet_handle, did.as_ptr(), recipient_vk.as_ptr(), cb) })
+}
+
+/// Imports DID bundle created by `export_did` into the wallet.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by Wallet::open)
+/// * `recipient_vk` - key id (verkey) of my key the bundle is encrypted for.
+/// * `bundle` - encrypted DID bundle.
+///
+/// # Returns
+/// imported DID
+pub fn import_did(wallet_handle: WalletHandle, recipient_vk: &str, bundle: &[u8]) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _import_did(command_handle, wallet_handle, recipient_vk, bundle, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _import_did(command_handle: CommandHandle, wallet_handle: WalletHandle, recipient_vk: &str, bundle: &[u8], cb: Option<ResponseStringCB>) -> ErrorCode {
+    let recipient_vk = c_str!(recipient_vk);
+
+    ErrorCode::from(unsafe { did::indy_import_did(command_handle, wallet_handle, recipient_vk.as_ptr(), bundle.as_ptr() as *const u8, bundle.len() as u32, cb) })
+}
+
+/// Marks DID (my or their) stored in the wallet as deactivated.
+/// Ledger requests are not signed by deactivated DID anymore (see Ledger::force_sign_request).
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by Wallet::open)
+/// * `did` - DID to deactivate.
+pub fn deactivate_did(wallet_handle: WalletHandle, did: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _deactivate_did(command_handle, wallet_handle, did, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _deactivate_did(command_handle: CommandHandle, wallet_handle: WalletHandle, did: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let did = c_str!(did);
+
+    ErrorCode::from(unsafe { did::indy_deactivate_did(command_handle, wallet_handle, did.as_ptr(), cb) })
+}
+
+/// Publishes deactivation of DID to the ledger (NYM with null verkey)
+/// and marks DID as deactivated in the wallet on successful reply.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by Wallet::open)
+/// * `pool_handle` - pool handle (created by Pool::open_ledger).
+/// * `did` - DID to deactivate.
+///
+/// # Returns
+/// ledger response json
+pub fn publish_did_deactivation(wallet_handle: WalletHandle, pool_handle: PoolHandle, did: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _publish_did_deactivation(command_handle, wallet_handle, pool_handle, did, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _publish_did_deactivation(command_handle: CommandHandle, wallet_handle: WalletHandle, pool_handle: PoolHandle, did: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let did = c_str!(did);
+
+    ErrorCode::from(unsafe { did::indy_publish_did_deactivation(command_handle, wallet_handle, pool_handle, did.as_ptr(), cb) })
+}
@@ -1,18 +1,24 @@
 use serde_json::json;
 use {ErrorCode, IndyError};
 
-use std::ffi::CString;
+use std::collections::HashMap;
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
 use std::ptr::null;
+use std::sync::Mutex;
 
 use futures::Future;
 
 use ffi::ledger;
-use ffi::{ResponseStringCB,
+use ffi::{ResponseBoolCB,
+          ResponseEmptyCB,
+          ResponseI32CB,
+          ResponseStringCB,
           ResponseStringStringCB,
           ResponseStringStringU64CB};
 
 use utils::callbacks::{ClosureHandler, ResultHandler};
-use {WalletHandle, CommandHandle, PoolHandle};
+use {WalletHandle, CommandHandle, IndyHandle, PoolHandle};
 
 /// Signs and submits request message to validator pool.
 ///
@@ -74,6 +80,263 @@ fn _submit_request(command_handle: CommandHandle, pool_handle: PoolHandle, reque
     ErrorCode::from(unsafe { ledger::indy_submit_request(command_handle, pool_handle, request_json.as_ptr(), cb) })
 }
 
+/// Publishes request message to validator pool (no signing) with per-request reply timeout and priority.
+///
+/// High priority requests are sent before the normal priority requests still waiting in the queue
+/// of the pool worker.
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by Pool::open_ledger).
+/// * `request_json` - Request data json.
+/// * `options_json` - submit options
+///     {
+///         "timeout": <int> (optional) - time in seconds to wait for the replies. Overrides pool `timeout`.
+///         "priority": <string> (optional) - "normal" (default) or "high".
+///     }
+///
+/// # Returns
+/// Request result as json.
+pub fn submit_request_with_options(pool_handle: PoolHandle, request_json: &str, options_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _submit_request_with_options(command_handle, pool_handle, request_json, options_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _submit_request_with_options(command_handle: CommandHandle, pool_handle: PoolHandle, request_json: &str, options_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let request_json = c_str!(request_json);
+    let options_json = c_str!(options_json);
+
+    ErrorCode::from(unsafe { ledger::indy_submit_request_with_options(command_handle, pool_handle, request_json.as_ptr(), options_json.as_ptr(), cb) })
+}
+
+/// Publishes signed SCHEMA or CRED_DEF request message to validator pool treating rejection
+/// of already existing ledger entity as success.
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by Pool::open_ledger).
+/// * `request_json` - Signed SCHEMA or CRED_DEF request data json.
+///
+/// # Returns
+/// Submission result json: `alreadyExists` flag and `response` - reply to the write request
+/// or reply to the read request fetching existing entity.
+pub fn submit_request_idempotent(pool_handle: PoolHandle, request_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _submit_request_idempotent(command_handle, pool_handle, request_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _submit_request_idempotent(command_handle: CommandHandle, pool_handle: PoolHandle, request_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let request_json = c_str!(request_json);
+
+    ErrorCode::from(unsafe { ledger::indy_submit_request_idempotent(command_handle, pool_handle, request_json.as_ptr(), cb) })
+}
+
+/// Publishes several request messages to validator pool at once (no signing).
+///
+/// Requests are sent concurrently and failure of one request doesn't affect other requests of the batch.
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by Pool::open_ledger).
+/// * `requests_json` - json array of prepared requests.
+///
+/// # Returns
+/// Json array of results in the same order as requests.
+/// Every result contains either `response` (request result json) or `error` (`code` and `message`).
+pub fn submit_requests(pool_handle: PoolHandle, requests_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _submit_requests(command_handle, pool_handle, requests_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _submit_requests(command_handle: CommandHandle, pool_handle: PoolHandle, requests_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let requests_json = c_str!(requests_json);
+
+    ErrorCode::from(unsafe { ledger::indy_submit_requests(command_handle, pool_handle, requests_json.as_ptr(), cb) })
+}
+
+/// Fetches a range of transactions from the ledger in one call (GET_TXN requests are sent by batches).
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by Pool::open_ledger).
+/// * `submitter_did` - (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+/// * `ledger_type` - (Optional) type of the ledger the requested transactions belong to:
+///     DOMAIN - used default,
+///     POOL,
+///     CONFIG
+///     any number
+/// * `from` - sequence number of the first requested transaction.
+/// * `to` - sequence number of the last requested transaction. Fetching stops at the end of the ledger.
+///
+/// # Returns
+/// Json array of transactions ordered by sequence number (transactions not found on the ledger are omitted).
+pub fn get_txns(pool_handle: PoolHandle, submitter_did: Option<&str>, ledger_type: Option<&str>, from: i32, to: i32) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _get_txns(command_handle, pool_handle, submitter_did, ledger_type, from, to, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _get_txns(command_handle: CommandHandle, pool_handle: PoolHandle, submitter_did: Option<&str>, ledger_type: Option<&str>, from: i32, to: i32, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did_str = opt_c_str!(submitter_did);
+    let ledger_type_str = opt_c_str!(ledger_type);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_get_txns(command_handle,
+                              pool_handle,
+                              opt_c_ptr!(submitter_did, submitter_did_str),
+                              opt_c_ptr!(ledger_type, ledger_type_str),
+                              from,
+                              to,
+                              cb)
+    })
+}
+
+lazy_static! {
+    static ref STREAM_TXNS_CALLBACKS: Mutex<HashMap<CommandHandle, Box<dyn Fn(String) + Send>>> = Default::default();
+}
+
+/// Streams a range of transactions from the ledger.
+///
+/// Transactions are passed to `txns_cb` by batches as soon as every batch is fetched.
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by Pool::open_ledger).
+/// * `submitter_did` - (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+/// * `ledger_type` - (Optional) type of the ledger the requested transactions belong to.
+/// * `from` - sequence number of the first requested transaction.
+/// * `to` - sequence number of the last requested transaction. Fetching stops at the end of the ledger.
+/// * `txns_cb` - callback that takes json array of transactions of a batch ordered by sequence number.
+pub fn stream_txns(pool_handle: PoolHandle, submitter_did: Option<&str>, ledger_type: Option<&str>, from: i32, to: i32,
+                   txns_cb: Box<dyn Fn(String) + Send>) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    STREAM_TXNS_CALLBACKS.lock().unwrap().insert(command_handle, txns_cb);
+
+    let err = _stream_txns(command_handle, pool_handle, submitter_did, ledger_type, from, to, cb);
+
+    Box::new(ResultHandler::empty(command_handle, err, receiver)
+        .then(move |res| {
+            STREAM_TXNS_CALLBACKS.lock().unwrap().remove(&command_handle);
+            res
+        }))
+}
+
+extern fn _stream_txns_callback(command_handle: CommandHandle, txns_json: *const c_char) {
+    if let Some(txns_cb) = STREAM_TXNS_CALLBACKS.lock().unwrap().get(&command_handle) {
+        txns_cb(rust_str!(txns_json))
+    }
+}
+
+fn _stream_txns(command_handle: CommandHandle, pool_handle: PoolHandle, submitter_did: Option<&str>, ledger_type: Option<&str>, from: i32, to: i32, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let submitter_did_str = opt_c_str!(submitter_did);
+    let ledger_type_str = opt_c_str!(ledger_type);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_stream_txns(command_handle,
+                                 pool_handle,
+                                 opt_c_ptr!(submitter_did, submitter_did_str),
+                                 opt_c_ptr!(ledger_type, ledger_type_str),
+                                 from,
+                                 to,
+                                 Some(_stream_txns_callback),
+                                 cb)
+    })
+}
+
+lazy_static! {
+    static ref PENDING_SUBSCRIPTIONS: Mutex<HashMap<CommandHandle, (Box<dyn Fn(String) + Send>, ResponseI32CB)>> = Default::default();
+    static ref LEDGER_EVENTS_CALLBACKS: Mutex<HashMap<IndyHandle, Box<dyn Fn(String) + Send>>> = Default::default();
+}
+
+/// Subscribes to notifications about new transactions written to the ledger.
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by Pool::open_ledger).
+/// * `filter_json` - filter of transactions to notify about:
+///     {
+///         "dids": [<string>, ...], - (Optional) DIDs to notify about transactions authored by or targeting them
+///         "txnTypes": [<string>, ...], - (Optional) types of transactions to notify about (f.e. NYM or 1)
+///         "ledgerType": <string>, - (Optional) type of the ledger to watch (DOMAIN - used default, POOL, CONFIG or any number)
+///         "fromSeqNo": <int>, - (Optional) sequence number of the first transaction to check (1 - used default)
+///         "pollInterval": <int>, - (Optional) interval in seconds between polls of the ledger (10 - used default)
+///     }
+/// * `event_cb` - callback that takes json of every matched transaction.
+///
+/// # Returns
+/// Handle of the subscription.
+pub fn subscribe_ledger_events(pool_handle: PoolHandle, filter_json: &str,
+                               event_cb: Box<dyn Fn(String) + Send>) -> Box<dyn Future<Item=IndyHandle, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_handle();
+
+    PENDING_SUBSCRIPTIONS.lock().unwrap().insert(command_handle, (event_cb, cb.unwrap()));
+
+    let err = _subscribe_ledger_events(command_handle, pool_handle, filter_json);
+
+    if err != ErrorCode::Success {
+        PENDING_SUBSCRIPTIONS.lock().unwrap().remove(&command_handle);
+    }
+
+    ResultHandler::handle(command_handle, err, receiver)
+}
+
+// The event callback is registered before libindy starts polling the ledger
+// so no event is lost while the future isn't resolved yet.
+extern fn _subscribe_ledger_events_callback(command_handle: CommandHandle, err: i32, subscription_handle: IndyHandle) {
+    if let Some((event_cb, cb)) = PENDING_SUBSCRIPTIONS.lock().unwrap().remove(&command_handle) {
+        if err == 0 {
+            LEDGER_EVENTS_CALLBACKS.lock().unwrap().insert(subscription_handle, event_cb);
+        }
+        cb(command_handle, err, subscription_handle)
+    }
+}
+
+extern fn _ledger_event_callback(subscription_handle: IndyHandle, event_json: *const c_char) {
+    if let Some(event_cb) = LEDGER_EVENTS_CALLBACKS.lock().unwrap().get(&subscription_handle) {
+        event_cb(rust_str!(event_json))
+    }
+}
+
+fn _subscribe_ledger_events(command_handle: CommandHandle, pool_handle: PoolHandle, filter_json: &str) -> ErrorCode {
+    let filter_json = c_str!(filter_json);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_subscribe_ledger_events(command_handle,
+                                             pool_handle,
+                                             filter_json.as_ptr(),
+                                             Some(_ledger_event_callback),
+                                             Some(_subscribe_ledger_events_callback))
+    })
+}
+
+/// Cancels subscription to ledger events created by `subscribe_ledger_events`.
+///
+/// # Arguments
+/// * `subscription_handle` - handle of the subscription.
+pub fn unsubscribe_ledger_events(subscription_handle: IndyHandle) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _unsubscribe_ledger_events(command_handle, subscription_handle, cb);
+
+    Box::new(ResultHandler::empty(command_handle, err, receiver)
+        .then(move |res| {
+            LEDGER_EVENTS_CALLBACKS.lock().unwrap().remove(&subscription_handle);
+            res
+        }))
+}
+
+fn _unsubscribe_ledger_events(command_handle: CommandHandle, subscription_handle: IndyHandle, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    ErrorCode::from(unsafe {
+        ledger::indy_unsubscribe_ledger_events(command_handle, subscription_handle, cb)
+    })
+}
+
 pub fn submit_action(pool_handle: PoolHandle, request_json: &str, nodes: Option<&str>, wait_timeout: Option<i32>) -> Box<dyn Future<Item=String, Error=IndyError>> {
     let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
 
@@ -118,6 +381,31 @@ fn _sign_request(command_handle: CommandHandle, wallet_handle: WalletHandle, sub
     ErrorCode::from(unsafe { ledger::indy_sign_request(command_handle, wallet_handle, submitter_did.as_ptr(), request_json.as_ptr(), cb) })
 }
 
+/// Signs request message as `sign_request` does but even if submitter DID is deactivated
+/// (see Did::deactivate_did).
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by Wallet::open).
+/// * `submitter_did` - Id of Identity stored in secured Wallet.
+/// * `request_json` - Request data json.
+///
+/// # Returns
+/// Signed request json.
+pub fn force_sign_request(wallet_handle: WalletHandle, submitter_did: &str, request_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _force_sign_request(command_handle, wallet_handle, submitter_did, request_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _force_sign_request(command_handle: CommandHandle, wallet_handle: WalletHandle, submitter_did: &str, request_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did = c_str!(submitter_did);
+    let request_json = c_str!(request_json);
+
+    ErrorCode::from(unsafe { ledger::indy_force_sign_request(command_handle, wallet_handle, submitter_did.as_ptr(), request_json.as_ptr(), cb) })
+}
+
 /// Multi signs request message.
 ///
 /// Adds submitter information to passed request json, signs it with submitter
@@ -145,6 +433,49 @@ fn _multi_sign_request(command_handle: CommandHandle, wallet_handle: WalletHandl
     ErrorCode::from(unsafe { ledger::indy_multi_sign_request(command_handle, wallet_handle, submitter_did.as_ptr(), request_json.as_ptr(), cb) })
 }
 
+/// Merges several multi signed copies of the same request into one request.
+///
+/// # Arguments
+/// * `requests_json` - json array of signed copies of the request.
+///
+/// # Returns
+/// Request json containing signatures of all signers.
+pub fn merge_multi_signed_requests(requests_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _merge_multi_signed_requests(command_handle, requests_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _merge_multi_signed_requests(command_handle: CommandHandle, requests_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let requests_json = c_str!(requests_json);
+
+    ErrorCode::from(unsafe { ledger::indy_merge_multi_signed_requests(command_handle, requests_json.as_ptr(), cb) })
+}
+
+/// Checks whether signatures of the request satisfy the ledger auth rules.
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by Pool::open_ledger).
+/// * `request_json` - signed request json.
+///
+/// # Returns
+/// Report json with signers, missing signers and signatures and `complete` flag.
+pub fn check_request_signatures(pool_handle: PoolHandle, request_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _check_request_signatures(command_handle, pool_handle, request_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _check_request_signatures(command_handle: CommandHandle, pool_handle: PoolHandle, request_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let request_json = c_str!(request_json);
+
+    ErrorCode::from(unsafe { ledger::indy_check_request_signatures(command_handle, pool_handle, request_json.as_ptr(), cb) })
+}
+
 /// Builds a request to get a DDO.
 ///
 /// # Arguments
@@ -220,6 +551,40 @@ fn _build_nym_request(command_handle: CommandHandle,
     })
 }
 
+/// Builds a NYM request with the fields introduced by did:indy method.
+///
+/// # Arguments
+/// * `submitter_did` - Identifier (DID) of the transaction author as base58-encoded string.
+/// * `target_did` - Target DID as base58-encoded string for 16 or 32 bit DID value.
+/// * `nym_data_json` - NYM data
+///     {
+///         "verkey": <string> (optional) - target identity verification key as base58-encoded string.
+///         "alias": <string> (optional) - NYM's alias.
+///         "role": <string> (optional) - TRUSTEE, STEWARD, ENDORSER, NETWORK_MONITOR or RESET to reset role.
+///         "diddocContent": <object> (optional) - DID Document content to be merged into the ledger DID Document.
+///         "version": <int> (optional) - DID self-certification check: 0 - none, 1 - did:sov, 2 - did:indy.
+///     }
+///
+/// # Returns
+/// Request result as json.
+pub fn build_nym_request_with_data(submitter_did: &str, target_did: &str, nym_data_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_nym_request_with_data(command_handle, submitter_did, target_did, nym_data_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_nym_request_with_data(command_handle: CommandHandle, submitter_did: &str, target_did: &str, nym_data_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did = c_str!(submitter_did);
+    let target_did = c_str!(target_did);
+    let nym_data_json = c_str!(nym_data_json);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_build_nym_request_with_data(command_handle, submitter_did.as_ptr(), target_did.as_ptr(), nym_data_json.as_ptr(), cb)
+    })
+}
+
 /// Builds a GET_NYM request. Request to get information about a DID (NYM).
 ///
 /// # Arguments
@@ -243,6 +608,42 @@ fn _build_get_nym_request(command_handle: CommandHandle, submitter_did: Option<&
     ErrorCode::from(unsafe { ledger::indy_build_get_nym_request(command_handle, opt_c_ptr!(submitter_did, submitter_did_str), target_did.as_ptr(), cb) })
 }
 
+/// Builds a GET_NYM request to get information about a DID (NYM) as it was at some point in the past.
+///
+/// Note: only one of `seq_no` and `timestamp` can be specified.
+///
+/// # Arguments
+/// * `submitter_did` - (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+/// * `target_did` - Target DID as base58-encoded string for 16 or 32 bit DID value.
+/// * `seq_no` - (Optional) return NYM state as of the transaction with this sequence number.
+/// * `timestamp` - (Optional) return NYM state as of this time.
+///
+/// # Returns
+/// Request result as json.
+pub fn build_get_historical_nym_request(submitter_did: Option<&str>, target_did: &str, seq_no: Option<u64>, timestamp: Option<u64>) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_get_historical_nym_request(command_handle, submitter_did, target_did, seq_no, timestamp, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_get_historical_nym_request(command_handle: CommandHandle, submitter_did: Option<&str>, target_did: &str, seq_no: Option<u64>, timestamp: Option<u64>, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did_str = opt_c_str!(submitter_did);
+    let target_did = c_str!(target_did);
+    let seq_no = opt_u64!(seq_no);
+    let timestamp = opt_u64!(timestamp);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_build_get_historical_nym_request(command_handle,
+                                                      opt_c_ptr!(submitter_did, submitter_did_str),
+                                                      target_did.as_ptr(),
+                                                      seq_no,
+                                                      timestamp,
+                                                      cb)
+    })
+}
+
 /// Parse a GET_NYM response to get NYM data.
 ///
 /// # Arguments
@@ -260,6 +661,8 @@ fn _build_get_nym_request(command_handle: CommandHandle, submitter_did: Option<&
 ///                             101 - TRUST_ANCHOR
 ///                             101 - ENDORSER - equal to TRUST_ANCHOR that will be removed soon
 ///                             201 - NETWORK_MONITOR
+///     seqNo: (optional) sequence number of the NYM transaction,
+///     txnTime: (optional) time of the NYM transaction
 /// }
 pub fn parse_get_nym_response(get_nym_response: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
     let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
@@ -275,6 +678,178 @@ fn _parse_get_nym_response(command_handle: CommandHandle, get_nym_response: &str
     ErrorCode::from(unsafe { ledger::indy_parse_get_nym_response(command_handle, get_nym_response.as_ptr(), cb) })
 }
 
+/// Parse a GET_ATTRIB response to get attribute data.
+///
+/// # Arguments
+/// * `get_attrib_response` - response on GET_ATTRIB request.
+///
+/// # Returns
+/// Attribute data as json: {"data": <data>, "seqNo": <int> (optional), "txnTime": <int> (optional)}
+pub fn parse_get_attrib_response(get_attrib_response: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _parse_get_attrib_response(command_handle, get_attrib_response, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _parse_get_attrib_response(command_handle: CommandHandle, get_attrib_response: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let get_attrib_response = c_str!(get_attrib_response);
+
+    ErrorCode::from(unsafe { ledger::indy_parse_get_attrib_response(command_handle, get_attrib_response.as_ptr(), cb) })
+}
+
+/// Parse a GET_TXN response to get transaction data.
+///
+/// # Arguments
+/// * `get_txn_response` - response on GET_TXN request.
+///
+/// # Returns
+/// Transaction data as json: {"data": <data>, "seqNo": <int> (optional), "txnTime": <int> (optional)}
+pub fn parse_get_txn_response(get_txn_response: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _parse_get_txn_response(command_handle, get_txn_response, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _parse_get_txn_response(command_handle: CommandHandle, get_txn_response: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let get_txn_response = c_str!(get_txn_response);
+
+    ErrorCode::from(unsafe { ledger::indy_parse_get_txn_response(command_handle, get_txn_response.as_ptr(), cb) })
+}
+
+/// Parse a GET_AUTH_RULE response to get list of auth rules.
+///
+/// # Arguments
+/// * `get_auth_rule_response` - response on GET_AUTH_RULE request.
+///
+/// # Returns
+/// Auth rules as json: {"data": <data>, "seqNo": <int> (optional), "txnTime": <int> (optional)}
+pub fn parse_get_auth_rule_response(get_auth_rule_response: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _parse_get_auth_rule_response(command_handle, get_auth_rule_response, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _parse_get_auth_rule_response(command_handle: CommandHandle, get_auth_rule_response: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let get_auth_rule_response = c_str!(get_auth_rule_response);
+
+    ErrorCode::from(unsafe { ledger::indy_parse_get_auth_rule_response(command_handle, get_auth_rule_response.as_ptr(), cb) })
+}
+
+/// Parse a response on GET_VALIDATOR_INFO action (see indy_submit_action) to get validator info of each node.
+///
+/// # Arguments
+/// * `get_validator_info_response` - response of `submit_action` on GET_VALIDATOR_INFO request.
+///
+/// # Returns
+/// Validator info as json: {"data": <data>, "seqNo": <int> (optional), "txnTime": <int> (optional)}
+pub fn parse_get_validator_info_response(get_validator_info_response: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _parse_get_validator_info_response(command_handle, get_validator_info_response, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _parse_get_validator_info_response(command_handle: CommandHandle, get_validator_info_response: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let get_validator_info_response = c_str!(get_validator_info_response);
+
+    ErrorCode::from(unsafe { ledger::indy_parse_get_validator_info_response(command_handle, get_validator_info_response.as_ptr(), cb) })
+}
+
+/// Parse a response on GET_VALIDATOR_INFO action (see indy_submit_action) to get validator info of each node
+/// normalized into a common structure regardless of the node version.
+///
+/// # Arguments
+/// * `get_validator_info_response` - response of `submit_action` on GET_VALIDATOR_INFO request.
+///
+/// # Returns
+/// Validator info summary as json: {"data": {<node alias>: {"alias", "nodeVersion", "mode", "uptime", "ledgerSizes", "pool", "timestamp", "error"}}}
+/// (fields that aren't reported by the node are omitted)
+pub fn parse_get_validator_info_summary_response(get_validator_info_response: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _parse_get_validator_info_summary_response(command_handle, get_validator_info_response, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _parse_get_validator_info_summary_response(command_handle: CommandHandle, get_validator_info_response: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let get_validator_info_response = c_str!(get_validator_info_response);
+
+    ErrorCode::from(unsafe { ledger::indy_parse_get_validator_info_summary_response(command_handle, get_validator_info_response.as_ptr(), cb) })
+}
+
+/// Parse a response on GET_VALIDATOR_INFO action (see indy_submit_action) to get upgrade status of each node
+/// taken from the last record of the node upgrade log.
+///
+/// # Arguments
+/// * `get_validator_info_response` - response of `submit_action` on GET_VALIDATOR_INFO request.
+///
+/// # Returns
+/// Upgrade status as json: {"data": {<node alias>: {"alias", "nodeVersion", "lastEvent", "error"}}}
+/// (fields that aren't reported by the node are omitted)
+pub fn parse_pool_upgrade_status_response(get_validator_info_response: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _parse_pool_upgrade_status_response(command_handle, get_validator_info_response, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _parse_pool_upgrade_status_response(command_handle: CommandHandle, get_validator_info_response: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let get_validator_info_response = c_str!(get_validator_info_response);
+
+    ErrorCode::from(unsafe { ledger::indy_parse_pool_upgrade_status_response(command_handle, get_validator_info_response.as_ptr(), cb) })
+}
+
+/// Parse a GET_TXN_AUTHR_AGRMT response to get Transaction Author Agreement data.
+///
+/// # Arguments
+/// * `get_txn_author_agreement_response` - response on GET_TXN_AUTHR_AGRMT request.
+///
+/// # Returns
+/// Transaction Author Agreement data as json: {"data": <data>, "seqNo": <int> (optional), "txnTime": <int> (optional)}
+pub fn parse_get_txn_author_agreement_response(get_txn_author_agreement_response: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _parse_get_txn_author_agreement_response(command_handle, get_txn_author_agreement_response, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _parse_get_txn_author_agreement_response(command_handle: CommandHandle, get_txn_author_agreement_response: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let get_txn_author_agreement_response = c_str!(get_txn_author_agreement_response);
+
+    ErrorCode::from(unsafe { ledger::indy_parse_get_txn_author_agreement_response(command_handle, get_txn_author_agreement_response.as_ptr(), cb) })
+}
+
+/// Parse a GET_TXN_AUTHR_AGRMT_AML response to get acceptance mechanisms data.
+///
+/// # Arguments
+/// * `get_acceptance_mechanisms_response` - response on GET_TXN_AUTHR_AGRMT_AML request.
+///
+/// # Returns
+/// Acceptance mechanisms data as json: {"data": <data>, "seqNo": <int> (optional), "txnTime": <int> (optional)}
+pub fn parse_get_acceptance_mechanisms_response(get_acceptance_mechanisms_response: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _parse_get_acceptance_mechanisms_response(command_handle, get_acceptance_mechanisms_response, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _parse_get_acceptance_mechanisms_response(command_handle: CommandHandle, get_acceptance_mechanisms_response: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let get_acceptance_mechanisms_response = c_str!(get_acceptance_mechanisms_response);
+
+    ErrorCode::from(unsafe { ledger::indy_parse_get_acceptance_mechanisms_response(command_handle, get_acceptance_mechanisms_response.as_ptr(), cb) })
+}
+
 /// Builds a GET_TXN request. Request to get any transaction by its seq_no.
 ///
 /// # Arguments
@@ -287,95 +862,214 @@ fn _parse_get_nym_response(command_handle: CommandHandle, get_nym_response: &str
 ///
 /// # Returns
 /// Request result as json.
-pub fn build_get_txn_request(submitter_did: Option<&str>, ledger_type: Option<&str>, seq_no: i32) -> Box<dyn Future<Item=String, Error=IndyError>> {
+pub fn build_get_txn_request(submitter_did: Option<&str>, ledger_type: Option<&str>, seq_no: i32) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_get_txn_request(command_handle, submitter_did, ledger_type, seq_no, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_get_txn_request(command_handle: CommandHandle, submitter_did: Option<&str>, ledger_type: Option<&str>, seq_no: i32, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did_str = opt_c_str!(submitter_did);
+    let ledger_type_str = opt_c_str!(ledger_type);
+
+    ErrorCode::from(unsafe { ledger::indy_build_get_txn_request(command_handle, opt_c_ptr!(submitter_did, submitter_did_str), opt_c_ptr!(ledger_type, ledger_type_str), seq_no, cb) })
+}
+
+/// Builds an ATTRIB request. Request to add attribute to a NYM record.
+///
+/// # Arguments
+/// * `submitter_did` - Identifier (DID) of the transaction author as base58-encoded string.
+///                Actual request sender may differ if Endorser is used (look at `append_request_endorser`)
+/// * `target_did` - Target DID as base58-encoded string for 16 or 32 bit DID value.
+/// * `hash` - (Optional) Hash of attribute data.
+/// * `raw` - (Optional) Json, where key is attribute name and value is attribute value.
+/// * `enc` - (Optional) Encrypted value attribute data.
+///
+/// # Returns
+/// Request result as json.
+pub fn build_attrib_request(submitter_did: &str, target_did: &str, hash: Option<&str>, raw: Option<&str>, enc: Option<&str>) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_attrib_request(command_handle, submitter_did, target_did, hash, raw, enc, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_attrib_request(command_handle: CommandHandle, submitter_did: &str, target_did: &str, hash: Option<&str>, raw: Option<&str>, enc: Option<&str>, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did = c_str!(submitter_did);
+    let target_did = c_str!(target_did);
+
+    let hash_str = opt_c_str!(hash);
+    let raw_str = opt_c_str!(raw);
+    let enc_str = opt_c_str!(enc);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_build_attrib_request(command_handle,
+                                          submitter_did.as_ptr(),
+                                          target_did.as_ptr(),
+                                          opt_c_ptr!(hash, hash_str),
+                                          opt_c_ptr!(raw, raw_str),
+                                          opt_c_ptr!(enc, enc_str),
+                                          cb)
+    })
+}
+
+/// Builds a GET_ATTRIB request. Request to get information about an Attribute for the specified DID.
+///
+/// # Arguments
+/// * `submitter_did` - DID of the read request sender.
+/// * `target_did` - Target DID as base58-encoded string for 16 or 32 bit DID value.
+/// * `raw` - (Optional) Requested attribute name.
+/// * `hash` - (Optional) Requested attribute hash.
+/// * `enc` - (Optional) Requested attribute encrypted value.
+///
+/// # Returns
+/// Request result as json.
+pub fn build_get_attrib_request(submitter_did: Option<&str>, target_did: &str, raw: Option<&str>, hash: Option<&str>, enc: Option<&str>) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_get_attrib_request(command_handle, submitter_did, target_did, raw, hash, enc, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_get_attrib_request(command_handle: CommandHandle, submitter_did: Option<&str>, target_did: &str, raw: Option<&str>, hash: Option<&str>, enc: Option<&str>, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did_str = opt_c_str!(submitter_did);
+    let target_did = c_str!(target_did);
+
+    let raw_str = opt_c_str!(raw);
+    let hash_str = opt_c_str!(hash);
+    let enc_str = opt_c_str!(enc);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_build_get_attrib_request(command_handle,
+                                              opt_c_ptr!(submitter_did, submitter_did_str),
+                                              target_did.as_ptr(),
+                                              opt_c_ptr!(raw, raw_str),
+                                              opt_c_ptr!(hash, hash_str),
+                                              opt_c_ptr!(enc, enc_str),
+                                              cb)
+    })
+}
+
+/// Builds a GET_ATTRIB request to get information about an Attribute for the specified DID
+/// as it was at some point in the past.
+///
+/// Note: only one of `seq_no` and `timestamp` can be specified.
+///
+/// # Arguments
+/// * `submitter_did` - DID of the read request sender.
+/// * `target_did` - Target DID as base58-encoded string for 16 or 32 bit DID value.
+/// * `raw` - (Optional) Requested attribute name.
+/// * `hash` - (Optional) Requested attribute hash.
+/// * `enc` - (Optional) Requested attribute encrypted value.
+/// * `seq_no` - (Optional) return attribute state as of the transaction with this sequence number.
+/// * `timestamp` - (Optional) return attribute state as of this time.
+///
+/// # Returns
+/// Request result as json.
+pub fn build_get_historical_attrib_request(submitter_did: Option<&str>, target_did: &str, raw: Option<&str>, hash: Option<&str>, enc: Option<&str>,
+                                           seq_no: Option<u64>, timestamp: Option<u64>) -> Box<dyn Future<Item=String, Error=IndyError>> {
     let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
 
-    let err = _build_get_txn_request(command_handle, submitter_did, ledger_type, seq_no, cb);
+    let err = _build_get_historical_attrib_request(command_handle, submitter_did, target_did, raw, hash, enc, seq_no, timestamp, cb);
 
     ResultHandler::str(command_handle, err, receiver)
 }
 
-fn _build_get_txn_request(command_handle: CommandHandle, submitter_did: Option<&str>, ledger_type: Option<&str>, seq_no: i32, cb: Option<ResponseStringCB>) -> ErrorCode {
+fn _build_get_historical_attrib_request(command_handle: CommandHandle,
+                                        submitter_did: Option<&str>,
+                                        target_did: &str,
+                                        raw: Option<&str>,
+                                        hash: Option<&str>,
+                                        enc: Option<&str>,
+                                        seq_no: Option<u64>,
+                                        timestamp: Option<u64>,
+                                        cb: Option<ResponseStringCB>) -> ErrorCode {
     let submitter_did_str = opt_c_str!(submitter_did);
-    let ledger_type_str = opt_c_str!(ledger_type);
+    let target_did = c_str!(target_did);
 
-    ErrorCode::from(unsafe { ledger::indy_build_get_txn_request(command_handle, opt_c_ptr!(submitter_did, submitter_did_str), opt_c_ptr!(ledger_type, ledger_type_str), seq_no, cb) })
+    let raw_str = opt_c_str!(raw);
+    let hash_str = opt_c_str!(hash);
+    let enc_str = opt_c_str!(enc);
+
+    let seq_no = opt_u64!(seq_no);
+    let timestamp = opt_u64!(timestamp);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_build_get_historical_attrib_request(command_handle,
+                                                         opt_c_ptr!(submitter_did, submitter_did_str),
+                                                         target_did.as_ptr(),
+                                                         opt_c_ptr!(raw, raw_str),
+                                                         opt_c_ptr!(hash, hash_str),
+                                                         opt_c_ptr!(enc, enc_str),
+                                                         seq_no,
+                                                         timestamp,
+                                                         cb)
+    })
 }
 
-/// Builds an ATTRIB request. Request to add attribute to a NYM record.
+/// Builds an ATTRIB request publishing endpoint of the DID as `raw` attrib.
 ///
 /// # Arguments
 /// * `submitter_did` - Identifier (DID) of the transaction author as base58-encoded string.
-///                Actual request sender may differ if Endorser is used (look at `append_request_endorser`)
 /// * `target_did` - Target DID as base58-encoded string for 16 or 32 bit DID value.
-/// * `hash` - (Optional) Hash of attribute data.
-/// * `raw` - (Optional) Json, where key is attribute name and value is attribute value.
-/// * `enc` - (Optional) Encrypted value attribute data.
+/// * `address` - Endpoint address in ip-address:port format.
+/// * `transport_key` - (Optional) Endpoint transport verkey as base58-encoded string.
 ///
 /// # Returns
 /// Request result as json.
-pub fn build_attrib_request(submitter_did: &str, target_did: &str, hash: Option<&str>, raw: Option<&str>, enc: Option<&str>) -> Box<dyn Future<Item=String, Error=IndyError>> {
+pub fn build_set_endpoint_request(submitter_did: &str, target_did: &str, address: &str, transport_key: Option<&str>) -> Box<dyn Future<Item=String, Error=IndyError>> {
     let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
 
-    let err = _build_attrib_request(command_handle, submitter_did, target_did, hash, raw, enc, cb);
+    let err = _build_set_endpoint_request(command_handle, submitter_did, target_did, address, transport_key, cb);
 
     ResultHandler::str(command_handle, err, receiver)
 }
 
-fn _build_attrib_request(command_handle: CommandHandle, submitter_did: &str, target_did: &str, hash: Option<&str>, raw: Option<&str>, enc: Option<&str>, cb: Option<ResponseStringCB>) -> ErrorCode {
+fn _build_set_endpoint_request(command_handle: CommandHandle, submitter_did: &str, target_did: &str, address: &str, transport_key: Option<&str>, cb: Option<ResponseStringCB>) -> ErrorCode {
     let submitter_did = c_str!(submitter_did);
     let target_did = c_str!(target_did);
-
-    let hash_str = opt_c_str!(hash);
-    let raw_str = opt_c_str!(raw);
-    let enc_str = opt_c_str!(enc);
+    let address = c_str!(address);
+    let transport_key_str = opt_c_str!(transport_key);
 
     ErrorCode::from(unsafe {
-        ledger::indy_build_attrib_request(command_handle,
-                                          submitter_did.as_ptr(),
-                                          target_did.as_ptr(),
-                                          opt_c_ptr!(hash, hash_str),
-                                          opt_c_ptr!(raw, raw_str),
-                                          opt_c_ptr!(enc, enc_str),
-                                          cb)
+        ledger::indy_build_set_endpoint_request(command_handle,
+                                                submitter_did.as_ptr(),
+                                                target_did.as_ptr(),
+                                                address.as_ptr(),
+                                                opt_c_ptr!(transport_key, transport_key_str),
+                                                cb)
     })
 }
 
-/// Builds a GET_ATTRIB request. Request to get information about an Attribute for the specified DID.
+/// Builds a GET_ATTRIB request to get endpoint of the DID.
 ///
 /// # Arguments
-/// * `submitter_did` - DID of the read request sender.
+/// * `submitter_did` - (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
 /// * `target_did` - Target DID as base58-encoded string for 16 or 32 bit DID value.
-/// * `raw` - (Optional) Requested attribute name.
-/// * `hash` - (Optional) Requested attribute hash.
-/// * `enc` - (Optional) Requested attribute encrypted value.
 ///
 /// # Returns
 /// Request result as json.
-pub fn build_get_attrib_request(submitter_did: Option<&str>, target_did: &str, raw: Option<&str>, hash: Option<&str>, enc: Option<&str>) -> Box<dyn Future<Item=String, Error=IndyError>> {
+pub fn build_get_endpoint_request(submitter_did: Option<&str>, target_did: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
     let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
 
-    let err = _build_get_attrib_request(command_handle, submitter_did, target_did, raw, hash, enc, cb);
+    let err = _build_get_endpoint_request(command_handle, submitter_did, target_did, cb);
 
     ResultHandler::str(command_handle, err, receiver)
 }
 
-fn _build_get_attrib_request(command_handle: CommandHandle, submitter_did: Option<&str>, target_did: &str, raw: Option<&str>, hash: Option<&str>, enc: Option<&str>, cb: Option<ResponseStringCB>) -> ErrorCode {
+fn _build_get_endpoint_request(command_handle: CommandHandle, submitter_did: Option<&str>, target_did: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
     let submitter_did_str = opt_c_str!(submitter_did);
     let target_did = c_str!(target_did);
 
-    let raw_str = opt_c_str!(raw);
-    let hash_str = opt_c_str!(hash);
-    let enc_str = opt_c_str!(enc);
-
     ErrorCode::from(unsafe {
-        ledger::indy_build_get_attrib_request(command_handle,
-                                              opt_c_ptr!(submitter_did, submitter_did_str),
-                                              target_did.as_ptr(),
-                                              opt_c_ptr!(raw, raw_str),
-                                              opt_c_ptr!(hash, hash_str),
-                                              opt_c_ptr!(enc, enc_str),
-                                              cb)
+        ledger::indy_build_get_endpoint_request(command_handle,
+                                                opt_c_ptr!(submitter_did, submitter_did_str),
+                                                target_did.as_ptr(),
+                                                cb)
     })
 }
 
@@ -747,6 +1441,38 @@ fn _build_pool_upgrade_request(command_handle: CommandHandle,
     })
 }
 
+/// Builds a POOL_UPGRADE request starting the upgrade by the schedule defined relative to a start time.
+/// Nodes are referenced by aliases which are resolved to Node DIDs using the ledger of the opened pool.
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by open_pool_ledger).
+/// * `submitter_did` - Identifier (DID) of the transaction author as base58-encoded string.
+/// * `upgrade_json` - upgrade description:
+///     {
+///         "name": string, "version": string, "sha256": string, "package": (optional) string,
+///         "startTime": int - UNIX timestamp in seconds,
+///         "nodeOffsets": {<node alias>: <offset in seconds from start time>, ...},
+///         "timeout": (optional) int, "justification": (optional) string,
+///         "reinstall": (optional) bool, "force": (optional) bool
+///     }
+///
+/// # Returns
+/// Request result as json.
+pub fn build_scheduled_pool_upgrade_request(pool_handle: PoolHandle, submitter_did: &str, upgrade_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_scheduled_pool_upgrade_request(command_handle, pool_handle, submitter_did, upgrade_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_scheduled_pool_upgrade_request(command_handle: CommandHandle, pool_handle: PoolHandle, submitter_did: &str, upgrade_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did = c_str!(submitter_did);
+    let upgrade_json = c_str!(upgrade_json);
+
+    ErrorCode::from(unsafe { ledger::indy_build_scheduled_pool_upgrade_request(command_handle, pool_handle, submitter_did.as_ptr(), upgrade_json.as_ptr(), cb) })
+}
+
 /// Builds a REVOC_REG_DEF request. Request to add the definition of revocation registry
 /// to an exists credential definition.
 ///
@@ -995,6 +1721,64 @@ fn _parse_get_revoc_reg_delta_response(command_handle: CommandHandle, get_revoc_
     ErrorCode::from(unsafe { ledger::indy_parse_get_revoc_reg_delta_response(command_handle, get_revoc_reg_delta_response.as_ptr(), cb) })
 }
 
+/// Builds a request of custom transaction type by the builder registered
+/// with `indy_register_ledger_transaction_builder`.
+///
+/// # Arguments
+/// * `submitter_did` - (Optional) DID of the request sender (if not provided then default Libindy DID will be used).
+/// * `txn_type` - type of custom transaction.
+/// * `data_json` - transaction specific data passed to the registered builder.
+///
+/// # Returns
+/// Request result as json.
+pub fn build_custom_request(submitter_did: Option<&str>, txn_type: &str, data_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_custom_request(command_handle, submitter_did, txn_type, data_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_custom_request(command_handle: CommandHandle, submitter_did: Option<&str>, txn_type: &str, data_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did_str = opt_c_str!(submitter_did);
+    let txn_type = c_str!(txn_type);
+    let data_json = c_str!(data_json);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_build_custom_request(command_handle,
+                                          opt_c_ptr!(submitter_did, submitter_did_str),
+                                          txn_type.as_ptr(),
+                                          data_json.as_ptr(),
+                                          cb)
+    })
+}
+
+/// Parses a reply for a request of custom transaction type by the parser registered
+/// with `indy_register_ledger_transaction_builder`.
+///
+/// # Arguments
+/// * `txn_type` - type of custom transaction.
+/// * `response_json` - response on a request of custom transaction type.
+///
+/// # Returns
+/// Json returned by the registered parser.
+pub fn parse_custom_response(txn_type: &str, response_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _parse_custom_response(command_handle, txn_type, response_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _parse_custom_response(command_handle: CommandHandle, txn_type: &str, response_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let txn_type = c_str!(txn_type);
+    let response_json = c_str!(response_json);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_parse_custom_response(command_handle, txn_type.as_ptr(), response_json.as_ptr(), cb)
+    })
+}
+
 /// Parse transaction response to fetch metadata.
 /// The important use case for this method is validation of Node's response freshens.
 ///
@@ -1039,6 +1823,40 @@ fn _get_response_metadata(command_handle: CommandHandle, response: &str, cb: Opt
     ErrorCode::from(unsafe { ledger::indy_get_response_metadata(command_handle, response.as_ptr(), cb) })
 }
 
+/// Verifies state proof and multi signature of the pool nodes for a reply on read request
+/// obtained out-of-band without any opened pool.
+///
+/// Note: freshness of the reply isn't checked (use `get_response_metadata` for that).
+///
+/// # Arguments
+/// * `request_json` - read request the reply is received on.
+/// * `response_json` - node's reply on the read request.
+/// * `bls_keys_json` - BLS keys of the pool nodes:
+///     {
+///         <node alias>: <base58 encoded BLS key>,
+///         ...
+///     }
+///
+/// # Returns
+/// true - if state proof and multi signature are valid, false - otherwise.
+pub fn verify_state_proof(request_json: &str, response_json: &str, bls_keys_json: &str) -> Box<dyn Future<Item=bool, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_bool();
+
+    let err = _verify_state_proof(command_handle, request_json, response_json, bls_keys_json, cb);
+
+    ResultHandler::bool(command_handle, err, receiver)
+}
+
+fn _verify_state_proof(command_handle: CommandHandle, request_json: &str, response_json: &str, bls_keys_json: &str, cb: Option<ResponseBoolCB>) -> ErrorCode {
+    let request_json = c_str!(request_json);
+    let response_json = c_str!(response_json);
+    let bls_keys_json = c_str!(bls_keys_json);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_verify_state_proof(command_handle, request_json.as_ptr(), response_json.as_ptr(), bls_keys_json.as_ptr(), cb)
+    })
+}
+
 /// Builds a AUTH_RULE request. Request to change authentication rules for a ledger transaction.
 ///
 /// # Arguments
@@ -1203,6 +2021,142 @@ fn _build_get_auth_rule_request(command_handle: CommandHandle,
     })
 }
 
+/// Builds a ROLE constraint to be used in AUTH_RULE and AUTH_RULES requests.
+///
+/// # Arguments
+/// * `role` - role alias ("TRUSTEE", "STEWARD", "ENDORSER", "NETWORK_MONITOR", "IDENTITY_OWNER"),
+///             associated value or "*" for any role.
+/// * `sig_count` - the number of signatures required to execution action.
+/// * `need_to_be_owner` - if user must be an owner of transaction.
+/// * `off_ledger_signature` - allow signature of DID unknown for the ledger (can be set only for any role).
+/// * `metadata_json` - (Optional) additional parameters of the constraint.
+///
+/// # Returns
+/// Constraint json.
+pub fn build_role_constraint(role: &str, sig_count: u32, need_to_be_owner: bool, off_ledger_signature: bool, metadata_json: Option<&str>) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_role_constraint(command_handle, role, sig_count, need_to_be_owner, off_ledger_signature, metadata_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_role_constraint(command_handle: CommandHandle, role: &str, sig_count: u32, need_to_be_owner: bool, off_ledger_signature: bool, metadata_json: Option<&str>, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let role = c_str!(role);
+    let metadata_json_str = opt_c_str!(metadata_json);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_build_role_constraint(command_handle,
+                                           role.as_ptr(),
+                                           sig_count,
+                                           need_to_be_owner,
+                                           off_ledger_signature,
+                                           opt_c_ptr!(metadata_json, metadata_json_str),
+                                           cb)
+    })
+}
+
+/// Combines several constraints into AND or OR constraint to be used in AUTH_RULE and AUTH_RULES requests.
+///
+/// # Arguments
+/// * `combination` - "AND" (all constraints must be met) or "OR" (any of them).
+/// * `constraints_json` - json array of constraints to combine.
+///
+/// # Returns
+/// Constraint json.
+pub fn build_combined_constraint(combination: &str, constraints_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_combined_constraint(command_handle, combination, constraints_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_combined_constraint(command_handle: CommandHandle, combination: &str, constraints_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let combination = c_str!(combination);
+    let constraints_json = c_str!(constraints_json);
+
+    ErrorCode::from(unsafe { ledger::indy_build_combined_constraint(command_handle, combination.as_ptr(), constraints_json.as_ptr(), cb) })
+}
+
+/// Evaluates whether the set of signers can perform the ledger write according to the auth rules.
+///
+/// # Arguments
+/// * `auth_rules_json` - json array of auth rules.
+/// * `action_json` - prospective ledger write (`auth_type`, `auth_action`, `field`, `old_value`, `new_value`, `owner`).
+/// * `signers_json` - json array of signers (`did`, `role`, `onLedger`, `verified`).
+///
+/// # Returns
+/// Evaluation json with `allowed` flag, matched `authRule` and `missingSignatures`.
+pub fn evaluate_auth_rules(auth_rules_json: &str, action_json: &str, signers_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _evaluate_auth_rules(command_handle, auth_rules_json, action_json, signers_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _evaluate_auth_rules(command_handle: CommandHandle, auth_rules_json: &str, action_json: &str, signers_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let auth_rules_json = c_str!(auth_rules_json);
+    let action_json = c_str!(action_json);
+    let signers_json = c_str!(signers_json);
+
+    ErrorCode::from(unsafe { ledger::indy_evaluate_auth_rules(command_handle, auth_rules_json.as_ptr(), action_json.as_ptr(), signers_json.as_ptr(), cb) })
+}
+
+/// Fetches the complete set of auth rules of the ledger config and returns one page of them.
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by Pool::open_ledger).
+/// * `submitter_did` - (Optional) DID of the read request sender.
+/// * `page_options_json` - (Optional) pagination options (`offset`, `limit`), all the rules are returned if not set.
+///
+/// # Returns
+/// Auth rules page json with `rules`, `total` and `nextOffset` (absent for the last page).
+pub fn get_auth_rules(pool_handle: PoolHandle, submitter_did: Option<&str>, page_options_json: Option<&str>) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _get_auth_rules(command_handle, pool_handle, submitter_did, page_options_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _get_auth_rules(command_handle: CommandHandle, pool_handle: PoolHandle, submitter_did: Option<&str>, page_options_json: Option<&str>, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did_str = opt_c_str!(submitter_did);
+    let page_options_json_str = opt_c_str!(page_options_json);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_get_auth_rules(command_handle,
+                                    pool_handle,
+                                    opt_c_ptr!(submitter_did, submitter_did_str),
+                                    opt_c_ptr!(page_options_json, page_options_json_str),
+                                    cb)
+    })
+}
+
+/// Compares the auth rules of the ledger with a proposed set of auth rules.
+///
+/// # Arguments
+/// * `current_auth_rules_json` - json array of the current auth rules.
+/// * `proposed_auth_rules_json` - json array of the proposed auth rules.
+///
+/// # Returns
+/// Diff json with `added`, `removed`, `changed` rules and the number of `unchanged` ones.
+pub fn diff_auth_rules(current_auth_rules_json: &str, proposed_auth_rules_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _diff_auth_rules(command_handle, current_auth_rules_json, proposed_auth_rules_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _diff_auth_rules(command_handle: CommandHandle, current_auth_rules_json: &str, proposed_auth_rules_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let current_auth_rules_json = c_str!(current_auth_rules_json);
+    let proposed_auth_rules_json = c_str!(proposed_auth_rules_json);
+
+    ErrorCode::from(unsafe { ledger::indy_diff_auth_rules(command_handle, current_auth_rules_json.as_ptr(), proposed_auth_rules_json.as_ptr(), cb) })
+}
+
 /// Builds a TXN_AUTHR_AGRMT request. Request to add a new version of Transaction Author Agreement to the ledger.
 ///
 /// # Arguments
@@ -1473,6 +2427,59 @@ fn _append_txn_author_agreement_acceptance_to_request(command_handle: CommandHan
     })
 }
 
+/// Prepares a request for signing with acceptance of the latest transaction author agreement set on the Ledger.
+/// This function should be called instead of `append_txn_author_agreement_acceptance_to_request`
+/// if an application doesn't track the agreement by itself.
+///
+/// Latest agreement and acceptance mechanisms are requested from the Ledger and cached for the pool for 10 minutes.
+/// Acceptance is stored in the wallet for the submitter DID and reused for all the following requests.
+/// The request is returned unchanged if there is no agreement set on the Ledger.
+///
+/// # Arguments
+/// * `pool_handle`: pool handle (created by open_pool_ledger).
+/// * `wallet_handle`: wallet handle (created by open_wallet).
+/// * `submitter_did`: Id of Identity stored in secured Wallet that is going to sign the request.
+/// * `request_json`: original request data json.
+/// * `mechanism`: (optional) mechanism how user has accepted the TAA.
+///     Can be omitted if the previous acceptance of the agreement was made by the submitter or
+///     if the Ledger allows the only acceptance mechanism.
+///
+/// # Returns
+/// Updated request result as json.
+pub fn prepare_request_with_taa(pool_handle: PoolHandle,
+                                wallet_handle: WalletHandle,
+                                submitter_did: &str,
+                                request_json: &str,
+                                mechanism: Option<&str>) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _prepare_request_with_taa(command_handle, pool_handle, wallet_handle, submitter_did, request_json, mechanism, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _prepare_request_with_taa(command_handle: CommandHandle,
+                             pool_handle: PoolHandle,
+                             wallet_handle: WalletHandle,
+                             submitter_did: &str,
+                             request_json: &str,
+                             mechanism: Option<&str>,
+                             cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did = c_str!(submitter_did);
+    let request_json = c_str!(request_json);
+    let mechanism_str = opt_c_str!(mechanism);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_prepare_request_with_taa(command_handle,
+                                              pool_handle,
+                                              wallet_handle,
+                                              submitter_did.as_ptr(),
+                                              request_json.as_ptr(),
+                                              opt_c_ptr!(mechanism, mechanism_str),
+                                              cb)
+    })
+}
+
 /// Append Endorser to an existing request.
 ///
 /// An author of request still is a `DID` used as a `submitter_did` parameter for the building of the request.
@@ -1512,6 +2519,43 @@ fn _append_request_endorser(command_handle: CommandHandle,
                                              cb)
     })
 }
+
+/// Sets protocol version of an existing request.
+///
+/// Allows to prepare requests for a specific version of Indy Node without an opened pool
+/// and without changing the global protocol version (see Pool::set_protocol_version).
+///
+/// Note: Request must be signed after that.
+///
+/// # Arguments
+/// * `request_json`: original request data json.
+/// * `protocol_version`: protocol version will be used:
+///     1 - for Indy Node 1.3
+///     2 - for Indy Node 1.4 and greater
+/// # Returns
+/// Updated request result as json.
+pub fn set_request_protocol_version(request_json: &str,
+                                    protocol_version: usize) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _set_request_protocol_version(command_handle, request_json, protocol_version, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _set_request_protocol_version(command_handle: CommandHandle,
+                                 request_json: &str,
+                                 protocol_version: usize,
+                                 cb: Option<ResponseStringCB>) -> ErrorCode {
+    let request_json = c_str!(request_json);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_set_request_protocol_version(command_handle,
+                                                  request_json.as_ptr(),
+                                                  protocol_version,
+                                                  cb)
+    })
+}
 /// Request to freeze list of ledgers.
 ///
 /// # Arguments
@@ -1572,4 +2616,32 @@ fn _build_get_frozen_ledgers_request(command_handle: CommandHandle, submitter_di
                                              submitter_did.as_ptr(),
                                              cb)
     })
+}
+
+/// Parse a GET_FROZEN_LEDGERS response to get the list of frozen ledgers.
+///
+/// # Arguments
+/// * `get_frozen_ledgers_response`: response on GET_FROZEN_LEDGERS request.
+///
+/// # Returns
+/// Frozen ledgers sorted by ledger id (empty list if there are no frozen ledgers):
+/// {
+///     data: [{ledgerId: <int>, ledgerRoot: <string>, stateRoot: <string>, seqNo: <int>}, ...],
+///     seqNo: (optional) sequence number of the LEDGERS_FREEZE transaction,
+///     txnTime: (optional) time of the LEDGERS_FREEZE transaction
+/// }
+pub fn parse_get_frozen_ledgers_response(get_frozen_ledgers_response: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+    let err = _parse_get_frozen_ledgers_response(command_handle, get_frozen_ledgers_response, cb);
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _parse_get_frozen_ledgers_response(command_handle: CommandHandle, get_frozen_ledgers_response: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let get_frozen_ledgers_response = c_str!(get_frozen_ledgers_response);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_parse_get_frozen_ledgers_response(command_handle,
+                                                       get_frozen_ledgers_response.as_ptr(),
+                                                       cb)
+    })
 }
\ No newline at end of file
@@ -37,9 +37,13 @@ mod utils;
 use std::ffi::CString;
 use std::fmt;
 use std::ptr;
+use std::ptr::null;
 use std::ffi::CStr;
 
 use failure::{Backtrace, Fail};
+use futures::Future;
+
+use utils::callbacks::{ClosureHandler, ResultHandler};
 
 pub use ffi::{
     RecordHandle,
@@ -57,14 +61,29 @@ pub use ffi::{
     StorageHandle,
     INVALID_WALLET_HANDLE,
     INVALID_POOL_HANDLE,
-    INVALID_COMMAND_HANDLE
+    INVALID_COMMAND_HANDLE,
+    PreCommandHookCB,
+    PostCommandHookCB,
+    AuditCB
 };
 
 /// Set libindy runtime configuration. Can be optionally called to change current params.
 ///
 /// # Arguments
 /// * `config` - {
-///     "crypto_thread_pool_size": <int> - size of thread pool for the most expensive crypto operations. (4 by default)
+///     "crypto_thread_pool_size": <int> - size of thread pool for the most expensive crypto operations
+///         like wallet key derivation and credential definition creation. (4 by default)
+///     "anoncreds_worker_threads": <int> - number of worker threads creating and verifying proofs,
+///         so these operations don't block other commands. Proofs of the same wallet are created in
+///         submission order. Other commands still run on the single command thread. (4 by default)
+///     "metrics_buckets_ms": <array<float>> - increasing upper bounds of command duration histogram buckets in milliseconds.
+///         Must be set before invocation of any other API functions.
+///     "log_format": <string> - format of log records: "text" (default) or "json".
+///     "slow_operation_threshold_ms": <int> - wallet storage calls and pool requests lasting longer are logged. (0 - disabled by default)
+///     "max_pending_commands": <int> - maximum number of commands waiting for execution, further calls fail with CommonQueueFull. (0 - unbounded by default)
+///     "queue_full_timeout_ms": <int> - how long calls wait for the full queue to drain before failing. (0 - fail at once by default)
+///     "command_timeout_ms": <int> - calls that haven't completed in this time fail with CommonTimeout. (0 - disabled by default)
+///         Streaming calls restart it on every chunk. Operation running in a wallet storage plugin can't be cancelled.
 /// }
 pub fn set_runtime_config(config: &str) -> ErrorCode {
     let config = c_str!(config);
@@ -74,6 +93,64 @@ pub fn set_runtime_config(config: &str) -> ErrorCode {
     })
 }
 
+/// Register hooks invoked around execution of every libindy command.
+/// Hooks are called from libindy threads, so they should return fast.
+/// Registering new hooks replaces the previous ones.
+///
+/// # Arguments
+/// * `pre_cb` - called before command execution with snake case command name and digest of parameters of the call.
+/// * `post_cb` - called once the callback of the call is called with command name, parameters digest,
+///   duration from the call to the callback in milliseconds and result code passed to the callback.
+pub fn register_command_hook(pre_cb: Option<PreCommandHookCB>, post_cb: Option<PostCommandHookCB>) -> ErrorCode {
+    ErrorCode::from(unsafe {
+        ffi::indy_register_command_hook(pre_cb, post_cb)
+    })
+}
+
+/// Register callback receiving audit events of sensitive operations (key creation, signing,
+/// DID keys rotation, wallet export, credential issuance). Pass `None` to disable audit.
+/// Events are only passed to the callback, libindy doesn't persist them.
+///
+/// # Arguments
+/// * `context` - application context passed to the callback.
+/// * `audit_cb` - called with context and audit event JSON.
+pub fn register_audit_callback(context: *const ffi::CVoid, audit_cb: Option<AuditCB>) -> ErrorCode {
+    ErrorCode::from(unsafe {
+        ffi::indy_register_audit_callback(context, audit_cb)
+    })
+}
+
+/// Set audit context of the calling thread. Audit events of operations called from this thread contain it.
+///
+/// # Arguments
+/// * `context_json` - (optional) JSON object supplied by the application.
+pub fn set_audit_context(context_json: Option<&str>) -> ErrorCode {
+    let context_json_str = opt_c_str!(context_json);
+
+    ErrorCode::from(unsafe {
+        ffi::indy_set_audit_context(opt_c_ptr!(context_json, context_json_str))
+    })
+}
+
+/// Gracefully shut libindy down: stop accepting new calls, wait for the queued ones and close opened searches, wallets and pools.
+///
+/// # Arguments
+/// * `force` - cancel the queued calls instead of waiting for them.
+/// * `timeout_ms` - how long to wait for the queued calls before cancelling them. (0 - wait without limit)
+pub fn shutdown(force: bool, timeout_ms: u64) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _shutdown(command_handle, force, timeout_ms, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _shutdown(command_handle: CommandHandle, force: bool, timeout_ms: u64, cb: Option<ffi::ResponseEmptyCB>) -> ErrorCode {
+    ErrorCode::from(unsafe {
+        ffi::indy_shutdown(command_handle, force, timeout_ms, cb)
+    })
+}
+
 #[derive(Fail, Debug, PartialEq, Copy, Clone, FromPrimitive, ToPrimitive)]
 #[repr(i32)]
 #[allow(dead_code)]
@@ -179,6 +256,12 @@ pub enum ErrorCode
     // Caller passed invalid value as param 27 (null, invalid json and etc..)
     #[fail(display = "CommonInvalidParam27")]
     CommonInvalidParam27 = 129,
+    // Too many commands are waiting for execution
+    #[fail(display = "CommonQueueFull")]
+    CommonQueueFull = 130,
+    // Command hasn't completed in the configured timeout
+    #[fail(display = "CommonTimeout")]
+    CommonTimeout = 131,
     // Wallet errors
     // Caller passed invalid wallet handle
     #[fail(display = "WalletInvalidHandle")]
@@ -228,6 +311,9 @@ pub enum ErrorCode
     // Returned if provided wallet query is invalid
     #[fail(display = "WalletQueryError")]
     WalletQueryError = 214,
+    // Returned if wallet item was modified since expected state was read
+    #[fail(display = "WalletItemConflict")]
+    WalletItemConflict = 215,
     // Ledger errors
     // Trying to open pool ledger that wasn't created before
     #[fail(display = "PoolLedgerNotCreatedError")]
@@ -286,6 +372,9 @@ pub enum ErrorCode
     // Attempt to create duplicate did
     #[fail(display = "DidAlreadyExistsError")]
     DidAlreadyExistsError = 600,
+    // Attempt to sign with deactivated did
+    #[fail(display = "DidDeactivatedError")]
+    DidDeactivatedError = 601,
     // Unknown payment method was given
     #[fail(display = "UnknownPaymentMethod")]
     UnknownPaymentMethod = 700,
@@ -336,7 +425,10 @@ impl Into<i32> for ErrorCode {
 pub struct IndyError {
     pub error_code: ErrorCode,
     pub message: String,
-    pub indy_backtrace: Option<String>
+    pub indy_backtrace: Option<String>,
+    pub component: Option<String>,
+    pub retriable: bool,
+    pub causes: Vec<String>,
 }
 
 impl Fail for IndyError {
@@ -368,6 +460,9 @@ impl IndyError {
                     error_code: ErrorCode::CommonInvalidState,
                     message: String::from("Invalid ErrorMessage pointer"),
                     indy_backtrace: None,
+                    component: None,
+                    retriable: false,
+                    causes: Vec::new(),
                 };
             }
         };
@@ -377,11 +472,17 @@ impl IndyError {
                 error_code,
                 message: error.message,
                 indy_backtrace: error.backtrace,
+                component: error.component,
+                retriable: error.retriable,
+                causes: error.causes,
             },
             Err(err) => IndyError {
                 error_code: ErrorCode::CommonInvalidState,
                 message: err.to_string(),
                 indy_backtrace: None,
+                component: None,
+                retriable: false,
+                causes: Vec::new(),
             }
         }
     }
@@ -390,5 +491,10 @@ impl IndyError {
 #[derive(Deserialize)]
 struct ErrorDetails {
     message: String,
-    backtrace: Option<String>
+    backtrace: Option<String>,
+    component: Option<String>,
+    #[serde(default)]
+    retriable: bool,
+    #[serde(default)]
+    causes: Vec<String>,
 }
\ No newline at end of file
@@ -32,6 +32,24 @@ pub fn set_default_logger(pattern: Option<&str>) -> Result<(), IndyError> {
     }
 }
 
+/// Set log level and target filter of the installed Libindy logger.
+///
+/// # Arguments
+/// * `filter` - comma separated list of `target=level` or `level` directives,
+///     e.g. "indy::services::pool=trace,indy::services::wallet=warn".
+pub fn set_log_filter(filter: &str) -> Result<(), IndyError> {
+    let filter = c_str!(filter);
+
+    let res = ErrorCode::from(unsafe {
+        logger::indy_set_log_filter(filter.as_ptr())
+    });
+
+    match res {
+        ErrorCode::Success => Ok(()),
+        err => Err(IndyError::new(err))
+    }
+}
+
 /// Set application logger implementation to Libindy.
 ///
 /// # Arguments
@@ -44,6 +62,9 @@ pub fn set_logger(logger: &'static dyn Log) -> Result<(), IndyError> {
                     error_code: ErrorCode::CommonInvalidState,
                     message: "Logger is already set".to_string(),
                     indy_backtrace: None,
+                    component: None,
+                    retriable: false,
+                    causes: Vec::new(),
                 });
             }
             LOGGER = Some(Box::new(logger));
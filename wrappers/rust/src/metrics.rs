@@ -6,7 +6,7 @@ use ffi::metrics;
 
 use utils::callbacks::{ClosureHandler, ResultHandler};
 
-use ffi::ResponseStringCB;
+use ffi::{ResponseEmptyCB, ResponseStringCB};
 use CommandHandle;
 
 /// Collect metrics from libindy.
@@ -26,3 +26,72 @@ fn _collect_metrics(command_handle: CommandHandle, cb: Option<ResponseStringCB>)
       metrics::indy_collect_metrics(command_handle, cb)
     })
 }
+
+/// Collect metrics from libindy in Prometheus text exposition format.
+///
+/// # Returns
+/// String with metrics in Prometheus text format ready to be served to a scraper.
+pub fn collect_metrics_prometheus() -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _collect_metrics_prometheus(command_handle, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _collect_metrics_prometheus(command_handle: CommandHandle, cb: Option<ResponseStringCB>) -> ErrorCode {
+    ErrorCode::from(unsafe {
+      metrics::indy_collect_metrics_prometheus(command_handle, cb)
+    })
+}
+
+/// Collect metrics accumulated since the previous call of this function (or since reset).
+///
+/// # Returns
+/// String with a dictionary of metrics in JSON format of `collect_metrics`.
+pub fn collect_metrics_delta() -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _collect_metrics_delta(command_handle, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _collect_metrics_delta(command_handle: CommandHandle, cb: Option<ResponseStringCB>) -> ErrorCode {
+    ErrorCode::from(unsafe {
+      metrics::indy_collect_metrics_delta(command_handle, cb)
+    })
+}
+
+/// Reset metric counters of libindy. Gauges are not affected.
+pub fn reset_metrics() -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _reset_metrics(command_handle, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _reset_metrics(command_handle: CommandHandle, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    ErrorCode::from(unsafe {
+      metrics::indy_reset_metrics(command_handle, cb)
+    })
+}
+
+/// List currently open wallet, pool, search and blob storage handles.
+///
+/// # Returns
+/// String with JSON array of handles with their type, name and age in milliseconds.
+pub fn list_handles() -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _list_handles(command_handle, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _list_handles(command_handle: CommandHandle, cb: Option<ResponseStringCB>) -> ErrorCode {
+    ErrorCode::from(unsafe {
+      metrics::indy_list_handles(command_handle, cb)
+    })
+}
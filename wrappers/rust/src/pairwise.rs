@@ -10,8 +10,9 @@ use utils::callbacks::{ClosureHandler, ResultHandler};
 use ffi::pairwise;
 use ffi::{ResponseEmptyCB,
           ResponseStringCB,
-          ResponseBoolCB};
-use {WalletHandle, CommandHandle};
+          ResponseBoolCB,
+          ResponseI32UsizeCB};
+use {WalletHandle, CommandHandle, SearchHandle};
 
 pub fn is_pairwise_exists(wallet_handle: WalletHandle, their_did: &str) -> Box<dyn Future<Item=bool, Error=IndyError>> {
     let (receiver, command_handle, cb) = ClosureHandler::cb_ec_bool();
@@ -93,3 +94,165 @@ fn _set_pairwise_metadata(command_handle: CommandHandle, wallet_handle: WalletHa
         pairwise::indy_set_pairwise_metadata(command_handle, wallet_handle, their_did.as_ptr(), opt_c_ptr!(metadata, metadata_str), cb)
     })
 }
+
+pub fn update_pairwise(wallet_handle: WalletHandle, their_did: &str, new_their_did: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _update_pairwise(command_handle, wallet_handle, their_did, new_their_did, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _update_pairwise(command_handle: CommandHandle, wallet_handle: WalletHandle, their_did: &str, new_their_did: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let their_did = c_str!(their_did);
+    let new_their_did = c_str!(new_their_did);
+
+    ErrorCode::from(unsafe {
+        pairwise::indy_update_pairwise(command_handle, wallet_handle, their_did.as_ptr(), new_their_did.as_ptr(), cb)
+    })
+}
+
+pub fn delete_pairwise(wallet_handle: WalletHandle, their_did: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _delete_pairwise(command_handle, wallet_handle, their_did, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _delete_pairwise(command_handle: CommandHandle, wallet_handle: WalletHandle, their_did: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let their_did = c_str!(their_did);
+
+    ErrorCode::from(unsafe {
+        pairwise::indy_delete_pairwise(command_handle, wallet_handle, their_did.as_ptr(), cb)
+    })
+}
+
+pub fn link_pairwise_record(wallet_handle: WalletHandle, their_did: &str, record_type: &str, record_id: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _link_pairwise_record(command_handle, wallet_handle, their_did, record_type, record_id, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _link_pairwise_record(command_handle: CommandHandle, wallet_handle: WalletHandle, their_did: &str, record_type: &str, record_id: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let their_did = c_str!(their_did);
+    let record_type = c_str!(record_type);
+    let record_id = c_str!(record_id);
+
+    ErrorCode::from(unsafe {
+        pairwise::indy_link_pairwise_record(command_handle, wallet_handle, their_did.as_ptr(), record_type.as_ptr(), record_id.as_ptr(), cb)
+    })
+}
+
+pub fn unlink_pairwise_record(wallet_handle: WalletHandle, their_did: &str, record_type: &str, record_id: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _unlink_pairwise_record(command_handle, wallet_handle, their_did, record_type, record_id, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _unlink_pairwise_record(command_handle: CommandHandle, wallet_handle: WalletHandle, their_did: &str, record_type: &str, record_id: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let their_did = c_str!(their_did);
+    let record_type = c_str!(record_type);
+    let record_id = c_str!(record_id);
+
+    ErrorCode::from(unsafe {
+        pairwise::indy_unlink_pairwise_record(command_handle, wallet_handle, their_did.as_ptr(), record_type.as_ptr(), record_id.as_ptr(), cb)
+    })
+}
+
+pub fn get_pairwise_links(wallet_handle: WalletHandle, their_did: &str, record_type: Option<&str>) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _get_pairwise_links(command_handle, wallet_handle, their_did, record_type, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _get_pairwise_links(command_handle: CommandHandle, wallet_handle: WalletHandle, their_did: &str, record_type: Option<&str>, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let their_did = c_str!(their_did);
+    let record_type_str = opt_c_str!(record_type);
+
+    ErrorCode::from(unsafe {
+        pairwise::indy_get_pairwise_links(command_handle, wallet_handle, their_did.as_ptr(), opt_c_ptr!(record_type, record_type_str), cb)
+    })
+}
+
+pub fn set_pairwise_meta(wallet_handle: WalletHandle, their_did: &str, meta_json: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _set_pairwise_meta(command_handle, wallet_handle, their_did, meta_json, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _set_pairwise_meta(command_handle: CommandHandle, wallet_handle: WalletHandle, their_did: &str, meta_json: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let their_did = c_str!(their_did);
+    let meta_json = c_str!(meta_json);
+
+    ErrorCode::from(unsafe {
+        pairwise::indy_set_pairwise_meta(command_handle, wallet_handle, their_did.as_ptr(), meta_json.as_ptr(), cb)
+    })
+}
+
+pub fn migrate_pairwise_meta(wallet_handle: WalletHandle) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _migrate_pairwise_meta(command_handle, wallet_handle, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _migrate_pairwise_meta(command_handle: CommandHandle, wallet_handle: WalletHandle, cb: Option<ResponseStringCB>) -> ErrorCode {
+    ErrorCode::from(unsafe {
+        pairwise::indy_migrate_pairwise_meta(command_handle, wallet_handle, cb)
+    })
+}
+
+pub fn open_pairwise_search(wallet_handle: WalletHandle, query_json: Option<&str>, options_json: Option<&str>) -> Box<dyn Future<Item=(SearchHandle, usize), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_handle_usize();
+
+    let err = _open_pairwise_search(command_handle, wallet_handle, query_json, options_json, cb);
+
+    ResultHandler::handle_usize(command_handle, err, receiver)
+}
+
+fn _open_pairwise_search(command_handle: CommandHandle, wallet_handle: WalletHandle, query_json: Option<&str>, options_json: Option<&str>, cb: Option<ResponseI32UsizeCB>) -> ErrorCode {
+    let query_json_str = opt_c_str!(query_json);
+    let options_json_str = opt_c_str!(options_json);
+
+    ErrorCode::from(unsafe {
+        pairwise::indy_open_pairwise_search(command_handle, wallet_handle, opt_c_ptr!(query_json, query_json_str), opt_c_ptr!(options_json, options_json_str), cb)
+    })
+}
+
+pub fn fetch_pairwise(search_handle: SearchHandle, count: usize) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _fetch_pairwise(command_handle, search_handle, count, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _fetch_pairwise(command_handle: CommandHandle, search_handle: SearchHandle, count: usize, cb: Option<ResponseStringCB>) -> ErrorCode {
+    ErrorCode::from(unsafe {
+        pairwise::indy_fetch_pairwise(command_handle, search_handle, count, cb)
+    })
+}
+
+pub fn close_pairwise_search(search_handle: SearchHandle) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _close_pairwise_search(command_handle, search_handle, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _close_pairwise_search(command_handle: CommandHandle, search_handle: SearchHandle, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    ErrorCode::from(unsafe {
+        pairwise::indy_close_pairwise_search(command_handle, search_handle, cb)
+    })
+}
@@ -12,7 +12,8 @@ use ffi::{ResponseStringCB,
           ResponseSliceCB,
           ResponseBoolCB,
           WalletHandle,
-          CommandHandle
+          CommandHandle,
+          PoolHandle
 };
 
 use utils::callbacks::{ClosureHandler, ResultHandler};
@@ -74,6 +75,29 @@ fn _list_payment_addresses(command_handle: CommandHandle, wallet_handle: WalletH
     ErrorCode::from(unsafe { payments::indy_list_payment_addresses(command_handle, wallet_handle, cb) })
 }
 
+/// Fetches sources of all payment addresses that are stored in the wallet and returns consolidated balances summary
+///
+/// # Arguments
+/// * `wallet_handle` - wallet to search for payment_addresses
+/// * `pool_handle` - pool handle (created by open_pool_ledger)
+///
+/// # Returns
+/// * `balances_json` - {
+///     balances: {<payment method>: <int>}, // total balance per payment method
+///     addresses: [{address: <str>, paymentMethod: <str>, balance: <int>, sources: [<object>], error: <str>}]
+/// }
+pub fn get_payment_balances(wallet_handle: WalletHandle, pool_handle: PoolHandle) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _get_payment_balances(command_handle, wallet_handle, pool_handle, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _get_payment_balances(command_handle: CommandHandle, wallet_handle: WalletHandle, pool_handle: PoolHandle, cb: Option<ResponseStringCB>) -> ErrorCode {
+    ErrorCode::from(unsafe { payments::indy_get_payment_balances(command_handle, wallet_handle, pool_handle, cb) })
+}
+
 /// Modifies Indy request by adding information how to pay fees for this transaction
 /// according to selected payment method.
 ///
@@ -148,6 +172,89 @@ fn _add_request_fees(command_handle: CommandHandle,
     })
 }
 
+/// Prepares Indy write request for sending with fees in one call.
+///
+/// Appends TAA acceptance data (if `mechanism` is passed), fetches fees set on the ledger,
+/// adds fees information to the request and multi signs it by submitter DID.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle
+/// * `pool_handle` - pool handle used to fetch fees
+/// * `submitter_did` - DID of request sender
+/// * `req_json` - initial transaction request as json
+/// * `inputs_json` - The list of payment sources as json array: ["source1", ...]
+/// * `outputs_json` - The list of outputs as json array: [{recipient: <str>, amount: <int>}]
+/// * `extra` - optional information for payment operation
+/// * `text`, `version` - (optional) raw data about TAA from ledger
+/// * `taa_digest` - (optional) digest on text and version
+/// * `mechanism` - (optional) mechanism how user has accepted the TAA
+/// * `time` - UTC timestamp when user has accepted the TAA
+///
+/// # Returns
+/// * `req_with_fees_json` - Indy request with TAA acceptance and fees signed by submitter DID
+/// * `receipt_json` - expected payment of the request: {paymentMethod, txnType, fee, inputs, outputs}
+pub fn prepare_request_with_fees(wallet_handle: WalletHandle,
+                                 pool_handle: PoolHandle,
+                                 submitter_did: &str,
+                                 req_json: &str,
+                                 inputs_json: &str,
+                                 outputs_json: &str,
+                                 extra: Option<&str>,
+                                 text: Option<&str>,
+                                 version: Option<&str>,
+                                 taa_digest: Option<&str>,
+                                 mechanism: Option<&str>,
+                                 time: u64) -> Box<dyn Future<Item=(String, String), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string_string();
+
+    let err = _prepare_request_with_fees(command_handle, wallet_handle, pool_handle, submitter_did, req_json, inputs_json, outputs_json,
+                                         extra, text, version, taa_digest, mechanism, time, cb);
+
+    ResultHandler::str_str(command_handle, err, receiver)
+}
+
+fn _prepare_request_with_fees(command_handle: CommandHandle,
+                              wallet_handle: WalletHandle,
+                              pool_handle: PoolHandle,
+                              submitter_did: &str,
+                              req_json: &str,
+                              inputs_json: &str,
+                              outputs_json: &str,
+                              extra: Option<&str>,
+                              text: Option<&str>,
+                              version: Option<&str>,
+                              taa_digest: Option<&str>,
+                              mechanism: Option<&str>,
+                              time: u64,
+                              cb: Option<ResponseStringStringCB>) -> ErrorCode {
+    let submitter_did = c_str!(submitter_did);
+    let req_json = c_str!(req_json);
+    let inputs_json = c_str!(inputs_json);
+    let outputs_json = c_str!(outputs_json);
+    let extra_str = opt_c_str!(extra);
+    let text_str = opt_c_str!(text);
+    let version_str = opt_c_str!(version);
+    let taa_digest_str = opt_c_str!(taa_digest);
+    let mechanism_str = opt_c_str!(mechanism);
+
+    ErrorCode::from(unsafe {
+        payments::indy_prepare_request_with_fees(command_handle,
+                                                 wallet_handle,
+                                                 pool_handle,
+                                                 submitter_did.as_ptr(),
+                                                 req_json.as_ptr(),
+                                                 inputs_json.as_ptr(),
+                                                 outputs_json.as_ptr(),
+                                                 opt_c_ptr!(extra, extra_str),
+                                                 opt_c_ptr!(text, text_str),
+                                                 opt_c_ptr!(version, version_str),
+                                                 opt_c_ptr!(taa_digest, taa_digest_str),
+                                                 opt_c_ptr!(mechanism, mechanism_str),
+                                                 time,
+                                                 cb)
+    })
+}
+
 /// Parses response for Indy request with fees.
 ///
 /// # Arguments
@@ -300,6 +407,67 @@ fn _parse_get_payment_sources_with_from_response(command_handle: CommandHandle,
     ErrorCode::from(unsafe { payments::indy_parse_get_payment_sources_with_from_response(command_handle, payment_method.as_ptr(), resp_json.as_ptr(), cb) })
 }
 
+/// Builds Indy request for getting transaction history of payment address
+/// according to specified payment method.
+///
+/// Payment method must have history handlers registered.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle
+/// * `submitter_did` - (Optional) DID of request sender
+/// * `payment_address` - target payment address
+/// * `from` - (Optional) shift to the next slice of payment history
+///
+/// # Returns
+/// * `get_history_txn_json` - Indy request for getting transaction history of payment address
+/// * `payment_method`
+pub fn build_get_payment_history_request(wallet_handle: WalletHandle, submitter_did: Option<&str>, payment_address: &str, from: Option<i64>) -> Box<dyn Future<Item=(String, String), Error=IndyError>> {
+    let (receiver, command_handle, cb) =
+        ClosureHandler::cb_ec_string_string();
+
+    let err = _build_get_payment_history_request(command_handle, wallet_handle, submitter_did, payment_address, from, cb);
+
+    ResultHandler::str_str(command_handle, err, receiver)
+}
+
+fn _build_get_payment_history_request(command_handle: CommandHandle, wallet_handle: WalletHandle, submitter_did: Option<&str>, payment_address: &str, from: Option<i64>, cb: Option<ResponseStringStringCB>) -> ErrorCode {
+    let submitter_did_str = opt_c_str!(submitter_did);
+    let payment_address = c_str!(payment_address);
+
+    ErrorCode::from(unsafe { payments::indy_build_get_payment_history_request(command_handle, wallet_handle, opt_c_ptr!(submitter_did, submitter_did_str), payment_address.as_ptr(), from.unwrap_or(-1), cb) })
+}
+
+/// Parses response for Indy request for getting transaction history of payment address.
+///
+/// # Arguments
+/// * `payment_method`
+/// * `resp_json` - response for Indy request for getting transaction history
+///
+/// # Returns
+/// * `history_json` - parsed (payment method and node version agnostic) transactions as json:
+///   [{
+///      receipt: <str>, // receipt of the transaction output related to the address
+///      txnTime: <int>, // (Optional) time when transaction was written to the ledger
+///      sources: [<str>, ] // transaction inputs
+///      receipts: [ {recipient: <str>, receipt: <str>, amount: <int>}, ] // transaction outputs
+///      extra: <str>, // (Optional) extra data of the transaction
+///   }]
+/// * `next` - (Optional) shift to the next slice of payment history
+pub fn parse_get_payment_history_response(payment_method: &str, resp_json: &str) -> Box<dyn Future<Item=(String, Option<i64>), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string_i64();
+
+    let err = _parse_get_payment_history_response(command_handle, payment_method, resp_json, cb);
+
+    Box::new(ResultHandler::str_i64(command_handle, err, receiver).map(|(s, i)| (s, if i >= 0 {Some(i)} else {None})).into_future())
+}
+
+fn _parse_get_payment_history_response(command_handle: CommandHandle, payment_method: &str, resp_json: &str, cb: Option<ResponseStringI64CB>) -> ErrorCode {
+    let payment_method = c_str!(payment_method);
+    let resp_json = c_str!(resp_json);
+
+    ErrorCode::from(unsafe { payments::indy_parse_get_payment_history_response(command_handle, payment_method.as_ptr(), resp_json.as_ptr(), cb) })
+}
+
 /// Builds Indy request for doing tokens payment
 /// according to this payment method.
 ///
@@ -671,3 +839,106 @@ fn _verify_with_address(command_handle: CommandHandle, address: &str, message: &
                                            cb)
     })
 }
+
+/// Estimates fees that will be charged for the request
+///
+/// Payment method must be registered with v2 API.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle
+/// * `submitter_did` - (Optional) DID of request sender
+/// * `req_json` - initial transaction request as json
+/// * `inputs_json` - The list of payment sources as json array:
+///   ["source1", ...]
+///
+/// # Returns
+/// * `fees_estimate_json` - {
+///     fee: <int>, // amount that will be charged for the request
+///     <str>: <any>, // any additional payment method specific details
+/// }
+pub fn estimate_request_fees(wallet_handle: WalletHandle, submitter_did: Option<&str>, req_json: &str, inputs_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _estimate_request_fees(command_handle, wallet_handle, submitter_did, req_json, inputs_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _estimate_request_fees(command_handle: CommandHandle, wallet_handle: WalletHandle, submitter_did: Option<&str>, req_json: &str, inputs_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did_str = opt_c_str!(submitter_did);
+    let req_json = c_str!(req_json);
+    let inputs_json = c_str!(inputs_json);
+
+    ErrorCode::from(unsafe {
+        payments::indy_estimate_request_fees(command_handle, wallet_handle, opt_c_ptr!(submitter_did, submitter_did_str), req_json.as_ptr(), inputs_json.as_ptr(), cb)
+    })
+}
+
+/// Verifies a batch of payment receipts
+///
+/// Payment method must be registered with v2 API.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle
+/// * `submitter_did` - (Optional) DID of request sender
+/// * `receipts_json` - The list of payment receipts as json array:
+///   ["receipt1", ...]
+///
+/// # Returns
+/// * `receipts_info_json` - [{
+///     receipt: <str>, // verified receipt
+///     verified: <bool>, // whether receipt is confirmed by the ledger
+///     txn: <object>, // (Optional) receipt transaction info
+///     error: <str>, // (Optional) reason why receipt isn't verified
+/// }]
+pub fn verify_payment_receipts(wallet_handle: WalletHandle, submitter_did: Option<&str>, receipts_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _verify_payment_receipts(command_handle, wallet_handle, submitter_did, receipts_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _verify_payment_receipts(command_handle: CommandHandle, wallet_handle: WalletHandle, submitter_did: Option<&str>, receipts_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did_str = opt_c_str!(submitter_did);
+    let receipts_json = c_str!(receipts_json);
+
+    ErrorCode::from(unsafe {
+        payments::indy_verify_payment_receipts(command_handle, wallet_handle, opt_c_ptr!(submitter_did, submitter_did_str), receipts_json.as_ptr(), cb)
+    })
+}
+
+/// Verifies that payment receipt is included into the ledger using state proof of the reply
+///
+/// Payment method must have receipt proof parser registered. State proofs and their
+/// multi signatures are checked against BLS keys of the pool nodes.
+///
+/// # Arguments
+/// * `receipt` - payment receipt to verify
+/// * `resp_json` - response for the request built by `build_verify_payment_req`
+/// * `bls_keys_json` - BLS keys of the pool nodes as json object: {<node alias>: <base58 BLS key>}
+///
+/// # Returns
+/// * `receipt_info_json` - receipt transaction info confirmed by state proof
+///   {
+///     sources: [<str>, ]
+///     receipts: [ {recipient: <str>, receipt: <str>, amount: <int>}, ]
+///     extra: <str>,
+///   }
+pub fn verify_payment_receipt_with_proof(receipt: &str, resp_json: &str, bls_keys_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _verify_payment_receipt_with_proof(command_handle, receipt, resp_json, bls_keys_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _verify_payment_receipt_with_proof(command_handle: CommandHandle, receipt: &str, resp_json: &str, bls_keys_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let receipt = c_str!(receipt);
+    let resp_json = c_str!(resp_json);
+    let bls_keys_json = c_str!(bls_keys_json);
+
+    ErrorCode::from(unsafe {
+        payments::indy_verify_payment_receipt_with_proof(command_handle, receipt.as_ptr(), resp_json.as_ptr(), bls_keys_json.as_ptr(), cb)
+    })
+}
@@ -58,6 +58,20 @@ fn _create_pool_ledger_config(command_handle: CommandHandle, pool_name: &str, po
 ///     "number_read_nodes": int (optional) - the number of nodes to send read requests (2 by default)
 ///         By default Libindy sends a read requests to 2 nodes in the pool.
 ///         If response isn't received or `state proof` is invalid Libindy sends the request again but to 2 (`number_read_nodes`) * 2 = 4 nodes and so far until completion.
+///     "max_in_flight_requests": int (optional) - maximum number of requests processed by the pool at once,
+///         the next requests wait in the pool worker queue until the previous ones complete (0 - unbounded by default)
+///     "max_sockets": int (optional) - maximum number of node sockets opened by the pool handle.
+///         Every pool connection can open a socket to each node, so when the limit doesn't allow a new connection
+///         the requests share the most recent one. At least one connection is always opened. (0 - unbounded by default)
+///     "conn_count_per_node": int (optional) - number of sockets opened to each node for new requests.
+///         Requests are distributed over them round-robin, so they are pipelined rather than serialized on a single socket
+///         per node. Sockets still count against `max_sockets`. (1 by default)
+///     "reply_capture_size": int (optional) - number of the last requests recorded with raw node replies to them
+///         for debugging, see `get_pool_reply_capture` (0 - disabled by default)
+///     "read_connections": int (optional) - number of connections to the pool, each with its own worker thread and node sockets.
+///         Read requests are distributed over the connections round-robin, other requests are sent by the first one.
+///         Additional connections are opened in background after the pool is opened, replies of them aren't captured. (1 by default)
+///     Note: pool worker thread is named `indy-pool-<config_name>`.
 /// }
 ///
 /// # Returns
@@ -165,3 +179,26 @@ fn _set_protocol_version(command_handle: CommandHandle, protocol_version: usize,
       pool::indy_set_protocol_version(command_handle, protocol_version, cb)
     })
 }
+
+/// Returns the last requests sent by the pool with raw node replies to them.
+///
+/// Requests are recorded only if the pool is opened with `reply_capture_size` option.
+///
+/// # Arguments
+/// * `pool_handle` - pool handle returned by `open_pool_ledger`.
+///
+/// # Returns
+/// Json array of captured requests (`reqId`, `request`, `size`, `timestamp`) with node `replies`.
+pub fn get_pool_reply_capture(pool_handle: PoolHandle) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _get_pool_reply_capture(command_handle, pool_handle, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _get_pool_reply_capture(command_handle: CommandHandle, pool_handle: PoolHandle, cb: Option<ResponseStringCB>) -> ErrorCode {
+    ErrorCode::from(unsafe {
+        pool::indy_get_pool_reply_capture(command_handle, pool_handle, cb)
+    })
+}
@@ -2,8 +2,12 @@ use futures::Future;
 
 use {ErrorCode, IndyError};
 
-use std::ffi::CString;
+use std::collections::HashMap;
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
 use std::ptr::null;
+use std::slice;
+use std::sync::Mutex;
 
 use utils::callbacks::{ClosureHandler, ResultHandler};
 
@@ -14,6 +18,8 @@ use ffi::{ResponseEmptyCB,
           ResponseWalletHandleCB};
 use {CommandHandle, WalletHandle, SearchHandle};
 
+pub use ffi::non_secrets::ModifyWalletRecordValueCB;
+
 /// Registers custom wallet implementation.
 ///
 /// It allows library user to provide custom wallet implementation.
@@ -148,6 +154,49 @@ fn _register_storage(command_handle: CommandHandle,
     })
 }
 
+/// Unregisters custom wallet storage implementation.
+///
+/// Storage type can't be unregistered while wallets that use it are open.
+///
+/// # Arguments
+/// * `xtype` - storage type name.
+pub fn unregister_wallet_storage(xtype: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _unregister_storage(command_handle, xtype, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _unregister_storage(command_handle: CommandHandle, xtype: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let xtype = c_str!(xtype);
+
+    ErrorCode::from(unsafe { wallet::indy_unregister_wallet_storage(command_handle, xtype.as_ptr(), cb) })
+}
+
+/// Sets extended WQL operators supported by custom wallet storage implementation.
+///
+/// Queries using extended operators (for example `$contains`) are rejected unless wallet storage supports them.
+/// Applies to wallets opened after the call.
+///
+/// # Arguments
+/// * `xtype` - storage type name.
+/// * `extensions_json` - JSON array of operator names, for example ["$contains", "$match"].
+pub fn set_wallet_storage_query_extensions(xtype: &str, extensions_json: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _set_storage_query_extensions(command_handle, xtype, extensions_json, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _set_storage_query_extensions(command_handle: CommandHandle, xtype: &str, extensions_json: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let xtype = c_str!(xtype);
+    let extensions_json = c_str!(extensions_json);
+
+    ErrorCode::from(unsafe { wallet::indy_set_wallet_storage_query_extensions(command_handle, xtype.as_ptr(), extensions_json.as_ptr(), cb) })
+}
+
 /// Creates a new secure wallet with the given unique name.
 ///
 /// # Arguments
@@ -380,6 +429,80 @@ fn _update_wallet_record_value(command_handle: CommandHandle, wallet_handle: Wal
     })
 }
 
+/// Update a non-secret wallet record value only if it wasn't changed since it was read (compare-and-swap)
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by open_wallet)
+/// * `xtype` - allows to separate different record types collections
+/// * `id` - the id of record
+/// * `expected_value_hash` - hex encoded SHA-256 hash of the record value the update is based on
+/// * `value` - the new value of record
+///
+/// # Returns
+/// hex encoded SHA-256 hash of the new record value.
+/// `WalletItemConflict` error is returned if record value was changed since expected value was read.
+pub fn compare_and_swap_wallet_record_value(wallet_handle: WalletHandle, xtype: &str, id: &str, expected_value_hash: &str, value: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _compare_and_swap_wallet_record_value(command_handle, wallet_handle, xtype, id, expected_value_hash, value, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _compare_and_swap_wallet_record_value(command_handle: CommandHandle, wallet_handle: WalletHandle, xtype: &str, id: &str, expected_value_hash: &str, value: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let xtype = c_str!(xtype);
+    let id = c_str!(id);
+    let expected_value_hash = c_str!(expected_value_hash);
+    let value = c_str!(value);
+
+    ErrorCode::from(unsafe{
+        non_secrets::indy_compare_and_swap_wallet_record_value(command_handle,
+                                                               wallet_handle,
+                                                               xtype.as_ptr(),
+                                                               id.as_ptr(),
+                                                               expected_value_hash.as_ptr(),
+                                                               value.as_ptr(),
+                                                               cb)
+    })
+}
+
+/// Atomically update a non-secret wallet record value with the value calculated from the current one
+///
+/// The record is locked while `modify_cb` calculates the new value from the current one.
+/// `modify_cb` must set `new_value_p` and return `Success` to write the new value
+/// or return any other error code to leave the record unchanged.
+/// The memory pointed by `new_value_p` must stay valid until the returned future is completed.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by open_wallet)
+/// * `xtype` - allows to separate different record types collections
+/// * `id` - the id of record
+/// * `modify_cb` - callback calculating the new value of record from the current one
+///
+/// # Returns
+/// the value written to the record
+pub fn modify_wallet_record_value(wallet_handle: WalletHandle, xtype: &str, id: &str, modify_cb: ModifyWalletRecordValueCB) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _modify_wallet_record_value(command_handle, wallet_handle, xtype, id, modify_cb, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _modify_wallet_record_value(command_handle: CommandHandle, wallet_handle: WalletHandle, xtype: &str, id: &str, modify_cb: ModifyWalletRecordValueCB, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let xtype = c_str!(xtype);
+    let id = c_str!(id);
+
+    ErrorCode::from(unsafe{
+        non_secrets::indy_modify_wallet_record_value(command_handle,
+                                                     wallet_handle,
+                                                     xtype.as_ptr(),
+                                                     id.as_ptr(),
+                                                     Some(modify_cb),
+                                                     cb)
+    })
+}
+
 /// Update a non-secret wallet record tags
 ///
 /// # Arguments
@@ -499,6 +622,42 @@ fn _delete_wallet_record(command_handle: CommandHandle, wallet_handle: WalletHan
     })
 }
 
+/// Insert new or update existing non-secret records in the wallet in a single storage transaction.
+/// Value and tags of existing records are replaced.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by open_wallet)
+/// * `xtype` - allows to separate different record types collections
+/// * `records_json` - list of records to store as json:
+///   [{
+///     "id": <str>, // the id of record
+///     "value": <str>, // the value of record
+///     "tags": <tags json>, // (optional) the record tags
+///   }]
+///
+/// # Returns
+/// * `statuses_json` - status of each record in the same order as records passed:
+///   [{
+///     "id": <str>, // the id of record
+///     "status": <str>, // "inserted" for new record or "updated" for existing one
+///   }]
+pub fn upsert_wallet_records(wallet_handle: WalletHandle, xtype: &str, records_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _upsert_wallet_records(command_handle, wallet_handle, xtype, records_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _upsert_wallet_records(command_handle: CommandHandle, wallet_handle: WalletHandle, xtype: &str, records_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let xtype = c_str!(xtype);
+    let records_json = c_str!(records_json);
+
+    ErrorCode::from(unsafe {
+      non_secrets::indy_upsert_wallet_records(command_handle, wallet_handle, xtype.as_ptr(), records_json.as_ptr(), cb)
+    })
+}
+
 /// Get an wallet record by id
 ///
 /// # Arguments
@@ -537,6 +696,72 @@ fn _get_wallet_record(command_handle: CommandHandle, wallet_handle: WalletHandle
     })
 }
 
+/// Get all record types present in the wallet with count of records of each type
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by open_wallet)
+///
+/// # Returns
+/// record types with count of records as json:
+///   {
+///     "type1": <int>, // count of records of type1
+///     ...
+///   }
+pub fn get_wallet_record_types(wallet_handle: WalletHandle) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _get_wallet_record_types(command_handle, wallet_handle, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _get_wallet_record_types(command_handle: CommandHandle, wallet_handle: WalletHandle, cb: Option<ResponseStringCB>) -> ErrorCode {
+    ErrorCode::from(unsafe {
+      non_secrets::indy_get_wallet_record_types(command_handle, wallet_handle, cb)
+    })
+}
+
+/// Explain how wallet search with the query is executed by the storage.
+///
+/// Records are only counted, so types of records created by libindy (prefixed with "Indy::") can be explained too.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by open_wallet)
+/// * `xtype` - allows to separate different record types collections
+/// * `query_json` - MongoDB style query to wallet record tags (see `open_wallet_search`)
+///
+/// # Returns
+/// search plan as json:
+///   {
+///     "query": <wql>, // optimised query that is actually executed
+///     "tags": [{ // tags used by the query
+///         "name": string,
+///         "kind": "encrypted" | "plaintext" | "range",
+///         "operators": [string], // for example "$eq", "$like"
+///         "indexed": bool, // whether all conditions on the tag can use tag index
+///     }],
+///     "usesIndex": bool, // false if all records of the type are checked
+///     "recordsOfType": <int>, // count of records of the type
+///     "estimatedScan": <int>, // estimated count of records checked to execute the query
+///     "storagePlan": [string], // (optional) plan reported by storage
+///   }
+pub fn explain_wallet_search(wallet_handle: WalletHandle, xtype: &str, query_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _explain_wallet_search(command_handle, wallet_handle, xtype, query_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _explain_wallet_search(command_handle: CommandHandle, wallet_handle: WalletHandle, xtype: &str, query_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let xtype = c_str!(xtype);
+    let query_json = c_str!(query_json);
+
+    ErrorCode::from(unsafe {
+      non_secrets::indy_explain_wallet_search(command_handle, wallet_handle, xtype.as_ptr(), query_json.as_ptr(), cb)
+    })
+}
+
 /// Search for wallet records.
 ///
 /// Note instead of immediately returning of fetched records
@@ -561,6 +786,7 @@ fn _get_wallet_record(command_handle: CommandHandle, wallet_handle: WalletHandle
 ///    retrieveType: (optional, false by default) Retrieve record type,
 ///    retrieveValue: (optional, true by default) Retrieve record value,
 ///    retrieveTags: (optional, false by default) Retrieve record tags,
+///      list of tag names can be passed instead of flag to retrieve only these tags: ["tagName1", "~tagName2"]
 ///  }
 /// # Returns
 /// * `search_handle` - Wallet search handle that can be used later
@@ -635,6 +861,228 @@ fn _close_wallet_search(command_handle: CommandHandle, wallet_search_handle: Sea
     })
 }
 
+lazy_static! {
+    static ref STREAM_RECORDS_CALLBACKS: Mutex<HashMap<CommandHandle, Box<dyn Fn(String) + Send>>> = Default::default();
+    static ref STREAM_EXPORT_CALLBACKS: Mutex<HashMap<CommandHandle, Box<dyn Fn(&[u8]) + Send>>> = Default::default();
+}
+
+/// Streams wallet records matching the query.
+///
+/// Records are passed to `records_cb` by chunks as soon as every chunk is fetched.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by open_wallet)
+/// * `xtype` - allows to separate different record types collections
+/// * `query_json` - MongoDB style query to wallet record tags (see open_wallet_search)
+/// * `options_json` - search options (see open_wallet_search)
+/// * `chunk_size` - maximum count of records passed to `records_cb` at once
+/// * `records_cb` - callback that takes a chunk of records in the format of fetch_wallet_search_next_records result
+pub fn stream_wallet_records(wallet_handle: WalletHandle, xtype: &str, query_json: &str, options_json: &str, chunk_size: u32,
+                             records_cb: Box<dyn Fn(String) + Send>) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    STREAM_RECORDS_CALLBACKS.lock().unwrap().insert(command_handle, records_cb);
+
+    let err = _stream_wallet_records(command_handle, wallet_handle, xtype, query_json, options_json, chunk_size, cb);
+
+    Box::new(ResultHandler::empty(command_handle, err, receiver)
+        .then(move |res| {
+            STREAM_RECORDS_CALLBACKS.lock().unwrap().remove(&command_handle);
+            res
+        }))
+}
+
+extern fn _stream_wallet_records_callback(command_handle: CommandHandle, records_json: *const c_char) {
+    if let Some(records_cb) = STREAM_RECORDS_CALLBACKS.lock().unwrap().get(&command_handle) {
+        records_cb(rust_str!(records_json))
+    }
+}
+
+fn _stream_wallet_records(command_handle: CommandHandle, wallet_handle: WalletHandle, xtype: &str, query_json: &str, options_json: &str, chunk_size: u32, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let xtype = c_str!(xtype);
+    let query_json = c_str!(query_json);
+    let options_json = c_str!(options_json);
+
+    ErrorCode::from(unsafe {
+      non_secrets::indy_stream_wallet_records(command_handle, wallet_handle, xtype.as_ptr(), query_json.as_ptr(), options_json.as_ptr(), chunk_size, Some(_stream_wallet_records_callback), cb)
+    })
+}
+
+/// Enables revision history of wallet records of the type.
+/// Before record is updated or deleted its current state is saved as the next revision.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by open_wallet)
+/// * `xtype` - record type. Besides non-secret types "Indy::Credential" and "Indy::DidMetadata" are allowed
+/// * `config_json` - history config as json:
+///   {
+///     "maxRevisions": <int>, // (optional) count of the latest revisions to keep for each record
+///   }
+pub fn enable_wallet_record_history(wallet_handle: WalletHandle, xtype: &str, config_json: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _enable_wallet_record_history(command_handle, wallet_handle, xtype, config_json, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _enable_wallet_record_history(command_handle: CommandHandle, wallet_handle: WalletHandle, xtype: &str, config_json: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let xtype = c_str!(xtype);
+    let config_json = c_str!(config_json);
+
+    ErrorCode::from(unsafe {
+      non_secrets::indy_enable_wallet_record_history(command_handle, wallet_handle, xtype.as_ptr(), config_json.as_ptr(), cb)
+    })
+}
+
+/// Disables revision history of wallet records of the type. Already saved revisions are kept.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by open_wallet)
+/// * `xtype` - record type
+pub fn disable_wallet_record_history(wallet_handle: WalletHandle, xtype: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _disable_wallet_record_history(command_handle, wallet_handle, xtype, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _disable_wallet_record_history(command_handle: CommandHandle, wallet_handle: WalletHandle, xtype: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let xtype = c_str!(xtype);
+
+    ErrorCode::from(unsafe {
+      non_secrets::indy_disable_wallet_record_history(command_handle, wallet_handle, xtype.as_ptr(), cb)
+    })
+}
+
+/// Gets the list of saved revisions of wallet record
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by open_wallet)
+/// * `xtype` - record type
+/// * `id` - the id of record
+///
+/// # Returns
+/// revisions from the oldest to the latest one:
+///   [{
+///     "revision": <int>, // number of revision
+///     "replacedAt": <int>, // time (in seconds since epoch) when the state was replaced
+///   }]
+pub fn get_wallet_record_revisions(wallet_handle: WalletHandle, xtype: &str, id: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _get_wallet_record_revisions(command_handle, wallet_handle, xtype, id, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _get_wallet_record_revisions(command_handle: CommandHandle, wallet_handle: WalletHandle, xtype: &str, id: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let xtype = c_str!(xtype);
+    let id = c_str!(id);
+
+    ErrorCode::from(unsafe {
+      non_secrets::indy_get_wallet_record_revisions(command_handle, wallet_handle, xtype.as_ptr(), id.as_ptr(), cb)
+    })
+}
+
+/// Gets saved revision of wallet record
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by open_wallet)
+/// * `xtype` - record type
+/// * `id` - the id of record
+/// * `revision` - number of revision
+///
+/// # Returns
+/// prior state of the record:
+///   {
+///     "revision": <int>, // number of revision
+///     "value": <str>, // the value of record
+///     "tags": <tags json>, // the record tags
+///     "replacedAt": <int>, // time (in seconds since epoch) when the state was replaced
+///   }
+pub fn get_wallet_record_revision(wallet_handle: WalletHandle, xtype: &str, id: &str, revision: u32) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _get_wallet_record_revision(command_handle, wallet_handle, xtype, id, revision, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _get_wallet_record_revision(command_handle: CommandHandle, wallet_handle: WalletHandle, xtype: &str, id: &str, revision: u32, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let xtype = c_str!(xtype);
+    let id = c_str!(id);
+
+    ErrorCode::from(unsafe {
+      non_secrets::indy_get_wallet_record_revision(command_handle, wallet_handle, xtype.as_ptr(), id.as_ptr(), revision, cb)
+    })
+}
+
+/// Deletes saved revisions of wallet record except `keep_last` latest ones
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by open_wallet)
+/// * `xtype` - record type
+/// * `id` - the id of record
+/// * `keep_last` - count of the latest revisions to keep
+pub fn prune_wallet_record_history(wallet_handle: WalletHandle, xtype: &str, id: &str, keep_last: u32) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _prune_wallet_record_history(command_handle, wallet_handle, xtype, id, keep_last, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _prune_wallet_record_history(command_handle: CommandHandle, wallet_handle: WalletHandle, xtype: &str, id: &str, keep_last: u32, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let xtype = c_str!(xtype);
+    let id = c_str!(id);
+
+    ErrorCode::from(unsafe {
+      non_secrets::indy_prune_wallet_record_history(command_handle, wallet_handle, xtype.as_ptr(), id.as_ptr(), keep_last, cb)
+    })
+}
+
+/// Streams export of opened wallet.
+///
+/// Exported content is passed to `data_cb` by chunks. Concatenated chunks have the same format
+/// as the file written by export_wallet.
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle returned by indy_open_wallet
+/// * `export_config` - JSON containing settings for input operation.
+///   {
+///     "key": passphrase used to derive export key
+///   }
+/// * `data_cb` - callback that takes next chunk of exported wallet content
+pub fn stream_export_wallet(wallet_handle: WalletHandle, export_config: &str, data_cb: Box<dyn Fn(&[u8]) + Send>) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    STREAM_EXPORT_CALLBACKS.lock().unwrap().insert(command_handle, data_cb);
+
+    let err = _stream_export_wallet(command_handle, wallet_handle, export_config, cb);
+
+    Box::new(ResultHandler::empty(command_handle, err, receiver)
+        .then(move |res| {
+            STREAM_EXPORT_CALLBACKS.lock().unwrap().remove(&command_handle);
+            res
+        }))
+}
+
+extern fn _stream_export_wallet_callback(command_handle: CommandHandle, data: *const u8, data_len: u32) {
+    if let Some(data_cb) = STREAM_EXPORT_CALLBACKS.lock().unwrap().get(&command_handle) {
+        data_cb(unsafe { slice::from_raw_parts(data, data_len as usize) })
+    }
+}
+
+fn _stream_export_wallet(command_handle: CommandHandle, wallet_handle: WalletHandle, export_config: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let export_config = c_str!(export_config);
+
+    ErrorCode::from(unsafe {
+      wallet::indy_stream_export_wallet(command_handle, wallet_handle, export_config.as_ptr(), Some(_stream_export_wallet_callback), cb)
+    })
+}
+
 fn _default_credentials(credentials: Option<&str>) -> CString {
     match credentials {
         Some(s) => c_str!(s),